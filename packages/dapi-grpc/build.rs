@@ -47,15 +47,21 @@ fn configure_platform(mut platform: MappingConfig) -> MappingConfig {
     // Derive features for versioned messages
     //
     // "GetConsensusParamsRequest" is excluded as this message does not support proofs
-    const VERSIONED_REQUESTS: [&str; 26] = [
+    // "EstimateStateTransitionFeeRequest" is excluded as this message does not support proofs
+    const VERSIONED_REQUESTS: [&str; 33] = [
         "GetDataContractHistoryRequest",
         "GetDataContractRequest",
         "GetDataContractsRequest",
         "GetDocumentsRequest",
+        "GetDocumentsCountRequest",
+        "GetDocumentOffersRequest",
+        "GetDocumentHistoryRequest",
         "GetIdentitiesByPublicKeyHashesRequest",
         "GetIdentitiesRequest",
         "GetIdentityNonceRequest",
         "GetIdentityContractNonceRequest",
+        "GetIdentityPendingRecoveryRequest",
+        "GetIdentityWithdrawalsRequest",
         "GetIdentityBalanceAndRevisionRequest",
         "GetIdentityBalanceRequest",
         "GetIdentityByPublicKeyHashRequest",
@@ -73,21 +79,30 @@ fn configure_platform(mut platform: MappingConfig) -> MappingConfig {
         "GetContestedResourceVotersForIdentityRequest",
         "GetContestedResourceIdentityVotesRequest",
         "GetVotePollsByEndDateRequest",
+        "GetGovernanceProposalVotePollStateRequest",
         "GetTotalCreditsInPlatformRequest",
+        "SubscribePlatformEventsRequest",
     ];
 
     //  "GetConsensusParamsResponse" is excluded as this message does not support proofs
-    const VERSIONED_RESPONSES: [&str; 27] = [
+    //  "EstimateStateTransitionFeeResponse" is excluded as this message does not support proofs
+    //  "SubscribePlatformEventsResponse" is excluded as its proof is not part of a result
+    const VERSIONED_RESPONSES: [&str; 33] = [
         "GetDataContractHistoryResponse",
         "GetDataContractResponse",
         "GetDataContractsResponse",
         "GetDocumentsResponse",
+        "GetDocumentsCountResponse",
+        "GetDocumentOffersResponse",
+        "GetDocumentHistoryResponse",
         "GetIdentitiesByPublicKeyHashesResponse",
         "GetIdentitiesResponse",
         "GetIdentityBalanceAndRevisionResponse",
         "GetIdentityBalanceResponse",
         "GetIdentityNonceResponse",
         "GetIdentityContractNonceResponse",
+        "GetIdentityPendingRecoveryResponse",
+        "GetIdentityWithdrawalsResponse",
         "GetIdentityByPublicKeyHashResponse",
         "GetIdentityKeysResponse",
         "GetIdentityResponse",
@@ -104,6 +119,7 @@ fn configure_platform(mut platform: MappingConfig) -> MappingConfig {
         "GetContestedResourceVotersForIdentityResponse",
         "GetContestedResourceIdentityVotesResponse",
         "GetVotePollsByEndDateResponse",
+        "GetGovernanceProposalVotePollStateResponse",
         "GetTotalCreditsInPlatformResponse",
     ];
 
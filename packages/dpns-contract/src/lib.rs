@@ -1,5 +1,6 @@
 mod error;
 pub mod v1;
+pub mod v2;
 
 pub use crate::error::Error;
 use platform_value::{Identifier, IdentifierBytes32};
@@ -26,21 +27,22 @@ pub const DPNS_DASH_TLD_PREORDER_SALT: [u8; 32] = [
 pub const ID: Identifier = Identifier(IdentifierBytes32(ID_BYTES));
 pub const OWNER_ID: Identifier = Identifier(IdentifierBytes32(OWNER_ID_BYTES));
 pub fn load_definitions(platform_version: &PlatformVersion) -> Result<Option<Value>, Error> {
-    match platform_version.system_data_contracts.withdrawals {
-        1 => Ok(None),
+    match platform_version.system_data_contracts.dpns {
+        1 | 2 => Ok(None),
         version => Err(Error::UnknownVersionMismatch {
             method: "dpns_contract::load_definitions".to_string(),
-            known_versions: vec![1],
+            known_versions: vec![1, 2],
             received: version,
         }),
     }
 }
 pub fn load_documents_schemas(platform_version: &PlatformVersion) -> Result<Value, Error> {
-    match platform_version.system_data_contracts.withdrawals {
+    match platform_version.system_data_contracts.dpns {
         1 => v1::load_documents_schemas(),
+        2 => v2::load_documents_schemas(),
         version => Err(Error::UnknownVersionMismatch {
             method: "dpns_contract::load_documents_schemas".to_string(),
-            known_versions: vec![1],
+            known_versions: vec![1, 2],
             received: version,
         }),
     }
@@ -8,6 +8,15 @@ pub mod document_types {
         pub mod properties {
             pub const PROPERTY_BLOCK_HEIGHT: &str = "height";
             pub const PROPERTY_ENABLE_AT_HEIGHT: &str = "enableAtHeight";
+            pub const PROPERTY_BLOCK_MAX_BYTES: &str = "block.maxBytes";
+            pub const PROPERTY_BLOCK_MAX_GAS: &str = "block.maxGas";
+            pub const PROPERTY_EVIDENCE_MAX_AGE_NUM_BLOCKS: &str = "evidence.maxAgeNumBlocks";
+            pub const PROPERTY_EVIDENCE_MAX_AGE_DURATION_SECONDS: &str =
+                "evidence.maxAgeDuration.seconds";
+            pub const PROPERTY_EVIDENCE_MAX_AGE_DURATION_NANOS: &str =
+                "evidence.maxAgeDuration.nanos";
+            pub const PROPERTY_EVIDENCE_MAX_BYTES: &str = "evidence.maxBytes";
+            pub const PROPERTY_VERSION_APP_VERSION: &str = "version.appVersion";
         }
     }
 }
@@ -149,6 +149,30 @@ impl_transport_request_grpc!(
     get_documents
 );
 
+impl_transport_request_grpc!(
+    platform_proto::GetDocumentsCountRequest,
+    platform_proto::GetDocumentsCountResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_documents_count
+);
+
+impl_transport_request_grpc!(
+    platform_proto::GetDocumentOffersRequest,
+    platform_proto::GetDocumentOffersResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_document_offers
+);
+
+impl_transport_request_grpc!(
+    platform_proto::GetDocumentHistoryRequest,
+    platform_proto::GetDocumentHistoryResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_document_history
+);
+
 impl_transport_request_grpc!(
     platform_proto::GetDataContractRequest,
     platform_proto::GetDataContractResponse,
@@ -181,6 +205,14 @@ impl_transport_request_grpc!(
     broadcast_state_transition
 );
 
+impl_transport_request_grpc!(
+    platform_proto::EstimateStateTransitionFeeRequest,
+    platform_proto::EstimateStateTransitionFeeResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    estimate_state_transition_fee
+);
+
 impl_transport_request_grpc!(
     platform_proto::WaitForStateTransitionResultRequest,
     platform_proto::WaitForStateTransitionResultResponse,
@@ -224,6 +256,22 @@ impl_transport_request_grpc!(
     get_identity_contract_nonce
 );
 
+impl_transport_request_grpc!(
+    platform_proto::GetIdentityPendingRecoveryRequest,
+    platform_proto::GetIdentityPendingRecoveryResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_identity_pending_recovery
+);
+
+impl_transport_request_grpc!(
+    platform_proto::GetIdentityWithdrawalsRequest,
+    platform_proto::GetIdentityWithdrawalsResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_identity_withdrawals
+);
+
 impl_transport_request_grpc!(
     platform_proto::GetIdentityBalanceAndRevisionRequest,
     platform_proto::GetIdentityBalanceAndRevisionResponse,
@@ -323,6 +371,15 @@ impl_transport_request_grpc!(
     get_vote_polls_by_end_date
 );
 
+// rpc getGovernanceProposalVotePollState(GetGovernanceProposalVotePollStateRequest) returns (GetGovernanceProposalVotePollStateResponse);
+impl_transport_request_grpc!(
+    platform_proto::GetGovernanceProposalVotePollStateRequest,
+    platform_proto::GetGovernanceProposalVotePollStateResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_governance_proposal_vote_poll_state
+);
+
 // rpc getPrefundedSpecializedBalance(GetPrefundedSpecializedBalanceRequest) returns (GetPrefundedSpecializedBalanceResponse);
 impl_transport_request_grpc!(
     platform_proto::GetPrefundedSpecializedBalanceRequest,
@@ -350,6 +407,18 @@ impl_transport_request_grpc!(
     get_total_credits_in_platform
 );
 
+// rpc subscribePlatformEvents(SubscribePlatformEventsRequest) returns (stream SubscribePlatformEventsResponse);
+impl_transport_request_grpc!(
+    platform_proto::SubscribePlatformEventsRequest,
+    Streaming<platform_proto::SubscribePlatformEventsResponse>,
+    PlatformGrpcClient,
+    RequestSettings {
+        timeout: Some(STREAMING_TIMEOUT),
+        ..RequestSettings::default()
+    },
+    subscribe_platform_events
+);
+
 // Link to each core gRPC request what client and method to use:
 
 impl_transport_request_grpc!(
@@ -386,3 +455,25 @@ impl_transport_request_grpc!(
     },
     subscribe_to_transactions_with_proofs
 );
+
+impl_transport_request_grpc!(
+    core_proto::BlockHeadersWithChainLocksRequest,
+    Streaming<core_proto::BlockHeadersWithChainLocksResponse>,
+    CoreGrpcClient,
+    RequestSettings {
+        timeout: Some(STREAMING_TIMEOUT),
+        ..RequestSettings::default()
+    },
+    subscribe_to_block_headers_with_chain_locks
+);
+
+impl_transport_request_grpc!(
+    core_proto::MasternodeListRequest,
+    Streaming<core_proto::MasternodeListResponse>,
+    CoreGrpcClient,
+    RequestSettings {
+        timeout: Some(STREAMING_TIMEOUT),
+        ..RequestSettings::default()
+    },
+    subscribe_to_masternode_list
+);
@@ -16,8 +16,8 @@ use std::collections::BTreeMap;
 #[serde(rename_all = "camelCase", default)]
 pub struct DataContractConfigV0 {
     /// Can the contract ever be deleted. If the contract is deleted, so should be all
-    /// documents associated with it. TODO: There should also be a way to "stop" the contract -
-    /// contract and documents are kept in the system, but no new documents can be added to it
+    /// documents associated with it. Such a contract can also be archived instead - contract
+    /// and documents are kept in the system, but no new documents can be added to it
     pub can_be_deleted: bool,
     /// Is the contract mutable. Means that the document definitions can be changed or new
     /// document definitions can be added to the contract
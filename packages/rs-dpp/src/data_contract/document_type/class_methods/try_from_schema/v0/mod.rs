@@ -1,4 +1,8 @@
 #[cfg(feature = "validation")]
+use crate::consensus::basic::data_contract::AggregatedIndicesLimitReachedError;
+#[cfg(feature = "validation")]
+use crate::consensus::basic::data_contract::UnknownTradeModeError;
+#[cfg(feature = "validation")]
 use crate::consensus::basic::data_contract::{
     DuplicateIndexNameError, InvalidIndexPropertyTypeError, InvalidIndexedPropertyConstraintError,
     SystemPropertyIndexAlreadyPresentError, UndefinedIndexPropertyError,
@@ -31,6 +35,8 @@ use crate::consensus::basic::data_contract::InvalidDocumentTypeNameError;
 use crate::consensus::basic::document::MissingPositionsInDocumentTypePropertiesError;
 #[cfg(feature = "validation")]
 use crate::consensus::basic::BasicError;
+#[cfg(feature = "validation")]
+use crate::consensus::basic::UnsupportedFeatureError;
 use crate::data_contract::document_type::class_methods::{
     consensus_or_protocol_data_contract_error, consensus_or_protocol_value_error,
 };
@@ -198,6 +204,21 @@ impl DocumentTypeV0 {
 
         let trade_mode = documents_trade_mode_u8.try_into()?;
 
+        // Trade modes introduced by later protocol versions are unknown to earlier ones
+        #[cfg(feature = "validation")]
+        if full_validation
+            && documents_trade_mode_u8
+                > platform_version.dpp.validation.document_type.max_trade_mode
+        {
+            return Err(ProtocolError::ConsensusError(Box::new(
+                UnknownTradeModeError::new(
+                    (0..=platform_version.dpp.validation.document_type.max_trade_mode).collect(),
+                    documents_trade_mode_u8,
+                )
+                .into(),
+            )));
+        }
+
         // What is the creation restriction mode of this document type?
         let documents_creation_restriction_mode_u8: u8 =
             Value::inner_optional_integer_value(schema_map, CREATION_RESTRICTION_MODE)
@@ -303,6 +324,9 @@ impl DocumentTypeV0 {
         #[cfg(feature = "validation")]
         let mut contested_indices_count = 0;
 
+        #[cfg(feature = "validation")]
+        let mut aggregated_indices_count = 0;
+
         let indices: BTreeMap<String, Index> = index_values
             .map(|index_values| {
                 index_values
@@ -416,6 +440,88 @@ impl DocumentTypeV0 {
                                 last_contested_unique_index_name = Some(index.name.clone());
                             }
 
+                            // Contested indices can only be resolved in ways the protocol version knows
+                            if let Some(contested_index) = index.contested_index.as_ref() {
+                                if contested_index.resolution as u8
+                                    > platform_version
+                                        .dpp
+                                        .validation
+                                        .document_type
+                                        .max_contested_index_resolution
+                                {
+                                    return Err(ProtocolError::ConsensusError(Box::new(
+                                        UnsupportedFeatureError::new(
+                                            "contested index auction resolution".to_string(),
+                                            platform_version.protocol_version,
+                                        )
+                                        .into(),
+                                    )));
+                                }
+                            }
+
+                            // Count and sum trees are updated on every document change
+                            // so we limit their number as well
+                            if index.is_aggregated() {
+                                aggregated_indices_count += 1;
+                                if aggregated_indices_count
+                                    > platform_version
+                                        .dpp
+                                        .validation
+                                        .document_type
+                                        .aggregated_index_limit
+                                {
+                                    return Err(ProtocolError::ConsensusError(Box::new(
+                                        AggregatedIndicesLimitReachedError::new(
+                                            name.to_string(),
+                                            platform_version
+                                                .dpp
+                                                .validation
+                                                .document_type
+                                                .aggregated_index_limit,
+                                        )
+                                        .into(),
+                                    )));
+                                }
+                            }
+
+                            // Summed property must be an integer defined in user schema
+                            if let Some(summable) = index.summable.as_ref() {
+                                let property_definition = flattened_document_properties
+                                    .get(summable)
+                                    .ok_or_else(|| {
+                                        ProtocolError::ConsensusError(Box::new(
+                                            UndefinedIndexPropertyError::new(
+                                                name.to_owned(),
+                                                index.name.to_owned(),
+                                                summable.to_owned(),
+                                            )
+                                            .into(),
+                                        ))
+                                    })?;
+
+                                // Sums are stored as i64
+                                if !matches!(
+                                    property_definition.property_type,
+                                    DocumentPropertyType::I64
+                                        | DocumentPropertyType::U32
+                                        | DocumentPropertyType::I32
+                                        | DocumentPropertyType::U16
+                                        | DocumentPropertyType::I16
+                                        | DocumentPropertyType::U8
+                                        | DocumentPropertyType::I8
+                                ) {
+                                    return Err(ProtocolError::ConsensusError(Box::new(
+                                        InvalidIndexPropertyTypeError::new(
+                                            name.to_owned(),
+                                            index.name.to_owned(),
+                                            summable.to_owned(),
+                                            property_definition.property_type.name(),
+                                        )
+                                        .into(),
+                                    )));
+                                }
+                            }
+
                             // Index names must be unique for the document type
                             if !index_names.insert(index.name.to_owned()) {
                                 return Err(ProtocolError::ConsensusError(Box::new(
@@ -1059,4 +1165,174 @@ mod tests {
             );
         }
     }
+
+    mod trade_mode {
+        use super::*;
+
+        fn schema_with_trade_mode(trade_mode: u8) -> Value {
+            platform_value!({
+                "type": "object",
+                "tradeMode": trade_mode,
+                "properties": {
+                    "name": {
+                        "type": "string",
+                        "position": 0
+                    }
+                },
+                "additionalProperties": false
+            })
+        }
+
+        #[test]
+        fn should_reject_offers_before_the_protocol_version_introducing_them() {
+            let platform_version = PlatformVersion::first();
+
+            let result = DocumentTypeV0::try_from_schema_v0(
+                Identifier::new([1; 32]),
+                "card",
+                schema_with_trade_mode(2),
+                None,
+                false,
+                false,
+                false,
+                true,
+                &mut vec![],
+                platform_version,
+            );
+
+            assert_matches!(
+                result,
+                Err(ProtocolError::ConsensusError(boxed)) => {
+                    assert_matches!(
+                        boxed.as_ref(),
+                        ConsensusError::BasicError(
+                            BasicError::UnknownTradeModeError(e)
+                        ) if e.received() == 2
+                    )
+                }
+            );
+        }
+
+        #[test]
+        fn should_accept_offers_in_the_latest_protocol_version() {
+            let platform_version = PlatformVersion::latest();
+
+            for trade_mode in [2, 3] {
+                DocumentTypeV0::try_from_schema_v0(
+                    Identifier::new([1; 32]),
+                    "card",
+                    schema_with_trade_mode(trade_mode),
+                    None,
+                    false,
+                    false,
+                    false,
+                    true,
+                    &mut vec![],
+                    platform_version,
+                )
+                .expect("should be valid");
+            }
+        }
+    }
+
+    mod contested_index_resolution {
+        use super::*;
+
+        fn schema_with_contested_index_resolution(resolution: u8) -> Value {
+            platform_value!({
+                "type": "object",
+                "documentsMutable": false,
+                "indices": [
+                    {
+                        "name": "label",
+                        "properties": [
+                            {
+                                "label": "asc"
+                            }
+                        ],
+                        "unique": true,
+                        "contested": {
+                            "fieldMatches": [
+                                {
+                                    "field": "label",
+                                    "regexPattern": "^[a-z]{3,19}$"
+                                }
+                            ],
+                            "resolution": resolution
+                        }
+                    }
+                ],
+                "properties": {
+                    "label": {
+                        "type": "string",
+                        "maxLength": 63,
+                        "position": 0
+                    }
+                },
+                "required": ["label"],
+                "additionalProperties": false
+            })
+        }
+
+        #[test]
+        fn should_reject_auctions_before_the_protocol_version_introducing_them() {
+            let platform_version = PlatformVersion::first();
+
+            let result = DocumentTypeV0::try_from_schema_v0(
+                Identifier::new([1; 32]),
+                "ticket",
+                schema_with_contested_index_resolution(1),
+                None,
+                false,
+                false,
+                false,
+                true,
+                &mut vec![],
+                platform_version,
+            );
+
+            assert_matches!(
+                result,
+                Err(ProtocolError::ConsensusError(boxed)) => {
+                    assert_matches!(
+                        boxed.as_ref(),
+                        ConsensusError::BasicError(BasicError::UnsupportedFeatureError(_))
+                    )
+                }
+            );
+
+            DocumentTypeV0::try_from_schema_v0(
+                Identifier::new([1; 32]),
+                "ticket",
+                schema_with_contested_index_resolution(0),
+                None,
+                false,
+                false,
+                false,
+                true,
+                &mut vec![],
+                platform_version,
+            )
+            .expect("masternode vote resolution should be valid");
+        }
+
+        #[test]
+        fn should_accept_auctions_in_the_latest_protocol_version() {
+            let platform_version = PlatformVersion::latest();
+
+            DocumentTypeV0::try_from_schema_v0(
+                Identifier::new([1; 32]),
+                "ticket",
+                schema_with_contested_index_resolution(1),
+                None,
+                false,
+                false,
+                false,
+                true,
+                &mut vec![],
+                platform_version,
+            )
+            .expect("should be valid");
+        }
+    }
 }
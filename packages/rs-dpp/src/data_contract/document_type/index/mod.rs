@@ -15,7 +15,7 @@ use crate::data_contract::errors::DataContractError;
 use crate::ProtocolError;
 use anyhow::anyhow;
 
-use crate::data_contract::document_type::ContestedIndexResolution::MasternodeVote;
+use crate::data_contract::document_type::ContestedIndexResolution::{Auction, MasternodeVote};
 use crate::data_contract::errors::DataContractError::RegexError;
 use platform_value::{Value, ValueMap};
 use rand::distributions::{Alphanumeric, DistString};
@@ -34,6 +34,9 @@ pub mod random_index;
 #[cfg_attr(feature = "index-serde-conversion", derive(Serialize, Deserialize))]
 pub enum ContestedIndexResolution {
     MasternodeVote = 0,
+    /// Contenders commit sealed bids while the vote poll is open and reveal them afterwards,
+    /// the highest revealed bid wins and is paid to the contract owner
+    Auction = 1,
 }
 
 impl TryFrom<u8> for ContestedIndexResolution {
@@ -42,6 +45,7 @@ impl TryFrom<u8> for ContestedIndexResolution {
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
             0 => Ok(MasternodeVote),
+            1 => Ok(Auction),
             value => Err(ProtocolError::UnknownStorageKeyRequirements(format!(
                 "contested index resolution unknown: {}",
                 value
@@ -266,6 +270,14 @@ pub struct Index {
     pub null_searchable: bool,
     /// Contested indexes are useful when a resource is considered valuable
     pub contested_index: Option<ContestedIndexInformation>,
+    /// Countable indexes maintain the number of documents for every prefix of index values,
+    /// allowing documents to be counted with proofs
+    #[cfg_attr(feature = "index-serde-conversion", serde(default))]
+    pub countable: bool,
+    /// Name of an integer property whose values are summed for every prefix of index values,
+    /// allowing them to be totaled with proofs
+    #[cfg_attr(feature = "index-serde-conversion", serde(default))]
+    pub summable: Option<String>,
 }
 
 impl Index {
@@ -300,6 +312,11 @@ impl Index {
             .map(|property| data.get(&property.name).cloned().unwrap_or(Value::Null))
             .collect()
     }
+
+    /// Returns true if the index maintains document counts or property sums
+    pub fn is_aggregated(&self) -> bool {
+        self.countable || self.summable.is_some()
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Ord, PartialOrd)]
@@ -439,6 +456,8 @@ impl TryFrom<&[(Value, Value)]> for Index {
         let mut null_searchable = true;
         let mut name = None;
         let mut contested_index = None;
+        let mut countable = false;
+        let mut summable = None;
         let mut index_properties: Vec<IndexProperty> = Vec::new();
 
         for (key_value, value_value) in index_type_value_map {
@@ -465,6 +484,21 @@ impl TryFrom<&[(Value, Value)]> for Index {
                         null_searchable = value_value.as_bool().expect("confirmed as bool");
                     }
                 }
+                "countable" => {
+                    if value_value.is_bool() {
+                        countable = value_value.as_bool().expect("confirmed as bool");
+                    }
+                }
+                "summable" => {
+                    summable = Some(
+                        value_value
+                            .as_text()
+                            .ok_or(DataContractError::InvalidContractStructure(
+                                "index summable should be a property name".to_string(),
+                            ))?
+                            .to_owned(),
+                    );
+                }
                 "contested" => {
                     let contested_properties_value_map = value_value.to_map()?;
 
@@ -593,6 +627,8 @@ impl TryFrom<&[(Value, Value)]> for Index {
             unique,
             null_searchable,
             contested_index,
+            countable,
+            summable,
         })
     }
 }
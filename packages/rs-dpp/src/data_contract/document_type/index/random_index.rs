@@ -60,6 +60,8 @@ impl Index {
             unique,
             null_searchable: true,
             contested_index: None,
+            countable: false,
+            summable: None,
         })
     }
 }
@@ -260,6 +260,74 @@ impl IndexLevel {
 
         SimpleConsensusValidationResult::new()
     }
+
+    /// Same as `validate_update`, except that indexes can be added. They are backfilled by
+    /// Drive for existing documents, however existing indexes can't be removed or modified.
+    #[cfg(feature = "validation")]
+    pub fn validate_update_with_added_indexes(
+        &self,
+        document_type_name: &str,
+        new_indices: &Self,
+    ) -> SimpleConsensusValidationResult {
+        // There is no changes. All good
+        if self == new_indices {
+            return SimpleConsensusValidationResult::new();
+        }
+
+        // Level identifiers are attributed in index order, so adding an index might shift
+        // them and we only compare the structure.
+        if let Some(invalid_path) = new_indices.first_missing_index_path(self) {
+            return SimpleConsensusValidationResult::new_with_error(
+                DataContractInvalidIndexDefinitionUpdateError::new(
+                    document_type_name.to_string(),
+                    invalid_path,
+                )
+                .into(),
+            );
+        }
+
+        SimpleConsensusValidationResult::new()
+    }
+
+    /// Returns the path of the first index level of `rhs` that doesn't exist in `self`,
+    /// or where an index of `rhs` ends but doesn't end, or has another type, in `self`.
+    ///
+    /// When an index ends at a level in `rhs` but continues in `self`, the returned path
+    /// is the one of the index continuing in `self`.
+    #[cfg(feature = "validation")]
+    fn first_missing_index_path(&self, rhs: &IndexLevel) -> Option<String> {
+        if let Some(rhs_index_type) = rhs.has_index_with_type {
+            if self.has_index_with_type != Some(rhs_index_type) {
+                // the index was extended or changed, point to where it continues if it does
+                return Some(
+                    self.sub_index_levels
+                        .iter()
+                        .find(|(key, _)| !rhs.sub_index_levels.contains_key(*key))
+                        .map(|(key, _)| key.to_string())
+                        .unwrap_or_default(),
+                );
+            }
+        }
+
+        for (key, rhs_sub_index) in &rhs.sub_index_levels {
+            match self.sub_index_levels.get(key) {
+                Some(self_sub_index) => {
+                    if let Some(invalid_path) =
+                        self_sub_index.first_missing_index_path(rhs_sub_index)
+                    {
+                        return Some(if invalid_path.is_empty() {
+                            key.to_string()
+                        } else {
+                            format!("{} -> {}", key, invalid_path)
+                        });
+                    }
+                }
+                None => return Some(key.to_string()),
+            }
+        }
+
+        None
+    }
 }
 
 #[cfg(test)]
@@ -282,6 +350,8 @@ mod tests {
             unique: false,
             null_searchable: true,
             contested_index: None,
+            countable: false,
+            summable: None,
         }];
 
         let old_index_structure =
@@ -309,6 +379,8 @@ mod tests {
             unique: false,
             null_searchable: true,
             contested_index: None,
+            countable: false,
+            summable: None,
         }];
 
         let new_indices = vec![
@@ -321,6 +393,8 @@ mod tests {
                 unique: false,
                 null_searchable: true,
                 contested_index: None,
+                countable: false,
+                summable: None,
             },
             Index {
                 name: "test2".to_string(),
@@ -331,6 +405,8 @@ mod tests {
                 unique: false,
                 null_searchable: true,
                 contested_index: None,
+                countable: false,
+                summable: None,
             },
         ];
 
@@ -367,6 +443,8 @@ mod tests {
                 unique: false,
                 null_searchable: true,
                 contested_index: None,
+                countable: false,
+                summable: None,
             },
             Index {
                 name: "test2".to_string(),
@@ -377,6 +455,8 @@ mod tests {
                 unique: false,
                 null_searchable: true,
                 contested_index: None,
+                countable: false,
+                summable: None,
             },
         ];
 
@@ -389,6 +469,8 @@ mod tests {
             unique: false,
             null_searchable: true,
             contested_index: None,
+            countable: false,
+            summable: None,
         }];
 
         let old_index_structure =
@@ -423,6 +505,8 @@ mod tests {
             unique: false,
             null_searchable: true,
             contested_index: None,
+            countable: false,
+            summable: None,
         }];
 
         let new_indices = vec![Index {
@@ -440,6 +524,8 @@ mod tests {
             unique: false,
             null_searchable: true,
             contested_index: None,
+            countable: false,
+            summable: None,
         }];
 
         let old_index_structure =
@@ -480,6 +566,8 @@ mod tests {
             unique: false,
             null_searchable: true,
             contested_index: None,
+            countable: false,
+            summable: None,
         }];
 
         let new_indices = vec![Index {
@@ -491,6 +579,8 @@ mod tests {
             unique: false,
             null_searchable: true,
             contested_index: None,
+            countable: false,
+            summable: None,
         }];
 
         let old_index_structure =
@@ -510,4 +600,111 @@ mod tests {
             )] if e.index_path() == "test -> test2"
         );
     }
+
+    mod validate_update_with_added_indexes {
+        use super::*;
+
+        fn index(name: &str, property_names: &[&str]) -> Index {
+            Index {
+                name: name.to_string(),
+                properties: property_names
+                    .iter()
+                    .map(|property_name| IndexProperty {
+                        name: property_name.to_string(),
+                        ascending: false,
+                    })
+                    .collect(),
+                unique: false,
+                null_searchable: true,
+                contested_index: None,
+                countable: false,
+                summable: None,
+            }
+        }
+
+        #[test]
+        fn should_pass_if_new_index_is_added() {
+            let platform_version = PlatformVersion::latest();
+            let document_type_name = "test";
+
+            let old_index_structure = IndexLevel::try_from_indices(
+                &[index("test", &["test"])],
+                document_type_name,
+                platform_version,
+            )
+            .expect("failed to create old index level");
+
+            let new_index_structure = IndexLevel::try_from_indices(
+                &[index("test", &["test"]), index("test2", &["test2"])],
+                document_type_name,
+                platform_version,
+            )
+            .expect("failed to create new index level");
+
+            let result = old_index_structure
+                .validate_update_with_added_indexes(document_type_name, &new_index_structure);
+
+            assert!(result.is_valid());
+        }
+
+        #[test]
+        fn should_return_invalid_result_if_some_indices_are_removed() {
+            let platform_version = PlatformVersion::latest();
+            let document_type_name = "test";
+
+            let old_index_structure = IndexLevel::try_from_indices(
+                &[index("test", &["test"]), index("test2", &["test2"])],
+                document_type_name,
+                platform_version,
+            )
+            .expect("failed to create old index level");
+
+            let new_index_structure = IndexLevel::try_from_indices(
+                &[index("test", &["test"])],
+                document_type_name,
+                platform_version,
+            )
+            .expect("failed to create new index level");
+
+            let result = old_index_structure
+                .validate_update_with_added_indexes(document_type_name, &new_index_structure);
+
+            assert_matches!(
+                result.errors.as_slice(),
+                [ConsensusError::BasicError(
+                    BasicError::DataContractInvalidIndexDefinitionUpdateError(e)
+                )] if e.index_path() == "test2"
+            );
+        }
+
+        #[test]
+        fn should_return_invalid_result_if_additional_property_is_added_to_existing_index() {
+            let platform_version = PlatformVersion::latest();
+            let document_type_name = "test";
+
+            let old_index_structure = IndexLevel::try_from_indices(
+                &[index("test", &["test"])],
+                document_type_name,
+                platform_version,
+            )
+            .expect("failed to create old index level");
+
+            let new_index_structure = IndexLevel::try_from_indices(
+                &[index("test", &["test", "test2"])],
+                document_type_name,
+                platform_version,
+            )
+            .expect("failed to create new index level");
+
+            let result = old_index_structure
+                .validate_update_with_added_indexes(document_type_name, &new_index_structure);
+
+            assert_matches!(
+                result.errors.as_slice(),
+                [ConsensusError::BasicError(
+                    BasicError::DataContractInvalidIndexDefinitionUpdateError(e)
+                )] if e.index_path() == "test -> test2"
+            );
+        }
+    }
 }
@@ -31,11 +31,49 @@ impl<'a> DocumentTypeRef<'a> {
             return Ok(result);
         }
 
+        // Validate index aggregations
+        let result = self.validate_index_aggregations(new_document_type);
+
+        if !result.is_valid() {
+            return Ok(result);
+        }
+
         // Validate schema compatibility
         self.validate_schema(new_document_type, platform_version)
     }
 
-    fn validate_config(
+    /// Aggregations are maintained from document creation, so they can't be added to
+    /// or removed from existing indexes
+    pub(super) fn validate_index_aggregations(
+        &self,
+        new_document_type: DocumentTypeRef,
+    ) -> SimpleConsensusValidationResult {
+        for (index_name, new_index) in new_document_type.indexes() {
+            let (old_countable, old_summable) = self
+                .indexes()
+                .get(index_name)
+                .map(|index| (index.countable, index.summable.as_ref()))
+                .unwrap_or_default();
+
+            if new_index.countable != old_countable || new_index.summable.as_ref() != old_summable {
+                return SimpleConsensusValidationResult::new_with_error(
+                    DocumentTypeUpdateError::new(
+                        self.data_contract_id(),
+                        self.name(),
+                        format!(
+                            "document type can not change countable or summable options of index {}",
+                            index_name
+                        ),
+                    )
+                    .into(),
+                );
+            }
+        }
+
+        SimpleConsensusValidationResult::new()
+    }
+
+    pub(super) fn validate_config(
         &self,
         new_document_type: DocumentTypeRef,
     ) -> SimpleConsensusValidationResult {
@@ -181,7 +219,7 @@ impl<'a> DocumentTypeRef<'a> {
         SimpleConsensusValidationResult::new()
     }
 
-    fn validate_schema(
+    pub(super) fn validate_schema(
         &self,
         new_document_type: DocumentTypeRef,
         platform_version: &PlatformVersion,
@@ -0,0 +1,273 @@
+use crate::consensus::basic::data_contract::{
+    DataContractHaveNewUniqueIndexError, DataContractInvalidIndexDefinitionUpdateError,
+};
+use crate::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use crate::data_contract::document_type::DocumentTypeRef;
+use crate::validation::SimpleConsensusValidationResult;
+use crate::ProtocolError;
+use platform_version::version::PlatformVersion;
+
+impl<'a> DocumentTypeRef<'a> {
+    /// Same as v0, except that non-unique indices can be added to the document type
+    #[inline(always)]
+    pub(super) fn validate_update_v1(
+        &self,
+        new_document_type: DocumentTypeRef,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, ProtocolError> {
+        // Validate configuration
+        let result = self.validate_config(new_document_type);
+
+        if !result.is_valid() {
+            return Ok(result);
+        }
+
+        // Validate index structure, indices can be added
+        let result = self
+            .index_structure()
+            .validate_update_with_added_indexes(self.name(), new_document_type.index_structure());
+
+        if !result.is_valid() {
+            return Ok(result);
+        }
+
+        // Validate added and existing indices
+        let result = self.validate_indices(new_document_type);
+
+        if !result.is_valid() {
+            return Ok(result);
+        }
+
+        // Validate index aggregations
+        let result = self.validate_index_aggregations(new_document_type);
+
+        if !result.is_valid() {
+            return Ok(result);
+        }
+
+        // Validate schema compatibility
+        self.validate_schema(new_document_type, platform_version)
+    }
+
+    /// Existing indices can't be changed, and new indices can be added only if they are not
+    /// unique since existing documents could already violate the uniqueness constraint
+    fn validate_indices(
+        &self,
+        new_document_type: DocumentTypeRef,
+    ) -> SimpleConsensusValidationResult {
+        for (index_name, old_index) in self.indexes() {
+            if new_document_type.indexes().get(index_name) != Some(old_index) {
+                return SimpleConsensusValidationResult::new_with_error(
+                    DataContractInvalidIndexDefinitionUpdateError::new(
+                        self.name().clone(),
+                        index_name.clone(),
+                    )
+                    .into(),
+                );
+            }
+        }
+
+        for (index_name, new_index) in new_document_type.indexes() {
+            if !self.indexes().contains_key(index_name) && new_index.unique {
+                return SimpleConsensusValidationResult::new_with_error(
+                    DataContractHaveNewUniqueIndexError::new(
+                        self.name().clone(),
+                        index_name.clone(),
+                    )
+                    .into(),
+                );
+            }
+        }
+
+        SimpleConsensusValidationResult::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::consensus::ConsensusError;
+    use crate::data_contract::document_type::DocumentType;
+    use assert_matches::assert_matches;
+    use platform_value::platform_value;
+    use platform_value::Identifier;
+
+    mod validate_indices {
+        use super::*;
+        use crate::consensus::basic::BasicError;
+
+        fn document_type_with_indices(
+            data_contract_id: Identifier,
+            indices: platform_value::Value,
+            platform_version: &PlatformVersion,
+        ) -> DocumentType {
+            let schema = platform_value!({
+                "type": "object",
+                "properties": {
+                    "firstName": {
+                        "type": "string",
+                        "maxLength": 63,
+                        "position": 0,
+                    },
+                    "lastName": {
+                        "type": "string",
+                        "maxLength": 63,
+                        "position": 1,
+                    }
+                },
+                "indices": indices,
+                "additionalProperties": false,
+            });
+
+            DocumentType::try_from_schema(
+                data_contract_id,
+                "test",
+                schema,
+                None,
+                false,
+                false,
+                false,
+                false,
+                &mut Vec::new(),
+                platform_version,
+            )
+            .expect("failed to create document type")
+        }
+
+        #[test]
+        fn should_pass_when_non_unique_index_is_added() {
+            let platform_version = PlatformVersion::latest();
+            let data_contract_id = Identifier::random();
+
+            let old_document_type = document_type_with_indices(
+                data_contract_id,
+                platform_value!([
+                    {
+                        "name": "firstName",
+                        "properties": [{"firstName": "asc"}],
+                    }
+                ]),
+                platform_version,
+            );
+
+            let new_document_type = document_type_with_indices(
+                data_contract_id,
+                platform_value!([
+                    {
+                        "name": "firstName",
+                        "properties": [{"firstName": "asc"}],
+                    },
+                    {
+                        "name": "lastName",
+                        "properties": [{"lastName": "asc"}],
+                    }
+                ]),
+                platform_version,
+            );
+
+            let result = old_document_type
+                .as_ref()
+                .validate_update_v1(new_document_type.as_ref(), platform_version)
+                .expect("failed to validate update");
+
+            assert!(result.is_valid());
+
+            // indices could not be added before
+            let result = old_document_type
+                .as_ref()
+                .validate_update_v0(new_document_type.as_ref(), platform_version)
+                .expect("failed to validate update");
+
+            assert_matches!(
+                result.errors.as_slice(),
+                [ConsensusError::BasicError(
+                    BasicError::DataContractInvalidIndexDefinitionUpdateError(e)
+                )] if e.index_path() == "lastName"
+            );
+        }
+
+        #[test]
+        fn should_return_invalid_result_when_unique_index_is_added() {
+            let platform_version = PlatformVersion::latest();
+            let data_contract_id = Identifier::random();
+
+            let old_document_type = document_type_with_indices(
+                data_contract_id,
+                platform_value!([
+                    {
+                        "name": "firstName",
+                        "properties": [{"firstName": "asc"}],
+                    }
+                ]),
+                platform_version,
+            );
+
+            let new_document_type = document_type_with_indices(
+                data_contract_id,
+                platform_value!([
+                    {
+                        "name": "firstName",
+                        "properties": [{"firstName": "asc"}],
+                    },
+                    {
+                        "name": "lastName",
+                        "properties": [{"lastName": "asc"}],
+                        "unique": true,
+                    }
+                ]),
+                platform_version,
+            );
+
+            let result = old_document_type
+                .as_ref()
+                .validate_indices(new_document_type.as_ref());
+
+            assert_matches!(
+                result.errors.as_slice(),
+                [ConsensusError::BasicError(
+                    BasicError::DataContractHaveNewUniqueIndexError(e)
+                )] if e.index_name() == "lastName"
+            );
+        }
+
+        #[test]
+        fn should_return_invalid_result_when_index_is_renamed() {
+            let platform_version = PlatformVersion::latest();
+            let data_contract_id = Identifier::random();
+
+            let old_document_type = document_type_with_indices(
+                data_contract_id,
+                platform_value!([
+                    {
+                        "name": "firstName",
+                        "properties": [{"firstName": "asc"}],
+                    }
+                ]),
+                platform_version,
+            );
+
+            let new_document_type = document_type_with_indices(
+                data_contract_id,
+                platform_value!([
+                    {
+                        "name": "byFirstName",
+                        "properties": [{"firstName": "asc"}],
+                    }
+                ]),
+                platform_version,
+            );
+
+            let result = old_document_type
+                .as_ref()
+                .validate_indices(new_document_type.as_ref());
+
+            assert_matches!(
+                result.errors.as_slice(),
+                [ConsensusError::BasicError(
+                    BasicError::DataContractInvalidIndexDefinitionUpdateError(e)
+                )] if e.index_path() == "firstName"
+            );
+        }
+    }
+}
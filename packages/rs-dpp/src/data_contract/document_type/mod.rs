@@ -110,6 +110,29 @@ impl<'a> DocumentTypeRef<'a> {
             DocumentTypeRef::V0(v0) => DocumentType::V0((*v0).to_owned()),
         }
     }
+
+    /// A copy of the document type only keeping the indexes accepted by `keep`.
+    ///
+    /// This is used to maintain only part of the indexes of a document, for example while
+    /// a newly added index has not yet been backfilled for all existing documents.
+    pub fn with_filtered_indexes(
+        &self,
+        keep: impl Fn(&Index) -> bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<DocumentType, ProtocolError> {
+        match self {
+            DocumentTypeRef::V0(v0) => {
+                let mut filtered = (*v0).clone();
+                filtered.indices.retain(|_, index| keep(index));
+                filtered.index_structure = IndexLevel::try_from_indices(
+                    filtered.indices.values(),
+                    filtered.name.as_str(),
+                    platform_version,
+                )?;
+                Ok(DocumentType::V0(filtered))
+            }
+        }
+    }
 }
 
 impl<'a> DocumentTypeV0Methods for DocumentTypeRef<'a> {
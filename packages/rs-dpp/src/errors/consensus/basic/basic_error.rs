@@ -7,12 +7,13 @@ use crate::consensus::basic::data_contract::data_contract_max_depth_exceed_error
 #[cfg(feature = "json-schema-validation")]
 use crate::consensus::basic::data_contract::InvalidJsonSchemaRefError;
 use crate::consensus::basic::data_contract::{
-    ContestedUniqueIndexOnMutableDocumentTypeError, ContestedUniqueIndexWithUniqueIndexError,
-    DataContractHaveNewUniqueIndexError, DataContractImmutablePropertiesUpdateError,
-    DataContractInvalidIndexDefinitionUpdateError, DataContractUniqueIndicesChangedError,
-    DuplicateIndexError, DuplicateIndexNameError, IncompatibleDataContractSchemaError,
-    IncompatibleDocumentTypeSchemaError, IncompatibleRe2PatternError, InvalidCompoundIndexError,
-    InvalidDataContractIdError, InvalidDataContractVersionError, InvalidDocumentTypeNameError,
+    AggregatedIndicesLimitReachedError, ContestedUniqueIndexOnMutableDocumentTypeError,
+    ContestedUniqueIndexWithUniqueIndexError, DataContractHaveNewUniqueIndexError,
+    DataContractImmutablePropertiesUpdateError, DataContractInvalidIndexDefinitionUpdateError,
+    DataContractUniqueIndicesChangedError, DuplicateIndexError, DuplicateIndexNameError,
+    IncompatibleDataContractSchemaError, IncompatibleDocumentTypeSchemaError,
+    IncompatibleRe2PatternError, InvalidCompoundIndexError, InvalidDataContractIdError,
+    InvalidDataContractVersionError, InvalidDocumentTypeNameError,
     InvalidDocumentTypeRequiredSecurityLevelError, InvalidIndexPropertyTypeError,
     InvalidIndexedPropertyConstraintError, SystemPropertyIndexAlreadyPresentError,
     UndefinedIndexPropertyError, UniqueIndicesLimitReachedError,
@@ -35,20 +36,23 @@ use crate::consensus::basic::document::{
 };
 use crate::consensus::basic::identity::{
     DataContractBoundsNotPresentError, DisablingKeyIdAlsoBeingAddedInSameTransitionError,
-    DuplicatedIdentityPublicKeyBasicError, DuplicatedIdentityPublicKeyIdBasicError,
-    IdentityAssetLockProofLockedTransactionMismatchError,
+    DuplicatedIdentityCreditTransferRecipientError, DuplicatedIdentityPublicKeyBasicError,
+    DuplicatedIdentityPublicKeyIdBasicError, IdentityAssetLockProofLockedTransactionMismatchError,
     IdentityAssetLockStateTransitionReplayError, IdentityAssetLockTransactionIsNotFoundError,
     IdentityAssetLockTransactionOutPointAlreadyConsumedError,
     IdentityAssetLockTransactionOutPointNotEnoughBalanceError,
-    IdentityAssetLockTransactionOutputNotFoundError, IdentityCreditTransferToSelfError,
-    InvalidAssetLockProofCoreChainHeightError, InvalidAssetLockProofTransactionHeightError,
-    InvalidAssetLockTransactionOutputReturnSizeError,
-    InvalidIdentityAssetLockProofChainLockValidationError,
+    IdentityAssetLockTransactionOutputNotFoundError, IdentityCreditTransferMemoTooLongError,
+    IdentityCreditTransferToSelfError, InvalidAssetLockProofCoreChainHeightError,
+    InvalidAssetLockProofTransactionHeightError, InvalidAssetLockTransactionOutputReturnSizeError,
+    InvalidContractsOwnerKeyBoundsError, InvalidIdentityAssetLockProofChainLockValidationError,
     InvalidIdentityAssetLockTransactionError, InvalidIdentityAssetLockTransactionOutputError,
-    InvalidIdentityCreditTransferAmountError, InvalidIdentityCreditWithdrawalTransitionAmountError,
+    InvalidIdentityCreditBatchTransferOutputsCountError, InvalidIdentityCreditTransferAmountError,
+    InvalidIdentityCreditWithdrawalTransitionAmountError,
     InvalidIdentityCreditWithdrawalTransitionCoreFeeError,
     InvalidIdentityCreditWithdrawalTransitionOutputScriptError, InvalidIdentityKeySignatureError,
     InvalidIdentityPublicKeyDataError, InvalidIdentityPublicKeySecurityLevelError,
+    InvalidIdentityPublicKeySignatureThresholdError, InvalidIdentityPublicKeyValidityWindowError,
+    InvalidIdentityRecoveryConfigError, InvalidIdentityRecoveryMasterKeyError,
     InvalidIdentityUpdateTransitionDisableKeysError, InvalidIdentityUpdateTransitionEmptyError,
     InvalidInstantAssetLockProofError, InvalidInstantAssetLockProofSignatureError,
     MissingMasterPublicKeyError, NotImplementedIdentityCreditWithdrawalTransitionPoolingError,
@@ -397,6 +401,37 @@ pub enum BasicError {
 
     #[error(transparent)]
     ContestedDocumentsTemporarilyNotAllowedError(ContestedDocumentsTemporarilyNotAllowedError),
+
+    #[error(transparent)]
+    AggregatedIndicesLimitReachedError(AggregatedIndicesLimitReachedError),
+
+    #[error(transparent)]
+    InvalidIdentityPublicKeyValidityWindowError(InvalidIdentityPublicKeyValidityWindowError),
+
+    #[error(transparent)]
+    InvalidIdentityPublicKeySignatureThresholdError(
+        InvalidIdentityPublicKeySignatureThresholdError,
+    ),
+
+    #[error(transparent)]
+    InvalidContractsOwnerKeyBoundsError(InvalidContractsOwnerKeyBoundsError),
+
+    #[error(transparent)]
+    InvalidIdentityRecoveryConfigError(InvalidIdentityRecoveryConfigError),
+
+    #[error(transparent)]
+    InvalidIdentityRecoveryMasterKeyError(InvalidIdentityRecoveryMasterKeyError),
+
+    #[error(transparent)]
+    InvalidIdentityCreditBatchTransferOutputsCountError(
+        InvalidIdentityCreditBatchTransferOutputsCountError,
+    ),
+
+    #[error(transparent)]
+    DuplicatedIdentityCreditTransferRecipientError(DuplicatedIdentityCreditTransferRecipientError),
+
+    #[error(transparent)]
+    IdentityCreditTransferMemoTooLongError(IdentityCreditTransferMemoTooLongError),
 }
 
 impl From<BasicError> for ConsensusError {
@@ -0,0 +1,44 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("'{document_type}' document has more than '{index_limit}' countable or summable indexes")]
+#[platform_serialize(unversioned)]
+pub struct AggregatedIndicesLimitReachedError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    document_type: String,
+    index_limit: u16,
+}
+
+impl AggregatedIndicesLimitReachedError {
+    pub fn new(document_type: String, index_limit: u16) -> Self {
+        Self {
+            document_type,
+            index_limit,
+        }
+    }
+
+    pub fn document_type(&self) -> &str {
+        &self.document_type
+    }
+
+    pub fn index_limit(&self) -> u16 {
+        self.index_limit
+    }
+}
+
+impl From<AggregatedIndicesLimitReachedError> for ConsensusError {
+    fn from(err: AggregatedIndicesLimitReachedError) -> Self {
+        Self::BasicError(BasicError::AggregatedIndicesLimitReachedError(err))
+    }
+}
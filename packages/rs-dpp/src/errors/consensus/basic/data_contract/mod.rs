@@ -1,3 +1,4 @@
+mod aggregated_indices_limit_reached_error;
 mod contested_unique_index_on_mutable_document_type_error;
 mod contested_unique_index_with_unique_index_error;
 mod data_contract_have_new_unique_index_error;
@@ -29,6 +30,7 @@ mod unknown_storage_key_requirements_error;
 mod unknown_trade_mode_error;
 mod unknown_transferable_type_error;
 
+pub use aggregated_indices_limit_reached_error::*;
 pub use data_contract_have_new_unique_index_error::*;
 pub use data_contract_immutable_properties_update_error::*;
 pub use data_contract_invalid_index_definition_update_error::*;
@@ -0,0 +1,42 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error(
+    "Identity {recipient_id} is the recipient of more than one output of the credit batch transfer"
+)]
+#[platform_serialize(unversioned)]
+pub struct DuplicatedIdentityCreditTransferRecipientError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    recipient_id: Identifier,
+}
+
+impl DuplicatedIdentityCreditTransferRecipientError {
+    pub fn new(recipient_id: Identifier) -> Self {
+        Self { recipient_id }
+    }
+
+    pub fn recipient_id(&self) -> Identifier {
+        self.recipient_id
+    }
+}
+
+impl From<DuplicatedIdentityCreditTransferRecipientError> for ConsensusError {
+    fn from(err: DuplicatedIdentityCreditTransferRecipientError) -> Self {
+        Self::BasicError(BasicError::DuplicatedIdentityCreditTransferRecipientError(
+            err,
+        ))
+    }
+}
@@ -0,0 +1,52 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Memo of the credit transfer to {recipient_id} is {memo_size} bytes long, it must be at most {max_memo_size} bytes")]
+#[platform_serialize(unversioned)]
+pub struct IdentityCreditTransferMemoTooLongError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    recipient_id: Identifier,
+    memo_size: u32,
+    max_memo_size: u16,
+}
+
+impl IdentityCreditTransferMemoTooLongError {
+    pub fn new(recipient_id: Identifier, memo_size: u32, max_memo_size: u16) -> Self {
+        Self {
+            recipient_id,
+            memo_size,
+            max_memo_size,
+        }
+    }
+
+    pub fn recipient_id(&self) -> Identifier {
+        self.recipient_id
+    }
+
+    pub fn memo_size(&self) -> u32 {
+        self.memo_size
+    }
+
+    pub fn max_memo_size(&self) -> u16 {
+        self.max_memo_size
+    }
+}
+
+impl From<IdentityCreditTransferMemoTooLongError> for ConsensusError {
+    fn from(err: IdentityCreditTransferMemoTooLongError) -> Self {
+        Self::BasicError(BasicError::IdentityCreditTransferMemoTooLongError(err))
+    }
+}
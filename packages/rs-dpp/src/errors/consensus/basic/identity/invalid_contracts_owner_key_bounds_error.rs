@@ -0,0 +1,54 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+use crate::identity::KeyID;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity public key {public_key_id} can't be bound to the contracts of owner {owner_id}: {reason}")]
+#[platform_serialize(unversioned)]
+pub struct InvalidContractsOwnerKeyBoundsError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    public_key_id: KeyID,
+    owner_id: Identifier,
+    reason: String,
+}
+
+impl InvalidContractsOwnerKeyBoundsError {
+    pub fn new(public_key_id: KeyID, owner_id: Identifier, reason: String) -> Self {
+        Self {
+            public_key_id,
+            owner_id,
+            reason,
+        }
+    }
+
+    pub fn public_key_id(&self) -> KeyID {
+        self.public_key_id
+    }
+
+    pub fn owner_id(&self) -> Identifier {
+        self.owner_id
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl From<InvalidContractsOwnerKeyBoundsError> for ConsensusError {
+    fn from(err: InvalidContractsOwnerKeyBoundsError) -> Self {
+        Self::BasicError(BasicError::InvalidContractsOwnerKeyBoundsError(err))
+    }
+}
@@ -0,0 +1,47 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error(
+    "Credit batch transfer has {outputs_count} outputs, it must have between 1 and {max_outputs}"
+)]
+#[platform_serialize(unversioned)]
+pub struct InvalidIdentityCreditBatchTransferOutputsCountError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    outputs_count: u32,
+    max_outputs: u16,
+}
+
+impl InvalidIdentityCreditBatchTransferOutputsCountError {
+    pub fn new(outputs_count: u32, max_outputs: u16) -> Self {
+        Self {
+            outputs_count,
+            max_outputs,
+        }
+    }
+
+    pub fn outputs_count(&self) -> u32 {
+        self.outputs_count
+    }
+
+    pub fn max_outputs(&self) -> u16 {
+        self.max_outputs
+    }
+}
+
+impl From<InvalidIdentityCreditBatchTransferOutputsCountError> for ConsensusError {
+    fn from(err: InvalidIdentityCreditBatchTransferOutputsCountError) -> Self {
+        Self::BasicError(BasicError::InvalidIdentityCreditBatchTransferOutputsCountError(err))
+    }
+}
@@ -0,0 +1,55 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use crate::identity::KeyID;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity public key {public_key_id} has invalid signature threshold {threshold}, it must be between 2 and {max_threshold}")]
+#[platform_serialize(unversioned)]
+pub struct InvalidIdentityPublicKeySignatureThresholdError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    public_key_id: KeyID,
+    threshold: u8,
+    max_threshold: u8,
+}
+
+impl InvalidIdentityPublicKeySignatureThresholdError {
+    pub fn new(public_key_id: KeyID, threshold: u8, max_threshold: u8) -> Self {
+        Self {
+            public_key_id,
+            threshold,
+            max_threshold,
+        }
+    }
+
+    pub fn public_key_id(&self) -> KeyID {
+        self.public_key_id
+    }
+
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    pub fn max_threshold(&self) -> u8 {
+        self.max_threshold
+    }
+}
+
+impl From<InvalidIdentityPublicKeySignatureThresholdError> for ConsensusError {
+    fn from(err: InvalidIdentityPublicKeySignatureThresholdError) -> Self {
+        Self::BasicError(BasicError::InvalidIdentityPublicKeySignatureThresholdError(
+            err,
+        ))
+    }
+}
@@ -0,0 +1,64 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use crate::identity::{KeyID, TimestampMillis};
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Invalid identity public key {public_key_id} validity window: valid from {valid_from:?} until {valid_until:?}. {reason}")]
+#[platform_serialize(unversioned)]
+pub struct InvalidIdentityPublicKeyValidityWindowError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    public_key_id: KeyID,
+    valid_from: Option<TimestampMillis>,
+    valid_until: Option<TimestampMillis>,
+    reason: String,
+}
+
+impl InvalidIdentityPublicKeyValidityWindowError {
+    pub fn new(
+        public_key_id: KeyID,
+        valid_from: Option<TimestampMillis>,
+        valid_until: Option<TimestampMillis>,
+        reason: String,
+    ) -> Self {
+        Self {
+            public_key_id,
+            valid_from,
+            valid_until,
+            reason,
+        }
+    }
+
+    pub fn public_key_id(&self) -> KeyID {
+        self.public_key_id
+    }
+
+    pub fn valid_from(&self) -> Option<TimestampMillis> {
+        self.valid_from
+    }
+
+    pub fn valid_until(&self) -> Option<TimestampMillis> {
+        self.valid_until
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl From<InvalidIdentityPublicKeyValidityWindowError> for ConsensusError {
+    fn from(err: InvalidIdentityPublicKeyValidityWindowError) -> Self {
+        Self::BasicError(BasicError::InvalidIdentityPublicKeyValidityWindowError(err))
+    }
+}
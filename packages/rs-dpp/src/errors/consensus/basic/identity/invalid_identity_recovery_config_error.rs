@@ -0,0 +1,46 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Invalid recovery configuration for identity {identity_id}: {reason}")]
+#[platform_serialize(unversioned)]
+pub struct InvalidIdentityRecoveryConfigError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    identity_id: Identifier,
+    reason: String,
+}
+
+impl InvalidIdentityRecoveryConfigError {
+    pub fn new(identity_id: Identifier, reason: String) -> Self {
+        Self {
+            identity_id,
+            reason,
+        }
+    }
+
+    pub fn identity_id(&self) -> Identifier {
+        self.identity_id
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl From<InvalidIdentityRecoveryConfigError> for ConsensusError {
+    fn from(err: InvalidIdentityRecoveryConfigError) -> Self {
+        Self::BasicError(BasicError::InvalidIdentityRecoveryConfigError(err))
+    }
+}
@@ -0,0 +1,53 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use crate::identity::{KeyID, Purpose, SecurityLevel};
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity public key {public_key_id} can't be installed by a recovery: it must be an enabled master authentication key, but got purpose {purpose:?} with security level {security_level:?}")]
+#[platform_serialize(unversioned)]
+pub struct InvalidIdentityRecoveryMasterKeyError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    public_key_id: KeyID,
+    purpose: Purpose,
+    security_level: SecurityLevel,
+}
+
+impl InvalidIdentityRecoveryMasterKeyError {
+    pub fn new(public_key_id: KeyID, purpose: Purpose, security_level: SecurityLevel) -> Self {
+        Self {
+            public_key_id,
+            purpose,
+            security_level,
+        }
+    }
+
+    pub fn public_key_id(&self) -> KeyID {
+        self.public_key_id
+    }
+
+    pub fn purpose(&self) -> Purpose {
+        self.purpose
+    }
+
+    pub fn security_level(&self) -> SecurityLevel {
+        self.security_level
+    }
+}
+
+impl From<InvalidIdentityRecoveryMasterKeyError> for ConsensusError {
+    fn from(err: InvalidIdentityRecoveryMasterKeyError) -> Self {
+        Self::BasicError(BasicError::InvalidIdentityRecoveryMasterKeyError(err))
+    }
+}
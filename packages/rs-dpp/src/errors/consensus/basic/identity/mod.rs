@@ -1,5 +1,6 @@
 pub use data_contract_bounds_not_present_error::*;
 pub use disabling_key_id_also_being_added_in_same_transition_error::*;
+pub use duplicated_identity_credit_transfer_recipient_error::*;
 pub use duplicated_identity_public_key_basic_error::*;
 pub use duplicated_identity_public_key_id_basic_error::*;
 pub use identity_asset_lock_proof_locked_transaction_mismatch_error::*;
@@ -8,13 +9,16 @@ pub use identity_asset_lock_transaction_is_not_found_error::*;
 pub use identity_asset_lock_transaction_out_point_already_consumed_error::*;
 pub use identity_asset_lock_transaction_out_point_not_enough_balance_error::*;
 pub use identity_asset_lock_transaction_output_not_found_error::*;
+pub use identity_credit_transfer_memo_too_long_error::*;
 pub use identity_credit_transfer_to_self_error::*;
 pub use invalid_asset_lock_proof_core_chain_height_error::*;
 pub use invalid_asset_lock_proof_transaction_height_error::*;
 pub use invalid_asset_lock_transaction_output_return_size::*;
+pub use invalid_contracts_owner_key_bounds_error::*;
 pub use invalid_identity_asset_lock_proof_chain_lock_validation_error::*;
 pub use invalid_identity_asset_lock_transaction_error::*;
 pub use invalid_identity_asset_lock_transaction_output_error::*;
+pub use invalid_identity_credit_batch_transfer_outputs_count_error::*;
 pub use invalid_identity_credit_transfer_amount_error::*;
 pub use invalid_identity_credit_withdrawal_transition_amount_error::*;
 pub use invalid_identity_credit_withdrawal_transition_core_fee_error::*;
@@ -22,6 +26,10 @@ pub use invalid_identity_credit_withdrawal_transition_output_script_error::*;
 pub use invalid_identity_key_signature_error::*;
 pub use invalid_identity_public_key_data_error::*;
 pub use invalid_identity_public_key_security_level_error::*;
+pub use invalid_identity_public_key_signature_threshold_error::*;
+pub use invalid_identity_public_key_validity_window_error::*;
+pub use invalid_identity_recovery_config_error::*;
+pub use invalid_identity_recovery_master_key_error::*;
 pub use invalid_identity_update_transition_disable_keys_error::*;
 pub use invalid_identity_update_transition_empty_error::*;
 pub use invalid_instant_asset_lock_proof_error::*;
@@ -32,6 +40,7 @@ pub use too_many_master_public_key_error::*;
 
 mod data_contract_bounds_not_present_error;
 mod disabling_key_id_also_being_added_in_same_transition_error;
+mod duplicated_identity_credit_transfer_recipient_error;
 mod duplicated_identity_public_key_basic_error;
 mod duplicated_identity_public_key_id_basic_error;
 mod identity_asset_lock_proof_locked_transaction_mismatch_error;
@@ -41,13 +50,16 @@ mod identity_asset_lock_transaction_out_point_already_consumed_error;
 mod identity_asset_lock_state_transition_replay_error;
 mod identity_asset_lock_transaction_out_point_not_enough_balance_error;
 mod identity_asset_lock_transaction_output_not_found_error;
+mod identity_credit_transfer_memo_too_long_error;
 mod identity_credit_transfer_to_self_error;
 mod invalid_asset_lock_proof_core_chain_height_error;
 mod invalid_asset_lock_proof_transaction_height_error;
 mod invalid_asset_lock_transaction_output_return_size;
+mod invalid_contracts_owner_key_bounds_error;
 mod invalid_identity_asset_lock_proof_chain_lock_validation_error;
 mod invalid_identity_asset_lock_transaction_error;
 mod invalid_identity_asset_lock_transaction_output_error;
+mod invalid_identity_credit_batch_transfer_outputs_count_error;
 mod invalid_identity_credit_transfer_amount_error;
 mod invalid_identity_credit_withdrawal_transition_amount_error;
 mod invalid_identity_credit_withdrawal_transition_core_fee_error;
@@ -55,6 +67,10 @@ mod invalid_identity_credit_withdrawal_transition_output_script_error;
 mod invalid_identity_key_signature_error;
 mod invalid_identity_public_key_data_error;
 mod invalid_identity_public_key_security_level_error;
+mod invalid_identity_public_key_signature_threshold_error;
+mod invalid_identity_public_key_validity_window_error;
+mod invalid_identity_recovery_config_error;
+mod invalid_identity_recovery_master_key_error;
 mod invalid_identity_update_transition_disable_keys_error;
 mod invalid_identity_update_transition_empty_error;
 mod invalid_instant_asset_lock_proof_error;
@@ -98,6 +98,7 @@ impl ErrorWithCode for BasicError {
             Self::ContractError(DataContractError::RegexError(_)) => 10247,
             Self::ContestedUniqueIndexOnMutableDocumentTypeError(_) => 10248,
             Self::ContestedUniqueIndexWithUniqueIndexError(_) => 10249,
+            Self::AggregatedIndicesLimitReachedError(_) => 10250,
 
             // Document Errors: 10400-10499
             Self::DataContractNotPresentError { .. } => 10400,
@@ -153,6 +154,14 @@ impl ErrorWithCode for BasicError {
             Self::MasterPublicKeyUpdateError(_) => 10529,
             Self::IdentityAssetLockTransactionOutPointNotEnoughBalanceError(_) => 10530,
             Self::IdentityAssetLockStateTransitionReplayError(_) => 10531,
+            Self::InvalidIdentityPublicKeyValidityWindowError(_) => 10532,
+            Self::InvalidIdentityPublicKeySignatureThresholdError(_) => 10533,
+            Self::InvalidContractsOwnerKeyBoundsError(_) => 10534,
+            Self::InvalidIdentityRecoveryConfigError(_) => 10535,
+            Self::InvalidIdentityRecoveryMasterKeyError(_) => 10536,
+            Self::InvalidIdentityCreditBatchTransferOutputsCountError(_) => 10537,
+            Self::DuplicatedIdentityCreditTransferRecipientError(_) => 10538,
+            Self::IdentityCreditTransferMemoTooLongError(_) => 10539,
 
             // State Transition Errors: 10600-10699
             Self::InvalidStateTransitionTypeError { .. } => 10600,
@@ -180,6 +189,10 @@ impl ErrorWithCode for SignatureError {
             Self::BasicECDSAError(_) => 20009,
             Self::BasicBLSError(_) => 20010,
             Self::InvalidSignaturePublicKeyPurposeError(_) => 20011,
+            Self::PublicKeyIsNotValidAtTimeError(_) => 20012,
+            Self::NotEnoughThresholdSignaturesError(_) => 20013,
+            Self::InvalidGuardianSignatureError(_) => 20014,
+            Self::NotEnoughGuardianSignaturesError(_) => 20015,
         }
     }
 }
@@ -199,6 +212,9 @@ impl ErrorWithCode for StateError {
             Self::DataContractAlreadyPresentError { .. } => 40000,
             Self::DataContractIsReadonlyError { .. } => 40001,
             Self::DataContractConfigUpdateError { .. } => 40002,
+            Self::DataContractIsArchivedError(_) => 40003,
+            Self::DataContractIsNotDeletableError(_) => 40004,
+            Self::DataContractTooManyDocumentsToDeleteError(_) => 40005,
 
             // Document Errors: 40100-40199
             Self::DocumentAlreadyPresentError { .. } => 40100,
@@ -214,6 +230,9 @@ impl ErrorWithCode for StateError {
             Self::DocumentContestCurrentlyLockedError(_) => 40110,
             Self::DocumentContestNotJoinableError(_) => 40111,
             Self::DocumentContestIdentityAlreadyContestantError(_) => 40112,
+            Self::DocumentOfferAlreadyExistsError(_) => 40113,
+            Self::DocumentOfferNotFoundError(_) => 40114,
+            Self::DocumentOfferPriceTooLowError(_) => 40115,
 
             // Identity Errors: 40200-40299
             Self::IdentityAlreadyExistsError(_) => 40200,
@@ -230,6 +249,11 @@ impl ErrorWithCode for StateError {
             Self::IdentityPublicKeyAlreadyExistsForUniqueContractBoundsError(_) => 40211,
             Self::DocumentTypeUpdateError(_) => 40212,
             Self::DataContractUpdatePermissionError(_) => 40213,
+            Self::IdentityRecoveryNotConfiguredError(_) => 40214,
+            Self::IdentityRecoveryAlreadyPendingError(_) => 40215,
+            Self::NoPendingIdentityRecoveryError(_) => 40216,
+            Self::IdentityRecoveryDelayNotElapsedError(_) => 40217,
+            Self::IdentityRecoveryGuardianNotFoundError(_) => 40218,
 
             // Voting Errors: 40300-40399
             Self::MasternodeNotFoundError(_) => 40300,
@@ -239,6 +263,16 @@ impl ErrorWithCode for StateError {
             Self::MasternodeVoteAlreadyPresentError(_) => 40304,
             Self::MasternodeIncorrectVotingAddressError(_) => 40305,
             Self::MasternodeIncorrectVoterIdentityIdError(_) => 40306,
+            Self::MasternodeNotEvonodeError(_) => 40307,
+            Self::GovernanceProposalVotePollEndDateOutOfRangeError(_) => 40308,
+            Self::GovernanceProposalVotePollEndedError(_) => 40309,
+            Self::VotePollResolvedByAuctionError(_) => 40310,
+            Self::VotePollNotResolvedByAuctionError(_) => 40311,
+            Self::AuctionBidOutsideOfWindowError(_) => 40312,
+            Self::AuctionBidderNotContenderError(_) => 40313,
+            Self::AuctionBidAlreadyCommittedError(_) => 40314,
+            Self::InvalidAuctionBidRevealError(_) => 40315,
+            Self::GovernanceProposalVotePollsOpenedLimitReachedError(_) => 40316,
 
             // Prefunded specialized balances Errors: 40400-40499
             Self::PrefundedSpecializedBalanceInsufficientError(_) => 40400,
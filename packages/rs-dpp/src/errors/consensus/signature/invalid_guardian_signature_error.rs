@@ -0,0 +1,54 @@
+use thiserror::Error;
+
+use crate::consensus::signature::signature_error::SignatureError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use crate::identity::KeyID;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Invalid signature of recovery guardian {guardian_id} with key {public_key_id}: {reason}")]
+#[platform_serialize(unversioned)]
+pub struct InvalidGuardianSignatureError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    guardian_id: Identifier,
+    public_key_id: KeyID,
+    reason: String,
+}
+
+impl InvalidGuardianSignatureError {
+    pub fn new(guardian_id: Identifier, public_key_id: KeyID, reason: String) -> Self {
+        Self {
+            guardian_id,
+            public_key_id,
+            reason,
+        }
+    }
+
+    pub fn guardian_id(&self) -> Identifier {
+        self.guardian_id
+    }
+
+    pub fn public_key_id(&self) -> KeyID {
+        self.public_key_id
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl From<InvalidGuardianSignatureError> for ConsensusError {
+    fn from(err: InvalidGuardianSignatureError) -> Self {
+        Self::SignatureError(SignatureError::InvalidGuardianSignatureError(err))
+    }
+}
@@ -1,12 +1,16 @@
 mod basic_bls_error;
 mod basic_ecdsa_error;
 mod identity_not_found_error;
+mod invalid_guardian_signature_error;
 mod invalid_identity_public_key_type_error;
 mod invalid_signature_public_key_purpose_error;
 mod invalid_signature_public_key_security_level_error;
 mod invalid_state_transition_signature_error;
 mod missing_public_key_error;
+mod not_enough_guardian_signatures_error;
+mod not_enough_threshold_signatures_error;
 mod public_key_is_disabled_error;
+mod public_key_is_not_valid_at_time_error;
 mod public_key_security_level_not_met_error;
 mod signature_error;
 mod signature_should_not_be_present_error;
@@ -15,12 +19,16 @@ mod wrong_public_key_purpose_error;
 pub use crate::consensus::signature::basic_bls_error::BasicBLSError;
 pub use crate::consensus::signature::basic_ecdsa_error::BasicECDSAError;
 pub use crate::consensus::signature::identity_not_found_error::IdentityNotFoundError;
+pub use crate::consensus::signature::invalid_guardian_signature_error::InvalidGuardianSignatureError;
 pub use crate::consensus::signature::invalid_identity_public_key_type_error::InvalidIdentityPublicKeyTypeError;
 pub use crate::consensus::signature::invalid_signature_public_key_purpose_error::InvalidSignaturePublicKeyPurposeError;
 pub use crate::consensus::signature::invalid_signature_public_key_security_level_error::InvalidSignaturePublicKeySecurityLevelError;
 pub use crate::consensus::signature::invalid_state_transition_signature_error::InvalidStateTransitionSignatureError;
 pub use crate::consensus::signature::missing_public_key_error::MissingPublicKeyError;
+pub use crate::consensus::signature::not_enough_guardian_signatures_error::NotEnoughGuardianSignaturesError;
+pub use crate::consensus::signature::not_enough_threshold_signatures_error::NotEnoughThresholdSignaturesError;
 pub use crate::consensus::signature::public_key_is_disabled_error::PublicKeyIsDisabledError;
+pub use crate::consensus::signature::public_key_is_not_valid_at_time_error::PublicKeyIsNotValidAtTimeError;
 pub use crate::consensus::signature::public_key_security_level_not_met_error::PublicKeySecurityLevelNotMetError;
 pub use crate::consensus::signature::signature_error::SignatureError;
 pub use crate::consensus::signature::signature_should_not_be_present_error::SignatureShouldNotBePresentError;
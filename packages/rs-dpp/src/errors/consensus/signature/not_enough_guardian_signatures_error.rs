@@ -0,0 +1,53 @@
+use thiserror::Error;
+
+use crate::consensus::signature::signature_error::SignatureError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Recovery of identity {identity_id} requires {required} guardian signatures, but only {provided} were provided")]
+#[platform_serialize(unversioned)]
+pub struct NotEnoughGuardianSignaturesError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    identity_id: Identifier,
+    required: u8,
+    provided: u8,
+}
+
+impl NotEnoughGuardianSignaturesError {
+    pub fn new(identity_id: Identifier, required: u8, provided: u8) -> Self {
+        Self {
+            identity_id,
+            required,
+            provided,
+        }
+    }
+
+    pub fn identity_id(&self) -> Identifier {
+        self.identity_id
+    }
+
+    pub fn required(&self) -> u8 {
+        self.required
+    }
+
+    pub fn provided(&self) -> u8 {
+        self.provided
+    }
+}
+
+impl From<NotEnoughGuardianSignaturesError> for ConsensusError {
+    fn from(err: NotEnoughGuardianSignaturesError) -> Self {
+        Self::SignatureError(SignatureError::NotEnoughGuardianSignaturesError(err))
+    }
+}
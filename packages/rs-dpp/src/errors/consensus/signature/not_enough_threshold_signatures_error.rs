@@ -0,0 +1,53 @@
+use thiserror::Error;
+
+use crate::consensus::signature::signature_error::SignatureError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use crate::identity::KeyID;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity key {public_key_id} requires {required} valid signatures, but only {provided} were provided")]
+#[platform_serialize(unversioned)]
+pub struct NotEnoughThresholdSignaturesError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    public_key_id: KeyID,
+    required: u8,
+    provided: u8,
+}
+
+impl NotEnoughThresholdSignaturesError {
+    pub fn new(public_key_id: KeyID, required: u8, provided: u8) -> Self {
+        Self {
+            public_key_id,
+            required,
+            provided,
+        }
+    }
+
+    pub fn public_key_id(&self) -> KeyID {
+        self.public_key_id
+    }
+
+    pub fn required(&self) -> u8 {
+        self.required
+    }
+
+    pub fn provided(&self) -> u8 {
+        self.provided
+    }
+}
+
+impl From<NotEnoughThresholdSignaturesError> for ConsensusError {
+    fn from(err: NotEnoughThresholdSignaturesError) -> Self {
+        Self::SignatureError(SignatureError::NotEnoughThresholdSignaturesError(err))
+    }
+}
@@ -0,0 +1,64 @@
+use thiserror::Error;
+
+use crate::consensus::signature::signature_error::SignatureError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use crate::identity::{KeyID, TimestampMillis};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity key {public_key_id} is only valid from {valid_from:?} until {valid_until:?}, but block time is {block_time}")]
+#[platform_serialize(unversioned)]
+pub struct PublicKeyIsNotValidAtTimeError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    public_key_id: KeyID,
+    valid_from: Option<TimestampMillis>,
+    valid_until: Option<TimestampMillis>,
+    block_time: TimestampMillis,
+}
+
+impl PublicKeyIsNotValidAtTimeError {
+    pub fn new(
+        public_key_id: KeyID,
+        valid_from: Option<TimestampMillis>,
+        valid_until: Option<TimestampMillis>,
+        block_time: TimestampMillis,
+    ) -> Self {
+        Self {
+            public_key_id,
+            valid_from,
+            valid_until,
+            block_time,
+        }
+    }
+
+    pub fn public_key_id(&self) -> KeyID {
+        self.public_key_id
+    }
+
+    pub fn valid_from(&self) -> Option<TimestampMillis> {
+        self.valid_from
+    }
+
+    pub fn valid_until(&self) -> Option<TimestampMillis> {
+        self.valid_until
+    }
+
+    pub fn block_time(&self) -> TimestampMillis {
+        self.block_time
+    }
+}
+
+impl From<PublicKeyIsNotValidAtTimeError> for ConsensusError {
+    fn from(err: PublicKeyIsNotValidAtTimeError) -> Self {
+        Self::SignatureError(SignatureError::PublicKeyIsNotValidAtTimeError(err))
+    }
+}
@@ -1,8 +1,10 @@
 use crate::consensus::signature::{
-    BasicBLSError, BasicECDSAError, IdentityNotFoundError, InvalidIdentityPublicKeyTypeError,
-    InvalidSignaturePublicKeySecurityLevelError, InvalidStateTransitionSignatureError,
-    MissingPublicKeyError, PublicKeyIsDisabledError, PublicKeySecurityLevelNotMetError,
-    SignatureShouldNotBePresentError, WrongPublicKeyPurposeError,
+    BasicBLSError, BasicECDSAError, IdentityNotFoundError, InvalidGuardianSignatureError,
+    InvalidIdentityPublicKeyTypeError, InvalidSignaturePublicKeySecurityLevelError,
+    InvalidStateTransitionSignatureError, MissingPublicKeyError, NotEnoughGuardianSignaturesError,
+    NotEnoughThresholdSignaturesError, PublicKeyIsDisabledError, PublicKeyIsNotValidAtTimeError,
+    PublicKeySecurityLevelNotMetError, SignatureShouldNotBePresentError,
+    WrongPublicKeyPurposeError,
 };
 use crate::consensus::ConsensusError;
 use bincode::{Decode, Encode};
@@ -56,6 +58,18 @@ pub enum SignatureError {
 
     #[error(transparent)]
     BasicBLSError(BasicBLSError),
+
+    #[error(transparent)]
+    PublicKeyIsNotValidAtTimeError(PublicKeyIsNotValidAtTimeError),
+
+    #[error(transparent)]
+    NotEnoughThresholdSignaturesError(NotEnoughThresholdSignaturesError),
+
+    #[error(transparent)]
+    InvalidGuardianSignatureError(InvalidGuardianSignatureError),
+
+    #[error(transparent)]
+    NotEnoughGuardianSignaturesError(NotEnoughGuardianSignaturesError),
 }
 
 impl From<SignatureError> for ConsensusError {
@@ -0,0 +1,45 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Data Contract {data_contract_id} has more than {max_documents} documents and can't be deleted, it can only be archived")]
+#[platform_serialize(unversioned)]
+pub struct DataContractTooManyDocumentsToDeleteError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    data_contract_id: Identifier,
+    max_documents: u16,
+}
+
+impl DataContractTooManyDocumentsToDeleteError {
+    pub fn new(data_contract_id: Identifier, max_documents: u16) -> Self {
+        Self {
+            data_contract_id,
+            max_documents,
+        }
+    }
+
+    pub fn data_contract_id(&self) -> &Identifier {
+        &self.data_contract_id
+    }
+
+    pub fn max_documents(&self) -> u16 {
+        self.max_documents
+    }
+}
+
+impl From<DataContractTooManyDocumentsToDeleteError> for ConsensusError {
+    fn from(err: DataContractTooManyDocumentsToDeleteError) -> Self {
+        Self::StateError(StateError::DataContractTooManyDocumentsToDeleteError(err))
+    }
+}
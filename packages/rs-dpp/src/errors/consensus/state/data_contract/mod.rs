@@ -1,5 +1,8 @@
 pub mod data_contract_already_present_error;
 pub mod data_contract_config_update_error;
+pub mod data_contract_is_archived_error;
+pub mod data_contract_is_not_deletable_error;
 pub mod data_contract_is_readonly_error;
+pub mod data_contract_too_many_documents_to_delete_error;
 pub mod data_contract_update_permission_error;
 pub mod document_type_update_error;
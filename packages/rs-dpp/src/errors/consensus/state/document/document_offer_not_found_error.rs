@@ -0,0 +1,46 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("{bidder_id} has no open offer on document {document_id}")]
+#[platform_serialize(unversioned)]
+pub struct DocumentOfferNotFoundError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    document_id: Identifier,
+
+    bidder_id: Identifier,
+}
+
+impl DocumentOfferNotFoundError {
+    pub fn new(document_id: Identifier, bidder_id: Identifier) -> Self {
+        Self {
+            document_id,
+            bidder_id,
+        }
+    }
+
+    pub fn document_id(&self) -> &Identifier {
+        &self.document_id
+    }
+
+    pub fn bidder_id(&self) -> &Identifier {
+        &self.bidder_id
+    }
+}
+
+impl From<DocumentOfferNotFoundError> for ConsensusError {
+    fn from(err: DocumentOfferNotFoundError) -> Self {
+        Self::StateError(StateError::DocumentOfferNotFoundError(err))
+    }
+}
@@ -0,0 +1,54 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use crate::fee::Credits;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("an offer of {offered_price} on document {document_id} is below its minimum price of {min_price} (in credits)")]
+#[platform_serialize(unversioned)]
+pub struct DocumentOfferPriceTooLowError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    document_id: Identifier,
+
+    offered_price: Credits,
+
+    min_price: Credits,
+}
+
+impl DocumentOfferPriceTooLowError {
+    pub fn new(document_id: Identifier, offered_price: Credits, min_price: Credits) -> Self {
+        Self {
+            document_id,
+            offered_price,
+            min_price,
+        }
+    }
+
+    pub fn document_id(&self) -> &Identifier {
+        &self.document_id
+    }
+
+    pub fn offered_price(&self) -> Credits {
+        self.offered_price
+    }
+
+    pub fn min_price(&self) -> Credits {
+        self.min_price
+    }
+}
+
+impl From<DocumentOfferPriceTooLowError> for ConsensusError {
+    fn from(err: DocumentOfferPriceTooLowError) -> Self {
+        Self::StateError(StateError::DocumentOfferPriceTooLowError(err))
+    }
+}
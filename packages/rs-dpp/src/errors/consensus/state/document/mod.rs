@@ -5,6 +5,9 @@ pub mod document_contest_not_joinable_error;
 pub mod document_incorrect_purchase_price_error;
 pub mod document_not_for_sale_error;
 pub mod document_not_found_error;
+pub mod document_offer_already_exists_error;
+pub mod document_offer_not_found_error;
+pub mod document_offer_price_too_low_error;
 pub mod document_owner_id_mismatch_error;
 pub mod document_timestamp_window_violation_error;
 pub mod document_timestamps_are_equal_error;
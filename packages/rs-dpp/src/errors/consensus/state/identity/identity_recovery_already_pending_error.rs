@@ -0,0 +1,47 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use crate::prelude::{Identifier, TimestampMillis};
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity {identity_id} already has a pending recovery executable at {executable_at}")]
+#[platform_serialize(unversioned)]
+pub struct IdentityRecoveryAlreadyPendingError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    identity_id: Identifier,
+    executable_at: TimestampMillis,
+}
+
+impl IdentityRecoveryAlreadyPendingError {
+    pub fn new(identity_id: Identifier, executable_at: TimestampMillis) -> Self {
+        Self {
+            identity_id,
+            executable_at,
+        }
+    }
+
+    pub fn identity_id(&self) -> Identifier {
+        self.identity_id
+    }
+
+    pub fn executable_at(&self) -> TimestampMillis {
+        self.executable_at
+    }
+}
+
+impl From<IdentityRecoveryAlreadyPendingError> for ConsensusError {
+    fn from(err: IdentityRecoveryAlreadyPendingError) -> Self {
+        Self::StateError(StateError::IdentityRecoveryAlreadyPendingError(err))
+    }
+}
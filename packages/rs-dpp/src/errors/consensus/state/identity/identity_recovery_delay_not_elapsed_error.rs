@@ -0,0 +1,57 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use crate::prelude::{Identifier, TimestampMillis};
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Recovery of identity {identity_id} can only be completed from {executable_at}, current block time is {current_time}")]
+#[platform_serialize(unversioned)]
+pub struct IdentityRecoveryDelayNotElapsedError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    identity_id: Identifier,
+    executable_at: TimestampMillis,
+    current_time: TimestampMillis,
+}
+
+impl IdentityRecoveryDelayNotElapsedError {
+    pub fn new(
+        identity_id: Identifier,
+        executable_at: TimestampMillis,
+        current_time: TimestampMillis,
+    ) -> Self {
+        Self {
+            identity_id,
+            executable_at,
+            current_time,
+        }
+    }
+
+    pub fn identity_id(&self) -> Identifier {
+        self.identity_id
+    }
+
+    pub fn executable_at(&self) -> TimestampMillis {
+        self.executable_at
+    }
+
+    pub fn current_time(&self) -> TimestampMillis {
+        self.current_time
+    }
+}
+
+impl From<IdentityRecoveryDelayNotElapsedError> for ConsensusError {
+    fn from(err: IdentityRecoveryDelayNotElapsedError) -> Self {
+        Self::StateError(StateError::IdentityRecoveryDelayNotElapsedError(err))
+    }
+}
@@ -0,0 +1,47 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use crate::prelude::Identifier;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Recovery guardian {guardian_id} of identity {identity_id} does not exist")]
+#[platform_serialize(unversioned)]
+pub struct IdentityRecoveryGuardianNotFoundError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    identity_id: Identifier,
+    guardian_id: Identifier,
+}
+
+impl IdentityRecoveryGuardianNotFoundError {
+    pub fn new(identity_id: Identifier, guardian_id: Identifier) -> Self {
+        Self {
+            identity_id,
+            guardian_id,
+        }
+    }
+
+    pub fn identity_id(&self) -> Identifier {
+        self.identity_id
+    }
+
+    pub fn guardian_id(&self) -> Identifier {
+        self.guardian_id
+    }
+}
+
+impl From<IdentityRecoveryGuardianNotFoundError> for ConsensusError {
+    fn from(err: IdentityRecoveryGuardianNotFoundError) -> Self {
+        Self::StateError(StateError::IdentityRecoveryGuardianNotFoundError(err))
+    }
+}
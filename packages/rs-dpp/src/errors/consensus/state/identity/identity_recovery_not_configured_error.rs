@@ -0,0 +1,39 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use crate::prelude::Identifier;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity {identity_id} has no recovery configuration")]
+#[platform_serialize(unversioned)]
+pub struct IdentityRecoveryNotConfiguredError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    identity_id: Identifier,
+}
+
+impl IdentityRecoveryNotConfiguredError {
+    pub fn new(identity_id: Identifier) -> Self {
+        Self { identity_id }
+    }
+
+    pub fn identity_id(&self) -> Identifier {
+        self.identity_id
+    }
+}
+
+impl From<IdentityRecoveryNotConfiguredError> for ConsensusError {
+    fn from(err: IdentityRecoveryNotConfiguredError) -> Self {
+        Self::StateError(StateError::IdentityRecoveryNotConfiguredError(err))
+    }
+}
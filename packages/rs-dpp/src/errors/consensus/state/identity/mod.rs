@@ -8,9 +8,14 @@ mod identity_insufficient_balance_error;
 pub mod identity_public_key_already_exists_for_unique_contract_bounds_error;
 pub mod identity_public_key_is_disabled_error;
 pub mod identity_public_key_is_read_only_error;
+pub mod identity_recovery_already_pending_error;
+pub mod identity_recovery_delay_not_elapsed_error;
+pub mod identity_recovery_guardian_not_found_error;
+pub mod identity_recovery_not_configured_error;
 pub mod invalid_identity_contract_nonce_error;
 pub mod invalid_identity_public_key_id_error;
 pub mod invalid_identity_revision_error;
 pub mod master_public_key_update_error;
 pub mod max_identity_public_key_limit_reached_error;
 pub mod missing_identity_public_key_ids_error;
+pub mod no_pending_identity_recovery_error;
@@ -5,7 +5,10 @@ use thiserror::Error;
 
 use crate::consensus::state::data_contract::data_contract_already_present_error::DataContractAlreadyPresentError;
 use crate::consensus::state::data_contract::data_contract_config_update_error::DataContractConfigUpdateError;
+use crate::consensus::state::data_contract::data_contract_is_archived_error::DataContractIsArchivedError;
+use crate::consensus::state::data_contract::data_contract_is_not_deletable_error::DataContractIsNotDeletableError;
 use crate::consensus::state::data_contract::data_contract_is_readonly_error::DataContractIsReadonlyError;
+use crate::consensus::state::data_contract::data_contract_too_many_documents_to_delete_error::DataContractTooManyDocumentsToDeleteError;
 #[cfg(feature = "state-transition-validation")]
 use crate::consensus::state::data_trigger::DataTriggerError;
 use crate::consensus::state::document::document_already_present_error::DocumentAlreadyPresentError;
@@ -19,10 +22,15 @@ use crate::consensus::state::identity::duplicated_identity_public_key_id_state_e
 use crate::consensus::state::identity::duplicated_identity_public_key_state_error::DuplicatedIdentityPublicKeyStateError;
 use crate::consensus::state::identity::identity_public_key_is_disabled_error::IdentityPublicKeyIsDisabledError;
 use crate::consensus::state::identity::identity_public_key_is_read_only_error::IdentityPublicKeyIsReadOnlyError;
+use crate::consensus::state::identity::identity_recovery_already_pending_error::IdentityRecoveryAlreadyPendingError;
+use crate::consensus::state::identity::identity_recovery_delay_not_elapsed_error::IdentityRecoveryDelayNotElapsedError;
+use crate::consensus::state::identity::identity_recovery_guardian_not_found_error::IdentityRecoveryGuardianNotFoundError;
+use crate::consensus::state::identity::identity_recovery_not_configured_error::IdentityRecoveryNotConfiguredError;
 use crate::consensus::state::identity::invalid_identity_public_key_id_error::InvalidIdentityPublicKeyIdError;
 use crate::consensus::state::identity::invalid_identity_revision_error::InvalidIdentityRevisionError;
 use crate::consensus::state::identity::max_identity_public_key_limit_reached_error::MaxIdentityPublicKeyLimitReachedError;
 use crate::consensus::state::identity::missing_identity_public_key_ids_error::MissingIdentityPublicKeyIdsError;
+use crate::consensus::state::identity::no_pending_identity_recovery_error::NoPendingIdentityRecoveryError;
 use crate::consensus::state::identity::{
     IdentityAlreadyExistsError, IdentityInsufficientBalanceError,
 };
@@ -34,17 +42,30 @@ use crate::consensus::state::document::document_contest_identity_already_contest
 use crate::consensus::state::document::document_contest_not_joinable_error::DocumentContestNotJoinableError;
 use crate::consensus::state::document::document_incorrect_purchase_price_error::DocumentIncorrectPurchasePriceError;
 use crate::consensus::state::document::document_not_for_sale_error::DocumentNotForSaleError;
+use crate::consensus::state::document::document_offer_already_exists_error::DocumentOfferAlreadyExistsError;
+use crate::consensus::state::document::document_offer_not_found_error::DocumentOfferNotFoundError;
+use crate::consensus::state::document::document_offer_price_too_low_error::DocumentOfferPriceTooLowError;
 use crate::consensus::state::identity::identity_public_key_already_exists_for_unique_contract_bounds_error::IdentityPublicKeyAlreadyExistsForUniqueContractBoundsError;
 use crate::consensus::state::identity::invalid_identity_contract_nonce_error::InvalidIdentityNonceError;
 use crate::consensus::state::prefunded_specialized_balances::prefunded_specialized_balance_insufficient_error::PrefundedSpecializedBalanceInsufficientError;
 use crate::consensus::state::prefunded_specialized_balances::prefunded_specialized_balance_not_found_error::PrefundedSpecializedBalanceNotFoundError;
+use crate::consensus::state::voting::auction_bid_already_committed_error::AuctionBidAlreadyCommittedError;
+use crate::consensus::state::voting::auction_bid_outside_of_window_error::AuctionBidOutsideOfWindowError;
+use crate::consensus::state::voting::auction_bidder_not_contender_error::AuctionBidderNotContenderError;
+use crate::consensus::state::voting::governance_proposal_vote_poll_end_date_out_of_range_error::GovernanceProposalVotePollEndDateOutOfRangeError;
+use crate::consensus::state::voting::governance_proposal_vote_poll_ended_error::GovernanceProposalVotePollEndedError;
+use crate::consensus::state::voting::governance_proposal_vote_polls_opened_limit_reached_error::GovernanceProposalVotePollsOpenedLimitReachedError;
 use crate::consensus::state::voting::masternode_incorrect_voter_identity_id_error::MasternodeIncorrectVoterIdentityIdError;
 use crate::consensus::state::voting::masternode_incorrect_voting_address_error::MasternodeIncorrectVotingAddressError;
+use crate::consensus::state::voting::masternode_not_evonode_error::MasternodeNotEvonodeError;
 use crate::consensus::state::voting::masternode_not_found_error::MasternodeNotFoundError;
 use crate::consensus::state::voting::masternode_vote_already_present_error::MasternodeVoteAlreadyPresentError;
 use crate::consensus::state::voting::masternode_voted_too_many_times::MasternodeVotedTooManyTimesError;
 use crate::consensus::state::voting::vote_poll_not_available_for_voting_error::VotePollNotAvailableForVotingError;
+use crate::consensus::state::voting::invalid_auction_bid_reveal_error::InvalidAuctionBidRevealError;
 use crate::consensus::state::voting::vote_poll_not_found_error::VotePollNotFoundError;
+use crate::consensus::state::voting::vote_poll_not_resolved_by_auction_error::VotePollNotResolvedByAuctionError;
+use crate::consensus::state::voting::vote_poll_resolved_by_auction_error::VotePollResolvedByAuctionError;
 
 use super::document::document_timestamps_are_equal_error::DocumentTimestampsAreEqualError;
 
@@ -181,6 +202,73 @@ pub enum StateError {
 
     #[error(transparent)]
     MasternodeVoteAlreadyPresentError(MasternodeVoteAlreadyPresentError),
+
+    #[error(transparent)]
+    DataContractIsArchivedError(DataContractIsArchivedError),
+
+    #[error(transparent)]
+    DataContractIsNotDeletableError(DataContractIsNotDeletableError),
+
+    #[error(transparent)]
+    DataContractTooManyDocumentsToDeleteError(DataContractTooManyDocumentsToDeleteError),
+
+    #[error(transparent)]
+    DocumentOfferAlreadyExistsError(DocumentOfferAlreadyExistsError),
+
+    #[error(transparent)]
+    DocumentOfferNotFoundError(DocumentOfferNotFoundError),
+
+    #[error(transparent)]
+    DocumentOfferPriceTooLowError(DocumentOfferPriceTooLowError),
+
+    #[error(transparent)]
+    IdentityRecoveryNotConfiguredError(IdentityRecoveryNotConfiguredError),
+
+    #[error(transparent)]
+    IdentityRecoveryAlreadyPendingError(IdentityRecoveryAlreadyPendingError),
+
+    #[error(transparent)]
+    NoPendingIdentityRecoveryError(NoPendingIdentityRecoveryError),
+
+    #[error(transparent)]
+    IdentityRecoveryDelayNotElapsedError(IdentityRecoveryDelayNotElapsedError),
+
+    #[error(transparent)]
+    IdentityRecoveryGuardianNotFoundError(IdentityRecoveryGuardianNotFoundError),
+
+    #[error(transparent)]
+    MasternodeNotEvonodeError(MasternodeNotEvonodeError),
+
+    #[error(transparent)]
+    GovernanceProposalVotePollEndDateOutOfRangeError(
+        GovernanceProposalVotePollEndDateOutOfRangeError,
+    ),
+
+    #[error(transparent)]
+    GovernanceProposalVotePollEndedError(GovernanceProposalVotePollEndedError),
+
+    #[error(transparent)]
+    VotePollResolvedByAuctionError(VotePollResolvedByAuctionError),
+
+    #[error(transparent)]
+    VotePollNotResolvedByAuctionError(VotePollNotResolvedByAuctionError),
+
+    #[error(transparent)]
+    AuctionBidOutsideOfWindowError(AuctionBidOutsideOfWindowError),
+
+    #[error(transparent)]
+    AuctionBidderNotContenderError(AuctionBidderNotContenderError),
+
+    #[error(transparent)]
+    AuctionBidAlreadyCommittedError(AuctionBidAlreadyCommittedError),
+
+    #[error(transparent)]
+    InvalidAuctionBidRevealError(InvalidAuctionBidRevealError),
+
+    #[error(transparent)]
+    GovernanceProposalVotePollsOpenedLimitReachedError(
+        GovernanceProposalVotePollsOpenedLimitReachedError,
+    ),
 }
 
 impl From<StateError> for ConsensusError {
@@ -0,0 +1,45 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use crate::prelude::Identifier;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity {identity_id} already committed a bid to vote poll {vote_poll_id}")]
+#[platform_serialize(unversioned)]
+pub struct AuctionBidAlreadyCommittedError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    vote_poll_id: Identifier,
+    identity_id: Identifier,
+}
+
+impl AuctionBidAlreadyCommittedError {
+    pub fn new(vote_poll_id: Identifier, identity_id: Identifier) -> Self {
+        Self {
+            vote_poll_id,
+            identity_id,
+        }
+    }
+
+    pub fn vote_poll_id(&self) -> Identifier {
+        self.vote_poll_id
+    }
+
+    pub fn identity_id(&self) -> Identifier {
+        self.identity_id
+    }
+}
+
+impl From<AuctionBidAlreadyCommittedError> for ConsensusError {
+    fn from(err: AuctionBidAlreadyCommittedError) -> Self {
+        Self::StateError(StateError::AuctionBidAlreadyCommittedError(err))
+    }
+}
@@ -0,0 +1,62 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use crate::prelude::{Identifier, TimestampMillis};
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Auction bid to vote poll {vote_poll_id} at {current_time} is outside of the allowed window [{window_start}, {window_end})")]
+#[platform_serialize(unversioned)]
+pub struct AuctionBidOutsideOfWindowError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    vote_poll_id: Identifier,
+    current_time: TimestampMillis,
+    window_start: TimestampMillis,
+    window_end: TimestampMillis,
+}
+
+impl AuctionBidOutsideOfWindowError {
+    pub fn new(
+        vote_poll_id: Identifier,
+        current_time: TimestampMillis,
+        window_start: TimestampMillis,
+        window_end: TimestampMillis,
+    ) -> Self {
+        Self {
+            vote_poll_id,
+            current_time,
+            window_start,
+            window_end,
+        }
+    }
+
+    pub fn vote_poll_id(&self) -> Identifier {
+        self.vote_poll_id
+    }
+
+    pub fn current_time(&self) -> TimestampMillis {
+        self.current_time
+    }
+
+    pub fn window_start(&self) -> TimestampMillis {
+        self.window_start
+    }
+
+    pub fn window_end(&self) -> TimestampMillis {
+        self.window_end
+    }
+}
+
+impl From<AuctionBidOutsideOfWindowError> for ConsensusError {
+    fn from(err: AuctionBidOutsideOfWindowError) -> Self {
+        Self::StateError(StateError::AuctionBidOutsideOfWindowError(err))
+    }
+}
@@ -0,0 +1,47 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use crate::prelude::Identifier;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error(
+    "Identity {identity_id} is not a contender of vote poll {vote_poll_id} and can not bid on it"
+)]
+#[platform_serialize(unversioned)]
+pub struct AuctionBidderNotContenderError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    vote_poll_id: Identifier,
+    identity_id: Identifier,
+}
+
+impl AuctionBidderNotContenderError {
+    pub fn new(vote_poll_id: Identifier, identity_id: Identifier) -> Self {
+        Self {
+            vote_poll_id,
+            identity_id,
+        }
+    }
+
+    pub fn vote_poll_id(&self) -> Identifier {
+        self.vote_poll_id
+    }
+
+    pub fn identity_id(&self) -> Identifier {
+        self.identity_id
+    }
+}
+
+impl From<AuctionBidderNotContenderError> for ConsensusError {
+    fn from(err: AuctionBidderNotContenderError) -> Self {
+        Self::StateError(StateError::AuctionBidderNotContenderError(err))
+    }
+}
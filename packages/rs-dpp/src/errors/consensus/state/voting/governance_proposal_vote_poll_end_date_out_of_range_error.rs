@@ -0,0 +1,62 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use crate::prelude::{Identifier, TimestampMillis};
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Governance proposal vote poll {vote_poll_id} ends at {end_date}, but a new vote poll must end between {min_end_date} and {max_end_date}")]
+#[platform_serialize(unversioned)]
+pub struct GovernanceProposalVotePollEndDateOutOfRangeError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    vote_poll_id: Identifier,
+    end_date: TimestampMillis,
+    min_end_date: TimestampMillis,
+    max_end_date: TimestampMillis,
+}
+
+impl GovernanceProposalVotePollEndDateOutOfRangeError {
+    pub fn new(
+        vote_poll_id: Identifier,
+        end_date: TimestampMillis,
+        min_end_date: TimestampMillis,
+        max_end_date: TimestampMillis,
+    ) -> Self {
+        Self {
+            vote_poll_id,
+            end_date,
+            min_end_date,
+            max_end_date,
+        }
+    }
+
+    pub fn vote_poll_id(&self) -> Identifier {
+        self.vote_poll_id
+    }
+
+    pub fn end_date(&self) -> TimestampMillis {
+        self.end_date
+    }
+
+    pub fn min_end_date(&self) -> TimestampMillis {
+        self.min_end_date
+    }
+
+    pub fn max_end_date(&self) -> TimestampMillis {
+        self.max_end_date
+    }
+}
+
+impl From<GovernanceProposalVotePollEndDateOutOfRangeError> for ConsensusError {
+    fn from(err: GovernanceProposalVotePollEndDateOutOfRangeError) -> Self {
+        Self::StateError(StateError::GovernanceProposalVotePollEndDateOutOfRangeError(err))
+    }
+}
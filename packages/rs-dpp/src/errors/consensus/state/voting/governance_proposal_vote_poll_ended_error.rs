@@ -0,0 +1,45 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use crate::prelude::{Identifier, TimestampMillis};
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Governance proposal vote poll {vote_poll_id} ended at {end_date} and can no longer be voted on")]
+#[platform_serialize(unversioned)]
+pub struct GovernanceProposalVotePollEndedError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    vote_poll_id: Identifier,
+    end_date: TimestampMillis,
+}
+
+impl GovernanceProposalVotePollEndedError {
+    pub fn new(vote_poll_id: Identifier, end_date: TimestampMillis) -> Self {
+        Self {
+            vote_poll_id,
+            end_date,
+        }
+    }
+
+    pub fn vote_poll_id(&self) -> Identifier {
+        self.vote_poll_id
+    }
+
+    pub fn end_date(&self) -> TimestampMillis {
+        self.end_date
+    }
+}
+
+impl From<GovernanceProposalVotePollEndedError> for ConsensusError {
+    fn from(err: GovernanceProposalVotePollEndedError) -> Self {
+        Self::StateError(StateError::GovernanceProposalVotePollEndedError(err))
+    }
+}
@@ -0,0 +1,56 @@
+use crate::block::epoch::EpochIndex;
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use crate::prelude::Identifier;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Evonode {pro_tx_hash} already opened {max_vote_polls_opened} governance proposal vote polls in epoch {epoch_index}")]
+#[platform_serialize(unversioned)]
+pub struct GovernanceProposalVotePollsOpenedLimitReachedError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    pro_tx_hash: Identifier,
+    epoch_index: EpochIndex,
+    max_vote_polls_opened: u16,
+}
+
+impl GovernanceProposalVotePollsOpenedLimitReachedError {
+    pub fn new(
+        pro_tx_hash: Identifier,
+        epoch_index: EpochIndex,
+        max_vote_polls_opened: u16,
+    ) -> Self {
+        Self {
+            pro_tx_hash,
+            epoch_index,
+            max_vote_polls_opened,
+        }
+    }
+
+    pub fn pro_tx_hash(&self) -> Identifier {
+        self.pro_tx_hash
+    }
+
+    pub fn epoch_index(&self) -> EpochIndex {
+        self.epoch_index
+    }
+
+    pub fn max_vote_polls_opened(&self) -> u16 {
+        self.max_vote_polls_opened
+    }
+}
+
+impl From<GovernanceProposalVotePollsOpenedLimitReachedError> for ConsensusError {
+    fn from(err: GovernanceProposalVotePollsOpenedLimitReachedError) -> Self {
+        Self::StateError(StateError::GovernanceProposalVotePollsOpenedLimitReachedError(err))
+    }
+}
@@ -0,0 +1,53 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use crate::prelude::Identifier;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error(
+    "Invalid reveal of the bid of identity {identity_id} to vote poll {vote_poll_id}: {message}"
+)]
+#[platform_serialize(unversioned)]
+pub struct InvalidAuctionBidRevealError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    vote_poll_id: Identifier,
+    identity_id: Identifier,
+    message: String,
+}
+
+impl InvalidAuctionBidRevealError {
+    pub fn new(vote_poll_id: Identifier, identity_id: Identifier, message: String) -> Self {
+        Self {
+            vote_poll_id,
+            identity_id,
+            message,
+        }
+    }
+
+    pub fn vote_poll_id(&self) -> Identifier {
+        self.vote_poll_id
+    }
+
+    pub fn identity_id(&self) -> Identifier {
+        self.identity_id
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl From<InvalidAuctionBidRevealError> for ConsensusError {
+    fn from(err: InvalidAuctionBidRevealError) -> Self {
+        Self::StateError(StateError::InvalidAuctionBidRevealError(err))
+    }
+}
@@ -0,0 +1,39 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error(
+    "Masternode {pro_tx_hash} is not an evonode, only evonodes can vote on governance proposals"
+)]
+#[platform_serialize(unversioned)]
+pub struct MasternodeNotEvonodeError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    pro_tx_hash: Identifier,
+}
+
+impl MasternodeNotEvonodeError {
+    pub fn new(pro_tx_hash: Identifier) -> Self {
+        Self { pro_tx_hash }
+    }
+
+    pub fn pro_tx_hash(&self) -> Identifier {
+        self.pro_tx_hash
+    }
+}
+
+impl From<MasternodeNotEvonodeError> for ConsensusError {
+    fn from(err: MasternodeNotEvonodeError) -> Self {
+        Self::StateError(StateError::MasternodeNotEvonodeError(err))
+    }
+}
@@ -1,7 +1,17 @@
+pub mod auction_bid_already_committed_error;
+pub mod auction_bid_outside_of_window_error;
+pub mod auction_bidder_not_contender_error;
+pub mod governance_proposal_vote_poll_end_date_out_of_range_error;
+pub mod governance_proposal_vote_poll_ended_error;
+pub mod governance_proposal_vote_polls_opened_limit_reached_error;
+pub mod invalid_auction_bid_reveal_error;
 pub mod masternode_incorrect_voter_identity_id_error;
 pub mod masternode_incorrect_voting_address_error;
+pub mod masternode_not_evonode_error;
 pub mod masternode_not_found_error;
 pub mod masternode_vote_already_present_error;
 pub mod masternode_voted_too_many_times;
 pub mod vote_poll_not_available_for_voting_error;
 pub mod vote_poll_not_found_error;
+pub mod vote_poll_not_resolved_by_auction_error;
+pub mod vote_poll_resolved_by_auction_error;
@@ -6,5 +6,8 @@ pub mod property_names {
 
 pub const IDENTITY_MAX_KEYS: u16 = 15000;
 
+/// The most keys that can be required to co-sign with a threshold key
+pub const MAX_SIGNATURE_THRESHOLD: u8 = 16;
+
 pub const IDENTIFIER_FIELDS_JSON: [&str; 1] = [property_names::ID_JSON];
 pub const IDENTIFIER_FIELDS_RAW_OBJECT: [&str; 1] = [property_names::ID_RAW_OBJECT];
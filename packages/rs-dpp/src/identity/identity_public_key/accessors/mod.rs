@@ -2,6 +2,10 @@ use crate::identity::contract_bounds::ContractBounds;
 use crate::identity::identity_public_key::accessors::v0::{
     IdentityPublicKeyGettersV0, IdentityPublicKeySettersV0,
 };
+use crate::identity::identity_public_key::accessors::v1::{
+    IdentityPublicKeyGettersV1, IdentityPublicKeySettersV1,
+};
+use crate::identity::identity_public_key::v1::IdentityPublicKeyV1;
 use crate::identity::KeyType;
 use crate::identity::Purpose;
 use crate::identity::SecurityLevel;
@@ -9,65 +13,76 @@ use crate::identity::{IdentityPublicKey, KeyID, TimestampMillis};
 use platform_value::BinaryData;
 
 pub mod v0;
+pub mod v1;
 
 impl IdentityPublicKeyGettersV0 for IdentityPublicKey {
     fn id(&self) -> KeyID {
         match self {
             IdentityPublicKey::V0(v0) => v0.id(),
+            IdentityPublicKey::V1(v1) => v1.id(),
         }
     }
 
     fn purpose(&self) -> Purpose {
         match self {
             IdentityPublicKey::V0(v0) => v0.purpose(),
+            IdentityPublicKey::V1(v1) => v1.purpose(),
         }
     }
 
     fn security_level(&self) -> SecurityLevel {
         match self {
             IdentityPublicKey::V0(v0) => v0.security_level(),
+            IdentityPublicKey::V1(v1) => v1.security_level(),
         }
     }
 
     fn key_type(&self) -> KeyType {
         match self {
             IdentityPublicKey::V0(v0) => v0.key_type(),
+            IdentityPublicKey::V1(v1) => v1.key_type(),
         }
     }
 
     fn read_only(&self) -> bool {
         match self {
             IdentityPublicKey::V0(v0) => v0.read_only(),
+            IdentityPublicKey::V1(v1) => v1.read_only(),
         }
     }
 
     fn data(&self) -> &BinaryData {
         match self {
             IdentityPublicKey::V0(v0) => v0.data(),
+            IdentityPublicKey::V1(v1) => v1.data(),
         }
     }
 
     fn data_owned(self) -> BinaryData {
         match self {
             IdentityPublicKey::V0(v0) => v0.data_owned(),
+            IdentityPublicKey::V1(v1) => v1.data_owned(),
         }
     }
 
     fn disabled_at(&self) -> Option<TimestampMillis> {
         match self {
             IdentityPublicKey::V0(v0) => v0.disabled_at(),
+            IdentityPublicKey::V1(v1) => v1.disabled_at(),
         }
     }
 
     fn is_disabled(&self) -> bool {
         match self {
             IdentityPublicKey::V0(v0) => v0.is_disabled(),
+            IdentityPublicKey::V1(v1) => v1.is_disabled(),
         }
     }
 
     fn contract_bounds(&self) -> Option<&ContractBounds> {
         match self {
             IdentityPublicKey::V0(v0) => v0.contract_bounds(),
+            IdentityPublicKey::V1(v1) => v1.contract_bounds(),
         }
     }
 }
@@ -78,6 +93,9 @@ impl IdentityPublicKeySettersV0 for IdentityPublicKey {
             IdentityPublicKey::V0(v0) => {
                 v0.set_id(id);
             }
+            IdentityPublicKey::V1(v1) => {
+                v1.set_id(id);
+            }
         }
     }
 
@@ -86,6 +104,9 @@ impl IdentityPublicKeySettersV0 for IdentityPublicKey {
             IdentityPublicKey::V0(v0) => {
                 v0.set_purpose(purpose);
             }
+            IdentityPublicKey::V1(v1) => {
+                v1.set_purpose(purpose);
+            }
         }
     }
 
@@ -94,6 +115,9 @@ impl IdentityPublicKeySettersV0 for IdentityPublicKey {
             IdentityPublicKey::V0(v0) => {
                 v0.set_security_level(security_level);
             }
+            IdentityPublicKey::V1(v1) => {
+                v1.set_security_level(security_level);
+            }
         }
     }
 
@@ -102,6 +126,9 @@ impl IdentityPublicKeySettersV0 for IdentityPublicKey {
             IdentityPublicKey::V0(v0) => {
                 v0.set_key_type(key_type);
             }
+            IdentityPublicKey::V1(v1) => {
+                v1.set_key_type(key_type);
+            }
         }
     }
 
@@ -110,6 +137,9 @@ impl IdentityPublicKeySettersV0 for IdentityPublicKey {
             IdentityPublicKey::V0(v0) => {
                 v0.set_read_only(read_only);
             }
+            IdentityPublicKey::V1(v1) => {
+                v1.set_read_only(read_only);
+            }
         }
     }
 
@@ -118,6 +148,9 @@ impl IdentityPublicKeySettersV0 for IdentityPublicKey {
             IdentityPublicKey::V0(v0) => {
                 v0.set_data(data);
             }
+            IdentityPublicKey::V1(v1) => {
+                v1.set_data(data);
+            }
         }
     }
 
@@ -126,6 +159,9 @@ impl IdentityPublicKeySettersV0 for IdentityPublicKey {
             IdentityPublicKey::V0(v0) => {
                 v0.set_disabled_at(timestamp_millis);
             }
+            IdentityPublicKey::V1(v1) => {
+                v1.set_disabled_at(timestamp_millis);
+            }
         }
     }
 
@@ -137,3 +173,67 @@ impl IdentityPublicKeySettersV0 for IdentityPublicKey {
         }
     }
 }
+
+impl IdentityPublicKeyGettersV1 for IdentityPublicKey {
+    fn valid_from(&self) -> Option<TimestampMillis> {
+        match self {
+            IdentityPublicKey::V0(_) => None,
+            IdentityPublicKey::V1(v1) => v1.valid_from(),
+        }
+    }
+
+    fn valid_until(&self) -> Option<TimestampMillis> {
+        match self {
+            IdentityPublicKey::V0(_) => None,
+            IdentityPublicKey::V1(v1) => v1.valid_until(),
+        }
+    }
+
+    fn signature_threshold(&self) -> Option<u8> {
+        match self {
+            IdentityPublicKey::V0(_) => None,
+            IdentityPublicKey::V1(v1) => v1.signature_threshold(),
+        }
+    }
+}
+
+impl IdentityPublicKeySettersV1 for IdentityPublicKey {
+    fn set_valid_from(&mut self, valid_from: Option<TimestampMillis>) {
+        match self {
+            IdentityPublicKey::V0(v0) => {
+                if valid_from.is_some() {
+                    let mut v1: IdentityPublicKeyV1 = v0.clone().into();
+                    v1.set_valid_from(valid_from);
+                    *self = v1.into();
+                }
+            }
+            IdentityPublicKey::V1(v1) => v1.set_valid_from(valid_from),
+        }
+    }
+
+    fn set_valid_until(&mut self, valid_until: Option<TimestampMillis>) {
+        match self {
+            IdentityPublicKey::V0(v0) => {
+                if valid_until.is_some() {
+                    let mut v1: IdentityPublicKeyV1 = v0.clone().into();
+                    v1.set_valid_until(valid_until);
+                    *self = v1.into();
+                }
+            }
+            IdentityPublicKey::V1(v1) => v1.set_valid_until(valid_until),
+        }
+    }
+
+    fn set_signature_threshold(&mut self, signature_threshold: Option<u8>) {
+        match self {
+            IdentityPublicKey::V0(v0) => {
+                if signature_threshold.is_some() {
+                    let mut v1: IdentityPublicKeyV1 = v0.clone().into();
+                    v1.set_signature_threshold(signature_threshold);
+                    *self = v1.into();
+                }
+            }
+            IdentityPublicKey::V1(v1) => v1.set_signature_threshold(signature_threshold),
+        }
+    }
+}
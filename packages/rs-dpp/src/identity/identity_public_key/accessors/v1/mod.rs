@@ -0,0 +1,32 @@
+use crate::identity::TimestampMillis;
+
+/// Trait for getters of the usage policy introduced in IdentityPublicKeyV1
+pub trait IdentityPublicKeyGettersV1 {
+    /// Returns the time from which the key can be used, if bounded
+    fn valid_from(&self) -> Option<TimestampMillis>;
+
+    /// Returns the time from which the key can no longer be used, if bounded
+    fn valid_until(&self) -> Option<TimestampMillis>;
+
+    /// Is the public key within its validity window at the given time
+    fn is_within_validity_window(&self, time_ms: TimestampMillis) -> bool {
+        self.valid_from().map_or(true, |from| time_ms >= from)
+            && self.valid_until().map_or(true, |until| time_ms < until)
+    }
+
+    /// Returns how many distinct keys must sign state transitions signed with this key, if more
+    /// than this key alone
+    fn signature_threshold(&self) -> Option<u8>;
+}
+
+/// Trait for setters of the usage policy introduced in IdentityPublicKeyV1
+pub trait IdentityPublicKeySettersV1 {
+    /// Sets the time from which the key can be used
+    fn set_valid_from(&mut self, valid_from: Option<TimestampMillis>);
+
+    /// Sets the time from which the key can no longer be used
+    fn set_valid_until(&mut self, valid_until: Option<TimestampMillis>);
+
+    /// Sets how many distinct keys must sign state transitions signed with this key
+    fn set_signature_threshold(&mut self, signature_threshold: Option<u8>);
+}
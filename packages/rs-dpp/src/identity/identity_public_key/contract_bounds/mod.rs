@@ -1,6 +1,6 @@
 use crate::identifier::Identifier;
 use crate::identity::identity_public_key::contract_bounds::ContractBounds::{
-    SingleContract, SingleContractDocumentType,
+    MultipleContractsOfSameOwner, SingleContract, SingleContractDocumentType,
 };
 use crate::ProtocolError;
 use bincode::{Decode, Encode};
@@ -29,9 +29,9 @@ pub enum ContractBounds {
         id: Identifier,
         document_type_name: String,
     } = 1,
-    // /// this key can only be used within contracts owned by a specified owner
-    // #[serde(rename = "multipleContractsOfSameOwner")]
-    // MultipleContractsOfSameOwner { owner_id: Identifier } = 2,
+    /// this key can only be used within contracts owned by a specified owner
+    #[serde(rename = "multipleContractsOfSameOwner")]
+    MultipleContractsOfSameOwner { owner_id: Identifier } = 2,
 }
 
 impl ContractBounds {
@@ -49,6 +49,9 @@ impl ContractBounds {
                 id: Identifier::from_bytes(identifier.as_slice())?,
                 document_type_name: document_type,
             },
+            2 => MultipleContractsOfSameOwner {
+                owner_id: Identifier::from_bytes(identifier.as_slice())?,
+            },
             _ => {
                 return Err(ProtocolError::InvalidKeyContractBoundsError(format!(
                     "unrecognized contract bounds type: {}",
@@ -63,7 +66,7 @@ impl ContractBounds {
         match self {
             SingleContract { .. } => 0,
             SingleContractDocumentType { .. } => 1,
-            // MultipleContractsOfSameOwner { .. } => 2,
+            MultipleContractsOfSameOwner { .. } => 2,
         }
     }
 
@@ -71,8 +74,9 @@ impl ContractBounds {
         match str {
             "singleContract" => Ok(0),
             "documentType" => Ok(1),
+            "multipleContractsOfSameOwner" => Ok(2),
             _ => Err(ProtocolError::DecodingError(String::from(
-                "Expected type to be one of none, singleContract or singleContractDocumentType",
+                "Expected type to be one of none, singleContract, singleContractDocumentType or multipleContractsOfSameOwner",
             ))),
         }
     }
@@ -81,16 +85,17 @@ impl ContractBounds {
         match self {
             SingleContract { .. } => "singleContract",
             SingleContractDocumentType { .. } => "documentType",
-            // MultipleContractsOfSameOwner { .. } => "multipleContractsOfSameOwner",
+            MultipleContractsOfSameOwner { .. } => "multipleContractsOfSameOwner",
         }
     }
 
-    /// Gets the identifier
+    /// Gets the identifier, the contract id or for keys bound to all contracts of an owner,
+    /// the owner id
     pub fn identifier(&self) -> &Identifier {
         match self {
             SingleContract { id } => id,
             SingleContractDocumentType { id, .. } => id,
-            // MultipleContractsOfSameOwner { owner_id } => owner_id,
+            MultipleContractsOfSameOwner { owner_id } => owner_id,
         }
     }
 
@@ -102,7 +107,7 @@ impl ContractBounds {
                 document_type_name: document_type,
                 ..
             } => Some(document_type),
-            // MultipleContractsOfSameOwner { .. } => None,
+            MultipleContractsOfSameOwner { .. } => None,
         }
     }
     //
@@ -156,3 +161,28 @@ impl ContractBounds {
     //     )
     // }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_create_contracts_of_same_owner_bounds_from_type() {
+        let owner_id = Identifier::new([3; 32]);
+        let contract_bounds = ContractBounds::new_from_type(
+            ContractBounds::contract_bounds_type_from_str("multipleContractsOfSameOwner")
+                .expect("expected a known type"),
+            owner_id.to_vec(),
+            String::new(),
+        )
+        .expect("expected to create contract bounds");
+
+        assert_eq!(
+            contract_bounds,
+            ContractBounds::MultipleContractsOfSameOwner { owner_id }
+        );
+        assert_eq!(contract_bounds.contract_bounds_type(), 2);
+        assert_eq!(contract_bounds.identifier(), &owner_id);
+        assert_eq!(contract_bounds.document_type(), None);
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
 use crate::identity::identity_public_key::v0::IdentityPublicKeyV0;
+use crate::identity::identity_public_key::v1::IdentityPublicKeyV1;
 use bincode::{Decode, Encode};
 use derive_more::From;
 use serde::{Deserialize, Serialize};
@@ -16,6 +17,7 @@ pub mod accessors;
 pub(crate) mod conversion;
 pub mod fields;
 pub mod v0;
+pub mod v1;
 use crate::version::PlatformVersion;
 use crate::ProtocolError;
 pub use fields::*;
@@ -52,6 +54,8 @@ pub type TimestampMillis = u64;
 pub enum IdentityPublicKey {
     #[serde(rename = "0")]
     V0(IdentityPublicKeyV0),
+    #[serde(rename = "1")]
+    V1(IdentityPublicKeyV1),
 }
 
 impl IdentityPublicKey {
@@ -84,9 +88,10 @@ impl IdentityPublicKey {
             .identity_key_structure_version
         {
             0 => Ok(IdentityPublicKeyV0::max_possible_size_key(id).into()),
+            1 => Ok(IdentityPublicKeyV1::max_possible_size_key(id).into()),
             version => Err(ProtocolError::UnknownVersionMismatch {
                 method: "IdentityPublicKey::max_possible_size_key".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             }),
         }
@@ -99,9 +104,10 @@ impl IdentityPublicKey {
             .identity_key_structure_version
         {
             0 => Ok(IdentityPublicKeyV0::default().into()),
+            1 => Ok(IdentityPublicKeyV1::default().into()),
             version => Err(ProtocolError::UnknownVersionMismatch {
                 method: "IdentityPublicKey::default_versioned".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             }),
         }
@@ -110,6 +116,9 @@ impl IdentityPublicKey {
 
 #[cfg(test)]
 mod tests {
+    use crate::identity::identity_public_key::accessors::v1::{
+        IdentityPublicKeyGettersV1, IdentityPublicKeySettersV1,
+    };
     use crate::identity::identity_public_key::v0::IdentityPublicKeyV0;
     use crate::identity::IdentityPublicKey;
     use crate::serialization::{PlatformDeserializable, PlatformSerializable};
@@ -134,4 +143,33 @@ mod tests {
                 .expect("expected to deserialize key");
         assert_eq!(key, unserialized)
     }
+
+    #[test]
+    fn test_identity_key_with_validity_window() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(5);
+        let mut key: IdentityPublicKey =
+            IdentityPublicKeyV0::random_ecdsa_high_level_authentication_key_with_rng(
+                1,
+                &mut rng,
+                LATEST_PLATFORM_VERSION,
+            )
+            .expect("expected a random key")
+            .0
+            .into();
+        assert!(key.is_within_validity_window(0));
+
+        key.set_valid_from(Some(1_000));
+        key.set_valid_until(Some(2_000));
+        assert!(matches!(key, IdentityPublicKey::V1(_)));
+        assert!(!key.is_within_validity_window(999));
+        assert!(key.is_within_validity_window(1_000));
+        assert!(key.is_within_validity_window(1_999));
+        assert!(!key.is_within_validity_window(2_000));
+
+        let serialized = key.serialize_to_bytes().expect("expected to serialize key");
+        let unserialized: IdentityPublicKey =
+            PlatformDeserializable::deserialize_from_bytes(serialized.as_slice())
+                .expect("expected to deserialize key");
+        assert_eq!(key, unserialized)
+    }
 }
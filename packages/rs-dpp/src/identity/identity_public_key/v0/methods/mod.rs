@@ -6,43 +6,51 @@ use crate::ProtocolError;
 use anyhow::anyhow;
 use dashcore::hashes::Hash;
 use dashcore::PublicKey as ECDSAPublicKey;
-use platform_value::Bytes20;
+use platform_value::{BinaryData, Bytes20};
 
 impl IdentityPublicKeyHashMethodsV0 for IdentityPublicKeyV0 {
     /// Get the original public key hash
     fn public_key_hash(&self) -> Result<[u8; 20], ProtocolError> {
-        if self.data.is_empty() {
-            return Err(ProtocolError::EmptyPublicKeyDataError);
-        }
+        public_key_data_hash(self.key_type, &self.data)
+    }
+}
 
-        match self.key_type {
-            KeyType::ECDSA_SECP256K1 => {
-                let key = match self.data.len() {
-                    // TODO: We need to update schema and tests for 65 len keys
-                    65 | 33 => ECDSAPublicKey::from_slice(self.data.as_slice())
-                        .map_err(|e| anyhow!("unable to create pub key - {}", e))?,
-                    _ => {
-                        return Err(ProtocolError::ParsingError(format!(
-                            "the key length is invalid: {} Allowed sizes: 33 or 65 bytes for ecdsa key",
-                            self.data.len()
-                        )));
-                    }
-                };
-                Ok(key.pubkey_hash().to_byte_array())
-            }
-            KeyType::BLS12_381 => {
-                if self.data.len() != 48 {
-                    Err(ProtocolError::ParsingError(format!(
-                        "the key length is invalid: {} Allowed sizes: 48 bytes for bls key",
-                        self.data.len()
-                    )))
-                } else {
-                    Ok(ripemd160_sha256(self.data.as_slice()))
+/// Hashes the public key data the same way for every identity public key structure version
+pub(in crate::identity::identity_public_key) fn public_key_data_hash(
+    key_type: KeyType,
+    data: &BinaryData,
+) -> Result<[u8; 20], ProtocolError> {
+    if data.is_empty() {
+        return Err(ProtocolError::EmptyPublicKeyDataError);
+    }
+
+    match key_type {
+        KeyType::ECDSA_SECP256K1 => {
+            let key = match data.len() {
+                // TODO: We need to update schema and tests for 65 len keys
+                65 | 33 => ECDSAPublicKey::from_slice(data.as_slice())
+                    .map_err(|e| anyhow!("unable to create pub key - {}", e))?,
+                _ => {
+                    return Err(ProtocolError::ParsingError(format!(
+                        "the key length is invalid: {} Allowed sizes: 33 or 65 bytes for ecdsa key",
+                        data.len()
+                    )));
                 }
+            };
+            Ok(key.pubkey_hash().to_byte_array())
+        }
+        KeyType::BLS12_381 => {
+            if data.len() != 48 {
+                Err(ProtocolError::ParsingError(format!(
+                    "the key length is invalid: {} Allowed sizes: 48 bytes for bls key",
+                    data.len()
+                )))
+            } else {
+                Ok(ripemd160_sha256(data.as_slice()))
             }
-            KeyType::ECDSA_HASH160 | KeyType::BIP13_SCRIPT_HASH | KeyType::EDDSA_25519_HASH160 => {
-                Ok(Bytes20::from_vec(self.data.to_vec())?.into_buffer())
-            }
+        }
+        KeyType::ECDSA_HASH160 | KeyType::BIP13_SCRIPT_HASH | KeyType::EDDSA_25519_HASH160 => {
+            Ok(Bytes20::from_vec(data.to_vec())?.into_buffer())
         }
     }
 }
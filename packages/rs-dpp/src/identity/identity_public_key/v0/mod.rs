@@ -7,6 +7,7 @@ mod random;
 pub use crate::identity::KeyType;
 pub use crate::identity::Purpose;
 pub use crate::identity::SecurityLevel;
+pub(in crate::identity::identity_public_key) use methods::public_key_data_hash;
 
 use bincode::{Decode, Encode};
 
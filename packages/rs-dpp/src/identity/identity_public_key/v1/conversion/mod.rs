@@ -0,0 +1,2 @@
+#[cfg(feature = "platform-value")]
+mod platform_value;
@@ -0,0 +1,27 @@
+use crate::identity::identity_public_key::v1::IdentityPublicKeyV1;
+use platform_value::Value;
+use std::convert::TryFrom;
+
+impl TryFrom<&IdentityPublicKeyV1> for Value {
+    type Error = platform_value::Error;
+
+    fn try_from(value: &IdentityPublicKeyV1) -> Result<Self, Self::Error> {
+        platform_value::to_value(value)
+    }
+}
+
+impl TryFrom<IdentityPublicKeyV1> for Value {
+    type Error = platform_value::Error;
+
+    fn try_from(value: IdentityPublicKeyV1) -> Result<Self, Self::Error> {
+        platform_value::to_value(value)
+    }
+}
+
+impl TryFrom<Value> for IdentityPublicKeyV1 {
+    type Error = platform_value::Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        platform_value::from_value(value)
+    }
+}
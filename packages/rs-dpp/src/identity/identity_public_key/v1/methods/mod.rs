@@ -0,0 +1,11 @@
+use crate::identity::identity_public_key::methods::hash::IdentityPublicKeyHashMethodsV0;
+use crate::identity::identity_public_key::v0::public_key_data_hash;
+use crate::identity::identity_public_key::v1::IdentityPublicKeyV1;
+use crate::ProtocolError;
+
+impl IdentityPublicKeyHashMethodsV0 for IdentityPublicKeyV1 {
+    /// Get the original public key hash
+    fn public_key_hash(&self) -> Result<[u8; 20], ProtocolError> {
+        public_key_data_hash(self.key_type, &self.data)
+    }
+}
@@ -0,0 +1,113 @@
+mod accessors;
+mod conversion;
+mod methods;
+
+use bincode::{Decode, Encode};
+
+use platform_value::BinaryData;
+use serde::{Deserialize, Serialize};
+
+use crate::identity::identity_public_key::contract_bounds::ContractBounds;
+use crate::identity::identity_public_key::v0::IdentityPublicKeyV0;
+use crate::identity::{KeyID, KeyType, Purpose, SecurityLevel, TimestampMillis};
+#[cfg(feature = "state-transitions")]
+use crate::state_transition::public_key_in_creation::v1::IdentityPublicKeyInCreationV1;
+
+/// An identity public key with a usage policy.
+///
+/// Outside of `[valid_from, valid_until)` the key can't be used to sign state transitions,
+/// which allows issuing keys that expire on their own. A key with a signature threshold only
+/// signs together with other keys of the identity (M-of-N control).
+#[derive(
+    Default,
+    Debug,
+    Serialize,
+    Deserialize,
+    Encode,
+    Decode,
+    Clone,
+    PartialEq,
+    Eq,
+    Ord,
+    PartialOrd,
+    Hash,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityPublicKeyV1 {
+    pub id: KeyID,
+    pub purpose: Purpose,
+    pub security_level: SecurityLevel,
+    pub contract_bounds: Option<ContractBounds>,
+    #[serde(rename = "type")]
+    pub key_type: KeyType,
+    pub read_only: bool,
+    pub data: BinaryData,
+    #[serde(default)]
+    pub disabled_at: Option<TimestampMillis>,
+    /// The key can't be used before this block time
+    #[serde(default)]
+    pub valid_from: Option<TimestampMillis>,
+    /// The key can't be used at or after this block time
+    #[serde(default)]
+    pub valid_until: Option<TimestampMillis>,
+    /// How many distinct keys, this one included, must sign state transitions signed with this key
+    #[serde(default)]
+    pub signature_threshold: Option<u8>,
+}
+
+impl IdentityPublicKeyV1 {
+    pub fn max_possible_size_key(id: KeyID) -> Self {
+        let mut key: IdentityPublicKeyV1 = IdentityPublicKeyV0::max_possible_size_key(id).into();
+        key.valid_from = Some(TimestampMillis::MAX);
+        key.valid_until = Some(TimestampMillis::MAX);
+        key.signature_threshold = Some(u8::MAX);
+        key
+    }
+}
+
+impl From<IdentityPublicKeyV0> for IdentityPublicKeyV1 {
+    fn from(value: IdentityPublicKeyV0) -> Self {
+        let IdentityPublicKeyV0 {
+            id,
+            purpose,
+            security_level,
+            contract_bounds,
+            key_type,
+            read_only,
+            data,
+            disabled_at,
+        } = value;
+        IdentityPublicKeyV1 {
+            id,
+            purpose,
+            security_level,
+            contract_bounds,
+            key_type,
+            read_only,
+            data,
+            disabled_at,
+            valid_from: None,
+            valid_until: None,
+            signature_threshold: None,
+        }
+    }
+}
+
+#[cfg(feature = "state-transitions")]
+impl From<&IdentityPublicKeyV1> for IdentityPublicKeyInCreationV1 {
+    fn from(value: &IdentityPublicKeyV1) -> Self {
+        IdentityPublicKeyInCreationV1 {
+            id: value.id,
+            purpose: value.purpose,
+            security_level: value.security_level,
+            key_type: value.key_type,
+            read_only: value.read_only,
+            data: value.data.clone(),
+            signature: BinaryData::default(),
+            contract_bounds: value.contract_bounds.clone(),
+            valid_from: value.valid_from,
+            valid_until: value.valid_until,
+            signature_threshold: value.signature_threshold,
+        }
+    }
+}
@@ -28,6 +28,7 @@ pub mod identity_nonce;
 pub mod methods;
 #[cfg(feature = "random-identities")]
 pub mod random;
+pub mod recovery;
 pub mod v0;
 
 pub use fields::*;
@@ -0,0 +1,122 @@
+use crate::identity::{IdentityPublicKey, KeyID, TimestampMillis};
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::{BinaryData, Identifier};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// The maximum amount of guardians an identity can name in its recovery configuration
+pub const MAX_RECOVERY_GUARDIANS: usize = 16;
+
+/// The shortest allowed time lock between initiating and completing a recovery (1 day)
+pub const MIN_RECOVERY_DELAY: TimestampMillis = 24 * 60 * 60 * 1000;
+
+/// The longest allowed time lock between initiating and completing a recovery (30 days)
+pub const MAX_RECOVERY_DELAY: TimestampMillis = 30 * 24 * 60 * 60 * 1000;
+
+/// The opt-in social recovery configuration of an identity.
+///
+/// When at least `threshold` of the `guardians` sign a recovery, a new master key is scheduled
+/// and can be installed once `delay` has elapsed, unless the owner cancels it in the meantime.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    Encode,
+    Decode,
+    PlatformSerialize,
+    PlatformDeserialize,
+)]
+#[serde(rename_all = "camelCase")]
+#[platform_serialize(limit = 2000, unversioned)]
+pub struct IdentityRecoveryConfig {
+    pub guardians: BTreeSet<Identifier>,
+    pub threshold: u8,
+    pub delay: TimestampMillis,
+}
+
+impl IdentityRecoveryConfig {
+    /// Checks if the identity is one of the guardians
+    pub fn is_guardian(&self, identity_id: &Identifier) -> bool {
+        self.guardians.contains(identity_id)
+    }
+}
+
+/// A recovery that was approved by the guardians and is waiting for its time lock to elapse
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    Encode,
+    Decode,
+    PlatformSerialize,
+    PlatformDeserialize,
+)]
+#[serde(rename_all = "camelCase")]
+#[platform_serialize(limit = 4000, unversioned)]
+pub struct PendingIdentityRecovery {
+    pub new_master_key: IdentityPublicKey,
+    pub approved_by: BTreeSet<Identifier>,
+    pub initiated_at: TimestampMillis,
+    pub executable_at: TimestampMillis,
+}
+
+impl PendingIdentityRecovery {
+    /// Checks if the time lock has elapsed at the given block time
+    pub fn is_executable_at(&self, block_time_ms: TimestampMillis) -> bool {
+        block_time_ms >= self.executable_at
+    }
+}
+
+/// A guardian's signature over the signable bytes of a recovery transition
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct GuardianSignature {
+    pub public_key_id: KeyID,
+    pub signature: BinaryData,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identity::identity_public_key::v0::IdentityPublicKeyV0;
+    use crate::identity::{KeyType, Purpose, SecurityLevel};
+    use crate::serialization::{PlatformDeserializable, PlatformSerializable};
+
+    #[test]
+    fn should_serialize_and_deserialize_pending_recovery() {
+        let pending_recovery = PendingIdentityRecovery {
+            new_master_key: IdentityPublicKeyV0 {
+                id: 3,
+                purpose: Purpose::AUTHENTICATION,
+                security_level: SecurityLevel::MASTER,
+                contract_bounds: None,
+                key_type: KeyType::ECDSA_SECP256K1,
+                read_only: false,
+                data: BinaryData::new(vec![2; 33]),
+                disabled_at: None,
+            }
+            .into(),
+            approved_by: BTreeSet::from([Identifier::new([1; 32]), Identifier::new([2; 32])]),
+            initiated_at: 1000,
+            executable_at: 1000 + MIN_RECOVERY_DELAY,
+        };
+
+        let bytes = pending_recovery
+            .serialize_to_bytes()
+            .expect("expected to serialize");
+        let restored = PendingIdentityRecovery::deserialize_from_bytes(&bytes)
+            .expect("expected to deserialize");
+
+        assert_eq!(restored, pending_recovery);
+        assert!(!restored.is_executable_at(1000));
+        assert!(restored.is_executable_at(1000 + MIN_RECOVERY_DELAY));
+    }
+}
@@ -0,0 +1,69 @@
+use crate::fee::Credits;
+use crate::prelude::TimestampMillis;
+use crate::util::hash::hash_double;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+#[cfg(feature = "document-serde-conversion")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An open offer from a bidder to buy a document.
+///
+/// The offered credits are removed from the bidder's balance when the offer is made and held
+/// in escrow until the offer is either accepted by the document owner or withdrawn by the bidder.
+#[derive(Debug, Clone, Encode, Decode, PlatformSerialize, PlatformDeserialize, PartialEq)]
+#[cfg_attr(
+    feature = "document-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[platform_serialize(unversioned)]
+pub struct DocumentOffer {
+    /// The document the offer is made on
+    pub document_id: Identifier,
+    /// The identity making the offer
+    pub bidder_id: Identifier,
+    /// For private offers, the only identity that can accept the offer
+    pub recipient_id: Option<Identifier>,
+    /// The escrowed amount the bidder is willing to pay
+    pub price: Credits,
+    /// The time the offer was made
+    pub created_at: TimestampMillis,
+}
+
+impl fmt::Display for DocumentOffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DocumentOffer {{ document_id: {}, bidder_id: {}, recipient_id: {}, price: {}, created_at: {} }}",
+            self.document_id,
+            self.bidder_id,
+            self.recipient_id
+                .map(|recipient_id| recipient_id.to_string())
+                .unwrap_or_else(|| "none".to_string()),
+            self.price,
+            self.created_at
+        )
+    }
+}
+
+impl DocumentOffer {
+    /// The id of the prefunded specialized balance escrowing the credits of an offer.
+    ///
+    /// There can only be one open offer from a bidder on a document, so the escrow is
+    /// identified by the document and the bidder.
+    pub fn escrow_balance_id(
+        contract_id: Identifier,
+        document_type_name: &str,
+        document_id: Identifier,
+        bidder_id: Identifier,
+    ) -> Identifier {
+        let mut bytes = Vec::with_capacity(96 + document_type_name.len());
+        bytes.extend_from_slice(contract_id.as_slice());
+        bytes.extend_from_slice(document_type_name.as_bytes());
+        bytes.extend_from_slice(document_id.as_slice());
+        bytes.extend_from_slice(bidder_id.as_slice());
+        Identifier::new(hash_double(bytes))
+    }
+}
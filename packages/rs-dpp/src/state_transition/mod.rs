@@ -40,6 +40,7 @@ use crate::util::hash::{hash_double_to_vec, hash_single};
 pub mod proof_result;
 mod serialization;
 pub mod state_transitions;
+pub mod threshold_signature;
 mod traits;
 
 // pub mod state_transition_fee;
@@ -61,6 +62,8 @@ use crate::fee::Credits;
     feature = "state-transition-validation"
 ))]
 use crate::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+#[cfg(feature = "state-transition-validation")]
+use crate::identity::identity_public_key::accessors::v1::IdentityPublicKeyGettersV1;
 #[cfg(feature = "state-transition-signing")]
 use crate::identity::signer::Signer;
 use crate::identity::state_transition::OptionallyAssetLockProved;
@@ -73,12 +76,27 @@ use crate::identity::{IdentityPublicKey, KeyType};
 use crate::identity::{KeyID, SecurityLevel};
 use crate::prelude::{AssetLockProof, UserFeeIncrease};
 use crate::state_transition::masternode_vote_transition::MasternodeVoteTransitionSignable;
+#[cfg(any(
+    feature = "state-transition-signing",
+    feature = "state-transition-validation"
+))]
+use crate::state_transition::threshold_signature::ThresholdSignature;
 pub use state_transitions::*;
 
+#[cfg(feature = "state-transition-validation")]
+use crate::serialization::PlatformDeserializable;
+#[cfg(feature = "state-transition-signing")]
+use crate::serialization::PlatformSerializable;
 use crate::serialization::Signable;
+use crate::state_transition::auction_bid_transition::{
+    AuctionBidTransition, AuctionBidTransitionSignable,
+};
 use crate::state_transition::data_contract_create_transition::{
     DataContractCreateTransition, DataContractCreateTransitionSignable,
 };
+use crate::state_transition::data_contract_delete_transition::{
+    DataContractDeleteTransition, DataContractDeleteTransitionSignable,
+};
 use crate::state_transition::data_contract_update_transition::{
     DataContractUpdateTransition, DataContractUpdateTransitionSignable,
 };
@@ -96,12 +114,18 @@ use crate::state_transition::errors::{
 use crate::state_transition::identity_create_transition::{
     IdentityCreateTransition, IdentityCreateTransitionSignable,
 };
+use crate::state_transition::identity_credit_batch_transfer_transition::{
+    IdentityCreditBatchTransferTransition, IdentityCreditBatchTransferTransitionSignable,
+};
 use crate::state_transition::identity_credit_transfer_transition::{
     IdentityCreditTransferTransition, IdentityCreditTransferTransitionSignable,
 };
 use crate::state_transition::identity_credit_withdrawal_transition::{
     IdentityCreditWithdrawalTransition, IdentityCreditWithdrawalTransitionSignable,
 };
+use crate::state_transition::identity_recovery_transition::{
+    IdentityRecoveryTransition, IdentityRecoveryTransitionSignable,
+};
 use crate::state_transition::identity_topup_transition::{
     IdentityTopUpTransition, IdentityTopUpTransitionSignable,
 };
@@ -129,6 +153,10 @@ macro_rules! call_method {
             StateTransition::IdentityUpdate(st) => st.$method($args),
             StateTransition::IdentityCreditTransfer(st) => st.$method($args),
             StateTransition::MasternodeVote(st) => st.$method($args),
+            StateTransition::DataContractDelete(st) => st.$method($args),
+            StateTransition::IdentityRecovery(st) => st.$method($args),
+            StateTransition::IdentityCreditBatchTransfer(st) => st.$method($args),
+            StateTransition::AuctionBid(st) => st.$method($args),
         }
     };
     ($state_transition:expr, $method:ident ) => {
@@ -142,6 +170,10 @@ macro_rules! call_method {
             StateTransition::IdentityUpdate(st) => st.$method(),
             StateTransition::IdentityCreditTransfer(st) => st.$method(),
             StateTransition::MasternodeVote(st) => st.$method(),
+            StateTransition::DataContractDelete(st) => st.$method(),
+            StateTransition::IdentityRecovery(st) => st.$method(),
+            StateTransition::IdentityCreditBatchTransfer(st) => st.$method(),
+            StateTransition::AuctionBid(st) => st.$method(),
         }
     };
 }
@@ -158,6 +190,10 @@ macro_rules! call_getter_method_identity_signed {
             StateTransition::IdentityUpdate(st) => Some(st.$method($args)),
             StateTransition::IdentityCreditTransfer(st) => Some(st.$method($args)),
             StateTransition::MasternodeVote(st) => Some(st.$method($args)),
+            StateTransition::DataContractDelete(st) => Some(st.$method($args)),
+            StateTransition::IdentityRecovery(st) => Some(st.$method($args)),
+            StateTransition::IdentityCreditBatchTransfer(st) => Some(st.$method($args)),
+            StateTransition::AuctionBid(st) => Some(st.$method($args)),
         }
     };
     ($state_transition:expr, $method:ident ) => {
@@ -171,6 +207,10 @@ macro_rules! call_getter_method_identity_signed {
             StateTransition::IdentityUpdate(st) => Some(st.$method()),
             StateTransition::IdentityCreditTransfer(st) => Some(st.$method()),
             StateTransition::MasternodeVote(st) => Some(st.$method()),
+            StateTransition::DataContractDelete(st) => Some(st.$method()),
+            StateTransition::IdentityRecovery(st) => Some(st.$method()),
+            StateTransition::IdentityCreditBatchTransfer(st) => Some(st.$method()),
+            StateTransition::AuctionBid(st) => Some(st.$method()),
         }
     };
 }
@@ -187,6 +227,10 @@ macro_rules! call_method_identity_signed {
             StateTransition::IdentityUpdate(st) => st.$method($args),
             StateTransition::IdentityCreditTransfer(st) => st.$method($args),
             StateTransition::MasternodeVote(st) => st.$method($args),
+            StateTransition::DataContractDelete(st) => st.$method($args),
+            StateTransition::IdentityRecovery(st) => st.$method($args),
+            StateTransition::IdentityCreditBatchTransfer(st) => st.$method($args),
+            StateTransition::AuctionBid(st) => st.$method($args),
         }
     };
     ($state_transition:expr, $method:ident ) => {
@@ -200,6 +244,10 @@ macro_rules! call_method_identity_signed {
             StateTransition::IdentityUpdate(st) => st.$method(),
             StateTransition::IdentityCreditTransfer(st) => st.$method(),
             StateTransition::MasternodeVote(st) => st.$method(),
+            StateTransition::DataContractDelete(st) => st.$method(),
+            StateTransition::IdentityRecovery(st) => st.$method(),
+            StateTransition::IdentityCreditBatchTransfer(st) => st.$method(),
+            StateTransition::AuctionBid(st) => st.$method(),
         }
     };
 }
@@ -221,6 +269,10 @@ macro_rules! call_errorable_method_identity_signed {
             StateTransition::IdentityUpdate(st) => st.$method($args),
             StateTransition::IdentityCreditTransfer(st) => st.$method($args),
             StateTransition::MasternodeVote(st) => st.$method($args),
+            StateTransition::DataContractDelete(st) => st.$method($args),
+            StateTransition::IdentityRecovery(st) => st.$method($args),
+            StateTransition::IdentityCreditBatchTransfer(st) => st.$method($args),
+            StateTransition::AuctionBid(st) => st.$method($args),
         }
     };
     ($state_transition:expr, $method:ident ) => {
@@ -238,6 +290,10 @@ macro_rules! call_errorable_method_identity_signed {
             StateTransition::IdentityUpdate(st) => st.$method(),
             StateTransition::IdentityCreditTransfer(st) => st.$method(),
             StateTransition::MasternodeVote(st) => st.$method(),
+            StateTransition::DataContractDelete(st) => st.$method(),
+            StateTransition::IdentityRecovery(st) => st.$method(),
+            StateTransition::IdentityCreditBatchTransfer(st) => st.$method(),
+            StateTransition::AuctionBid(st) => st.$method(),
         }
     };
 }
@@ -291,6 +347,10 @@ pub enum StateTransition {
     IdentityUpdate(IdentityUpdateTransition),
     IdentityCreditTransfer(IdentityCreditTransferTransition),
     MasternodeVote(MasternodeVoteTransition),
+    DataContractDelete(DataContractDeleteTransition),
+    IdentityRecovery(IdentityRecoveryTransition),
+    IdentityCreditBatchTransfer(IdentityCreditBatchTransferTransition),
+    AuctionBid(AuctionBidTransition),
 }
 
 impl OptionallyAssetLockProved for StateTransition {
@@ -361,6 +421,9 @@ impl StateTransition {
                                 DocumentTransition::Transfer(_) => "Transfer",
                                 DocumentTransition::UpdatePrice(_) => "UpdatePrice",
                                 DocumentTransition::Purchase(_) => "Purchase",
+                                DocumentTransition::MakeOffer(_) => "MakeOffer",
+                                DocumentTransition::WithdrawOffer(_) => "WithdrawOffer",
+                                DocumentTransition::AcceptOffer(_) => "AcceptOffer",
                             };
                             document_transition_types.push(document_transition_name);
                         }
@@ -374,6 +437,10 @@ impl StateTransition {
             Self::IdentityUpdate(_) => "IdentityUpdate".to_string(),
             Self::IdentityCreditTransfer(_) => "IdentityCreditTransfer".to_string(),
             Self::MasternodeVote(_) => "MasternodeVote".to_string(),
+            Self::DataContractDelete(_) => "DataContractDelete".to_string(),
+            Self::IdentityRecovery(_) => "IdentityRecovery".to_string(),
+            Self::IdentityCreditBatchTransfer(_) => "IdentityCreditBatchTransfer".to_string(),
+            Self::AuctionBid(_) => "AuctionBid".to_string(),
         }
     }
 
@@ -506,6 +573,22 @@ impl StateTransition {
                 st.verify_public_key_level_and_purpose(identity_public_key)?;
                 st.verify_public_key_is_enabled(identity_public_key)?;
             }
+            StateTransition::DataContractDelete(st) => {
+                st.verify_public_key_level_and_purpose(identity_public_key)?;
+                st.verify_public_key_is_enabled(identity_public_key)?;
+            }
+            StateTransition::IdentityRecovery(st) => {
+                st.verify_public_key_level_and_purpose(identity_public_key)?;
+                st.verify_public_key_is_enabled(identity_public_key)?;
+            }
+            StateTransition::IdentityCreditBatchTransfer(st) => {
+                st.verify_public_key_level_and_purpose(identity_public_key)?;
+                st.verify_public_key_is_enabled(identity_public_key)?;
+            }
+            StateTransition::AuctionBid(st) => {
+                st.verify_public_key_level_and_purpose(identity_public_key)?;
+                st.verify_public_key_is_enabled(identity_public_key)?;
+            }
         }
         let data = self.signable_bytes()?;
         self.set_signature(signer.sign(identity_public_key, data.as_slice())?);
@@ -621,12 +704,17 @@ impl StateTransition {
         public_key_type: KeyType,
         bls: &T,
     ) -> Result<(), ProtocolError> {
+        let signature = self.signature().as_slice();
         match public_key_type {
-            KeyType::ECDSA_SECP256K1 => self.verify_ecdsa_signature_by_public_key(public_key),
+            KeyType::ECDSA_SECP256K1 => {
+                self.verify_ecdsa_signature_by_public_key(public_key, signature)
+            }
             KeyType::ECDSA_HASH160 => {
-                self.verify_ecdsa_hash_160_signature_by_public_key_hash(public_key)
+                self.verify_ecdsa_hash_160_signature_by_public_key_hash(public_key, signature)
+            }
+            KeyType::BLS12_381 => {
+                self.verify_bls_signature_by_public_key(public_key, signature, bls)
             }
-            KeyType::BLS12_381 => self.verify_bls_signature_by_public_key(public_key, bls),
             KeyType::BIP13_SCRIPT_HASH | KeyType::EDDSA_25519_HASH160 => {
                 Err(ProtocolError::InvalidIdentityPublicKeyTypeError(
                     InvalidIdentityPublicKeyTypeError::new(public_key_type),
@@ -661,53 +749,118 @@ impl StateTransition {
             ));
         }
 
+        // A threshold key signs alongside its co-signers, only its own signature is checked here
+        if public_key.signature_threshold().is_some() {
+            let threshold_signature = self.threshold_signature()?;
+            let signature = threshold_signature
+                .signature_for(public_key.id())
+                .ok_or_else(|| {
+                    ProtocolError::from(ConsensusError::SignatureError(
+                        SignatureError::InvalidStateTransitionSignatureError(
+                            InvalidStateTransitionSignatureError::new(format!(
+                                "threshold signature is missing the signature of key {}",
+                                public_key.id()
+                            )),
+                        ),
+                    ))
+                })?;
+            return self.verify_signature_bytes(public_key, signature.as_slice(), bls);
+        }
+
+        self.verify_signature_bytes(public_key, signature.as_slice(), bls)
+    }
+
+    #[cfg(feature = "state-transition-validation")]
+    /// Verifies a signature made by the public key over the signable bytes of the state
+    /// transition, whether or not the key is the one referenced by the state transition.
+    pub fn verify_signature_bytes(
+        &self,
+        public_key: &IdentityPublicKey,
+        signature: &[u8],
+        bls: &impl BlsModule,
+    ) -> Result<(), ProtocolError> {
         let public_key_bytes = public_key.data().as_slice();
         match public_key.key_type() {
             KeyType::ECDSA_HASH160 => {
-                self.verify_ecdsa_hash_160_signature_by_public_key_hash(public_key_bytes)
+                self.verify_ecdsa_hash_160_signature_by_public_key_hash(public_key_bytes, signature)
             }
 
-            KeyType::ECDSA_SECP256K1 => self.verify_ecdsa_signature_by_public_key(public_key_bytes),
+            KeyType::ECDSA_SECP256K1 => {
+                self.verify_ecdsa_signature_by_public_key(public_key_bytes, signature)
+            }
 
-            KeyType::BLS12_381 => self.verify_bls_signature_by_public_key(public_key_bytes, bls),
+            KeyType::BLS12_381 => {
+                self.verify_bls_signature_by_public_key(public_key_bytes, signature, bls)
+            }
 
             // per https://github.com/dashevo/platform/pull/353, signing and verification is not supported
             KeyType::BIP13_SCRIPT_HASH | KeyType::EDDSA_25519_HASH160 => Ok(()),
         }
     }
 
+    #[cfg(feature = "state-transition-validation")]
+    /// Decodes the signatures of a state transition signed by a threshold key
+    pub fn threshold_signature(&self) -> Result<ThresholdSignature, ProtocolError> {
+        ThresholdSignature::deserialize_from_bytes(self.signature().as_slice()).map_err(|e| {
+            ProtocolError::from(ConsensusError::SignatureError(
+                SignatureError::InvalidStateTransitionSignatureError(
+                    InvalidStateTransitionSignatureError::new(format!(
+                        "invalid threshold signature: {}",
+                        e
+                    )),
+                ),
+            ))
+        })
+    }
+
+    #[cfg(feature = "state-transition-signing")]
+    /// Sets the signatures collected for a threshold key as the signature of the state transition
+    pub fn set_threshold_signature(
+        &mut self,
+        public_key_id: KeyID,
+        threshold_signature: &ThresholdSignature,
+    ) -> Result<(), ProtocolError> {
+        self.set_signature(threshold_signature.serialize_to_bytes()?.into());
+        self.set_signature_public_key_id(public_key_id);
+        Ok(())
+    }
+
     #[cfg(feature = "state-transition-validation")]
     fn verify_ecdsa_hash_160_signature_by_public_key_hash(
         &self,
         public_key_hash: &[u8],
+        signature: &[u8],
     ) -> Result<(), ProtocolError> {
-        if self.signature().is_empty() {
+        if signature.is_empty() {
             return Err(ProtocolError::StateTransitionIsNotSignedError(
                 StateTransitionIsNotSignedError::new(self.clone()),
             ));
         }
         let data = self.signable_bytes()?;
         let data_hash = double_sha(data);
-        signer::verify_hash_signature(&data_hash, self.signature().as_slice(), public_key_hash)
-            .map_err(|e| {
-                ProtocolError::from(ConsensusError::SignatureError(
-                    SignatureError::InvalidStateTransitionSignatureError(
-                        InvalidStateTransitionSignatureError::new(e.to_string()),
-                    ),
-                ))
-            })
+        signer::verify_hash_signature(&data_hash, signature, public_key_hash).map_err(|e| {
+            ProtocolError::from(ConsensusError::SignatureError(
+                SignatureError::InvalidStateTransitionSignatureError(
+                    InvalidStateTransitionSignatureError::new(e.to_string()),
+                ),
+            ))
+        })
     }
 
     #[cfg(feature = "state-transition-validation")]
     /// Verifies an ECDSA signature with the public key
-    fn verify_ecdsa_signature_by_public_key(&self, public_key: &[u8]) -> Result<(), ProtocolError> {
-        if self.signature().is_empty() {
+    fn verify_ecdsa_signature_by_public_key(
+        &self,
+        public_key: &[u8],
+        signature: &[u8],
+    ) -> Result<(), ProtocolError> {
+        if signature.is_empty() {
             return Err(ProtocolError::StateTransitionIsNotSignedError(
                 StateTransitionIsNotSignedError::new(self.clone()),
             ));
         }
         let data = self.signable_bytes()?;
-        signer::verify_data_signature(&data, self.signature().as_slice(), public_key).map_err(|e| {
+        signer::verify_data_signature(&data, signature, public_key).map_err(|e| {
             // TODO: it shouldn't respond with consensus error
 
             ProtocolError::from(ConsensusError::SignatureError(
@@ -723,9 +876,10 @@ impl StateTransition {
     fn verify_bls_signature_by_public_key<T: BlsModule>(
         &self,
         public_key: &[u8],
+        signature: &[u8],
         bls: &T,
     ) -> Result<(), ProtocolError> {
-        if self.signature().is_empty() {
+        if signature.is_empty() {
             return Err(ProtocolError::StateTransitionIsNotSignedError(
                 StateTransitionIsNotSignedError::new(self.clone()),
             ));
@@ -733,7 +887,7 @@ impl StateTransition {
 
         let data = self.signable_bytes()?;
 
-        bls.verify_signature(self.signature().as_slice(), &data, public_key)
+        bls.verify_signature(signature, &data, public_key)
             .map(|_| ())
             .map_err(|e| {
                 // TODO: it shouldn't respond with consensus error
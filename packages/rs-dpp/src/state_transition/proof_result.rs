@@ -1,5 +1,6 @@
 use crate::data_contract::DataContract;
 use crate::document::Document;
+use crate::identity::recovery::{IdentityRecoveryConfig, PendingIdentityRecovery};
 use crate::identity::{Identity, PartialIdentity};
 use crate::voting::votes::Vote;
 use platform_value::Identifier;
@@ -13,4 +14,8 @@ pub enum StateTransitionProofResult {
     VerifiedBalanceTransfer(PartialIdentity, PartialIdentity), //from/to
     VerifiedDocuments(BTreeMap<Identifier, Option<Document>>),
     VerifiedMasternodeVote(Vote),
+    VerifiedDataContractDeleted(Identifier),
+    VerifiedIdentityRecoveryConfig(Identifier, Option<IdentityRecoveryConfig>),
+    VerifiedPendingIdentityRecovery(Identifier, Option<PendingIdentityRecovery>),
+    VerifiedBalanceBatchTransfer(PartialIdentity, Vec<PartialIdentity>), //from/to
 }
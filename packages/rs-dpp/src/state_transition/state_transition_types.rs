@@ -29,6 +29,10 @@ pub enum StateTransitionType {
     IdentityCreditWithdrawal = 6,
     IdentityCreditTransfer = 7,
     MasternodeVote = 8,
+    DataContractDelete = 9,
+    IdentityRecovery = 10,
+    IdentityCreditBatchTransfer = 11,
+    AuctionBid = 12,
 }
 
 impl std::fmt::Display for StateTransitionType {
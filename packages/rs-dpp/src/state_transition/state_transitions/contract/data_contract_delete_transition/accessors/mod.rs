@@ -0,0 +1,50 @@
+mod v0;
+
+use crate::prelude::IdentityNonce;
+use crate::state_transition::data_contract_delete_transition::{
+    DataContractDeleteMode, DataContractDeleteTransition,
+};
+use platform_value::Identifier;
+pub use v0::*;
+
+impl DataContractDeleteTransitionAccessorsV0 for DataContractDeleteTransition {
+    fn data_contract_id(&self) -> Identifier {
+        match self {
+            DataContractDeleteTransition::V0(transition) => transition.data_contract_id,
+        }
+    }
+
+    fn set_data_contract_id(&mut self, data_contract_id: Identifier) {
+        match self {
+            DataContractDeleteTransition::V0(transition) => {
+                transition.data_contract_id = data_contract_id
+            }
+        }
+    }
+
+    fn mode(&self) -> DataContractDeleteMode {
+        match self {
+            DataContractDeleteTransition::V0(transition) => transition.mode,
+        }
+    }
+
+    fn set_mode(&mut self, mode: DataContractDeleteMode) {
+        match self {
+            DataContractDeleteTransition::V0(transition) => transition.mode = mode,
+        }
+    }
+
+    fn identity_contract_nonce(&self) -> IdentityNonce {
+        match self {
+            DataContractDeleteTransition::V0(transition) => transition.identity_contract_nonce,
+        }
+    }
+
+    fn set_identity_contract_nonce(&mut self, identity_contract_nonce: IdentityNonce) {
+        match self {
+            DataContractDeleteTransition::V0(transition) => {
+                transition.identity_contract_nonce = identity_contract_nonce
+            }
+        }
+    }
+}
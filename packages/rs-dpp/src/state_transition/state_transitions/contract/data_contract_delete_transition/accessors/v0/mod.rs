@@ -0,0 +1,14 @@
+use crate::prelude::IdentityNonce;
+use crate::state_transition::data_contract_delete_transition::DataContractDeleteMode;
+use platform_value::Identifier;
+
+pub trait DataContractDeleteTransitionAccessorsV0 {
+    fn data_contract_id(&self) -> Identifier;
+    fn set_data_contract_id(&mut self, data_contract_id: Identifier);
+
+    fn mode(&self) -> DataContractDeleteMode;
+    fn set_mode(&mut self, mode: DataContractDeleteMode);
+
+    fn identity_contract_nonce(&self) -> IdentityNonce;
+    fn set_identity_contract_nonce(&mut self, identity_contract_nonce: IdentityNonce);
+}
@@ -0,0 +1,43 @@
+use crate::data_contract::DataContract;
+use crate::identity::signer::Signer;
+use crate::identity::{KeyID, PartialIdentity};
+
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+use crate::state_transition::data_contract_delete_transition::DataContractDeleteMode;
+use crate::state_transition::{StateTransition, StateTransitionType};
+use crate::version::FeatureVersion;
+use crate::ProtocolError;
+use platform_version::version::PlatformVersion;
+
+pub trait DataContractDeleteTransitionMethodsV0 {
+    /// Creates a new signed `DataContractDeleteTransition` archiving or deleting `data_contract`.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_contract` - The `DataContract` to archive or delete, it must be owned by `identity`.
+    /// * `mode` - Whether the contract is archived or deleted with all its documents.
+    /// * `identity` - A reference to the `PartialIdentity` containing the public keys.
+    /// * `key_id` - The `KeyID` (public key identifier) to be used for signing the transition.
+    /// * `signer` - A reference to the `Signer` object that will sign the transition.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<StateTransition, ProtocolError>` - If successful, returns the signed transition.
+    ///   In case of any error, a relevant `ProtocolError` is returned.
+    fn new_from_data_contract<S: Signer>(
+        data_contract: &DataContract,
+        mode: DataContractDeleteMode,
+        identity: &PartialIdentity,
+        key_id: KeyID,
+        identity_contract_nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError>;
+
+    /// Get State Transition Type
+    fn get_type() -> StateTransitionType {
+        StateTransitionType::DataContractDelete
+    }
+}
@@ -0,0 +1,128 @@
+pub mod accessors;
+pub mod fields;
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+pub mod methods;
+mod mode;
+mod state_transition_like;
+pub mod v0;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use crate::state_transition::data_contract_delete_transition::v0::DataContractDeleteTransitionV0Signable;
+use crate::state_transition::StateTransitionFieldTypes;
+
+use crate::identity::state_transition::OptionallyAssetLockProved;
+use bincode::{Decode, Encode};
+use derive_more::From;
+use fields::*;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize, PlatformSignable};
+use platform_versioning::PlatformVersioned;
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+pub use mode::DataContractDeleteMode;
+pub use v0::DataContractDeleteTransitionV0;
+
+pub type DataContractDeleteTransitionLatest = DataContractDeleteTransitionV0;
+
+/// Archives a data contract, or deletes it together with all its documents.
+///
+/// Only the contract owner can sign it, with a critical authentication key.
+#[derive(
+    Debug,
+    Clone,
+    Encode,
+    Decode,
+    PlatformDeserialize,
+    PlatformSerialize,
+    PlatformSignable,
+    PlatformVersioned,
+    From,
+    PartialEq,
+)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(tag = "$version")
+)]
+#[platform_serialize(unversioned)] //versioned directly, no need to use platform_version
+#[platform_version_path_bounds(
+    "dpp.state_transition_serialization_versions.contract_delete_state_transition"
+)]
+pub enum DataContractDeleteTransition {
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "0"))]
+    V0(DataContractDeleteTransitionV0),
+}
+
+impl OptionallyAssetLockProved for DataContractDeleteTransition {}
+
+impl StateTransitionFieldTypes for DataContractDeleteTransition {
+    fn signature_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE, SIGNATURE_PUBLIC_KEY_ID]
+    }
+
+    fn identifiers_property_paths() -> Vec<&'static str> {
+        vec![OWNER_ID, DATA_CONTRACT_ID]
+    }
+
+    fn binary_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::data_contract::accessors::v0::DataContractV0Getters;
+    use crate::state_transition::data_contract_delete_transition::accessors::DataContractDeleteTransitionAccessorsV0;
+    use crate::state_transition::{StateTransitionLike, StateTransitionType};
+    use crate::tests::fixtures::get_data_contract_fixture;
+    use platform_version::version::PlatformVersion;
+
+    fn get_test_transition(mode: DataContractDeleteMode) -> DataContractDeleteTransition {
+        let platform_version = PlatformVersion::latest();
+        let data_contract = get_data_contract_fixture(None, 0, platform_version.protocol_version)
+            .data_contract_owned();
+
+        DataContractDeleteTransitionV0 {
+            owner_id: data_contract.owner_id(),
+            data_contract_id: data_contract.id(),
+            mode,
+            identity_contract_nonce: 1,
+            user_fee_increase: 0,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into()
+    }
+
+    #[test]
+    fn should_return_transition_type() {
+        let transition = get_test_transition(DataContractDeleteMode::Archive);
+        assert_eq!(
+            StateTransitionType::DataContractDelete,
+            transition.state_transition_type()
+        );
+    }
+
+    #[test]
+    fn should_return_mode_and_modified_data_ids() {
+        let transition = get_test_transition(DataContractDeleteMode::Delete);
+        assert_eq!(transition.mode(), DataContractDeleteMode::Delete);
+        assert_eq!(
+            transition.modified_data_ids(),
+            vec![transition.data_contract_id()]
+        );
+    }
+
+    #[test]
+    fn is_data_contract_state_transition() {
+        let transition = get_test_transition(DataContractDeleteMode::Archive);
+        assert!(transition.is_data_contract_state_transition());
+        assert!(!transition.is_document_state_transition());
+        assert!(!transition.is_identity_state_transition());
+    }
+}
@@ -0,0 +1,26 @@
+use bincode::{Decode, Encode};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt;
+
+/// What happens to the data contract when the delete transition is applied
+#[repr(u8)]
+#[derive(
+    Serialize_repr, Deserialize_repr, PartialEq, Eq, Clone, Copy, Debug, Encode, Decode, Default,
+)]
+pub enum DataContractDeleteMode {
+    /// The contract is frozen: no document can be created or changed anymore, but the contract
+    /// and its documents can still be queried
+    #[default]
+    Archive = 0,
+    /// The contract and all its documents are removed from the state, storage fees are refunded
+    Delete = 1,
+}
+
+impl fmt::Display for DataContractDeleteMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataContractDeleteMode::Archive => write!(f, "archive"),
+            DataContractDeleteMode::Delete => write!(f, "delete"),
+        }
+    }
+}
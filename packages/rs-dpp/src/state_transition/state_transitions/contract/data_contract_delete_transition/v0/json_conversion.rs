@@ -0,0 +1,4 @@
+use crate::state_transition::data_contract_delete_transition::DataContractDeleteTransitionV0;
+use crate::state_transition::StateTransitionJsonConvert;
+
+impl<'a> StateTransitionJsonConvert<'a> for DataContractDeleteTransitionV0 {}
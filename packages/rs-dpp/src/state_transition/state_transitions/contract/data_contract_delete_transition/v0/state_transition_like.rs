@@ -0,0 +1,75 @@
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use platform_value::BinaryData;
+
+use crate::prelude::UserFeeIncrease;
+use crate::{
+    prelude::Identifier,
+    state_transition::{StateTransitionLike, StateTransitionType},
+};
+
+use crate::state_transition::data_contract_delete_transition::{
+    DataContractDeleteTransition, DataContractDeleteTransitionV0,
+};
+use crate::state_transition::StateTransition;
+use crate::state_transition::StateTransitionType::DataContractDelete;
+use crate::version::FeatureVersion;
+
+impl From<DataContractDeleteTransitionV0> for StateTransition {
+    fn from(value: DataContractDeleteTransitionV0) -> Self {
+        let data_contract_delete_transition: DataContractDeleteTransition = value.into();
+        data_contract_delete_transition.into()
+    }
+}
+
+impl StateTransitionLike for DataContractDeleteTransitionV0 {
+    /// Returns ID of the archived or deleted contract
+    fn modified_data_ids(&self) -> Vec<Identifier> {
+        vec![self.data_contract_id]
+    }
+
+    fn state_transition_protocol_version(&self) -> FeatureVersion {
+        0
+    }
+
+    /// returns the type of State Transition
+    fn state_transition_type(&self) -> StateTransitionType {
+        DataContractDelete
+    }
+
+    /// returns the signature as a byte-array
+    fn signature(&self) -> &BinaryData {
+        &self.signature
+    }
+
+    /// set a new signature
+    fn set_signature(&mut self, signature: BinaryData) {
+        self.signature = signature
+    }
+
+    fn set_signature_bytes(&mut self, signature: Vec<u8>) {
+        self.signature = BinaryData::new(signature)
+    }
+
+    /// Get owner ID
+    fn owner_id(&self) -> Identifier {
+        self.owner_id
+    }
+
+    fn unique_identifiers(&self) -> Vec<String> {
+        vec![format!(
+            "{}-{}-{:x}",
+            BASE64_STANDARD.encode(self.owner_id),
+            BASE64_STANDARD.encode(self.data_contract_id),
+            self.identity_contract_nonce
+        )]
+    }
+
+    fn user_fee_increase(&self) -> UserFeeIncrease {
+        self.user_fee_increase
+    }
+
+    fn set_user_fee_increase(&mut self, user_fee_increase: UserFeeIncrease) {
+        self.user_fee_increase = user_fee_increase
+    }
+}
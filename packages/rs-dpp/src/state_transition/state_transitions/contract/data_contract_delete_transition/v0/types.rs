@@ -0,0 +1,17 @@
+use crate::state_transition::data_contract_delete_transition::fields::*;
+use crate::state_transition::data_contract_delete_transition::DataContractDeleteTransitionV0;
+use crate::state_transition::StateTransitionFieldTypes;
+
+impl StateTransitionFieldTypes for DataContractDeleteTransitionV0 {
+    fn signature_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE, SIGNATURE_PUBLIC_KEY_ID]
+    }
+
+    fn identifiers_property_paths() -> Vec<&'static str> {
+        vec![OWNER_ID, DATA_CONTRACT_ID]
+    }
+
+    fn binary_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE]
+    }
+}
@@ -0,0 +1,53 @@
+use crate::data_contract::accessors::v0::DataContractV0Getters;
+use crate::data_contract::DataContract;
+use crate::identity::signer::Signer;
+use crate::identity::{KeyID, PartialIdentity};
+use crate::serialization::Signable;
+
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+use crate::state_transition::data_contract_delete_transition::methods::DataContractDeleteTransitionMethodsV0;
+use crate::state_transition::data_contract_delete_transition::{
+    DataContractDeleteMode, DataContractDeleteTransition, DataContractDeleteTransitionV0,
+};
+use crate::state_transition::StateTransition;
+use crate::version::FeatureVersion;
+use crate::{NonConsensusError, ProtocolError};
+use platform_version::version::PlatformVersion;
+
+impl DataContractDeleteTransitionMethodsV0 for DataContractDeleteTransitionV0 {
+    fn new_from_data_contract<S: Signer>(
+        data_contract: &DataContract,
+        mode: DataContractDeleteMode,
+        identity: &PartialIdentity,
+        key_id: KeyID,
+        identity_contract_nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        _platform_version: &PlatformVersion,
+        _feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let transition = DataContractDeleteTransition::V0(DataContractDeleteTransitionV0 {
+            owner_id: data_contract.owner_id(),
+            data_contract_id: data_contract.id(),
+            mode,
+            identity_contract_nonce,
+            user_fee_increase,
+            signature_public_key_id: key_id,
+            signature: Default::default(),
+        });
+
+        let mut state_transition: StateTransition = transition.into();
+        let value = state_transition.signable_bytes()?;
+        let public_key =
+            identity
+                .loaded_public_keys
+                .get(&key_id)
+                .ok_or(ProtocolError::NonConsensusError(
+                    NonConsensusError::StateTransitionCreationError(
+                        "public key did not exist".to_string(),
+                    ),
+                ))?;
+        state_transition.set_signature(signer.sign(public_key, &value)?);
+        Ok(state_transition)
+    }
+}
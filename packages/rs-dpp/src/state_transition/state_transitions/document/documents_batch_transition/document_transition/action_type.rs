@@ -11,6 +11,9 @@ pub enum DocumentTransitionActionType {
     Purchase,
     UpdatePrice,
     IgnoreWhileBumpingRevision,
+    MakeOffer,
+    WithdrawOffer,
+    AcceptOffer,
 }
 
 pub trait TransitionActionTypeGetter {
@@ -26,6 +29,9 @@ impl TransitionActionTypeGetter for DocumentTransition {
             DocumentTransition::Transfer(_) => DocumentTransitionActionType::Transfer,
             DocumentTransition::UpdatePrice(_) => DocumentTransitionActionType::UpdatePrice,
             DocumentTransition::Purchase(_) => DocumentTransitionActionType::Purchase,
+            DocumentTransition::MakeOffer(_) => DocumentTransitionActionType::MakeOffer,
+            DocumentTransition::WithdrawOffer(_) => DocumentTransitionActionType::WithdrawOffer,
+            DocumentTransition::AcceptOffer(_) => DocumentTransitionActionType::AcceptOffer,
         }
     }
 }
@@ -0,0 +1,42 @@
+use platform_version::version::{FeatureVersion, PlatformVersion};
+use crate::data_contract::document_type::DocumentTypeRef;
+use crate::document::{Document, DocumentV0Getters};
+use crate::document::errors::DocumentError;
+use crate::fee::Credits;
+use crate::prelude::{Identifier, IdentityNonce};
+use crate::ProtocolError;
+use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+use crate::state_transition::documents_batch_transition::document_transition::document_accept_offer_transition::DocumentAcceptOfferTransitionV0;
+
+impl DocumentAcceptOfferTransitionV0 {
+    pub(crate) fn from_document(
+        document: Document,
+        document_type: DocumentTypeRef,
+        bidder_id: Identifier,
+        price: Credits,
+        identity_contract_nonce: IdentityNonce,
+        platform_version: &PlatformVersion,
+        base_feature_version: Option<FeatureVersion>,
+    ) -> Result<Self, ProtocolError> {
+        let Some(revision) = document.revision() else {
+            return Err(ProtocolError::Document(Box::new(
+                DocumentError::DocumentNoRevisionError {
+                    document: Box::new(document.clone()),
+                },
+            )));
+        };
+
+        Ok(DocumentAcceptOfferTransitionV0 {
+            base: DocumentBaseTransition::from_document(
+                &document,
+                document_type,
+                identity_contract_nonce,
+                platform_version,
+                base_feature_version,
+            )?,
+            revision,
+            bidder_id,
+            price,
+        })
+    }
+}
@@ -0,0 +1,42 @@
+mod from_document;
+pub mod v0_methods;
+
+use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+
+use bincode::{Decode, Encode};
+use derive_more::Display;
+
+use crate::fee::Credits;
+use crate::prelude::{Identifier, Revision};
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+pub use super::super::document_base_transition::IDENTIFIER_FIELDS;
+
+/// Accepts the open offer of a bidder on a document, the document is transferred to the bidder
+/// and the escrowed credits are paid to the document owner.
+#[derive(Debug, Clone, Default, Encode, Decode, PartialEq, Display)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[display(fmt = "Base: {}", "base")]
+pub struct DocumentAcceptOfferTransitionV0 {
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(flatten))]
+    pub base: DocumentBaseTransition,
+    #[cfg_attr(
+        feature = "state-transition-serde-conversion",
+        serde(rename = "$revision")
+    )]
+    pub revision: Revision,
+    /// The identity that made the accepted offer
+    #[cfg_attr(
+        feature = "state-transition-serde-conversion",
+        serde(rename = "bidderId")
+    )]
+    pub bidder_id: Identifier,
+    /// The price of the accepted offer, it must match the escrowed amount
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "price"))]
+    pub price: Credits,
+}
@@ -0,0 +1,59 @@
+use crate::fee::Credits;
+use crate::prelude::{Identifier, Revision};
+use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+use crate::state_transition::documents_batch_transition::document_transition::document_accept_offer_transition::DocumentAcceptOfferTransitionV0;
+
+pub trait DocumentAcceptOfferTransitionV0Methods {
+    /// Returns a reference to the `base` field of the `DocumentAcceptOfferTransitionV0`.
+    fn base(&self) -> &DocumentBaseTransition;
+    fn base_mut(&mut self) -> &mut DocumentBaseTransition;
+
+    /// Sets the value of the `base` field in the `DocumentAcceptOfferTransitionV0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - A value of type `DocumentBaseTransition` to set.
+    fn set_base(&mut self, base: DocumentBaseTransition);
+
+    /// Returns the `revision` the document will have once the offer is accepted.
+    fn revision(&self) -> Revision;
+
+    /// Sets the value of the `revision` field in the `DocumentAcceptOfferTransitionV0`.
+    fn set_revision(&mut self, revision: Revision);
+
+    /// The identity that made the accepted offer.
+    fn bidder_id(&self) -> Identifier;
+
+    /// The price of the accepted offer.
+    fn price(&self) -> Credits;
+}
+
+impl DocumentAcceptOfferTransitionV0Methods for DocumentAcceptOfferTransitionV0 {
+    fn base(&self) -> &DocumentBaseTransition {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut DocumentBaseTransition {
+        &mut self.base
+    }
+
+    fn set_base(&mut self, base: DocumentBaseTransition) {
+        self.base = base
+    }
+
+    fn revision(&self) -> Revision {
+        self.revision
+    }
+
+    fn set_revision(&mut self, revision: Revision) {
+        self.revision = revision;
+    }
+
+    fn bidder_id(&self) -> Identifier {
+        self.bidder_id
+    }
+
+    fn price(&self) -> Credits {
+        self.price
+    }
+}
@@ -0,0 +1,49 @@
+use crate::fee::Credits;
+use crate::prelude::{Identifier, Revision};
+use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+use crate::state_transition::documents_batch_transition::document_transition::document_accept_offer_transition::v0::v0_methods::DocumentAcceptOfferTransitionV0Methods;
+use crate::state_transition::documents_batch_transition::document_transition::DocumentAcceptOfferTransition;
+
+impl DocumentAcceptOfferTransitionV0Methods for DocumentAcceptOfferTransition {
+    fn base(&self) -> &DocumentBaseTransition {
+        match self {
+            DocumentAcceptOfferTransition::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_mut(&mut self) -> &mut DocumentBaseTransition {
+        match self {
+            DocumentAcceptOfferTransition::V0(v0) => &mut v0.base,
+        }
+    }
+
+    fn set_base(&mut self, base: DocumentBaseTransition) {
+        match self {
+            DocumentAcceptOfferTransition::V0(v0) => v0.base = base,
+        }
+    }
+
+    fn revision(&self) -> Revision {
+        match self {
+            DocumentAcceptOfferTransition::V0(v0) => v0.revision,
+        }
+    }
+
+    fn set_revision(&mut self, revision: Revision) {
+        match self {
+            DocumentAcceptOfferTransition::V0(v0) => v0.revision = revision,
+        }
+    }
+
+    fn bidder_id(&self) -> Identifier {
+        match self {
+            DocumentAcceptOfferTransition::V0(v0) => v0.bidder_id,
+        }
+    }
+
+    fn price(&self) -> Credits {
+        match self {
+            DocumentAcceptOfferTransition::V0(v0) => v0.price,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::data_contract::document_type::DocumentTypeRef;
+use crate::document::Document;
+use crate::fee::Credits;
+use crate::prelude::{Identifier, IdentityNonce};
+use crate::ProtocolError;
+use platform_version::version::{FeatureVersion, PlatformVersion};
+
+use crate::state_transition::documents_batch_transition::document_transition::DocumentMakeOfferTransition;
+use crate::state_transition::documents_batch_transition::document_transition::document_make_offer_transition::DocumentMakeOfferTransitionV0;
+
+impl DocumentMakeOfferTransition {
+    pub fn from_document(
+        document: Document,
+        document_type: DocumentTypeRef,
+        price: Credits,
+        recipient_id: Option<Identifier>,
+        identity_contract_nonce: IdentityNonce,
+        platform_version: &PlatformVersion,
+        feature_version: Option<FeatureVersion>,
+        base_feature_version: Option<FeatureVersion>,
+    ) -> Result<Self, ProtocolError> {
+        match feature_version.unwrap_or(
+            platform_version
+                .dpp
+                .state_transition_serialization_versions
+                .document_make_offer_state_transition
+                .bounds
+                .default_current_version,
+        ) {
+            0 => Ok(DocumentMakeOfferTransitionV0::from_document(
+                document,
+                document_type,
+                price,
+                recipient_id,
+                identity_contract_nonce,
+                platform_version,
+                base_feature_version,
+            )?
+            .into()),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "DocumentMakeOfferTransition::from_document".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+}
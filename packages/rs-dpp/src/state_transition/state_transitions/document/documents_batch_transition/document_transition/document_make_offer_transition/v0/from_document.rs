@@ -0,0 +1,32 @@
+use platform_version::version::{FeatureVersion, PlatformVersion};
+use crate::data_contract::document_type::DocumentTypeRef;
+use crate::document::Document;
+use crate::fee::Credits;
+use crate::prelude::{Identifier, IdentityNonce};
+use crate::ProtocolError;
+use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+use crate::state_transition::documents_batch_transition::document_transition::document_make_offer_transition::DocumentMakeOfferTransitionV0;
+
+impl DocumentMakeOfferTransitionV0 {
+    pub(crate) fn from_document(
+        document: Document,
+        document_type: DocumentTypeRef,
+        price: Credits,
+        recipient_id: Option<Identifier>,
+        identity_contract_nonce: IdentityNonce,
+        platform_version: &PlatformVersion,
+        base_feature_version: Option<FeatureVersion>,
+    ) -> Result<Self, ProtocolError> {
+        Ok(DocumentMakeOfferTransitionV0 {
+            base: DocumentBaseTransition::from_document(
+                &document,
+                document_type,
+                identity_contract_nonce,
+                platform_version,
+                base_feature_version,
+            )?,
+            price,
+            recipient_id,
+        })
+    }
+}
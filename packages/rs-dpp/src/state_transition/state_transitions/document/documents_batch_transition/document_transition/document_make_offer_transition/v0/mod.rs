@@ -0,0 +1,36 @@
+mod from_document;
+pub mod v0_methods;
+
+use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+
+use bincode::{Decode, Encode};
+use derive_more::Display;
+
+use crate::fee::Credits;
+use crate::prelude::Identifier;
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+pub use super::super::document_base_transition::IDENTIFIER_FIELDS;
+
+/// Places an offer on a document, the offered credits are escrowed until the offer is
+/// accepted by the document owner or withdrawn.
+#[derive(Debug, Clone, Default, Encode, Decode, PartialEq, Display)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[display(fmt = "Base: {}", "base")]
+pub struct DocumentMakeOfferTransitionV0 {
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(flatten))]
+    pub base: DocumentBaseTransition,
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "price"))]
+    pub price: Credits,
+    /// The identity the offer is made to, required for document types with private offers
+    #[cfg_attr(
+        feature = "state-transition-serde-conversion",
+        serde(rename = "recipientId")
+    )]
+    pub recipient_id: Option<Identifier>,
+}
@@ -0,0 +1,45 @@
+use crate::fee::Credits;
+use crate::prelude::Identifier;
+use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+use crate::state_transition::documents_batch_transition::document_transition::document_make_offer_transition::DocumentMakeOfferTransitionV0;
+
+pub trait DocumentMakeOfferTransitionV0Methods {
+    /// Returns a reference to the `base` field of the `DocumentMakeOfferTransitionV0`.
+    fn base(&self) -> &DocumentBaseTransition;
+    fn base_mut(&mut self) -> &mut DocumentBaseTransition;
+
+    /// Sets the value of the `base` field in the `DocumentMakeOfferTransitionV0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - A value of type `DocumentBaseTransition` to set.
+    fn set_base(&mut self, base: DocumentBaseTransition);
+
+    /// The amount of credits offered and escrowed.
+    fn price(&self) -> Credits;
+
+    /// The identity the offer is made to, if it is private.
+    fn recipient_id(&self) -> Option<Identifier>;
+}
+
+impl DocumentMakeOfferTransitionV0Methods for DocumentMakeOfferTransitionV0 {
+    fn base(&self) -> &DocumentBaseTransition {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut DocumentBaseTransition {
+        &mut self.base
+    }
+
+    fn set_base(&mut self, base: DocumentBaseTransition) {
+        self.base = base
+    }
+
+    fn price(&self) -> Credits {
+        self.price
+    }
+
+    fn recipient_id(&self) -> Option<Identifier> {
+        self.recipient_id
+    }
+}
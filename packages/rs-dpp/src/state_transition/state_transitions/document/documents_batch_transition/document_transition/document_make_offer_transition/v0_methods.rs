@@ -0,0 +1,37 @@
+use crate::fee::Credits;
+use crate::prelude::Identifier;
+use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+use crate::state_transition::documents_batch_transition::document_transition::document_make_offer_transition::v0::v0_methods::DocumentMakeOfferTransitionV0Methods;
+use crate::state_transition::documents_batch_transition::document_transition::DocumentMakeOfferTransition;
+
+impl DocumentMakeOfferTransitionV0Methods for DocumentMakeOfferTransition {
+    fn base(&self) -> &DocumentBaseTransition {
+        match self {
+            DocumentMakeOfferTransition::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_mut(&mut self) -> &mut DocumentBaseTransition {
+        match self {
+            DocumentMakeOfferTransition::V0(v0) => &mut v0.base,
+        }
+    }
+
+    fn set_base(&mut self, base: DocumentBaseTransition) {
+        match self {
+            DocumentMakeOfferTransition::V0(v0) => v0.base = base,
+        }
+    }
+
+    fn price(&self) -> Credits {
+        match self {
+            DocumentMakeOfferTransition::V0(v0) => v0.price,
+        }
+    }
+
+    fn recipient_id(&self) -> Option<Identifier> {
+        match self {
+            DocumentMakeOfferTransition::V0(v0) => v0.recipient_id,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::data_contract::document_type::DocumentTypeRef;
+use crate::document::Document;
+use crate::prelude::IdentityNonce;
+use crate::ProtocolError;
+use platform_version::version::{FeatureVersion, PlatformVersion};
+
+use crate::state_transition::documents_batch_transition::document_transition::DocumentWithdrawOfferTransition;
+use crate::state_transition::documents_batch_transition::document_transition::document_withdraw_offer_transition::DocumentWithdrawOfferTransitionV0;
+
+impl DocumentWithdrawOfferTransition {
+    pub fn from_document(
+        document: Document,
+        document_type: DocumentTypeRef,
+        identity_contract_nonce: IdentityNonce,
+        platform_version: &PlatformVersion,
+        feature_version: Option<FeatureVersion>,
+        base_feature_version: Option<FeatureVersion>,
+    ) -> Result<Self, ProtocolError> {
+        match feature_version.unwrap_or(
+            platform_version
+                .dpp
+                .state_transition_serialization_versions
+                .document_withdraw_offer_state_transition
+                .bounds
+                .default_current_version,
+        ) {
+            0 => Ok(DocumentWithdrawOfferTransitionV0::from_document(
+                document,
+                document_type,
+                identity_contract_nonce,
+                platform_version,
+                base_feature_version,
+            )?
+            .into()),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "DocumentWithdrawOfferTransition::from_document".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+}
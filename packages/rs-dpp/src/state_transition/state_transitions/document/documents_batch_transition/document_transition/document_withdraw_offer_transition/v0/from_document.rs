@@ -0,0 +1,27 @@
+use platform_version::version::{FeatureVersion, PlatformVersion};
+use crate::data_contract::document_type::DocumentTypeRef;
+use crate::document::Document;
+use crate::prelude::IdentityNonce;
+use crate::ProtocolError;
+use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+use crate::state_transition::documents_batch_transition::document_transition::document_withdraw_offer_transition::DocumentWithdrawOfferTransitionV0;
+
+impl DocumentWithdrawOfferTransitionV0 {
+    pub(crate) fn from_document(
+        document: Document,
+        document_type: DocumentTypeRef,
+        identity_contract_nonce: IdentityNonce,
+        platform_version: &PlatformVersion,
+        base_feature_version: Option<FeatureVersion>,
+    ) -> Result<Self, ProtocolError> {
+        Ok(DocumentWithdrawOfferTransitionV0 {
+            base: DocumentBaseTransition::from_document(
+                &document,
+                document_type,
+                identity_contract_nonce,
+                platform_version,
+                base_feature_version,
+            )?,
+        })
+    }
+}
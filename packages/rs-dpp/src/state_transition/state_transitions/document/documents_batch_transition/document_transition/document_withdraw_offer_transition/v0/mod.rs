@@ -0,0 +1,26 @@
+mod from_document;
+pub mod v0_methods;
+
+use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+
+use bincode::{Decode, Encode};
+use derive_more::Display;
+
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+pub use super::super::document_base_transition::IDENTIFIER_FIELDS;
+
+/// Withdraws the open offer of the owner of the batch on a document, refunding the escrow.
+/// The document itself does not need to exist anymore.
+#[derive(Debug, Clone, Default, Encode, Decode, PartialEq, Display)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[display(fmt = "Base: {}", "base")]
+pub struct DocumentWithdrawOfferTransitionV0 {
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(flatten))]
+    pub base: DocumentBaseTransition,
+}
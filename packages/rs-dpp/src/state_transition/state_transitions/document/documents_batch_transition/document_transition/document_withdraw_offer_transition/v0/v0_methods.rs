@@ -0,0 +1,29 @@
+use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+use crate::state_transition::documents_batch_transition::document_transition::document_withdraw_offer_transition::DocumentWithdrawOfferTransitionV0;
+
+pub trait DocumentWithdrawOfferTransitionV0Methods {
+    /// Returns a reference to the `base` field of the `DocumentWithdrawOfferTransitionV0`.
+    fn base(&self) -> &DocumentBaseTransition;
+    fn base_mut(&mut self) -> &mut DocumentBaseTransition;
+
+    /// Sets the value of the `base` field in the `DocumentWithdrawOfferTransitionV0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - A value of type `DocumentBaseTransition` to set.
+    fn set_base(&mut self, base: DocumentBaseTransition);
+}
+
+impl DocumentWithdrawOfferTransitionV0Methods for DocumentWithdrawOfferTransitionV0 {
+    fn base(&self) -> &DocumentBaseTransition {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut DocumentBaseTransition {
+        &mut self.base
+    }
+
+    fn set_base(&mut self, base: DocumentBaseTransition) {
+        self.base = base
+    }
+}
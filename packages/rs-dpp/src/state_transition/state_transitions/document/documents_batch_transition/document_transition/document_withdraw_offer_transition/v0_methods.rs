@@ -0,0 +1,23 @@
+use crate::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+use crate::state_transition::documents_batch_transition::document_transition::document_withdraw_offer_transition::v0::v0_methods::DocumentWithdrawOfferTransitionV0Methods;
+use crate::state_transition::documents_batch_transition::document_transition::DocumentWithdrawOfferTransition;
+
+impl DocumentWithdrawOfferTransitionV0Methods for DocumentWithdrawOfferTransition {
+    fn base(&self) -> &DocumentBaseTransition {
+        match self {
+            DocumentWithdrawOfferTransition::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_mut(&mut self) -> &mut DocumentBaseTransition {
+        match self {
+            DocumentWithdrawOfferTransition::V0(v0) => &mut v0.base,
+        }
+    }
+
+    fn set_base(&mut self, base: DocumentBaseTransition) {
+        match self {
+            DocumentWithdrawOfferTransition::V0(v0) => v0.base = base,
+        }
+    }
+}
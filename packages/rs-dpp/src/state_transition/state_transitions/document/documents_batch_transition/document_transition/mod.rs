@@ -9,13 +9,16 @@ use crate::prelude::{Identifier, IdentityNonce};
 use document_base_transition::DocumentBaseTransition;
 
 pub mod action_type;
+pub mod document_accept_offer_transition;
 pub mod document_base_transition;
 pub mod document_create_transition;
 pub mod document_delete_transition;
+pub mod document_make_offer_transition;
 pub mod document_purchase_transition;
 pub mod document_replace_transition;
 pub mod document_transfer_transition;
 pub mod document_update_price_transition;
+pub mod document_withdraw_offer_transition;
 
 use crate::prelude::Revision;
 use crate::state_transition::documents_batch_transition::document_base_transition::v0::v0_methods::DocumentBaseTransitionV0Methods;
@@ -26,9 +29,15 @@ pub use document_replace_transition::DocumentReplaceTransition;
 pub use document_transfer_transition::DocumentTransferTransition;
 pub use document_purchase_transition::DocumentPurchaseTransition;
 pub use document_update_price_transition::DocumentUpdatePriceTransition;
+pub use document_make_offer_transition::DocumentMakeOfferTransition;
+pub use document_withdraw_offer_transition::DocumentWithdrawOfferTransition;
+pub use document_accept_offer_transition::DocumentAcceptOfferTransition;
 use platform_value::Value;
 use crate::state_transition::documents_batch_transition::document_transition::document_purchase_transition::v0::v0_methods::DocumentPurchaseTransitionV0Methods;
 use crate::state_transition::documents_batch_transition::document_transition::document_update_price_transition::v0::v0_methods::DocumentUpdatePriceTransitionV0Methods;
+use crate::state_transition::documents_batch_transition::document_transition::document_make_offer_transition::v0::v0_methods::DocumentMakeOfferTransitionV0Methods;
+use crate::state_transition::documents_batch_transition::document_transition::document_withdraw_offer_transition::v0::v0_methods::DocumentWithdrawOfferTransitionV0Methods;
+use crate::state_transition::documents_batch_transition::document_transition::document_accept_offer_transition::v0::v0_methods::DocumentAcceptOfferTransitionV0Methods;
 
 use crate::state_transition::state_transitions::document::documents_batch_transition::document_transition::document_create_transition::v0::v0_methods::DocumentCreateTransitionV0Methods;
 use crate::state_transition::state_transitions::document::documents_batch_transition::document_transition::document_replace_transition::v0::v0_methods::DocumentReplaceTransitionV0Methods;
@@ -93,6 +102,15 @@ pub enum DocumentTransition {
 
     #[display(fmt = "PurchaseDocumentTransition({})", "_0")]
     Purchase(DocumentPurchaseTransition),
+
+    #[display(fmt = "MakeOfferDocumentTransition({})", "_0")]
+    MakeOffer(DocumentMakeOfferTransition),
+
+    #[display(fmt = "WithdrawOfferDocumentTransition({})", "_0")]
+    WithdrawOffer(DocumentWithdrawOfferTransition),
+
+    #[display(fmt = "AcceptOfferDocumentTransition({})", "_0")]
+    AcceptOffer(DocumentAcceptOfferTransition),
 }
 
 impl DocumentTransition {
@@ -134,6 +152,14 @@ impl DocumentTransition {
             None
         }
     }
+
+    pub fn as_transition_make_offer(&self) -> Option<&DocumentMakeOfferTransition> {
+        if let Self::MakeOffer(ref t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
 }
 
 impl DocumentTransitionV0Methods for DocumentTransition {
@@ -145,6 +171,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Transfer(t) => t.base(),
             DocumentTransition::UpdatePrice(t) => t.base(),
             DocumentTransition::Purchase(t) => t.base(),
+            DocumentTransition::MakeOffer(t) => t.base(),
+            DocumentTransition::WithdrawOffer(t) => t.base(),
+            DocumentTransition::AcceptOffer(t) => t.base(),
         }
     }
 
@@ -156,6 +185,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Transfer(_) => None,
             DocumentTransition::UpdatePrice(_) => None,
             DocumentTransition::Purchase(_) => None,
+            DocumentTransition::MakeOffer(_) => None,
+            DocumentTransition::WithdrawOffer(_) => None,
+            DocumentTransition::AcceptOffer(_) => None,
         }
     }
 
@@ -179,6 +211,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Transfer(_) => None,
             DocumentTransition::UpdatePrice(_) => None,
             DocumentTransition::Purchase(_) => None,
+            DocumentTransition::MakeOffer(_) => None,
+            DocumentTransition::WithdrawOffer(_) => None,
+            DocumentTransition::AcceptOffer(_) => None,
         }
     }
 
@@ -190,6 +225,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Transfer(t) => Some(t.revision()),
             DocumentTransition::UpdatePrice(t) => Some(t.revision()),
             DocumentTransition::Purchase(t) => Some(t.revision()),
+            DocumentTransition::MakeOffer(_) => None,
+            DocumentTransition::WithdrawOffer(_) => None,
+            DocumentTransition::AcceptOffer(t) => Some(t.revision()),
         }
     }
 
@@ -201,6 +239,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Transfer(t) => t.base().identity_contract_nonce(),
             DocumentTransition::UpdatePrice(t) => t.base().identity_contract_nonce(),
             DocumentTransition::Purchase(t) => t.base().identity_contract_nonce(),
+            DocumentTransition::MakeOffer(t) => t.base().identity_contract_nonce(),
+            DocumentTransition::WithdrawOffer(t) => t.base().identity_contract_nonce(),
+            DocumentTransition::AcceptOffer(t) => t.base().identity_contract_nonce(),
         }
     }
 
@@ -221,6 +262,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Transfer(_) => {}
             DocumentTransition::UpdatePrice(_) => {}
             DocumentTransition::Purchase(_) => {}
+            DocumentTransition::MakeOffer(_) => {}
+            DocumentTransition::WithdrawOffer(_) => {}
+            DocumentTransition::AcceptOffer(_) => {}
         }
     }
 
@@ -236,6 +280,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Transfer(t) => t.base_mut(),
             DocumentTransition::UpdatePrice(t) => t.base_mut(),
             DocumentTransition::Purchase(t) => t.base_mut(),
+            DocumentTransition::MakeOffer(t) => t.base_mut(),
+            DocumentTransition::WithdrawOffer(t) => t.base_mut(),
+            DocumentTransition::AcceptOffer(t) => t.base_mut(),
         }
     }
 
@@ -247,6 +294,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Transfer(_) => None,
             DocumentTransition::UpdatePrice(_) => None,
             DocumentTransition::Purchase(_) => None,
+            DocumentTransition::MakeOffer(_) => None,
+            DocumentTransition::WithdrawOffer(_) => None,
+            DocumentTransition::AcceptOffer(_) => None,
         }
     }
 
@@ -258,6 +308,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Transfer(ref mut t) => t.set_revision(revision),
             DocumentTransition::UpdatePrice(ref mut t) => t.set_revision(revision),
             DocumentTransition::Purchase(ref mut t) => t.set_revision(revision),
+            DocumentTransition::MakeOffer(_) => {}
+            DocumentTransition::WithdrawOffer(_) => {}
+            DocumentTransition::AcceptOffer(ref mut t) => t.set_revision(revision),
         }
     }
 
@@ -269,6 +322,9 @@ impl DocumentTransitionV0Methods for DocumentTransition {
             DocumentTransition::Transfer(t) => t.base_mut().set_identity_contract_nonce(nonce),
             DocumentTransition::UpdatePrice(t) => t.base_mut().set_identity_contract_nonce(nonce),
             DocumentTransition::Purchase(t) => t.base_mut().set_identity_contract_nonce(nonce),
+            DocumentTransition::MakeOffer(t) => t.base_mut().set_identity_contract_nonce(nonce),
+            DocumentTransition::WithdrawOffer(t) => t.base_mut().set_identity_contract_nonce(nonce),
+            DocumentTransition::AcceptOffer(t) => t.base_mut().set_identity_contract_nonce(nonce),
         }
     }
 }
@@ -32,6 +32,12 @@ impl DocumentsBatchTransitionMethodsV0 for DocumentsBatchTransition {
         }
     }
 
+    fn all_offers_amount(&self) -> Result<Option<Credits>, ProtocolError> {
+        match self {
+            DocumentsBatchTransition::V0(v0) => v0.all_offers_amount(),
+        }
+    }
+
     fn all_conflicting_index_collateral_voting_funds(
         &self,
     ) -> Result<Option<Credits>, ProtocolError> {
@@ -323,4 +329,41 @@ impl DocumentsBatchTransitionMethodsV0 for DocumentsBatchTransition {
             }),
         }
     }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn new_documents_batch_transition_from_transitions<S: Signer>(
+        owner_id: Identifier,
+        transitions: Vec<DocumentTransition>,
+        identity_public_key: &IdentityPublicKey,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        batch_feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        match batch_feature_version.unwrap_or(
+            platform_version
+                .dpp
+                .state_transition_serialization_versions
+                .documents_batch_state_transition
+                .default_current_version,
+        ) {
+            0 => Ok(
+                DocumentsBatchTransitionV0::new_documents_batch_transition_from_transitions(
+                    owner_id,
+                    transitions,
+                    identity_public_key,
+                    user_fee_increase,
+                    signer,
+                    platform_version,
+                    batch_feature_version,
+                )?,
+            ),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "DocumentsBatchTransition::new_documents_batch_transition_from_transitions"
+                    .to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
 }
@@ -114,6 +114,19 @@ pub trait DocumentsBatchTransitionMethodsV0: DocumentsBatchTransitionAccessorsV0
         base_feature_version: Option<FeatureVersion>,
     ) -> Result<StateTransition, ProtocolError>;
 
+    /// Creates a documents batch transition containing several document transitions
+    /// of the same owner and signs it once
+    #[cfg(feature = "state-transition-signing")]
+    fn new_documents_batch_transition_from_transitions<S: Signer>(
+        owner_id: Identifier,
+        transitions: Vec<DocumentTransition>,
+        identity_public_key: &IdentityPublicKey,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        batch_feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError>;
+
     fn contract_based_security_level_requirement(
         &self,
         get_data_contract_security_level_requirement: impl Fn(
@@ -161,4 +174,7 @@ pub trait DocumentsBatchTransitionMethodsV0: DocumentsBatchTransitionAccessorsV0
     ) -> Result<Option<Credits>, ProtocolError>;
 
     fn all_purchases_amount(&self) -> Result<Option<Credits>, ProtocolError>;
+
+    /// The sum of the credits escrowed by all offers made in the batch
+    fn all_offers_amount(&self) -> Result<Option<Credits>, ProtocolError>;
 }
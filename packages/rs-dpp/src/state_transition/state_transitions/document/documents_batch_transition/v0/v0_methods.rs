@@ -37,6 +37,7 @@ use platform_value::Identifier;
 use platform_version::version::{FeatureVersion, PlatformVersion};
 use crate::state_transition::documents_batch_transition::document_create_transition::v0::v0_methods::DocumentCreateTransitionV0Methods;
 use crate::state_transition::documents_batch_transition::document_transition::document_purchase_transition::v0::v0_methods::DocumentPurchaseTransitionV0Methods;
+use crate::state_transition::documents_batch_transition::document_transition::document_make_offer_transition::v0::v0_methods::DocumentMakeOfferTransitionV0Methods;
 
 impl DocumentsBatchTransitionAccessorsV0 for DocumentsBatchTransitionV0 {
     fn transitions(&self) -> &Vec<DocumentTransition> {
@@ -291,6 +292,33 @@ impl DocumentsBatchTransitionMethodsV0 for DocumentsBatchTransitionV0 {
         Ok(state_transition)
     }
 
+    #[cfg(feature = "state-transition-signing")]
+    fn new_documents_batch_transition_from_transitions<S: Signer>(
+        owner_id: Identifier,
+        transitions: Vec<DocumentTransition>,
+        identity_public_key: &IdentityPublicKey,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        _platform_version: &PlatformVersion,
+        _batch_feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let documents_batch_transition: DocumentsBatchTransition = DocumentsBatchTransitionV0 {
+            owner_id,
+            transitions,
+            user_fee_increase,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+        let mut state_transition: StateTransition = documents_batch_transition.into();
+        state_transition.sign_external(
+            identity_public_key,
+            signer,
+            Some(|_, _| Ok(SecurityLevel::HIGH)),
+        )?;
+        Ok(state_transition)
+    }
+
     fn set_transitions(&mut self, transitions: Vec<DocumentTransition>) {
         self.transitions = transitions;
     }
@@ -324,6 +352,29 @@ impl DocumentsBatchTransitionMethodsV0 for DocumentsBatchTransitionV0 {
         }
     }
 
+    fn all_offers_amount(&self) -> Result<Option<Credits>, ProtocolError> {
+        let (total, any_offers): (Option<Credits>, bool) = self
+            .transitions
+            .iter()
+            .filter_map(|transition| {
+                transition
+                    .as_transition_make_offer()
+                    .map(|make_offer| make_offer.price())
+            })
+            .fold((None, false), |(acc, _), price| match acc {
+                Some(acc_val) => acc_val
+                    .checked_add(price)
+                    .map_or((None, true), |sum| (Some(sum), true)),
+                None => (Some(price), true),
+            });
+
+        match (total, any_offers) {
+            (Some(total), _) => Ok(Some(total)),
+            (None, true) => Err(ProtocolError::Overflow("overflow in all offers amount")), // Overflow occurred
+            _ => Ok(None), // No offers were made
+        }
+    }
+
     fn all_conflicting_index_collateral_voting_funds(
         &self,
     ) -> Result<Option<Credits>, ProtocolError> {
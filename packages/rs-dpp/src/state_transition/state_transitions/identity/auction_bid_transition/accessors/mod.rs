@@ -0,0 +1,64 @@
+mod v0;
+
+use crate::prelude::IdentityNonce;
+use crate::state_transition::auction_bid_transition::AuctionBidTransition;
+use crate::voting::auction_bids::AuctionBid;
+use crate::voting::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePoll;
+use platform_value::Identifier;
+pub use v0::*;
+
+impl AuctionBidTransitionAccessorsV0 for AuctionBidTransition {
+    fn identity_id(&self) -> Identifier {
+        match self {
+            AuctionBidTransition::V0(transition) => transition.identity_id,
+        }
+    }
+
+    fn set_identity_id(&mut self, identity_id: Identifier) {
+        match self {
+            AuctionBidTransition::V0(transition) => {
+                transition.identity_id = identity_id;
+            }
+        }
+    }
+
+    fn vote_poll(&self) -> &ContestedDocumentResourceVotePoll {
+        match self {
+            AuctionBidTransition::V0(transition) => &transition.vote_poll,
+        }
+    }
+
+    fn set_vote_poll(&mut self, vote_poll: ContestedDocumentResourceVotePoll) {
+        match self {
+            AuctionBidTransition::V0(transition) => {
+                transition.vote_poll = vote_poll;
+            }
+        }
+    }
+
+    fn bid(&self) -> &AuctionBid {
+        match self {
+            AuctionBidTransition::V0(transition) => &transition.bid,
+        }
+    }
+
+    fn set_bid(&mut self, bid: AuctionBid) {
+        match self {
+            AuctionBidTransition::V0(transition) => {
+                transition.bid = bid;
+            }
+        }
+    }
+
+    fn set_nonce(&mut self, nonce: IdentityNonce) {
+        match self {
+            AuctionBidTransition::V0(transition) => transition.nonce = nonce,
+        }
+    }
+
+    fn nonce(&self) -> IdentityNonce {
+        match self {
+            AuctionBidTransition::V0(transition) => transition.nonce,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::prelude::IdentityNonce;
+use crate::voting::auction_bids::AuctionBid;
+use crate::voting::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePoll;
+
+use platform_value::Identifier;
+
+pub trait AuctionBidTransitionAccessorsV0 {
+    fn identity_id(&self) -> Identifier;
+    fn set_identity_id(&mut self, identity_id: Identifier);
+    fn vote_poll(&self) -> &ContestedDocumentResourceVotePoll;
+    fn set_vote_poll(&mut self, vote_poll: ContestedDocumentResourceVotePoll);
+    fn bid(&self) -> &AuctionBid;
+    fn set_bid(&mut self, bid: AuctionBid);
+    fn set_nonce(&mut self, nonce: IdentityNonce);
+    fn nonce(&self) -> IdentityNonce;
+}
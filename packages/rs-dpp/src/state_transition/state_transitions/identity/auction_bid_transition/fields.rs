@@ -0,0 +1,16 @@
+use crate::state_transition::state_transitions;
+
+use crate::state_transition::auction_bid_transition::fields::property_names::VOTE_POLL_CONTRACT_ID;
+pub use state_transitions::common_fields::property_names::{
+    IDENTITY_NONCE, SIGNATURE, SIGNATURE_PUBLIC_KEY_ID, STATE_TRANSITION_PROTOCOL_VERSION,
+    TRANSITION_TYPE,
+};
+pub use state_transitions::identity::common_fields::property_names::IDENTITY_ID;
+
+pub(crate) mod property_names {
+    pub const VOTE_POLL_CONTRACT_ID: &str = "votePoll.contractId";
+}
+
+pub const IDENTIFIER_FIELDS: [&str; 2] = [IDENTITY_ID, VOTE_POLL_CONTRACT_ID];
+pub const BINARY_FIELDS: [&str; 1] = [SIGNATURE];
+pub const U32_FIELDS: [&str; 1] = [STATE_TRANSITION_PROTOCOL_VERSION];
@@ -0,0 +1,29 @@
+use crate::identity::{KeyID, Purpose, SecurityLevel};
+use crate::state_transition::auction_bid_transition::AuctionBidTransition;
+use crate::state_transition::StateTransitionIdentitySigned;
+
+impl StateTransitionIdentitySigned for AuctionBidTransition {
+    fn signature_public_key_id(&self) -> KeyID {
+        match self {
+            AuctionBidTransition::V0(transition) => transition.signature_public_key_id(),
+        }
+    }
+
+    fn set_signature_public_key_id(&mut self, key_id: KeyID) {
+        match self {
+            AuctionBidTransition::V0(transition) => transition.set_signature_public_key_id(key_id),
+        }
+    }
+
+    fn security_level_requirement(&self) -> Vec<SecurityLevel> {
+        match self {
+            AuctionBidTransition::V0(transition) => transition.security_level_requirement(),
+        }
+    }
+
+    fn purpose_requirement(&self) -> Purpose {
+        match self {
+            AuctionBidTransition::V0(transition) => transition.purpose_requirement(),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::state_transition::auction_bid_transition::AuctionBidTransition;
+use crate::state_transition::state_transitions::auction_bid_transition::fields::*;
+use crate::state_transition::{
+    JsonStateTransitionSerializationOptions, StateTransitionJsonConvert,
+};
+use crate::ProtocolError;
+use serde_json::Number;
+use serde_json::Value as JsonValue;
+
+impl<'a> StateTransitionJsonConvert<'a> for AuctionBidTransition {
+    fn to_json(
+        &self,
+        options: JsonStateTransitionSerializationOptions,
+    ) -> Result<JsonValue, ProtocolError> {
+        match self {
+            AuctionBidTransition::V0(transition) => {
+                let mut value = transition.to_json(options)?;
+                let map_value = value.as_object_mut().expect("expected an object");
+                map_value.insert(
+                    STATE_TRANSITION_PROTOCOL_VERSION.to_string(),
+                    JsonValue::Number(Number::from(0)),
+                );
+                Ok(value)
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+mod v0;
+
+pub use v0::*;
+
+use crate::state_transition::auction_bid_transition::AuctionBidTransition;
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::FeatureVersion;
+
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::{Identity, IdentityPublicKey};
+#[cfg(feature = "state-transition-signing")]
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::auction_bid_transition::v0::AuctionBidTransitionV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::StateTransition;
+#[cfg(feature = "state-transition-signing")]
+use crate::version::PlatformVersion;
+#[cfg(feature = "state-transition-signing")]
+use crate::voting::auction_bids::AuctionBid;
+#[cfg(feature = "state-transition-signing")]
+use crate::voting::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePoll;
+#[cfg(feature = "state-transition-signing")]
+use crate::ProtocolError;
+
+impl AuctionBidTransitionMethodsV0 for AuctionBidTransition {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity<S: Signer>(
+        identity: &Identity,
+        vote_poll: ContestedDocumentResourceVotePoll,
+        bid: AuctionBid,
+        user_fee_increase: UserFeeIncrease,
+        signer: S,
+        signing_key_to_use: Option<&IdentityPublicKey>,
+        nonce: IdentityNonce,
+        platform_version: &PlatformVersion,
+        version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        match version.unwrap_or(
+            platform_version
+                .dpp
+                .state_transition_conversion_versions
+                .identity_to_auction_bid_transition,
+        ) {
+            0 => Ok(AuctionBidTransitionV0::try_from_identity(
+                identity,
+                vote_poll,
+                bid,
+                user_fee_increase,
+                signer,
+                signing_key_to_use,
+                nonce,
+                platform_version,
+                version,
+            )?),
+            v => Err(ProtocolError::UnknownVersionError(format!(
+                "Unknown AuctionBidTransition version for try_from_identity {v}"
+            ))),
+        }
+    }
+}
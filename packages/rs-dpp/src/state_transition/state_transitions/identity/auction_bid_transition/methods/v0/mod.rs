@@ -0,0 +1,39 @@
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::Identity;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::IdentityPublicKey;
+#[cfg(feature = "state-transition-signing")]
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::StateTransition;
+use crate::state_transition::StateTransitionType;
+#[cfg(feature = "state-transition-signing")]
+use crate::voting::auction_bids::AuctionBid;
+#[cfg(feature = "state-transition-signing")]
+use crate::voting::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePoll;
+#[cfg(feature = "state-transition-signing")]
+use crate::ProtocolError;
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::{FeatureVersion, PlatformVersion};
+
+pub trait AuctionBidTransitionMethodsV0 {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity<S: Signer>(
+        identity: &Identity,
+        vote_poll: ContestedDocumentResourceVotePoll,
+        bid: AuctionBid,
+        user_fee_increase: UserFeeIncrease,
+        signer: S,
+        signing_key_to_use: Option<&IdentityPublicKey>,
+        nonce: IdentityNonce,
+        platform_version: &PlatformVersion,
+        version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError>;
+
+    /// Get State Transition Type
+    fn get_type() -> StateTransitionType {
+        StateTransitionType::AuctionBid
+    }
+}
@@ -0,0 +1,4 @@
+use crate::state_transition::auction_bid_transition::v0::AuctionBidTransitionV0;
+use crate::state_transition::StateTransitionJsonConvert;
+
+impl<'a> StateTransitionJsonConvert<'a> for AuctionBidTransitionV0 {}
@@ -0,0 +1,122 @@
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+mod state_transition_like;
+mod types;
+pub(super) mod v0_methods;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use crate::identity::KeyID;
+
+use crate::prelude::{Identifier, IdentityNonce, UserFeeIncrease};
+use crate::voting::auction_bids::AuctionBid;
+use crate::voting::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePoll;
+
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize, PlatformSignable};
+use platform_value::BinaryData;
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug,
+    Clone,
+    Encode,
+    Decode,
+    PlatformSerialize,
+    PlatformDeserialize,
+    PlatformSignable,
+    PartialEq,
+)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[platform_serialize(unversioned)]
+#[derive(Default)]
+pub struct AuctionBidTransitionV0 {
+    // Own ST fields
+    pub identity_id: Identifier,
+    pub vote_poll: ContestedDocumentResourceVotePoll,
+    pub bid: AuctionBid,
+    pub nonce: IdentityNonce,
+    pub user_fee_increase: UserFeeIncrease,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature_public_key_id: KeyID,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature: BinaryData,
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::serialization::{PlatformDeserializable, PlatformSerializable};
+
+    use crate::state_transition::auction_bid_transition::v0::AuctionBidTransitionV0;
+    use crate::voting::auction_bids::AuctionBid;
+    use crate::voting::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePoll;
+    use platform_value::{Bytes32, Identifier, Value};
+    use rand::Rng;
+    use std::fmt::Debug;
+
+    fn test_auction_bid_transition<
+        T: PlatformSerializable + PlatformDeserializable + Debug + PartialEq,
+    >(
+        transition: T,
+    ) where
+        <T as PlatformSerializable>::Error: std::fmt::Debug,
+    {
+        let serialized = T::serialize_to_bytes(&transition).expect("expected to serialize");
+        let deserialized =
+            T::deserialize_from_bytes(serialized.as_slice()).expect("expected to deserialize");
+        assert_eq!(transition, deserialized);
+    }
+
+    #[test]
+    fn test_auction_bid_transition_commit_and_reveal() {
+        let mut rng = rand::thread_rng();
+        let vote_poll = ContestedDocumentResourceVotePoll {
+            contract_id: Identifier::random(),
+            document_type_name: "domain".to_string(),
+            index_name: "parentNameAndLabel".to_string(),
+            index_values: vec![
+                Value::Text("dash".to_string()),
+                Value::Text("quantum".to_string()),
+            ],
+        };
+
+        let transition = AuctionBidTransitionV0 {
+            identity_id: Identifier::random(),
+            vote_poll: vote_poll.clone(),
+            bid: AuctionBid::Commit {
+                sealed_bid: Bytes32::new(rng.gen()),
+                deposit: rng.gen(),
+            },
+            nonce: 1,
+            user_fee_increase: 0,
+            signature_public_key_id: rng.gen(),
+            signature: [0; 65].to_vec().into(),
+        };
+
+        test_auction_bid_transition(transition);
+
+        let transition = AuctionBidTransitionV0 {
+            identity_id: Identifier::random(),
+            vote_poll,
+            bid: AuctionBid::Reveal {
+                amount: rng.gen(),
+                salt: Bytes32::new(rng.gen()),
+            },
+            nonce: 2,
+            user_fee_increase: 0,
+            signature_public_key_id: rng.gen(),
+            signature: [0; 65].to_vec().into(),
+        };
+
+        test_auction_bid_transition(transition);
+    }
+}
@@ -0,0 +1,72 @@
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use platform_value::BinaryData;
+
+use crate::prelude::UserFeeIncrease;
+use crate::{
+    prelude::Identifier,
+    state_transition::{StateTransitionLike, StateTransitionType},
+};
+
+use crate::state_transition::auction_bid_transition::v0::AuctionBidTransitionV0;
+use crate::state_transition::auction_bid_transition::AuctionBidTransition;
+
+use crate::state_transition::StateTransition;
+use crate::state_transition::StateTransitionType::AuctionBid;
+use crate::version::FeatureVersion;
+
+impl From<AuctionBidTransitionV0> for StateTransition {
+    fn from(value: AuctionBidTransitionV0) -> Self {
+        let auction_bid_transition: AuctionBidTransition = value.into();
+        auction_bid_transition.into()
+    }
+}
+
+impl StateTransitionLike for AuctionBidTransitionV0 {
+    fn state_transition_protocol_version(&self) -> FeatureVersion {
+        0
+    }
+
+    /// returns the type of State Transition
+    fn state_transition_type(&self) -> StateTransitionType {
+        AuctionBid
+    }
+    /// returns the signature as a byte-array
+    fn signature(&self) -> &BinaryData {
+        &self.signature
+    }
+    /// set a new signature
+    fn set_signature(&mut self, signature: BinaryData) {
+        self.signature = signature
+    }
+    /// Returns ID of the bidder
+    fn modified_data_ids(&self) -> Vec<Identifier> {
+        vec![self.identity_id]
+    }
+
+    fn set_signature_bytes(&mut self, signature: Vec<u8>) {
+        self.signature = BinaryData::new(signature)
+    }
+
+    /// Get owner ID
+    fn owner_id(&self) -> Identifier {
+        self.identity_id
+    }
+
+    /// We want things to be unique based on the nonce, so we don't add the transition type
+    fn unique_identifiers(&self) -> Vec<String> {
+        vec![format!(
+            "{}-{:x}",
+            BASE64_STANDARD.encode(self.identity_id),
+            self.nonce
+        )]
+    }
+
+    fn user_fee_increase(&self) -> UserFeeIncrease {
+        self.user_fee_increase
+    }
+
+    fn set_user_fee_increase(&mut self, user_fee_increase: UserFeeIncrease) {
+        self.user_fee_increase = user_fee_increase
+    }
+}
@@ -0,0 +1,18 @@
+use crate::state_transition::auction_bid_transition::fields::property_names::*;
+use crate::state_transition::auction_bid_transition::fields::*;
+use crate::state_transition::auction_bid_transition::v0::AuctionBidTransitionV0;
+use crate::state_transition::StateTransitionFieldTypes;
+
+impl StateTransitionFieldTypes for AuctionBidTransitionV0 {
+    fn signature_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE]
+    }
+
+    fn identifiers_property_paths() -> Vec<&'static str> {
+        vec![IDENTITY_ID, VOTE_POLL_CONTRACT_ID]
+    }
+
+    fn binary_property_paths() -> Vec<&'static str> {
+        vec![]
+    }
+}
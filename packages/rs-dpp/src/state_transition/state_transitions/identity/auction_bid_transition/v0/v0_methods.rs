@@ -0,0 +1,72 @@
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::accessors::IdentityGettersV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::IdentityPublicKey;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::{Identity, KeyType, Purpose, SecurityLevel};
+#[cfg(feature = "state-transition-signing")]
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+use crate::state_transition::auction_bid_transition::methods::AuctionBidTransitionMethodsV0;
+use crate::state_transition::auction_bid_transition::v0::AuctionBidTransitionV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::{GetDataContractSecurityLevelRequirementFn, StateTransition};
+#[cfg(feature = "state-transition-signing")]
+use crate::voting::auction_bids::AuctionBid;
+#[cfg(feature = "state-transition-signing")]
+use crate::voting::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePoll;
+#[cfg(feature = "state-transition-signing")]
+use crate::ProtocolError;
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::{FeatureVersion, PlatformVersion};
+
+impl AuctionBidTransitionMethodsV0 for AuctionBidTransitionV0 {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity<S: Signer>(
+        identity: &Identity,
+        vote_poll: ContestedDocumentResourceVotePoll,
+        bid: AuctionBid,
+        user_fee_increase: UserFeeIncrease,
+        signer: S,
+        signing_key_to_use: Option<&IdentityPublicKey>,
+        nonce: IdentityNonce,
+        _platform_version: &PlatformVersion,
+        _version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let mut transition: StateTransition = AuctionBidTransitionV0 {
+            identity_id: identity.id(),
+            vote_poll,
+            bid,
+            nonce,
+            user_fee_increase,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+
+        let identity_public_key = match signing_key_to_use {
+            Some(key) => key,
+            None => identity
+                .get_first_public_key_matching(
+                    Purpose::AUTHENTICATION,
+                    [SecurityLevel::CRITICAL, SecurityLevel::HIGH].into(),
+                    KeyType::all_key_types().into(),
+                    false,
+                )
+                .ok_or_else(|| {
+                    ProtocolError::DesiredKeyWithTypePurposeSecurityLevelMissing(
+                        "no critical or high authentication public key".to_string(),
+                    )
+                })?,
+        };
+
+        transition.sign_external(
+            identity_public_key,
+            &signer,
+            None::<GetDataContractSecurityLevelRequirementFn>,
+        )?;
+
+        Ok(transition)
+    }
+}
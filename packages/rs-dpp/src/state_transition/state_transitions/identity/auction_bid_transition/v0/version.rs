@@ -0,0 +1,9 @@
+use crate::state_transition::auction_bid_transition::v0::AuctionBidTransitionV0;
+use crate::state_transition::FeatureVersioned;
+use crate::version::FeatureVersion;
+
+impl FeatureVersioned for AuctionBidTransitionV0 {
+    fn feature_version(&self) -> FeatureVersion {
+        0
+    }
+}
@@ -0,0 +1,11 @@
+use crate::state_transition::auction_bid_transition::AuctionBidTransition;
+use crate::state_transition::FeatureVersioned;
+use crate::version::FeatureVersion;
+
+impl FeatureVersioned for AuctionBidTransition {
+    fn feature_version(&self) -> FeatureVersion {
+        match self {
+            AuctionBidTransition::V0(v0) => v0.feature_version(),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+mod v0;
+
+use crate::prelude::IdentityNonce;
+use crate::state_transition::identity_credit_batch_transfer_transition::{
+    CreditTransferOutput, IdentityCreditBatchTransferTransition,
+};
+use platform_value::Identifier;
+pub use v0::*;
+
+impl IdentityCreditBatchTransferTransitionAccessorsV0 for IdentityCreditBatchTransferTransition {
+    fn outputs(&self) -> &Vec<CreditTransferOutput> {
+        match self {
+            IdentityCreditBatchTransferTransition::V0(transition) => &transition.outputs,
+        }
+    }
+
+    fn outputs_owned(self) -> Vec<CreditTransferOutput> {
+        match self {
+            IdentityCreditBatchTransferTransition::V0(transition) => transition.outputs,
+        }
+    }
+
+    fn set_outputs(&mut self, outputs: Vec<CreditTransferOutput>) {
+        match self {
+            IdentityCreditBatchTransferTransition::V0(transition) => {
+                transition.outputs = outputs;
+            }
+        }
+    }
+
+    fn identity_id(&self) -> Identifier {
+        match self {
+            IdentityCreditBatchTransferTransition::V0(transition) => transition.identity_id,
+        }
+    }
+
+    fn set_identity_id(&mut self, identity_id: Identifier) {
+        match self {
+            IdentityCreditBatchTransferTransition::V0(transition) => {
+                transition.identity_id = identity_id;
+            }
+        }
+    }
+
+    fn set_nonce(&mut self, nonce: IdentityNonce) {
+        match self {
+            IdentityCreditBatchTransferTransition::V0(transition) => transition.nonce = nonce,
+        }
+    }
+
+    fn nonce(&self) -> IdentityNonce {
+        match self {
+            IdentityCreditBatchTransferTransition::V0(transition) => transition.nonce,
+        }
+    }
+}
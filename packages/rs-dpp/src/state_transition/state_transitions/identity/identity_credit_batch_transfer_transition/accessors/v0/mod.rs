@@ -0,0 +1,23 @@
+use crate::fee::Credits;
+use crate::prelude::IdentityNonce;
+use crate::state_transition::identity_credit_batch_transfer_transition::CreditTransferOutput;
+
+use platform_value::Identifier;
+
+pub trait IdentityCreditBatchTransferTransitionAccessorsV0 {
+    fn outputs(&self) -> &Vec<CreditTransferOutput>;
+    fn outputs_owned(self) -> Vec<CreditTransferOutput>;
+    fn set_outputs(&mut self, outputs: Vec<CreditTransferOutput>);
+    /// The sum of all output amounts, `None` on overflow
+    fn total_amount(&self) -> Option<Credits> {
+        self.outputs()
+            .iter()
+            .try_fold(0 as Credits, |total, output| {
+                total.checked_add(output.amount)
+            })
+    }
+    fn identity_id(&self) -> Identifier;
+    fn set_identity_id(&mut self, identity_id: Identifier);
+    fn set_nonce(&mut self, nonce: IdentityNonce);
+    fn nonce(&self) -> IdentityNonce;
+}
@@ -0,0 +1,59 @@
+mod v0;
+
+pub use v0::*;
+
+use crate::state_transition::identity_credit_batch_transfer_transition::IdentityCreditBatchTransferTransition;
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::FeatureVersion;
+
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::{Identity, IdentityPublicKey};
+#[cfg(feature = "state-transition-signing")]
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::identity_credit_batch_transfer_transition::v0::IdentityCreditBatchTransferTransitionV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::identity_credit_batch_transfer_transition::CreditTransferOutput;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::StateTransition;
+#[cfg(feature = "state-transition-signing")]
+use crate::version::PlatformVersion;
+#[cfg(feature = "state-transition-signing")]
+use crate::ProtocolError;
+
+impl IdentityCreditBatchTransferTransitionMethodsV0 for IdentityCreditBatchTransferTransition {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity<S: Signer>(
+        identity: &Identity,
+        outputs: Vec<CreditTransferOutput>,
+        user_fee_increase: UserFeeIncrease,
+        signer: S,
+        signing_transfer_key_to_use: Option<&IdentityPublicKey>,
+        nonce: IdentityNonce,
+        platform_version: &PlatformVersion,
+        version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        match version.unwrap_or(
+            platform_version
+                .dpp
+                .state_transition_conversion_versions
+                .identity_to_identity_batch_transfer_transition,
+        ) {
+            0 => Ok(IdentityCreditBatchTransferTransitionV0::try_from_identity(
+                identity,
+                outputs,
+                user_fee_increase,
+                signer,
+                signing_transfer_key_to_use,
+                nonce,
+                platform_version,
+                version,
+            )?),
+            v => Err(ProtocolError::UnknownVersionError(format!(
+                "Unknown IdentityCreditBatchTransferTransition version for try_from_identity {v}"
+            ))),
+        }
+    }
+}
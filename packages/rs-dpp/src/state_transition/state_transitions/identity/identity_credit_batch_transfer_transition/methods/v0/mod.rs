@@ -0,0 +1,36 @@
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::Identity;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::IdentityPublicKey;
+#[cfg(feature = "state-transition-signing")]
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::identity_credit_batch_transfer_transition::CreditTransferOutput;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::StateTransition;
+use crate::state_transition::StateTransitionType;
+#[cfg(feature = "state-transition-signing")]
+use crate::ProtocolError;
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::{FeatureVersion, PlatformVersion};
+
+pub trait IdentityCreditBatchTransferTransitionMethodsV0 {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity<S: Signer>(
+        identity: &Identity,
+        outputs: Vec<CreditTransferOutput>,
+        user_fee_increase: UserFeeIncrease,
+        signer: S,
+        signing_transfer_key_to_use: Option<&IdentityPublicKey>,
+        nonce: IdentityNonce,
+        platform_version: &PlatformVersion,
+        version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError>;
+
+    /// Get State Transition Type
+    fn get_type() -> StateTransitionType {
+        StateTransitionType::IdentityCreditBatchTransfer
+    }
+}
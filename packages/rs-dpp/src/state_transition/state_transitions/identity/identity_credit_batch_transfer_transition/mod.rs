@@ -0,0 +1,92 @@
+pub mod accessors;
+pub mod fields;
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+pub mod methods;
+mod output;
+mod state_transition_like;
+pub mod v0;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use crate::state_transition::identity_credit_batch_transfer_transition::fields::property_names::OUTPUTS_RECIPIENT_ID;
+use crate::state_transition::identity_credit_batch_transfer_transition::v0::IdentityCreditBatchTransferTransitionV0;
+use crate::state_transition::identity_credit_batch_transfer_transition::v0::IdentityCreditBatchTransferTransitionV0Signable;
+use crate::state_transition::StateTransitionFieldTypes;
+
+use crate::identity::state_transition::OptionallyAssetLockProved;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use derive_more::From;
+use fields::*;
+pub use output::CreditTransferOutput;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize, PlatformSignable};
+use platform_version::version::PlatformVersion;
+use platform_versioning::PlatformVersioned;
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+pub type IdentityCreditBatchTransferTransitionLatest = IdentityCreditBatchTransferTransitionV0;
+
+#[derive(
+    Debug,
+    Clone,
+    Encode,
+    Decode,
+    PlatformDeserialize,
+    PlatformSerialize,
+    PlatformSignable,
+    PlatformVersioned,
+    From,
+    PartialEq,
+)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(tag = "$version")
+)]
+#[platform_serialize(unversioned)] //versioned directly, no need to use platform_version
+#[platform_version_path_bounds(
+    "dpp.state_transition_serialization_versions.identity_credit_batch_transfer_state_transition"
+)]
+pub enum IdentityCreditBatchTransferTransition {
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "0"))]
+    V0(IdentityCreditBatchTransferTransitionV0),
+}
+
+impl IdentityCreditBatchTransferTransition {
+    pub fn default_versioned(platform_version: &PlatformVersion) -> Result<Self, ProtocolError> {
+        match platform_version
+            .dpp
+            .identity_versions
+            .identity_structure_version
+        {
+            0 => Ok(IdentityCreditBatchTransferTransition::V0(
+                IdentityCreditBatchTransferTransitionV0::default(),
+            )),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "IdentityCreditBatchTransferTransitionV0::default_versioned".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+}
+
+impl OptionallyAssetLockProved for IdentityCreditBatchTransferTransition {}
+
+impl StateTransitionFieldTypes for IdentityCreditBatchTransferTransition {
+    fn signature_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE]
+    }
+
+    fn identifiers_property_paths() -> Vec<&'static str> {
+        vec![IDENTITY_ID, OUTPUTS_RECIPIENT_ID]
+    }
+
+    fn binary_property_paths() -> Vec<&'static str> {
+        vec![]
+    }
+}
@@ -0,0 +1,29 @@
+use crate::fee::Credits;
+use bincode::{Decode, Encode};
+use platform_value::Identifier;
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+/// A single recipient of a credit batch transfer
+#[derive(Debug, Clone, Encode, Decode, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct CreditTransferOutput {
+    pub recipient_id: Identifier,
+    pub amount: Credits,
+    /// An optional note for the recipient, its size is limited by the platform version
+    pub memo: Option<String>,
+}
+
+impl CreditTransferOutput {
+    pub fn new(recipient_id: Identifier, amount: Credits, memo: Option<String>) -> Self {
+        Self {
+            recipient_id,
+            amount,
+            memo,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+use crate::state_transition::identity_credit_batch_transfer_transition::v0::IdentityCreditBatchTransferTransitionV0;
+use crate::state_transition::StateTransitionJsonConvert;
+
+impl<'a> StateTransitionJsonConvert<'a> for IdentityCreditBatchTransferTransitionV0 {}
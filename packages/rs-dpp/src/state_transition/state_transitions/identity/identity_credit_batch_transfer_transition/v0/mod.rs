@@ -0,0 +1,97 @@
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+mod state_transition_like;
+mod types;
+pub(super) mod v0_methods;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use crate::identity::KeyID;
+
+use crate::prelude::{Identifier, IdentityNonce, UserFeeIncrease};
+use crate::state_transition::identity_credit_batch_transfer_transition::CreditTransferOutput;
+
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize, PlatformSignable};
+use platform_value::BinaryData;
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug,
+    Clone,
+    Encode,
+    Decode,
+    PlatformSerialize,
+    PlatformDeserialize,
+    PlatformSignable,
+    PartialEq,
+)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[platform_serialize(unversioned)]
+#[derive(Default)]
+pub struct IdentityCreditBatchTransferTransitionV0 {
+    // Own ST fields
+    pub identity_id: Identifier,
+    pub outputs: Vec<CreditTransferOutput>,
+    pub nonce: IdentityNonce,
+    pub user_fee_increase: UserFeeIncrease,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature_public_key_id: KeyID,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature: BinaryData,
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::serialization::{PlatformDeserializable, PlatformSerializable};
+
+    use crate::state_transition::identity_credit_batch_transfer_transition::v0::IdentityCreditBatchTransferTransitionV0;
+    use crate::state_transition::identity_credit_batch_transfer_transition::CreditTransferOutput;
+    use platform_value::Identifier;
+    use rand::Rng;
+    use std::fmt::Debug;
+
+    fn test_identity_credit_batch_transfer_transition<
+        T: PlatformSerializable + PlatformDeserializable + Debug + PartialEq,
+    >(
+        transition: T,
+    ) where
+        <T as PlatformSerializable>::Error: std::fmt::Debug,
+    {
+        let serialized = T::serialize_to_bytes(&transition).expect("expected to serialize");
+        let deserialized =
+            T::deserialize_from_bytes(serialized.as_slice()).expect("expected to deserialize");
+        assert_eq!(transition, deserialized);
+    }
+
+    #[test]
+    fn test_identity_credit_batch_transfer_transition1() {
+        let mut rng = rand::thread_rng();
+        let transition = IdentityCreditBatchTransferTransitionV0 {
+            identity_id: Identifier::random(),
+            outputs: vec![
+                CreditTransferOutput::new(Identifier::random(), rng.gen(), None),
+                CreditTransferOutput::new(
+                    Identifier::random(),
+                    rng.gen(),
+                    Some("for the pizza".to_string()),
+                ),
+            ],
+            nonce: 1,
+            user_fee_increase: 0,
+            signature_public_key_id: rng.gen(),
+            signature: [0; 65].to_vec().into(),
+        };
+
+        test_identity_credit_batch_transfer_transition(transition);
+    }
+}
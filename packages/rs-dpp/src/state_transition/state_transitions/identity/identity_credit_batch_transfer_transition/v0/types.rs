@@ -0,0 +1,18 @@
+use crate::state_transition::identity_credit_batch_transfer_transition::fields::property_names::*;
+use crate::state_transition::identity_credit_batch_transfer_transition::fields::*;
+use crate::state_transition::identity_credit_batch_transfer_transition::v0::IdentityCreditBatchTransferTransitionV0;
+use crate::state_transition::StateTransitionFieldTypes;
+
+impl StateTransitionFieldTypes for IdentityCreditBatchTransferTransitionV0 {
+    fn signature_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE]
+    }
+
+    fn identifiers_property_paths() -> Vec<&'static str> {
+        vec![IDENTITY_ID, OUTPUTS_RECIPIENT_ID]
+    }
+
+    fn binary_property_paths() -> Vec<&'static str> {
+        vec![]
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::accessors::IdentityGettersV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::IdentityPublicKey;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::{Identity, KeyType, Purpose, SecurityLevel};
+#[cfg(feature = "state-transition-signing")]
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+use crate::state_transition::identity_credit_batch_transfer_transition::methods::IdentityCreditBatchTransferTransitionMethodsV0;
+use crate::state_transition::identity_credit_batch_transfer_transition::v0::IdentityCreditBatchTransferTransitionV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::identity_credit_batch_transfer_transition::CreditTransferOutput;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::{GetDataContractSecurityLevelRequirementFn, StateTransition};
+#[cfg(feature = "state-transition-signing")]
+use crate::ProtocolError;
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::{FeatureVersion, PlatformVersion};
+
+impl IdentityCreditBatchTransferTransitionMethodsV0 for IdentityCreditBatchTransferTransitionV0 {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity<S: Signer>(
+        identity: &Identity,
+        outputs: Vec<CreditTransferOutput>,
+        user_fee_increase: UserFeeIncrease,
+        signer: S,
+        signing_transfer_key_to_use: Option<&IdentityPublicKey>,
+        nonce: IdentityNonce,
+        _platform_version: &PlatformVersion,
+        _version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let mut transition: StateTransition = IdentityCreditBatchTransferTransitionV0 {
+            identity_id: identity.id(),
+            outputs,
+            nonce,
+            user_fee_increase,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+
+        let identity_public_key = match signing_transfer_key_to_use {
+            Some(key) => key,
+            None => identity
+                .get_first_public_key_matching(
+                    Purpose::TRANSFER,
+                    SecurityLevel::full_range().into(),
+                    KeyType::all_key_types().into(),
+                    true,
+                )
+                .ok_or_else(|| {
+                    ProtocolError::DesiredKeyWithTypePurposeSecurityLevelMissing(
+                        "no transfer public key".to_string(),
+                    )
+                })?,
+        };
+
+        transition.sign_external(
+            identity_public_key,
+            &signer,
+            None::<GetDataContractSecurityLevelRequirementFn>,
+        )?;
+
+        Ok(transition)
+    }
+}
@@ -0,0 +1,9 @@
+use crate::state_transition::identity_credit_batch_transfer_transition::v0::IdentityCreditBatchTransferTransitionV0;
+use crate::state_transition::FeatureVersioned;
+use crate::version::FeatureVersion;
+
+impl FeatureVersioned for IdentityCreditBatchTransferTransitionV0 {
+    fn feature_version(&self) -> FeatureVersion {
+        0
+    }
+}
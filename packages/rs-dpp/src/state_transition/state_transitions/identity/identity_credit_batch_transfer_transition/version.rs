@@ -0,0 +1,11 @@
+use crate::state_transition::identity_credit_batch_transfer_transition::IdentityCreditBatchTransferTransition;
+use crate::state_transition::FeatureVersioned;
+use crate::version::FeatureVersion;
+
+impl FeatureVersioned for IdentityCreditBatchTransferTransition {
+    fn feature_version(&self) -> FeatureVersion {
+        match self {
+            IdentityCreditBatchTransferTransition::V0(v0) => v0.feature_version(),
+        }
+    }
+}
@@ -3,5 +3,59 @@ mod v0;
 pub use v0::*;
 
 use crate::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::FeatureVersion;
 
-impl IdentityCreditTransferTransitionMethodsV0 for IdentityCreditTransferTransition {}
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::{Identity, IdentityPublicKey};
+#[cfg(feature = "state-transition-signing")]
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::StateTransition;
+#[cfg(feature = "state-transition-signing")]
+use crate::version::PlatformVersion;
+#[cfg(feature = "state-transition-signing")]
+use crate::ProtocolError;
+#[cfg(feature = "state-transition-signing")]
+use platform_value::Identifier;
+
+impl IdentityCreditTransferTransitionMethodsV0 for IdentityCreditTransferTransition {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity<S: Signer>(
+        identity: &Identity,
+        to_identity_with_identifier: Identifier,
+        amount: u64,
+        user_fee_increase: UserFeeIncrease,
+        signer: S,
+        signing_transfer_key_to_use: Option<&IdentityPublicKey>,
+        nonce: IdentityNonce,
+        platform_version: &PlatformVersion,
+        version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        match version.unwrap_or(
+            platform_version
+                .dpp
+                .state_transition_conversion_versions
+                .identity_to_identity_transfer_transition,
+        ) {
+            0 => Ok(IdentityCreditTransferTransitionV0::try_from_identity(
+                identity,
+                to_identity_with_identifier,
+                amount,
+                user_fee_increase,
+                signer,
+                signing_transfer_key_to_use,
+                nonce,
+                platform_version,
+                version,
+            )?),
+            v => Err(ProtocolError::UnknownVersionError(format!(
+                "Unknown IdentityCreditTransferTransition version for try_from_identity {v}"
+            ))),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+mod v0;
+
+use crate::identity::recovery::GuardianSignature;
+use crate::prelude::IdentityNonce;
+use crate::state_transition::identity_recovery_transition::{
+    IdentityRecoveryOperation, IdentityRecoveryTransition,
+};
+use platform_value::Identifier;
+use std::collections::BTreeMap;
+pub use v0::*;
+
+impl IdentityRecoveryTransitionAccessorsV0 for IdentityRecoveryTransition {
+    fn identity_id(&self) -> Identifier {
+        match self {
+            IdentityRecoveryTransition::V0(transition) => transition.identity_id,
+        }
+    }
+
+    fn set_identity_id(&mut self, identity_id: Identifier) {
+        match self {
+            IdentityRecoveryTransition::V0(transition) => transition.identity_id = identity_id,
+        }
+    }
+
+    fn operation(&self) -> &IdentityRecoveryOperation {
+        match self {
+            IdentityRecoveryTransition::V0(transition) => &transition.operation,
+        }
+    }
+
+    fn set_operation(&mut self, operation: IdentityRecoveryOperation) {
+        match self {
+            IdentityRecoveryTransition::V0(transition) => transition.operation = operation,
+        }
+    }
+
+    fn nonce(&self) -> IdentityNonce {
+        match self {
+            IdentityRecoveryTransition::V0(transition) => transition.nonce,
+        }
+    }
+
+    fn set_nonce(&mut self, nonce: IdentityNonce) {
+        match self {
+            IdentityRecoveryTransition::V0(transition) => transition.nonce = nonce,
+        }
+    }
+
+    fn guardian_signatures(&self) -> &BTreeMap<Identifier, GuardianSignature> {
+        match self {
+            IdentityRecoveryTransition::V0(transition) => &transition.guardian_signatures,
+        }
+    }
+
+    fn insert_guardian_signature(
+        &mut self,
+        guardian_id: Identifier,
+        guardian_signature: GuardianSignature,
+    ) {
+        match self {
+            IdentityRecoveryTransition::V0(transition) => {
+                transition
+                    .guardian_signatures
+                    .insert(guardian_id, guardian_signature);
+            }
+        }
+    }
+}
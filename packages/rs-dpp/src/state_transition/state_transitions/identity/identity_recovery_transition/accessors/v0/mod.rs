@@ -0,0 +1,25 @@
+use crate::identity::recovery::GuardianSignature;
+use crate::prelude::IdentityNonce;
+use crate::state_transition::identity_recovery_transition::IdentityRecoveryOperation;
+use platform_value::Identifier;
+use std::collections::BTreeMap;
+
+pub trait IdentityRecoveryTransitionAccessorsV0 {
+    fn identity_id(&self) -> Identifier;
+    fn set_identity_id(&mut self, identity_id: Identifier);
+
+    fn operation(&self) -> &IdentityRecoveryOperation;
+    fn set_operation(&mut self, operation: IdentityRecoveryOperation);
+
+    fn nonce(&self) -> IdentityNonce;
+    fn set_nonce(&mut self, nonce: IdentityNonce);
+
+    /// The signatures of the guardians approving an initiated recovery
+    fn guardian_signatures(&self) -> &BTreeMap<Identifier, GuardianSignature>;
+    /// Adds or replaces the signature of a guardian
+    fn insert_guardian_signature(
+        &mut self,
+        guardian_id: Identifier,
+        guardian_signature: GuardianSignature,
+    );
+}
@@ -0,0 +1,17 @@
+use crate::state_transition::state_transitions;
+
+pub use state_transitions::common_fields::property_names::{
+    NONCE, SIGNATURE, SIGNATURE_PUBLIC_KEY_ID, STATE_TRANSITION_PROTOCOL_VERSION, TRANSITION_TYPE,
+};
+pub use state_transitions::identity::common_fields::property_names::IDENTITY_ID;
+
+pub(crate) mod property_names {
+    pub const OPERATION: &str = "operation";
+    pub const GUARDIAN_SIGNATURES: &str = "guardianSignatures";
+}
+
+pub use property_names::*;
+
+pub const IDENTIFIER_FIELDS: [&str; 1] = [IDENTITY_ID];
+pub const BINARY_FIELDS: [&str; 1] = [SIGNATURE];
+pub const U32_FIELDS: [&str; 1] = [STATE_TRANSITION_PROTOCOL_VERSION];
@@ -0,0 +1,27 @@
+use crate::state_transition::identity_recovery_transition::IdentityRecoveryTransition;
+use crate::state_transition::state_transitions::identity_recovery_transition::fields::*;
+use crate::state_transition::{
+    JsonStateTransitionSerializationOptions, StateTransitionJsonConvert,
+};
+use crate::ProtocolError;
+use serde_json::Number;
+use serde_json::Value as JsonValue;
+
+impl<'a> StateTransitionJsonConvert<'a> for IdentityRecoveryTransition {
+    fn to_json(
+        &self,
+        options: JsonStateTransitionSerializationOptions,
+    ) -> Result<JsonValue, ProtocolError> {
+        match self {
+            IdentityRecoveryTransition::V0(transition) => {
+                let mut value = transition.to_json(options)?;
+                let map_value = value.as_object_mut().expect("expected an object");
+                map_value.insert(
+                    STATE_TRANSITION_PROTOCOL_VERSION.to_string(),
+                    JsonValue::Number(Number::from(0)),
+                );
+                Ok(value)
+            }
+        }
+    }
+}
@@ -0,0 +1,106 @@
+mod v0;
+
+pub use v0::*;
+
+use crate::state_transition::identity_recovery_transition::IdentityRecoveryTransition;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::identity_recovery_transition::{
+    IdentityRecoveryOperation, IdentityRecoveryTransitionV0,
+};
+
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::IdentityPublicKey;
+#[cfg(feature = "state-transition-signing")]
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::StateTransition;
+#[cfg(feature = "state-transition-signing")]
+use crate::version::FeatureVersion;
+#[cfg(feature = "state-transition-signing")]
+use crate::ProtocolError;
+#[cfg(feature = "state-transition-signing")]
+use platform_value::Identifier;
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::PlatformVersion;
+
+impl IdentityRecoveryTransitionMethodsV0 for IdentityRecoveryTransition {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity_id<S: Signer>(
+        identity_id: Identifier,
+        operation: IdentityRecoveryOperation,
+        signing_key: &IdentityPublicKey,
+        nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        match feature_version.unwrap_or(
+            platform_version
+                .dpp
+                .state_transition_serialization_versions
+                .identity_recovery_state_transition
+                .default_current_version,
+        ) {
+            0 => IdentityRecoveryTransitionV0::try_from_identity_id(
+                identity_id,
+                operation,
+                signing_key,
+                nonce,
+                user_fee_increase,
+                signer,
+                platform_version,
+                feature_version,
+            ),
+            v => Err(ProtocolError::UnknownVersionError(format!(
+                "Unknown IdentityRecoveryTransition version for try_from_identity_id {v}"
+            ))),
+        }
+    }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn new_initiation(
+        identity_id: Identifier,
+        new_master_key: IdentityPublicKey,
+        nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        platform_version: &PlatformVersion,
+        feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        match feature_version.unwrap_or(
+            platform_version
+                .dpp
+                .state_transition_serialization_versions
+                .identity_recovery_state_transition
+                .default_current_version,
+        ) {
+            0 => IdentityRecoveryTransitionV0::new_initiation(
+                identity_id,
+                new_master_key,
+                nonce,
+                user_fee_increase,
+                platform_version,
+                feature_version,
+            ),
+            v => Err(ProtocolError::UnknownVersionError(format!(
+                "Unknown IdentityRecoveryTransition version for new_initiation {v}"
+            ))),
+        }
+    }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn sign_as_guardian<S: Signer>(
+        &mut self,
+        guardian_id: Identifier,
+        guardian_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<(), ProtocolError> {
+        match self {
+            IdentityRecoveryTransition::V0(transition) => {
+                transition.sign_as_guardian(guardian_id, guardian_key, signer)
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::IdentityPublicKey;
+#[cfg(feature = "state-transition-signing")]
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::identity_recovery_transition::IdentityRecoveryOperation;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::StateTransition;
+use crate::state_transition::StateTransitionType;
+#[cfg(feature = "state-transition-signing")]
+use crate::version::FeatureVersion;
+#[cfg(feature = "state-transition-signing")]
+use crate::ProtocolError;
+#[cfg(feature = "state-transition-signing")]
+use platform_value::Identifier;
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::PlatformVersion;
+
+pub trait IdentityRecoveryTransitionMethodsV0 {
+    /// Creates a new signed `IdentityRecoveryTransition` for an operation made by a key of the
+    /// identity: setting the recovery configuration or cancelling a pending recovery with a
+    /// current master key, or completing a recovery with the pending master key.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity_id` - The identifier of the identity being recovered.
+    /// * `operation` - The recovery operation, it can't be an initiation.
+    /// * `signing_key` - The master key signing the transition.
+    /// * `signer` - A reference to the `Signer` object that will sign the transition.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<StateTransition, ProtocolError>` - If successful, returns the signed transition.
+    ///   In case of any error, a relevant `ProtocolError` is returned.
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity_id<S: Signer>(
+        identity_id: Identifier,
+        operation: IdentityRecoveryOperation,
+        signing_key: &IdentityPublicKey,
+        nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError>;
+
+    /// Creates an unsigned recovery initiation scheduling `new_master_key`.
+    ///
+    /// The transition is then passed around the guardians, each of them adding their
+    /// signature with [`IdentityRecoveryTransitionMethodsV0::sign_as_guardian`].
+    #[cfg(feature = "state-transition-signing")]
+    fn new_initiation(
+        identity_id: Identifier,
+        new_master_key: IdentityPublicKey,
+        nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        platform_version: &PlatformVersion,
+        feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError>;
+
+    /// Adds the signature of a guardian approving the recovery
+    #[cfg(feature = "state-transition-signing")]
+    fn sign_as_guardian<S: Signer>(
+        &mut self,
+        guardian_id: Identifier,
+        guardian_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<(), ProtocolError>;
+
+    /// Get State Transition Type
+    fn get_type() -> StateTransitionType {
+        StateTransitionType::IdentityRecovery
+    }
+}
@@ -0,0 +1,121 @@
+pub mod accessors;
+pub mod fields;
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+pub mod methods;
+mod operation;
+mod state_transition_like;
+pub mod v0;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use crate::state_transition::identity_recovery_transition::v0::IdentityRecoveryTransitionV0Signable;
+use crate::state_transition::StateTransitionFieldTypes;
+
+use crate::identity::state_transition::OptionallyAssetLockProved;
+use bincode::{Decode, Encode};
+use derive_more::From;
+use fields::*;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize, PlatformSignable};
+use platform_versioning::PlatformVersioned;
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+pub use operation::IdentityRecoveryOperation;
+pub use v0::IdentityRecoveryTransitionV0;
+
+pub type IdentityRecoveryTransitionLatest = IdentityRecoveryTransitionV0;
+
+/// Manages the social recovery of an identity that lost its master keys.
+///
+/// The owner configures guardians and a threshold with a master key. Enough guardians can then
+/// initiate a time-locked recovery of a new master key, which the owner can cancel during the
+/// delay, and which is completed with a signature of the new master key once it has elapsed.
+#[derive(
+    Debug,
+    Clone,
+    Encode,
+    Decode,
+    PlatformDeserialize,
+    PlatformSerialize,
+    PlatformSignable,
+    PlatformVersioned,
+    From,
+    PartialEq,
+)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(tag = "$version")
+)]
+#[platform_serialize(unversioned)] //versioned directly, no need to use platform_version
+#[platform_version_path_bounds(
+    "dpp.state_transition_serialization_versions.identity_recovery_state_transition"
+)]
+pub enum IdentityRecoveryTransition {
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "0"))]
+    V0(IdentityRecoveryTransitionV0),
+}
+
+impl OptionallyAssetLockProved for IdentityRecoveryTransition {}
+
+impl StateTransitionFieldTypes for IdentityRecoveryTransition {
+    fn signature_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE, SIGNATURE_PUBLIC_KEY_ID, GUARDIAN_SIGNATURES]
+    }
+
+    fn identifiers_property_paths() -> Vec<&'static str> {
+        vec![IDENTITY_ID]
+    }
+
+    fn binary_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::state_transition::identity_recovery_transition::accessors::IdentityRecoveryTransitionAccessorsV0;
+    use crate::state_transition::{StateTransitionLike, StateTransitionType};
+    use platform_value::Identifier;
+
+    fn get_test_transition(operation: IdentityRecoveryOperation) -> IdentityRecoveryTransition {
+        IdentityRecoveryTransitionV0 {
+            identity_id: Identifier::random(),
+            operation,
+            nonce: 1,
+            ..Default::default()
+        }
+        .into()
+    }
+
+    #[test]
+    fn should_return_transition_type() {
+        let transition = get_test_transition(IdentityRecoveryOperation::Cancel);
+        assert_eq!(
+            StateTransitionType::IdentityRecovery,
+            transition.state_transition_type()
+        );
+    }
+
+    #[test]
+    fn should_return_operation_and_modified_data_ids() {
+        let transition = get_test_transition(IdentityRecoveryOperation::Complete);
+        assert_eq!(transition.operation(), &IdentityRecoveryOperation::Complete);
+        assert_eq!(
+            transition.modified_data_ids(),
+            vec![transition.identity_id()]
+        );
+    }
+
+    #[test]
+    fn is_identity_state_transition() {
+        let transition = get_test_transition(IdentityRecoveryOperation::Cancel);
+        assert!(transition.is_identity_state_transition());
+        assert!(!transition.is_data_contract_state_transition());
+        assert!(!transition.is_document_state_transition());
+    }
+}
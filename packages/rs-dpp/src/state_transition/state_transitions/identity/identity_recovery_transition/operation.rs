@@ -0,0 +1,55 @@
+use crate::identity::recovery::IdentityRecoveryConfig;
+use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
+use bincode::{Decode, Encode};
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// What an identity recovery transition does
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Default)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum IdentityRecoveryOperation {
+    /// Sets, replaces or (with `None`) removes the recovery configuration.
+    /// Signed by the owner with a master key.
+    SetConfig(Option<IdentityRecoveryConfig>),
+    /// Schedules the installation of a new master key once the configured delay has elapsed.
+    /// Approved by the guardians through their guardian signatures, the owner doesn't sign.
+    #[cfg_attr(
+        feature = "state-transition-serde-conversion",
+        serde(rename_all = "camelCase")
+    )]
+    Initiate {
+        new_master_key: IdentityPublicKeyInCreation,
+    },
+    /// Cancels the pending recovery. Signed by the owner with a master key.
+    #[default]
+    Cancel,
+    /// Installs the pending master key and disables all other master keys.
+    /// Signed by the pending master key, which proves its possession.
+    Complete,
+}
+
+impl fmt::Display for IdentityRecoveryOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdentityRecoveryOperation::SetConfig(_) => write!(f, "set config"),
+            IdentityRecoveryOperation::Initiate { .. } => write!(f, "initiate"),
+            IdentityRecoveryOperation::Cancel => write!(f, "cancel"),
+            IdentityRecoveryOperation::Complete => write!(f, "complete"),
+        }
+    }
+}
+
+impl IdentityRecoveryOperation {
+    /// Whether the operation must be signed by one of the identity's current master keys
+    pub fn is_signed_by_owner(&self) -> bool {
+        matches!(
+            self,
+            IdentityRecoveryOperation::SetConfig(_) | IdentityRecoveryOperation::Cancel
+        )
+    }
+}
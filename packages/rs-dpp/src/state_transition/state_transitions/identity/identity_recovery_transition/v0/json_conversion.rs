@@ -0,0 +1,4 @@
+use crate::state_transition::identity_recovery_transition::IdentityRecoveryTransitionV0;
+use crate::state_transition::StateTransitionJsonConvert;
+
+impl<'a> StateTransitionJsonConvert<'a> for IdentityRecoveryTransitionV0 {}
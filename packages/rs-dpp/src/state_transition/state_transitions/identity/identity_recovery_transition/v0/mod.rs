@@ -0,0 +1,160 @@
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+mod state_transition_like;
+mod types;
+pub(super) mod v0_methods;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use std::collections::BTreeMap;
+
+use crate::identity::recovery::GuardianSignature;
+use crate::identity::KeyID;
+
+use crate::prelude::{Identifier, IdentityNonce, UserFeeIncrease};
+use crate::state_transition::identity_recovery_transition::IdentityRecoveryOperation;
+
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize, PlatformSignable};
+use platform_value::BinaryData;
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug,
+    Clone,
+    Encode,
+    Decode,
+    PlatformSerialize,
+    PlatformDeserialize,
+    PlatformSignable,
+    PartialEq,
+)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[platform_serialize(unversioned)]
+#[derive(Default)]
+pub struct IdentityRecoveryTransitionV0 {
+    // Own ST fields
+    pub identity_id: Identifier,
+    pub operation: IdentityRecoveryOperation,
+    pub nonce: IdentityNonce,
+    pub user_fee_increase: UserFeeIncrease,
+    /// Guardians sign the same signable bytes as the owner would, so an initiated recovery can
+    /// gather their approvals offline in any order
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(default))]
+    #[platform_signable(exclude_from_sig_hash)]
+    pub guardian_signatures: BTreeMap<Identifier, GuardianSignature>,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature_public_key_id: KeyID,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature: BinaryData,
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::identity::recovery::{
+        GuardianSignature, IdentityRecoveryConfig, MIN_RECOVERY_DELAY,
+    };
+    use crate::serialization::{PlatformDeserializable, PlatformSerializable, Signable};
+
+    use crate::state_transition::identity_recovery_transition::v0::IdentityRecoveryTransitionV0;
+    use crate::state_transition::identity_recovery_transition::IdentityRecoveryOperation;
+    use crate::state_transition::public_key_in_creation::v0::IdentityPublicKeyInCreationV0;
+    use platform_value::{BinaryData, Identifier};
+    use rand::Rng;
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::fmt::Debug;
+
+    fn test_identity_recovery_transition<
+        T: PlatformSerializable + PlatformDeserializable + Debug + PartialEq,
+    >(
+        transition: T,
+    ) where
+        <T as PlatformSerializable>::Error: std::fmt::Debug,
+    {
+        let serialized = T::serialize_to_bytes(&transition).expect("expected to serialize");
+        let deserialized =
+            T::deserialize_from_bytes(serialized.as_slice()).expect("expected to deserialize");
+        assert_eq!(transition, deserialized);
+    }
+
+    #[test]
+    fn test_identity_recovery_transition_set_config() {
+        let mut rng = rand::thread_rng();
+        let transition = IdentityRecoveryTransitionV0 {
+            identity_id: Identifier::random(),
+            operation: IdentityRecoveryOperation::SetConfig(Some(IdentityRecoveryConfig {
+                guardians: BTreeSet::from([Identifier::random(), Identifier::random()]),
+                threshold: 2,
+                delay: MIN_RECOVERY_DELAY,
+            })),
+            nonce: 1,
+            user_fee_increase: 0,
+            guardian_signatures: BTreeMap::new(),
+            signature_public_key_id: rng.gen(),
+            signature: [0; 65].to_vec().into(),
+        };
+
+        test_identity_recovery_transition(transition);
+    }
+
+    #[test]
+    fn test_identity_recovery_transition_initiate() {
+        let transition = IdentityRecoveryTransitionV0 {
+            identity_id: Identifier::random(),
+            operation: IdentityRecoveryOperation::Initiate {
+                new_master_key: IdentityPublicKeyInCreationV0::default().into(),
+            },
+            nonce: 3,
+            user_fee_increase: 0,
+            guardian_signatures: BTreeMap::from([(
+                Identifier::random(),
+                GuardianSignature {
+                    public_key_id: 1,
+                    signature: BinaryData::new(vec![1; 65]),
+                },
+            )]),
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        };
+
+        test_identity_recovery_transition(transition);
+    }
+
+    #[test]
+    fn guardian_signatures_should_not_change_signable_bytes() {
+        let mut transition = IdentityRecoveryTransitionV0 {
+            identity_id: Identifier::random(),
+            operation: IdentityRecoveryOperation::Complete,
+            nonce: 2,
+            ..Default::default()
+        };
+
+        let signable_bytes = transition
+            .signable_bytes()
+            .expect("expected signable bytes");
+
+        transition.guardian_signatures.insert(
+            Identifier::random(),
+            GuardianSignature {
+                public_key_id: 0,
+                signature: BinaryData::new(vec![2; 65]),
+            },
+        );
+
+        assert_eq!(
+            transition
+                .signable_bytes()
+                .expect("expected signable bytes"),
+            signable_bytes
+        );
+    }
+}
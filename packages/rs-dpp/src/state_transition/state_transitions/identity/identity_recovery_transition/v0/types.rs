@@ -0,0 +1,17 @@
+use crate::state_transition::identity_recovery_transition::fields::*;
+use crate::state_transition::identity_recovery_transition::IdentityRecoveryTransitionV0;
+use crate::state_transition::StateTransitionFieldTypes;
+
+impl StateTransitionFieldTypes for IdentityRecoveryTransitionV0 {
+    fn signature_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE, SIGNATURE_PUBLIC_KEY_ID, GUARDIAN_SIGNATURES]
+    }
+
+    fn identifiers_property_paths() -> Vec<&'static str> {
+        vec![IDENTITY_ID]
+    }
+
+    fn binary_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE]
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::recovery::GuardianSignature;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::signer::Signer;
+#[cfg(feature = "state-transition-signing")]
+use crate::identity::IdentityPublicKey;
+#[cfg(feature = "state-transition-signing")]
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+#[cfg(feature = "state-transition-signing")]
+use crate::serialization::Signable;
+use crate::state_transition::identity_recovery_transition::methods::IdentityRecoveryTransitionMethodsV0;
+use crate::state_transition::identity_recovery_transition::v0::IdentityRecoveryTransitionV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::identity_recovery_transition::IdentityRecoveryOperation;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::{GetDataContractSecurityLevelRequirementFn, StateTransition};
+#[cfg(feature = "state-transition-signing")]
+use crate::version::FeatureVersion;
+#[cfg(feature = "state-transition-signing")]
+use crate::{NonConsensusError, ProtocolError};
+#[cfg(feature = "state-transition-signing")]
+use platform_value::Identifier;
+#[cfg(feature = "state-transition-signing")]
+use platform_version::version::PlatformVersion;
+
+impl IdentityRecoveryTransitionMethodsV0 for IdentityRecoveryTransitionV0 {
+    #[cfg(feature = "state-transition-signing")]
+    fn try_from_identity_id<S: Signer>(
+        identity_id: Identifier,
+        operation: IdentityRecoveryOperation,
+        signing_key: &IdentityPublicKey,
+        nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        _platform_version: &PlatformVersion,
+        _feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        if matches!(operation, IdentityRecoveryOperation::Initiate { .. }) {
+            return Err(ProtocolError::NonConsensusError(
+                NonConsensusError::StateTransitionCreationError(
+                    "a recovery initiation is signed by the guardians".to_string(),
+                ),
+            ));
+        }
+
+        let mut transition: StateTransition = IdentityRecoveryTransitionV0 {
+            identity_id,
+            operation,
+            nonce,
+            user_fee_increase,
+            guardian_signatures: Default::default(),
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+
+        transition.sign_external(
+            signing_key,
+            signer,
+            None::<GetDataContractSecurityLevelRequirementFn>,
+        )?;
+
+        Ok(transition)
+    }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn new_initiation(
+        identity_id: Identifier,
+        new_master_key: IdentityPublicKey,
+        nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        _platform_version: &PlatformVersion,
+        _feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let new_master_key: IdentityPublicKeyInCreation = new_master_key.into();
+
+        Ok(IdentityRecoveryTransitionV0 {
+            identity_id,
+            operation: IdentityRecoveryOperation::Initiate { new_master_key },
+            nonce,
+            user_fee_increase,
+            guardian_signatures: Default::default(),
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into())
+    }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn sign_as_guardian<S: Signer>(
+        &mut self,
+        guardian_id: Identifier,
+        guardian_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<(), ProtocolError> {
+        let signature = signer.sign(guardian_key, &self.signable_bytes()?)?;
+        self.guardian_signatures.insert(
+            guardian_id,
+            GuardianSignature {
+                public_key_id: guardian_key.id(),
+                signature,
+            },
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+use crate::state_transition::identity_recovery_transition::IdentityRecoveryTransitionV0;
+use crate::state_transition::FeatureVersioned;
+use crate::version::FeatureVersion;
+
+impl FeatureVersioned for IdentityRecoveryTransitionV0 {
+    fn feature_version(&self) -> FeatureVersion {
+        0
+    }
+}
@@ -0,0 +1,11 @@
+use crate::state_transition::identity_recovery_transition::IdentityRecoveryTransition;
+use crate::state_transition::FeatureVersioned;
+use crate::version::FeatureVersion;
+
+impl FeatureVersioned for IdentityRecoveryTransition {
+    fn feature_version(&self) -> FeatureVersion {
+        match self {
+            IdentityRecoveryTransition::V0(v0) => v0.feature_version(),
+        }
+    }
+}
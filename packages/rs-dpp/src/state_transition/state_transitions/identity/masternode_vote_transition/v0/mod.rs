@@ -56,8 +56,14 @@ mod test {
 
     use crate::state_transition::masternode_vote_transition::v0::MasternodeVoteTransitionV0;
     use crate::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+    use crate::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
     use crate::voting::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePoll;
+    use crate::voting::vote_polls::governance_proposal_vote_poll::{
+        GovernanceProposal, GovernanceProposalVotePoll,
+    };
     use crate::voting::vote_polls::VotePoll;
+    use crate::voting::votes::governance_proposal_vote::v0::GovernanceProposalVoteV0;
+    use crate::voting::votes::governance_proposal_vote::GovernanceProposalVote;
     use crate::voting::votes::resource_vote::v0::ResourceVoteV0;
     use crate::voting::votes::resource_vote::ResourceVote;
     use crate::voting::votes::Vote;
@@ -102,4 +108,31 @@ mod test {
 
         test_masternode_vote_transition(transition);
     }
+
+    #[test]
+    fn test_masternode_vote_transition_on_governance_proposal() {
+        let mut rng = rand::thread_rng();
+        let transition = MasternodeVoteTransitionV0 {
+            pro_tx_hash: Identifier::random(),
+            voter_identity_id: Identifier::random(),
+            vote: Vote::GovernanceProposalVote(GovernanceProposalVote::V0(
+                GovernanceProposalVoteV0 {
+                    vote_poll: GovernanceProposalVotePoll {
+                        proposal: GovernanceProposal::SystemContractUpdate {
+                            contract_id: Identifier::random(),
+                            contract_hash: [3; 32],
+                        },
+                        description_hash: [7; 32],
+                        end_date: 1_000_000,
+                    },
+                    vote_choice: YesNoAbstainVoteChoice::YES,
+                },
+            )),
+            nonce: 1,
+            signature_public_key_id: rng.gen(),
+            signature: [0; 65].to_vec().into(),
+        };
+
+        test_masternode_vote_transition(transition);
+    }
 }
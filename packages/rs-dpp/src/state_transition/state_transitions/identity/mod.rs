@@ -1,7 +1,10 @@
+pub mod auction_bid_transition;
 mod common_fields;
 pub mod identity_create_transition;
+pub mod identity_credit_batch_transfer_transition;
 pub mod identity_credit_transfer_transition;
 pub mod identity_credit_withdrawal_transition;
+pub mod identity_recovery_transition;
 pub mod identity_topup_transition;
 pub mod identity_update_transition;
 pub mod masternode_vote_transition;
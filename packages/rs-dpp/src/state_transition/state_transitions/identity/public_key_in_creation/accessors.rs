@@ -1,5 +1,5 @@
 use crate::identity::contract_bounds::ContractBounds;
-use crate::identity::{KeyID, KeyType, Purpose, SecurityLevel};
+use crate::identity::{KeyID, KeyType, Purpose, SecurityLevel, TimestampMillis};
 use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
 use platform_value::BinaryData;
 
@@ -45,52 +45,72 @@ pub trait IdentityPublicKeyInCreationV0Setters {
     fn set_read_only(&mut self, read_only: bool);
 }
 
+/// Trait providing getters for the usage policy of `IdentityPublicKeyInCreationV1`.
+pub trait IdentityPublicKeyInCreationV1Getters {
+    /// Returns the time from which the key can be used, if bounded.
+    fn valid_from(&self) -> Option<TimestampMillis>;
+
+    /// Returns the time from which the key can no longer be used, if bounded.
+    fn valid_until(&self) -> Option<TimestampMillis>;
+
+    /// Returns how many distinct keys must sign with this key, if more than this key alone.
+    fn signature_threshold(&self) -> Option<u8>;
+}
+
 impl IdentityPublicKeyInCreationV0Setters for IdentityPublicKeyInCreation {
     fn set_signature(&mut self, signature: BinaryData) {
         match self {
             IdentityPublicKeyInCreation::V0(v0) => v0.signature = signature,
+            IdentityPublicKeyInCreation::V1(v1) => v1.signature = signature,
         }
     }
 
     fn set_id(&mut self, id: KeyID) {
         match self {
             IdentityPublicKeyInCreation::V0(v0) => v0.id = id,
+            IdentityPublicKeyInCreation::V1(v1) => v1.id = id,
         }
     }
 
     fn set_type(&mut self, key_type: KeyType) {
         match self {
             IdentityPublicKeyInCreation::V0(v0) => v0.key_type = key_type,
+            IdentityPublicKeyInCreation::V1(v1) => v1.key_type = key_type,
         }
     }
 
     fn set_data(&mut self, data: BinaryData) {
         match self {
             IdentityPublicKeyInCreation::V0(v0) => v0.data = data,
+            IdentityPublicKeyInCreation::V1(v1) => v1.data = data,
         }
     }
 
     fn set_purpose(&mut self, purpose: Purpose) {
         match self {
             IdentityPublicKeyInCreation::V0(v0) => v0.purpose = purpose,
+            IdentityPublicKeyInCreation::V1(v1) => v1.purpose = purpose,
         }
     }
 
     fn set_security_level(&mut self, security_level: SecurityLevel) {
         match self {
             IdentityPublicKeyInCreation::V0(v0) => v0.security_level = security_level,
+            IdentityPublicKeyInCreation::V1(v1) => v1.security_level = security_level,
         }
     }
 
     fn set_contract_bounds(&mut self, contract_bounds: Option<ContractBounds>) {
         match self {
             IdentityPublicKeyInCreation::V0(v0) => v0.contract_bounds = contract_bounds,
+            IdentityPublicKeyInCreation::V1(v1) => v1.contract_bounds = contract_bounds,
         }
     }
 
     fn set_read_only(&mut self, read_only: bool) {
         match self {
             IdentityPublicKeyInCreation::V0(v0) => v0.read_only = read_only,
+            IdentityPublicKeyInCreation::V1(v1) => v1.read_only = read_only,
         }
     }
 }
@@ -100,48 +120,79 @@ impl IdentityPublicKeyInCreationV0Getters for IdentityPublicKeyInCreation {
     fn id(&self) -> KeyID {
         match self {
             IdentityPublicKeyInCreation::V0(v0) => v0.id,
+            IdentityPublicKeyInCreation::V1(v1) => v1.id,
         }
     }
 
     fn key_type(&self) -> KeyType {
         match self {
             IdentityPublicKeyInCreation::V0(v0) => v0.key_type,
+            IdentityPublicKeyInCreation::V1(v1) => v1.key_type,
         }
     }
 
     fn purpose(&self) -> Purpose {
         match self {
             IdentityPublicKeyInCreation::V0(v0) => v0.purpose,
+            IdentityPublicKeyInCreation::V1(v1) => v1.purpose,
         }
     }
 
     fn security_level(&self) -> SecurityLevel {
         match self {
             IdentityPublicKeyInCreation::V0(v0) => v0.security_level,
+            IdentityPublicKeyInCreation::V1(v1) => v1.security_level,
         }
     }
 
     fn read_only(&self) -> bool {
         match self {
             IdentityPublicKeyInCreation::V0(v0) => v0.read_only,
+            IdentityPublicKeyInCreation::V1(v1) => v1.read_only,
         }
     }
 
     fn data(&self) -> &BinaryData {
         match self {
             IdentityPublicKeyInCreation::V0(v0) => &v0.data,
+            IdentityPublicKeyInCreation::V1(v1) => &v1.data,
         }
     }
 
     fn signature(&self) -> &BinaryData {
         match self {
             IdentityPublicKeyInCreation::V0(v0) => &v0.signature,
+            IdentityPublicKeyInCreation::V1(v1) => &v1.signature,
         }
     }
 
     fn contract_bounds(&self) -> Option<&ContractBounds> {
         match self {
             IdentityPublicKeyInCreation::V0(v0) => v0.contract_bounds.as_ref(),
+            IdentityPublicKeyInCreation::V1(v1) => v1.contract_bounds.as_ref(),
+        }
+    }
+}
+
+impl IdentityPublicKeyInCreationV1Getters for IdentityPublicKeyInCreation {
+    fn valid_from(&self) -> Option<TimestampMillis> {
+        match self {
+            IdentityPublicKeyInCreation::V0(_) => None,
+            IdentityPublicKeyInCreation::V1(v1) => v1.valid_from,
+        }
+    }
+
+    fn valid_until(&self) -> Option<TimestampMillis> {
+        match self {
+            IdentityPublicKeyInCreation::V0(_) => None,
+            IdentityPublicKeyInCreation::V1(v1) => v1.valid_until,
+        }
+    }
+
+    fn signature_threshold(&self) -> Option<u8> {
+        match self {
+            IdentityPublicKeyInCreation::V0(_) => None,
+            IdentityPublicKeyInCreation::V1(v1) => v1.signature_threshold,
         }
     }
 }
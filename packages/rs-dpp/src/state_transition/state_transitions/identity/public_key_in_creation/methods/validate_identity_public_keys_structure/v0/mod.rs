@@ -1,19 +1,24 @@
 use crate::consensus::basic::identity::{
     DuplicatedIdentityPublicKeyBasicError, DuplicatedIdentityPublicKeyIdBasicError,
-    InvalidIdentityPublicKeySecurityLevelError, MissingMasterPublicKeyError,
+    InvalidIdentityPublicKeySecurityLevelError, InvalidIdentityPublicKeySignatureThresholdError,
+    InvalidIdentityPublicKeyValidityWindowError, MissingMasterPublicKeyError,
     TooManyMasterPublicKeyError,
 };
-use crate::consensus::basic::BasicError;
+use crate::consensus::basic::{BasicError, UnsupportedVersionError};
+use crate::consensus::ConsensusError;
 use lazy_static::lazy_static;
 use std::collections::HashMap;
 
 use crate::consensus::state::identity::max_identity_public_key_limit_reached_error::MaxIdentityPublicKeyLimitReachedError;
 
 use crate::consensus::state::state_error::StateError;
-use crate::identity::{Purpose, SecurityLevel};
+use crate::identity::{Purpose, SecurityLevel, MAX_SIGNATURE_THRESHOLD};
 
-use crate::state_transition::public_key_in_creation::accessors::IdentityPublicKeyInCreationV0Getters;
+use crate::state_transition::public_key_in_creation::accessors::{
+    IdentityPublicKeyInCreationV0Getters, IdentityPublicKeyInCreationV1Getters,
+};
 use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
+use crate::state_transition::FeatureVersioned;
 use crate::validation::SimpleConsensusValidationResult;
 use crate::ProtocolError;
 use platform_version::version::PlatformVersion;
@@ -67,6 +72,25 @@ impl IdentityPublicKeyInCreation {
             ));
         }
 
+        // Keys can only be created in the versions the current protocol version supports
+        let key_version_bounds = &platform_version
+            .dpp
+            .state_transition_serialization_versions
+            .identity_public_key_in_creation;
+        if let Some(key) = identity_public_keys_with_witness
+            .iter()
+            .find(|key| !key_version_bounds.check_version(key.feature_version()))
+        {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                UnsupportedVersionError::new(
+                    key.feature_version(),
+                    key_version_bounds.min_version,
+                    key_version_bounds.max_version,
+                )
+                .into(),
+            ));
+        }
+
         // Check that there's not duplicates key ids in the state transition
         let duplicated_ids = IdentityPublicKeyInCreation::duplicated_key_ids_witness(
             identity_public_keys_with_witness,
@@ -116,7 +140,7 @@ impl IdentityPublicKeyInCreation {
         }
 
         // We should check all the security levels
-        let validation_errors = identity_public_keys_with_witness
+        let mut validation_errors: Vec<ConsensusError> = identity_public_keys_with_witness
             .iter()
             .filter_map(|identity_public_key| {
                 let allowed_security_levels =
@@ -148,8 +172,190 @@ impl IdentityPublicKeyInCreation {
                 }
             })
             .collect();
+
+        // Keys with a validity window must be usable at some point, and master keys can't
+        // expire as that would lock the identity out of its own updates
+        validation_errors.extend(identity_public_keys_with_witness.iter().filter_map(
+            |identity_public_key| {
+                let valid_from = identity_public_key.valid_from();
+                let valid_until = identity_public_key.valid_until();
+                if valid_from.is_none() && valid_until.is_none() {
+                    return None;
+                }
+                let reason = match (valid_from, valid_until) {
+                    _ if identity_public_key.security_level() == SecurityLevel::MASTER => {
+                        "Master keys can not have a validity window"
+                    }
+                    (Some(from), Some(until)) if until <= from => {
+                        "The key must become invalid after it becomes valid"
+                    }
+                    _ => return None,
+                };
+                Some(
+                    InvalidIdentityPublicKeyValidityWindowError::new(
+                        identity_public_key.id(),
+                        valid_from,
+                        valid_until,
+                        reason.to_string(),
+                    )
+                    .into(),
+                )
+            },
+        ));
+
+        // A threshold of one is the same as no threshold, so it must be left out
+        validation_errors.extend(identity_public_keys_with_witness.iter().filter_map(
+            |identity_public_key| {
+                let threshold = identity_public_key.signature_threshold()?;
+                if (2..=MAX_SIGNATURE_THRESHOLD).contains(&threshold) {
+                    return None;
+                }
+                Some(
+                    InvalidIdentityPublicKeySignatureThresholdError::new(
+                        identity_public_key.id(),
+                        threshold,
+                        MAX_SIGNATURE_THRESHOLD,
+                    )
+                    .into(),
+                )
+            },
+        ));
+
         Ok(SimpleConsensusValidationResult::new_with_errors(
             validation_errors,
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identity::KeyType;
+    use crate::state_transition::public_key_in_creation::v1::IdentityPublicKeyInCreationV1;
+    use platform_value::BinaryData;
+
+    fn key_with_window(
+        id: u32,
+        security_level: SecurityLevel,
+        valid_from: Option<u64>,
+        valid_until: Option<u64>,
+    ) -> IdentityPublicKeyInCreation {
+        IdentityPublicKeyInCreationV1 {
+            id,
+            key_type: KeyType::ECDSA_HASH160,
+            purpose: Purpose::AUTHENTICATION,
+            security_level,
+            contract_bounds: None,
+            read_only: false,
+            data: BinaryData::new(vec![id as u8; 20]),
+            valid_from,
+            valid_until,
+            signature_threshold: None,
+            signature: BinaryData::default(),
+        }
+        .into()
+    }
+
+    fn key_with_threshold(id: u32, signature_threshold: u8) -> IdentityPublicKeyInCreation {
+        let mut key = key_with_window(id, SecurityLevel::HIGH, None, None);
+        let IdentityPublicKeyInCreation::V1(v1) = &mut key else {
+            unreachable!()
+        };
+        v1.signature_threshold = Some(signature_threshold);
+        key
+    }
+
+    #[test]
+    fn should_reject_keys_in_versions_the_protocol_does_not_support() {
+        let platform_version = PlatformVersion::first();
+        let keys = vec![key_with_window(1, SecurityLevel::HIGH, None, None)];
+
+        let result = IdentityPublicKeyInCreation::validate_identity_public_keys_structure_v0(
+            &keys,
+            false,
+            platform_version,
+        )
+        .expect("expected to validate keys");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::BasicError(BasicError::UnsupportedVersionError(e))] if e.received_version() == 1
+        ));
+    }
+
+    #[test]
+    fn should_accept_keys_with_a_valid_window() {
+        let platform_version = PlatformVersion::latest();
+        let keys = vec![
+            key_with_window(1, SecurityLevel::HIGH, Some(1_000), Some(2_000)),
+            key_with_window(2, SecurityLevel::HIGH, None, Some(2_000)),
+        ];
+
+        let result = IdentityPublicKeyInCreation::validate_identity_public_keys_structure_v0(
+            &keys,
+            false,
+            platform_version,
+        )
+        .expect("expected to validate keys");
+
+        assert!(result.is_valid());
+    }
+
+    #[test]
+    fn should_reject_empty_windows_and_expiring_master_keys() {
+        let platform_version = PlatformVersion::latest();
+        let keys = vec![
+            key_with_window(1, SecurityLevel::HIGH, Some(2_000), Some(2_000)),
+            key_with_window(2, SecurityLevel::MASTER, None, Some(2_000)),
+        ];
+
+        let result = IdentityPublicKeyInCreation::validate_identity_public_keys_structure_v0(
+            &keys,
+            false,
+            platform_version,
+        )
+        .expect("expected to validate keys");
+
+        let key_ids: Vec<u32> = result
+            .errors
+            .iter()
+            .map(|error| match error {
+                ConsensusError::BasicError(
+                    BasicError::InvalidIdentityPublicKeyValidityWindowError(e),
+                ) => e.public_key_id(),
+                e => panic!("unexpected error {e}"),
+            })
+            .collect();
+        assert_eq!(key_ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn should_reject_signature_thresholds_out_of_range() {
+        let platform_version = PlatformVersion::latest();
+        let keys = vec![
+            key_with_threshold(1, 2),
+            key_with_threshold(2, 1),
+            key_with_threshold(3, MAX_SIGNATURE_THRESHOLD),
+            key_with_threshold(4, MAX_SIGNATURE_THRESHOLD + 1),
+        ];
+
+        let result = IdentityPublicKeyInCreation::validate_identity_public_keys_structure_v0(
+            &keys,
+            false,
+            platform_version,
+        )
+        .expect("expected to validate keys");
+
+        let key_ids: Vec<u32> = result
+            .errors
+            .iter()
+            .map(|error| match error {
+                ConsensusError::BasicError(
+                    BasicError::InvalidIdentityPublicKeySignatureThresholdError(e),
+                ) => e.public_key_id(),
+                e => panic!("unexpected error {e}"),
+            })
+            .collect();
+        assert_eq!(key_ids, vec![2, 4]);
+    }
+}
@@ -1,6 +1,8 @@
 use crate::identity::IdentityPublicKey;
 use crate::state_transition::public_key_in_creation::v0::IdentityPublicKeyInCreationV0;
 use crate::state_transition::public_key_in_creation::v0::IdentityPublicKeyInCreationV0Signable;
+use crate::state_transition::public_key_in_creation::v1::IdentityPublicKeyInCreationV1;
+use crate::state_transition::public_key_in_creation::v1::IdentityPublicKeyInCreationV1Signable;
 use crate::ProtocolError;
 use bincode::{Decode, Encode};
 use derive_more::From;
@@ -17,6 +19,7 @@ mod json_conversion;
 mod methods;
 mod types;
 pub mod v0;
+pub mod v1;
 #[cfg(feature = "state-transition-value-conversion")]
 mod value_conversion;
 mod version;
@@ -32,6 +35,8 @@ mod version;
 pub enum IdentityPublicKeyInCreation {
     #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "0"))]
     V0(IdentityPublicKeyInCreationV0),
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "1"))]
+    V1(IdentityPublicKeyInCreationV1),
 }
 
 impl IdentityPublicKeyInCreation {
@@ -42,9 +47,10 @@ impl IdentityPublicKeyInCreation {
             .identity_key_structure_version
         {
             0 => Ok(IdentityPublicKeyInCreationV0::default().into()),
+            1 => Ok(IdentityPublicKeyInCreationV1::default().into()),
             version => Err(ProtocolError::UnknownVersionMismatch {
                 method: "IdentityPublicKeyInCreation::default_versioned".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             }),
         }
@@ -55,6 +61,7 @@ impl From<&IdentityPublicKeyInCreation> for IdentityPublicKey {
     fn from(val: &IdentityPublicKeyInCreation) -> Self {
         match val {
             IdentityPublicKeyInCreation::V0(v0) => v0.into(),
+            IdentityPublicKeyInCreation::V1(v1) => v1.into(),
         }
     }
 }
@@ -63,6 +70,7 @@ impl From<IdentityPublicKeyInCreation> for IdentityPublicKey {
     fn from(val: IdentityPublicKeyInCreation) -> Self {
         match val {
             IdentityPublicKeyInCreation::V0(v0) => v0.into(),
+            IdentityPublicKeyInCreation::V1(v1) => v1.into(),
         }
     }
 }
@@ -74,6 +82,10 @@ impl From<IdentityPublicKey> for IdentityPublicKeyInCreation {
                 let v0: IdentityPublicKeyInCreationV0 = val.into();
                 v0.into()
             }
+            IdentityPublicKey::V1(ref v1) => {
+                let v1: IdentityPublicKeyInCreationV1 = v1.into();
+                v1.into()
+            }
         }
     }
 }
@@ -85,6 +97,10 @@ impl From<&IdentityPublicKey> for IdentityPublicKeyInCreation {
                 let v0: IdentityPublicKeyInCreationV0 = val.into();
                 v0.into()
             }
+            IdentityPublicKey::V1(v1) => {
+                let v1: IdentityPublicKeyInCreationV1 = v1.into();
+                v1.into()
+            }
         }
     }
 }
@@ -0,0 +1,4 @@
+use crate::state_transition::public_key_in_creation::v1::IdentityPublicKeyInCreationV1;
+use crate::state_transition::StateTransitionJsonConvert;
+
+impl<'a> StateTransitionJsonConvert<'a> for IdentityPublicKeyInCreationV1 {}
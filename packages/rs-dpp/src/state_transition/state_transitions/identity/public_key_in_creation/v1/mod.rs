@@ -0,0 +1,193 @@
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+mod types;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use crate::identity::{IdentityPublicKey, KeyID, KeyType, Purpose, SecurityLevel, TimestampMillis};
+
+use std::convert::TryFrom;
+
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+use platform_value::{BinaryData, Value};
+
+use crate::errors::ProtocolError;
+
+use crate::identity::contract_bounds::ContractBounds;
+use platform_serialization_derive::PlatformSignable;
+
+use crate::identity::identity_public_key::v1::IdentityPublicKeyV1;
+
+use crate::state_transition::public_key_in_creation::accessors::{
+    IdentityPublicKeyInCreationV0Getters, IdentityPublicKeyInCreationV0Setters,
+    IdentityPublicKeyInCreationV1Getters,
+};
+use crate::state_transition::public_key_in_creation::methods::IdentityPublicKeyInCreationMethodsV0;
+
+/// A public key with a usage policy being added to an identity
+#[derive(
+    Default, Debug, Serialize, Deserialize, Encode, Decode, PlatformSignable, Clone, PartialEq, Eq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityPublicKeyInCreationV1 {
+    pub id: KeyID,
+    #[serde(rename = "type")]
+    pub key_type: KeyType,
+    pub purpose: Purpose,
+    pub security_level: SecurityLevel,
+    pub contract_bounds: Option<ContractBounds>,
+    pub read_only: bool,
+    pub data: BinaryData,
+    /// The key can't be used before this block time
+    #[serde(default)]
+    pub valid_from: Option<TimestampMillis>,
+    /// The key can't be used at or after this block time
+    #[serde(default)]
+    pub valid_until: Option<TimestampMillis>,
+    /// How many distinct keys, this one included, must sign state transitions signed with this key
+    #[serde(default)]
+    pub signature_threshold: Option<u8>,
+    /// The signature is needed for ECDSA_SECP256K1 Key type and BLS12_381 Key type
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature: BinaryData,
+}
+
+impl IdentityPublicKeyInCreationV0Getters for IdentityPublicKeyInCreationV1 {
+    fn id(&self) -> KeyID {
+        self.id
+    }
+
+    fn key_type(&self) -> KeyType {
+        self.key_type
+    }
+
+    fn purpose(&self) -> Purpose {
+        self.purpose
+    }
+
+    fn security_level(&self) -> SecurityLevel {
+        self.security_level
+    }
+
+    fn read_only(&self) -> bool {
+        self.read_only
+    }
+
+    fn data(&self) -> &BinaryData {
+        &self.data
+    }
+
+    fn signature(&self) -> &BinaryData {
+        &self.signature
+    }
+
+    fn contract_bounds(&self) -> Option<&ContractBounds> {
+        self.contract_bounds.as_ref()
+    }
+}
+
+impl IdentityPublicKeyInCreationV0Setters for IdentityPublicKeyInCreationV1 {
+    fn set_signature(&mut self, signature: BinaryData) {
+        self.signature = signature
+    }
+
+    fn set_data(&mut self, data: BinaryData) {
+        self.data = data
+    }
+
+    fn set_id(&mut self, id: KeyID) {
+        self.id = id
+    }
+
+    fn set_type(&mut self, key_type: KeyType) {
+        self.key_type = key_type;
+    }
+
+    fn set_security_level(&mut self, security_level: SecurityLevel) {
+        self.security_level = security_level;
+    }
+
+    fn set_contract_bounds(&mut self, contract_bounds: Option<ContractBounds>) {
+        self.contract_bounds = contract_bounds;
+    }
+
+    fn set_purpose(&mut self, purpose: Purpose) {
+        self.purpose = purpose;
+    }
+
+    fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+    }
+}
+
+impl IdentityPublicKeyInCreationV1Getters for IdentityPublicKeyInCreationV1 {
+    fn valid_from(&self) -> Option<TimestampMillis> {
+        self.valid_from
+    }
+
+    fn valid_until(&self) -> Option<TimestampMillis> {
+        self.valid_until
+    }
+
+    fn signature_threshold(&self) -> Option<u8> {
+        self.signature_threshold
+    }
+}
+
+impl IdentityPublicKeyInCreationMethodsV0 for IdentityPublicKeyInCreationV1 {
+    fn into_identity_public_key(self) -> IdentityPublicKey {
+        self.into()
+    }
+}
+
+impl From<IdentityPublicKeyInCreationV1> for IdentityPublicKey {
+    fn from(val: IdentityPublicKeyInCreationV1) -> Self {
+        IdentityPublicKeyV1 {
+            id: val.id,
+            purpose: val.purpose,
+            security_level: val.security_level,
+            contract_bounds: val.contract_bounds,
+            key_type: val.key_type,
+            read_only: val.read_only,
+            data: val.data,
+            disabled_at: None,
+            valid_from: val.valid_from,
+            valid_until: val.valid_until,
+            signature_threshold: val.signature_threshold,
+        }
+        .into()
+    }
+}
+
+impl From<&IdentityPublicKeyInCreationV1> for IdentityPublicKey {
+    fn from(val: &IdentityPublicKeyInCreationV1) -> Self {
+        val.clone().into()
+    }
+}
+
+impl TryFrom<Value> for IdentityPublicKeyInCreationV1 {
+    type Error = platform_value::Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        platform_value::from_value(value)
+    }
+}
+
+impl TryFrom<IdentityPublicKeyInCreationV1> for Value {
+    type Error = platform_value::Error;
+
+    fn try_from(value: IdentityPublicKeyInCreationV1) -> Result<Self, Self::Error> {
+        platform_value::to_value(value)
+    }
+}
+
+impl TryFrom<&IdentityPublicKeyInCreationV1> for Value {
+    type Error = platform_value::Error;
+
+    fn try_from(value: &IdentityPublicKeyInCreationV1) -> Result<Self, Self::Error> {
+        platform_value::to_value(value)
+    }
+}
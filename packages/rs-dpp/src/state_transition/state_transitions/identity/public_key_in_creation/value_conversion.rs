@@ -1,6 +1,7 @@
 use crate::serialization::ValueConvertible;
 use crate::state_transition::documents_batch_transition::fields::property_names::STATE_TRANSITION_PROTOCOL_VERSION;
 use crate::state_transition::public_key_in_creation::v0::IdentityPublicKeyInCreationV0;
+use crate::state_transition::public_key_in_creation::v1::IdentityPublicKeyInCreationV1;
 use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
 use crate::state_transition::StateTransitionValueConvert;
 use crate::ProtocolError;
@@ -19,6 +20,11 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityPublicKeyInCreation {
                 value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(0))?;
                 Ok(value)
             }
+            IdentityPublicKeyInCreation::V1(public_key) => {
+                let mut value = public_key.to_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(1))?;
+                Ok(value)
+            }
         }
     }
 
@@ -29,6 +35,11 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityPublicKeyInCreation {
                 value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(0))?;
                 Ok(value)
             }
+            IdentityPublicKeyInCreation::V1(public_key) => {
+                let mut value = public_key.to_canonical_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(1))?;
+                Ok(value)
+            }
         }
     }
 
@@ -39,6 +50,11 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityPublicKeyInCreation {
                 value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(0))?;
                 Ok(value)
             }
+            IdentityPublicKeyInCreation::V1(public_key) => {
+                let mut value = public_key.to_canonical_cleaned_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(1))?;
+                Ok(value)
+            }
         }
     }
 
@@ -49,6 +65,11 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityPublicKeyInCreation {
                 value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(0))?;
                 Ok(value)
             }
+            IdentityPublicKeyInCreation::V1(public_key) => {
+                let mut value = public_key.to_cleaned_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(1))?;
+                Ok(value)
+            }
         }
     }
 
@@ -71,6 +92,9 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityPublicKeyInCreation {
             0 => Ok(
                 IdentityPublicKeyInCreationV0::from_object(raw_object, platform_version)?.into(),
             ),
+            1 => Ok(
+                IdentityPublicKeyInCreationV1::from_object(raw_object, platform_version)?.into(),
+            ),
             n => Err(ProtocolError::UnknownVersionError(format!(
                 "Unknown IdentityPublicKeyInCreation version {n}"
             ))),
@@ -98,6 +122,11 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityPublicKeyInCreation {
                 platform_version,
             )?
             .into()),
+            1 => Ok(IdentityPublicKeyInCreationV1::from_value_map(
+                raw_value_map,
+                platform_version,
+            )?
+            .into()),
             n => Err(ProtocolError::UnknownVersionError(format!(
                 "Unknown IdentityPublicKeyInCreation version {n}"
             ))),
@@ -111,6 +140,7 @@ impl<'a> StateTransitionValueConvert<'a> for IdentityPublicKeyInCreation {
 
         match version {
             0 => IdentityPublicKeyInCreationV0::clean_value(value),
+            1 => IdentityPublicKeyInCreationV1::clean_value(value),
             n => Err(ProtocolError::UnknownVersionError(format!(
                 "Unknown IdentityPublicKeyInCreation version {n}"
             ))),
@@ -0,0 +1,59 @@
+use crate::identity::KeyID;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::BinaryData;
+use std::collections::BTreeMap;
+
+/// The signatures of a threshold key and of its co-signers.
+///
+/// When the key referenced by a state transition's `signature_public_key_id` has a signature
+/// threshold, the transition's signature holds this container serialized instead of a single
+/// signature. Every key signs the same signable bytes, which don't cover the signature and the
+/// key id, so partial signatures can be gathered offline in any order.
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[platform_serialize(limit = 2000, unversioned)]
+pub struct ThresholdSignature {
+    pub signatures: BTreeMap<KeyID, BinaryData>,
+}
+
+impl ThresholdSignature {
+    /// Returns the signature made by the given key, if any
+    pub fn signature_for(&self, key_id: KeyID) -> Option<&BinaryData> {
+        self.signatures.get(&key_id)
+    }
+
+    /// Adds or replaces the signature made by the given key
+    pub fn insert_signature(&mut self, key_id: KeyID, signature: BinaryData) {
+        self.signatures.insert(key_id, signature);
+    }
+
+    /// The amount of keys that signed
+    pub fn signer_count(&self) -> usize {
+        self.signatures.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serialization::{PlatformDeserializable, PlatformSerializable};
+
+    #[test]
+    fn should_serialize_and_deserialize_threshold_signature() {
+        let mut threshold_signature = ThresholdSignature::default();
+        threshold_signature.insert_signature(1, BinaryData::new(vec![1; 65]));
+        threshold_signature.insert_signature(4, BinaryData::new(vec![4; 96]));
+
+        let bytes = threshold_signature
+            .serialize_to_bytes()
+            .expect("expected to serialize");
+        let restored =
+            ThresholdSignature::deserialize_from_bytes(&bytes).expect("expected to deserialize");
+
+        assert_eq!(restored, threshold_signature);
+        assert_eq!(restored.signer_count(), 2);
+    }
+}
@@ -11,19 +11,25 @@ use crate::state_transition::{StateTransition, StateTransitionFieldTypes};
 pub const DOCUMENT_TRANSITION_TYPES: [StateTransitionType; 1] =
     [StateTransitionType::DocumentsBatch];
 
-pub const IDENTITY_TRANSITION_TYPE: [StateTransitionType; 5] = [
+pub const IDENTITY_TRANSITION_TYPE: [StateTransitionType; 7] = [
     StateTransitionType::IdentityCreate,
     StateTransitionType::IdentityTopUp,
     StateTransitionType::IdentityUpdate,
     StateTransitionType::IdentityCreditTransfer,
     StateTransitionType::IdentityCreditWithdrawal,
+    StateTransitionType::IdentityRecovery,
+    StateTransitionType::IdentityCreditBatchTransfer,
 ];
 
-pub const VOTING_TRANSITION_TYPE: [StateTransitionType; 1] = [StateTransitionType::MasternodeVote];
+pub const VOTING_TRANSITION_TYPE: [StateTransitionType; 2] = [
+    StateTransitionType::MasternodeVote,
+    StateTransitionType::AuctionBid,
+];
 
-pub const DATA_CONTRACT_TRANSITION_TYPES: [StateTransitionType; 2] = [
+pub const DATA_CONTRACT_TRANSITION_TYPES: [StateTransitionType; 3] = [
     StateTransitionType::DataContractCreate,
     StateTransitionType::DataContractUpdate,
+    StateTransitionType::DataContractDelete,
 ];
 
 /// The StateTransitionLike represents set of methods that are shared for all types of State Transition.
@@ -0,0 +1,110 @@
+use crate::fee::Credits;
+use crate::util::hash::hash_double;
+use bincode::{Decode, Encode};
+use platform_value::{Bytes32, Identifier};
+#[cfg(feature = "vote-serde-conversion")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A bid in a sealed-bid auction deciding who gets a contested resource.
+///
+/// While the vote poll is open a contender commits to a bid by only giving out the hash of it,
+/// along with a deposit that is escrowed and that must cover the bid. Once bidding has closed
+/// the contender reveals the amount and the salt that were used to compute the hash.
+#[derive(Debug, Clone, Copy, Encode, Decode, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "vote-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum AuctionBid {
+    Commit {
+        /// The hash of the bid, see [AuctionBid::sealed_bid_hash]
+        sealed_bid: Bytes32,
+        /// The credits that are escrowed until the auction is resolved
+        deposit: Credits,
+    },
+    Reveal {
+        amount: Credits,
+        salt: Bytes32,
+    },
+}
+
+impl Default for AuctionBid {
+    fn default() -> Self {
+        AuctionBid::Commit {
+            sealed_bid: Bytes32::default(),
+            deposit: 0,
+        }
+    }
+}
+
+impl fmt::Display for AuctionBid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuctionBid::Commit {
+                sealed_bid,
+                deposit,
+            } => write!(
+                f,
+                "Commit {{ sealed_bid: {}, deposit: {} }}",
+                hex::encode(sealed_bid.as_slice()),
+                deposit
+            ),
+            AuctionBid::Reveal { amount, .. } => write!(f, "Reveal {{ amount: {} }}", amount),
+        }
+    }
+}
+
+impl AuctionBid {
+    /// The hash a bidder commits to, it binds the bid to the vote poll and to the bidder so that
+    /// a commitment can not be replayed by someone else.
+    pub fn sealed_bid_hash(
+        vote_poll_id: Identifier,
+        bidder_id: Identifier,
+        amount: Credits,
+        salt: Bytes32,
+    ) -> Bytes32 {
+        let mut payload = Vec::with_capacity(32 + 32 + 8 + 32);
+        payload.extend_from_slice(vote_poll_id.as_slice());
+        payload.extend_from_slice(bidder_id.as_slice());
+        payload.extend_from_slice(&amount.to_be_bytes());
+        payload.extend_from_slice(salt.as_slice());
+        Bytes32::new(hash_double(payload))
+    }
+
+    pub fn is_commit(&self) -> bool {
+        matches!(self, AuctionBid::Commit { .. })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sealed_bid_hash_should_be_bound_to_the_bidder_and_the_amount() {
+        let vote_poll_id = Identifier::new([1; 32]);
+        let bidder_id = Identifier::new([2; 32]);
+        let salt = Bytes32::new([3; 32]);
+
+        let hash = AuctionBid::sealed_bid_hash(vote_poll_id, bidder_id, 1000, salt);
+
+        assert_eq!(
+            hash,
+            AuctionBid::sealed_bid_hash(vote_poll_id, bidder_id, 1000, salt)
+        );
+        assert_ne!(
+            hash,
+            AuctionBid::sealed_bid_hash(vote_poll_id, Identifier::new([4; 32]), 1000, salt)
+        );
+        assert_ne!(
+            hash,
+            AuctionBid::sealed_bid_hash(vote_poll_id, bidder_id, 1001, salt)
+        );
+        assert_ne!(
+            hash,
+            AuctionBid::sealed_bid_hash(vote_poll_id, bidder_id, 1000, Bytes32::new([5; 32]))
+        );
+    }
+}
@@ -1,3 +1,4 @@
+pub mod auction_bids;
 pub mod contender_structs;
 pub mod vote_choices;
 pub mod vote_info_storage;
@@ -1,10 +1,12 @@
+use crate::ProtocolError;
 use bincode::{Decode, Encode};
-#[cfg(feature = "state-transition-serde-conversion")]
+#[cfg(feature = "vote-serde-conversion")]
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
-#[derive(Debug, Clone, Copy, Encode, Decode, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, Encode, Decode, Ord, Eq, PartialOrd, PartialEq, Default)]
 #[cfg_attr(
-    feature = "state-transition-serde-conversion",
+    feature = "vote-serde-conversion",
     derive(Serialize, Deserialize),
     serde(rename_all = "camelCase")
 )]
@@ -14,3 +16,39 @@ pub enum YesNoAbstainVoteChoice {
     #[default]
     ABSTAIN,
 }
+
+impl fmt::Display for YesNoAbstainVoteChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            YesNoAbstainVoteChoice::YES => write!(f, "Yes"),
+            YesNoAbstainVoteChoice::NO => write!(f, "No"),
+            YesNoAbstainVoteChoice::ABSTAIN => write!(f, "Abstain"),
+        }
+    }
+}
+
+impl From<YesNoAbstainVoteChoice> for u8 {
+    fn from(value: YesNoAbstainVoteChoice) -> Self {
+        match value {
+            YesNoAbstainVoteChoice::YES => 0,
+            YesNoAbstainVoteChoice::NO => 1,
+            YesNoAbstainVoteChoice::ABSTAIN => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for YesNoAbstainVoteChoice {
+    type Error = ProtocolError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(YesNoAbstainVoteChoice::YES),
+            1 => Ok(YesNoAbstainVoteChoice::NO),
+            2 => Ok(YesNoAbstainVoteChoice::ABSTAIN),
+            value => Err(ProtocolError::DecodingError(format!(
+                "unknown yes/no/abstain vote choice {}",
+                value
+            ))),
+        }
+    }
+}
@@ -0,0 +1,213 @@
+mod v0;
+
+use crate::block::block_info::BlockInfo;
+use crate::fee::Credits;
+use crate::identity::state_transition::asset_lock_proof::{Decode, Encode};
+use crate::voting::vote_info_storage::contested_document_auction_bid_stored_info::v0::ContestedDocumentAuctionBidStoredInfoV0;
+use crate::ProtocolError;
+use derive_more::From;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::{Bytes32, Identifier};
+use platform_version::version::PlatformVersion;
+use std::fmt;
+pub use v0::ContestedDocumentAuctionBidStoredInfoV0Getters;
+
+/// Represents a bid of a contender in a contested resource vote poll that is resolved by a
+/// sealed-bid auction.
+///
+/// It is created when the bid is committed and updated when the bid is revealed.
+#[derive(
+    Debug, PartialEq, Eq, Clone, From, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[platform_serialize(unversioned)]
+pub enum ContestedDocumentAuctionBidStoredInfo {
+    /// V0.
+    V0(ContestedDocumentAuctionBidStoredInfoV0),
+}
+
+impl fmt::Display for ContestedDocumentAuctionBidStoredInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContestedDocumentAuctionBidStoredInfo::V0(info) => write!(f, "V0({})", info),
+        }
+    }
+}
+
+impl ContestedDocumentAuctionBidStoredInfo {
+    pub fn new(
+        sealed_bid: Bytes32,
+        deposit: Credits,
+        committed_block: BlockInfo,
+        platform_version: &PlatformVersion,
+    ) -> Result<ContestedDocumentAuctionBidStoredInfo, ProtocolError> {
+        match platform_version
+            .dpp
+            .voting_versions
+            .contested_document_auction_bid_stored_info_version
+        {
+            0 => Ok(ContestedDocumentAuctionBidStoredInfoV0::new(
+                sealed_bid,
+                deposit,
+                committed_block,
+            )
+            .into()),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "ContestedDocumentAuctionBidStoredInfo::new".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+
+    pub fn matches_reveal(
+        &self,
+        vote_poll_id: Identifier,
+        bidder_id: Identifier,
+        amount: Credits,
+        salt: Bytes32,
+    ) -> bool {
+        match self {
+            ContestedDocumentAuctionBidStoredInfo::V0(v0) => {
+                v0.matches_reveal(vote_poll_id, bidder_id, amount, salt)
+            }
+        }
+    }
+
+    pub fn reveal(&mut self, amount: Credits) -> Result<(), ProtocolError> {
+        match self {
+            ContestedDocumentAuctionBidStoredInfo::V0(v0) => v0.reveal(amount),
+        }
+    }
+
+    /// Finds the winner of an auction, the highest revealed bid wins.
+    ///
+    /// Ties are won by the bid that was committed first, then by the lowest identity id.
+    /// Bids that were never revealed can not win.
+    pub fn winning_bid<'a>(
+        bids: impl IntoIterator<Item = (Identifier, &'a ContestedDocumentAuctionBidStoredInfo)>,
+    ) -> Option<(Identifier, Credits)> {
+        bids.into_iter()
+            .filter_map(|(bidder_id, bid)| {
+                bid.revealed_amount()
+                    .map(|amount| (bidder_id, amount, bid.committed_block().height))
+            })
+            .max_by(|(a_id, a_amount, a_height), (b_id, b_amount, b_height)| {
+                a_amount
+                    .cmp(b_amount)
+                    .then_with(|| b_height.cmp(a_height))
+                    .then_with(|| b_id.cmp(a_id))
+            })
+            .map(|(bidder_id, amount, _)| (bidder_id, amount))
+    }
+}
+
+impl ContestedDocumentAuctionBidStoredInfoV0Getters for ContestedDocumentAuctionBidStoredInfo {
+    fn sealed_bid(&self) -> Bytes32 {
+        match self {
+            ContestedDocumentAuctionBidStoredInfo::V0(v0) => v0.sealed_bid(),
+        }
+    }
+
+    fn deposit(&self) -> Credits {
+        match self {
+            ContestedDocumentAuctionBidStoredInfo::V0(v0) => v0.deposit(),
+        }
+    }
+
+    fn committed_block(&self) -> BlockInfo {
+        match self {
+            ContestedDocumentAuctionBidStoredInfo::V0(v0) => v0.committed_block(),
+        }
+    }
+
+    fn revealed_amount(&self) -> Option<Credits> {
+        match self {
+            ContestedDocumentAuctionBidStoredInfo::V0(v0) => v0.revealed_amount(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bid(
+        deposit: Credits,
+        committed_at_height: u64,
+        revealed_amount: Option<Credits>,
+    ) -> ContestedDocumentAuctionBidStoredInfo {
+        ContestedDocumentAuctionBidStoredInfoV0 {
+            sealed_bid: Bytes32::default(),
+            deposit,
+            committed_block: BlockInfo {
+                height: committed_at_height,
+                ..Default::default()
+            },
+            revealed_amount,
+        }
+        .into()
+    }
+
+    #[test]
+    fn should_pick_the_highest_revealed_bid() {
+        let alice = Identifier::new([1; 32]);
+        let bob = Identifier::new([2; 32]);
+        let carol = Identifier::new([3; 32]);
+
+        let alice_bid = bid(1000, 5, Some(600));
+        let bob_bid = bid(900, 3, Some(800));
+        // Carol would have won, but never revealed her bid
+        let carol_bid = bid(5000, 1, None);
+
+        assert_eq!(
+            ContestedDocumentAuctionBidStoredInfo::winning_bid([
+                (alice, &alice_bid),
+                (bob, &bob_bid),
+                (carol, &carol_bid)
+            ]),
+            Some((bob, 800))
+        );
+
+        assert_eq!(
+            ContestedDocumentAuctionBidStoredInfo::winning_bid([(carol, &carol_bid)]),
+            None
+        );
+    }
+
+    #[test]
+    fn should_give_ties_to_the_earliest_bid() {
+        let alice = Identifier::new([1; 32]);
+        let bob = Identifier::new([2; 32]);
+
+        let alice_bid = bid(1000, 5, Some(700));
+        let bob_bid = bid(1000, 3, Some(700));
+
+        assert_eq!(
+            ContestedDocumentAuctionBidStoredInfo::winning_bid([
+                (alice, &alice_bid),
+                (bob, &bob_bid)
+            ]),
+            Some((bob, 700))
+        );
+
+        let alice_bid = bid(1000, 3, Some(700));
+
+        assert_eq!(
+            ContestedDocumentAuctionBidStoredInfo::winning_bid([
+                (bob, &bob_bid),
+                (alice, &alice_bid)
+            ]),
+            Some((alice, 700))
+        );
+    }
+
+    #[test]
+    fn should_only_reveal_once_and_within_the_deposit() {
+        let mut stored_bid = bid(1000, 1, None);
+
+        assert!(stored_bid.reveal(1001).is_err());
+        stored_bid.reveal(1000).expect("expected to reveal the bid");
+        assert_eq!(stored_bid.revealed_amount(), Some(1000));
+        assert!(stored_bid.reveal(10).is_err());
+    }
+}
@@ -0,0 +1,101 @@
+use crate::block::block_info::BlockInfo;
+use crate::fee::Credits;
+use crate::voting::auction_bids::AuctionBid;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_value::{Bytes32, Identifier};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Clone, Encode, Decode)]
+pub struct ContestedDocumentAuctionBidStoredInfoV0 {
+    /// The hash the bidder committed to
+    pub sealed_bid: Bytes32,
+    /// The credits held in escrow for the bid
+    pub deposit: Credits,
+    /// The block in which the bid was committed, earlier bids win ties
+    pub committed_block: BlockInfo,
+    /// The amount of the bid, only set once it has been revealed
+    pub revealed_amount: Option<Credits>,
+}
+
+impl fmt::Display for ContestedDocumentAuctionBidStoredInfoV0 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ContestedDocumentAuctionBidStoredInfoV0 {{ sealed_bid: {}, deposit: {}, committed_block: {}, revealed_amount: {} }}",
+            hex::encode(self.sealed_bid.as_slice()),
+            self.deposit,
+            self.committed_block,
+            self.revealed_amount
+                .map(|amount| amount.to_string())
+                .unwrap_or("None".to_string())
+        )
+    }
+}
+
+impl ContestedDocumentAuctionBidStoredInfoV0 {
+    pub fn new(
+        sealed_bid: Bytes32,
+        deposit: Credits,
+        committed_block: BlockInfo,
+    ) -> ContestedDocumentAuctionBidStoredInfoV0 {
+        ContestedDocumentAuctionBidStoredInfoV0 {
+            sealed_bid,
+            deposit,
+            committed_block,
+            revealed_amount: None,
+        }
+    }
+
+    /// Checks that the amount and salt are the ones the bidder committed to
+    pub fn matches_reveal(
+        &self,
+        vote_poll_id: Identifier,
+        bidder_id: Identifier,
+        amount: Credits,
+        salt: Bytes32,
+    ) -> bool {
+        AuctionBid::sealed_bid_hash(vote_poll_id, bidder_id, amount, salt) == self.sealed_bid
+    }
+
+    /// Records the revealed amount, a bid can only be revealed once.
+    pub fn reveal(&mut self, amount: Credits) -> Result<(), ProtocolError> {
+        if self.revealed_amount.is_some() {
+            return Err(ProtocolError::CorruptedCodeExecution(
+                "trying to reveal an auction bid that was already revealed".to_string(),
+            ));
+        }
+        if amount > self.deposit {
+            return Err(ProtocolError::CorruptedCodeExecution(
+                "trying to reveal an auction bid that is higher than its deposit".to_string(),
+            ));
+        }
+        self.revealed_amount = Some(amount);
+        Ok(())
+    }
+}
+
+pub trait ContestedDocumentAuctionBidStoredInfoV0Getters {
+    fn sealed_bid(&self) -> Bytes32;
+    fn deposit(&self) -> Credits;
+    fn committed_block(&self) -> BlockInfo;
+    fn revealed_amount(&self) -> Option<Credits>;
+}
+
+impl ContestedDocumentAuctionBidStoredInfoV0Getters for ContestedDocumentAuctionBidStoredInfoV0 {
+    fn sealed_bid(&self) -> Bytes32 {
+        self.sealed_bid
+    }
+
+    fn deposit(&self) -> Credits {
+        self.deposit
+    }
+
+    fn committed_block(&self) -> BlockInfo {
+        self.committed_block
+    }
+
+    fn revealed_amount(&self) -> Option<Credits> {
+        self.revealed_amount
+    }
+}
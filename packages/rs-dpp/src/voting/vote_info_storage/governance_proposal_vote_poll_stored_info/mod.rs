@@ -0,0 +1,247 @@
+mod v0;
+
+use crate::block::block_info::BlockInfo;
+use crate::identity::state_transition::asset_lock_proof::{Decode, Encode};
+use crate::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use crate::voting::vote_info_storage::governance_proposal_vote_poll_stored_info::v0::GovernanceProposalVotePollStoredInfoV0;
+use crate::voting::vote_polls::governance_proposal_vote_poll::GovernanceProposalVotePoll;
+use crate::ProtocolError;
+use derive_more::From;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_version::version::PlatformVersion;
+use std::fmt;
+pub use v0::GovernanceProposalVotePollStoredInfoV0Getters;
+
+/// The outcome of a governance proposal vote poll
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Encode, Decode)]
+pub enum GovernanceProposalOutcome {
+    /// Not enough of the evonodes voted
+    #[default]
+    QuorumNotReached,
+    /// The quorum was reached and enough of the yes/no votes were yes votes
+    Approved,
+    /// The quorum was reached but not enough of the yes/no votes were yes votes
+    Rejected,
+}
+
+impl fmt::Display for GovernanceProposalOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GovernanceProposalOutcome::QuorumNotReached => write!(f, "QuorumNotReached"),
+            GovernanceProposalOutcome::Approved => write!(f, "Approved"),
+            GovernanceProposalOutcome::Rejected => write!(f, "Rejected"),
+        }
+    }
+}
+
+/// How many evonodes voted for each of the choices of a governance proposal vote poll
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Encode, Decode)]
+pub struct GovernanceProposalVoteTally {
+    pub yes: u32,
+    pub no: u32,
+    pub abstain: u32,
+}
+
+impl fmt::Display for GovernanceProposalVoteTally {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "GovernanceProposalVoteTally {{ yes: {}, no: {}, abstain: {} }}",
+            self.yes, self.no, self.abstain
+        )
+    }
+}
+
+impl GovernanceProposalVoteTally {
+    /// Adds a vote to the tally
+    pub fn add_vote(&mut self, vote_choice: YesNoAbstainVoteChoice) {
+        match vote_choice {
+            YesNoAbstainVoteChoice::YES => self.yes += 1,
+            YesNoAbstainVoteChoice::NO => self.no += 1,
+            YesNoAbstainVoteChoice::ABSTAIN => self.abstain += 1,
+        }
+    }
+
+    /// How many evonodes took part in the vote poll, abstaining counts towards the quorum
+    pub fn participation(&self) -> u64 {
+        self.yes as u64 + self.no as u64 + self.abstain as u64
+    }
+
+    /// Determines the outcome of the vote poll.
+    ///
+    /// The quorum is reached when at least `quorum_percentage` of the eligible voters voted,
+    /// the proposal is then approved when at least `approval_percentage` of the yes/no votes
+    /// are yes votes.
+    pub fn outcome(
+        &self,
+        eligible_voters: u32,
+        quorum_percentage: u8,
+        approval_percentage: u8,
+    ) -> GovernanceProposalOutcome {
+        let participation = self.participation();
+        if participation == 0
+            || participation * 100 < eligible_voters as u64 * quorum_percentage as u64
+        {
+            return GovernanceProposalOutcome::QuorumNotReached;
+        }
+        let decisive = self.yes as u64 + self.no as u64;
+        if self.yes > 0 && self.yes as u64 * 100 >= decisive * approval_percentage as u64 {
+            GovernanceProposalOutcome::Approved
+        } else {
+            GovernanceProposalOutcome::Rejected
+        }
+    }
+}
+
+/// The final result of a governance proposal vote poll
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Encode, Decode)]
+pub struct GovernanceProposalVotePollResult {
+    /// The votes given to each choice
+    pub tally: GovernanceProposalVoteTally,
+    /// The amount of evonodes at the end of the vote poll
+    pub eligible_voters: u32,
+    /// The outcome
+    pub outcome: GovernanceProposalOutcome,
+    /// Finalization Block
+    pub finalization_block: BlockInfo,
+}
+
+impl fmt::Display for GovernanceProposalVotePollResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "GovernanceProposalVotePollResult {{ tally: {}, eligible_voters: {}, outcome: {}, finalization_block: {} }}",
+            self.tally, self.eligible_voters, self.outcome, self.finalization_block
+        )
+    }
+}
+
+/// Represents the stored info of a governance proposal vote poll.
+///
+/// It is created with the first vote on the proposal and keeps the result once the poll ended.
+#[derive(Debug, PartialEq, Clone, From, Encode, Decode, PlatformSerialize, PlatformDeserialize)]
+#[platform_serialize(unversioned)]
+pub enum GovernanceProposalVotePollStoredInfo {
+    /// V0.
+    V0(GovernanceProposalVotePollStoredInfoV0),
+}
+
+impl fmt::Display for GovernanceProposalVotePollStoredInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GovernanceProposalVotePollStoredInfo::V0(info) => write!(f, "V0({})", info),
+        }
+    }
+}
+
+impl GovernanceProposalVotePollStoredInfo {
+    pub fn new(
+        vote_poll: GovernanceProposalVotePoll,
+        start_block: BlockInfo,
+        platform_version: &PlatformVersion,
+    ) -> Result<GovernanceProposalVotePollStoredInfo, ProtocolError> {
+        match platform_version
+            .dpp
+            .voting_versions
+            .governance_proposal_vote_poll_stored_info_version
+        {
+            0 => Ok(GovernanceProposalVotePollStoredInfoV0::new(vote_poll, start_block).into()),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "GovernanceProposalVotePollStoredInfo::new".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+
+    pub fn finalize_vote_poll(
+        &mut self,
+        result: GovernanceProposalVotePollResult,
+    ) -> Result<(), ProtocolError> {
+        match self {
+            GovernanceProposalVotePollStoredInfo::V0(v0) => v0.finalize_vote_poll(result),
+        }
+    }
+}
+
+impl GovernanceProposalVotePollStoredInfoV0Getters for GovernanceProposalVotePollStoredInfo {
+    fn vote_poll(&self) -> &GovernanceProposalVotePoll {
+        match self {
+            GovernanceProposalVotePollStoredInfo::V0(v0) => v0.vote_poll(),
+        }
+    }
+
+    fn start_block(&self) -> BlockInfo {
+        match self {
+            GovernanceProposalVotePollStoredInfo::V0(v0) => v0.start_block(),
+        }
+    }
+
+    fn result(&self) -> Option<&GovernanceProposalVotePollResult> {
+        match self {
+            GovernanceProposalVotePollStoredInfo::V0(v0) => v0.result(),
+        }
+    }
+
+    fn has_ended(&self) -> bool {
+        match self {
+            GovernanceProposalVotePollStoredInfo::V0(v0) => v0.has_ended(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_not_reach_quorum_without_enough_participation() {
+        let tally = GovernanceProposalVoteTally {
+            yes: 4,
+            no: 0,
+            abstain: 4,
+        };
+
+        // 8 of 100 voted, a 10% quorum is not reached
+        assert_eq!(
+            tally.outcome(100, 10, 60),
+            GovernanceProposalOutcome::QuorumNotReached
+        );
+        // Abstaining counts towards the quorum
+        assert_eq!(
+            tally.outcome(80, 10, 60),
+            GovernanceProposalOutcome::Approved
+        );
+    }
+
+    #[test]
+    fn should_approve_or_reject_based_on_yes_and_no_votes() {
+        let mut tally = GovernanceProposalVoteTally::default();
+        tally.add_vote(YesNoAbstainVoteChoice::YES);
+        tally.add_vote(YesNoAbstainVoteChoice::YES);
+        tally.add_vote(YesNoAbstainVoteChoice::YES);
+        tally.add_vote(YesNoAbstainVoteChoice::NO);
+        tally.add_vote(YesNoAbstainVoteChoice::NO);
+        tally.add_vote(YesNoAbstainVoteChoice::ABSTAIN);
+
+        // 60% of the yes/no votes are yes
+        assert_eq!(
+            tally.outcome(6, 10, 60),
+            GovernanceProposalOutcome::Approved
+        );
+        assert_eq!(
+            tally.outcome(6, 10, 61),
+            GovernanceProposalOutcome::Rejected
+        );
+
+        let only_abstain = GovernanceProposalVoteTally {
+            yes: 0,
+            no: 0,
+            abstain: 40,
+        };
+        assert_eq!(
+            only_abstain.outcome(40, 10, 0),
+            GovernanceProposalOutcome::Rejected
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::block::block_info::BlockInfo;
+use crate::voting::vote_info_storage::governance_proposal_vote_poll_stored_info::GovernanceProposalVotePollResult;
+use crate::voting::vote_polls::governance_proposal_vote_poll::GovernanceProposalVotePoll;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone, Encode, Decode)]
+pub struct GovernanceProposalVotePollStoredInfoV0 {
+    /// The vote poll
+    pub vote_poll: GovernanceProposalVotePoll,
+    /// Start Block, the block of the first vote
+    pub start_block: BlockInfo,
+    /// The result, only set once the vote poll has ended
+    pub result: Option<GovernanceProposalVotePollResult>,
+}
+
+impl fmt::Display for GovernanceProposalVotePollStoredInfoV0 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "GovernanceProposalVotePollStoredInfoV0 {{ vote_poll: {}, start_block: {}, result: {} }}",
+            self.vote_poll,
+            self.start_block,
+            self.result
+                .as_ref()
+                .map(|result| result.to_string())
+                .unwrap_or("None".to_string())
+        )
+    }
+}
+
+impl GovernanceProposalVotePollStoredInfoV0 {
+    pub fn new(
+        vote_poll: GovernanceProposalVotePoll,
+        start_block: BlockInfo,
+    ) -> GovernanceProposalVotePollStoredInfoV0 {
+        GovernanceProposalVotePollStoredInfoV0 {
+            vote_poll,
+            start_block,
+            result: None,
+        }
+    }
+
+    /// This will finalize the vote poll, a governance proposal vote poll can only end once.
+    pub fn finalize_vote_poll(
+        &mut self,
+        result: GovernanceProposalVotePollResult,
+    ) -> Result<(), ProtocolError> {
+        if self.result.is_some() {
+            return Err(ProtocolError::CorruptedCodeExecution(
+                "trying to finalize a governance proposal vote poll that has already ended"
+                    .to_string(),
+            ));
+        }
+        self.result = Some(result);
+        Ok(())
+    }
+}
+
+pub trait GovernanceProposalVotePollStoredInfoV0Getters {
+    fn vote_poll(&self) -> &GovernanceProposalVotePoll;
+    fn start_block(&self) -> BlockInfo;
+    fn result(&self) -> Option<&GovernanceProposalVotePollResult>;
+    fn has_ended(&self) -> bool;
+}
+
+impl GovernanceProposalVotePollStoredInfoV0Getters for GovernanceProposalVotePollStoredInfoV0 {
+    fn vote_poll(&self) -> &GovernanceProposalVotePoll {
+        &self.vote_poll
+    }
+
+    fn start_block(&self) -> BlockInfo {
+        self.start_block
+    }
+
+    fn result(&self) -> Option<&GovernanceProposalVotePollResult> {
+        self.result.as_ref()
+    }
+
+    fn has_ended(&self) -> bool {
+        self.result.is_some()
+    }
+}
@@ -1,2 +1,4 @@
+pub mod contested_document_auction_bid_stored_info;
 pub mod contested_document_vote_poll_stored_info;
 pub mod contested_document_vote_poll_winner_info;
+pub mod governance_proposal_vote_poll_stored_info;
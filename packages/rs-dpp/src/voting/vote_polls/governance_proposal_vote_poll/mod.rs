@@ -0,0 +1,104 @@
+use crate::identity::state_transition::asset_lock_proof::{Decode, Encode};
+use crate::prelude::TimestampMillis;
+use crate::serialization::PlatformSerializable;
+use crate::util::hash::hash_double;
+use crate::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::{Identifier, Value};
+#[cfg(feature = "vote-serde-conversion")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// What a governance proposal asks the evonodes to approve
+#[derive(Debug, Clone, Encode, Decode, PartialEq)]
+#[cfg_attr(
+    feature = "vote-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum GovernanceProposal {
+    /// Changes the value of a platform parameter
+    #[cfg_attr(feature = "vote-serde-conversion", serde(rename_all = "camelCase"))]
+    ParameterChange { parameter: String, value: Value },
+    /// Updates a system data contract to the contract with the given hash
+    #[cfg_attr(feature = "vote-serde-conversion", serde(rename_all = "camelCase"))]
+    SystemContractUpdate {
+        contract_id: Identifier,
+        contract_hash: [u8; 32],
+    },
+}
+
+impl fmt::Display for GovernanceProposal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GovernanceProposal::ParameterChange { parameter, value } => {
+                write!(
+                    f,
+                    "ParameterChange {{ parameter: {}, value: {} }}",
+                    parameter, value
+                )
+            }
+            GovernanceProposal::SystemContractUpdate {
+                contract_id,
+                contract_hash,
+            } => write!(
+                f,
+                "SystemContractUpdate {{ contract_id: {}, contract_hash: {} }}",
+                contract_id,
+                hex::encode(contract_hash)
+            ),
+        }
+    }
+}
+
+impl Default for GovernanceProposal {
+    fn default() -> Self {
+        GovernanceProposal::ParameterChange {
+            parameter: "".to_string(),
+            value: Value::Null,
+        }
+    }
+}
+
+/// A yes/no/abstain poll on a platform governance proposal, only evonodes can vote on it.
+///
+/// The poll is started by the first vote cast on it and ends at `end_date`.
+/// The full description of the proposal lives off chain, only its hash is part of the poll.
+#[derive(
+    Debug, Clone, Default, Encode, Decode, PlatformSerialize, PlatformDeserialize, PartialEq,
+)]
+#[cfg_attr(
+    feature = "vote-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[platform_serialize(unversioned)] //versioned directly, no need to use platform_version
+#[platform_serialize(limit = 100000)]
+pub struct GovernanceProposalVotePoll {
+    pub proposal: GovernanceProposal,
+    pub description_hash: [u8; 32],
+    pub end_date: TimestampMillis,
+}
+
+impl fmt::Display for GovernanceProposalVotePoll {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "GovernanceProposalVotePoll {{ proposal: {}, description_hash: {}, end_date: {} }}",
+            self.proposal,
+            hex::encode(self.description_hash),
+            self.end_date
+        )
+    }
+}
+
+impl GovernanceProposalVotePoll {
+    pub fn sha256_2_hash(&self) -> Result<[u8; 32], ProtocolError> {
+        let encoded = self.serialize_to_bytes()?;
+        Ok(hash_double(encoded))
+    }
+
+    pub fn unique_id(&self) -> Result<Identifier, ProtocolError> {
+        self.sha256_2_hash().map(Identifier::new)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::identity::state_transition::asset_lock_proof::{Decode, Encode};
 use crate::voting::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePoll;
+use crate::voting::vote_polls::governance_proposal_vote_poll::GovernanceProposalVotePoll;
 use crate::ProtocolError;
 use derive_more::From;
 use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
@@ -9,6 +10,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 
 pub mod contested_document_resource_vote_poll;
+pub mod governance_proposal_vote_poll;
 
 #[derive(Debug, Clone, Encode, Decode, PlatformSerialize, PlatformDeserialize, PartialEq, From)]
 #[cfg_attr(
@@ -20,6 +22,7 @@ pub mod contested_document_resource_vote_poll;
 #[platform_serialize(limit = 100000)]
 pub enum VotePoll {
     ContestedDocumentResourceVotePoll(ContestedDocumentResourceVotePoll),
+    GovernanceProposalVotePoll(GovernanceProposalVotePoll),
 }
 
 impl fmt::Display for VotePoll {
@@ -28,6 +31,9 @@ impl fmt::Display for VotePoll {
             VotePoll::ContestedDocumentResourceVotePoll(poll) => {
                 write!(f, "ContestedDocumentResourceVotePoll({})", poll)
             }
+            VotePoll::GovernanceProposalVotePoll(poll) => {
+                write!(f, "GovernanceProposalVotePoll({})", poll)
+            }
         }
     }
 }
@@ -46,6 +52,10 @@ impl VotePoll {
                     contested_document_resource_vote_poll.specialized_balance_id()?,
                 ))
             }
+            // The evonode opening a governance proposal vote poll funds it
+            VotePoll::GovernanceProposalVotePoll(governance_proposal_vote_poll) => {
+                Ok(Some(governance_proposal_vote_poll.unique_id()?))
+            }
         }
     }
 
@@ -54,6 +64,9 @@ impl VotePoll {
             VotePoll::ContestedDocumentResourceVotePoll(contested_document_resource_vote_poll) => {
                 contested_document_resource_vote_poll.unique_id()
             }
+            VotePoll::GovernanceProposalVotePoll(governance_proposal_vote_poll) => {
+                governance_proposal_vote_poll.unique_id()
+            }
         }
     }
 }
@@ -0,0 +1,26 @@
+use crate::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use crate::voting::vote_polls::governance_proposal_vote_poll::GovernanceProposalVotePoll;
+use crate::voting::votes::governance_proposal_vote::accessors::v0::GovernanceProposalVoteGettersV0;
+use crate::voting::votes::governance_proposal_vote::GovernanceProposalVote;
+
+pub mod v0;
+
+impl GovernanceProposalVoteGettersV0 for GovernanceProposalVote {
+    fn vote_poll(&self) -> &GovernanceProposalVotePoll {
+        match self {
+            GovernanceProposalVote::V0(v0) => &v0.vote_poll,
+        }
+    }
+
+    fn vote_poll_owned(self) -> GovernanceProposalVotePoll {
+        match self {
+            GovernanceProposalVote::V0(v0) => v0.vote_poll,
+        }
+    }
+
+    fn vote_choice(&self) -> YesNoAbstainVoteChoice {
+        match self {
+            GovernanceProposalVote::V0(v0) => v0.vote_choice,
+        }
+    }
+}
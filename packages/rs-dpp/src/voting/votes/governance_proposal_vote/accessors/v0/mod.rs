@@ -0,0 +1,14 @@
+use crate::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use crate::voting::vote_polls::governance_proposal_vote_poll::GovernanceProposalVotePoll;
+
+/// Trait for getters in Governance Proposal Vote
+pub trait GovernanceProposalVoteGettersV0 {
+    /// The vote poll
+    fn vote_poll(&self) -> &GovernanceProposalVotePoll;
+
+    /// The vote poll as owned
+    fn vote_poll_owned(self) -> GovernanceProposalVotePoll;
+
+    /// The choice made in the vote
+    fn vote_choice(&self) -> YesNoAbstainVoteChoice;
+}
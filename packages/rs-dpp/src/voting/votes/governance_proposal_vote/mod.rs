@@ -0,0 +1,27 @@
+use crate::identity::state_transition::asset_lock_proof::{Decode, Encode};
+use crate::voting::votes::governance_proposal_vote::v0::GovernanceProposalVoteV0;
+use crate::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+#[cfg(feature = "vote-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+pub mod accessors;
+pub mod v0;
+
+#[derive(Debug, Clone, Encode, Decode, PlatformSerialize, PlatformDeserialize, PartialEq)]
+#[cfg_attr(
+    feature = "vote-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(tag = "$version")
+)]
+#[platform_serialize(limit = 15000, unversioned)]
+pub enum GovernanceProposalVote {
+    #[cfg_attr(feature = "vote-serde-conversion", serde(rename = "0"))]
+    V0(GovernanceProposalVoteV0),
+}
+
+impl Default for GovernanceProposalVote {
+    fn default() -> Self {
+        Self::V0(GovernanceProposalVoteV0::default())
+    }
+}
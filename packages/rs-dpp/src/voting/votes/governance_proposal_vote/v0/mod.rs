@@ -0,0 +1,28 @@
+use crate::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use crate::voting::vote_polls::governance_proposal_vote_poll::GovernanceProposalVotePoll;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+#[cfg(feature = "vote-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Clone, Default, Encode, Decode, PlatformDeserialize, PlatformSerialize, PartialEq,
+)]
+#[cfg_attr(
+    feature = "vote-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[platform_serialize(unversioned)]
+pub struct GovernanceProposalVoteV0 {
+    pub vote_poll: GovernanceProposalVotePoll,
+    pub vote_choice: YesNoAbstainVoteChoice,
+}
+
+impl GovernanceProposalVoteV0 {
+    pub fn vote_poll_unique_id(&self) -> Result<Identifier, ProtocolError> {
+        self.vote_poll.unique_id()
+    }
+}
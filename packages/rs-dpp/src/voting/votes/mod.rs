@@ -1,5 +1,9 @@
+pub mod governance_proposal_vote;
 pub mod resource_vote;
 
+use crate::voting::vote_polls::VotePoll;
+use crate::voting::votes::governance_proposal_vote::accessors::v0::GovernanceProposalVoteGettersV0;
+use crate::voting::votes::governance_proposal_vote::GovernanceProposalVote;
 use crate::voting::votes::resource_vote::accessors::v0::ResourceVoteGettersV0;
 use crate::voting::votes::resource_vote::ResourceVote;
 use crate::ProtocolError;
@@ -19,6 +23,7 @@ use serde::{Deserialize, Serialize};
 #[platform_serialize(limit = 15000, unversioned)]
 pub enum Vote {
     ResourceVote(ResourceVote),
+    GovernanceProposalVote(GovernanceProposalVote),
 }
 
 impl Default for Vote {
@@ -31,11 +36,27 @@ impl Vote {
     pub fn specialized_balance_id(&self) -> Result<Option<Identifier>, ProtocolError> {
         match self {
             Vote::ResourceVote(resource_vote) => resource_vote.vote_poll().specialized_balance_id(),
+            Vote::GovernanceProposalVote(governance_proposal_vote) => {
+                Ok(Some(governance_proposal_vote.vote_poll().unique_id()?))
+            }
         }
     }
     pub fn vote_poll_unique_id(&self) -> Result<Identifier, ProtocolError> {
         match self {
             Vote::ResourceVote(resource_vote) => resource_vote.vote_poll().unique_id(),
+            Vote::GovernanceProposalVote(governance_proposal_vote) => {
+                governance_proposal_vote.vote_poll().unique_id()
+            }
+        }
+    }
+
+    /// The vote poll the vote is cast on
+    pub fn vote_poll(&self) -> VotePoll {
+        match self {
+            Vote::ResourceVote(resource_vote) => resource_vote.vote_poll().clone(),
+            Vote::GovernanceProposalVote(governance_proposal_vote) => {
+                VotePoll::GovernanceProposalVotePoll(governance_proposal_vote.vote_poll().clone())
+            }
         }
     }
 }
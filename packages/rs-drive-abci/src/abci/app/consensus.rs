@@ -1,10 +1,14 @@
-use crate::abci::app::{BlockExecutionApplication, PlatformApplication, TransactionalApplication};
+use crate::abci::app::{
+    BlockExecutionApplication, PlatformApplication, SnapshotManagerApplication,
+    StateSyncApplication, TransactionalApplication,
+};
 use crate::abci::handler;
 use crate::abci::handler::error::error_into_exception;
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
 use crate::execution::types::block_execution_context::BlockExecutionContext;
 use crate::platform_types::platform::Platform;
+use crate::platform_types::snapshot::{SnapshotFetchingSession, SnapshotManager};
 use crate::rpc::core::CoreRPCLike;
 use dpp::version::PlatformVersion;
 use drive::grovedb::Transaction;
@@ -23,15 +27,23 @@ pub struct ConsensusAbciApplication<'a, C> {
     transaction: RwLock<Option<Transaction<'a>>>,
     /// The current block execution context
     block_execution_context: RwLock<Option<BlockExecutionContext>>,
+    /// The State sync session
+    snapshot_fetching_session: RwLock<Option<SnapshotFetchingSession<'a>>>,
+    /// The snapshot manager
+    snapshot_manager: SnapshotManager,
 }
 
 impl<'a, C> ConsensusAbciApplication<'a, C> {
     /// Create new ABCI app
     pub fn new(platform: &'a Platform<C>) -> Self {
+        let snapshot_manager = SnapshotManager::new_from_config(&platform.config.state_sync);
+
         Self {
             platform,
             transaction: Default::default(),
             block_execution_context: Default::default(),
+            snapshot_fetching_session: Default::default(),
+            snapshot_manager,
         }
     }
 }
@@ -48,6 +60,22 @@ impl<'a, C> BlockExecutionApplication for ConsensusAbciApplication<'a, C> {
     }
 }
 
+impl<'a, C> SnapshotManagerApplication for ConsensusAbciApplication<'a, C> {
+    fn snapshot_manager(&self) -> &SnapshotManager {
+        &self.snapshot_manager
+    }
+}
+
+impl<'a, C> StateSyncApplication<'a, C> for ConsensusAbciApplication<'a, C> {
+    fn snapshot_fetching_session(&self) -> &RwLock<Option<SnapshotFetchingSession<'a>>> {
+        &self.snapshot_fetching_session
+    }
+
+    fn platform(&self) -> &'a Platform<C> {
+        self.platform
+    }
+}
+
 impl<'a, C> TransactionalApplication<'a> for ConsensusAbciApplication<'a, C> {
     /// create and store a new transaction
     fn start_transaction(&self) {
@@ -149,4 +177,32 @@ where
     ) -> Result<proto::ResponseVerifyVoteExtension, proto::ResponseException> {
         handler::verify_vote_extension(self, request).map_err(error_into_exception)
     }
+
+    fn list_snapshots(
+        &self,
+        request: proto::RequestListSnapshots,
+    ) -> Result<proto::ResponseListSnapshots, proto::ResponseException> {
+        handler::list_snapshots(self, request).map_err(error_into_exception)
+    }
+
+    fn offer_snapshot(
+        &self,
+        request: proto::RequestOfferSnapshot,
+    ) -> Result<proto::ResponseOfferSnapshot, proto::ResponseException> {
+        handler::offer_snapshot(self, request).map_err(error_into_exception)
+    }
+
+    fn load_snapshot_chunk(
+        &self,
+        request: proto::RequestLoadSnapshotChunk,
+    ) -> Result<proto::ResponseLoadSnapshotChunk, proto::ResponseException> {
+        handler::load_snapshot_chunk(self, request).map_err(error_into_exception)
+    }
+
+    fn apply_snapshot_chunk(
+        &self,
+        request: proto::RequestApplySnapshotChunk,
+    ) -> Result<proto::ResponseApplySnapshotChunk, proto::ResponseException> {
+        handler::apply_snapshot_chunk(self, request).map_err(error_into_exception)
+    }
 }
@@ -10,6 +10,7 @@ pub mod execution_result;
 mod full;
 
 use crate::execution::types::block_execution_context::BlockExecutionContext;
+use crate::platform_types::snapshot::{SnapshotFetchingSession, SnapshotManager};
 use crate::rpc::core::DefaultCoreRPC;
 pub use check_tx::CheckTxAbciApplication;
 pub use consensus::ConsensusAbciApplication;
@@ -39,3 +40,18 @@ pub trait BlockExecutionApplication {
     /// Returns the current block execution context
     fn block_execution_context(&self) -> &RwLock<Option<BlockExecutionContext>>;
 }
+
+/// Application that creates and serves GroveDB snapshots for state sync
+pub trait SnapshotManagerApplication {
+    /// Returns the snapshot manager
+    fn snapshot_manager(&self) -> &SnapshotManager;
+}
+
+/// Application that can restore its state from snapshots served by other nodes
+pub trait StateSyncApplication<'p, C> {
+    /// Returns the current snapshot fetching session
+    fn snapshot_fetching_session(&self) -> &RwLock<Option<SnapshotFetchingSession<'p>>>;
+
+    /// Returns Platform
+    fn platform(&self) -> &'p Platform<C>;
+}
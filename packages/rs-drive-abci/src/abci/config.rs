@@ -1,6 +1,6 @@
 //! Configuration of ABCI Application server
 
-use crate::utils::from_opt_str_or_number;
+use crate::utils::{from_opt_str_or_number, from_str_or_number};
 use serde::{Deserialize, Serialize};
 
 // We allow changes in the ABCI configuration, but there should be a social process
@@ -37,6 +37,27 @@ pub struct AbciConfig {
     /// Maximum time limit (in ms) to process state transitions to prepare proposal
     #[serde(default, deserialize_with = "from_opt_str_or_number")]
     pub proposer_tx_processing_time_limit: Option<u16>,
+
+    /// Tenderdash RPC address used to relay state transitions broadcasted through gRPC
+    ///
+    /// Address should be an URL with scheme `http://`, for example:
+    /// - `http://127.0.0.1:26657`
+    #[serde(default = "AbciConfig::default_tenderdash_rpc_url")]
+    pub tenderdash_rpc_url: String,
+
+    /// Maximum time (in ms) to wait for a broadcasted state transition to be executed
+    #[serde(
+        default = "AbciConfig::default_state_transition_wait_timeout_ms",
+        deserialize_with = "from_str_or_number"
+    )]
+    pub state_transition_wait_timeout_ms: u64,
+
+    /// Maximum number of clients waiting for state transition results at the same time
+    #[serde(
+        default = "AbciConfig::default_max_state_transition_waiters",
+        deserialize_with = "from_str_or_number"
+    )]
+    pub max_state_transition_waiters: usize,
 }
 
 impl AbciConfig {
@@ -47,6 +68,18 @@ impl AbciConfig {
     pub(crate) fn default_genesis_core_height() -> u32 {
         1
     }
+
+    pub(crate) fn default_tenderdash_rpc_url() -> String {
+        "http://127.0.0.1:26657".to_string()
+    }
+
+    pub(crate) fn default_state_transition_wait_timeout_ms() -> u64 {
+        80000
+    }
+
+    pub(crate) fn default_max_state_transition_waiters() -> usize {
+        1000
+    }
 }
 
 impl Default for AbciConfig {
@@ -58,6 +91,10 @@ impl Default for AbciConfig {
             chain_id: "chain_id".to_string(),
             log: Default::default(),
             proposer_tx_processing_time_limit: Default::default(),
+            tenderdash_rpc_url: AbciConfig::default_tenderdash_rpc_url(),
+            state_transition_wait_timeout_ms: AbciConfig::default_state_transition_wait_timeout_ms(
+            ),
+            max_state_transition_waiters: AbciConfig::default_max_state_transition_waiters(),
         }
     }
 }
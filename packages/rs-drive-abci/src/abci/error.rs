@@ -87,6 +87,14 @@ pub enum AbciError {
         drive: String,
     },
 
+    /// State sync bad request received from Tenderdash
+    #[error("bad state sync request: {0}")]
+    StateSyncBadRequest(String),
+
+    /// State sync internal error
+    #[error("state sync internal error: {0}")]
+    StateSyncInternalError(String),
+
     /// Generic with code should only be used in tests
     #[error("invalid state transition error: {0}")]
     InvalidStateTransition(#[from] ConsensusError),
@@ -0,0 +1,186 @@
+use crate::abci::app::StateSyncApplication;
+use crate::abci::AbciError;
+use crate::error::Error;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+use crate::platform_types::snapshot::{SnapshotFetchingSession, CURRENT_STATE_SYNC_VERSION};
+use dpp::serialization::PlatformDeserializableFromVersionedStructure;
+use dpp::version::PlatformVersion;
+use drive::grovedb::GroveDb;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use tenderdash_abci::proto::abci as proto;
+use tenderdash_abci::proto::abci::response_apply_snapshot_chunk::Result as ApplySnapshotChunkResult;
+
+pub fn apply_snapshot_chunk<'p, A, C>(
+    app: &A,
+    request: proto::RequestApplySnapshotChunk,
+) -> Result<proto::ResponseApplySnapshotChunk, Error>
+where
+    A: StateSyncApplication<'p, C>,
+    C: 'p,
+{
+    let _timer = crate::metrics::abci_request_duration("apply_snapshot_chunk");
+
+    let platform = app.platform();
+
+    let mut session_guard = app.snapshot_fetching_session().write().unwrap();
+
+    let session = session_guard.as_mut().ok_or_else(|| {
+        AbciError::StateSyncBadRequest(
+            "received a snapshot chunk without an accepted snapshot".to_string(),
+        )
+    })?;
+
+    let platform_version = PlatformVersion::get(session.metadata.protocol_version)?;
+
+    let next_chunks = match session.state_sync_info.apply_chunk(
+        &platform.drive.grove,
+        &request.chunk_id,
+        request.chunk,
+        CURRENT_STATE_SYNC_VERSION,
+        &platform_version.drive.grove_version,
+    ) {
+        Ok(next_chunks) => next_chunks,
+        Err(e) => {
+            tracing::debug!(
+                chunk_id = hex::encode(&request.chunk_id),
+                sender = request.sender,
+                "unable to apply snapshot chunk, refetching: {}",
+                e
+            );
+
+            return Ok(proto::ResponseApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Retry as i32,
+                refetch_chunks: vec![request.chunk_id],
+                reject_senders: vec![request.sender],
+                next_chunks: vec![],
+            });
+        }
+    };
+
+    if !session.state_sync_info.is_sync_completed() {
+        return Ok(proto::ResponseApplySnapshotChunk {
+            result: ApplySnapshotChunkResult::Accept as i32,
+            refetch_chunks: vec![],
+            reject_senders: vec![],
+            next_chunks,
+        });
+    }
+
+    let SnapshotFetchingSession {
+        snapshot,
+        app_hash,
+        metadata,
+        state_sync_info,
+    } = session_guard
+        .take()
+        .expect("snapshot fetching session is checked above");
+
+    drop(session_guard);
+
+    platform
+        .drive
+        .grove
+        .commit_session(state_sync_info)
+        .map_err(|e| {
+            AbciError::StateSyncInternalError(format!("unable to commit state sync: {}", e))
+        })?;
+
+    // Every restored merk must hash to what its parent commits to, otherwise the root hash
+    // below could be read from a tree that doesn't match its content
+    let integrity_issues = platform
+        .drive
+        .grove
+        .verify_grovedb(None, true, false, &platform_version.drive.grove_version)
+        .map_err(|e| {
+            AbciError::StateSyncInternalError(format!("unable to verify restored state: {}", e))
+        })?;
+
+    if !integrity_issues.is_empty() {
+        tracing::error!(
+            height = snapshot.height,
+            issues = integrity_issues.len(),
+            "restored state is inconsistent with its root hash, rejecting snapshot"
+        );
+
+        return reject_snapshot(&platform.drive.grove);
+    }
+
+    let restored_app_hash = platform
+        .drive
+        .grove
+        .root_hash(None, &platform_version.drive.grove_version)
+        .value
+        .map_err(|e| {
+            AbciError::StateSyncInternalError(format!("unable to get restored root hash: {}", e))
+        })?;
+
+    if restored_app_hash != app_hash {
+        tracing::error!(
+            height = snapshot.height,
+            expected_app_hash = hex::encode(app_hash),
+            restored_app_hash = hex::encode(restored_app_hash),
+            "restored state does not match app hash, rejecting snapshot"
+        );
+
+        return reject_snapshot(&platform.drive.grove);
+    }
+
+    let platform_state =
+        match PlatformState::versioned_deserialize(&metadata.platform_state, platform_version) {
+            Ok(platform_state) => platform_state,
+            Err(e) => {
+                tracing::error!(height = snapshot.height, "invalid platform state: {}", e);
+                return reject_snapshot(&platform.drive.grove);
+            }
+        };
+
+    if platform_state.last_committed_block_height() != snapshot.height
+        || platform_state.last_committed_block_app_hash() != Some(app_hash)
+    {
+        tracing::error!(
+            height = snapshot.height,
+            state_height = platform_state.last_committed_block_height(),
+            "platform state in snapshot metadata does not match restored state"
+        );
+        return reject_snapshot(&platform.drive.grove);
+    }
+
+    platform.store_platform_state(&platform_state, None, platform_version)?;
+
+    PlatformVersion::set_current(platform_version);
+
+    platform.state.store(Arc::new(platform_state));
+
+    platform
+        .committed_block_height_guard
+        .store(snapshot.height, Ordering::Relaxed);
+
+    tracing::info!(
+        height = snapshot.height,
+        app_hash = hex::encode(app_hash),
+        "state sync completed"
+    );
+
+    Ok(proto::ResponseApplySnapshotChunk {
+        result: ApplySnapshotChunkResult::Accept as i32,
+        refetch_chunks: vec![],
+        reject_senders: vec![],
+        next_chunks: vec![],
+    })
+}
+
+/// Wipe partially restored state so another snapshot can be offered
+fn reject_snapshot(grove: &GroveDb) -> Result<proto::ResponseApplySnapshotChunk, Error> {
+    grove
+        .wipe()
+        .map_err(|e| AbciError::StateSyncInternalError(format!("unable to wipe grovedb: {}", e)))?;
+
+    Ok(proto::ResponseApplySnapshotChunk {
+        result: ApplySnapshotChunkResult::RejectSnapshot as i32,
+        refetch_chunks: vec![],
+        reject_senders: vec![],
+        next_chunks: vec![],
+    })
+}
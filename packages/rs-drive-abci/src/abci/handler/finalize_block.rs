@@ -1,10 +1,16 @@
-use crate::abci::app::{BlockExecutionApplication, PlatformApplication, TransactionalApplication};
+use crate::abci::app::{
+    BlockExecutionApplication, PlatformApplication, SnapshotManagerApplication,
+    TransactionalApplication,
+};
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
 use crate::execution::types::block_execution_context::v0::BlockExecutionContextV0Getters;
 use crate::platform_types::cleaned_abci_messages::finalized_block_cleaned_request::v0::FinalizeBlockCleanedRequest;
+use crate::platform_types::platform_events::CommittedBlock;
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::snapshot::SnapshotMetadata;
 use crate::rpc::core::CoreRPCLike;
+use dpp::serialization::PlatformSerializable;
 use std::sync::atomic::Ordering;
 use tenderdash_abci::proto::abci as proto;
 
@@ -13,7 +19,10 @@ pub fn finalize_block<'a, A, C>(
     request: proto::RequestFinalizeBlock,
 ) -> Result<proto::ResponseFinalizeBlock, Error>
 where
-    A: PlatformApplication<C> + TransactionalApplication<'a> + BlockExecutionApplication,
+    A: PlatformApplication<C>
+        + TransactionalApplication<'a>
+        + BlockExecutionApplication
+        + SnapshotManagerApplication,
     C: CoreRPCLike,
 {
     let _timer = crate::metrics::abci_request_duration("finalize_block");
@@ -44,6 +53,15 @@ where
 
     let block_height = request_finalize_block.height;
 
+    // Committed blocks are only needed if somebody is subscribed to platform events
+    let committed_block = app.platform().events.has_subscribers().then(|| {
+        CommittedBlock::from_transactions(
+            block_height,
+            request_finalize_block.hash,
+            &request_finalize_block.block.data.txs,
+        )
+    });
+
     let block_finalization_outcome = app.platform().finalize_block_proposal(
         request_finalize_block,
         block_execution_context,
@@ -72,5 +90,36 @@ where
         .committed_block_height_guard
         .store(block_height, Ordering::Relaxed);
 
+    if let Some(committed_block) = committed_block {
+        app.platform().events.publish(committed_block);
+    }
+
+    // Snapshots are best effort, failing to create one must not halt the chain
+    if app.platform().config.state_sync.snapshots_enabled {
+        // The committed state might already be on a newer protocol version than the block
+        let platform_state = app.platform().state.load();
+
+        let snapshot_result =
+            platform_state
+                .current_platform_version()
+                .and_then(|state_platform_version| {
+                    let metadata = SnapshotMetadata {
+                        protocol_version: state_platform_version.protocol_version,
+                        platform_state: platform_state.serialize_to_bytes()?,
+                    };
+
+                    app.snapshot_manager().create_snapshot(
+                        &app.platform().drive.grove,
+                        block_height,
+                        metadata,
+                        state_platform_version,
+                    )
+                });
+
+        if let Err(e) = snapshot_result {
+            tracing::error!(height = block_height, "unable to create snapshot: {}", e);
+        }
+    }
+
     Ok(proto::ResponseFinalizeBlock { retain_height: 0 })
 }
@@ -0,0 +1,32 @@
+use crate::abci::app::{PlatformApplication, SnapshotManagerApplication};
+use crate::error::Error;
+use crate::platform_types::snapshot::Snapshot;
+use tenderdash_abci::proto::abci as proto;
+
+pub fn list_snapshots<A, C>(
+    app: &A,
+    _request: proto::RequestListSnapshots,
+) -> Result<proto::ResponseListSnapshots, Error>
+where
+    A: SnapshotManagerApplication + PlatformApplication<C>,
+{
+    let _timer = crate::metrics::abci_request_duration("list_snapshots");
+
+    let snapshots = app
+        .snapshot_manager()
+        .get_snapshots(&app.platform().drive.grove)?
+        .into_iter()
+        .map(convert_snapshots)
+        .collect();
+
+    Ok(proto::ResponseListSnapshots { snapshots })
+}
+
+fn convert_snapshots(snapshot: Snapshot) -> proto::Snapshot {
+    proto::Snapshot {
+        height: snapshot.height,
+        version: snapshot.version as u32,
+        hash: snapshot.hash.to_vec(),
+        metadata: snapshot.metadata,
+    }
+}
@@ -0,0 +1,61 @@
+use crate::abci::app::{PlatformApplication, SnapshotManagerApplication};
+use crate::abci::AbciError;
+use crate::error::Error;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use drive::grovedb::GroveDb;
+use tenderdash_abci::proto::abci as proto;
+
+pub fn load_snapshot_chunk<A, C>(
+    app: &A,
+    request: proto::RequestLoadSnapshotChunk,
+) -> Result<proto::ResponseLoadSnapshotChunk, Error>
+where
+    A: SnapshotManagerApplication + PlatformApplication<C>,
+{
+    let _timer = crate::metrics::abci_request_duration("load_snapshot_chunk");
+
+    let snapshot = app
+        .snapshot_manager()
+        .get_snapshot_at_height(&app.platform().drive.grove, request.height)?
+        .ok_or_else(|| {
+            AbciError::StateSyncBadRequest(format!(
+                "no snapshot available at height {}",
+                request.height
+            ))
+        })?;
+
+    if snapshot.version as u32 != request.version {
+        return Err(AbciError::StateSyncBadRequest(format!(
+            "snapshot at height {} has version {}, but version {} was requested",
+            request.height, snapshot.version, request.version
+        ))
+        .into());
+    }
+
+    let platform_version = app.platform().state.load().current_platform_version()?;
+
+    let checkpoint = GroveDb::open(&snapshot.path).map_err(|e| {
+        AbciError::StateSyncInternalError(format!(
+            "unable to open checkpoint at height {}: {}",
+            snapshot.height, e
+        ))
+    })?;
+
+    let chunk = checkpoint
+        .fetch_chunk(
+            &request.chunk_id,
+            None,
+            snapshot.version,
+            &platform_version.drive.grove_version,
+        )
+        .map_err(|e| {
+            AbciError::StateSyncInternalError(format!(
+                "unable to fetch chunk {} of snapshot at height {}: {}",
+                hex::encode(&request.chunk_id),
+                snapshot.height,
+                e
+            ))
+        })?;
+
+    Ok(proto::ResponseLoadSnapshotChunk { chunk })
+}
@@ -0,0 +1,146 @@
+use crate::abci::app::StateSyncApplication;
+use crate::abci::AbciError;
+use crate::error::Error;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::snapshot::{
+    Snapshot, SnapshotFetchingSession, SnapshotMetadata, CURRENT_STATE_SYNC_VERSION,
+};
+use dpp::version::PlatformVersion;
+use tenderdash_abci::proto::abci as proto;
+use tenderdash_abci::proto::abci::response_offer_snapshot::Result as OfferSnapshotResult;
+
+pub fn offer_snapshot<'p, A, C>(
+    app: &A,
+    request: proto::RequestOfferSnapshot,
+) -> Result<proto::ResponseOfferSnapshot, Error>
+where
+    A: StateSyncApplication<'p, C>,
+    C: 'p,
+{
+    let _timer = crate::metrics::abci_request_duration("offer_snapshot");
+
+    let app_hash: [u8; 32] = request.app_hash.try_into().map_err(|_| {
+        AbciError::StateSyncBadRequest("offered snapshot app hash must be 32 bytes".to_string())
+    })?;
+
+    let offered_snapshot = request.snapshot.ok_or_else(|| {
+        AbciError::StateSyncBadRequest("offer snapshot request must contain a snapshot".to_string())
+    })?;
+
+    if offered_snapshot.version != CURRENT_STATE_SYNC_VERSION as u32 {
+        tracing::debug!(
+            height = offered_snapshot.height,
+            version = offered_snapshot.version,
+            "rejecting snapshot with unsupported format version"
+        );
+        return Ok(response(OfferSnapshotResult::RejectFormat));
+    }
+
+    let Ok(hash) = <[u8; 32]>::try_from(offered_snapshot.hash) else {
+        return Ok(response(OfferSnapshotResult::Reject));
+    };
+
+    // The app hash comes from the light client verified block, the snapshot hash from the peer
+    if hash != app_hash {
+        tracing::debug!(
+            height = offered_snapshot.height,
+            snapshot_hash = hex::encode(hash),
+            app_hash = hex::encode(app_hash),
+            "rejecting snapshot not matching the app hash"
+        );
+        return Ok(response(OfferSnapshotResult::Reject));
+    }
+
+    let metadata = match SnapshotMetadata::from_bytes(&offered_snapshot.metadata) {
+        Ok(metadata) => metadata,
+        Err(e) => {
+            tracing::debug!(
+                height = offered_snapshot.height,
+                "rejecting snapshot: {}",
+                e
+            );
+            return Ok(response(OfferSnapshotResult::Reject));
+        }
+    };
+
+    let Ok(platform_version) = PlatformVersion::get(metadata.protocol_version) else {
+        tracing::debug!(
+            height = offered_snapshot.height,
+            protocol_version = metadata.protocol_version,
+            "rejecting snapshot with unsupported protocol version"
+        );
+        return Ok(response(OfferSnapshotResult::Reject));
+    };
+
+    let platform = app.platform();
+
+    // State sync is only allowed to bootstrap a fresh node, we never wipe executed blocks
+    if platform.state.load().last_committed_block_info().is_some() {
+        tracing::warn!(
+            height = offered_snapshot.height,
+            "aborting state sync: platform already has committed blocks"
+        );
+        return Ok(response(OfferSnapshotResult::Abort));
+    }
+
+    let mut session_guard = app.snapshot_fetching_session().write().unwrap();
+
+    if let Some(session) = session_guard.as_ref() {
+        if session.snapshot.height >= offered_snapshot.height {
+            return Ok(response(OfferSnapshotResult::Reject));
+        }
+
+        tracing::debug!(
+            current_height = session.snapshot.height,
+            offered_height = offered_snapshot.height,
+            "abandoning current snapshot in favor of a newer one"
+        );
+
+        session_guard.take();
+    }
+
+    platform
+        .drive
+        .grove
+        .wipe()
+        .map_err(|e| AbciError::StateSyncInternalError(format!("unable to wipe grovedb: {}", e)))?;
+
+    let state_sync_info = platform
+        .drive
+        .grove
+        .start_snapshot_syncing(
+            app_hash,
+            CURRENT_STATE_SYNC_VERSION,
+            &platform_version.drive.grove_version,
+        )
+        .map_err(|e| {
+            AbciError::StateSyncInternalError(format!("unable to start snapshot syncing: {}", e))
+        })?;
+
+    tracing::info!(
+        height = offered_snapshot.height,
+        app_hash = hex::encode(app_hash),
+        "snapshot accepted, syncing state"
+    );
+
+    session_guard.replace(SnapshotFetchingSession {
+        snapshot: Snapshot {
+            height: offered_snapshot.height,
+            version: CURRENT_STATE_SYNC_VERSION,
+            path: String::new(),
+            hash,
+            metadata: offered_snapshot.metadata,
+        },
+        app_hash,
+        metadata,
+        state_sync_info,
+    });
+
+    Ok(response(OfferSnapshotResult::Accept))
+}
+
+fn response(result: OfferSnapshotResult) -> proto::ResponseOfferSnapshot {
+    proto::ResponseOfferSnapshot {
+        result: result as i32,
+    }
+}
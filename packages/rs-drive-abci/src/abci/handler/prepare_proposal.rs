@@ -125,6 +125,7 @@ where
         app_hash,
         state_transitions_result,
         validator_set_update,
+        consensus_param_updates,
         platform_version,
         mut block_execution_context,
     } = run_result.into_data().map_err(Error::Protocol)?;
@@ -199,8 +200,7 @@ where
             signature: chain_lock.signature.to_bytes().to_vec(),
         }),
         validator_set_update,
-        // TODO: implement consensus param updates
-        consensus_param_updates: None,
+        consensus_param_updates,
         app_version: platform_version.protocol_version as u64,
     };
 
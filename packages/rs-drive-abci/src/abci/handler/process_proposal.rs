@@ -208,6 +208,7 @@ where
         app_hash,
         state_transitions_result: state_transition_results,
         validator_set_update,
+        consensus_param_updates,
         platform_version,
         block_execution_context,
     } = run_result.into_data().map_err(Error::Protocol)?;
@@ -274,8 +275,7 @@ where
         tx_results,
         status: proto::response_process_proposal::ProposalStatus::Accept.into(),
         validator_set_update,
-        // TODO: Implement consensus param updates
-        consensus_param_updates: None,
+        consensus_param_updates,
         events: Vec::new(),
     };
 
@@ -6,6 +6,6 @@ pub mod config;
 /// ABCI applications
 pub mod app;
 
-mod handler;
+pub(crate) mod handler;
 
 pub use error::AbciError;
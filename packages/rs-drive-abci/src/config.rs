@@ -114,6 +114,37 @@ pub struct ExecutionConfig {
     pub epoch_time_length_s: u64,
 }
 
+/// Configuration of ABCI state sync (GroveDB snapshots)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+// NOTE: in renames, we use lower_snake_case, because uppercase does not work; see
+// https://github.com/softprops/envy/issues/61 and https://github.com/softprops/envy/pull/69
+pub struct StateSyncConfig {
+    /// Should we take GroveDB checkpoints and serve them to syncing nodes
+    #[serde(
+        default = "StateSyncConfig::default_snapshots_enabled",
+        deserialize_with = "from_str_or_number"
+    )]
+    pub snapshots_enabled: bool,
+
+    /// Path to the directory where checkpoints are stored
+    #[serde(default = "StateSyncConfig::default_checkpoints_path")]
+    pub checkpoints_path: PathBuf,
+
+    /// A checkpoint is taken every `snapshots_frequency` blocks
+    #[serde(
+        default = "StateSyncConfig::default_snapshots_frequency",
+        deserialize_with = "from_str_or_number"
+    )]
+    pub snapshots_frequency: u64,
+
+    /// Maximum number of checkpoints to keep, older ones are removed
+    #[serde(
+        default = "StateSyncConfig::default_max_num_snapshots",
+        deserialize_with = "from_str_or_number"
+    )]
+    pub max_num_snapshots: usize,
+}
+
 /// Configuration of Dash Platform.
 ///
 /// All fields in this struct can be configured using environment variables.
@@ -180,6 +211,10 @@ pub struct PlatformConfig {
     #[serde(flatten)]
     pub instant_lock: InstantLockConfig,
 
+    /// State sync configuration
+    #[serde(flatten)]
+    pub state_sync: StateSyncConfig,
+
     // todo: this should probably be coming from Tenderdash config. It's a test only param
     /// Approximately how often are blocks produced
     pub block_spacing_ms: u64,
@@ -555,6 +590,24 @@ impl ExecutionConfig {
     }
 }
 
+impl StateSyncConfig {
+    fn default_snapshots_enabled() -> bool {
+        false
+    }
+
+    fn default_checkpoints_path() -> PathBuf {
+        PathBuf::from("/var/lib/dash-platform/data/checkpoints")
+    }
+
+    fn default_snapshots_frequency() -> u64 {
+        3
+    }
+
+    fn default_max_num_snapshots() -> usize {
+        10
+    }
+}
+
 impl PlatformConfig {
     fn default_initial_protocol_version() -> ProtocolVersion {
         INITIAL_PROTOCOL_VERSION
@@ -606,6 +659,17 @@ impl Default for ExecutionConfig {
     }
 }
 
+impl Default for StateSyncConfig {
+    fn default() -> Self {
+        Self {
+            snapshots_enabled: StateSyncConfig::default_snapshots_enabled(),
+            checkpoints_path: StateSyncConfig::default_checkpoints_path(),
+            snapshots_frequency: StateSyncConfig::default_snapshots_frequency(),
+            max_num_snapshots: StateSyncConfig::default_max_num_snapshots(),
+        }
+    }
+}
+
 impl Default for PlatformConfig {
     fn default() -> Self {
         Self::default_mainnet()
@@ -655,6 +719,7 @@ impl PlatformConfig {
             abci: Default::default(),
             core: Default::default(),
             execution: Default::default(),
+            state_sync: Default::default(),
             db_path: PathBuf::from("/var/lib/dash-platform/data"),
             rejections_path: Some(PathBuf::from("/var/log/dash/rejected")),
             #[cfg(feature = "testing-config")]
@@ -698,6 +763,7 @@ impl PlatformConfig {
             abci: Default::default(),
             core: Default::default(),
             execution: Default::default(),
+            state_sync: Default::default(),
             db_path: PathBuf::from("/var/lib/dash-platform/data"),
             rejections_path: Some(PathBuf::from("/var/log/dash/rejected")),
             #[cfg(feature = "testing-config")]
@@ -741,6 +807,7 @@ impl PlatformConfig {
             abci: Default::default(),
             core: Default::default(),
             execution: Default::default(),
+            state_sync: Default::default(),
             db_path: PathBuf::from("/var/lib/dash-platform/data"),
             rejections_path: Some(PathBuf::from("/var/log/dash/rejected")),
             #[cfg(feature = "testing-config")]
@@ -784,6 +851,7 @@ impl PlatformConfig {
             abci: Default::default(),
             core: Default::default(),
             execution: Default::default(),
+            state_sync: Default::default(),
             db_path: PathBuf::from("/var/lib/dash-platform/data"),
             rejections_path: Some(PathBuf::from("/var/log/dash/rejected")),
             #[cfg(feature = "testing-config")]
@@ -873,6 +941,8 @@ mod tests {
         let config = super::PlatformConfig::from_env().expect("expected config from env");
         assert!(config.execution.verify_sum_trees);
         assert_ne!(config.validator_set.quorum_type, QuorumType::UNKNOWN);
+        assert_eq!(config.abci.state_transition_wait_timeout_ms, 80000);
+        assert_eq!(config.abci.max_state_transition_waiters, 1000);
         for id in vectors {
             matches!(config.abci.log[id.0].destination, LogDestination::Bytes);
         }
@@ -161,6 +161,20 @@ where
             platform_version,
         )?;
 
+        // Migrate the state on the first block of a new protocol version
+        let previous_protocol_version = last_committed_platform_state
+            .current_platform_version()?
+            .protocol_version;
+
+        if previous_protocol_version != platform_version.protocol_version {
+            self.perform_events_on_first_block_of_protocol_change(
+                &block_info,
+                transaction,
+                previous_protocol_version,
+                platform_version,
+            )?;
+        }
+
         // If there is a core chain lock update, we should start by verifying it
         if let Some(core_chain_lock_update) = core_chain_lock_update.as_ref() {
             if !known_from_us {
@@ -325,6 +339,9 @@ where
             platform_version,
         )?;
 
+        // Fill indexes added to existing document types for a bounded number of documents
+        self.backfill_document_indexes(Some(transaction), platform_version)?;
+
         // Create a new block execution context
 
         let mut block_execution_context: BlockExecutionContext =
@@ -371,6 +388,9 @@ where
             platform_version,
         )?;
 
+        let consensus_param_updates =
+            self.consensus_param_updates(&block_info, Some(transaction), platform_version)?;
+
         if tracing::enabled!(tracing::Level::TRACE) {
             tracing::trace!(
                 method = "run_block_proposal_v0",
@@ -389,6 +409,7 @@ where
                 app_hash: root_hash,
                 state_transitions_result,
                 validator_set_update,
+                consensus_param_updates,
                 platform_version,
                 block_execution_context,
             },
@@ -0,0 +1,53 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+
+use crate::platform_types::platform::Platform;
+
+use dpp::version::PlatformVersion;
+use drive::grovedb::TransactionArg;
+
+impl<C> Platform<C> {
+    /// Fills the indexes that were added to existing document types for a bounded number of
+    /// existing documents. Once all documents of a document type are indexed, the added
+    /// indexes can be queried.
+    ///
+    /// Indexes can only be added to existing document types from protocol version 2, the
+    /// backfill doesn't run for previous versions.
+    ///
+    /// This function is a version handler that directs to specific version implementations
+    /// of the backfill_document_indexes function.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction` - A `TransactionArg` reference representing the current transaction.
+    /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
+    ///   the method to call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error>` - Returns `Ok(())` if successful, otherwise returns an `Error`.
+    pub(in crate::execution) fn backfill_document_indexes(
+        &self,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .block_end
+            .backfill_document_indexes
+        {
+            Some(0) => self.backfill_document_indexes_v0(transaction, platform_version),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "backfill_document_indexes".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            // Indexes can't be added to existing document types before, so there is nothing
+            // to backfill
+            None => Ok(()),
+        }
+    }
+}
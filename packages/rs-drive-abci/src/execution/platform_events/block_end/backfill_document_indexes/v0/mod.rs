@@ -0,0 +1,34 @@
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use dpp::version::PlatformVersion;
+use drive::grovedb::TransactionArg;
+
+impl<C> Platform<C> {
+    pub(super) fn backfill_document_indexes_v0(
+        &self,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let max_documents = platform_version
+            .drive_abci
+            .validation_and_processing
+            .event_constants
+            .maximum_documents_to_backfill_indexes;
+
+        let backfilled_documents = self.drive.backfill_pending_index_builds(
+            max_documents,
+            transaction,
+            platform_version,
+        )?;
+
+        if backfilled_documents > 0 {
+            tracing::debug!(
+                backfilled_documents,
+                "filled added indexes for {} existing documents",
+                backfilled_documents
+            );
+        }
+
+        Ok(())
+    }
+}
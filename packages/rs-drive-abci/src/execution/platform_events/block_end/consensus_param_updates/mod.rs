@@ -0,0 +1,53 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+
+use crate::platform_types::platform::Platform;
+
+use dpp::block::block_info::BlockInfo;
+use dpp::version::PlatformVersion;
+use drive::grovedb::TransactionArg;
+use tenderdash_abci::proto::types::ConsensusParams;
+
+impl<C> Platform<C> {
+    /// Looks up the consensus params updates scheduled with the feature flags data contract.
+    ///
+    /// This function is a version handler that directs to specific version implementations
+    /// of the consensus_param_updates function.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_info` - Information about the block being executed.
+    /// * `transaction` - The transaction in which the block is executed.
+    /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
+    ///   the method to call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<ConsensusParams>, Error>` - The consensus params which Tenderdash must
+    ///   apply from the next block on, or `None` if there are no updates scheduled or if the
+    ///   platform version does not apply consensus params updates yet.
+    ///
+    pub fn consensus_param_updates(
+        &self,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<ConsensusParams>, Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .block_end
+            .consensus_param_updates
+        {
+            None => Ok(None),
+            Some(0) => self.consensus_param_updates_v0(block_info, transaction, platform_version),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "consensus_param_updates".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
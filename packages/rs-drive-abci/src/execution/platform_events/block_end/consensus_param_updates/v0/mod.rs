@@ -0,0 +1,451 @@
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::platform_value::btreemap_extensions::BTreeValueMapPathHelper;
+use dpp::platform_value::Value;
+use dpp::system_data_contracts::feature_flags_contract;
+use dpp::system_data_contracts::feature_flags_contract::v1::document_types::update_consensus_params;
+use dpp::system_data_contracts::feature_flags_contract::v1::document_types::update_consensus_params::properties;
+use dpp::version::PlatformVersion;
+use drive::drive::document::query::QueryDocumentsOutcomeV0Methods;
+use drive::grovedb::TransactionArg;
+use drive::query::{DriveDocumentQuery, InternalClauses, WhereClause, WhereOperator};
+use indexmap::IndexMap;
+use std::collections::BTreeMap;
+use tenderdash_abci::proto::google::protobuf::Duration;
+use tenderdash_abci::proto::types::{BlockParams, ConsensusParams, EvidenceParams, VersionParams};
+
+/// The maximum block size Tenderdash accepts, in bytes
+const MAX_BLOCK_SIZE_BYTES: i64 = 104_857_600;
+
+const NANOS_PER_SECOND: i32 = 1_000_000_000;
+
+impl<C> Platform<C> {
+    /// Tenderdash applies consensus params updates returned for a block starting from the next
+    /// block, so we are looking for the update scheduled to be enabled at the next height.
+    pub(super) fn consensus_param_updates_v0(
+        &self,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<ConsensusParams>, Error> {
+        // The feature flags contract is not registered at genesis,
+        // so there is nothing to apply until it's deployed
+        let Some(contract_fetch_info) = self.drive.get_contract_with_fetch_info(
+            feature_flags_contract::ID_BYTES,
+            true,
+            transaction,
+            platform_version,
+        )?
+        else {
+            return Ok(None);
+        };
+
+        let contract = &contract_fetch_info.contract;
+
+        let document_type = contract.document_type_for_name(update_consensus_params::NAME)?;
+
+        let enable_at_height = block_info.height + 1;
+
+        let mut equal_clauses = BTreeMap::new();
+
+        equal_clauses.insert(
+            properties::PROPERTY_ENABLE_AT_HEIGHT.to_string(),
+            WhereClause {
+                field: properties::PROPERTY_ENABLE_AT_HEIGHT.to_string(),
+                operator: WhereOperator::Equal,
+                value: Value::U64(enable_at_height),
+            },
+        );
+
+        let drive_query = DriveDocumentQuery {
+            contract,
+            document_type,
+            internal_clauses: InternalClauses {
+                primary_key_in_clause: None,
+                primary_key_equal_clause: None,
+                in_clause: None,
+                range_clause: None,
+                equal_clauses,
+            },
+            offset: None,
+            // enableAtHeight index is unique
+            limit: Some(1),
+            order_by: IndexMap::new(),
+            start_at: None,
+            start_at_included: true,
+            block_time_ms: None,
+        };
+
+        let documents = self
+            .drive
+            .query_documents(
+                drive_query,
+                None,
+                false,
+                transaction,
+                Some(platform_version.protocol_version),
+            )?
+            .documents_owned();
+
+        let Some(document) = documents.first() else {
+            return Ok(None);
+        };
+
+        match consensus_params_from_document(document, platform_version) {
+            Ok(consensus_params) => {
+                tracing::info!(
+                    height = enable_at_height,
+                    ?consensus_params,
+                    "consensus params will be updated at height {}",
+                    enable_at_height
+                );
+
+                Ok(Some(consensus_params))
+            }
+            Err(reason) => {
+                // Updates are scheduled in advance, so an invalid one must not halt the chain
+                tracing::warn!(
+                    height = enable_at_height,
+                    document_id = %document.id(),
+                    "skipping invalid consensus params update scheduled for height {}: {}",
+                    enable_at_height,
+                    reason
+                );
+
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// Translates an `updateConsensusParams` feature flag document into Tenderdash consensus params
+/// and checks that Tenderdash is able to apply them
+fn consensus_params_from_document(
+    document: &Document,
+    platform_version: &PlatformVersion,
+) -> Result<ConsensusParams, String> {
+    let document_properties = document.properties();
+
+    let get_integer = |path: &str| -> Result<Option<i64>, String> {
+        document_properties
+            .get_optional_integer_at_path(path)
+            .map_err(|e| format!("invalid {}: {}", path, e))
+    };
+
+    let block_max_bytes = get_integer(properties::PROPERTY_BLOCK_MAX_BYTES)?;
+    let block_max_gas = get_integer(properties::PROPERTY_BLOCK_MAX_GAS)?;
+
+    let block = if block_max_bytes.is_some() || block_max_gas.is_some() {
+        let max_bytes = block_max_bytes.ok_or("block.maxBytes must be set with block.maxGas")?;
+
+        if max_bytes <= 0 || max_bytes > MAX_BLOCK_SIZE_BYTES {
+            return Err(format!(
+                "block.maxBytes {} out of bounds of [1, {}]",
+                max_bytes, MAX_BLOCK_SIZE_BYTES
+            ));
+        }
+
+        // -1 means unlimited gas for Tenderdash
+        let max_gas = block_max_gas.unwrap_or(-1);
+
+        if max_gas < -1 {
+            return Err(format!("block.maxGas {} must be -1 or greater", max_gas));
+        }
+
+        Some(BlockParams { max_bytes, max_gas })
+    } else {
+        None
+    };
+
+    let evidence_max_age_num_blocks =
+        get_integer(properties::PROPERTY_EVIDENCE_MAX_AGE_NUM_BLOCKS)?;
+    let evidence_max_age_seconds =
+        get_integer(properties::PROPERTY_EVIDENCE_MAX_AGE_DURATION_SECONDS)?;
+    let evidence_max_age_nanos = get_integer(properties::PROPERTY_EVIDENCE_MAX_AGE_DURATION_NANOS)?;
+    let evidence_max_bytes = get_integer(properties::PROPERTY_EVIDENCE_MAX_BYTES)?;
+
+    let evidence = if evidence_max_age_num_blocks.is_some()
+        || evidence_max_age_seconds.is_some()
+        || evidence_max_bytes.is_some()
+    {
+        // Tenderdash replaces evidence params as a whole, so all of them must be set
+        let (Some(max_age_num_blocks), Some(seconds), Some(max_bytes)) = (
+            evidence_max_age_num_blocks,
+            evidence_max_age_seconds,
+            evidence_max_bytes,
+        ) else {
+            return Err(
+                "evidence.maxAgeNumBlocks, evidence.maxAgeDuration and evidence.maxBytes must be set together"
+                    .to_string(),
+            );
+        };
+
+        if max_age_num_blocks <= 0 {
+            return Err(format!(
+                "evidence.maxAgeNumBlocks {} must be positive",
+                max_age_num_blocks
+            ));
+        }
+
+        let nanos = evidence_max_age_nanos.unwrap_or_default();
+
+        if seconds <= 0 || !(0..NANOS_PER_SECOND as i64).contains(&nanos) {
+            return Err(format!(
+                "evidence.maxAgeDuration {}s {}ns must be positive with nanos lower than a second",
+                seconds, nanos
+            ));
+        }
+
+        if max_bytes <= 0 {
+            return Err(format!("evidence.maxBytes {} must be positive", max_bytes));
+        }
+
+        if let Some(block) = block.as_ref() {
+            if max_bytes > block.max_bytes {
+                return Err(format!(
+                    "evidence.maxBytes {} must not be greater than block.maxBytes {}",
+                    max_bytes, block.max_bytes
+                ));
+            }
+        }
+
+        Some(EvidenceParams {
+            max_age_num_blocks,
+            max_age_duration: Some(Duration {
+                seconds,
+                nanos: nanos as i32,
+            }),
+            max_bytes,
+        })
+    } else {
+        None
+    };
+
+    let version = match get_integer(properties::PROPERTY_VERSION_APP_VERSION)? {
+        // The app version is driven by protocol upgrade voting and reported with every block,
+        // so feature flags can't move it elsewhere
+        Some(app_version) if app_version != platform_version.protocol_version as i64 => {
+            return Err(format!(
+                "version.appVersion {} doesn't match protocol version {}",
+                app_version, platform_version.protocol_version
+            ));
+        }
+        Some(app_version) => Some(VersionParams {
+            app_version: app_version as u64,
+        }),
+        None => None,
+    };
+
+    if block.is_none() && evidence.is_none() && version.is_none() {
+        return Err("no consensus params to update".to_string());
+    }
+
+    Ok(ConsensusParams {
+        block,
+        evidence,
+        version,
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
+    use dpp::platform_value::platform_value;
+    use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+    use drive::util::test_helpers::setup::{setup_document, setup_system_data_contract};
+
+    fn schedule_consensus_params_update(
+        platform: &Platform<crate::rpc::core::MockCoreRPCLike>,
+        data: Value,
+        platform_version: &PlatformVersion,
+    ) {
+        let data_contract =
+            load_system_data_contract(SystemDataContract::FeatureFlags, platform_version)
+                .expect("expected to load feature flags contract");
+
+        if platform
+            .drive
+            .get_contract_with_fetch_info(
+                feature_flags_contract::ID_BYTES,
+                false,
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch contract")
+            .is_none()
+        {
+            setup_system_data_contract(&platform.drive, &data_contract, None);
+        }
+
+        let document_type = data_contract
+            .document_type_for_name(update_consensus_params::NAME)
+            .expect("expected document type");
+
+        let document = document_type
+            .create_document_from_data(
+                data,
+                feature_flags_contract::OWNER_ID,
+                1,
+                1,
+                [1; 32],
+                platform_version,
+            )
+            .expect("expected to create document");
+
+        setup_document(
+            &platform.drive,
+            &document,
+            &data_contract,
+            document_type,
+            None,
+        );
+    }
+
+    #[test]
+    fn should_return_none_without_feature_flags_contract() {
+        let platform_version = PlatformVersion::latest();
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let block_info = BlockInfo::default_with_height(10);
+
+        let result = platform
+            .consensus_param_updates_v0(&block_info, None, platform_version)
+            .expect("expected to look up consensus params");
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn should_return_consensus_params_scheduled_for_next_height() {
+        let platform_version = PlatformVersion::latest();
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        schedule_consensus_params_update(
+            &platform,
+            platform_value!({
+                "enableAtHeight": 11u64,
+                "block": {
+                    "maxBytes": 2_000_000u64,
+                    "maxGas": 100u64,
+                },
+                "evidence": {
+                    "maxAgeNumBlocks": 1_000u64,
+                    "maxAgeDuration": {
+                        "seconds": 3_600u64,
+                        "nanos": 0u64,
+                    },
+                    "maxBytes": 1_000u64,
+                },
+            }),
+            platform_version,
+        );
+
+        let result = platform
+            .consensus_param_updates_v0(&BlockInfo::default_with_height(9), None, platform_version)
+            .expect("expected to look up consensus params");
+
+        assert_eq!(result, None);
+
+        let result = platform
+            .consensus_param_updates_v0(&BlockInfo::default_with_height(10), None, platform_version)
+            .expect("expected to look up consensus params");
+
+        assert_eq!(
+            result,
+            Some(ConsensusParams {
+                block: Some(BlockParams {
+                    max_bytes: 2_000_000,
+                    max_gas: 100,
+                }),
+                evidence: Some(EvidenceParams {
+                    max_age_num_blocks: 1_000,
+                    max_age_duration: Some(Duration {
+                        seconds: 3_600,
+                        nanos: 0,
+                    }),
+                    max_bytes: 1_000,
+                }),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn should_skip_invalid_consensus_params() {
+        let platform_version = PlatformVersion::latest();
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        schedule_consensus_params_update(
+            &platform,
+            platform_value!({
+                "enableAtHeight": 11u64,
+                "block": {
+                    "maxBytes": 1_000u64,
+                },
+                "evidence": {
+                    "maxAgeNumBlocks": 1_000u64,
+                    "maxAgeDuration": {
+                        "seconds": 3_600u64,
+                        "nanos": 0u64,
+                    },
+                    "maxBytes": 2_000u64,
+                },
+            }),
+            platform_version,
+        );
+
+        let result = platform
+            .consensus_param_updates_v0(&BlockInfo::default_with_height(10), None, platform_version)
+            .expect("expected to look up consensus params");
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn should_only_apply_consensus_params_from_the_version_that_enables_them() {
+        let platform_version = PlatformVersion::latest();
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        schedule_consensus_params_update(
+            &platform,
+            platform_value!({
+                "enableAtHeight": 11u64,
+                "block": {
+                    "maxBytes": 2_000_000u64,
+                    "maxGas": 100u64,
+                },
+            }),
+            platform_version,
+        );
+
+        let result = platform
+            .consensus_param_updates(
+                &BlockInfo::default_with_height(10),
+                None,
+                PlatformVersion::first(),
+            )
+            .expect("expected to look up consensus params");
+
+        assert_eq!(result, None);
+
+        let result = platform
+            .consensus_param_updates(&BlockInfo::default_with_height(10), None, platform_version)
+            .expect("expected to look up consensus params");
+
+        assert!(result.is_some());
+    }
+}
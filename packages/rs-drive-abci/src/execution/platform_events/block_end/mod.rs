@@ -1,3 +1,7 @@
+/// Filling indexes added to existing document types
+pub(in crate::execution) mod backfill_document_indexes;
+/// Consensus params updates scheduled with feature flags
+pub(in crate::execution) mod consensus_param_updates;
 /// Updating the state cache happens as the final part of block finalization
 pub(in crate::execution) mod update_state_cache;
 /// Validator set update
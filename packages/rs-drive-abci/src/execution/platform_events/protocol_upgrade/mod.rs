@@ -1,2 +1,3 @@
 mod check_for_desired_protocol_upgrade;
+mod perform_events_on_first_block_of_protocol_change;
 mod upgrade_protocol_version;
@@ -0,0 +1,165 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use dpp::block::block_info::BlockInfo;
+use dpp::util::deserializer::ProtocolVersion;
+use dpp::version::PlatformVersion;
+use drive::grovedb::Transaction;
+
+impl<C> Platform<C> {
+    /// Performs the events that need to happen on the first block of a new protocol version,
+    /// usually migrations of the state to what the new protocol version expects.
+    ///
+    /// This function should be called after the protocol version was upgraded.
+    ///
+    /// It takes four parameters:
+    /// * `block_info`: Information about the current block.
+    /// * `transaction`: The current transaction.
+    /// * `previous_protocol_version`: The protocol version of the previous block.
+    /// * `platform_version`: The new version of the platform.
+    pub(in crate::execution) fn perform_events_on_first_block_of_protocol_change(
+        &self,
+        block_info: &BlockInfo,
+        transaction: &Transaction,
+        previous_protocol_version: ProtocolVersion,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .protocol_upgrade
+            .perform_events_on_first_block_of_protocol_change
+        {
+            Some(0) => self.perform_events_on_first_block_of_protocol_change_v0(
+                block_info,
+                transaction,
+                previous_protocol_version,
+                platform_version,
+            ),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "perform_events_on_first_block_of_protocol_change".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+    use dpp::system_data_contracts::dpns_contract;
+    use drive::drive::document::index_builds::PENDING_INDEX_BUILDS_KEY;
+    use drive::drive::system::misc_path;
+    use drive::util::grove_operations::DirectQueryType;
+
+    #[test]
+    fn should_create_pending_index_builds_tree_when_upgrading_to_version_2() {
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let has_pending_index_builds_tree = || {
+            platform
+                .drive
+                .grove_has_raw(
+                    (&misc_path()).into(),
+                    PENDING_INDEX_BUILDS_KEY,
+                    DirectQueryType::StatefulDirectQuery,
+                    Some(&transaction),
+                    &mut vec![],
+                    &PlatformVersion::latest().drive,
+                )
+                .expect("expected to check the pending index builds tree")
+        };
+
+        // Nothing happens within the first protocol version
+        platform
+            .perform_events_on_first_block_of_protocol_change(
+                &BlockInfo::default(),
+                &transaction,
+                1,
+                PlatformVersion::first(),
+            )
+            .expect("expected to perform events");
+
+        assert!(!has_pending_index_builds_tree());
+
+        platform
+            .perform_events_on_first_block_of_protocol_change(
+                &BlockInfo::default(),
+                &transaction,
+                1,
+                PlatformVersion::get(2).expect("expected protocol version 2"),
+            )
+            .expect("expected to perform events");
+
+        assert!(has_pending_index_builds_tree());
+    }
+
+    #[test]
+    fn should_update_dpns_contract_when_upgrading_to_version_2() {
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let platform_version = PlatformVersion::get(2).expect("expected protocol version 2");
+
+        let stored_dpns_contract_version = || {
+            platform
+                .drive
+                .get_contract_with_fetch_info(
+                    dpns_contract::ID_BYTES,
+                    false,
+                    Some(&transaction),
+                    platform_version,
+                )
+                .expect("expected to fetch the DPNS contract")
+                .expect("expected the DPNS contract to exist")
+                .contract
+                .version()
+        };
+
+        assert_eq!(stored_dpns_contract_version(), 1);
+
+        platform
+            .perform_events_on_first_block_of_protocol_change(
+                &BlockInfo::default(),
+                &transaction,
+                1,
+                platform_version,
+            )
+            .expect("expected to perform events");
+
+        assert_eq!(stored_dpns_contract_version(), 2);
+        assert_eq!(
+            platform
+                .drive
+                .cache
+                .system_data_contracts
+                .load_dpns()
+                .version(),
+            2
+        );
+
+        let cached_dpns_contract = platform.drive.cache.system_data_contracts.load_dpns();
+
+        let domain_document_type = cached_dpns_contract
+            .document_type_for_name(dpns_contract::v2::document_types::domain::NAME)
+            .expect("expected the domain document type");
+
+        assert!(domain_document_type
+            .flattened_properties()
+            .contains_key("records.dataContract"));
+    }
+}
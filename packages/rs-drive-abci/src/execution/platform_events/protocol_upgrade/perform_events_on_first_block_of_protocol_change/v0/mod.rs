@@ -0,0 +1,70 @@
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use dpp::block::block_info::BlockInfo;
+use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+use dpp::util::deserializer::ProtocolVersion;
+use dpp::version::PlatformVersion;
+use drive::drive::document::index_builds::PENDING_INDEX_BUILDS_KEY;
+use drive::drive::system::misc_path;
+use drive::grovedb::Transaction;
+
+impl<C> Platform<C> {
+    /// Runs the migrations of every protocol version between the previous and the new one,
+    /// each of them only once, on the first block of the new protocol version.
+    pub(super) fn perform_events_on_first_block_of_protocol_change_v0(
+        &self,
+        block_info: &BlockInfo,
+        transaction: &Transaction,
+        previous_protocol_version: ProtocolVersion,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        if previous_protocol_version < 2 && platform_version.protocol_version >= 2 {
+            self.transition_to_version_2(block_info, transaction, platform_version)?;
+        }
+
+        Ok(())
+    }
+
+    /// Protocol version 2 allows adding indexes to existing document types. The pending
+    /// index builds tree is created here so that the block end backfill, which starts
+    /// with this version, always finds it.
+    ///
+    /// It also introduces typed DPNS domain records, so the stored DPNS contract is replaced
+    /// with the version the domain data trigger of this protocol version validates against.
+    fn transition_to_version_2(
+        &self,
+        block_info: &BlockInfo,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        self.drive.grove_insert_empty_tree(
+            (&misc_path()).into(),
+            PENDING_INDEX_BUILDS_KEY,
+            Some(transaction),
+            None,
+            &mut vec![],
+            &platform_version.drive,
+        )?;
+
+        if platform_version.system_data_contracts.dpns >= 2 {
+            let dpns_contract =
+                load_system_data_contract(SystemDataContract::DPNS, platform_version)?;
+
+            self.drive.update_contract(
+                &dpns_contract,
+                *block_info,
+                true,
+                Some(transaction),
+                platform_version,
+                None,
+            )?;
+
+            self.drive
+                .cache
+                .system_data_contracts
+                .reload_dpns(platform_version)?;
+        }
+
+        Ok(())
+    }
+}
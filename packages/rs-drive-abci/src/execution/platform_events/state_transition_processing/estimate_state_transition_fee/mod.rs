@@ -0,0 +1,53 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+use crate::rpc::core::CoreRPCLike;
+use dpp::version::PlatformVersion;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Executes a raw state transition against the latest committed state without persisting
+    /// anything, in order to learn the fees it would be charged.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw_state_transition` - The serialized state transition.
+    /// * `platform_state` - The latest committed platform state.
+    /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
+    ///   the method to call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<StateTransitionExecutionResult, Error>` - The result the state transition would
+    ///   have if it was included in the next block, along with the fees it would be charged.
+    pub(crate) fn estimate_state_transition_fee(
+        &self,
+        raw_state_transition: &[u8],
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<StateTransitionExecutionResult, Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .state_transition_processing
+            .estimate_state_transition_fee
+        {
+            0 => self.estimate_state_transition_fee_v0(
+                raw_state_transition,
+                platform_state,
+                platform_version,
+            ),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "estimate_state_transition_fee".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,304 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::version::PlatformVersion;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn estimate_state_transition_fee_v0(
+        &self,
+        raw_state_transition: &[u8],
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<StateTransitionExecutionResult, Error> {
+        // The state transition is processed exactly like it would be in the next block, but
+        // in a transaction that is never committed so that the state stays untouched, and with
+        // Drive caches of its own so that the caches used to execute blocks stay untouched too
+        let platform = self.with_isolated_drive_cache();
+
+        let last_block_info = platform_state.last_block_info();
+
+        // The time of the next block isn't known yet, so the last block time is used
+        let next_block_info = BlockInfo {
+            height: last_block_info.height + 1,
+            ..*last_block_info
+        };
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let processing_result = platform.process_raw_state_transitions(
+            &vec![raw_state_transition.to_vec()],
+            platform_state,
+            &next_block_info,
+            &transaction,
+            platform_version,
+            false,
+            None,
+        )?;
+
+        drop(transaction);
+
+        processing_result
+            .into_execution_results()
+            .into_iter()
+            .next()
+            .ok_or(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                "expected exactly one state transition execution result",
+            )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execution::validation::state_transition::tests::{
+        setup_identity, setup_identity_with_withdrawal_key_and_system_credits,
+    };
+    use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use assert_matches::assert_matches;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::dash_to_credits;
+    use dpp::data_contract::accessors::v0::{DataContractV0Getters, DataContractV0Setters};
+    use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+    use dpp::data_contract::schema::DataContractSchemaMethodsV0;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+    use dpp::platform_value::platform_value;
+    use dpp::serialization::PlatformSerializable;
+    use dpp::state_transition::data_contract_update_transition::methods::DataContractUpdateTransitionMethodsV0;
+    use dpp::state_transition::data_contract_update_transition::DataContractUpdateTransition;
+    use dpp::state_transition::identity_credit_transfer_transition::methods::IdentityCreditTransferTransitionMethodsV0;
+    use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+    use dpp::tests::json_document::json_document_to_contract;
+    use drive::util::storage_flags::StorageFlags;
+    use platform_version::version::PlatformVersion;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_estimating_fee_does_not_change_state() {
+        let platform_version = PlatformVersion::latest();
+
+        let mut platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let (sender, signer, _, transfer_key) =
+            setup_identity_with_withdrawal_key_and_system_credits(
+                &mut platform,
+                958,
+                dash_to_credits!(1),
+            );
+
+        let (recipient, _, _) = setup_identity(&mut platform, 450, dash_to_credits!(0.1));
+
+        let credit_transfer_transition = IdentityCreditTransferTransition::try_from_identity(
+            &sender,
+            recipient.id(),
+            dash_to_credits!(0.5),
+            0,
+            signer,
+            Some(&transfer_key),
+            1,
+            platform_version,
+            None,
+        )
+        .expect("expected a credit transfer transition");
+
+        let serialized_transition = credit_transfer_transition
+            .serialize_to_bytes()
+            .expect("expected serialized state transition");
+
+        let platform_state = platform.state.load();
+
+        let first_estimation = platform
+            .estimate_state_transition_fee(
+                &serialized_transition,
+                &platform_state,
+                platform_version,
+            )
+            .expect("expected to estimate fee");
+
+        let fee_result = assert_matches!(
+            &first_estimation,
+            StateTransitionExecutionResult::SuccessfulExecution(_, fee_result) => fee_result
+        );
+
+        assert!(fee_result.processing_fee > 0);
+
+        // Nothing was committed, so the same state transition can be estimated again
+        let second_estimation = platform
+            .estimate_state_transition_fee(
+                &serialized_transition,
+                &platform_state,
+                platform_version,
+            )
+            .expect("expected to estimate fee");
+
+        assert_eq!(first_estimation, second_estimation);
+
+        let sender_balance = platform
+            .drive
+            .fetch_identity_balance(sender.id().to_buffer(), None, platform_version)
+            .expect("expected to fetch balance");
+
+        assert_eq!(sender_balance, Some(dash_to_credits!(1)));
+
+        let recipient_balance = platform
+            .drive
+            .fetch_identity_balance(recipient.id().to_buffer(), None, platform_version)
+            .expect("expected to fetch balance");
+
+        assert_eq!(recipient_balance, Some(dash_to_credits!(0.1)));
+    }
+
+    #[test]
+    fn test_estimating_fee_of_contract_update_does_not_change_cached_contract() {
+        let platform_version = PlatformVersion::latest();
+
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let (identity, signer, key) = setup_identity(&mut platform, 958, dash_to_credits!(0.1));
+
+        let mut contract = json_document_to_contract(
+            "tests/supporting_files/contract/crypto-card-game/crypto-card-game-direct-purchase.json",
+            true,
+            platform_version,
+        )
+        .expect("expected to get data contract");
+
+        contract.set_owner_id(identity.id());
+
+        platform
+            .drive
+            .apply_contract(
+                &contract,
+                BlockInfo::default(),
+                true,
+                StorageFlags::optional_default_as_cow(),
+                None,
+                platform_version,
+            )
+            .expect("expected to apply contract successfully");
+
+        // the contract is used by blocks, so it is in the global cache
+        let cached_contract = platform
+            .drive
+            .get_contract_with_fetch_info(contract.id().to_buffer(), true, None, platform_version)
+            .expect("expected to fetch contract")
+            .expect("expected the contract to exist");
+
+        let mut card_schema = contract
+            .document_type_for_name("card")
+            .expect("expected card document type")
+            .schema()
+            .clone();
+
+        card_schema
+            .get_mut("indices")
+            .expect("expected to get indices")
+            .expect("expected indices to be set")
+            .to_array_mut()
+            .expect("expected indices to be an array")
+            .push(platform_value!({
+                "name": "name",
+                "properties": [{"name": "asc"}],
+            }));
+
+        let mut updated_contract = contract.clone();
+
+        updated_contract
+            .set_document_schema("card", card_schema, true, &mut vec![], platform_version)
+            .expect("expected to set document schema");
+
+        updated_contract.set_version(2);
+
+        let data_contract_update_transition = DataContractUpdateTransition::new_from_data_contract(
+            updated_contract,
+            &identity.into_partial_identity_info(),
+            key.id(),
+            2,
+            0,
+            &signer,
+            platform_version,
+            None,
+        )
+        .expect("expected to create data contract update transition");
+
+        let serialized_transition = data_contract_update_transition
+            .serialize_to_bytes()
+            .expect("expected serialized state transition");
+
+        let platform_state = platform.state.load();
+
+        let estimation = platform
+            .estimate_state_transition_fee(
+                &serialized_transition,
+                &platform_state,
+                platform_version,
+            )
+            .expect("expected to estimate fee");
+
+        assert_matches!(
+            estimation,
+            StateTransitionExecutionResult::SuccessfulExecution(..)
+        );
+
+        // Neither the global nor the block cache got the updated contract or lost the cached one
+        for is_block_cache in [false, true] {
+            let contract_in_cache = platform
+                .drive
+                .cache
+                .data_contracts
+                .get(contract.id().to_buffer(), is_block_cache)
+                .expect("expected the contract to stay in cache");
+
+            assert!(Arc::ptr_eq(&contract_in_cache, &cached_contract));
+            assert_eq!(contract_in_cache.contract.version(), 1);
+        }
+
+        let stored_contract = platform
+            .drive
+            .fetch_contract(
+                contract.id().to_buffer(),
+                None,
+                None,
+                None,
+                platform_version,
+            )
+            .unwrap()
+            .expect("expected to fetch contract")
+            .expect("expected the contract to exist");
+
+        assert_eq!(stored_contract.contract.version(), 1);
+    }
+
+    #[test]
+    fn test_estimating_fee_of_invalid_encoding() {
+        let platform_version = PlatformVersion::latest();
+
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let platform_state = platform.state.load();
+
+        let result = platform
+            .estimate_state_transition_fee(&[0; 8], &platform_state, platform_version)
+            .expect("expected to estimate fee");
+
+        assert_matches!(
+            result,
+            StateTransitionExecutionResult::UnpaidConsensusError(_)
+        );
+    }
+}
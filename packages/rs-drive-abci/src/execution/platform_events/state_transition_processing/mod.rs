@@ -1,4 +1,5 @@
 mod decode_raw_state_transitions;
+mod estimate_state_transition_fee;
 mod execute_event;
 mod process_raw_state_transitions;
 mod validate_fees_of_event;
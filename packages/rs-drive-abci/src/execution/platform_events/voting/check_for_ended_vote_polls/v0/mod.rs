@@ -1,3 +1,4 @@
+use crate::error::execution::ExecutionError;
 use crate::error::Error;
 use crate::platform_types::platform::Platform;
 use crate::platform_types::platform_state::PlatformState;
@@ -164,6 +165,11 @@ where
                         )?;
                         Ok(ResolvedVotePollWithVotes::ContestedDocumentResourceVotePollWithContractInfoAndVotes(resolved_contested_document_resource_vote_poll, identifiers_voting_for_contenders))
                     }
+                    ResolvedVotePoll::GovernanceProposalVotePoll(_) => {
+                        Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                            "governance proposal vote polls can not exist in this version",
+                        )))
+                    }
                 }
             }).collect::<Result<Vec<ResolvedVotePollWithVotes>, Error>>()?;
             Ok((end_date, vote_polls_with_votes))
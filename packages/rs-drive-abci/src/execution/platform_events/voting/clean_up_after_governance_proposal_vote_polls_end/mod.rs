@@ -0,0 +1,50 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use dpp::voting::vote_polls::governance_proposal_vote_poll::GovernanceProposalVotePoll;
+use drive::grovedb::TransactionArg;
+use std::collections::BTreeMap;
+
+mod v0;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Removes the votes of governance proposal vote polls that have ended, their results are kept
+    pub(in crate::execution) fn clean_up_after_governance_proposal_vote_polls_end(
+        &self,
+        vote_polls: Vec<(
+            &GovernanceProposalVotePoll,
+            &BTreeMap<Identifier, YesNoAbstainVoteChoice>,
+        )>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .voting
+            .clean_up_after_governance_proposal_vote_polls_end
+        {
+            Some(0) => self.clean_up_after_governance_proposal_vote_polls_end_v0(
+                vote_polls,
+                transaction,
+                platform_version,
+            ),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "clean_up_after_governance_proposal_vote_polls_end".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "clean_up_after_governance_proposal_vote_polls_end".to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use dpp::voting::vote_polls::governance_proposal_vote_poll::GovernanceProposalVotePoll;
+use drive::grovedb::TransactionArg;
+use std::collections::BTreeMap;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    #[inline(always)]
+    pub(super) fn clean_up_after_governance_proposal_vote_polls_end_v0(
+        &self,
+        vote_polls: Vec<(
+            &GovernanceProposalVotePoll,
+            &BTreeMap<Identifier, YesNoAbstainVoteChoice>,
+        )>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let mut operations = vec![];
+
+        self.drive.remove_governance_proposal_vote_poll_operations(
+            vote_polls.as_slice(),
+            &mut operations,
+            transaction,
+            platform_version,
+        )?;
+
+        if !operations.is_empty() {
+            self.drive.apply_batch_low_level_drive_operations(
+                None,
+                transaction,
+                operations,
+                &mut vec![],
+                &platform_version.drive,
+            )?;
+        }
+
+        Ok(())
+    }
+}
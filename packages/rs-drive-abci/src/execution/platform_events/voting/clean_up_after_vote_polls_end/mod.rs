@@ -9,6 +9,7 @@ use drive::grovedb::TransactionArg;
 use std::collections::BTreeMap;
 
 mod v0;
+mod v1;
 
 impl<C> Platform<C>
 where
@@ -28,9 +29,10 @@ where
             .clean_up_after_vote_poll_end
         {
             0 => self.clean_up_after_vote_polls_end_v0(vote_polls, transaction, platform_version),
+            1 => self.clean_up_after_vote_polls_end_v1(vote_polls, transaction, platform_version),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "clean_up_after_vote_polls_end".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             })),
         }
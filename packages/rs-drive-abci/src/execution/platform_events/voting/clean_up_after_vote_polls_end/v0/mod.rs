@@ -1,3 +1,4 @@
+use crate::error::execution::ExecutionError;
 use crate::error::Error;
 use crate::platform_types::platform::Platform;
 use crate::rpc::core::CoreRPCLike;
@@ -35,7 +36,12 @@ where
                 match vote_poll {
                     ResolvedVotePollWithVotes::ContestedDocumentResourceVotePollWithContractInfoAndVotes(contested_poll, vote_info) => {
                         contested_polls.push((contested_poll, end_date, vote_info));
-                    } // Add more match arms here for other types of vote polls in the future
+                    }
+                    ResolvedVotePollWithVotes::GovernanceProposalVotePollWithVotes(..) => {
+                        return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                            "governance proposal vote polls can not exist in this version",
+                        )));
+                    }
                 }
             }
         }
@@ -0,0 +1,56 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use drive::drive::votes::resolved::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePollWithContractInfo;
+use drive::grovedb::TransactionArg;
+use std::collections::BTreeMap;
+
+mod v0;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Resolves a contested resource vote poll that is decided by a sealed-bid auction, the
+    /// document is awarded to the highest revealed bid and deposits are settled.
+    ///
+    /// Returns the contenders of the vote poll so that they can be cleaned up.
+    pub(in crate::execution) fn resolve_auction_for_contested_document_resource_vote_poll(
+        &self,
+        block_platform_state: &PlatformState,
+        block_info: &BlockInfo,
+        vote_poll: &ContestedDocumentResourceVotePollWithContractInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<ResourceVoteChoice, Vec<Identifier>>, Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .voting
+            .resolve_auction_for_contested_document_resource_vote_poll
+        {
+            Some(0) => self.resolve_auction_for_contested_document_resource_vote_poll_v0(
+                block_platform_state,
+                block_info,
+                vote_poll,
+                transaction,
+                platform_version,
+            ),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "resolve_auction_for_contested_document_resource_vote_poll".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "resolve_auction_for_contested_document_resource_vote_poll".to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
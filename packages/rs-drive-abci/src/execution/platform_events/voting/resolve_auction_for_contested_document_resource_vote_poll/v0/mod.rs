@@ -0,0 +1,178 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::fee::Credits;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use dpp::voting::contender_structs::FinalizedContender;
+use dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dpp::voting::vote_info_storage::contested_document_auction_bid_stored_info::{
+    ContestedDocumentAuctionBidStoredInfo, ContestedDocumentAuctionBidStoredInfoV0Getters,
+};
+use dpp::voting::vote_info_storage::contested_document_vote_poll_winner_info::ContestedDocumentVotePollWinnerInfo;
+use drive::drive::votes::resolved::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePollWithContractInfo;
+use drive::grovedb::TransactionArg;
+use drive::util::batch::drive_op_batch::PrefundedSpecializedBalanceOperationType;
+use drive::util::batch::DriveOperation::{IdentityOperation, PrefundedSpecializedBalanceOperation};
+use drive::util::batch::IdentityOperationType::AddToIdentityBalance;
+use std::collections::BTreeMap;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Resolves an auction once its reveal period is over
+    #[inline(always)]
+    pub(super) fn resolve_auction_for_contested_document_resource_vote_poll_v0(
+        &self,
+        block_platform_state: &PlatformState,
+        block_info: &BlockInfo,
+        vote_poll: &ContestedDocumentResourceVotePollWithContractInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<ResourceVoteChoice, Vec<Identifier>>, Error> {
+        let document_type = vote_poll.document_type()?;
+
+        // Masternodes can't vote on auctions, we only need the contenders and their documents
+        let result = self.tally_votes_for_contested_document_resource_vote_poll(
+            vote_poll.into(),
+            transaction,
+            platform_version,
+        )?;
+
+        let mut bids: BTreeMap<Identifier, ContestedDocumentAuctionBidStoredInfo> = BTreeMap::new();
+        for contender in result.contenders.iter() {
+            if let Some(bid) = self.drive.fetch_auction_bid(
+                vote_poll,
+                contender.identity_id,
+                transaction,
+                platform_version,
+            )? {
+                bids.insert(contender.identity_id, bid);
+            }
+        }
+
+        let winning_bid = ContestedDocumentAuctionBidStoredInfo::winning_bid(
+            bids.iter().map(|(bidder_id, bid)| (*bidder_id, bid)),
+        );
+
+        // Every deposit was escrowed in the specialized balance of the vote poll, the winning
+        // bid goes to the contract owner and everything else is given back to the bidders
+        let mut total_deposits: Credits = 0;
+        let mut refunds = vec![];
+        for (bidder_id, bid) in bids.iter() {
+            let deposit = bid.deposit();
+            total_deposits = total_deposits.checked_add(deposit).ok_or(Error::Execution(
+                ExecutionError::Overflow("overflow when adding up the deposits of an auction"),
+            ))?;
+
+            let paid_amount = match winning_bid {
+                Some((winner_id, amount)) if winner_id == *bidder_id => amount,
+                _ => 0,
+            };
+
+            let refund = deposit.checked_sub(paid_amount).ok_or(Error::Execution(
+                ExecutionError::CorruptedCodeExecution(
+                    "a revealed bid can not be higher than its deposit",
+                ),
+            ))?;
+
+            if refund > 0 {
+                refunds.push(IdentityOperation(AddToIdentityBalance {
+                    identity_id: bidder_id.to_buffer(),
+                    added_balance: refund,
+                }));
+            }
+        }
+
+        let mut drive_operations = vec![];
+
+        if total_deposits > 0 {
+            drive_operations.push(PrefundedSpecializedBalanceOperation(
+                PrefundedSpecializedBalanceOperationType::DeductFromPrefundedBalance {
+                    prefunded_specialized_balance_id: vote_poll.specialized_balance_id()?,
+                    remove_balance: total_deposits,
+                },
+            ));
+        }
+
+        drive_operations.append(&mut refunds);
+
+        if let Some((_, amount)) = winning_bid.filter(|(_, amount)| *amount > 0) {
+            drive_operations.push(IdentityOperation(AddToIdentityBalance {
+                identity_id: vote_poll.contract.as_ref().owner_id().to_buffer(),
+                added_balance: amount,
+            }));
+        }
+
+        if !drive_operations.is_empty() {
+            self.drive.apply_drive_operations(
+                drive_operations,
+                true,
+                block_info,
+                transaction,
+                platform_version,
+                Some(block_platform_state.previous_fee_versions()),
+            )?;
+        }
+
+        let contenders: BTreeMap<ResourceVoteChoice, Vec<Identifier>> = result
+            .contenders
+            .iter()
+            .map(|contender| {
+                (
+                    ResourceVoteChoice::TowardsIdentity(contender.identity_id),
+                    vec![],
+                )
+            })
+            .collect();
+
+        let winner_info = if let Some((winner_id, _)) = winning_bid {
+            let winning_contender = result
+                .contenders
+                .into_iter()
+                .find(|contender| contender.identity_id == winner_id)
+                .ok_or(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                    "the winner of an auction must be one of its contenders",
+                )))?;
+
+            let winning_contender =
+                FinalizedContender::try_from_contender_with_serialized_document(
+                    winning_contender,
+                    document_type,
+                    platform_version,
+                )
+                .map_err(Error::Protocol)?;
+
+            // We award the document to the highest bidder
+            self.award_document_to_winner(
+                block_info,
+                winning_contender,
+                vote_poll,
+                transaction,
+                platform_version,
+            )?;
+            ContestedDocumentVotePollWinnerInfo::WonByIdentity(winner_id)
+        } else {
+            // Nobody revealed a valid bid
+            ContestedDocumentVotePollWinnerInfo::NoWinner
+        };
+
+        self.keep_record_of_finished_contested_resource_vote_poll(
+            block_platform_state,
+            block_info,
+            vote_poll,
+            &contenders,
+            winner_info,
+            transaction,
+            platform_version,
+        )?;
+
+        Ok(contenders)
+    }
+}
@@ -0,0 +1,54 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use dpp::voting::vote_polls::governance_proposal_vote_poll::GovernanceProposalVotePoll;
+use drive::grovedb::TransactionArg;
+use std::collections::BTreeMap;
+
+mod v0;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Tally the votes for a governance proposal vote poll that has ended and keep a record of
+    /// its result, returns the votes that were cast so they can be cleaned up
+    pub(in crate::execution) fn tally_votes_for_governance_proposal_vote_poll(
+        &self,
+        block_platform_state: &PlatformState,
+        block_info: &BlockInfo,
+        vote_poll: &GovernanceProposalVotePoll,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<Identifier, YesNoAbstainVoteChoice>, Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .voting
+            .tally_votes_for_governance_proposal_vote_poll
+        {
+            Some(0) => self.tally_votes_for_governance_proposal_vote_poll_v0(
+                block_platform_state,
+                block_info,
+                vote_poll,
+                transaction,
+                platform_version,
+            ),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "tally_votes_for_governance_proposal_vote_poll".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "tally_votes_for_governance_proposal_vote_poll".to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::dashcore::hashes::Hash;
+use dpp::dashcore::ProTxHash;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use dpp::voting::vote_info_storage::governance_proposal_vote_poll_stored_info::{
+    GovernanceProposalVotePollResult, GovernanceProposalVoteTally,
+};
+use dpp::voting::vote_polls::governance_proposal_vote_poll::GovernanceProposalVotePoll;
+use drive::grovedb::TransactionArg;
+use std::collections::BTreeMap;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Tally the votes for a governance proposal vote poll that has ended
+    pub(super) fn tally_votes_for_governance_proposal_vote_poll_v0(
+        &self,
+        block_platform_state: &PlatformState,
+        block_info: &BlockInfo,
+        vote_poll: &GovernanceProposalVotePoll,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<Identifier, YesNoAbstainVoteChoice>, Error> {
+        let vote_poll_id = vote_poll.unique_id()?;
+
+        let Some(mut stored_info) = self.drive.fetch_governance_proposal_vote_poll_stored_info(
+            vote_poll_id,
+            transaction,
+            platform_version,
+        )?
+        else {
+            return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                "a governance proposal vote poll that ended should have stored info",
+            )));
+        };
+
+        let votes = self.drive.fetch_governance_proposal_votes(
+            vote_poll_id,
+            transaction,
+            platform_version,
+        )?;
+
+        // Only evonodes that are still in the masternode list at the end of the vote poll count
+        let evonodes = block_platform_state.hpmn_masternode_list();
+
+        let mut tally = GovernanceProposalVoteTally::default();
+        for (voter, vote_choice) in &votes {
+            if evonodes.contains_key(&ProTxHash::from_byte_array(voter.to_buffer())) {
+                tally.add_vote(*vote_choice);
+            }
+        }
+
+        let eligible_voters = evonodes.len() as u32;
+
+        let voting_versions = &platform_version.dpp.voting_versions;
+
+        let outcome = tally.outcome(
+            eligible_voters,
+            voting_versions.governance_proposal_quorum_percentage,
+            voting_versions.governance_proposal_approval_percentage,
+        );
+
+        stored_info.finalize_vote_poll(GovernanceProposalVotePollResult {
+            tally,
+            eligible_voters,
+            outcome,
+            finalization_block: *block_info,
+        })?;
+
+        self.drive
+            .insert_stored_info_for_governance_proposal_vote_poll(
+                vote_poll_id,
+                stored_info,
+                transaction,
+                platform_version,
+            )?;
+
+        Ok(votes)
+    }
+}
@@ -11,6 +11,7 @@ use drive::drive::identity::withdrawals::{
 use std::collections::HashMap;
 
 mod v0;
+mod v1;
 
 impl<C> Platform<C>
 where
@@ -45,9 +46,14 @@ where
             0 => {
                 self.build_untied_withdrawal_transactions_from_documents_v0(documents, start_index)
             }
+            1 => self.build_untied_withdrawal_transactions_from_documents_v1(
+                documents,
+                start_index,
+                platform_version,
+            ),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "build_untied_withdrawal_transactions_from_documents".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             })),
         }
@@ -0,0 +1,336 @@
+use std::collections::{BTreeMap, HashMap};
+
+use dashcore_rpc::dashcore::{
+    blockdata::transaction::special_transaction::asset_unlock::{
+        qualified_asset_unlock::ASSET_UNLOCK_TX_SIZE,
+        unqualified_asset_unlock::{AssetUnlockBasePayload, AssetUnlockBaseTransactionInfo},
+    },
+    consensus::Encodable,
+    ScriptBuf, TxOut,
+};
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+use dpp::system_data_contracts::withdrawals_contract::v1::document_types::withdrawal;
+use dpp::version::PlatformVersion;
+use dpp::withdrawal::Pooling;
+
+use drive::dpp::identifier::Identifier;
+use drive::dpp::identity::convert_credits_to_duffs;
+use drive::drive::identity::withdrawals::{
+    WithdrawalTransactionIndex, WithdrawalTransactionIndexAndBytes,
+};
+
+use crate::{
+    error::{execution::ExecutionError, Error},
+    platform_types::platform::Platform,
+    rpc::core::CoreRPCLike,
+};
+
+/// A withdrawal document output ready to be put into a Core transaction
+struct WithdrawalOutput {
+    document_id: Identifier,
+    tx_out: TxOut,
+    core_fee_per_byte: u32,
+}
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Build list of Core transactions from withdrawal documents
+    ///
+    /// Withdrawals with `Pooling::Never` get their own transaction. Withdrawals with
+    /// `Pooling::Standard` are pooled together with other withdrawals paying the same core fee
+    /// per byte into transactions with several outputs. Withdrawals with `Pooling::IfAvailable`
+    /// join a pool of standard withdrawals with the same fee if one has room left, and get their
+    /// own transaction otherwise; they never open a pool themselves.
+    /// All documents pooled into the same transaction get the same transaction index.
+    pub(super) fn build_untied_withdrawal_transactions_from_documents_v1(
+        &self,
+        documents: &[Document],
+        start_index: WithdrawalTransactionIndex,
+        platform_version: &PlatformVersion,
+    ) -> Result<HashMap<Identifier, WithdrawalTransactionIndexAndBytes>, Error> {
+        let max_outputs_per_transaction = platform_version
+            .system_limits
+            .max_withdrawal_outputs_per_pooled_transaction
+            .max(1) as usize;
+
+        let mut transaction_outputs: Vec<Vec<WithdrawalOutput>> = Vec::new();
+
+        // core fee per byte -> position of the pooled transaction which still accepts outputs
+        let mut open_pools: BTreeMap<u32, usize> = BTreeMap::new();
+
+        // Pools are opened by standard withdrawals, so withdrawals pooled only if a pool
+        // is available are placed once all pools are known
+        let mut if_available_outputs = Vec::new();
+
+        for document in documents {
+            let output_script_bytes = document
+                .properties()
+                .get_bytes(withdrawal::properties::OUTPUT_SCRIPT)
+                .map_err(|_| {
+                    Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "Can't get outputScript from withdrawal document",
+                    ))
+                })?;
+
+            let amount = document
+                .properties()
+                .get_integer(withdrawal::properties::AMOUNT)
+                .map_err(|_| {
+                    Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "Can't get amount from withdrawal document",
+                    ))
+                })?;
+
+            let core_fee_per_byte: u32 = document
+                .properties()
+                .get_integer(withdrawal::properties::CORE_FEE_PER_BYTE)
+                .map_err(|_| {
+                    Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "Can't get coreFeePerByte from withdrawal document",
+                    ))
+                })?;
+
+            let pooling: u8 = document
+                .properties()
+                .get_integer(withdrawal::properties::POOLING)
+                .map_err(|_| {
+                    Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "Can't get pooling from withdrawal document",
+                    ))
+                })?;
+
+            let output = WithdrawalOutput {
+                document_id: document.id(),
+                tx_out: TxOut {
+                    value: convert_credits_to_duffs(amount)?,
+                    script_pubkey: ScriptBuf::from_bytes(output_script_bytes),
+                },
+                core_fee_per_byte,
+            };
+
+            if pooling == Pooling::Never as u8 {
+                transaction_outputs.push(vec![output]);
+                continue;
+            }
+
+            if pooling == Pooling::IfAvailable as u8 {
+                if_available_outputs.push(output);
+                continue;
+            }
+
+            match open_pools.get(&core_fee_per_byte) {
+                Some(&position)
+                    if transaction_outputs[position].len() < max_outputs_per_transaction =>
+                {
+                    transaction_outputs[position].push(output);
+                }
+                _ => {
+                    open_pools.insert(core_fee_per_byte, transaction_outputs.len());
+                    transaction_outputs.push(vec![output]);
+                }
+            }
+        }
+
+        for output in if_available_outputs {
+            match open_pools.get(&output.core_fee_per_byte) {
+                Some(&position)
+                    if transaction_outputs[position].len() < max_outputs_per_transaction =>
+                {
+                    transaction_outputs[position].push(output);
+                }
+                _ => transaction_outputs.push(vec![output]),
+            }
+        }
+
+        let mut withdrawals: HashMap<Identifier, WithdrawalTransactionIndexAndBytes> =
+            HashMap::new();
+
+        for (i, outputs) in transaction_outputs.into_iter().enumerate() {
+            let transaction_index = start_index + i as WithdrawalTransactionIndex;
+
+            // All outputs of a transaction pay the same fee per byte
+            let core_fee_per_byte = outputs[0].core_fee_per_byte;
+
+            // ASSET_UNLOCK_TX_SIZE already accounts for a single output
+            let additional_outputs_size: usize = outputs
+                .iter()
+                .skip(1)
+                .map(|output| transaction_output_size(&output.tx_out))
+                .sum();
+
+            let transaction_size = ASSET_UNLOCK_TX_SIZE as usize + additional_outputs_size;
+
+            let document_ids: Vec<Identifier> =
+                outputs.iter().map(|output| output.document_id).collect();
+
+            let withdrawal_transaction = AssetUnlockBaseTransactionInfo {
+                version: 1,
+                lock_time: 0,
+                output: outputs.into_iter().map(|output| output.tx_out).collect(),
+                base_payload: AssetUnlockBasePayload {
+                    version: 1,
+                    index: transaction_index,
+                    fee: transaction_size as u32 * core_fee_per_byte,
+                },
+            };
+
+            let mut transaction_buffer: Vec<u8> = vec![];
+
+            withdrawal_transaction
+                .consensus_encode(&mut transaction_buffer)
+                .map_err(|_| {
+                    Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "Can't consensus encode a withdrawal transaction",
+                    ))
+                })?;
+
+            for document_id in document_ids {
+                withdrawals.insert(document_id, (transaction_index, transaction_buffer.clone()));
+            }
+        }
+
+        Ok(withdrawals)
+    }
+}
+
+/// Serialized size of a transaction output: value, script length and script
+fn transaction_output_size(tx_out: &TxOut) -> usize {
+    let script_len = tx_out.script_pubkey.len();
+
+    let script_len_prefix_size = match script_len {
+        0..=0xFC => 1,
+        0xFD..=0xFFFF => 3,
+        _ => 5,
+    };
+
+    8 + script_len_prefix_size + script_len
+}
+
+#[cfg(test)]
+mod tests {
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::data_contracts::withdrawals_contract;
+    use dpp::data_contracts::withdrawals_contract::v1::document_types::withdrawal;
+    use dpp::identity::core_script::CoreScript;
+    use dpp::platform_value::platform_value;
+    use dpp::prelude::Identifier;
+    use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+    use dpp::tests::fixtures::get_withdrawal_document_fixture;
+    use dpp::version::PlatformVersion;
+    use dpp::withdrawal::Pooling;
+    use drive::util::test_helpers::setup::{setup_document, setup_system_data_contract};
+    use itertools::Itertools;
+
+    use crate::test::helpers::setup::TestPlatformBuilder;
+
+    #[test]
+    fn test_build_pooled_transactions() {
+        let platform_version = PlatformVersion::latest();
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let data_contract =
+            load_system_data_contract(SystemDataContract::Withdrawals, platform_version)
+                .expect("to load system data contract");
+
+        setup_system_data_contract(&platform.drive, &data_contract, Some(&transaction));
+
+        let document_type = data_contract
+            .document_type_for_name(withdrawal::NAME)
+            .expect("expected to get document type");
+
+        let owner_id = Identifier::new([1u8; 32]);
+
+        let poolings = [
+            (Pooling::Standard, 1u32),
+            (Pooling::Never, 1u32),
+            (Pooling::IfAvailable, 1u32),
+            (Pooling::Standard, 2u32),
+            (Pooling::IfAvailable, 3u32),
+            (Pooling::IfAvailable, 3u32),
+        ];
+
+        let documents = poolings
+            .iter()
+            .enumerate()
+            .map(|(i, (pooling, core_fee_per_byte))| {
+                let document = get_withdrawal_document_fixture(
+                    &data_contract,
+                    owner_id,
+                    platform_value!({
+                        "amount": 1000u64 + i as u64,
+                        "coreFeePerByte": *core_fee_per_byte,
+                        "pooling": *pooling as u8,
+                        "outputScript": CoreScript::from_bytes((0..23).collect::<Vec<u8>>()),
+                        "status": withdrawals_contract::WithdrawalStatus::QUEUED as u8,
+                    }),
+                    None,
+                    platform_version.protocol_version,
+                )
+                .expect("expected withdrawal document");
+
+                setup_document(
+                    &platform.drive,
+                    &document,
+                    &data_contract,
+                    document_type,
+                    Some(&transaction),
+                );
+
+                document
+            })
+            .collect::<Vec<_>>();
+
+        let transactions = platform
+            .build_untied_withdrawal_transactions_from_documents_v1(
+                &documents,
+                50,
+                platform_version,
+            )
+            .expect("to build transactions from documents");
+
+        assert_eq!(transactions.len(), 6);
+
+        let indices = documents
+            .iter()
+            .map(|document| {
+                transactions
+                    .get(&document.id())
+                    .expect("expected a transaction for every document")
+                    .0
+            })
+            .collect::<Vec<_>>();
+
+        // Standard and IfAvailable withdrawals with the same fee share a transaction,
+        // IfAvailable withdrawals without a standard pool of their fee are not pooled together
+        assert_eq!(indices, vec![50, 51, 50, 52, 53, 54]);
+
+        assert_eq!(
+            transactions
+                .values()
+                .map(|(index, _)| *index)
+                .unique()
+                .count(),
+            5
+        );
+
+        let pooled_transaction = &transactions.get(&documents[0].id()).unwrap().1;
+        let single_transaction = &transactions.get(&documents[1].id()).unwrap().1;
+
+        assert_eq!(
+            pooled_transaction,
+            &transactions.get(&documents[2].id()).unwrap().1
+        );
+        assert!(pooled_transaction.len() > single_transaction.len());
+        assert_eq!(
+            transactions.get(&documents[4].id()).unwrap().1.len(),
+            single_transaction.len()
+        );
+    }
+}
@@ -124,15 +124,29 @@ where
         transaction: TransactionArg,
         platform_version: &PlatformVersion,
     ) -> Result<Vec<Document>, Error> {
-        let documents = self
-            .drive
-            .find_withdrawal_documents_by_status_and_transaction_indices(
-                withdrawals_contract::WithdrawalStatus::POOLED,
-                transaction_indices,
-                DEFAULT_QUERY_LIMIT,
-                transaction,
-                platform_version,
-            )?;
+        // A pooled transaction has up to this many withdrawal documents, so documents are
+        // fetched for as many transactions at once as fit in a query
+        let max_outputs_per_transaction = platform_version
+            .system_limits
+            .max_withdrawal_outputs_per_pooled_transaction
+            .max(1);
+        let transactions_per_query =
+            (DEFAULT_QUERY_LIMIT / max_outputs_per_transaction).max(1) as usize;
+
+        let mut documents = Vec::new();
+
+        for transaction_indices_page in transaction_indices.chunks(transactions_per_query) {
+            documents.extend(
+                self.drive
+                    .find_withdrawal_documents_by_status_and_transaction_indices(
+                        withdrawals_contract::WithdrawalStatus::POOLED,
+                        transaction_indices_page,
+                        transaction_indices_page.len() as u16 * max_outputs_per_transaction,
+                        transaction,
+                        platform_version,
+                    )?,
+            );
+        }
 
         documents
             .into_iter()
@@ -190,3 +204,158 @@ fn build_unsigned_transaction(
     build_asset_unlock_tx(&unsigned_transaction_bytes)
         .map_err(|error| Error::Protocol(ProtocolError::DashCoreError(error)))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dpp::block::epoch::Epoch;
+    use dpp::data_contracts::SystemDataContract;
+    use dpp::identifier::Identifier;
+    use dpp::identity::core_script::CoreScript;
+    use dpp::platform_value::platform_value;
+    use dpp::system_data_contracts::load_system_data_contract;
+    use dpp::tests::fixtures::get_withdrawal_document_fixture;
+    use dpp::withdrawal::Pooling;
+    use drive::util::test_helpers::setup::{setup_document, setup_system_data_contract};
+
+    use crate::test::helpers::setup::TestPlatformBuilder;
+
+    #[test]
+    fn test_dequeue_more_than_a_query_limit_of_pooled_withdrawals() {
+        let platform_version = PlatformVersion::latest();
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let data_contract =
+            load_system_data_contract(SystemDataContract::Withdrawals, platform_version)
+                .expect("to load system data contract");
+
+        setup_system_data_contract(&platform.drive, &data_contract, Some(&transaction));
+
+        let document_type = data_contract
+            .document_type_for_name(withdrawal::NAME)
+            .expect("expected to get document type");
+
+        let owner_id = Identifier::new([1u8; 32]);
+
+        let withdrawals_count = 150;
+
+        for i in 0..withdrawals_count {
+            let document = get_withdrawal_document_fixture(
+                &data_contract,
+                owner_id,
+                platform_value!({
+                    "amount": 1000u64 + i as u64,
+                    "coreFeePerByte": 1u32,
+                    "pooling": Pooling::Standard as u8,
+                    "outputScript": CoreScript::from_bytes((0..23).collect::<Vec<u8>>()),
+                    "status": withdrawals_contract::WithdrawalStatus::QUEUED as u8,
+                }),
+                None,
+                platform_version.protocol_version,
+            )
+            .expect("expected withdrawal document");
+
+            setup_document(
+                &platform.drive,
+                &document,
+                &data_contract,
+                document_type,
+                Some(&transaction),
+            );
+        }
+
+        // Queued withdrawals are pooled up to a query limit per block
+        for height in 1..=2 {
+            let block_info = BlockInfo {
+                time_ms: height,
+                height,
+                core_height: 96,
+                epoch: Epoch::default(),
+            };
+
+            platform
+                .pool_withdrawals_into_transactions_queue(
+                    &block_info,
+                    Some(&transaction),
+                    platform_version,
+                )
+                .expect("to pool withdrawal documents into transactions");
+        }
+
+        let pooled_documents = platform
+            .drive
+            .fetch_oldest_withdrawal_documents_by_status(
+                withdrawals_contract::WithdrawalStatus::POOLED.into(),
+                withdrawals_count,
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("to fetch withdrawal documents");
+
+        assert_eq!(pooled_documents.len(), withdrawals_count as usize);
+
+        let block_info = BlockInfo {
+            time_ms: 3,
+            height: 3,
+            core_height: 96,
+            epoch: Epoch::default(),
+        };
+
+        let unsigned_transactions = platform
+            .dequeue_and_build_unsigned_withdrawal_transactions_v0(
+                [2u8; 32],
+                &block_info,
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("to dequeue withdrawal transactions");
+
+        let max_outputs_per_transaction = platform_version
+            .system_limits
+            .max_withdrawal_outputs_per_pooled_transaction
+            as usize;
+
+        // 100 then 50 withdrawals pooled into transactions of up to 32 outputs
+        assert_eq!(
+            unsigned_transactions.len(),
+            100usize.div_ceil(max_outputs_per_transaction)
+                + 50usize.div_ceil(max_outputs_per_transaction)
+        );
+        assert_eq!(
+            unsigned_transactions
+                .iter()
+                .map(|transaction| transaction.output.len())
+                .sum::<usize>(),
+            withdrawals_count as usize
+        );
+
+        // All documents of the dequeued transactions are broadcasted
+        let broadcasted_documents = platform
+            .drive
+            .fetch_oldest_withdrawal_documents_by_status(
+                withdrawals_contract::WithdrawalStatus::BROADCASTED.into(),
+                withdrawals_count,
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("to fetch withdrawal documents");
+
+        assert_eq!(broadcasted_documents.len(), withdrawals_count as usize);
+
+        let pooled_documents = platform
+            .drive
+            .fetch_oldest_withdrawal_documents_by_status(
+                withdrawals_contract::WithdrawalStatus::POOLED.into(),
+                withdrawals_count,
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("to fetch withdrawal documents");
+
+        assert!(pooled_documents.is_empty());
+    }
+}
@@ -8,6 +8,7 @@ use dpp::version::PlatformVersion;
 use drive::grovedb::TransactionArg;
 
 mod v0;
+mod v1;
 
 impl<C> Platform<C>
 where
@@ -43,9 +44,14 @@ where
                 transaction,
                 platform_version,
             ),
+            1 => self.pool_withdrawals_into_transactions_queue_v1(
+                block_info,
+                transaction,
+                platform_version,
+            ),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "pool_withdrawals_into_transactions_queue".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             })),
         }
@@ -64,7 +64,6 @@ pub(in crate::execution) enum ExecutionEvent<'a> {
         operations: Vec<DriveOperation<'a>>,
     },
     /// A drive event that is free
-    #[allow(dead_code)] // TODO investigate why `variant `Free` is never constructed`
     Free {
         /// the operations that should be performed
         operations: Vec<DriveOperation<'a>>,
@@ -162,6 +161,46 @@ impl<'a> ExecutionEvent<'a> {
                     )))
                 }
             }
+            StateTransitionAction::IdentityCreditBatchTransferAction(
+                identity_credit_batch_transfer,
+            ) => {
+                let user_fee_increase = identity_credit_batch_transfer.user_fee_increase();
+                let removed_balance = identity_credit_batch_transfer.total_transfer_amount();
+                let operations =
+                    action.into_high_level_drive_operations(epoch, platform_version)?;
+                if let Some(identity) = identity {
+                    Ok(ExecutionEvent::Paid {
+                        identity,
+                        removed_balance: Some(removed_balance),
+                        operations,
+                        execution_operations: execution_context.operations_consume(),
+                        user_fee_increase,
+                    })
+                } else {
+                    Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "partial identity should be present for identity credit batch transfer action",
+                    )))
+                }
+            }
+            StateTransitionAction::AuctionBidAction(auction_bid_action) => {
+                let user_fee_increase = auction_bid_action.user_fee_increase();
+                let removed_balance = auction_bid_action.escrowed_amount();
+                let operations =
+                    action.into_high_level_drive_operations(epoch, platform_version)?;
+                if let Some(identity) = identity {
+                    Ok(ExecutionEvent::Paid {
+                        identity,
+                        removed_balance: Some(removed_balance),
+                        operations,
+                        execution_operations: execution_context.operations_consume(),
+                        user_fee_increase,
+                    })
+                } else {
+                    Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "partial identity should be present for auction bid action",
+                    )))
+                }
+            }
             StateTransitionAction::DocumentsBatchAction(document_batch_action) => {
                 let user_fee_increase = action.user_fee_increase();
                 let removed_balance = document_batch_action.all_used_balances()?;
@@ -181,10 +220,29 @@ impl<'a> ExecutionEvent<'a> {
                     )))
                 }
             }
-            StateTransitionAction::MasternodeVoteAction(_) => {
+            StateTransitionAction::MasternodeVoteAction(masternode_vote_action) => {
+                let vote_poll_funding = masternode_vote_action.vote_poll_funding();
                 let operations =
                     action.into_high_level_drive_operations(epoch, platform_version)?;
 
+                if let Some(vote_poll_funding) = vote_poll_funding {
+                    // The vote opening a governance proposal vote poll funds it from the voter
+                    //  identity, which also pays for the processing
+                    return if let Some(identity) = identity {
+                        Ok(ExecutionEvent::Paid {
+                            identity,
+                            removed_balance: Some(vote_poll_funding),
+                            operations,
+                            execution_operations: execution_context.operations_consume(),
+                            user_fee_increase: 0,
+                        })
+                    } else {
+                        Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                            "partial identity should be present for a vote opening a vote poll",
+                        )))
+                    };
+                }
+
                 Ok(ExecutionEvent::PaidFixedCost {
                     operations,
                     fees_to_add_to_pool: platform_version
@@ -25,6 +25,14 @@ impl RetrieveIdentityInfo {
         }
     }
 
+    pub fn keys(key_count: u16) -> Self {
+        RetrieveIdentityInfo {
+            query_by_key_id_key_count: key_count,
+            request_balance: false,
+            request_revision: false,
+        }
+    }
+
     pub fn one_key() -> Self {
         RetrieveIdentityInfo {
             query_by_key_id_key_count: 1,
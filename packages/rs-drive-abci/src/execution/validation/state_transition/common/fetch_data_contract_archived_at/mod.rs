@@ -0,0 +1,49 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+use crate::execution::validation::state_transition::common::fetch_data_contract_archived_at::v0::fetch_data_contract_archived_at_v0;
+use dpp::block::block_info::BlockInfo;
+use dpp::identifier::Identifier;
+use dpp::prelude::TimestampMillis;
+use dpp::version::PlatformVersion;
+use drive::drive::Drive;
+use drive::grovedb::TransactionArg;
+
+pub mod v0;
+
+/// Fetches the time a data contract was archived at, adding the cost of the lookup to the
+/// execution context.
+///
+/// Contracts can't be archived before the protocol version that introduced archiving, so no
+/// lookup is made and `None` is returned for earlier versions.
+pub(crate) fn fetch_data_contract_archived_at(
+    drive: &Drive,
+    data_contract_id: Identifier,
+    block_info: &BlockInfo,
+    execution_context: &mut StateTransitionExecutionContext,
+    transaction: TransactionArg,
+    platform_version: &PlatformVersion,
+) -> Result<Option<TimestampMillis>, Error> {
+    match platform_version
+        .drive_abci
+        .validation_and_processing
+        .state_transitions
+        .common_validation_methods
+        .fetch_data_contract_archived_at
+    {
+        Some(0) => fetch_data_contract_archived_at_v0(
+            drive,
+            data_contract_id,
+            block_info,
+            execution_context,
+            transaction,
+            platform_version,
+        ),
+        Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+            method: "fetch_data_contract_archived_at".to_string(),
+            known_versions: vec![0],
+            received: version,
+        })),
+        None => Ok(None),
+    }
+}
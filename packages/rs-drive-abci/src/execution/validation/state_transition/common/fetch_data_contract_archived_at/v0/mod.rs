@@ -0,0 +1,32 @@
+use crate::error::Error;
+use crate::execution::types::execution_operation::ValidationOperation;
+use crate::execution::types::state_transition_execution_context::{
+    StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0,
+};
+use dpp::block::block_info::BlockInfo;
+use dpp::identifier::Identifier;
+use dpp::prelude::TimestampMillis;
+use dpp::version::PlatformVersion;
+use drive::drive::Drive;
+use drive::grovedb::TransactionArg;
+
+pub(super) fn fetch_data_contract_archived_at_v0(
+    drive: &Drive,
+    data_contract_id: Identifier,
+    block_info: &BlockInfo,
+    execution_context: &mut StateTransitionExecutionContext,
+    transaction: TransactionArg,
+    platform_version: &PlatformVersion,
+) -> Result<Option<TimestampMillis>, Error> {
+    let (archived_at, fee) = drive.fetch_contract_archived_at_with_fees(
+        data_contract_id.to_buffer(),
+        block_info,
+        true,
+        transaction,
+        platform_version,
+    )?;
+
+    execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee));
+
+    Ok(archived_at)
+}
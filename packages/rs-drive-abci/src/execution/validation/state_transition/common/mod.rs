@@ -1,5 +1,6 @@
 /// A module for validating asset locks
 pub mod asset_lock;
+pub mod fetch_data_contract_archived_at;
 pub mod validate_identity_public_key_contract_bounds;
 pub mod validate_identity_public_key_ids_dont_exist_in_state;
 pub mod validate_identity_public_key_ids_exist_in_state;
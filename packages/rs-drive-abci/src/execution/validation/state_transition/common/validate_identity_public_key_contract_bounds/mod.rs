@@ -8,8 +8,10 @@ use crate::error::Error;
 use crate::error::execution::ExecutionError;
 use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
 use crate::execution::validation::state_transition::common::validate_identity_public_key_contract_bounds::v0::validate_identity_public_keys_contract_bounds_v0;
+use crate::execution::validation::state_transition::common::validate_identity_public_key_contract_bounds::v1::validate_identity_public_keys_contract_bounds_v1;
 
 pub mod v0;
+pub mod v1;
 
 pub(crate) fn validate_identity_public_keys_contract_bounds(
     identity_id: Identifier,
@@ -34,10 +36,94 @@ pub(crate) fn validate_identity_public_keys_contract_bounds(
             execution_context,
             platform_version,
         ),
+        1 => validate_identity_public_keys_contract_bounds_v1(
+            identity_id,
+            identity_public_keys_with_witness,
+            drive,
+            transaction,
+            execution_context,
+            platform_version,
+        ),
         version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
             method: "validate_identity_public_keys_contract_bounds".to_string(),
-            known_versions: vec![0],
+            known_versions: vec![0, 1],
             received: version,
         })),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContextMethodsV0;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::consensus::basic::BasicError;
+    use dpp::consensus::ConsensusError;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::contract_bounds::ContractBounds;
+    use dpp::identity::{Identity, Purpose};
+    use dpp::state_transition::public_key_in_creation::v0::IdentityPublicKeyInCreationV0;
+
+    #[test]
+    fn should_only_accept_keys_bound_to_the_contracts_of_an_owner_from_v1() {
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let owner = Identity::random_identity(3, Some(5), PlatformVersion::latest())
+            .expect("expected a random identity");
+
+        platform
+            .drive
+            .add_new_identity(
+                owner.clone(),
+                false,
+                &BlockInfo::default(),
+                true,
+                None,
+                PlatformVersion::latest(),
+            )
+            .expect("expected to add the owner identity");
+
+        let public_keys = [IdentityPublicKeyInCreation::V0(
+            IdentityPublicKeyInCreationV0 {
+                id: 2,
+                purpose: Purpose::ENCRYPTION,
+                contract_bounds: Some(ContractBounds::MultipleContractsOfSameOwner {
+                    owner_id: owner.id(),
+                }),
+                ..Default::default()
+            },
+        )];
+
+        let validate = |platform_version: &PlatformVersion| {
+            let mut execution_context =
+                StateTransitionExecutionContext::default_for_platform_version(platform_version)
+                    .expect("expected an execution context");
+
+            validate_identity_public_keys_contract_bounds(
+                Identifier::new([1; 32]),
+                &public_keys,
+                &platform.drive,
+                None,
+                &mut execution_context,
+                platform_version,
+            )
+            .expect("expected to validate contract bounds")
+        };
+
+        let result = validate(PlatformVersion::first());
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::BasicError(
+                BasicError::UnsupportedFeatureError(_)
+            )]
+        ));
+
+        let result = validate(PlatformVersion::latest());
+
+        assert!(result.is_valid());
+    }
+}
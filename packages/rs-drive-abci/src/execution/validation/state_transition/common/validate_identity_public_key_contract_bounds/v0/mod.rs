@@ -4,7 +4,7 @@ use dpp::consensus::basic::document::{
     DataContractNotPresentError, InvalidDocumentTypeError,
 };
 use dpp::consensus::basic::identity::DataContractBoundsNotPresentError;
-use dpp::consensus::basic::BasicError;
+use dpp::consensus::basic::{BasicError, UnsupportedFeatureError};
 use dpp::consensus::ConsensusError;
 use dpp::consensus::state::identity::identity_public_key_already_exists_for_unique_contract_bounds_error::IdentityPublicKeyAlreadyExistsForUniqueContractBoundsError;
 use dpp::consensus::state::state_error::StateError;
@@ -290,6 +290,16 @@ fn validate_identity_public_key_contract_bounds_v0(
                     }
                 }
             }
+            ContractBounds::MultipleContractsOfSameOwner { .. } => {
+                // Keys bound to all contracts of an owner are only known from validation v1 on
+                Ok(SimpleConsensusValidationResult::new_with_error(
+                    UnsupportedFeatureError::new(
+                        "keys bound to the contracts of an owner".to_string(),
+                        platform_version.protocol_version,
+                    )
+                    .into(),
+                ))
+            }
         }
     } else {
         Ok(SimpleConsensusValidationResult::new())
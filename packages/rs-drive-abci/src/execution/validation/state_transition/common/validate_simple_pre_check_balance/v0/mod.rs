@@ -27,13 +27,13 @@ impl ValidateSimplePreCheckBalanceV0 for StateTransition {
                     .state_transition_min_fees
                     .contract_create
             }
-            StateTransition::DataContractUpdate(_) => {
+            StateTransition::DataContractUpdate(_) | StateTransition::DataContractDelete(_) => {
                 platform_version
                     .fee_version
                     .state_transition_min_fees
                     .contract_update
             }
-            StateTransition::DocumentsBatch(_) => {
+            StateTransition::DocumentsBatch(_) | StateTransition::AuctionBid(_) => {
                 platform_version
                     .fee_version
                     .state_transition_min_fees
@@ -48,13 +48,14 @@ impl ValidateSimplePreCheckBalanceV0 for StateTransition {
                     .state_transition_min_fees
                     .credit_withdrawal
             }
-            StateTransition::IdentityUpdate(_) => {
+            StateTransition::IdentityUpdate(_) | StateTransition::IdentityRecovery(_) => {
                 platform_version
                     .fee_version
                     .state_transition_min_fees
                     .identity_update
             }
-            StateTransition::IdentityCreditTransfer(_) => {
+            StateTransition::IdentityCreditTransfer(_)
+            | StateTransition::IdentityCreditBatchTransfer(_) => {
                 platform_version
                     .fee_version
                     .state_transition_min_fees
@@ -1,3 +1,4 @@
+use dpp::block::block_info::BlockInfo;
 use dpp::identity::PartialIdentity;
 use dpp::state_transition::StateTransition;
 use dpp::validation::ConsensusValidationResult;
@@ -8,13 +9,16 @@ use crate::error::Error;
 use crate::error::execution::ExecutionError;
 use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
 use crate::execution::validation::state_transition::common::validate_state_transition_identity_signed::v0::ValidateStateTransitionIdentitySignatureV0;
+use crate::execution::validation::state_transition::common::validate_state_transition_identity_signed::v1::ValidateStateTransitionIdentitySignatureV1;
 
 pub mod v0;
+pub mod v1;
 
 pub trait ValidateStateTransitionIdentitySignature {
     fn validate_state_transition_identity_signed(
         &self,
         drive: &Drive,
+        block_info: &BlockInfo,
         request_balance: bool,
         request_revision: bool,
         transaction: TransactionArg,
@@ -27,6 +31,7 @@ impl ValidateStateTransitionIdentitySignature for StateTransition {
     fn validate_state_transition_identity_signed(
         &self,
         drive: &Drive,
+        block_info: &BlockInfo,
         request_balance: bool,
         request_revision: bool,
         transaction: TransactionArg,
@@ -42,6 +47,16 @@ impl ValidateStateTransitionIdentitySignature for StateTransition {
         {
             0 => self.validate_state_transition_identity_signed_v0(
                 drive,
+                block_info,
+                request_balance,
+                request_revision,
+                transaction,
+                execution_context,
+                platform_version,
+            ),
+            1 => self.validate_state_transition_identity_signed_v1(
+                drive,
+                block_info,
                 request_balance,
                 request_revision,
                 transaction,
@@ -50,9 +65,294 @@ impl ValidateStateTransitionIdentitySignature for StateTransition {
             ),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "StateTransition::validate_state_transition_identity_signature".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             })),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContextMethodsV0;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
+    use dpp::consensus::signature::SignatureError;
+    use dpp::consensus::ConsensusError;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeySettersV0;
+    use dpp::identity::identity_public_key::accessors::v1::IdentityPublicKeySettersV1;
+    use dpp::identity::signer::Signer;
+    use dpp::identity::{
+        Identity, IdentityPublicKey, IdentityV0, KeyID, KeyType, Purpose, SecurityLevel,
+    };
+    use dpp::platform_value::BinaryData;
+    use dpp::prelude::Identifier;
+    use dpp::serialization::Signable;
+    use dpp::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
+    use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+    use dpp::state_transition::threshold_signature::ThresholdSignature;
+    use rand::prelude::StdRng;
+    use rand::SeedableRng;
+    use simple_signer::signer::SimpleSigner;
+    use std::collections::BTreeMap;
+
+    const THRESHOLD_KEY_ID: u32 = 1;
+
+    /// Sets up an identity whose transfer key 1 needs 3 signatures, with transfer keys 2 and 3
+    /// as its co-signers
+    fn setup_threshold_identity(
+        platform: &TempPlatform<MockCoreRPCLike>,
+        disable_last_co_signer: bool,
+    ) -> (Identity, SimpleSigner) {
+        let platform_version = PlatformVersion::latest();
+        let mut rng = StdRng::seed_from_u64(567);
+        let mut signer = SimpleSigner::default();
+
+        let (master_key, master_private_key) =
+            IdentityPublicKey::random_ecdsa_master_authentication_key_with_rng(
+                0,
+                &mut rng,
+                platform_version,
+            )
+            .expect("expected to get key pair");
+        signer.add_key(master_key.clone(), master_private_key);
+
+        let mut public_keys = BTreeMap::from([(0, master_key)]);
+
+        for key_id in 1..=3 {
+            let (mut key, private_key) = IdentityPublicKey::random_key_with_known_attributes(
+                key_id,
+                &mut rng,
+                Purpose::TRANSFER,
+                SecurityLevel::CRITICAL,
+                KeyType::ECDSA_SECP256K1,
+                None,
+                platform_version,
+            )
+            .expect("expected to get key pair");
+
+            if key_id == THRESHOLD_KEY_ID {
+                key.set_signature_threshold(Some(3));
+            } else if key_id == 3 && disable_last_co_signer {
+                key.set_disabled_at(1);
+            }
+
+            signer.add_key(key.clone(), private_key);
+            public_keys.insert(key_id, key);
+        }
+
+        let identity: Identity = IdentityV0 {
+            id: Identifier::random_with_rng(&mut rng),
+            public_keys,
+            balance: 1_000_000_000,
+            revision: 0,
+        }
+        .into();
+
+        platform
+            .drive
+            .add_new_identity(
+                identity.clone(),
+                false,
+                &BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to add a new identity");
+
+        (identity, signer)
+    }
+
+    fn credit_transfer(identity: &Identity) -> StateTransition {
+        IdentityCreditTransferTransition::V0(IdentityCreditTransferTransitionV0 {
+            identity_id: identity.id(),
+            recipient_id: Identifier::new([2; 32]),
+            amount: 100_000,
+            nonce: 1,
+            ..Default::default()
+        })
+        .into()
+    }
+
+    /// Every listed key signs the state transition, the signature is attached to the threshold key
+    fn sign_with_keys(
+        state_transition: &mut StateTransition,
+        identity: &Identity,
+        signer: &SimpleSigner,
+        signatures: &[(KeyID, KeyID)],
+    ) {
+        let data = state_transition
+            .signable_bytes()
+            .expect("expected signable bytes");
+
+        let mut threshold_signature = ThresholdSignature::default();
+        for (key_id, signing_key_id) in signatures {
+            let signing_key = identity
+                .public_keys()
+                .get(signing_key_id)
+                .expect("expected the signing key");
+            let signature = signer.sign(signing_key, &data).expect("expected to sign");
+            threshold_signature.insert_signature(*key_id, signature);
+        }
+
+        state_transition
+            .set_threshold_signature(THRESHOLD_KEY_ID, &threshold_signature)
+            .expect("expected to set the threshold signature");
+    }
+
+    fn validate(
+        platform: &TempPlatform<MockCoreRPCLike>,
+        state_transition: &StateTransition,
+    ) -> ConsensusValidationResult<PartialIdentity> {
+        let platform_version = PlatformVersion::latest();
+        let mut execution_context =
+            StateTransitionExecutionContext::default_for_platform_version(platform_version)
+                .expect("expected an execution context");
+
+        state_transition
+            .validate_state_transition_identity_signed(
+                &platform.drive,
+                &BlockInfo::default(),
+                true,
+                false,
+                None,
+                &mut execution_context,
+                platform_version,
+            )
+            .expect("expected to validate the signature")
+    }
+
+    fn setup_platform() -> TempPlatform<MockCoreRPCLike> {
+        TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_initial_state_structure()
+    }
+
+    #[test]
+    fn should_accept_threshold_key_with_enough_co_signers() {
+        let platform = setup_platform();
+        let (identity, signer) = setup_threshold_identity(&platform, false);
+
+        let mut state_transition = credit_transfer(&identity);
+        sign_with_keys(
+            &mut state_transition,
+            &identity,
+            &signer,
+            &[(1, 1), (2, 2), (3, 3)],
+        );
+
+        let result = validate(&platform, &state_transition);
+
+        assert!(result.is_valid_with_data());
+    }
+
+    #[test]
+    fn should_reject_threshold_key_with_too_few_co_signers() {
+        let platform = setup_platform();
+        let (identity, signer) = setup_threshold_identity(&platform, false);
+
+        let mut state_transition = credit_transfer(&identity);
+        sign_with_keys(&mut state_transition, &identity, &signer, &[(1, 1), (2, 2)]);
+
+        let result = validate(&platform, &state_transition);
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::SignatureError(
+                SignatureError::NotEnoughThresholdSignaturesError(_)
+            )]
+        ));
+    }
+
+    #[test]
+    fn should_reject_threshold_key_with_invalid_co_signer_signature() {
+        let platform = setup_platform();
+        let (identity, signer) = setup_threshold_identity(&platform, false);
+
+        let mut state_transition = credit_transfer(&identity);
+        // Key 3 signs in place of key 2
+        sign_with_keys(
+            &mut state_transition,
+            &identity,
+            &signer,
+            &[(1, 1), (2, 3), (3, 3)],
+        );
+
+        let result = validate(&platform, &state_transition);
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::SignatureError(
+                SignatureError::InvalidStateTransitionSignatureError(_)
+            )]
+        ));
+    }
+
+    #[test]
+    fn should_not_count_disabled_co_signers() {
+        let platform = setup_platform();
+        let (identity, signer) = setup_threshold_identity(&platform, true);
+
+        let mut state_transition = credit_transfer(&identity);
+        sign_with_keys(
+            &mut state_transition,
+            &identity,
+            &signer,
+            &[(1, 1), (2, 2), (3, 3)],
+        );
+
+        let result = validate(&platform, &state_transition);
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::SignatureError(
+                SignatureError::NotEnoughThresholdSignaturesError(_)
+            )]
+        ));
+    }
+
+    #[test]
+    fn should_count_duplicate_co_signers_once() {
+        let platform = setup_platform();
+        let (identity, signer) = setup_threshold_identity(&platform, false);
+
+        let mut state_transition = credit_transfer(&identity);
+        let data = state_transition
+            .signable_bytes()
+            .expect("expected signable bytes");
+
+        let sign = |key_id: KeyID| {
+            signer
+                .sign(
+                    identity
+                        .public_keys()
+                        .get(&key_id)
+                        .expect("expected the signing key"),
+                    &data,
+                )
+                .expect("expected to sign")
+        };
+
+        // A threshold signature can't hold the same key twice, so the duplicate is encoded by hand
+        let signatures: Vec<(KeyID, BinaryData)> = vec![(1, sign(1)), (2, sign(2)), (2, sign(2))];
+        let config = bincode::config::standard().with_big_endian();
+        let signature =
+            bincode::encode_to_vec(&signatures, config).expect("expected to encode signatures");
+
+        state_transition.set_signature(signature.into());
+        state_transition.set_signature_public_key_id(THRESHOLD_KEY_ID);
+
+        let result = validate(&platform, &state_transition);
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::SignatureError(
+                SignatureError::NotEnoughThresholdSignaturesError(_)
+            )]
+        ));
+    }
+}
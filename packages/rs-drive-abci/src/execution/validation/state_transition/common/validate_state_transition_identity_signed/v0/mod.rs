@@ -3,9 +3,10 @@ use crate::error::Error;
 use dpp::consensus::signature::{
     IdentityNotFoundError, InvalidSignaturePublicKeyPurposeError,
     InvalidSignaturePublicKeySecurityLevelError, InvalidStateTransitionSignatureError,
-    PublicKeySecurityLevelNotMetError,
+    PublicKeyIsNotValidAtTimeError, PublicKeySecurityLevelNotMetError,
 };
 
+use dpp::block::block_info::BlockInfo;
 use dpp::identity::PartialIdentity;
 
 use crate::execution::types::execution_operation::signature_verification_operation::SignatureVerificationOperation;
@@ -20,6 +21,7 @@ use dpp::consensus::signature::{
     SignatureError,
 };
 use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::identity_public_key::accessors::v1::IdentityPublicKeyGettersV1;
 use dpp::native_bls::NativeBlsModule;
 use dpp::state_transition::StateTransition;
 use dpp::validation::ConsensusValidationResult;
@@ -46,6 +48,7 @@ pub(super) trait ValidateStateTransitionIdentitySignatureV0<'a> {
     fn validate_state_transition_identity_signed_v0(
         &self,
         drive: &Drive,
+        block_info: &BlockInfo,
         request_identity_balance: bool,
         request_identity_revision: bool,
         transaction: TransactionArg,
@@ -58,6 +61,7 @@ impl<'a> ValidateStateTransitionIdentitySignatureV0<'a> for StateTransition {
     fn validate_state_transition_identity_signed_v0(
         &self,
         drive: &Drive,
+        block_info: &BlockInfo,
         request_identity_balance: bool,
         request_identity_revision: bool,
         transaction: TransactionArg,
@@ -195,6 +199,18 @@ impl<'a> ValidateStateTransitionIdentitySignatureV0<'a> for StateTransition {
             return Ok(validation_result);
         }
 
+        if !public_key.is_within_validity_window(block_info.time_ms) {
+            validation_result.add_error(SignatureError::PublicKeyIsNotValidAtTimeError(
+                PublicKeyIsNotValidAtTimeError::new(
+                    public_key.id(),
+                    public_key.valid_from(),
+                    public_key.valid_until(),
+                    block_info.time_ms,
+                ),
+            ));
+            return Ok(validation_result);
+        }
+
         let operation = SignatureVerificationOperation::new(public_key.key_type());
         execution_context.add_operation(ValidationOperation::SignatureVerification(operation));
 
@@ -0,0 +1,361 @@
+use crate::error::Error;
+use crate::execution::validation::state_transition::common::validate_state_transition_identity_signed::v0::convert_to_consensus_signature_error;
+
+use dpp::consensus::signature::{
+    IdentityNotFoundError, InvalidSignaturePublicKeyPurposeError,
+    InvalidSignaturePublicKeySecurityLevelError, NotEnoughThresholdSignaturesError,
+    PublicKeyIsNotValidAtTimeError,
+};
+
+use dpp::block::block_info::BlockInfo;
+use dpp::identity::{IdentityPublicKey, KeyID, PartialIdentity, Purpose, SecurityLevel};
+
+use crate::execution::types::execution_operation::signature_verification_operation::SignatureVerificationOperation;
+use crate::execution::types::execution_operation::{RetrieveIdentityInfo, ValidationOperation};
+use crate::execution::types::state_transition_execution_context::{
+    StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0,
+};
+
+use dpp::consensus::signature::{
+    InvalidIdentityPublicKeyTypeError, MissingPublicKeyError, PublicKeyIsDisabledError,
+    SignatureError,
+};
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::identity_public_key::accessors::v1::IdentityPublicKeyGettersV1;
+use dpp::native_bls::NativeBlsModule;
+use dpp::state_transition::StateTransition;
+use dpp::validation::{ConsensusValidationResult, SimpleConsensusValidationResult};
+use dpp::version::PlatformVersion;
+use dpp::ProtocolError;
+use drive::dpp::identity::KeyType;
+use drive::drive::identity::key::fetch::{IdentityKeysRequest, KeyIDIdentityPublicKeyPairBTreeMap};
+use drive::drive::Drive;
+use drive::grovedb::TransactionArg;
+use lazy_static::lazy_static;
+use std::collections::HashSet;
+
+lazy_static! {
+    static ref SUPPORTED_KEY_TYPES: HashSet<KeyType> = {
+        let mut keys = HashSet::new();
+        keys.insert(KeyType::ECDSA_SECP256K1);
+        keys.insert(KeyType::BLS12_381);
+        keys.insert(KeyType::ECDSA_HASH160);
+        keys
+    };
+}
+
+pub(super) trait ValidateStateTransitionIdentitySignatureV1<'a> {
+    fn validate_state_transition_identity_signed_v1(
+        &self,
+        drive: &Drive,
+        block_info: &BlockInfo,
+        request_identity_balance: bool,
+        request_identity_revision: bool,
+        transaction: TransactionArg,
+        execution_context: &mut StateTransitionExecutionContext,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<PartialIdentity>, Error>;
+}
+
+impl<'a> ValidateStateTransitionIdentitySignatureV1<'a> for StateTransition {
+    fn validate_state_transition_identity_signed_v1(
+        &self,
+        drive: &Drive,
+        block_info: &BlockInfo,
+        request_identity_balance: bool,
+        request_identity_revision: bool,
+        transaction: TransactionArg,
+        execution_context: &mut StateTransitionExecutionContext,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<PartialIdentity>, Error> {
+        let mut validation_result = ConsensusValidationResult::<PartialIdentity>::default();
+
+        let key_id =
+            self.signature_public_key_id()
+                .ok_or(ProtocolError::CorruptedCodeExecution(
+                    "state_transition does not have a public key Id to verify".to_string(),
+                ))?;
+
+        let owner_id = self.owner_id();
+
+        let security_levels =
+            self.security_level_requirement()
+                .ok_or(ProtocolError::CorruptedCodeExecution(
+                    "state_transition does not have security level".to_string(),
+                ))?;
+
+        let purpose = self
+            .purpose_requirement()
+            .ok_or(ProtocolError::CorruptedCodeExecution(
+                "state_transition does not have a key purpose requirement".to_string(),
+            ))?;
+
+        let key_request = IdentityKeysRequest::new_specific_key_query(owner_id.as_bytes(), key_id);
+
+        let maybe_partial_identity = match (request_identity_balance, request_identity_revision) {
+            (true, true) => {
+                // This is for identity update
+                execution_context.add_operation(ValidationOperation::RetrieveIdentity(
+                    RetrieveIdentityInfo::one_key_and_balance_and_revision(),
+                ));
+                drive.fetch_identity_balance_with_keys_and_revision(
+                    key_request,
+                    transaction,
+                    platform_version,
+                )?
+            }
+            (true, false) => {
+                // This is for most state transitions
+                execution_context.add_operation(ValidationOperation::RetrieveIdentity(
+                    RetrieveIdentityInfo::one_key_and_balance(),
+                ));
+                drive.fetch_identity_balance_with_keys(
+                    key_request,
+                    transaction,
+                    platform_version,
+                )?
+            }
+            (false, true) => {
+                // This currently is not used
+                execution_context.add_operation(ValidationOperation::RetrieveIdentity(
+                    RetrieveIdentityInfo::one_key_and_revision(),
+                ));
+                drive.fetch_identity_revision_with_keys(
+                    key_request,
+                    transaction,
+                    platform_version,
+                )?
+            }
+            (false, false) => {
+                // This is for masternode vote transition
+                execution_context.add_operation(ValidationOperation::RetrieveIdentity(
+                    RetrieveIdentityInfo::one_key(),
+                ));
+                drive.fetch_identity_keys_as_partial_identity(
+                    key_request,
+                    transaction,
+                    platform_version,
+                )?
+            }
+        };
+
+        let partial_identity = match maybe_partial_identity {
+            None => {
+                validation_result.add_error(SignatureError::IdentityNotFoundError(
+                    IdentityNotFoundError::new(owner_id),
+                ));
+                return Ok(validation_result);
+            }
+            Some(partial_identity) => partial_identity,
+        };
+
+        if !partial_identity.not_found_public_keys.is_empty() {
+            validation_result.add_error(SignatureError::MissingPublicKeyError(
+                MissingPublicKeyError::new(key_id),
+            ));
+            return Ok(validation_result);
+        }
+
+        // This is very cheap because there will only be 1 key
+        let Some(public_key) = partial_identity.loaded_public_keys.get(&key_id) else {
+            validation_result.add_error(SignatureError::MissingPublicKeyError(
+                MissingPublicKeyError::new(key_id),
+            ));
+            return Ok(validation_result);
+        };
+
+        // Todo: is this needed?
+        if !SUPPORTED_KEY_TYPES.contains(&public_key.key_type()) {
+            validation_result.add_error(SignatureError::InvalidIdentityPublicKeyTypeError(
+                InvalidIdentityPublicKeyTypeError::new(public_key.key_type()),
+            ));
+            return Ok(validation_result);
+        }
+
+        if purpose != public_key.purpose() {
+            validation_result.add_error(SignatureError::InvalidSignaturePublicKeyPurposeError(
+                InvalidSignaturePublicKeyPurposeError::new(public_key.purpose(), purpose),
+            ));
+            return Ok(validation_result);
+        }
+
+        if !security_levels.contains(&public_key.security_level()) {
+            validation_result.add_error(
+                SignatureError::InvalidSignaturePublicKeySecurityLevelError(
+                    InvalidSignaturePublicKeySecurityLevelError::new(
+                        public_key.security_level(),
+                        security_levels,
+                    ),
+                ),
+            );
+            return Ok(validation_result);
+        }
+
+        if public_key.is_disabled() {
+            validation_result.add_error(SignatureError::PublicKeyIsDisabledError(
+                PublicKeyIsDisabledError::new(public_key.id()),
+            ));
+            return Ok(validation_result);
+        }
+
+        if !public_key.is_within_validity_window(block_info.time_ms) {
+            validation_result.add_error(SignatureError::PublicKeyIsNotValidAtTimeError(
+                PublicKeyIsNotValidAtTimeError::new(
+                    public_key.id(),
+                    public_key.valid_from(),
+                    public_key.valid_until(),
+                    block_info.time_ms,
+                ),
+            ));
+            return Ok(validation_result);
+        }
+
+        let operation = SignatureVerificationOperation::new(public_key.key_type());
+        execution_context.add_operation(ValidationOperation::SignatureVerification(operation));
+
+        let signature_is_valid = self.verify_signature(public_key, &NativeBlsModule);
+
+        if let Err(err) = signature_is_valid {
+            let consensus_error = convert_to_consensus_signature_error(err)?;
+            validation_result.add_error(consensus_error);
+            return Ok(validation_result);
+        }
+
+        if let Some(signature_threshold) = public_key.signature_threshold() {
+            let threshold_result = self.validate_threshold_co_signers_v0(
+                drive,
+                block_info,
+                public_key,
+                signature_threshold,
+                purpose,
+                &security_levels,
+                transaction,
+                execution_context,
+                platform_version,
+            )?;
+            if !threshold_result.is_valid() {
+                validation_result.add_errors(threshold_result.errors);
+                return Ok(validation_result);
+            }
+        }
+
+        validation_result.set_data(partial_identity);
+
+        Ok(validation_result)
+    }
+}
+
+trait ValidateThresholdCoSignersV0 {
+    #[allow(clippy::too_many_arguments)]
+    fn validate_threshold_co_signers_v0(
+        &self,
+        drive: &Drive,
+        block_info: &BlockInfo,
+        public_key: &IdentityPublicKey,
+        signature_threshold: u8,
+        purpose: Purpose,
+        security_levels: &[SecurityLevel],
+        transaction: TransactionArg,
+        execution_context: &mut StateTransitionExecutionContext,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+
+impl ValidateThresholdCoSignersV0 for StateTransition {
+    /// The signature of the threshold key itself was already verified, here we count the other
+    /// keys of the identity that validly signed along with it. Co-signers must be usable for
+    /// this state transition on their own, otherwise their signature doesn't count.
+    fn validate_threshold_co_signers_v0(
+        &self,
+        drive: &Drive,
+        block_info: &BlockInfo,
+        public_key: &IdentityPublicKey,
+        signature_threshold: u8,
+        purpose: Purpose,
+        security_levels: &[SecurityLevel],
+        transaction: TransactionArg,
+        execution_context: &mut StateTransitionExecutionContext,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let threshold_signature = match self.threshold_signature() {
+            Ok(threshold_signature) => threshold_signature,
+            Err(err) => {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    convert_to_consensus_signature_error(err)?,
+                ))
+            }
+        };
+
+        let not_enough_signatures = |provided: usize| {
+            SimpleConsensusValidationResult::new_with_error(
+                SignatureError::NotEnoughThresholdSignaturesError(
+                    NotEnoughThresholdSignaturesError::new(
+                        public_key.id(),
+                        signature_threshold,
+                        provided.min(u8::MAX as usize) as u8,
+                    ),
+                )
+                .into(),
+            )
+        };
+
+        let co_signer_key_ids: Vec<KeyID> = threshold_signature
+            .signatures
+            .keys()
+            .copied()
+            .filter(|key_id| *key_id != public_key.id())
+            .collect();
+
+        // The threshold key counts as one of the signers
+        if co_signer_key_ids.len() + 1 < signature_threshold as usize {
+            return Ok(not_enough_signatures(co_signer_key_ids.len() + 1));
+        }
+
+        execution_context.add_operation(ValidationOperation::RetrieveIdentity(
+            RetrieveIdentityInfo::keys(co_signer_key_ids.len() as u16),
+        ));
+        let co_signer_keys: KeyIDIdentityPublicKeyPairBTreeMap = drive.fetch_identity_keys(
+            IdentityKeysRequest::new_specific_keys_query(
+                self.owner_id().as_bytes(),
+                co_signer_key_ids,
+            ),
+            transaction,
+            platform_version,
+        )?;
+
+        let mut valid_signers = 1usize;
+        for (key_id, co_signer_key) in co_signer_keys.iter() {
+            if !SUPPORTED_KEY_TYPES.contains(&co_signer_key.key_type())
+                || co_signer_key.purpose() != purpose
+                || !security_levels.contains(&co_signer_key.security_level())
+                || co_signer_key.is_disabled()
+                || !co_signer_key.is_within_validity_window(block_info.time_ms)
+            {
+                continue;
+            }
+
+            let Some(signature) = threshold_signature.signature_for(*key_id) else {
+                continue;
+            };
+
+            let operation = SignatureVerificationOperation::new(co_signer_key.key_type());
+            execution_context.add_operation(ValidationOperation::SignatureVerification(operation));
+
+            if let Err(err) =
+                self.verify_signature_bytes(co_signer_key, signature.as_slice(), &NativeBlsModule)
+            {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    convert_to_consensus_signature_error(err)?,
+                ));
+            }
+
+            valid_signers += 1;
+        }
+
+        if valid_signers < signature_threshold as usize {
+            return Ok(not_enough_signatures(valid_signers));
+        }
+
+        Ok(SimpleConsensusValidationResult::new())
+    }
+}
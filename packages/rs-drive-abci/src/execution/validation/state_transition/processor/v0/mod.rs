@@ -14,9 +14,12 @@ use std::collections::BTreeMap;
 
 use crate::error::execution::ExecutionError;
 use dpp::serialization::Signable;
+use dpp::state_transition::identity_recovery_transition::accessors::IdentityRecoveryTransitionAccessorsV0;
+use dpp::state_transition::masternode_vote_transition::accessors::MasternodeVoteTransitionAccessorsV0;
 use dpp::state_transition::StateTransition;
 use dpp::validation::SimpleConsensusValidationResult;
 use dpp::version::{DefaultForPlatformVersion, PlatformVersion};
+use dpp::voting::votes::Vote;
 use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
 use drive::state_transition_action::StateTransitionAction;
@@ -27,6 +30,7 @@ use crate::execution::validation::state_transition::common::validate_state_trans
 use crate::execution::validation::state_transition::identity_create::{StateTransitionStateValidationForIdentityCreateTransitionV0, StateTransitionStructureKnownInStateValidationForIdentityCreateTransitionV0};
 use crate::execution::validation::state_transition::identity_top_up::StateTransitionIdentityTopUpTransitionActionTransformer;
 use crate::execution::validation::state_transition::state_transitions::identity_update::advanced_structure::v0::IdentityUpdateStateTransitionIdentityAndSignaturesValidationV0;
+use crate::execution::validation::state_transition::state_transitions::identity_recovery::signatures::IdentityRecoveryStateTransitionSignaturesValidation;
 use crate::execution::validation::state_transition::state_transitions::identity_top_up::identity_retrieval::v0::IdentityTopUpStateTransitionIdentityRetrievalV0;
 use crate::execution::validation::state_transition::ValidationMode;
 pub(super) fn process_state_transition_v0<'a, C: CoreRPCLike>(
@@ -55,6 +59,7 @@ pub(super) fn process_state_transition_v0<'a, C: CoreRPCLike>(
         let result = if state_transition.validates_signature_based_on_identity_info() {
             state_transition.validate_identity_signed_state_transition(
                 platform.drive,
+                block_info,
                 transaction,
                 &mut state_transition_execution_context,
                 platform_version,
@@ -277,6 +282,7 @@ pub(crate) trait StateTransitionIdentityBasedSignatureValidationV0 {
     /// # Arguments
     ///
     /// * `drive` - A reference to the drive containing the transaction data.
+    /// * `block_info` - The block the transaction is validated against, used to check key validity windows.
     /// * `tx` - The transaction argument to be authenticated.
     /// * `execution_context` - A mutable reference to the StateTransitionExecutionContext that provides the context for validation.
     /// * `platform_version` - A reference to the PlatformVersion to be used for validation.
@@ -290,6 +296,7 @@ pub(crate) trait StateTransitionIdentityBasedSignatureValidationV0 {
     fn validate_identity_signed_state_transition(
         &self,
         drive: &Drive,
+        block_info: &BlockInfo,
         tx: TransactionArg,
         execution_context: &mut StateTransitionExecutionContext,
         platform_version: &PlatformVersion,
@@ -514,12 +521,15 @@ impl StateTransitionBasicStructureValidationV0 for StateTransition {
         match self {
             StateTransition::DataContractCreate(_)
             | StateTransition::DataContractUpdate(_)
-            | StateTransition::MasternodeVote(_) => {
+            | StateTransition::DataContractDelete(_)
+            | StateTransition::MasternodeVote(_)
+            | StateTransition::AuctionBid(_) => {
                 // no basic structure validation
                 Ok(SimpleConsensusValidationResult::new())
             }
             StateTransition::IdentityCreate(st) => st.validate_basic_structure(platform_version),
             StateTransition::IdentityUpdate(st) => st.validate_basic_structure(platform_version),
+            StateTransition::IdentityRecovery(st) => st.validate_basic_structure(platform_version),
             StateTransition::IdentityTopUp(st) => st.validate_basic_structure(platform_version),
             StateTransition::IdentityCreditWithdrawal(st) => {
                 st.validate_basic_structure(platform_version)
@@ -528,6 +538,9 @@ impl StateTransitionBasicStructureValidationV0 for StateTransition {
             StateTransition::IdentityCreditTransfer(st) => {
                 st.validate_basic_structure(platform_version)
             }
+            StateTransition::IdentityCreditBatchTransfer(st) => {
+                st.validate_basic_structure(platform_version)
+            }
         }
     }
     fn has_basic_structure_validation(&self) -> bool {
@@ -535,7 +548,9 @@ impl StateTransitionBasicStructureValidationV0 for StateTransition {
             self,
             StateTransition::DataContractCreate(_)
                 | StateTransition::DataContractUpdate(_)
+                | StateTransition::DataContractDelete(_)
                 | StateTransition::MasternodeVote(_)
+                | StateTransition::AuctionBid(_)
         )
     }
 }
@@ -571,6 +586,13 @@ impl StateTransitionNonceValidationV0 for StateTransition {
                 execution_context,
                 platform_version,
             ),
+            StateTransition::DataContractDelete(st) => st.validate_nonces(
+                platform,
+                block_info,
+                tx,
+                execution_context,
+                platform_version,
+            ),
             StateTransition::IdentityUpdate(st) => st.validate_nonces(
                 platform,
                 block_info,
@@ -578,6 +600,13 @@ impl StateTransitionNonceValidationV0 for StateTransition {
                 execution_context,
                 platform_version,
             ),
+            StateTransition::IdentityRecovery(st) => st.validate_nonces(
+                platform,
+                block_info,
+                tx,
+                execution_context,
+                platform_version,
+            ),
             StateTransition::IdentityCreditTransfer(st) => st.validate_nonces(
                 platform,
                 block_info,
@@ -585,6 +614,13 @@ impl StateTransitionNonceValidationV0 for StateTransition {
                 execution_context,
                 platform_version,
             ),
+            StateTransition::IdentityCreditBatchTransfer(st) => st.validate_nonces(
+                platform,
+                block_info,
+                tx,
+                execution_context,
+                platform_version,
+            ),
             StateTransition::IdentityCreditWithdrawal(st) => st.validate_nonces(
                 platform,
                 block_info,
@@ -599,6 +635,13 @@ impl StateTransitionNonceValidationV0 for StateTransition {
                 execution_context,
                 platform_version,
             ),
+            StateTransition::AuctionBid(st) => st.validate_nonces(
+                platform,
+                block_info,
+                tx,
+                execution_context,
+                platform_version,
+            ),
             _ => Ok(SimpleConsensusValidationResult::new()),
         }
     }
@@ -609,9 +652,13 @@ impl StateTransitionNonceValidationV0 for StateTransition {
             StateTransition::DocumentsBatch(_)
                 | StateTransition::DataContractCreate(_)
                 | StateTransition::DataContractUpdate(_)
+                | StateTransition::DataContractDelete(_)
                 | StateTransition::IdentityUpdate(_)
+                | StateTransition::IdentityRecovery(_)
                 | StateTransition::IdentityCreditTransfer(_)
+                | StateTransition::IdentityCreditBatchTransfer(_)
                 | StateTransition::IdentityCreditWithdrawal(_)
+                | StateTransition::AuctionBid(_)
         )
     }
 }
@@ -626,15 +673,23 @@ impl StateTransitionIdentityBalanceValidationV0 for StateTransition {
             StateTransition::IdentityCreditTransfer(st) => {
                 st.validate_minimum_balance_pre_check(identity, platform_version)
             }
+            StateTransition::IdentityCreditBatchTransfer(st) => {
+                st.validate_minimum_balance_pre_check(identity, platform_version)
+            }
             StateTransition::IdentityCreditWithdrawal(st) => {
                 st.validate_minimum_balance_pre_check(identity, platform_version)
             }
             StateTransition::DocumentsBatch(st) => {
                 st.validate_minimum_balance_pre_check(identity, platform_version)
             }
+            StateTransition::AuctionBid(st) => {
+                st.validate_minimum_balance_pre_check(identity, platform_version)
+            }
             StateTransition::DataContractCreate(_)
             | StateTransition::DataContractUpdate(_)
-            | StateTransition::IdentityUpdate(_) => {
+            | StateTransition::DataContractDelete(_)
+            | StateTransition::IdentityUpdate(_)
+            | StateTransition::IdentityRecovery(_) => {
                 self.validate_simple_pre_check_minimum_balance(identity, platform_version)
             }
             StateTransition::MasternodeVote(_)
@@ -647,11 +702,15 @@ impl StateTransitionIdentityBalanceValidationV0 for StateTransition {
         matches!(
             self,
             StateTransition::IdentityCreditTransfer(_)
+                | StateTransition::IdentityCreditBatchTransfer(_)
                 | StateTransition::IdentityCreditWithdrawal(_)
                 | StateTransition::DataContractCreate(_)
                 | StateTransition::DataContractUpdate(_)
+                | StateTransition::DataContractDelete(_)
                 | StateTransition::DocumentsBatch(_)
                 | StateTransition::IdentityUpdate(_)
+                | StateTransition::IdentityRecovery(_)
+                | StateTransition::AuctionBid(_)
         )
     }
 }
@@ -803,6 +862,7 @@ impl StateTransitionIdentityBasedSignatureValidationV0 for StateTransition {
     fn validate_identity_signed_state_transition(
         &self,
         drive: &Drive,
+        block_info: &BlockInfo,
         tx: TransactionArg,
         execution_context: &mut StateTransitionExecutionContext,
         platform_version: &PlatformVersion,
@@ -810,12 +870,16 @@ impl StateTransitionIdentityBasedSignatureValidationV0 for StateTransition {
         match self {
             StateTransition::DataContractCreate(_)
             | StateTransition::DataContractUpdate(_)
+            | StateTransition::DataContractDelete(_)
             | StateTransition::IdentityCreditWithdrawal(_)
             | StateTransition::IdentityCreditTransfer(_)
-            | StateTransition::DocumentsBatch(_) => {
+            | StateTransition::IdentityCreditBatchTransfer(_)
+            | StateTransition::DocumentsBatch(_)
+            | StateTransition::AuctionBid(_) => {
                 //Basic signature verification
                 Ok(self.validate_state_transition_identity_signed(
                     drive,
+                    block_info,
                     true,
                     false,
                     tx,
@@ -827,6 +891,7 @@ impl StateTransitionIdentityBasedSignatureValidationV0 for StateTransition {
                 //Basic signature verification
                 Ok(self.validate_state_transition_identity_signed(
                     drive,
+                    block_info,
                     true,
                     true,
                     tx,
@@ -834,15 +899,44 @@ impl StateTransitionIdentityBasedSignatureValidationV0 for StateTransition {
                     platform_version,
                 )?)
             }
-            StateTransition::MasternodeVote(_) => {
+            StateTransition::IdentityRecovery(st) => {
+                if st.operation().is_signed_by_owner() {
+                    //Basic signature verification
+                    Ok(self.validate_state_transition_identity_signed(
+                        drive,
+                        block_info,
+                        true,
+                        false,
+                        tx,
+                        execution_context,
+                        platform_version,
+                    )?)
+                } else {
+                    // Initiating is approved by the guardians and completing is signed by the
+                    //  pending master key, neither of which is a key of the identity yet
+                    let signable_bytes: Vec<u8> = self.signable_bytes()?;
+                    st.validate_identity_recovery_signatures(
+                        signable_bytes,
+                        drive,
+                        block_info,
+                        tx,
+                        execution_context,
+                        platform_version,
+                    )
+                }
+            }
+            StateTransition::MasternodeVote(st) => {
                 //Basic signature verification
 
-                // We do not request the balance because masternodes do not pay for their voting
-                //  themselves
+                // Masternodes do not pay for voting on contested resources themselves, however
+                //  the vote opening a governance proposal vote poll is paid by the voter identity,
+                //  so we need its balance
+                let request_balance = matches!(st.vote(), Vote::GovernanceProposalVote(_));
 
                 Ok(self.validate_state_transition_identity_signed(
                     drive,
-                    false,
+                    block_info,
+                    request_balance,
                     false,
                     tx,
                     execution_context,
@@ -915,6 +1009,15 @@ impl StateTransitionStateValidationV0 for StateTransition {
                 execution_context,
                 tx,
             ),
+            // The replay attack is prevented by identity data contract nonce
+            StateTransition::DataContractDelete(st) => st.validate_state(
+                action,
+                platform,
+                validation_mode,
+                block_info,
+                execution_context,
+                tx,
+            ),
             StateTransition::IdentityCreate(st) => {
                 let action =
                     action.ok_or(Error::Execution(ExecutionError::CorruptedCodeExecution(
@@ -940,6 +1043,14 @@ impl StateTransitionStateValidationV0 for StateTransition {
                 execution_context,
                 tx,
             ),
+            StateTransition::IdentityRecovery(st) => st.validate_state(
+                action,
+                platform,
+                validation_mode,
+                block_info,
+                execution_context,
+                tx,
+            ),
             StateTransition::IdentityTopUp(st) => {
                 // Nothing to validate from state
                 if let Some(action) = action {
@@ -980,6 +1091,14 @@ impl StateTransitionStateValidationV0 for StateTransition {
                 execution_context,
                 tx,
             ),
+            StateTransition::IdentityCreditBatchTransfer(st) => st.validate_state(
+                action,
+                platform,
+                validation_mode,
+                block_info,
+                execution_context,
+                tx,
+            ),
             StateTransition::MasternodeVote(st) => st.validate_state(
                 action,
                 platform,
@@ -988,6 +1107,14 @@ impl StateTransitionStateValidationV0 for StateTransition {
                 execution_context,
                 tx,
             ),
+            StateTransition::AuctionBid(st) => st.validate_state(
+                action,
+                platform,
+                validation_mode,
+                block_info,
+                execution_context,
+                tx,
+            ),
         }
     }
 }
@@ -996,14 +1123,22 @@ impl StateTransitionIsAllowedValidationV0 for StateTransition {
     fn has_is_allowed_validation(&self, platform_version: &PlatformVersion) -> Result<bool, Error> {
         match self {
             StateTransition::DocumentsBatch(st) => st.has_is_allowed_validation(platform_version),
+            StateTransition::DataContractDelete(st) => {
+                st.has_is_allowed_validation(platform_version)
+            }
+            StateTransition::IdentityRecovery(st) => st.has_is_allowed_validation(platform_version),
+            StateTransition::MasternodeVote(st) => st.has_is_allowed_validation(platform_version),
+            StateTransition::AuctionBid(st) => st.has_is_allowed_validation(platform_version),
+            StateTransition::IdentityCreditBatchTransfer(st) => {
+                st.has_is_allowed_validation(platform_version)
+            }
             StateTransition::DataContractCreate(_)
             | StateTransition::DataContractUpdate(_)
             | StateTransition::IdentityCreate(_)
             | StateTransition::IdentityTopUp(_)
             | StateTransition::IdentityCreditWithdrawal(_)
             | StateTransition::IdentityUpdate(_)
-            | StateTransition::IdentityCreditTransfer(_)
-            | StateTransition::MasternodeVote(_) => Ok(false),
+            | StateTransition::IdentityCreditTransfer(_) => Ok(false),
         }
     }
 
@@ -1016,6 +1151,19 @@ impl StateTransitionIsAllowedValidationV0 for StateTransition {
             StateTransition::DocumentsBatch(st) => {
                 st.validate_is_allowed(platform, platform_version)
             }
+            StateTransition::DataContractDelete(st) => {
+                st.validate_is_allowed(platform, platform_version)
+            }
+            StateTransition::IdentityRecovery(st) => {
+                st.validate_is_allowed(platform, platform_version)
+            }
+            StateTransition::MasternodeVote(st) => {
+                st.validate_is_allowed(platform, platform_version)
+            }
+            StateTransition::AuctionBid(st) => st.validate_is_allowed(platform, platform_version),
+            StateTransition::IdentityCreditBatchTransfer(st) => {
+                st.validate_is_allowed(platform, platform_version)
+            }
             _ => Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
                 "validate_is_allowed is not implemented for this state transition",
             ))),
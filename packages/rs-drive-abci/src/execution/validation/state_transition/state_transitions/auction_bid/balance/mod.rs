@@ -0,0 +1,39 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::execution::validation::state_transition::auction_bid::balance::v0::AuctionBidTransitionBalanceValidationV0;
+use crate::execution::validation::state_transition::processor::v0::StateTransitionIdentityBalanceValidationV0;
+use dpp::identity::PartialIdentity;
+use dpp::state_transition::auction_bid_transition::AuctionBidTransition;
+use dpp::validation::SimpleConsensusValidationResult;
+use dpp::version::PlatformVersion;
+
+pub(crate) mod v0;
+impl StateTransitionIdentityBalanceValidationV0 for AuctionBidTransition {
+    fn validate_minimum_balance_pre_check(
+        &self,
+        identity: &PartialIdentity,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .auction_bid_state_transition
+            .as_ref()
+            .and_then(|versions| versions.advanced_minimum_balance_pre_check)
+        {
+            Some(0) => {
+                self.validate_advanced_minimum_balance_pre_check_v0(identity, platform_version)
+            }
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "auction bid transition: validate_balance".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "auction bid transition: validate_balance".to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::error::Error;
+use dpp::consensus::state::identity::IdentityInsufficientBalanceError;
+use dpp::identity::PartialIdentity;
+use dpp::state_transition::auction_bid_transition::accessors::AuctionBidTransitionAccessorsV0;
+use dpp::state_transition::auction_bid_transition::AuctionBidTransition;
+
+use dpp::validation::SimpleConsensusValidationResult;
+
+use crate::error::execution::ExecutionError;
+use dpp::version::PlatformVersion;
+use dpp::voting::auction_bids::AuctionBid;
+
+pub(in crate::execution::validation::state_transition::state_transitions) trait AuctionBidTransitionBalanceValidationV0
+{
+    fn validate_advanced_minimum_balance_pre_check_v0(
+        &self,
+        identity: &PartialIdentity,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+
+impl AuctionBidTransitionBalanceValidationV0 for AuctionBidTransition {
+    fn validate_advanced_minimum_balance_pre_check_v0(
+        &self,
+        identity: &PartialIdentity,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let balance =
+            identity
+                .balance
+                .ok_or(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                    "expected to have a balance on identity for auction bid transition",
+                )))?;
+
+        // Committing a bid escrows the deposit, revealing it only costs the fee
+        let escrowed_amount = match self.bid() {
+            AuctionBid::Commit { deposit, .. } => *deposit,
+            AuctionBid::Reveal { .. } => 0,
+        };
+
+        let required_balance = escrowed_amount.checked_add(
+            platform_version
+                .fee_version
+                .state_transition_min_fees
+                .document_batch_sub_transition,
+        );
+
+        match required_balance {
+            Some(required_balance) if balance >= required_balance => {
+                Ok(SimpleConsensusValidationResult::new())
+            }
+            _ => Ok(SimpleConsensusValidationResult::new_with_error(
+                IdentityInsufficientBalanceError::new(
+                    self.identity_id(),
+                    balance,
+                    required_balance.unwrap_or(u64::MAX),
+                )
+                .into(),
+            )),
+        }
+    }
+}
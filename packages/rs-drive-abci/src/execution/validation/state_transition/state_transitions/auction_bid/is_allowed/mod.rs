@@ -0,0 +1,41 @@
+use crate::error::Error;
+use crate::execution::validation::state_transition::processor::v0::StateTransitionIsAllowedValidationV0;
+use crate::platform_types::platform::PlatformRef;
+use dpp::consensus::basic::UnsupportedFeatureError;
+use dpp::state_transition::auction_bid_transition::AuctionBidTransition;
+use dpp::validation::ConsensusValidationResult;
+use dpp::version::PlatformVersion;
+
+impl StateTransitionIsAllowedValidationV0 for AuctionBidTransition {
+    fn has_is_allowed_validation(
+        &self,
+        _platform_version: &PlatformVersion,
+    ) -> Result<bool, Error> {
+        Ok(true)
+    }
+
+    /// Bids can only be placed from the protocol version that resolves contested indices by auction
+    fn validate_is_allowed<C>(
+        &self,
+        _platform: &PlatformRef<C>,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<()>, Error> {
+        if platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .auction_bid_state_transition
+            .is_some()
+        {
+            return Ok(ConsensusValidationResult::new());
+        }
+
+        Ok(ConsensusValidationResult::new_with_error(
+            UnsupportedFeatureError::new(
+                "contested index auctions".to_string(),
+                platform_version.protocol_version,
+            )
+            .into(),
+        ))
+    }
+}
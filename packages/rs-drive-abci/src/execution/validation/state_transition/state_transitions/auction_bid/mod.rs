@@ -0,0 +1,299 @@
+mod balance;
+mod is_allowed;
+mod nonce;
+mod state;
+
+use dpp::block::block_info::BlockInfo;
+use dpp::state_transition::auction_bid_transition::AuctionBidTransition;
+use dpp::validation::ConsensusValidationResult;
+use drive::state_transition_action::StateTransitionAction;
+
+use drive::grovedb::TransactionArg;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+use crate::platform_types::platform::PlatformRef;
+use crate::rpc::core::CoreRPCLike;
+
+use crate::execution::validation::state_transition::auction_bid::state::v0::AuctionBidStateTransitionStateValidationV0;
+use crate::execution::validation::state_transition::processor::v0::StateTransitionStateValidationV0;
+use crate::execution::validation::state_transition::transformer::StateTransitionActionTransformerV0;
+use crate::execution::validation::state_transition::ValidationMode;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+
+impl StateTransitionActionTransformerV0 for AuctionBidTransition {
+    fn transform_into_action<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
+        _validation_mode: ValidationMode,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version = platform.state.current_platform_version()?;
+
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .auction_bid_state_transition
+            .as_ref()
+            .map(|versions| versions.transform_into_action)
+        {
+            // What is stored for the bid depends on the bid already committed in state,
+            // so the action can only be resolved while validating the state
+            Some(0) => self.validate_state_v0(
+                platform,
+                block_info,
+                execution_context,
+                tx,
+                platform_version,
+            ),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "auction bid transition: transform_into_action".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "auction bid transition: transform_into_action".to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
+
+impl StateTransitionStateValidationV0 for AuctionBidTransition {
+    fn validate_state<C: CoreRPCLike>(
+        &self,
+        action: Option<StateTransitionAction>,
+        platform: &PlatformRef<C>,
+        _validation_mode: ValidationMode,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version = platform.state.current_platform_version()?;
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .auction_bid_state_transition
+            .as_ref()
+            .map(|versions| versions.state)
+        {
+            Some(0) => {
+                if action.is_some() {
+                    return Err(Error::Execution(ExecutionError::CorruptedCodeExecution("auction bid is calling validate state, and the action is already known. It should not be known at this point")));
+                }
+                self.validate_state_v0(
+                    platform,
+                    block_info,
+                    execution_context,
+                    tx,
+                    platform_version,
+                )
+            }
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "auction bid transition: validate_state".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "auction bid transition: validate_state".to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execution::validation::state_transition::tests::{
+        process_state_transition_in_current_version, setup_identity,
+    };
+    use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
+    use assert_matches::assert_matches;
+    use dpp::consensus::basic::BasicError;
+    use dpp::consensus::ConsensusError;
+    use dpp::dash_to_credits;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::data_contract::document_type::random_document::{
+        CreateRandomDocument, DocumentFieldFillSize, DocumentFieldFillType,
+    };
+    use dpp::document::DocumentV0Setters;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::{Identity, IdentityPublicKey};
+    use dpp::platform_value::Bytes32;
+    use dpp::state_transition::auction_bid_transition::methods::AuctionBidTransitionMethodsV0;
+    use dpp::state_transition::auction_bid_transition::AuctionBidTransition;
+    use dpp::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
+    use dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
+    use dpp::state_transition::StateTransition;
+    use dpp::version::PlatformVersion;
+    use dpp::voting::auction_bids::AuctionBid;
+    use dpp::voting::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePoll;
+    use drive::util::test_helpers::setup_contract;
+    use rand::prelude::StdRng;
+    use rand::{Rng, SeedableRng};
+    use simple_signer::signer::SimpleSigner;
+
+    const HANDLE: &str = "bob";
+
+    /// Registers the same handle for two identities, which opens a vote poll resolved by auction
+    /// at the genesis block time, and returns the first contender with the vote poll
+    fn setup_auction_contest(
+        platform: &mut TempPlatform<MockCoreRPCLike>,
+    ) -> (
+        (Identity, SimpleSigner, IdentityPublicKey),
+        ContestedDocumentResourceVotePoll,
+    ) {
+        let platform_version = PlatformVersion::latest();
+        let mut rng = StdRng::seed_from_u64(433);
+
+        let contract = setup_contract(
+            &platform.drive,
+            "tests/supporting_files/contract/auction/auction-contract-contested-unique-index.json",
+            None,
+            None,
+        );
+
+        let handle_type = contract
+            .document_type_for_name("handle")
+            .expect("expected a handle document type");
+
+        let contenders = [
+            setup_identity(platform, rng.gen(), dash_to_credits!(0.5)),
+            setup_identity(platform, rng.gen(), dash_to_credits!(0.5)),
+        ];
+
+        for (identity, signer, key) in &contenders {
+            let entropy = Bytes32::random_with_rng(&mut rng);
+
+            let mut document = handle_type
+                .random_document_with_identifier_and_entropy(
+                    &mut rng,
+                    identity.id(),
+                    entropy,
+                    DocumentFieldFillType::FillIfNotRequired,
+                    DocumentFieldFillSize::AnyDocumentFillSize,
+                    platform_version,
+                )
+                .expect("expected a random document");
+
+            document.set("label", HANDLE.into());
+
+            let documents_batch_create_transition =
+                DocumentsBatchTransition::new_document_creation_transition_from_document(
+                    document,
+                    handle_type,
+                    entropy.0,
+                    key,
+                    1,
+                    0,
+                    signer,
+                    platform_version,
+                    None,
+                    None,
+                    None,
+                )
+                .expect("expect to create documents batch transition");
+
+            assert_matches!(
+                process_state_transition_in_current_version(
+                    platform,
+                    &documents_batch_create_transition
+                ),
+                StateTransitionExecutionResult::SuccessfulExecution(..)
+            );
+        }
+
+        let vote_poll = ContestedDocumentResourceVotePoll {
+            contract_id: contract.id(),
+            document_type_name: "handle".to_string(),
+            index_name: "label".to_string(),
+            index_values: vec![HANDLE.into()],
+        };
+
+        let [contender, _] = contenders;
+
+        (contender, vote_poll)
+    }
+
+    fn commit_bid(
+        (identity, signer, key): &(Identity, SimpleSigner, IdentityPublicKey),
+        vote_poll: ContestedDocumentResourceVotePoll,
+    ) -> StateTransition {
+        let amount = dash_to_credits!(0.1);
+        let sealed_bid = AuctionBid::sealed_bid_hash(
+            vote_poll.unique_id().expect("expected a vote poll id"),
+            identity.id(),
+            amount,
+            Bytes32::new([7; 32]),
+        );
+
+        AuctionBidTransition::try_from_identity(
+            identity,
+            vote_poll,
+            AuctionBid::Commit {
+                sealed_bid,
+                deposit: amount,
+            },
+            0,
+            signer.clone(),
+            Some(key),
+            1,
+            PlatformVersion::latest(),
+            None,
+        )
+        .expect("expected an auction bid transition")
+    }
+
+    #[test]
+    fn test_auction_bid_is_rejected_before_protocol_version_2() {
+        let mut platform = TestPlatformBuilder::new()
+            .with_initial_protocol_version(1)
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        // Contracts can't declare auction resolution on protocol version 1 so no vote poll
+        // could have been opened, the bid must be turned down before looking it up
+        let bidder = setup_identity(&mut platform, 958, dash_to_credits!(0.5));
+        let vote_poll = ContestedDocumentResourceVotePoll {
+            index_values: vec![HANDLE.into()],
+            ..Default::default()
+        };
+
+        let result =
+            process_state_transition_in_current_version(&platform, &commit_bid(&bidder, vote_poll));
+
+        assert_matches!(
+            result,
+            StateTransitionExecutionResult::UnpaidConsensusError(ConsensusError::BasicError(
+                BasicError::UnsupportedFeatureError(_)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_auction_bid_commit_is_processed_from_protocol_version_2() {
+        let mut platform = TestPlatformBuilder::new()
+            .with_initial_protocol_version(2)
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let (contender, vote_poll) = setup_auction_contest(&mut platform);
+
+        let result = process_state_transition_in_current_version(
+            &platform,
+            &commit_bid(&contender, vote_poll),
+        );
+
+        assert_matches!(
+            result,
+            StateTransitionExecutionResult::SuccessfulExecution(..)
+        );
+    }
+}
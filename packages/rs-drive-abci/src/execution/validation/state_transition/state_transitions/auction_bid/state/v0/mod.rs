@@ -0,0 +1,304 @@
+use crate::error::Error;
+
+use crate::platform_types::platform::PlatformRef;
+
+use dpp::block::block_info::BlockInfo;
+use dpp::consensus::basic::document::DataContractNotPresentError;
+use dpp::consensus::basic::BasicError;
+use dpp::consensus::state::voting::auction_bid_already_committed_error::AuctionBidAlreadyCommittedError;
+use dpp::consensus::state::voting::auction_bid_outside_of_window_error::AuctionBidOutsideOfWindowError;
+use dpp::consensus::state::voting::auction_bidder_not_contender_error::AuctionBidderNotContenderError;
+use dpp::consensus::state::voting::invalid_auction_bid_reveal_error::InvalidAuctionBidRevealError;
+use dpp::consensus::state::voting::vote_poll_not_available_for_voting_error::VotePollNotAvailableForVotingError;
+use dpp::consensus::state::voting::vote_poll_not_found_error::VotePollNotFoundError;
+use dpp::consensus::state::voting::vote_poll_not_resolved_by_auction_error::VotePollNotResolvedByAuctionError;
+use dpp::consensus::ConsensusError;
+use dpp::prelude::ConsensusValidationResult;
+use dpp::state_transition::auction_bid_transition::accessors::AuctionBidTransitionAccessorsV0;
+use dpp::state_transition::auction_bid_transition::AuctionBidTransition;
+use dpp::version::PlatformVersion;
+use dpp::voting::auction_bids::AuctionBid;
+use dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dpp::voting::vote_info_storage::contested_document_auction_bid_stored_info::{
+    ContestedDocumentAuctionBidStoredInfo, ContestedDocumentAuctionBidStoredInfoV0Getters,
+};
+use dpp::voting::vote_info_storage::contested_document_vote_poll_stored_info::{
+    ContestedDocumentVotePollStatus, ContestedDocumentVotePollStoredInfoV0Getters,
+};
+use dpp::voting::vote_polls::VotePoll;
+use drive::drive::votes::paths::VotePollPaths;
+use drive::drive::votes::resolved::vote_polls::contested_document_resource_vote_poll::resolve::ContestedDocumentResourceVotePollResolver;
+use drive::drive::votes::ResourceVoteChoiceToKeyTrait;
+use drive::drive::Drive;
+use drive::error::drive::DriveError;
+use drive::grovedb::TransactionArg;
+use drive::state_transition_action::identity::auction_bid::AuctionBidTransitionAction;
+use drive::state_transition_action::system::bump_identity_nonce_action::BumpIdentityNonceAction;
+use drive::state_transition_action::StateTransitionAction;
+use drive::util::grove_operations::DirectQueryType;
+
+use crate::execution::types::execution_operation::ValidationOperation;
+use crate::execution::types::state_transition_execution_context::{
+    StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0,
+};
+
+pub(in crate::execution::validation::state_transition::state_transitions::auction_bid) trait AuctionBidStateTransitionStateValidationV0
+{
+    fn validate_state_v0<C>(
+        &self,
+        platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+}
+
+impl AuctionBidStateTransitionStateValidationV0 for AuctionBidTransition {
+    fn validate_state_v0<C>(
+        &self,
+        platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let drive = platform.drive;
+        let identity_id = self.identity_id();
+        let vote_poll = self.vote_poll();
+        let vote_poll_id = vote_poll.unique_id()?;
+
+        let mut drive_operations = vec![];
+
+        let (contract_fee, maybe_contract_fetch_info) = drive
+            .get_contract_with_fetch_info_and_fee(
+                vote_poll.contract_id.to_buffer(),
+                Some(&block_info.epoch),
+                false,
+                tx,
+                platform_version,
+            )?;
+
+        if let Some(contract_fee) = contract_fee {
+            execution_context
+                .add_operation(ValidationOperation::PrecalculatedOperation(contract_fee));
+        }
+
+        let Some(contract_fetch_info) = maybe_contract_fetch_info else {
+            return Ok(bump_nonce_with_error(
+                self,
+                BasicError::DataContractNotPresentError(DataContractNotPresentError::new(
+                    vote_poll.contract_id,
+                ))
+                .into(),
+            ));
+        };
+
+        let resolved_vote_poll =
+            vote_poll.resolve_with_provided_arc_contract_fetch_info(contract_fetch_info)?;
+
+        match resolved_vote_poll.is_resolved_by_auction() {
+            Ok(true) => {}
+            Ok(false) => {
+                return Ok(bump_nonce_with_error(
+                    self,
+                    VotePollNotResolvedByAuctionError::new(
+                        VotePoll::ContestedDocumentResourceVotePoll(vote_poll.clone()),
+                    )
+                    .into(),
+                ));
+            }
+            // The document type or the index of the vote poll does not exist in the contract
+            Err(drive::error::Error::Drive(DriveError::ContestedIndexNotFound(_)))
+            | Err(drive::error::Error::Protocol(_)) => {
+                return Ok(bump_nonce_with_error(
+                    self,
+                    VotePollNotFoundError::new(VotePoll::ContestedDocumentResourceVotePoll(
+                        vote_poll.clone(),
+                    ))
+                    .into(),
+                ));
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        let (stored_info_fee, maybe_stored_info) = drive
+            .fetch_contested_document_vote_poll_stored_info(
+                &resolved_vote_poll,
+                Some(&block_info.epoch),
+                tx,
+                platform_version,
+            )?;
+
+        if let Some(stored_info_fee) = stored_info_fee {
+            execution_context
+                .add_operation(ValidationOperation::PrecalculatedOperation(stored_info_fee));
+        }
+
+        let Some(stored_info) = maybe_stored_info else {
+            return Ok(bump_nonce_with_error(
+                self,
+                VotePollNotFoundError::new(VotePoll::ContestedDocumentResourceVotePoll(
+                    vote_poll.clone(),
+                ))
+                .into(),
+            ));
+        };
+
+        let ContestedDocumentVotePollStatus::Started(start_block) =
+            stored_info.vote_poll_status_ref()
+        else {
+            return Ok(bump_nonce_with_error(
+                self,
+                VotePollNotAvailableForVotingError::new(
+                    VotePoll::ContestedDocumentResourceVotePoll(vote_poll.clone()),
+                    stored_info.vote_poll_status(),
+                )
+                .into(),
+            ));
+        };
+
+        // Bids are committed while the vote poll would normally be open, and are revealed
+        // during the extra time auctions are given before they are resolved
+        let voting_versions = &platform_version.dpp.voting_versions;
+        let bidding_end = start_block
+            .time_ms
+            .saturating_add(voting_versions.default_vote_poll_time_duration_ms);
+        let (window_start, window_end) = if self.bid().is_commit() {
+            (start_block.time_ms, bidding_end)
+        } else {
+            (
+                bidding_end,
+                bidding_end.saturating_add(voting_versions.auction_bid_reveal_duration_ms),
+            )
+        };
+
+        if block_info.time_ms < window_start || block_info.time_ms >= window_end {
+            return Ok(bump_nonce_with_error(
+                self,
+                AuctionBidOutsideOfWindowError::new(
+                    vote_poll_id,
+                    block_info.time_ms,
+                    window_start,
+                    window_end,
+                )
+                .into(),
+            ));
+        }
+
+        let is_contender = drive.grove_has_raw(
+            resolved_vote_poll
+                .contenders_path(platform_version)?
+                .as_slice()
+                .into(),
+            ResourceVoteChoice::TowardsIdentity(identity_id)
+                .to_key()
+                .as_slice(),
+            DirectQueryType::StatefulDirectQuery,
+            tx,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        if !is_contender {
+            return Ok(bump_nonce_with_error(
+                self,
+                AuctionBidderNotContenderError::new(vote_poll_id, identity_id).into(),
+            ));
+        }
+
+        let existing_bid =
+            drive.fetch_auction_bid(&resolved_vote_poll, identity_id, tx, platform_version)?;
+
+        let stored_bid = match self.bid() {
+            AuctionBid::Commit {
+                sealed_bid,
+                deposit,
+            } => {
+                if existing_bid.is_some() {
+                    return Ok(bump_nonce_with_error(
+                        self,
+                        AuctionBidAlreadyCommittedError::new(vote_poll_id, identity_id).into(),
+                    ));
+                }
+
+                ContestedDocumentAuctionBidStoredInfo::new(
+                    *sealed_bid,
+                    *deposit,
+                    *block_info,
+                    platform_version,
+                )?
+            }
+            AuctionBid::Reveal { amount, salt } => {
+                let Some(mut stored_bid) = existing_bid else {
+                    return Ok(bump_nonce_with_error(
+                        self,
+                        InvalidAuctionBidRevealError::new(
+                            vote_poll_id,
+                            identity_id,
+                            "no bid was committed".to_string(),
+                        )
+                        .into(),
+                    ));
+                };
+
+                let invalid_reveal_message = if stored_bid.revealed_amount().is_some() {
+                    Some("the bid was already revealed")
+                } else if !stored_bid.matches_reveal(vote_poll_id, identity_id, *amount, *salt) {
+                    Some("the amount and the salt do not match the sealed bid")
+                } else if *amount > stored_bid.deposit() {
+                    Some("the amount is higher than the deposit")
+                } else {
+                    None
+                };
+
+                if let Some(message) = invalid_reveal_message {
+                    return Ok(bump_nonce_with_error(
+                        self,
+                        InvalidAuctionBidRevealError::new(
+                            vote_poll_id,
+                            identity_id,
+                            message.to_string(),
+                        )
+                        .into(),
+                    ));
+                }
+
+                stored_bid.reveal(*amount)?;
+                stored_bid
+            }
+        };
+
+        if !drive_operations.is_empty() {
+            let fee = Drive::calculate_fee(
+                None,
+                Some(drive_operations),
+                &block_info.epoch,
+                drive.config.epochs_per_era,
+                platform_version,
+                None,
+            )?;
+            execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee));
+        }
+
+        Ok(ConsensusValidationResult::new_with_data(
+            AuctionBidTransitionAction::from_transition_with_stored_bid(
+                self,
+                resolved_vote_poll,
+                stored_bid,
+            )
+            .into(),
+        ))
+    }
+}
+
+fn bump_nonce_with_error(
+    transition: &AuctionBidTransition,
+    error: ConsensusError,
+) -> ConsensusValidationResult<StateTransitionAction> {
+    let bump_action = StateTransitionAction::BumpIdentityNonceAction(
+        BumpIdentityNonceAction::from_borrowed_auction_bid_transition(transition),
+    );
+
+    ConsensusValidationResult::new_with_data_and_errors(bump_action, vec![error])
+}
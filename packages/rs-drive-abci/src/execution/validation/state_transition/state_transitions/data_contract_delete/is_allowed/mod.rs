@@ -0,0 +1,41 @@
+use crate::error::Error;
+use crate::execution::validation::state_transition::processor::v0::StateTransitionIsAllowedValidationV0;
+use crate::platform_types::platform::PlatformRef;
+use dpp::consensus::basic::UnsupportedFeatureError;
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
+use dpp::validation::ConsensusValidationResult;
+use dpp::version::PlatformVersion;
+
+impl StateTransitionIsAllowedValidationV0 for DataContractDeleteTransition {
+    fn has_is_allowed_validation(
+        &self,
+        _platform_version: &PlatformVersion,
+    ) -> Result<bool, Error> {
+        Ok(true)
+    }
+
+    /// Data contracts can only be deleted from the protocol version that introduced the transition
+    fn validate_is_allowed<C>(
+        &self,
+        _platform: &PlatformRef<C>,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<()>, Error> {
+        if platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .contract_delete_state_transition
+            .is_some()
+        {
+            return Ok(ConsensusValidationResult::new());
+        }
+
+        Ok(ConsensusValidationResult::new_with_error(
+            UnsupportedFeatureError::new(
+                "data contract delete".to_string(),
+                platform_version.protocol_version,
+            )
+            .into(),
+        ))
+    }
+}
@@ -0,0 +1,153 @@
+mod identity_contract_nonce;
+mod is_allowed;
+mod state;
+
+use dpp::block::block_info::BlockInfo;
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
+use dpp::validation::ConsensusValidationResult;
+
+use drive::grovedb::TransactionArg;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+
+use drive::state_transition_action::StateTransitionAction;
+
+use crate::execution::validation::state_transition::data_contract_delete::state::v0::DataContractDeleteStateTransitionStateValidationV0;
+use crate::execution::validation::state_transition::transformer::StateTransitionActionTransformerV0;
+use crate::execution::validation::state_transition::ValidationMode;
+use crate::platform_types::platform::PlatformRef;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::rpc::core::CoreRPCLike;
+
+impl StateTransitionActionTransformerV0 for DataContractDeleteTransition {
+    fn transform_into_action<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        _block_info: &BlockInfo,
+        _validation_mode: ValidationMode,
+        _execution_context: &mut StateTransitionExecutionContext,
+        _tx: TransactionArg,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version = platform.state.current_platform_version()?;
+
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .contract_delete_state_transition
+            .as_ref()
+            .map(|versions| versions.transform_into_action)
+        {
+            Some(0) => self.transform_into_action_v0(),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "data contract delete transition: transform_into_action".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "data contract delete transition: transform_into_action".to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execution::validation::state_transition::tests::{
+        process_state_transition_in_current_version, setup_identity,
+    };
+    use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
+    use dpp::block::block_info::BlockInfo;
+    use dpp::consensus::basic::BasicError;
+    use dpp::consensus::ConsensusError;
+    use dpp::dash_to_credits;
+    use dpp::data_contract::accessors::v0::{DataContractV0Getters, DataContractV0Setters};
+    use dpp::data_contract::config::v0::DataContractConfigSettersV0;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+    use dpp::state_transition::data_contract_delete_transition::methods::DataContractDeleteTransitionMethodsV0;
+    use dpp::state_transition::data_contract_delete_transition::{
+        DataContractDeleteMode, DataContractDeleteTransition,
+    };
+    use dpp::state_transition::StateTransition;
+    use dpp::tests::json_document::json_document_to_contract;
+    use dpp::version::ProtocolVersion;
+    use drive::util::storage_flags::StorageFlags;
+    use platform_version::version::PlatformVersion;
+
+    /// Start a platform on `protocol_version` with a deletable contract and sign its archival
+    fn setup_contract_archival(
+        protocol_version: ProtocolVersion,
+    ) -> (TempPlatform<MockCoreRPCLike>, StateTransition) {
+        let mut platform = TestPlatformBuilder::new()
+            .with_initial_protocol_version(protocol_version)
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let (identity, signer, key) = setup_identity(&mut platform, 958, dash_to_credits!(0.1));
+
+        let platform_version = PlatformVersion::latest();
+
+        let mut contract = json_document_to_contract(
+            "tests/supporting_files/contract/dashpay/dashpay-contract-all-mutable.json",
+            true,
+            platform_version,
+        )
+        .expect("expected to get data contract");
+
+        contract.set_owner_id(identity.id());
+        contract.config_mut().set_can_be_deleted(true);
+
+        platform
+            .drive
+            .apply_contract(
+                &contract,
+                BlockInfo::default(),
+                true,
+                StorageFlags::optional_default_as_cow(),
+                None,
+                platform_version,
+            )
+            .expect("expected to apply contract successfully");
+
+        let state_transition = DataContractDeleteTransition::new_from_data_contract(
+            &contract,
+            DataContractDeleteMode::Archive,
+            &identity.into_partial_identity_info(),
+            key.id(),
+            1,
+            0,
+            &signer,
+            platform_version,
+            None,
+        )
+        .expect("expected a data contract delete transition");
+
+        (platform, state_transition)
+    }
+
+    #[test]
+    fn test_data_contract_archival_is_only_processed_from_protocol_version_2() {
+        let (platform, state_transition) = setup_contract_archival(1);
+
+        assert!(matches!(
+            process_state_transition_in_current_version(&platform, &state_transition),
+            StateTransitionExecutionResult::UnpaidConsensusError(ConsensusError::BasicError(
+                BasicError::UnsupportedFeatureError(_)
+            ))
+        ));
+
+        let (platform, state_transition) = setup_contract_archival(2);
+
+        assert!(matches!(
+            process_state_transition_in_current_version(&platform, &state_transition),
+            StateTransitionExecutionResult::SuccessfulExecution(..)
+        ));
+    }
+}
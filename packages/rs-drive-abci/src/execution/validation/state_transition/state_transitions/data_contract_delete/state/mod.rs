@@ -0,0 +1,62 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+use crate::execution::validation::state_transition::data_contract_delete::state::v0::DataContractDeleteStateTransitionStateValidationV0;
+use crate::execution::validation::state_transition::processor::v0::StateTransitionStateValidationV0;
+use crate::execution::validation::state_transition::ValidationMode;
+use crate::platform_types::platform::PlatformRef;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
+use dpp::validation::ConsensusValidationResult;
+use drive::grovedb::TransactionArg;
+use drive::state_transition_action::StateTransitionAction;
+
+pub(crate) mod v0;
+
+impl StateTransitionStateValidationV0 for DataContractDeleteTransition {
+    fn validate_state<C: CoreRPCLike>(
+        &self,
+        action: Option<StateTransitionAction>,
+        platform: &PlatformRef<C>,
+        validation_mode: ValidationMode,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version = platform.state.current_platform_version()?;
+
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .contract_delete_state_transition
+            .as_ref()
+            .map(|versions| versions.state)
+        {
+            Some(0) => {
+                if action.is_some() {
+                    return Err(Error::Execution(ExecutionError::CorruptedCodeExecution("data contract delete is calling validate state, and the action is already known. It should not be known at this point")));
+                }
+                self.validate_state_v0(
+                    platform,
+                    validation_mode,
+                    block_info,
+                    execution_context,
+                    tx,
+                    platform_version,
+                )
+            }
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "data contract delete transition: validate_state".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "data contract delete transition: validate_state".to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
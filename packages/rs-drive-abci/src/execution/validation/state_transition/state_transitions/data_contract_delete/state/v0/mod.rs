@@ -0,0 +1,216 @@
+use crate::error::Error;
+use crate::platform_types::platform::PlatformRef;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+
+use dpp::consensus::basic::document::DataContractNotPresentError;
+use dpp::consensus::basic::BasicError;
+use dpp::consensus::state::data_contract::data_contract_is_archived_error::DataContractIsArchivedError;
+use dpp::consensus::state::data_contract::data_contract_is_not_deletable_error::DataContractIsNotDeletableError;
+use dpp::consensus::state::data_contract::data_contract_too_many_documents_to_delete_error::DataContractTooManyDocumentsToDeleteError;
+use dpp::consensus::state::data_contract::data_contract_update_permission_error::DataContractUpdatePermissionError;
+use dpp::consensus::state::state_error::StateError;
+use dpp::consensus::ConsensusError;
+
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::config::v0::DataContractConfigGettersV0;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+
+use dpp::prelude::ConsensusValidationResult;
+
+use dpp::state_transition::data_contract_delete_transition::accessors::DataContractDeleteTransitionAccessorsV0;
+use dpp::state_transition::data_contract_delete_transition::{
+    DataContractDeleteMode, DataContractDeleteTransition,
+};
+use dpp::state_transition::StateTransitionLike;
+use dpp::version::PlatformVersion;
+
+use crate::error::execution::ExecutionError;
+use crate::execution::validation::state_transition::ValidationMode;
+use drive::grovedb::TransactionArg;
+use drive::state_transition_action::contract::data_contract_delete::DataContractDeleteTransitionAction;
+use drive::state_transition_action::system::bump_identity_data_contract_nonce_action::BumpIdentityDataContractNonceAction;
+
+use crate::execution::types::execution_operation::ValidationOperation;
+use crate::execution::types::state_transition_execution_context::{
+    StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0,
+};
+use drive::state_transition_action::StateTransitionAction;
+
+pub(in crate::execution::validation::state_transition::state_transitions::data_contract_delete) trait DataContractDeleteStateTransitionStateValidationV0 {
+    fn validate_state_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        validation_mode: ValidationMode,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+
+    fn transform_into_action_v0(
+        &self,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+}
+
+impl DataContractDeleteStateTransitionStateValidationV0 for DataContractDeleteTransition {
+    fn validate_state_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        validation_mode: ValidationMode,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let drive = platform.drive;
+        let data_contract_id = self.data_contract_id();
+
+        // Data contract should exist
+        let add_to_cache_if_pulled = validation_mode.can_alter_cache();
+
+        let data_contract_fetch_info = drive.get_contract_with_fetch_info_and_fee(
+            data_contract_id.to_buffer(),
+            Some(&block_info.epoch),
+            add_to_cache_if_pulled,
+            tx,
+            platform_version,
+        )?;
+
+        let fee = data_contract_fetch_info.0.ok_or(Error::Execution(
+            ExecutionError::CorruptedCodeExecution(
+                "fee must exist in validate state for data contract delete transition",
+            ),
+        ))?;
+
+        // We add the cost for fetching the contract even if the contract doesn't exist or was in cache
+        execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee));
+
+        let Some(contract_fetch_info) = data_contract_fetch_info.1 else {
+            return Ok(bump_nonce_with_error(
+                self,
+                BasicError::DataContractNotPresentError(DataContractNotPresentError::new(
+                    data_contract_id,
+                ))
+                .into(),
+            ));
+        };
+
+        let data_contract = &contract_fetch_info.contract;
+
+        // Only the owner can archive or delete the contract
+        if data_contract.owner_id() != self.owner_id() {
+            return Ok(bump_nonce_with_error(
+                self,
+                StateError::DataContractUpdatePermissionError(
+                    DataContractUpdatePermissionError::new(data_contract_id, self.owner_id()),
+                )
+                .into(),
+            ));
+        }
+
+        if !data_contract.config().can_be_deleted() {
+            return Ok(bump_nonce_with_error(
+                self,
+                StateError::DataContractIsNotDeletableError(DataContractIsNotDeletableError::new(
+                    data_contract_id,
+                ))
+                .into(),
+            ));
+        }
+
+        match self.mode() {
+            DataContractDeleteMode::Archive => {
+                let (archived_at, fee) = drive.fetch_contract_archived_at_with_fees(
+                    data_contract_id.to_buffer(),
+                    block_info,
+                    true,
+                    tx,
+                    platform_version,
+                )?;
+
+                execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee));
+
+                if archived_at.is_some() {
+                    return Ok(bump_nonce_with_error(
+                        self,
+                        StateError::DataContractIsArchivedError(DataContractIsArchivedError::new(
+                            data_contract_id,
+                        ))
+                        .into(),
+                    ));
+                }
+            }
+            DataContractDeleteMode::Delete => {
+                // Contested documents are locked in vote polls that would be left dangling,
+                // and open offers hold escrowed credits of their bidders
+                if !data_contract
+                    .document_types_with_contested_indexes()
+                    .is_empty()
+                    || data_contract
+                        .document_types()
+                        .values()
+                        .any(|document_type| document_type.trade_mode().accepts_offers())
+                {
+                    return Ok(bump_nonce_with_error(
+                        self,
+                        StateError::DataContractIsNotDeletableError(
+                            DataContractIsNotDeletableError::new(data_contract_id),
+                        )
+                        .into(),
+                    ));
+                }
+
+                let max_documents = platform_version
+                    .system_limits
+                    .max_documents_deleted_with_contract;
+
+                let (document_count, fee) = drive.count_contract_documents_up_to_with_fees(
+                    data_contract,
+                    max_documents,
+                    block_info,
+                    tx,
+                    platform_version,
+                )?;
+
+                execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee));
+
+                if document_count > max_documents as u32 {
+                    return Ok(bump_nonce_with_error(
+                        self,
+                        StateError::DataContractTooManyDocumentsToDeleteError(
+                            DataContractTooManyDocumentsToDeleteError::new(
+                                data_contract_id,
+                                max_documents,
+                            ),
+                        )
+                        .into(),
+                    ));
+                }
+            }
+        }
+
+        self.transform_into_action_v0()
+    }
+
+    fn transform_into_action_v0(
+        &self,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let action: StateTransitionAction = DataContractDeleteTransitionAction::from(self).into();
+        Ok(action.into())
+    }
+}
+
+/// Keeps the transition to bump the identity contract nonce, failing with `error`
+fn bump_nonce_with_error(
+    transition: &DataContractDeleteTransition,
+    error: ConsensusError,
+) -> ConsensusValidationResult<StateTransitionAction> {
+    let bump_action = StateTransitionAction::BumpIdentityDataContractNonceAction(
+        BumpIdentityDataContractNonceAction::from_borrowed_data_contract_delete_transition(
+            transition,
+        ),
+    );
+
+    ConsensusValidationResult::new_with_data_and_errors(bump_action, vec![error])
+}
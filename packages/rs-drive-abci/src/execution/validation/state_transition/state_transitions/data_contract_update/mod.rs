@@ -666,4 +666,347 @@ mod tests {
                 && error.additional_message() == "document type can not change creation restriction mode: changing from Owner Only to No Restrictions"
         ));
     }
+
+    #[test]
+    fn test_data_contract_update_adding_index_backfills_existing_documents() {
+        use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+        use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
+        use dpp::data_contract::schema::DataContractSchemaMethodsV0;
+        use dpp::platform_value::platform_value;
+        use drive::error::query::QuerySyntaxError;
+        use drive::query::DriveDocumentQuery;
+        use drive::util::object_size_info::DocumentInfo::DocumentRefInfo;
+        use drive::util::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let (identity, signer, key) = setup_identity(&mut platform, 958, dash_to_credits!(0.1));
+
+        let card_game_path =
+            "tests/supporting_files/contract/crypto-card-game/crypto-card-game-direct-purchase.json";
+
+        let platform_state = platform.state.load();
+        let platform_version = platform_state
+            .current_platform_version()
+            .expect("expected to get current platform version");
+
+        let mut contract = json_document_to_contract(card_game_path, true, platform_version)
+            .expect("expected to get data contract");
+
+        contract.set_owner_id(identity.id());
+
+        platform
+            .drive
+            .apply_contract(
+                &contract,
+                BlockInfo::default(),
+                true,
+                StorageFlags::optional_default_as_cow(),
+                None,
+                platform_version,
+            )
+            .expect("expected to apply contract successfully");
+
+        let card_type = contract
+            .document_type_for_name("card")
+            .expect("expected card document type");
+
+        // let's add a few cards before the index exists
+        for i in 0..5u8 {
+            let document = card_type
+                .create_document_from_data(
+                    platform_value!({
+                        "name": format!("card {}", i),
+                        "attack": i as u64,
+                        "defense": i as u64,
+                    }),
+                    identity.id(),
+                    1,
+                    1,
+                    [i; 32],
+                    platform_version,
+                )
+                .expect("expected to create document");
+
+            platform
+                .drive
+                .add_document_for_contract(
+                    DocumentAndContractInfo {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentRefInfo((&document, None)),
+                            owner_id: None,
+                        },
+                        contract: &contract,
+                        document_type: card_type,
+                    },
+                    false,
+                    BlockInfo::default(),
+                    true,
+                    None,
+                    platform_version,
+                    None,
+                )
+                .expect("expected to add document");
+        }
+
+        let mut card_schema = card_type.schema().clone();
+
+        card_schema
+            .get_mut("indices")
+            .expect("expected to get indices")
+            .expect("expected indices to be set")
+            .to_array_mut()
+            .expect("expected indices to be an array")
+            .push(platform_value!({
+                "name": "name",
+                "properties": [{"name": "asc"}],
+            }));
+
+        let mut updated_contract = contract.clone();
+
+        updated_contract
+            .set_document_schema("card", card_schema, true, &mut vec![], platform_version)
+            .expect("expected to set document schema");
+
+        updated_contract.set_version(2);
+
+        let data_contract_update_transition = DataContractUpdateTransition::new_from_data_contract(
+            updated_contract.clone(),
+            &identity.into_partial_identity_info(),
+            key.id(),
+            2,
+            0,
+            &signer,
+            platform_version,
+            None,
+        )
+        .expect("expect to create data contract update transition");
+
+        let data_contract_update_serialized_transition = data_contract_update_transition
+            .serialize_to_bytes()
+            .expect("expected data contract update serialized state transition");
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let processing_result = platform
+            .platform
+            .process_raw_state_transitions(
+                &vec![data_contract_update_serialized_transition],
+                &platform_state,
+                &BlockInfo::default(),
+                &transaction,
+                platform_version,
+                false,
+                None,
+            )
+            .expect("expected to process state transition");
+
+        assert_eq!(processing_result.valid_count(), 1);
+
+        platform
+            .drive
+            .grove
+            .commit_transaction(transaction)
+            .unwrap()
+            .expect("expected to commit transaction");
+
+        let pending_build = platform
+            .drive
+            .fetch_pending_index_build(
+                contract.id_ref().as_bytes(),
+                "card",
+                None,
+                &mut vec![],
+                platform_version,
+            )
+            .expect("expected to fetch pending index build")
+            .expect("expected the index build to be pending");
+
+        assert_eq!(pending_build.index_names, vec!["name".to_string()]);
+        assert_eq!(pending_build.last_backfilled_document_id, None);
+
+        let query = DriveDocumentQuery::from_sql_expr(
+            "select * from card where name = 'card 1'",
+            &updated_contract,
+            None,
+        )
+        .expect("expected to create query");
+
+        let result =
+            query.execute_raw_results_no_proof(&platform.drive, None, None, platform_version);
+
+        assert!(matches!(
+            result,
+            Err(drive::error::Error::Query(
+                QuerySyntaxError::IndexNotYetQueryable(_)
+            ))
+        ));
+
+        // Backfilling happens over several blocks when there are more documents than the limit
+        let backfilled = platform
+            .drive
+            .backfill_pending_index_builds(3, None, platform_version)
+            .expect("expected to backfill index");
+
+        assert_eq!(backfilled, 3);
+
+        assert!(platform
+            .drive
+            .fetch_pending_index_build(
+                contract.id_ref().as_bytes(),
+                "card",
+                None,
+                &mut vec![],
+                platform_version,
+            )
+            .expect("expected to fetch pending index build")
+            .is_some());
+
+        let backfilled = platform
+            .drive
+            .backfill_pending_index_builds(3, None, platform_version)
+            .expect("expected to backfill index");
+
+        assert_eq!(backfilled, 2);
+
+        assert!(platform
+            .drive
+            .fetch_pending_index_build(
+                contract.id_ref().as_bytes(),
+                "card",
+                None,
+                &mut vec![],
+                platform_version,
+            )
+            .expect("expected to fetch pending index build")
+            .is_none());
+
+        let (results, _, _) = query
+            .execute_raw_results_no_proof(&platform.drive, None, None, platform_version)
+            .expect("expected to query the built index");
+
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_data_contract_update_adding_index_is_not_allowed_in_first_protocol_version() {
+        use dpp::consensus::basic::BasicError;
+        use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+        use dpp::data_contract::schema::DataContractSchemaMethodsV0;
+        use dpp::platform_value::platform_value;
+
+        let mut platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let (identity, signer, key) = setup_identity(&mut platform, 958, dash_to_credits!(0.1));
+
+        let card_game_path =
+            "tests/supporting_files/contract/crypto-card-game/crypto-card-game-direct-purchase.json";
+
+        let platform_state = platform.state.load();
+        let platform_version = platform_state
+            .current_platform_version()
+            .expect("expected to get current platform version");
+
+        let mut contract = json_document_to_contract(card_game_path, true, platform_version)
+            .expect("expected to get data contract");
+
+        contract.set_owner_id(identity.id());
+
+        platform
+            .drive
+            .apply_contract(
+                &contract,
+                BlockInfo::default(),
+                true,
+                StorageFlags::optional_default_as_cow(),
+                None,
+                platform_version,
+            )
+            .expect("expected to apply contract successfully");
+
+        let card_type = contract
+            .document_type_for_name("card")
+            .expect("expected card document type");
+
+        let mut card_schema = card_type.schema().clone();
+
+        card_schema
+            .get_mut("indices")
+            .expect("expected to get indices")
+            .expect("expected indices to be set")
+            .to_array_mut()
+            .expect("expected indices to be an array")
+            .push(platform_value!({
+                "name": "name",
+                "properties": [{"name": "asc"}],
+            }));
+
+        let mut updated_contract = contract.clone();
+
+        updated_contract
+            .set_document_schema("card", card_schema, true, &mut vec![], platform_version)
+            .expect("expected to set document schema");
+
+        updated_contract.set_version(2);
+
+        let data_contract_update_transition = DataContractUpdateTransition::new_from_data_contract(
+            updated_contract.clone(),
+            &identity.into_partial_identity_info(),
+            key.id(),
+            2,
+            0,
+            &signer,
+            platform_version,
+            None,
+        )
+        .expect("expect to create data contract update transition");
+
+        let data_contract_update_serialized_transition = data_contract_update_transition
+            .serialize_to_bytes()
+            .expect("expected data contract update serialized state transition");
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let processing_result = platform
+            .platform
+            .process_raw_state_transitions(
+                &vec![data_contract_update_serialized_transition],
+                &platform_state,
+                &BlockInfo::default(),
+                &transaction,
+                platform_version,
+                false,
+                None,
+            )
+            .expect("expected to process state transition");
+
+        assert_eq!(processing_result.invalid_paid_count(), 1);
+
+        assert!(matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::PaidConsensusError(
+                ConsensusError::BasicError(
+                    BasicError::DataContractInvalidIndexDefinitionUpdateError(_)
+                ),
+                _
+            )]
+        ));
+
+        assert!(platform
+            .drive
+            .fetch_pending_index_build(
+                contract.id_ref().as_bytes(),
+                "card",
+                Some(&transaction),
+                &mut vec![],
+                platform_version,
+            )
+            .expect("expected to fetch pending index build")
+            .is_none());
+    }
 }
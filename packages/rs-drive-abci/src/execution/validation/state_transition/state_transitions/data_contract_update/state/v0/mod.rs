@@ -1,12 +1,17 @@
 use crate::error::Error;
 use crate::platform_types::platform::PlatformRef;
 use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
 use dpp::block::epoch::Epoch;
 
 use dpp::consensus::basic::document::DataContractNotPresentError;
 use dpp::consensus::basic::BasicError;
+use dpp::consensus::state::data_contract::data_contract_is_archived_error::DataContractIsArchivedError;
+use dpp::consensus::state::data_contract::document_type_update_error::DocumentTypeUpdateError;
+use dpp::consensus::state::state_error::StateError;
 
 use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 
 use dpp::data_contract::validate_update::DataContractUpdateValidationMethodsV0;
 
@@ -18,6 +23,7 @@ use dpp::version::PlatformVersion;
 
 use crate::error::execution::ExecutionError;
 use crate::execution::validation::state_transition::ValidationMode;
+use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
 use drive::state_transition_action::contract::data_contract_update::DataContractUpdateTransitionAction;
 use drive::state_transition_action::system::bump_identity_data_contract_nonce_action::BumpIdentityDataContractNonceAction;
@@ -26,6 +32,7 @@ use crate::execution::types::execution_operation::ValidationOperation;
 use crate::execution::types::state_transition_execution_context::{
     StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0,
 };
+use crate::execution::validation::state_transition::common::fetch_data_contract_archived_at::fetch_data_contract_archived_at;
 use drive::state_transition_action::StateTransitionAction;
 
 pub(in crate::execution::validation::state_transition::state_transitions::data_contract_update) trait DataContractUpdateStateTransitionStateValidationV0 {
@@ -126,6 +133,34 @@ impl DataContractUpdateStateTransitionStateValidationV0 for DataContractUpdateTr
             ));
         };
 
+        // Archived contracts can't be updated
+        let archived_at = fetch_data_contract_archived_at(
+            drive,
+            new_data_contract.id(),
+            &BlockInfo::default_with_epoch(*epoch),
+            execution_context,
+            tx,
+            platform_version,
+        )?;
+
+        if archived_at.is_some() {
+            let bump_action = StateTransitionAction::BumpIdentityDataContractNonceAction(
+                BumpIdentityDataContractNonceAction::from_borrowed_data_contract_update_transition(
+                    self,
+                ),
+            );
+
+            return Ok(ConsensusValidationResult::new_with_data_and_errors(
+                bump_action,
+                vec![
+                    StateError::DataContractIsArchivedError(DataContractIsArchivedError::new(
+                        new_data_contract.id(),
+                    ))
+                    .into(),
+                ],
+            ));
+        }
+
         let old_data_contract = &contract_fetch_info.contract;
 
         let validation_result =
@@ -144,6 +179,63 @@ impl DataContractUpdateStateTransitionStateValidationV0 for DataContractUpdateTr
             ));
         }
 
+        // Indexes can't be added to a document type while previously added ones are still
+        // being built
+        for (document_type_name, new_document_type) in new_data_contract.document_types() {
+            let Some(old_document_type) =
+                old_data_contract.document_type_optional_for_name(document_type_name)
+            else {
+                continue;
+            };
+
+            if new_document_type
+                .indexes()
+                .keys()
+                .all(|index_name| old_document_type.indexes().contains_key(index_name))
+            {
+                continue;
+            }
+
+            let mut drive_operations = vec![];
+
+            let pending_index_build = drive.fetch_pending_index_build(
+                new_data_contract.id_ref().as_bytes(),
+                document_type_name,
+                tx,
+                &mut drive_operations,
+                platform_version,
+            )?;
+
+            let fee = Drive::calculate_fee(
+                None,
+                Some(drive_operations),
+                epoch,
+                drive.config.epochs_per_era,
+                platform_version,
+                None,
+            )?;
+
+            execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee));
+
+            if pending_index_build.is_some() {
+                let bump_action = StateTransitionAction::BumpIdentityDataContractNonceAction(
+                    BumpIdentityDataContractNonceAction::from_borrowed_data_contract_update_transition(
+                        self,
+                    ),
+                );
+
+                return Ok(ConsensusValidationResult::new_with_data_and_errors(
+                    bump_action,
+                    vec![StateError::DocumentTypeUpdateError(DocumentTypeUpdateError::new(
+                        new_data_contract.id(),
+                        document_type_name,
+                        "document type can not add indexes while previously added indexes are still being built",
+                    ))
+                    .into()],
+                ));
+            }
+        }
+
         Ok(action)
     }
 
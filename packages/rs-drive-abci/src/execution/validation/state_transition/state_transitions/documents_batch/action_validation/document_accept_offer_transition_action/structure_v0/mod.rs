@@ -0,0 +1,61 @@
+use dpp::consensus::basic::document::{InvalidDocumentTransitionActionError, InvalidDocumentTypeError};
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::validation::SimpleConsensusValidationResult;
+use drive::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::document_accept_offer_transition_action::{DocumentAcceptOfferTransitionAction, DocumentAcceptOfferTransitionActionAccessorsV0};
+use dpp::version::PlatformVersion;
+use crate::error::Error;
+
+pub(super) trait DocumentAcceptOfferTransitionActionStructureValidationV0 {
+    fn validate_structure_v0(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+impl DocumentAcceptOfferTransitionActionStructureValidationV0
+    for DocumentAcceptOfferTransitionAction
+{
+    fn validate_structure_v0(
+        &self,
+        _platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let contract_fetch_info = self.base().data_contract_fetch_info();
+        let data_contract = &contract_fetch_info.contract;
+        let document_type_name = self.base().document_type_name();
+
+        // Make sure that the document type is defined in the contract
+        let Some(document_type) = data_contract.document_type_optional_for_name(document_type_name)
+        else {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                InvalidDocumentTypeError::new(document_type_name.clone(), data_contract.id())
+                    .into(),
+            ));
+        };
+
+        // We can not accept an offer we made ourselves
+        if self.original_owner_id() == self.offer().bidder_id {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                InvalidDocumentTransitionActionError::new(format!(
+                    "on document type: {} identity trying to accept an offer it made on its own document",
+                    document_type_name
+                ))
+                .into(),
+            ));
+        }
+
+        let trade_mode = document_type.trade_mode();
+
+        if !trade_mode.accepts_offers() {
+            Ok(SimpleConsensusValidationResult::new_with_error(
+                InvalidDocumentTransitionActionError::new(format!(
+                    "{} trade mode is {} which does not accept offers",
+                    document_type_name, trade_mode
+                ))
+                .into(),
+            ))
+        } else {
+            Ok(SimpleConsensusValidationResult::default())
+        }
+    }
+}
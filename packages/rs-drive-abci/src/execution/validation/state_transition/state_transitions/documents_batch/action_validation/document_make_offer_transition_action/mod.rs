@@ -0,0 +1,95 @@
+use dpp::block::block_info::BlockInfo;
+use dpp::identifier::Identifier;
+
+use dpp::validation::SimpleConsensusValidationResult;
+use drive::state_transition_action::document::documents_batch::document_transition::document_make_offer_transition_action::DocumentMakeOfferTransitionAction;
+use dpp::version::PlatformVersion;
+use drive::grovedb::TransactionArg;
+use crate::error::Error;
+use crate::error::execution::ExecutionError;
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+use crate::execution::validation::state_transition::documents_batch::action_validation::document_make_offer_transition_action::state_v0::DocumentMakeOfferTransitionActionStateValidationV0;
+use crate::execution::validation::state_transition::documents_batch::action_validation::document_make_offer_transition_action::structure_v0::DocumentMakeOfferTransitionActionStructureValidationV0;
+use crate::platform_types::platform::PlatformStateRef;
+
+mod state_v0;
+mod structure_v0;
+
+pub trait DocumentMakeOfferTransitionActionValidation {
+    fn validate_structure(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+
+    fn validate_state(
+        &self,
+        platform: &PlatformStateRef,
+        owner_id: Identifier,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+
+impl DocumentMakeOfferTransitionActionValidation for DocumentMakeOfferTransitionAction {
+    fn validate_structure(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .documents_batch_state_transition
+            .document_make_offer_transition_structure_validation
+        {
+            Some(0) => self.validate_structure_v0(platform_version),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "DocumentMakeOfferTransitionAction::validate_structure".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "DocumentMakeOfferTransitionAction::validate_structure".to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+
+    fn validate_state(
+        &self,
+        platform: &PlatformStateRef,
+        owner_id: Identifier,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .documents_batch_state_transition
+            .document_make_offer_transition_state_validation
+        {
+            Some(0) => self.validate_state_v0(
+                platform,
+                owner_id,
+                block_info,
+                execution_context,
+                transaction,
+                platform_version,
+            ),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "DocumentMakeOfferTransitionAction::validate_state".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "DocumentMakeOfferTransitionAction::validate_state".to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use dpp::block::block_info::BlockInfo;
+use dpp::consensus::state::document::document_offer_already_exists_error::DocumentOfferAlreadyExistsError;
+use dpp::consensus::state::state_error::StateError;
+use dpp::identifier::Identifier;
+use dpp::validation::SimpleConsensusValidationResult;
+use drive::state_transition_action::document::documents_batch::document_transition::document_make_offer_transition_action::{DocumentMakeOfferTransitionAction, DocumentMakeOfferTransitionActionAccessorsV0};
+use dpp::version::PlatformVersion;
+use drive::grovedb::TransactionArg;
+use drive::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+use crate::error::Error;
+use crate::execution::types::execution_operation::ValidationOperation;
+use crate::execution::types::state_transition_execution_context::{StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0};
+use crate::platform_types::platform::PlatformStateRef;
+
+pub(super) trait DocumentMakeOfferTransitionActionStateValidationV0 {
+    fn validate_state_v0(
+        &self,
+        platform: &PlatformStateRef,
+        owner_id: Identifier,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+impl DocumentMakeOfferTransitionActionStateValidationV0 for DocumentMakeOfferTransitionAction {
+    fn validate_state_v0(
+        &self,
+        platform: &PlatformStateRef,
+        owner_id: Identifier,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        // There is no need to verify that the document exists, since this is done when
+        // transforming into an action
+
+        // A bidder can only have one open offer on a document, it must be withdrawn before
+        // making a new one
+        let (existing_offer, fee_result) = platform.drive.fetch_document_offer_with_fees(
+            self.base().data_contract_id(),
+            self.base().document_type_name(),
+            self.base().id(),
+            owner_id,
+            block_info,
+            transaction,
+            platform_version,
+        )?;
+
+        execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee_result));
+
+        if existing_offer.is_some() {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                StateError::DocumentOfferAlreadyExistsError(DocumentOfferAlreadyExistsError::new(
+                    self.base().id(),
+                    owner_id,
+                ))
+                .into(),
+            ));
+        }
+
+        Ok(SimpleConsensusValidationResult::new())
+    }
+}
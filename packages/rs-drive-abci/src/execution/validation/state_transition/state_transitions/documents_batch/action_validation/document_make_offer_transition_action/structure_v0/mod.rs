@@ -0,0 +1,76 @@
+use dpp::consensus::basic::document::{InvalidDocumentTransitionActionError, InvalidDocumentTypeError};
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::validation::SimpleConsensusValidationResult;
+use drive::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::document_make_offer_transition_action::{DocumentMakeOfferTransitionAction, DocumentMakeOfferTransitionActionAccessorsV0};
+use dpp::version::PlatformVersion;
+use crate::error::Error;
+
+pub(super) trait DocumentMakeOfferTransitionActionStructureValidationV0 {
+    fn validate_structure_v0(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+impl DocumentMakeOfferTransitionActionStructureValidationV0 for DocumentMakeOfferTransitionAction {
+    fn validate_structure_v0(
+        &self,
+        _platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let contract_fetch_info = self.base().data_contract_fetch_info();
+        let data_contract = &contract_fetch_info.contract;
+        let document_type_name = self.base().document_type_name();
+
+        // Make sure that the document type is defined in the contract
+        let Some(document_type) = data_contract.document_type_optional_for_name(document_type_name)
+        else {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                InvalidDocumentTypeError::new(document_type_name.clone(), data_contract.id())
+                    .into(),
+            ));
+        };
+
+        // We can not make an offer on our own document
+        if self.offer().bidder_id == self.document_owner_id() {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                InvalidDocumentTransitionActionError::new(format!(
+                    "on document type: {} identity trying to make an offer on a document it already owns",
+                    document_type_name
+                ))
+                .into(),
+            ));
+        }
+
+        let trade_mode = document_type.trade_mode();
+
+        if !trade_mode.accepts_offers() {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                InvalidDocumentTransitionActionError::new(format!(
+                    "{} trade mode is {} which does not accept offers",
+                    document_type_name, trade_mode
+                ))
+                .into(),
+            ));
+        }
+
+        // Private offers are made to a recipient, public offers to whoever owns the document
+        match (trade_mode.offers_are_private(), self.offer().recipient_id) {
+            (true, None) => Ok(SimpleConsensusValidationResult::new_with_error(
+                InvalidDocumentTransitionActionError::new(format!(
+                    "{} only accepts private offers but the offer has no recipient",
+                    document_type_name
+                ))
+                .into(),
+            )),
+            (false, Some(_)) => Ok(SimpleConsensusValidationResult::new_with_error(
+                InvalidDocumentTransitionActionError::new(format!(
+                    "{} only accepts public offers but the offer has a recipient",
+                    document_type_name
+                ))
+                .into(),
+            )),
+            _ => Ok(SimpleConsensusValidationResult::default()),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use dpp::block::block_info::BlockInfo;
+use dpp::identifier::Identifier;
+use dpp::validation::SimpleConsensusValidationResult;
+use drive::state_transition_action::document::documents_batch::document_transition::document_withdraw_offer_transition_action::DocumentWithdrawOfferTransitionAction;
+use dpp::version::PlatformVersion;
+use drive::grovedb::TransactionArg;
+use crate::error::Error;
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+use crate::platform_types::platform::PlatformStateRef;
+
+pub(super) trait DocumentWithdrawOfferTransitionActionStateValidationV0 {
+    fn validate_state_v0(
+        &self,
+        platform: &PlatformStateRef,
+        owner_id: Identifier,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+impl DocumentWithdrawOfferTransitionActionStateValidationV0
+    for DocumentWithdrawOfferTransitionAction
+{
+    fn validate_state_v0(
+        &self,
+        _platform: &PlatformStateRef,
+        _owner_id: Identifier,
+        _block_info: &BlockInfo,
+        _execution_context: &mut StateTransitionExecutionContext,
+        _transaction: TransactionArg,
+        _platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        // There is no need to verify that the offer exists, since this is done when
+        // transforming into an action
+        Ok(SimpleConsensusValidationResult::new())
+    }
+}
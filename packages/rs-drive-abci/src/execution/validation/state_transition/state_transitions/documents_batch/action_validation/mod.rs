@@ -1,6 +1,9 @@
+pub(crate) mod document_accept_offer_transition_action;
 pub(crate) mod document_create_transition_action;
 pub(crate) mod document_delete_transition_action;
+pub(crate) mod document_make_offer_transition_action;
 pub(crate) mod document_purchase_transition_action;
 pub(crate) mod document_replace_transition_action;
 pub(crate) mod document_transfer_transition_action;
 pub(crate) mod document_update_price_transition_action;
+pub(crate) mod document_withdraw_offer_transition_action;
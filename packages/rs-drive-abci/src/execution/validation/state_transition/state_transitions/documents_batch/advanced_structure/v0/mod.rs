@@ -31,6 +31,9 @@ use crate::execution::types::state_transition_execution_context::{StateTransitio
 use crate::execution::validation::state_transition::documents_batch::action_validation::document_purchase_transition_action::DocumentPurchaseTransitionActionValidation;
 use crate::execution::validation::state_transition::documents_batch::action_validation::document_transfer_transition_action::DocumentTransferTransitionActionValidation;
 use crate::execution::validation::state_transition::documents_batch::action_validation::document_update_price_transition_action::DocumentUpdatePriceTransitionActionValidation;
+use crate::execution::validation::state_transition::documents_batch::action_validation::document_make_offer_transition_action::DocumentMakeOfferTransitionActionValidation;
+use crate::execution::validation::state_transition::documents_batch::action_validation::document_withdraw_offer_transition_action::DocumentWithdrawOfferTransitionActionValidation;
+use crate::execution::validation::state_transition::documents_batch::action_validation::document_accept_offer_transition_action::DocumentAcceptOfferTransitionActionValidation;
 
 pub(in crate::execution::validation::state_transition::state_transitions::documents_batch) trait DocumentsBatchStateTransitionStructureValidationV0
 {
@@ -196,6 +199,45 @@ impl DocumentsBatchStateTransitionStructureValidationV0 for DocumentsBatchTransi
                         ));
                     }
                 }
+                DocumentTransitionAction::MakeOfferAction(make_offer_action) => {
+                    let result = make_offer_action.validate_structure(platform_version)?;
+                    if !result.is_valid() {
+                        let bump_action = StateTransitionAction::BumpIdentityDataContractNonceAction(
+                            BumpIdentityDataContractNonceAction::from_borrowed_document_base_transition_action(transition.base().expect("there is always a base for the make offer action"), self.owner_id(), self.user_fee_increase()),
+                        );
+
+                        return Ok(ConsensusValidationResult::new_with_data_and_errors(
+                            bump_action,
+                            result.errors,
+                        ));
+                    }
+                }
+                DocumentTransitionAction::WithdrawOfferAction(withdraw_offer_action) => {
+                    let result = withdraw_offer_action.validate_structure(platform_version)?;
+                    if !result.is_valid() {
+                        let bump_action = StateTransitionAction::BumpIdentityDataContractNonceAction(
+                            BumpIdentityDataContractNonceAction::from_borrowed_document_base_transition_action(transition.base().expect("there is always a base for the withdraw offer action"), self.owner_id(), self.user_fee_increase()),
+                        );
+
+                        return Ok(ConsensusValidationResult::new_with_data_and_errors(
+                            bump_action,
+                            result.errors,
+                        ));
+                    }
+                }
+                DocumentTransitionAction::AcceptOfferAction(accept_offer_action) => {
+                    let result = accept_offer_action.validate_structure(platform_version)?;
+                    if !result.is_valid() {
+                        let bump_action = StateTransitionAction::BumpIdentityDataContractNonceAction(
+                            BumpIdentityDataContractNonceAction::from_borrowed_document_base_transition_action(transition.base().expect("there is always a base for the accept offer action"), self.owner_id(), self.user_fee_increase()),
+                        );
+
+                        return Ok(ConsensusValidationResult::new_with_data_and_errors(
+                            bump_action,
+                            result.errors,
+                        ));
+                    }
+                }
                 DocumentTransitionAction::BumpIdentityDataContractNonce(_) => {
                     return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
                         "we should not have a bump identity contract nonce at this stage",
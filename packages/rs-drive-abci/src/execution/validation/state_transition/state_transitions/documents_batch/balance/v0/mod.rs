@@ -65,6 +65,20 @@ impl DocumentsBatchTransitionBalanceValidationV0 for DocumentsBatchTransition {
                 Err(e) => return Err(e.into()),
             };
 
+        // Offers escrow the offered amounts until they are accepted or withdrawn
+
+        let offers_amount = match self.all_offers_amount() {
+            Ok(offers_amount) => offers_amount.unwrap_or_default(),
+            Err(ProtocolError::Overflow(e)) => {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    ConsensusError::BasicError(BasicError::OverflowError(OverflowError::new(
+                        e.to_owned(),
+                    ))),
+                ))
+            }
+            Err(e) => return Err(e.into()),
+        };
+
         let base_fees = match platform_version.fee_version.state_transition_min_fees.document_batch_sub_transition.checked_mul(self.transitions().len() as u64) {
             None => return Ok(SimpleConsensusValidationResult::new_with_error(ConsensusError::BasicError(BasicError::OverflowError(OverflowError::new("overflow when multiplying base fee and amount of sub transitions in documents batch transition".to_string()))))),
             Some(base_fees) => base_fees
@@ -72,8 +86,8 @@ impl DocumentsBatchTransitionBalanceValidationV0 for DocumentsBatchTransition {
 
         // This is just the needed balance to pass this validation step, most likely the actual fees are smaller
         let needed_balance = match purchases_amount
-            .checked_add(conflicting_indices_collateral_amount).and_then(|added| added.checked_add(base_fees)) {
-            None => return Ok(SimpleConsensusValidationResult::new_with_error(ConsensusError::BasicError(BasicError::OverflowError(OverflowError::new("overflow when adding all purchases amount with conflicting_indices_collateral_amounts and base fees in documents batch transition".to_string()))))),
+            .checked_add(conflicting_indices_collateral_amount).and_then(|added| added.checked_add(offers_amount)).and_then(|added| added.checked_add(base_fees)) {
+            None => return Ok(SimpleConsensusValidationResult::new_with_error(ConsensusError::BasicError(BasicError::OverflowError(OverflowError::new("overflow when adding all purchases amount with conflicting_indices_collateral_amounts, offers amount and base fees in documents batch transition".to_string()))))),
             Some(needed_balance) => needed_balance
         };
 
@@ -0,0 +1,291 @@
+use std::str::FromStr;
+
+use dpp::consensus::state::data_trigger::data_trigger_condition_error::DataTriggerConditionError;
+use dpp::dashcore::address::NetworkUnchecked;
+use dpp::dashcore::Address;
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+use dpp::system_data_contracts::dpns_contract::v2::document_types::domain::properties::RECORDS;
+use dpp::system_data_contracts::dpns_contract::v2::document_types::domain::records::{
+    DASH_CORE_PAYMENT_ADDRESS, DATA_CONTRACT,
+};
+use dpp::version::PlatformVersion;
+use dpp::ProtocolError;
+use drive::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::document_create_transition_action::DocumentCreateTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::DocumentTransitionAction;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContextMethodsV0;
+use crate::execution::validation::state_transition::documents_batch::data_triggers::triggers::dpns::v0::create_domain_data_trigger_v0;
+use crate::execution::validation::state_transition::documents_batch::data_triggers::{
+    DataTriggerExecutionContext, DataTriggerExecutionResult,
+};
+
+/// Creates a data trigger for handling domain documents.
+///
+/// On top of the checks done by the first version of the trigger, the typed records of the
+/// domain are validated: a data contract record must point to an existing data contract and
+/// a Dash Core payment address record must be a valid address on the network of the node.
+/// Size limits of the records are enforced by the DPNS contract schema.
+#[inline(always)]
+pub(super) fn create_domain_data_trigger_v1(
+    document_transition: &DocumentTransitionAction,
+    context: &DataTriggerExecutionContext<'_>,
+    platform_version: &PlatformVersion,
+) -> Result<DataTriggerExecutionResult, Error> {
+    let mut result = create_domain_data_trigger_v0(document_transition, context, platform_version)?;
+
+    if context.state_transition_execution_context.in_dry_run() {
+        return Ok(result);
+    }
+
+    let base = document_transition.base().ok_or(Error::Execution(
+        ExecutionError::CorruptedCodeExecution("expecting action to have a base"),
+    ))?;
+    let data_contract_id = base.data_contract_id();
+    let transition_id = base.id();
+
+    let DocumentTransitionAction::CreateAction(document_create_transition) = document_transition
+    else {
+        return Err(Error::Execution(ExecutionError::DataTriggerExecutionError(
+            format!("the Document Transition {} isn't 'CREATE", transition_id),
+        )));
+    };
+
+    let records = document_create_transition
+        .data()
+        .get(RECORDS)
+        .ok_or(ExecutionError::DataTriggerExecutionError(format!(
+            "property '{}' doesn't exist",
+            RECORDS
+        )))?
+        .to_btree_ref_string_map()
+        .map_err(ProtocolError::ValueError)?;
+
+    if let Some(contract_id) = records
+        .get_optional_identifier(DATA_CONTRACT)
+        .map_err(ProtocolError::ValueError)?
+    {
+        let contract = context.platform.drive.get_contract_with_fetch_info(
+            contract_id.to_buffer(),
+            false,
+            context.transaction,
+            platform_version,
+        )?;
+
+        if contract.is_none() {
+            let err = DataTriggerConditionError::new(
+                data_contract_id,
+                transition_id,
+                format!(
+                    "Data contract {} referenced by the {} record doesn't exist",
+                    contract_id, DATA_CONTRACT
+                ),
+            );
+
+            result.add_error(err);
+        }
+    }
+
+    if let Some(address) = records
+        .get_optional_str(DASH_CORE_PAYMENT_ADDRESS)
+        .map_err(ProtocolError::ValueError)?
+    {
+        let network = context.platform.config.network;
+
+        let is_valid_address = Address::<NetworkUnchecked>::from_str(address)
+            .map(|address| address.is_valid_for_network(network))
+            .unwrap_or(false);
+
+        if !is_valid_address {
+            let err = DataTriggerConditionError::new(
+                data_contract_id,
+                transition_id,
+                format!(
+                    "{} record {} is not a valid address on {}",
+                    DASH_CORE_PAYMENT_ADDRESS, address, network
+                ),
+            );
+
+            result.add_error(err);
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+    use dpp::consensus::state::data_trigger::DataTriggerError;
+    use crate::platform_types::platform::PlatformStateRef;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::document::DocumentV0Setters;
+    use dpp::platform_value::{Bytes32, Identifier, Value};
+    use dpp::tests::fixtures::{
+        get_document_transitions_fixture, get_dpns_data_contract_fixture,
+        get_dpns_parent_document_fixture, ParentDocumentOptions,
+    };
+    use dpp::tests::utils::generate_random_identifier_struct;
+    use dpp::version::DefaultForPlatformVersion;
+    use drive::state_transition_action::document::documents_batch::document_transition::document_create_transition_action::DocumentCreateTransitionAction;
+    use drive::state_transition_action::document::documents_batch::document_transition::DocumentTransitionActionType;
+    use platform_version::version::mocks::v3_test::TEST_PROTOCOL_VERSION_3;
+    use std::collections::BTreeMap;
+
+    /// Executes the trigger for a domain with the given records, the closure is given the id of
+    /// the DPNS contract that exists in state
+    fn execute_trigger_with_records(
+        records: impl FnOnce(Identifier) -> Vec<(Value, Value)>,
+    ) -> DataTriggerExecutionResult {
+        let platform_version = PlatformVersion::get(TEST_PROTOCOL_VERSION_3)
+            .expect("expected to get the test platform version");
+
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let state = platform.state.load();
+
+        let platform_ref = PlatformStateRef {
+            drive: &platform.drive,
+            state: &state,
+            config: &platform.config,
+        };
+
+        let owner_id = generate_random_identifier_struct();
+
+        let data_contract =
+            get_dpns_data_contract_fixture(Some(owner_id), 0, platform_version.protocol_version)
+                .data_contract_owned();
+
+        platform
+            .drive
+            .apply_contract(
+                &data_contract,
+                BlockInfo::default(),
+                true,
+                None,
+                None,
+                platform_version,
+            )
+            .expect("expected to apply the DPNS contract");
+
+        let mut document = get_dpns_parent_document_fixture(
+            ParentDocumentOptions {
+                owner_id,
+                ..Default::default()
+            },
+            platform_version.protocol_version,
+        );
+        document.set(RECORDS, Value::Map(records(data_contract.id())));
+
+        let document_type = data_contract
+            .document_type_for_name("domain")
+            .expect("expected to get domain document type");
+
+        let mut nonce_counter = BTreeMap::new();
+        let transitions = get_document_transitions_fixture(
+            [(
+                DocumentTransitionActionType::Create,
+                vec![(document, document_type, Bytes32::default())],
+            )],
+            &mut nonce_counter,
+        );
+        let document_create_transition = transitions
+            .first()
+            .expect("transition should be present")
+            .as_transition_create()
+            .expect("expected a document create transition");
+
+        let transition_execution_context =
+            StateTransitionExecutionContext::default_for_platform_version(platform_version)
+                .unwrap();
+
+        let data_trigger_context = DataTriggerExecutionContext {
+            platform: &platform_ref,
+            owner_id: &owner_id,
+            state_transition_execution_context: &transition_execution_context,
+            transaction: None,
+        };
+
+        let contract_fetch_info = platform
+            .drive
+            .get_contract_with_fetch_info(
+                data_contract.id().to_buffer(),
+                false,
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch the DPNS contract")
+            .expect("expected the DPNS contract to exist");
+
+        create_domain_data_trigger_v1(
+            &DocumentCreateTransitionAction::from_document_borrowed_create_transition_with_contract_lookup(&platform.drive, None,
+                document_create_transition, &BlockInfo::default(), |_identifier| {
+                    Ok(contract_fetch_info.clone())
+                }, platform_version).expect("expected to create action").0.into(),
+            &data_trigger_context,
+            platform_version,
+        )
+        .expect("the execution result should be returned")
+    }
+
+    fn condition_error_messages(result: &DataTriggerExecutionResult) -> Vec<String> {
+        result
+            .errors
+            .iter()
+            .filter_map(|error| match error {
+                DataTriggerError::DataTriggerConditionError(e) => Some(e.message().to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_return_invalid_result_if_records_are_invalid() {
+        let missing_contract_id = generate_random_identifier_struct();
+
+        let result = execute_trigger_with_records(|_| {
+            vec![
+                (
+                    Value::Text(DATA_CONTRACT.to_string()),
+                    Value::Identifier(missing_contract_id.to_buffer()),
+                ),
+                (
+                    Value::Text(DASH_CORE_PAYMENT_ADDRESS.to_string()),
+                    Value::Text("XnotAnAddress1111111111111111111".to_string()),
+                ),
+            ]
+        });
+
+        let messages = condition_error_messages(&result);
+
+        assert!(messages.contains(&format!(
+            "Data contract {} referenced by the {} record doesn't exist",
+            missing_contract_id, DATA_CONTRACT
+        )));
+        assert!(messages.iter().any(|message| message.starts_with(&format!(
+            "{} record XnotAnAddress1111111111111111111 is not a valid address",
+            DASH_CORE_PAYMENT_ADDRESS
+        ))));
+    }
+
+    #[test]
+    fn should_accept_a_record_pointing_to_an_existing_data_contract() {
+        let result = execute_trigger_with_records(|dpns_contract_id| {
+            vec![(
+                Value::Text(DATA_CONTRACT.to_string()),
+                Value::Identifier(dpns_contract_id.to_buffer()),
+            )]
+        });
+
+        assert!(!condition_error_messages(&result)
+            .iter()
+            .any(|message| message.contains(DATA_CONTRACT)));
+    }
+}
@@ -2,6 +2,9 @@ use crate::error::execution::ExecutionError;
 use crate::error::Error;
 use crate::execution::validation::state_transition::processor::v0::StateTransitionIsAllowedValidationV0;
 use crate::platform_types::platform::PlatformRef;
+use dpp::consensus::basic::UnsupportedFeatureError;
+use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
+use dpp::state_transition::documents_batch_transition::document_transition::DocumentTransition;
 use dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
 use dpp::validation::ConsensusValidationResult;
 use dpp::version::PlatformVersion;
@@ -26,12 +29,41 @@ impl StateTransitionIsAllowedValidationV0 for DocumentsBatchTransition {
         }
     }
 
-    /// Disable contested document create transitions for the first 3 epochs
+    /// Disable contested document create transitions for the first 3 epochs, and offers before
+    /// the protocol version that introduced them
     fn validate_is_allowed<C>(
         &self,
         platform: &PlatformRef<C>,
         platform_version: &PlatformVersion,
     ) -> Result<ConsensusValidationResult<()>, Error> {
+        // Offers can only be made from the protocol version that introduced them
+        let has_offers = self.transitions().iter().any(|transition| {
+            matches!(
+                transition,
+                DocumentTransition::MakeOffer(_)
+                    | DocumentTransition::WithdrawOffer(_)
+                    | DocumentTransition::AcceptOffer(_)
+            )
+        });
+
+        if has_offers
+            && platform_version
+                .drive_abci
+                .validation_and_processing
+                .state_transitions
+                .documents_batch_state_transition
+                .document_make_offer_transition_structure_validation
+                .is_none()
+        {
+            return Ok(ConsensusValidationResult::new_with_error(
+                UnsupportedFeatureError::new(
+                    "document offers".to_string(),
+                    platform_version.protocol_version,
+                )
+                .into(),
+            ));
+        }
+
         match platform_version
             .drive_abci
             .validation_and_processing
@@ -6977,6 +6977,544 @@ mod tests {
         }
     }
 
+    mod nft_offer_tests {
+        use super::*;
+        use crate::platform_types::state_transitions_processing_result::StateTransitionsProcessingResult;
+        use crate::rpc::core::MockCoreRPCLike;
+        use crate::test::helpers::setup::TempPlatform;
+        use dpp::data_contract::DataContract;
+        use dpp::document::Document;
+        use dpp::identity::Identity;
+        use dpp::identity::IdentityPublicKey;
+        use dpp::state_transition::documents_batch_transition::document_transition::{
+            DocumentAcceptOfferTransition, DocumentMakeOfferTransition,
+            DocumentWithdrawOfferTransition,
+        };
+        use dpp::state_transition::StateTransition;
+        use simple_signer::signer::SimpleSigner;
+
+        fn create_card(
+            platform: &TempPlatform<MockCoreRPCLike>,
+            contract: &DataContract,
+            owner: &Identity,
+            signer: &SimpleSigner,
+            key: &IdentityPublicKey,
+            rng: &mut StdRng,
+            platform_version: &PlatformVersion,
+        ) -> Document {
+            let card_document_type = contract
+                .document_type_for_name("card")
+                .expect("expected a card document type");
+
+            let entropy = Bytes32::random_with_rng(rng);
+
+            let mut document = card_document_type
+                .random_document_with_identifier_and_entropy(
+                    rng,
+                    owner.id(),
+                    entropy,
+                    DocumentFieldFillType::DoNotFillIfNotRequired,
+                    DocumentFieldFillSize::AnyDocumentFillSize,
+                    platform_version,
+                )
+                .expect("expected a random document");
+
+            document.set("attack", 4.into());
+            document.set("defense", 7.into());
+
+            let documents_batch_create_transition =
+                DocumentsBatchTransition::new_document_creation_transition_from_document(
+                    document.clone(),
+                    card_document_type,
+                    entropy.0,
+                    key,
+                    2,
+                    0,
+                    signer,
+                    platform_version,
+                    None,
+                    None,
+                    None,
+                )
+                .expect("expect to create documents batch transition");
+
+            let processing_result = process_transition(
+                platform,
+                documents_batch_create_transition,
+                platform_version,
+            );
+
+            assert_eq!(processing_result.valid_count(), 1);
+
+            document
+        }
+
+        fn process_transition(
+            platform: &TempPlatform<MockCoreRPCLike>,
+            state_transition: StateTransition,
+            platform_version: &PlatformVersion,
+        ) -> StateTransitionsProcessingResult {
+            let serialized_transition = state_transition
+                .serialize_to_bytes()
+                .expect("expected documents batch serialized state transition");
+
+            let platform_state = platform.state.load();
+
+            let transaction = platform.drive.grove.start_transaction();
+
+            let processing_result = platform
+                .platform
+                .process_raw_state_transitions(
+                    &vec![serialized_transition],
+                    &platform_state,
+                    &BlockInfo::default_with_time(50000000),
+                    &transaction,
+                    platform_version,
+                    false,
+                    None,
+                )
+                .expect("expected to process state transition");
+
+            platform
+                .drive
+                .grove
+                .commit_transaction(transaction)
+                .unwrap()
+                .expect("expected to commit transaction");
+
+            processing_result
+        }
+
+        #[test]
+        fn test_document_public_offer_and_accept() {
+            let platform_version = PlatformVersion::latest();
+            let (mut platform, contract) = TestPlatformBuilder::new()
+                .with_latest_protocol_version()
+                .build_with_mock_rpc()
+                .set_initial_state_structure()
+                .with_crypto_card_game_nft(TradeMode::PublicOffer);
+
+            let mut rng = StdRng::seed_from_u64(433);
+
+            let (identity, signer, key) = setup_identity(&mut platform, 958, dash_to_credits!(0.1));
+
+            let (bidder, bidder_signer, bidder_key) =
+                setup_identity(&mut platform, 450, dash_to_credits!(1.0));
+
+            let card_document_type = contract
+                .document_type_for_name("card")
+                .expect("expected a card document type");
+
+            let mut document = create_card(
+                &platform,
+                &contract,
+                &identity,
+                &signer,
+                &key,
+                &mut rng,
+                platform_version,
+            );
+
+            let seller_balance_before_sale = platform
+                .drive
+                .fetch_identity_balance(identity.id().to_buffer(), None, platform_version)
+                .expect("expected to get identity balance")
+                .expect("expected that identity exists");
+
+            let make_offer_transition = DocumentMakeOfferTransition::from_document(
+                document.clone(),
+                card_document_type,
+                dash_to_credits!(0.3),
+                None,
+                1,
+                platform_version,
+                None,
+                None,
+            )
+            .expect("expected to create make offer transition");
+
+            let documents_batch_make_offer_transition =
+                DocumentsBatchTransition::new_documents_batch_transition_from_transitions(
+                    bidder.id(),
+                    vec![make_offer_transition.into()],
+                    &bidder_key,
+                    0,
+                    &bidder_signer,
+                    platform_version,
+                    None,
+                )
+                .expect("expect to create documents batch transition for the offer");
+
+            let processing_result = process_transition(
+                &platform,
+                documents_batch_make_offer_transition,
+                platform_version,
+            );
+
+            assert_eq!(processing_result.invalid_paid_count(), 0);
+
+            assert_eq!(processing_result.invalid_unpaid_count(), 0);
+
+            assert_eq!(processing_result.valid_count(), 1);
+
+            let offer = platform
+                .drive
+                .fetch_document_offer(
+                    contract.id(),
+                    "card",
+                    document.id(),
+                    bidder.id(),
+                    None,
+                    platform_version,
+                )
+                .expect("expected to fetch offer")
+                .expect("expected the offer to exist");
+
+            assert_eq!(offer.price, dash_to_credits!(0.3));
+
+            assert_eq!(offer.recipient_id, None);
+
+            let bidder_balance = platform
+                .drive
+                .fetch_identity_balance(bidder.id().to_buffer(), None, platform_version)
+                .expect("expected to get bidder balance")
+                .expect("expected that bidder exists");
+
+            // the offered credits are escrowed on top of the fees
+            assert!(bidder_balance < dash_to_credits!(0.7));
+
+            // The owner now accepts the offer
+
+            document.set_revision(Some(2));
+
+            let accept_offer_transition = DocumentAcceptOfferTransition::from_document(
+                document.clone(),
+                card_document_type,
+                bidder.id(),
+                dash_to_credits!(0.3),
+                3,
+                platform_version,
+                None,
+                None,
+            )
+            .expect("expected to create accept offer transition");
+
+            let documents_batch_accept_offer_transition =
+                DocumentsBatchTransition::new_documents_batch_transition_from_transitions(
+                    identity.id(),
+                    vec![accept_offer_transition.into()],
+                    &key,
+                    0,
+                    &signer,
+                    platform_version,
+                    None,
+                )
+                .expect("expect to create documents batch transition for the acceptance");
+
+            let processing_result = process_transition(
+                &platform,
+                documents_batch_accept_offer_transition,
+                platform_version,
+            );
+
+            assert_eq!(processing_result.invalid_paid_count(), 0);
+
+            assert_eq!(processing_result.invalid_unpaid_count(), 0);
+
+            assert_eq!(processing_result.valid_count(), 1);
+
+            let offer = platform
+                .drive
+                .fetch_document_offer(
+                    contract.id(),
+                    "card",
+                    document.id(),
+                    bidder.id(),
+                    None,
+                    platform_version,
+                )
+                .expect("expected to fetch offer");
+
+            assert_eq!(offer, None);
+
+            let receiver_documents_sql_string =
+                format!("select * from card where $ownerId == '{}'", bidder.id());
+
+            let query_receiver_identity_documents = DriveDocumentQuery::from_sql_expr(
+                receiver_documents_sql_string.as_str(),
+                &contract,
+                Some(&platform.config.drive),
+            )
+            .expect("expected document query");
+
+            let query_receiver_results = platform
+                .drive
+                .query_documents(query_receiver_identity_documents, None, false, None, None)
+                .expect("expected query result");
+
+            // The bidder is now the owner of the card
+            assert_eq!(query_receiver_results.documents().len(), 1);
+
+            let seller_balance = platform
+                .drive
+                .fetch_identity_balance(identity.id().to_buffer(), None, platform_version)
+                .expect("expected to get identity balance")
+                .expect("expected that identity exists");
+
+            // the seller received the escrowed credits minus the processing fees
+            assert!(seller_balance > seller_balance_before_sale + dash_to_credits!(0.29));
+        }
+
+        #[test]
+        fn test_document_public_offer_and_withdraw() {
+            let platform_version = PlatformVersion::latest();
+            let (mut platform, contract) = TestPlatformBuilder::new()
+                .with_latest_protocol_version()
+                .build_with_mock_rpc()
+                .set_initial_state_structure()
+                .with_crypto_card_game_nft(TradeMode::PublicOffer);
+
+            let mut rng = StdRng::seed_from_u64(433);
+
+            let (identity, signer, key) = setup_identity(&mut platform, 958, dash_to_credits!(0.1));
+
+            let (bidder, bidder_signer, bidder_key) =
+                setup_identity(&mut platform, 450, dash_to_credits!(1.0));
+
+            let card_document_type = contract
+                .document_type_for_name("card")
+                .expect("expected a card document type");
+
+            let document = create_card(
+                &platform,
+                &contract,
+                &identity,
+                &signer,
+                &key,
+                &mut rng,
+                platform_version,
+            );
+
+            let make_offer_transition = DocumentMakeOfferTransition::from_document(
+                document.clone(),
+                card_document_type,
+                dash_to_credits!(0.3),
+                None,
+                1,
+                platform_version,
+                None,
+                None,
+            )
+            .expect("expected to create make offer transition");
+
+            let documents_batch_make_offer_transition =
+                DocumentsBatchTransition::new_documents_batch_transition_from_transitions(
+                    bidder.id(),
+                    vec![make_offer_transition.clone().into()],
+                    &bidder_key,
+                    0,
+                    &bidder_signer,
+                    platform_version,
+                    None,
+                )
+                .expect("expect to create documents batch transition for the offer");
+
+            let processing_result = process_transition(
+                &platform,
+                documents_batch_make_offer_transition,
+                platform_version,
+            );
+
+            assert_eq!(processing_result.valid_count(), 1);
+
+            // A second offer of the same bidder is not allowed
+
+            let second_make_offer_transition = DocumentMakeOfferTransition::from_document(
+                document.clone(),
+                card_document_type,
+                dash_to_credits!(0.4),
+                None,
+                2,
+                platform_version,
+                None,
+                None,
+            )
+            .expect("expected to create make offer transition");
+
+            let documents_batch_second_offer_transition =
+                DocumentsBatchTransition::new_documents_batch_transition_from_transitions(
+                    bidder.id(),
+                    vec![second_make_offer_transition.into()],
+                    &bidder_key,
+                    0,
+                    &bidder_signer,
+                    platform_version,
+                    None,
+                )
+                .expect("expect to create documents batch transition for the offer");
+
+            let processing_result = process_transition(
+                &platform,
+                documents_batch_second_offer_transition,
+                platform_version,
+            );
+
+            assert_eq!(processing_result.invalid_paid_count(), 1);
+
+            let result = processing_result.into_execution_results().remove(0);
+
+            let StateTransitionExecutionResult::PaidConsensusError(consensus_error, _) = result
+            else {
+                panic!("expected a paid consensus error");
+            };
+            assert!(consensus_error
+                .to_string()
+                .contains("already has an open offer"));
+
+            let bidder_balance_before_withdrawal = platform
+                .drive
+                .fetch_identity_balance(bidder.id().to_buffer(), None, platform_version)
+                .expect("expected to get bidder balance")
+                .expect("expected that bidder exists");
+
+            let withdraw_offer_transition = DocumentWithdrawOfferTransition::from_document(
+                document.clone(),
+                card_document_type,
+                3,
+                platform_version,
+                None,
+                None,
+            )
+            .expect("expected to create withdraw offer transition");
+
+            let documents_batch_withdraw_offer_transition =
+                DocumentsBatchTransition::new_documents_batch_transition_from_transitions(
+                    bidder.id(),
+                    vec![withdraw_offer_transition.into()],
+                    &bidder_key,
+                    0,
+                    &bidder_signer,
+                    platform_version,
+                    None,
+                )
+                .expect("expect to create documents batch transition for the withdrawal");
+
+            let processing_result = process_transition(
+                &platform,
+                documents_batch_withdraw_offer_transition,
+                platform_version,
+            );
+
+            assert_eq!(processing_result.invalid_paid_count(), 0);
+
+            assert_eq!(processing_result.invalid_unpaid_count(), 0);
+
+            assert_eq!(processing_result.valid_count(), 1);
+
+            let offer = platform
+                .drive
+                .fetch_document_offer(
+                    contract.id(),
+                    "card",
+                    document.id(),
+                    bidder.id(),
+                    None,
+                    platform_version,
+                )
+                .expect("expected to fetch offer");
+
+            assert_eq!(offer, None);
+
+            let bidder_balance = platform
+                .drive
+                .fetch_identity_balance(bidder.id().to_buffer(), None, platform_version)
+                .expect("expected to get bidder balance")
+                .expect("expected that bidder exists");
+
+            // the escrowed credits are given back minus the processing fees
+            assert!(bidder_balance > bidder_balance_before_withdrawal + dash_to_credits!(0.29));
+        }
+
+        #[test]
+        fn test_document_private_offer_to_someone_else_than_the_owner() {
+            let platform_version = PlatformVersion::latest();
+            let (mut platform, contract) = TestPlatformBuilder::new()
+                .with_latest_protocol_version()
+                .build_with_mock_rpc()
+                .set_initial_state_structure()
+                .with_crypto_card_game_nft(TradeMode::PrivateOffer);
+
+            let mut rng = StdRng::seed_from_u64(433);
+
+            let (identity, signer, key) = setup_identity(&mut platform, 958, dash_to_credits!(0.1));
+
+            let (bidder, bidder_signer, bidder_key) =
+                setup_identity(&mut platform, 450, dash_to_credits!(1.0));
+
+            let card_document_type = contract
+                .document_type_for_name("card")
+                .expect("expected a card document type");
+
+            let document = create_card(
+                &platform,
+                &contract,
+                &identity,
+                &signer,
+                &key,
+                &mut rng,
+                platform_version,
+            );
+
+            let make_offer_transition = DocumentMakeOfferTransition::from_document(
+                document.clone(),
+                card_document_type,
+                dash_to_credits!(0.3),
+                Some(bidder.id()),
+                1,
+                platform_version,
+                None,
+                None,
+            )
+            .expect("expected to create make offer transition");
+
+            let documents_batch_make_offer_transition =
+                DocumentsBatchTransition::new_documents_batch_transition_from_transitions(
+                    bidder.id(),
+                    vec![make_offer_transition.into()],
+                    &bidder_key,
+                    0,
+                    &bidder_signer,
+                    platform_version,
+                    None,
+                )
+                .expect("expect to create documents batch transition for the offer");
+
+            let processing_result = process_transition(
+                &platform,
+                documents_batch_make_offer_transition,
+                platform_version,
+            );
+
+            assert_eq!(processing_result.invalid_paid_count(), 1);
+
+            assert_eq!(processing_result.valid_count(), 0);
+
+            let offer = platform
+                .drive
+                .fetch_document_offer(
+                    contract.id(),
+                    "card",
+                    document.id(),
+                    bidder.id(),
+                    None,
+                    platform_version,
+                )
+                .expect("expected to fetch offer");
+
+            assert_eq!(offer, None);
+        }
+    }
+
     mod dpns_tests {
         use super::*;
         use crate::execution::validation::state_transition::tests::setup_identity;
@@ -7878,4 +8416,268 @@ mod tests {
             assert_eq!(documents.len(), 2);
         }
     }
+
+    mod multi_transition_tests {
+        use super::*;
+        use crate::platform_types::platform_state::PlatformState;
+        use crate::rpc::core::MockCoreRPCLike;
+        use crate::test::helpers::setup::TempPlatform;
+        use assert_matches::assert_matches;
+        use dapi_grpc::platform::v0::get_proofs_response::{get_proofs_response_v0, Version};
+        use dpp::data_contract::document_type::DocumentTypeRef;
+        use dpp::document::Document;
+        use dpp::identity::IdentityPublicKey;
+        use dpp::prelude::Identifier;
+        use dpp::state_transition::documents_batch_transition::document_transition::{
+            DocumentCreateTransition, DocumentTransition,
+        };
+        use dpp::state_transition::proof_result::StateTransitionProofResult;
+        use dpp::state_transition::StateTransition;
+        use drive::drive::Drive;
+        use simple_signer::signer::SimpleSigner;
+
+        fn random_document(
+            document_type: DocumentTypeRef,
+            owner_id: Identifier,
+            rng: &mut StdRng,
+            platform_version: &PlatformVersion,
+        ) -> (Document, Bytes32) {
+            let entropy = Bytes32::random_with_rng(rng);
+
+            let document = document_type
+                .random_document_with_identifier_and_entropy(
+                    rng,
+                    owner_id,
+                    entropy,
+                    DocumentFieldFillType::FillIfNotRequired,
+                    DocumentFieldFillSize::AnyDocumentFillSize,
+                    platform_version,
+                )
+                .expect("expected a random document");
+
+            (document, entropy)
+        }
+
+        fn creation_batch(
+            documents: Vec<(Document, DocumentTypeRef, Bytes32)>,
+            owner_id: Identifier,
+            key: &IdentityPublicKey,
+            signer: &SimpleSigner,
+            platform_version: &PlatformVersion,
+        ) -> StateTransition {
+            let transitions = documents
+                .into_iter()
+                .enumerate()
+                .map(|(index, (document, document_type, entropy))| {
+                    DocumentCreateTransition::from_document(
+                        document,
+                        document_type,
+                        entropy.0,
+                        2 + index as u64,
+                        platform_version,
+                        None,
+                        None,
+                    )
+                    .expect("expected a create transition")
+                    .into()
+                })
+                .collect::<Vec<DocumentTransition>>();
+
+            DocumentsBatchTransition::new_documents_batch_transition_from_transitions(
+                owner_id,
+                transitions,
+                key,
+                0,
+                signer,
+                platform_version,
+                None,
+            )
+            .expect("expected to create documents batch transition")
+        }
+
+        fn prove(
+            platform: &TempPlatform<MockCoreRPCLike>,
+            state_transition: &StateTransition,
+            platform_state: &PlatformState,
+            platform_version: &PlatformVersion,
+        ) -> Vec<u8> {
+            let response = platform
+                .query_state_transition_proofs(
+                    state_transition.clone(),
+                    platform_state,
+                    platform_version,
+                )
+                .expect("expected query to succeed")
+                .into_data()
+                .expect("expected query to be valid");
+
+            let Some(Version::V0(response_v0)) = response.version else {
+                panic!("expected v0 response");
+            };
+
+            let Some(get_proofs_response_v0::Result::Proof(proof)) = response_v0.result else {
+                panic!("expected proof");
+            };
+
+            proof.grovedb_proof
+        }
+
+        #[test]
+        fn test_documents_batch_with_several_transitions_proves_every_document() {
+            let platform_version = PlatformVersion::latest();
+            let mut platform = TestPlatformBuilder::new()
+                .with_latest_protocol_version()
+                .build_with_mock_rpc()
+                .set_genesis_state();
+
+            let mut rng = StdRng::seed_from_u64(433);
+
+            let platform_state = platform.state.load();
+
+            let (identity, signer, key) = setup_identity(&mut platform, 958, dash_to_credits!(0.1));
+
+            let dashpay = platform.drive.cache.system_data_contracts.load_dashpay();
+
+            let profile = dashpay
+                .document_type_for_name("profile")
+                .expect("expected a profile document type");
+            let contact_info = dashpay
+                .document_type_for_name("contactInfo")
+                .expect("expected a contact info document type");
+
+            let (profile_document, profile_entropy) =
+                random_document(profile, identity.id(), &mut rng, platform_version);
+            let (contact_info_document, contact_info_entropy) =
+                random_document(contact_info, identity.id(), &mut rng, platform_version);
+
+            let documents_batch_transition = creation_batch(
+                vec![
+                    (profile_document.clone(), profile, profile_entropy),
+                    (
+                        contact_info_document.clone(),
+                        contact_info,
+                        contact_info_entropy,
+                    ),
+                ],
+                identity.id(),
+                &key,
+                &signer,
+                platform_version,
+            );
+
+            let documents_batch_serialized_transition = documents_batch_transition
+                .serialize_to_bytes()
+                .expect("expected documents batch serialized state transition");
+
+            let transaction = platform.drive.grove.start_transaction();
+
+            let processing_result = platform
+                .platform
+                .process_raw_state_transitions(
+                    &vec![documents_batch_serialized_transition],
+                    &platform_state,
+                    &BlockInfo::default(),
+                    &transaction,
+                    platform_version,
+                    false,
+                    None,
+                )
+                .expect("expected to process state transition");
+
+            assert_matches!(
+                processing_result.execution_results().as_slice(),
+                [StateTransitionExecutionResult::SuccessfulExecution(_, _)]
+            );
+
+            platform
+                .drive
+                .grove
+                .commit_transaction(transaction)
+                .unwrap()
+                .expect("expected to commit transaction");
+
+            let proof = prove(
+                &platform,
+                &documents_batch_transition,
+                &platform_state,
+                platform_version,
+            );
+
+            let (_, proof_result) = Drive::verify_state_transition_was_executed_with_proof(
+                &documents_batch_transition,
+                &BlockInfo::default(),
+                &proof,
+                &|_| Ok(Some(dashpay.clone())),
+                platform_version,
+            )
+            .expect("expected to verify the documents batch proof");
+
+            let StateTransitionProofResult::VerifiedDocuments(documents) = proof_result else {
+                panic!("expected verified documents");
+            };
+
+            assert_eq!(documents.len(), 2);
+            for id in [profile_document.id(), contact_info_document.id()] {
+                let document = documents
+                    .get(&id)
+                    .expect("expected the document to be proved")
+                    .as_ref()
+                    .expect("expected the document to exist");
+                assert_eq!(document.owner_id(), identity.id());
+            }
+
+            // A proof of only one document of the batch must not verify the whole batch
+            let profile_only_transition = creation_batch(
+                vec![(profile_document, profile, profile_entropy)],
+                identity.id(),
+                &key,
+                &signer,
+                platform_version,
+            );
+
+            let profile_only_proof = prove(
+                &platform,
+                &profile_only_transition,
+                &platform_state,
+                platform_version,
+            );
+
+            Drive::verify_state_transition_was_executed_with_proof(
+                &documents_batch_transition,
+                &BlockInfo::default(),
+                &profile_only_proof,
+                &|_| Ok(Some(dashpay.clone())),
+                platform_version,
+            )
+            .expect_err("expected a proof missing a document of the batch to be rejected");
+
+            // A proof of the batch must not verify a batch with a document that was not created
+            let (other_contact_info_document, other_contact_info_entropy) =
+                random_document(contact_info, identity.id(), &mut rng, platform_version);
+
+            let mismatched_transition = creation_batch(
+                vec![
+                    (contact_info_document, contact_info, contact_info_entropy),
+                    (
+                        other_contact_info_document,
+                        contact_info,
+                        other_contact_info_entropy,
+                    ),
+                ],
+                identity.id(),
+                &key,
+                &signer,
+                platform_version,
+            );
+
+            Drive::verify_state_transition_was_executed_with_proof(
+                &mismatched_transition,
+                &BlockInfo::default(),
+                &proof,
+                &|_| Ok(Some(dashpay.clone())),
+                platform_version,
+            )
+            .expect_err("expected a proof of another batch to be rejected");
+        }
+    }
 }
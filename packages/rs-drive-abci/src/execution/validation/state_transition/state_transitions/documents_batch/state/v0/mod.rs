@@ -19,6 +19,9 @@ use crate::execution::validation::state_transition::documents_batch::action_vali
 use crate::execution::validation::state_transition::documents_batch::action_validation::document_replace_transition_action::DocumentReplaceTransitionActionValidation;
 use crate::execution::validation::state_transition::documents_batch::action_validation::document_transfer_transition_action::DocumentTransferTransitionActionValidation;
 use crate::execution::validation::state_transition::documents_batch::action_validation::document_update_price_transition_action::DocumentUpdatePriceTransitionActionValidation;
+use crate::execution::validation::state_transition::documents_batch::action_validation::document_make_offer_transition_action::DocumentMakeOfferTransitionActionValidation;
+use crate::execution::validation::state_transition::documents_batch::action_validation::document_withdraw_offer_transition_action::DocumentWithdrawOfferTransitionActionValidation;
+use crate::execution::validation::state_transition::documents_batch::action_validation::document_accept_offer_transition_action::DocumentAcceptOfferTransitionActionValidation;
 use crate::execution::validation::state_transition::documents_batch::data_triggers::{data_trigger_bindings_list, DataTriggerExecutionContext, DataTriggerExecutor};
 use crate::platform_types::platform::{PlatformStateRef};
 use crate::execution::validation::state_transition::state_transitions::documents_batch::transformer::v0::DocumentsBatchTransitionTransformerV0;
@@ -133,6 +136,35 @@ impl DocumentsBatchStateTransitionStateValidationV0 for DocumentsBatchTransition
                         transaction,
                         platform_version,
                     )?,
+                DocumentTransitionAction::MakeOfferAction(make_offer_action) => make_offer_action
+                    .validate_state(
+                    platform,
+                    owner_id,
+                    block_info,
+                    execution_context,
+                    transaction,
+                    platform_version,
+                )?,
+                DocumentTransitionAction::WithdrawOfferAction(withdraw_offer_action) => {
+                    withdraw_offer_action.validate_state(
+                        platform,
+                        owner_id,
+                        block_info,
+                        execution_context,
+                        transaction,
+                        platform_version,
+                    )?
+                }
+                DocumentTransitionAction::AcceptOfferAction(accept_offer_action) => {
+                    accept_offer_action.validate_state(
+                        platform,
+                        owner_id,
+                        block_info,
+                        execution_context,
+                        transaction,
+                        platform_version,
+                    )?
+                }
                 DocumentTransitionAction::BumpIdentityDataContractNonce(..) => {
                     return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
                         "we should never start with a bump identity data contract nonce",
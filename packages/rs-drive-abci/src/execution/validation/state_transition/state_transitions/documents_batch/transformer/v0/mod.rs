@@ -10,6 +10,7 @@ use dpp::consensus::basic::BasicError;
 use dpp::consensus::state::document::document_not_found_error::DocumentNotFoundError;
 use dpp::consensus::state::document::document_owner_id_mismatch_error::DocumentOwnerIdMismatchError;
 
+use dpp::consensus::state::data_contract::data_contract_is_archived_error::DataContractIsArchivedError;
 use dpp::consensus::state::document::invalid_document_revision_error::InvalidDocumentRevisionError;
 use dpp::consensus::state::state_error::StateError;
 use dpp::data_contract::accessors::v0::DataContractV0Getters;
@@ -17,6 +18,8 @@ use dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dpp::block::block_info::BlockInfo;
 use dpp::consensus::state::document::document_incorrect_purchase_price_error::DocumentIncorrectPurchasePriceError;
 use dpp::consensus::state::document::document_not_for_sale_error::DocumentNotForSaleError;
+use dpp::consensus::state::document::document_offer_not_found_error::DocumentOfferNotFoundError;
+use dpp::consensus::state::document::document_offer_price_too_low_error::DocumentOfferPriceTooLowError;
 use dpp::document::property_names::PRICE;
 use dpp::document::{Document, DocumentV0Getters};
 use dpp::fee::Credits;
@@ -30,6 +33,9 @@ use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatch
 use dpp::state_transition::documents_batch_transition::document_base_transition::v0::v0_methods::DocumentBaseTransitionV0Methods;
 use dpp::state_transition::documents_batch_transition::document_transition::{DocumentTransition, DocumentTransitionV0Methods};
 use dpp::state_transition::documents_batch_transition::document_transition::document_purchase_transition::v0::v0_methods::DocumentPurchaseTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::document_make_offer_transition::v0::v0_methods::DocumentMakeOfferTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::document_withdraw_offer_transition::v0::v0_methods::DocumentWithdrawOfferTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::document_accept_offer_transition::v0::v0_methods::DocumentAcceptOfferTransitionV0Methods;
 use dpp::state_transition::StateTransitionLike;
 use drive::state_transition_action::document::documents_batch::document_transition::document_create_transition_action::DocumentCreateTransitionAction;
 use drive::state_transition_action::document::documents_batch::document_transition::document_delete_transition_action::DocumentDeleteTransitionAction;
@@ -50,8 +56,12 @@ use drive::drive::Drive;
 use drive::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::DocumentPurchaseTransitionAction;
 use drive::state_transition_action::document::documents_batch::document_transition::document_transfer_transition_action::DocumentTransferTransitionAction;
 use drive::state_transition_action::document::documents_batch::document_transition::document_update_price_transition_action::DocumentUpdatePriceTransitionAction;
+use drive::state_transition_action::document::documents_batch::document_transition::document_make_offer_transition_action::DocumentMakeOfferTransitionAction;
+use drive::state_transition_action::document::documents_batch::document_transition::document_withdraw_offer_transition_action::DocumentWithdrawOfferTransitionAction;
+use drive::state_transition_action::document::documents_batch::document_transition::document_accept_offer_transition_action::DocumentAcceptOfferTransitionAction;
 use drive::state_transition_action::system::bump_identity_data_contract_nonce_action::BumpIdentityDataContractNonceAction;
 use crate::execution::types::execution_operation::ValidationOperation;
+use crate::execution::validation::state_transition::common::fetch_data_contract_archived_at::fetch_data_contract_archived_at;
 use crate::execution::types::state_transition_execution_context::{StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0};
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 
@@ -232,6 +242,31 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
             ));
         };
 
+        // Documents of archived contracts can't be created or modified, however bidders must
+        // still be able to withdraw their offers to get back their escrowed credits
+        let archived_at = fetch_data_contract_archived_at(
+            drive,
+            *data_contract_id,
+            block_info,
+            execution_context,
+            transaction,
+            platform_version,
+        )?;
+
+        let only_withdraws_offers = document_transitions
+            .values()
+            .flatten()
+            .all(|transition| matches!(transition, DocumentTransition::WithdrawOffer(_)));
+
+        if archived_at.is_some() && !only_withdraws_offers {
+            return Ok(ConsensusValidationResult::new_with_error(
+                StateError::DataContractIsArchivedError(DataContractIsArchivedError::new(
+                    *data_contract_id,
+                ))
+                .into(),
+            ));
+        }
+
         let validation_result = document_transitions
             .iter()
             .map(|(document_type_name, document_transitions)| {
@@ -292,6 +327,8 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
                         | DocumentTransition::Transfer(_)
                         | DocumentTransition::Purchase(_)
                         | DocumentTransition::UpdatePrice(_)
+                        | DocumentTransition::MakeOffer(_)
+                        | DocumentTransition::AcceptOffer(_)
                 )
             })
             .copied()
@@ -655,6 +692,213 @@ impl DocumentsBatchTransitionInternalTransformerV0 for DocumentsBatchTransition
                     Ok(result)
                 }
             }
+            DocumentTransition::MakeOffer(document_make_offer_transition) => {
+                let mut result = ConsensusValidationResult::<DocumentTransitionAction>::new();
+
+                let validation_result =
+                    Self::find_replaced_document_v0(transition, replaced_documents);
+
+                if !validation_result.is_valid_with_data() {
+                    result.merge(validation_result);
+                    return Ok(result);
+                }
+
+                let original_document = validation_result.into_data()?;
+
+                // A private offer is only made to the current owner of the document
+                if let Some(recipient_id) = document_make_offer_transition.recipient_id() {
+                    if recipient_id != original_document.owner_id() {
+                        result.add_error(StateError::DocumentOwnerIdMismatchError(
+                            DocumentOwnerIdMismatchError::new(
+                                original_document.id(),
+                                recipient_id,
+                                original_document.owner_id(),
+                            ),
+                        ));
+                        return Ok(result);
+                    }
+                }
+
+                // The price set by the owner, if any, is the minimum they are willing to accept
+                if let Some(min_price) = original_document
+                    .properties()
+                    .get_optional_integer::<Credits>(PRICE)?
+                {
+                    if document_make_offer_transition.price() < min_price {
+                        result.add_error(StateError::DocumentOfferPriceTooLowError(
+                            DocumentOfferPriceTooLowError::new(
+                                original_document.id(),
+                                document_make_offer_transition.price(),
+                                min_price,
+                            ),
+                        ));
+                        return Ok(result);
+                    }
+                }
+
+                let document_make_offer_action =
+                    DocumentMakeOfferTransitionAction::try_from_borrowed_document_make_offer_transition(
+                        document_make_offer_transition,
+                        original_document.owner_id(),
+                        owner_id,
+                        block_info,
+                        |_identifier| Ok(data_contract_fetch_info.clone()),
+                    )?;
+
+                if result.is_valid() {
+                    Ok(
+                        DocumentTransitionAction::MakeOfferAction(document_make_offer_action)
+                            .into(),
+                    )
+                } else {
+                    Ok(result)
+                }
+            }
+            DocumentTransition::WithdrawOffer(document_withdraw_offer_transition) => {
+                let mut result = ConsensusValidationResult::<DocumentTransitionAction>::new();
+
+                let base = document_withdraw_offer_transition.base();
+
+                // The document doesn't need to exist anymore, only the offer of the owner
+                let (offer, fee_result) = drive.fetch_document_offer_with_fees(
+                    base.data_contract_id(),
+                    base.document_type_name(),
+                    base.id(),
+                    owner_id,
+                    block_info,
+                    transaction,
+                    platform_version,
+                )?;
+
+                execution_context
+                    .add_operation(ValidationOperation::PrecalculatedOperation(fee_result));
+
+                let Some(offer) = offer else {
+                    result.add_error(StateError::DocumentOfferNotFoundError(
+                        DocumentOfferNotFoundError::new(base.id(), owner_id),
+                    ));
+                    return Ok(result);
+                };
+
+                let document_withdraw_offer_action =
+                    DocumentWithdrawOfferTransitionAction::try_from_borrowed_document_withdraw_offer_transition(
+                        document_withdraw_offer_transition,
+                        offer,
+                        |_identifier| Ok(data_contract_fetch_info.clone()),
+                    )?;
+
+                Ok(
+                    DocumentTransitionAction::WithdrawOfferAction(document_withdraw_offer_action)
+                        .into(),
+                )
+            }
+            DocumentTransition::AcceptOffer(document_accept_offer_transition) => {
+                let mut result = ConsensusValidationResult::<DocumentTransitionAction>::new();
+
+                let validation_result =
+                    Self::find_replaced_document_v0(transition, replaced_documents);
+
+                if !validation_result.is_valid_with_data() {
+                    result.merge(validation_result);
+                    return Ok(result);
+                }
+
+                let original_document = validation_result.into_data()?;
+
+                let validation_result = Self::check_ownership_of_old_replaced_document_v0(
+                    document_accept_offer_transition.base().id(),
+                    original_document,
+                    &owner_id,
+                );
+
+                if !validation_result.is_valid() {
+                    result.merge(validation_result);
+                    return Ok(result);
+                }
+
+                if validate_against_state {
+                    //there are situations where we don't want to validate this against the state
+                    // for example when we already applied the state transition action
+                    // and we are just validating it happened
+                    let validation_result = Self::check_revision_is_bumped_by_one_during_replace_v0(
+                        document_accept_offer_transition.revision(),
+                        document_accept_offer_transition.base().id(),
+                        original_document,
+                    );
+
+                    if !validation_result.is_valid() {
+                        result.merge(validation_result);
+                        return Ok(result);
+                    }
+                }
+
+                let base = document_accept_offer_transition.base();
+                let bidder_id = document_accept_offer_transition.bidder_id();
+
+                let (offer, fee_result) = drive.fetch_document_offer_with_fees(
+                    base.data_contract_id(),
+                    base.document_type_name(),
+                    base.id(),
+                    bidder_id,
+                    block_info,
+                    transaction,
+                    platform_version,
+                )?;
+
+                execution_context
+                    .add_operation(ValidationOperation::PrecalculatedOperation(fee_result));
+
+                let Some(offer) = offer else {
+                    result.add_error(StateError::DocumentOfferNotFoundError(
+                        DocumentOfferNotFoundError::new(base.id(), bidder_id),
+                    ));
+                    return Ok(result);
+                };
+
+                // The owner must agree to the escrowed amount
+                if offer.price != document_accept_offer_transition.price() {
+                    result.add_error(StateError::DocumentIncorrectPurchasePriceError(
+                        DocumentIncorrectPurchasePriceError::new(
+                            original_document.id(),
+                            document_accept_offer_transition.price(),
+                            offer.price,
+                        ),
+                    ));
+                    return Ok(result);
+                }
+
+                // A private offer can only be accepted by its recipient
+                if let Some(recipient_id) = offer.recipient_id {
+                    if recipient_id != owner_id {
+                        result.add_error(StateError::DocumentOwnerIdMismatchError(
+                            DocumentOwnerIdMismatchError::new(
+                                original_document.id(),
+                                owner_id,
+                                recipient_id,
+                            ),
+                        ));
+                        return Ok(result);
+                    }
+                }
+
+                let document_accept_offer_action =
+                    DocumentAcceptOfferTransitionAction::try_from_borrowed_document_accept_offer_transition(
+                        document_accept_offer_transition,
+                        original_document.clone(), //todo: find a way to not have to use cloning
+                        offer,
+                        block_info,
+                        |_identifier| Ok(data_contract_fetch_info.clone()),
+                    )?;
+
+                if result.is_valid() {
+                    Ok(
+                        DocumentTransitionAction::AcceptOfferAction(document_accept_offer_action)
+                            .into(),
+                    )
+                } else {
+                    Ok(result)
+                }
+            }
         }
     }
 
@@ -0,0 +1,65 @@
+use crate::error::Error;
+use dpp::consensus::state::identity::IdentityInsufficientBalanceError;
+use dpp::identity::PartialIdentity;
+use dpp::state_transition::identity_credit_batch_transfer_transition::accessors::IdentityCreditBatchTransferTransitionAccessorsV0;
+use dpp::state_transition::identity_credit_batch_transfer_transition::IdentityCreditBatchTransferTransition;
+
+use dpp::validation::SimpleConsensusValidationResult;
+
+use crate::error::execution::ExecutionError;
+use dpp::version::PlatformVersion;
+
+pub(in crate::execution::validation::state_transition::state_transitions) trait IdentityCreditBatchTransferTransitionBalanceValidationV0
+{
+    fn validate_advanced_minimum_balance_pre_check_v0(
+        &self,
+        identity: &PartialIdentity,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+
+impl IdentityCreditBatchTransferTransitionBalanceValidationV0
+    for IdentityCreditBatchTransferTransition
+{
+    fn validate_advanced_minimum_balance_pre_check_v0(
+        &self,
+        identity: &PartialIdentity,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let balance =
+            identity
+                .balance
+                .ok_or(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                    "expected to have a balance on identity for credit batch transfer transition",
+                )))?;
+
+        let min_fees = &platform_version.fee_version.state_transition_min_fees;
+        let output_min_fees = &platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transition_output_min_fees;
+
+        // An overflowing total can never be covered by any balance
+        let required_balance = self.total_amount().and_then(|total_amount| {
+            output_min_fees
+                .credit_batch_transfer_output
+                .checked_mul(self.outputs().len() as u64)
+                .and_then(|outputs_fee| outputs_fee.checked_add(min_fees.credit_transfer))
+                .and_then(|min_fee| min_fee.checked_add(total_amount))
+        });
+
+        match required_balance {
+            Some(required_balance) if balance >= required_balance => {
+                Ok(SimpleConsensusValidationResult::new())
+            }
+            _ => Ok(SimpleConsensusValidationResult::new_with_error(
+                IdentityInsufficientBalanceError::new(
+                    self.identity_id(),
+                    balance,
+                    self.total_amount().unwrap_or(u64::MAX),
+                )
+                .into(),
+            )),
+        }
+    }
+}
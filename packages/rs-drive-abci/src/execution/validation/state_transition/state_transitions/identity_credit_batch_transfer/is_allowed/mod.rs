@@ -0,0 +1,42 @@
+use crate::error::Error;
+use crate::execution::validation::state_transition::processor::v0::StateTransitionIsAllowedValidationV0;
+use crate::platform_types::platform::PlatformRef;
+use dpp::consensus::basic::UnsupportedFeatureError;
+use dpp::state_transition::identity_credit_batch_transfer_transition::IdentityCreditBatchTransferTransition;
+use dpp::validation::ConsensusValidationResult;
+use dpp::version::PlatformVersion;
+
+impl StateTransitionIsAllowedValidationV0 for IdentityCreditBatchTransferTransition {
+    fn has_is_allowed_validation(
+        &self,
+        _platform_version: &PlatformVersion,
+    ) -> Result<bool, Error> {
+        Ok(true)
+    }
+
+    /// Credits can only be transferred to several recipients at once from the protocol version
+    /// that registers batch transfers
+    fn validate_is_allowed<C>(
+        &self,
+        _platform: &PlatformRef<C>,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<()>, Error> {
+        if platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .identity_credit_batch_transfer_state_transition
+            .is_some()
+        {
+            return Ok(ConsensusValidationResult::new());
+        }
+
+        Ok(ConsensusValidationResult::new_with_error(
+            UnsupportedFeatureError::new(
+                "identity credit batch transfer".to_string(),
+                platform_version.protocol_version,
+            )
+            .into(),
+        ))
+    }
+}
@@ -0,0 +1,642 @@
+mod balance;
+mod is_allowed;
+mod nonce;
+mod state;
+mod structure;
+
+use dpp::block::block_info::BlockInfo;
+use dpp::state_transition::identity_credit_batch_transfer_transition::IdentityCreditBatchTransferTransition;
+use dpp::validation::{ConsensusValidationResult, SimpleConsensusValidationResult};
+use dpp::version::PlatformVersion;
+use drive::state_transition_action::StateTransitionAction;
+
+use drive::grovedb::TransactionArg;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+use crate::platform_types::platform::PlatformRef;
+use crate::rpc::core::CoreRPCLike;
+
+use crate::execution::validation::state_transition::identity_credit_batch_transfer::state::v0::IdentityCreditBatchTransferStateTransitionStateValidationV0;
+use crate::execution::validation::state_transition::identity_credit_batch_transfer::structure::v0::IdentityCreditBatchTransferStateTransitionStructureValidationV0;
+use crate::execution::validation::state_transition::processor::v0::{
+    StateTransitionBasicStructureValidationV0, StateTransitionStateValidationV0,
+};
+use crate::execution::validation::state_transition::transformer::StateTransitionActionTransformerV0;
+use crate::execution::validation::state_transition::ValidationMode;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+
+impl StateTransitionActionTransformerV0 for IdentityCreditBatchTransferTransition {
+    fn transform_into_action<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        _block_info: &BlockInfo,
+        _validation_mode: ValidationMode,
+        _execution_context: &mut StateTransitionExecutionContext,
+        _tx: TransactionArg,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version = platform.state.current_platform_version()?;
+
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .identity_credit_batch_transfer_state_transition
+            .as_ref()
+            .map(|versions| versions.transform_into_action)
+        {
+            Some(0) => self.transform_into_action_v0(),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "identity credit batch transfer transition: transform_into_action"
+                    .to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "identity credit batch transfer transition: transform_into_action"
+                    .to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
+
+impl StateTransitionBasicStructureValidationV0 for IdentityCreditBatchTransferTransition {
+    fn validate_basic_structure(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .identity_credit_batch_transfer_state_transition
+            .as_ref()
+            .and_then(|versions| versions.basic_structure)
+        {
+            Some(0) => {
+                // There is nothing expensive here
+                self.validate_basic_structure_v0(platform_version)
+            }
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "identity credit batch transfer transition: validate_basic_structure"
+                    .to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "identity credit batch transfer transition: validate_basic_structure"
+                    .to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
+
+impl StateTransitionStateValidationV0 for IdentityCreditBatchTransferTransition {
+    fn validate_state<C: CoreRPCLike>(
+        &self,
+        _action: Option<StateTransitionAction>,
+        platform: &PlatformRef<C>,
+        _validation_mode: ValidationMode,
+        _block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version = platform.state.current_platform_version()?;
+
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .identity_credit_batch_transfer_state_transition
+            .as_ref()
+            .map(|versions| versions.state)
+        {
+            Some(0) => self.validate_state_v0(platform, execution_context, tx, platform_version),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "identity credit batch transfer transition: validate_state".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "identity credit batch transfer transition: validate_state".to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execution::validation::state_transition::tests::{
+        fetch_expected_identity_balance, process_state_transition_in_current_version,
+        process_state_transitions, setup_identity_with_system_credits,
+        setup_identity_with_withdrawal_key_and_system_credits,
+    };
+    use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
+    use assert_matches::assert_matches;
+    use dapi_grpc::platform::v0::get_proofs_response::{get_proofs_response_v0, Version};
+    use dpp::block::block_info::BlockInfo;
+    use dpp::consensus::basic::BasicError;
+    use dpp::consensus::signature::SignatureError;
+    use dpp::consensus::state::state_error::StateError;
+    use dpp::consensus::ConsensusError;
+    use dpp::dash_to_credits;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::{Identity, IdentityPublicKey};
+    use dpp::prelude::Identifier;
+    use dpp::serialization::PlatformSerializable;
+    use dpp::state_transition::identity_credit_batch_transfer_transition::methods::IdentityCreditBatchTransferTransitionMethodsV0;
+    use dpp::state_transition::identity_credit_batch_transfer_transition::{
+        CreditTransferOutput, IdentityCreditBatchTransferTransition,
+    };
+    use dpp::state_transition::proof_result::StateTransitionProofResult;
+    use dpp::state_transition::StateTransition;
+    use drive::drive::Drive;
+    use platform_version::version::PlatformVersion;
+    use rand::prelude::StdRng;
+    use rand::{Rng, SeedableRng};
+    use simple_signer::signer::SimpleSigner;
+
+    fn setup_sender(
+        platform: &mut TempPlatform<MockCoreRPCLike>,
+        rng: &mut StdRng,
+    ) -> (Identity, SimpleSigner, IdentityPublicKey) {
+        let (identity, signer, _, transfer_key) =
+            setup_identity_with_withdrawal_key_and_system_credits(
+                platform,
+                rng.gen(),
+                dash_to_credits!(1),
+            );
+        (identity, signer, transfer_key)
+    }
+
+    fn batch_transfer(
+        identity: &Identity,
+        signer: &SimpleSigner,
+        transfer_key: &IdentityPublicKey,
+        outputs: Vec<CreditTransferOutput>,
+        nonce: u64,
+    ) -> StateTransition {
+        IdentityCreditBatchTransferTransition::try_from_identity(
+            identity,
+            outputs,
+            0,
+            signer.clone(),
+            Some(transfer_key),
+            nonce,
+            PlatformVersion::latest(),
+            None,
+        )
+        .expect("expected a credit batch transfer transition")
+    }
+
+    fn process_transition(
+        platform: &TempPlatform<MockCoreRPCLike>,
+        state_transition: &StateTransition,
+    ) -> StateTransitionExecutionResult {
+        let platform_version = PlatformVersion::latest();
+        let platform_state = platform.state.load();
+
+        let serialized_transition = state_transition
+            .serialize_to_bytes()
+            .expect("expected serialized state transition");
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let processing_result = platform
+            .platform
+            .process_raw_state_transitions(
+                &vec![serialized_transition],
+                &platform_state,
+                &BlockInfo::default(),
+                &transaction,
+                platform_version,
+                false,
+                None,
+            )
+            .expect("expected to process state transition");
+
+        platform
+            .drive
+            .grove
+            .commit_transaction(transaction)
+            .unwrap()
+            .expect("expected to commit transaction");
+
+        processing_result
+            .execution_results()
+            .first()
+            .cloned()
+            .expect("expected an execution result")
+    }
+
+    fn consensus_error(result: StateTransitionExecutionResult) -> ConsensusError {
+        match result {
+            StateTransitionExecutionResult::PaidConsensusError(error, _)
+            | StateTransitionExecutionResult::UnpaidConsensusError(error) => error,
+            result => panic!("expected a consensus error, got {:?}", result),
+        }
+    }
+
+    fn output(recipient_id: Identifier, amount: u64) -> CreditTransferOutput {
+        CreditTransferOutput::new(recipient_id, amount, None)
+    }
+
+    fn process_structure_error(
+        outputs: impl FnOnce(Identifier) -> Vec<CreditTransferOutput>,
+    ) -> ConsensusError {
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let mut rng = StdRng::seed_from_u64(567);
+
+        let (identity, signer, transfer_key) = setup_sender(&mut platform, &mut rng);
+
+        let state_transition =
+            batch_transfer(&identity, &signer, &transfer_key, outputs(identity.id()), 1);
+
+        consensus_error(process_transition(&platform, &state_transition))
+    }
+
+    #[test]
+    fn test_credit_batch_transfer_with_no_outputs_is_invalid() {
+        let error = process_structure_error(|_| vec![]);
+
+        assert_matches!(
+            error,
+            ConsensusError::BasicError(
+                BasicError::InvalidIdentityCreditBatchTransferOutputsCountError(_)
+            )
+        );
+    }
+
+    #[test]
+    fn test_credit_batch_transfer_with_duplicate_recipients_is_invalid() {
+        let recipient_id = Identifier::new([1; 32]);
+
+        let error = process_structure_error(|_| {
+            vec![
+                output(recipient_id, dash_to_credits!(0.01)),
+                output(recipient_id, dash_to_credits!(0.02)),
+            ]
+        });
+
+        assert_matches!(
+            error,
+            ConsensusError::BasicError(BasicError::DuplicatedIdentityCreditTransferRecipientError(
+                _
+            ))
+        );
+    }
+
+    #[test]
+    fn test_credit_batch_transfer_to_sender_is_invalid() {
+        let error = process_structure_error(|sender_id| {
+            vec![
+                output(Identifier::new([1; 32]), dash_to_credits!(0.01)),
+                output(sender_id, dash_to_credits!(0.01)),
+            ]
+        });
+
+        assert_matches!(
+            error,
+            ConsensusError::BasicError(BasicError::IdentityCreditTransferToSelfError(_))
+        );
+    }
+
+    #[test]
+    fn test_credit_batch_transfer_with_too_many_outputs_is_invalid() {
+        let max_outputs = PlatformVersion::latest()
+            .system_limits
+            .max_outputs_in_credit_batch_transfer;
+
+        let error = process_structure_error(|_| {
+            (0..=max_outputs)
+                .map(|i| {
+                    let mut recipient_id = [1; 32];
+                    recipient_id[..4].copy_from_slice(&i.to_be_bytes());
+                    output(Identifier::new(recipient_id), dash_to_credits!(0.001))
+                })
+                .collect()
+        });
+
+        assert_matches!(
+            error,
+            ConsensusError::BasicError(
+                BasicError::InvalidIdentityCreditBatchTransferOutputsCountError(_)
+            )
+        );
+    }
+
+    #[test]
+    fn test_credit_batch_transfer_with_zero_amount_is_invalid() {
+        let error = process_structure_error(|_| {
+            vec![
+                output(Identifier::new([1; 32]), dash_to_credits!(0.01)),
+                output(Identifier::new([2; 32]), 0),
+            ]
+        });
+
+        assert_matches!(
+            error,
+            ConsensusError::BasicError(BasicError::InvalidIdentityCreditTransferAmountError(_))
+        );
+    }
+
+    #[test]
+    fn test_credit_batch_transfer_with_overflowing_sum_is_invalid() {
+        let error = process_structure_error(|_| {
+            vec![
+                output(Identifier::new([1; 32]), u64::MAX),
+                output(Identifier::new([2; 32]), dash_to_credits!(0.01)),
+            ]
+        });
+
+        assert_matches!(
+            error,
+            ConsensusError::BasicError(BasicError::OverflowError(_))
+        );
+    }
+
+    #[test]
+    fn test_credit_batch_transfer_with_insufficient_balance_is_invalid() {
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let mut rng = StdRng::seed_from_u64(567);
+
+        let (identity, signer, transfer_key) = setup_sender(&mut platform, &mut rng);
+
+        let (first_recipient, ..) =
+            setup_identity_with_system_credits(&mut platform, rng.gen(), dash_to_credits!(0.1));
+        let (second_recipient, ..) =
+            setup_identity_with_system_credits(&mut platform, rng.gen(), dash_to_credits!(0.1));
+
+        let state_transition = batch_transfer(
+            &identity,
+            &signer,
+            &transfer_key,
+            vec![
+                output(first_recipient.id(), dash_to_credits!(0.6)),
+                output(second_recipient.id(), dash_to_credits!(0.6)),
+            ],
+            1,
+        );
+
+        let error = consensus_error(process_transition(&platform, &state_transition));
+
+        assert_matches!(
+            error,
+            ConsensusError::StateError(StateError::IdentityInsufficientBalanceError(_))
+        );
+    }
+
+    #[test]
+    fn test_credit_batch_transfer_to_missing_recipient_is_invalid() {
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let mut rng = StdRng::seed_from_u64(567);
+
+        let (identity, signer, transfer_key) = setup_sender(&mut platform, &mut rng);
+
+        let (recipient, ..) =
+            setup_identity_with_system_credits(&mut platform, rng.gen(), dash_to_credits!(0.1));
+
+        let missing_recipient_id = Identifier::random_with_rng(&mut rng);
+
+        let state_transition = batch_transfer(
+            &identity,
+            &signer,
+            &transfer_key,
+            vec![
+                output(recipient.id(), dash_to_credits!(0.1)),
+                output(missing_recipient_id, dash_to_credits!(0.1)),
+            ],
+            1,
+        );
+
+        let error = consensus_error(process_transition(&platform, &state_transition));
+
+        assert_matches!(
+            error,
+            ConsensusError::SignatureError(SignatureError::IdentityNotFoundError(e)) if e.identity_id() == missing_recipient_id
+        );
+
+        fetch_expected_identity_balance(
+            &platform,
+            recipient.id(),
+            PlatformVersion::latest(),
+            dash_to_credits!(0.1),
+        );
+    }
+
+    #[test]
+    fn test_credit_batch_transfer_processing_fee_scales_with_outputs() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let mut rng = StdRng::seed_from_u64(567);
+
+        let (single_sender, single_signer, single_transfer_key) =
+            setup_sender(&mut platform, &mut rng);
+        let (batch_sender, batch_signer, batch_transfer_key) =
+            setup_sender(&mut platform, &mut rng);
+
+        let recipients = (0..3)
+            .map(|_| {
+                setup_identity_with_system_credits(&mut platform, rng.gen(), dash_to_credits!(0.1))
+                    .0
+            })
+            .collect::<Vec<_>>();
+
+        let single_output_transition = batch_transfer(
+            &single_sender,
+            &single_signer,
+            &single_transfer_key,
+            vec![output(recipients[0].id(), dash_to_credits!(0.1))],
+            1,
+        );
+
+        let three_outputs_transition = batch_transfer(
+            &batch_sender,
+            &batch_signer,
+            &batch_transfer_key,
+            recipients
+                .iter()
+                .map(|recipient| output(recipient.id(), dash_to_credits!(0.1)))
+                .collect(),
+            1,
+        );
+
+        let platform_state = platform.state.load();
+
+        let (fee_results, _) = process_state_transitions(
+            &platform,
+            &[single_output_transition, three_outputs_transition],
+            BlockInfo::default(),
+            &platform_state,
+        );
+
+        let [single_output_fee, three_outputs_fee] = fee_results.as_slice() else {
+            panic!("expected two fee results");
+        };
+
+        assert!(three_outputs_fee.processing_fee > single_output_fee.processing_fee);
+
+        fetch_expected_identity_balance(
+            &platform,
+            recipients[0].id(),
+            platform_version,
+            dash_to_credits!(0.3),
+        );
+        fetch_expected_identity_balance(
+            &platform,
+            recipients[2].id(),
+            platform_version,
+            dash_to_credits!(0.2),
+        );
+    }
+
+    #[test]
+    fn test_credit_batch_transfer_proof_verifies_all_balances() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let mut rng = StdRng::seed_from_u64(567);
+
+        let (identity, signer, transfer_key) = setup_sender(&mut platform, &mut rng);
+
+        let recipients = (0..2)
+            .map(|_| {
+                setup_identity_with_system_credits(&mut platform, rng.gen(), dash_to_credits!(0.1))
+                    .0
+            })
+            .collect::<Vec<_>>();
+
+        let state_transition = batch_transfer(
+            &identity,
+            &signer,
+            &transfer_key,
+            recipients
+                .iter()
+                .map(|recipient| output(recipient.id(), dash_to_credits!(0.1)))
+                .collect(),
+            1,
+        );
+
+        let platform_state = platform.state.load();
+
+        let (fee_results, _) = process_state_transitions(
+            &platform,
+            &[state_transition.clone()],
+            BlockInfo::default(),
+            &platform_state,
+        );
+
+        assert_eq!(fee_results.len(), 1);
+
+        let response = platform
+            .query_state_transition_proofs(
+                state_transition.clone(),
+                &platform_state,
+                platform_version,
+            )
+            .expect("expected query to succeed")
+            .into_data()
+            .expect("expected query to be valid");
+
+        let Some(Version::V0(response_v0)) = response.version else {
+            panic!("expected v0 response");
+        };
+
+        let Some(get_proofs_response_v0::Result::Proof(proof)) = response_v0.result else {
+            panic!("expected proof");
+        };
+
+        let (_, proof_result) = Drive::verify_state_transition_was_executed_with_proof(
+            &state_transition,
+            &BlockInfo::default(),
+            &proof.grovedb_proof,
+            &|_: &Identifier| Ok(None),
+            platform_version,
+        )
+        .expect("expected to verify the batch transfer proof");
+
+        let StateTransitionProofResult::VerifiedBalanceBatchTransfer(sender, verified_recipients) =
+            proof_result
+        else {
+            panic!("expected a verified balance batch transfer");
+        };
+
+        let sender_balance = platform
+            .drive
+            .fetch_identity_balance(identity.id().to_buffer(), None, platform_version)
+            .expect("expected to fetch sender balance");
+
+        assert_eq!(sender.id, identity.id());
+        assert_eq!(sender.balance, sender_balance);
+        assert!(sender_balance.expect("expected a sender balance") < dash_to_credits!(0.8));
+        assert_eq!(verified_recipients.len(), 2);
+        for (verified_recipient, recipient) in verified_recipients.iter().zip(recipients.iter()) {
+            assert_eq!(verified_recipient.id, recipient.id());
+            assert_eq!(verified_recipient.balance, Some(dash_to_credits!(0.2)));
+        }
+    }
+
+    #[test]
+    fn test_credit_batch_transfer_is_only_processed_from_protocol_version_2() {
+        for (protocol_version, is_processed) in [(1, false), (2, true)] {
+            let mut platform = TestPlatformBuilder::new()
+                .with_initial_protocol_version(protocol_version)
+                .build_with_mock_rpc()
+                .set_initial_state_structure();
+
+            let mut rng = StdRng::seed_from_u64(567);
+
+            let (identity, signer, transfer_key) = setup_sender(&mut platform, &mut rng);
+
+            let (recipient, ..) =
+                setup_identity_with_system_credits(&mut platform, rng.gen(), dash_to_credits!(0.1));
+
+            let state_transition = batch_transfer(
+                &identity,
+                &signer,
+                &transfer_key,
+                vec![output(recipient.id(), dash_to_credits!(0.1))],
+                1,
+            );
+
+            let result = process_state_transition_in_current_version(&platform, &state_transition);
+
+            if is_processed {
+                assert_matches!(
+                    result,
+                    StateTransitionExecutionResult::SuccessfulExecution(..)
+                );
+            } else {
+                assert_matches!(
+                    consensus_error(result),
+                    ConsensusError::BasicError(BasicError::UnsupportedFeatureError(_))
+                );
+            }
+        }
+    }
+}
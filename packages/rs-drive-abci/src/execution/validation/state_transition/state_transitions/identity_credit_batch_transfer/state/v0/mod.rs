@@ -0,0 +1,101 @@
+use crate::error::Error;
+use crate::execution::types::execution_operation::{RetrieveIdentityInfo, ValidationOperation};
+use crate::execution::types::state_transition_execution_context::{
+    StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0,
+};
+use crate::platform_types::platform::PlatformRef;
+use crate::rpc::core::CoreRPCLike;
+
+use dpp::consensus::signature::IdentityNotFoundError;
+
+use dpp::consensus::state::identity::IdentityInsufficientBalanceError;
+
+use dpp::prelude::ConsensusValidationResult;
+use dpp::state_transition::identity_credit_batch_transfer_transition::accessors::IdentityCreditBatchTransferTransitionAccessorsV0;
+use dpp::state_transition::identity_credit_batch_transfer_transition::IdentityCreditBatchTransferTransition;
+use drive::state_transition_action::identity::identity_credit_batch_transfer::IdentityCreditBatchTransferTransitionAction;
+
+use dpp::version::PlatformVersion;
+use drive::grovedb::TransactionArg;
+use drive::state_transition_action::StateTransitionAction;
+
+pub(in crate::execution::validation::state_transition::state_transitions::identity_credit_batch_transfer) trait IdentityCreditBatchTransferStateTransitionStateValidationV0 {
+    fn validate_state_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+
+    fn transform_into_action_v0(
+        &self,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+}
+
+impl IdentityCreditBatchTransferStateTransitionStateValidationV0
+    for IdentityCreditBatchTransferTransition
+{
+    fn validate_state_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let maybe_existing_identity_balance = platform.drive.fetch_identity_balance(
+            self.identity_id().to_buffer(),
+            tx,
+            platform_version,
+        )?;
+
+        let Some(existing_identity_balance) = maybe_existing_identity_balance else {
+            return Ok(ConsensusValidationResult::new_with_error(
+                IdentityNotFoundError::new(self.identity_id()).into(),
+            ));
+        };
+
+        // Overflowing sums are rejected by the basic structure validation
+        let total_amount = self.total_amount().unwrap_or(u64::MAX);
+
+        if existing_identity_balance < total_amount {
+            return Ok(ConsensusValidationResult::new_with_error(
+                IdentityInsufficientBalanceError::new(
+                    self.identity_id(),
+                    existing_identity_balance,
+                    total_amount,
+                )
+                .into(),
+            ));
+        }
+
+        for output in self.outputs() {
+            // Every recipient lookup is a grovedb read that the sender has to pay for
+            execution_context.add_operation(ValidationOperation::RetrieveIdentity(
+                RetrieveIdentityInfo::only_balance(),
+            ));
+
+            let maybe_existing_recipient = platform.drive.fetch_identity_balance(
+                output.recipient_id.to_buffer(),
+                tx,
+                platform_version,
+            )?;
+
+            if maybe_existing_recipient.is_none() {
+                return Ok(ConsensusValidationResult::new_with_error(
+                    IdentityNotFoundError::new(output.recipient_id).into(),
+                ));
+            }
+        }
+
+        self.transform_into_action_v0()
+    }
+
+    fn transform_into_action_v0(
+        &self,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        Ok(ConsensusValidationResult::new_with_data(
+            IdentityCreditBatchTransferTransitionAction::from(self).into(),
+        ))
+    }
+}
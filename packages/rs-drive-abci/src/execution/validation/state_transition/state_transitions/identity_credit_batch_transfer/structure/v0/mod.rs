@@ -0,0 +1,102 @@
+use dpp::consensus::basic::identity::{
+    DuplicatedIdentityCreditTransferRecipientError, IdentityCreditTransferMemoTooLongError,
+    IdentityCreditTransferToSelfError, InvalidIdentityCreditBatchTransferOutputsCountError,
+    InvalidIdentityCreditTransferAmountError,
+};
+
+use crate::error::Error;
+use dpp::consensus::basic::overflow_error::OverflowError;
+use dpp::state_transition::identity_credit_batch_transfer_transition::accessors::IdentityCreditBatchTransferTransitionAccessorsV0;
+use dpp::state_transition::identity_credit_batch_transfer_transition::IdentityCreditBatchTransferTransition;
+use dpp::validation::SimpleConsensusValidationResult;
+use dpp::version::PlatformVersion;
+use std::collections::BTreeSet;
+
+const MIN_TRANSFER_AMOUNT: u64 = 100000;
+
+pub(in crate::execution::validation::state_transition::state_transitions::identity_credit_batch_transfer) trait IdentityCreditBatchTransferStateTransitionStructureValidationV0 {
+    fn validate_basic_structure_v0(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+
+impl IdentityCreditBatchTransferStateTransitionStructureValidationV0
+    for IdentityCreditBatchTransferTransition
+{
+    fn validate_basic_structure_v0(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let result = SimpleConsensusValidationResult::new();
+
+        let max_outputs = platform_version
+            .system_limits
+            .max_outputs_in_credit_batch_transfer;
+        let max_memo_size = platform_version.system_limits.max_credit_transfer_memo_size;
+
+        let outputs = self.outputs();
+
+        if outputs.is_empty() || outputs.len() > max_outputs as usize {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                InvalidIdentityCreditBatchTransferOutputsCountError::new(
+                    outputs.len() as u32,
+                    max_outputs,
+                )
+                .into(),
+            ));
+        }
+
+        let identity_id = self.identity_id();
+        let mut recipients = BTreeSet::new();
+
+        for output in outputs {
+            if output.recipient_id == identity_id {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    IdentityCreditTransferToSelfError::default().into(),
+                ));
+            }
+
+            if !recipients.insert(output.recipient_id) {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    DuplicatedIdentityCreditTransferRecipientError::new(output.recipient_id).into(),
+                ));
+            }
+
+            if output.amount < MIN_TRANSFER_AMOUNT {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    InvalidIdentityCreditTransferAmountError::new(
+                        output.amount,
+                        MIN_TRANSFER_AMOUNT,
+                    )
+                    .into(),
+                ));
+            }
+
+            if let Some(memo) = &output.memo {
+                if memo.len() > max_memo_size as usize {
+                    return Ok(SimpleConsensusValidationResult::new_with_error(
+                        IdentityCreditTransferMemoTooLongError::new(
+                            output.recipient_id,
+                            memo.len() as u32,
+                            max_memo_size,
+                        )
+                        .into(),
+                    ));
+                }
+            }
+        }
+
+        if self.total_amount().is_none() {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                OverflowError::new(
+                    "overflow when adding all output amounts in identity credit batch transfer transition"
+                        .to_string(),
+                )
+                .into(),
+            ));
+        }
+
+        Ok(result)
+    }
+}
@@ -2,7 +2,6 @@ use dpp::consensus::basic::identity::{
     InvalidIdentityCreditWithdrawalTransitionAmountError,
     InvalidIdentityCreditWithdrawalTransitionCoreFeeError,
     InvalidIdentityCreditWithdrawalTransitionOutputScriptError,
-    NotImplementedIdentityCreditWithdrawalTransitionPoolingError,
 };
 use dpp::consensus::ConsensusError;
 
@@ -14,7 +13,6 @@ use dpp::state_transition::identity_credit_withdrawal_transition::v0::{
 use dpp::state_transition::identity_credit_withdrawal_transition::IdentityCreditWithdrawalTransition;
 use dpp::util::is_fibonacci_number::is_fibonacci_number;
 use dpp::validation::SimpleConsensusValidationResult;
-use dpp::withdrawal::Pooling;
 
 pub(in crate::execution::validation::state_transition::state_transitions::identity_credit_withdrawal) trait IdentityCreditWithdrawalStateTransitionStructureValidationV1 {
     fn validate_basic_structure_v1(&self) -> Result<SimpleConsensusValidationResult, Error>;
@@ -35,17 +33,8 @@ impl IdentityCreditWithdrawalStateTransitionStructureValidationV1
             ));
         }
 
-        // currently we do not support pooling, so we must validate that pooling is `Never`
-
-        if self.pooling() != Pooling::Never {
-            result.add_error(
-                NotImplementedIdentityCreditWithdrawalTransitionPoolingError::new(
-                    self.pooling() as u8
-                ),
-            );
-
-            return Ok(result);
-        }
+        // all pooling options are supported, withdrawals are pooled into
+        // shared transactions when the documents are pooled
 
         // validate core_fee is in fibonacci sequence
         if !is_fibonacci_number(self.core_fee_per_byte() as u64) {
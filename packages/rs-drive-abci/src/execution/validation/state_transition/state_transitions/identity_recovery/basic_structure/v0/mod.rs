@@ -0,0 +1,105 @@
+use crate::error::Error;
+use dpp::consensus::basic::identity::{
+    InvalidIdentityRecoveryConfigError, InvalidIdentityRecoveryMasterKeyError,
+};
+use dpp::consensus::ConsensusError;
+use dpp::identity::recovery::{MAX_RECOVERY_DELAY, MAX_RECOVERY_GUARDIANS, MIN_RECOVERY_DELAY};
+use dpp::identity::{Purpose, SecurityLevel};
+use dpp::state_transition::identity_recovery_transition::accessors::IdentityRecoveryTransitionAccessorsV0;
+use dpp::state_transition::identity_recovery_transition::{
+    IdentityRecoveryOperation, IdentityRecoveryTransition,
+};
+use dpp::state_transition::public_key_in_creation::accessors::{
+    IdentityPublicKeyInCreationV0Getters, IdentityPublicKeyInCreationV1Getters,
+};
+use dpp::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
+use dpp::validation::SimpleConsensusValidationResult;
+use dpp::version::PlatformVersion;
+
+pub(in crate::execution::validation::state_transition::state_transitions::identity_recovery) trait IdentityRecoveryStateTransitionStructureValidationV0
+{
+    fn validate_basic_structure_v0(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+
+impl IdentityRecoveryStateTransitionStructureValidationV0 for IdentityRecoveryTransition {
+    fn validate_basic_structure_v0(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let identity_id = self.identity_id();
+
+        match self.operation() {
+            IdentityRecoveryOperation::SetConfig(Some(config)) => {
+                let invalid_config = |reason: String| {
+                    Ok(SimpleConsensusValidationResult::new_with_error(
+                        ConsensusError::from(InvalidIdentityRecoveryConfigError::new(
+                            identity_id,
+                            reason,
+                        )),
+                    ))
+                };
+
+                if config.guardians.len() > MAX_RECOVERY_GUARDIANS {
+                    return invalid_config(format!(
+                        "at most {} guardians can be named, got {}",
+                        MAX_RECOVERY_GUARDIANS,
+                        config.guardians.len()
+                    ));
+                }
+
+                if config.is_guardian(&identity_id) {
+                    return invalid_config("an identity can not be its own guardian".to_string());
+                }
+
+                if config.threshold == 0 || config.threshold as usize > config.guardians.len() {
+                    return invalid_config(format!(
+                        "threshold must be between 1 and the {} guardians, got {}",
+                        config.guardians.len(),
+                        config.threshold
+                    ));
+                }
+
+                if config.delay < MIN_RECOVERY_DELAY || config.delay > MAX_RECOVERY_DELAY {
+                    return invalid_config(format!(
+                        "delay must be between {} and {} ms, got {}",
+                        MIN_RECOVERY_DELAY, MAX_RECOVERY_DELAY, config.delay
+                    ));
+                }
+
+                Ok(SimpleConsensusValidationResult::new())
+            }
+            IdentityRecoveryOperation::Initiate { new_master_key } => {
+                // The recovered key replaces all master keys, it must be usable on its own
+                // and at any time
+                if new_master_key.purpose() != Purpose::AUTHENTICATION
+                    || new_master_key.security_level() != SecurityLevel::MASTER
+                    || new_master_key.contract_bounds().is_some()
+                    || new_master_key.valid_from().is_some()
+                    || new_master_key.valid_until().is_some()
+                    || new_master_key.signature_threshold().is_some()
+                {
+                    return Ok(SimpleConsensusValidationResult::new_with_error(
+                        ConsensusError::from(InvalidIdentityRecoveryMasterKeyError::new(
+                            new_master_key.id(),
+                            new_master_key.purpose(),
+                            new_master_key.security_level(),
+                        )),
+                    ));
+                }
+
+                IdentityPublicKeyInCreation::validate_identity_public_keys_structure(
+                    std::slice::from_ref(new_master_key),
+                    false,
+                    platform_version,
+                )
+                .map_err(Error::Protocol)
+            }
+            IdentityRecoveryOperation::SetConfig(None)
+            | IdentityRecoveryOperation::Cancel
+            | IdentityRecoveryOperation::Complete => Ok(SimpleConsensusValidationResult::new()),
+        }
+    }
+}
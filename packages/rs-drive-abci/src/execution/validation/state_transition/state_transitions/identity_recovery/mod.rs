@@ -0,0 +1,219 @@
+mod basic_structure;
+mod nonce;
+pub(crate) mod signatures;
+mod state;
+
+use dpp::block::block_info::BlockInfo;
+use dpp::state_transition::identity_recovery_transition::IdentityRecoveryTransition;
+use dpp::validation::{ConsensusValidationResult, SimpleConsensusValidationResult};
+use dpp::version::PlatformVersion;
+use drive::state_transition_action::StateTransitionAction;
+
+use drive::grovedb::TransactionArg;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+
+use crate::platform_types::platform::PlatformRef;
+use crate::rpc::core::CoreRPCLike;
+
+use crate::execution::validation::state_transition::identity_recovery::basic_structure::v0::IdentityRecoveryStateTransitionStructureValidationV0;
+use crate::execution::validation::state_transition::identity_recovery::state::v0::IdentityRecoveryStateTransitionStateValidationV0;
+use crate::execution::validation::state_transition::processor::v0::{
+    StateTransitionBasicStructureValidationV0, StateTransitionStateValidationV0,
+};
+
+use crate::execution::validation::state_transition::transformer::StateTransitionActionTransformerV0;
+use crate::execution::validation::state_transition::ValidationMode;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+
+impl StateTransitionActionTransformerV0 for IdentityRecoveryTransition {
+    fn transform_into_action<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
+        _validation_mode: ValidationMode,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version = platform.state.current_platform_version()?;
+
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .identity_recovery_state_transition
+            .as_ref()
+            .map(|versions| versions.transform_into_action)
+        {
+            // What the recovery does depends on the pending recovery and the keys in state,
+            // so the action can only be resolved while validating the state
+            Some(0) => self.validate_state_v0(
+                platform,
+                block_info,
+                execution_context,
+                tx,
+                platform_version,
+            ),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "identity recovery transition: transform_into_action".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "identity recovery transition: transform_into_action".to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
+
+impl StateTransitionBasicStructureValidationV0 for IdentityRecoveryTransition {
+    fn validate_basic_structure(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .identity_recovery_state_transition
+            .as_ref()
+            .and_then(|versions| versions.basic_structure)
+        {
+            Some(0) => self.validate_basic_structure_v0(platform_version),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "identity recovery transition: validate_basic_structure".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "identity recovery transition: validate_basic_structure".to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
+
+impl StateTransitionStateValidationV0 for IdentityRecoveryTransition {
+    fn validate_state<C: CoreRPCLike>(
+        &self,
+        action: Option<StateTransitionAction>,
+        platform: &PlatformRef<C>,
+        _validation_mode: ValidationMode,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version = platform.state.current_platform_version()?;
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .identity_recovery_state_transition
+            .as_ref()
+            .map(|versions| versions.state)
+        {
+            Some(0) => {
+                if action.is_some() {
+                    return Err(Error::Execution(ExecutionError::CorruptedCodeExecution("identity recovery is calling validate state, and the action is already known. It should not be known at this point")));
+                }
+                self.validate_state_v0(
+                    platform,
+                    block_info,
+                    execution_context,
+                    tx,
+                    platform_version,
+                )
+            }
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "identity recovery transition: validate_state".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "identity recovery transition: validate_state".to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execution::validation::state_transition::tests::{
+        process_state_transition_in_current_version, setup_identity,
+        setup_identity_return_master_key,
+    };
+    use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
+    use dpp::consensus::basic::BasicError;
+    use dpp::consensus::ConsensusError;
+    use dpp::dash_to_credits;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::recovery::{IdentityRecoveryConfig, MIN_RECOVERY_DELAY};
+    use dpp::state_transition::identity_recovery_transition::methods::IdentityRecoveryTransitionMethodsV0;
+    use dpp::state_transition::identity_recovery_transition::{
+        IdentityRecoveryOperation, IdentityRecoveryTransition,
+    };
+    use dpp::state_transition::StateTransition;
+    use dpp::version::ProtocolVersion;
+    use platform_version::version::PlatformVersion;
+    use std::collections::BTreeSet;
+
+    /// Start a platform on `protocol_version` and have an identity name a guardian for its recovery
+    fn setup_recovery_config(
+        protocol_version: ProtocolVersion,
+    ) -> (TempPlatform<MockCoreRPCLike>, StateTransition) {
+        let mut platform = TestPlatformBuilder::new()
+            .with_initial_protocol_version(protocol_version)
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let (identity, signer, master_key) =
+            setup_identity_return_master_key(&mut platform, 958, dash_to_credits!(0.1));
+
+        let (guardian, _, _) = setup_identity(&mut platform, 959, dash_to_credits!(0.1));
+
+        let config = IdentityRecoveryConfig {
+            guardians: BTreeSet::from([guardian.id()]),
+            threshold: 1,
+            delay: MIN_RECOVERY_DELAY,
+        };
+
+        let state_transition = IdentityRecoveryTransition::try_from_identity_id(
+            identity.id(),
+            IdentityRecoveryOperation::SetConfig(Some(config)),
+            &master_key,
+            1,
+            0,
+            &signer,
+            PlatformVersion::latest(),
+            None,
+        )
+        .expect("expected an identity recovery transition");
+
+        (platform, state_transition)
+    }
+
+    #[test]
+    fn test_identity_recovery_config_is_only_processed_from_protocol_version_2() {
+        let (platform, state_transition) = setup_recovery_config(1);
+
+        assert!(matches!(
+            process_state_transition_in_current_version(&platform, &state_transition),
+            StateTransitionExecutionResult::UnpaidConsensusError(ConsensusError::BasicError(
+                BasicError::UnsupportedFeatureError(_)
+            ))
+        ));
+
+        let (platform, state_transition) = setup_recovery_config(2);
+
+        assert!(matches!(
+            process_state_transition_in_current_version(&platform, &state_transition),
+            StateTransitionExecutionResult::SuccessfulExecution(..)
+        ));
+    }
+}
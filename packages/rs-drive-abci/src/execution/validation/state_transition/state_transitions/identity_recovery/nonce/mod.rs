@@ -0,0 +1,49 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+use crate::execution::validation::state_transition::identity_recovery::nonce::v0::IdentityRecoveryTransitionIdentityContractNonceV0;
+use crate::execution::validation::state_transition::processor::v0::StateTransitionNonceValidationV0;
+use crate::platform_types::platform::PlatformStateRef;
+use dpp::block::block_info::BlockInfo;
+use dpp::state_transition::identity_recovery_transition::IdentityRecoveryTransition;
+use dpp::validation::SimpleConsensusValidationResult;
+use dpp::version::PlatformVersion;
+use drive::grovedb::TransactionArg;
+
+pub(crate) mod v0;
+impl StateTransitionNonceValidationV0 for IdentityRecoveryTransition {
+    fn validate_nonces(
+        &self,
+        platform: &PlatformStateRef,
+        block_info: &BlockInfo,
+        tx: TransactionArg,
+        execution_context: &mut StateTransitionExecutionContext,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .identity_recovery_state_transition
+            .as_ref()
+            .and_then(|versions| versions.nonce)
+        {
+            Some(0) => self.validate_nonce_v0(
+                platform,
+                block_info,
+                tx,
+                execution_context,
+                platform_version,
+            ),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "identity recovery transition: validate_nonces".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "identity recovery transition: validate_nonces".to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
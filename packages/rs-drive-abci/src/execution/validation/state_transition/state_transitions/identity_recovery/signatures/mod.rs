@@ -0,0 +1,70 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+use crate::execution::validation::state_transition::identity_recovery::signatures::v0::IdentityRecoveryStateTransitionSignaturesValidationV0;
+use dpp::block::block_info::BlockInfo;
+use dpp::identity::PartialIdentity;
+use dpp::state_transition::identity_recovery_transition::IdentityRecoveryTransition;
+use dpp::validation::ConsensusValidationResult;
+use dpp::version::PlatformVersion;
+use drive::drive::Drive;
+use drive::grovedb::TransactionArg;
+
+pub(crate) mod v0;
+
+pub(in crate::execution::validation::state_transition) trait IdentityRecoveryStateTransitionSignaturesValidation
+{
+    /// Validates the signatures of the recovery operations that are not signed by a current
+    /// master key of the identity: the guardian signatures of an initiation, and the signature
+    /// of the pending master key on completion.
+    fn validate_identity_recovery_signatures(
+        &self,
+        signable_bytes: Vec<u8>,
+        drive: &Drive,
+        block_info: &BlockInfo,
+        tx: TransactionArg,
+        execution_context: &mut StateTransitionExecutionContext,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<PartialIdentity>, Error>;
+}
+
+impl IdentityRecoveryStateTransitionSignaturesValidation for IdentityRecoveryTransition {
+    fn validate_identity_recovery_signatures(
+        &self,
+        signable_bytes: Vec<u8>,
+        drive: &Drive,
+        block_info: &BlockInfo,
+        tx: TransactionArg,
+        execution_context: &mut StateTransitionExecutionContext,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<PartialIdentity>, Error> {
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .identity_recovery_state_transition
+            .as_ref()
+            .and_then(|versions| versions.identity_signatures)
+        {
+            Some(0) => self.validate_identity_recovery_signatures_v0(
+                signable_bytes,
+                drive,
+                block_info,
+                tx,
+                execution_context,
+                platform_version,
+            ),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "identity recovery transition: validate_identity_recovery_signatures"
+                    .to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "identity recovery transition: validate_identity_recovery_signatures"
+                    .to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
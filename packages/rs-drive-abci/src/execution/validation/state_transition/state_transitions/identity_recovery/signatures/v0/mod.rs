@@ -0,0 +1,287 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::execution::types::execution_operation::signature_verification_operation::SignatureVerificationOperation;
+use crate::execution::types::execution_operation::{RetrieveIdentityInfo, ValidationOperation};
+use crate::execution::types::state_transition_execution_context::{
+    StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0,
+};
+use dpp::block::block_info::BlockInfo;
+use dpp::consensus::signature::{
+    IdentityNotFoundError, InvalidGuardianSignatureError, InvalidStateTransitionSignatureError,
+    NotEnoughGuardianSignaturesError, SignatureError,
+};
+use dpp::consensus::state::identity::identity_recovery_not_configured_error::IdentityRecoveryNotConfiguredError;
+use dpp::consensus::state::identity::no_pending_identity_recovery_error::NoPendingIdentityRecoveryError;
+use dpp::consensus::ConsensusError;
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::identity_public_key::accessors::v1::IdentityPublicKeyGettersV1;
+use dpp::identity::{PartialIdentity, Purpose, SecurityLevel};
+use dpp::prelude::Identifier;
+use dpp::serialization::PlatformMessageSignable;
+use dpp::state_transition::identity_recovery_transition::accessors::IdentityRecoveryTransitionAccessorsV0;
+use dpp::state_transition::identity_recovery_transition::{
+    IdentityRecoveryOperation, IdentityRecoveryTransition,
+};
+use dpp::state_transition::{StateTransitionIdentitySigned, StateTransitionLike};
+use dpp::validation::ConsensusValidationResult;
+use dpp::version::PlatformVersion;
+use drive::drive::identity::key::fetch::{IdentityKeysRequest, KeyIDIdentityPublicKeyPairBTreeMap};
+use drive::drive::Drive;
+use drive::grovedb::TransactionArg;
+
+pub(in crate::execution::validation::state_transition::state_transitions::identity_recovery) trait IdentityRecoveryStateTransitionSignaturesValidationV0
+{
+    fn validate_identity_recovery_signatures_v0(
+        &self,
+        signable_bytes: Vec<u8>,
+        drive: &Drive,
+        block_info: &BlockInfo,
+        tx: TransactionArg,
+        execution_context: &mut StateTransitionExecutionContext,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<PartialIdentity>, Error>;
+}
+
+impl IdentityRecoveryStateTransitionSignaturesValidationV0 for IdentityRecoveryTransition {
+    fn validate_identity_recovery_signatures_v0(
+        &self,
+        signable_bytes: Vec<u8>,
+        drive: &Drive,
+        block_info: &BlockInfo,
+        tx: TransactionArg,
+        execution_context: &mut StateTransitionExecutionContext,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<PartialIdentity>, Error> {
+        let identity_id = self.identity_id();
+
+        let mut drive_operations = vec![];
+
+        match self.operation() {
+            IdentityRecoveryOperation::Initiate { .. } => {
+                let Some(config) = drive.fetch_identity_recovery_config(
+                    identity_id.to_buffer(),
+                    tx,
+                    &mut drive_operations,
+                    platform_version,
+                )?
+                else {
+                    return Ok(ConsensusValidationResult::new_with_error(
+                        IdentityRecoveryNotConfiguredError::new(identity_id).into(),
+                    ));
+                };
+
+                execution_context.add_operation(ValidationOperation::RetrieveIdentity(
+                    RetrieveIdentityInfo::keys(self.guardian_signatures().len() as u16),
+                ));
+
+                // Every guardian signature must be valid, a signature that doesn't count would
+                // otherwise hide a guardian that was impersonated
+                for (guardian_id, guardian_signature) in self.guardian_signatures() {
+                    let invalid_guardian_signature = |reason: &str| {
+                        ConsensusValidationResult::new_with_error(ConsensusError::from(
+                            InvalidGuardianSignatureError::new(
+                                *guardian_id,
+                                guardian_signature.public_key_id,
+                                reason.to_string(),
+                            ),
+                        ))
+                    };
+
+                    if !config.is_guardian(guardian_id) {
+                        return Ok(invalid_guardian_signature(
+                            "identity is not a guardian of the recovered identity",
+                        ));
+                    }
+
+                    let guardian_keys: KeyIDIdentityPublicKeyPairBTreeMap = drive
+                        .fetch_identity_keys(
+                            IdentityKeysRequest::new_specific_key_query(
+                                guardian_id.as_bytes(),
+                                guardian_signature.public_key_id,
+                            ),
+                            tx,
+                            platform_version,
+                        )?;
+
+                    let Some(guardian_key) = guardian_keys.get(&guardian_signature.public_key_id)
+                    else {
+                        return Ok(invalid_guardian_signature("public key not found"));
+                    };
+
+                    if guardian_key.purpose() != Purpose::AUTHENTICATION
+                        || !matches!(
+                            guardian_key.security_level(),
+                            SecurityLevel::MASTER | SecurityLevel::CRITICAL
+                        )
+                    {
+                        return Ok(invalid_guardian_signature(
+                            "guardians must sign with a master or critical authentication key",
+                        ));
+                    }
+
+                    if guardian_key.is_disabled()
+                        || !guardian_key.is_within_validity_window(block_info.time_ms)
+                    {
+                        return Ok(invalid_guardian_signature("public key is not usable"));
+                    }
+
+                    if guardian_key.signature_threshold().is_some() {
+                        return Ok(invalid_guardian_signature(
+                            "threshold keys can not approve a recovery on their own",
+                        ));
+                    }
+
+                    execution_context.add_operation(ValidationOperation::SignatureVerification(
+                        SignatureVerificationOperation::new(guardian_key.key_type()),
+                    ));
+
+                    if !signable_bytes
+                        .as_slice()
+                        .verify_signature(
+                            guardian_key.key_type(),
+                            guardian_key.data().as_slice(),
+                            guardian_signature.signature.as_slice(),
+                        )
+                        .is_valid()
+                    {
+                        return Ok(invalid_guardian_signature("signature is not valid"));
+                    }
+                }
+
+                let provided = self.guardian_signatures().len();
+                if provided < config.threshold as usize {
+                    return Ok(ConsensusValidationResult::new_with_error(
+                        NotEnoughGuardianSignaturesError::new(
+                            identity_id,
+                            config.threshold,
+                            provided as u8,
+                        )
+                        .into(),
+                    ));
+                }
+
+                let fee = Drive::calculate_fee(
+                    None,
+                    Some(drive_operations),
+                    &block_info.epoch,
+                    drive.config.epochs_per_era,
+                    platform_version,
+                    None,
+                )?;
+                execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee));
+
+                fetch_partial_identity(
+                    identity_id,
+                    false,
+                    drive,
+                    tx,
+                    execution_context,
+                    platform_version,
+                )
+            }
+            IdentityRecoveryOperation::Complete => {
+                let Some(pending_recovery) = drive.fetch_identity_pending_recovery(
+                    identity_id.to_buffer(),
+                    tx,
+                    &mut drive_operations,
+                    platform_version,
+                )?
+                else {
+                    return Ok(ConsensusValidationResult::new_with_error(
+                        NoPendingIdentityRecoveryError::new(identity_id).into(),
+                    ));
+                };
+
+                let new_master_key = &pending_recovery.new_master_key;
+
+                if self.signature_public_key_id() != new_master_key.id() {
+                    return Ok(ConsensusValidationResult::new_with_error(
+                        SignatureError::InvalidStateTransitionSignatureError(
+                            InvalidStateTransitionSignatureError::new(format!(
+                                "a recovery must be completed with the pending master key {}",
+                                new_master_key.id()
+                            )),
+                        )
+                        .into(),
+                    ));
+                }
+
+                execution_context.add_operation(ValidationOperation::SignatureVerification(
+                    SignatureVerificationOperation::new(new_master_key.key_type()),
+                ));
+
+                let signature_result = signable_bytes.as_slice().verify_signature(
+                    new_master_key.key_type(),
+                    new_master_key.data().as_slice(),
+                    self.signature().as_slice(),
+                );
+
+                if !signature_result.is_valid() {
+                    return Ok(ConsensusValidationResult::new_with_errors(
+                        signature_result.errors,
+                    ));
+                }
+
+                let fee = Drive::calculate_fee(
+                    None,
+                    Some(drive_operations),
+                    &block_info.epoch,
+                    drive.config.epochs_per_era,
+                    platform_version,
+                    None,
+                )?;
+                execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee));
+
+                fetch_partial_identity(
+                    identity_id,
+                    true,
+                    drive,
+                    tx,
+                    execution_context,
+                    platform_version,
+                )
+            }
+            IdentityRecoveryOperation::SetConfig(_) | IdentityRecoveryOperation::Cancel => {
+                Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                    "identity recovery operations signed by the owner use the identity signature validation",
+                )))
+            }
+        }
+    }
+}
+
+/// The identity being recovered pays for the recovery, although none of its keys signed it
+fn fetch_partial_identity(
+    identity_id: Identifier,
+    request_revision: bool,
+    drive: &Drive,
+    tx: TransactionArg,
+    execution_context: &mut StateTransitionExecutionContext,
+    platform_version: &PlatformVersion,
+) -> Result<ConsensusValidationResult<PartialIdentity>, Error> {
+    execution_context.add_operation(ValidationOperation::RetrieveIdentity(
+        RetrieveIdentityInfo::only_balance(),
+    ));
+
+    let Some(balance) =
+        drive.fetch_identity_balance(identity_id.to_buffer(), tx, platform_version)?
+    else {
+        return Ok(ConsensusValidationResult::new_with_error(
+            SignatureError::IdentityNotFoundError(IdentityNotFoundError::new(identity_id)).into(),
+        ));
+    };
+
+    let revision = if request_revision {
+        drive.fetch_identity_revision(identity_id.to_buffer(), true, tx, platform_version)?
+    } else {
+        None
+    };
+
+    Ok(ConsensusValidationResult::new_with_data(PartialIdentity {
+        id: identity_id,
+        loaded_public_keys: Default::default(),
+        balance: Some(balance),
+        revision,
+        not_found_public_keys: Default::default(),
+    }))
+}
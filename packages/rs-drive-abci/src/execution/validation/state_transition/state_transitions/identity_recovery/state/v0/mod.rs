@@ -0,0 +1,321 @@
+use crate::error::Error;
+
+use crate::platform_types::platform::PlatformRef;
+use crate::rpc::core::CoreRPCLike;
+
+use dpp::block::block_info::BlockInfo;
+use dpp::consensus::signature::{IdentityNotFoundError, SignatureError};
+use dpp::consensus::state::identity::identity_recovery_already_pending_error::IdentityRecoveryAlreadyPendingError;
+use dpp::consensus::state::identity::identity_recovery_delay_not_elapsed_error::IdentityRecoveryDelayNotElapsedError;
+use dpp::consensus::state::identity::identity_recovery_guardian_not_found_error::IdentityRecoveryGuardianNotFoundError;
+use dpp::consensus::state::identity::identity_recovery_not_configured_error::IdentityRecoveryNotConfiguredError;
+use dpp::consensus::state::identity::no_pending_identity_recovery_error::NoPendingIdentityRecoveryError;
+use dpp::consensus::ConsensusError;
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::recovery::PendingIdentityRecovery;
+use dpp::identity::{KeyID, Purpose, SecurityLevel};
+use dpp::prelude::ConsensusValidationResult;
+use dpp::state_transition::identity_recovery_transition::accessors::IdentityRecoveryTransitionAccessorsV0;
+use dpp::state_transition::identity_recovery_transition::{
+    IdentityRecoveryOperation, IdentityRecoveryTransition,
+};
+use dpp::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
+use dpp::version::PlatformVersion;
+use drive::drive::identity::key::fetch::{IdentityKeysRequest, KeyIDIdentityPublicKeyPairBTreeMap};
+use drive::drive::Drive;
+use drive::grovedb::TransactionArg;
+use drive::state_transition_action::identity::identity_recovery::v0::IdentityRecoveryActionOperation;
+use drive::state_transition_action::identity::identity_recovery::IdentityRecoveryTransitionAction;
+use drive::state_transition_action::system::bump_identity_nonce_action::BumpIdentityNonceAction;
+use drive::state_transition_action::StateTransitionAction;
+
+use crate::execution::types::execution_operation::{RetrieveIdentityInfo, ValidationOperation};
+use crate::execution::types::state_transition_execution_context::{
+    StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0,
+};
+use crate::execution::validation::state_transition::common::validate_identity_public_key_ids_dont_exist_in_state::validate_identity_public_key_ids_dont_exist_in_state;
+use crate::execution::validation::state_transition::common::validate_unique_identity_public_key_hashes_in_state::validate_unique_identity_public_key_hashes_not_in_state;
+
+pub(in crate::execution::validation::state_transition::state_transitions::identity_recovery) trait IdentityRecoveryStateTransitionStateValidationV0
+{
+    fn validate_state_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+
+    fn transform_into_action_v0(
+        &self,
+        operation: IdentityRecoveryActionOperation,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+}
+
+impl IdentityRecoveryStateTransitionStateValidationV0 for IdentityRecoveryTransition {
+    fn validate_state_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let drive = platform.drive;
+        let identity_id = self.identity_id();
+
+        let mut drive_operations = vec![];
+
+        let operation = match self.operation() {
+            IdentityRecoveryOperation::SetConfig(config) => {
+                if let Some(config) = config {
+                    for guardian_id in config.guardians.iter() {
+                        execution_context.add_operation(ValidationOperation::RetrieveIdentity(
+                            RetrieveIdentityInfo::only_balance(),
+                        ));
+                        if drive
+                            .fetch_identity_balance(guardian_id.to_buffer(), tx, platform_version)?
+                            .is_none()
+                        {
+                            return Ok(bump_nonce_with_error(
+                                self,
+                                IdentityRecoveryGuardianNotFoundError::new(
+                                    identity_id,
+                                    *guardian_id,
+                                )
+                                .into(),
+                            ));
+                        }
+                    }
+                }
+                IdentityRecoveryActionOperation::SetConfig(config.clone())
+            }
+            IdentityRecoveryOperation::Initiate { new_master_key } => {
+                // The configuration could have changed since the signatures were validated
+                let Some(config) = drive.fetch_identity_recovery_config(
+                    identity_id.to_buffer(),
+                    tx,
+                    &mut drive_operations,
+                    platform_version,
+                )?
+                else {
+                    return Ok(bump_nonce_with_error(
+                        self,
+                        IdentityRecoveryNotConfiguredError::new(identity_id).into(),
+                    ));
+                };
+
+                if let Some(pending_recovery) = drive.fetch_identity_pending_recovery(
+                    identity_id.to_buffer(),
+                    tx,
+                    &mut drive_operations,
+                    platform_version,
+                )? {
+                    return Ok(bump_nonce_with_error(
+                        self,
+                        IdentityRecoveryAlreadyPendingError::new(
+                            identity_id,
+                            pending_recovery.executable_at,
+                        )
+                        .into(),
+                    ));
+                }
+
+                let new_master_keys = std::slice::from_ref(new_master_key);
+                if let Some(error) = validate_new_master_key_in_state(
+                    self,
+                    new_master_keys,
+                    drive,
+                    execution_context,
+                    tx,
+                    platform_version,
+                )? {
+                    return Ok(bump_nonce_with_error(self, error));
+                }
+
+                IdentityRecoveryActionOperation::Initiate(PendingIdentityRecovery {
+                    new_master_key: new_master_key.clone().into(),
+                    approved_by: self.guardian_signatures().keys().copied().collect(),
+                    initiated_at: block_info.time_ms,
+                    executable_at: block_info.time_ms.saturating_add(config.delay),
+                })
+            }
+            IdentityRecoveryOperation::Cancel => {
+                if drive
+                    .fetch_identity_pending_recovery(
+                        identity_id.to_buffer(),
+                        tx,
+                        &mut drive_operations,
+                        platform_version,
+                    )?
+                    .is_none()
+                {
+                    return Ok(bump_nonce_with_error(
+                        self,
+                        NoPendingIdentityRecoveryError::new(identity_id).into(),
+                    ));
+                }
+                IdentityRecoveryActionOperation::Cancel
+            }
+            IdentityRecoveryOperation::Complete => {
+                let Some(pending_recovery) = drive.fetch_identity_pending_recovery(
+                    identity_id.to_buffer(),
+                    tx,
+                    &mut drive_operations,
+                    platform_version,
+                )?
+                else {
+                    return Ok(bump_nonce_with_error(
+                        self,
+                        NoPendingIdentityRecoveryError::new(identity_id).into(),
+                    ));
+                };
+
+                if !pending_recovery.is_executable_at(block_info.time_ms) {
+                    return Ok(bump_nonce_with_error(
+                        self,
+                        IdentityRecoveryDelayNotElapsedError::new(
+                            identity_id,
+                            pending_recovery.executable_at,
+                            block_info.time_ms,
+                        )
+                        .into(),
+                    ));
+                }
+
+                // Keys could have been added by the owner during the delay
+                let new_master_keys = [IdentityPublicKeyInCreation::from(
+                    &pending_recovery.new_master_key,
+                )];
+                if let Some(error) = validate_new_master_key_in_state(
+                    self,
+                    &new_master_keys,
+                    drive,
+                    execution_context,
+                    tx,
+                    platform_version,
+                )? {
+                    return Ok(bump_nonce_with_error(self, error));
+                }
+
+                let identity_keys: KeyIDIdentityPublicKeyPairBTreeMap = drive.fetch_identity_keys(
+                    IdentityKeysRequest::new_all_keys_query(&identity_id.to_buffer(), None),
+                    tx,
+                    platform_version,
+                )?;
+
+                execution_context.add_operation(ValidationOperation::RetrieveIdentity(
+                    RetrieveIdentityInfo::keys(identity_keys.len() as u16),
+                ));
+
+                let master_keys_to_disable: Vec<KeyID> = identity_keys
+                    .values()
+                    .filter(|key| {
+                        key.purpose() == Purpose::AUTHENTICATION
+                            && key.security_level() == SecurityLevel::MASTER
+                            && !key.is_disabled()
+                    })
+                    .map(|key| key.id())
+                    .collect();
+
+                execution_context.add_operation(ValidationOperation::RetrieveIdentity(
+                    RetrieveIdentityInfo::one_key_and_revision(),
+                ));
+
+                let Some(revision) = drive.fetch_identity_revision(
+                    identity_id.to_buffer(),
+                    true,
+                    tx,
+                    platform_version,
+                )?
+                else {
+                    return Ok(bump_nonce_with_error(
+                        self,
+                        SignatureError::IdentityNotFoundError(IdentityNotFoundError::new(
+                            identity_id,
+                        ))
+                        .into(),
+                    ));
+                };
+
+                IdentityRecoveryActionOperation::Complete {
+                    new_master_key: pending_recovery.new_master_key,
+                    master_keys_to_disable,
+                    revision: revision + 1,
+                }
+            }
+        };
+
+        if !drive_operations.is_empty() {
+            let fee = Drive::calculate_fee(
+                None,
+                Some(drive_operations),
+                &block_info.epoch,
+                drive.config.epochs_per_era,
+                platform_version,
+                None,
+            )?;
+            execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee));
+        }
+
+        self.transform_into_action_v0(operation)
+    }
+
+    fn transform_into_action_v0(
+        &self,
+        operation: IdentityRecoveryActionOperation,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let mut validation_result = ConsensusValidationResult::<StateTransitionAction>::default();
+
+        validation_result.set_data(
+            IdentityRecoveryTransitionAction::from_transition_with_operation(self, operation)
+                .into(),
+        );
+        Ok(validation_result)
+    }
+}
+
+/// The recovered master key must not collide with a key of the identity or a unique key of
+/// another identity
+fn validate_new_master_key_in_state(
+    transition: &IdentityRecoveryTransition,
+    new_master_keys: &[IdentityPublicKeyInCreation],
+    drive: &Drive,
+    execution_context: &mut StateTransitionExecutionContext,
+    tx: TransactionArg,
+    platform_version: &PlatformVersion,
+) -> Result<Option<ConsensusError>, Error> {
+    let result = validate_unique_identity_public_key_hashes_not_in_state(
+        new_master_keys,
+        drive,
+        execution_context,
+        tx,
+        platform_version,
+    )?;
+    if let Some(error) = result.errors.into_iter().next() {
+        return Ok(Some(error));
+    }
+
+    let result = validate_identity_public_key_ids_dont_exist_in_state(
+        transition.identity_id(),
+        new_master_keys,
+        drive,
+        tx,
+        execution_context,
+        platform_version,
+    )?;
+    Ok(result.errors.into_iter().next())
+}
+
+/// Keeps the transition to bump the identity nonce, failing with `error`
+fn bump_nonce_with_error(
+    transition: &IdentityRecoveryTransition,
+    error: ConsensusError,
+) -> ConsensusValidationResult<StateTransitionAction> {
+    let bump_action = StateTransitionAction::BumpIdentityNonceAction(
+        BumpIdentityNonceAction::from_borrowed_identity_recovery_transition(transition),
+    );
+
+    ConsensusValidationResult::new_with_data_and_errors(bump_action, vec![error])
+}
@@ -14,6 +14,7 @@ use crate::execution::types::state_transition_execution_context::{
     StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0,
 };
 use dpp::version::PlatformVersion;
+use dpp::voting::votes::Vote;
 use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
 
@@ -45,11 +46,11 @@ impl MasternodeVoteTransitionBalanceValidationV0 for MasternodeVoteTransition {
 
         let vote = self.vote();
 
-        let balance_id = vote.specialized_balance_id()?.ok_or(Error::Execution(
-            ExecutionError::CorruptedCodeExecution(
+        let Some(balance_id) = vote.specialized_balance_id()? else {
+            return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
                 "In this version there should always be a specialized balance id",
-            ),
-        ))?;
+            )));
+        };
         let maybe_balance = drive.fetch_prefunded_specialized_balance(
             balance_id.to_buffer(),
             tx,
@@ -57,6 +58,11 @@ impl MasternodeVoteTransitionBalanceValidationV0 for MasternodeVoteTransition {
         )?;
 
         let Some(balance) = maybe_balance else {
+            if matches!(vote, Vote::GovernanceProposalVote(_)) {
+                // The first vote on a governance proposal opens its vote poll and is paid by the
+                // voter identity, the state validation makes sure it can afford it
+                return Ok(ConsensusValidationResult::new_with_data(BTreeMap::new()));
+            }
             // If there is no balance we are voting on something that either was never created or has finished
             return Ok(ConsensusValidationResult::new_with_error(
                 PrefundedSpecializedBalanceNotFoundError::new(balance_id).into(),
@@ -0,0 +1,43 @@
+use crate::error::Error;
+use crate::execution::validation::state_transition::processor::v0::StateTransitionIsAllowedValidationV0;
+use crate::platform_types::platform::PlatformRef;
+use dpp::consensus::basic::UnsupportedFeatureError;
+use dpp::state_transition::masternode_vote_transition::accessors::MasternodeVoteTransitionAccessorsV0;
+use dpp::state_transition::masternode_vote_transition::MasternodeVoteTransition;
+use dpp::validation::ConsensusValidationResult;
+use dpp::version::PlatformVersion;
+use dpp::voting::votes::Vote;
+
+impl StateTransitionIsAllowedValidationV0 for MasternodeVoteTransition {
+    fn has_is_allowed_validation(
+        &self,
+        _platform_version: &PlatformVersion,
+    ) -> Result<bool, Error> {
+        Ok(matches!(self.vote(), Vote::GovernanceProposalVote(_)))
+    }
+
+    /// Governance proposals can only be voted on from the protocol version that tallies them
+    fn validate_is_allowed<C>(
+        &self,
+        _platform: &PlatformRef<C>,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<()>, Error> {
+        if platform_version
+            .drive_abci
+            .methods
+            .voting
+            .tally_votes_for_governance_proposal_vote_poll
+            .is_some()
+        {
+            return Ok(ConsensusValidationResult::new());
+        }
+
+        Ok(ConsensusValidationResult::new_with_error(
+            UnsupportedFeatureError::new(
+                "governance proposal votes".to_string(),
+                platform_version.protocol_version,
+            )
+            .into(),
+        ))
+    }
+}
@@ -1,5 +1,6 @@
 mod advanced_structure;
 mod balance;
+mod is_allowed;
 mod nonce;
 mod state;
 mod transform_into_action;
@@ -59,7 +60,7 @@ impl StateTransitionStateValidationV0 for MasternodeVoteTransition {
         action: Option<StateTransitionAction>,
         platform: &PlatformRef<C>,
         _validation_mode: ValidationMode,
-        _block_info: &BlockInfo,
+        block_info: &BlockInfo,
         _execution_context: &mut StateTransitionExecutionContext,
         tx: TransactionArg,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
@@ -71,7 +72,7 @@ impl StateTransitionStateValidationV0 for MasternodeVoteTransition {
             .masternode_vote_state_transition
             .state
         {
-            0 => self.validate_state_v0(action, platform, tx, platform_version),
+            0 => self.validate_state_v0(action, platform, block_info, tx, platform_version),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "masternode votes state transition: validate_state".to_string(),
                 known_versions: vec![0],
@@ -8025,5 +8026,289 @@ mod tests {
                 }
             }
         }
+        mod governance_proposal_votes {
+            use super::*;
+            use crate::execution::validation::state_transition::state_transitions::tests::process_state_transition_in_current_version;
+            use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+            use assert_matches::assert_matches;
+            use dpp::consensus::basic::BasicError;
+            use dpp::consensus::state::state_error::StateError;
+            use dpp::consensus::ConsensusError;
+            use dpp::identity::IdentityPublicKey;
+            use dpp::prelude::IdentityNonce;
+            use dpp::state_transition::masternode_vote_transition::methods::MasternodeVoteTransitionMethodsV0;
+            use dpp::state_transition::masternode_vote_transition::MasternodeVoteTransition;
+            use dpp::state_transition::StateTransition;
+            use dpp::version::ProtocolVersion;
+            use dpp::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+            use dpp::voting::vote_polls::governance_proposal_vote_poll::{
+                GovernanceProposal, GovernanceProposalVotePoll,
+            };
+            use dpp::voting::votes::governance_proposal_vote::v0::GovernanceProposalVoteV0;
+            use dpp::voting::votes::governance_proposal_vote::GovernanceProposalVote;
+            use dpp::voting::votes::Vote;
+            use simple_signer::signer::SimpleSigner;
+
+            /// Two days after the default block time, within the allowed vote poll duration
+            const END_DATE: u64 = 172_800_000;
+
+            fn max_state_transition_size_proposal(value: u64) -> GovernanceProposalVotePoll {
+                GovernanceProposalVotePoll {
+                    proposal: GovernanceProposal::ParameterChange {
+                        parameter: "maxStateTransitionSize".to_string(),
+                        value: value.into(),
+                    },
+                    description_hash: [5; 32],
+                    end_date: END_DATE,
+                }
+            }
+
+            fn governance_proposal_vote_transition(
+                vote_poll: GovernanceProposalVotePoll,
+                signer: &SimpleSigner,
+                pro_tx_hash: Identifier,
+                voting_key: &IdentityPublicKey,
+                nonce: IdentityNonce,
+                platform_version: &PlatformVersion,
+            ) -> StateTransition {
+                let vote = Vote::GovernanceProposalVote(GovernanceProposalVote::V0(
+                    GovernanceProposalVoteV0 {
+                        vote_poll,
+                        vote_choice: YesNoAbstainVoteChoice::YES,
+                    },
+                ));
+
+                MasternodeVoteTransition::try_from_vote_with_signer(
+                    vote,
+                    signer,
+                    pro_tx_hash,
+                    voting_key,
+                    nonce,
+                    platform_version,
+                    None,
+                )
+                .expect("expected to make transition vote")
+            }
+
+            /// Sets up an evonode whose voting identity holds the given credits
+            fn setup_funded_masternode_voting_identity(
+                platform: &mut TempPlatform<MockCoreRPCLike>,
+                seed: u64,
+                credits: Credits,
+                platform_version: &PlatformVersion,
+            ) -> (Identifier, SimpleSigner, IdentityPublicKey) {
+                let (pro_tx_hash, voting_identity, signer, voting_key) =
+                    setup_masternode_voting_identity(platform, seed, platform_version);
+
+                platform
+                    .drive
+                    .add_to_identity_balance(
+                        voting_identity.id().to_buffer(),
+                        credits,
+                        &BlockInfo::default(),
+                        true,
+                        None,
+                        platform_version,
+                    )
+                    .expect("expected to fund the voting identity");
+
+                (pro_tx_hash, signer, voting_key)
+            }
+
+            fn setup_platform(protocol_version: ProtocolVersion) -> TempPlatform<MockCoreRPCLike> {
+                TestPlatformBuilder::new()
+                    .with_initial_protocol_version(protocol_version)
+                    .build_with_mock_rpc()
+                    .set_initial_state_structure()
+            }
+
+            #[test]
+            fn test_governance_proposal_vote_is_only_processed_from_protocol_version_2() {
+                let mut platform = setup_platform(1);
+                let platform_version = PlatformVersion::get(1).expect("expected version 1");
+
+                let (pro_tx_hash, signer, voting_key) = setup_funded_masternode_voting_identity(
+                    &mut platform,
+                    10,
+                    dash_to_credits!(2.0),
+                    platform_version,
+                );
+
+                let state_transition = governance_proposal_vote_transition(
+                    max_state_transition_size_proposal(20480),
+                    &signer,
+                    pro_tx_hash,
+                    &voting_key,
+                    1,
+                    platform_version,
+                );
+
+                assert_matches!(
+                    process_state_transition_in_current_version(&platform, &state_transition),
+                    StateTransitionExecutionResult::UnpaidConsensusError(
+                        ConsensusError::BasicError(BasicError::UnsupportedFeatureError(_))
+                    )
+                );
+
+                let mut platform = setup_platform(2);
+                let platform_version = PlatformVersion::get(2).expect("expected version 2");
+
+                let (pro_tx_hash, signer, voting_key) = setup_funded_masternode_voting_identity(
+                    &mut platform,
+                    10,
+                    dash_to_credits!(2.0),
+                    platform_version,
+                );
+
+                let vote_poll = max_state_transition_size_proposal(20480);
+
+                let state_transition = governance_proposal_vote_transition(
+                    vote_poll.clone(),
+                    &signer,
+                    pro_tx_hash,
+                    &voting_key,
+                    1,
+                    platform_version,
+                );
+
+                assert_matches!(
+                    process_state_transition_in_current_version(&platform, &state_transition),
+                    StateTransitionExecutionResult::SuccessfulExecution(..)
+                );
+
+                // The opening vote moved the funding of the vote poll into its prefunded balance
+                let vote_poll_balance = platform
+                    .drive
+                    .fetch_prefunded_specialized_balance(
+                        vote_poll
+                            .unique_id()
+                            .expect("expected a unique id")
+                            .to_buffer(),
+                        None,
+                        platform_version,
+                    )
+                    .expect("expected to fetch the prefunded balance");
+
+                assert_eq!(
+                    vote_poll_balance,
+                    Some(
+                        platform_version
+                            .dpp
+                            .voting_versions
+                            .governance_proposal_vote_poll_funding_amount
+                    )
+                );
+            }
+
+            #[test]
+            fn test_governance_proposal_the_voter_can_not_pay_for_is_rejected() {
+                let mut platform = setup_platform(2);
+                let platform_version = PlatformVersion::get(2).expect("expected version 2");
+
+                // Enough for opening a single vote poll
+                let (pro_tx_hash, signer, voting_key) = setup_funded_masternode_voting_identity(
+                    &mut platform,
+                    10,
+                    dash_to_credits!(1.5),
+                    platform_version,
+                );
+
+                let state_transition = governance_proposal_vote_transition(
+                    max_state_transition_size_proposal(20480),
+                    &signer,
+                    pro_tx_hash,
+                    &voting_key,
+                    1,
+                    platform_version,
+                );
+
+                assert_matches!(
+                    process_state_transition_in_current_version(&platform, &state_transition),
+                    StateTransitionExecutionResult::SuccessfulExecution(..)
+                );
+
+                let state_transition = governance_proposal_vote_transition(
+                    max_state_transition_size_proposal(40960),
+                    &signer,
+                    pro_tx_hash,
+                    &voting_key,
+                    2,
+                    platform_version,
+                );
+
+                assert_matches!(
+                    process_state_transition_in_current_version(&platform, &state_transition),
+                    StateTransitionExecutionResult::UnpaidConsensusError(
+                        ConsensusError::StateError(StateError::IdentityInsufficientBalanceError(_))
+                    )
+                );
+            }
+
+            #[test]
+            fn test_evonode_can_only_open_a_limited_number_of_vote_polls_per_epoch() {
+                let mut platform = setup_platform(2);
+                let platform_version = PlatformVersion::get(2).expect("expected version 2");
+
+                let (pro_tx_hash, signer, voting_key) = setup_funded_masternode_voting_identity(
+                    &mut platform,
+                    10,
+                    dash_to_credits!(10.0),
+                    platform_version,
+                );
+
+                let opened_vote_poll = max_state_transition_size_proposal(20480);
+
+                let state_transition = governance_proposal_vote_transition(
+                    opened_vote_poll.clone(),
+                    &signer,
+                    pro_tx_hash,
+                    &voting_key,
+                    1,
+                    platform_version,
+                );
+
+                assert_matches!(
+                    process_state_transition_in_current_version(&platform, &state_transition),
+                    StateTransitionExecutionResult::SuccessfulExecution(..)
+                );
+
+                let state_transition = governance_proposal_vote_transition(
+                    max_state_transition_size_proposal(40960),
+                    &signer,
+                    pro_tx_hash,
+                    &voting_key,
+                    2,
+                    platform_version,
+                );
+
+                assert_matches!(
+                    process_state_transition_in_current_version(&platform, &state_transition),
+                    StateTransitionExecutionResult::UnpaidConsensusError(
+                        ConsensusError::StateError(
+                            StateError::GovernanceProposalVotePollsOpenedLimitReachedError(_)
+                        )
+                    )
+                );
+
+                // Voting on a vote poll that is already open is paid by its prefunded balance, so
+                // an evonode without credits can still do it
+                let (other_pro_tx_hash, other_signer, other_voting_key) =
+                    setup_funded_masternode_voting_identity(&mut platform, 11, 0, platform_version);
+
+                let state_transition = governance_proposal_vote_transition(
+                    opened_vote_poll,
+                    &other_signer,
+                    other_pro_tx_hash,
+                    &other_voting_key,
+                    1,
+                    platform_version,
+                );
+
+                assert_matches!(
+                    process_state_transition_in_current_version(&platform, &state_transition),
+                    StateTransitionExecutionResult::SuccessfulExecution(..)
+                );
+            }
+        }
     }
 }
@@ -1,8 +1,14 @@
 use crate::error::Error;
 use crate::platform_types::platform::PlatformRef;
+use dpp::block::block_info::BlockInfo;
+use dpp::consensus::state::identity::IdentityInsufficientBalanceError;
 use dpp::consensus::state::state_error::StateError;
+use dpp::consensus::state::voting::governance_proposal_vote_poll_end_date_out_of_range_error::GovernanceProposalVotePollEndDateOutOfRangeError;
+use dpp::consensus::state::voting::governance_proposal_vote_poll_ended_error::GovernanceProposalVotePollEndedError;
+use dpp::consensus::state::voting::governance_proposal_vote_polls_opened_limit_reached_error::GovernanceProposalVotePollsOpenedLimitReachedError;
 use dpp::consensus::state::voting::vote_poll_not_available_for_voting_error::VotePollNotAvailableForVotingError;
 use dpp::consensus::state::voting::vote_poll_not_found_error::VotePollNotFoundError;
+use dpp::consensus::state::voting::vote_poll_resolved_by_auction_error::VotePollResolvedByAuctionError;
 use dpp::consensus::ConsensusError;
 
 use dpp::prelude::ConsensusValidationResult;
@@ -13,6 +19,8 @@ use dpp::version::PlatformVersion;
 use dpp::voting::vote_info_storage::contested_document_vote_poll_stored_info::{
     ContestedDocumentVotePollStatus, ContestedDocumentVotePollStoredInfoV0Getters,
 };
+use dpp::voting::vote_info_storage::governance_proposal_vote_poll_stored_info::GovernanceProposalVotePollStoredInfoV0Getters;
+use dpp::voting::votes::governance_proposal_vote::accessors::v0::GovernanceProposalVoteGettersV0;
 use drive::drive::votes::resolved::vote_polls::ResolvedVotePoll;
 use drive::drive::votes::resolved::votes::resolved_resource_vote::accessors::v0::ResolvedResourceVoteGettersV0;
 use drive::drive::votes::resolved::votes::ResolvedVote;
@@ -25,6 +33,7 @@ pub(in crate::execution::validation::state_transition::state_transitions::master
         &self,
         action: Option<StateTransitionAction>,
         platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
         tx: TransactionArg,
         platform_version: &PlatformVersion,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
@@ -35,10 +44,11 @@ impl MasternodeVoteStateTransitionStateValidationV0 for MasternodeVoteTransition
         &self,
         action: Option<StateTransitionAction>,
         platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
         tx: TransactionArg,
         platform_version: &PlatformVersion,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
-        let Some(StateTransitionAction::MasternodeVoteAction(masternode_vote_action)) = action
+        let Some(StateTransitionAction::MasternodeVoteAction(mut masternode_vote_action)) = action
         else {
             return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
                 "action should be known in validate state for masternode vote transition",
@@ -53,6 +63,17 @@ impl MasternodeVoteStateTransitionStateValidationV0 for MasternodeVoteTransition
                     ResolvedVotePoll::ContestedDocumentResourceVotePollWithContractInfo(
                         contested_document_resource_vote_poll,
                     ) => {
+                        if contested_document_resource_vote_poll.is_resolved_by_auction()? {
+                            // Auctions are decided by the bids of the contenders
+                            return Ok(ConsensusValidationResult::new_with_error(
+                                ConsensusError::StateError(
+                                    StateError::VotePollResolvedByAuctionError(
+                                        VotePollResolvedByAuctionError::new(vote_poll.into()),
+                                    ),
+                                ),
+                            ));
+                        }
+
                         let Some(stored_info) = platform
                             .drive
                             .fetch_contested_document_vote_poll_stored_info(
@@ -92,7 +113,122 @@ impl MasternodeVoteStateTransitionStateValidationV0 for MasternodeVoteTransition
                             }
                         }
                     }
+                    ResolvedVotePoll::GovernanceProposalVotePoll(_) => {
+                        Ok(ConsensusValidationResult::new_with_error(
+                            ConsensusError::StateError(StateError::VotePollNotFoundError(
+                                VotePollNotFoundError::new(vote_poll.into()),
+                            )),
+                        ))
+                    }
+                }
+            }
+            ResolvedVote::ResolvedGovernanceProposalVote(governance_proposal_vote) => {
+                let vote_poll = governance_proposal_vote.vote_poll();
+                let vote_poll_id = vote_poll.unique_id()?;
+
+                let maybe_stored_info = platform
+                    .drive
+                    .fetch_governance_proposal_vote_poll_stored_info(
+                        vote_poll_id,
+                        tx,
+                        platform_version,
+                    )?;
+
+                let has_ended = maybe_stored_info
+                    .as_ref()
+                    .is_some_and(|stored_info| stored_info.has_ended());
+
+                if has_ended || block_info.time_ms >= vote_poll.end_date {
+                    return Ok(ConsensusValidationResult::new_with_error(
+                        ConsensusError::StateError(
+                            StateError::GovernanceProposalVotePollEndedError(
+                                GovernanceProposalVotePollEndedError::new(
+                                    vote_poll_id,
+                                    vote_poll.end_date,
+                                ),
+                            ),
+                        ),
+                    ));
                 }
+
+                if maybe_stored_info.is_none() {
+                    // The first vote starts the vote poll, so its end date must be within the
+                    // allowed voting period
+                    let voting_versions = &platform_version.dpp.voting_versions;
+                    let min_end_date = block_info.time_ms.saturating_add(
+                        voting_versions.governance_proposal_min_vote_poll_duration_ms,
+                    );
+                    let max_end_date = block_info.time_ms.saturating_add(
+                        voting_versions.governance_proposal_max_vote_poll_duration_ms,
+                    );
+                    if vote_poll.end_date < min_end_date || vote_poll.end_date > max_end_date {
+                        return Ok(ConsensusValidationResult::new_with_error(
+                            ConsensusError::StateError(
+                                StateError::GovernanceProposalVotePollEndDateOutOfRangeError(
+                                    GovernanceProposalVotePollEndDateOutOfRangeError::new(
+                                        vote_poll_id,
+                                        vote_poll.end_date,
+                                        min_end_date,
+                                        max_end_date,
+                                    ),
+                                ),
+                            ),
+                        ));
+                    }
+
+                    // Opening the vote poll is paid by the voter identity, the credits fund the
+                    // votes that follow
+                    let vote_poll_funding =
+                        voting_versions.governance_proposal_vote_poll_funding_amount;
+                    let voter_identity_id = masternode_vote_action.voter_identity_id();
+                    let balance = platform
+                        .drive
+                        .fetch_identity_balance(
+                            voter_identity_id.to_buffer(),
+                            tx,
+                            platform_version,
+                        )?
+                        .unwrap_or_default();
+                    if balance < vote_poll_funding {
+                        return Ok(ConsensusValidationResult::new_with_error(
+                            IdentityInsufficientBalanceError::new(
+                                voter_identity_id,
+                                balance,
+                                vote_poll_funding,
+                            )
+                            .into(),
+                        ));
+                    }
+
+                    let pro_tx_hash = masternode_vote_action.pro_tx_hash();
+                    let max_vote_polls_opened =
+                        voting_versions.governance_proposal_max_vote_polls_opened_per_epoch;
+                    let vote_polls_opened = platform
+                        .drive
+                        .fetch_governance_proposal_vote_polls_opened_in_epoch(
+                            pro_tx_hash,
+                            block_info.epoch.index,
+                            tx,
+                            &mut vec![],
+                            platform_version,
+                        )?;
+                    if vote_polls_opened >= max_vote_polls_opened {
+                        return Ok(ConsensusValidationResult::new_with_error(
+                            GovernanceProposalVotePollsOpenedLimitReachedError::new(
+                                pro_tx_hash,
+                                block_info.epoch.index,
+                                max_vote_polls_opened,
+                            )
+                            .into(),
+                        ));
+                    }
+
+                    masternode_vote_action.set_vote_poll_funding(Some(vote_poll_funding));
+                }
+
+                Ok(ConsensusValidationResult::new_with_data(
+                    masternode_vote_action.into(),
+                ))
             }
         }
     }
@@ -2,9 +2,11 @@ use crate::error::Error;
 use crate::platform_types::platform::PlatformRef;
 use dashcore_rpc::dashcore_rpc_json::MasternodeType;
 use dpp::consensus::state::state_error::StateError;
+use dpp::consensus::state::voting::masternode_not_evonode_error::MasternodeNotEvonodeError;
 use dpp::consensus::state::voting::masternode_not_found_error::MasternodeNotFoundError;
 use dpp::consensus::state::voting::masternode_vote_already_present_error::MasternodeVoteAlreadyPresentError;
 use dpp::consensus::state::voting::masternode_voted_too_many_times::MasternodeVotedTooManyTimesError;
+use dpp::consensus::state::voting::vote_poll_not_found_error::VotePollNotFoundError;
 use dpp::consensus::ConsensusError;
 use dpp::dashcore::hashes::Hash;
 use dpp::dashcore::ProTxHash;
@@ -103,8 +105,18 @@ impl MasternodeVoteStateTransitionTransformIntoActionValidationV0 for Masternode
                                 }
                             }
                         }
+                        VotePoll::GovernanceProposalVotePoll(_) => {
+                            // Governance proposals can only be voted on with yes, no or abstain
+                            return Ok(ConsensusValidationResult::new_with_error(
+                                ConsensusError::StateError(StateError::VotePollNotFoundError(
+                                    VotePollNotFoundError::new(resource_vote.vote_poll().clone()),
+                                )),
+                            ));
+                        }
                     }
                 }
+                // A later vote on a governance proposal simply replaces the previous one
+                Vote::GovernanceProposalVote(_) => {}
             }
         }
 
@@ -118,6 +130,15 @@ impl MasternodeVoteStateTransitionTransformIntoActionValidationV0 for Masternode
             ));
         };
 
+        if matches!(self.vote(), Vote::GovernanceProposalVote(_))
+            && masternode.node_type != MasternodeType::Evo
+        {
+            // Only evonodes take part in governance decisions
+            return Ok(ConsensusValidationResult::new_with_error(
+                MasternodeNotEvonodeError::new(self.pro_tx_hash()).into(),
+            ));
+        }
+
         let strength = match masternode.node_type {
             MasternodeType::Regular => 1,
             MasternodeType::Evo => 4,
@@ -7,6 +7,9 @@ pub mod identity_create;
 /// Module for managing transfers of credit between identity entities.
 pub mod identity_credit_transfer;
 
+/// Module for managing transfers of credit from one identity to several others at once.
+pub mod identity_credit_batch_transfer;
+
 /// Module for managing withdrawals of credit from an identity entity.
 pub mod identity_credit_withdrawal;
 
@@ -16,15 +19,24 @@ pub mod identity_top_up;
 /// Module for updating an existing identity entity.
 pub mod identity_update;
 
+/// Module for the social recovery of an identity entity.
+pub mod identity_recovery;
+
 /// Module for creating a data contract entity.
 pub mod data_contract_create;
 
 /// Module for updating an existing data contract entity.
 pub mod data_contract_update;
 
+/// Module for archiving or deleting an existing data contract entity.
+pub mod data_contract_delete;
+
 /// Module for voting from a masternode.
 pub mod masternode_vote;
 
+/// Module for bidding in contested resource auctions.
+pub mod auction_bid;
+
 /// The validation mode we are using
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ValidationMode {
@@ -455,6 +467,50 @@ pub(in crate::execution) mod tests {
         (fee_results, processed_block_fees)
     }
 
+    /// Processes a single state transition with the protocol version the platform is running
+    /// and commits the outcome
+    pub(in crate::execution) fn process_state_transition_in_current_version(
+        platform: &TempPlatform<MockCoreRPCLike>,
+        state_transition: &StateTransition,
+    ) -> StateTransitionExecutionResult {
+        let platform_state = platform.state.load();
+        let platform_version = platform_state
+            .current_platform_version()
+            .expect("expected to get current platform version");
+
+        let serialized_transition = state_transition
+            .serialize_to_bytes()
+            .expect("expected serialized state transition");
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let processing_result = platform
+            .platform
+            .process_raw_state_transitions(
+                &vec![serialized_transition],
+                &platform_state,
+                &BlockInfo::default(),
+                &transaction,
+                platform_version,
+                false,
+                None,
+            )
+            .expect("expected to process state transition");
+
+        platform
+            .drive
+            .grove
+            .commit_transaction(transaction)
+            .unwrap()
+            .expect("expected to commit transaction");
+
+        processing_result
+            .into_execution_results()
+            .into_iter()
+            .next()
+            .expect("expected an execution result")
+    }
+
     pub(in crate::execution) fn fetch_expected_identity_balance(
         platform: &TempPlatform<MockCoreRPCLike>,
         identity_id: Identifier,
@@ -64,6 +64,13 @@ impl StateTransitionActionTransformerV0 for StateTransition {
                 execution_context,
                 tx,
             ),
+            StateTransition::DataContractDelete(st) => st.transform_into_action(
+                platform,
+                block_info,
+                validation_mode,
+                execution_context,
+                tx,
+            ),
             StateTransition::IdentityCreate(st) => {
                 let signable_bytes = self.signable_bytes()?;
                 st.transform_into_action_for_identity_create_transition(
@@ -81,6 +88,13 @@ impl StateTransitionActionTransformerV0 for StateTransition {
                 execution_context,
                 tx,
             ),
+            StateTransition::IdentityRecovery(st) => st.transform_into_action(
+                platform,
+                block_info,
+                validation_mode,
+                execution_context,
+                tx,
+            ),
             StateTransition::IdentityTopUp(st) => {
                 let signable_bytes = self.signable_bytes()?;
                 st.transform_into_action_for_identity_top_up_transition(
@@ -112,6 +126,13 @@ impl StateTransitionActionTransformerV0 for StateTransition {
                 execution_context,
                 tx,
             ),
+            StateTransition::IdentityCreditBatchTransfer(st) => st.transform_into_action(
+                platform,
+                block_info,
+                validation_mode,
+                execution_context,
+                tx,
+            ),
             StateTransition::MasternodeVote(st) => st.transform_into_action(
                 platform,
                 block_info,
@@ -119,6 +140,13 @@ impl StateTransitionActionTransformerV0 for StateTransition {
                 execution_context,
                 tx,
             ),
+            StateTransition::AuctionBid(st) => st.transform_into_action(
+                platform,
+                block_info,
+                validation_mode,
+                execution_context,
+                tx,
+            ),
         }
     }
 }
@@ -4,6 +4,7 @@ use crate::platform_types::state_transitions_processing_result::StateTransitions
 use dpp::validation::SimpleValidationResult;
 use dpp::version::PlatformVersion;
 use tenderdash_abci::proto::abci::ValidatorSetUpdate;
+use tenderdash_abci::proto::types::ConsensusParams;
 
 /// The outcome of the block execution, either by prepare proposal, or process proposal
 #[derive(Clone)]
@@ -16,6 +17,8 @@ pub struct BlockExecutionOutcome {
     /// The changes to the validator set
     // TODO We should use another DTO, only abci module should deal with Tenderdash proto structures
     pub validator_set_update: Option<ValidatorSetUpdate>,
+    /// The consensus params Tenderdash must apply from the next block on
+    pub consensus_param_updates: Option<ConsensusParams>,
     /// Current block platform version
     pub platform_version: &'static PlatformVersion,
     /// Block execution context
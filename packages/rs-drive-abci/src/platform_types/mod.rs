@@ -14,10 +14,14 @@ pub mod event_execution_result;
 pub mod masternode;
 /// Main platform structs, not versioned
 pub mod platform;
+/// Blocks committed by finalize block, distributed to platform event subscribers
+pub mod platform_events;
 /// Platform state
 pub mod platform_state;
 /// Required identity public key set for system identities
 pub mod required_identity_public_key_set;
+/// GroveDB snapshots for ABCI state sync
+pub mod snapshot;
 /// Signature verification quorums for Core
 pub mod signature_verification_quorum_set;
 /// The state transition execution result as part of the block execution outcome
@@ -11,11 +11,12 @@ use arc_swap::ArcSwap;
 use dpp::version::INITIAL_PROTOCOL_VERSION;
 use std::path::Path;
 use std::str::FromStr;
-use std::sync::atomic::AtomicU64;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 use dashcore_rpc::dashcore::BlockHash;
 
+use crate::platform_types::platform_events::PlatformEvents;
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use crate::platform_types::platform_state::PlatformState;
 use dpp::version::{PlatformVersion, PlatformVersionCurrentVersion};
@@ -41,6 +42,8 @@ pub struct Platform<C> {
     pub config: PlatformConfig,
     /// Core RPC Client
     pub core_rpc: C,
+    /// Committed blocks for platform event subscribers
+    pub events: PlatformEvents,
 }
 
 // @append_only
@@ -240,6 +243,7 @@ impl<C> Platform<C> {
             committed_block_height_guard: AtomicU64::from(height),
             config,
             core_rpc,
+            events: PlatformEvents::default(),
         };
 
         Ok(platform)
@@ -272,12 +276,40 @@ impl<C> Platform<C> {
             committed_block_height_guard: AtomicU64::from(height),
             config,
             core_rpc,
+            events: PlatformEvents::default(),
         })
     }
 }
 
+impl<C> Platform<C> {
+    /// Returns a platform on the same GroveDB and state, but with its own Drive caches and
+    /// no event subscribers.
+    ///
+    /// It is used to execute state transitions that are only simulated, like when estimating
+    /// fees, so that neither the caches of the block being executed nor the global ones are
+    /// read or changed.
+    pub(crate) fn with_isolated_drive_cache(&self) -> Platform<&C> {
+        Platform {
+            drive: self.drive.with_isolated_cache(),
+            state: ArcSwap::new(self.state.load_full()),
+            committed_block_height_guard: AtomicU64::from(
+                self.committed_block_height_guard.load(Ordering::Relaxed),
+            ),
+            config: self.config.clone(),
+            core_rpc: &self.core_rpc,
+            events: PlatformEvents::default(),
+        }
+    }
+}
+
 impl<C> Drop for Platform<C> {
     fn drop(&mut self) {
+        // Platforms with isolated Drive caches share GroveDB with the main one, which
+        // flushes it when shutting down
+        if Arc::strong_count(&self.drive.grove) > 1 {
+            return;
+        }
+
         tracing::trace!("platform is shutting down");
 
         if let Err(error) = self.drive.grove.flush() {
@@ -0,0 +1,158 @@
+use dpp::serialization::PlatformDeserializable;
+use dpp::state_transition::StateTransition;
+use dpp::util::hash::hash_single;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+/// How many committed blocks are buffered for a subscriber before it starts lagging behind
+pub const PLATFORM_EVENTS_CHANNEL_CAPACITY: usize = 64;
+
+/// A state transition executed in a committed block
+#[derive(Debug, Clone)]
+pub struct CommittedStateTransition {
+    /// Hash of the serialized state transition, as known by Tenderdash
+    pub hash: [u8; 32],
+    /// The serialized state transition
+    pub serialized_state_transition: Vec<u8>,
+    /// The state transition
+    pub state_transition: StateTransition,
+}
+
+/// A block committed by finalize block
+#[derive(Debug, Clone)]
+pub struct CommittedBlock {
+    /// Height of the block
+    pub height: u64,
+    /// Hash of the block
+    pub block_hash: [u8; 32],
+    /// Number of transactions in the block
+    pub transactions_count: u32,
+    /// The state transitions executed in the block
+    pub state_transitions: Vec<CommittedStateTransition>,
+}
+
+impl CommittedBlock {
+    /// Creates a committed block from the transactions of a finalized block
+    ///
+    /// Transactions which can't be deserialized into state transitions are skipped,
+    /// they can't be part of a committed block anyway.
+    pub fn from_transactions(height: u64, block_hash: [u8; 32], transactions: &[Vec<u8>]) -> Self {
+        let state_transitions = transactions
+            .iter()
+            .filter_map(|transaction| {
+                match StateTransition::deserialize_from_bytes(transaction) {
+                    Ok(state_transition) => Some(CommittedStateTransition {
+                        hash: hash_single(transaction),
+                        serialized_state_transition: transaction.clone(),
+                        state_transition,
+                    }),
+                    Err(error) => {
+                        tracing::error!(
+                            height,
+                            ?error,
+                            "unable to deserialize committed state transition"
+                        );
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        Self {
+            height,
+            block_hash,
+            transactions_count: transactions.len() as u32,
+            state_transitions,
+        }
+    }
+}
+
+/// Distributes committed blocks to platform event subscribers
+#[derive(Debug)]
+pub struct PlatformEvents {
+    sender: broadcast::Sender<Arc<CommittedBlock>>,
+}
+
+impl Default for PlatformEvents {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(PLATFORM_EVENTS_CHANNEL_CAPACITY);
+
+        Self { sender }
+    }
+}
+
+impl PlatformEvents {
+    /// Subscribes to blocks committed from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<CommittedBlock>> {
+        self.sender.subscribe()
+    }
+
+    /// Returns true if anyone is listening to committed blocks
+    pub fn has_subscribers(&self) -> bool {
+        self.sender.receiver_count() > 0
+    }
+
+    /// Publishes a committed block to current subscribers
+    pub fn publish(&self, block: CommittedBlock) {
+        // Sending fails only if nobody is subscribed, which is fine
+        let _ = self.sender.send(Arc::new(block));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dpp::serialization::PlatformSerializable;
+    use dpp::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
+    use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+
+    #[test]
+    fn test_committed_block_skips_invalid_transactions() {
+        let state_transition = StateTransition::IdentityCreditTransfer(
+            IdentityCreditTransferTransition::V0(IdentityCreditTransferTransitionV0 {
+                amount: 1000,
+                ..Default::default()
+            }),
+        );
+
+        let serialized_state_transition = state_transition
+            .serialize_to_bytes()
+            .expect("expected to serialize state transition");
+
+        let block = CommittedBlock::from_transactions(
+            5,
+            [1; 32],
+            &[serialized_state_transition.clone(), vec![0xFF, 0xFF]],
+        );
+
+        assert_eq!(block.height, 5);
+        assert_eq!(block.transactions_count, 2);
+        assert_eq!(block.state_transitions.len(), 1);
+        assert_eq!(
+            block.state_transitions[0].hash,
+            hash_single(&serialized_state_transition)
+        );
+        assert_eq!(block.state_transitions[0].state_transition, state_transition);
+    }
+
+    #[test]
+    fn test_publish_reaches_subscribers() {
+        let events = PlatformEvents::default();
+
+        assert!(!events.has_subscribers());
+
+        // Publishing without subscribers must not fail
+        events.publish(CommittedBlock::from_transactions(1, [0; 32], &[]));
+
+        let mut receiver = events.subscribe();
+
+        assert!(events.has_subscribers());
+
+        events.publish(CommittedBlock::from_transactions(2, [2; 32], &[]));
+
+        let block = receiver.try_recv().expect("expected a committed block");
+
+        assert_eq!(block.height, 2);
+        assert_eq!(block.block_hash, [2; 32]);
+    }
+}
@@ -541,3 +541,95 @@ impl PlatformStateV0Methods for PlatformState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dpp::block::epoch::EpochIndex;
+    use dpp::version::fee::FeeVersion;
+
+    /// Encodes fee versions field by field in the layout they were stored with, so that any
+    /// change to the encoding of `FeeVersion` breaks the decoding of stored platform states
+    fn encode_previous_fee_versions_in_stored_layout(
+        previous_fee_versions: &CachedEpochIndexFeeVersions,
+    ) -> Vec<u8> {
+        let config = config::standard().with_big_endian().with_no_limit();
+        let stored_layout = previous_fee_versions
+            .iter()
+            .map(|(epoch_index, fee_version)| {
+                let min_fees = &fee_version.state_transition_min_fees;
+                (
+                    *epoch_index,
+                    (
+                        fee_version.uses_version_fee_multiplier_permille,
+                        &fee_version.storage,
+                        &fee_version.signature,
+                        &fee_version.hashing,
+                        &fee_version.processing,
+                        &fee_version.data_contract,
+                        (
+                            min_fees.credit_transfer,
+                            min_fees.credit_withdrawal,
+                            min_fees.identity_update,
+                            min_fees.document_batch_sub_transition,
+                            min_fees.contract_create,
+                            min_fees.contract_update,
+                            min_fees.masternode_vote,
+                        ),
+                        &fee_version.vote_resolution_fund_fees,
+                    ),
+                )
+            })
+            .collect::<Vec<_>>();
+        bincode::encode_to_vec(stored_layout, config).expect("expected to encode fee versions")
+    }
+
+    #[test]
+    fn should_decode_platform_state_with_fee_versions_in_stored_layout() {
+        let platform_version = PlatformVersion::first();
+        let mut platform_state = PlatformState::default_with_protocol_versions(
+            platform_version.protocol_version,
+            platform_version.protocol_version,
+            &PlatformConfig::default(),
+        )
+        .expect("expected a platform state");
+
+        // The fee versions are the last field of the saved state, encoded as an empty map here
+        let mut stored_bytes = platform_state
+            .serialize_to_bytes()
+            .expect("expected to serialize platform state");
+        assert_eq!(stored_bytes.pop(), Some(0));
+
+        let previous_fee_versions: CachedEpochIndexFeeVersions = BTreeMap::from([
+            (0 as EpochIndex, platform_version.fee_version.clone()),
+            (5, FeeVersion::default()),
+        ]);
+        stored_bytes.extend(encode_previous_fee_versions_in_stored_layout(
+            &previous_fee_versions,
+        ));
+
+        let decoded_platform_state =
+            PlatformState::versioned_deserialize(&stored_bytes, platform_version)
+                .expect("expected to decode platform state saved in the stored layout");
+
+        assert_eq!(
+            decoded_platform_state.previous_fee_versions(),
+            &previous_fee_versions
+        );
+
+        *platform_state.previous_fee_versions_mut() = previous_fee_versions;
+
+        assert_eq!(
+            decoded_platform_state
+                .serialize_to_bytes()
+                .expect("expected to serialize platform state"),
+            stored_bytes
+        );
+        assert_eq!(
+            platform_state
+                .serialize_to_bytes()
+                .expect("expected to serialize platform state"),
+            stored_bytes
+        );
+    }
+}
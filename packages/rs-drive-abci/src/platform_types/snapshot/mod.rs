@@ -0,0 +1,300 @@
+use crate::abci::AbciError;
+use crate::config::StateSyncConfig;
+use crate::error::Error;
+use bincode::{Decode, Encode};
+use dpp::util::deserializer::ProtocolVersion;
+use dpp::version::PlatformVersion;
+use drive::grovedb::replication::MultiStateSyncSession;
+use drive::grovedb::GroveDb;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+
+/// Aux key under which the list of available snapshots is stored
+const SNAPSHOT_KEY: &[u8] = b"snapshots";
+
+/// Version of the chunk format served to and applied from other nodes
+pub const CURRENT_STATE_SYNC_VERSION: u16 = 1;
+
+/// Snapshot entity
+#[derive(Clone, Encode, Decode, PartialEq, Debug)]
+pub struct Snapshot {
+    /// Block height
+    pub height: u64,
+    /// Version
+    pub version: u16,
+    /// Path to the checkpoint
+    pub path: String,
+    /// Root hash of the checkpoint, equal to the app hash of the block
+    pub hash: [u8; 32],
+    /// Metadata sent alongside the snapshot, see [SnapshotMetadata]
+    pub metadata: Vec<u8>,
+}
+
+/// Platform data that is not part of GroveDB merk trees, so it can't be restored from chunks
+/// and is sent to the syncing node as snapshot metadata
+#[derive(Clone, Encode, Decode, PartialEq, Debug)]
+pub struct SnapshotMetadata {
+    /// Protocol version the platform state was serialized with
+    pub protocol_version: ProtocolVersion,
+    /// Serialized platform state at the snapshot height
+    pub platform_state: Vec<u8>,
+}
+
+impl SnapshotMetadata {
+    /// Serialize metadata to be sent with a snapshot
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        bincode::encode_to_vec(self, bincode::config::standard()).map_err(|e| {
+            Error::Abci(AbciError::StateSyncInternalError(format!(
+                "unable to serialize snapshot metadata: {}",
+                e
+            )))
+        })
+    }
+
+    /// Deserialize metadata received with an offered snapshot
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        bincode::decode_from_slice(bytes, bincode::config::standard())
+            .map(|(metadata, _)| metadata)
+            .map_err(|e| {
+                Error::Abci(AbciError::StateSyncBadRequest(format!(
+                    "invalid snapshot metadata: {}",
+                    e
+                )))
+            })
+    }
+}
+
+/// Snapshot manager is responsible for creating, listing and pruning GroveDB checkpoints
+/// which are served to other nodes through ABCI state sync
+#[derive(Default, Clone, Debug)]
+pub struct SnapshotManager {
+    freq: u64,
+    number_stored_snapshots: usize,
+    checkpoints_path: PathBuf,
+}
+
+/// Snapshot currently being applied by a node that is syncing its state from other nodes
+pub struct SnapshotFetchingSession<'db> {
+    /// Snapshot accepted
+    pub snapshot: Snapshot,
+    /// App hash we expect to have once all chunks are applied
+    pub app_hash: [u8; 32],
+    /// Decoded snapshot metadata
+    pub metadata: SnapshotMetadata,
+    /// GroveDB state sync session
+    pub state_sync_info: Pin<Box<MultiStateSyncSession<'db>>>,
+}
+
+impl SnapshotManager {
+    /// Create a new instance of snapshot manager
+    pub fn new(checkpoints_path: PathBuf, number_stored_snapshots: usize, freq: u64) -> Self {
+        Self {
+            freq,
+            number_stored_snapshots,
+            checkpoints_path,
+        }
+    }
+
+    /// Create a new instance of snapshot manager from the state sync config
+    pub fn new_from_config(config: &StateSyncConfig) -> Self {
+        Self::new(
+            config.checkpoints_path.clone(),
+            config.max_num_snapshots,
+            config.snapshots_frequency,
+        )
+    }
+
+    /// Return a list of all available snapshots
+    pub fn get_snapshots(&self, grove: &GroveDb) -> Result<Vec<Snapshot>, Error> {
+        let data = grove.get_aux(SNAPSHOT_KEY, None).value.map_err(|e| {
+            Error::Abci(AbciError::StateSyncInternalError(format!(
+                "unable to read snapshots: {}",
+                e
+            )))
+        })?;
+
+        match data {
+            Some(data) => bincode::decode_from_slice(data.as_slice(), bincode::config::standard())
+                .map(|(snapshots, _)| snapshots)
+                .map_err(|e| {
+                    Error::Abci(AbciError::StateSyncInternalError(format!(
+                        "unable to decode snapshots: {}",
+                        e
+                    )))
+                }),
+            None => Ok(vec![]),
+        }
+    }
+
+    /// Return the snapshot taken at the given height, if any
+    pub fn get_snapshot_at_height(
+        &self,
+        grove: &GroveDb,
+        height: u64,
+    ) -> Result<Option<Snapshot>, Error> {
+        let snapshots = self.get_snapshots(grove)?;
+
+        Ok(snapshots
+            .into_iter()
+            .find(|snapshot| snapshot.height == height))
+    }
+
+    /// Create a snapshot of the committed state if the height is a multiple of the frequency
+    ///
+    /// Should only be called right after the block transaction is committed, with the platform
+    /// version of the committed platform state
+    pub fn create_snapshot(
+        &self,
+        grove: &GroveDb,
+        height: u64,
+        metadata: SnapshotMetadata,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        if self.freq == 0 || height == 0 || height % self.freq != 0 {
+            return Ok(());
+        }
+
+        let checkpoint_path: PathBuf = self.checkpoints_path.join(height.to_string());
+
+        if checkpoint_path.exists() {
+            tracing::debug!(height, "checkpoint already exists, skipping snapshot");
+            return Ok(());
+        }
+
+        std::fs::create_dir_all(&self.checkpoints_path).map_err(|e| {
+            Error::Abci(AbciError::StateSyncInternalError(format!(
+                "unable to create checkpoints directory {}: {}",
+                self.checkpoints_path.display(),
+                e
+            )))
+        })?;
+
+        grove.create_checkpoint(&checkpoint_path).map_err(|e| {
+            Error::Abci(AbciError::StateSyncInternalError(format!(
+                "unable to create checkpoint at height {}: {}",
+                height, e
+            )))
+        })?;
+
+        let root_hash = grove
+            .root_hash(None, &platform_version.drive.grove_version)
+            .value
+            .map_err(|e| {
+                Error::Abci(AbciError::StateSyncInternalError(format!(
+                    "unable to get root hash for checkpoint at height {}: {}",
+                    height, e
+                )))
+            })?;
+
+        let snapshot = Snapshot {
+            height,
+            version: CURRENT_STATE_SYNC_VERSION,
+            path: checkpoint_path.to_string_lossy().to_string(),
+            hash: root_hash,
+            metadata: metadata.to_bytes()?,
+        };
+
+        let mut snapshots = self.get_snapshots(grove)?;
+        snapshots.push(snapshot);
+        let snapshots = self.prune_excess_snapshots(snapshots)?;
+        self.save_snapshots(grove, snapshots)?;
+
+        tracing::debug!(
+            height,
+            root_hash = hex::encode(root_hash),
+            "snapshot created"
+        );
+
+        Ok(())
+    }
+
+    fn prune_excess_snapshots(&self, mut snapshots: Vec<Snapshot>) -> Result<Vec<Snapshot>, Error> {
+        if snapshots.len() <= self.number_stored_snapshots {
+            return Ok(snapshots);
+        }
+
+        snapshots.sort_by_key(|snapshot| snapshot.height);
+
+        let excess = snapshots.len() - self.number_stored_snapshots;
+
+        for snapshot in snapshots.drain(..excess) {
+            let path = Path::new(&snapshot.path);
+            if path.exists() {
+                std::fs::remove_dir_all(path).map_err(|e| {
+                    Error::Abci(AbciError::StateSyncInternalError(format!(
+                        "unable to remove checkpoint at height {}: {}",
+                        snapshot.height, e
+                    )))
+                })?;
+            }
+        }
+
+        Ok(snapshots)
+    }
+
+    fn save_snapshots(&self, grove: &GroveDb, snapshots: Vec<Snapshot>) -> Result<(), Error> {
+        let data = bincode::encode_to_vec(snapshots, bincode::config::standard()).map_err(|e| {
+            Error::Abci(AbciError::StateSyncInternalError(format!(
+                "unable to encode snapshots: {}",
+                e
+            )))
+        })?;
+
+        grove
+            .put_aux(SNAPSHOT_KEY, data.as_slice(), None, None)
+            .value
+            .map_err(|e| {
+                Error::Abci(AbciError::StateSyncInternalError(format!(
+                    "unable to store snapshots: {}",
+                    e
+                )))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use drive::grovedb::GroveDb;
+
+    #[test]
+    fn test_create_and_prune_snapshots() {
+        let db_dir = tempfile::tempdir().expect("expected to create temp dir");
+        let checkpoints_dir = tempfile::tempdir().expect("expected to create temp dir");
+
+        let grove = GroveDb::open(db_dir.path()).expect("expected to open grovedb");
+
+        let manager = SnapshotManager::new(checkpoints_dir.path().join("checkpoints"), 3, 2);
+
+        let metadata = SnapshotMetadata {
+            protocol_version: 1,
+            platform_state: vec![1, 2, 3],
+        };
+
+        for height in 1..=10 {
+            manager
+                .create_snapshot(&grove, height, metadata.clone(), PlatformVersion::latest())
+                .expect("expected to create snapshot");
+        }
+
+        let snapshots = manager
+            .get_snapshots(&grove)
+            .expect("expected to get snapshots");
+
+        let heights: Vec<u64> = snapshots.iter().map(|snapshot| snapshot.height).collect();
+        assert_eq!(heights, vec![6, 8, 10]);
+
+        assert!(!checkpoints_dir.path().join("checkpoints/4").exists());
+        assert!(checkpoints_dir.path().join("checkpoints/10").exists());
+
+        let snapshot = manager
+            .get_snapshot_at_height(&grove, 8)
+            .expect("expected to get snapshot")
+            .expect("expected snapshot at height 8");
+
+        assert_eq!(
+            SnapshotMetadata::from_bytes(&snapshot.metadata).expect("expected metadata"),
+            metadata
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_documents_count_request::GetDocumentsCountRequestV0;
+use dapi_grpc::platform::v0::get_documents_count_response::{
+    get_documents_count_response_v0, GetDocumentsCountResponseV0,
+};
+use dpp::check_validation_result_with_data;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::identifier::Identifier;
+use dpp::platform_value::Value;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use drive::error::query::QuerySyntaxError;
+use drive::query::DriveDocumentCountQuery;
+
+impl<C> Platform<C> {
+    pub(super) fn query_documents_count_v0(
+        &self,
+        GetDocumentsCountRequestV0 {
+            data_contract_id,
+            document_type: document_type_name,
+            r#where,
+            sum_property,
+            prove,
+        }: GetDocumentsCountRequestV0,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetDocumentsCountResponseV0>, Error> {
+        let contract_id: Identifier = check_validation_result_with_data!(data_contract_id
+            .try_into()
+            .map_err(|_| QueryError::InvalidArgument(
+                "id must be a valid identifier (32 bytes long)".to_string()
+            )));
+
+        let (_, contract) = self.drive.get_contract_with_fetch_info_and_fee(
+            contract_id.to_buffer(),
+            None,
+            true,
+            None,
+            platform_version,
+        )?;
+
+        let contract = check_validation_result_with_data!(contract.ok_or(QueryError::Query(
+            QuerySyntaxError::DataContractNotFound(
+                "contract not found when querying from value with contract info",
+            )
+        )));
+
+        let contract_ref = &contract.contract;
+
+        let document_type = check_validation_result_with_data!(contract_ref
+            .document_type_for_name(document_type_name.as_str())
+            .map_err(|_| QueryError::InvalidArgument(format!(
+                "document type {} not found for contract {}",
+                document_type_name, contract_id
+            ))));
+
+        let where_clause = if r#where.is_empty() {
+            Value::Null
+        } else {
+            check_validation_result_with_data!(ciborium::de::from_reader(r#where.as_slice())
+                .map_err(|_| {
+                    QueryError::Query(QuerySyntaxError::DeserializationError(
+                        "unable to decode 'where' query from cbor".to_string(),
+                    ))
+                }))
+        };
+
+        let is_sum = sum_property.is_some();
+
+        let count_query =
+            check_validation_result_with_data!(DriveDocumentCountQuery::from_decomposed_values(
+                where_clause,
+                sum_property,
+                contract_ref,
+                document_type,
+            )
+            .map_err(|e| match e {
+                drive::error::Error::Query(query_error) => QueryError::Query(query_error),
+                e => QueryError::Drive(e),
+            }));
+
+        let response = if prove {
+            let proof = match count_query.execute_with_proof(
+                &self.drive,
+                None,
+                &mut vec![],
+                platform_version,
+            ) {
+                Ok(proof) => proof,
+                Err(drive::error::Error::Query(query_error)) => {
+                    return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                        query_error,
+                    )));
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            GetDocumentsCountResponseV0 {
+                result: Some(get_documents_count_response_v0::Result::Proof(
+                    self.response_proof_v0(platform_state, proof),
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        } else {
+            let total = match count_query.execute_no_proof(
+                &self.drive,
+                None,
+                &mut vec![],
+                platform_version,
+            ) {
+                Ok(total) => total,
+                Err(drive::error::Error::Query(query_error)) => {
+                    return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                        query_error,
+                    )));
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            let result = if is_sum {
+                get_documents_count_response_v0::Result::Sum(total)
+            } else {
+                get_documents_count_response_v0::Result::Count(total as u64)
+            };
+
+            GetDocumentsCountResponseV0 {
+                result: Some(result),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::tests::{assert_invalid_identifier, setup_platform, store_data_contract};
+    use dpp::dashcore::Network;
+    use dpp::tests::fixtures::get_data_contract_fixture;
+
+    #[test]
+    fn test_invalid_data_contract_id() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let request = GetDocumentsCountRequestV0 {
+            data_contract_id: vec![0; 8],
+            document_type: "niceDocument".to_string(),
+            r#where: vec![],
+            sum_property: None,
+            prove: false,
+        };
+
+        let result = platform
+            .query_documents_count_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert_invalid_identifier(result);
+    }
+
+    #[test]
+    fn test_count_without_countable_index() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let created_data_contract = get_data_contract_fixture(None, 0, version.protocol_version);
+        store_data_contract(&platform, created_data_contract.data_contract(), version);
+
+        let request = GetDocumentsCountRequestV0 {
+            data_contract_id: created_data_contract.data_contract().id().to_vec(),
+            document_type: "niceDocument".to_string(),
+            r#where: vec![],
+            sum_property: None,
+            prove: false,
+        };
+
+        let result = platform
+            .query_documents_count_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::Query(
+                QuerySyntaxError::WhereClauseOnNonIndexedProperty(_)
+            )]
+        ));
+    }
+}
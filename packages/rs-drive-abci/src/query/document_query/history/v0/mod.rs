@@ -0,0 +1,402 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_document_history_request::GetDocumentHistoryRequestV0;
+use dapi_grpc::platform::v0::get_document_history_response::{
+    get_document_history_response_v0, GetDocumentHistoryResponseV0,
+};
+use dpp::check_validation_result_with_data;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::identifier::Identifier;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use dpp::ProtocolError;
+use drive::error::query::QuerySyntaxError;
+
+impl<C> Platform<C> {
+    pub(super) fn query_document_history_v0(
+        &self,
+        GetDocumentHistoryRequestV0 {
+            data_contract_id,
+            document_type: document_type_name,
+            document_id,
+            start_at_ms,
+            limit,
+            prove,
+        }: GetDocumentHistoryRequestV0,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetDocumentHistoryResponseV0>, Error> {
+        let config = &self.config.drive;
+
+        let contract_id: Identifier = check_validation_result_with_data!(data_contract_id
+            .try_into()
+            .map_err(|_| QueryError::InvalidArgument(
+                "id must be a valid identifier (32 bytes long)".to_string()
+            )));
+
+        let document_id: Identifier = check_validation_result_with_data!(document_id
+            .try_into()
+            .map_err(|_| QueryError::InvalidArgument(
+                "document_id must be a valid identifier (32 bytes long)".to_string()
+            )));
+
+        let limit = check_validation_result_with_data!(limit.map_or(
+            Ok(config.default_query_limit),
+            |limit| {
+                let limit = u16::try_from(limit)
+                    .map_err(|_| QueryError::InvalidArgument("limit out of bounds".to_string()))?;
+                if limit == 0 || limit > config.default_query_limit {
+                    Err(QueryError::InvalidArgument(format!(
+                        "limit {} out of bounds of [1, {}]",
+                        limit, config.default_query_limit
+                    )))
+                } else {
+                    Ok(limit)
+                }
+            }
+        ));
+
+        let (_, contract) = self.drive.get_contract_with_fetch_info_and_fee(
+            contract_id.to_buffer(),
+            None,
+            true,
+            None,
+            platform_version,
+        )?;
+
+        let contract = check_validation_result_with_data!(contract.ok_or(QueryError::Query(
+            QuerySyntaxError::DataContractNotFound(
+                "contract not found when querying from value with contract info",
+            )
+        )));
+
+        let document_type = check_validation_result_with_data!(contract
+            .contract
+            .document_type_for_name(document_type_name.as_str())
+            .map_err(|_| QueryError::InvalidArgument(format!(
+                "document type {} not found for contract {}",
+                document_type_name, contract_id
+            ))));
+
+        if !document_type.documents_keep_history() {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::InvalidArgument(format!(
+                    "document type {} of contract {} does not keep history",
+                    document_type_name, contract_id
+                )),
+            ));
+        }
+
+        let response = if prove {
+            let proof = self.drive.prove_document_history(
+                contract_id,
+                document_type_name.as_str(),
+                document_id,
+                start_at_ms,
+                Some(limit),
+                None,
+                platform_version,
+            )?;
+
+            GetDocumentHistoryResponseV0 {
+                result: Some(get_document_history_response_v0::Result::Proof(
+                    self.response_proof_v0(platform_state, proof),
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        } else {
+            let document_entries = self
+                .drive
+                .fetch_document_history(
+                    contract_id,
+                    document_type,
+                    document_id,
+                    start_at_ms,
+                    Some(limit),
+                    None,
+                    platform_version,
+                )?
+                .into_iter()
+                .map(|(date, document)| {
+                    Ok(get_document_history_response_v0::DocumentHistoryEntry {
+                        date,
+                        value: document.serialize(document_type, platform_version)?,
+                    })
+                })
+                .collect::<Result<Vec<_>, ProtocolError>>()?;
+
+            GetDocumentHistoryResponseV0 {
+                result: Some(get_document_history_response_v0::Result::DocumentHistory(
+                    get_document_history_response_v0::DocumentHistory { document_entries },
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::tests::{assert_invalid_identifier, setup_platform, store_data_contract};
+    use crate::rpc::core::MockCoreRPCLike;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::dashcore::Network;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+    use dpp::data_contract::DataContract;
+    use dpp::document::document_methods::DocumentMethodsV0;
+    use dpp::document::{Document, DocumentV0Getters};
+    use dpp::tests::fixtures::get_data_contract_fixture;
+    use dpp::tests::json_document::json_document_to_contract;
+    use drive::util::object_size_info::DocumentInfo::DocumentRefInfo;
+    use drive::util::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+
+    const PROFILE_KEEPS_HISTORY_CONTRACT_PATH: &str =
+        "tests/supporting_files/contract/dashpay/dashpay-contract-profile-keeps-history.json";
+
+    /// Stores a profile at the given times, the first time creating it and then updating it
+    fn store_profile_revisions(
+        platform: &Platform<MockCoreRPCLike>,
+        data_contract: &DataContract,
+        times_ms: &[u64],
+        platform_version: &PlatformVersion,
+    ) -> Document {
+        let document_type = data_contract
+            .document_type_for_name("profile")
+            .expect("expected profile document type");
+
+        let mut document = document_type
+            .random_document(Some(3), platform_version)
+            .expect("expected a random profile");
+
+        for (i, time_ms) in times_ms.iter().enumerate() {
+            if i == 0 {
+                platform
+                    .drive
+                    .add_document_for_contract(
+                        DocumentAndContractInfo {
+                            owned_document_info: OwnedDocumentInfo {
+                                document_info: DocumentRefInfo((&document, None)),
+                                owner_id: None,
+                            },
+                            contract: data_contract,
+                            document_type,
+                        },
+                        false,
+                        BlockInfo::default_with_time(*time_ms),
+                        true,
+                        None,
+                        platform_version,
+                        None,
+                    )
+                    .expect("expected to insert profile");
+            } else {
+                document.increment_revision().expect("expected a revision");
+
+                platform
+                    .drive
+                    .update_document_for_contract(
+                        &document,
+                        data_contract,
+                        document_type,
+                        None,
+                        BlockInfo::default_with_time(*time_ms),
+                        true,
+                        None,
+                        None,
+                        platform_version,
+                        None,
+                    )
+                    .expect("expected to update profile");
+            }
+        }
+
+        document
+    }
+
+    #[test]
+    fn test_invalid_document_id() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let request = GetDocumentHistoryRequestV0 {
+            data_contract_id: vec![0; 32],
+            document_type: "profile".to_string(),
+            document_id: vec![0; 8],
+            start_at_ms: 0,
+            limit: None,
+            prove: false,
+        };
+
+        let result = platform
+            .query_document_history_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::InvalidArgument(msg)] if msg.contains("document_id must be a valid identifier")
+        ));
+    }
+
+    #[test]
+    fn test_invalid_data_contract_id() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let request = GetDocumentHistoryRequestV0 {
+            data_contract_id: vec![0; 8],
+            document_type: "profile".to_string(),
+            document_id: vec![0; 32],
+            start_at_ms: 0,
+            limit: None,
+            prove: false,
+        };
+
+        let result = platform
+            .query_document_history_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert_invalid_identifier(result);
+    }
+
+    #[test]
+    fn test_document_type_without_history() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let created_data_contract = get_data_contract_fixture(None, 0, version.protocol_version);
+        store_data_contract(&platform, created_data_contract.data_contract(), version);
+
+        let request = GetDocumentHistoryRequestV0 {
+            data_contract_id: created_data_contract.data_contract().id().to_vec(),
+            document_type: "niceDocument".to_string(),
+            document_id: vec![0; 32],
+            start_at_ms: 0,
+            limit: None,
+            prove: false,
+        };
+
+        let result = platform
+            .query_document_history_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::InvalidArgument(msg)] if msg.contains("does not keep history")
+        ));
+    }
+
+    #[test]
+    fn test_document_history_oldest_first() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let data_contract =
+            json_document_to_contract(PROFILE_KEEPS_HISTORY_CONTRACT_PATH, false, version)
+                .expect("expected to load contract");
+        store_data_contract(&platform, &data_contract, version);
+
+        let document =
+            store_profile_revisions(&platform, &data_contract, &[1_000, 2_000, 3_000], version);
+
+        let request = GetDocumentHistoryRequestV0 {
+            data_contract_id: data_contract.id().to_vec(),
+            document_type: "profile".to_string(),
+            document_id: document.id().to_vec(),
+            start_at_ms: 2_000,
+            limit: None,
+            prove: false,
+        };
+
+        let result = platform
+            .query_document_history_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(result.is_valid());
+
+        let Some(GetDocumentHistoryResponseV0 {
+            result:
+                Some(get_document_history_response_v0::Result::DocumentHistory(
+                    get_document_history_response_v0::DocumentHistory { document_entries },
+                )),
+            metadata: Some(_),
+        }) = result.data
+        else {
+            panic!("expected document history");
+        };
+
+        assert_eq!(
+            document_entries
+                .iter()
+                .map(|entry| entry.date)
+                .collect::<Vec<_>>(),
+            vec![2_000, 3_000]
+        );
+
+        let document_type = data_contract
+            .document_type_for_name("profile")
+            .expect("expected profile document type");
+
+        let latest = Document::from_bytes(&document_entries[1].value, document_type, version)
+            .expect("expected to deserialize document");
+
+        assert_eq!(latest.revision(), document.revision());
+    }
+
+    #[test]
+    fn test_document_history_proof() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let data_contract =
+            json_document_to_contract(PROFILE_KEEPS_HISTORY_CONTRACT_PATH, false, version)
+                .expect("expected to load contract");
+        store_data_contract(&platform, &data_contract, version);
+
+        let document = store_profile_revisions(&platform, &data_contract, &[1_000, 2_000], version);
+
+        let request = GetDocumentHistoryRequestV0 {
+            data_contract_id: data_contract.id().to_vec(),
+            document_type: "profile".to_string(),
+            document_id: document.id().to_vec(),
+            start_at_ms: 0,
+            limit: None,
+            prove: true,
+        };
+
+        let result = platform
+            .query_document_history_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        let Some(GetDocumentHistoryResponseV0 {
+            result: Some(get_document_history_response_v0::Result::Proof(proof)),
+            metadata: Some(_),
+        }) = result.data
+        else {
+            panic!("expected a proof");
+        };
+
+        let document_type = data_contract
+            .document_type_for_name("profile")
+            .expect("expected profile document type");
+
+        let (_, revisions) = drive::drive::Drive::verify_document_history_proof(
+            &proof.grovedb_proof,
+            data_contract.id(),
+            document_type,
+            document.id(),
+            0,
+            Some(platform.config.drive.default_query_limit),
+            false,
+            version,
+        )
+        .expect("expected to verify proof");
+
+        assert_eq!(
+            revisions.keys().copied().collect::<Vec<_>>(),
+            vec![1_000, 2_000]
+        );
+    }
+}
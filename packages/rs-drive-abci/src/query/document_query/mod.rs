@@ -8,6 +8,9 @@ use dapi_grpc::platform::v0::get_documents_response::Version as ResponseVersion;
 use dapi_grpc::platform::v0::{GetDocumentsRequest, GetDocumentsResponse};
 use dpp::version::PlatformVersion;
 
+mod count;
+mod history;
+mod offers;
 mod v0;
 
 impl<C> Platform<C> {
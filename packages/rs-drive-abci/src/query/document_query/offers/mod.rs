@@ -0,0 +1,54 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_document_offers_request::Version as RequestVersion;
+use dapi_grpc::platform::v0::get_document_offers_response::Version as ResponseVersion;
+use dapi_grpc::platform::v0::{GetDocumentOffersRequest, GetDocumentOffersResponse};
+use dpp::version::PlatformVersion;
+
+mod v0;
+
+impl<C> Platform<C> {
+    /// Querying of the open offers made on a document
+    pub fn query_document_offers(
+        &self,
+        GetDocumentOffersRequest { version }: GetDocumentOffersRequest,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetDocumentOffersResponse>, Error> {
+        let Some(version) = version else {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::DecodingError("could not decode document offers query".to_string()),
+            ));
+        };
+
+        let feature_version_bounds = &platform_version.drive_abci.query.document_offers_query;
+
+        let feature_version = match &version {
+            RequestVersion::V0(_) => 0,
+        };
+        if !feature_version_bounds.check_version(feature_version) {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::UnsupportedQueryVersion(
+                    "document_offers".to_string(),
+                    feature_version_bounds.min_version,
+                    feature_version_bounds.max_version,
+                    platform_version.protocol_version,
+                    feature_version,
+                ),
+            ));
+        }
+        match version {
+            RequestVersion::V0(request_v0) => {
+                let result =
+                    self.query_document_offers_v0(request_v0, platform_state, platform_version)?;
+
+                Ok(result.map(|response_v0| GetDocumentOffersResponse {
+                    version: Some(ResponseVersion::V0(response_v0)),
+                }))
+            }
+        }
+    }
+}
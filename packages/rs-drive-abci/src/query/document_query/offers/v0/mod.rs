@@ -0,0 +1,221 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_document_offers_request::GetDocumentOffersRequestV0;
+use dapi_grpc::platform::v0::get_document_offers_response::{
+    get_document_offers_response_v0, GetDocumentOffersResponseV0,
+};
+use dpp::check_validation_result_with_data;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::identifier::Identifier;
+use dpp::serialization::PlatformSerializable;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use drive::error::query::QuerySyntaxError;
+
+impl<C> Platform<C> {
+    pub(super) fn query_document_offers_v0(
+        &self,
+        GetDocumentOffersRequestV0 {
+            data_contract_id,
+            document_type: document_type_name,
+            document_id,
+            start_after,
+            limit,
+            prove,
+        }: GetDocumentOffersRequestV0,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetDocumentOffersResponseV0>, Error> {
+        let config = &self.config.drive;
+
+        let contract_id: Identifier = check_validation_result_with_data!(data_contract_id
+            .try_into()
+            .map_err(|_| QueryError::InvalidArgument(
+                "id must be a valid identifier (32 bytes long)".to_string()
+            )));
+
+        let document_id: Identifier = check_validation_result_with_data!(document_id
+            .try_into()
+            .map_err(|_| QueryError::InvalidArgument(
+                "document_id must be a valid identifier (32 bytes long)".to_string()
+            )));
+
+        let start_after: Option<Identifier> = check_validation_result_with_data!(start_after
+            .map(|start_after| start_after.try_into())
+            .transpose()
+            .map_err(|_| QueryError::InvalidArgument(
+                "start_after must be a valid identifier (32 bytes long)".to_string()
+            )));
+
+        let limit = check_validation_result_with_data!(limit.map_or(
+            Ok(config.default_query_limit),
+            |limit| {
+                let limit = u16::try_from(limit)
+                    .map_err(|_| QueryError::InvalidArgument("limit out of bounds".to_string()))?;
+                if limit == 0 || limit > config.default_query_limit {
+                    Err(QueryError::InvalidArgument(format!(
+                        "limit {} out of bounds of [1, {}]",
+                        limit, config.default_query_limit
+                    )))
+                } else {
+                    Ok(limit)
+                }
+            }
+        ));
+
+        let (_, contract) = self.drive.get_contract_with_fetch_info_and_fee(
+            contract_id.to_buffer(),
+            None,
+            true,
+            None,
+            platform_version,
+        )?;
+
+        let contract = check_validation_result_with_data!(contract.ok_or(QueryError::Query(
+            QuerySyntaxError::DataContractNotFound(
+                "contract not found when querying from value with contract info",
+            )
+        )));
+
+        let document_type = check_validation_result_with_data!(contract
+            .contract
+            .document_type_for_name(document_type_name.as_str())
+            .map_err(|_| QueryError::InvalidArgument(format!(
+                "document type {} not found for contract {}",
+                document_type_name, contract_id
+            ))));
+
+        if !document_type.trade_mode().accepts_offers() {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::InvalidArgument(format!(
+                    "document type {} of contract {} does not accept offers",
+                    document_type_name, contract_id
+                )),
+            ));
+        }
+
+        let response = if prove {
+            let proof = self.drive.prove_document_offers(
+                contract_id,
+                document_type_name.as_str(),
+                document_id,
+                Some(limit),
+                start_after,
+                None,
+                platform_version,
+            )?;
+
+            GetDocumentOffersResponseV0 {
+                result: Some(get_document_offers_response_v0::Result::Proof(
+                    self.response_proof_v0(platform_state, proof),
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        } else {
+            let offers = self
+                .drive
+                .fetch_document_offers(
+                    contract_id,
+                    document_type_name.as_str(),
+                    document_id,
+                    Some(limit),
+                    start_after,
+                    None,
+                    platform_version,
+                )?
+                .into_iter()
+                .map(|offer| offer.serialize_to_bytes())
+                .collect::<Result<Vec<Vec<u8>>, _>>()?;
+
+            GetDocumentOffersResponseV0 {
+                result: Some(get_document_offers_response_v0::Result::Offers(
+                    get_document_offers_response_v0::DocumentOffers { offers },
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::tests::{assert_invalid_identifier, setup_platform, store_data_contract};
+    use dpp::dashcore::Network;
+    use dpp::tests::fixtures::get_data_contract_fixture;
+
+    #[test]
+    fn test_invalid_document_id() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let request = GetDocumentOffersRequestV0 {
+            data_contract_id: vec![0; 32],
+            document_type: "niceDocument".to_string(),
+            document_id: vec![0; 8],
+            start_after: None,
+            limit: None,
+            prove: false,
+        };
+
+        let result = platform
+            .query_document_offers_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::InvalidArgument(msg)] if msg.contains("document_id must be a valid identifier")
+        ));
+    }
+
+    #[test]
+    fn test_invalid_data_contract_id() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let request = GetDocumentOffersRequestV0 {
+            data_contract_id: vec![0; 8],
+            document_type: "niceDocument".to_string(),
+            document_id: vec![0; 32],
+            start_after: None,
+            limit: None,
+            prove: false,
+        };
+
+        let result = platform
+            .query_document_offers_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert_invalid_identifier(result);
+    }
+
+    #[test]
+    fn test_document_type_without_offers() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let created_data_contract = get_data_contract_fixture(None, 0, version.protocol_version);
+        store_data_contract(&platform, created_data_contract.data_contract(), version);
+
+        let request = GetDocumentOffersRequestV0 {
+            data_contract_id: created_data_contract.data_contract().id().to_vec(),
+            document_type: "niceDocument".to_string(),
+            document_id: vec![0; 32],
+            start_after: None,
+            limit: None,
+            prove: false,
+        };
+
+        let result = platform
+            .query_document_offers_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::InvalidArgument(msg)] if msg.contains("does not accept offers")
+        ));
+    }
+}
@@ -24,6 +24,7 @@ impl<C> Platform<C> {
             document_type_name,
             purposes,
             prove,
+            contracts_owner_id,
         }: GetIdentitiesContractKeysRequestV0,
         platform_state: &PlatformState,
         platform_version: &PlatformVersion,
@@ -51,6 +52,15 @@ impl<C> Platform<C> {
                 )
             }));
 
+        let contracts_owner_id = check_validation_result_with_data!(contracts_owner_id
+            .map(|contracts_owner_id| Bytes32::from_vec(contracts_owner_id).map(|bytes| bytes.0))
+            .transpose()
+            .map_err(|_| {
+                QueryError::InvalidArgument(
+                    "contracts_owner_id must be a valid identifier (32 bytes long)".to_string(),
+                )
+            }));
+
         let purposes = check_validation_result_with_data!(purposes
             .into_iter()
             .map(
@@ -69,6 +79,7 @@ impl<C> Platform<C> {
                 &contract_id,
                 document_type_name,
                 purposes,
+                contracts_owner_id,
                 None,
                 &platform_version.drive,
             )?;
@@ -90,6 +101,7 @@ impl<C> Platform<C> {
                 &contract_id,
                 document_type_name,
                 purposes,
+                contracts_owner_id,
                 None,
                 platform_version,
             )?;
@@ -160,6 +172,7 @@ mod tests {
             document_type_name: Some("contactRequest".to_string()),
             purposes: vec![Purpose::ENCRYPTION as i32, Purpose::DECRYPTION as i32],
             prove: false,
+            contracts_owner_id: None,
         };
 
         let result = platform
@@ -192,6 +205,7 @@ mod tests {
             document_type_name: Some("contactRequest".to_string()),
             purposes: vec![Purpose::ENCRYPTION as i32, Purpose::DECRYPTION as i32],
             prove: true,
+            contracts_owner_id: None,
         };
 
         let result = platform
@@ -211,6 +225,7 @@ mod tests {
             dashpay.id().as_bytes(),
             Some("contactRequest".to_string()),
             vec![Purpose::ENCRYPTION, Purpose::DECRYPTION],
+            None,
             false,
             platform_version,
         )
@@ -239,6 +254,7 @@ mod tests {
             document_type_name: Some("contactRequest".to_string()),
             purposes: vec![Purpose::ENCRYPTION as i32, Purpose::DECRYPTION as i32],
             prove: false,
+            contracts_owner_id: None,
         };
 
         let result = platform
@@ -267,6 +283,7 @@ mod tests {
             document_type_name: Some("contactRequest".to_string()),
             purposes: vec![Purpose::ENCRYPTION as i32, Purpose::DECRYPTION as i32],
             prove: true,
+            contracts_owner_id: None,
         };
 
         let result = platform
@@ -286,6 +303,7 @@ mod tests {
             &[2; 32],
             Some("contactRequest".to_string()),
             vec![Purpose::ENCRYPTION, Purpose::DECRYPTION],
+            None,
             false,
             platform_version,
         )
@@ -328,6 +346,7 @@ mod tests {
             document_type_name: Some("contactRequest".to_string()),
             purposes: vec![Purpose::ENCRYPTION as i32, Purpose::DECRYPTION as i32],
             prove: false,
+            contracts_owner_id: None,
         };
 
         let result = platform
@@ -370,6 +389,7 @@ mod tests {
             document_type_name: Some("contactRequest".to_string()),
             purposes: vec![Purpose::ENCRYPTION as i32, Purpose::DECRYPTION as i32],
             prove: true,
+            contracts_owner_id: None,
         };
 
         let result = platform
@@ -389,6 +409,7 @@ mod tests {
             &[2; 32],
             Some("contactRequest".to_string()),
             vec![Purpose::ENCRYPTION, Purpose::DECRYPTION],
+            None,
             false,
             platform_version,
         )
@@ -433,6 +454,7 @@ mod tests {
             document_type_name: Some("contactRequest".to_string()),
             purposes: vec![Purpose::ENCRYPTION as i32, Purpose::DECRYPTION as i32],
             prove: false,
+            contracts_owner_id: None,
         };
 
         let result = platform
@@ -477,6 +499,7 @@ mod tests {
             document_type_name: Some("contactRequest".to_string()),
             purposes: vec![Purpose::ENCRYPTION as i32, Purpose::DECRYPTION as i32],
             prove: true,
+            contracts_owner_id: None,
         };
 
         let result = platform
@@ -496,6 +519,7 @@ mod tests {
             dashpay.id().as_bytes(),
             Some("contactRequest".to_string()),
             vec![Purpose::ENCRYPTION, Purpose::DECRYPTION],
+            None,
             false,
             platform_version,
         )
@@ -608,6 +632,7 @@ mod tests {
             document_type_name: Some("contactRequest".to_string()),
             purposes: vec![Purpose::ENCRYPTION as i32, Purpose::DECRYPTION as i32],
             prove: false,
+            contracts_owner_id: None,
         };
 
         let result = platform
@@ -732,6 +757,7 @@ mod tests {
             document_type_name: Some("contactRequest".to_string()),
             purposes: vec![Purpose::ENCRYPTION as i32, Purpose::DECRYPTION as i32],
             prove: true,
+            contracts_owner_id: None,
         };
 
         let result = platform
@@ -751,6 +777,7 @@ mod tests {
             dashpay.id().as_bytes(),
             Some("contactRequest".to_string()),
             vec![Purpose::ENCRYPTION, Purpose::DECRYPTION],
+            None,
             false,
             platform_version,
         )
@@ -893,6 +920,7 @@ mod tests {
             document_type_name: Some("contactRequest".to_string()),
             purposes: vec![Purpose::ENCRYPTION as i32, Purpose::DECRYPTION as i32],
             prove: false,
+            contracts_owner_id: None,
         };
 
         let result = platform
@@ -1087,6 +1115,7 @@ mod tests {
             document_type_name: Some("contactRequest".to_string()),
             purposes: vec![Purpose::ENCRYPTION as i32, Purpose::DECRYPTION as i32],
             prove: true,
+            contracts_owner_id: None,
         };
 
         let result = platform
@@ -1110,6 +1139,7 @@ mod tests {
             dashpay.id().as_bytes(),
             Some("contactRequest".to_string()),
             vec![Purpose::ENCRYPTION, Purpose::DECRYPTION],
+            None,
             false,
             platform_version,
         )
@@ -1143,4 +1173,116 @@ mod tests {
             vec![None, None]
         );
     }
+
+    #[test]
+    fn test_identities_contract_keys_with_contracts_owner_keys_proof() {
+        let (platform, state, platform_version) = setup_platform(Some((1, 1)), Network::Testnet);
+
+        let dashpay = platform.drive.cache.system_data_contracts.load_dashpay();
+
+        let mut rng = StdRng::seed_from_u64(10);
+
+        let alice_id = rng.gen::<[u8; 32]>();
+        let alice = create_test_identity_with_rng(
+            &platform.drive,
+            alice_id,
+            &mut rng,
+            None,
+            platform_version,
+        )
+        .expect("expected to create a test identity");
+
+        let block = BlockInfo::default_with_epoch(Epoch::new(0).unwrap());
+
+        let (alice_enc_key, _) = IdentityPublicKey::random_key_with_known_attributes(
+            2,
+            &mut rng,
+            Purpose::ENCRYPTION,
+            SecurityLevel::MEDIUM,
+            KeyType::ECDSA_SECP256K1,
+            Some(ContractBounds::SingleContractDocumentType {
+                id: dashpay.id(),
+                document_type_name: "contactRequest".to_string(),
+            }),
+            platform_version,
+        )
+        .unwrap();
+
+        // Bound to every contract of the dashpay owner, used when no key of this purpose is
+        // bound to the contract itself
+        let (alice_owner_dec_key, _) = IdentityPublicKey::random_key_with_known_attributes(
+            3,
+            &mut rng,
+            Purpose::DECRYPTION,
+            SecurityLevel::MEDIUM,
+            KeyType::ECDSA_SECP256K1,
+            Some(ContractBounds::MultipleContractsOfSameOwner {
+                owner_id: dashpay.owner_id(),
+            }),
+            platform_version,
+        )
+        .unwrap();
+
+        let db_transaction = platform.drive.grove.start_transaction();
+
+        platform
+            .drive
+            .add_new_unique_keys_to_identity(
+                alice.id().to_buffer(),
+                vec![alice_enc_key.clone(), alice_owner_dec_key.clone()],
+                &block,
+                true,
+                Some(&db_transaction),
+                platform_version,
+            )
+            .expect("expected to add new keys");
+        platform
+            .drive
+            .grove
+            .commit_transaction(db_transaction)
+            .unwrap()
+            .expect("expected to be able to commit a transaction");
+
+        let request = GetIdentitiesContractKeysRequestV0 {
+            identities_ids: vec![alice.id().to_vec()],
+            contract_id: dashpay.id().to_vec(),
+            document_type_name: Some("contactRequest".to_string()),
+            purposes: vec![Purpose::ENCRYPTION as i32, Purpose::DECRYPTION as i32],
+            prove: true,
+            contracts_owner_id: Some(dashpay.owner_id().to_vec()),
+        };
+
+        let result = platform
+            .query_identities_contract_keys_v0(request, &state, platform_version)
+            .expect("query failed");
+
+        let GetIdentitiesContractKeysResponseV0 { result, .. } =
+            result.data.expect("expected data");
+
+        let Result::Proof(proof) = result.expect("expected proof") else {
+            panic!("expected IdentitiesKeys");
+        };
+
+        let (_, results) = Drive::verify_identities_contract_keys(
+            proof.grovedb_proof.as_slice(),
+            &[alice.id().to_buffer()],
+            dashpay.id().as_bytes(),
+            Some("contactRequest".to_string()),
+            vec![Purpose::ENCRYPTION, Purpose::DECRYPTION],
+            Some(dashpay.owner_id().to_buffer()),
+            false,
+            platform_version,
+        )
+        .expect("expected to verify proof");
+
+        assert_eq!(
+            results
+                .get(&alice.id())
+                .expect("expected this identifier")
+                .values()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![Some(alice_enc_key), Some(alice_owner_dec_key)]
+        );
+    }
 }
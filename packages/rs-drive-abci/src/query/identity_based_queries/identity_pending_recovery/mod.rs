@@ -0,0 +1,67 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_identity_pending_recovery_request::Version as RequestVersion;
+use dapi_grpc::platform::v0::get_identity_pending_recovery_response::Version as ResponseVersion;
+use dapi_grpc::platform::v0::{
+    GetIdentityPendingRecoveryRequest, GetIdentityPendingRecoveryResponse,
+};
+use dpp::version::PlatformVersion;
+
+mod v0;
+
+impl<C> Platform<C> {
+    /// Querying of the pending social recovery of an identity
+    pub fn query_identity_pending_recovery(
+        &self,
+        GetIdentityPendingRecoveryRequest { version }: GetIdentityPendingRecoveryRequest,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetIdentityPendingRecoveryResponse>, Error> {
+        let Some(version) = version else {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::DecodingError(
+                    "could not decode identity pending recovery query".to_string(),
+                ),
+            ));
+        };
+
+        let feature_version_bounds = &platform_version
+            .drive_abci
+            .query
+            .identity_based_queries
+            .identity_pending_recovery;
+
+        let feature_version = match &version {
+            RequestVersion::V0(_) => 0,
+        };
+        if !feature_version_bounds.check_version(feature_version) {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::UnsupportedQueryVersion(
+                    "identity_pending_recovery".to_string(),
+                    feature_version_bounds.min_version,
+                    feature_version_bounds.max_version,
+                    platform_version.protocol_version,
+                    feature_version,
+                ),
+            ));
+        }
+        match version {
+            RequestVersion::V0(request_v0) => {
+                let result = self.query_identity_pending_recovery_v0(
+                    request_v0,
+                    platform_state,
+                    platform_version,
+                )?;
+
+                Ok(
+                    result.map(|response_v0| GetIdentityPendingRecoveryResponse {
+                        version: Some(ResponseVersion::V0(response_v0)),
+                    }),
+                )
+            }
+        }
+    }
+}
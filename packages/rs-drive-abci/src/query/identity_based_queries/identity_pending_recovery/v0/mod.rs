@@ -0,0 +1,208 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_identity_pending_recovery_request::GetIdentityPendingRecoveryRequestV0;
+use dapi_grpc::platform::v0::get_identity_pending_recovery_response::{
+    get_identity_pending_recovery_response_v0, GetIdentityPendingRecoveryResponseV0,
+};
+use dpp::check_validation_result_with_data;
+use dpp::platform_value::Identifier;
+use dpp::serialization::PlatformSerializable;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+
+impl<C> Platform<C> {
+    pub(super) fn query_identity_pending_recovery_v0(
+        &self,
+        GetIdentityPendingRecoveryRequestV0 { identity_id, prove }: GetIdentityPendingRecoveryRequestV0,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetIdentityPendingRecoveryResponseV0>, Error> {
+        let identity_id = check_validation_result_with_data!(Identifier::from_vec(identity_id)
+            .map(|bytes| bytes.0)
+            .map_err(|_| QueryError::InvalidArgument(
+                "identity id must be 32 bytes long".to_string()
+            )));
+
+        let response = if prove {
+            let proof = self.drive.prove_identity_pending_recovery(
+                identity_id.0,
+                None,
+                platform_version,
+            )?;
+
+            GetIdentityPendingRecoveryResponseV0 {
+                result: Some(get_identity_pending_recovery_response_v0::Result::Proof(
+                    self.response_proof_v0(platform_state, proof),
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        } else {
+            let maybe_pending_recovery = self.drive.fetch_identity_pending_recovery(
+                identity_id.0,
+                None,
+                &mut vec![],
+                platform_version,
+            )?;
+
+            // empty here means that no recovery is pending
+            let pending_recovery = maybe_pending_recovery
+                .map(|pending_recovery| pending_recovery.serialize_to_bytes())
+                .transpose()?
+                .unwrap_or_default();
+
+            GetIdentityPendingRecoveryResponseV0 {
+                metadata: Some(self.response_metadata_v0(platform_state)),
+                result: Some(
+                    get_identity_pending_recovery_response_v0::Result::PendingRecovery(
+                        pending_recovery,
+                    ),
+                ),
+            }
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::tests::setup_platform;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::dashcore::Network;
+    use dpp::identity::recovery::{PendingIdentityRecovery, MIN_RECOVERY_DELAY};
+    use dpp::identity::IdentityPublicKey;
+    use dpp::serialization::PlatformDeserializable;
+    use drive::util::batch::DriveOperation::IdentityOperation;
+    use drive::util::batch::IdentityOperationType;
+    use rand::prelude::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_invalid_identity_id() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let request = GetIdentityPendingRecoveryRequestV0 {
+            identity_id: vec![0; 8],
+            prove: false,
+        };
+
+        let result = platform
+            .query_identity_pending_recovery_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::InvalidArgument(msg)] if msg.contains("identity id must be 32 bytes long")));
+    }
+
+    #[test]
+    fn test_no_pending_recovery() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let request = GetIdentityPendingRecoveryRequestV0 {
+            identity_id: vec![0; 32],
+            prove: false,
+        };
+
+        let result = platform
+            .query_identity_pending_recovery_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(result.is_valid());
+
+        assert!(matches!(
+            result.data,
+            Some(GetIdentityPendingRecoveryResponseV0 {
+                result: Some(get_identity_pending_recovery_response_v0::Result::PendingRecovery(bytes)),
+                metadata: Some(_),
+            }) if bytes.is_empty()
+        ));
+    }
+
+    #[test]
+    fn test_pending_recovery_is_found() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+        let mut rng = StdRng::seed_from_u64(10);
+        let id = rng.gen::<[u8; 32]>();
+
+        let (new_master_key, _) =
+            IdentityPublicKey::random_ecdsa_master_authentication_key_with_rng(
+                5, &mut rng, version,
+            )
+            .expect("expected a random key");
+
+        let pending_recovery = PendingIdentityRecovery {
+            new_master_key,
+            approved_by: BTreeSet::from([Identifier::new(rng.gen::<[u8; 32]>())]),
+            initiated_at: 1000,
+            executable_at: 1000 + MIN_RECOVERY_DELAY,
+        };
+
+        platform
+            .drive
+            .apply_drive_operations(
+                vec![IdentityOperation(
+                    IdentityOperationType::SetIdentityPendingRecovery {
+                        identity_id: id,
+                        pending_recovery: pending_recovery.clone(),
+                    },
+                )],
+                true,
+                &BlockInfo::genesis(),
+                None,
+                version,
+                None,
+            )
+            .expect("expected to apply drive operations");
+
+        let request = GetIdentityPendingRecoveryRequestV0 {
+            identity_id: id.to_vec(),
+            prove: false,
+        };
+
+        let result = platform
+            .query_identity_pending_recovery_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(result.is_valid());
+
+        let Some(GetIdentityPendingRecoveryResponseV0 {
+            result: Some(get_identity_pending_recovery_response_v0::Result::PendingRecovery(bytes)),
+            metadata: Some(_),
+        }) = result.data
+        else {
+            panic!("expected a pending recovery");
+        };
+
+        let restored = PendingIdentityRecovery::deserialize_from_bytes(&bytes)
+            .expect("expected to deserialize the pending recovery");
+        assert_eq!(restored, pending_recovery);
+    }
+
+    #[test]
+    fn test_pending_recovery_absence_proof() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let request = GetIdentityPendingRecoveryRequestV0 {
+            identity_id: vec![0; 32],
+            prove: true,
+        };
+
+        let result = platform
+            .query_identity_pending_recovery_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.data,
+            Some(GetIdentityPendingRecoveryResponseV0 {
+                result: Some(get_identity_pending_recovery_response_v0::Result::Proof(_)),
+                metadata: Some(_),
+            })
+        ));
+    }
+}
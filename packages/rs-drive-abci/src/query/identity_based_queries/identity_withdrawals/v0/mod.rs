@@ -0,0 +1,344 @@
+use crate::error::execution::ExecutionError;
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_identity_withdrawals_request::GetIdentityWithdrawalsRequestV0;
+use dapi_grpc::platform::v0::get_identity_withdrawals_response::{
+    get_identity_withdrawals_response_v0, GetIdentityWithdrawalsResponseV0,
+};
+use dpp::check_validation_result_with_data;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+use dpp::platform_value::Identifier;
+use dpp::system_data_contracts::withdrawals_contract::v1::document_types::withdrawal;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+
+impl<C> Platform<C> {
+    pub(super) fn query_identity_withdrawals_v0(
+        &self,
+        GetIdentityWithdrawalsRequestV0 {
+            identity_id,
+            start_after,
+            limit,
+            prove,
+        }: GetIdentityWithdrawalsRequestV0,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetIdentityWithdrawalsResponseV0>, Error> {
+        let config = &self.config.drive;
+
+        let identity_id: Identifier = check_validation_result_with_data!(identity_id
+            .try_into()
+            .map_err(|_| QueryError::InvalidArgument(
+                "id must be a valid identifier (32 bytes long)".to_string()
+            )));
+
+        let start_after: Option<Identifier> = check_validation_result_with_data!(start_after
+            .map(|start_after| start_after.try_into())
+            .transpose()
+            .map_err(|_| QueryError::InvalidArgument(
+                "start_after must be a valid identifier (32 bytes long)".to_string()
+            )));
+
+        let limit = check_validation_result_with_data!(limit.map_or(
+            Ok(config.default_query_limit),
+            |limit| {
+                let limit = u16::try_from(limit)
+                    .map_err(|_| QueryError::InvalidArgument("limit out of bounds".to_string()))?;
+                if limit == 0 || limit > config.default_query_limit {
+                    Err(QueryError::InvalidArgument(format!(
+                        "limit {} out of bounds of [1, {}]",
+                        limit, config.default_query_limit
+                    )))
+                } else {
+                    Ok(limit)
+                }
+            }
+        ));
+
+        let response = if prove {
+            let proof = self.drive.prove_identity_withdrawal_documents(
+                identity_id,
+                Some(limit),
+                start_after,
+                None,
+                platform_version,
+            )?;
+
+            GetIdentityWithdrawalsResponseV0 {
+                result: Some(get_identity_withdrawals_response_v0::Result::Proof(
+                    self.response_proof_v0(platform_state, proof),
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        } else {
+            let withdrawals = self
+                .drive
+                .fetch_identity_withdrawal_documents(
+                    identity_id,
+                    Some(limit),
+                    start_after,
+                    None,
+                    platform_version,
+                )?
+                .iter()
+                .map(identity_withdrawal_from_document)
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            GetIdentityWithdrawalsResponseV0 {
+                result: Some(get_identity_withdrawals_response_v0::Result::Withdrawals(
+                    get_identity_withdrawals_response_v0::IdentityWithdrawals { withdrawals },
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
+
+fn identity_withdrawal_from_document(
+    document: &Document,
+) -> Result<get_identity_withdrawals_response_v0::IdentityWithdrawal, Error> {
+    let properties = document.properties();
+
+    let corrupted = |property: &str| {
+        Error::Execution(ExecutionError::CorruptedDriveResponse(format!(
+            "can't get {} from withdrawal document {}",
+            property,
+            document.id()
+        )))
+    };
+
+    Ok(get_identity_withdrawals_response_v0::IdentityWithdrawal {
+        id: document.id().to_vec(),
+        status: properties
+            .get_integer::<u8>(withdrawal::properties::STATUS)
+            .map_err(|_| corrupted(withdrawal::properties::STATUS))? as u32,
+        transaction_index: properties
+            .get_optional_integer(withdrawal::properties::TRANSACTION_INDEX)
+            .map_err(|_| corrupted(withdrawal::properties::TRANSACTION_INDEX))?,
+        transaction_sign_height: properties
+            .get_optional_integer(withdrawal::properties::TRANSACTION_SIGN_HEIGHT)
+            .map_err(|_| corrupted(withdrawal::properties::TRANSACTION_SIGN_HEIGHT))?,
+        amount: properties
+            .get_integer(withdrawal::properties::AMOUNT)
+            .map_err(|_| corrupted(withdrawal::properties::AMOUNT))?,
+        core_fee_per_byte: properties
+            .get_integer(withdrawal::properties::CORE_FEE_PER_BYTE)
+            .map_err(|_| corrupted(withdrawal::properties::CORE_FEE_PER_BYTE))?,
+        output_script: properties
+            .get_bytes(withdrawal::properties::OUTPUT_SCRIPT)
+            .map_err(|_| corrupted(withdrawal::properties::OUTPUT_SCRIPT))?,
+        created_at: document.created_at().unwrap_or_default(),
+        updated_at: document.updated_at().unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::tests::{assert_invalid_identifier, setup_platform};
+    use crate::rpc::core::MockCoreRPCLike;
+    use dpp::dashcore::Network;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::data_contracts::withdrawals_contract::WithdrawalStatus;
+    use dpp::data_contracts::SystemDataContract;
+    use dpp::document::DocumentV0Setters;
+    use dpp::identity::core_script::CoreScript;
+    use dpp::platform_value::platform_value;
+    use dpp::system_data_contracts::load_system_data_contract;
+    use dpp::tests::fixtures::get_withdrawal_document_fixture;
+    use dpp::withdrawal::Pooling;
+    use drive::util::test_helpers::setup::setup_document;
+
+    fn insert_withdrawal(
+        platform: &Platform<MockCoreRPCLike>,
+        owner_id: Identifier,
+        status: WithdrawalStatus,
+        transaction_index: Option<u64>,
+        updated_at: u64,
+        platform_version: &PlatformVersion,
+    ) -> Identifier {
+        let data_contract =
+            load_system_data_contract(SystemDataContract::Withdrawals, platform_version)
+                .expect("to load system data contract");
+
+        let mut properties = platform_value!({
+            "amount": 1000u64,
+            "coreFeePerByte": 1u32,
+            "pooling": Pooling::Never as u8,
+            "outputScript": CoreScript::from_bytes((0..23).collect::<Vec<u8>>()),
+            "status": status as u8,
+        });
+
+        if let Some(transaction_index) = transaction_index {
+            properties
+                .insert(
+                    withdrawal::properties::TRANSACTION_INDEX.to_string(),
+                    transaction_index.into(),
+                )
+                .expect("expected to insert the transaction index");
+        }
+
+        let mut document = get_withdrawal_document_fixture(
+            &data_contract,
+            owner_id,
+            properties,
+            None,
+            platform_version.protocol_version,
+        )
+        .expect("expected withdrawal document");
+
+        document.set_created_at(Some(updated_at));
+        document.set_updated_at(Some(updated_at));
+
+        let document_type = data_contract
+            .document_type_for_name(withdrawal::NAME)
+            .expect("expected to get document type");
+
+        setup_document(
+            &platform.drive,
+            &document,
+            &data_contract,
+            document_type,
+            None,
+        );
+
+        document.id()
+    }
+
+    #[test]
+    fn test_invalid_identity_id() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let request = GetIdentityWithdrawalsRequestV0 {
+            identity_id: vec![0; 8],
+            start_after: None,
+            limit: None,
+            prove: false,
+        };
+
+        let result = platform
+            .query_identity_withdrawals_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert_invalid_identifier(result);
+    }
+
+    #[test]
+    fn test_invalid_limit() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let request = GetIdentityWithdrawalsRequestV0 {
+            identity_id: vec![0; 32],
+            start_after: None,
+            limit: Some(0),
+            prove: false,
+        };
+
+        let result = platform
+            .query_identity_withdrawals_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::InvalidArgument(msg)] if msg.contains("limit 0 out of bounds")
+        ));
+    }
+
+    #[test]
+    fn test_identity_withdrawals_most_recent_first() {
+        let (platform, state, version) = setup_platform(Some((1, 1)), Network::Testnet);
+
+        let owner_id = Identifier::new([1; 32]);
+
+        let completed_id = insert_withdrawal(
+            &platform,
+            owner_id,
+            WithdrawalStatus::COMPLETE,
+            Some(3),
+            1_000,
+            version,
+        );
+        insert_withdrawal(
+            &platform,
+            Identifier::new([2; 32]),
+            WithdrawalStatus::QUEUED,
+            None,
+            2_000,
+            version,
+        );
+        let queued_id = insert_withdrawal(
+            &platform,
+            owner_id,
+            WithdrawalStatus::QUEUED,
+            None,
+            3_000,
+            version,
+        );
+
+        let request = GetIdentityWithdrawalsRequestV0 {
+            identity_id: owner_id.to_vec(),
+            start_after: None,
+            limit: None,
+            prove: false,
+        };
+
+        let result = platform
+            .query_identity_withdrawals_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(result.is_valid());
+
+        let Some(GetIdentityWithdrawalsResponseV0 {
+            result:
+                Some(get_identity_withdrawals_response_v0::Result::Withdrawals(
+                    get_identity_withdrawals_response_v0::IdentityWithdrawals { withdrawals },
+                )),
+            metadata: Some(_),
+        }) = result.data
+        else {
+            panic!("expected withdrawals");
+        };
+
+        assert_eq!(withdrawals.len(), 2);
+
+        assert_eq!(withdrawals[0].id, queued_id.to_vec());
+        assert_eq!(withdrawals[0].status, WithdrawalStatus::QUEUED as u32);
+        assert_eq!(withdrawals[0].transaction_index, None);
+        assert_eq!(withdrawals[0].updated_at, 3_000);
+
+        assert_eq!(withdrawals[1].id, completed_id.to_vec());
+        assert_eq!(withdrawals[1].status, WithdrawalStatus::COMPLETE as u32);
+        assert_eq!(withdrawals[1].transaction_index, Some(3));
+        assert_eq!(withdrawals[1].amount, 1000);
+    }
+
+    #[test]
+    fn test_identity_withdrawals_proof() {
+        let (platform, state, version) = setup_platform(Some((1, 1)), Network::Testnet);
+
+        let request = GetIdentityWithdrawalsRequestV0 {
+            identity_id: vec![1; 32],
+            start_after: None,
+            limit: None,
+            prove: true,
+        };
+
+        let result = platform
+            .query_identity_withdrawals_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.data,
+            Some(GetIdentityWithdrawalsResponseV0 {
+                result: Some(get_identity_withdrawals_response_v0::Result::Proof(_)),
+                metadata: Some(_),
+            })
+        ));
+    }
+}
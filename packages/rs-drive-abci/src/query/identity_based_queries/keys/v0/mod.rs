@@ -12,7 +12,10 @@ use drive::error::query::QuerySyntaxError;
 use std::collections::BTreeMap;
 
 use crate::platform_types::platform_state::PlatformState;
-use dpp::identity::{KeyID, Purpose, SecurityLevel};
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::identity_public_key::accessors::v1::IdentityPublicKeyGettersV1;
+use dpp::identity::{IdentityPublicKey, KeyID, Purpose, SecurityLevel};
+use dpp::serialization::PlatformDeserializable;
 use dpp::validation::ValidationResult;
 use dpp::version::PlatformVersion;
 use drive::drive::identity::key::fetch::{
@@ -79,6 +82,7 @@ impl<C> Platform<C> {
             limit,
             offset,
             prove,
+            valid_at,
         }: GetIdentityKeysRequestV0,
         platform_state: &PlatformState,
         platform_version: &PlatformVersion,
@@ -147,10 +151,21 @@ impl<C> Platform<C> {
                 metadata: Some(self.response_metadata_v0(platform_state)),
             }
         } else {
-            let keys: SerializedKeyVec =
+            let mut keys: SerializedKeyVec =
                 self.drive
                     .fetch_identity_keys(key_request, None, platform_version)?;
 
+            if let Some(valid_at) = valid_at {
+                let mut usable_keys = Vec::with_capacity(keys.len());
+                for key_bytes in keys {
+                    let key = IdentityPublicKey::deserialize_from_bytes(&key_bytes)?;
+                    if !key.is_disabled() && key.is_within_validity_window(valid_at) {
+                        usable_keys.push(key_bytes);
+                    }
+                }
+                keys = usable_keys;
+            }
+
             GetIdentityKeysResponseV0 {
                 result: Some(get_identity_keys_response_v0::Result::Keys(
                     get_identity_keys_response_v0::Keys { keys_bytes: keys },
@@ -169,7 +184,11 @@ mod tests {
     use crate::query::tests::{assert_invalid_identifier, setup_platform};
     use dapi_grpc::platform::v0::key_request_type::Request;
     use dapi_grpc::platform::v0::{AllKeys, KeyRequestType, SearchKey, SecurityLevelMap};
+    use dpp::block::block_info::BlockInfo;
     use dpp::dashcore::Network;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::identity_public_key::accessors::v1::IdentityPublicKeySettersV1;
+    use dpp::identity::Identity;
 
     #[test]
     fn test_invalid_identity_id() {
@@ -181,6 +200,7 @@ mod tests {
             limit: None,
             offset: None,
             prove: false,
+            valid_at: None,
         };
 
         let result = platform
@@ -200,6 +220,7 @@ mod tests {
             limit: Some(u32::MAX),
             offset: None,
             prove: false,
+            valid_at: None,
         };
 
         let result = platform
@@ -222,6 +243,7 @@ mod tests {
             limit: Some((platform.config.drive.max_query_limit + 1) as u32),
             offset: None,
             prove: false,
+            valid_at: None,
         };
 
         let result = platform
@@ -249,6 +271,7 @@ mod tests {
             limit: None,
             offset: Some(u32::MAX),
             prove: false,
+            valid_at: None,
         };
 
         let result = platform
@@ -271,6 +294,7 @@ mod tests {
             limit: None,
             offset: None,
             prove: false,
+            valid_at: None,
         };
 
         let result = platform
@@ -293,6 +317,7 @@ mod tests {
             limit: None,
             offset: None,
             prove: false,
+            valid_at: None,
         };
 
         let result = platform
@@ -326,6 +351,7 @@ mod tests {
             limit: None,
             offset: None,
             prove: false,
+            valid_at: None,
         };
 
         let result = platform
@@ -350,6 +376,7 @@ mod tests {
             limit: None,
             offset: None,
             prove: false,
+            valid_at: None,
         };
 
         let result = platform
@@ -377,6 +404,7 @@ mod tests {
             limit: None,
             offset: None,
             prove: true,
+            valid_at: None,
         };
 
         let result = platform
@@ -391,4 +419,63 @@ mod tests {
             })
         ));
     }
+
+    #[test]
+    fn test_keys_filtered_by_valid_at() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let mut identity =
+            Identity::random_identity(3, Some(50), version).expect("expected an identity");
+        let identity_id = identity.id();
+
+        let public_keys = identity.public_keys_mut();
+        public_keys
+            .get_mut(&1)
+            .expect("expected key 1")
+            .set_valid_until(Some(1_000));
+        public_keys
+            .get_mut(&2)
+            .expect("expected key 2")
+            .set_valid_from(Some(2_000));
+
+        platform
+            .drive
+            .add_new_identity(identity, false, &BlockInfo::default(), true, None, version)
+            .expect("expected to add identity");
+
+        let request = GetIdentityKeysRequestV0 {
+            identity_id: identity_id.to_vec(),
+            request_type: Some(KeyRequestType {
+                request: Some(Request::AllKeys(AllKeys {})),
+            }),
+            limit: None,
+            offset: None,
+            prove: false,
+            valid_at: Some(1_500),
+        };
+
+        let result = platform
+            .query_keys_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        let Some(GetIdentityKeysResponseV0 {
+            result: Some(get_identity_keys_response_v0::Result::Keys(keys)),
+            ..
+        }) = result.data
+        else {
+            panic!("expected keys");
+        };
+
+        let key_ids: Vec<KeyID> = keys
+            .keys_bytes
+            .iter()
+            .map(|bytes| {
+                IdentityPublicKey::deserialize_from_bytes(bytes)
+                    .expect("expected a key")
+                    .id()
+            })
+            .collect();
+
+        assert_eq!(key_ids, vec![0]);
+    }
 }
@@ -5,4 +5,6 @@ mod identity;
 mod identity_by_public_key_hash;
 mod identity_contract_nonce;
 mod identity_nonce;
+mod identity_pending_recovery;
+mod identity_withdrawals;
 mod keys;
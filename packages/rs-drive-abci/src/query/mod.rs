@@ -1,10 +1,12 @@
 mod data_contract_based_queries;
 mod document_query;
 mod identity_based_queries;
+mod platform_events;
 mod prefunded_specialized_balances;
 mod proofs;
 mod response_metadata;
 mod service;
+mod state_transition_fee_estimation;
 mod system;
 mod voting;
 
@@ -0,0 +1,134 @@
+use crate::error::execution::ExecutionError;
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_events::CommittedBlock;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::subscribe_platform_events_request::Version as RequestVersion;
+use dapi_grpc::platform::v0::subscribe_platform_events_response::Version as ResponseVersion;
+use dapi_grpc::platform::v0::{SubscribePlatformEventsRequest, SubscribePlatformEventsResponse};
+use dpp::prelude::Identifier;
+use dpp::version::PlatformVersion;
+use drive::query::WhereClause;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+mod v0;
+
+/// Filters of a platform events subscription
+///
+/// An event is sent to the subscriber if it matches any of the filters.
+#[derive(Debug, Clone, Default)]
+pub struct PlatformEventFilters {
+    /// Send every committed block
+    pub blocks: bool,
+    /// Send state transitions made by or affecting these identities
+    pub identity_ids: BTreeSet<Identifier>,
+    /// Send state transitions creating, updating or using these data contracts
+    pub data_contract_ids: BTreeSet<Identifier>,
+    /// Send document changes matching these filters
+    pub documents: Vec<DocumentEventFilter>,
+}
+
+/// Matches changes of documents of a document type
+#[derive(Debug, Clone)]
+pub struct DocumentEventFilter {
+    /// The data contract containing the documents
+    pub data_contract_id: Identifier,
+    /// The type of the documents
+    pub document_type_name: String,
+    /// Conditions the changed documents must meet
+    pub where_clauses: Vec<WhereClause>,
+}
+
+/// A committed block to turn into the events of a subscription
+#[derive(Debug, Clone)]
+pub struct PlatformEventsQuery {
+    /// Filters of the subscription
+    pub filters: Arc<PlatformEventFilters>,
+    /// The committed block
+    pub block: Arc<CommittedBlock>,
+}
+
+impl<C> Platform<C> {
+    /// Validates a platform events subscription and returns its filters
+    pub fn query_platform_event_filters(
+        &self,
+        SubscribePlatformEventsRequest { version }: SubscribePlatformEventsRequest,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<PlatformEventFilters>, Error> {
+        let Some(version) = version else {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::DecodingError(
+                    "could not decode platform events subscription".to_string(),
+                ),
+            ));
+        };
+
+        let feature_version_bounds = &platform_version
+            .drive_abci
+            .query
+            .platform_events_subscription;
+
+        let feature_version = match &version {
+            RequestVersion::V0(_) => 0,
+        };
+        if !feature_version_bounds.check_version(feature_version) {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::UnsupportedQueryVersion(
+                    "platform_events_subscription".to_string(),
+                    feature_version_bounds.min_version,
+                    feature_version_bounds.max_version,
+                    platform_version.protocol_version,
+                    feature_version,
+                ),
+            ));
+        }
+        match version {
+            RequestVersion::V0(request_v0) => {
+                self.query_platform_event_filters_v0(request_v0, platform_state, platform_version)
+            }
+        }
+    }
+
+    /// Events of a committed block matching the filters of a subscription, with their proofs
+    ///
+    /// Past states can't be proved, so proofs are taken from the latest committed state.
+    /// It is the state of the block when events are queried as soon as the block is committed.
+    /// Otherwise, the proofs are taken at a later height. The height of the proofs is returned
+    /// in the metadata of each event.
+    pub fn query_platform_events(
+        &self,
+        query: PlatformEventsQuery,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<Vec<SubscribePlatformEventsResponse>>, Error> {
+        match platform_version
+            .drive_abci
+            .query
+            .platform_events_subscription
+            .default_current_version
+        {
+            0 => {
+                let result =
+                    self.query_platform_events_v0(query, platform_state, platform_version)?;
+
+                Ok(result.map(|responses_v0| {
+                    responses_v0
+                        .into_iter()
+                        .map(|response_v0| SubscribePlatformEventsResponse {
+                            version: Some(ResponseVersion::V0(response_v0)),
+                        })
+                        .collect()
+                }))
+            }
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "query_platform_events".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
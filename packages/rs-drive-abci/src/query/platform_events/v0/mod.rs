@@ -0,0 +1,793 @@
+use crate::error::execution::ExecutionError;
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::platform_events::{DocumentEventFilter, PlatformEventFilters, PlatformEventsQuery};
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_proofs_request::{GetProofsRequestV0, Version as ProofsRequestVersion};
+use dapi_grpc::platform::v0::get_proofs_response::{get_proofs_response_v0, Version as ProofsResponseVersion};
+use dapi_grpc::platform::v0::subscribe_platform_events_request::subscribe_platform_events_request_v0::{
+    event_filter, state_transition_filter, DocumentFilter, EventFilter, StateTransitionFilter,
+};
+use dapi_grpc::platform::v0::subscribe_platform_events_request::SubscribePlatformEventsRequestV0;
+use dapi_grpc::platform::v0::subscribe_platform_events_response::subscribe_platform_events_response_v0::{
+    document_changed, BlockCommitted, DocumentChanged, Event, StateTransitionExecuted,
+};
+use dapi_grpc::platform::v0::subscribe_platform_events_response::SubscribePlatformEventsResponseV0;
+use dapi_grpc::platform::v0::{GetProofsRequest, GetProofsResponse, Proof, ResponseMetadata};
+use dpp::check_validation_result_with_data;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::document::document_methods::DocumentMethodsV0;
+use dpp::document::{Document, DocumentV0};
+use dpp::platform_value::Value;
+use dpp::prelude::Identifier;
+use dpp::state_transition::data_contract_create_transition::accessors::DataContractCreateTransitionAccessorsV0;
+use dpp::state_transition::data_contract_delete_transition::accessors::DataContractDeleteTransitionAccessorsV0;
+use dpp::state_transition::data_contract_update_transition::accessors::DataContractUpdateTransitionAccessorsV0;
+use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
+use dpp::state_transition::documents_batch_transition::document_transition::document_transfer_transition::v0::v0_methods::DocumentTransferTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::{
+    DocumentTransition, DocumentTransitionV0Methods,
+};
+use dpp::state_transition::auction_bid_transition::accessors::AuctionBidTransitionAccessorsV0;
+use dpp::state_transition::identity_credit_batch_transfer_transition::accessors::IdentityCreditBatchTransferTransitionAccessorsV0;
+use dpp::state_transition::identity_credit_transfer_transition::accessors::IdentityCreditTransferTransitionAccessorsV0;
+use dpp::state_transition::masternode_vote_transition::accessors::MasternodeVoteTransitionAccessorsV0;
+use dpp::state_transition::StateTransition;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_polls::VotePoll;
+use dpp::voting::votes::resource_vote::accessors::v0::ResourceVoteGettersV0;
+use dpp::voting::votes::Vote;
+use drive::error::query::QuerySyntaxError;
+use drive::query::WhereClause;
+
+/// System fields known for every document change
+const DOCUMENT_CHANGE_SYSTEM_FIELDS: [&str; 2] = ["$id", "$ownerId"];
+
+impl<C> Platform<C> {
+    pub(super) fn query_platform_event_filters_v0(
+        &self,
+        SubscribePlatformEventsRequestV0 { filters }: SubscribePlatformEventsRequestV0,
+        _platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<PlatformEventFilters>, Error> {
+        if filters.is_empty() {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::InvalidArgument("at least one event filter is required".to_string()),
+            ));
+        }
+
+        let mut event_filters = PlatformEventFilters::default();
+
+        for EventFilter { filter } in filters {
+            match filter {
+                Some(event_filter::Filter::Blocks(blocks)) => {
+                    event_filters.blocks |= blocks;
+                }
+                Some(event_filter::Filter::StateTransitions(StateTransitionFilter { filter })) => {
+                    match filter {
+                        Some(state_transition_filter::Filter::IdentityId(identity_id)) => {
+                            let identity_id: Identifier =
+                                check_validation_result_with_data!(identity_id.try_into().map_err(
+                                    |_| QueryError::InvalidArgument(
+                                        "id must be a valid identifier (32 bytes long)".to_string()
+                                    )
+                                ));
+
+                            event_filters.identity_ids.insert(identity_id);
+                        }
+                        Some(state_transition_filter::Filter::DataContractId(data_contract_id)) => {
+                            let data_contract_id: Identifier =
+                                check_validation_result_with_data!(data_contract_id
+                                    .try_into()
+                                    .map_err(|_| QueryError::InvalidArgument(
+                                        "id must be a valid identifier (32 bytes long)".to_string()
+                                    )));
+
+                            event_filters.data_contract_ids.insert(data_contract_id);
+                        }
+                        None => {
+                            return Ok(QueryValidationResult::new_with_error(
+                                QueryError::InvalidArgument(
+                                    "state transition filter must have an identity or a data contract id"
+                                        .to_string(),
+                                ),
+                            ));
+                        }
+                    }
+                }
+                Some(event_filter::Filter::Documents(document_filter)) => {
+                    let document_filter = check_validation_result_with_data!(
+                        self.document_event_filter_v0(document_filter, platform_version)?
+                    );
+
+                    event_filters.documents.push(document_filter);
+                }
+                None => {
+                    return Ok(QueryValidationResult::new_with_error(
+                        QueryError::InvalidArgument("event filter must not be empty".to_string()),
+                    ));
+                }
+            }
+        }
+
+        Ok(QueryValidationResult::new_with_data(event_filters))
+    }
+
+    fn document_event_filter_v0(
+        &self,
+        DocumentFilter {
+            data_contract_id,
+            document_type: document_type_name,
+            r#where,
+        }: DocumentFilter,
+        platform_version: &PlatformVersion,
+    ) -> Result<Result<DocumentEventFilter, QueryError>, Error> {
+        let Ok(data_contract_id) = Identifier::try_from(data_contract_id) else {
+            return Ok(Err(QueryError::InvalidArgument(
+                "id must be a valid identifier (32 bytes long)".to_string(),
+            )));
+        };
+
+        let Some(contract_fetch_info) = self.drive.get_contract_with_fetch_info(
+            data_contract_id.to_buffer(),
+            false,
+            None,
+            platform_version,
+        )?
+        else {
+            return Ok(Err(QueryError::Query(
+                QuerySyntaxError::DataContractNotFound(
+                    "contract not found when subscribing to document changes",
+                ),
+            )));
+        };
+
+        let Ok(document_type) = contract_fetch_info
+            .contract
+            .document_type_for_name(document_type_name.as_str())
+        else {
+            return Ok(Err(QueryError::InvalidArgument(format!(
+                "document type {} not found for contract {}",
+                document_type_name, data_contract_id
+            ))));
+        };
+
+        let where_clauses = if r#where.is_empty() {
+            vec![]
+        } else {
+            let Ok(Value::Array(clauses)) = ciborium::de::from_reader(r#where.as_slice()) else {
+                return Ok(Err(QueryError::Query(
+                    QuerySyntaxError::DeserializationError(
+                        "unable to decode 'where' query from cbor".to_string(),
+                    ),
+                )));
+            };
+
+            let where_clauses = clauses
+                .iter()
+                .map(|clause| match clause {
+                    Value::Array(clause_components) => {
+                        WhereClause::from_components(clause_components)
+                    }
+                    _ => Err(drive::error::Error::Query(
+                        QuerySyntaxError::InvalidFormatWhereClause("where clause must be an array"),
+                    )),
+                })
+                .collect::<Result<Vec<WhereClause>, drive::error::Error>>();
+
+            match where_clauses {
+                Ok(where_clauses) => where_clauses,
+                Err(drive::error::Error::Query(error)) => return Ok(Err(QueryError::Query(error))),
+                Err(error) => return Err(error.into()),
+            }
+        };
+
+        for where_clause in &where_clauses {
+            if !DOCUMENT_CHANGE_SYSTEM_FIELDS.contains(&where_clause.field.as_str())
+                && !document_type
+                    .flattened_properties()
+                    .contains_key(&where_clause.field)
+            {
+                return Ok(Err(QueryError::InvalidArgument(format!(
+                    "where clause field {} can't be used to filter document changes",
+                    where_clause.field
+                ))));
+            }
+
+            // Matching an empty value serializes the values of the clause,
+            // so clauses which could never be evaluated are rejected upfront
+            match where_clause.matches_serialized_value(&[], document_type, platform_version) {
+                Ok(_) => {}
+                Err(drive::error::Error::Query(error)) => return Ok(Err(QueryError::Query(error))),
+                Err(drive::error::Error::Protocol(error)) => {
+                    return Ok(Err(QueryError::Protocol(error)))
+                }
+                Err(error) => return Err(error.into()),
+            }
+        }
+
+        Ok(Ok(DocumentEventFilter {
+            data_contract_id,
+            document_type_name,
+            where_clauses,
+        }))
+    }
+
+    pub(super) fn query_platform_events_v0(
+        &self,
+        PlatformEventsQuery { filters, block }: PlatformEventsQuery,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<Vec<SubscribePlatformEventsResponseV0>>, Error> {
+        let mut responses = vec![];
+
+        if filters.blocks {
+            responses.push(SubscribePlatformEventsResponseV0 {
+                event: Some(Event::BlockCommitted(BlockCommitted {
+                    height: block.height,
+                    block_hash: block.block_hash.to_vec(),
+                    state_transitions_count: block.state_transitions.len() as u32,
+                })),
+                proof: Some(self.response_proof_v0(platform_state, vec![])),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            });
+        }
+
+        for committed_state_transition in &block.state_transitions {
+            let state_transition = &committed_state_transition.state_transition;
+
+            if state_transition_matches(&filters, state_transition) {
+                let proofs = self.query_state_transition_proofs(
+                    state_transition.clone(),
+                    platform_state,
+                    platform_version,
+                )?;
+
+                let (proof, metadata) =
+                    check_validation_result_with_data!(proof_with_metadata(proofs)?);
+
+                responses.push(SubscribePlatformEventsResponseV0 {
+                    event: Some(Event::StateTransitionExecuted(StateTransitionExecuted {
+                        height: block.height,
+                        state_transition_hash: committed_state_transition.hash.to_vec(),
+                        state_transition: committed_state_transition
+                            .serialized_state_transition
+                            .clone(),
+                    })),
+                    proof: Some(proof),
+                    metadata: Some(metadata),
+                });
+            }
+
+            let StateTransition::DocumentsBatch(documents_batch_transition) = state_transition
+            else {
+                continue;
+            };
+
+            for document_transition in documents_batch_transition.transitions() {
+                if !self.document_transition_matches_v0(
+                    &filters.documents,
+                    document_transition,
+                    state_transition.owner_id(),
+                    platform_version,
+                )? {
+                    continue;
+                }
+
+                let document_request = check_validation_result_with_data!(
+                    self.document_transition_proof_request(document_transition, platform_version)?
+                );
+
+                let proofs = self.query_proofs(
+                    GetProofsRequest {
+                        version: Some(ProofsRequestVersion::V0(GetProofsRequestV0 {
+                            identities: vec![],
+                            contracts: vec![],
+                            documents: vec![document_request],
+                            votes: vec![],
+                        })),
+                    },
+                    platform_state,
+                    platform_version,
+                )?;
+
+                let (proof, metadata) =
+                    check_validation_result_with_data!(proof_with_metadata(proofs)?);
+
+                responses.push(SubscribePlatformEventsResponseV0 {
+                    event: Some(Event::DocumentChanged(DocumentChanged {
+                        height: block.height,
+                        state_transition_hash: committed_state_transition.hash.to_vec(),
+                        data_contract_id: document_transition.data_contract_id().to_vec(),
+                        document_type: document_transition.document_type_name().clone(),
+                        document_id: document_transition.get_id().to_vec(),
+                        action: document_change_action(document_transition) as i32,
+                    })),
+                    proof: Some(proof),
+                    metadata: Some(metadata),
+                });
+            }
+        }
+
+        Ok(QueryValidationResult::new_with_data(responses))
+    }
+
+    /// Whether a document change matches any of the document filters
+    ///
+    /// Where clauses are evaluated against the change as submitted, so the document only
+    /// has its id, the owner that submitted the change and the data of the transition.
+    fn document_transition_matches_v0(
+        &self,
+        document_filters: &[DocumentEventFilter],
+        document_transition: &DocumentTransition,
+        owner_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Result<bool, Error> {
+        for document_filter in document_filters {
+            if document_filter.data_contract_id != document_transition.data_contract_id()
+                || &document_filter.document_type_name != document_transition.document_type_name()
+            {
+                continue;
+            }
+
+            if document_filter.where_clauses.is_empty() {
+                return Ok(true);
+            }
+
+            let Some(contract_fetch_info) = self.drive.get_contract_with_fetch_info(
+                document_filter.data_contract_id.to_buffer(),
+                false,
+                None,
+                platform_version,
+            )?
+            else {
+                continue;
+            };
+
+            let Ok(document_type) = contract_fetch_info
+                .contract
+                .document_type_for_name(document_filter.document_type_name.as_str())
+            else {
+                continue;
+            };
+
+            let document: Document = DocumentV0 {
+                id: document_transition.get_id(),
+                owner_id,
+                properties: document_transition.data().cloned().unwrap_or_default(),
+                ..Default::default()
+            }
+            .into();
+
+            let mut matches = true;
+
+            for where_clause in &document_filter.where_clauses {
+                let serialized_value = document.get_raw_for_document_type(
+                    where_clause.field.as_str(),
+                    document_type,
+                    None,
+                    platform_version,
+                )?;
+
+                let matches_clause = match serialized_value {
+                    Some(serialized_value) => where_clause.matches_serialized_value(
+                        &serialized_value,
+                        document_type,
+                        platform_version,
+                    )?,
+                    None => false,
+                };
+
+                if !matches_clause {
+                    matches = false;
+                    break;
+                }
+            }
+
+            if matches {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+/// Splits a proofs query result into the proof and its metadata
+fn proof_with_metadata(
+    mut result: QueryValidationResult<GetProofsResponse>,
+) -> Result<Result<(Proof, ResponseMetadata), QueryError>, Error> {
+    if !result.is_valid() {
+        return Ok(Err(result.errors.swap_remove(0)));
+    }
+
+    let GetProofsResponse {
+        version: Some(ProofsResponseVersion::V0(response_v0)),
+    } = result.into_data()?
+    else {
+        return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+            "proofs query must return a versioned response",
+        )));
+    };
+
+    match (response_v0.result, response_v0.metadata) {
+        (Some(get_proofs_response_v0::Result::Proof(proof)), Some(metadata)) => {
+            Ok(Ok((proof, metadata)))
+        }
+        _ => Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+            "proofs query must return a proof with metadata",
+        ))),
+    }
+}
+
+/// Whether a state transition matches the identity or data contract filters
+fn state_transition_matches(
+    filters: &PlatformEventFilters,
+    state_transition: &StateTransition,
+) -> bool {
+    state_transition_identity_ids(state_transition)
+        .iter()
+        .any(|identity_id| filters.identity_ids.contains(identity_id))
+        || state_transition_data_contract_ids(state_transition)
+            .iter()
+            .any(|data_contract_id| filters.data_contract_ids.contains(data_contract_id))
+}
+
+/// Identities making or affected by a state transition
+fn state_transition_identity_ids(state_transition: &StateTransition) -> Vec<Identifier> {
+    let mut identity_ids = vec![state_transition.owner_id()];
+
+    match state_transition {
+        StateTransition::IdentityCreditTransfer(transition) => {
+            identity_ids.push(transition.recipient_id());
+        }
+        StateTransition::IdentityCreditBatchTransfer(transition) => {
+            identity_ids.extend(
+                transition
+                    .outputs()
+                    .iter()
+                    .map(|output| output.recipient_id),
+            );
+        }
+        StateTransition::DocumentsBatch(transition) => {
+            identity_ids.extend(transition.transitions().iter().filter_map(
+                |document_transition| {
+                    document_transition
+                        .as_transition_transfer()
+                        .map(|transfer_transition| transfer_transition.recipient_owner_id())
+                },
+            ));
+        }
+        _ => {}
+    }
+
+    identity_ids
+}
+
+/// Data contracts created, updated or used by a state transition
+fn state_transition_data_contract_ids(state_transition: &StateTransition) -> Vec<Identifier> {
+    match state_transition {
+        StateTransition::DataContractCreate(transition) => vec![transition.data_contract().id()],
+        StateTransition::DataContractUpdate(transition) => vec![transition.data_contract().id()],
+        StateTransition::DataContractDelete(transition) => vec![transition.data_contract_id()],
+        StateTransition::DocumentsBatch(transition) => transition
+            .transitions()
+            .iter()
+            .map(|document_transition| document_transition.data_contract_id())
+            .collect(),
+        StateTransition::MasternodeVote(transition) => match transition.vote() {
+            Vote::ResourceVote(resource_vote) => match resource_vote.vote_poll() {
+                VotePoll::ContestedDocumentResourceVotePoll(vote_poll) => {
+                    vec![vote_poll.contract_id]
+                }
+                VotePoll::GovernanceProposalVotePoll(_) => vec![],
+            },
+            Vote::GovernanceProposalVote(_) => vec![],
+        },
+        StateTransition::AuctionBid(transition) => vec![transition.vote_poll().contract_id],
+        StateTransition::IdentityCreate(_)
+        | StateTransition::IdentityTopUp(_)
+        | StateTransition::IdentityCreditWithdrawal(_)
+        | StateTransition::IdentityUpdate(_)
+        | StateTransition::IdentityRecovery(_)
+        | StateTransition::IdentityCreditTransfer(_)
+        | StateTransition::IdentityCreditBatchTransfer(_) => vec![],
+    }
+}
+
+fn document_change_action(document_transition: &DocumentTransition) -> document_changed::Action {
+    match document_transition {
+        DocumentTransition::Create(_) => document_changed::Action::Create,
+        DocumentTransition::Replace(_) => document_changed::Action::Replace,
+        DocumentTransition::Delete(_) => document_changed::Action::Delete,
+        DocumentTransition::Transfer(_) => document_changed::Action::Transfer,
+        DocumentTransition::UpdatePrice(_) => document_changed::Action::UpdatePrice,
+        DocumentTransition::Purchase(_) => document_changed::Action::Purchase,
+        DocumentTransition::MakeOffer(_) => document_changed::Action::MakeOffer,
+        DocumentTransition::WithdrawOffer(_) => document_changed::Action::WithdrawOffer,
+        DocumentTransition::AcceptOffer(_) => document_changed::Action::AcceptOffer,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform_types::platform_events::CommittedBlock;
+    use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+    use crate::query::tests::{assert_invalid_identifier, setup_platform, store_data_contract};
+    use dpp::dashcore::Network;
+    use dpp::platform_value::platform_value;
+    use dpp::serialization::PlatformSerializable;
+    use dpp::state_transition::documents_batch_transition::document_base_transition::v0::DocumentBaseTransitionV0;
+    use dpp::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+    use dpp::state_transition::documents_batch_transition::document_create_transition::DocumentCreateTransitionV0;
+    use dpp::state_transition::documents_batch_transition::{
+        DocumentCreateTransition, DocumentsBatchTransition, DocumentsBatchTransitionV0,
+    };
+    use dpp::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
+    use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+    use dpp::tests::fixtures::get_data_contract_fixture;
+    use std::sync::Arc;
+
+    fn committed_block(state_transitions: Vec<StateTransition>) -> Arc<CommittedBlock> {
+        let transactions = state_transitions
+            .iter()
+            .map(|state_transition| {
+                state_transition
+                    .serialize_to_bytes()
+                    .expect("expected to serialize state transition")
+            })
+            .collect::<Vec<_>>();
+
+        Arc::new(CommittedBlock::from_transactions(1, [7; 32], &transactions))
+    }
+
+    fn encode_where(where_clauses: Value) -> Vec<u8> {
+        let mut bytes = vec![];
+
+        ciborium::ser::into_writer(&where_clauses, &mut bytes)
+            .expect("expected to serialize where clauses");
+
+        bytes
+    }
+
+    #[test]
+    fn test_empty_filters() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let request = SubscribePlatformEventsRequestV0 { filters: vec![] };
+
+        let result = platform
+            .query_platform_event_filters_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::InvalidArgument(msg)] if msg == "at least one event filter is required"
+        ));
+    }
+
+    #[test]
+    fn test_invalid_identity_id() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let request = SubscribePlatformEventsRequestV0 {
+            filters: vec![EventFilter {
+                filter: Some(event_filter::Filter::StateTransitions(
+                    StateTransitionFilter {
+                        filter: Some(state_transition_filter::Filter::IdentityId(vec![0; 8])),
+                    },
+                )),
+            }],
+        };
+
+        let result = platform
+            .query_platform_event_filters_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert_invalid_identifier(result);
+    }
+
+    #[test]
+    fn test_document_filter_with_unknown_field() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let created_data_contract = get_data_contract_fixture(None, 0, version.protocol_version);
+        store_data_contract(&platform, created_data_contract.data_contract(), version);
+
+        let request = SubscribePlatformEventsRequestV0 {
+            filters: vec![EventFilter {
+                filter: Some(event_filter::Filter::Documents(DocumentFilter {
+                    data_contract_id: created_data_contract.data_contract().id().to_vec(),
+                    document_type: "niceDocument".to_string(),
+                    r#where: encode_where(platform_value!([["unknown", "==", "alice"]])),
+                })),
+            }],
+        };
+
+        let result = platform
+            .query_platform_event_filters_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::InvalidArgument(msg)] if msg.contains("unknown")
+        ));
+    }
+
+    #[test]
+    fn test_block_committed_event() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let query = PlatformEventsQuery {
+            filters: Arc::new(PlatformEventFilters {
+                blocks: true,
+                ..Default::default()
+            }),
+            block: committed_block(vec![]),
+        };
+
+        let result = platform
+            .query_platform_events_v0(query, &state, version)
+            .expect("expected query to succeed");
+
+        let responses = result.into_data().expect("expected responses");
+
+        assert_eq!(responses.len(), 1);
+        assert!(matches!(
+            &responses[0].event,
+            Some(Event::BlockCommitted(BlockCommitted { height: 1, block_hash, state_transitions_count: 0 }))
+                if block_hash == &vec![7; 32]
+        ));
+        assert!(responses[0].proof.is_some());
+
+        // The proof is taken from the latest committed state, its height is in the metadata
+        assert_eq!(
+            responses[0]
+                .metadata
+                .as_ref()
+                .map(|metadata| metadata.height),
+            Some(state.last_committed_block_height())
+        );
+    }
+
+    #[test]
+    fn test_credit_transfer_matched_by_recipient() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let recipient_id = Identifier::new([2; 32]);
+
+        let state_transition = StateTransition::IdentityCreditTransfer(
+            IdentityCreditTransferTransition::V0(IdentityCreditTransferTransitionV0 {
+                identity_id: Identifier::new([1; 32]),
+                recipient_id,
+                amount: 1000,
+                ..Default::default()
+            }),
+        );
+
+        let block = committed_block(vec![state_transition]);
+
+        let query = PlatformEventsQuery {
+            filters: Arc::new(PlatformEventFilters {
+                identity_ids: [recipient_id].into(),
+                ..Default::default()
+            }),
+            block: block.clone(),
+        };
+
+        let result = platform
+            .query_platform_events_v0(query, &state, version)
+            .expect("expected query to succeed");
+
+        let responses = result.into_data().expect("expected responses");
+
+        assert_eq!(responses.len(), 1);
+        assert!(matches!(
+            &responses[0].event,
+            Some(Event::StateTransitionExecuted(StateTransitionExecuted { state_transition_hash, .. }))
+                if state_transition_hash == &block.state_transitions[0].hash.to_vec()
+        ));
+        assert!(responses[0].proof.is_some());
+
+        let query = PlatformEventsQuery {
+            filters: Arc::new(PlatformEventFilters {
+                identity_ids: [Identifier::new([3; 32])].into(),
+                ..Default::default()
+            }),
+            block,
+        };
+
+        let result = platform
+            .query_platform_events_v0(query, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(result.into_data().expect("expected responses").is_empty());
+    }
+
+    #[test]
+    fn test_document_changes_matched_by_where_clause() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let created_data_contract = get_data_contract_fixture(None, 0, version.protocol_version);
+        let data_contract_id = created_data_contract.data_contract().id();
+        store_data_contract(&platform, created_data_contract.data_contract(), version);
+
+        let document_id = Identifier::new([5; 32]);
+
+        let state_transition = StateTransition::DocumentsBatch(DocumentsBatchTransition::V0(
+            DocumentsBatchTransitionV0 {
+                owner_id: Identifier::new([1; 32]),
+                transitions: vec![DocumentTransition::Create(DocumentCreateTransition::V0(
+                    DocumentCreateTransitionV0 {
+                        base: DocumentBaseTransition::V0(DocumentBaseTransitionV0 {
+                            id: document_id,
+                            identity_contract_nonce: 1,
+                            document_type_name: "niceDocument".to_string(),
+                            data_contract_id,
+                        }),
+                        data: platform_value!({ "name": "alice" })
+                            .into_btree_string_map()
+                            .expect("expected a map"),
+                        ..Default::default()
+                    },
+                ))],
+                ..Default::default()
+            },
+        ));
+
+        let block = committed_block(vec![state_transition]);
+
+        let filters_for = |name: &str| {
+            let request = SubscribePlatformEventsRequestV0 {
+                filters: vec![EventFilter {
+                    filter: Some(event_filter::Filter::Documents(DocumentFilter {
+                        data_contract_id: data_contract_id.to_vec(),
+                        document_type: "niceDocument".to_string(),
+                        r#where: encode_where(platform_value!([["name", "==", name]])),
+                    })),
+                }],
+            };
+
+            platform
+                .query_platform_event_filters_v0(request, &state, version)
+                .expect("expected query to succeed")
+                .into_data()
+                .expect("expected valid filters")
+        };
+
+        let query = PlatformEventsQuery {
+            filters: Arc::new(filters_for("alice")),
+            block: block.clone(),
+        };
+
+        let result = platform
+            .query_platform_events_v0(query, &state, version)
+            .expect("expected query to succeed");
+
+        let responses = result.into_data().expect("expected responses");
+
+        assert_eq!(responses.len(), 1);
+        assert!(matches!(
+            &responses[0].event,
+            Some(Event::DocumentChanged(DocumentChanged { document_id: id, action, .. }))
+                if id == &document_id.to_vec() && *action == document_changed::Action::Create as i32
+        ));
+        assert!(responses[0].proof.is_some());
+
+        let query = PlatformEventsQuery {
+            filters: Arc::new(filters_for("bob")),
+            block,
+        };
+
+        let result = platform
+            .query_platform_events_v0(query, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(result.into_data().expect("expected responses").is_empty());
+    }
+}
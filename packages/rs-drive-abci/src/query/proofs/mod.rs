@@ -8,6 +8,7 @@ use dapi_grpc::platform::v0::get_proofs_response::Version as ResponseVersion;
 use dapi_grpc::platform::v0::{GetProofsRequest, GetProofsResponse};
 use dpp::version::PlatformVersion;
 
+mod state_transition;
 mod v0;
 
 impl<C> Platform<C> {
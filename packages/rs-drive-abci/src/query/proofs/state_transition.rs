@@ -0,0 +1,349 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_proofs_request::get_proofs_request_v0::vote_status_request::{
+    ContestedResourceVoteStatusRequest, GovernanceProposalVoteStatusRequest, RequestType,
+};
+use dapi_grpc::platform::v0::get_proofs_request::get_proofs_request_v0::{
+    document_request, identity_request, ContractRequest, DocumentRequest, IdentityRequest,
+    VoteStatusRequest,
+};
+use dapi_grpc::platform::v0::get_proofs_request::{GetProofsRequestV0, Version as RequestVersion};
+use dapi_grpc::platform::v0::{GetProofsRequest, GetProofsResponse};
+use dpp::check_validation_result_with_data;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::prelude::Identifier;
+use dpp::serialization::PlatformSerializable;
+use dpp::state_transition::auction_bid_transition::accessors::AuctionBidTransitionAccessorsV0;
+use dpp::state_transition::data_contract_create_transition::accessors::DataContractCreateTransitionAccessorsV0;
+use dpp::state_transition::data_contract_delete_transition::accessors::DataContractDeleteTransitionAccessorsV0;
+use dpp::state_transition::data_contract_update_transition::accessors::DataContractUpdateTransitionAccessorsV0;
+use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
+use dpp::state_transition::documents_batch_transition::document_base_transition::v0::v0_methods::DocumentBaseTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_create_transition::v0::v0_methods::DocumentCreateTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::{
+    DocumentTransition, DocumentTransitionV0Methods,
+};
+use dpp::state_transition::identity_create_transition::accessors::IdentityCreateTransitionAccessorsV0;
+use dpp::state_transition::identity_credit_batch_transfer_transition::accessors::IdentityCreditBatchTransferTransitionAccessorsV0;
+use dpp::state_transition::identity_credit_transfer_transition::accessors::IdentityCreditTransferTransitionAccessorsV0;
+use dpp::state_transition::identity_credit_withdrawal_transition::accessors::IdentityCreditWithdrawalTransitionAccessorsV0;
+use dpp::state_transition::identity_recovery_transition::accessors::IdentityRecoveryTransitionAccessorsV0;
+use dpp::state_transition::identity_recovery_transition::IdentityRecoveryOperation;
+use dpp::state_transition::identity_topup_transition::accessors::IdentityTopUpTransitionAccessorsV0;
+use dpp::state_transition::identity_update_transition::accessors::IdentityUpdateTransitionAccessorsV0;
+use dpp::state_transition::masternode_vote_transition::accessors::MasternodeVoteTransitionAccessorsV0;
+use dpp::state_transition::StateTransition;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_polls::VotePoll;
+use dpp::voting::votes::governance_proposal_vote::accessors::v0::GovernanceProposalVoteGettersV0;
+use dpp::voting::votes::resource_vote::accessors::v0::ResourceVoteGettersV0;
+use dpp::voting::votes::Vote;
+
+impl<C> Platform<C> {
+    /// Querying of the proof of data modified by an executed state transition
+    ///
+    /// The proof can be verified with `Drive::verify_state_transition_was_executed_with_proof`
+    pub fn query_state_transition_proofs(
+        &self,
+        state_transition: StateTransition,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetProofsResponse>, Error> {
+        let mut request = GetProofsRequestV0 {
+            identities: vec![],
+            contracts: vec![],
+            documents: vec![],
+            votes: vec![],
+        };
+
+        match &state_transition {
+            StateTransition::DataContractCreate(transition) => {
+                request.contracts.push(ContractRequest {
+                    contract_id: transition.data_contract().id().to_vec(),
+                });
+            }
+            StateTransition::DataContractUpdate(transition) => {
+                request.contracts.push(ContractRequest {
+                    contract_id: transition.data_contract().id().to_vec(),
+                });
+            }
+            StateTransition::DataContractDelete(transition) => {
+                request.contracts.push(ContractRequest {
+                    contract_id: transition.data_contract_id().to_vec(),
+                });
+            }
+            StateTransition::DocumentsBatch(transition) => {
+                for document_transition in transition.transitions() {
+                    let document_request = check_validation_result_with_data!(self
+                        .document_transition_proof_request(
+                            document_transition,
+                            platform_version
+                        )?);
+
+                    request.documents.push(document_request);
+                }
+            }
+            StateTransition::IdentityCreate(transition) => {
+                request.identities.push(identity_proof_request(
+                    transition.identity_id(),
+                    identity_request::Type::FullIdentity,
+                ));
+            }
+            StateTransition::IdentityTopUp(transition) => {
+                request.identities.push(identity_proof_request(
+                    *transition.identity_id(),
+                    identity_request::Type::Balance,
+                ));
+                request.identities.push(identity_proof_request(
+                    *transition.identity_id(),
+                    identity_request::Type::Revision,
+                ));
+            }
+            StateTransition::IdentityCreditWithdrawal(transition) => {
+                request.identities.push(identity_proof_request(
+                    transition.identity_id(),
+                    identity_request::Type::Balance,
+                ));
+            }
+            StateTransition::IdentityUpdate(transition) => {
+                request.identities.push(identity_proof_request(
+                    transition.identity_id(),
+                    identity_request::Type::Keys,
+                ));
+            }
+            StateTransition::IdentityRecovery(transition) => {
+                let request_type = match transition.operation() {
+                    IdentityRecoveryOperation::SetConfig(_) => {
+                        identity_request::Type::RecoveryConfig
+                    }
+                    IdentityRecoveryOperation::Initiate { .. }
+                    | IdentityRecoveryOperation::Cancel
+                    | IdentityRecoveryOperation::Complete => {
+                        identity_request::Type::PendingRecovery
+                    }
+                };
+                request.identities.push(identity_proof_request(
+                    transition.identity_id(),
+                    request_type,
+                ));
+            }
+            StateTransition::IdentityCreditTransfer(transition) => {
+                request.identities.push(identity_proof_request(
+                    transition.identity_id(),
+                    identity_request::Type::Balance,
+                ));
+                request.identities.push(identity_proof_request(
+                    transition.recipient_id(),
+                    identity_request::Type::Balance,
+                ));
+            }
+            StateTransition::IdentityCreditBatchTransfer(transition) => {
+                request.identities.push(identity_proof_request(
+                    transition.identity_id(),
+                    identity_request::Type::Balance,
+                ));
+                for output in transition.outputs() {
+                    request.identities.push(identity_proof_request(
+                        output.recipient_id,
+                        identity_request::Type::Balance,
+                    ));
+                }
+            }
+            StateTransition::AuctionBid(transition) => {
+                request.identities.push(identity_proof_request(
+                    transition.identity_id(),
+                    identity_request::Type::Balance,
+                ));
+            }
+            StateTransition::MasternodeVote(transition) => match transition.vote() {
+                Vote::ResourceVote(resource_vote) => match resource_vote.vote_poll() {
+                    VotePoll::ContestedDocumentResourceVotePoll(vote_poll) => {
+                        let config = bincode::config::standard()
+                            .with_big_endian()
+                            .with_no_limit();
+
+                        let index_values = vote_poll
+                            .index_values
+                            .iter()
+                            .map(|value| bincode::encode_to_vec(value, config))
+                            .collect::<Result<Vec<_>, _>>()
+                            .map_err(|e| {
+                                Error::Protocol(dpp::ProtocolError::EncodingError(e.to_string()))
+                            })?;
+
+                        request.votes.push(VoteStatusRequest {
+                            request_type: Some(RequestType::ContestedResourceVoteStatusRequest(
+                                ContestedResourceVoteStatusRequest {
+                                    contract_id: vote_poll.contract_id.to_vec(),
+                                    document_type_name: vote_poll.document_type_name.clone(),
+                                    index_name: vote_poll.index_name.clone(),
+                                    index_values,
+                                    voter_identifier: transition.pro_tx_hash().to_vec(),
+                                },
+                            )),
+                        });
+                    }
+                    VotePoll::GovernanceProposalVotePoll(_) => {
+                        return Ok(QueryValidationResult::new_with_error(
+                            QueryError::InvalidArgument(
+                                "resource votes can not be cast on governance proposal vote polls"
+                                    .to_string(),
+                            ),
+                        ));
+                    }
+                },
+                Vote::GovernanceProposalVote(governance_proposal_vote) => {
+                    request.votes.push(VoteStatusRequest {
+                        request_type: Some(RequestType::GovernanceProposalVoteStatusRequest(
+                            GovernanceProposalVoteStatusRequest {
+                                vote_poll: governance_proposal_vote
+                                    .vote_poll()
+                                    .serialize_to_bytes()?,
+                                voter_identifier: transition.pro_tx_hash().to_vec(),
+                            },
+                        )),
+                    });
+                }
+            },
+        }
+
+        self.query_proofs(
+            GetProofsRequest {
+                version: Some(RequestVersion::V0(request)),
+            },
+            platform_state,
+            platform_version,
+        )
+    }
+
+    /// The proof request of the document modified by a document transition
+    pub(in crate::query) fn document_transition_proof_request(
+        &self,
+        document_transition: &DocumentTransition,
+        platform_version: &PlatformVersion,
+    ) -> Result<Result<DocumentRequest, QueryError>, Error> {
+        let contract_id = document_transition.data_contract_id();
+
+        let Some(contract_fetch_info) = self.drive.get_contract_with_fetch_info(
+            contract_id.to_buffer(),
+            false,
+            None,
+            platform_version,
+        )?
+        else {
+            return Ok(Err(QueryError::NotFound(format!(
+                "data contract {} not found",
+                contract_id
+            ))));
+        };
+
+        let document_type_keeps_history = match contract_fetch_info
+            .contract
+            .document_type_for_name(document_transition.document_type_name())
+        {
+            Ok(document_type) => document_type.documents_keep_history(),
+            Err(_) => {
+                return Ok(Err(QueryError::NotFound(format!(
+                    "document type {} not found in data contract {}",
+                    document_transition.document_type_name(),
+                    contract_id
+                ))))
+            }
+        };
+
+        let document_contested_status = match document_transition {
+            DocumentTransition::Create(create_transition)
+                if create_transition.prefunded_voting_balance().is_some() =>
+            {
+                document_request::DocumentContestedStatus::Contested
+            }
+            _ => document_request::DocumentContestedStatus::NotContested,
+        };
+
+        Ok(Ok(DocumentRequest {
+            contract_id: contract_id.to_vec(),
+            document_type: document_transition.document_type_name().clone(),
+            document_type_keeps_history,
+            document_id: document_transition.base().id().to_vec(),
+            document_contested_status: document_contested_status as i32,
+        }))
+    }
+}
+
+fn identity_proof_request(
+    identity_id: Identifier,
+    request_type: identity_request::Type,
+) -> IdentityRequest {
+    IdentityRequest {
+        identity_id: identity_id.to_vec(),
+        request_type: request_type as i32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::tests::setup_platform;
+    use dapi_grpc::platform::v0::get_proofs_response::{get_proofs_response_v0, Version};
+    use dpp::dashcore::Network;
+    use dpp::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
+    use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+    use drive::drive::Drive;
+
+    #[test]
+    fn test_credit_transfer_proves_sender_and_recipient_balances() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let sender_id = Identifier::new([1; 32]);
+        let recipient_id = Identifier::new([2; 32]);
+
+        let state_transition = StateTransition::IdentityCreditTransfer(
+            IdentityCreditTransferTransition::V0(IdentityCreditTransferTransitionV0 {
+                identity_id: sender_id,
+                recipient_id,
+                amount: 1000,
+                ..Default::default()
+            }),
+        );
+
+        let response = platform
+            .query_state_transition_proofs(state_transition, &state, version)
+            .expect("expected query to succeed")
+            .into_data()
+            .expect("expected query to be valid");
+
+        let Some(Version::V0(response_v0)) = response.version else {
+            panic!("expected v0 response");
+        };
+
+        let Some(get_proofs_response_v0::Result::Proof(proof)) = response_v0.result else {
+            panic!("expected proof");
+        };
+
+        let (sender_root_hash, sender_balance) = Drive::verify_identity_balance_for_identity_id(
+            &proof.grovedb_proof,
+            sender_id.to_buffer(),
+            true,
+            version,
+        )
+        .expect("expected to verify sender balance");
+
+        let (recipient_root_hash, recipient_balance) =
+            Drive::verify_identity_balance_for_identity_id(
+                &proof.grovedb_proof,
+                recipient_id.to_buffer(),
+                true,
+                version,
+            )
+            .expect("expected to verify recipient balance");
+
+        assert_eq!(sender_root_hash, recipient_root_hash);
+        assert_eq!(sender_balance, None);
+        assert_eq!(recipient_balance, None);
+    }
+}
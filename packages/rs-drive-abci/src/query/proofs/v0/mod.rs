@@ -9,9 +9,12 @@ use dapi_grpc::platform::v0::get_proofs_response::{get_proofs_response_v0, GetPr
 use dpp::check_validation_result_with_data;
 use dpp::platform_value::Bytes32;
 use dpp::prelude::Identifier;
+use dpp::serialization::PlatformDeserializable;
 use dpp::validation::ValidationResult;
 use dpp::version::PlatformVersion;
 use dpp::voting::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePoll;
+use dpp::voting::vote_polls::governance_proposal_vote_poll::GovernanceProposalVotePoll;
+use dpp::voting::vote_polls::VotePoll;
 use drive::drive::identity::{IdentityDriveQuery, IdentityProveRequestType};
 use drive::query::{IdentityBasedVoteDriveQuery, SingleDocumentDriveQuery};
 
@@ -138,6 +141,24 @@ impl<C> Platform<C> {
                                 vote_poll,
                             }))
                         }
+                        RequestType::GovernanceProposalVoteStatusRequest(governance_proposal_vote_status_request) => {
+                            let identity_id = match governance_proposal_vote_status_request.voter_identifier.try_into() {
+                                Ok(identity_id) => identity_id,
+                                Err(_) => return Some(Err(QueryError::InvalidArgument(
+                                    "voter_identifier must be a valid identifier (32 bytes long)".to_string(),
+                                ))),
+                            };
+                            let vote_poll = match GovernanceProposalVotePoll::deserialize_from_bytes(&governance_proposal_vote_status_request.vote_poll) {
+                                Ok(vote_poll) => vote_poll,
+                                Err(_) => return Some(Err(QueryError::InvalidArgument(
+                                    "vote_poll must be a serialized governance proposal vote poll".to_string(),
+                                ))),
+                            };
+                            Some(Ok(IdentityBasedVoteDriveQuery {
+                                identity_id,
+                                vote_poll: VotePoll::GovernanceProposalVotePoll(vote_poll),
+                            }))
+                        }
                     }
                 } else {
                     None
@@ -1,49 +1,87 @@
+use crate::abci::handler;
 use crate::error::query::QueryError;
 use crate::error::Error;
 use crate::metrics::{abci_response_code_metric_label, query_duration_metric};
 use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_events::PLATFORM_EVENTS_CHANNEL_CAPACITY;
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use crate::platform_types::platform_state::PlatformState;
+use crate::query::platform_events::PlatformEventsQuery;
 use crate::query::QueryValidationResult;
 use crate::rpc::core::DefaultCoreRPC;
-use crate::utils::spawn_blocking_task_with_name_if_supported;
+use crate::rpc::tenderdash::{TenderdashRPC, TenderdashRPCError, TxResult};
+use crate::utils::{spawn_blocking_task_with_name_if_supported, spawn_task_with_name_if_supported};
 use async_trait::async_trait;
+use dapi_grpc::platform::v0::get_consensus_params_request::Version as ConsensusParamsRequestVersion;
+use dapi_grpc::platform::v0::get_consensus_params_response::{
+    ConsensusParamsBlock, ConsensusParamsEvidence, GetConsensusParamsResponseV0,
+    Version as ConsensusParamsResponseVersion,
+};
+use dapi_grpc::platform::v0::get_proofs_response::{self, get_proofs_response_v0};
 use dapi_grpc::platform::v0::platform_server::Platform as PlatformService;
+use dapi_grpc::platform::v0::wait_for_state_transition_result_request::Version as WaitForStateTransitionResultRequestVersion;
+use dapi_grpc::platform::v0::wait_for_state_transition_result_response::{
+    wait_for_state_transition_result_response_v0,
+    Version as WaitForStateTransitionResultResponseVersion, WaitForStateTransitionResultResponseV0,
+};
 use dapi_grpc::platform::v0::{
-    BroadcastStateTransitionRequest, BroadcastStateTransitionResponse, GetConsensusParamsRequest,
-    GetConsensusParamsResponse, GetContestedResourceIdentityVotesRequest,
-    GetContestedResourceIdentityVotesResponse, GetContestedResourceVoteStateRequest,
-    GetContestedResourceVoteStateResponse, GetContestedResourceVotersForIdentityRequest,
-    GetContestedResourceVotersForIdentityResponse, GetContestedResourcesRequest,
-    GetContestedResourcesResponse, GetDataContractHistoryRequest, GetDataContractHistoryResponse,
-    GetDataContractRequest, GetDataContractResponse, GetDataContractsRequest,
-    GetDataContractsResponse, GetDocumentsRequest, GetDocumentsResponse, GetEpochsInfoRequest,
-    GetEpochsInfoResponse, GetIdentitiesContractKeysRequest, GetIdentitiesContractKeysResponse,
-    GetIdentityBalanceAndRevisionRequest, GetIdentityBalanceAndRevisionResponse,
-    GetIdentityBalanceRequest, GetIdentityBalanceResponse, GetIdentityByPublicKeyHashRequest,
-    GetIdentityByPublicKeyHashResponse, GetIdentityContractNonceRequest,
-    GetIdentityContractNonceResponse, GetIdentityKeysRequest, GetIdentityKeysResponse,
-    GetIdentityNonceRequest, GetIdentityNonceResponse, GetIdentityRequest, GetIdentityResponse,
+    BroadcastStateTransitionRequest, BroadcastStateTransitionResponse,
+    EstimateStateTransitionFeeRequest, EstimateStateTransitionFeeResponse,
+    GetConsensusParamsRequest, GetConsensusParamsResponse,
+    GetContestedResourceIdentityVotesRequest, GetContestedResourceIdentityVotesResponse,
+    GetContestedResourceVoteStateRequest, GetContestedResourceVoteStateResponse,
+    GetContestedResourceVotersForIdentityRequest, GetContestedResourceVotersForIdentityResponse,
+    GetContestedResourcesRequest, GetContestedResourcesResponse, GetDataContractHistoryRequest,
+    GetDataContractHistoryResponse, GetDataContractRequest, GetDataContractResponse,
+    GetDataContractsRequest, GetDataContractsResponse, GetDocumentHistoryRequest,
+    GetDocumentHistoryResponse, GetDocumentOffersRequest, GetDocumentOffersResponse,
+    GetDocumentsCountRequest, GetDocumentsCountResponse, GetDocumentsRequest, GetDocumentsResponse,
+    GetEpochsInfoRequest, GetEpochsInfoResponse, GetGovernanceProposalVotePollStateRequest,
+    GetGovernanceProposalVotePollStateResponse, GetIdentitiesContractKeysRequest,
+    GetIdentitiesContractKeysResponse, GetIdentityBalanceAndRevisionRequest,
+    GetIdentityBalanceAndRevisionResponse, GetIdentityBalanceRequest, GetIdentityBalanceResponse,
+    GetIdentityByPublicKeyHashRequest, GetIdentityByPublicKeyHashResponse,
+    GetIdentityContractNonceRequest, GetIdentityContractNonceResponse, GetIdentityKeysRequest,
+    GetIdentityKeysResponse, GetIdentityNonceRequest, GetIdentityNonceResponse,
+    GetIdentityPendingRecoveryRequest, GetIdentityPendingRecoveryResponse, GetIdentityRequest,
+    GetIdentityResponse, GetIdentityWithdrawalsRequest, GetIdentityWithdrawalsResponse,
     GetPathElementsRequest, GetPathElementsResponse, GetPrefundedSpecializedBalanceRequest,
     GetPrefundedSpecializedBalanceResponse, GetProofsRequest, GetProofsResponse,
     GetProtocolVersionUpgradeStateRequest, GetProtocolVersionUpgradeStateResponse,
     GetProtocolVersionUpgradeVoteStatusRequest, GetProtocolVersionUpgradeVoteStatusResponse,
     GetStatusRequest, GetStatusResponse, GetTotalCreditsInPlatformRequest,
     GetTotalCreditsInPlatformResponse, GetVotePollsByEndDateRequest, GetVotePollsByEndDateResponse,
+    StateTransitionBroadcastError, SubscribePlatformEventsRequest, SubscribePlatformEventsResponse,
     WaitForStateTransitionResultRequest, WaitForStateTransitionResultResponse,
 };
+use dapi_grpc::tonic::metadata::{MetadataMap, MetadataValue};
 use dapi_grpc::tonic::{Code, Request, Response, Status};
+use dpp::consensus::ConsensusError;
+use dpp::platform_value::string_encoding::{decode, Encoding};
+use dpp::serialization::PlatformDeserializable;
+use dpp::state_transition::StateTransition;
 use dpp::version::PlatformVersion;
 use std::fmt::Debug;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::thread::sleep;
 use std::time::Duration;
+use tenderdash_abci::proto::abci as proto;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{mpsc, Semaphore};
+use tokio::time::Instant;
+use tokio_stream::wrappers::ReceiverStream;
 use tracing::Instrument;
 
+/// Interval between checks whether Tenderdash indexed the result of a committed state transition
+const TX_INDEX_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
 /// Service to handle platform queries
+#[derive(Clone)]
 pub struct QueryService {
     platform: Arc<Platform<DefaultCoreRPC>>,
+    tenderdash: Arc<TenderdashRPC>,
+    state_transition_waiters: Arc<Semaphore>,
 }
 
 type QueryMethod<RQ, RS> = fn(
@@ -56,7 +94,142 @@ type QueryMethod<RQ, RS> = fn(
 impl QueryService {
     /// Creates new QueryService
     pub fn new(platform: Arc<Platform<DefaultCoreRPC>>) -> Self {
-        Self { platform }
+        let tenderdash = Arc::new(TenderdashRPC::new(&platform.config.abci.tenderdash_rpc_url));
+        let state_transition_waiters = Arc::new(Semaphore::new(
+            platform.config.abci.max_state_transition_waiters,
+        ));
+
+        Self {
+            platform,
+            tenderdash,
+            state_transition_waiters,
+        }
+    }
+
+    async fn call_tenderdash<T, F>(
+        &self,
+        endpoint_name: &str,
+        method: F,
+    ) -> Result<Result<T, TenderdashRPCError>, Status>
+    where
+        T: Send + 'static,
+        F: FnOnce(&TenderdashRPC) -> Result<T, TenderdashRPCError> + Send + 'static,
+    {
+        let tenderdash = Arc::clone(&self.tenderdash);
+
+        spawn_blocking_task_with_name_if_supported("tenderdash_rpc", move || method(&tenderdash))?
+            .instrument(tracing::trace_span!("tenderdash_rpc", endpoint_name))
+            .await
+            .map_err(|error| Status::internal(format!("tenderdash rpc thread failed: {}", error)))
+    }
+
+    async fn find_tx(&self, hash: &[u8]) -> Result<Option<TxResult>, Status> {
+        let hash = hash.to_vec();
+
+        self.call_tenderdash("wait_for_state_transition_result", move |tenderdash| {
+            tenderdash.tx(&hash)
+        })
+        .await?
+        .map_err(tenderdash_error_into_status)
+    }
+
+    /// Tenderdash indexes transactions after the block is committed by Drive,
+    /// so the result of a state transition from a committed block may not be there yet
+    async fn wait_for_indexed_tx(
+        &self,
+        hash: &[u8],
+        deadline: Instant,
+    ) -> Result<TxResult, Status> {
+        loop {
+            if let Some(tx_result) = self.find_tx(hash).await? {
+                return Ok(tx_result);
+            }
+
+            if Instant::now() + TX_INDEX_POLL_INTERVAL > deadline {
+                return Err(state_transition_wait_exceeded(hash));
+            }
+
+            tokio::time::sleep(TX_INDEX_POLL_INTERVAL).await;
+        }
+    }
+
+    async fn prove_state_transition(
+        &self,
+        state_transition: StateTransition,
+    ) -> Result<GetProofsResponse, Status> {
+        self.handle_blocking_query(
+            Request::new(state_transition),
+            Platform::<DefaultCoreRPC>::query_state_transition_proofs,
+            "wait_for_state_transition_result",
+        )
+        .await
+        .map(Response::into_inner)
+    }
+
+    /// Waits until the state transition with the given hash is committed and returns its result
+    ///
+    /// Instead of polling Tenderdash, the waiter is woken up by blocks committed by this node.
+    /// If a proof is requested, it is generated as soon as the block including the state
+    /// transition is committed, so it is taken at the inclusion height. Only a state transition
+    /// committed before the waiter subscribed is proved at the latest height. In both cases
+    /// the proof height is returned in the response metadata.
+    async fn wait_for_committed_state_transition(
+        &self,
+        hash: &[u8],
+        prove: bool,
+        deadline: Instant,
+    ) -> Result<(TxResult, Option<Result<GetProofsResponse, Status>>), Status> {
+        // Subscribe before looking the state transition up, so its block can't be missed in between
+        let mut blocks = self.platform.events.subscribe();
+
+        let mut look_up_tx = true;
+
+        loop {
+            if look_up_tx {
+                if let Some(tx_result) = self.find_tx(hash).await? {
+                    return Ok((tx_result, None));
+                }
+
+                look_up_tx = false;
+            }
+
+            let block = match tokio::time::timeout_at(deadline, blocks.recv()).await {
+                Ok(Ok(block)) => block,
+                // Skipped blocks might include the state transition
+                Ok(Err(RecvError::Lagged(_))) => {
+                    look_up_tx = true;
+                    continue;
+                }
+                Ok(Err(RecvError::Closed)) => {
+                    return Err(Status::unavailable("platform is shutting down"))
+                }
+                Err(_) => return Err(state_transition_wait_exceeded(hash)),
+            };
+
+            let Some(committed_state_transition) = block
+                .state_transitions
+                .iter()
+                .find(|state_transition| state_transition.hash.as_slice() == hash)
+            else {
+                continue;
+            };
+
+            // Prove before the next block is committed, the execution result is checked later
+            let proofs = if prove {
+                Some(
+                    self.prove_state_transition(
+                        committed_state_transition.state_transition.clone(),
+                    )
+                    .await,
+                )
+            } else {
+                None
+            };
+
+            let tx_result = self.wait_for_indexed_tx(hash, deadline).await?;
+
+            return Ok((tx_result, proofs));
+        }
     }
 
     async fn handle_blocking_query<'a, RQ, RS>(
@@ -229,19 +402,77 @@ impl QueryService {
     }
 }
 
-fn respond_with_unimplemented<RS>(name: &str) -> Result<Response<RS>, Status> {
-    tracing::error!("{} endpoint is called but it's not supported", name);
-
-    Err(Status::unimplemented("the endpoint is not supported"))
-}
-
 #[async_trait]
 impl PlatformService for QueryService {
     async fn broadcast_state_transition(
         &self,
-        _request: Request<BroadcastStateTransitionRequest>,
+        request: Request<BroadcastStateTransitionRequest>,
     ) -> Result<Response<BroadcastStateTransitionResponse>, Status> {
-        respond_with_unimplemented("broadcast_state_transition")
+        let BroadcastStateTransitionRequest { state_transition } = request.into_inner();
+
+        if state_transition.is_empty() {
+            return Err(Status::invalid_argument(
+                "state transition is not specified",
+            ));
+        }
+
+        if let Err(error) = StateTransition::deserialize_from_bytes(&state_transition) {
+            return Err(Status::invalid_argument(format!(
+                "invalid state transition: {}",
+                error
+            )));
+        }
+
+        // Check the state transition locally first, so invalid ones are not relayed to Tenderdash
+        let platform = Arc::clone(&self.platform);
+        let tx = state_transition.clone();
+
+        let check_tx_response =
+            spawn_blocking_task_with_name_if_supported("check_tx", move || {
+                handler::check_tx(
+                    &platform,
+                    &platform.core_rpc,
+                    proto::RequestCheckTx {
+                        tx,
+                        r#type: proto::CheckTxType::New as i32,
+                    },
+                )
+            })?
+            .await
+            .map_err(|error| Status::internal(format!("check tx thread failed: {}", error)))?
+            .map_err(error_into_status)?;
+
+        if check_tx_response.code != 0 {
+            return Err(drive_response_into_status(
+                check_tx_response.code,
+                &check_tx_response.info,
+            ));
+        }
+
+        let result = self
+            .call_tenderdash("broadcast_state_transition", move |tenderdash| {
+                tenderdash.broadcast_tx(&state_transition)
+            })
+            .await?
+            .map_err(broadcast_error_into_status)?;
+
+        if result.code != 0 {
+            return Err(drive_response_into_status(result.code, &result.info));
+        }
+
+        Ok(Response::new(BroadcastStateTransitionResponse {}))
+    }
+
+    async fn estimate_state_transition_fee(
+        &self,
+        request: Request<EstimateStateTransitionFeeRequest>,
+    ) -> Result<Response<EstimateStateTransitionFeeResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_state_transition_fee_estimation,
+            "estimate_state_transition_fee",
+        )
+        .await
     }
 
     async fn get_identity(
@@ -304,6 +535,30 @@ impl PlatformService for QueryService {
         .await
     }
 
+    async fn get_identity_pending_recovery(
+        &self,
+        request: Request<GetIdentityPendingRecoveryRequest>,
+    ) -> Result<Response<GetIdentityPendingRecoveryResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_identity_pending_recovery,
+            "get_identity_pending_recovery",
+        )
+        .await
+    }
+
+    async fn get_identity_withdrawals(
+        &self,
+        request: Request<GetIdentityWithdrawalsRequest>,
+    ) -> Result<Response<GetIdentityWithdrawalsResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_identity_withdrawals,
+            "get_identity_withdrawals",
+        )
+        .await
+    }
+
     async fn get_identity_balance(
         &self,
         request: Request<GetIdentityBalanceRequest>,
@@ -388,6 +643,42 @@ impl PlatformService for QueryService {
         .await
     }
 
+    async fn get_documents_count(
+        &self,
+        request: Request<GetDocumentsCountRequest>,
+    ) -> Result<Response<GetDocumentsCountResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_documents_count,
+            "get_documents_count",
+        )
+        .await
+    }
+
+    async fn get_document_offers(
+        &self,
+        request: Request<GetDocumentOffersRequest>,
+    ) -> Result<Response<GetDocumentOffersResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_document_offers,
+            "get_document_offers",
+        )
+        .await
+    }
+
+    async fn get_document_history(
+        &self,
+        request: Request<GetDocumentHistoryRequest>,
+    ) -> Result<Response<GetDocumentHistoryResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_document_history,
+            "get_document_history",
+        )
+        .await
+    }
+
     async fn get_identity_by_public_key_hash(
         &self,
         request: Request<GetIdentityByPublicKeyHashRequest>,
@@ -402,16 +693,142 @@ impl PlatformService for QueryService {
 
     async fn wait_for_state_transition_result(
         &self,
-        _request: Request<WaitForStateTransitionResultRequest>,
+        request: Request<WaitForStateTransitionResultRequest>,
     ) -> Result<Response<WaitForStateTransitionResultResponse>, Status> {
-        respond_with_unimplemented("wait_for_state_transition_result")
+        let Some(WaitForStateTransitionResultRequestVersion::V0(request_v0)) =
+            request.into_inner().version
+        else {
+            return Err(Status::invalid_argument(
+                "could not decode wait for state transition result request",
+            ));
+        };
+
+        if request_v0.state_transition_hash.is_empty() {
+            return Err(Status::invalid_argument(
+                "state transition hash is not specified",
+            ));
+        }
+
+        let _waiter = Arc::clone(&self.state_transition_waiters)
+            .try_acquire_owned()
+            .map_err(|_| {
+                Status::resource_exhausted(
+                    "too many clients are waiting for state transition results",
+                )
+            })?;
+
+        let deadline = Instant::now()
+            + Duration::from_millis(self.platform.config.abci.state_transition_wait_timeout_ms);
+
+        let (tx_result, proofs) = self
+            .wait_for_committed_state_transition(
+                &request_v0.state_transition_hash,
+                request_v0.prove,
+                deadline,
+            )
+            .await?;
+
+        let response_v0 = if tx_result.tx_result.code != 0 {
+            let status =
+                drive_response_into_status(tx_result.tx_result.code, &tx_result.tx_result.info);
+
+            WaitForStateTransitionResultResponseV0 {
+                result: Some(wait_for_state_transition_result_response_v0::Result::Error(
+                    StateTransitionBroadcastError {
+                        code: tx_result.tx_result.code,
+                        message: status.message().to_string(),
+                        data: drive_error_data(status.metadata()),
+                    },
+                )),
+                metadata: None,
+            }
+        } else if request_v0.prove {
+            let proofs_response = match proofs {
+                Some(proofs) => proofs?,
+                None => {
+                    let state_transition = StateTransition::deserialize_from_bytes(&tx_result.tx)
+                        .map_err(|error| {
+                        Status::internal(format!("invalid state transition in block: {}", error))
+                    })?;
+
+                    self.prove_state_transition(state_transition).await?
+                }
+            };
+
+            let Some(get_proofs_response::Version::V0(proofs_response_v0)) =
+                proofs_response.version
+            else {
+                return Err(Status::internal("unexpected proofs response version"));
+            };
+
+            WaitForStateTransitionResultResponseV0 {
+                result: proofs_response_v0.result.map(
+                    |get_proofs_response_v0::Result::Proof(proof)| {
+                        wait_for_state_transition_result_response_v0::Result::Proof(proof)
+                    },
+                ),
+                metadata: proofs_response_v0.metadata,
+            }
+        } else {
+            WaitForStateTransitionResultResponseV0 {
+                result: None,
+                metadata: None,
+            }
+        };
+
+        Ok(Response::new(WaitForStateTransitionResultResponse {
+            version: Some(WaitForStateTransitionResultResponseVersion::V0(response_v0)),
+        }))
     }
 
     async fn get_consensus_params(
         &self,
-        _request: Request<GetConsensusParamsRequest>,
+        request: Request<GetConsensusParamsRequest>,
     ) -> Result<Response<GetConsensusParamsResponse>, Status> {
-        respond_with_unimplemented("get_consensus_params")
+        let Some(ConsensusParamsRequestVersion::V0(request_v0)) = request.into_inner().version
+        else {
+            return Err(Status::invalid_argument(
+                "could not decode consensus params request",
+            ));
+        };
+
+        if request_v0.prove {
+            return Err(Status::unimplemented(
+                "proofs are not supported for consensus params",
+            ));
+        }
+
+        let height = match request_v0.height {
+            0 => None,
+            height if height < 0 => {
+                return Err(Status::invalid_argument("height must be a positive number"))
+            }
+            height => Some(height as u64),
+        };
+
+        let consensus_params = self
+            .call_tenderdash("get_consensus_params", move |tenderdash| {
+                tenderdash.consensus_params(height)
+            })
+            .await?
+            .map_err(tenderdash_error_into_status)?;
+
+        Ok(Response::new(GetConsensusParamsResponse {
+            version: Some(ConsensusParamsResponseVersion::V0(
+                GetConsensusParamsResponseV0 {
+                    block: Some(ConsensusParamsBlock {
+                        max_bytes: consensus_params.block.max_bytes,
+                        max_gas: consensus_params.block.max_gas,
+                        time_iota_ms: consensus_params.block.time_iota_ms,
+                    }),
+                    evidence: Some(ConsensusParamsEvidence {
+                        max_age_num_blocks: consensus_params.evidence.max_age_num_blocks,
+                        max_age_duration: consensus_params.evidence.max_age_duration,
+                        max_bytes: consensus_params.evidence.max_bytes,
+                    }),
+                },
+            )),
+        }))
     }
 
     async fn get_protocol_version_upgrade_state(
@@ -522,6 +939,18 @@ impl PlatformService for QueryService {
         .await
     }
 
+    async fn get_governance_proposal_vote_poll_state(
+        &self,
+        request: Request<GetGovernanceProposalVotePollStateRequest>,
+    ) -> Result<Response<GetGovernanceProposalVotePollStateResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_governance_proposal_vote_poll_state,
+            "get_governance_proposal_vote_poll_state",
+        )
+        .await
+    }
+
     async fn get_prefunded_specialized_balance(
         &self,
         request: Request<GetPrefundedSpecializedBalanceRequest>,
@@ -557,6 +986,90 @@ impl PlatformService for QueryService {
         )
         .await
     }
+
+    type SubscribePlatformEventsStream =
+        ReceiverStream<Result<SubscribePlatformEventsResponse, Status>>;
+
+    async fn subscribe_platform_events(
+        &self,
+        request: Request<SubscribePlatformEventsRequest>,
+    ) -> Result<Response<Self::SubscribePlatformEventsStream>, Status> {
+        // Subscribe before validating filters, so blocks committed in the meantime are not missed
+        let mut blocks = self.platform.events.subscribe();
+
+        let filters = Arc::new(
+            self.handle_blocking_query(
+                request,
+                Platform::<DefaultCoreRPC>::query_platform_event_filters,
+                "subscribe_platform_events",
+            )
+            .await?
+            .into_inner(),
+        );
+
+        let (sender, receiver) = mpsc::channel(PLATFORM_EVENTS_CHANNEL_CAPACITY);
+
+        let service = self.clone();
+
+        spawn_task_with_name_if_supported("platform_events", async move {
+            loop {
+                let block = match blocks.recv().await {
+                    Ok(block) => block,
+                    Err(RecvError::Lagged(skipped_blocks)) => {
+                        let _ = sender
+                            .send(Err(Status::resource_exhausted(format!(
+                                "subscriber is too slow, {} blocks were skipped",
+                                skipped_blocks
+                            ))))
+                            .await;
+
+                        break;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+
+                // Subscriber is gone
+                if sender.is_closed() {
+                    break;
+                }
+
+                let result = service
+                    .handle_blocking_query(
+                        Request::new(PlatformEventsQuery {
+                            filters: Arc::clone(&filters),
+                            block,
+                        }),
+                        Platform::<DefaultCoreRPC>::query_platform_events,
+                        "subscribe_platform_events",
+                    )
+                    .await;
+
+                match result {
+                    Ok(response) => {
+                        for event in response.into_inner() {
+                            if sender.send(Ok(event)).await.is_err() {
+                                return;
+                            }
+                        }
+                    }
+                    Err(status) => {
+                        let _ = sender.send(Err(status)).await;
+
+                        break;
+                    }
+                }
+            }
+        })?;
+
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+}
+
+fn state_transition_wait_exceeded(hash: &[u8]) -> Status {
+    Status::deadline_exceeded(format!(
+        "waiting period for state transition {} exceeded",
+        hex::encode_upper(hash)
+    ))
 }
 
 fn query_error_into_status(error: QueryError) -> Status {
@@ -575,3 +1088,592 @@ fn query_error_into_status(error: QueryError) -> Status {
 fn error_into_status(error: Error) -> Status {
     Status::internal(format!("query: {}", error))
 }
+
+/// Converts check tx and deliver tx response code and info into gRPC status
+///
+/// Codes up to 16 are gRPC codes, codes in the 10000-50000 range are consensus errors
+/// which are returned with the serialized error and the code in metadata
+fn drive_response_into_status(code: u32, info: &str) -> Status {
+    let (message, data) = decode_drive_response_info(info);
+
+    let mut metadata = MetadataMap::new();
+
+    if let Some(data) = &data {
+        metadata.insert_bin(
+            "drive-error-data-bin",
+            MetadataValue::from_bytes(&encode_cbor(data)),
+        );
+    }
+
+    let consensus_error_message = || {
+        data.as_ref()
+            .and_then(|data| cbor_map_get(data, "serializedError"))
+            .and_then(ciborium::Value::as_bytes)
+            .and_then(|bytes| ConsensusError::deserialize_from_bytes(bytes).ok())
+            .map(|error| error.to_string())
+            .unwrap_or_default()
+    };
+
+    let grpc_code = match code {
+        0..=16 => {
+            return Status::with_metadata(Code::from(code as i32), message, metadata);
+        }
+        10000..=19999 | 40000..=49999 => Code::InvalidArgument,
+        20000..=29999 => Code::Unauthenticated,
+        30000..=39999 => Code::FailedPrecondition,
+        _ => {
+            return Status::with_metadata(
+                Code::Unknown,
+                format!("unknown drive error code {}: {}", code, message),
+                metadata,
+            );
+        }
+    };
+
+    metadata.insert("code", MetadataValue::from(code));
+
+    Status::with_metadata(grpc_code, consensus_error_message(), metadata)
+}
+
+/// Decodes base64 encoded CBOR info of a check tx or deliver tx response into message and data
+fn decode_drive_response_info(info: &str) -> (String, Option<ciborium::Value>) {
+    let Some(ciborium::Value::Map(fields)) = decode(info, Encoding::Base64)
+        .ok()
+        .and_then(|bytes| ciborium::de::from_reader(bytes.as_slice()).ok())
+    else {
+        return (String::new(), None);
+    };
+
+    let mut message = String::new();
+    let mut data = None;
+
+    for (key, value) in fields {
+        match (key.as_text(), value) {
+            (Some("message"), ciborium::Value::Text(text)) => message = text,
+            (Some("data"), value) => data = Some(value),
+            _ => {}
+        }
+    }
+
+    (message, data)
+}
+
+fn cbor_map_get<'a>(value: &'a ciborium::Value, name: &str) -> Option<&'a ciborium::Value> {
+    value
+        .as_map()?
+        .iter()
+        .find(|(key, _)| key.as_text() == Some(name))
+        .map(|(_, value)| value)
+}
+
+fn encode_cbor(value: &ciborium::Value) -> Vec<u8> {
+    let mut bytes = Vec::new();
+
+    ciborium::ser::into_writer(value, &mut bytes).expect("cbor value must be serializable");
+
+    bytes
+}
+
+/// Returns the data of a deliver tx error for `StateTransitionBroadcastError`
+fn drive_error_data(metadata: &MetadataMap) -> Vec<u8> {
+    metadata
+        .get_bin("drive-error-data-bin")
+        .and_then(|value| value.to_bytes().ok())
+        .map(|bytes| bytes.to_vec())
+        .unwrap_or_default()
+}
+
+fn broadcast_error_into_status(error: TenderdashRPCError) -> Status {
+    if let TenderdashRPCError::JsonRpc { data, .. } = &error {
+        if data == "tx already exists in cache" {
+            return Status::already_exists("state transition already in chain");
+        }
+
+        if let Some(message) = data.strip_prefix("Tx too large. ") {
+            return Status::invalid_argument(format!("state transition is too large. {}", message));
+        }
+
+        if data.starts_with("mempool is full") || data.contains("too_many_resets") {
+            return Status::resource_exhausted(data.clone());
+        }
+
+        if data.contains("context deadline exceeded") {
+            return Status::resource_exhausted("broadcasting state transition is timed out");
+        }
+    }
+
+    tenderdash_error_into_status(error)
+}
+
+fn tenderdash_error_into_status(error: TenderdashRPCError) -> Status {
+    match error {
+        TenderdashRPCError::Unavailable(_) => Status::unavailable("tenderdash is not available"),
+        error => {
+            tracing::error!("unexpected tenderdash rpc error: {:?}", error);
+
+            Status::internal(error.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abci::handler::error::consensus::AbciResponseInfoGetter;
+    use crate::abci::handler::error::HandlerError;
+    use crate::config::PlatformConfig;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use crate::test::helpers::tenderdash::MockTenderdashRPC;
+    use crate::platform_types::platform_events::CommittedBlock;
+    use dapi_grpc::platform::v0::get_consensus_params_request::GetConsensusParamsRequestV0;
+    use dapi_grpc::platform::v0::subscribe_platform_events_request::subscribe_platform_events_request_v0::{
+        event_filter, EventFilter,
+    };
+    use dapi_grpc::platform::v0::subscribe_platform_events_request::{
+        SubscribePlatformEventsRequestV0, Version as SubscribePlatformEventsRequestVersion,
+    };
+    use dapi_grpc::platform::v0::subscribe_platform_events_response::subscribe_platform_events_response_v0::Event;
+    use dapi_grpc::platform::v0::subscribe_platform_events_response::Version as SubscribePlatformEventsResponseVersion;
+    use dapi_grpc::platform::v0::wait_for_state_transition_result_request::WaitForStateTransitionResultRequestV0;
+    use dpp::dashcore::Network;
+    use dpp::platform_value::string_encoding::encode;
+    use dpp::serialization::PlatformSerializable;
+    use dpp::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
+    use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+    use dpp::util::hash::hash_single;
+    use serde_json::json;
+    use std::sync::atomic::AtomicBool;
+    use tempfile::TempDir;
+    use tokio_stream::StreamExt;
+
+    fn setup_query_service(tenderdash: &MockTenderdashRPC) -> (QueryService, TempDir) {
+        let mut config = PlatformConfig::default_for_network(Network::Testnet);
+        config.abci.tenderdash_rpc_url = tenderdash.url();
+        config.abci.state_transition_wait_timeout_ms = 1000;
+
+        let platform = TestPlatformBuilder::new()
+            .with_config(config)
+            .build_with_default_rpc();
+
+        (
+            QueryService::new(Arc::new(platform.platform)),
+            platform.tempdir,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_get_consensus_params() {
+        let tenderdash = MockTenderdashRPC::start(|method, params| {
+            assert_eq!(method, "consensus_params");
+            assert_eq!(params, &json!({ "height": "5" }));
+
+            Ok(json!({
+                "block_height": "5",
+                "consensus_params": {
+                    "block": { "max_bytes": "2097152", "max_gas": "57631392000" },
+                    "evidence": {
+                        "max_age_num_blocks": "100000",
+                        "max_age_duration": "172800000000000",
+                        "max_bytes": "512000"
+                    }
+                }
+            }))
+        });
+
+        let (service, _tempdir) = setup_query_service(&tenderdash);
+
+        let response = service
+            .get_consensus_params(Request::new(GetConsensusParamsRequest {
+                version: Some(ConsensusParamsRequestVersion::V0(
+                    GetConsensusParamsRequestV0 {
+                        height: 5,
+                        prove: false,
+                    },
+                )),
+            }))
+            .await
+            .expect("expected to get consensus params")
+            .into_inner();
+
+        let Some(ConsensusParamsResponseVersion::V0(response_v0)) = response.version else {
+            panic!("expected v0 response");
+        };
+
+        assert_eq!(
+            response_v0.block,
+            Some(ConsensusParamsBlock {
+                max_bytes: "2097152".to_string(),
+                max_gas: "57631392000".to_string(),
+                time_iota_ms: "".to_string(),
+            })
+        );
+        assert_eq!(
+            response_v0.evidence,
+            Some(ConsensusParamsEvidence {
+                max_age_num_blocks: "100000".to_string(),
+                max_age_duration: "172800000000000".to_string(),
+                max_bytes: "512000".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_consensus_params_tenderdash_unavailable() {
+        let mut config = PlatformConfig::default_for_network(Network::Testnet);
+        // Nothing is listening on the port 1
+        config.abci.tenderdash_rpc_url = "http://127.0.0.1:1".to_string();
+
+        let platform = TestPlatformBuilder::new()
+            .with_config(config)
+            .build_with_default_rpc();
+
+        let service = QueryService::new(Arc::new(platform.platform));
+
+        let status = service
+            .get_consensus_params(Request::new(GetConsensusParamsRequest {
+                version: Some(ConsensusParamsRequestVersion::V0(
+                    GetConsensusParamsRequestV0 {
+                        height: 0,
+                        prove: false,
+                    },
+                )),
+            }))
+            .await
+            .expect_err("expected tenderdash to be unavailable");
+
+        assert_eq!(status.code(), Code::Unavailable);
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_invalid_state_transition_is_not_relayed() {
+        let tenderdash = MockTenderdashRPC::start(|_, _| Ok(json!({ "code": 0 })));
+
+        let (service, _tempdir) = setup_query_service(&tenderdash);
+
+        let status = service
+            .broadcast_state_transition(Request::new(BroadcastStateTransitionRequest {
+                state_transition: vec![255; 32],
+            }))
+            .await
+            .expect_err("expected state transition to be rejected");
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert!(tenderdash.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_state_transition_result_with_execution_error() {
+        let info = HandlerError::Internal("execution failed".to_string())
+            .response_info()
+            .expect("expected to encode info");
+
+        let tenderdash = MockTenderdashRPC::start(move |method, params| {
+            assert_eq!(method, "tx");
+            assert_eq!(params["hash"], json!(hex::encode_upper([1; 32])));
+
+            Ok(json!({
+                "hash": hex::encode_upper([1; 32]),
+                "height": "10",
+                "tx": "",
+                "tx_result": { "code": 13, "info": info },
+            }))
+        });
+
+        let (service, _tempdir) = setup_query_service(&tenderdash);
+
+        let response = service
+            .wait_for_state_transition_result(Request::new(WaitForStateTransitionResultRequest {
+                version: Some(WaitForStateTransitionResultRequestVersion::V0(
+                    WaitForStateTransitionResultRequestV0 {
+                        state_transition_hash: vec![1; 32],
+                        prove: true,
+                    },
+                )),
+            }))
+            .await
+            .expect("expected to get state transition result")
+            .into_inner();
+
+        let Some(WaitForStateTransitionResultResponseVersion::V0(response_v0)) = response.version
+        else {
+            panic!("expected v0 response");
+        };
+
+        let Some(wait_for_state_transition_result_response_v0::Result::Error(error)) =
+            response_v0.result
+        else {
+            panic!("expected error result");
+        };
+
+        assert_eq!(error.code, 13);
+        assert_eq!(error.message, "execution failed");
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_state_transition_result_timeout() {
+        let tenderdash = MockTenderdashRPC::start(|_, params| {
+            Err(json!({
+                "code": -32603,
+                "message": "Internal error",
+                "data": format!("tx ({}) not found", params["hash"].as_str().unwrap_or_default()),
+            }))
+        });
+
+        let (service, _tempdir) = setup_query_service(&tenderdash);
+
+        let status = service
+            .wait_for_state_transition_result(Request::new(WaitForStateTransitionResultRequest {
+                version: Some(WaitForStateTransitionResultRequestVersion::V0(
+                    WaitForStateTransitionResultRequestV0 {
+                        state_transition_hash: vec![1; 32],
+                        prove: false,
+                    },
+                )),
+            }))
+            .await
+            .expect_err("expected waiting to time out");
+
+        assert_eq!(status.code(), Code::DeadlineExceeded);
+        // Without committed blocks, Tenderdash is not polled again
+        assert_eq!(tenderdash.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_state_transition_result_woken_up_by_committed_block() {
+        let state_transition = StateTransition::IdentityCreditTransfer(
+            IdentityCreditTransferTransition::V0(IdentityCreditTransferTransitionV0 {
+                amount: 1000,
+                ..Default::default()
+            }),
+        );
+
+        let serialized_state_transition = state_transition
+            .serialize_to_bytes()
+            .expect("expected to serialize state transition");
+
+        let hash = hash_single(&serialized_state_transition);
+
+        let tx = encode(&serialized_state_transition, Encoding::Base64);
+        let committed = Arc::new(AtomicBool::new(false));
+        let tenderdash_committed = Arc::clone(&committed);
+
+        let tenderdash = MockTenderdashRPC::start(move |method, params| {
+            assert_eq!(method, "tx");
+
+            if tenderdash_committed.load(Ordering::SeqCst) {
+                Ok(json!({
+                    "hash": hex::encode_upper(hash),
+                    "height": "7",
+                    "tx": tx,
+                    "tx_result": { "code": 0 },
+                }))
+            } else {
+                Err(json!({
+                    "code": -32603,
+                    "message": "Internal error",
+                    "data": format!("tx ({}) not found", params["hash"].as_str().unwrap_or_default()),
+                }))
+            }
+        });
+
+        let (service, _tempdir) = setup_query_service(&tenderdash);
+
+        let waiter = tokio::spawn({
+            let service = service.clone();
+
+            async move {
+                service
+                    .wait_for_state_transition_result(Request::new(
+                        WaitForStateTransitionResultRequest {
+                            version: Some(WaitForStateTransitionResultRequestVersion::V0(
+                                WaitForStateTransitionResultRequestV0 {
+                                    state_transition_hash: hash.to_vec(),
+                                    prove: false,
+                                },
+                            )),
+                        },
+                    ))
+                    .await
+            }
+        });
+
+        // Let the waiter look the state transition up and subscribe to committed blocks
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        // A block without the state transition doesn't wake the waiter up
+        service
+            .platform
+            .events
+            .publish(CommittedBlock::from_transactions(6, [6; 32], &[]));
+
+        committed.store(true, Ordering::SeqCst);
+
+        service
+            .platform
+            .events
+            .publish(CommittedBlock::from_transactions(
+                7,
+                [7; 32],
+                &[serialized_state_transition],
+            ));
+
+        let response = waiter
+            .await
+            .expect("expected waiter to finish")
+            .expect("expected to get state transition result")
+            .into_inner();
+
+        let Some(WaitForStateTransitionResultResponseVersion::V0(response_v0)) = response.version
+        else {
+            panic!("expected v0 response");
+        };
+
+        assert!(response_v0.result.is_none());
+        assert_eq!(tenderdash.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_state_transition_result_too_many_waiters() {
+        let tenderdash = MockTenderdashRPC::start(|_, params| {
+            Err(json!({
+                "code": -32603,
+                "message": "Internal error",
+                "data": format!("tx ({}) not found", params["hash"].as_str().unwrap_or_default()),
+            }))
+        });
+
+        let mut config = PlatformConfig::default_for_network(Network::Testnet);
+        config.abci.tenderdash_rpc_url = tenderdash.url();
+        config.abci.state_transition_wait_timeout_ms = 1000;
+        config.abci.max_state_transition_waiters = 1;
+
+        let platform = TestPlatformBuilder::new()
+            .with_config(config)
+            .build_with_default_rpc();
+
+        let service = QueryService::new(Arc::new(platform.platform));
+
+        let request = || {
+            Request::new(WaitForStateTransitionResultRequest {
+                version: Some(WaitForStateTransitionResultRequestVersion::V0(
+                    WaitForStateTransitionResultRequestV0 {
+                        state_transition_hash: vec![1; 32],
+                        prove: false,
+                    },
+                )),
+            })
+        };
+
+        let waiter = tokio::spawn({
+            let service = service.clone();
+            let request = request();
+
+            async move { service.wait_for_state_transition_result(request).await }
+        });
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let status = service
+            .wait_for_state_transition_result(request())
+            .await
+            .expect_err("expected waiter to be rejected");
+
+        assert_eq!(status.code(), Code::ResourceExhausted);
+
+        let status = waiter
+            .await
+            .expect("expected waiter to finish")
+            .expect_err("expected waiting to time out");
+
+        assert_eq!(status.code(), Code::DeadlineExceeded);
+
+        // The slot is released once the waiter is done
+        let status = service
+            .wait_for_state_transition_result(request())
+            .await
+            .expect_err("expected waiting to time out");
+
+        assert_eq!(status.code(), Code::DeadlineExceeded);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_platform_events_receives_committed_blocks() {
+        let tenderdash = MockTenderdashRPC::start(|_, _| Ok(json!({})));
+
+        let (service, _tempdir) = setup_query_service(&tenderdash);
+
+        let mut stream = service
+            .subscribe_platform_events(Request::new(SubscribePlatformEventsRequest {
+                version: Some(SubscribePlatformEventsRequestVersion::V0(
+                    SubscribePlatformEventsRequestV0 {
+                        filters: vec![EventFilter {
+                            filter: Some(event_filter::Filter::Blocks(true)),
+                        }],
+                    },
+                )),
+            }))
+            .await
+            .expect("expected to subscribe")
+            .into_inner();
+
+        service
+            .platform
+            .events
+            .publish(CommittedBlock::from_transactions(1, [3; 32], &[]));
+
+        let response = stream
+            .next()
+            .await
+            .expect("expected an event")
+            .expect("expected a valid event");
+
+        let Some(SubscribePlatformEventsResponseVersion::V0(response_v0)) = response.version else {
+            panic!("expected v0 response");
+        };
+
+        let Some(Event::BlockCommitted(block_committed)) = response_v0.event else {
+            panic!("expected block committed event");
+        };
+
+        assert_eq!(block_committed.height, 1);
+        assert_eq!(block_committed.block_hash, vec![3; 32]);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_platform_events_without_filters() {
+        let tenderdash = MockTenderdashRPC::start(|_, _| Ok(json!({})));
+
+        let (service, _tempdir) = setup_query_service(&tenderdash);
+
+        let status = service
+            .subscribe_platform_events(Request::new(SubscribePlatformEventsRequest {
+                version: Some(SubscribePlatformEventsRequestVersion::V0(
+                    SubscribePlatformEventsRequestV0 { filters: vec![] },
+                )),
+            }))
+            .await
+            .expect_err("expected subscription to be rejected");
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+
+    #[test]
+    fn test_consensus_error_response_into_status() {
+        let info = ConsensusError::DefaultError
+            .response_info_for_version(PlatformVersion::latest())
+            .expect("expected to encode info");
+
+        let status = drive_response_into_status(40000, &info);
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(status.message(), "default error");
+        assert_eq!(
+            status
+                .metadata()
+                .get("code")
+                .and_then(|code| code.to_str().ok()),
+            Some("40000")
+        );
+        assert!(status.metadata().get_bin("drive-error-data-bin").is_some());
+    }
+}
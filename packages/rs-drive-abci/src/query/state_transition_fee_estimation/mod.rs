@@ -0,0 +1,70 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use crate::rpc::core::CoreRPCLike;
+use dapi_grpc::platform::v0::estimate_state_transition_fee_request::Version as RequestVersion;
+use dapi_grpc::platform::v0::estimate_state_transition_fee_response::Version as ResponseVersion;
+use dapi_grpc::platform::v0::{
+    EstimateStateTransitionFeeRequest, EstimateStateTransitionFeeResponse,
+};
+use dpp::version::PlatformVersion;
+
+mod v0;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Querying of the fees a state transition would be charged if it was broadcast now
+    pub fn query_state_transition_fee_estimation(
+        &self,
+        EstimateStateTransitionFeeRequest { version }: EstimateStateTransitionFeeRequest,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<EstimateStateTransitionFeeResponse>, Error> {
+        let Some(version) = version else {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::DecodingError(
+                    "could not decode state transition fee estimation query".to_string(),
+                ),
+            ));
+        };
+
+        let feature_version_bounds = &platform_version
+            .drive_abci
+            .query
+            .state_transition_fee_estimation_query;
+
+        let feature_version = match &version {
+            RequestVersion::V0(_) => 0,
+        };
+        if !feature_version_bounds.check_version(feature_version) {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::UnsupportedQueryVersion(
+                    "state_transition_fee_estimation".to_string(),
+                    feature_version_bounds.min_version,
+                    feature_version_bounds.max_version,
+                    platform_version.protocol_version,
+                    feature_version,
+                ),
+            ));
+        }
+        match version {
+            RequestVersion::V0(request_v0) => {
+                let result = self.query_state_transition_fee_estimation_v0(
+                    request_v0,
+                    platform_state,
+                    platform_version,
+                )?;
+
+                Ok(
+                    result.map(|response_v0| EstimateStateTransitionFeeResponse {
+                        version: Some(ResponseVersion::V0(response_v0)),
+                    }),
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use crate::abci::handler::error::HandlerError;
+use crate::error::execution::ExecutionError;
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+use crate::query::QueryValidationResult;
+use crate::rpc::core::CoreRPCLike;
+use dapi_grpc::platform::v0::estimate_state_transition_fee_request::EstimateStateTransitionFeeRequestV0;
+use dapi_grpc::platform::v0::estimate_state_transition_fee_response::{
+    estimate_state_transition_fee_response_v0, EstimateStateTransitionFeeResponseV0,
+};
+use dapi_grpc::platform::v0::StateTransitionBroadcastError;
+use dpp::consensus::ConsensusError;
+use dpp::fee::fee_result::FeeResult;
+use dpp::platform_value::platform_value;
+use dpp::serialization::PlatformSerializableWithPlatformVersion;
+use dpp::version::PlatformVersion;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn query_state_transition_fee_estimation_v0(
+        &self,
+        EstimateStateTransitionFeeRequestV0 { state_transition }: EstimateStateTransitionFeeRequestV0,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<EstimateStateTransitionFeeResponseV0>, Error> {
+        if state_transition.is_empty() {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::InvalidArgument("state transition is not specified".to_string()),
+            ));
+        }
+
+        let execution_result = self.estimate_state_transition_fee(
+            &state_transition,
+            platform_state,
+            platform_version,
+        )?;
+
+        let (fee_result, consensus_error) = match execution_result {
+            StateTransitionExecutionResult::SuccessfulExecution(_, fee_result) => {
+                (Some(fee_result), None)
+            }
+            StateTransitionExecutionResult::PaidConsensusError(error, fee_result) => {
+                (Some(fee_result), Some(error))
+            }
+            StateTransitionExecutionResult::UnpaidConsensusError(error) => (None, Some(error)),
+            StateTransitionExecutionResult::InternalError(message) => {
+                return Ok(QueryValidationResult::new_with_error(
+                    QueryError::InvalidArgument(format!(
+                        "state transition could not be executed: {}",
+                        message
+                    )),
+                ));
+            }
+            StateTransitionExecutionResult::NotExecuted(_) => {
+                return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                    "a state transition is always executed when estimating its fee",
+                )));
+            }
+        };
+
+        let error = consensus_error
+            .map(|error| consensus_error_to_broadcast_error(error, platform_version))
+            .transpose()?;
+
+        Ok(QueryValidationResult::new_with_data(
+            EstimateStateTransitionFeeResponseV0 {
+                fee_result: fee_result.map(fee_result_to_proto),
+                error,
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            },
+        ))
+    }
+}
+
+fn fee_result_to_proto(
+    fee_result: FeeResult,
+) -> estimate_state_transition_fee_response_v0::FeeResult {
+    let fee_refunds = fee_result
+        .fee_refunds
+        .into_iter()
+        .map(|(identity_id, credits_per_epoch)| {
+            let mut epoch_refunds: Vec<_> = credits_per_epoch
+                .into_iter()
+                .map(
+                    |(epoch, credits)| estimate_state_transition_fee_response_v0::EpochRefund {
+                        epoch: epoch as u32,
+                        credits,
+                    },
+                )
+                .collect();
+
+            epoch_refunds.sort_by_key(|epoch_refund| epoch_refund.epoch);
+
+            estimate_state_transition_fee_response_v0::IdentityRefunds {
+                identity_id: identity_id.to_vec(),
+                epoch_refunds,
+            }
+        })
+        .collect();
+
+    estimate_state_transition_fee_response_v0::FeeResult {
+        storage_fee: fee_result.storage_fee,
+        processing_fee: fee_result.processing_fee,
+        fee_refunds,
+        removed_bytes_from_system: fee_result.removed_bytes_from_system,
+    }
+}
+
+/// Encodes a consensus error the same way errors of executed state transitions are returned
+fn consensus_error_to_broadcast_error(
+    error: ConsensusError,
+    platform_version: &PlatformVersion,
+) -> Result<StateTransitionBroadcastError, Error> {
+    let consensus_error_bytes = error
+        .serialize_to_bytes_with_platform_version(platform_version)
+        .map_err(Error::Protocol)?;
+
+    let data = platform_value!({
+        "serializedError": consensus_error_bytes
+    })
+    .to_cbor_buffer()
+    .map_err(|e| Error::Protocol(e.into()))?;
+
+    Ok(StateTransitionBroadcastError {
+        code: HandlerError::from(&error).code(),
+        message: error.to_string(),
+        data,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::tests::setup_platform;
+    use ciborium::Value as CborValue;
+    use dpp::dashcore::Network;
+    use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+    use dpp::platform_value::Value;
+    use dpp::serialization::PlatformDeserializable;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_empty_state_transition() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let request = EstimateStateTransitionFeeRequestV0 {
+            state_transition: vec![],
+        };
+
+        let result = platform
+            .query_state_transition_fee_estimation_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::InvalidArgument(msg)] if msg.contains("state transition is not specified")
+        ));
+    }
+
+    #[test]
+    fn test_state_transition_that_would_not_be_paid_for() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let request = EstimateStateTransitionFeeRequestV0 {
+            state_transition: vec![0; 8],
+        };
+
+        let result = platform
+            .query_state_transition_fee_estimation_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(result.is_valid());
+
+        let Some(EstimateStateTransitionFeeResponseV0 {
+            fee_result: None,
+            error: Some(error),
+            metadata: Some(_),
+        }) = result.data
+        else {
+            panic!("expected an unpaid consensus error");
+        };
+
+        let data: BTreeMap<String, CborValue> =
+            ciborium::de::from_reader(error.data.as_slice()).expect("expected cbor error data");
+        let data: BTreeMap<String, Value> =
+            Value::convert_from_cbor_map(data).expect("expected error data values");
+
+        let serialized_error = data
+            .get_bytes("serializedError")
+            .expect("expected a serialized error");
+
+        let consensus_error = ConsensusError::deserialize_from_bytes(&serialized_error)
+            .expect("expected a consensus error");
+
+        assert_eq!(error.message, consensus_error.to_string());
+        assert_eq!(error.code, HandlerError::from(&consensus_error).code());
+    }
+}
@@ -0,0 +1,209 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_governance_proposal_vote_poll_state_request::GetGovernanceProposalVotePollStateRequestV0;
+use dapi_grpc::platform::v0::get_governance_proposal_vote_poll_state_response::{
+    get_governance_proposal_vote_poll_state_response_v0,
+    GetGovernanceProposalVotePollStateResponseV0,
+};
+use dpp::check_validation_result_with_data;
+use dpp::platform_value::Identifier;
+use dpp::serialization::PlatformSerializable;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+
+impl<C> Platform<C> {
+    pub(super) fn query_governance_proposal_vote_poll_state_v0(
+        &self,
+        GetGovernanceProposalVotePollStateRequestV0 {
+            vote_poll_id,
+            prove,
+        }: GetGovernanceProposalVotePollStateRequestV0,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetGovernanceProposalVotePollStateResponseV0>, Error> {
+        let vote_poll_id = check_validation_result_with_data!(Identifier::from_vec(vote_poll_id)
+            .map_err(|_| QueryError::InvalidArgument(
+                "vote poll id must be 32 bytes long".to_string()
+            )));
+
+        let response = if prove {
+            let proof = self.drive.prove_governance_proposal_vote_poll_stored_info(
+                vote_poll_id.to_buffer(),
+                None,
+                platform_version,
+            )?;
+
+            GetGovernanceProposalVotePollStateResponseV0 {
+                result: Some(
+                    get_governance_proposal_vote_poll_state_response_v0::Result::Proof(
+                        self.response_proof_v0(platform_state, proof),
+                    ),
+                ),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        } else {
+            let maybe_stored_info = self.drive.fetch_governance_proposal_vote_poll_stored_info(
+                vote_poll_id,
+                None,
+                platform_version,
+            )?;
+
+            // empty here means that nobody voted on the proposal yet
+            let stored_info = maybe_stored_info
+                .map(|stored_info| stored_info.serialize_to_bytes())
+                .transpose()?
+                .unwrap_or_default();
+
+            GetGovernanceProposalVotePollStateResponseV0 {
+                metadata: Some(self.response_metadata_v0(platform_state)),
+                result: Some(
+                    get_governance_proposal_vote_poll_state_response_v0::Result::StoredInfo(
+                        stored_info,
+                    ),
+                ),
+            }
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::tests::setup_platform;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::dashcore::Network;
+    use dpp::serialization::PlatformDeserializable;
+    use dpp::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+    use dpp::voting::vote_info_storage::governance_proposal_vote_poll_stored_info::{
+        GovernanceProposalVotePollStoredInfo, GovernanceProposalVotePollStoredInfoV0Getters,
+    };
+    use dpp::voting::vote_polls::governance_proposal_vote_poll::{
+        GovernanceProposal, GovernanceProposalVotePoll,
+    };
+    use dpp::voting::votes::governance_proposal_vote::v0::GovernanceProposalVoteV0;
+    use dpp::voting::votes::governance_proposal_vote::GovernanceProposalVote;
+
+    #[test]
+    fn test_invalid_vote_poll_id() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let request = GetGovernanceProposalVotePollStateRequestV0 {
+            vote_poll_id: vec![0; 8],
+            prove: false,
+        };
+
+        let result = platform
+            .query_governance_proposal_vote_poll_state_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::InvalidArgument(msg)] if msg.contains("vote poll id must be 32 bytes long")));
+    }
+
+    #[test]
+    fn test_vote_poll_not_found() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let request = GetGovernanceProposalVotePollStateRequestV0 {
+            vote_poll_id: vec![0; 32],
+            prove: false,
+        };
+
+        let result = platform
+            .query_governance_proposal_vote_poll_state_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(result.is_valid());
+
+        assert!(matches!(
+            result.data,
+            Some(GetGovernanceProposalVotePollStateResponseV0 {
+                result: Some(get_governance_proposal_vote_poll_state_response_v0::Result::StoredInfo(bytes)),
+                metadata: Some(_),
+            }) if bytes.is_empty()
+        ));
+    }
+
+    #[test]
+    fn test_vote_poll_is_found() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let vote_poll = GovernanceProposalVotePoll {
+            proposal: GovernanceProposal::ParameterChange {
+                parameter: "maxStateTransitionSize".to_string(),
+                value: 20480u64.into(),
+            },
+            description_hash: [5; 32],
+            end_date: 1_000_000,
+        };
+
+        let vote_poll_id = vote_poll.unique_id().expect("expected a unique id");
+
+        platform
+            .drive
+            .register_governance_proposal_identity_vote(
+                [1; 32],
+                GovernanceProposalVote::V0(GovernanceProposalVoteV0 {
+                    vote_poll: vote_poll.clone(),
+                    vote_choice: YesNoAbstainVoteChoice::YES,
+                }),
+                &BlockInfo::genesis(),
+                None,
+                version,
+            )
+            .expect("expected to register the vote");
+
+        let request = GetGovernanceProposalVotePollStateRequestV0 {
+            vote_poll_id: vote_poll_id.to_vec(),
+            prove: false,
+        };
+
+        let result = platform
+            .query_governance_proposal_vote_poll_state_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(result.is_valid());
+
+        let Some(GetGovernanceProposalVotePollStateResponseV0 {
+            result:
+                Some(get_governance_proposal_vote_poll_state_response_v0::Result::StoredInfo(bytes)),
+            metadata: Some(_),
+        }) = result.data
+        else {
+            panic!("expected stored info");
+        };
+
+        let stored_info = GovernanceProposalVotePollStoredInfo::deserialize_from_bytes(&bytes)
+            .expect("expected to deserialize the stored info");
+        assert_eq!(stored_info.vote_poll(), &vote_poll);
+        assert!(!stored_info.has_ended());
+    }
+
+    #[test]
+    fn test_vote_poll_absence_proof() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet);
+
+        let request = GetGovernanceProposalVotePollStateRequestV0 {
+            vote_poll_id: vec![0; 32],
+            prove: true,
+        };
+
+        let result = platform
+            .query_governance_proposal_vote_poll_state_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.data,
+            Some(GetGovernanceProposalVotePollStateResponseV0 {
+                result: Some(get_governance_proposal_vote_poll_state_response_v0::Result::Proof(_)),
+                metadata: Some(_),
+            })
+        ));
+    }
+}
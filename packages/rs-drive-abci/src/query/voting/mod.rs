@@ -2,4 +2,5 @@ mod contested_resource_identity_votes;
 mod contested_resource_vote_state;
 mod contested_resource_voters_for_identity;
 mod contested_resources;
+mod governance_proposal_vote_poll_state;
 mod vote_polls_by_end_date_query;
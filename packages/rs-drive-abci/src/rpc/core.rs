@@ -344,3 +344,112 @@ impl CoreRPCLike for DefaultCoreRPC {
             .get_asset_unlock_statuses(indices, Some(core_chain_locked_height)))
     }
 }
+
+/// Core RPC of a platform sharing the core RPC client of another one
+impl<T: CoreRPCLike> CoreRPCLike for &T {
+    fn get_block_hash(&self, height: CoreHeight) -> Result<BlockHash, Error> {
+        (**self).get_block_hash(height)
+    }
+
+    fn get_block_header(&self, block_hash: &BlockHash) -> Result<Header, Error> {
+        (**self).get_block_header(block_hash)
+    }
+
+    fn get_block_time_from_height(&self, height: CoreHeight) -> Result<TimestampMillis, Error> {
+        (**self).get_block_time_from_height(height)
+    }
+
+    fn get_best_chain_lock(&self) -> Result<ChainLock, Error> {
+        (**self).get_best_chain_lock()
+    }
+
+    fn submit_chain_lock(&self, chain_lock: &ChainLock) -> Result<u32, Error> {
+        (**self).submit_chain_lock(chain_lock)
+    }
+
+    fn get_transaction(&self, tx_id: &Txid) -> Result<Transaction, Error> {
+        (**self).get_transaction(tx_id)
+    }
+
+    fn get_asset_unlock_statuses(
+        &self,
+        indices: &[u64],
+        core_chain_locked_height: u32,
+    ) -> Result<Vec<AssetUnlockStatusResult>, Error> {
+        (**self).get_asset_unlock_statuses(indices, core_chain_locked_height)
+    }
+
+    fn get_transaction_extended_info(
+        &self,
+        tx_id: &Txid,
+    ) -> Result<GetRawTransactionResult, Error> {
+        (**self).get_transaction_extended_info(tx_id)
+    }
+
+    fn get_optional_transaction_extended_info(
+        &self,
+        transaction_id: &Txid,
+    ) -> Result<Option<GetRawTransactionResult>, Error> {
+        (**self).get_optional_transaction_extended_info(transaction_id)
+    }
+
+    fn get_fork_info(&self, name: &str) -> Result<Option<SoftforkInfo>, Error> {
+        (**self).get_fork_info(name)
+    }
+
+    fn get_block(&self, block_hash: &BlockHash) -> Result<Block, Error> {
+        (**self).get_block(block_hash)
+    }
+
+    fn get_block_json(&self, block_hash: &BlockHash) -> Result<Value, Error> {
+        (**self).get_block_json(block_hash)
+    }
+
+    fn get_chain_tips(&self) -> Result<GetChainTipsResult, Error> {
+        (**self).get_chain_tips()
+    }
+
+    fn get_quorum_listextended(
+        &self,
+        height: Option<CoreHeight>,
+    ) -> Result<ExtendedQuorumListResult, Error> {
+        (**self).get_quorum_listextended(height)
+    }
+
+    fn get_quorum_info(
+        &self,
+        quorum_type: QuorumType,
+        hash: &QuorumHash,
+        include_secret_key_share: Option<bool>,
+    ) -> Result<QuorumInfoResult, Error> {
+        (**self).get_quorum_info(quorum_type, hash, include_secret_key_share)
+    }
+
+    fn get_protx_diff_with_masternodes(
+        &self,
+        base_block: Option<u32>,
+        block: u32,
+    ) -> Result<MasternodeListDiff, Error> {
+        (**self).get_protx_diff_with_masternodes(base_block, block)
+    }
+
+    fn verify_instant_lock(
+        &self,
+        instant_lock: &InstantLock,
+        max_height: Option<u32>,
+    ) -> Result<bool, Error> {
+        (**self).verify_instant_lock(instant_lock, max_height)
+    }
+
+    fn verify_chain_lock(&self, chain_lock: &ChainLock) -> Result<bool, Error> {
+        (**self).verify_chain_lock(chain_lock)
+    }
+
+    fn masternode_sync_status(&self) -> Result<MnSyncStatus, Error> {
+        (**self).masternode_sync_status()
+    }
+
+    fn send_raw_transaction(&self, transaction: &[u8]) -> Result<Txid, Error> {
+        (**self).send_raw_transaction(transaction)
+    }
+}
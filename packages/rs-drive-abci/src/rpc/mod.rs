@@ -2,3 +2,5 @@
 pub mod core;
 /// Core signatures verification
 pub mod signature;
+/// Tenderdash RPC
+pub mod tenderdash;
@@ -0,0 +1,339 @@
+use crate::utils::from_str_or_number;
+use dpp::platform_value::string_encoding::{decode, encode, Encoding};
+use serde::{Deserialize, Deserializer};
+use serde_json::{json, Value as JsonValue};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Timeout of a single Tenderdash RPC request
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Errors returned by the Tenderdash RPC client
+#[derive(Debug, thiserror::Error)]
+pub enum TenderdashRPCError {
+    /// Tenderdash could not be reached
+    #[error("tenderdash is not available: {0}")]
+    Unavailable(String),
+
+    /// Tenderdash responded with a JSON-RPC error
+    #[error("tenderdash rpc error {code}: {message}: {data}")]
+    JsonRpc {
+        /// JSON-RPC error code
+        code: i64,
+        /// JSON-RPC error message
+        message: String,
+        /// Additional error information
+        data: String,
+    },
+
+    /// Tenderdash response could not be decoded
+    #[error("invalid tenderdash rpc response: {0}")]
+    InvalidResponse(String),
+}
+
+/// Result of the mempool check of a broadcasted transaction
+#[derive(Debug, Clone, Deserialize)]
+pub struct BroadcastTxResult {
+    /// Check tx response code, 0 if the transaction was added to the mempool
+    #[serde(default)]
+    pub code: u32,
+    /// Check tx response info
+    #[serde(default)]
+    pub info: String,
+}
+
+/// Result of the execution of a transaction included in a block
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ExecTxResult {
+    /// Execution response code, 0 if the transaction was executed successfully
+    #[serde(default)]
+    pub code: u32,
+    /// Execution response info
+    #[serde(default)]
+    pub info: String,
+}
+
+/// A transaction included in a block
+#[derive(Debug, Clone, Deserialize)]
+pub struct TxResult {
+    /// Height of the block including the transaction
+    #[serde(deserialize_with = "from_str_or_number")]
+    pub height: u64,
+    /// The transaction
+    #[serde(default, deserialize_with = "from_base64")]
+    pub tx: Vec<u8>,
+    /// Result of the transaction execution
+    #[serde(default)]
+    pub tx_result: ExecTxResult,
+}
+
+/// Block consensus params
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConsensusParamsBlock {
+    /// Maximum block size in bytes
+    pub max_bytes: String,
+    /// Maximum gas per block
+    pub max_gas: String,
+    /// Minimum time increment between consecutive blocks in milliseconds
+    pub time_iota_ms: String,
+}
+
+/// Evidence consensus params
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConsensusParamsEvidence {
+    /// Maximum age of evidence in blocks
+    pub max_age_num_blocks: String,
+    /// Maximum age of evidence as a duration
+    pub max_age_duration: String,
+    /// Maximum size of evidence in bytes
+    pub max_bytes: String,
+}
+
+/// Consensus params of the chain at a given height
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConsensusParams {
+    /// Block params
+    pub block: ConsensusParamsBlock,
+    /// Evidence params
+    pub evidence: ConsensusParamsEvidence,
+}
+
+/// Tenderdash JSON-RPC client
+#[derive(Debug)]
+pub struct TenderdashRPC {
+    url: String,
+    agent: ureq::Agent,
+    request_id: AtomicU64,
+}
+
+impl TenderdashRPC {
+    /// Creates a client for the Tenderdash RPC listening on `url`
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            agent: ureq::AgentBuilder::new().timeout(REQUEST_TIMEOUT).build(),
+            request_id: AtomicU64::new(0),
+        }
+    }
+
+    /// Adds a transaction to the mempool once it passed check tx
+    pub fn broadcast_tx(&self, tx: &[u8]) -> Result<BroadcastTxResult, TenderdashRPCError> {
+        let result = self.request(
+            "broadcast_tx",
+            json!({ "tx": encode(tx, Encoding::Base64) }),
+        )?;
+
+        serde_json::from_value(result)
+            .map_err(|e| TenderdashRPCError::InvalidResponse(e.to_string()))
+    }
+
+    /// Returns the transaction with the given hash if it was included in a block
+    pub fn tx(&self, hash: &[u8]) -> Result<Option<TxResult>, TenderdashRPCError> {
+        let result = match self.request(
+            "tx",
+            json!({ "hash": hex::encode_upper(hash), "prove": false }),
+        ) {
+            Ok(result) => result,
+            Err(TenderdashRPCError::JsonRpc { data, .. }) if data.contains("not found") => {
+                return Ok(None)
+            }
+            Err(e) => return Err(e),
+        };
+
+        serde_json::from_value(result)
+            .map(Some)
+            .map_err(|e| TenderdashRPCError::InvalidResponse(e.to_string()))
+    }
+
+    /// Returns the consensus params at the given height, or at the latest height
+    pub fn consensus_params(
+        &self,
+        height: Option<u64>,
+    ) -> Result<ConsensusParams, TenderdashRPCError> {
+        let params = match height {
+            Some(height) => json!({ "height": height.to_string() }),
+            None => json!({}),
+        };
+
+        let result = self.request("consensus_params", params)?;
+
+        let consensus_params = result.get("consensus_params").ok_or_else(|| {
+            TenderdashRPCError::InvalidResponse("consensus params are missing".to_string())
+        })?;
+
+        // Params are encoded as strings or numbers depending on the Tenderdash version
+        let param = |section: &str, name: &str| -> String {
+            match consensus_params.get(section).and_then(|s| s.get(name)) {
+                Some(JsonValue::String(value)) => value.clone(),
+                Some(JsonValue::Null) | None => String::new(),
+                Some(value) => value.to_string(),
+            }
+        };
+
+        Ok(ConsensusParams {
+            block: ConsensusParamsBlock {
+                max_bytes: param("block", "max_bytes"),
+                max_gas: param("block", "max_gas"),
+                time_iota_ms: param("block", "time_iota_ms"),
+            },
+            evidence: ConsensusParamsEvidence {
+                max_age_num_blocks: param("evidence", "max_age_num_blocks"),
+                max_age_duration: param("evidence", "max_age_duration"),
+                max_bytes: param("evidence", "max_bytes"),
+            },
+        })
+    }
+
+    fn request(&self, method: &str, params: JsonValue) -> Result<JsonValue, TenderdashRPCError> {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": self.request_id.fetch_add(1, Ordering::Relaxed),
+            "method": method,
+            "params": params,
+        });
+
+        let response = match self
+            .agent
+            .post(&self.url)
+            .set("Content-Type", "application/json")
+            .send_string(&body.to_string())
+        {
+            Ok(response) => response,
+            // JSON-RPC errors can be returned with an HTTP error status
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => return Err(TenderdashRPCError::Unavailable(e.to_string())),
+        };
+
+        let response: JsonValue = response
+            .into_string()
+            .map_err(|e| TenderdashRPCError::Unavailable(e.to_string()))
+            .and_then(|body| {
+                serde_json::from_str(&body)
+                    .map_err(|e| TenderdashRPCError::InvalidResponse(e.to_string()))
+            })?;
+
+        if let Some(error) = response.get("error").filter(|error| !error.is_null()) {
+            return Err(TenderdashRPCError::JsonRpc {
+                code: error
+                    .get("code")
+                    .and_then(JsonValue::as_i64)
+                    .unwrap_or(-32603),
+                message: error
+                    .get("message")
+                    .and_then(JsonValue::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                data: error
+                    .get("data")
+                    .and_then(JsonValue::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+            });
+        }
+
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| TenderdashRPCError::InvalidResponse("result is missing".to_string()))
+    }
+}
+
+fn from_base64<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let encoded = String::deserialize(deserializer)?;
+
+    decode(&encoded, Encoding::Base64).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::helpers::tenderdash::MockTenderdashRPC;
+
+    #[test]
+    fn test_broadcast_tx() {
+        let server = MockTenderdashRPC::start(|_, _| {
+            Ok(json!({ "code": 0, "data": "", "info": "", "hash": "00" }))
+        });
+
+        let client = TenderdashRPC::new(&server.url());
+
+        let result = client
+            .broadcast_tx(&[1, 2, 3])
+            .expect("expected to broadcast tx");
+
+        assert_eq!(result.code, 0);
+        assert_eq!(
+            server.requests(),
+            vec![("broadcast_tx".to_string(), json!({ "tx": "AQID" }))]
+        );
+    }
+
+    #[test]
+    fn test_broadcast_tx_json_rpc_error() {
+        let server = MockTenderdashRPC::start(|_, _| {
+            Err(json!({
+                "code": -32603,
+                "message": "Internal error",
+                "data": "tx already exists in cache",
+            }))
+        });
+
+        let client = TenderdashRPC::new(&server.url());
+
+        let error = client
+            .broadcast_tx(&[1, 2, 3])
+            .expect_err("expected json rpc error");
+
+        assert!(matches!(
+            error,
+            TenderdashRPCError::JsonRpc { code: -32603, data, .. } if data == "tx already exists in cache"
+        ));
+    }
+
+    #[test]
+    fn test_tx() {
+        let server = MockTenderdashRPC::start(|_, params| {
+            if params["hash"] == json!(hex::encode_upper([1; 32])) {
+                Ok(json!({
+                    "hash": hex::encode_upper([1; 32]),
+                    "height": "42",
+                    "tx": "AQID",
+                    "tx_result": { "code": 0 },
+                }))
+            } else {
+                Err(json!({
+                    "code": -32603,
+                    "message": "Internal error",
+                    "data": format!("tx ({}) not found", params["hash"]),
+                }))
+            }
+        });
+
+        let client = TenderdashRPC::new(&server.url());
+
+        let tx = client
+            .tx(&[1; 32])
+            .expect("expected to get tx")
+            .expect("expected tx to be found");
+
+        assert_eq!(tx.height, 42);
+        assert_eq!(tx.tx, vec![1, 2, 3]);
+        assert_eq!(tx.tx_result.code, 0);
+
+        assert!(client.tx(&[2; 32]).expect("expected to get tx").is_none());
+    }
+
+    #[test]
+    fn test_unavailable() {
+        // Nothing is listening on the port 1
+        let client = TenderdashRPC::new("http://127.0.0.1:1");
+
+        assert!(matches!(
+            client.consensus_params(None),
+            Err(TenderdashRPCError::Unavailable(_))
+        ));
+    }
+}
@@ -3,6 +3,9 @@
 pub mod fast_forward_to_block;
 pub mod fee_pools;
 pub mod setup;
+/// Mock of Tenderdash JSON-RPC
+#[cfg(test)]
+pub mod tenderdash;
 // TODO: Move tests to appropriate place
 
 #[cfg(test)]
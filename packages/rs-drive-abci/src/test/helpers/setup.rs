@@ -33,9 +33,11 @@
 //!
 
 use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
 
 use crate::platform_types::platform::Platform;
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
 #[cfg(any(feature = "mocks", test))]
 use crate::rpc::core::MockCoreRPCLike;
 use crate::{config::PlatformConfig, rpc::core::DefaultCoreRPC};
@@ -44,6 +46,7 @@ use dpp::document::transfer::Transferable;
 use dpp::nft::TradeMode;
 use dpp::prelude::{CoreBlockHeight, DataContract, TimestampMillis};
 use dpp::tests::json_document::json_document_to_contract;
+use dpp::util::deserializer::ProtocolVersion;
 use dpp::version::PlatformVersion;
 use drive::util::storage_flags::StorageFlags;
 use tempfile::TempDir;
@@ -51,6 +54,7 @@ use tempfile::TempDir;
 /// A test platform builder.
 pub struct TestPlatformBuilder {
     config: Option<PlatformConfig>,
+    initial_protocol_version: Option<ProtocolVersion>,
     tempdir: TempDir,
 }
 
@@ -74,11 +78,24 @@ impl TestPlatformBuilder {
         self
     }
 
+    /// Start Platform in the given protocol version instead of the initial one
+    pub fn with_initial_protocol_version(mut self, protocol_version: ProtocolVersion) -> Self {
+        self.initial_protocol_version = Some(protocol_version);
+        self
+    }
+
+    /// Start Platform in the latest protocol version
+    pub fn with_latest_protocol_version(self) -> Self {
+        self.with_initial_protocol_version(PlatformVersion::latest().protocol_version)
+    }
+
     /// Create a new temp platform with a mock core rpc
     pub fn build_with_mock_rpc(self) -> TempPlatform<MockCoreRPCLike> {
         let platform = Platform::<MockCoreRPCLike>::open(self.tempdir.path(), self.config)
             .expect("should open Platform successfully");
 
+        set_protocol_version(&platform, self.initial_protocol_version);
+
         TempPlatform {
             platform,
             tempdir: self.tempdir,
@@ -90,6 +107,8 @@ impl TestPlatformBuilder {
         let platform = Platform::<DefaultCoreRPC>::open(self.tempdir.path(), self.config)
             .expect("should open Platform successfully");
 
+        set_protocol_version(&platform, self.initial_protocol_version);
+
         TempPlatform {
             platform,
             tempdir: self.tempdir,
@@ -103,10 +122,31 @@ impl Default for TestPlatformBuilder {
         Self {
             tempdir,
             config: None,
+            initial_protocol_version: None,
         }
     }
 }
 
+/// Replaces the freshly opened platform state with one in the requested protocol version
+fn set_protocol_version<C>(platform: &Platform<C>, protocol_version: Option<ProtocolVersion>) {
+    let Some(protocol_version) = protocol_version else {
+        return;
+    };
+
+    let state = PlatformState::default_with_protocol_versions(
+        protocol_version,
+        protocol_version,
+        &platform.config,
+    )
+    .expect("expected to create platform state");
+
+    PlatformVersion::set_current(
+        PlatformVersion::get(protocol_version).expect("expected a known protocol version"),
+    );
+
+    platform.state.store(Arc::new(state));
+}
+
 impl TempPlatform<MockCoreRPCLike> {
     /// A function which sets initial state structure for Platform.
     pub fn set_initial_state_structure(self) -> Self {
@@ -163,6 +203,8 @@ impl TempPlatform<MockCoreRPCLike> {
     pub fn with_crypto_card_game_nft(self, marketplace: TradeMode) -> (Self, DataContract) {
         let card_game_path = match marketplace {
             TradeMode::DirectPurchase => "tests/supporting_files/contract/crypto-card-game/crypto-card-game-direct-purchase.json",
+            TradeMode::PublicOffer => "tests/supporting_files/contract/crypto-card-game/crypto-card-game-public-offer.json",
+            TradeMode::PrivateOffer => "tests/supporting_files/contract/crypto-card-game/crypto-card-game-private-offer.json",
             _ => panic!("not yet supported")
         };
 
@@ -0,0 +1,118 @@
+use serde_json::{json, Value as JsonValue};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+type RequestHandler = dyn Fn(&str, &JsonValue) -> Result<JsonValue, JsonValue> + Send + Sync;
+
+/// A local HTTP server mocking Tenderdash JSON-RPC
+///
+/// Every request is answered by the handler, with the `result` of the JSON-RPC response if it
+/// returns `Ok` and with its `error` otherwise.
+pub struct MockTenderdashRPC {
+    address: SocketAddr,
+    requests: Arc<Mutex<Vec<(String, JsonValue)>>>,
+}
+
+impl MockTenderdashRPC {
+    /// Starts the server on a random local port
+    pub fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&str, &JsonValue) -> Result<JsonValue, JsonValue> + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("expected to bind mock server");
+        let address = listener.local_addr().expect("expected local address");
+
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let handler: Arc<RequestHandler> = Arc::new(handler);
+
+        let server_requests = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else {
+                    continue;
+                };
+
+                let requests = Arc::clone(&server_requests);
+                let handler = Arc::clone(&handler);
+
+                thread::spawn(move || handle_connection(stream, handler.as_ref(), &requests));
+            }
+        });
+
+        Self { address, requests }
+    }
+
+    /// URL of the server
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Methods and params of the requests received so far
+    pub fn requests(&self) -> Vec<(String, JsonValue)> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn handle_connection(
+    stream: TcpStream,
+    handler: &RequestHandler,
+    requests: &Mutex<Vec<(String, JsonValue)>>,
+) {
+    let mut reader = BufReader::new(stream);
+
+    let mut content_length = 0;
+
+    loop {
+        let mut line = String::new();
+
+        if reader.read_line(&mut line).unwrap_or(0) == 0 {
+            return;
+        }
+
+        let line = line.trim_end();
+
+        if line.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or_default();
+            }
+        }
+    }
+
+    let mut body = vec![0; content_length];
+
+    if reader.read_exact(&mut body).is_err() {
+        return;
+    }
+
+    let request: JsonValue = serde_json::from_slice(&body).unwrap_or_default();
+
+    let method = request["method"].as_str().unwrap_or_default().to_string();
+    let params = request["params"].clone();
+
+    requests
+        .lock()
+        .unwrap()
+        .push((method.clone(), params.clone()));
+
+    let response = match handler(&method, &params) {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }),
+        Err(error) => json!({ "jsonrpc": "2.0", "id": request["id"], "error": error }),
+    }
+    .to_string();
+
+    let mut stream = reader.into_inner();
+
+    let _ = write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.len(),
+        response
+    );
+}
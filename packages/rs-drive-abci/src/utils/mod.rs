@@ -3,4 +3,4 @@ mod spawn;
 
 pub use serialization::from_opt_str_or_number;
 pub use serialization::from_str_or_number;
-pub use spawn::spawn_blocking_task_with_name_if_supported;
+pub use spawn::{spawn_blocking_task_with_name_if_supported, spawn_task_with_name_if_supported};
@@ -1,3 +1,4 @@
+use std::future::Future;
 use std::io;
 use tokio::task::JoinHandle;
 
@@ -22,3 +23,25 @@ where
         Ok(tokio::task::spawn_blocking(function))
     }
 }
+
+/// Spawn a tokio task with name if tokio_unstable flag is set
+pub fn spawn_task_with_name_if_supported<Task>(
+    _sometimes_used_name: &str,
+    task: Task,
+) -> io::Result<JoinHandle<Task::Output>>
+where
+    Task: Future + Send + 'static,
+    Task::Output: Send + 'static,
+{
+    #[cfg(all(tokio_unstable, feature = "console"))]
+    {
+        tokio::task::Builder::new()
+            .name(_sometimes_used_name)
+            .spawn(task)
+    }
+
+    #[cfg(not(all(tokio_unstable, feature = "console")))]
+    {
+        Ok(tokio::task::spawn(task))
+    }
+}
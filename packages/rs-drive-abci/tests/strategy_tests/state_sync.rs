@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod tests {
+    use crate::execution::run_chain_for_strategy;
+    use crate::strategy::NetworkStrategy;
+    use dpp::version::PlatformVersion;
+    use drive_abci::abci::app::FullAbciApplication;
+    use drive_abci::config::{
+        ChainLockConfig, ExecutionConfig, InstantLockConfig, PlatformConfig, PlatformTestConfig,
+        StateSyncConfig, ValidatorSetConfig,
+    };
+    use drive_abci::platform_types::platform_state::v0::PlatformStateV0Methods;
+    use drive_abci::test::helpers::setup::TestPlatformBuilder;
+    use std::collections::VecDeque;
+    use strategy_tests::{IdentityInsertInfo, StartIdentities, Strategy};
+    use tenderdash_abci::proto::abci::{
+        response_apply_snapshot_chunk, response_offer_snapshot, RequestApplySnapshotChunk,
+        RequestListSnapshots, RequestLoadSnapshotChunk, RequestOfferSnapshot,
+    };
+    use tenderdash_abci::Application;
+
+    fn state_sync_config(checkpoints_path: std::path::PathBuf) -> PlatformConfig {
+        PlatformConfig {
+            validator_set: ValidatorSetConfig::default_100_67(),
+            chain_lock: ChainLockConfig::default_100_67(),
+            instant_lock: InstantLockConfig::default_100_67(),
+            execution: ExecutionConfig {
+                verify_sum_trees: true,
+                ..ExecutionConfig::default()
+            },
+            state_sync: StateSyncConfig {
+                snapshots_enabled: true,
+                checkpoints_path,
+                snapshots_frequency: 5,
+                max_num_snapshots: 2,
+            },
+            block_spacing_ms: 3000,
+            testing_configs: PlatformTestConfig::default_minimal_verifications(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn run_state_sync_between_two_nodes() {
+        let strategy = NetworkStrategy {
+            strategy: Strategy {
+                start_contracts: vec![],
+                operations: vec![],
+                start_identities: StartIdentities::default(),
+                identity_inserts: IdentityInsertInfo::default(),
+
+                identity_contract_nonce_gaps: None,
+                signer: None,
+            },
+            total_hpmns: 100,
+            extra_normal_mns: 0,
+            validator_quorum_count: 24,
+            chain_lock_quorum_count: 24,
+            upgrading_info: None,
+            proposer_strategy: Default::default(),
+            rotate_quorums: false,
+            failure_testing: None,
+            query_testing: None,
+            verify_state_transition_results: false,
+            ..Default::default()
+        };
+
+        let source_checkpoints_dir = tempfile::tempdir().expect("expected temp dir");
+        let source_config = state_sync_config(source_checkpoints_dir.path().join("checkpoints"));
+
+        let mut source_platform = TestPlatformBuilder::new()
+            .with_config(source_config.clone())
+            .build_with_mock_rpc();
+
+        let outcome = run_chain_for_strategy(
+            &mut source_platform,
+            17,
+            strategy,
+            source_config.clone(),
+            15,
+            &mut None,
+        );
+
+        let snapshots = outcome
+            .abci_app
+            .list_snapshots(RequestListSnapshots {})
+            .expect("expected to list snapshots")
+            .snapshots;
+
+        // Only the most recent snapshots are kept
+        let heights: Vec<u64> = snapshots.iter().map(|snapshot| snapshot.height).collect();
+        assert_eq!(heights, vec![10, 15]);
+
+        let snapshot = snapshots.last().cloned().expect("expected a snapshot");
+
+        // The syncing node starts from an empty database and doesn't take snapshots itself
+        let target_checkpoints_dir = tempfile::tempdir().expect("expected temp dir");
+        let mut target_config =
+            state_sync_config(target_checkpoints_dir.path().join("checkpoints"));
+        target_config.state_sync.snapshots_enabled = false;
+
+        let target_platform = TestPlatformBuilder::new()
+            .with_config(target_config)
+            .build_with_mock_rpc();
+
+        let target_app = FullAbciApplication::new(&target_platform);
+
+        // A snapshot that doesn't match the app hash of the verified block is rejected
+        let mismatched_offer_response = target_app
+            .offer_snapshot(RequestOfferSnapshot {
+                snapshot: Some(snapshot.clone()),
+                app_hash: vec![0; 32],
+            })
+            .expect("expected to offer snapshot");
+
+        assert_eq!(
+            mismatched_offer_response.result,
+            response_offer_snapshot::Result::Reject as i32
+        );
+
+        let offer_response = target_app
+            .offer_snapshot(RequestOfferSnapshot {
+                snapshot: Some(snapshot.clone()),
+                app_hash: snapshot.hash.clone(),
+            })
+            .expect("expected to offer snapshot");
+
+        assert_eq!(
+            offer_response.result,
+            response_offer_snapshot::Result::Accept as i32
+        );
+
+        // The root chunk is identified by the app hash
+        let mut chunk_queue = VecDeque::from([snapshot.hash.clone()]);
+        let mut applied_chunks = 0;
+
+        while let Some(chunk_id) = chunk_queue.pop_front() {
+            let chunk = outcome
+                .abci_app
+                .load_snapshot_chunk(RequestLoadSnapshotChunk {
+                    height: snapshot.height,
+                    version: snapshot.version,
+                    chunk_id: chunk_id.clone(),
+                })
+                .expect("expected to load snapshot chunk")
+                .chunk;
+
+            let apply_response = target_app
+                .apply_snapshot_chunk(RequestApplySnapshotChunk {
+                    chunk_id,
+                    chunk,
+                    sender: "source".to_string(),
+                })
+                .expect("expected to apply snapshot chunk");
+
+            assert_eq!(
+                apply_response.result,
+                response_apply_snapshot_chunk::Result::Accept as i32
+            );
+
+            applied_chunks += 1;
+            chunk_queue.extend(apply_response.next_chunks);
+        }
+
+        assert!(applied_chunks > 1);
+
+        let platform_version = PlatformVersion::latest();
+
+        let restored_root_hash = target_platform
+            .drive
+            .grove
+            .root_hash(None, &platform_version.drive.grove_version)
+            .unwrap()
+            .expect("expected root hash");
+
+        assert_eq!(restored_root_hash.to_vec(), snapshot.hash);
+
+        let target_state = target_platform.state.load();
+
+        assert_eq!(target_state.last_committed_block_height(), 15);
+        assert_eq!(
+            target_state.last_committed_block_app_hash(),
+            Some(restored_root_hash)
+        );
+
+        // A node that already has state must not accept snapshots
+        let second_offer_response = target_app
+            .offer_snapshot(RequestOfferSnapshot {
+                snapshot: Some(snapshot.clone()),
+                app_hash: snapshot.hash.clone(),
+            })
+            .expect("expected to offer snapshot");
+
+        assert_eq!(
+            second_offer_response.result,
+            response_offer_snapshot::Result::Abort as i32
+        );
+    }
+}
@@ -10,6 +10,7 @@ use dapi_grpc::platform::v0::get_proofs_request::get_proofs_request_v0::vote_sta
 use dapi_grpc::platform::v0::get_proofs_request::get_proofs_request_v0::vote_status_request::RequestType;
 use dpp::asset_lock::reduced_asset_lock_value::AssetLockValueGettersV0;
 use dpp::document::property_names::PRICE;
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteMode;
 use dpp::state_transition::StateTransition;
 use dpp::version::PlatformVersion;
 use drive::drive::identity::key::fetch::IdentityKeysRequest;
@@ -27,6 +28,7 @@ use dpp::voting::votes::Vote;
 use drive::drive::votes::resolved::vote_polls::ResolvedVotePoll;
 use drive::drive::votes::resolved::votes::resolved_resource_vote::accessors::v0::ResolvedResourceVoteGettersV0;
 use drive::drive::votes::resolved::votes::ResolvedVote;
+use drive::state_transition_action::document::documents_batch::document_transition::document_accept_offer_transition_action::DocumentAcceptOfferTransitionActionAccessorsV0;
 use drive::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
 use drive::state_transition_action::document::documents_batch::document_transition::document_create_transition_action::{DocumentCreateTransitionActionAccessorsV0, DocumentFromCreateTransitionAction};
 use drive::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::DocumentPurchaseTransitionActionAccessorsV0;
@@ -209,6 +211,45 @@ pub(crate) fn verify_state_transitions_were_or_were_not_executed(
                         );
                     }
                 }
+                StateTransitionAction::DataContractDeleteAction(data_contract_delete) => {
+                    proofs_request
+                        .contracts
+                        .push(get_proofs_request_v0::ContractRequest {
+                            contract_id: data_contract_delete.data_contract_id().to_vec(),
+                        });
+                    let versioned_request = GetProofsRequest {
+                        version: Some(get_proofs_request::Version::V0(proofs_request)),
+                    };
+                    let result = abci_app
+                        .platform
+                        .query_proofs(versioned_request, &state, platform_version)
+                        .expect("expected to query proofs");
+                    let response = result.into_data().expect("expected queries to be valid");
+
+                    let response_proof = response.proof_owned().expect("expected to get proof");
+
+                    let (root_hash, contract) = Drive::verify_contract(
+                        &response_proof.grovedb_proof,
+                        None,
+                        false,
+                        true,
+                        data_contract_delete.data_contract_id().into_buffer(),
+                        platform_version,
+                    )
+                    .expect("expected to verify contract");
+                    assert_eq!(
+                        &root_hash,
+                        expected_root_hash,
+                        "state last block info {:?}",
+                        platform.state.last_committed_block_info()
+                    );
+                    if *was_executed {
+                        match data_contract_delete.mode() {
+                            DataContractDeleteMode::Archive => assert!(contract.is_some()),
+                            DataContractDeleteMode::Delete => assert!(contract.is_none()),
+                        }
+                    }
+                }
                 StateTransitionAction::DocumentsBatchAction(documents_batch_transition) => {
                     documents_batch_transition
                         .transitions()
@@ -479,6 +520,29 @@ pub(crate) fn verify_state_transitions_were_or_were_not_executed(
                                     }
                                 }
                             }
+                            DocumentTransitionAction::MakeOfferAction(_)
+                            | DocumentTransitionAction::WithdrawOfferAction(_) => {
+                                // offers don't change the document itself
+                            }
+                            DocumentTransitionAction::AcceptOfferAction(accept_offer_action) => {
+                                if *was_executed {
+                                    if let Some(document) = document {
+                                        assert_eq!(
+                                            document.owner_id(),
+                                            accept_offer_action.document().owner_id()
+                                        );
+                                    }
+                                } else {
+                                    //there is the possibility that the state transition was not executed and the state is equal to the previous
+                                    // state, aka there would have been no change anyways, we can discount that for now
+                                    if let Some(document) = document {
+                                        assert_ne!(
+                                            document.owner_id(),
+                                            accept_offer_action.document().owner_id()
+                                        );
+                                    }
+                                }
+                            }
                         }
                     }
                 }
@@ -750,6 +814,83 @@ pub(crate) fn verify_state_transitions_were_or_were_not_executed(
                         );
                     }
                 }
+                StateTransitionAction::IdentityCreditBatchTransferAction(
+                    identity_credit_batch_transfer_action,
+                ) => {
+                    proofs_request
+                        .identities
+                        .push(get_proofs_request_v0::IdentityRequest {
+                            identity_id: identity_credit_batch_transfer_action
+                                .identity_id()
+                                .to_vec(),
+                            request_type: get_proofs_request_v0::identity_request::Type::Balance
+                                .into(),
+                        });
+
+                    for (recipient_id, _) in identity_credit_batch_transfer_action.outputs() {
+                        proofs_request
+                            .identities
+                            .push(get_proofs_request_v0::IdentityRequest {
+                                identity_id: recipient_id.to_vec(),
+                                request_type:
+                                    get_proofs_request_v0::identity_request::Type::Balance.into(),
+                            });
+                    }
+
+                    let versioned_request = GetProofsRequest {
+                        version: Some(get_proofs_request::Version::V0(proofs_request)),
+                    };
+
+                    let result = abci_app
+                        .platform
+                        .query_proofs(versioned_request, &state, platform_version)
+                        .expect("expected to query proofs");
+                    let response = result.into_data().expect("expected queries to be valid");
+
+                    let response_proof = response.proof_owned().expect("proof should be present");
+
+                    let (root_hash_identity, _balance_identity) =
+                        Drive::verify_identity_balance_for_identity_id(
+                            &response_proof.grovedb_proof,
+                            identity_credit_batch_transfer_action
+                                .identity_id()
+                                .into_buffer(),
+                            true,
+                            platform_version,
+                        )
+                        .expect("expected to verify balance identity for credit batch transfer");
+
+                    assert_eq!(
+                        &root_hash_identity,
+                        expected_root_hash,
+                        "state last block info {:?}",
+                        platform.state.last_committed_block_info()
+                    );
+
+                    for (recipient_id, amount) in identity_credit_batch_transfer_action.outputs() {
+                        let (root_hash_recipient, balance_recipient) =
+                            Drive::verify_identity_balance_for_identity_id(
+                                &response_proof.grovedb_proof,
+                                recipient_id.to_buffer(),
+                                true,
+                                platform_version,
+                            )
+                            .expect("expected to verify balance recipient");
+
+                        assert_eq!(
+                            &root_hash_recipient,
+                            expected_root_hash,
+                            "state last block info {:?}",
+                            platform.state.last_committed_block_info()
+                        );
+
+                        if *was_executed {
+                            let balance_recipient = balance_recipient.expect("expected a balance");
+
+                            assert!(balance_recipient >= *amount);
+                        }
+                    }
+                }
                 StateTransitionAction::MasternodeVoteAction(masternode_vote_action) => {
                     let data_contract = match masternode_vote_action.vote_ref() {
                         ResolvedVote::ResolvedResourceVote(resource_vote) => match resource_vote
@@ -783,7 +924,13 @@ pub(crate) fn verify_state_transitions_were_or_were_not_executed(
                                 });
                                 contested_document_resource_vote_poll.contract.as_ref()
                             }
+                            ResolvedVotePoll::GovernanceProposalVotePoll(_) => {
+                                panic!("resource votes can not be cast on governance proposal vote polls")
+                            }
                         },
+                        ResolvedVote::ResolvedGovernanceProposalVote(_) => {
+                            panic!("governance proposal votes are not generated by strategy tests")
+                        }
                     };
 
                     let versioned_request = GetProofsRequest {
@@ -824,6 +971,9 @@ pub(crate) fn verify_state_transitions_were_or_were_not_executed(
                         assert_eq!(&executed_vote, &vote);
                     }
                 }
+                // Strategies don't generate identity recoveries or auction bids
+                StateTransitionAction::IdentityRecoveryAction(_) => {}
+                StateTransitionAction::AuctionBidAction(_) => {}
                 StateTransitionAction::BumpIdentityNonceAction(_) => {}
                 StateTransitionAction::BumpIdentityDataContractNonceAction(_) => {}
                 StateTransitionAction::PartiallyUseAssetLockAction(_) => {}
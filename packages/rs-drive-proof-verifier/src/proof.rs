@@ -10,15 +10,18 @@ use dapi_grpc::platform::v0::get_protocol_version_upgrade_vote_status_request::{
 use dapi_grpc::platform::v0::security_level_map::KeyKindRequestType as GrpcKeyKind;
 use dapi_grpc::platform::v0::{
     get_contested_resource_identity_votes_request, get_data_contract_history_request,
-    get_data_contract_request, get_data_contracts_request, get_epochs_info_request,
-    get_identities_contract_keys_request, get_identity_balance_and_revision_request,
-    get_identity_balance_request, get_identity_by_public_key_hash_request,
-    get_identity_contract_nonce_request, get_identity_keys_request, get_identity_nonce_request,
-    get_identity_request, get_path_elements_request, get_prefunded_specialized_balance_request,
-    GetContestedResourceVotersForIdentityRequest, GetContestedResourceVotersForIdentityResponse,
-    GetPathElementsRequest, GetPathElementsResponse, GetProtocolVersionUpgradeStateRequest,
-    GetProtocolVersionUpgradeStateResponse, GetProtocolVersionUpgradeVoteStatusRequest,
-    GetProtocolVersionUpgradeVoteStatusResponse, Proof, ResponseMetadata,
+    get_data_contract_request, get_data_contracts_request, get_document_history_request,
+    get_document_offers_request, get_epochs_info_request,
+    get_governance_proposal_vote_poll_state_request, get_identities_contract_keys_request,
+    get_identity_balance_and_revision_request, get_identity_balance_request,
+    get_identity_by_public_key_hash_request, get_identity_contract_nonce_request,
+    get_identity_keys_request, get_identity_nonce_request, get_identity_pending_recovery_request,
+    get_identity_request, get_identity_withdrawals_request, get_path_elements_request,
+    get_prefunded_specialized_balance_request, GetContestedResourceVotersForIdentityRequest,
+    GetContestedResourceVotersForIdentityResponse, GetPathElementsRequest, GetPathElementsResponse,
+    GetProtocolVersionUpgradeStateRequest, GetProtocolVersionUpgradeStateResponse,
+    GetProtocolVersionUpgradeVoteStatusRequest, GetProtocolVersionUpgradeVoteStatusResponse, Proof,
+    ResponseMetadata,
 };
 use dapi_grpc::platform::{
     v0::{self as platform, key_request_type, KeyRequestType as GrpcKeyType},
@@ -30,8 +33,12 @@ use dpp::block::extended_epoch_info::ExtendedEpochInfo;
 use dpp::core_subsidy::NetworkCoreSubsidy;
 use dpp::dashcore::hashes::Hash;
 use dpp::dashcore::{Network, ProTxHash};
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dpp::document::{Document, DocumentV0Getters};
 use dpp::identity::identities_contract_keys::IdentitiesContractKeys;
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::identity_public_key::accessors::v1::IdentityPublicKeyGettersV1;
+use dpp::identity::recovery::PendingIdentityRecovery;
 use dpp::identity::Purpose;
 use dpp::platform_value::{self};
 use dpp::prelude::{DataContract, Identifier, Identity};
@@ -39,6 +46,7 @@ use dpp::serialization::PlatformDeserializable;
 use dpp::state_transition::proof_result::StateTransitionProofResult;
 use dpp::state_transition::StateTransition;
 use dpp::version::PlatformVersion;
+use dpp::voting::vote_info_storage::governance_proposal_vote_poll_stored_info::GovernanceProposalVotePollStoredInfo;
 use dpp::voting::votes::Vote;
 use drive::drive::identity::key::fetch::{
     IdentityKeysRequest, KeyKindRequestType, KeyRequestType, PurposeU8, SecurityLevelU8,
@@ -49,7 +57,7 @@ use drive::query::contested_resource_votes_given_by_identity_query::ContestedRes
 use drive::query::vote_poll_contestant_votes_query::ContestedDocumentVotePollVotesDriveQuery;
 use drive::query::vote_poll_vote_state_query::ContestedDocumentVotePollDriveQuery;
 use drive::query::vote_polls_by_document_type_query::VotePollsByDocumentTypeQuery;
-use drive::query::{DriveDocumentQuery, VotePollsByEndDateDriveQuery};
+use drive::query::{DriveDocumentCountQuery, DriveDocumentQuery, VotePollsByEndDateDriveQuery};
 use std::array::TryFromSliceError;
 use std::collections::BTreeMap;
 use std::num::TryFromIntError;
@@ -362,7 +370,7 @@ impl FromProof<platform::GetIdentityKeysRequest> for IdentityPublicKeys {
 
         let mtd = response.metadata().or(Err(Error::EmptyResponseMetadata))?;
 
-        let (request_type, identity_id, limit, offset) =
+        let (request_type, identity_id, limit, offset, valid_at) =
             match request.version.ok_or(Error::EmptyVersion)? {
                 get_identity_keys_request::Version::V0(v0) => {
                     let request_type = v0.request_type;
@@ -373,7 +381,7 @@ impl FromProof<platform::GetIdentityKeysRequest> for IdentityPublicKeys {
                         .into_buffer();
                     let limit = v0.limit.map(|i| i as u16);
                     let offset = v0.offset.map(|i| i as u16);
-                    (request_type, identity_id, limit, offset)
+                    (request_type, identity_id, limit, offset, v0.valid_at)
                 }
             };
 
@@ -425,9 +433,15 @@ impl FromProof<platform::GetIdentityKeysRequest> for IdentityPublicKeys {
             if identity.loaded_public_keys.is_empty() {
                 None
             } else {
+                // Keys that are not usable at the requested time are left out, like Platform does
                 let mut keys = identity
                     .loaded_public_keys
                     .into_iter()
+                    .filter(|(_, key)| {
+                        valid_at.map_or(true, |time_ms| {
+                            !key.is_disabled() && key.is_within_validity_window(time_ms)
+                        })
+                    })
                     .map(|(k, v)| (k, Some(v.clone())))
                     .collect::<IdentityPublicKeys>();
 
@@ -560,6 +574,107 @@ impl FromProof<platform::GetIdentityNonceRequest> for IdentityNonceFetcher {
     }
 }
 
+impl FromProof<platform::GetIdentityPendingRecoveryRequest> for PendingIdentityRecovery {
+    type Request = platform::GetIdentityPendingRecoveryRequest;
+    type Response = platform::GetIdentityPendingRecoveryResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        _network: Network,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata, Proof), Error>
+    where
+        PendingIdentityRecovery: 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        // Parse response to read proof and metadata
+        let proof = response.proof().or(Err(Error::NoProofInResult))?;
+
+        let mtd = response.metadata().or(Err(Error::EmptyResponseMetadata))?;
+
+        let identity_id = match request.version.ok_or(Error::EmptyVersion)? {
+            get_identity_pending_recovery_request::Version::V0(v0) => {
+                Ok::<dpp::identifier::Identifier, Error>(
+                    Identifier::from_bytes(&v0.identity_id).map_err(|e| Error::ProtocolError {
+                        error: e.to_string(),
+                    })?,
+                )
+            }
+        }?;
+
+        // Extract content from proof and verify Drive/GroveDB proofs
+        let (root_hash, maybe_pending_recovery) = Drive::verify_identity_pending_recovery(
+            &proof.grovedb_proof,
+            identity_id.into_buffer(),
+            false,
+            platform_version,
+        )
+        .map_err(|e| Error::DriveError {
+            error: e.to_string(),
+        })?;
+
+        verify_tenderdash_proof(proof, mtd, &root_hash, provider)?;
+
+        Ok((maybe_pending_recovery, mtd.clone(), proof.clone()))
+    }
+}
+
+impl FromProof<platform::GetGovernanceProposalVotePollStateRequest>
+    for GovernanceProposalVotePollStoredInfo
+{
+    type Request = platform::GetGovernanceProposalVotePollStateRequest;
+    type Response = platform::GetGovernanceProposalVotePollStateResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        _network: Network,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata, Proof), Error>
+    where
+        GovernanceProposalVotePollStoredInfo: 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        // Parse response to read proof and metadata
+        let proof = response.proof().or(Err(Error::NoProofInResult))?;
+
+        let mtd = response.metadata().or(Err(Error::EmptyResponseMetadata))?;
+
+        let vote_poll_id = match request.version.ok_or(Error::EmptyVersion)? {
+            get_governance_proposal_vote_poll_state_request::Version::V0(v0) => {
+                Ok::<dpp::identifier::Identifier, Error>(
+                    Identifier::from_bytes(&v0.vote_poll_id).map_err(|e| Error::ProtocolError {
+                        error: e.to_string(),
+                    })?,
+                )
+            }
+        }?;
+
+        // Extract content from proof and verify Drive/GroveDB proofs
+        let (root_hash, maybe_stored_info) =
+            Drive::verify_governance_proposal_vote_poll_stored_info(
+                &proof.grovedb_proof,
+                vote_poll_id.into_buffer(),
+                false,
+                platform_version,
+            )
+            .map_err(|e| Error::DriveError {
+                error: e.to_string(),
+            })?;
+
+        verify_tenderdash_proof(proof, mtd, &root_hash, provider)?;
+
+        Ok((maybe_stored_info, mtd.clone(), proof.clone()))
+    }
+}
+
 impl FromProof<platform::GetIdentityContractNonceRequest> for IdentityContractNonceFetcher {
     type Request = platform::GetIdentityContractNonceRequest;
     type Response = platform::GetIdentityContractNonceResponse;
@@ -1237,6 +1352,288 @@ where
     }
 }
 
+impl<'dq, Q> FromProof<Q> for DocumentsCount
+where
+    Q: TryInto<DriveDocumentCountQuery<'dq>> + Clone + 'dq,
+    Q::Error: std::fmt::Display,
+{
+    type Request = Q;
+    type Response = platform::GetDocumentsCountResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        _network: Network,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata, Proof), Error>
+    where
+        Self: 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        let request: DriveDocumentCountQuery<'dq> =
+            request
+                .clone()
+                .try_into()
+                .map_err(|e: Q::Error| Error::RequestError {
+                    error: e.to_string(),
+                })?;
+
+        // Parse response to read proof and metadata
+        let proof = response.proof().or(Err(Error::NoProofInResult))?;
+
+        let mtd = response.metadata().or(Err(Error::EmptyResponseMetadata))?;
+
+        let (root_hash, total) = request
+            .verify_document_count_proof(&proof.grovedb_proof, platform_version)
+            .map_err(|e| Error::DriveError {
+                error: e.to_string(),
+            })?;
+
+        verify_tenderdash_proof(proof, mtd, &root_hash, provider)?;
+
+        Ok((Some(DocumentsCount(total)), mtd.clone(), proof.clone()))
+    }
+}
+
+impl FromProof<platform::GetDocumentOffersRequest> for DocumentOffers {
+    type Request = platform::GetDocumentOffersRequest;
+    type Response = platform::GetDocumentOffersResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        _network: Network,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata, Proof), Error>
+    where
+        Self: Sized + 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        let (contract_id, document_type_name, document_id, limit, start_after) =
+            match request.version.ok_or(Error::EmptyVersion)? {
+                get_document_offers_request::Version::V0(v0) => {
+                    let contract_id = Identifier::from_vec(v0.data_contract_id).map_err(|e| {
+                        Error::RequestError {
+                            error: e.to_string(),
+                        }
+                    })?;
+                    let document_id =
+                        Identifier::from_vec(v0.document_id).map_err(|e| Error::RequestError {
+                            error: e.to_string(),
+                        })?;
+                    let start_after = v0
+                        .start_after
+                        .map(Identifier::from_vec)
+                        .transpose()
+                        .map_err(|e| Error::RequestError {
+                            error: e.to_string(),
+                        })?;
+                    let limit = v0.limit.map(u16::try_from).transpose().map_err(|e| {
+                        Error::RequestError {
+                            error: e.to_string(),
+                        }
+                    })?;
+                    (
+                        contract_id,
+                        v0.document_type,
+                        document_id,
+                        limit,
+                        start_after,
+                    )
+                }
+            };
+
+        let proof = response.proof().or(Err(Error::NoProofInResult))?;
+
+        let (root_hash, offers) = Drive::verify_document_offers_proof(
+            &proof.grovedb_proof,
+            contract_id,
+            document_type_name.as_str(),
+            document_id,
+            limit,
+            start_after,
+            false,
+            platform_version,
+        )?;
+
+        let mtd = response.metadata().or(Err(Error::EmptyResponseMetadata))?;
+
+        verify_tenderdash_proof(proof, mtd, &root_hash, provider)?;
+
+        Ok((Some(DocumentOffers(offers)), mtd.clone(), proof.clone()))
+    }
+}
+
+impl FromProof<platform::GetDocumentHistoryRequest> for DocumentHistory {
+    type Request = platform::GetDocumentHistoryRequest;
+    type Response = platform::GetDocumentHistoryResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        _network: Network,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata, Proof), Error>
+    where
+        Self: Sized + 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        let (contract_id, document_type_name, document_id, start_at_ms, limit) =
+            match request.version.ok_or(Error::EmptyVersion)? {
+                get_document_history_request::Version::V0(v0) => {
+                    let contract_id = Identifier::from_vec(v0.data_contract_id).map_err(|e| {
+                        Error::RequestError {
+                            error: e.to_string(),
+                        }
+                    })?;
+                    let document_id =
+                        Identifier::from_vec(v0.document_id).map_err(|e| Error::RequestError {
+                            error: e.to_string(),
+                        })?;
+                    let limit = v0.limit.map(u16::try_from).transpose().map_err(|e| {
+                        Error::RequestError {
+                            error: e.to_string(),
+                        }
+                    })?;
+                    (
+                        contract_id,
+                        v0.document_type,
+                        document_id,
+                        v0.start_at_ms,
+                        limit,
+                    )
+                }
+            };
+
+        let contract = provider
+            .get_data_contract(&contract_id)?
+            .ok_or(Error::RequestError {
+                error: format!("data contract {} not found", contract_id),
+            })?;
+
+        let document_type = contract
+            .document_type_for_name(document_type_name.as_str())
+            .map_err(|e| Error::ProtocolError {
+                error: e.to_string(),
+            })?;
+
+        let proof = response.proof().or(Err(Error::NoProofInResult))?;
+
+        let (root_hash, revisions) = Drive::verify_document_history_proof(
+            &proof.grovedb_proof,
+            contract_id,
+            document_type,
+            document_id,
+            start_at_ms,
+            limit,
+            false,
+            platform_version,
+        )?;
+
+        let mtd = response.metadata().or(Err(Error::EmptyResponseMetadata))?;
+
+        verify_tenderdash_proof(proof, mtd, &root_hash, provider)?;
+
+        let history: DocumentHistory = revisions
+            .into_iter()
+            .map(|(date, document)| (date, Some(DocumentRevision(document))))
+            .collect();
+
+        Ok((history.into_option(), mtd.clone(), proof.clone()))
+    }
+}
+
+impl FromProof<platform::GetIdentityWithdrawalsRequest> for IdentityWithdrawals {
+    type Request = platform::GetIdentityWithdrawalsRequest;
+    type Response = platform::GetIdentityWithdrawalsResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        _network: Network,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata, Proof), Error>
+    where
+        Self: Sized + 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        let (identity_id, limit, start_after) =
+            match request.version.ok_or(Error::EmptyVersion)? {
+                get_identity_withdrawals_request::Version::V0(v0) => {
+                    let identity_id =
+                        Identifier::from_vec(v0.identity_id).map_err(|e| Error::RequestError {
+                            error: e.to_string(),
+                        })?;
+                    let start_after = v0
+                        .start_after
+                        .map(Identifier::from_vec)
+                        .transpose()
+                        .map_err(|e| Error::RequestError {
+                            error: e.to_string(),
+                        })?;
+                    let limit = v0.limit.map(u16::try_from).transpose().map_err(|e| {
+                        Error::RequestError {
+                            error: e.to_string(),
+                        }
+                    })?;
+                    (identity_id, limit, start_after)
+                }
+            };
+
+        let withdrawals_contract = provider
+            .get_data_contract(&withdrawals_contract::ID)?
+            .ok_or(Error::RequestError {
+                error: "withdrawals data contract not found".to_string(),
+            })?;
+
+        let withdrawal_document_type = withdrawals_contract
+            .document_type_for_name(withdrawals_contract::v1::document_types::withdrawal::NAME)
+            .map_err(|e| Error::ProtocolError {
+                error: e.to_string(),
+            })?;
+
+        let drive_query = Drive::identity_withdrawal_documents_query(
+            &withdrawals_contract,
+            withdrawal_document_type,
+            identity_id,
+            limit,
+            start_after,
+        );
+
+        let proof = response.proof().or(Err(Error::NoProofInResult))?;
+
+        let (root_hash, documents) =
+            drive_query.verify_proof(&proof.grovedb_proof, platform_version)?;
+
+        let mtd = response.metadata().or(Err(Error::EmptyResponseMetadata))?;
+
+        verify_tenderdash_proof(proof, mtd, &root_hash, provider)?;
+
+        let withdrawals = documents
+            .into_iter()
+            .map(IdentityWithdrawal::try_from)
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok((
+            Some(IdentityWithdrawals(withdrawals)),
+            mtd.clone(),
+            proof.clone(),
+        ))
+    }
+}
+
 impl FromProof<platform::GetIdentitiesContractKeysRequest> for IdentitiesContractKeys {
     type Request = platform::GetIdentitiesContractKeysRequest;
     type Response = platform::GetIdentitiesContractKeysResponse;
@@ -1259,7 +1656,7 @@ impl FromProof<platform::GetIdentitiesContractKeysRequest> for IdentitiesContrac
 
         let mtd = response.metadata().or(Err(Error::EmptyResponseMetadata))?;
 
-        let (identities_ids, contract_id, document_type_name, purposes) =
+        let (identities_ids, contract_id, document_type_name, purposes, contracts_owner_id) =
             match request.version.ok_or(Error::EmptyVersion)? {
                 get_identities_contract_keys_request::Version::V0(v0) => {
                     let GetIdentitiesContractKeysRequestV0 {
@@ -1267,6 +1664,7 @@ impl FromProof<platform::GetIdentitiesContractKeysRequest> for IdentitiesContrac
                         contract_id,
                         document_type_name,
                         purposes,
+                        contracts_owner_id,
                         ..
                     } = v0;
                     let identifiers = identities_ids
@@ -1292,7 +1690,21 @@ impl FromProof<platform::GetIdentitiesContractKeysRequest> for IdentitiesContrac
                             })
                         })
                         .collect::<Result<Vec<Purpose>, Error>>()?;
-                    (identifiers, contract_id, document_type_name, purposes)
+                    let contracts_owner_id = contracts_owner_id
+                        .map(|contracts_owner_id| {
+                            Identifier::from_vec(contracts_owner_id).map(Identifier::into_buffer)
+                        })
+                        .transpose()
+                        .map_err(|e| Error::ProtocolError {
+                            error: e.to_string(),
+                        })?;
+                    (
+                        identifiers,
+                        contract_id,
+                        document_type_name,
+                        purposes,
+                        contracts_owner_id,
+                    )
                 }
             };
 
@@ -1303,6 +1715,7 @@ impl FromProof<platform::GetIdentitiesContractKeysRequest> for IdentitiesContrac
             &contract_id,
             document_type_name,
             purposes,
+            contracts_owner_id,
             false,
             platform_version,
         )
@@ -6,7 +6,11 @@
 //! defined in this module.
 
 use dpp::data_contract::document_type::DocumentType;
+use dpp::document::DocumentV0Getters;
 use dpp::fee::Credits;
+use dpp::identity::core_script::CoreScript;
+use dpp::nft::offer::DocumentOffer;
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
 use dpp::platform_value::Value;
 use dpp::prelude::{IdentityNonce, TimestampMillis};
 use dpp::version::PlatformVersion;
@@ -26,6 +30,8 @@ use dpp::{
 };
 use drive::grovedb::Element;
 use std::collections::{BTreeMap, BTreeSet};
+use withdrawals_contract::v1::document_types::withdrawal;
+use withdrawals_contract::WithdrawalStatus;
 
 use drive::grovedb::query_result_type::Path;
 #[cfg(feature = "mocks")]
@@ -56,6 +62,10 @@ pub type RetrievedObjects<K, O> = BTreeMap<K, Option<O>>;
 ///
 /// Contains a map of data contract revisions to data contracts.
 pub type DataContractHistory = BTreeMap<u64, DataContract>;
+/// History of a document of a document type keeping history.
+///
+/// Contains a map of the times at which document revisions were stored to the revisions.
+pub type DocumentHistory = RetrievedObjects<u64, DocumentRevision>;
 /// Multiple data contracts.
 ///
 /// Mapping between data contract IDs and data contracts.
@@ -116,6 +126,10 @@ impl FromIterator<(Identifier, Option<ContenderWithSerializedDocument>)> for Con
     }
 }
 
+/// Single revision of a document of a document type keeping history.
+#[derive(Debug, Clone, PartialEq, derive_more::From)]
+pub struct DocumentRevision(pub Document);
+
 /// Identifier of a single voter
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Hash, Clone, derive_more::From, Default)]
 #[cfg_attr(
@@ -176,6 +190,109 @@ pub struct KeysInPath {
 )]
 pub struct TotalCreditsInPlatform(pub Credits);
 
+/// Number of documents matching a count query, or the sum of their summed property.
+#[derive(Debug, derive_more::From, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "mocks",
+    derive(Encode, Decode, PlatformSerialize, PlatformDeserialize),
+    platform_serialize(unversioned)
+)]
+pub struct DocumentsCount(pub i64);
+
+/// Open offers made on a document, ordered by bidder id.
+#[derive(Debug, derive_more::From, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "mocks",
+    derive(Encode, Decode, PlatformSerialize, PlatformDeserialize),
+    platform_serialize(unversioned)
+)]
+pub struct DocumentOffers(pub Vec<DocumentOffer>);
+
+/// A withdrawal of credits from an identity to Core, as recorded in the withdrawals
+/// system data contract.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "mocks",
+    derive(Encode, Decode, PlatformSerialize, PlatformDeserialize),
+    platform_serialize(unversioned)
+)]
+pub struct IdentityWithdrawal {
+    /// ID of the withdrawal document
+    pub id: Identifier,
+    /// Current status of the withdrawal
+    #[cfg_attr(feature = "mocks", bincode(with_serde))]
+    pub status: WithdrawalStatus,
+    /// Index of the Core asset unlock transaction, set once the withdrawal is pooled.
+    ///
+    /// Core identifies asset unlocks by this index (see `getassetunlockstatuses`).
+    pub transaction_index: Option<u64>,
+    /// Core height at which the asset unlock transaction was signed
+    pub transaction_sign_height: Option<u32>,
+    /// Withdrawn amount
+    pub amount: Credits,
+    /// Core fee per byte paid for the asset unlock transaction
+    pub core_fee_per_byte: u32,
+    /// Core output script receiving the funds
+    pub output_script: CoreScript,
+    /// Time the withdrawal was requested
+    pub created_at: TimestampMillis,
+    /// Time of the last status change
+    pub updated_at: TimestampMillis,
+}
+
+impl TryFrom<Document> for IdentityWithdrawal {
+    type Error = crate::Error;
+
+    fn try_from(document: Document) -> Result<Self, Self::Error> {
+        let properties = document.properties();
+
+        let map_err = |e: dpp::platform_value::Error| crate::Error::ProtocolError {
+            error: format!("invalid withdrawal document {}: {}", document.id(), e),
+        };
+
+        let status: u8 = properties
+            .get_integer(withdrawal::properties::STATUS)
+            .map_err(map_err)?;
+
+        Ok(Self {
+            id: document.id(),
+            status: WithdrawalStatus::try_from(status).map_err(|e| {
+                crate::Error::ProtocolError {
+                    error: e.to_string(),
+                }
+            })?,
+            transaction_index: properties
+                .get_optional_integer(withdrawal::properties::TRANSACTION_INDEX)
+                .map_err(map_err)?,
+            transaction_sign_height: properties
+                .get_optional_integer(withdrawal::properties::TRANSACTION_SIGN_HEIGHT)
+                .map_err(map_err)?,
+            amount: properties
+                .get_integer(withdrawal::properties::AMOUNT)
+                .map_err(map_err)?,
+            core_fee_per_byte: properties
+                .get_integer(withdrawal::properties::CORE_FEE_PER_BYTE)
+                .map_err(map_err)?,
+            output_script: CoreScript::from_bytes(
+                properties
+                    .get_bytes(withdrawal::properties::OUTPUT_SCRIPT)
+                    .map_err(map_err)?,
+            ),
+            created_at: document.created_at().unwrap_or_default(),
+            updated_at: document.updated_at().unwrap_or_default(),
+        })
+    }
+}
+
+/// Withdrawals of an identity, most recently updated first.
+#[derive(Debug, derive_more::From, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "mocks",
+    derive(Encode, Decode, PlatformSerialize, PlatformDeserialize),
+    platform_serialize(unversioned)
+)]
+pub struct IdentityWithdrawals(pub Vec<IdentityWithdrawal>);
+
 /// A query with no parameters
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(
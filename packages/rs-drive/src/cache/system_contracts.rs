@@ -42,6 +42,16 @@ impl SystemDataContracts {
         })
     }
 
+    /// Replaces the cached DPNS contract with the version used by the given platform version
+    pub fn reload_dpns(&self, platform_version: &PlatformVersion) -> Result<(), Error> {
+        self.dpns.store(Arc::new(load_system_data_contract(
+            SystemDataContract::DPNS,
+            platform_version,
+        )?));
+
+        Ok(())
+    }
+
     /// Returns withdrawals contract
     pub fn load_withdrawals(&self) -> Guard<Arc<DataContract>> {
         self.withdrawals.load()
@@ -62,3 +72,15 @@ impl SystemDataContracts {
         self.masternode_reward_shares.load()
     }
 }
+
+impl Clone for SystemDataContracts {
+    /// Shares the currently loaded system contracts
+    fn clone(&self) -> Self {
+        Self {
+            withdrawals: ArcSwap::new(self.withdrawals.load_full()),
+            dpns: ArcSwap::new(self.dpns.load_full()),
+            dashpay: ArcSwap::new(self.dashpay.load_full()),
+            masternode_reward_shares: ArcSwap::new(self.masternode_reward_shares.load_full()),
+        }
+    }
+}
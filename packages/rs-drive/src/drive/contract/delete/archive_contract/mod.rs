@@ -0,0 +1,65 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::block::block_info::BlockInfo;
+use dpp::identifier::Identifier;
+
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerInformation;
+use std::collections::HashMap;
+
+impl Drive {
+    /// Creates the operations to archive a data contract.
+    ///
+    /// An archived contract stays in state, but its documents can no longer be created or
+    /// modified and the contract can no longer be updated. The archival time is stored under the
+    /// contract root and paid for by the contract owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - The ID of the contract to archive.
+    /// * `owner_id` - The ID of the contract owner, paying for the storage of the archive marker.
+    /// * `block_info` - Information about the block in which the contract is archived.
+    /// * `estimated_costs_only_with_layer_info` - If provided, only the costs are estimated.
+    /// * `platform_version` - A reference to the platform version.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the low-level drive operations, otherwise an `Error` if the
+    /// version is not supported.
+    pub(crate) fn archive_contract_operations(
+        &self,
+        contract_id: Identifier,
+        owner_id: Identifier,
+        block_info: &BlockInfo,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .contract
+            .delete
+            .archive_contract
+        {
+            0 => self.archive_contract_operations_v0(
+                contract_id,
+                owner_id,
+                block_info,
+                estimated_costs_only_with_layer_info,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "archive_contract_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
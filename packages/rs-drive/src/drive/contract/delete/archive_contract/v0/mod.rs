@@ -0,0 +1,58 @@
+use crate::drive::contract::paths::{contract_root_path, CONTRACT_ARCHIVED_AT_KEY};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::object_size_info::PathKeyElementInfo::PathFixedSizeKeyRefElement;
+use crate::util::storage_flags::StorageFlags;
+use dpp::block::block_info::BlockInfo;
+use dpp::identifier::Identifier;
+
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{Element, EstimatedLayerInformation};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Creates the operations to archive a data contract
+    pub(super) fn archive_contract_operations_v0(
+        &self,
+        contract_id: Identifier,
+        owner_id: Identifier,
+        block_info: &BlockInfo,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_levels_up_to_contract(
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+        }
+
+        let storage_flags = Some(StorageFlags::new_single_epoch(
+            block_info.epoch.index,
+            Some(owner_id.to_buffer()),
+        ));
+
+        let archived_at_element = Element::Item(
+            block_info.time_ms.to_be_bytes().to_vec(),
+            StorageFlags::map_to_some_element_flags(storage_flags.as_ref()),
+        );
+
+        self.batch_insert(
+            PathFixedSizeKeyRefElement((
+                contract_root_path(contract_id.as_slice()),
+                &CONTRACT_ARCHIVED_AT_KEY,
+                archived_at_element,
+            )),
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        Ok(drive_operations)
+    }
+}
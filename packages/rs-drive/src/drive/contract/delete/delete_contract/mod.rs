@@ -0,0 +1,64 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::identifier::Identifier;
+
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Creates the operations to delete a data contract together with all its documents.
+    ///
+    /// Every element under the contract root is removed, children before their parent trees,
+    /// and the contract root itself is removed last. Storage is refunded to the identities that
+    /// paid for it, as recorded in the storage flags of the removed elements.
+    ///
+    /// The number of elements under the contract is not bounded by this method, callers must
+    /// make sure the contract has a limited number of documents before calling it.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - The ID of the contract to delete.
+    /// * `estimated_costs_only_with_layer_info` - If provided, only the costs are estimated.
+    /// * `transaction` - Transaction arguments.
+    /// * `platform_version` - A reference to the platform version.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the low-level drive operations, otherwise an `Error` if the
+    /// operation fails or the version is not supported.
+    pub(crate) fn delete_contract_operations(
+        &self,
+        contract_id: Identifier,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .contract
+            .delete
+            .delete_contract
+        {
+            0 => self.delete_contract_operations_v0(
+                contract_id,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "delete_contract_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::drive::contract::paths::{all_contracts_global_root_path, contract_root_path_vec};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::query::QueryItem;
+use crate::util::grove_operations::BatchDeleteApplyType;
+use dpp::identifier::Identifier;
+
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::{Element, EstimatedLayerInformation, PathQuery, TransactionArg};
+use std::collections::HashMap;
+use std::ops::RangeFull;
+
+impl Drive {
+    /// Creates the operations to delete a data contract together with all its documents
+    pub(super) fn delete_contract_operations_v0(
+        &self,
+        contract_id: Identifier,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_levels_up_to_contract(
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+        }
+
+        self.delete_subtree_contents_operations_v0(
+            contract_root_path_vec(contract_id.as_slice()),
+            transaction,
+            &mut drive_operations,
+            platform_version,
+        )?;
+
+        self.batch_delete(
+            all_contracts_global_root_path().as_slice().into(),
+            contract_id.as_slice(),
+            BatchDeleteApplyType::StatefulBatchDelete {
+                is_known_to_be_subtree_with_sum: Some((true, false)),
+            },
+            transaction,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        Ok(drive_operations)
+    }
+
+    /// Deletes every element under `path`, recursing into subtrees so that they are empty by the
+    /// time they are deleted themselves
+    fn delete_subtree_contents_operations_v0(
+        &self,
+        path: Vec<Vec<u8>>,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let path_query =
+            PathQuery::new_single_query_item(path.clone(), QueryItem::RangeFull(RangeFull));
+
+        let key_elements = self
+            .grove_get_raw_path_query(
+                &path_query,
+                transaction,
+                QueryResultType::QueryKeyElementPairResultType,
+                &mut vec![],
+                &platform_version.drive,
+            )?
+            .0
+            .to_key_elements();
+
+        for (key, element) in key_elements {
+            let is_known_to_be_subtree_with_sum = match element {
+                Element::Tree(..) => Some((true, false)),
+                Element::SumTree(..) => Some((true, true)),
+                _ => Some((false, false)),
+            };
+
+            if let Some((true, _)) = is_known_to_be_subtree_with_sum {
+                let mut subtree_path = path.clone();
+                subtree_path.push(key.clone());
+                self.delete_subtree_contents_operations_v0(
+                    subtree_path,
+                    transaction,
+                    drive_operations,
+                    platform_version,
+                )?;
+            }
+
+            self.batch_delete(
+                path.as_slice().into(),
+                key.as_slice(),
+                BatchDeleteApplyType::StatefulBatchDelete {
+                    is_known_to_be_subtree_with_sum,
+                },
+                transaction,
+                drive_operations,
+                &platform_version.drive,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,2 @@
+mod archive_contract;
+mod delete_contract;
@@ -0,0 +1,107 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::{drive::DriveError, Error};
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::DataContract;
+use dpp::fee::fee_result::FeeResult;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Creates the operations to count the documents of a contract, across all its document types.
+    ///
+    /// Counting stops as soon as more than `limit` documents are found, so the returned count is at
+    /// most `limit + 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract` - The contract whose documents are counted.
+    /// * `limit` - The number of documents after which counting stops.
+    /// * `transaction` - Transaction arguments.
+    /// * `drive_operations` - A mutable reference to a vector of low-level drive operations.
+    /// * `platform_version` - A reference to the platform version.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the number of documents counted, otherwise an `Error` if the
+    /// operation fails or the version is not supported.
+    pub fn count_contract_documents_up_to_operations(
+        &self,
+        contract: &DataContract,
+        limit: u16,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<u32, Error> {
+        match platform_version
+            .drive
+            .methods
+            .contract
+            .get
+            .count_contract_documents_up_to
+        {
+            0 => self.count_contract_documents_up_to_operations_v0(
+                contract,
+                limit,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "count_contract_documents_up_to_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Counts the documents of a contract, across all its document types, with the associated fees.
+    ///
+    /// Counting stops as soon as more than `limit` documents are found, so the returned count is at
+    /// most `limit + 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract` - The contract whose documents are counted.
+    /// * `limit` - The number of documents after which counting stops.
+    /// * `block_info` - Information about the block.
+    /// * `transaction` - Transaction arguments.
+    /// * `platform_version` - A reference to the platform version.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the number of documents counted and the `FeeResult`, otherwise
+    /// an `Error` if the operation fails or the version is not supported.
+    pub fn count_contract_documents_up_to_with_fees(
+        &self,
+        contract: &DataContract,
+        limit: u16,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(u32, FeeResult), Error> {
+        match platform_version
+            .drive
+            .methods
+            .contract
+            .get
+            .count_contract_documents_up_to
+        {
+            0 => self.count_contract_documents_up_to_with_fees_v0(
+                contract,
+                limit,
+                block_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "count_contract_documents_up_to_with_fees".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::drive::contract::paths::DataContractPaths;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::query::QueryItem;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::DataContract;
+use dpp::fee::fee_result::FeeResult;
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::{PathQuery, Query, SizedQuery, TransactionArg};
+use std::ops::RangeFull;
+
+impl Drive {
+    /// Creates the operations to count the documents of a contract, stopping after `limit`
+    pub(super) fn count_contract_documents_up_to_operations_v0(
+        &self,
+        contract: &DataContract,
+        limit: u16,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<u32, Error> {
+        let mut count: u32 = 0;
+        for document_type_name in contract.document_types().keys() {
+            // We only need to know if there are more than `limit` documents
+            let remaining = (limit as u32 + 1).saturating_sub(count);
+            if remaining == 0 {
+                break;
+            }
+
+            let path_query = PathQuery::new(
+                contract
+                    .documents_primary_key_path(document_type_name)
+                    .iter()
+                    .map(|segment| segment.to_vec())
+                    .collect(),
+                SizedQuery::new(
+                    Query::new_single_query_item(QueryItem::RangeFull(RangeFull)),
+                    Some(remaining as u16),
+                    None,
+                ),
+            );
+
+            let (elements, _) = self.grove_get_raw_path_query(
+                &path_query,
+                transaction,
+                QueryResultType::QueryKeyElementPairResultType,
+                drive_operations,
+                &platform_version.drive,
+            )?;
+
+            count += elements.len() as u32;
+        }
+
+        Ok(count)
+    }
+
+    /// Counts the documents of a contract, stopping after `limit`, with the associated fees
+    pub(super) fn count_contract_documents_up_to_with_fees_v0(
+        &self,
+        contract: &DataContract,
+        limit: u16,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(u32, FeeResult), Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        let count = self.count_contract_documents_up_to_operations_v0(
+            contract,
+            limit,
+            transaction,
+            &mut drive_operations,
+            platform_version,
+        )?;
+        let fees = Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            self.config.epochs_per_era,
+            platform_version,
+            None,
+        )?;
+        Ok((count, fees))
+    }
+}
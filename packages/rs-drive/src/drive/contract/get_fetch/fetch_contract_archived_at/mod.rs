@@ -0,0 +1,148 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::{drive::DriveError, Error};
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::prelude::TimestampMillis;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the time at which a contract was archived.
+    ///
+    /// This function uses the versioning system to call the appropriate handler based on the provided `DriveVersion`.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - The ID of the contract.
+    /// * `apply` - If `true`, the changes are applied, otherwise only the cost is estimated.
+    /// * `transaction` - Transaction arguments.
+    /// * `platform_version` - A reference to the platform version.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the archival time if the contract is archived, `None` otherwise,
+    /// or an `Error` if the operation fails or the version is not supported.
+    pub fn fetch_contract_archived_at(
+        &self,
+        contract_id: [u8; 32],
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<TimestampMillis>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .contract
+            .get
+            .fetch_contract_archived_at
+        {
+            0 => self.fetch_contract_archived_at_v0(
+                contract_id,
+                apply,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_contract_archived_at".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Creates the operations to get the time at which a contract was archived.
+    ///
+    /// This function uses the versioning system to call the appropriate handler based on the provided `DriveVersion`.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - The ID of the contract.
+    /// * `apply` - If `true`, the changes are applied, otherwise only the cost is estimated.
+    /// * `transaction` - Transaction arguments.
+    /// * `drive_operations` - A mutable reference to a vector of low-level drive operations.
+    /// * `platform_version` - A reference to the platform version.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the archival time if the contract is archived, `None` otherwise,
+    /// or an `Error` if the operation fails or the version is not supported.
+    pub fn fetch_contract_archived_at_operations(
+        &self,
+        contract_id: [u8; 32],
+        apply: bool,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<TimestampMillis>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .contract
+            .get
+            .fetch_contract_archived_at
+        {
+            0 => self.fetch_contract_archived_at_operations_v0(
+                contract_id,
+                apply,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_contract_archived_at_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Fetches the time at which a contract was archived with its associated fees.
+    ///
+    /// This function uses the versioning system to call the appropriate handler based on the provided `DriveVersion`.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - The ID of the contract.
+    /// * `block_info` - Information about the block.
+    /// * `apply` - If `true`, the changes are applied, otherwise only the cost is estimated.
+    /// * `transaction` - Transaction arguments.
+    /// * `platform_version` - A reference to the platform version.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the archival time, if any, and the `FeeResult`, otherwise an
+    /// `Error` if the operation fails or the version is not supported.
+    pub fn fetch_contract_archived_at_with_fees(
+        &self,
+        contract_id: [u8; 32],
+        block_info: &BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(Option<TimestampMillis>, FeeResult), Error> {
+        match platform_version
+            .drive
+            .methods
+            .contract
+            .get
+            .fetch_contract_archived_at
+        {
+            0 => self.fetch_contract_archived_at_with_fees_v0(
+                contract_id,
+                block_info,
+                apply,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_contract_archived_at_with_fees".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
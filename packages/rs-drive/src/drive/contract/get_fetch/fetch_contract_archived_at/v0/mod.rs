@@ -0,0 +1,111 @@
+use crate::drive::contract::paths::{contract_root_path, CONTRACT_ARCHIVED_AT_KEY};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::DirectQueryType;
+use crate::util::grove_operations::QueryTarget::QueryTargetValue;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::prelude::TimestampMillis;
+
+use dpp::version::PlatformVersion;
+use grovedb::Element::Item;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the time at which a contract was archived
+    /// Passing apply as false get the estimated cost instead
+    pub(super) fn fetch_contract_archived_at_v0(
+        &self,
+        contract_id: [u8; 32],
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<TimestampMillis>, Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        self.fetch_contract_archived_at_operations_v0(
+            contract_id,
+            apply,
+            transaction,
+            &mut drive_operations,
+            platform_version,
+        )
+    }
+
+    /// Creates the operations to get the time at which a contract was archived
+    /// This gets operations based on apply flag (stateful vs stateless)
+    pub(super) fn fetch_contract_archived_at_operations_v0(
+        &self,
+        contract_id: [u8; 32],
+        apply: bool,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<TimestampMillis>, Error> {
+        let direct_query_type = if apply {
+            DirectQueryType::StatefulDirectQuery
+        } else {
+            DirectQueryType::StatelessDirectQuery {
+                in_tree_using_sums: false,
+                query_target: QueryTargetValue(8),
+            }
+        };
+        match self.grove_get_raw_optional(
+            contract_root_path(contract_id.as_slice()).as_slice().into(),
+            &CONTRACT_ARCHIVED_AT_KEY,
+            direct_query_type,
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        ) {
+            Ok(Some(Item(encoded_time, _))) => {
+                let archived_at =
+                    TimestampMillis::from_be_bytes(encoded_time.try_into().map_err(|_| {
+                        Error::Drive(DriveError::CorruptedElementType(
+                            "contract archival time was not 8 bytes as expected",
+                        ))
+                    })?);
+
+                Ok(Some(archived_at))
+            }
+
+            Ok(None) => Ok(None),
+
+            Ok(Some(..)) => Err(Error::Drive(DriveError::CorruptedElementType(
+                "contract archival time was present but was not identified as an item",
+            ))),
+
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Fetches the time at which a contract was archived with its associated fees
+    /// Passing apply as false get the estimated cost instead
+    pub(super) fn fetch_contract_archived_at_with_fees_v0(
+        &self,
+        contract_id: [u8; 32],
+        block_info: &BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(Option<TimestampMillis>, FeeResult), Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        let value = self.fetch_contract_archived_at_operations_v0(
+            contract_id,
+            apply,
+            transaction,
+            &mut drive_operations,
+            platform_version,
+        )?;
+        let fees = Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            self.config.epochs_per_era,
+            platform_version,
+            None,
+        )?;
+        Ok((value, fees))
+    }
+}
@@ -1,4 +1,6 @@
+mod count_contract_documents_up_to;
 mod fetch_contract;
+mod fetch_contract_archived_at;
 mod fetch_contract_with_history;
 mod get_cached_contract_with_fetch_info;
 mod get_contract_with_fetch_info;
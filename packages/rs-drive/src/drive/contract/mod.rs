@@ -8,6 +8,8 @@ mod apply;
 #[cfg(feature = "server")]
 mod contract_fetch_info;
 #[cfg(feature = "server")]
+mod delete;
+#[cfg(feature = "server")]
 mod estimation_costs;
 #[cfg(feature = "server")]
 mod get_fetch;
@@ -42,6 +44,7 @@ mod tests {
     use std::option::Option::None;
 
     use crate::drive::Drive;
+    use crate::util::batch::{DataContractOperationType, DriveOperation};
     use crate::util::object_size_info::{DocumentAndContractInfo, DocumentInfo, OwnedDocumentInfo};
     use crate::util::storage_flags::StorageFlags;
     use dpp::data_contract::accessors::v0::DataContractV0Getters;
@@ -497,4 +500,92 @@ mod tests {
             )
             .expect("expected to apply contract successfully");
     }
+
+    #[test]
+    fn test_archive_contract() {
+        let (drive, contract) = setup_dashpay();
+
+        let platform_version = PlatformVersion::latest();
+
+        let block_info = BlockInfo::default_with_time(1000);
+
+        assert_eq!(
+            drive
+                .fetch_contract_archived_at(contract.id().to_buffer(), true, None, platform_version)
+                .expect("expected to fetch archival time"),
+            None
+        );
+
+        drive
+            .apply_drive_operations(
+                vec![DriveOperation::DataContractOperation(
+                    DataContractOperationType::ArchiveContract {
+                        contract_id: contract.id(),
+                        owner_id: contract.owner_id(),
+                    },
+                )],
+                true,
+                &block_info,
+                None,
+                platform_version,
+                None,
+            )
+            .expect("expected to archive contract");
+
+        assert_eq!(
+            drive
+                .fetch_contract_archived_at(contract.id().to_buffer(), true, None, platform_version)
+                .expect("expected to fetch archival time"),
+            Some(1000)
+        );
+
+        // the contract itself is kept
+        let fetched_contract = drive
+            .fetch_contract(
+                contract.id().to_buffer(),
+                None,
+                None,
+                None,
+                platform_version,
+            )
+            .unwrap()
+            .expect("expected to fetch contract");
+
+        assert!(fetched_contract.is_some());
+    }
+
+    #[test]
+    fn test_delete_contract() {
+        let (drive, contract) = setup_reference_contract();
+
+        let platform_version = PlatformVersion::latest();
+
+        drive
+            .apply_drive_operations(
+                vec![DriveOperation::DataContractOperation(
+                    DataContractOperationType::DeleteContract {
+                        contract_id: contract.id(),
+                    },
+                )],
+                true,
+                &BlockInfo::default(),
+                None,
+                platform_version,
+                None,
+            )
+            .expect("expected to delete contract");
+
+        let fetched_contract = drive
+            .fetch_contract(
+                contract.id().to_buffer(),
+                None,
+                None,
+                None,
+                platform_version,
+            )
+            .unwrap()
+            .expect("expected to fetch contract");
+
+        assert!(fetched_contract.is_none());
+    }
 }
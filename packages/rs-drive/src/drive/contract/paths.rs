@@ -133,3 +133,7 @@ pub fn contract_keeping_history_storage_time_reference_path(
         encoded_time,
     ]
 }
+
+/// The key, under a contract's root path, of the item holding the time at which the contract
+/// was archived. Contracts that are not archived don't have it.
+pub const CONTRACT_ARCHIVED_AT_KEY: [u8; 1] = [2];
@@ -328,6 +328,29 @@ impl Drive {
                         index_cache.insert(index_bytes);
                     }
                 }
+
+                // Indexes added to an existing document type are filled for the existing
+                // documents progressively at the end of blocks
+                let added_index_names: Vec<String> = document_type
+                    .indexes()
+                    .keys()
+                    .filter(|index_name| {
+                        !original_document_type.indexes().contains_key(*index_name)
+                    })
+                    .cloned()
+                    .collect();
+
+                if !added_index_names.is_empty() && estimated_costs_only_with_layer_info.is_none() {
+                    self.schedule_index_build_operations(
+                        contract.id().to_buffer(),
+                        type_key,
+                        added_index_names,
+                        &mut None,
+                        transaction,
+                        &mut batch_operations,
+                        platform_version,
+                    )?;
+                }
             } else {
                 // We can just insert this directly because the original document type already exists
                 self.batch_insert_empty_tree(
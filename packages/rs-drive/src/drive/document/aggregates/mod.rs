@@ -0,0 +1,9 @@
+//! Document Aggregates.
+//!
+//! This module implements functions in Drive maintaining the count and sum trees of
+//! countable and summable indexes.
+//!
+
+// Module: update_aggregates_for_document_operations
+// This module contains functionality for updating the aggregates of a document's indexes
+mod update_aggregates_for_document_operations;
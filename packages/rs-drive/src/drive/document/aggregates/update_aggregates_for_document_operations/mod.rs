@@ -0,0 +1,64 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::storage_flags::StorageFlags;
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::data_contract::DataContract;
+use dpp::document::Document;
+
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Gathers the operations to update the count and sum trees of aggregated indexes when a
+    /// document is created (no old document), updated, or deleted (no new document).
+    ///
+    /// When estimating costs, the layer information of the aggregate trees is added to
+    /// `estimated_costs_only_with_layer_info` and the operations don't read the state.
+    pub(crate) fn update_aggregates_for_document_operations(
+        &self,
+        contract: &DataContract,
+        document_type: DocumentTypeRef,
+        old_document: Option<&Document>,
+        new_document: Option<&Document>,
+        storage_flags: Option<&StorageFlags>,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .aggregates
+            .update_aggregates_for_document_operations
+        {
+            0 => self.update_aggregates_for_document_operations_v0(
+                contract,
+                document_type,
+                old_document,
+                new_document,
+                storage_flags,
+                previous_batch_operations,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                batch_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "update_aggregates_for_document_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
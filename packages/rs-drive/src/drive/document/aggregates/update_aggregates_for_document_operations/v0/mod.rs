@@ -0,0 +1,403 @@
+use crate::drive::document::paths::{
+    contract_document_type_path_vec, DOCUMENT_TYPE_AGGREGATES_TREE_KEY, INDEX_COUNT_TREE_KEY,
+    INDEX_SUM_TREE_KEY,
+};
+use crate::drive::Drive;
+use crate::error::fee::FeeError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::{BatchDeleteApplyType, BatchInsertTreeApplyType};
+use crate::util::object_size_info::{DocumentInfo, DocumentInfoV0Methods, PathKeyInfo};
+use crate::util::storage_flags::StorageFlags;
+use crate::util::type_constants::{DEFAULT_HASH_SIZE_U32, DEFAULT_HASH_SIZE_U8, U64_SIZE_U32};
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::{DocumentTypeRef, Index};
+use dpp::data_contract::DataContract;
+use dpp::document::document_methods::DocumentMethodsV0;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::version::drive_versions::DriveVersion;
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerCount::{ApproximateElements, PotentiallyAtMaxElements};
+use grovedb::EstimatedLayerSizes::{AllItems, AllSubtrees};
+use grovedb::EstimatedSumTrees::{AllSumTrees, NoSumTrees};
+use grovedb::{Element, EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+/// The index values of a document, and the value it adds to an aggregate
+type AggregateEntry = (Vec<Vec<u8>>, i64);
+
+impl Drive {
+    /// Gathers the operations to update the count and sum trees of aggregated indexes.
+    ///
+    /// Each aggregated index mirrors its index levels with sum trees, so the total at any
+    /// level is the aggregate for the index values leading to it:
+    /// `[type path, 2, index name, 0 (count) or 1 (sum), value 1, ..., value n, document id]`
+    #[inline(always)]
+    pub(super) fn update_aggregates_for_document_operations_v0(
+        &self,
+        contract: &DataContract,
+        document_type: DocumentTypeRef,
+        old_document: Option<&Document>,
+        new_document: Option<&Document>,
+        storage_flags: Option<&StorageFlags>,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let Some(document_id) = new_document.or(old_document).map(|document| document.id()) else {
+            return Ok(());
+        };
+
+        if let (Some(estimated_costs_only_with_layer_info), Some(new_document)) =
+            (estimated_costs_only_with_layer_info.as_mut(), new_document)
+        {
+            Self::add_estimation_costs_for_document_aggregates(
+                contract,
+                document_type,
+                new_document,
+                storage_flags,
+                estimated_costs_only_with_layer_info,
+                platform_version,
+            )?;
+        }
+
+        for index in document_type
+            .indexes()
+            .values()
+            .filter(|index| index.is_aggregated())
+        {
+            let old_values = old_document
+                .map(|document| {
+                    Self::aggregated_index_values(document, document_type, index, platform_version)
+                })
+                .transpose()?;
+            let new_values = new_document
+                .map(|document| {
+                    Self::aggregated_index_values(document, document_type, index, platform_version)
+                })
+                .transpose()?;
+
+            if index.countable {
+                self.update_index_aggregate_operations(
+                    contract,
+                    document_type,
+                    index,
+                    INDEX_COUNT_TREE_KEY,
+                    document_id.as_slice(),
+                    old_values.clone().map(|values| (values, 1)),
+                    new_values.clone().map(|values| (values, 1)),
+                    storage_flags,
+                    previous_batch_operations,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    batch_operations,
+                    &platform_version.drive,
+                )?;
+            }
+
+            if let Some(summable) = index.summable.as_ref() {
+                // Documents without the summed property don't add to the sum
+                let old_entry = match (old_values, old_document) {
+                    (Some(values), Some(document)) => document
+                        .properties()
+                        .get(summable)
+                        .filter(|value| !value.is_null())
+                        .map(|value| value.to_integer::<i64>())
+                        .transpose()?
+                        .map(|summed| (values, summed)),
+                    _ => None,
+                };
+                let new_entry = match (new_values, new_document) {
+                    (Some(values), Some(document)) => document
+                        .properties()
+                        .get(summable)
+                        .filter(|value| !value.is_null())
+                        .map(|value| value.to_integer::<i64>())
+                        .transpose()?
+                        .map(|summed| (values, summed)),
+                    _ => None,
+                };
+
+                self.update_index_aggregate_operations(
+                    contract,
+                    document_type,
+                    index,
+                    INDEX_SUM_TREE_KEY,
+                    document_id.as_slice(),
+                    old_entry,
+                    new_entry,
+                    storage_flags,
+                    previous_batch_operations,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    batch_operations,
+                    &platform_version.drive,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The serialized values of the index properties of a document, empty for null values
+    fn aggregated_index_values(
+        document: &Document,
+        document_type: DocumentTypeRef,
+        index: &Index,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<Vec<u8>>, Error> {
+        index
+            .properties
+            .iter()
+            .map(|property| {
+                Ok(document
+                    .get_raw_for_document_type(
+                        &property.name,
+                        document_type,
+                        None,
+                        platform_version,
+                    )?
+                    .unwrap_or_default())
+            })
+            .collect()
+    }
+
+    /// Adds the estimated layer information of the count and sum trees a new document is
+    /// aggregated in
+    fn add_estimation_costs_for_document_aggregates(
+        contract: &DataContract,
+        document_type: DocumentTypeRef,
+        document: &Document,
+        storage_flags: Option<&StorageFlags>,
+        estimated_costs_only_with_layer_info: &mut HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let flags_len = storage_flags.map(|s| s.serialized_size());
+        let document_info = DocumentInfo::DocumentRefInfo((document, storage_flags));
+
+        let aggregated_indexes: Vec<&Index> = document_type
+            .indexes()
+            .values()
+            .filter(|index| index.is_aggregated())
+            .collect();
+
+        let mut aggregates_path = contract_document_type_path_vec(
+            contract.id_ref().as_bytes(),
+            document_type.name().as_str(),
+        );
+        aggregates_path.push(vec![DOCUMENT_TYPE_AGGREGATES_TREE_KEY]);
+
+        let index_name_max_size = aggregated_indexes
+            .iter()
+            .map(|index| index.name.len())
+            .max()
+            .unwrap_or_default()
+            .min(u8::MAX as usize) as u8;
+
+        // The aggregates tree holds a tree for each aggregated index
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_owned_path(aggregates_path.clone()),
+            EstimatedLayerInformation {
+                is_sum_tree: false,
+                estimated_layer_count: ApproximateElements(aggregated_indexes.len() as u32),
+                estimated_layer_sizes: AllSubtrees(index_name_max_size, NoSumTrees, flags_len),
+            },
+        );
+
+        for index in aggregated_indexes {
+            let mut index_path = aggregates_path.clone();
+            index_path.push(index.name.as_bytes().to_vec());
+
+            let aggregate_keys: Vec<u8> = [
+                index.countable.then_some(INDEX_COUNT_TREE_KEY),
+                index.summable.as_ref().map(|_| INDEX_SUM_TREE_KEY),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+
+            // The index tree holds its count and sum trees
+            estimated_costs_only_with_layer_info.insert(
+                KeyInfoPath::from_known_owned_path(index_path.clone()),
+                EstimatedLayerInformation {
+                    is_sum_tree: false,
+                    estimated_layer_count: ApproximateElements(aggregate_keys.len() as u32),
+                    estimated_layer_sizes: AllSubtrees(1, AllSumTrees, flags_len),
+                },
+            );
+
+            let values =
+                Self::aggregated_index_values(document, document_type, index, platform_version)?;
+
+            for aggregate_key in aggregate_keys {
+                let mut path = index_path.clone();
+                path.push(vec![aggregate_key]);
+
+                for (property, value) in index.properties.iter().zip(values.iter()) {
+                    let estimated_size = document_info
+                        .get_estimated_size_for_document_type(&property.name, document_type)?;
+
+                    if estimated_size > u8::MAX as u16 {
+                        return Err(Error::Fee(FeeError::Overflow(
+                            "document field is too big for being an aggregated index",
+                        )));
+                    }
+
+                    estimated_costs_only_with_layer_info.insert(
+                        KeyInfoPath::from_known_owned_path(path.clone()),
+                        EstimatedLayerInformation {
+                            is_sum_tree: true,
+                            estimated_layer_count: PotentiallyAtMaxElements,
+                            estimated_layer_sizes: AllSubtrees(
+                                estimated_size as u8,
+                                AllSumTrees,
+                                flags_len,
+                            ),
+                        },
+                    );
+
+                    path.push(value.clone());
+                }
+
+                // The last level holds a sum item per document
+                estimated_costs_only_with_layer_info.insert(
+                    KeyInfoPath::from_known_owned_path(path),
+                    EstimatedLayerInformation {
+                        is_sum_tree: true,
+                        estimated_layer_count: PotentiallyAtMaxElements,
+                        estimated_layer_sizes: AllItems(
+                            DEFAULT_HASH_SIZE_U8,
+                            U64_SIZE_U32,
+                            flags_len,
+                        ),
+                    },
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Moves the sum item of a document from the old index values to the new ones, creating
+    /// missing sum trees along the way
+    #[allow(clippy::too_many_arguments)]
+    fn update_index_aggregate_operations(
+        &self,
+        contract: &DataContract,
+        document_type: DocumentTypeRef,
+        index: &Index,
+        aggregate_key: u8,
+        document_id: &[u8],
+        old_entry: Option<AggregateEntry>,
+        new_entry: Option<AggregateEntry>,
+        storage_flags: Option<&StorageFlags>,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        drive_version: &DriveVersion,
+    ) -> Result<(), Error> {
+        if old_entry == new_entry {
+            return Ok(());
+        }
+
+        let estimated_costs_only = estimated_costs_only_with_layer_info.is_some();
+
+        let type_path = contract_document_type_path_vec(
+            contract.id_ref().as_bytes(),
+            document_type.name().as_str(),
+        );
+
+        if let Some((old_values, _)) = &old_entry {
+            // A sum item at the same place is replaced by the insertion below
+            let moved = new_entry
+                .as_ref()
+                .map_or(true, |(new_values, _)| new_values != old_values);
+
+            if moved {
+                let mut path = type_path.clone();
+                path.push(vec![DOCUMENT_TYPE_AGGREGATES_TREE_KEY]);
+                path.push(index.name.as_bytes().to_vec());
+                path.push(vec![aggregate_key]);
+                path.extend(old_values.iter().cloned());
+
+                self.batch_delete(
+                    path.as_slice().into(),
+                    document_id,
+                    if estimated_costs_only {
+                        BatchDeleteApplyType::StatelessBatchDelete {
+                            is_sum_tree: true,
+                            estimated_key_size: DEFAULT_HASH_SIZE_U32,
+                            estimated_value_size: U64_SIZE_U32,
+                        }
+                    } else {
+                        BatchDeleteApplyType::StatefulBatchDelete {
+                            is_known_to_be_subtree_with_sum: Some((false, false)),
+                        }
+                    },
+                    transaction,
+                    batch_operations,
+                    drive_version,
+                )?;
+            }
+        }
+
+        if let Some((new_values, summed)) = new_entry {
+            let tree_keys = [
+                (vec![DOCUMENT_TYPE_AGGREGATES_TREE_KEY], false),
+                (index.name.as_bytes().to_vec(), false),
+                (vec![aggregate_key], true),
+            ]
+            .into_iter()
+            .chain(new_values.into_iter().map(|value| (value, true)));
+
+            let mut path = type_path;
+            let mut in_tree_using_sums = false;
+            for (key, use_sum_tree) in tree_keys {
+                let apply_type = if estimated_costs_only {
+                    BatchInsertTreeApplyType::StatelessBatchInsertTree {
+                        in_tree_using_sums,
+                        is_sum_tree: use_sum_tree,
+                        flags_len: storage_flags
+                            .map(|s| s.serialized_size())
+                            .unwrap_or_default(),
+                    }
+                } else {
+                    BatchInsertTreeApplyType::StatefulBatchInsertTree
+                };
+
+                self.batch_insert_empty_tree_if_not_exists(
+                    PathKeyInfo::<0>::PathKey((path.clone(), key.clone())),
+                    use_sum_tree,
+                    storage_flags,
+                    apply_type,
+                    transaction,
+                    previous_batch_operations,
+                    batch_operations,
+                    drive_version,
+                )?;
+                path.push(key);
+                in_tree_using_sums = use_sum_tree;
+            }
+
+            batch_operations.push(LowLevelDriveOperation::insert_for_known_path_key_element(
+                path,
+                document_id.to_vec(),
+                Element::new_sum_item_with_flags(
+                    summed,
+                    storage_flags.and_then(StorageFlags::to_some_element_flags),
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -18,7 +18,9 @@ use dpp::document::Document;
 use crate::drive::Drive;
 use crate::util::grove_operations::DirectQueryType;
 use crate::util::grove_operations::QueryTarget::QueryTargetValue;
-use crate::util::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+use crate::util::object_size_info::{
+    DocumentAndContractInfo, DocumentInfoV0Methods, OwnedDocumentInfo,
+};
 
 use crate::error::drive::DriveError;
 
@@ -134,13 +136,30 @@ impl Drive {
             platform_version,
         )?;
 
+        // Indexes being built are only maintained for documents they were already filled for
+        let document_type_without_unbuilt_indexes =
+            if estimated_costs_only_with_layer_info.is_none() {
+                self.document_type_without_unbuilt_indexes(
+                    contract.id_ref().as_bytes(),
+                    document_type,
+                    document_id.as_bytes(),
+                    transaction,
+                    &mut batch_operations,
+                    platform_version,
+                )?
+            } else {
+                None
+            };
+
         let document_and_contract_info = DocumentAndContractInfo {
             owned_document_info: OwnedDocumentInfo {
                 document_info,
                 owner_id: None,
             },
             contract,
-            document_type,
+            document_type: document_type_without_unbuilt_indexes
+                .as_ref()
+                .map_or(document_type, |document_type| document_type.as_ref()),
         };
 
         self.remove_indices_for_top_index_level_for_contract_operations(
@@ -151,6 +170,26 @@ impl Drive {
             &mut batch_operations,
             platform_version,
         )?;
+
+        if let Some(document) = document_and_contract_info
+            .owned_document_info
+            .document_info
+            .get_borrowed_document()
+        {
+            self.update_aggregates_for_document_operations(
+                contract,
+                document_type,
+                Some(document),
+                None,
+                None,
+                &mut None,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                &mut batch_operations,
+                platform_version,
+            )?;
+        }
+
         Ok(batch_operations)
     }
 }
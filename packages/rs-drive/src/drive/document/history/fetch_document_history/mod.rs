@@ -0,0 +1,56 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::document::Document;
+use dpp::identifier::Identifier;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// Fetches the revisions of a document, keyed by the time at which they were stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `document_type` - The document type of the document, it must keep history.
+    /// * `start_at_ms` - The time from which revisions should be returned, included.
+    /// * `limit` - The maximum number of revisions to return.
+    #[allow(clippy::too_many_arguments)]
+    pub fn fetch_document_history(
+        &self,
+        contract_id: Identifier,
+        document_type: DocumentTypeRef,
+        document_id: Identifier,
+        start_at_ms: u64,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<u64, Document>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .history
+            .fetch_document_history
+        {
+            0 => self.fetch_document_history_v0(
+                contract_id,
+                document_type,
+                document_id,
+                start_at_ms,
+                limit,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_document_history".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
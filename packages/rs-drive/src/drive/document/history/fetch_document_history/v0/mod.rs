@@ -0,0 +1,194 @@
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::{DocumentPropertyType, DocumentTypeRef};
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::document::Document;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::QueryResultElement;
+use grovedb::query_result_type::QueryResultType::QueryKeyElementPairResultType;
+use grovedb::Element::Item;
+use grovedb::TransactionArg;
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// Fetches the revisions of a document, keyed by the time at which they were stored.
+    #[allow(clippy::too_many_arguments)]
+    #[inline(always)]
+    pub(super) fn fetch_document_history_v0(
+        &self,
+        contract_id: Identifier,
+        document_type: DocumentTypeRef,
+        document_id: Identifier,
+        start_at_ms: u64,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<u64, Document>, Error> {
+        let path_query = Self::document_history_path_query(
+            contract_id.to_buffer(),
+            document_type.name(),
+            document_id.to_buffer(),
+            start_at_ms,
+            limit,
+        );
+
+        let results = match self.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryKeyElementPairResultType,
+            &mut vec![],
+            &platform_version.drive,
+        ) {
+            Ok((results, _)) => results.elements,
+            // The document doesn't exist
+            Err(Error::GroveDB(grovedb::Error::PathKeyNotFound(_)))
+            | Err(Error::GroveDB(grovedb::Error::PathNotFound(_)))
+            | Err(Error::GroveDB(grovedb::Error::PathParentLayerNotFound(_))) => vec![],
+            Err(e) => return Err(e),
+        };
+
+        results
+            .into_iter()
+            .map(|result| match result {
+                QueryResultElement::KeyElementPairResultItem((
+                    key,
+                    Item(serialized_document, _),
+                )) => {
+                    let time = DocumentPropertyType::decode_date_timestamp(&key).ok_or(
+                        Error::Drive(DriveError::CorruptedDocumentPath(
+                            "document revision key is not a valid time",
+                        )),
+                    )?;
+
+                    let document = Document::from_bytes(
+                        &serialized_document,
+                        document_type,
+                        platform_version,
+                    )?;
+
+                    Ok((time, document))
+                }
+                _ => Err(Error::Drive(DriveError::CorruptedDocumentNotItem(
+                    "document revision was present but was not identified as an item",
+                ))),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::util::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use crate::util::test_helpers::setup::setup_drive_with_initial_state_structure;
+    use crate::util::test_helpers::setup_contract;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+    use dpp::document::document_methods::DocumentMethodsV0;
+    use dpp::document::DocumentV0Getters;
+    use dpp::version::PlatformVersion;
+
+    #[test]
+    fn should_fetch_document_revisions_oldest_first() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let platform_version = PlatformVersion::latest();
+
+        let contract = setup_contract(
+            &drive,
+            "tests/supporting_files/contract/family/family-contract-with-history.json",
+            None,
+            None,
+        );
+
+        let document_type = contract
+            .document_type_for_name("person")
+            .expect("expected to get document type");
+
+        let mut document = document_type
+            .random_document(Some(5), platform_version)
+            .expect("expected a random document");
+
+        let initial_revision = document.revision().expect("expected a revision");
+
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((&document, None)),
+                        owner_id: None,
+                    },
+                    contract: &contract,
+                    document_type,
+                },
+                false,
+                BlockInfo::default_with_time(1_000),
+                true,
+                None,
+                platform_version,
+                None,
+            )
+            .expect("expected to insert document");
+
+        for time_ms in [2_000, 3_000] {
+            document.increment_revision().expect("expected a revision");
+
+            drive
+                .update_document_for_contract(
+                    &document,
+                    &contract,
+                    document_type,
+                    None,
+                    BlockInfo::default_with_time(time_ms),
+                    true,
+                    None,
+                    None,
+                    platform_version,
+                    None,
+                )
+                .expect("expected to update document");
+        }
+
+        let history = drive
+            .fetch_document_history(
+                contract.id(),
+                document_type,
+                document.id(),
+                0,
+                None,
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch document history");
+
+        assert_eq!(
+            history
+                .iter()
+                .map(|(time, document)| (*time, document.revision()))
+                .collect::<Vec<_>>(),
+            vec![
+                (1_000, Some(initial_revision)),
+                (2_000, Some(initial_revision + 1)),
+                (3_000, Some(initial_revision + 2))
+            ]
+        );
+
+        let history = drive
+            .fetch_document_history(
+                contract.id(),
+                document_type,
+                document.id(),
+                2_000,
+                Some(1),
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch document history");
+
+        assert_eq!(history.keys().copied().collect::<Vec<_>>(), vec![2_000]);
+    }
+}
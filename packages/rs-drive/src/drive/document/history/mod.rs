@@ -0,0 +1,45 @@
+//! Document History.
+//!
+//! This module implements functions in Drive reading the revisions of documents of
+//! document types keeping history.
+//!
+//! Every revision is stored under the document id, keyed by the time of the block in which
+//! it was stored, next to a reference to the latest revision:
+//! `[primary key path, document id, encoded time] -> serialized document`
+//!
+
+#[cfg(feature = "server")]
+mod fetch_document_history;
+#[cfg(feature = "server")]
+mod prove_document_history;
+
+use crate::drive::document::paths::contract_documents_keeping_history_primary_key_path_for_document_id_vec;
+use crate::drive::Drive;
+use dpp::data_contract::document_type::DocumentPropertyType;
+use grovedb::{PathQuery, Query, SizedQuery};
+
+impl Drive {
+    /// The path query for the revisions of a document, oldest first.
+    ///
+    /// The reference to the latest revision is stored under a single byte key, so it is never
+    /// part of the queried range of encoded times.
+    pub fn document_history_path_query(
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        document_id: [u8; 32],
+        start_at_ms: u64,
+        limit: Option<u16>,
+    ) -> PathQuery {
+        let mut query = Query::new();
+        query.insert_range_from(DocumentPropertyType::encode_date_timestamp(start_at_ms)..);
+
+        PathQuery::new(
+            contract_documents_keeping_history_primary_key_path_for_document_id_vec(
+                &contract_id,
+                document_type_name,
+                &document_id,
+            ),
+            SizedQuery::new(query, limit, None),
+        )
+    }
+}
@@ -0,0 +1,52 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::identifier::Identifier;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves the revisions of a document, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_at_ms` - The time from which revisions should be proved, included.
+    /// * `limit` - The maximum number of revisions to prove.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove_document_history(
+        &self,
+        contract_id: Identifier,
+        document_type_name: &str,
+        document_id: Identifier,
+        start_at_ms: u64,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .history
+            .prove_document_history
+        {
+            0 => self.prove_document_history_v0(
+                contract_id,
+                document_type_name,
+                document_id,
+                start_at_ms,
+                limit,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "prove_document_history".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::drive::Drive;
+use crate::error::Error;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves the revisions of a document, oldest first.
+    #[allow(clippy::too_many_arguments)]
+    #[inline(always)]
+    pub(super) fn prove_document_history_v0(
+        &self,
+        contract_id: Identifier,
+        document_type_name: &str,
+        document_id: Identifier,
+        start_at_ms: u64,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let path_query = Self::document_history_path_query(
+            contract_id.to_buffer(),
+            document_type_name,
+            document_id.to_buffer(),
+            start_at_ms,
+            limit,
+        );
+
+        self.grove_get_proved_path_query(
+            &path_query,
+            transaction,
+            &mut vec![],
+            &platform_version.drive,
+        )
+    }
+}
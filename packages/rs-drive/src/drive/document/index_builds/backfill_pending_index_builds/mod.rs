@@ -0,0 +1,36 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fills the indexes being built for up to `max_documents` existing documents, and marks
+    /// the builds that are done as complete. Returns the number of documents that were indexed.
+    pub fn backfill_pending_index_builds(
+        &self,
+        max_documents: u16,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<u16, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .index_builds
+            .backfill_pending_index_builds
+        {
+            0 => {
+                self.backfill_pending_index_builds_v0(max_documents, transaction, platform_version)
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "backfill_pending_index_builds".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
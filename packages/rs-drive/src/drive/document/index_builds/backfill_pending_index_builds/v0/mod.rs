@@ -0,0 +1,215 @@
+use crate::drive::document::index_builds::{pending_index_builds_path_vec, PendingIndexBuild};
+use crate::drive::document::paths::contract_documents_primary_key_path;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::BatchDeleteApplyType;
+use crate::util::object_size_info::{DocumentAndContractInfo, DocumentInfo, OwnedDocumentInfo};
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::DataContract;
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::version::PlatformVersion;
+use grovedb::{Element, PathQuery, Query, SizedQuery, TransactionArg};
+
+impl Drive {
+    /// Fills the indexes being built for up to `max_documents` existing documents.
+    #[inline(always)]
+    pub(super) fn backfill_pending_index_builds_v0(
+        &self,
+        max_documents: u16,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<u16, Error> {
+        let mut remaining = max_documents;
+
+        // Every build processes at least one document or completes
+        for pending_index_build in
+            self.fetch_pending_index_builds(Some(max_documents), transaction, platform_version)?
+        {
+            if remaining == 0 {
+                break;
+            }
+
+            let contract_fetch_info = self.get_contract_with_fetch_info(
+                pending_index_build.contract_id,
+                false,
+                transaction,
+                platform_version,
+            )?;
+
+            let backfilled = match &contract_fetch_info {
+                Some(contract_fetch_info) => self.backfill_pending_index_build_v0(
+                    &contract_fetch_info.contract,
+                    pending_index_build,
+                    remaining,
+                    transaction,
+                    platform_version,
+                )?,
+                // The contract was deleted since
+                None => {
+                    self.complete_pending_index_build_v0(
+                        &pending_index_build,
+                        transaction,
+                        platform_version,
+                    )?;
+                    0
+                }
+            };
+
+            remaining -= backfilled;
+        }
+
+        Ok(max_documents - remaining)
+    }
+
+    /// Fills the indexes of a pending build for up to `limit` documents, in ascending order
+    /// of document ids.
+    fn backfill_pending_index_build_v0(
+        &self,
+        contract: &DataContract,
+        mut pending_index_build: PendingIndexBuild,
+        limit: u16,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<u16, Error> {
+        let Some(document_type) =
+            contract.document_type_optional_for_name(&pending_index_build.document_type_name)
+        else {
+            self.complete_pending_index_build_v0(
+                &pending_index_build,
+                transaction,
+                platform_version,
+            )?;
+            return Ok(0);
+        };
+
+        // We only add the references of the indexes being built, the others already exist
+        let building_document_type = document_type.with_filtered_indexes(
+            |index| pending_index_build.is_building(index.name.as_str()),
+            platform_version,
+        )?;
+
+        let mut query = Query::new();
+        match pending_index_build.last_backfilled_document_id {
+            Some(last_backfilled_document_id) => {
+                query.insert_range_after(last_backfilled_document_id.to_vec()..)
+            }
+            None => query.insert_all(),
+        }
+        if document_type.documents_keep_history() {
+            // the latest version of the document
+            query.set_subquery_key(vec![0]);
+        }
+
+        let path_query = PathQuery::new(
+            contract_documents_primary_key_path(
+                contract.id_ref().as_bytes(),
+                document_type.name().as_str(),
+            )
+            .iter()
+            .map(|segment| segment.to_vec())
+            .collect(),
+            SizedQuery::new(query, Some(limit), None),
+        );
+
+        let (serialized_documents, _) = self.grove_get_path_query_serialized_results(
+            &path_query,
+            transaction,
+            &mut vec![],
+            &platform_version.drive,
+        )?;
+
+        let documents = serialized_documents
+            .iter()
+            .map(|serialized_document| {
+                Document::from_bytes(serialized_document, document_type, platform_version)
+            })
+            .collect::<Result<Vec<Document>, _>>()?;
+
+        for document in &documents {
+            // Documents sharing index values share index trees, so each document is applied
+            // on its own for the trees to be only inserted once
+            let mut batch_operations: Vec<LowLevelDriveOperation> = vec![];
+
+            self.add_indices_for_top_index_level_for_contract_operations(
+                &DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentInfo::DocumentRefInfo((document, None)),
+                        owner_id: None,
+                    },
+                    contract,
+                    document_type: building_document_type.as_ref(),
+                },
+                &mut None,
+                &mut None,
+                transaction,
+                &mut batch_operations,
+                platform_version,
+            )?;
+
+            self.apply_batch_low_level_drive_operations(
+                None,
+                transaction,
+                batch_operations,
+                &mut vec![],
+                &platform_version.drive,
+            )?;
+        }
+
+        if documents.len() < limit as usize {
+            // All existing documents are indexed, the indexes can now be queried
+            self.complete_pending_index_build_v0(
+                &pending_index_build,
+                transaction,
+                platform_version,
+            )?;
+        } else if let Some(last_document) = documents.last() {
+            pending_index_build.last_backfilled_document_id = Some(last_document.id().to_buffer());
+
+            self.apply_batch_low_level_drive_operations(
+                None,
+                transaction,
+                vec![LowLevelDriveOperation::insert_for_known_path_key_element(
+                    pending_index_builds_path_vec(),
+                    pending_index_build.key(),
+                    Element::new_item(pending_index_build.serialize()?),
+                )],
+                &mut vec![],
+                &platform_version.drive,
+            )?;
+        }
+
+        Ok(documents.len() as u16)
+    }
+
+    /// Removes a pending index build
+    fn complete_pending_index_build_v0(
+        &self,
+        pending_index_build: &PendingIndexBuild,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let mut batch_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        self.batch_delete(
+            pending_index_builds_path_vec().as_slice().into(),
+            pending_index_build.key().as_slice(),
+            BatchDeleteApplyType::StatefulBatchDelete {
+                is_known_to_be_subtree_with_sum: Some((false, false)),
+            },
+            transaction,
+            &mut batch_operations,
+            &platform_version.drive,
+        )?;
+
+        self.apply_batch_low_level_drive_operations(
+            None,
+            transaction,
+            batch_operations,
+            &mut vec![],
+            &platform_version.drive,
+        )
+    }
+}
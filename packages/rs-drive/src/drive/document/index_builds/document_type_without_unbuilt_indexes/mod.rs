@@ -0,0 +1,50 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::data_contract::document_type::{DocumentType, DocumentTypeRef};
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Returns the document type that should be used to maintain the indexes of a document
+    /// if some of its indexes are being built and are not yet maintained for this document,
+    /// or `None` if all indexes should be maintained.
+    ///
+    /// Before index builds exist no pending build is read and all indexes are maintained.
+    pub(crate) fn document_type_without_unbuilt_indexes(
+        &self,
+        contract_id: &[u8; 32],
+        document_type: DocumentTypeRef,
+        document_id: &[u8; 32],
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<DocumentType>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .index_builds
+            .document_type_without_unbuilt_indexes
+        {
+            Some(0) => self.document_type_without_unbuilt_indexes_v0(
+                contract_id,
+                document_type,
+                document_id,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            Some(version) => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "document_type_without_unbuilt_indexes".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::{DocumentType, DocumentTypeRef};
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Returns the document type without the indexes that are being built and are not yet
+    /// maintained for the document, or `None` if all indexes should be maintained.
+    #[inline(always)]
+    pub(super) fn document_type_without_unbuilt_indexes_v0(
+        &self,
+        contract_id: &[u8; 32],
+        document_type: DocumentTypeRef,
+        document_id: &[u8; 32],
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<DocumentType>, Error> {
+        let Some(pending_index_build) = self.fetch_pending_index_build(
+            contract_id,
+            document_type.name().as_str(),
+            transaction,
+            drive_operations,
+            platform_version,
+        )?
+        else {
+            return Ok(None);
+        };
+
+        if pending_index_build.is_backfilled(document_id) {
+            return Ok(None);
+        }
+
+        Ok(Some(document_type.with_filtered_indexes(
+            |index| !pending_index_build.is_building(index.name.as_str()),
+            platform_version,
+        )?))
+    }
+}
@@ -0,0 +1,43 @@
+mod v0;
+
+use crate::drive::document::index_builds::PendingIndexBuild;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the pending index build of a document type if it has one.
+    pub fn fetch_pending_index_build(
+        &self,
+        contract_id: &[u8; 32],
+        document_type_name: &str,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<PendingIndexBuild>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .index_builds
+            .fetch_pending_index_build
+        {
+            0 => self.fetch_pending_index_build_v0(
+                contract_id,
+                document_type_name,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_pending_index_build".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::drive::document::index_builds::{
+    pending_index_build_key, pending_index_builds_path, PendingIndexBuild,
+};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::DirectQueryType;
+use dpp::version::PlatformVersion;
+use grovedb::Element::Item;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the pending index build of a document type if it has one.
+    #[inline(always)]
+    pub(super) fn fetch_pending_index_build_v0(
+        &self,
+        contract_id: &[u8; 32],
+        document_type_name: &str,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<PendingIndexBuild>, Error> {
+        let element = match self.grove_get_raw_optional(
+            (&pending_index_builds_path()).into(),
+            pending_index_build_key(contract_id, document_type_name).as_slice(),
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        ) {
+            Ok(element) => element,
+            // No index build was ever scheduled
+            Err(Error::GroveDB(grovedb::Error::PathNotFound(_)))
+            | Err(Error::GroveDB(grovedb::Error::PathParentLayerNotFound(_))) => None,
+            Err(e) => return Err(e),
+        };
+
+        match element {
+            Some(Item(serialized_pending_index_build, _)) => Ok(Some(
+                PendingIndexBuild::deserialize(&serialized_pending_index_build)?,
+            )),
+            None => Ok(None),
+            Some(_) => Err(Error::Drive(DriveError::CorruptedElementType(
+                "pending index build was present but was not identified as an item",
+            ))),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+mod v0;
+
+use crate::drive::document::index_builds::PendingIndexBuild;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the pending index builds, ordered by contract id and document type name.
+    pub fn fetch_pending_index_builds(
+        &self,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<PendingIndexBuild>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .index_builds
+            .fetch_pending_index_builds
+        {
+            0 => self.fetch_pending_index_builds_v0(limit, transaction, platform_version),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_pending_index_builds".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
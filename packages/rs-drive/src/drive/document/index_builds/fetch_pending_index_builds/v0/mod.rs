@@ -0,0 +1,57 @@
+use crate::drive::document::index_builds::{pending_index_builds_path_vec, PendingIndexBuild};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::query::QueryItem;
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::QueryResultType::QueryElementResultType;
+use grovedb::Element::Item;
+use grovedb::{PathQuery, Query, SizedQuery, TransactionArg};
+use std::ops::RangeFull;
+
+impl Drive {
+    /// Fetches the pending index builds, ordered by contract id and document type name.
+    #[inline(always)]
+    pub(super) fn fetch_pending_index_builds_v0(
+        &self,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<PendingIndexBuild>, Error> {
+        let path_query = PathQuery::new(
+            pending_index_builds_path_vec(),
+            SizedQuery::new(
+                Query::new_single_query_item(QueryItem::RangeFull(RangeFull)),
+                limit,
+                None,
+            ),
+        );
+
+        let elements = match self.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryElementResultType,
+            &mut vec![],
+            &platform_version.drive,
+        ) {
+            Ok((results, _)) => results.to_elements(),
+            // No index build was ever scheduled
+            Err(Error::GroveDB(grovedb::Error::PathKeyNotFound(_)))
+            | Err(Error::GroveDB(grovedb::Error::PathNotFound(_)))
+            | Err(Error::GroveDB(grovedb::Error::PathParentLayerNotFound(_))) => vec![],
+            Err(e) => return Err(e),
+        };
+
+        elements
+            .into_iter()
+            .map(|element| match element {
+                Item(serialized_pending_index_build, _) => {
+                    PendingIndexBuild::deserialize(&serialized_pending_index_build)
+                }
+                _ => Err(Error::Drive(DriveError::CorruptedElementType(
+                    "pending index build was present but was not identified as an item",
+                ))),
+            })
+            .collect()
+    }
+}
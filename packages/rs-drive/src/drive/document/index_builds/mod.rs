@@ -0,0 +1,109 @@
+//! Document Index Builds.
+//!
+//! Indexes added to an existing document type through a data contract update need to be
+//! filled for the documents that already exist. This is done progressively at the end of
+//! blocks, a bounded number of documents at a time, in ascending order of document ids.
+//!
+//! While an index is being built, it is only maintained by document writes for documents
+//! with an id lower or equal to the last backfilled document id, the others will be indexed
+//! by the backfill. The index can't be queried until it is fully built.
+//!
+//! Pending builds are stored in the misc tree:
+//! `[Misc, "I", contract id + document type name] -> pending index build`
+//!
+
+mod backfill_pending_index_builds;
+mod document_type_without_unbuilt_indexes;
+mod fetch_pending_index_build;
+mod fetch_pending_index_builds;
+mod schedule_index_build_operations;
+
+use crate::drive::system::{misc_path, misc_path_vec};
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use bincode::{Decode, Encode};
+use dpp::ProtocolError;
+
+/// Pending index builds key in the misc tree
+pub const PENDING_INDEX_BUILDS_KEY: &[u8; 1] = b"I";
+
+/// The path of the pending index builds tree
+pub fn pending_index_builds_path() -> [&'static [u8]; 2] {
+    let [misc] = misc_path();
+    [misc, PENDING_INDEX_BUILDS_KEY]
+}
+
+/// The path of the pending index builds tree as a vector
+pub fn pending_index_builds_path_vec() -> Vec<Vec<u8>> {
+    let mut path = misc_path_vec();
+    path.push(PENDING_INDEX_BUILDS_KEY.to_vec());
+    path
+}
+
+/// The key of the pending index build of a document type
+pub fn pending_index_build_key(contract_id: &[u8; 32], document_type_name: &str) -> Vec<u8> {
+    let mut key = Vec::with_capacity(32 + document_type_name.len());
+    key.extend_from_slice(contract_id);
+    key.extend_from_slice(document_type_name.as_bytes());
+    key
+}
+
+/// Indexes of a document type that are being built
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct PendingIndexBuild {
+    /// The contract id
+    pub contract_id: [u8; 32],
+    /// The document type name
+    pub document_type_name: String,
+    /// The names of the indexes being built
+    pub index_names: Vec<String>,
+    /// The last document for which the indexes were filled, `None` if none were yet
+    pub last_backfilled_document_id: Option<[u8; 32]>,
+}
+
+impl PendingIndexBuild {
+    /// Whether the indexes being built are already maintained for a document
+    pub fn is_backfilled(&self, document_id: &[u8; 32]) -> bool {
+        self.last_backfilled_document_id
+            .is_some_and(|last_backfilled_document_id| document_id <= &last_backfilled_document_id)
+    }
+
+    /// Whether an index is being built
+    pub fn is_building(&self, index_name: &str) -> bool {
+        self.index_names.iter().any(|name| name == index_name)
+    }
+
+    /// The storage key of the pending index build
+    pub fn key(&self) -> Vec<u8> {
+        pending_index_build_key(&self.contract_id, self.document_type_name.as_str())
+    }
+
+    /// Serializes the pending index build for storage
+    pub fn serialize(&self) -> Result<Vec<u8>, Error> {
+        let config = bincode::config::standard()
+            .with_big_endian()
+            .with_no_limit();
+        bincode::encode_to_vec(self, config).map_err(|e| {
+            Error::Protocol(ProtocolError::CorruptedSerialization(format!(
+                "can not encode pending index build: {}",
+                e
+            )))
+        })
+    }
+
+    /// Deserializes a stored pending index build
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
+        let config = bincode::config::standard()
+            .with_big_endian()
+            .with_no_limit();
+        Ok(bincode::decode_from_slice(bytes, config)
+            .map_err(|e| {
+                Error::Drive(DriveError::CorruptedSerialization(format!(
+                    "serialization of pending index build {} is corrupted: {}",
+                    hex::encode(bytes),
+                    e
+                )))
+            })?
+            .0)
+    }
+}
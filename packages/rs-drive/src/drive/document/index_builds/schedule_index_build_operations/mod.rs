@@ -0,0 +1,47 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Gathers the operations to schedule the build of indexes added to an existing
+    /// document type. The document type must not already have a pending index build.
+    pub(crate) fn schedule_index_build_operations(
+        &self,
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        index_names: Vec<String>,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .index_builds
+            .schedule_index_build_operations
+        {
+            0 => self.schedule_index_build_operations_v0(
+                contract_id,
+                document_type_name,
+                index_names,
+                previous_batch_operations,
+                transaction,
+                batch_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "schedule_index_build_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
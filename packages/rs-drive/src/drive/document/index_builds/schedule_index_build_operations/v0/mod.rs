@@ -0,0 +1,53 @@
+use crate::drive::document::index_builds::{
+    pending_index_builds_path_vec, PendingIndexBuild, PENDING_INDEX_BUILDS_KEY,
+};
+use crate::drive::system::misc_path_vec;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::BatchInsertTreeApplyType;
+use crate::util::object_size_info::PathKeyInfo;
+use dpp::version::PlatformVersion;
+use grovedb::{Element, TransactionArg};
+
+impl Drive {
+    /// Gathers the operations to schedule the build of indexes added to an existing
+    /// document type.
+    #[inline(always)]
+    pub(super) fn schedule_index_build_operations_v0(
+        &self,
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        index_names: Vec<String>,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        self.batch_insert_empty_tree_if_not_exists(
+            PathKeyInfo::<0>::PathKey((misc_path_vec(), PENDING_INDEX_BUILDS_KEY.to_vec())),
+            false,
+            None,
+            BatchInsertTreeApplyType::StatefulBatchInsertTree,
+            transaction,
+            previous_batch_operations,
+            batch_operations,
+            &platform_version.drive,
+        )?;
+
+        let pending_index_build = PendingIndexBuild {
+            contract_id,
+            document_type_name: document_type_name.to_string(),
+            index_names,
+            last_backfilled_document_id: None,
+        };
+
+        batch_operations.push(LowLevelDriveOperation::insert_for_known_path_key_element(
+            pending_index_builds_path_vec(),
+            pending_index_build.key(),
+            Element::new_item(pending_index_build.serialize()?),
+        ));
+
+        Ok(())
+    }
+}
@@ -35,6 +35,7 @@
 
 mod internal;
 
+mod validate_document_accept_offer_transition_action_uniqueness;
 mod validate_document_create_transition_action_uniqueness;
 
 mod validate_document_replace_transition_action_uniqueness;
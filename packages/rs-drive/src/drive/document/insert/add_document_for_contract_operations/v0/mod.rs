@@ -9,6 +9,7 @@ use dpp::block::block_info::BlockInfo;
 use dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
+use dpp::document::DocumentV0Getters;
 
 use dpp::version::PlatformVersion;
 use grovedb::batch::KeyInfoPath;
@@ -92,6 +93,32 @@ impl Drive {
             return Ok(batch_operations);
         }
 
+        // Indexes being built are only maintained for documents they were already filled for
+        let document_type_without_unbuilt_indexes = match document_and_contract_info
+            .owned_document_info
+            .document_info
+            .get_borrowed_document()
+        {
+            Some(document) if estimated_costs_only_with_layer_info.is_none() => self
+                .document_type_without_unbuilt_indexes(
+                    document_and_contract_info.contract.id_ref().as_bytes(),
+                    document_and_contract_info.document_type,
+                    document.id_ref().as_bytes(),
+                    transaction,
+                    &mut batch_operations,
+                    platform_version,
+                )?,
+            _ => None,
+        };
+
+        let document_and_contract_info = match &document_type_without_unbuilt_indexes {
+            Some(document_type) => DocumentAndContractInfo {
+                document_type: document_type.as_ref(),
+                ..document_and_contract_info
+            },
+            None => document_and_contract_info,
+        };
+
         // if we are trying to get estimated costs we need to add the upper levels
         if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
             Self::add_estimation_costs_for_levels_up_to_contract_document_type_excluded(
@@ -121,6 +148,25 @@ impl Drive {
             platform_version,
         )?;
 
+        if let Some((document, storage_flags)) = document_and_contract_info
+            .owned_document_info
+            .document_info
+            .get_borrowed_document_and_storage_flags()
+        {
+            self.update_aggregates_for_document_operations(
+                document_and_contract_info.contract,
+                document_and_contract_info.document_type,
+                None,
+                Some(document),
+                storage_flags,
+                previous_batch_operations,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                &mut batch_operations,
+                platform_version,
+            )?;
+        }
+
         Ok(batch_operations)
     }
 }
@@ -50,12 +50,19 @@ impl Drive {
             platform_version,
         )?;
 
-        let end_date = block_info.time_ms.saturating_add(
-            platform_version
-                .dpp
-                .voting_versions
-                .default_vote_poll_time_duration_ms,
-        );
+        let voting_versions = &platform_version.dpp.voting_versions;
+
+        // Auctions stay open after bidding has closed so that contenders can reveal their bids
+        let vote_poll_duration_ms =
+            if contested_document_resource_vote_poll.is_resolved_by_auction()? {
+                voting_versions
+                    .default_vote_poll_time_duration_ms
+                    .saturating_add(voting_versions.auction_bid_reveal_duration_ms)
+            } else {
+                voting_versions.default_vote_poll_time_duration_ms
+            };
+
+        let end_date = block_info.time_ms.saturating_add(vote_poll_duration_ms);
 
         let contest_already_existed = self.add_contested_indices_for_contract_operations(
             &document_and_contract_info,
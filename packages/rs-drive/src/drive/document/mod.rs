@@ -21,16 +21,27 @@ use grovedb::reference_path::ReferencePathType::UpstreamRootHeightReference;
 #[cfg(feature = "server")]
 use grovedb::Element;
 
+#[cfg(any(feature = "server", feature = "fixtures-and-mocks"))]
+mod aggregates;
 #[cfg(feature = "server")]
 mod delete;
 #[cfg(feature = "server")]
 mod estimation_costs;
+/// Revisions of documents of document types keeping history
+#[cfg(any(feature = "server", feature = "verify"))]
+pub mod history;
+/// Building of indexes added to existing document types
+#[cfg(feature = "server")]
+pub mod index_builds;
 #[cfg(feature = "server")]
 mod index_uniqueness;
 #[cfg(any(feature = "server", feature = "fixtures-and-mocks"))]
 mod insert;
 #[cfg(any(feature = "server", feature = "fixtures-and-mocks"))]
 mod insert_contested;
+/// Offers on documents
+#[cfg(any(feature = "server", feature = "verify"))]
+pub mod offers;
 #[cfg(any(feature = "server", feature = "fixtures-and-mocks"))]
 pub mod query;
 #[cfg(any(feature = "server", feature = "fixtures-and-mocks"))]
@@ -0,0 +1,52 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::storage_flags::StorageFlags;
+use dpp::identifier::Identifier;
+use dpp::nft::offer::DocumentOffer;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Gathers the operations to store an open offer on a document, creating the offers trees
+    /// of the document type and of the document if they don't exist yet.
+    pub(crate) fn add_document_offer_operations(
+        &self,
+        contract_id: Identifier,
+        document_type_name: &str,
+        offer: &DocumentOffer,
+        storage_flags: Option<&StorageFlags>,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .offers
+            .add_document_offer_operations
+        {
+            0 => self.add_document_offer_operations_v0(
+                contract_id,
+                document_type_name,
+                offer,
+                storage_flags,
+                previous_batch_operations,
+                transaction,
+                batch_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "add_document_offer_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
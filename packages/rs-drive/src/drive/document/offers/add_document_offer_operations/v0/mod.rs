@@ -0,0 +1,60 @@
+use crate::drive::document::paths::{
+    contract_document_type_path_vec, DOCUMENT_TYPE_OFFERS_TREE_KEY,
+};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::BatchInsertTreeApplyType;
+use crate::util::object_size_info::PathKeyInfo;
+use crate::util::storage_flags::StorageFlags;
+use dpp::identifier::Identifier;
+use dpp::nft::offer::DocumentOffer;
+use dpp::serialization::PlatformSerializable;
+use dpp::version::PlatformVersion;
+use grovedb::{Element, TransactionArg};
+
+impl Drive {
+    /// Gathers the operations to store an open offer on a document.
+    #[inline(always)]
+    pub(super) fn add_document_offer_operations_v0(
+        &self,
+        contract_id: Identifier,
+        document_type_name: &str,
+        offer: &DocumentOffer,
+        storage_flags: Option<&StorageFlags>,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let mut path = contract_document_type_path_vec(contract_id.as_slice(), document_type_name);
+
+        for key in [
+            vec![DOCUMENT_TYPE_OFFERS_TREE_KEY],
+            offer.document_id.to_vec(),
+        ] {
+            self.batch_insert_empty_tree_if_not_exists(
+                PathKeyInfo::<0>::PathKey((path.clone(), key.clone())),
+                false,
+                storage_flags,
+                BatchInsertTreeApplyType::StatefulBatchInsertTree,
+                transaction,
+                previous_batch_operations,
+                batch_operations,
+                &platform_version.drive,
+            )?;
+            path.push(key);
+        }
+
+        batch_operations.push(LowLevelDriveOperation::insert_for_known_path_key_element(
+            path,
+            offer.bidder_id.to_vec(),
+            Element::new_item_with_flags(
+                offer.serialize_to_bytes()?,
+                storage_flags.and_then(StorageFlags::to_some_element_flags),
+            ),
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::identifier::Identifier;
+use dpp::nft::offer::DocumentOffer;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the open offer of a bidder on a document.
+    pub fn fetch_document_offer(
+        &self,
+        contract_id: Identifier,
+        document_type_name: &str,
+        document_id: Identifier,
+        bidder_id: Identifier,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<DocumentOffer>, Error> {
+        self.fetch_document_offer_operations(
+            contract_id,
+            document_type_name,
+            document_id,
+            bidder_id,
+            transaction,
+            &mut vec![],
+            platform_version,
+        )
+    }
+
+    /// Fetches the open offer of a bidder on a document, along with the fees of fetching it.
+    pub fn fetch_document_offer_with_fees(
+        &self,
+        contract_id: Identifier,
+        document_type_name: &str,
+        document_id: Identifier,
+        bidder_id: Identifier,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(Option<DocumentOffer>, FeeResult), Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        let offer = self.fetch_document_offer_operations(
+            contract_id,
+            document_type_name,
+            document_id,
+            bidder_id,
+            transaction,
+            &mut drive_operations,
+            platform_version,
+        )?;
+        let fees = Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            self.config.epochs_per_era,
+            platform_version,
+            None,
+        )?;
+        Ok((offer, fees))
+    }
+
+    /// Fetches the open offer of a bidder on a document, adding the costs of the fetch to
+    /// the drive operations.
+    pub(crate) fn fetch_document_offer_operations(
+        &self,
+        contract_id: Identifier,
+        document_type_name: &str,
+        document_id: Identifier,
+        bidder_id: Identifier,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<DocumentOffer>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .offers
+            .fetch_document_offer
+        {
+            0 => self.fetch_document_offer_operations_v0(
+                contract_id,
+                document_type_name,
+                document_id,
+                bidder_id,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_document_offer".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
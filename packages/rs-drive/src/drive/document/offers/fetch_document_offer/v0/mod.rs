@@ -0,0 +1,50 @@
+use crate::drive::document::paths::document_offers_path_vec;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::DirectQueryType;
+use dpp::identifier::Identifier;
+use dpp::nft::offer::DocumentOffer;
+use dpp::serialization::PlatformDeserializable;
+use dpp::version::PlatformVersion;
+use grovedb::Element::Item;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the open offer of a bidder on a document.
+    #[inline(always)]
+    pub(super) fn fetch_document_offer_operations_v0(
+        &self,
+        contract_id: Identifier,
+        document_type_name: &str,
+        document_id: Identifier,
+        bidder_id: Identifier,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<DocumentOffer>, Error> {
+        let path = document_offers_path_vec(
+            contract_id.as_slice(),
+            document_type_name,
+            document_id.as_slice(),
+        );
+
+        match self.grove_get_raw_optional(
+            path.as_slice().into(),
+            bidder_id.as_slice(),
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        )? {
+            Some(Item(serialized_offer, _)) => Ok(Some(DocumentOffer::deserialize_from_bytes(
+                &serialized_offer,
+            )?)),
+            None => Ok(None),
+            Some(_) => Err(Error::Drive(DriveError::CorruptedElementType(
+                "document offer was present but was not identified as an item",
+            ))),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::identifier::Identifier;
+use dpp::nft::offer::DocumentOffer;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the open offers on a document, ordered by bidder id.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - The maximum number of offers to return.
+    /// * `start_after` - The bidder id after which offers should be returned, for paging.
+    pub fn fetch_document_offers(
+        &self,
+        contract_id: Identifier,
+        document_type_name: &str,
+        document_id: Identifier,
+        limit: Option<u16>,
+        start_after: Option<Identifier>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DocumentOffer>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .offers
+            .fetch_document_offers
+        {
+            0 => self.fetch_document_offers_v0(
+                contract_id,
+                document_type_name,
+                document_id,
+                limit,
+                start_after,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_document_offers".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
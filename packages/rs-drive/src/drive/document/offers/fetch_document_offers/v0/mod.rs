@@ -0,0 +1,60 @@
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::identifier::Identifier;
+use dpp::nft::offer::DocumentOffer;
+use dpp::serialization::PlatformDeserializable;
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::QueryResultType::QueryElementResultType;
+use grovedb::Element::Item;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the open offers on a document, ordered by bidder id.
+    #[inline(always)]
+    pub(super) fn fetch_document_offers_v0(
+        &self,
+        contract_id: Identifier,
+        document_type_name: &str,
+        document_id: Identifier,
+        limit: Option<u16>,
+        start_after: Option<Identifier>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DocumentOffer>, Error> {
+        let path_query = Self::document_offers_path_query(
+            contract_id.to_buffer(),
+            document_type_name,
+            document_id.to_buffer(),
+            limit,
+            start_after.map(|bidder_id| bidder_id.to_buffer()),
+        );
+
+        let elements = match self.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryElementResultType,
+            &mut vec![],
+            &platform_version.drive,
+        ) {
+            Ok((results, _)) => results.to_elements(),
+            // No offer was ever made on the document
+            Err(Error::GroveDB(grovedb::Error::PathKeyNotFound(_)))
+            | Err(Error::GroveDB(grovedb::Error::PathNotFound(_)))
+            | Err(Error::GroveDB(grovedb::Error::PathParentLayerNotFound(_))) => vec![],
+            Err(e) => return Err(e),
+        };
+
+        elements
+            .into_iter()
+            .map(|element| match element {
+                Item(serialized_offer, _) => {
+                    Ok(DocumentOffer::deserialize_from_bytes(&serialized_offer)?)
+                }
+                _ => Err(Error::Drive(DriveError::CorruptedElementType(
+                    "document offer was present but was not identified as an item",
+                ))),
+            })
+            .collect()
+    }
+}
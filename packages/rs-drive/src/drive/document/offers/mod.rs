@@ -0,0 +1,45 @@
+//! Document Offers.
+//!
+//! This module implements functions in Drive storing the open offers made on documents
+//! of document types trading through offers.
+//!
+//! Offers are stored per document and per bidder:
+//! `[type path, 3, document id, bidder id] -> serialized offer`
+//!
+
+#[cfg(feature = "server")]
+mod add_document_offer_operations;
+#[cfg(feature = "server")]
+mod fetch_document_offer;
+#[cfg(feature = "server")]
+mod fetch_document_offers;
+#[cfg(feature = "server")]
+mod prove_document_offers;
+#[cfg(feature = "server")]
+mod remove_document_offer_operations;
+
+use crate::drive::document::paths::document_offers_path_vec;
+use crate::drive::Drive;
+use grovedb::{PathQuery, Query, SizedQuery};
+
+impl Drive {
+    /// The path query for the open offers on a document, ordered by bidder id
+    pub fn document_offers_path_query(
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        document_id: [u8; 32],
+        limit: Option<u16>,
+        start_after: Option<[u8; 32]>,
+    ) -> PathQuery {
+        let mut query = Query::new();
+        match start_after {
+            Some(bidder_id) => query.insert_range_after(bidder_id.to_vec()..),
+            None => query.insert_all(),
+        }
+
+        PathQuery::new(
+            document_offers_path_vec(&contract_id, document_type_name, &document_id),
+            SizedQuery::new(query, limit, None),
+        )
+    }
+}
@@ -0,0 +1,51 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::identifier::Identifier;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves the open offers on a document, ordered by bidder id.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - The maximum number of offers to prove.
+    /// * `start_after` - The bidder id after which offers should be proved, for paging.
+    pub fn prove_document_offers(
+        &self,
+        contract_id: Identifier,
+        document_type_name: &str,
+        document_id: Identifier,
+        limit: Option<u16>,
+        start_after: Option<Identifier>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .offers
+            .prove_document_offers
+        {
+            0 => self.prove_document_offers_v0(
+                contract_id,
+                document_type_name,
+                document_id,
+                limit,
+                start_after,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "prove_document_offers".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::drive::Drive;
+use crate::error::Error;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves the open offers on a document, ordered by bidder id.
+    #[inline(always)]
+    pub(super) fn prove_document_offers_v0(
+        &self,
+        contract_id: Identifier,
+        document_type_name: &str,
+        document_id: Identifier,
+        limit: Option<u16>,
+        start_after: Option<Identifier>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let path_query = Self::document_offers_path_query(
+            contract_id.to_buffer(),
+            document_type_name,
+            document_id.to_buffer(),
+            limit,
+            start_after.map(|bidder_id| bidder_id.to_buffer()),
+        );
+
+        self.grove_get_proved_path_query(
+            &path_query,
+            transaction,
+            &mut vec![],
+            &platform_version.drive,
+        )
+    }
+}
@@ -0,0 +1,47 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::identifier::Identifier;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Gathers the operations to remove the open offer of a bidder on a document.
+    pub(crate) fn remove_document_offer_operations(
+        &self,
+        contract_id: Identifier,
+        document_type_name: &str,
+        document_id: Identifier,
+        bidder_id: Identifier,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .offers
+            .remove_document_offer_operations
+        {
+            0 => self.remove_document_offer_operations_v0(
+                contract_id,
+                document_type_name,
+                document_id,
+                bidder_id,
+                transaction,
+                batch_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "remove_document_offer_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
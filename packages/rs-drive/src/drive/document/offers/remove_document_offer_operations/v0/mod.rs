@@ -0,0 +1,43 @@
+use crate::drive::document::paths::document_offers_path_vec;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::BatchDeleteApplyType;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Gathers the operations to remove the open offer of a bidder on a document.
+    ///
+    /// The offers tree of the document is kept, as it is cheaper than removing and recreating
+    /// it for documents receiving offers repeatedly.
+    #[inline(always)]
+    pub(super) fn remove_document_offer_operations_v0(
+        &self,
+        contract_id: Identifier,
+        document_type_name: &str,
+        document_id: Identifier,
+        bidder_id: Identifier,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let path = document_offers_path_vec(
+            contract_id.as_slice(),
+            document_type_name,
+            document_id.as_slice(),
+        );
+
+        self.batch_delete(
+            path.as_slice().into(),
+            bidder_id.as_slice(),
+            BatchDeleteApplyType::StatefulBatchDelete {
+                is_known_to_be_subtree_with_sum: Some((false, false)),
+            },
+            transaction,
+            batch_operations,
+            &platform_version.drive,
+        )
+    }
+}
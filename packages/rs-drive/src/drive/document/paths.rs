@@ -36,6 +36,39 @@ pub(crate) fn contract_document_type_path_vec(
     ]
 }
 
+#[cfg(any(feature = "server", feature = "verify"))]
+/// The key of the tree holding count and sum trees of aggregated indexes of a document type
+pub const DOCUMENT_TYPE_AGGREGATES_TREE_KEY: u8 = 2;
+
+#[cfg(any(feature = "server", feature = "verify"))]
+/// The key of the tree holding the open offers on the documents of a document type
+pub const DOCUMENT_TYPE_OFFERS_TREE_KEY: u8 = 3;
+
+#[cfg(any(feature = "server", feature = "verify"))]
+/// Returns the path to the open offers on a document.
+pub fn document_offers_path_vec(
+    contract_id: &[u8],
+    document_type_name: &str,
+    document_id: &[u8],
+) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::DataContractDocuments as u8],
+        contract_id.to_vec(),
+        vec![1u8],
+        document_type_name.as_bytes().to_vec(),
+        vec![DOCUMENT_TYPE_OFFERS_TREE_KEY],
+        document_id.to_vec(),
+    ]
+}
+
+#[cfg(any(feature = "server", feature = "verify"))]
+/// The key of the sum tree counting documents of an aggregated index
+pub const INDEX_COUNT_TREE_KEY: u8 = 0;
+
+#[cfg(any(feature = "server", feature = "verify"))]
+/// The key of the sum tree totaling the summed property of an aggregated index
+pub const INDEX_SUM_TREE_KEY: u8 = 1;
+
 #[cfg(any(feature = "server", feature = "verify"))]
 /// Returns the path to the primary keys of a contract document type.
 pub(crate) fn contract_documents_primary_key_path<'a>(
@@ -68,6 +101,23 @@ pub fn contract_documents_keeping_history_primary_key_path_for_document_id<'a>(
     ]
 }
 
+#[cfg(any(feature = "server", feature = "verify"))]
+/// Returns the path to the revisions of a document of a document type keeping history.
+pub fn contract_documents_keeping_history_primary_key_path_for_document_id_vec(
+    contract_id: &[u8],
+    document_type_name: &str,
+    document_id: &[u8],
+) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::DataContractDocuments as u8],
+        contract_id.to_vec(),
+        vec![1u8],
+        document_type_name.as_bytes().to_vec(),
+        vec![0],
+        document_id.to_vec(),
+    ]
+}
+
 #[cfg(feature = "server")]
 /// Returns the path to a contract document when the document id isn't known.
 pub fn contract_documents_keeping_history_primary_key_path_for_unknown_document_id(
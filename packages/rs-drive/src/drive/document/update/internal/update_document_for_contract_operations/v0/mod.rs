@@ -169,9 +169,29 @@ impl Drive {
             )));
         };
 
+        // Indexes being built are only maintained for documents they were already filled for
+        let document_type_without_unbuilt_indexes =
+            if estimated_costs_only_with_layer_info.is_none() {
+                self.document_type_without_unbuilt_indexes(
+                    contract.id_ref().as_bytes(),
+                    document_type,
+                    document.id_ref().as_bytes(),
+                    transaction,
+                    &mut batch_operations,
+                    platform_version,
+                )?
+            } else {
+                None
+            };
+        let maintained_indexes = document_type_without_unbuilt_indexes
+            .as_ref()
+            .map_or(document_type.indexes(), |document_type| {
+                document_type.indexes()
+            });
+
         let mut batch_insertion_cache: HashSet<Vec<Vec<u8>>> = HashSet::new();
         // fourth we need to store a reference to the document for each index
-        for index in document_type.indexes().values() {
+        for index in maintained_indexes.values() {
             // at this point the contract path is to the contract documents
             // for each index the top index component will already have been added
             // when the contract itself was created
@@ -477,6 +497,25 @@ impl Drive {
                 }
             }
         }
+
+        let old_document = old_document_info
+            .get_borrowed_document()
+            .ok_or(Error::Drive(DriveError::CorruptedCodeExecution(
+                "old document must be known",
+            )))?;
+        self.update_aggregates_for_document_operations(
+            contract,
+            document_type,
+            Some(old_document),
+            Some(document),
+            storage_flags,
+            previous_batch_operations,
+            estimated_costs_only_with_layer_info,
+            transaction,
+            &mut batch_operations,
+            platform_version,
+        )?;
+
         Ok(batch_operations)
     }
 }
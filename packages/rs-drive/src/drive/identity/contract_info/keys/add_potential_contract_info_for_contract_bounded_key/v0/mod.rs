@@ -114,8 +114,12 @@ impl Drive {
 
         for contract_info in contract_infos.into_iter() {
             let root_id = contract_info.root_id();
+            let is_contract_family_based = contract_info.is_contract_family_based();
 
-            let contract = if estimated_costs_only_with_layer_info.is_none() {
+            let contract = if is_contract_family_based {
+                // keys bound to the contracts of an owner don't depend on any contract config
+                None
+            } else if estimated_costs_only_with_layer_info.is_none() {
                 // we should start by fetching the contract
                 let (fee, contract) = self.get_contract_with_fetch_info_and_fee(
                     root_id,
@@ -314,9 +318,21 @@ impl Drive {
 
                     let sibling_ref_type_path = SiblingReference(key_id_bytes);
 
+                    // keys bound to the contracts of an owner keep it with the keys of their
+                    // purpose, where the contract keys query looks for the current key
+                    let latest_key_reference_path = if is_contract_family_based {
+                        identity_contract_info_group_path_key_purpose_vec(
+                            &identity_id,
+                            &root_id,
+                            purpose,
+                        )
+                    } else {
+                        identity_contract_info_group_keys_path_vec(&identity_id, &root_id)
+                    };
+
                     self.batch_insert(
                         PathKeyElementInfo::<0>::PathKeyElement((
-                            identity_contract_info_group_keys_path_vec(&identity_id, &root_id),
+                            latest_key_reference_path,
                             vec![],
                             Element::Reference(sibling_ref_type_path, Some(2), None),
                         )),
@@ -1,4 +1,6 @@
-use crate::drive::identity::contract_info::keys::IdentityDataContractKeyApplyInfo::ContractBased;
+use crate::drive::identity::contract_info::keys::IdentityDataContractKeyApplyInfo::{
+    ContractBased, ContractFamilyBased,
+};
 use crate::drive::Drive;
 use crate::error::identity::IdentityError;
 use crate::error::Error;
@@ -25,21 +27,26 @@ pub enum IdentityDataContractKeyApplyInfo {
         document_type_keys: BTreeMap<String, Vec<(KeyID, Purpose)>>,
         contract_keys: Vec<(KeyID, Purpose)>,
     },
-    // ContractFamilyBased {
-    //     contracts_owner_id: Identifier,
-    //     family_keys: Vec<KeyID>,
-    // },
+    /// The root_id is the owner id of the contracts, for keys bound to all the contracts
+    /// of an owner
+    ContractFamilyBased {
+        contracts_owner_id: Identifier,
+        family_keys: Vec<(KeyID, Purpose)>,
+    },
 }
 
 impl IdentityDataContractKeyApplyInfo {
     fn root_id(&self) -> [u8; 32] {
         match self {
             ContractBased { contract_id, .. } => contract_id.to_buffer(),
-            // ContractFamilyBased {
-            //     contracts_owner_id, ..
-            // } => contracts_owner_id.to_buffer(),
+            ContractFamilyBased {
+                contracts_owner_id, ..
+            } => contracts_owner_id.to_buffer(),
         }
     }
+    fn is_contract_family_based(&self) -> bool {
+        matches!(self, ContractFamilyBased { .. })
+    }
     fn keys(
         self,
     ) -> (
@@ -52,7 +59,7 @@ impl IdentityDataContractKeyApplyInfo {
                 contract_keys,
                 ..
             } => (document_type_keys, contract_keys),
-            // ContractFamilyBased { family_keys, .. } => (BTreeMap::new(), family_keys),
+            ContractFamilyBased { family_keys, .. } => (BTreeMap::new(), family_keys),
         }
     }
     fn new_from_single_key(
@@ -65,6 +72,13 @@ impl IdentityDataContractKeyApplyInfo {
         drive_operations: &mut Vec<LowLevelDriveOperation>,
         platform_version: &PlatformVersion,
     ) -> Result<Self, Error> {
+        if let ContractBounds::MultipleContractsOfSameOwner { owner_id } = contract_bounds {
+            // there is no single contract to get, the keys are grouped under the owner
+            return Ok(ContractFamilyBased {
+                contracts_owner_id: *owner_id,
+                family_keys: vec![(key_id, purpose)],
+            });
+        }
         let contract_id = contract_bounds.identifier().to_buffer();
         // we are getting with fetch info to add the cost to the drive operations
         let maybe_contract_fetch_info = drive.get_contract_with_fetch_info_and_add_to_operations(
@@ -100,10 +114,12 @@ impl IdentityDataContractKeyApplyInfo {
                     )]),
                     contract_keys: vec![],
                 })
-            } // ContractBounds::MultipleContractsOfSameOwner { .. } => Ok(ContractFamilyBased {
-              //     contracts_owner_id: contract.owner_id(),
-              //     family_keys: vec![key_id],
-              // }),
+            }
+            ContractBounds::MultipleContractsOfSameOwner { .. } => {
+                Err(Error::Identity(IdentityError::IdentityKeyBoundsError(
+                    "keys bound to the contracts of an owner are not bound to a single contract",
+                )))
+            }
         }
     }
 }
@@ -0,0 +1,57 @@
+use crate::util::type_constants::DEFAULT_HASH_SIZE_U8;
+
+use crate::drive::{identity_tree_path, Drive};
+
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerCount::{EstimatedLevel, PotentiallyAtMaxElements};
+use grovedb::EstimatedLayerInformation;
+use grovedb::EstimatedLayerSizes::{AllSubtrees, Mix};
+
+use crate::drive::identity::identity_path_vec;
+use crate::drive::identity::recovery::ESTIMATED_RECOVERY_ITEM_SIZE;
+
+use grovedb::EstimatedSumTrees::NoSumTrees;
+use std::collections::HashMap;
+
+impl Drive {
+    /// Adds the estimation costs of the identity layer holding the recovery items.
+    pub(super) fn add_estimation_costs_for_identity_recovery_v0(
+        identity_id: [u8; 32],
+        estimated_costs_only_with_layer_info: &mut HashMap<KeyInfoPath, EstimatedLayerInformation>,
+    ) {
+        // we need to add the root
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path([]),
+            EstimatedLayerInformation {
+                is_sum_tree: false,
+                estimated_layer_count: EstimatedLevel(0, false),
+                estimated_layer_sizes: AllSubtrees(1, NoSumTrees, None),
+            },
+        );
+
+        // we then need to insert the root identity layer
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path(identity_tree_path()),
+            EstimatedLayerInformation {
+                is_sum_tree: false,
+                estimated_layer_count: PotentiallyAtMaxElements,
+                estimated_layer_sizes: AllSubtrees(DEFAULT_HASH_SIZE_U8, NoSumTrees, None),
+            },
+        );
+
+        // The recovery items sit at the bottom of the identity layer, under the contract info
+        // and the keys
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_owned_path(identity_path_vec(identity_id.as_slice())),
+            EstimatedLayerInformation {
+                is_sum_tree: false,
+                estimated_layer_count: EstimatedLevel(2, false),
+                estimated_layer_sizes: Mix {
+                    subtrees_size: Some((1, NoSumTrees, None, 1)),
+                    items_size: Some((1, ESTIMATED_RECOVERY_ITEM_SIZE, None, 1)),
+                    references_size: None,
+                },
+            },
+        );
+    }
+}
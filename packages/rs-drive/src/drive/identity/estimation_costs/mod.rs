@@ -4,6 +4,7 @@ mod for_identity_contract_info;
 mod for_identity_contract_info_group;
 mod for_identity_contract_info_group_key_purpose;
 mod for_identity_contract_info_group_keys;
+mod for_identity_recovery;
 mod for_keys_for_identity_id;
 mod for_negative_credit;
 mod for_purpose_in_key_reference_tree;
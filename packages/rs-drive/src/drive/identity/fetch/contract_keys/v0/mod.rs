@@ -11,12 +11,16 @@ use std::collections::BTreeMap;
 
 impl Drive {
     /// Fetches identities keys bound to specified contract
+    ///
+    /// A key bound to the contract takes precedence over a key of the same purpose bound to all
+    /// the contracts of its owner.
     pub(super) fn fetch_identities_contract_keys_v0(
         &self,
         identity_ids: &[[u8; 32]],
         contract_id: &[u8; 32],
         document_type_name: Option<String>,
         purposes: Vec<Purpose>,
+        contracts_owner_id: Option<[u8; 32]>,
         transaction: TransactionArg,
         platform_version: &PlatformVersion,
     ) -> Result<BTreeMap<Identifier, BTreeMap<Purpose, Vec<u8>>>, Error> {
@@ -68,12 +72,14 @@ impl Drive {
 
         let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
 
+        let groups_count = if contracts_owner_id.is_some() { 2 } else { 1 };
         let query = Self::identities_contract_keys_query(
             identity_ids,
             contract_id,
             &document_type_name,
             &purposes,
-            Some((identity_ids.len() * purposes.len()) as u16),
+            contracts_owner_id.as_ref(),
+            Some((identity_ids.len() * purposes.len() * groups_count) as u16),
         );
 
         let result = self
@@ -121,7 +127,13 @@ impl Drive {
                     .entry(identity_id)
                     .or_insert(BTreeMap::new());
 
-                entry.insert(purpose, element.into_item_bytes()?);
+                let item_bytes = element.into_item_bytes()?;
+
+                if Self::is_contracts_owner_key_path(&path, contracts_owner_id.as_ref()) {
+                    entry.entry(purpose).or_insert(item_bytes);
+                } else {
+                    entry.insert(purpose, item_bytes);
+                }
             }
         }
 
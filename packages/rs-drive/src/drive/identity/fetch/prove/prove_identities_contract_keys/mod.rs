@@ -19,6 +19,7 @@ impl Drive {
     /// * `contract_id` - The contract id
     /// * `document_type_name` - The optional document type name
     /// * `purposes` - Key purposes
+    /// * `contracts_owner_id` - The optional owner of the contract, to also get the keys bound to all of its contracts
     /// * `transaction` - Transaction arguments.
     /// * `drive_version` - A reference to the drive version.
     ///
@@ -31,6 +32,7 @@ impl Drive {
         contract_id: &[u8; 32],
         document_type_name: Option<String>,
         purposes: Vec<Purpose>,
+        contracts_owner_id: Option<[u8; 32]>,
         transaction: TransactionArg,
         drive_version: &DriveVersion,
     ) -> Result<Vec<u8>, Error> {
@@ -45,6 +47,7 @@ impl Drive {
                 contract_id,
                 document_type_name,
                 purposes,
+                contracts_owner_id,
                 transaction,
                 drive_version,
             ),
@@ -14,16 +14,19 @@ impl Drive {
         contract_id: &[u8; 32],
         document_type_name: Option<String>,
         purposes: Vec<Purpose>,
+        contracts_owner_id: Option<[u8; 32]>,
         transaction: TransactionArg,
         drive_version: &DriveVersion,
     ) -> Result<Vec<u8>, Error> {
         let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        let groups_count = if contracts_owner_id.is_some() { 2 } else { 1 };
         let query = Self::identities_contract_keys_query(
             identity_ids,
             contract_id,
             &document_type_name,
             &purposes,
-            Some((identity_ids.len() * purposes.len()) as u16),
+            contracts_owner_id.as_ref(),
+            Some((identity_ids.len() * purposes.len() * groups_count) as u16),
         );
         self.grove_get_proved_path_query(&query, transaction, &mut drive_operations, drive_version)
     }
@@ -6,7 +6,9 @@ use crate::error::Error;
 
 use crate::drive::identity::contract_info::ContractInfoStructure;
 use crate::drive::identity::contract_info::ContractInfoStructure::IdentityContractNonceKey;
-use crate::drive::identity::IdentityRootStructure::{IdentityTreeNonce, IdentityTreeRevision};
+use crate::drive::identity::IdentityRootStructure::{
+    IdentityPendingRecovery, IdentityRecoveryConfig, IdentityTreeNonce, IdentityTreeRevision,
+};
 use crate::drive::identity::{
     identity_contract_info_group_path_vec, identity_path_vec, IdentityRootStructure,
 };
@@ -23,6 +25,9 @@ use grovedb_version::version::GroveVersion;
 /// * `FullIdentity`: Represents a request to prove the full identity (0).
 /// * `Balance`: Represents a request to prove the account balance (1).
 /// * `Keys`: Represents a request to prove the public keys (2).
+/// * `Revision`: Represents a request to prove the revision (3).
+/// * `RecoveryConfig`: Represents a request to prove the social recovery configuration (4).
+/// * `PendingRecovery`: Represents a request to prove the pending social recovery (5).
 #[repr(u8)]
 pub enum IdentityProveRequestType {
     /// FullIdentity: A variant representing full identity access, assigned the value 0.
@@ -33,6 +38,10 @@ pub enum IdentityProveRequestType {
     Keys = 2,
     /// Revision: A variant representing revision field
     Revision = 3,
+    /// RecoveryConfig: A variant representing the social recovery configuration
+    RecoveryConfig = 4,
+    /// PendingRecovery: A variant representing the pending social recovery
+    PendingRecovery = 5,
 }
 
 impl TryFrom<u8> for IdentityProveRequestType {
@@ -44,6 +53,8 @@ impl TryFrom<u8> for IdentityProveRequestType {
             1 => Ok(IdentityProveRequestType::Balance),
             2 => Ok(IdentityProveRequestType::Keys),
             3 => Ok(IdentityProveRequestType::Revision),
+            4 => Ok(IdentityProveRequestType::RecoveryConfig),
+            5 => Ok(IdentityProveRequestType::PendingRecovery),
             _ => Err(Error::Query(QuerySyntaxError::InvalidIdentityProveRequest(
                 "unknown prove request type",
             ))),
@@ -205,11 +216,15 @@ impl Drive {
     }
 
     /// The query for the identity contract bounded keys for multiple identities
+    ///
+    /// When `contracts_owner_id` is set, the keys bound to all the contracts of that owner are
+    /// queried as well.
     pub fn identities_contract_keys_query(
         identity_ids: &[[u8; 32]],
         contract_id: &[u8; 32],
         document_type_name: &Option<String>,
         purposes: &[Purpose],
+        contracts_owner_id: Option<&[u8; 32]>,
         limit: Option<u16>,
     ) -> PathQuery {
         let identities_path = identity_tree_path_vec();
@@ -226,12 +241,6 @@ impl Drive {
             group_id.extend(document_type_name.as_bytes());
         }
 
-        query.default_subquery_branch.subquery_path = Some(vec![
-            vec![IdentityRootStructure::IdentityContractInfo as u8],
-            group_id,
-            vec![ContractInfoStructure::ContractInfoKeysKey as u8],
-        ]);
-
         let mut sub_query = Query::new();
 
         sub_query.insert_keys(
@@ -243,7 +252,27 @@ impl Drive {
 
         sub_query.set_subquery_key(Key::new());
 
-        query.default_subquery_branch.subquery = Some(sub_query.into());
+        if let Some(contracts_owner_id) = contracts_owner_id {
+            query.default_subquery_branch.subquery_path = Some(vec![vec![
+                IdentityRootStructure::IdentityContractInfo as u8,
+            ]]);
+
+            let mut groups_query = Query::new();
+            groups_query.insert_keys(vec![group_id, contracts_owner_id.to_vec()]);
+            groups_query.default_subquery_branch.subquery_path =
+                Some(vec![vec![ContractInfoStructure::ContractInfoKeysKey as u8]]);
+            groups_query.default_subquery_branch.subquery = Some(sub_query.into());
+
+            query.default_subquery_branch.subquery = Some(groups_query.into());
+        } else {
+            query.default_subquery_branch.subquery_path = Some(vec![
+                vec![IdentityRootStructure::IdentityContractInfo as u8],
+                group_id,
+                vec![ContractInfoStructure::ContractInfoKeysKey as u8],
+            ]);
+
+            query.default_subquery_branch.subquery = Some(sub_query.into());
+        }
         PathQuery {
             path: identities_path,
             query: SizedQuery {
@@ -254,6 +283,18 @@ impl Drive {
         }
     }
 
+    /// Whether a path returned by the contract keys query is the one of the keys bound to all
+    /// the contracts of an owner
+    pub(crate) fn is_contracts_owner_key_path(
+        path: &[Vec<u8>],
+        contracts_owner_id: Option<&[u8; 32]>,
+    ) -> bool {
+        contracts_owner_id.is_some_and(|contracts_owner_id| {
+            path.get(3)
+                .is_some_and(|group_id| group_id.as_slice() == contracts_owner_id.as_slice())
+        })
+    }
+
     /// The query for the identity contract document type bounded keys for multiple identities
     pub fn identities_contract_document_type_keys_query(
         identity_ids: &[[u8; 32]],
@@ -309,6 +350,18 @@ impl Drive {
         PathQuery::new_single_key(identity_path, vec![IdentityTreeNonce as u8])
     }
 
+    /// The query for proving the social recovery configuration of an identity.
+    pub fn identity_recovery_config_query(identity_id: [u8; 32]) -> PathQuery {
+        let identity_path = identity_path_vec(identity_id.as_slice());
+        PathQuery::new_single_key(identity_path, vec![IdentityRecoveryConfig as u8])
+    }
+
+    /// The query for proving the pending social recovery of an identity.
+    pub fn identity_pending_recovery_query(identity_id: [u8; 32]) -> PathQuery {
+        let identity_path = identity_path_vec(identity_id.as_slice());
+        PathQuery::new_single_key(identity_path, vec![IdentityPendingRecovery as u8])
+    }
+
     /// The query for proving the identities nonce for a specific contract.
     pub fn identity_contract_nonce_query(
         identity_id: [u8; 32],
@@ -14,7 +14,7 @@ use dpp::identity::Purpose;
 #[cfg(feature = "server")]
 use dpp::identity::{KeyID, SecurityLevel};
 
-#[cfg(feature = "server")]
+#[cfg(any(feature = "server", feature = "verify"))]
 /// Everything related to withdrawals
 pub mod withdrawals;
 
@@ -36,6 +36,9 @@ mod insert;
 #[cfg(any(feature = "server", feature = "verify"))]
 /// Module related to Identity Keys
 pub mod key;
+/// Module related to the social recovery of identities
+#[cfg(feature = "server")]
+pub mod recovery;
 /// Module related to updating of identity
 #[cfg(feature = "server")]
 pub mod update;
@@ -321,6 +324,10 @@ pub enum IdentityRootStructure {
     IdentityTreeNegativeCredit = 96,
     /// Identity contract information
     IdentityContractInfo = 32,
+    /// The social recovery configuration of the identity
+    IdentityRecoveryConfig = 224,
+    /// The recovery approved by the guardians, waiting for its time lock
+    IdentityPendingRecovery = 16,
 }
 
 #[cfg(any(feature = "server", feature = "verify"))]
@@ -333,6 +340,8 @@ impl fmt::Display for IdentityRootStructure {
             IdentityRootStructure::IdentityTreeKeyReferences => "IdentityKeyReferences",
             IdentityRootStructure::IdentityTreeNegativeCredit => "NegativeCredit",
             IdentityRootStructure::IdentityContractInfo => "ContractInfo",
+            IdentityRootStructure::IdentityRecoveryConfig => "RecoveryConfig",
+            IdentityRootStructure::IdentityPendingRecovery => "PendingRecovery",
         };
         write!(f, "{}", variant_name)
     }
@@ -350,6 +359,8 @@ impl TryFrom<u8> for IdentityRootStructure {
             160 => Ok(IdentityRootStructure::IdentityTreeKeyReferences),
             96 => Ok(IdentityRootStructure::IdentityTreeNegativeCredit),
             32 => Ok(IdentityRootStructure::IdentityContractInfo),
+            224 => Ok(IdentityRootStructure::IdentityRecoveryConfig),
+            16 => Ok(IdentityRootStructure::IdentityPendingRecovery),
             _ => Err(Error::Drive(DriveError::NotSupported(
                 "unknown identity root structure tree item",
             ))),
@@ -388,6 +399,8 @@ impl From<IdentityRootStructure> for &'static [u8; 1] {
             IdentityRootStructure::IdentityTreeKeyReferences => &[160],
             IdentityRootStructure::IdentityTreeNegativeCredit => &[96],
             IdentityRootStructure::IdentityContractInfo => &[32],
+            IdentityRootStructure::IdentityRecoveryConfig => &[224],
+            IdentityRootStructure::IdentityPendingRecovery => &[16],
         }
     }
 }
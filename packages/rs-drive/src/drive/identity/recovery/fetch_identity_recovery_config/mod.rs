@@ -0,0 +1,54 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::{drive::DriveError, Error};
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::identity::recovery::IdentityRecoveryConfig;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the social recovery configuration of an identity from the backing store
+    ///
+    /// This function uses the versioning system to call the appropriate handler based on the provided `DriveVersion`.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity_id` - Identity Id to fetch.
+    /// * `transaction` - Transaction arguments.
+    /// * `drive_operations` - A mutable reference to a vector of low-level drive operations.
+    /// * `platform_version` - A reference to the platform version.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an `Option` for the recovery configuration, `None` if the
+    /// identity didn't opt in to social recovery.
+    pub fn fetch_identity_recovery_config(
+        &self,
+        identity_id: [u8; 32],
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<IdentityRecoveryConfig>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .recovery
+            .fetch_identity_recovery_config
+        {
+            0 => self.fetch_identity_recovery_config_v0(
+                identity_id,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_identity_recovery_config".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::drive::identity::identity_path;
+use crate::drive::identity::IdentityRootStructure::IdentityRecoveryConfig as IdentityRecoveryConfigKey;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::DirectQueryType;
+use dpp::identity::recovery::IdentityRecoveryConfig;
+use dpp::serialization::PlatformDeserializable;
+
+use dpp::version::PlatformVersion;
+use grovedb::Element::Item;
+use grovedb::TransactionArg;
+
+impl Drive {
+    pub(super) fn fetch_identity_recovery_config_v0(
+        &self,
+        identity_id: [u8; 32],
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<IdentityRecoveryConfig>, Error> {
+        let identity_path = identity_path(identity_id.as_slice());
+        match self.grove_get_raw_optional(
+            (&identity_path).into(),
+            &[IdentityRecoveryConfigKey as u8],
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        )? {
+            Some(Item(serialized_config, _)) => Ok(Some(
+                IdentityRecoveryConfig::deserialize_from_bytes(&serialized_config)?,
+            )),
+            None => Ok(None),
+            Some(..) => Err(Error::Drive(DriveError::CorruptedElementType(
+                "identity recovery config was present but was not identified as an item",
+            ))),
+        }
+    }
+}
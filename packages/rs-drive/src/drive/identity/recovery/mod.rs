@@ -0,0 +1,9 @@
+mod fetch_identity_pending_recovery;
+mod fetch_identity_recovery_config;
+mod prove_identity_pending_recovery;
+mod remove_identity_pending_recovery;
+mod set_identity_pending_recovery;
+mod set_identity_recovery_config;
+
+/// The estimated size of a serialized recovery configuration or pending recovery
+pub(crate) const ESTIMATED_RECOVERY_ITEM_SIZE: u32 = 256;
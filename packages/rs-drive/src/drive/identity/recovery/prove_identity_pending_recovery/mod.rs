@@ -0,0 +1,46 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::{drive::DriveError, Error};
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves the pending social recovery of an identity, or its absence
+    ///
+    /// This function uses the versioning system to call the appropriate handler based on the provided `DriveVersion`.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity_id` - Identity Id to prove.
+    /// * `transaction` - Transaction arguments.
+    /// * `platform_version` - A reference to the platform version.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a Proof for the Identity's pending recovery, otherwise an `Error` if the operation fails or the version is not supported.
+    pub fn prove_identity_pending_recovery(
+        &self,
+        identity_id: [u8; 32],
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .recovery
+            .prove_identity_pending_recovery
+        {
+            0 => {
+                self.prove_identity_pending_recovery_v0(identity_id, transaction, platform_version)
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "prove_identity_pending_recovery".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
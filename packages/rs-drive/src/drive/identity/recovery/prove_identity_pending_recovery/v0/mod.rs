@@ -0,0 +1,22 @@
+use crate::drive::Drive;
+use crate::error::Error;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    pub(super) fn prove_identity_pending_recovery_v0(
+        &self,
+        identity_id: [u8; 32],
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let pending_recovery_query = Self::identity_pending_recovery_query(identity_id);
+        self.grove_get_proved_path_query(
+            &pending_recovery_query,
+            transaction,
+            &mut vec![],
+            &platform_version.drive,
+        )
+    }
+}
@@ -0,0 +1,51 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::{drive::DriveError, Error};
+use crate::fees::op::LowLevelDriveOperation;
+
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// The operations for removing the pending recovery of an identity, once it was cancelled
+    /// by the owner or completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity_id` - The identity that was being recovered.
+    /// * `estimated_costs_only_with_layer_info` - If set, only the costs are estimated.
+    /// * `transaction` - Transaction arguments.
+    /// * `platform_version` - A reference to the platform version.
+    pub fn remove_identity_pending_recovery_operations(
+        &self,
+        identity_id: [u8; 32],
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .recovery
+            .remove_identity_pending_recovery
+        {
+            0 => self.remove_identity_pending_recovery_operations_v0(
+                identity_id,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "remove_identity_pending_recovery_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::drive::identity::recovery::ESTIMATED_RECOVERY_ITEM_SIZE;
+use crate::drive::identity::{identity_path, IdentityRootStructure};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::BatchDeleteApplyType;
+
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    pub(super) fn remove_identity_pending_recovery_operations_v0(
+        &self,
+        identity_id: [u8; 32],
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let apply_type = if let Some(estimated_costs_only_with_layer_info) =
+            estimated_costs_only_with_layer_info
+        {
+            Self::add_estimation_costs_for_identity_recovery(
+                identity_id,
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+            BatchDeleteApplyType::StatelessBatchDelete {
+                is_sum_tree: false,
+                estimated_key_size: 1,
+                estimated_value_size: ESTIMATED_RECOVERY_ITEM_SIZE,
+            }
+        } else {
+            BatchDeleteApplyType::StatefulBatchDelete {
+                is_known_to_be_subtree_with_sum: Some((false, false)),
+            }
+        };
+
+        let mut drive_operations = vec![];
+        let identity_path = identity_path(identity_id.as_slice());
+        self.batch_delete(
+            (&identity_path).into(),
+            Into::<&[u8; 1]>::into(IdentityRootStructure::IdentityPendingRecovery),
+            apply_type,
+            transaction,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+        Ok(drive_operations)
+    }
+}
@@ -0,0 +1,53 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::{drive::DriveError, Error};
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::identity::recovery::PendingIdentityRecovery;
+
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerInformation;
+use std::collections::HashMap;
+
+impl Drive {
+    /// The operations for storing a recovery approved by the guardians.
+    ///
+    /// State validation guarantees that no other recovery is pending for the identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity_id` - The identity being recovered.
+    /// * `pending_recovery` - The approved recovery, waiting for its time lock.
+    /// * `estimated_costs_only_with_layer_info` - If set, only the costs are estimated.
+    /// * `platform_version` - A reference to the platform version.
+    pub fn set_identity_pending_recovery_operations(
+        &self,
+        identity_id: [u8; 32],
+        pending_recovery: PendingIdentityRecovery,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .recovery
+            .set_identity_pending_recovery
+        {
+            0 => self.set_identity_pending_recovery_operations_v0(
+                identity_id,
+                pending_recovery,
+                estimated_costs_only_with_layer_info,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "set_identity_pending_recovery_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::drive::identity::{identity_path_vec, IdentityRootStructure};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::identity::recovery::PendingIdentityRecovery;
+use dpp::serialization::PlatformSerializable;
+
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{Element, EstimatedLayerInformation};
+use std::collections::HashMap;
+
+impl Drive {
+    pub(super) fn set_identity_pending_recovery_operations_v0(
+        &self,
+        identity_id: [u8; 32],
+        pending_recovery: PendingIdentityRecovery,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_identity_recovery(
+                identity_id,
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+        }
+
+        Ok(vec![
+            LowLevelDriveOperation::insert_for_known_path_key_element(
+                identity_path_vec(identity_id.as_slice()),
+                Into::<&[u8; 1]>::into(IdentityRootStructure::IdentityPendingRecovery).to_vec(),
+                Element::new_item(pending_recovery.serialize_consume_to_bytes()?),
+            ),
+        ])
+    }
+}
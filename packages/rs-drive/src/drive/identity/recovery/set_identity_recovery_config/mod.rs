@@ -0,0 +1,55 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::{drive::DriveError, Error};
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::identity::recovery::IdentityRecoveryConfig;
+
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// The operations for setting, replacing or removing the social recovery configuration
+    /// of an identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity_id` - The identity opting in or out of social recovery.
+    /// * `config` - The new configuration, `None` removes the existing one.
+    /// * `estimated_costs_only_with_layer_info` - If set, only the costs are estimated.
+    /// * `transaction` - Transaction arguments.
+    /// * `platform_version` - A reference to the platform version.
+    pub fn set_identity_recovery_config_operations(
+        &self,
+        identity_id: [u8; 32],
+        config: Option<IdentityRecoveryConfig>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .recovery
+            .set_identity_recovery_config
+        {
+            0 => self.set_identity_recovery_config_operations_v0(
+                identity_id,
+                config,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "set_identity_recovery_config_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
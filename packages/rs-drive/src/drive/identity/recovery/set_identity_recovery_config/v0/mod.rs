@@ -0,0 +1,98 @@
+use crate::drive::identity::recovery::ESTIMATED_RECOVERY_ITEM_SIZE;
+use crate::drive::identity::{identity_path, identity_path_vec, IdentityRootStructure};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::BatchDeleteApplyType;
+use dpp::identity::recovery::IdentityRecoveryConfig;
+use dpp::serialization::PlatformSerializable;
+
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{Element, EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    pub(super) fn set_identity_recovery_config_operations_v0(
+        &self,
+        identity_id: [u8; 32],
+        config: Option<IdentityRecoveryConfig>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_identity_recovery(
+                identity_id,
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+        }
+
+        let key = Into::<&[u8; 1]>::into(IdentityRootStructure::IdentityRecoveryConfig);
+
+        let mut drive_operations = vec![];
+
+        let existing_config = if estimated_costs_only_with_layer_info.is_none() {
+            self.fetch_identity_recovery_config(
+                identity_id,
+                transaction,
+                &mut drive_operations,
+                platform_version,
+            )?
+        } else {
+            None
+        };
+
+        match config {
+            Some(config) => {
+                let element = Element::new_item(config.serialize_consume_to_bytes()?);
+                if existing_config.is_some() {
+                    drive_operations.push(
+                        LowLevelDriveOperation::replace_for_known_path_key_element(
+                            identity_path_vec(identity_id.as_slice()),
+                            key.to_vec(),
+                            element,
+                        ),
+                    );
+                } else {
+                    drive_operations.push(
+                        LowLevelDriveOperation::insert_for_known_path_key_element(
+                            identity_path_vec(identity_id.as_slice()),
+                            key.to_vec(),
+                            element,
+                        ),
+                    );
+                }
+            }
+            None => {
+                if existing_config.is_some() || estimated_costs_only_with_layer_info.is_some() {
+                    let apply_type = if estimated_costs_only_with_layer_info.is_some() {
+                        BatchDeleteApplyType::StatelessBatchDelete {
+                            is_sum_tree: false,
+                            estimated_key_size: 1,
+                            estimated_value_size: ESTIMATED_RECOVERY_ITEM_SIZE,
+                        }
+                    } else {
+                        BatchDeleteApplyType::StatefulBatchDelete {
+                            is_known_to_be_subtree_with_sum: Some((false, false)),
+                        }
+                    };
+                    let identity_path = identity_path(identity_id.as_slice());
+                    self.batch_delete(
+                        (&identity_path).into(),
+                        key,
+                        apply_type,
+                        transaction,
+                        &mut drive_operations,
+                        &platform_version.drive,
+                    )?;
+                }
+            }
+        }
+
+        Ok(drive_operations)
+    }
+}
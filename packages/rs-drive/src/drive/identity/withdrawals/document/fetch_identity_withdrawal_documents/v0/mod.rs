@@ -0,0 +1,187 @@
+use crate::drive::document::query::QueryDocumentsOutcomeV0Methods;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contracts::withdrawals_contract;
+use dpp::data_contracts::withdrawals_contract::v1::document_types::withdrawal;
+use dpp::document::Document;
+use dpp::identifier::Identifier;
+use grovedb::TransactionArg;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    pub(super) fn fetch_identity_withdrawal_documents_v0(
+        &self,
+        identity_id: Identifier,
+        limit: Option<u16>,
+        start_after: Option<Identifier>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<Document>, Error> {
+        let contract_fetch_info = self
+            .get_contract_with_fetch_info_and_fee(
+                withdrawals_contract::ID.to_buffer(),
+                None,
+                true,
+                transaction,
+                platform_version,
+            )?
+            .1
+            .ok_or_else(|| {
+                Error::Drive(DriveError::CorruptedCodeExecution(
+                    "Can't fetch data contract",
+                ))
+            })?;
+
+        let document_type = contract_fetch_info
+            .contract
+            .document_type_for_name(withdrawal::NAME)?;
+
+        let drive_query = Self::identity_withdrawal_documents_query(
+            &contract_fetch_info.contract,
+            document_type,
+            identity_id,
+            limit,
+            start_after,
+        );
+
+        let outcome = self.query_documents(
+            drive_query,
+            None,
+            false,
+            transaction,
+            Some(platform_version.protocol_version),
+        )?;
+
+        Ok(outcome.documents_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::test_helpers::setup::{
+        setup_document, setup_drive_with_initial_state_structure, setup_system_data_contract,
+    };
+    use dpp::document::{DocumentV0Getters, DocumentV0Setters};
+    use dpp::identity::core_script::CoreScript;
+    use dpp::platform_value::platform_value;
+    use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+    use dpp::tests::fixtures::get_withdrawal_document_fixture;
+    use dpp::withdrawal::Pooling;
+
+    #[test]
+    fn test_return_identity_withdrawals_most_recent_first() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let transaction = drive.grove.start_transaction();
+
+        let platform_version = PlatformVersion::latest();
+
+        let data_contract =
+            load_system_data_contract(SystemDataContract::Withdrawals, platform_version)
+                .expect("to load system data contract");
+
+        setup_system_data_contract(&drive, &data_contract, Some(&transaction));
+
+        let owner_id = Identifier::new([1u8; 32]);
+
+        let documents = drive
+            .fetch_identity_withdrawal_documents(
+                owner_id,
+                None,
+                None,
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("to fetch identity withdrawals");
+
+        assert!(documents.is_empty());
+
+        let document_type = data_contract
+            .document_type_for_name(withdrawal::NAME)
+            .expect("expected to get document type");
+
+        let mut inserted_ids = vec![];
+
+        for (i, (document_owner_id, status)) in [
+            (owner_id, withdrawals_contract::WithdrawalStatus::COMPLETE),
+            (
+                Identifier::new([2u8; 32]),
+                withdrawals_contract::WithdrawalStatus::QUEUED,
+            ),
+            (
+                owner_id,
+                withdrawals_contract::WithdrawalStatus::BROADCASTED,
+            ),
+            (owner_id, withdrawals_contract::WithdrawalStatus::QUEUED),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let mut document = get_withdrawal_document_fixture(
+                &data_contract,
+                document_owner_id,
+                platform_value!({
+                    "amount": 1000u64,
+                    "coreFeePerByte": 1u32,
+                    "pooling": Pooling::Never as u8,
+                    "outputScript": CoreScript::from_bytes((0..23).collect::<Vec<u8>>()),
+                    "status": status as u8,
+                    "transactionIndex": i as u64,
+                }),
+                None,
+                platform_version.protocol_version,
+            )
+            .expect("expected withdrawal document");
+
+            document.set_created_at(Some(1_000 + i as u64));
+            document.set_updated_at(Some(1_000 + i as u64));
+
+            setup_document(
+                &drive,
+                &document,
+                &data_contract,
+                document_type,
+                Some(&transaction),
+            );
+
+            if document_owner_id == owner_id {
+                inserted_ids.push(document.id());
+            }
+        }
+
+        let documents = drive
+            .fetch_identity_withdrawal_documents(
+                owner_id,
+                None,
+                None,
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("to fetch identity withdrawals");
+
+        let expected_ids: Vec<_> = inserted_ids.iter().rev().copied().collect();
+
+        assert_eq!(
+            documents.iter().map(|d| d.id()).collect::<Vec<_>>(),
+            expected_ids
+        );
+
+        let documents = drive
+            .fetch_identity_withdrawal_documents(
+                owner_id,
+                Some(1),
+                Some(expected_ids[0]),
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("to fetch identity withdrawals");
+
+        assert_eq!(
+            documents.iter().map(|d| d.id()).collect::<Vec<_>>(),
+            vec![expected_ids[1]]
+        );
+    }
+}
@@ -14,9 +14,8 @@ use platform_version::version::PlatformVersion;
 use std::collections::BTreeMap;
 
 impl Drive {
-    // TODO(withdrawals): Currently it queries only up to 100 documents.
-    //  It works while we don't have pooling
-
+    // Pooled transactions have several documents, so callers must query few enough
+    // transaction indices for all of their documents to fit within `limit`
     pub(super) fn find_withdrawal_documents_by_status_and_transaction_indices_v0(
         &self,
         status: withdrawals_contract::WithdrawalStatus,
@@ -1,4 +1,82 @@
+#[cfg(feature = "server")]
+/// This module dedicated for a versioned fetch_identity_withdrawal_documents
+pub mod fetch_identity_withdrawal_documents;
+#[cfg(feature = "server")]
 /// This module dedicated for a versioned fetch_up_to_100_oldest_withdrawal_documents_by_status
 pub mod fetch_oldest_withdrawal_documents_by_status;
+#[cfg(feature = "server")]
 /// This module dedicated for a versioned find_up_to_100_withdrawal_documents_by_status_and_transaction_indices
 pub mod find_withdrawal_documents_by_status_and_transaction_indices;
+#[cfg(feature = "server")]
+/// This module dedicated for a versioned prove_identity_withdrawal_documents
+pub mod prove_identity_withdrawal_documents;
+
+use crate::drive::Drive;
+use crate::query::{DriveDocumentQuery, InternalClauses, OrderClause, WhereClause, WhereOperator};
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::data_contract::DataContract;
+use dpp::document::property_names;
+use dpp::identifier::Identifier;
+use dpp::platform_value::Value;
+use indexmap::IndexMap;
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// The document query for the withdrawals of an identity, most recently updated first.
+    ///
+    /// It is shared by the server, to fetch and prove the withdrawals, and by clients, to verify
+    /// the proof, so both sides build exactly the same query.
+    ///
+    /// # Arguments
+    ///
+    /// * `withdrawals_contract` - The withdrawals system data contract.
+    /// * `withdrawal_document_type` - The withdrawal document type of that contract.
+    /// * `limit` - The maximum number of withdrawals to query.
+    /// * `start_after` - The withdrawal document id after which withdrawals are queried, for paging.
+    pub fn identity_withdrawal_documents_query<'a>(
+        withdrawals_contract: &'a DataContract,
+        withdrawal_document_type: DocumentTypeRef<'a>,
+        identity_id: Identifier,
+        limit: Option<u16>,
+        start_after: Option<Identifier>,
+    ) -> DriveDocumentQuery<'a> {
+        let mut where_clauses = BTreeMap::new();
+
+        where_clauses.insert(
+            property_names::OWNER_ID.to_string(),
+            WhereClause {
+                field: property_names::OWNER_ID.to_string(),
+                operator: WhereOperator::Equal,
+                value: Value::Identifier(identity_id.to_buffer()),
+            },
+        );
+
+        let mut order_by = IndexMap::new();
+
+        order_by.insert(
+            property_names::UPDATED_AT.to_string(),
+            OrderClause {
+                field: property_names::UPDATED_AT.to_string(),
+                ascending: false,
+            },
+        );
+
+        DriveDocumentQuery {
+            contract: withdrawals_contract,
+            document_type: withdrawal_document_type,
+            internal_clauses: InternalClauses {
+                primary_key_in_clause: None,
+                primary_key_equal_clause: None,
+                in_clause: None,
+                range_clause: None,
+                equal_clauses: where_clauses,
+            },
+            offset: None,
+            limit,
+            order_by,
+            start_at: start_after.map(|document_id| document_id.to_buffer()),
+            start_at_included: false,
+            block_time_ms: None,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::identifier::Identifier;
+use grovedb::TransactionArg;
+use platform_version::version::PlatformVersion;
+
+mod v0;
+
+impl Drive {
+    /// Prove the withdrawal documents of an identity ordered by updated_at descending
+    pub fn prove_identity_withdrawal_documents(
+        &self,
+        identity_id: Identifier,
+        limit: Option<u16>,
+        start_after: Option<Identifier>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .withdrawals
+            .document
+            .prove_identity_withdrawal_documents
+        {
+            0 => self.prove_identity_withdrawal_documents_v0(
+                identity_id,
+                limit,
+                start_after,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "prove_identity_withdrawal_documents".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
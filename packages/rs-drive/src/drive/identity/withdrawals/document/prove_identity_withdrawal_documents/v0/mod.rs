@@ -0,0 +1,52 @@
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contracts::withdrawals_contract;
+use dpp::data_contracts::withdrawals_contract::v1::document_types::withdrawal;
+use dpp::identifier::Identifier;
+use grovedb::TransactionArg;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    pub(super) fn prove_identity_withdrawal_documents_v0(
+        &self,
+        identity_id: Identifier,
+        limit: Option<u16>,
+        start_after: Option<Identifier>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let contract_fetch_info = self
+            .get_contract_with_fetch_info_and_fee(
+                withdrawals_contract::ID.to_buffer(),
+                None,
+                true,
+                transaction,
+                platform_version,
+            )?
+            .1
+            .ok_or_else(|| {
+                Error::Drive(DriveError::CorruptedCodeExecution(
+                    "Can't fetch data contract",
+                ))
+            })?;
+
+        let document_type = contract_fetch_info
+            .contract
+            .document_type_for_name(withdrawal::NAME)?;
+
+        let drive_query = Self::identity_withdrawal_documents_query(
+            &contract_fetch_info.contract,
+            document_type,
+            identity_id,
+            limit,
+            start_after,
+        );
+
+        let (proof, _) =
+            drive_query.execute_with_proof(self, None, transaction, platform_version)?;
+
+        Ok(proof)
+    }
+}
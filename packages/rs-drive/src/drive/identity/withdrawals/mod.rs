@@ -1,12 +1,16 @@
 /// Functions related to withdrawal documents
 pub mod document;
 
+#[cfg(feature = "server")]
 /// Functions and constants related to GroveDB paths
 pub mod paths;
+#[cfg(feature = "server")]
 /// Functions related to withdrawal transactions
 pub mod transaction;
 
+#[cfg(feature = "server")]
 /// Simple type alias for withdrawal transaction with it's index
 pub type WithdrawalTransactionIndexAndBytes = (WithdrawalTransactionIndex, Vec<u8>);
+#[cfg(feature = "server")]
 /// Transaction index type
 pub type WithdrawalTransactionIndex = u64;
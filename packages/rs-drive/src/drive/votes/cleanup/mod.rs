@@ -1,6 +1,8 @@
 mod remove_all_votes_given_by_identities;
+mod remove_contested_resource_vote_poll_auction_bids_operations;
 mod remove_contested_resource_vote_poll_contenders_operations;
 mod remove_contested_resource_vote_poll_documents_operations;
 mod remove_contested_resource_vote_poll_end_date_query_operations;
 mod remove_contested_resource_vote_poll_votes_operations;
+mod remove_governance_proposal_vote_poll_operations;
 mod remove_specific_votes_given_by_identity;
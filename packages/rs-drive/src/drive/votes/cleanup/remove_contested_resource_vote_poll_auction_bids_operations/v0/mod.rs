@@ -0,0 +1,67 @@
+use crate::drive::votes::paths::{VotePollPaths, CONTESTED_RESOURCE_AUCTION_BID_KEY};
+use crate::drive::votes::resolved::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePollWithContractInfo;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::{BatchDeleteApplyType, DirectQueryType};
+use dpp::identifier::Identifier;
+use dpp::identity::TimestampMillis;
+use dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use grovedb::TransactionArg;
+use platform_version::version::PlatformVersion;
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// Removes the sealed bids of the contenders of vote polls resolved by an auction
+    pub(in crate::drive::votes) fn remove_contested_resource_vote_poll_auction_bids_operations_v0(
+        &self,
+        vote_polls: &[(
+            &ContestedDocumentResourceVotePollWithContractInfo,
+            &TimestampMillis,
+            &BTreeMap<ResourceVoteChoice, Vec<Identifier>>,
+        )],
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        for (vote_poll, _, vote_choices) in vote_polls {
+            if !vote_poll.is_resolved_by_auction()? {
+                continue;
+            }
+
+            for resource_vote_choice in vote_choices.keys() {
+                if !matches!(resource_vote_choice, ResourceVoteChoice::TowardsIdentity(_)) {
+                    continue;
+                }
+
+                let contender_path =
+                    vote_poll.contender_path(resource_vote_choice, platform_version)?;
+
+                // Contenders that never bid have nothing to remove
+                let has_bid = self.grove_has_raw(
+                    contender_path.as_slice().into(),
+                    &[CONTESTED_RESOURCE_AUCTION_BID_KEY],
+                    DirectQueryType::StatefulDirectQuery,
+                    transaction,
+                    &mut vec![],
+                    &platform_version.drive,
+                )?;
+
+                if has_bid {
+                    self.batch_delete(
+                        contender_path.as_slice().into(),
+                        &[CONTESTED_RESOURCE_AUCTION_BID_KEY],
+                        BatchDeleteApplyType::StatefulBatchDelete {
+                            is_known_to_be_subtree_with_sum: Some((false, false)),
+                        },
+                        transaction,
+                        batch_operations,
+                        &platform_version.drive,
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+mod v0;
+
+use crate::drive::Drive;
+use std::collections::BTreeMap;
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use dpp::voting::vote_polls::governance_proposal_vote_poll::GovernanceProposalVotePoll;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Removes the votes of ended governance proposal vote polls and their end date queries.
+    /// The stored info of the vote polls is kept, as it holds the result of the vote.
+    pub fn remove_governance_proposal_vote_poll_operations(
+        &self,
+        vote_polls: &[(
+            &GovernanceProposalVotePoll,
+            &BTreeMap<Identifier, YesNoAbstainVoteChoice>,
+        )],
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .governance_proposal
+            .remove_governance_proposal_vote_poll_operations
+        {
+            0 => self.remove_governance_proposal_vote_poll_operations_v0(
+                vote_polls,
+                batch_operations,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "remove_governance_proposal_vote_poll_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::drive::votes::paths::{
+    vote_contested_resource_end_date_queries_at_time_tree_path_vec,
+    vote_decisions_vote_poll_path_vec, vote_decisions_vote_poll_voters_path_vec,
+    GOVERNANCE_PROPOSAL_VOTERS_TREE_KEY,
+};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::{BatchDeleteApplyType, BatchDeleteUpTreeApplyType};
+use dpp::identifier::Identifier;
+use dpp::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use dpp::voting::vote_polls::governance_proposal_vote_poll::GovernanceProposalVotePoll;
+use grovedb::batch::KeyInfoPath;
+use grovedb::TransactionArg;
+use platform_version::version::PlatformVersion;
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// Removes the votes of ended governance proposal vote polls and their end date queries.
+    pub(in crate::drive::votes) fn remove_governance_proposal_vote_poll_operations_v0(
+        &self,
+        vote_polls: &[(
+            &GovernanceProposalVotePoll,
+            &BTreeMap<Identifier, YesNoAbstainVoteChoice>,
+        )],
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let delete_apply_type = BatchDeleteApplyType::StatefulBatchDelete {
+            is_known_to_be_subtree_with_sum: Some((false, false)),
+        };
+
+        for (vote_poll, votes) in vote_polls {
+            let unique_id = vote_poll.unique_id()?;
+
+            let voters_path = vote_decisions_vote_poll_voters_path_vec(unique_id.as_slice());
+
+            for voter in votes.keys() {
+                self.batch_delete(
+                    voters_path.as_slice().into(),
+                    voter.as_slice(),
+                    delete_apply_type,
+                    transaction,
+                    batch_operations,
+                    &platform_version.drive,
+                )?;
+            }
+
+            let vote_poll_path = vote_decisions_vote_poll_path_vec(unique_id.as_slice());
+
+            self.batch_delete(
+                vote_poll_path.as_slice().into(),
+                &[GOVERNANCE_PROPOSAL_VOTERS_TREE_KEY],
+                delete_apply_type,
+                transaction,
+                batch_operations,
+                &platform_version.drive,
+            )?;
+
+            let time_path =
+                vote_contested_resource_end_date_queries_at_time_tree_path_vec(vote_poll.end_date);
+
+            self.batch_delete_up_tree_while_empty(
+                KeyInfoPath::from_known_owned_path(time_path),
+                unique_id.as_bytes(),
+                Some(2),
+                BatchDeleteUpTreeApplyType::StatefulBatchDelete {
+                    is_known_to_be_subtree_with_sum: Some((false, false)),
+                },
+                transaction,
+                &None,
+                batch_operations,
+                &platform_version.drive,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use crate::drive::votes::resolved::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePollWithContractInfo;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_info_storage::contested_document_auction_bid_stored_info::ContestedDocumentAuctionBidStoredInfo;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the sealed bid a contender placed in an auction, if they placed one.
+    pub fn fetch_auction_bid(
+        &self,
+        vote_poll: &ContestedDocumentResourceVotePollWithContractInfo,
+        bidder_id: Identifier,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<ContestedDocumentAuctionBidStoredInfo>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .auction
+            .fetch_auction_bid
+        {
+            0 => self.fetch_auction_bid_v0(vote_poll, bidder_id, transaction, platform_version),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_auction_bid".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::drive::votes::paths::{VotePollPaths, CONTESTED_RESOURCE_AUCTION_BID_KEY};
+use crate::drive::votes::resolved::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePollWithContractInfo;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::util::grove_operations::DirectQueryType;
+use dpp::identifier::Identifier;
+use dpp::serialization::PlatformDeserializable;
+use dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dpp::voting::vote_info_storage::contested_document_auction_bid_stored_info::ContestedDocumentAuctionBidStoredInfo;
+use grovedb::TransactionArg;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    /// Fetches the sealed bid a contender placed in an auction
+    pub(super) fn fetch_auction_bid_v0(
+        &self,
+        vote_poll: &ContestedDocumentResourceVotePollWithContractInfo,
+        bidder_id: Identifier,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<ContestedDocumentAuctionBidStoredInfo>, Error> {
+        let contender_path = vote_poll.contender_path(
+            &ResourceVoteChoice::TowardsIdentity(bidder_id),
+            platform_version,
+        )?;
+
+        let maybe_element = self.grove_get_raw_optional(
+            contender_path.as_slice().into(),
+            &[CONTESTED_RESOURCE_AUCTION_BID_KEY],
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            &mut vec![],
+            &platform_version.drive,
+        )?;
+
+        let Some(element) = maybe_element else {
+            return Ok(None);
+        };
+
+        let stored_bid_bytes = element.into_item_bytes()?;
+        let stored_bid =
+            ContestedDocumentAuctionBidStoredInfo::deserialize_from_bytes(&stored_bid_bytes)?;
+        Ok(Some(stored_bid))
+    }
+}
@@ -0,0 +1,40 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_info_storage::governance_proposal_vote_poll_stored_info::GovernanceProposalVotePollStoredInfo;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the stored info of a governance proposal vote poll.
+    pub fn fetch_governance_proposal_vote_poll_stored_info(
+        &self,
+        vote_poll_id: Identifier,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<GovernanceProposalVotePollStoredInfo>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .governance_proposal
+            .fetch_governance_proposal_vote_poll_stored_info
+        {
+            0 => self.fetch_governance_proposal_vote_poll_stored_info_v0(
+                vote_poll_id,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_governance_proposal_vote_poll_stored_info".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
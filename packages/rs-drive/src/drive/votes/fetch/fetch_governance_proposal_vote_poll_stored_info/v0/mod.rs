@@ -0,0 +1,42 @@
+use crate::drive::votes::paths::{
+    vote_decisions_vote_poll_path, GOVERNANCE_PROPOSAL_STORED_INFO_KEY,
+};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::util::grove_operations::DirectQueryType;
+use dpp::identifier::Identifier;
+use dpp::serialization::PlatformDeserializable;
+use dpp::voting::vote_info_storage::governance_proposal_vote_poll_stored_info::GovernanceProposalVotePollStoredInfo;
+use grovedb::TransactionArg;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    /// Fetches the governance proposal vote poll stored info
+    pub(super) fn fetch_governance_proposal_vote_poll_stored_info_v0(
+        &self,
+        vote_poll_id: Identifier,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<GovernanceProposalVotePollStoredInfo>, Error> {
+        let path = vote_decisions_vote_poll_path(vote_poll_id.as_slice());
+        let maybe_element = self.grove_get_raw_optional(
+            (&path).into(),
+            &[GOVERNANCE_PROPOSAL_STORED_INFO_KEY],
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            &mut vec![],
+            &platform_version.drive,
+        )?;
+
+        maybe_element
+            .map(|element| {
+                let stored_info_bytes = element.into_item_bytes()?;
+                Ok(
+                    GovernanceProposalVotePollStoredInfo::deserialize_from_bytes(
+                        &stored_info_bytes,
+                    )?,
+                )
+            })
+            .transpose()
+    }
+}
@@ -0,0 +1,45 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::block::epoch::EpochIndex;
+use dpp::platform_value::Identifier;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches how many governance proposal vote polls an evonode opened during an epoch.
+    pub fn fetch_governance_proposal_vote_polls_opened_in_epoch(
+        &self,
+        masternode_pro_tx_hash: Identifier,
+        epoch_index: EpochIndex,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<u16, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .governance_proposal
+            .fetch_governance_proposal_vote_polls_opened_in_epoch
+        {
+            0 => self.fetch_governance_proposal_vote_polls_opened_in_epoch_v0(
+                masternode_pro_tx_hash,
+                epoch_index,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_governance_proposal_vote_polls_opened_in_epoch".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::drive::votes::paths::vote_decisions_vote_polls_opened_path;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::DirectQueryType;
+use dpp::block::epoch::EpochIndex;
+use dpp::identifier::Identifier;
+use grovedb::TransactionArg;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    /// Fetches how many governance proposal vote polls an evonode opened during an epoch.
+    pub(super) fn fetch_governance_proposal_vote_polls_opened_in_epoch_v0(
+        &self,
+        masternode_pro_tx_hash: Identifier,
+        epoch_index: EpochIndex,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<u16, Error> {
+        let path = vote_decisions_vote_polls_opened_path();
+
+        let element = match self.grove_get_raw_optional(
+            (&path).into(),
+            masternode_pro_tx_hash.as_slice(),
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        ) {
+            Ok(element) => element,
+            // The tree is only created once the first governance proposal vote poll is opened
+            Err(Error::GroveDB(grovedb::Error::PathNotFound(_)))
+            | Err(Error::GroveDB(grovedb::Error::PathParentLayerNotFound(_))) => None,
+            Err(e) => return Err(e),
+        };
+
+        let Some(element) = element else {
+            return Ok(0);
+        };
+
+        let bytes = element.into_item_bytes()?;
+
+        // The big endian epoch index of the last opening followed by the big endian number of
+        // vote polls opened in that epoch
+        let [epoch_0, epoch_1, count_0, count_1] = bytes.as_slice() else {
+            return Err(Error::Drive(DriveError::CorruptedSerialization(format!(
+                "vote polls opened by {} are not stored on 4 bytes",
+                masternode_pro_tx_hash
+            ))));
+        };
+
+        if EpochIndex::from_be_bytes([*epoch_0, *epoch_1]) != epoch_index {
+            return Ok(0);
+        }
+
+        Ok(u16::from_be_bytes([*count_0, *count_1]))
+    }
+}
@@ -0,0 +1,39 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use grovedb::TransactionArg;
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// Fetches the votes cast on a governance proposal, keyed by the ProRegTx hash of the voter.
+    pub fn fetch_governance_proposal_votes(
+        &self,
+        vote_poll_id: Identifier,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<Identifier, YesNoAbstainVoteChoice>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .governance_proposal
+            .fetch_governance_proposal_votes
+        {
+            0 => {
+                self.fetch_governance_proposal_votes_v0(vote_poll_id, transaction, platform_version)
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_governance_proposal_votes".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::drive::votes::paths::vote_decisions_vote_poll_voters_path_vec;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::identifier::Identifier;
+use dpp::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::{PathQuery, Query, QueryItem, SizedQuery, TransactionArg};
+use platform_version::version::PlatformVersion;
+use std::collections::BTreeMap;
+use std::ops::RangeFull;
+
+impl Drive {
+    /// Fetches the votes cast on a governance proposal.
+    pub(super) fn fetch_governance_proposal_votes_v0(
+        &self,
+        vote_poll_id: Identifier,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<Identifier, YesNoAbstainVoteChoice>, Error> {
+        let path_query = PathQuery {
+            path: vote_decisions_vote_poll_voters_path_vec(vote_poll_id.as_slice()),
+            query: SizedQuery {
+                query: Query::new_single_query_item(QueryItem::RangeFull(RangeFull)),
+                limit: None,
+                offset: None,
+            },
+        };
+
+        self.grove_get_path_query(
+            &path_query,
+            transaction,
+            QueryResultType::QueryKeyElementPairResultType,
+            &mut vec![],
+            &platform_version.drive,
+        )?
+        .0
+        .to_key_elements()
+        .into_iter()
+        .map(|(voter, element)| {
+            let voter = Identifier::from_bytes(&voter)?;
+            let vote_choice_bytes = element.into_item_bytes()?;
+            let [vote_choice] = vote_choice_bytes.as_slice() else {
+                return Err(Error::Drive(DriveError::CorruptedSerialization(format!(
+                    "vote of {} on governance proposal {} is not a single byte",
+                    voter, vote_poll_id
+                ))));
+            };
+            Ok((voter, YesNoAbstainVoteChoice::try_from(*vote_choice)?))
+        })
+        .collect()
+    }
+}
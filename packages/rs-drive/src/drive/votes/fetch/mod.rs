@@ -1,3 +1,7 @@
+mod fetch_auction_bid;
 mod fetch_contested_document_vote_poll_stored_info;
+mod fetch_governance_proposal_vote_poll_stored_info;
+mod fetch_governance_proposal_vote_polls_opened_in_epoch;
+mod fetch_governance_proposal_votes;
 mod fetch_identities_voting_for_contenders;
 mod fetch_identity_contested_resource_vote;
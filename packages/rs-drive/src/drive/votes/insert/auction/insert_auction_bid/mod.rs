@@ -0,0 +1,44 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use crate::drive::votes::resolved::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePollWithContractInfo;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_info_storage::contested_document_auction_bid_stored_info::ContestedDocumentAuctionBidStoredInfo;
+
+impl Drive {
+    /// Returns the operations of storing the sealed bid of a contender in an auction, this is
+    /// used both when the bid is committed and when it is revealed.
+    pub fn insert_auction_bid_operations(
+        &self,
+        vote_poll: &ContestedDocumentResourceVotePollWithContractInfo,
+        bidder_id: Identifier,
+        stored_bid: ContestedDocumentAuctionBidStoredInfo,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .auction
+            .insert_auction_bid_operations
+        {
+            0 => self.insert_auction_bid_operations_v0(
+                vote_poll,
+                bidder_id,
+                stored_bid,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "insert_auction_bid_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
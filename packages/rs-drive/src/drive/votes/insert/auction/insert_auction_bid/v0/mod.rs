@@ -0,0 +1,42 @@
+use crate::drive::votes::paths::{VotePollPaths, CONTESTED_RESOURCE_AUCTION_BID_KEY};
+use crate::drive::votes::resolved::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePollWithContractInfo;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::object_size_info::PathKeyElementInfo::PathKeyElement;
+use dpp::identifier::Identifier;
+use dpp::serialization::PlatformSerializable;
+use dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dpp::voting::vote_info_storage::contested_document_auction_bid_stored_info::ContestedDocumentAuctionBidStoredInfo;
+use grovedb::Element;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    pub(super) fn insert_auction_bid_operations_v0(
+        &self,
+        vote_poll: &ContestedDocumentResourceVotePollWithContractInfo,
+        bidder_id: Identifier,
+        stored_bid: ContestedDocumentAuctionBidStoredInfo,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        let serialization = stored_bid.serialize_consume_to_bytes()?;
+        let contender_path = vote_poll.contender_path(
+            &ResourceVoteChoice::TowardsIdentity(bidder_id),
+            platform_version,
+        )?;
+
+        self.batch_insert::<0>(
+            PathKeyElement((
+                contender_path,
+                vec![CONTESTED_RESOURCE_AUCTION_BID_KEY],
+                Element::new_item(serialization),
+            )),
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        Ok(drive_operations)
+    }
+}
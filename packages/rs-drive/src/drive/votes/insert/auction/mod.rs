@@ -0,0 +1 @@
+mod insert_auction_bid;
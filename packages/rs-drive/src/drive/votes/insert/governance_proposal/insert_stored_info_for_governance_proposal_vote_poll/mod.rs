@@ -0,0 +1,71 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_info_storage::governance_proposal_vote_poll_stored_info::GovernanceProposalVotePollStoredInfo;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Inserts or replaces the stored info of a governance proposal vote poll
+    pub fn insert_stored_info_for_governance_proposal_vote_poll(
+        &self,
+        vote_poll_id: Identifier,
+        governance_proposal_vote_poll_stored_info: GovernanceProposalVotePollStoredInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .governance_proposal
+            .insert_stored_info_for_governance_proposal_vote_poll
+        {
+            0 => self.insert_stored_info_for_governance_proposal_vote_poll_v0(
+                vote_poll_id,
+                governance_proposal_vote_poll_stored_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "insert_stored_info_for_governance_proposal_vote_poll".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Returns the operations of inserting or replacing the stored info of a governance proposal vote poll
+    pub fn insert_stored_info_for_governance_proposal_vote_poll_operations(
+        &self,
+        vote_poll_id: Identifier,
+        governance_proposal_vote_poll_stored_info: GovernanceProposalVotePollStoredInfo,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .governance_proposal
+            .insert_stored_info_for_governance_proposal_vote_poll
+        {
+            0 => self.insert_stored_info_for_governance_proposal_vote_poll_operations_v0(
+                vote_poll_id,
+                governance_proposal_vote_poll_stored_info,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "insert_stored_info_for_governance_proposal_vote_poll_operations"
+                    .to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
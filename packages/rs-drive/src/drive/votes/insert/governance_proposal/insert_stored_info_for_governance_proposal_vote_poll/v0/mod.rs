@@ -0,0 +1,64 @@
+use crate::drive::votes::paths::{
+    vote_decisions_vote_poll_path_vec, GOVERNANCE_PROPOSAL_STORED_INFO_KEY,
+};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::object_size_info::PathKeyElementInfo::PathKeyElement;
+use dpp::identifier::Identifier;
+use dpp::serialization::PlatformSerializable;
+use dpp::voting::vote_info_storage::governance_proposal_vote_poll_stored_info::GovernanceProposalVotePollStoredInfo;
+use grovedb::{Element, TransactionArg};
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    pub(super) fn insert_stored_info_for_governance_proposal_vote_poll_v0(
+        &self,
+        vote_poll_id: Identifier,
+        governance_proposal_vote_poll_stored_info: GovernanceProposalVotePollStoredInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let batch_operations = self
+            .insert_stored_info_for_governance_proposal_vote_poll_operations_v0(
+                vote_poll_id,
+                governance_proposal_vote_poll_stored_info,
+                platform_version,
+            )?;
+
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        self.apply_batch_low_level_drive_operations(
+            None,
+            transaction,
+            batch_operations,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        Ok(())
+    }
+
+    pub(super) fn insert_stored_info_for_governance_proposal_vote_poll_operations_v0(
+        &self,
+        vote_poll_id: Identifier,
+        governance_proposal_vote_poll_stored_info: GovernanceProposalVotePollStoredInfo,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        let serialization =
+            governance_proposal_vote_poll_stored_info.serialize_consume_to_bytes()?;
+
+        self.batch_insert::<0>(
+            PathKeyElement((
+                vote_decisions_vote_poll_path_vec(vote_poll_id.as_slice()),
+                vec![GOVERNANCE_PROPOSAL_STORED_INFO_KEY],
+                Element::new_item(serialization),
+            )),
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        Ok(drive_operations)
+    }
+}
@@ -0,0 +1,2 @@
+mod insert_stored_info_for_governance_proposal_vote_poll;
+mod register_governance_proposal_identity_vote;
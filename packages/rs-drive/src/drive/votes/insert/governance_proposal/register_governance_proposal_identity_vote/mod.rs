@@ -0,0 +1,108 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::fee::fee_result::FeeResult;
+
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::block::block_info::BlockInfo;
+use dpp::version::PlatformVersion;
+use dpp::voting::votes::governance_proposal_vote::GovernanceProposalVote;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Registers the vote of an evonode on a governance proposal.
+    ///
+    /// The first vote on a proposal starts its vote poll, a later vote of the same evonode
+    /// replaces its previous vote.
+    ///
+    /// # Parameters
+    ///
+    /// - `voter_pro_tx_hash`: A 32-byte array representing the ProRegTx hash of the voter.
+    /// - `vote`: The vote on the governance proposal.
+    /// - `block_info`: Reference to the block information at the time of the vote.
+    /// - `transaction`: Transaction arguments providing context for this operation.
+    /// - `platform_version`: Reference to the platform version against which the operation is executed.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` that, on success, includes the `FeeResult` detailing any fees applied as a result of the vote.
+    /// On failure, it returns an `Error`.
+    pub fn register_governance_proposal_identity_vote(
+        &self,
+        voter_pro_tx_hash: [u8; 32],
+        vote: GovernanceProposalVote,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .governance_proposal
+            .register_governance_proposal_identity_vote
+        {
+            0 => self.register_governance_proposal_identity_vote_v0(
+                voter_pro_tx_hash,
+                vote,
+                block_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "register_governance_proposal_identity_vote".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Gathers the low-level drive operations needed to register the vote of an evonode on a
+    /// governance proposal, starting the vote poll if this is the first vote on it.
+    ///
+    /// # Parameters
+    ///
+    /// - `voter_pro_tx_hash`: A 32-byte array representing the ProRegTx hash of the voter.
+    /// - `vote`: The vote on the governance proposal.
+    /// - `block_info`: Reference to the block information at the time of the vote.
+    /// - `transaction`: Transaction arguments providing context for this operation.
+    /// - `platform_version`: Reference to the platform version against which the operation is executed.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `LowLevelDriveOperation` detailing the necessary operations
+    /// to execute the vote registration, or an `Error` if the operation cannot be completed.
+    pub fn register_governance_proposal_identity_vote_operations(
+        &self,
+        voter_pro_tx_hash: [u8; 32],
+        vote: GovernanceProposalVote,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .governance_proposal
+            .register_governance_proposal_identity_vote
+        {
+            0 => self.register_governance_proposal_identity_vote_operations_v0(
+                voter_pro_tx_hash,
+                vote,
+                block_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "register_governance_proposal_identity_vote_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
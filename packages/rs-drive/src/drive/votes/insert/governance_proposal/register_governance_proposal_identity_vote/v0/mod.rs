@@ -0,0 +1,184 @@
+use crate::drive::votes::paths::{
+    vote_decisions_tree_path, vote_decisions_tree_path_vec, vote_decisions_vote_poll_path,
+    vote_decisions_vote_poll_voters_path_vec, vote_decisions_vote_polls_opened_path_vec,
+    GOVERNANCE_PROPOSAL_VOTERS_TREE_KEY, VOTE_DECISIONS_VOTE_POLLS_OPENED_TREE_KEY,
+};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::{BatchInsertTreeApplyType, DirectQueryType};
+use crate::util::object_size_info::PathKeyElementInfo::PathKeyElement;
+use crate::util::object_size_info::{DriveKeyInfo, PathKeyInfo};
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::identifier::Identifier;
+use dpp::voting::vote_info_storage::governance_proposal_vote_poll_stored_info::GovernanceProposalVotePollStoredInfo;
+use dpp::voting::vote_polls::VotePoll;
+use dpp::voting::votes::governance_proposal_vote::accessors::v0::GovernanceProposalVoteGettersV0;
+use dpp::voting::votes::governance_proposal_vote::GovernanceProposalVote;
+use grovedb::{Element, TransactionArg};
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    pub(super) fn register_governance_proposal_identity_vote_v0(
+        &self,
+        voter_pro_tx_hash: [u8; 32],
+        vote: GovernanceProposalVote,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        let batch_operations = self.register_governance_proposal_identity_vote_operations_v0(
+            voter_pro_tx_hash,
+            vote,
+            block_info,
+            transaction,
+            platform_version,
+        )?;
+
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        self.apply_batch_low_level_drive_operations(
+            None,
+            transaction,
+            batch_operations,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        let fees = Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            self.config.epochs_per_era,
+            platform_version,
+            None,
+        )?;
+        Ok(fees)
+    }
+
+    pub(super) fn register_governance_proposal_identity_vote_operations_v0(
+        &self,
+        voter_pro_tx_hash: [u8; 32],
+        vote: GovernanceProposalVote,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        // We do not have to do estimated costs because voting does not use them and is fixed cost
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        // The vote at this point will have been verified as valid by rs-drive-abci
+
+        let vote_choice = vote.vote_choice();
+        let vote_poll = vote.vote_poll_owned();
+        let vote_poll_id = vote_poll.unique_id()?;
+
+        let vote_decisions_path = vote_decisions_tree_path();
+
+        let vote_poll_exists = self.grove_has_raw(
+            (&vote_decisions_path).into(),
+            vote_poll_id.as_slice(),
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        if !vote_poll_exists {
+            // The first vote on a proposal starts its vote poll
+            self.batch_insert_empty_tree(
+                vote_decisions_path,
+                DriveKeyInfo::Key(vote_poll_id.to_vec()),
+                None,
+                &mut drive_operations,
+                &platform_version.drive,
+            )?;
+
+            self.batch_insert_empty_tree(
+                vote_decisions_vote_poll_path(vote_poll_id.as_slice()),
+                DriveKeyInfo::Key(vec![GOVERNANCE_PROPOSAL_VOTERS_TREE_KEY]),
+                None,
+                &mut drive_operations,
+                &platform_version.drive,
+            )?;
+
+            let end_date = vote_poll.end_date;
+
+            let stored_info = GovernanceProposalVotePollStoredInfo::new(
+                vote_poll.clone(),
+                *block_info,
+                platform_version,
+            )?;
+
+            drive_operations.append(
+                &mut self.insert_stored_info_for_governance_proposal_vote_poll_operations(
+                    vote_poll_id,
+                    stored_info,
+                    platform_version,
+                )?,
+            );
+
+            self.add_vote_poll_end_date_query_operations(
+                None,
+                VotePoll::GovernanceProposalVotePoll(vote_poll),
+                end_date,
+                block_info,
+                &mut None,
+                &mut None,
+                &mut drive_operations,
+                transaction,
+                platform_version,
+            )?;
+
+            // Evonodes can only open a limited number of vote polls per epoch, so we keep track
+            // of the vote polls opened by the voter in the current epoch
+            let vote_polls_opened = self.fetch_governance_proposal_vote_polls_opened_in_epoch(
+                Identifier::new(voter_pro_tx_hash),
+                block_info.epoch.index,
+                transaction,
+                &mut drive_operations,
+                platform_version,
+            )?;
+
+            self.batch_insert_empty_tree_if_not_exists(
+                PathKeyInfo::PathKey::<0>((
+                    vote_decisions_tree_path_vec(),
+                    vec![VOTE_DECISIONS_VOTE_POLLS_OPENED_TREE_KEY as u8],
+                )),
+                false,
+                None,
+                BatchInsertTreeApplyType::StatefulBatchInsertTree,
+                transaction,
+                &mut None,
+                &mut drive_operations,
+                &platform_version.drive,
+            )?;
+
+            let mut vote_polls_opened_bytes = block_info.epoch.index.to_be_bytes().to_vec();
+            vote_polls_opened_bytes.extend_from_slice(&(vote_polls_opened + 1).to_be_bytes());
+
+            self.batch_insert::<0>(
+                PathKeyElement((
+                    vote_decisions_vote_polls_opened_path_vec(),
+                    voter_pro_tx_hash.to_vec(),
+                    Element::new_item(vote_polls_opened_bytes),
+                )),
+                &mut drive_operations,
+                &platform_version.drive,
+            )?;
+        }
+
+        // A later vote of the same evonode replaces its previous vote
+        self.batch_insert::<0>(
+            PathKeyElement((
+                vote_decisions_vote_poll_voters_path_vec(vote_poll_id.as_slice()),
+                voter_pro_tx_hash.to_vec(),
+                Element::new_item(vec![vote_choice.into()]),
+            )),
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        Ok(drive_operations)
+    }
+}
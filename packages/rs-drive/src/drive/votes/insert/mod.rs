@@ -1,3 +1,5 @@
+mod auction;
 mod contested_resource;
+mod governance_proposal;
 mod register_identity_vote;
 mod vote_poll;
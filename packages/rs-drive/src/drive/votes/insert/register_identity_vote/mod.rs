@@ -105,6 +105,7 @@ impl Drive {
         strength: u8,
         vote: ResolvedVote,
         previous_resource_vote_choice_to_remove: Option<(ResourceVoteChoice, PreviousVoteCount)>,
+        block_info: &BlockInfo,
         transaction: TransactionArg,
         platform_version: &PlatformVersion,
     ) -> Result<Vec<LowLevelDriveOperation>, Error> {
@@ -120,6 +121,7 @@ impl Drive {
                 strength,
                 vote,
                 previous_resource_vote_choice_to_remove,
+                block_info,
                 transaction,
                 platform_version,
             ),
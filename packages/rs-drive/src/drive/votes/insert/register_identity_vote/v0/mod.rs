@@ -2,6 +2,7 @@ use crate::drive::votes::resolved::vote_polls::ResolvedVotePoll;
 use crate::drive::votes::resolved::votes::resolved_resource_vote::accessors::v0::ResolvedResourceVoteGettersV0;
 use crate::drive::votes::resolved::votes::ResolvedVote;
 use crate::drive::Drive;
+use crate::error::drive::DriveError;
 use crate::error::Error;
 use crate::fees::op::LowLevelDriveOperation;
 use crate::state_transition_action::identity::masternode_vote::v0::PreviousVoteCount;
@@ -38,8 +39,21 @@ impl Drive {
                         transaction,
                         platform_version,
                     ),
+                    ResolvedVotePoll::GovernanceProposalVotePoll(_) => {
+                        Err(Error::Drive(DriveError::NotSupported(
+                            "resource votes can not be cast on governance proposal vote polls",
+                        )))
+                    }
                 }
             }
+            ResolvedVote::ResolvedGovernanceProposalVote(governance_proposal_vote) => self
+                .register_governance_proposal_identity_vote(
+                    voter_pro_tx_hash,
+                    governance_proposal_vote,
+                    block_info,
+                    transaction,
+                    platform_version,
+                ),
         }
     }
 
@@ -49,6 +63,7 @@ impl Drive {
         strength: u8,
         vote: ResolvedVote,
         previous_resource_vote_choice_to_remove: Option<(ResourceVoteChoice, PreviousVoteCount)>,
+        block_info: &BlockInfo,
         transaction: TransactionArg,
         platform_version: &PlatformVersion,
     ) -> Result<Vec<LowLevelDriveOperation>, Error> {
@@ -67,8 +82,21 @@ impl Drive {
                         transaction,
                         platform_version,
                     ),
+                    ResolvedVotePoll::GovernanceProposalVotePoll(_) => {
+                        Err(Error::Drive(DriveError::NotSupported(
+                            "resource votes can not be cast on governance proposal vote polls",
+                        )))
+                    }
                 }
             }
+            ResolvedVote::ResolvedGovernanceProposalVote(governance_proposal_vote) => self
+                .register_governance_proposal_identity_vote_operations(
+                    voter_pro_tx_hash,
+                    governance_proposal_vote,
+                    block_info,
+                    transaction,
+                    platform_version,
+                ),
         }
     }
 }
@@ -22,6 +22,12 @@ mod insert;
 #[cfg(any(feature = "server", feature = "verify"))]
 pub mod paths;
 
+#[cfg(feature = "server")]
+mod prove;
+
+#[cfg(any(feature = "server", feature = "verify"))]
+mod queries;
+
 #[cfg(feature = "server")]
 mod setup;
 
@@ -48,6 +54,9 @@ impl TreePath for Vote {
     fn tree_path<'a>(&'a self, contract: &'a DataContract) -> Result<Vec<&'a [u8]>, ProtocolError> {
         match self {
             Vote::ResourceVote(resource_vote) => resource_vote.tree_path(contract),
+            Vote::GovernanceProposalVote(_) => Err(ProtocolError::VoteError(
+                "governance proposal votes are not stored under a contract".to_string(),
+            )),
         }
     }
 }
@@ -92,6 +101,9 @@ impl TreePath for ResourceVote {
                 }
                 Ok(path)
             }
+            VotePoll::GovernanceProposalVotePoll(_) => Err(ProtocolError::VoteError(
+                "resource votes can not be cast on governance proposal vote polls".to_string(),
+            )),
         }
     }
 }
@@ -20,6 +20,10 @@ use platform_version::version::PlatformVersion;
 ///
 /// |- End date Queries [key: "e"]
 /// |- Decisions [key: "d"]
+///    |- Vote polls opened [key: "o"]
+///    |- Governance proposal vote poll [key: vote poll id]
+///       |- Stored info [key: 0]
+///       |- Voters [key: 1]
 /// |- Contested Resource [key: "c"]
 ///    |- Active polls [key: "p"]
 ///    |- Identifier Votes Query [key: "i"]
@@ -29,6 +33,10 @@ use platform_version::version::PlatformVersion;
 /// A subtree made for polls to the network that represent decisions.
 pub const VOTE_DECISIONS_TREE_KEY: char = 'd';
 
+/// A subtree of the decisions holding, for every evonode that opened a governance proposal vote
+/// poll, the epoch of its last opening and how many vote polls it opened in that epoch
+pub const VOTE_DECISIONS_VOTE_POLLS_OPENED_TREE_KEY: char = 'o';
+
 /// A subtree made for contested resources that will be voted on.
 pub const CONTESTED_RESOURCE_TREE_KEY: char = 'c';
 
@@ -65,6 +73,17 @@ pub const CONTESTED_DOCUMENT_INDEXES_TREE_KEY: u8 = 1;
 /// The tree key for storage
 pub const VOTING_STORAGE_TREE_KEY: u8 = 1;
 
+/// The key of the sealed bid of a contender when the contested resource is resolved by an auction,
+/// it sits next to the contender's document reference and voting tree
+pub const CONTESTED_RESOURCE_AUCTION_BID_KEY: u8 = 2;
+
+/// The key of the stored info of a governance proposal vote poll
+pub const GOVERNANCE_PROPOSAL_STORED_INFO_KEY: u8 = 0;
+
+/// The tree key of the voters of a governance proposal vote poll, voters are keyed by their
+/// pro_tx_hash and hold their vote choice
+pub const GOVERNANCE_PROPOSAL_VOTERS_TREE_KEY: u8 = 1;
+
 /// Convenience methods to be easily able to get a path when we know the vote poll
 pub trait VotePollPaths {
     /// The root path, under this there should be the documents area and the contract itself
@@ -301,6 +320,52 @@ pub fn vote_decisions_tree_path_vec() -> Vec<Vec<u8>> {
     ]
 }
 
+/// the path of the vote polls opened by evonodes in the decisions branch
+pub fn vote_decisions_vote_polls_opened_path<'a>() -> [&'a [u8]; 3] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Votes),
+        &[VOTE_DECISIONS_TREE_KEY as u8],
+        &[VOTE_DECISIONS_VOTE_POLLS_OPENED_TREE_KEY as u8],
+    ]
+}
+
+/// the path of the vote polls opened by evonodes in the decisions branch as a vec
+pub fn vote_decisions_vote_polls_opened_path_vec() -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Votes as u8],
+        vec![VOTE_DECISIONS_TREE_KEY as u8],
+        vec![VOTE_DECISIONS_VOTE_POLLS_OPENED_TREE_KEY as u8],
+    ]
+}
+
+/// the path of a governance proposal vote poll in the decisions branch
+pub fn vote_decisions_vote_poll_path(vote_poll_id: &[u8]) -> [&[u8]; 3] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Votes),
+        &[VOTE_DECISIONS_TREE_KEY as u8],
+        vote_poll_id,
+    ]
+}
+
+/// the path of a governance proposal vote poll in the decisions branch as a vec
+pub fn vote_decisions_vote_poll_path_vec(vote_poll_id: &[u8]) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Votes as u8],
+        vec![VOTE_DECISIONS_TREE_KEY as u8],
+        vote_poll_id.to_vec(),
+    ]
+}
+
+/// the path of the voters of a governance proposal vote poll as a vec
+pub fn vote_decisions_vote_poll_voters_path_vec(vote_poll_id: &[u8]) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Votes as u8],
+        vec![VOTE_DECISIONS_TREE_KEY as u8],
+        vote_poll_id.to_vec(),
+        vec![GOVERNANCE_PROPOSAL_VOTERS_TREE_KEY],
+    ]
+}
+
 /// the contested resource tree path of the voting branch
 pub fn vote_contested_resource_tree_path<'a>() -> [&'a [u8]; 2] {
     [
@@ -0,0 +1 @@
+mod prove_governance_proposal_vote_poll_stored_info;
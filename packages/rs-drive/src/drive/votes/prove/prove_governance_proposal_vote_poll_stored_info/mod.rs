@@ -0,0 +1,48 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::{drive::DriveError, Error};
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves the stored info of a governance proposal vote poll, or its absence
+    ///
+    /// This function uses the versioning system to call the appropriate handler based on the provided `DriveVersion`.
+    ///
+    /// # Arguments
+    ///
+    /// * `vote_poll_id` - The unique id of the governance proposal vote poll to prove.
+    /// * `transaction` - Transaction arguments.
+    /// * `platform_version` - A reference to the platform version.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a Proof for the vote poll's stored info, otherwise an `Error` if the operation fails or the version is not supported.
+    pub fn prove_governance_proposal_vote_poll_stored_info(
+        &self,
+        vote_poll_id: [u8; 32],
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .governance_proposal
+            .prove_governance_proposal_vote_poll_stored_info
+        {
+            0 => self.prove_governance_proposal_vote_poll_stored_info_v0(
+                vote_poll_id,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "prove_governance_proposal_vote_poll_stored_info".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::drive::Drive;
+use crate::error::Error;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    pub(super) fn prove_governance_proposal_vote_poll_stored_info_v0(
+        &self,
+        vote_poll_id: [u8; 32],
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let stored_info_query = Self::governance_proposal_vote_poll_stored_info_query(vote_poll_id);
+        self.grove_get_proved_path_query(
+            &stored_info_query,
+            transaction,
+            &mut vec![],
+            &platform_version.drive,
+        )
+    }
+}
@@ -0,0 +1,23 @@
+use crate::drive::votes::paths::{
+    vote_decisions_vote_poll_path_vec, vote_decisions_vote_poll_voters_path_vec,
+    GOVERNANCE_PROPOSAL_STORED_INFO_KEY,
+};
+use crate::drive::Drive;
+use grovedb::PathQuery;
+
+impl Drive {
+    /// The query for proving the stored info of a governance proposal vote poll.
+    pub fn governance_proposal_vote_poll_stored_info_query(vote_poll_id: [u8; 32]) -> PathQuery {
+        let vote_poll_path = vote_decisions_vote_poll_path_vec(vote_poll_id.as_slice());
+        PathQuery::new_single_key(vote_poll_path, vec![GOVERNANCE_PROPOSAL_STORED_INFO_KEY])
+    }
+
+    /// The query for proving the vote of an evonode on a governance proposal.
+    pub fn governance_proposal_vote_query(
+        vote_poll_id: [u8; 32],
+        voter_pro_tx_hash: [u8; 32],
+    ) -> PathQuery {
+        let voters_path = vote_decisions_vote_poll_voters_path_vec(vote_poll_id.as_slice());
+        PathQuery::new_single_key(voters_path, voter_pro_tx_hash.to_vec())
+    }
+}
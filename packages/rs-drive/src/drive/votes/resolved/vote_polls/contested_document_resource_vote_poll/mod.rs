@@ -6,7 +6,9 @@ use crate::error::Error;
 use crate::util::object_size_info::{DataContractOwnedResolvedInfo, DataContractResolvedInfo};
 use dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
-use dpp::data_contract::document_type::{DocumentType, DocumentTypeRef, Index};
+use dpp::data_contract::document_type::{
+    ContestedIndexInformation, ContestedIndexResolution, DocumentType, DocumentTypeRef, Index,
+};
 use dpp::identifier::Identifier;
 use dpp::platform_value::Value;
 use dpp::serialization::PlatformSerializable;
@@ -281,6 +283,18 @@ impl ContestedDocumentResourceVotePollWithContractInfo {
             .document_type_borrowed_for_name(self.document_type_name.as_str())
             .map_err(|e| Error::Protocol(ProtocolError::DataContractError(e)))
     }
+
+    /// Whether the contested index of the vote poll is resolved by a sealed-bid auction instead
+    /// of by masternode votes.
+    pub fn is_resolved_by_auction(&self) -> Result<bool, Error> {
+        Ok(matches!(
+            self.index()?.contested_index,
+            Some(ContestedIndexInformation {
+                resolution: ContestedIndexResolution::Auction,
+                ..
+            })
+        ))
+    }
 }
 
 impl<'a> ContestedDocumentResourceVotePollWithContractInfoAllowBorrowed<'a> {
@@ -2,6 +2,8 @@ use crate::drive::votes::resolved::vote_polls::contested_document_resource_vote_
 use derive_more::From;
 use dpp::identifier::Identifier;
 use dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dpp::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use dpp::voting::vote_polls::governance_proposal_vote_poll::GovernanceProposalVotePoll;
 use dpp::voting::vote_polls::VotePoll;
 use dpp::ProtocolError;
 use std::collections::BTreeMap;
@@ -20,6 +22,8 @@ pub enum ResolvedVotePoll {
     ContestedDocumentResourceVotePollWithContractInfo(
         ContestedDocumentResourceVotePollWithContractInfo,
     ),
+    /// A governance proposal vote poll, it needs no resolution.
+    GovernanceProposalVotePoll(GovernanceProposalVotePoll),
 }
 
 impl From<&ResolvedVotePoll> for VotePoll {
@@ -30,6 +34,9 @@ impl From<&ResolvedVotePoll> for VotePoll {
             ) => VotePoll::ContestedDocumentResourceVotePoll(
                 contested_document_resource_vote_poll.into(),
             ),
+            ResolvedVotePoll::GovernanceProposalVotePoll(governance_proposal_vote_poll) => {
+                VotePoll::GovernanceProposalVotePoll(governance_proposal_vote_poll.clone())
+            }
         }
     }
 }
@@ -42,6 +49,11 @@ pub enum ResolvedVotePollWithVotes {
         ContestedDocumentResourceVotePollWithContractInfo,
         BTreeMap<ResourceVoteChoice, Vec<Identifier>>,
     ),
+    /// A governance proposal vote poll with the vote choice of each voter, keyed by pro_tx_hash.
+    GovernanceProposalVotePollWithVotes(
+        GovernanceProposalVotePoll,
+        BTreeMap<Identifier, YesNoAbstainVoteChoice>,
+    ),
 }
 
 impl ResolvedVotePoll {
@@ -63,6 +75,9 @@ impl ResolvedVotePoll {
             ) => Ok(Some(
                 contested_document_resource_vote_poll.specialized_balance_id()?,
             )),
+            ResolvedVotePoll::GovernanceProposalVotePoll(governance_proposal_vote_poll) => {
+                Ok(Some(governance_proposal_vote_poll.unique_id()?))
+            }
         }
     }
 
@@ -81,6 +96,9 @@ impl ResolvedVotePoll {
             ResolvedVotePoll::ContestedDocumentResourceVotePollWithContractInfo(
                 contested_document_resource_vote_poll,
             ) => contested_document_resource_vote_poll.unique_id(),
+            ResolvedVotePoll::GovernanceProposalVotePoll(governance_proposal_vote_poll) => {
+                governance_proposal_vote_poll.unique_id()
+            }
         }
     }
 }
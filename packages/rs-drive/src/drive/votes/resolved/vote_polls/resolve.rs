@@ -63,6 +63,9 @@ impl VotePollResolver for VotePoll {
                     ),
                 )
             }
+            VotePoll::GovernanceProposalVotePoll(governance_proposal_vote_poll) => Ok(
+                ResolvedVotePoll::GovernanceProposalVotePoll(governance_proposal_vote_poll.clone()),
+            ),
         }
     }
 
@@ -84,6 +87,9 @@ impl VotePollResolver for VotePoll {
                     ),
                 )
             }
+            VotePoll::GovernanceProposalVotePoll(governance_proposal_vote_poll) => Ok(
+                ResolvedVotePoll::GovernanceProposalVotePoll(governance_proposal_vote_poll),
+            ),
         }
     }
 }
@@ -8,6 +8,8 @@ use crate::drive::votes::resolved::votes::resolved_resource_vote::accessors::v0:
 use crate::drive::votes::resolved::votes::resolved_resource_vote::ResolvedResourceVote;
 use dpp::identifier::Identifier;
 use dpp::voting::vote_polls::VotePoll;
+use dpp::voting::votes::governance_proposal_vote::accessors::v0::GovernanceProposalVoteGettersV0;
+use dpp::voting::votes::governance_proposal_vote::GovernanceProposalVote;
 use dpp::voting::votes::resource_vote::v0::ResourceVoteV0;
 use dpp::voting::votes::resource_vote::ResourceVote;
 use dpp::voting::votes::Vote;
@@ -18,6 +20,8 @@ use dpp::ProtocolError;
 pub enum ResolvedVote {
     /// A resolved vote for a specific resource.
     ResolvedResourceVote(ResolvedResourceVote),
+    /// A vote on a governance proposal, it needs no resolution.
+    ResolvedGovernanceProposalVote(GovernanceProposalVote),
 }
 
 impl ResolvedVote {
@@ -37,6 +41,9 @@ impl ResolvedVote {
             ResolvedVote::ResolvedResourceVote(resource_vote) => {
                 resource_vote.vote_poll().specialized_balance_id()
             }
+            ResolvedVote::ResolvedGovernanceProposalVote(governance_proposal_vote) => {
+                Ok(Some(governance_proposal_vote.vote_poll().unique_id()?))
+            }
         }
     }
 }
@@ -57,10 +64,21 @@ impl From<ResolvedVote> for Vote {
                                 ),
                                 resource_vote_choice: vote_choice,
                             })),
+                            ResolvedVotePoll::GovernanceProposalVotePoll(
+                                governance_proposal_vote_poll,
+                            ) => Self::ResourceVote(ResourceVote::V0(ResourceVoteV0 {
+                                vote_poll: VotePoll::GovernanceProposalVotePoll(
+                                    governance_proposal_vote_poll,
+                                ),
+                                resource_vote_choice: vote_choice,
+                            })),
                         }
                     }
                 }
             }
+            ResolvedVote::ResolvedGovernanceProposalVote(governance_proposal_vote) => {
+                Self::GovernanceProposalVote(governance_proposal_vote)
+            }
         }
     }
 }
@@ -144,4 +144,8 @@ pub enum QuerySyntaxError {
     /// Missing index values for query
     #[error("incorrect index values error: {0}")]
     IndexValuesError(String),
+
+    /// The index is still being built for existing documents
+    #[error("index not yet queryable error: {0}")]
+    IndexNotYetQueryable(String),
 }
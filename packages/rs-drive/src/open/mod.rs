@@ -68,4 +68,24 @@ impl Drive {
 
         Ok((drive, protocol_version))
     }
+
+    /// Returns a `Drive` on the same GroveDB with its own empty caches.
+    ///
+    /// Operations executed with it, like the ones of a state transition that is only
+    /// simulated, neither read nor change the caches used to execute blocks.
+    pub fn with_isolated_cache(&self) -> Self {
+        Drive {
+            grove: Arc::clone(&self.grove),
+            config: self.config.clone(),
+            cache: DriveCache {
+                data_contracts: DataContractCache::new(
+                    self.config.data_contracts_global_cache_size,
+                    self.config.data_contracts_block_cache_size,
+                ),
+                genesis_time_ms: parking_lot::RwLock::new(*self.cache.genesis_time_ms.read()),
+                protocol_versions_counter: parking_lot::RwLock::new(ProtocolVersionsCache::new()),
+                system_data_contracts: self.cache.system_data_contracts.clone(),
+            },
+        }
+    }
 }
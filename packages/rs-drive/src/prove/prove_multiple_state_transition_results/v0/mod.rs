@@ -60,6 +60,16 @@ impl Drive {
                         path_queries
                             .push(Self::identity_revision_query(&identity_query.identity_id));
                     }
+                    IdentityProveRequestType::RecoveryConfig => {
+                        path_queries.push(Self::identity_recovery_config_query(
+                            identity_query.identity_id,
+                        ));
+                    }
+                    IdentityProveRequestType::PendingRecovery => {
+                        path_queries.push(Self::identity_pending_recovery_query(
+                            identity_query.identity_id,
+                        ));
+                    }
                 }
             }
         }
@@ -45,6 +45,30 @@ fn sql_value_to_platform_value(sql_value: ast::Value) -> Option<Value> {
     }
 }
 
+/// Returns the type of a document property, or system field, referenced in a SQL expression
+fn sql_property_type<'a>(
+    field_name: &str,
+    document_type: &'a DocumentType,
+) -> Result<Cow<'a, DocumentPropertyType>, Error> {
+    match field_name {
+        "$id" | "$ownerId" => Ok(Cow::Owned(DocumentPropertyType::Identifier)),
+        "$createdAt" | "$updatedAt" => Ok(Cow::Owned(DocumentPropertyType::Date)),
+        "$revision" => Ok(Cow::Owned(DocumentPropertyType::U64)),
+        _ => {
+            let property = document_type
+                .flattened_properties()
+                .get(field_name)
+                .ok_or_else(|| {
+                    Error::Query(QuerySyntaxError::InvalidSQL(format!(
+                        "Invalid query: property named {} not in document type",
+                        field_name
+                    )))
+                })?;
+            Ok(Cow::Borrowed(&property.property_type))
+        }
+    }
+}
+
 /// Where operator arguments
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -673,6 +697,72 @@ impl<'a> WhereClause {
         Ok((left_key, right_key))
     }
 
+    /// Returns true if a field with the given serialized value satisfies the where clause
+    ///
+    /// Values are compared in their serialized form, the same way a query over an index
+    /// on the field compares them.
+    pub fn matches_serialized_value(
+        &self,
+        serialized_value: &[u8],
+        document_type: DocumentTypeRef,
+        platform_version: &PlatformVersion,
+    ) -> Result<bool, Error> {
+        let serialize = |value: &Value| {
+            document_type
+                .serialize_value_for_key(self.field.as_str(), value, platform_version)
+                .map_err(Error::from)
+        };
+
+        let matches = match self.operator {
+            Equal => serialized_value == serialize(&self.value)?.as_slice(),
+            GreaterThan => serialized_value > serialize(&self.value)?.as_slice(),
+            GreaterThanOrEquals => serialized_value >= serialize(&self.value)?.as_slice(),
+            LessThan => serialized_value < serialize(&self.value)?.as_slice(),
+            LessThanOrEquals => serialized_value <= serialize(&self.value)?.as_slice(),
+            Between => {
+                let (left_key, right_key) =
+                    self.split_value_for_between(document_type, platform_version)?;
+                serialized_value >= left_key.as_slice() && serialized_value <= right_key.as_slice()
+            }
+            BetweenExcludeBounds => {
+                let (left_key, right_key) =
+                    self.split_value_for_between(document_type, platform_version)?;
+                serialized_value > left_key.as_slice() && serialized_value < right_key.as_slice()
+            }
+            BetweenExcludeLeft => {
+                let (left_key, right_key) =
+                    self.split_value_for_between(document_type, platform_version)?;
+                serialized_value > left_key.as_slice() && serialized_value <= right_key.as_slice()
+            }
+            BetweenExcludeRight => {
+                let (left_key, right_key) =
+                    self.split_value_for_between(document_type, platform_version)?;
+                serialized_value >= left_key.as_slice() && serialized_value < right_key.as_slice()
+            }
+            In => {
+                let mut matches = false;
+                for value in self.in_values()?.iter() {
+                    if serialized_value == serialize(value)?.as_slice() {
+                        matches = true;
+                        break;
+                    }
+                }
+                matches
+            }
+            StartsWith => {
+                let prefix = serialize(&self.value)?;
+                if prefix.is_empty() {
+                    return Err(Error::Query(QuerySyntaxError::InvalidStartsWithClause(
+                        "starts with must have at least one character",
+                    )));
+                }
+                serialized_value.starts_with(prefix.as_slice())
+            }
+        };
+
+        Ok(matches)
+    }
+
     /// Returns a path query given the parameters
     // The start at document fields are:
     // document: The Document that we should start at
@@ -1073,23 +1163,7 @@ impl<'a> WhereClause {
                     )));
                 };
 
-                let property_type = match field_name.as_str() {
-                    "$id" | "$ownerId" => Cow::Owned(DocumentPropertyType::Identifier),
-                    "$createdAt" | "$updatedAt" => Cow::Owned(DocumentPropertyType::Date),
-                    "$revision" => Cow::Owned(DocumentPropertyType::U64),
-                    _ => {
-                        let property = document_type
-                            .flattened_properties()
-                            .get(&field_name)
-                            .ok_or_else(|| {
-                                Error::Query(QuerySyntaxError::InvalidSQL(format!(
-                                    "Invalid query: property named {} not in document type",
-                                    field_name
-                                )))
-                            })?;
-                        Cow::Borrowed(&property.property_type)
-                    }
-                };
+                let property_type = sql_property_type(&field_name, document_type)?;
 
                 let mut in_values: Vec<Value> = Vec::new();
                 for value in list {
@@ -1138,7 +1212,9 @@ impl<'a> WhereClause {
                 let field_name: String = if let ast::Expr::Identifier(ident) = &**expr {
                     ident.value.clone()
                 } else {
-                    panic!("unreachable: confirmed it's identifier variant");
+                    return Err(Error::Query(QuerySyntaxError::InvalidStartsWithClause(
+                        "Invalid query: like clause should start with an identifier",
+                    )));
                 };
 
                 let transformed_value = if let ast::Expr::Value(value) = &**pattern {
@@ -1164,7 +1240,9 @@ impl<'a> WhereClause {
                         )));
                     }
                 } else {
-                    panic!("unreachable: confirmed it's value variant");
+                    return Err(Error::Query(QuerySyntaxError::InvalidStartsWithClause(
+                        "Invalid query: like pattern should be a text value",
+                    )));
                 };
 
                 where_clauses.push(WhereClause {
@@ -1174,6 +1252,56 @@ impl<'a> WhereClause {
                 });
                 Ok(())
             }
+            ast::Expr::Between {
+                expr,
+                negated,
+                low,
+                high,
+            } => {
+                if *negated {
+                    return Err(Error::Query(QuerySyntaxError::Unsupported(
+                        "Invalid query: negated between clause not supported".to_string(),
+                    )));
+                }
+
+                let field_name: String = if let ast::Expr::Identifier(ident) = &**expr {
+                    ident.value.clone()
+                } else {
+                    return Err(Error::Query(QuerySyntaxError::InvalidBetweenClause(
+                        "Invalid query: between clause should start with an identifier",
+                    )));
+                };
+
+                let property_type = sql_property_type(&field_name, document_type)?;
+
+                let mut bounds: Vec<Value> = Vec::with_capacity(2);
+                for bound in [low, high] {
+                    let ast::Expr::Value(sql_value) = &**bound else {
+                        return Err(Error::Query(QuerySyntaxError::InvalidBetweenClause(
+                            "Invalid query: between bounds should be sql values",
+                        )));
+                    };
+                    let platform_value = sql_value_to_platform_value(sql_value.clone()).ok_or({
+                        Error::Query(QuerySyntaxError::InvalidSQL(
+                            "Invalid query: unexpected value type".to_string(),
+                        ))
+                    })?;
+                    let transformed_value = if let Value::Text(text_value) = &platform_value {
+                        property_type.value_from_string(text_value)?
+                    } else {
+                        platform_value
+                    };
+                    bounds.push(transformed_value);
+                }
+
+                where_clauses.push(WhereClause {
+                    field: field_name,
+                    operator: Between,
+                    value: Value::Array(bounds),
+                });
+
+                Ok(())
+            }
             ast::Expr::BinaryOp { left, op, right } => {
                 if *op == ast::BinaryOperator::And {
                     Self::build_where_clauses_from_operations(left, document_type, where_clauses)?;
@@ -1211,23 +1339,7 @@ impl<'a> WhereClause {
                         panic!("unreachable: confirmed it's identifier variant");
                     };
 
-                    let property_type = match field_name.as_str() {
-                        "$id" | "$ownerId" => Cow::Owned(DocumentPropertyType::Identifier),
-                        "$createdAt" | "$updatedAt" => Cow::Owned(DocumentPropertyType::Date),
-                        "$revision" => Cow::Owned(DocumentPropertyType::U64),
-                        _ => {
-                            let property = document_type
-                                .flattened_properties()
-                                .get(&field_name)
-                                .ok_or_else(|| {
-                                    Error::Query(QuerySyntaxError::InvalidSQL(format!(
-                                        "Invalid query: property named {} not in document type",
-                                        field_name
-                                    )))
-                                })?;
-                            Cow::Borrowed(&property.property_type)
-                        }
-                    };
+                    let property_type = sql_property_type(&field_name, document_type)?;
 
                     let transformed_value = if let ast::Expr::Value(value) = value_expr {
                         let platform_value = sql_value_to_platform_value(value.clone()).ok_or({
@@ -1271,9 +1383,14 @@ impl From<WhereClause> for Value {
 mod tests {
     use crate::query::conditions::WhereClause;
     use crate::query::conditions::WhereOperator::{
-        Equal, GreaterThan, GreaterThanOrEquals, In, LessThan, LessThanOrEquals,
+        BetweenExcludeRight, Equal, GreaterThan, GreaterThanOrEquals, In, LessThan,
+        LessThanOrEquals, StartsWith,
     };
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
     use dpp::platform_value::Value;
+    use dpp::tests::json_document::json_document_to_contract;
+    use dpp::version::PlatformVersion;
 
     #[test]
     fn test_allowed_sup_query_pairs() {
@@ -1398,4 +1515,90 @@ mod tests {
                 .expect_err("expected to not have a groupable pair");
         }
     }
+
+    #[test]
+    fn test_matches_serialized_value() {
+        let platform_version = PlatformVersion::latest();
+
+        let contract = json_document_to_contract(
+            "tests/supporting_files/contract/family/family-contract.json",
+            false,
+            platform_version,
+        )
+        .expect("expected to get contract");
+
+        let document_type = contract
+            .document_type_for_name("person")
+            .expect("expected to get document type");
+
+        let serialize = |field: &str, value: Value| {
+            document_type
+                .serialize_value_for_key(field, &value, platform_version)
+                .expect("expected to serialize value")
+        };
+
+        let matches = |clause: &WhereClause, serialized_value: &[u8]| {
+            clause
+                .matches_serialized_value(serialized_value, document_type, platform_version)
+                .expect("expected to match value")
+        };
+
+        let older_than_twenty = WhereClause {
+            field: "age".to_string(),
+            operator: GreaterThan,
+            value: Value::I64(20),
+        };
+
+        assert!(matches(
+            &older_than_twenty,
+            &serialize("age", Value::I64(30))
+        ));
+        assert!(!matches(
+            &older_than_twenty,
+            &serialize("age", Value::I64(20))
+        ));
+        assert!(!matches(
+            &older_than_twenty,
+            &serialize("age", Value::I64(-5))
+        ));
+
+        let teenager = WhereClause {
+            field: "age".to_string(),
+            operator: BetweenExcludeRight,
+            value: Value::Array(vec![Value::I64(13), Value::I64(20)]),
+        };
+
+        assert!(matches(&teenager, &serialize("age", Value::I64(13))));
+        assert!(!matches(&teenager, &serialize("age", Value::I64(20))));
+
+        let first_name_in = WhereClause {
+            field: "firstName".to_string(),
+            operator: In,
+            value: Value::Array(vec!["Sam".into(), "Alex".into()]),
+        };
+
+        assert!(matches(
+            &first_name_in,
+            &serialize("firstName", "Alex".into())
+        ));
+        assert!(!matches(
+            &first_name_in,
+            &serialize("firstName", "Sa".into())
+        ));
+
+        let first_name_starts_with = WhereClause {
+            field: "firstName".to_string(),
+            operator: StartsWith,
+            value: "Sa".into(),
+        };
+
+        assert!(matches(
+            &first_name_starts_with,
+            &serialize("firstName", "Samuel".into())
+        ));
+        assert!(!matches(
+            &first_name_starts_with,
+            &serialize("firstName", "Alex".into())
+        ));
+    }
 }
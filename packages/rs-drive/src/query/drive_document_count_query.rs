@@ -0,0 +1,679 @@
+use crate::drive::document::paths::{
+    contract_document_type_path_vec, DOCUMENT_TYPE_AGGREGATES_TREE_KEY, INDEX_COUNT_TREE_KEY,
+    INDEX_SUM_TREE_KEY,
+};
+#[cfg(feature = "server")]
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::query::QuerySyntaxError;
+use crate::error::Error;
+#[cfg(feature = "server")]
+use crate::fees::op::LowLevelDriveOperation;
+#[cfg(feature = "server")]
+use crate::query::GroveError;
+use crate::query::{
+    sql_select, sql_select_document_type_and_where_clauses, sql_select_query, InternalClauses,
+    Query, WhereClause,
+};
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
+use dpp::data_contract::document_type::{DocumentTypeRef, Index};
+use dpp::data_contract::DataContract;
+use dpp::platform_value::Value;
+#[cfg(feature = "server")]
+use grovedb::query_result_type::QueryResultType;
+#[cfg(feature = "server")]
+use grovedb::TransactionArg;
+use grovedb::{Element, PathQuery};
+use platform_version::version::PlatformVersion;
+use sqlparser::ast::{self, SelectItem};
+
+/// Drive query counting documents, or totaling an integer property, with a countable or
+/// summable index.
+///
+/// Where clauses must be equalities on the first properties of the index, optionally followed
+/// by a range or `in` clause on the next property.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DriveDocumentCountQuery<'a> {
+    /// DataContract
+    pub contract: &'a DataContract,
+    /// Document type
+    pub document_type: DocumentTypeRef<'a>,
+    /// The aggregated index serving the query
+    pub index: &'a Index,
+    /// The summed property, documents are counted if none
+    pub sum_property: Option<String>,
+    /// Equal clauses, in the order of the index properties
+    pub equal_clauses: Vec<WhereClause>,
+    /// Range or in clause on the property following the equal clauses
+    pub range_clause: Option<WhereClause>,
+}
+
+impl<'a> DriveDocumentCountQuery<'a> {
+    /// Creates a count query from where clauses, choosing an aggregated index matching them
+    pub fn from_where_clauses(
+        where_clauses: Vec<WhereClause>,
+        sum_property: Option<String>,
+        contract: &'a DataContract,
+        document_type: DocumentTypeRef<'a>,
+    ) -> Result<Self, Error> {
+        let InternalClauses {
+            primary_key_in_clause,
+            primary_key_equal_clause,
+            in_clause,
+            range_clause,
+            mut equal_clauses,
+        } = InternalClauses::extract_from_clauses(where_clauses)?;
+
+        if primary_key_in_clause.is_some() || primary_key_equal_clause.is_some() {
+            return Err(Error::Query(
+                QuerySyntaxError::InvalidWhereClauseComponents(
+                    "documents can not be counted by id",
+                ),
+            ));
+        }
+
+        let range_clause = match (in_clause, range_clause) {
+            (Some(_), Some(_)) => {
+                return Err(Error::Query(
+                    QuerySyntaxError::InvalidWhereClauseComponents(
+                        "documents can not be counted with both range and in clauses",
+                    ),
+                ))
+            }
+            (in_clause, range_clause) => in_clause.or(range_clause),
+        };
+
+        let index = document_type
+            .indexes()
+            .values()
+            .find(|index| {
+                let aggregates = match &sum_property {
+                    Some(sum_property) => index.summable.as_ref() == Some(sum_property),
+                    None => index.countable,
+                };
+                let equal_count = equal_clauses.len();
+
+                aggregates
+                    && index.properties.len() >= equal_count
+                    && index.properties[..equal_count]
+                        .iter()
+                        .all(|property| equal_clauses.contains_key(&property.name))
+                    && range_clause.as_ref().map_or(true, |range_clause| {
+                        index
+                            .properties
+                            .get(equal_count)
+                            .is_some_and(|property| property.name == range_clause.field)
+                    })
+            })
+            .ok_or_else(|| {
+                Error::Query(QuerySyntaxError::WhereClauseOnNonIndexedProperty(format!(
+                    "no {} index of document type {} matches the where clauses",
+                    if sum_property.is_some() {
+                        "summable"
+                    } else {
+                        "countable"
+                    },
+                    document_type.name()
+                )))
+            })?;
+
+        let equal_clauses = index
+            .properties
+            .iter()
+            .map_while(|property| equal_clauses.remove(&property.name))
+            .collect();
+
+        Ok(DriveDocumentCountQuery {
+            contract,
+            document_type,
+            index,
+            sum_property,
+            equal_clauses,
+            range_clause,
+        })
+    }
+
+    /// Creates a count query from a where clause value, as received in `getDocumentsCount`
+    pub fn from_decomposed_values(
+        where_clause: Value,
+        sum_property: Option<String>,
+        contract: &'a DataContract,
+        document_type: DocumentTypeRef<'a>,
+    ) -> Result<Self, Error> {
+        let where_clauses = match where_clause {
+            Value::Null => Ok(vec![]),
+            Value::Array(clauses) => clauses
+                .iter()
+                .map(|where_clause| {
+                    if let Value::Array(clauses_components) = where_clause {
+                        WhereClause::from_components(clauses_components)
+                    } else {
+                        Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
+                            "where clause must be an array",
+                        )))
+                    }
+                })
+                .collect::<Result<Vec<WhereClause>, Error>>(),
+            _ => Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
+                "where clause must be an array",
+            ))),
+        }?;
+
+        Self::from_where_clauses(where_clauses, sum_property, contract, document_type)
+    }
+
+    /// Creates a count query from a SQL expression.
+    ///
+    /// The projection is either `COUNT(*)` or `SUM(<integer property>)`, and where clauses are
+    /// parsed as in [DriveDocumentQuery::from_sql_expr](crate::query::DriveDocumentQuery::from_sql_expr).
+    pub fn from_sql_expr(sql_string: &str, contract: &'a DataContract) -> Result<Self, Error> {
+        let query = sql_select_query(sql_string)?;
+
+        if !query.order_by.is_empty() || query.limit.is_some() || query.offset.is_some() {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(
+                "Invalid query: order by, limit and offset are not supported by count queries"
+                    .to_string(),
+            )));
+        }
+
+        let select = sql_select(&query)?;
+
+        let function =
+            match select.projection.as_slice() {
+                [SelectItem::UnnamedExpr(ast::Expr::Function(function))]
+                | [SelectItem::ExprWithAlias {
+                    expr: ast::Expr::Function(function),
+                    ..
+                }] => function,
+                _ => return Err(Error::Query(QuerySyntaxError::InvalidSQL(
+                    "Invalid query: count queries should select either COUNT(*) or SUM(property)"
+                        .to_string(),
+                ))),
+            };
+
+        let function_name = function.name.to_string().to_lowercase();
+        let sum_property = match (function_name.as_str(), function.args.as_slice()) {
+            ("count", [ast::FunctionArg::Unnamed(ast::FunctionArgExpr::Wildcard)]) => None,
+            (
+                "sum",
+                [ast::FunctionArg::Unnamed(ast::FunctionArgExpr::Expr(ast::Expr::Identifier(ident)))],
+            ) => Some(ident.value.clone()),
+            ("count", _) => {
+                return Err(Error::Query(QuerySyntaxError::Unsupported(
+                    "Invalid query: only COUNT(*) is supported".to_string(),
+                )))
+            }
+            ("sum", _) => {
+                return Err(Error::Query(QuerySyntaxError::InvalidSQL(
+                    "Invalid query: SUM takes a single property name".to_string(),
+                )))
+            }
+            _ => {
+                return Err(Error::Query(QuerySyntaxError::Unsupported(format!(
+                    "Invalid query: aggregate function {} is not supported",
+                    function.name
+                ))))
+            }
+        };
+
+        let (document_type, where_clauses) =
+            sql_select_document_type_and_where_clauses(select, contract)?;
+
+        Self::from_where_clauses(
+            where_clauses,
+            sum_property,
+            contract,
+            document_type.as_ref(),
+        )
+    }
+
+    /// Operations to construct a path query.
+    ///
+    /// The query reaches the aggregated index from the document type tree, so aggregates
+    /// not created yet are proved absent.
+    pub fn construct_path_query(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<PathQuery, Error> {
+        let aggregate_key = if self.sum_property.is_some() {
+            INDEX_SUM_TREE_KEY
+        } else {
+            INDEX_COUNT_TREE_KEY
+        };
+
+        let mut keys = vec![self.index.name.as_bytes().to_vec(), vec![aggregate_key]];
+        for equal_clause in &self.equal_clauses {
+            keys.push(self.document_type.serialize_value_for_key(
+                equal_clause.field.as_str(),
+                &equal_clause.value,
+                platform_version,
+            )?);
+        }
+
+        let subquery = match &self.range_clause {
+            Some(range_clause) => {
+                range_clause.to_path_query(self.document_type, &None, true, platform_version)?
+            }
+            None => {
+                let last_key = keys
+                    .pop()
+                    .expect("keys always contain the aggregate tree key");
+                Query::new_single_key(last_key)
+            }
+        };
+
+        let mut query = Query::new_single_key(vec![DOCUMENT_TYPE_AGGREGATES_TREE_KEY]);
+        query.default_subquery_branch.subquery_path = Some(keys);
+        query.default_subquery_branch.subquery = Some(Box::new(subquery));
+
+        Ok(PathQuery::new_unsized(
+            contract_document_type_path_vec(
+                self.contract.id_ref().as_bytes(),
+                self.document_type.name().as_str(),
+            ),
+            query,
+        ))
+    }
+
+    /// Totals the sum trees returned by the path query
+    pub(crate) fn total_of_elements(
+        elements: impl IntoIterator<Item = Element>,
+    ) -> Result<i64, Error> {
+        elements
+            .into_iter()
+            .try_fold(0i64, |total, element| match element {
+                Element::SumTree(_, sum, _) => {
+                    total
+                        .checked_add(sum)
+                        .ok_or(Error::Drive(DriveError::CorruptedDriveState(
+                            "document aggregate overflow".to_string(),
+                        )))
+                }
+                _ => Err(Error::Drive(DriveError::CorruptedElementType(
+                    "document aggregates must be sum trees",
+                ))),
+            })
+    }
+
+    #[cfg(feature = "server")]
+    /// Executes the query with proof and returns the proof
+    pub fn execute_with_proof(
+        self,
+        drive: &Drive,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let path_query = self.construct_path_query(platform_version)?;
+        drive.grove_get_proved_path_query(
+            &path_query,
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        )
+    }
+
+    #[cfg(feature = "server")]
+    /// Executes the query with no proof and returns the count or sum
+    pub fn execute_no_proof(
+        &self,
+        drive: &Drive,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<i64, Error> {
+        let path_query = self.construct_path_query(platform_version)?;
+        let query_result = drive.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryResultType::QueryElementResultType,
+            drive_operations,
+            &platform_version.drive,
+        );
+        match query_result {
+            Err(Error::GroveDB(GroveError::PathKeyNotFound(_)))
+            | Err(Error::GroveDB(GroveError::PathNotFound(_)))
+            | Err(Error::GroveDB(GroveError::PathParentLayerNotFound(_))) => Ok(0),
+            Err(e) => Err(e),
+            Ok((query_result_elements, _)) => {
+                Self::total_of_elements(query_result_elements.to_elements())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::WhereOperator;
+    use crate::util::object_size_info::{DocumentAndContractInfo, DocumentInfo, OwnedDocumentInfo};
+    use crate::util::storage_flags::StorageFlags;
+    use crate::util::test_helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::document::{Document, DocumentV0, DocumentV0Getters};
+    use dpp::platform_value::{platform_value, Identifier};
+    use dpp::tests::json_document::json_document_to_contract;
+    use std::collections::BTreeMap;
+
+    fn setup_contract(drive: &Drive, platform_version: &PlatformVersion) -> DataContract {
+        let contract = json_document_to_contract(
+            "tests/supporting_files/contract/aggregates/posts-contract.json",
+            false,
+            platform_version,
+        )
+        .expect("expected to get contract");
+
+        drive
+            .apply_contract(
+                &contract,
+                BlockInfo::default(),
+                true,
+                StorageFlags::optional_default_as_cow(),
+                None,
+                platform_version,
+            )
+            .expect("expected to apply contract");
+
+        contract
+    }
+
+    fn post(id: u8, author: &str, likes: i64) -> Document {
+        let mut properties = BTreeMap::new();
+        properties.insert("author".to_string(), Value::Text(author.to_string()));
+        properties.insert("likes".to_string(), Value::I64(likes));
+
+        DocumentV0 {
+            id: Identifier::new([id; 32]),
+            owner_id: Identifier::new([100; 32]),
+            properties,
+            revision: Some(1),
+            created_at: None,
+            updated_at: None,
+            transferred_at: None,
+            created_at_block_height: None,
+            updated_at_block_height: None,
+            transferred_at_block_height: None,
+            created_at_core_block_height: None,
+            updated_at_core_block_height: None,
+            transferred_at_core_block_height: None,
+        }
+        .into()
+    }
+
+    fn count(
+        drive: &Drive,
+        contract: &DataContract,
+        where_clause: Value,
+        sum_property: Option<&str>,
+        platform_version: &PlatformVersion,
+    ) -> i64 {
+        let document_type = contract
+            .document_type_for_name("post")
+            .expect("expected post document type");
+        let query = DriveDocumentCountQuery::from_decomposed_values(
+            where_clause,
+            sum_property.map(ToString::to_string),
+            contract,
+            document_type,
+        )
+        .expect("expected to create count query");
+
+        let no_proof_total = query
+            .execute_no_proof(drive, None, &mut vec![], platform_version)
+            .expect("expected to execute count query");
+
+        let proof = query
+            .clone()
+            .execute_with_proof(drive, None, &mut vec![], platform_version)
+            .expect("expected to prove count query");
+        let (_, proved_total) = query
+            .verify_document_count_proof(&proof, platform_version)
+            .expect("expected to verify count proof");
+
+        assert_eq!(no_proof_total, proved_total);
+
+        proved_total
+    }
+
+    #[test]
+    fn should_count_and_sum_documents_by_index_values() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+        let contract = setup_contract(&drive, platform_version);
+        let document_type = contract
+            .document_type_for_name("post")
+            .expect("expected post document type");
+
+        assert_eq!(
+            count(&drive, &contract, Value::Null, None, platform_version),
+            0
+        );
+
+        for (id, author, likes) in [(1, "alice", 3), (2, "alice", 4), (3, "bob", 10)] {
+            drive
+                .add_document_for_contract(
+                    DocumentAndContractInfo {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentInfo::DocumentOwnedInfo((
+                                post(id, author, likes),
+                                StorageFlags::optional_default_as_cow(),
+                            )),
+                            owner_id: None,
+                        },
+                        contract: &contract,
+                        document_type,
+                    },
+                    false,
+                    BlockInfo::default(),
+                    true,
+                    None,
+                    platform_version,
+                    None,
+                )
+                .expect("expected to add post");
+        }
+
+        assert_eq!(
+            count(&drive, &contract, Value::Null, None, platform_version),
+            3
+        );
+        assert_eq!(
+            count(
+                &drive,
+                &contract,
+                platform_value!([["author", "==", "alice"]]),
+                None,
+                platform_version
+            ),
+            2
+        );
+        assert_eq!(
+            count(
+                &drive,
+                &contract,
+                platform_value!([["author", "==", "alice"]]),
+                Some("likes"),
+                platform_version
+            ),
+            7
+        );
+        assert_eq!(
+            count(
+                &drive,
+                &contract,
+                platform_value!([["author", ">", "alice"]]),
+                Some("likes"),
+                platform_version
+            ),
+            10
+        );
+        assert_eq!(
+            count(
+                &drive,
+                &contract,
+                platform_value!([["author", "==", "carol"]]),
+                None,
+                platform_version
+            ),
+            0
+        );
+
+        let deleted = post(2, "alice", 4);
+        drive
+            .delete_document_for_contract(
+                deleted.id(),
+                &contract,
+                "post",
+                BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+                None,
+            )
+            .expect("expected to delete post");
+
+        assert_eq!(
+            count(
+                &drive,
+                &contract,
+                platform_value!([["author", "==", "alice"]]),
+                Some("likes"),
+                platform_version
+            ),
+            3
+        );
+        assert_eq!(
+            count(&drive, &contract, Value::Null, None, platform_version),
+            2
+        );
+    }
+
+    #[test]
+    fn should_estimate_fees_of_aggregated_documents_above_actual_fees() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+        let contract = setup_contract(&drive, platform_version);
+        let document_type = contract
+            .document_type_for_name("post")
+            .expect("expected post document type");
+
+        let add_post = |apply: bool| {
+            drive
+                .add_document_for_contract(
+                    DocumentAndContractInfo {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentInfo::DocumentOwnedInfo((
+                                post(1, "alice", 3),
+                                StorageFlags::optional_default_as_cow(),
+                            )),
+                            owner_id: None,
+                        },
+                        contract: &contract,
+                        document_type,
+                    },
+                    false,
+                    BlockInfo::default(),
+                    apply,
+                    None,
+                    platform_version,
+                    None,
+                )
+                .expect("expected to add post")
+        };
+
+        let estimated_fees = add_post(false);
+
+        assert_eq!(
+            count(&drive, &contract, Value::Null, None, platform_version),
+            0
+        );
+
+        let actual_fees = add_post(true);
+
+        assert_eq!(
+            count(&drive, &contract, Value::Null, None, platform_version),
+            1
+        );
+        assert!(
+            estimated_fees.storage_fee >= actual_fees.storage_fee,
+            "estimated storage fee {} is below the actual {}",
+            estimated_fees.storage_fee,
+            actual_fees.storage_fee
+        );
+        assert!(
+            estimated_fees.processing_fee >= actual_fees.processing_fee,
+            "estimated processing fee {} is below the actual {}",
+            estimated_fees.processing_fee,
+            actual_fees.processing_fee
+        );
+    }
+
+    #[test]
+    fn should_not_count_with_an_index_that_is_not_countable() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+        let contract = setup_contract(&drive, platform_version);
+        let document_type = contract
+            .document_type_for_name("post")
+            .expect("expected post document type");
+
+        let result = DriveDocumentCountQuery::from_decomposed_values(
+            platform_value!([["likes", "==", 3]]),
+            None,
+            &contract,
+            document_type,
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::Query(
+                QuerySyntaxError::WhereClauseOnNonIndexedProperty(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn should_build_count_and_sum_queries_from_sql() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+        let contract = setup_contract(&drive, platform_version);
+
+        let query = DriveDocumentCountQuery::from_sql_expr(
+            "select count(*) from post where author = 'alice'",
+            &contract,
+        )
+        .expect("expected to build count query");
+
+        assert_eq!(query.index.name, "byAuthor");
+        assert_eq!(query.sum_property, None);
+        assert_eq!(query.equal_clauses.len(), 1);
+
+        let query = DriveDocumentCountQuery::from_sql_expr(
+            "select sum(likes) from post where author in ('alice', 'bob')",
+            &contract,
+        )
+        .expect("expected to build sum query");
+
+        assert_eq!(query.sum_property, Some("likes".to_string()));
+        assert_eq!(
+            query.range_clause.map(|clause| clause.operator),
+            Some(WhereOperator::In)
+        );
+
+        assert!(matches!(
+            DriveDocumentCountQuery::from_sql_expr("select count(author) from post", &contract),
+            Err(Error::Query(QuerySyntaxError::Unsupported(_)))
+        ));
+        assert!(matches!(
+            DriveDocumentCountQuery::from_sql_expr("select count(*) from post limit 5", &contract),
+            Err(Error::Query(QuerySyntaxError::Unsupported(_)))
+        ));
+        assert!(matches!(
+            DriveDocumentCountQuery::from_sql_expr("select * from post", &contract),
+            Err(Error::Query(QuerySyntaxError::InvalidSQL(_)))
+        ));
+    }
+}
@@ -3,6 +3,7 @@ use std::sync::Arc;
 #[cfg(any(feature = "server", feature = "verify"))]
 pub use {
     conditions::{WhereClause, WhereOperator},
+    drive_document_count_query::DriveDocumentCountQuery,
     grovedb::{PathQuery, Query, QueryItem, SizedQuery},
     ordering::OrderClause,
     single_document_drive_query::SingleDocumentDriveQuery,
@@ -21,20 +22,24 @@ use {
         data_contract::{
             accessors::v0::DataContractV0Getters,
             document_type::{accessors::DocumentTypeV0Getters, methods::DocumentTypeV0Methods},
-            document_type::{DocumentTypeRef, Index, IndexProperty},
+            document_type::{DocumentType, DocumentTypeRef, Index, IndexProperty},
             DataContract,
         },
         document::{
             document_methods::DocumentMethodsV0,
             serialization_traits::DocumentPlatformConversionMethodsV0, Document, DocumentV0Getters,
         },
-        platform_value::{btreemap_extensions::BTreeValueRemoveFromMapHelper, Value},
+        platform_value::{
+            btreemap_extensions::BTreeValueRemoveFromMapHelper, string_encoding::Encoding, Value,
+        },
         version::PlatformVersion,
         ProtocolError,
     },
     indexmap::IndexMap,
     sqlparser::{
-        ast::{self, OrderByExpr, Select, Statement, TableFactor::Table, Value::Number},
+        ast::{
+            self, OrderByExpr, Select, SelectItem, Statement, TableFactor::Table, Value::Number,
+        },
         dialect::MySqlDialect,
         parser::Parser,
     },
@@ -97,6 +102,10 @@ pub mod vote_polls_by_end_date_query;
 /// Vote polls by document type query
 pub mod vote_polls_by_document_type_query;
 
+#[cfg(any(feature = "server", feature = "verify"))]
+/// Document count and sum query
+pub mod drive_document_count_query;
+
 /// Function type for looking up a contract by identifier
 ///
 /// This function is used to look up a contract by its identifier.
@@ -665,30 +674,19 @@ impl<'a> DriveDocumentQuery<'a> {
 
     #[cfg(any(feature = "server", feature = "verify"))]
     /// Converts a SQL expression to a `DriveQuery`.
+    ///
+    /// Supports `=`, `<`, `<=`, `>`, `>=`, `IN`, `BETWEEN` and `LIKE 'prefix%'` where clauses
+    /// joined with `AND`, `ORDER BY` and `LIMIT`. `OFFSET` takes either a number of documents
+    /// to skip, or the base58 id of the document to start after.
+    ///
+    /// Aggregate functions such as `COUNT(*)` are served by
+    /// [DriveDocumentCountQuery::from_sql_expr].
     pub fn from_sql_expr(
         sql_string: &str,
         contract: &'a DataContract,
         config: Option<&DriveConfig>,
     ) -> Result<Self, Error> {
-        let dialect: MySqlDialect = MySqlDialect {};
-        let statements: Vec<Statement> = Parser::parse_sql(&dialect, sql_string)
-            .map_err(|e| Error::Query(QuerySyntaxError::SQLParsingError(e)))?;
-
-        // Should ideally iterate over each statement
-        let first_statement =
-            statements
-                .first()
-                .ok_or(Error::Query(QuerySyntaxError::InvalidSQL(
-                    "Issue parsing sql getting first statement".to_string(),
-                )))?;
-
-        let query: &ast::Query = match first_statement {
-            ast::Statement::Query(query_struct) => Some(query_struct),
-            _ => None,
-        }
-        .ok_or(Error::Query(QuerySyntaxError::InvalidSQL(
-            "Issue parsing sql: not a query".to_string(),
-        )))?;
+        let query = sql_select_query(sql_string)?;
 
         let max_limit = config
             .map(|config| config.max_query_limit)
@@ -725,6 +723,36 @@ impl<'a> DriveDocumentQuery<'a> {
                 .unwrap_or(DriveConfig::default().default_query_limit)
         };
 
+        // The offset is either a number of documents to skip or a cursor
+        let (offset, start_at) = match query.offset.as_ref().map(|offset| &offset.value) {
+            None => (None, None),
+            Some(ast::Expr::Value(Number(num_string, _))) => {
+                let offset = num_string.parse::<u16>().map_err(|e| {
+                    Error::Query(QuerySyntaxError::InvalidSQL(format!(
+                        "offset could not be parsed {}",
+                        e
+                    )))
+                })?;
+                (Some(offset), None)
+            }
+            Some(ast::Expr::Value(ast::Value::SingleQuotedString(start_after))) => {
+                let start_after =
+                    Identifier::from_string(start_after, Encoding::Base58).map_err(|_| {
+                        Error::Query(QuerySyntaxError::InvalidSQL(format!(
+                            "offset cursor {} should be a base58 document id",
+                            start_after
+                        )))
+                    })?;
+                (None, Some(start_after.into_buffer()))
+            }
+            Some(result) => {
+                return Err(Error::Query(QuerySyntaxError::InvalidSQL(format!(
+                    "offset {} should be a number or a document id",
+                    result
+                ))));
+            }
+        };
+
         let order_by: IndexMap<String, OrderClause> = query
             .order_by
             .iter()
@@ -735,92 +763,38 @@ impl<'a> DriveDocumentQuery<'a> {
             })
             .collect::<IndexMap<String, OrderClause>>();
 
-        // Grab the select section of the query
-        let select: &Select = match &*query.body {
-            ast::SetExpr::Select(select) => Some(select),
-            _ => None,
-        }
-        .ok_or(Error::Query(QuerySyntaxError::InvalidSQL(
-            "Issue parsing sql: Not a select".to_string(),
-        )))?;
+        let select = sql_select(&query)?;
 
-        // Get the document type from the 'from' section
-        let document_type_name = match &select
-            .from
-            .first()
-            .ok_or(Error::Query(QuerySyntaxError::InvalidSQL(
-                "Invalid query: missing from section".to_string(),
-            )))?
-            .relation
-        {
-            Table { name, .. } => name.0.first().as_ref().map(|identifier| &identifier.value),
-            _ => None,
+        if select.projection.iter().any(|item| {
+            matches!(
+                item,
+                SelectItem::UnnamedExpr(ast::Expr::Function(_))
+                    | SelectItem::ExprWithAlias {
+                        expr: ast::Expr::Function(_),
+                        ..
+                    }
+            )
+        }) {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(
+                "Invalid query: aggregate functions are only supported by count queries"
+                    .to_string(),
+            )));
         }
-        .ok_or(Error::Query(QuerySyntaxError::InvalidSQL(
-            "Issue parsing sql: invalid from value".to_string(),
-        )))?;
 
-        let document_type =
-            contract
-                .document_types()
-                .get(document_type_name)
-                .ok_or(Error::Query(QuerySyntaxError::DocumentTypeNotFound(
-                    "document type not found in contract",
-                )))?;
-
-        // Restrictions
-        // only binary where clauses are supported
-        // i.e. [<fieldname>, <operator>, <value>]
-        // [and] is used to separate where clauses
-        // currently where clauses are either binary operations or list descriptions (in clauses)
-        // hence once [and] is encountered [left] and [right] must be only one of the above
-        // i.e other where clauses
-        // e.g. firstname = wisdom and lastname = ogwu
-        // if op is not [and] then [left] or [right] must not be a binary operation or list description
-        let mut all_where_clauses: Vec<WhereClause> = Vec::new();
-        let selection_tree = select.selection.as_ref();
-
-        // Where clauses are optional
-        if let Some(selection_tree) = selection_tree {
-            WhereClause::build_where_clauses_from_operations(
-                selection_tree,
-                document_type,
-                &mut all_where_clauses,
-            )?;
-        }
+        let (document_type, all_where_clauses) =
+            sql_select_document_type_and_where_clauses(select, contract)?;
 
         let internal_clauses = InternalClauses::extract_from_clauses(all_where_clauses)?;
 
-        let start_at_option = None; //todo
-        let start_after_option = None; //todo
-        let mut start_at_included = true;
-        let mut start_option: Option<Value> = None;
-
-        if start_after_option.is_some() {
-            start_option = start_after_option;
-            start_at_included = false;
-        } else if start_at_option.is_some() {
-            start_option = start_at_option;
-            start_at_included = true;
-        }
-
-        let start_at: Option<[u8; 32]> = start_option
-            .map(|v| {
-                v.into_identifier()
-                    .map_err(|e| Error::Protocol(ProtocolError::ValueError(e)))
-                    .map(|identifier| identifier.into_buffer())
-            })
-            .transpose()?;
-
         Ok(DriveDocumentQuery {
             contract,
             document_type: document_type.as_ref(),
             internal_clauses,
-            offset: None,
+            offset,
             limit: Some(limit),
             order_by,
             start_at,
-            start_at_included,
+            start_at_included: start_at.is_none(),
             block_time_ms: None,
         })
     }
@@ -947,6 +921,26 @@ impl<'a> DriveDocumentQuery<'a> {
                 platform_version,
             )
         } else {
+            // Indexes added to the document type can only be used once existing documents
+            // were all indexed, the check is not part of the query cost
+            let index = self.find_best_index(platform_version)?;
+            if let Some(pending_index_build) = drive.fetch_pending_index_build(
+                self.contract.id_ref().as_bytes(),
+                self.document_type.name().as_str(),
+                transaction,
+                &mut vec![],
+                platform_version,
+            )? {
+                if pending_index_build.is_building(index.name.as_str()) {
+                    return Err(Error::Query(QuerySyntaxError::IndexNotYetQueryable(
+                        format!(
+                            "index {} of document type {} is still being built",
+                            index.name,
+                            self.document_type.name()
+                        ),
+                    )));
+                }
+            }
             self.get_non_primary_key_path_query(
                 document_type_path,
                 starts_at_document,
@@ -1962,6 +1956,93 @@ impl<'a> From<&DriveDocumentQuery<'a>> for BTreeMap<String, Value> {
     }
 }
 
+#[cfg(any(feature = "server", feature = "verify"))]
+/// Parses a SQL string holding a single select query
+pub(crate) fn sql_select_query(sql_string: &str) -> Result<Box<ast::Query>, Error> {
+    let dialect: MySqlDialect = MySqlDialect {};
+    let statements: Vec<Statement> = Parser::parse_sql(&dialect, sql_string)
+        .map_err(|e| Error::Query(QuerySyntaxError::SQLParsingError(e)))?;
+
+    // Should ideally iterate over each statement
+    let first_statement =
+        statements
+            .into_iter()
+            .next()
+            .ok_or(Error::Query(QuerySyntaxError::InvalidSQL(
+                "Issue parsing sql getting first statement".to_string(),
+            )))?;
+
+    match first_statement {
+        ast::Statement::Query(query_struct) => Ok(query_struct),
+        _ => Err(Error::Query(QuerySyntaxError::InvalidSQL(
+            "Issue parsing sql: not a query".to_string(),
+        ))),
+    }
+}
+
+#[cfg(any(feature = "server", feature = "verify"))]
+/// Returns the select section of a SQL query
+pub(crate) fn sql_select(query: &ast::Query) -> Result<&Select, Error> {
+    match &*query.body {
+        ast::SetExpr::Select(select) => Ok(select),
+        _ => Err(Error::Query(QuerySyntaxError::InvalidSQL(
+            "Issue parsing sql: Not a select".to_string(),
+        ))),
+    }
+}
+
+#[cfg(any(feature = "server", feature = "verify"))]
+/// Returns the document type of the `from` section of a SQL select and its where clauses
+pub(crate) fn sql_select_document_type_and_where_clauses<'c>(
+    select: &Select,
+    contract: &'c DataContract,
+) -> Result<(&'c DocumentType, Vec<WhereClause>), Error> {
+    // Get the document type from the 'from' section
+    let document_type_name = match &select
+        .from
+        .first()
+        .ok_or(Error::Query(QuerySyntaxError::InvalidSQL(
+            "Invalid query: missing from section".to_string(),
+        )))?
+        .relation
+    {
+        Table { name, .. } => name.0.first().as_ref().map(|identifier| &identifier.value),
+        _ => None,
+    }
+    .ok_or(Error::Query(QuerySyntaxError::InvalidSQL(
+        "Issue parsing sql: invalid from value".to_string(),
+    )))?;
+
+    let document_type = contract
+        .document_types()
+        .get(document_type_name)
+        .ok_or(Error::Query(QuerySyntaxError::DocumentTypeNotFound(
+            "document type not found in contract",
+        )))?;
+
+    // Restrictions
+    // only binary where clauses are supported
+    // i.e. [<fieldname>, <operator>, <value>]
+    // [and] is used to separate where clauses
+    // currently where clauses are either binary operations or list descriptions (in clauses)
+    // hence once [and] is encountered [left] and [right] must be only one of the above
+    // i.e other where clauses
+    // e.g. firstname = wisdom and lastname = ogwu
+    // if op is not [and] then [left] or [right] must not be a binary operation or list description
+    let mut all_where_clauses: Vec<WhereClause> = Vec::new();
+
+    // Where clauses are optional
+    if let Some(selection_tree) = select.selection.as_ref() {
+        WhereClause::build_where_clauses_from_operations(
+            selection_tree,
+            document_type,
+            &mut all_where_clauses,
+        )?;
+    }
+
+    Ok((document_type, all_where_clauses))
+}
+
 #[cfg(feature = "server")]
 #[cfg(test)]
 mod tests {
@@ -1,4 +1,5 @@
 use crate::drive::votes::paths::vote_contested_resource_identity_votes_tree_path_for_identity_vec;
+use crate::drive::Drive;
 use crate::error::Error;
 use crate::query::Query;
 use dpp::identifier::Identifier;
@@ -17,13 +18,21 @@ pub struct IdentityBasedVoteDriveQuery {
 impl IdentityBasedVoteDriveQuery {
     /// Operations to construct a path query.
     pub fn construct_path_query(&self) -> Result<PathQuery, Error> {
+        let vote_id = self.vote_poll.unique_id()?;
+
+        if let VotePoll::GovernanceProposalVotePoll(_) = &self.vote_poll {
+            // Governance proposal votes are stored under the vote poll itself
+            return Ok(Drive::governance_proposal_vote_query(
+                vote_id.to_buffer(),
+                self.identity_id.to_buffer(),
+            ));
+        }
+
         // First we should get the overall document_type_path
         let path = vote_contested_resource_identity_votes_tree_path_for_identity_vec(
             self.identity_id.as_bytes(),
         );
 
-        let vote_id = self.vote_poll.unique_id()?;
-
         let mut query = Query::new();
         query.insert_key(vote_id.to_vec());
 
@@ -0,0 +1,52 @@
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::state_transition_action::action_convert_to_operations::DriveHighLevelOperationConverter;
+use crate::state_transition_action::contract::data_contract_delete::DataContractDeleteTransitionAction;
+use crate::util::batch::DriveOperation::{DataContractOperation, IdentityOperation};
+use crate::util::batch::{DataContractOperationType, DriveOperation, IdentityOperationType};
+use dpp::block::epoch::Epoch;
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteMode;
+use dpp::version::PlatformVersion;
+
+impl DriveHighLevelOperationConverter for DataContractDeleteTransitionAction {
+    fn into_high_level_drive_operations<'a>(
+        self,
+        _epoch: &Epoch,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveOperation<'a>>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .state_transitions
+            .convert_to_high_level_operations
+            .data_contract_delete_transition
+        {
+            0 => {
+                let contract_operation = match self.mode() {
+                    DataContractDeleteMode::Archive => DataContractOperationType::ArchiveContract {
+                        contract_id: self.data_contract_id(),
+                        owner_id: self.owner_id(),
+                    },
+                    DataContractDeleteMode::Delete => DataContractOperationType::DeleteContract {
+                        contract_id: self.data_contract_id(),
+                    },
+                };
+
+                Ok(vec![
+                    IdentityOperation(IdentityOperationType::UpdateIdentityContractNonce {
+                        identity_id: self.owner_id().into_buffer(),
+                        contract_id: self.data_contract_id().into_buffer(),
+                        nonce: self.identity_contract_nonce(),
+                    }),
+                    DataContractOperation(contract_operation),
+                ])
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "DataContractDeleteTransitionAction::into_high_level_drive_operations"
+                    .to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -1,2 +1,3 @@
 mod data_contract_create_transition;
+mod data_contract_delete_transition;
 mod data_contract_update_transition;
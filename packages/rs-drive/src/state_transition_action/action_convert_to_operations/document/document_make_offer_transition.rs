@@ -0,0 +1,84 @@
+use crate::error::Error;
+use crate::state_transition_action::action_convert_to_operations::document::DriveHighLevelDocumentOperationConverter;
+use crate::util::batch::drive_op_batch::PrefundedSpecializedBalanceOperationType;
+use crate::util::batch::DriveOperation::{
+    DocumentOperation, IdentityOperation, PrefundedSpecializedBalanceOperation,
+};
+use crate::util::batch::{DocumentOperationType, DriveOperation, IdentityOperationType};
+use crate::util::storage_flags::StorageFlags;
+use dpp::block::epoch::Epoch;
+use dpp::nft::offer::DocumentOffer;
+
+use dpp::prelude::Identifier;
+use std::borrow::Cow;
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+use crate::state_transition_action::document::documents_batch::document_transition::document_make_offer_transition_action::{DocumentMakeOfferTransitionAction, DocumentMakeOfferTransitionActionAccessorsV0};
+use dpp::version::PlatformVersion;
+use crate::error::drive::DriveError;
+
+impl DriveHighLevelDocumentOperationConverter for DocumentMakeOfferTransitionAction {
+    fn into_high_level_document_drive_operations<'b>(
+        self,
+        epoch: &Epoch,
+        owner_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveOperation<'b>>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .state_transitions
+            .convert_to_high_level_operations
+            .document_make_offer_transition
+        {
+            0 => {
+                let data_contract_id = self.base().data_contract_id();
+                let document_type_name = self.base().document_type_name().clone();
+                let identity_contract_nonce = self.base().identity_contract_nonce();
+                let offer = self.offer().clone();
+
+                // The offer is paid for by the bidder
+                let storage_flags =
+                    StorageFlags::new_single_epoch(epoch.index, Some(owner_id.to_buffer()));
+
+                let escrow_balance_id = DocumentOffer::escrow_balance_id(
+                    data_contract_id,
+                    &document_type_name,
+                    offer.document_id,
+                    offer.bidder_id,
+                );
+
+                Ok(vec![
+                    IdentityOperation(IdentityOperationType::UpdateIdentityContractNonce {
+                        identity_id: owner_id.into_buffer(),
+                        contract_id: data_contract_id.into_buffer(),
+                        nonce: identity_contract_nonce,
+                    }),
+                    // The offered credits are escrowed until the offer is accepted or withdrawn
+                    IdentityOperation(IdentityOperationType::RemoveFromIdentityBalance {
+                        identity_id: owner_id.to_buffer(),
+                        balance_to_remove: offer.price,
+                    }),
+                    PrefundedSpecializedBalanceOperation(
+                        PrefundedSpecializedBalanceOperationType::CreateNewPrefundedBalance {
+                            prefunded_specialized_balance_id: escrow_balance_id,
+                            add_balance: offer.price,
+                        },
+                    ),
+                    DocumentOperation(DocumentOperationType::AddDocumentOffer {
+                        offer,
+                        contract_id: data_contract_id,
+                        document_type_name,
+                        storage_flags: Some(Cow::Owned(storage_flags)),
+                    }),
+                ])
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method:
+                    "DocumentMakeOfferTransitionAction::into_high_level_document_drive_operations"
+                        .to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -61,6 +61,27 @@ impl DriveHighLevelDocumentOperationConverter for DocumentTransitionAction {
                 bump_identity_contract_nonce_action,
             ) => bump_identity_contract_nonce_action
                 .into_high_level_drive_operations(epoch, platform_version),
+            DocumentTransitionAction::MakeOfferAction(document_make_offer_transition) => {
+                document_make_offer_transition.into_high_level_document_drive_operations(
+                    epoch,
+                    owner_id,
+                    platform_version,
+                )
+            }
+            DocumentTransitionAction::WithdrawOfferAction(document_withdraw_offer_transition) => {
+                document_withdraw_offer_transition.into_high_level_document_drive_operations(
+                    epoch,
+                    owner_id,
+                    platform_version,
+                )
+            }
+            DocumentTransitionAction::AcceptOfferAction(document_accept_offer_transition) => {
+                document_accept_offer_transition.into_high_level_document_drive_operations(
+                    epoch,
+                    owner_id,
+                    platform_version,
+                )
+            }
         }
     }
 }
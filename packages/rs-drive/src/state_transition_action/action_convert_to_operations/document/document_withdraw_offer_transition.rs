@@ -0,0 +1,78 @@
+use crate::error::Error;
+use crate::state_transition_action::action_convert_to_operations::document::DriveHighLevelDocumentOperationConverter;
+use crate::util::batch::drive_op_batch::PrefundedSpecializedBalanceOperationType;
+use crate::util::batch::DriveOperation::{
+    DocumentOperation, IdentityOperation, PrefundedSpecializedBalanceOperation,
+};
+use crate::util::batch::{DocumentOperationType, DriveOperation, IdentityOperationType};
+use dpp::block::epoch::Epoch;
+use dpp::nft::offer::DocumentOffer;
+
+use dpp::prelude::Identifier;
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+use crate::state_transition_action::document::documents_batch::document_transition::document_withdraw_offer_transition_action::{DocumentWithdrawOfferTransitionAction, DocumentWithdrawOfferTransitionActionAccessorsV0};
+use dpp::version::PlatformVersion;
+use crate::error::drive::DriveError;
+
+impl DriveHighLevelDocumentOperationConverter for DocumentWithdrawOfferTransitionAction {
+    fn into_high_level_document_drive_operations<'b>(
+        self,
+        _epoch: &Epoch,
+        owner_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveOperation<'b>>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .state_transitions
+            .convert_to_high_level_operations
+            .document_withdraw_offer_transition
+        {
+            0 => {
+                let data_contract_id = self.base().data_contract_id();
+                let document_type_name = self.base().document_type_name().clone();
+                let identity_contract_nonce = self.base().identity_contract_nonce();
+                let offer = self.offer();
+
+                let escrow_balance_id = DocumentOffer::escrow_balance_id(
+                    data_contract_id,
+                    &document_type_name,
+                    offer.document_id,
+                    offer.bidder_id,
+                );
+
+                Ok(vec![
+                    IdentityOperation(IdentityOperationType::UpdateIdentityContractNonce {
+                        identity_id: owner_id.into_buffer(),
+                        contract_id: data_contract_id.into_buffer(),
+                        nonce: identity_contract_nonce,
+                    }),
+                    DocumentOperation(DocumentOperationType::RemoveDocumentOffer {
+                        contract_id: data_contract_id,
+                        document_type_name,
+                        document_id: offer.document_id,
+                        bidder_id: offer.bidder_id,
+                    }),
+                    // The escrowed credits are given back to the bidder
+                    PrefundedSpecializedBalanceOperation(
+                        PrefundedSpecializedBalanceOperationType::DeductFromPrefundedBalance {
+                            prefunded_specialized_balance_id: escrow_balance_id,
+                            remove_balance: offer.price,
+                        },
+                    ),
+                    IdentityOperation(IdentityOperationType::AddToIdentityBalance {
+                        identity_id: offer.bidder_id.to_buffer(),
+                        added_balance: offer.price,
+                    }),
+                ])
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method:
+                    "DocumentWithdrawOfferTransitionAction::into_high_level_document_drive_operations"
+                        .to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
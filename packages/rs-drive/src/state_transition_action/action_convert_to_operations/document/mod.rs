@@ -4,13 +4,16 @@ use dpp::block::epoch::Epoch;
 use dpp::platform_value::Identifier;
 use dpp::version::PlatformVersion;
 
+mod document_accept_offer_transition;
 mod document_create_transition;
 mod document_delete_transition;
+mod document_make_offer_transition;
 mod document_purchase_transition;
 mod document_replace_transition;
 mod document_transfer_transition;
 mod document_transition;
 mod document_update_price_transition;
+mod document_withdraw_offer_transition;
 mod documents_batch_transition;
 
 /// A converter that will get High Level Drive Operations from State transitions
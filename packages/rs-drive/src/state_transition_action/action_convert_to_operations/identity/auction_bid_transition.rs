@@ -0,0 +1,74 @@
+use crate::state_transition_action::action_convert_to_operations::DriveHighLevelOperationConverter;
+use crate::util::batch::drive_op_batch::PrefundedSpecializedBalanceOperationType;
+use crate::util::batch::DriveOperation::{IdentityOperation, PrefundedSpecializedBalanceOperation};
+use crate::util::batch::{DriveOperation, IdentityOperationType};
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::state_transition_action::identity::auction_bid::AuctionBidTransitionAction;
+use dpp::block::epoch::Epoch;
+use dpp::version::PlatformVersion;
+
+impl DriveHighLevelOperationConverter for AuctionBidTransitionAction {
+    fn into_high_level_drive_operations<'a>(
+        self,
+        _epoch: &Epoch,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveOperation<'a>>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .state_transitions
+            .convert_to_high_level_operations
+            .auction_bid_transition
+        {
+            0 => {
+                let identity_id = self.identity_id();
+                let escrowed_amount = self.escrowed_amount();
+                let nonce = self.nonce();
+
+                let mut drive_operations = vec![IdentityOperation(
+                    IdentityOperationType::UpdateIdentityNonce {
+                        identity_id: identity_id.into_buffer(),
+                        nonce,
+                    },
+                )];
+
+                if escrowed_amount > 0 {
+                    // The deposit is held in the specialized balance of the vote poll until the
+                    // auction is resolved
+                    drive_operations.push(PrefundedSpecializedBalanceOperation(
+                        PrefundedSpecializedBalanceOperationType::CreateNewPrefundedBalance {
+                            prefunded_specialized_balance_id: self
+                                .vote_poll()
+                                .specialized_balance_id()?,
+                            add_balance: escrowed_amount,
+                        },
+                    ));
+
+                    drive_operations.push(IdentityOperation(
+                        IdentityOperationType::RemoveFromIdentityBalance {
+                            identity_id: identity_id.into_buffer(),
+                            balance_to_remove: escrowed_amount,
+                        },
+                    ));
+                }
+
+                let AuctionBidTransitionAction::V0(v0) = self;
+
+                drive_operations.push(IdentityOperation(IdentityOperationType::PlaceAuctionBid {
+                    bidder_id: identity_id.into_buffer(),
+                    vote_poll: v0.vote_poll,
+                    stored_bid: v0.stored_bid,
+                }));
+
+                Ok(drive_operations)
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "AuctionBidTransitionAction::into_high_level_drive_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
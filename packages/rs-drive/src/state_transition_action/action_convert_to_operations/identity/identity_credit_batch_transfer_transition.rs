@@ -0,0 +1,64 @@
+use crate::state_transition_action::action_convert_to_operations::DriveHighLevelOperationConverter;
+use crate::util::batch::DriveOperation::IdentityOperation;
+use crate::util::batch::{DriveOperation, IdentityOperationType};
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::state_transition_action::identity::identity_credit_batch_transfer::IdentityCreditBatchTransferTransitionAction;
+use dpp::block::epoch::Epoch;
+use dpp::version::PlatformVersion;
+
+impl DriveHighLevelOperationConverter for IdentityCreditBatchTransferTransitionAction {
+    fn into_high_level_drive_operations<'a>(
+        self,
+        _epoch: &Epoch,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveOperation<'a>>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .state_transitions
+            .convert_to_high_level_operations
+            .identity_credit_batch_transfer_transition
+        {
+            0 => {
+                let identity_id = self.identity_id();
+                let total_transfer_amount = self.total_transfer_amount();
+                let nonce = self.nonce();
+
+                let mut drive_operations = Vec::with_capacity(self.outputs().len() + 2);
+
+                drive_operations.push(IdentityOperation(
+                    IdentityOperationType::UpdateIdentityNonce {
+                        identity_id: identity_id.into_buffer(),
+                        nonce,
+                    },
+                ));
+                drive_operations.push(IdentityOperation(
+                    IdentityOperationType::RemoveFromIdentityBalance {
+                        identity_id: identity_id.to_buffer(),
+                        balance_to_remove: total_transfer_amount,
+                    },
+                ));
+
+                for (recipient_id, amount) in self.outputs() {
+                    drive_operations.push(IdentityOperation(
+                        IdentityOperationType::AddToIdentityBalance {
+                            identity_id: recipient_id.to_buffer(),
+                            added_balance: *amount,
+                        },
+                    ));
+                }
+
+                Ok(drive_operations)
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method:
+                    "IdentityCreditBatchTransferTransitionAction::into_high_level_drive_operations"
+                        .to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::state_transition_action::action_convert_to_operations::DriveHighLevelOperationConverter;
+use crate::util::batch::DriveOperation::IdentityOperation;
+use crate::util::batch::{DriveOperation, IdentityOperationType};
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::state_transition_action::identity::identity_recovery::v0::IdentityRecoveryActionOperation;
+use crate::state_transition_action::identity::identity_recovery::IdentityRecoveryTransitionAction;
+use dpp::block::epoch::Epoch;
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::version::PlatformVersion;
+
+impl DriveHighLevelOperationConverter for IdentityRecoveryTransitionAction {
+    fn into_high_level_drive_operations<'a>(
+        self,
+        _epoch: &Epoch,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveOperation<'a>>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .state_transitions
+            .convert_to_high_level_operations
+            .identity_recovery_transition
+        {
+            0 => {
+                let identity_id = self.identity_id().to_buffer();
+                let nonce = self.nonce();
+
+                let mut drive_operations = vec![IdentityOperation(
+                    IdentityOperationType::UpdateIdentityNonce { identity_id, nonce },
+                )];
+
+                match self.operation_owned() {
+                    IdentityRecoveryActionOperation::SetConfig(config) => {
+                        drive_operations.push(IdentityOperation(
+                            IdentityOperationType::SetIdentityRecoveryConfig {
+                                identity_id,
+                                config,
+                            },
+                        ));
+                    }
+                    IdentityRecoveryActionOperation::Initiate(pending_recovery) => {
+                        drive_operations.push(IdentityOperation(
+                            IdentityOperationType::SetIdentityPendingRecovery {
+                                identity_id,
+                                pending_recovery,
+                            },
+                        ));
+                    }
+                    IdentityRecoveryActionOperation::Cancel => {
+                        drive_operations.push(IdentityOperation(
+                            IdentityOperationType::RemoveIdentityPendingRecovery { identity_id },
+                        ));
+                    }
+                    IdentityRecoveryActionOperation::Complete {
+                        new_master_key,
+                        master_keys_to_disable,
+                        revision,
+                    } => {
+                        drive_operations.push(IdentityOperation(
+                            IdentityOperationType::UpdateIdentityRevision {
+                                identity_id,
+                                revision,
+                            },
+                        ));
+
+                        let (unique_keys_to_add, non_unique_keys_to_add) =
+                            if new_master_key.key_type().is_unique_key_type() {
+                                (vec![new_master_key], vec![])
+                            } else {
+                                (vec![], vec![new_master_key])
+                            };
+
+                        drive_operations.push(IdentityOperation(
+                            IdentityOperationType::AddNewKeysToIdentity {
+                                identity_id,
+                                unique_keys_to_add,
+                                non_unique_keys_to_add,
+                            },
+                        ));
+
+                        if !master_keys_to_disable.is_empty() {
+                            drive_operations.push(IdentityOperation(
+                                IdentityOperationType::DisableIdentityKeys {
+                                    identity_id,
+                                    keys_ids: master_keys_to_disable,
+                                },
+                            ));
+                        }
+
+                        drive_operations.push(IdentityOperation(
+                            IdentityOperationType::RemoveIdentityPendingRecovery { identity_id },
+                        ));
+                    }
+                }
+
+                Ok(drive_operations)
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "IdentityRecoveryTransitionAction::into_high_level_drive_operations"
+                    .to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -30,10 +30,11 @@ impl DriveHighLevelOperationConverter for MasternodeVoteTransitionAction {
                 let strength = self.vote_strength();
                 let previous_resource_vote_choice_to_remove =
                     self.take_previous_resource_vote_choice_to_remove();
+                let vote_poll_funding = self.vote_poll_funding();
                 let vote = self.vote_owned();
                 let prefunded_specialized_balance_id = vote.specialized_balance_id()?.ok_or(Error::Protocol(ProtocolError::VoteError("vote does not have a specialized balance from where it can use to pay for processing (this should have been caught during validation)".to_string())))?;
 
-                let drive_operations = vec![
+                let mut drive_operations = vec![
                     IdentityOperation(IdentityOperationType::UpdateIdentityNonce {
                         identity_id: voter_identity_id.into_buffer(),
                         nonce,
@@ -45,8 +46,26 @@ impl DriveHighLevelOperationConverter for MasternodeVoteTransitionAction {
                         vote,
                         previous_resource_vote_choice_to_remove,
                     }),
+                ];
+
+                if let Some(vote_poll_funding) = vote_poll_funding {
+                    // The vote opening a governance proposal vote poll funds it from the voter
+                    // identity, which also pays for processing this vote
+                    drive_operations.push(IdentityOperation(
+                        IdentityOperationType::RemoveFromIdentityBalance {
+                            identity_id: voter_identity_id.into_buffer(),
+                            balance_to_remove: vote_poll_funding,
+                        },
+                    ));
+                    drive_operations.push(PrefundedSpecializedBalanceOperation(
+                        PrefundedSpecializedBalanceOperationType::CreateNewPrefundedBalance {
+                            prefunded_specialized_balance_id,
+                            add_balance: vote_poll_funding,
+                        },
+                    ));
+                } else {
                     // Casting a vote has a fixed cost
-                    PrefundedSpecializedBalanceOperation(
+                    drive_operations.push(PrefundedSpecializedBalanceOperation(
                         PrefundedSpecializedBalanceOperationType::DeductFromPrefundedBalance {
                             prefunded_specialized_balance_id,
                             remove_balance: platform_version
@@ -54,8 +73,8 @@ impl DriveHighLevelOperationConverter for MasternodeVoteTransitionAction {
                                 .vote_resolution_fund_fees
                                 .contested_document_single_vote_cost,
                         },
-                    ),
-                ];
+                    ));
+                }
                 Ok(drive_operations)
             }
             version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
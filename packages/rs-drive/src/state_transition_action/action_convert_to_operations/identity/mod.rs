@@ -1,6 +1,9 @@
+mod auction_bid_transition;
 mod identity_create_transition;
+mod identity_credit_batch_transfer_transition;
 mod identity_credit_transfer_transition;
 mod identity_credit_withdrawal_transition;
+mod identity_recovery_transition;
 mod identity_top_up_transition;
 mod identity_update_transition;
 mod masternode_vote_transition;
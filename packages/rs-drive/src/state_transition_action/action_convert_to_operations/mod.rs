@@ -39,6 +39,10 @@ impl DriveHighLevelOperationConverter for StateTransitionAction {
                 data_contract_update_transition
                     .into_high_level_drive_operations(epoch, platform_version)
             }
+            StateTransitionAction::DataContractDeleteAction(data_contract_delete_transition) => {
+                data_contract_delete_transition
+                    .into_high_level_drive_operations(epoch, platform_version)
+            }
             StateTransitionAction::DocumentsBatchAction(documents_batch_transition) => {
                 documents_batch_transition.into_high_level_drive_operations(epoch, platform_version)
             }
@@ -55,13 +59,24 @@ impl DriveHighLevelOperationConverter for StateTransitionAction {
             StateTransitionAction::IdentityUpdateAction(identity_update_transition) => {
                 identity_update_transition.into_high_level_drive_operations(epoch, platform_version)
             }
+            StateTransitionAction::IdentityRecoveryAction(identity_recovery_transition) => {
+                identity_recovery_transition
+                    .into_high_level_drive_operations(epoch, platform_version)
+            }
             StateTransitionAction::IdentityCreditTransferAction(
                 identity_credit_transfer_transition,
             ) => identity_credit_transfer_transition
                 .into_high_level_drive_operations(epoch, platform_version),
+            StateTransitionAction::IdentityCreditBatchTransferAction(
+                identity_credit_batch_transfer_transition,
+            ) => identity_credit_batch_transfer_transition
+                .into_high_level_drive_operations(epoch, platform_version),
             StateTransitionAction::MasternodeVoteAction(masternode_vote_transition) => {
                 masternode_vote_transition.into_high_level_drive_operations(epoch, platform_version)
             }
+            StateTransitionAction::AuctionBidAction(auction_bid_transition) => {
+                auction_bid_transition.into_high_level_drive_operations(epoch, platform_version)
+            }
             StateTransitionAction::BumpIdentityNonceAction(bump_identity_nonce_transition) => {
                 bump_identity_nonce_transition
                     .into_high_level_drive_operations(epoch, platform_version)
@@ -0,0 +1,56 @@
+/// transformer
+pub mod transformer;
+/// v0
+pub mod v0;
+
+use crate::state_transition_action::contract::data_contract_delete::v0::DataContractDeleteTransitionActionV0;
+use derive_more::From;
+use dpp::identifier::Identifier;
+use dpp::prelude::{IdentityNonce, UserFeeIncrease};
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteMode;
+
+/// data contract delete transition action
+#[derive(Debug, Clone, From)]
+pub enum DataContractDeleteTransitionAction {
+    /// v0
+    V0(DataContractDeleteTransitionActionV0),
+}
+
+impl DataContractDeleteTransitionAction {
+    /// data contract id
+    pub fn data_contract_id(&self) -> Identifier {
+        match self {
+            DataContractDeleteTransitionAction::V0(transition) => transition.data_contract_id,
+        }
+    }
+
+    /// owner id
+    pub fn owner_id(&self) -> Identifier {
+        match self {
+            DataContractDeleteTransitionAction::V0(transition) => transition.owner_id,
+        }
+    }
+
+    /// whether the contract is archived or deleted
+    pub fn mode(&self) -> DataContractDeleteMode {
+        match self {
+            DataContractDeleteTransitionAction::V0(transition) => transition.mode,
+        }
+    }
+
+    /// identity contract nonce
+    pub fn identity_contract_nonce(&self) -> IdentityNonce {
+        match self {
+            DataContractDeleteTransitionAction::V0(transition) => {
+                transition.identity_contract_nonce
+            }
+        }
+    }
+
+    /// fee multiplier
+    pub fn user_fee_increase(&self) -> UserFeeIncrease {
+        match self {
+            DataContractDeleteTransitionAction::V0(transition) => transition.user_fee_increase,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+/// transformer
+pub mod transformer;
+
+use dpp::identifier::Identifier;
+use dpp::prelude::{IdentityNonce, UserFeeIncrease};
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteMode;
+
+/// data contract delete transition action v0
+#[derive(Debug, Clone)]
+pub struct DataContractDeleteTransitionActionV0 {
+    /// data contract id
+    pub data_contract_id: Identifier,
+    /// owner id
+    pub owner_id: Identifier,
+    /// whether the contract is archived or deleted
+    pub mode: DataContractDeleteMode,
+    /// identity contract nonce
+    pub identity_contract_nonce: IdentityNonce,
+    /// fee multiplier
+    pub user_fee_increase: UserFeeIncrease,
+}
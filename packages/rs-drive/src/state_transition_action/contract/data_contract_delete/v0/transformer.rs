@@ -0,0 +1,42 @@
+use crate::state_transition_action::contract::data_contract_delete::v0::DataContractDeleteTransitionActionV0;
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransitionV0;
+
+impl From<DataContractDeleteTransitionV0> for DataContractDeleteTransitionActionV0 {
+    fn from(value: DataContractDeleteTransitionV0) -> Self {
+        let DataContractDeleteTransitionV0 {
+            owner_id,
+            data_contract_id,
+            mode,
+            identity_contract_nonce,
+            user_fee_increase,
+            ..
+        } = value;
+        DataContractDeleteTransitionActionV0 {
+            data_contract_id,
+            owner_id,
+            mode,
+            identity_contract_nonce,
+            user_fee_increase,
+        }
+    }
+}
+
+impl From<&DataContractDeleteTransitionV0> for DataContractDeleteTransitionActionV0 {
+    fn from(value: &DataContractDeleteTransitionV0) -> Self {
+        let DataContractDeleteTransitionV0 {
+            owner_id,
+            data_contract_id,
+            mode,
+            identity_contract_nonce,
+            user_fee_increase,
+            ..
+        } = value;
+        DataContractDeleteTransitionActionV0 {
+            data_contract_id: *data_contract_id,
+            owner_id: *owner_id,
+            mode: *mode,
+            identity_contract_nonce: *identity_contract_nonce,
+            user_fee_increase: *user_fee_increase,
+        }
+    }
+}
@@ -17,6 +17,13 @@ impl TransitionActionTypeGetter for DocumentTransitionAction {
             DocumentTransitionAction::BumpIdentityDataContractNonce(_) => {
                 DocumentTransitionActionType::IgnoreWhileBumpingRevision
             }
+            DocumentTransitionAction::MakeOfferAction(_) => DocumentTransitionActionType::MakeOffer,
+            DocumentTransitionAction::WithdrawOfferAction(_) => {
+                DocumentTransitionActionType::WithdrawOffer
+            }
+            DocumentTransitionAction::AcceptOfferAction(_) => {
+                DocumentTransitionActionType::AcceptOffer
+            }
         }
     }
 }
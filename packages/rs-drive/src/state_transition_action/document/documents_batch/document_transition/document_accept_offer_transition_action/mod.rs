@@ -0,0 +1,58 @@
+mod v0;
+
+use derive_more::From;
+use dpp::document::Document;
+use dpp::nft::offer::DocumentOffer;
+
+use dpp::platform_value::Identifier;
+pub use v0::*;
+
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+
+/// transformer
+pub mod transformer;
+
+/// action
+#[derive(Debug, Clone, From)]
+pub enum DocumentAcceptOfferTransitionAction {
+    /// v0
+    V0(DocumentAcceptOfferTransitionActionV0),
+}
+
+impl DocumentAcceptOfferTransitionActionAccessorsV0 for DocumentAcceptOfferTransitionAction {
+    fn base(&self) -> &DocumentBaseTransitionAction {
+        match self {
+            DocumentAcceptOfferTransitionAction::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_owned(self) -> DocumentBaseTransitionAction {
+        match self {
+            DocumentAcceptOfferTransitionAction::V0(v0) => v0.base,
+        }
+    }
+
+    fn document(&self) -> &Document {
+        match self {
+            DocumentAcceptOfferTransitionAction::V0(v0) => &v0.document,
+        }
+    }
+
+    fn document_owned(self) -> Document {
+        match self {
+            DocumentAcceptOfferTransitionAction::V0(v0) => v0.document,
+        }
+    }
+
+    fn original_owner_id(&self) -> Identifier {
+        match self {
+            DocumentAcceptOfferTransitionAction::V0(v0) => v0.original_owner_id,
+        }
+    }
+
+    fn offer(&self) -> &DocumentOffer {
+        match self {
+            DocumentAcceptOfferTransitionAction::V0(v0) => &v0.offer,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use dpp::block::block_info::BlockInfo;
+use dpp::document::Document;
+use dpp::nft::offer::DocumentOffer;
+use dpp::platform_value::Identifier;
+use std::sync::Arc;
+
+use dpp::ProtocolError;
+use dpp::state_transition::documents_batch_transition::document_transition::DocumentAcceptOfferTransition;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::document::documents_batch::document_transition::document_accept_offer_transition_action::{DocumentAcceptOfferTransitionAction, DocumentAcceptOfferTransitionActionV0};
+
+impl DocumentAcceptOfferTransitionAction {
+    /// try from borrowed
+    pub fn try_from_borrowed_document_accept_offer_transition(
+        document_accept_offer_transition: &DocumentAcceptOfferTransition,
+        original_document: Document,
+        offer: DocumentOffer,
+        block_info: &BlockInfo,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+    ) -> Result<Self, ProtocolError> {
+        match document_accept_offer_transition {
+            DocumentAcceptOfferTransition::V0(v0) => Ok(
+                DocumentAcceptOfferTransitionActionV0::try_from_borrowed_document_accept_offer_transition(
+                    v0,
+                    original_document,
+                    offer,
+                    block_info,
+                    get_data_contract,
+                )?
+                .into(),
+            ),
+        }
+    }
+}
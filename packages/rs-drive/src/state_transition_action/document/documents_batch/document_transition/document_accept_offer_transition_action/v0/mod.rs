@@ -0,0 +1,37 @@
+pub mod transformer;
+
+use dpp::document::Document;
+use dpp::identifier::Identifier;
+use dpp::nft::offer::DocumentOffer;
+
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+
+/// document accept offer transition action v0
+#[derive(Debug, Clone)]
+pub struct DocumentAcceptOfferTransitionActionV0 {
+    /// Document Base Transition
+    pub base: DocumentBaseTransitionAction,
+    /// The document transferred to the bidder
+    pub document: Document,
+    /// The original owner id
+    pub original_owner_id: Identifier,
+    /// The accepted offer
+    pub offer: DocumentOffer,
+}
+
+/// document accept offer transition action accessors v0
+pub trait DocumentAcceptOfferTransitionActionAccessorsV0 {
+    /// base
+    fn base(&self) -> &DocumentBaseTransitionAction;
+    /// base owned
+    fn base_owned(self) -> DocumentBaseTransitionAction;
+    /// the document transferred to the bidder as a ref
+    fn document(&self) -> &Document;
+    /// the document transferred to the bidder as owned
+    fn document_owned(self) -> Document;
+
+    /// The original owner id
+    fn original_owner_id(&self) -> Identifier;
+    /// The accepted offer
+    fn offer(&self) -> &DocumentOffer;
+}
@@ -0,0 +1,44 @@
+mod v0;
+
+use derive_more::From;
+use dpp::nft::offer::DocumentOffer;
+use dpp::platform_value::Identifier;
+pub use v0::*;
+
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+
+/// transformer
+pub mod transformer;
+
+/// action
+#[derive(Debug, Clone, From)]
+pub enum DocumentMakeOfferTransitionAction {
+    /// v0
+    V0(DocumentMakeOfferTransitionActionV0),
+}
+
+impl DocumentMakeOfferTransitionActionAccessorsV0 for DocumentMakeOfferTransitionAction {
+    fn base(&self) -> &DocumentBaseTransitionAction {
+        match self {
+            DocumentMakeOfferTransitionAction::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_owned(self) -> DocumentBaseTransitionAction {
+        match self {
+            DocumentMakeOfferTransitionAction::V0(v0) => v0.base,
+        }
+    }
+
+    fn offer(&self) -> &DocumentOffer {
+        match self {
+            DocumentMakeOfferTransitionAction::V0(v0) => &v0.offer,
+        }
+    }
+
+    fn document_owner_id(&self) -> Identifier {
+        match self {
+            DocumentMakeOfferTransitionAction::V0(v0) => v0.document_owner_id,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use dpp::block::block_info::BlockInfo;
+use dpp::platform_value::Identifier;
+use std::sync::Arc;
+
+use dpp::ProtocolError;
+use dpp::state_transition::documents_batch_transition::document_transition::DocumentMakeOfferTransition;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::document::documents_batch::document_transition::document_make_offer_transition_action::{DocumentMakeOfferTransitionAction, DocumentMakeOfferTransitionActionV0};
+
+impl DocumentMakeOfferTransitionAction {
+    /// try from borrowed
+    pub fn try_from_borrowed_document_make_offer_transition(
+        document_make_offer_transition: &DocumentMakeOfferTransition,
+        document_owner_id: Identifier,
+        bidder_id: Identifier,
+        block_info: &BlockInfo,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+    ) -> Result<Self, ProtocolError> {
+        match document_make_offer_transition {
+            DocumentMakeOfferTransition::V0(v0) => Ok(
+                DocumentMakeOfferTransitionActionV0::try_from_borrowed_document_make_offer_transition(
+                    v0,
+                    document_owner_id,
+                    bidder_id,
+                    block_info,
+                    get_data_contract,
+                )?
+                .into(),
+            ),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+pub mod transformer;
+
+use dpp::identifier::Identifier;
+use dpp::nft::offer::DocumentOffer;
+
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+
+/// document make offer transition action v0
+#[derive(Debug, Clone)]
+pub struct DocumentMakeOfferTransitionActionV0 {
+    /// Document Base Transition
+    pub base: DocumentBaseTransitionAction,
+    /// The offer to be stored
+    pub offer: DocumentOffer,
+    /// The owner of the document when the offer is made
+    pub document_owner_id: Identifier,
+}
+
+/// document make offer transition action accessors v0
+pub trait DocumentMakeOfferTransitionActionAccessorsV0 {
+    /// base
+    fn base(&self) -> &DocumentBaseTransitionAction;
+    /// base owned
+    fn base_owned(self) -> DocumentBaseTransitionAction;
+    /// the offer to be stored
+    fn offer(&self) -> &DocumentOffer;
+    /// The owner of the document when the offer is made
+    fn document_owner_id(&self) -> Identifier;
+}
@@ -0,0 +1,46 @@
+use dpp::block::block_info::BlockInfo;
+use dpp::nft::offer::DocumentOffer;
+use dpp::platform_value::Identifier;
+use std::sync::Arc;
+
+use dpp::ProtocolError;
+use dpp::state_transition::documents_batch_transition::document_transition::document_make_offer_transition::DocumentMakeOfferTransitionV0;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::document::documents_batch::document_transition::document_make_offer_transition_action::v0::DocumentMakeOfferTransitionActionV0;
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::{DocumentBaseTransitionAction, DocumentBaseTransitionActionAccessorsV0};
+
+impl DocumentMakeOfferTransitionActionV0 {
+    /// try from borrowed
+    pub fn try_from_borrowed_document_make_offer_transition(
+        document_make_offer_transition: &DocumentMakeOfferTransitionV0,
+        document_owner_id: Identifier,
+        bidder_id: Identifier,
+        block_info: &BlockInfo,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+    ) -> Result<Self, ProtocolError> {
+        let DocumentMakeOfferTransitionV0 {
+            base,
+            price,
+            recipient_id,
+        } = document_make_offer_transition;
+        let base =
+            DocumentBaseTransitionAction::from_borrowed_base_transition_with_contract_lookup(
+                base,
+                get_data_contract,
+            )?;
+
+        let offer = DocumentOffer {
+            document_id: base.id(),
+            bidder_id,
+            recipient_id: *recipient_id,
+            price: *price,
+            created_at: block_info.time_ms,
+        };
+
+        Ok(DocumentMakeOfferTransitionActionV0 {
+            base,
+            offer,
+            document_owner_id,
+        })
+    }
+}
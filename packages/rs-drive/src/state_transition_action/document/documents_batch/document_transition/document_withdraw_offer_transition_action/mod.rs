@@ -0,0 +1,37 @@
+mod v0;
+
+use derive_more::From;
+use dpp::nft::offer::DocumentOffer;
+pub use v0::*;
+
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+
+/// transformer
+pub mod transformer;
+
+/// action
+#[derive(Debug, Clone, From)]
+pub enum DocumentWithdrawOfferTransitionAction {
+    /// v0
+    V0(DocumentWithdrawOfferTransitionActionV0),
+}
+
+impl DocumentWithdrawOfferTransitionActionAccessorsV0 for DocumentWithdrawOfferTransitionAction {
+    fn base(&self) -> &DocumentBaseTransitionAction {
+        match self {
+            DocumentWithdrawOfferTransitionAction::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_owned(self) -> DocumentBaseTransitionAction {
+        match self {
+            DocumentWithdrawOfferTransitionAction::V0(v0) => v0.base,
+        }
+    }
+
+    fn offer(&self) -> &DocumentOffer {
+        match self {
+            DocumentWithdrawOfferTransitionAction::V0(v0) => &v0.offer,
+        }
+    }
+}
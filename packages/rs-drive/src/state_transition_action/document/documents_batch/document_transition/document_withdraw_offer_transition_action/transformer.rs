@@ -0,0 +1,28 @@
+use dpp::nft::offer::DocumentOffer;
+use dpp::platform_value::Identifier;
+use std::sync::Arc;
+
+use dpp::ProtocolError;
+use dpp::state_transition::documents_batch_transition::document_transition::DocumentWithdrawOfferTransition;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::document::documents_batch::document_transition::document_withdraw_offer_transition_action::{DocumentWithdrawOfferTransitionAction, DocumentWithdrawOfferTransitionActionV0};
+
+impl DocumentWithdrawOfferTransitionAction {
+    /// try from borrowed
+    pub fn try_from_borrowed_document_withdraw_offer_transition(
+        document_withdraw_offer_transition: &DocumentWithdrawOfferTransition,
+        offer: DocumentOffer,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+    ) -> Result<Self, ProtocolError> {
+        match document_withdraw_offer_transition {
+            DocumentWithdrawOfferTransition::V0(v0) => Ok(
+                DocumentWithdrawOfferTransitionActionV0::try_from_borrowed_document_withdraw_offer_transition(
+                    v0,
+                    offer,
+                    get_data_contract,
+                )?
+                .into(),
+            ),
+        }
+    }
+}
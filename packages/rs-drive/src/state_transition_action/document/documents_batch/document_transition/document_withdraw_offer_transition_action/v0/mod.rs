@@ -0,0 +1,24 @@
+pub mod transformer;
+
+use dpp::nft::offer::DocumentOffer;
+
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+
+/// document withdraw offer transition action v0
+#[derive(Debug, Clone)]
+pub struct DocumentWithdrawOfferTransitionActionV0 {
+    /// Document Base Transition
+    pub base: DocumentBaseTransitionAction,
+    /// The offer to be removed
+    pub offer: DocumentOffer,
+}
+
+/// document withdraw offer transition action accessors v0
+pub trait DocumentWithdrawOfferTransitionActionAccessorsV0 {
+    /// base
+    fn base(&self) -> &DocumentBaseTransitionAction;
+    /// base owned
+    fn base_owned(self) -> DocumentBaseTransitionAction;
+    /// the offer to be removed
+    fn offer(&self) -> &DocumentOffer;
+}
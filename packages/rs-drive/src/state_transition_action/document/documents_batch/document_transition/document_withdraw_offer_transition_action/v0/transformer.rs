@@ -0,0 +1,27 @@
+use dpp::nft::offer::DocumentOffer;
+use dpp::platform_value::Identifier;
+use std::sync::Arc;
+
+use dpp::ProtocolError;
+use dpp::state_transition::documents_batch_transition::document_transition::document_withdraw_offer_transition::DocumentWithdrawOfferTransitionV0;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::document::documents_batch::document_transition::document_withdraw_offer_transition_action::v0::DocumentWithdrawOfferTransitionActionV0;
+use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
+
+impl DocumentWithdrawOfferTransitionActionV0 {
+    /// try from borrowed
+    pub fn try_from_borrowed_document_withdraw_offer_transition(
+        document_withdraw_offer_transition: &DocumentWithdrawOfferTransitionV0,
+        offer: DocumentOffer,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+    ) -> Result<Self, ProtocolError> {
+        let DocumentWithdrawOfferTransitionV0 { base } = document_withdraw_offer_transition;
+        let base =
+            DocumentBaseTransitionAction::from_borrowed_base_transition_with_contract_lookup(
+                base,
+                get_data_contract,
+            )?;
+
+        Ok(DocumentWithdrawOfferTransitionActionV0 { base, offer })
+    }
+}
@@ -1,10 +1,14 @@
 mod action_type;
+/// document_accept_offer_transition_action
+pub mod document_accept_offer_transition_action;
 /// document_base_transition_action
 pub mod document_base_transition_action;
 /// document_create_transition_action
 pub mod document_create_transition_action;
 /// document_delete_transition_action
 pub mod document_delete_transition_action;
+/// document_make_offer_transition_action
+pub mod document_make_offer_transition_action;
 /// document_purchase_transition_action
 pub mod document_purchase_transition_action;
 /// document_replace_transition_action
@@ -13,6 +17,8 @@ pub mod document_replace_transition_action;
 pub mod document_transfer_transition_action;
 /// document_update_price_transition_action
 pub mod document_update_price_transition_action;
+/// document_withdraw_offer_transition_action
+pub mod document_withdraw_offer_transition_action;
 
 pub use dpp::state_transition::documents_batch_transition::document_transition::action_type::DocumentTransitionActionType;
 
@@ -26,6 +32,9 @@ use crate::state_transition_action::document::documents_batch::document_transiti
 use crate::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::{DocumentPurchaseTransitionAction, DocumentPurchaseTransitionActionAccessorsV0};
 use crate::state_transition_action::document::documents_batch::document_transition::document_transfer_transition_action::{DocumentTransferTransitionAction, DocumentTransferTransitionActionAccessorsV0};
 use crate::state_transition_action::document::documents_batch::document_transition::document_update_price_transition_action::{DocumentUpdatePriceTransitionAction, DocumentUpdatePriceTransitionActionAccessorsV0};
+use crate::state_transition_action::document::documents_batch::document_transition::document_make_offer_transition_action::{DocumentMakeOfferTransitionAction, DocumentMakeOfferTransitionActionAccessorsV0};
+use crate::state_transition_action::document::documents_batch::document_transition::document_withdraw_offer_transition_action::{DocumentWithdrawOfferTransitionAction, DocumentWithdrawOfferTransitionActionAccessorsV0};
+use crate::state_transition_action::document::documents_batch::document_transition::document_accept_offer_transition_action::{DocumentAcceptOfferTransitionAction, DocumentAcceptOfferTransitionActionAccessorsV0};
 use crate::state_transition_action::system::bump_identity_data_contract_nonce_action::BumpIdentityDataContractNonceAction;
 
 /// version
@@ -48,6 +57,12 @@ pub enum DocumentTransitionAction {
     UpdatePriceAction(DocumentUpdatePriceTransitionAction),
     /// bump identity data contract nonce
     BumpIdentityDataContractNonce(BumpIdentityDataContractNonceAction),
+    /// make offer
+    MakeOfferAction(DocumentMakeOfferTransitionAction),
+    /// withdraw offer
+    WithdrawOfferAction(DocumentWithdrawOfferTransitionAction),
+    /// accept offer
+    AcceptOfferAction(DocumentAcceptOfferTransitionAction),
 }
 
 impl DocumentTransitionAction {
@@ -61,6 +76,9 @@ impl DocumentTransitionAction {
             DocumentTransitionAction::PurchaseAction(d) => Some(d.base()),
             DocumentTransitionAction::UpdatePriceAction(d) => Some(d.base()),
             DocumentTransitionAction::BumpIdentityDataContractNonce(_) => None,
+            DocumentTransitionAction::MakeOfferAction(d) => Some(d.base()),
+            DocumentTransitionAction::WithdrawOfferAction(d) => Some(d.base()),
+            DocumentTransitionAction::AcceptOfferAction(d) => Some(d.base()),
         }
     }
 
@@ -74,6 +92,9 @@ impl DocumentTransitionAction {
             DocumentTransitionAction::PurchaseAction(d) => Some(d.base_owned()),
             DocumentTransitionAction::UpdatePriceAction(d) => Some(d.base_owned()),
             DocumentTransitionAction::BumpIdentityDataContractNonce(_) => None,
+            DocumentTransitionAction::MakeOfferAction(d) => Some(d.base_owned()),
+            DocumentTransitionAction::WithdrawOfferAction(d) => Some(d.base_owned()),
+            DocumentTransitionAction::AcceptOfferAction(d) => Some(d.base_owned()),
         }
     }
 }
@@ -74,7 +74,8 @@ impl DocumentsBatchTransitionAction {
 }
 
 impl DocumentsBatchTransitionAction {
-    /// The sum of all purchases amount and all conflicting index collateral voting funds
+    /// The sum of all purchases amount, all conflicting index collateral voting funds and all
+    /// escrowed offers amount
     pub fn all_used_balances(&self) -> Result<Option<Credits>, ProtocolError> {
         match self {
             DocumentsBatchTransitionAction::V0(v0) => v0.all_used_balances(),
@@ -88,6 +89,13 @@ impl DocumentsBatchTransitionAction {
         }
     }
 
+    /// The sum of all escrowed amounts for all make offer transitions in the batch
+    pub fn all_offers_amount(&self) -> Result<Option<Credits>, ProtocolError> {
+        match self {
+            DocumentsBatchTransitionAction::V0(v0) => v0.all_offers_amount(),
+        }
+    }
+
     /// The sum of all conflicting index collateral voting funds for all document create transitions in the batch
     pub fn all_conflicting_index_collateral_voting_funds(
         &self,
@@ -5,6 +5,7 @@ use dpp::prelude::UserFeeIncrease;
 use dpp::ProtocolError;
 use crate::state_transition_action::document::documents_batch::document_transition::document_create_transition_action::DocumentCreateTransitionActionAccessorsV0;
 use crate::state_transition_action::document::documents_batch::document_transition::document_purchase_transition_action::DocumentPurchaseTransitionActionAccessorsV0;
+use crate::state_transition_action::document::documents_batch::document_transition::document_make_offer_transition_action::DocumentMakeOfferTransitionActionAccessorsV0;
 
 /// action v0
 #[derive(Default, Debug, Clone)]
@@ -19,11 +20,19 @@ pub struct DocumentsBatchTransitionActionV0 {
 
 impl DocumentsBatchTransitionActionV0 {
     pub(super) fn all_used_balances(&self) -> Result<Option<Credits>, ProtocolError> {
-        Ok(match (self.all_purchases_amount()?, self.all_conflicting_index_collateral_voting_funds()?) {
-            (Some(all_purchases_amount), Some(all_conflicting_index_collateral_voting_funds)) => Some(all_purchases_amount.checked_add(all_conflicting_index_collateral_voting_funds).ok_or(ProtocolError::Overflow("overflow between all_purchases_amount and all_conflicting_index_collateral_voting_funds"))?),
-            (Some(all_purchases_amount), None) => Some(all_purchases_amount),
-            (None, Some(all_conflicting_index_collateral_voting_funds)) => Some(all_conflicting_index_collateral_voting_funds),
-            (None, None) => None,
+        [
+            self.all_purchases_amount()?,
+            self.all_conflicting_index_collateral_voting_funds()?,
+            self.all_offers_amount()?,
+        ]
+        .into_iter()
+        .flatten()
+        .try_fold(None, |acc: Option<Credits>, amount| match acc {
+            Some(acc) => acc
+                .checked_add(amount)
+                .map(Some)
+                .ok_or(ProtocolError::Overflow("overflow in all used balances")),
+            None => Ok(Some(amount)),
         })
     }
     pub(super) fn all_purchases_amount(&self) -> Result<Option<Credits>, ProtocolError> {
@@ -48,6 +57,30 @@ impl DocumentsBatchTransitionActionV0 {
         }
     }
 
+    pub(super) fn all_offers_amount(&self) -> Result<Option<Credits>, ProtocolError> {
+        let (total, any_offers): (Option<Credits>, bool) = self
+            .transitions
+            .iter()
+            .filter_map(|transition| match transition {
+                DocumentTransitionAction::MakeOfferAction(make_offer) => {
+                    Some(make_offer.offer().price)
+                }
+                _ => None,
+            })
+            .fold((None, false), |(acc, _), price| match acc {
+                Some(acc_val) => acc_val
+                    .checked_add(price)
+                    .map_or((None, true), |sum| (Some(sum), true)),
+                None => (Some(price), true),
+            });
+
+        match (total, any_offers) {
+            (Some(total), _) => Ok(Some(total)),
+            (None, true) => Err(ProtocolError::Overflow("overflow in all offers amount")), // Overflow occurred
+            _ => Ok(None), // No offers were found
+        }
+    }
+
     pub(super) fn all_conflicting_index_collateral_voting_funds(
         &self,
     ) -> Result<Option<Credits>, ProtocolError> {
@@ -0,0 +1,63 @@
+/// transformer
+pub mod transformer;
+/// v0
+pub mod v0;
+
+use crate::drive::votes::resolved::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePollWithContractInfo;
+use crate::state_transition_action::identity::auction_bid::v0::AuctionBidTransitionActionV0;
+use derive_more::From;
+use dpp::fee::Credits;
+use dpp::platform_value::Identifier;
+use dpp::prelude::{IdentityNonce, UserFeeIncrease};
+use dpp::voting::vote_info_storage::contested_document_auction_bid_stored_info::ContestedDocumentAuctionBidStoredInfo;
+
+/// action
+#[derive(Debug, Clone, From)]
+pub enum AuctionBidTransitionAction {
+    /// v0
+    V0(AuctionBidTransitionActionV0),
+}
+
+impl AuctionBidTransitionAction {
+    /// Identity Id of the contender placing the bid
+    pub fn identity_id(&self) -> Identifier {
+        match self {
+            AuctionBidTransitionAction::V0(transition) => transition.identity_id,
+        }
+    }
+
+    /// The vote poll resolved by the auction
+    pub fn vote_poll(&self) -> &ContestedDocumentResourceVotePollWithContractInfo {
+        match self {
+            AuctionBidTransitionAction::V0(transition) => &transition.vote_poll,
+        }
+    }
+
+    /// The bid as it will be stored
+    pub fn stored_bid(&self) -> &ContestedDocumentAuctionBidStoredInfo {
+        match self {
+            AuctionBidTransitionAction::V0(transition) => &transition.stored_bid,
+        }
+    }
+
+    /// The credits escrowed from the identity balance, only a commitment escrows credits
+    pub fn escrowed_amount(&self) -> Credits {
+        match self {
+            AuctionBidTransitionAction::V0(transition) => transition.escrowed_amount,
+        }
+    }
+
+    /// Nonce
+    pub fn nonce(&self) -> IdentityNonce {
+        match self {
+            AuctionBidTransitionAction::V0(transition) => transition.nonce,
+        }
+    }
+
+    /// fee multiplier
+    pub fn user_fee_increase(&self) -> UserFeeIncrease {
+        match self {
+            AuctionBidTransitionAction::V0(transition) => transition.user_fee_increase,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::drive::votes::resolved::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePollWithContractInfo;
+use crate::state_transition_action::identity::auction_bid::v0::AuctionBidTransitionActionV0;
+use crate::state_transition_action::identity::auction_bid::AuctionBidTransitionAction;
+use dpp::state_transition::auction_bid_transition::AuctionBidTransition;
+use dpp::voting::vote_info_storage::contested_document_auction_bid_stored_info::ContestedDocumentAuctionBidStoredInfo;
+
+impl AuctionBidTransitionAction {
+    /// Creates the action from the transition and the bid it resolves to in the state
+    pub fn from_transition_with_stored_bid(
+        value: &AuctionBidTransition,
+        vote_poll: ContestedDocumentResourceVotePollWithContractInfo,
+        stored_bid: ContestedDocumentAuctionBidStoredInfo,
+    ) -> Self {
+        match value {
+            AuctionBidTransition::V0(v0) => {
+                AuctionBidTransitionActionV0::from_transition_with_stored_bid(
+                    v0, vote_poll, stored_bid,
+                )
+                .into()
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+mod transformer;
+
+use crate::drive::votes::resolved::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePollWithContractInfo;
+use dpp::fee::Credits;
+use dpp::platform_value::Identifier;
+use dpp::prelude::{IdentityNonce, UserFeeIncrease};
+use dpp::voting::vote_info_storage::contested_document_auction_bid_stored_info::ContestedDocumentAuctionBidStoredInfo;
+
+/// action v0
+#[derive(Debug, Clone)]
+pub struct AuctionBidTransitionActionV0 {
+    /// identity id of the contender placing the bid
+    pub identity_id: Identifier,
+    /// the vote poll resolved by the auction
+    pub vote_poll: ContestedDocumentResourceVotePollWithContractInfo,
+    /// the bid as it will be stored, on a reveal this already holds the revealed amount
+    pub stored_bid: ContestedDocumentAuctionBidStoredInfo,
+    /// the credits escrowed from the identity balance, zero on a reveal
+    pub escrowed_amount: Credits,
+    /// nonce
+    pub nonce: IdentityNonce,
+    /// fee multiplier
+    pub user_fee_increase: UserFeeIncrease,
+}
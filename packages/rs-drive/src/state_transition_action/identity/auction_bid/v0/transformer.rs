@@ -0,0 +1,33 @@
+use crate::drive::votes::resolved::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePollWithContractInfo;
+use crate::state_transition_action::identity::auction_bid::v0::AuctionBidTransitionActionV0;
+use dpp::state_transition::auction_bid_transition::v0::AuctionBidTransitionV0;
+use dpp::voting::auction_bids::AuctionBid;
+use dpp::voting::vote_info_storage::contested_document_auction_bid_stored_info::ContestedDocumentAuctionBidStoredInfo;
+
+impl AuctionBidTransitionActionV0 {
+    pub(in crate::state_transition_action::identity::auction_bid) fn from_transition_with_stored_bid(
+        value: &AuctionBidTransitionV0,
+        vote_poll: ContestedDocumentResourceVotePollWithContractInfo,
+        stored_bid: ContestedDocumentAuctionBidStoredInfo,
+    ) -> Self {
+        let AuctionBidTransitionV0 {
+            identity_id,
+            bid,
+            nonce,
+            user_fee_increase,
+            ..
+        } = value;
+        let escrowed_amount = match bid {
+            AuctionBid::Commit { deposit, .. } => *deposit,
+            AuctionBid::Reveal { .. } => 0,
+        };
+        AuctionBidTransitionActionV0 {
+            identity_id: *identity_id,
+            vote_poll,
+            stored_bid,
+            escrowed_amount,
+            nonce: *nonce,
+            user_fee_increase: *user_fee_increase,
+        }
+    }
+}
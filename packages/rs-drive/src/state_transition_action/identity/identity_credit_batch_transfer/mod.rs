@@ -0,0 +1,58 @@
+/// transformer
+pub mod transformer;
+/// v0
+pub mod v0;
+
+use crate::state_transition_action::identity::identity_credit_batch_transfer::v0::IdentityCreditBatchTransferTransitionActionV0;
+use derive_more::From;
+use dpp::fee::Credits;
+use dpp::platform_value::Identifier;
+use dpp::prelude::{IdentityNonce, UserFeeIncrease};
+
+/// action
+#[derive(Debug, Clone, From)]
+pub enum IdentityCreditBatchTransferTransitionAction {
+    /// v0
+    V0(IdentityCreditBatchTransferTransitionActionV0),
+}
+
+impl IdentityCreditBatchTransferTransitionAction {
+    /// Nonce
+    pub fn nonce(&self) -> IdentityNonce {
+        match self {
+            IdentityCreditBatchTransferTransitionAction::V0(transition) => transition.nonce,
+        }
+    }
+
+    /// Recipients and the amounts they receive
+    pub fn outputs(&self) -> &Vec<(Identifier, Credits)> {
+        match self {
+            IdentityCreditBatchTransferTransitionAction::V0(transition) => &transition.outputs,
+        }
+    }
+
+    /// Total amount removed from the sender
+    pub fn total_transfer_amount(&self) -> Credits {
+        match self {
+            IdentityCreditBatchTransferTransitionAction::V0(transition) => {
+                transition.total_transfer_amount
+            }
+        }
+    }
+
+    /// Identity Id
+    pub fn identity_id(&self) -> Identifier {
+        match self {
+            IdentityCreditBatchTransferTransitionAction::V0(transition) => transition.identity_id,
+        }
+    }
+
+    /// fee multiplier
+    pub fn user_fee_increase(&self) -> UserFeeIncrease {
+        match self {
+            IdentityCreditBatchTransferTransitionAction::V0(transition) => {
+                transition.user_fee_increase
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::state_transition_action::identity::identity_credit_batch_transfer::v0::IdentityCreditBatchTransferTransitionActionV0;
+use crate::state_transition_action::identity::identity_credit_batch_transfer::IdentityCreditBatchTransferTransitionAction;
+use dpp::state_transition::identity_credit_batch_transfer_transition::IdentityCreditBatchTransferTransition;
+
+impl From<IdentityCreditBatchTransferTransition> for IdentityCreditBatchTransferTransitionAction {
+    fn from(value: IdentityCreditBatchTransferTransition) -> Self {
+        match value {
+            IdentityCreditBatchTransferTransition::V0(v0) => {
+                IdentityCreditBatchTransferTransitionActionV0::from(v0).into()
+            }
+        }
+    }
+}
+
+impl From<&IdentityCreditBatchTransferTransition> for IdentityCreditBatchTransferTransitionAction {
+    fn from(value: &IdentityCreditBatchTransferTransition) -> Self {
+        match value {
+            IdentityCreditBatchTransferTransition::V0(v0) => {
+                IdentityCreditBatchTransferTransitionActionV0::from(v0).into()
+            }
+        }
+    }
+}
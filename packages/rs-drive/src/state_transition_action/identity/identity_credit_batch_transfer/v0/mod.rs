@@ -0,0 +1,20 @@
+mod transformer;
+
+use dpp::fee::Credits;
+use dpp::platform_value::Identifier;
+use dpp::prelude::{IdentityNonce, UserFeeIncrease};
+
+/// action v0
+#[derive(Default, Debug, Clone)]
+pub struct IdentityCreditBatchTransferTransitionActionV0 {
+    /// recipient ids and the amounts they receive, memos are not kept in state
+    pub outputs: Vec<(Identifier, Credits)>,
+    /// the sum of all output amounts
+    pub total_transfer_amount: Credits,
+    /// identity id
+    pub identity_id: Identifier,
+    /// nonce
+    pub nonce: IdentityNonce,
+    /// fee multiplier
+    pub user_fee_increase: UserFeeIncrease,
+}
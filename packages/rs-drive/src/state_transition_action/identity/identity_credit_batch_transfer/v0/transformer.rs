@@ -0,0 +1,61 @@
+use crate::state_transition_action::identity::identity_credit_batch_transfer::v0::IdentityCreditBatchTransferTransitionActionV0;
+use dpp::fee::Credits;
+use dpp::platform_value::Identifier;
+use dpp::state_transition::identity_credit_batch_transfer_transition::CreditTransferOutput;
+use dpp::state_transition::state_transitions::identity::identity_credit_batch_transfer_transition::v0::IdentityCreditBatchTransferTransitionV0;
+
+// The total can not overflow as it was checked during basic structure validation
+fn outputs_with_total(outputs: &[CreditTransferOutput]) -> (Vec<(Identifier, Credits)>, Credits) {
+    let outputs: Vec<(Identifier, Credits)> = outputs
+        .iter()
+        .map(|output| (output.recipient_id, output.amount))
+        .collect();
+    let total = outputs.iter().fold(0 as Credits, |total, (_, amount)| {
+        total.saturating_add(*amount)
+    });
+    (outputs, total)
+}
+
+impl From<IdentityCreditBatchTransferTransitionV0>
+    for IdentityCreditBatchTransferTransitionActionV0
+{
+    fn from(value: IdentityCreditBatchTransferTransitionV0) -> Self {
+        let IdentityCreditBatchTransferTransitionV0 {
+            identity_id,
+            outputs,
+            nonce,
+            user_fee_increase,
+            ..
+        } = value;
+        let (outputs, total_transfer_amount) = outputs_with_total(&outputs);
+        IdentityCreditBatchTransferTransitionActionV0 {
+            outputs,
+            total_transfer_amount,
+            identity_id,
+            nonce,
+            user_fee_increase,
+        }
+    }
+}
+
+impl From<&IdentityCreditBatchTransferTransitionV0>
+    for IdentityCreditBatchTransferTransitionActionV0
+{
+    fn from(value: &IdentityCreditBatchTransferTransitionV0) -> Self {
+        let IdentityCreditBatchTransferTransitionV0 {
+            identity_id,
+            outputs,
+            nonce,
+            user_fee_increase,
+            ..
+        } = value;
+        let (outputs, total_transfer_amount) = outputs_with_total(outputs);
+        IdentityCreditBatchTransferTransitionActionV0 {
+            outputs,
+            total_transfer_amount,
+            identity_id: *identity_id,
+            nonce: *nonce,
+            user_fee_increase: *user_fee_increase,
+        }
+    }
+}
@@ -4,7 +4,6 @@ use dpp::data_contracts::withdrawals_contract::v1::document_types::withdrawal;
 use dpp::document::{Document, DocumentV0};
 use dpp::platform_value::platform_value;
 use dpp::state_transition::state_transitions::identity::identity_credit_withdrawal_transition::v0::IdentityCreditWithdrawalTransitionV0;
-use dpp::withdrawal::Pooling;
 
 impl IdentityCreditWithdrawalTransitionActionV0 {
     /// from identity credit withdrawal
@@ -26,8 +25,7 @@ impl IdentityCreditWithdrawalTransitionActionV0 {
         let document_data = platform_value!({
             withdrawal::properties::AMOUNT: identity_credit_withdrawal.amount,
             withdrawal::properties::CORE_FEE_PER_BYTE: identity_credit_withdrawal.core_fee_per_byte,
-            // TODO(withdrawals): replace with actual value from state transition once pooling is done
-            withdrawal::properties::POOLING: Pooling::Never,
+            withdrawal::properties::POOLING: identity_credit_withdrawal.pooling,
             withdrawal::properties::OUTPUT_SCRIPT: identity_credit_withdrawal.output_script.as_bytes(),
             withdrawal::properties::STATUS: withdrawals_contract::WithdrawalStatus::QUEUED,
         });
@@ -0,0 +1,55 @@
+/// transformer
+pub mod transformer;
+/// v0
+pub mod v0;
+
+use crate::state_transition_action::identity::identity_recovery::v0::{
+    IdentityRecoveryActionOperation, IdentityRecoveryTransitionActionV0,
+};
+use derive_more::From;
+use dpp::platform_value::Identifier;
+use dpp::prelude::{IdentityNonce, UserFeeIncrease};
+
+/// action
+#[derive(Debug, Clone, From)]
+pub enum IdentityRecoveryTransitionAction {
+    /// v0
+    V0(IdentityRecoveryTransitionActionV0),
+}
+
+impl IdentityRecoveryTransitionAction {
+    /// Identity Id
+    pub fn identity_id(&self) -> Identifier {
+        match self {
+            IdentityRecoveryTransitionAction::V0(transition) => transition.identity_id,
+        }
+    }
+
+    /// What the recovery does, resolved against the state
+    pub fn operation(&self) -> &IdentityRecoveryActionOperation {
+        match self {
+            IdentityRecoveryTransitionAction::V0(transition) => &transition.operation,
+        }
+    }
+
+    /// Operation Owned
+    pub fn operation_owned(self) -> IdentityRecoveryActionOperation {
+        match self {
+            IdentityRecoveryTransitionAction::V0(transition) => transition.operation,
+        }
+    }
+
+    /// Nonce
+    pub fn nonce(&self) -> IdentityNonce {
+        match self {
+            IdentityRecoveryTransitionAction::V0(transition) => transition.nonce,
+        }
+    }
+
+    /// fee multiplier
+    pub fn user_fee_increase(&self) -> UserFeeIncrease {
+        match self {
+            IdentityRecoveryTransitionAction::V0(transition) => transition.user_fee_increase,
+        }
+    }
+}
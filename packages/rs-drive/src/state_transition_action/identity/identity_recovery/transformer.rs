@@ -0,0 +1,20 @@
+use crate::state_transition_action::identity::identity_recovery::v0::{
+    IdentityRecoveryActionOperation, IdentityRecoveryTransitionActionV0,
+};
+use crate::state_transition_action::identity::identity_recovery::IdentityRecoveryTransitionAction;
+use dpp::state_transition::identity_recovery_transition::IdentityRecoveryTransition;
+
+impl IdentityRecoveryTransitionAction {
+    /// Creates the action from the transition and the operation it resolves to in the state
+    pub fn from_transition_with_operation(
+        value: &IdentityRecoveryTransition,
+        operation: IdentityRecoveryActionOperation,
+    ) -> Self {
+        match value {
+            IdentityRecoveryTransition::V0(v0) => {
+                IdentityRecoveryTransitionActionV0::from_transition_with_operation(v0, operation)
+                    .into()
+            }
+        }
+    }
+}
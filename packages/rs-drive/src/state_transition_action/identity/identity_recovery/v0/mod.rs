@@ -0,0 +1,39 @@
+mod transformer;
+
+use dpp::identifier::Identifier;
+use dpp::identity::recovery::{IdentityRecoveryConfig, PendingIdentityRecovery};
+use dpp::identity::{IdentityPublicKey, KeyID};
+use dpp::prelude::{IdentityNonce, Revision, UserFeeIncrease};
+
+/// What an identity recovery transition does to the state
+#[derive(Debug, Clone)]
+pub enum IdentityRecoveryActionOperation {
+    /// sets, replaces or removes the recovery configuration
+    SetConfig(Option<IdentityRecoveryConfig>),
+    /// stores the recovery approved by the guardians
+    Initiate(PendingIdentityRecovery),
+    /// removes the pending recovery
+    Cancel,
+    /// installs the new master key and removes the pending recovery
+    Complete {
+        /// the new master key
+        new_master_key: IdentityPublicKey,
+        /// the master keys that were enabled before the recovery
+        master_keys_to_disable: Vec<KeyID>,
+        /// the revision of the identity after the recovery
+        revision: Revision,
+    },
+}
+
+/// action v0
+#[derive(Debug, Clone)]
+pub struct IdentityRecoveryTransitionActionV0 {
+    /// identity id
+    pub identity_id: Identifier,
+    /// operation
+    pub operation: IdentityRecoveryActionOperation,
+    /// nonce used to prevent replay attacks
+    pub nonce: IdentityNonce,
+    /// fee multiplier
+    pub user_fee_increase: UserFeeIncrease,
+}
@@ -0,0 +1,24 @@
+use crate::state_transition_action::identity::identity_recovery::v0::{
+    IdentityRecoveryActionOperation, IdentityRecoveryTransitionActionV0,
+};
+use dpp::state_transition::identity_recovery_transition::v0::IdentityRecoveryTransitionV0;
+
+impl IdentityRecoveryTransitionActionV0 {
+    pub(in crate::state_transition_action::identity::identity_recovery) fn from_transition_with_operation(
+        value: &IdentityRecoveryTransitionV0,
+        operation: IdentityRecoveryActionOperation,
+    ) -> Self {
+        let IdentityRecoveryTransitionV0 {
+            identity_id,
+            nonce,
+            user_fee_increase,
+            ..
+        } = value;
+        IdentityRecoveryTransitionActionV0 {
+            identity_id: *identity_id,
+            operation,
+            nonce: *nonce,
+            user_fee_increase: *user_fee_increase,
+        }
+    }
+}
@@ -8,6 +8,7 @@ use crate::state_transition_action::identity::masternode_vote::v0::{
     MasternodeVoteTransitionActionV0, PreviousVoteCount,
 };
 use derive_more::From;
+use dpp::fee::Credits;
 use dpp::platform_value::Identifier;
 use dpp::prelude::IdentityNonce;
 use dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
@@ -69,6 +70,24 @@ impl MasternodeVoteTransitionAction {
         }
     }
 
+    /// The credits moved from the voter identity to fund the governance proposal vote poll
+    /// this vote opens
+    pub fn vote_poll_funding(&self) -> Option<Credits> {
+        match self {
+            MasternodeVoteTransitionAction::V0(transition) => transition.vote_poll_funding,
+        }
+    }
+
+    /// Sets the credits moved from the voter identity to fund the governance proposal vote poll
+    /// this vote opens
+    pub fn set_vote_poll_funding(&mut self, vote_poll_funding: Option<Credits>) {
+        match self {
+            MasternodeVoteTransitionAction::V0(transition) => {
+                transition.vote_poll_funding = vote_poll_funding
+            }
+        }
+    }
+
     /// The previous resource vote choice that needs to be removed
     pub fn take_previous_resource_vote_choice_to_remove(
         &mut self,
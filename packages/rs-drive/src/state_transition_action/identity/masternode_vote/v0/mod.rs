@@ -1,6 +1,7 @@
 mod transformer;
 
 use crate::drive::votes::resolved::votes::ResolvedVote;
+use dpp::fee::Credits;
 use dpp::platform_value::Identifier;
 use dpp::prelude::IdentityNonce;
 use dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
@@ -25,4 +26,7 @@ pub struct MasternodeVoteTransitionActionV0 {
     pub previous_resource_vote_choice_to_remove: Option<(ResourceVoteChoice, PreviousVoteCount)>,
     /// nonce
     pub nonce: IdentityNonce,
+    /// The credits the voter identity moves to the prefunded balance of the governance proposal
+    /// vote poll this vote opens
+    pub vote_poll_funding: Option<Credits>,
 }
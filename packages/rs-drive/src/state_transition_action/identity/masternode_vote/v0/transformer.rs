@@ -35,6 +35,7 @@ impl MasternodeVoteTransitionActionV0 {
             vote: resolved_vote,
             previous_resource_vote_choice_to_remove,
             nonce,
+            vote_poll_funding: None,
         })
     }
 
@@ -63,6 +64,7 @@ impl MasternodeVoteTransitionActionV0 {
             vote: resolved_vote,
             previous_resource_vote_choice_to_remove,
             nonce: *nonce,
+            vote_poll_funding: None,
         })
     }
 }
@@ -1,9 +1,15 @@
+/// auction bids on contested resources
+pub mod auction_bid;
 /// identity create
 pub mod identity_create;
+/// identity credit batch transfer
+pub mod identity_credit_batch_transfer;
 /// identity credit transfer
 pub mod identity_credit_transfer;
 /// identity credit withdrawal
 pub mod identity_credit_withdrawal;
+/// identity recovery
+pub mod identity_recovery;
 /// identity topup
 pub mod identity_topup;
 /// identity update
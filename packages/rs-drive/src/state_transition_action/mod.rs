@@ -11,11 +11,15 @@ pub mod system;
 pub mod action_convert_to_operations;
 
 use crate::state_transition_action::contract::data_contract_create::DataContractCreateTransitionAction;
+use crate::state_transition_action::contract::data_contract_delete::DataContractDeleteTransitionAction;
 use crate::state_transition_action::contract::data_contract_update::DataContractUpdateTransitionAction;
 use crate::state_transition_action::document::documents_batch::DocumentsBatchTransitionAction;
+use crate::state_transition_action::identity::auction_bid::AuctionBidTransitionAction;
 use crate::state_transition_action::identity::identity_create::IdentityCreateTransitionAction;
+use crate::state_transition_action::identity::identity_credit_batch_transfer::IdentityCreditBatchTransferTransitionAction;
 use crate::state_transition_action::identity::identity_credit_transfer::IdentityCreditTransferTransitionAction;
 use crate::state_transition_action::identity::identity_credit_withdrawal::IdentityCreditWithdrawalTransitionAction;
+use crate::state_transition_action::identity::identity_recovery::IdentityRecoveryTransitionAction;
 use crate::state_transition_action::identity::identity_topup::IdentityTopUpTransitionAction;
 use crate::state_transition_action::identity::identity_update::IdentityUpdateTransitionAction;
 use crate::state_transition_action::identity::masternode_vote::MasternodeVoteTransitionAction;
@@ -38,6 +42,8 @@ pub enum StateTransitionAction {
     DataContractCreateAction(DataContractCreateTransitionAction),
     /// data contract update
     DataContractUpdateAction(DataContractUpdateTransitionAction),
+    /// data contract archive or delete
+    DataContractDeleteAction(DataContractDeleteTransitionAction),
     /// documents batch
     DocumentsBatchAction(DocumentsBatchTransitionAction),
     /// identity create
@@ -48,10 +54,16 @@ pub enum StateTransitionAction {
     IdentityCreditWithdrawalAction(IdentityCreditWithdrawalTransitionAction),
     /// identity update
     IdentityUpdateAction(IdentityUpdateTransitionAction),
+    /// identity social recovery
+    IdentityRecoveryAction(IdentityRecoveryTransitionAction),
     /// identity credit transfer
     IdentityCreditTransferAction(IdentityCreditTransferTransitionAction),
+    /// identity credit batch transfer
+    IdentityCreditBatchTransferAction(IdentityCreditBatchTransferTransitionAction),
     /// masternode vote action
     MasternodeVoteAction(MasternodeVoteTransitionAction),
+    /// auction bid action
+    AuctionBidAction(AuctionBidTransitionAction),
     /// bump identity nonce action
     /// this can only come in this form from identity state transitions that do not use asset locks
     /// it will also only happen if the state validation fails
@@ -71,6 +83,7 @@ impl StateTransitionAction {
         match self {
             StateTransitionAction::DataContractCreateAction(action) => action.user_fee_increase(),
             StateTransitionAction::DataContractUpdateAction(action) => action.user_fee_increase(),
+            StateTransitionAction::DataContractDeleteAction(action) => action.user_fee_increase(),
             StateTransitionAction::DocumentsBatchAction(action) => action.user_fee_increase(),
             StateTransitionAction::IdentityCreateAction(action) => action.user_fee_increase(),
             StateTransitionAction::IdentityTopUpAction(action) => action.user_fee_increase(),
@@ -78,9 +91,14 @@ impl StateTransitionAction {
                 action.user_fee_increase()
             }
             StateTransitionAction::IdentityUpdateAction(action) => action.user_fee_increase(),
+            StateTransitionAction::IdentityRecoveryAction(action) => action.user_fee_increase(),
             StateTransitionAction::IdentityCreditTransferAction(action) => {
                 action.user_fee_increase()
             }
+            StateTransitionAction::IdentityCreditBatchTransferAction(action) => {
+                action.user_fee_increase()
+            }
+            StateTransitionAction::AuctionBidAction(action) => action.user_fee_increase(),
             StateTransitionAction::BumpIdentityNonceAction(action) => action.user_fee_increase(),
             StateTransitionAction::BumpIdentityDataContractNonceAction(action) => {
                 action.user_fee_increase()
@@ -1,8 +1,10 @@
 use dpp::platform_value::Identifier;
 use dpp::prelude::UserFeeIncrease;
 
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
 use dpp::state_transition::data_contract_update_transition::DataContractUpdateTransition;
 use dpp::state_transition::documents_batch_transition::document_base_transition::DocumentBaseTransition;
+use crate::state_transition_action::contract::data_contract_delete::DataContractDeleteTransitionAction;
 use crate::state_transition_action::contract::data_contract_update::DataContractUpdateTransitionAction;
 use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionAction;
 use crate::state_transition_action::system::bump_identity_data_contract_nonce_action::{BumpIdentityDataContractNonceAction, BumpIdentityDataContractNonceActionV0};
@@ -122,4 +124,47 @@ impl BumpIdentityDataContractNonceAction {
             }
         }
     }
+
+    /// from data contract delete
+    pub fn from_data_contract_delete_transition(value: DataContractDeleteTransition) -> Self {
+        match value {
+            DataContractDeleteTransition::V0(v0) => {
+                BumpIdentityDataContractNonceActionV0::from_data_contract_delete(v0).into()
+            }
+        }
+    }
+
+    /// from borrowed data contract delete
+    pub fn from_borrowed_data_contract_delete_transition(
+        value: &DataContractDeleteTransition,
+    ) -> Self {
+        match value {
+            DataContractDeleteTransition::V0(v0) => {
+                BumpIdentityDataContractNonceActionV0::from_borrowed_data_contract_delete(v0).into()
+            }
+        }
+    }
+
+    /// from data contract delete action
+    pub fn from_data_contract_delete_transition_action(
+        value: DataContractDeleteTransitionAction,
+    ) -> Self {
+        match value {
+            DataContractDeleteTransitionAction::V0(v0) => {
+                BumpIdentityDataContractNonceActionV0::from_data_contract_delete_action(v0).into()
+            }
+        }
+    }
+
+    /// from borrowed data contract delete action
+    pub fn from_borrowed_data_contract_delete_transition_action(
+        value: &DataContractDeleteTransitionAction,
+    ) -> Self {
+        match value {
+            DataContractDeleteTransitionAction::V0(v0) => {
+                BumpIdentityDataContractNonceActionV0::from_borrowed_data_contract_delete_action(v0)
+                    .into()
+            }
+        }
+    }
 }
@@ -2,8 +2,10 @@ use dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dpp::platform_value::Identifier;
 use dpp::prelude::UserFeeIncrease;
 
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransitionV0;
 use dpp::state_transition::data_contract_update_transition::DataContractUpdateTransitionV0;
 use dpp::state_transition::documents_batch_transition::document_base_transition::v0::DocumentBaseTransitionV0;
+use crate::state_transition_action::contract::data_contract_delete::v0::DataContractDeleteTransitionActionV0;
 use crate::state_transition_action::contract::data_contract_update::v0::DataContractUpdateTransitionActionV0;
 use crate::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionV0;
 use crate::state_transition_action::system::bump_identity_data_contract_nonce_action::BumpIdentityDataContractNonceActionV0;
@@ -150,4 +152,74 @@ impl BumpIdentityDataContractNonceActionV0 {
             user_fee_increase: *user_fee_increase,
         }
     }
+
+    /// from data contract delete
+    pub fn from_data_contract_delete(value: DataContractDeleteTransitionV0) -> Self {
+        let DataContractDeleteTransitionV0 {
+            owner_id,
+            data_contract_id,
+            identity_contract_nonce,
+            user_fee_increase,
+            ..
+        } = value;
+        BumpIdentityDataContractNonceActionV0 {
+            identity_id: owner_id,
+            data_contract_id,
+            identity_contract_nonce,
+            user_fee_increase,
+        }
+    }
+
+    /// from borrowed data contract delete
+    pub fn from_borrowed_data_contract_delete(value: &DataContractDeleteTransitionV0) -> Self {
+        let DataContractDeleteTransitionV0 {
+            owner_id,
+            data_contract_id,
+            identity_contract_nonce,
+            user_fee_increase,
+            ..
+        } = value;
+        BumpIdentityDataContractNonceActionV0 {
+            identity_id: *owner_id,
+            data_contract_id: *data_contract_id,
+            identity_contract_nonce: *identity_contract_nonce,
+            user_fee_increase: *user_fee_increase,
+        }
+    }
+
+    /// from data contract delete action
+    pub fn from_data_contract_delete_action(value: DataContractDeleteTransitionActionV0) -> Self {
+        let DataContractDeleteTransitionActionV0 {
+            owner_id,
+            data_contract_id,
+            identity_contract_nonce,
+            user_fee_increase,
+            ..
+        } = value;
+        BumpIdentityDataContractNonceActionV0 {
+            identity_id: owner_id,
+            data_contract_id,
+            identity_contract_nonce,
+            user_fee_increase,
+        }
+    }
+
+    /// from borrowed data contract delete action
+    pub fn from_borrowed_data_contract_delete_action(
+        value: &DataContractDeleteTransitionActionV0,
+    ) -> Self {
+        let DataContractDeleteTransitionActionV0 {
+            owner_id,
+            data_contract_id,
+            identity_contract_nonce,
+            user_fee_increase,
+            ..
+        } = value;
+        BumpIdentityDataContractNonceActionV0 {
+            identity_id: *owner_id,
+            data_contract_id: *data_contract_id,
+            identity_contract_nonce: *identity_contract_nonce,
+            user_fee_increase: *user_fee_increase,
+        }
+    }
 }
@@ -5,9 +5,11 @@ use crate::state_transition_action::identity::identity_update::IdentityUpdateTra
 use crate::state_transition_action::system::bump_identity_nonce_action::{
     BumpIdentityNonceAction, BumpIdentityNonceActionV0,
 };
+use dpp::state_transition::auction_bid_transition::AuctionBidTransition;
 use dpp::state_transition::data_contract_create_transition::DataContractCreateTransition;
 use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
 use dpp::state_transition::identity_credit_withdrawal_transition::IdentityCreditWithdrawalTransition;
+use dpp::state_transition::identity_recovery_transition::IdentityRecoveryTransition;
 use dpp::state_transition::identity_update_transition::IdentityUpdateTransition;
 
 impl BumpIdentityNonceAction {
@@ -177,4 +179,22 @@ impl BumpIdentityNonceAction {
             }
         }
     }
+
+    /// from borrowed identity recovery
+    pub fn from_borrowed_identity_recovery_transition(value: &IdentityRecoveryTransition) -> Self {
+        match value {
+            IdentityRecoveryTransition::V0(v0) => {
+                BumpIdentityNonceActionV0::from_borrowed_identity_recovery(v0).into()
+            }
+        }
+    }
+
+    /// from borrowed auction bid
+    pub fn from_borrowed_auction_bid_transition(value: &AuctionBidTransition) -> Self {
+        match value {
+            AuctionBidTransition::V0(v0) => {
+                BumpIdentityNonceActionV0::from_borrowed_auction_bid(v0).into()
+            }
+        }
+    }
 }
@@ -4,9 +4,11 @@ use crate::state_transition_action::identity::identity_credit_withdrawal::v0::Id
 use crate::state_transition_action::identity::identity_update::v0::IdentityUpdateTransitionActionV0;
 use crate::state_transition_action::system::bump_identity_nonce_action::BumpIdentityNonceActionV0;
 use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::state_transition::auction_bid_transition::v0::AuctionBidTransitionV0;
 use dpp::state_transition::data_contract_create_transition::DataContractCreateTransitionV0;
 use dpp::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
 use dpp::state_transition::identity_credit_withdrawal_transition::v0::IdentityCreditWithdrawalTransitionV0;
+use dpp::state_transition::identity_recovery_transition::v0::IdentityRecoveryTransitionV0;
 use dpp::state_transition::identity_update_transition::v0::IdentityUpdateTransitionV0;
 
 impl BumpIdentityNonceActionV0 {
@@ -263,4 +265,34 @@ impl BumpIdentityNonceActionV0 {
             user_fee_increase: *user_fee_increase,
         }
     }
+
+    /// from borrowed identity recovery
+    pub fn from_borrowed_identity_recovery(value: &IdentityRecoveryTransitionV0) -> Self {
+        let IdentityRecoveryTransitionV0 {
+            identity_id,
+            nonce,
+            user_fee_increase,
+            ..
+        } = value;
+        BumpIdentityNonceActionV0 {
+            identity_id: *identity_id,
+            identity_nonce: *nonce,
+            user_fee_increase: *user_fee_increase,
+        }
+    }
+
+    /// from borrowed auction bid
+    pub fn from_borrowed_auction_bid(value: &AuctionBidTransitionV0) -> Self {
+        let AuctionBidTransitionV0 {
+            identity_id,
+            nonce,
+            user_fee_increase,
+            ..
+        } = value;
+        BumpIdentityNonceActionV0 {
+            identity_id: *identity_id,
+            identity_nonce: *nonce,
+            user_fee_increase: *user_fee_increase,
+        }
+    }
 }
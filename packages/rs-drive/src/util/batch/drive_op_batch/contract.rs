@@ -5,6 +5,7 @@ use crate::util::batch::drive_op_batch::DriveLowLevelOperationConverter;
 use crate::util::storage_flags::StorageFlags;
 use dpp::block::block_info::BlockInfo;
 use dpp::data_contract::DataContract;
+use dpp::identifier::Identifier;
 
 use crate::error::drive::DriveError;
 use crate::util::batch::drive_op_batch::finalize_task::{
@@ -44,6 +45,18 @@ pub enum DataContractOperationType<'a> {
         /// Storage flags for the contract
         storage_flags: Option<Cow<'a, StorageFlags>>,
     },
+    /// Archives a contract, its documents can no longer be created or modified.
+    ArchiveContract {
+        /// The contract id
+        contract_id: Identifier,
+        /// The contract owner, paying for the archive marker
+        owner_id: Identifier,
+    },
+    /// Deletes a contract together with all its documents.
+    DeleteContract {
+        /// The contract id
+        contract_id: Identifier,
+    },
 }
 
 impl DriveLowLevelOperationConverter for DataContractOperationType<'_> {
@@ -82,6 +95,23 @@ impl DriveLowLevelOperationConverter for DataContractOperationType<'_> {
                 transaction,
                 platform_version,
             ),
+            DataContractOperationType::ArchiveContract {
+                contract_id,
+                owner_id,
+            } => drive.archive_contract_operations(
+                contract_id,
+                owner_id,
+                block_info,
+                estimated_costs_only_with_layer_info,
+                platform_version,
+            ),
+            DataContractOperationType::DeleteContract { contract_id } => drive
+                .delete_contract_operations(
+                    contract_id,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    platform_version,
+                ),
         }
     }
 }
@@ -118,6 +148,11 @@ impl DataContractOperationType<'_> {
                     contract_id: contract.id(),
                 }]
             }
+            Self::ArchiveContract { contract_id, .. } | Self::DeleteContract { contract_id } => {
+                vec![DriveOperationFinalizeTask::RemoveDataContractFromCache {
+                    contract_id: *contract_id,
+                }]
+            }
         };
 
         Ok(Some(tasks))
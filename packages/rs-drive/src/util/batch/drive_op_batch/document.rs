@@ -12,6 +12,7 @@ use dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dpp::data_contract::document_type::DocumentTypeRef;
 use dpp::data_contract::DataContract;
 use dpp::document::Document;
+use dpp::nft::offer::DocumentOffer;
 use dpp::prelude::Identifier;
 
 use dpp::system_data_contracts::withdrawals_contract::v1::document_types::withdrawal;
@@ -109,6 +110,28 @@ pub enum DocumentOperationType<'a> {
         /// The document operations
         document_operations: DocumentOperationsForContractDocumentType<'a>,
     },
+    /// Adds an open offer on a document
+    AddDocumentOffer {
+        /// The offer
+        offer: DocumentOffer,
+        /// The contract of the document
+        contract_id: Identifier,
+        /// The document type of the document
+        document_type_name: String,
+        /// Add storage flags (like epoch, owner id, etc)
+        storage_flags: Option<Cow<'a, StorageFlags>>,
+    },
+    /// Removes the open offer of a bidder on a document
+    RemoveDocumentOffer {
+        /// The contract of the document
+        contract_id: Identifier,
+        /// The document type of the document
+        document_type_name: String,
+        /// The document the offer was made on
+        document_id: Identifier,
+        /// The identity that made the offer
+        bidder_id: Identifier,
+    },
 }
 
 impl DriveLowLevelOperationConverter for DocumentOperationType<'_> {
@@ -349,6 +372,43 @@ impl DriveLowLevelOperationConverter for DocumentOperationType<'_> {
                 }
                 Ok(drive_operations)
             }
+            DocumentOperationType::AddDocumentOffer {
+                offer,
+                contract_id,
+                document_type_name,
+                storage_flags,
+            } => {
+                let mut drive_operations = vec![];
+                drive.add_document_offer_operations(
+                    contract_id,
+                    document_type_name.as_str(),
+                    &offer,
+                    storage_flags.as_ref().map(|flags| flags.as_ref()),
+                    &mut None,
+                    transaction,
+                    &mut drive_operations,
+                    platform_version,
+                )?;
+                Ok(drive_operations)
+            }
+            DocumentOperationType::RemoveDocumentOffer {
+                contract_id,
+                document_type_name,
+                document_id,
+                bidder_id,
+            } => {
+                let mut drive_operations = vec![];
+                drive.remove_document_offer_operations(
+                    contract_id,
+                    document_type_name.as_str(),
+                    document_id,
+                    bidder_id,
+                    transaction,
+                    &mut drive_operations,
+                    platform_version,
+                )?;
+                Ok(drive_operations)
+            }
         }
     }
 }
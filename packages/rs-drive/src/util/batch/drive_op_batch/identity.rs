@@ -3,14 +3,17 @@ use crate::error::Error;
 use crate::fees::op::LowLevelDriveOperation;
 use crate::util::batch::drive_op_batch::DriveLowLevelOperationConverter;
 use dpp::block::block_info::BlockInfo;
+use dpp::identity::recovery::{IdentityRecoveryConfig, PendingIdentityRecovery};
 use dpp::identity::{Identity, IdentityPublicKey, KeyID};
 use dpp::prelude::{IdentityNonce, Revision};
 
 use crate::drive::identity::update::methods::merge_identity_nonce::MergeIdentityContractNonceResultToResult;
+use crate::drive::votes::resolved::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePollWithContractInfo;
 use crate::drive::votes::resolved::votes::ResolvedVote;
 use crate::state_transition_action::identity::masternode_vote::v0::PreviousVoteCount;
 use dpp::version::PlatformVersion;
 use dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dpp::voting::vote_info_storage::contested_document_auction_bid_stored_info::ContestedDocumentAuctionBidStoredInfo;
 use grovedb::batch::KeyInfoPath;
 use grovedb::{EstimatedLayerInformation, TransactionArg};
 use std::collections::HashMap;
@@ -88,6 +91,15 @@ pub enum IdentityOperationType {
         /// Remove previous contested resource vote choice
         previous_resource_vote_choice_to_remove: Option<(ResourceVoteChoice, PreviousVoteCount)>,
     },
+    /// Stores the sealed bid of a contender in a contested resource auction.
+    PlaceAuctionBid {
+        /// The identity id of the contender placing the bid
+        bidder_id: [u8; 32],
+        /// The vote poll resolved by the auction
+        vote_poll: ContestedDocumentResourceVotePollWithContractInfo,
+        /// The bid as it should be stored
+        stored_bid: ContestedDocumentAuctionBidStoredInfo,
+    },
     /// Updates an identities nonce for a specific contract.
     UpdateIdentityNonce {
         /// The revision id
@@ -105,6 +117,28 @@ pub enum IdentityOperationType {
         /// The nonce we are updating to
         nonce: IdentityNonce,
     },
+
+    /// Sets, replaces or removes the social recovery configuration of an identity.
+    SetIdentityRecoveryConfig {
+        /// The identity id
+        identity_id: [u8; 32],
+        /// The new configuration, `None` removes it
+        config: Option<IdentityRecoveryConfig>,
+    },
+
+    /// Stores a recovery approved by the guardians of an identity.
+    SetIdentityPendingRecovery {
+        /// The identity id
+        identity_id: [u8; 32],
+        /// The approved recovery
+        pending_recovery: PendingIdentityRecovery,
+    },
+
+    /// Removes the pending recovery of an identity.
+    RemoveIdentityPendingRecovery {
+        /// The identity id
+        identity_id: [u8; 32],
+    },
 }
 
 impl DriveLowLevelOperationConverter for IdentityOperationType {
@@ -203,17 +237,29 @@ impl DriveLowLevelOperationConverter for IdentityOperationType {
                 vote,
                 previous_resource_vote_choice_to_remove,
             } => {
-                // No need to have estimated_costs_only_with_layer_info and block_info here
+                // No need to have estimated_costs_only_with_layer_info here
                 // This is because voting is a special operation with a fixed cost
+                // The block info is needed to start governance proposal vote polls
                 drive.register_identity_vote_operations(
                     voter_pro_tx_hash,
                     strength,
                     vote,
                     previous_resource_vote_choice_to_remove,
+                    block_info,
                     transaction,
                     platform_version,
                 )
             }
+            IdentityOperationType::PlaceAuctionBid {
+                bidder_id,
+                vote_poll,
+                stored_bid,
+            } => drive.insert_auction_bid_operations(
+                &vote_poll,
+                bidder_id.into(),
+                stored_bid,
+                platform_version,
+            ),
             IdentityOperationType::UpdateIdentityContractNonce {
                 identity_id,
                 contract_id,
@@ -243,6 +289,32 @@ impl DriveLowLevelOperationConverter for IdentityOperationType {
                 result.to_result()?;
                 Ok(operations)
             }
+            IdentityOperationType::SetIdentityRecoveryConfig {
+                identity_id,
+                config,
+            } => drive.set_identity_recovery_config_operations(
+                identity_id,
+                config,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            ),
+            IdentityOperationType::SetIdentityPendingRecovery {
+                identity_id,
+                pending_recovery,
+            } => drive.set_identity_pending_recovery_operations(
+                identity_id,
+                pending_recovery,
+                estimated_costs_only_with_layer_info,
+                platform_version,
+            ),
+            IdentityOperationType::RemoveIdentityPendingRecovery { identity_id } => drive
+                .remove_identity_pending_recovery_operations(
+                    identity_id,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    platform_version,
+                ),
         }
     }
 }
@@ -40,6 +40,8 @@ enum KnownPath {
     IdentityTreeKeyReferencesInSecurityLevel(Purpose, SecurityLevel), //Level 4
     IdentityTreeNegativeCreditRoot,                                   //Level 2
     IdentityContractInfoRoot,                                         //Level 2
+    IdentityRecoveryConfigRoot,                                       //Level 2
+    IdentityPendingRecoveryRoot,                                      //Level 2
     UniquePublicKeyHashesToIdentitiesRoot,                            //Level 1
     NonUniquePublicKeyKeyHashesToIdentitiesRoot,                      //Level 1
     PoolsRoot,                                                        //Level 1
@@ -91,6 +93,10 @@ impl From<IdentityRootStructure> for KnownPath {
                 KnownPath::IdentityTreeNegativeCreditRoot
             }
             IdentityRootStructure::IdentityContractInfo => KnownPath::IdentityContractInfoRoot,
+            IdentityRootStructure::IdentityRecoveryConfig => KnownPath::IdentityRecoveryConfigRoot,
+            IdentityRootStructure::IdentityPendingRecovery => {
+                KnownPath::IdentityPendingRecoveryRoot
+            }
         }
     }
 }
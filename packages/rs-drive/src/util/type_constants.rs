@@ -14,6 +14,8 @@ pub const DEFAULT_FLOAT_SIZE: u32 = 8;
 pub const U64_SIZE_U16: u16 = 8;
 /// u64 size
 pub const U64_SIZE_U8: u8 = 8;
+/// u64 size
+pub const U64_SIZE_U32: u32 = 8;
 /// u32 size
 pub const U32_SIZE_U16: u16 = 4;
 /// u8 size
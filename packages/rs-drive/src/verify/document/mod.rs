@@ -1,3 +1,6 @@
+mod verify_document_count_proof;
+mod verify_document_history_proof;
+mod verify_document_offers_proof;
 mod verify_proof;
 mod verify_proof_keep_serialized;
 mod verify_start_at_document_in_proof;
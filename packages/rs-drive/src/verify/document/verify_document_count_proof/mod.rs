@@ -0,0 +1,49 @@
+mod v0;
+
+use crate::error::drive::DriveError;
+use crate::verify::RootHash;
+
+use crate::error::Error;
+use crate::query::DriveDocumentCountQuery;
+use dpp::version::PlatformVersion;
+
+impl<'a> DriveDocumentCountQuery<'a> {
+    /// Verifies a proof of a document count or sum, and returns the root hash and the total.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - A byte slice containing the proof data.
+    /// * `platform_version` - The platform version against which to verify the proof.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` with a tuple containing:
+    /// * The root hash of the verified proof.
+    /// * The number of matching documents, or the sum of their summed property.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an Error in the following cases:
+    /// * If the proof is corrupted (wrong path, wrong key, etc.).
+    /// * If a proved aggregate is not a sum tree.
+    pub fn verify_document_count_proof(
+        &self,
+        proof: &[u8],
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, i64), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .document
+            .verify_document_count_proof
+        {
+            0 => self.verify_document_count_proof_v0(proof, platform_version),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_document_count_proof".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::verify::RootHash;
+
+use crate::error::Error;
+use crate::query::DriveDocumentCountQuery;
+use dpp::version::PlatformVersion;
+use grovedb::GroveDb;
+
+impl<'a> DriveDocumentCountQuery<'a> {
+    /// Verifies a proof of a document count or sum.
+    ///
+    /// Aggregates proved absent, because no document was ever stored with these index
+    /// values, add nothing to the total.
+    #[inline(always)]
+    pub(super) fn verify_document_count_proof_v0(
+        &self,
+        proof: &[u8],
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, i64), Error> {
+        let path_query = self.construct_path_query(platform_version)?;
+        let (root_hash, proved_key_values) =
+            GroveDb::verify_query(proof, &path_query, &platform_version.drive.grove_version)?;
+
+        let total = Self::total_of_elements(
+            proved_key_values
+                .into_iter()
+                .filter_map(|(_, _, element)| element),
+        )?;
+
+        Ok((root_hash, total))
+    }
+}
@@ -0,0 +1,69 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::verify::RootHash;
+
+use crate::error::Error;
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::document::Document;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// Verifies a proof of the revisions of a document, and returns the root hash and the
+    /// revisions keyed by the time at which they were stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - A byte slice containing the proof data.
+    /// * `contract_id` - The contract of the document.
+    /// * `document_type` - The document type of the document, it must keep history.
+    /// * `document_id` - The document whose revisions were proved.
+    /// * `start_at_ms` - The time from which revisions were proved, included.
+    /// * `limit` - The maximum number of proved revisions.
+    /// * `verify_subset_of_proof` - Whether we are verifying a subset of a larger proof.
+    /// * `platform_version` - The platform version against which to verify the proof.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an Error in the following cases:
+    /// * If the proof is corrupted (wrong path, wrong key, etc.).
+    /// * If a proved revision is not an item or can not be deserialized.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_document_history_proof(
+        proof: &[u8],
+        contract_id: Identifier,
+        document_type: DocumentTypeRef,
+        document_id: Identifier,
+        start_at_ms: u64,
+        limit: Option<u16>,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, BTreeMap<u64, Document>), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .document
+            .verify_document_history_proof
+        {
+            0 => Self::verify_document_history_proof_v0(
+                proof,
+                contract_id,
+                document_type,
+                document_id,
+                start_at_ms,
+                limit,
+                verify_subset_of_proof,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_document_history_proof".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::drive::Drive;
+use crate::error::proof::ProofError;
+use crate::error::Error;
+use crate::verify::RootHash;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::{DocumentPropertyType, DocumentTypeRef};
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::document::Document;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use grovedb::Element::Item;
+use grovedb::GroveDb;
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// Verifies a proof of the revisions of a document.
+    #[allow(clippy::too_many_arguments)]
+    #[inline(always)]
+    pub(super) fn verify_document_history_proof_v0(
+        proof: &[u8],
+        contract_id: Identifier,
+        document_type: DocumentTypeRef,
+        document_id: Identifier,
+        start_at_ms: u64,
+        limit: Option<u16>,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, BTreeMap<u64, Document>), Error> {
+        let path_query = Self::document_history_path_query(
+            contract_id.to_buffer(),
+            document_type.name(),
+            document_id.to_buffer(),
+            start_at_ms,
+            limit,
+        );
+
+        let (root_hash, proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query(proof, &path_query, &platform_version.drive.grove_version)?
+        } else {
+            GroveDb::verify_query(proof, &path_query, &platform_version.drive.grove_version)?
+        };
+
+        let revisions = proved_key_values
+            .into_iter()
+            .filter_map(|(_, key, element)| element.map(|element| (key, element)))
+            .map(|(key, element)| match element {
+                Item(serialized_document, _) => {
+                    let time = DocumentPropertyType::decode_date_timestamp(&key).ok_or(
+                        Error::Proof(ProofError::CorruptedProof(
+                            "document revision key is not a valid time".to_string(),
+                        )),
+                    )?;
+
+                    let document = Document::from_bytes(
+                        &serialized_document,
+                        document_type,
+                        platform_version,
+                    )?;
+
+                    Ok((time, document))
+                }
+                _ => Err(Error::Proof(ProofError::CorruptedProof(
+                    "document revision was present but was not an item".to_string(),
+                ))),
+            })
+            .collect::<Result<BTreeMap<_, _>, Error>>()?;
+
+        Ok((root_hash, revisions))
+    }
+}
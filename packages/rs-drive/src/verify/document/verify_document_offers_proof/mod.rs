@@ -0,0 +1,67 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::verify::RootHash;
+
+use crate::error::Error;
+use dpp::identifier::Identifier;
+use dpp::nft::offer::DocumentOffer;
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies a proof of the open offers on a document, and returns the root hash and the
+    /// offers ordered by bidder id.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - A byte slice containing the proof data.
+    /// * `contract_id` - The contract of the document.
+    /// * `document_type_name` - The document type of the document.
+    /// * `document_id` - The document the offers were made on.
+    /// * `limit` - The maximum number of proved offers.
+    /// * `start_after` - The bidder id after which offers were proved, for paging.
+    /// * `verify_subset_of_proof` - Whether we are verifying a subset of a larger proof.
+    /// * `platform_version` - The platform version against which to verify the proof.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an Error in the following cases:
+    /// * If the proof is corrupted (wrong path, wrong key, etc.).
+    /// * If a proved offer is not an item or can not be deserialized.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_document_offers_proof(
+        proof: &[u8],
+        contract_id: Identifier,
+        document_type_name: &str,
+        document_id: Identifier,
+        limit: Option<u16>,
+        start_after: Option<Identifier>,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Vec<DocumentOffer>), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .document
+            .verify_document_offers_proof
+        {
+            0 => Self::verify_document_offers_proof_v0(
+                proof,
+                contract_id,
+                document_type_name,
+                document_id,
+                limit,
+                start_after,
+                verify_subset_of_proof,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_document_offers_proof".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
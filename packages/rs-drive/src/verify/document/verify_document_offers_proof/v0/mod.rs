@@ -0,0 +1,55 @@
+use crate::drive::Drive;
+use crate::error::proof::ProofError;
+use crate::error::Error;
+use crate::verify::RootHash;
+use dpp::identifier::Identifier;
+use dpp::nft::offer::DocumentOffer;
+use dpp::serialization::PlatformDeserializable;
+use dpp::version::PlatformVersion;
+use grovedb::Element::Item;
+use grovedb::GroveDb;
+
+impl Drive {
+    /// Verifies a proof of the open offers on a document.
+    #[allow(clippy::too_many_arguments)]
+    #[inline(always)]
+    pub(super) fn verify_document_offers_proof_v0(
+        proof: &[u8],
+        contract_id: Identifier,
+        document_type_name: &str,
+        document_id: Identifier,
+        limit: Option<u16>,
+        start_after: Option<Identifier>,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Vec<DocumentOffer>), Error> {
+        let path_query = Self::document_offers_path_query(
+            contract_id.to_buffer(),
+            document_type_name,
+            document_id.to_buffer(),
+            limit,
+            start_after.map(|bidder_id| bidder_id.to_buffer()),
+        );
+
+        let (root_hash, proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query(proof, &path_query, &platform_version.drive.grove_version)?
+        } else {
+            GroveDb::verify_query(proof, &path_query, &platform_version.drive.grove_version)?
+        };
+
+        let offers = proved_key_values
+            .into_iter()
+            .filter_map(|(_, _, element)| element)
+            .map(|element| match element {
+                Item(serialized_offer, _) => {
+                    Ok(DocumentOffer::deserialize_from_bytes(&serialized_offer)?)
+                }
+                _ => Err(Error::Proof(ProofError::CorruptedProof(
+                    "document offer was present but was not an item".to_string(),
+                ))),
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok((root_hash, offers))
+    }
+}
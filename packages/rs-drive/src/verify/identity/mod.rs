@@ -10,4 +10,6 @@ mod verify_identity_id_by_public_key_hash;
 mod verify_identity_ids_by_public_key_hashes;
 mod verify_identity_keys_by_identity_id;
 mod verify_identity_nonce;
+mod verify_identity_pending_recovery;
+mod verify_identity_recovery_config;
 mod verify_identity_revision_for_identity_id;
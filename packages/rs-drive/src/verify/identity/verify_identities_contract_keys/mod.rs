@@ -21,6 +21,8 @@ impl Drive {
     /// - `proof`: A byte slice representing the proof of authentication from the user.
     /// - `is_proof_subset`: A boolean indicating whether the proof is a subset.
     /// - `identity_id`: A 32-byte array representing the identity ID of the user.
+    /// - `contracts_owner_id`: The optional owner of the contract, when the keys bound to all of
+    ///   its contracts were requested as well.
     /// - `platform_version`: The platform version against which to verify the identity keys.
     ///
     /// # Returns
@@ -42,6 +44,7 @@ impl Drive {
         contract_id: &[u8; 32],
         document_type_name: Option<String>,
         purposes: Vec<Purpose>,
+        contracts_owner_id: Option<[u8; 32]>,
         is_proof_subset: bool,
         platform_version: &PlatformVersion,
     ) -> Result<(RootHash, IdentitiesContractKeys), Error> {
@@ -58,6 +61,7 @@ impl Drive {
                 contract_id,
                 document_type_name,
                 purposes,
+                contracts_owner_id,
                 is_proof_subset,
                 platform_version,
             ),
@@ -43,15 +43,18 @@ impl Drive {
         contract_id: &[u8; 32],
         document_type_name: Option<String>,
         purposes: Vec<Purpose>,
+        contracts_owner_id: Option<[u8; 32]>,
         is_proof_subset: bool,
         platform_version: &PlatformVersion,
     ) -> Result<(RootHash, IdentitiesContractKeys), Error> {
+        let groups_count = if contracts_owner_id.is_some() { 2 } else { 1 };
         let path_query = Self::identities_contract_keys_query(
             identity_ids,
             contract_id,
             &document_type_name,
             &purposes,
-            Some((identity_ids.len() * purposes.len()) as u16),
+            contracts_owner_id.as_ref(),
+            Some((identity_ids.len() * purposes.len() * groups_count) as u16),
         );
 
         let (root_hash, proved_values) = if is_proof_subset {
@@ -114,7 +117,16 @@ impl Drive {
                     .map(IdentityPublicKey::deserialize_from_bytes)
                     .transpose()?;
 
-                entry.insert(purpose, maybe_identity_public_key);
+                // a key bound to the contract takes precedence over one bound to all the
+                // contracts of its owner
+                if Self::is_contracts_owner_key_path(&path, contracts_owner_id.as_ref()) {
+                    let current = entry.entry(purpose).or_insert(None);
+                    if current.is_none() {
+                        *current = maybe_identity_public_key;
+                    }
+                } else if maybe_identity_public_key.is_some() || !entry.contains_key(&purpose) {
+                    entry.insert(purpose, maybe_identity_public_key);
+                }
             }
         }
 
@@ -0,0 +1,61 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use dpp::identity::recovery::PendingIdentityRecovery;
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies the pending social recovery of an identity by its identity ID.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of authentication from the user.
+    /// - `identity_id`: A 32-byte array representing the identity ID of the user.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    /// - `platform_version`: The platform version against which to verify the pending recovery.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<PendingIdentityRecovery>`, `None` if no recovery is pending.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof of authentication is not valid.
+    ///
+    pub fn verify_identity_pending_recovery(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Option<PendingIdentityRecovery>), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .identity
+            .verify_identity_pending_recovery
+        {
+            0 => Self::verify_identity_pending_recovery_v0(
+                proof,
+                identity_id,
+                verify_subset_of_proof,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_identity_pending_recovery".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
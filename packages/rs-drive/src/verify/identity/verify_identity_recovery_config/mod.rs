@@ -0,0 +1,61 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use dpp::identity::recovery::IdentityRecoveryConfig;
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies the social recovery configuration of an identity by its identity ID.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of authentication from the user.
+    /// - `identity_id`: A 32-byte array representing the identity ID of the user.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    /// - `platform_version`: The platform version against which to verify the recovery configuration.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<IdentityRecoveryConfig>`, `None` if the identity didn't opt in to social recovery.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof of authentication is not valid.
+    ///
+    pub fn verify_identity_recovery_config(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Option<IdentityRecoveryConfig>), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .identity
+            .verify_identity_recovery_config
+        {
+            0 => Self::verify_identity_recovery_config_v0(
+                proof,
+                identity_id,
+                verify_subset_of_proof,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_identity_recovery_config".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
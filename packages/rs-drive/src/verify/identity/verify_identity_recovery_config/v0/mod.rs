@@ -0,0 +1,66 @@
+use crate::drive::Drive;
+
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use crate::drive::identity::identity_path_vec;
+use crate::drive::identity::IdentityRootStructure::IdentityRecoveryConfig as IdentityRecoveryConfigKey;
+use dpp::identity::recovery::IdentityRecoveryConfig;
+use dpp::serialization::PlatformDeserializable;
+use grovedb::GroveDb;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    pub(crate) fn verify_identity_recovery_config_v0(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Option<IdentityRecoveryConfig>), Error> {
+        let mut path_query = Self::identity_recovery_config_query(identity_id);
+        path_query.query.limit = Some(1);
+        let (root_hash, mut proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query_with_absence_proof(
+                proof,
+                &path_query,
+                &platform_version.drive.grove_version,
+            )?
+        } else {
+            GroveDb::verify_query_with_absence_proof(
+                proof,
+                &path_query,
+                &platform_version.drive.grove_version,
+            )?
+        };
+        if proved_key_values.len() == 1 {
+            let (path, key, maybe_element) = proved_key_values.remove(0);
+            if path != identity_path_vec(&identity_id) {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path for the identity recovery config".to_string(),
+                )));
+            }
+            if key != vec![IdentityRecoveryConfigKey as u8] {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct key for the identity recovery config"
+                        .to_string(),
+                )));
+            }
+
+            let config = maybe_element
+                .map(|element| {
+                    let bytes = element.into_item_bytes().map_err(Error::GroveDB)?;
+                    Ok::<IdentityRecoveryConfig, Error>(
+                        IdentityRecoveryConfig::deserialize_from_bytes(&bytes)?,
+                    )
+                })
+                .transpose()?;
+            Ok((root_hash, config))
+        } else {
+            Err(Error::Proof(ProofError::TooManyElements(
+                "expected one identity recovery config",
+            )))
+        }
+    }
+}
@@ -10,15 +10,21 @@ use dpp::fee::Credits;
 use dpp::identity::PartialIdentity;
 use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
 use dpp::state_transition::data_contract_create_transition::accessors::DataContractCreateTransitionAccessorsV0;
+use dpp::state_transition::data_contract_delete_transition::accessors::DataContractDeleteTransitionAccessorsV0;
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteMode;
 use dpp::state_transition::data_contract_update_transition::accessors::DataContractUpdateTransitionAccessorsV0;
 use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
 use dpp::state_transition::documents_batch_transition::document_base_transition::v0::v0_methods::DocumentBaseTransitionV0Methods;
 use dpp::state_transition::documents_batch_transition::document_create_transition::v0::v0_methods::DocumentCreateTransitionV0Methods;
 use dpp::state_transition::documents_batch_transition::document_transition::{DocumentTransition, DocumentTransitionV0Methods};
 use dpp::state_transition::identity_create_transition::accessors::IdentityCreateTransitionAccessorsV0;
+use dpp::state_transition::auction_bid_transition::accessors::AuctionBidTransitionAccessorsV0;
+use dpp::state_transition::identity_credit_batch_transfer_transition::accessors::IdentityCreditBatchTransferTransitionAccessorsV0;
 use dpp::state_transition::identity_credit_transfer_transition::accessors::IdentityCreditTransferTransitionAccessorsV0;
 use dpp::state_transition::identity_credit_withdrawal_transition::accessors::IdentityCreditWithdrawalTransitionAccessorsV0;
 use dpp::state_transition::identity_topup_transition::accessors::IdentityTopUpTransitionAccessorsV0;
+use dpp::state_transition::identity_recovery_transition::accessors::IdentityRecoveryTransitionAccessorsV0;
+use dpp::state_transition::identity_recovery_transition::IdentityRecoveryOperation;
 use dpp::state_transition::identity_update_transition::accessors::IdentityUpdateTransitionAccessorsV0;
 use dpp::state_transition::{StateTransition, StateTransitionLike};
 use dpp::state_transition::documents_batch_transition::document_create_transition::DocumentFromCreateTransition;
@@ -28,9 +34,12 @@ use dpp::state_transition::documents_batch_transition::document_replace_transiti
 use dpp::state_transition::documents_batch_transition::document_transition::document_purchase_transition::v0::v0_methods::DocumentPurchaseTransitionV0Methods;
 use dpp::state_transition::documents_batch_transition::document_transition::document_transfer_transition::v0::v0_methods::DocumentTransferTransitionV0Methods;
 use dpp::state_transition::documents_batch_transition::document_transition::document_update_price_transition::v0::v0_methods::DocumentUpdatePriceTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::document_accept_offer_transition::v0::v0_methods::DocumentAcceptOfferTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::document_make_offer_transition::v0::v0_methods::DocumentMakeOfferTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::document_withdraw_offer_transition::v0::v0_methods::DocumentWithdrawOfferTransitionV0Methods;
 use dpp::state_transition::masternode_vote_transition::accessors::MasternodeVoteTransitionAccessorsV0;
 use dpp::state_transition::proof_result::StateTransitionProofResult;
-use dpp::state_transition::proof_result::StateTransitionProofResult::{VerifiedBalanceTransfer, VerifiedDataContract, VerifiedDocuments, VerifiedIdentity, VerifiedMasternodeVote, VerifiedPartialIdentity};
+use dpp::state_transition::proof_result::StateTransitionProofResult::{VerifiedBalanceBatchTransfer, VerifiedBalanceTransfer, VerifiedDataContract, VerifiedDataContractDeleted, VerifiedDocuments, VerifiedIdentity, VerifiedIdentityRecoveryConfig, VerifiedMasternodeVote, VerifiedPartialIdentity, VerifiedPendingIdentityRecovery};
 use dpp::voting::vote_polls::VotePoll;
 use dpp::voting::votes::resource_vote::accessors::v0::ResourceVoteGettersV0;
 use dpp::voting::votes::Vote;
@@ -91,6 +100,30 @@ impl Drive {
                 }
                 Ok((root_hash, VerifiedDataContract(contract)))
             }
+            StateTransition::DataContractDelete(data_contract_delete) => {
+                let data_contract_id = data_contract_delete.data_contract_id();
+                let (root_hash, contract) = Drive::verify_contract(
+                    proof,
+                    None,
+                    false,
+                    true,
+                    data_contract_id.into_buffer(),
+                    platform_version,
+                )?;
+                match data_contract_delete.mode() {
+                    DataContractDeleteMode::Archive => {
+                        // an archived contract stays in state
+                        let contract = contract.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain contract with id {} expected to exist because of state transition (archive)", data_contract_id))))?;
+                        Ok((root_hash, VerifiedDataContract(contract)))
+                    }
+                    DataContractDeleteMode::Delete => {
+                        if contract.is_some() {
+                            return Err(Error::Proof(ProofError::IncorrectProof(format!("proof contained contract with id {} expected to be absent because of state transition (delete)", data_contract_id))));
+                        }
+                        Ok((root_hash, VerifiedDataContractDeleted(data_contract_id)))
+                    }
+                }
+            }
             StateTransition::DocumentsBatch(documents_batch_transition) => {
                 if documents_batch_transition.transitions().len() > 1 {
                     return Err(Error::Proof(ProofError::InvalidTransition(format!("version {} does not support more than one document in a document batch transition", platform_version.protocol_version))));
@@ -141,6 +174,9 @@ impl Drive {
                     DocumentTransition::Transfer(_) => {}
                     DocumentTransition::UpdatePrice(_) => {}
                     DocumentTransition::Purchase(_) => {}
+                    DocumentTransition::MakeOffer(_) => {}
+                    DocumentTransition::WithdrawOffer(_) => {}
+                    DocumentTransition::AcceptOffer(_) => {}
                 }
 
                 let query = SingleDocumentDriveQuery {
@@ -261,6 +297,38 @@ impl Drive {
                             return Err(Error::Proof(ProofError::IncorrectProof(format!("proof of state transition execution did not have the transfer executed after expected transfer with id {}", purchase_transition.base().id()))));
                         }
 
+                        Ok((
+                            root_hash,
+                            VerifiedDocuments(BTreeMap::from([(document.id(), Some(document))])),
+                        ))
+                    }
+                    DocumentTransition::MakeOffer(make_offer_transition) => {
+                        // Making an offer doesn't change the document, the offer itself can be verified
+                        // with a proof of the offers on the document
+                        let document = document.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain document with id {} expected to exist because of state transition (make offer)", make_offer_transition.base().id()))))?;
+
+                        Ok((
+                            root_hash,
+                            VerifiedDocuments(BTreeMap::from([(document.id(), Some(document))])),
+                        ))
+                    }
+                    DocumentTransition::WithdrawOffer(withdraw_offer_transition) => {
+                        // Offers can be withdrawn from documents that were deleted since
+                        Ok((
+                            root_hash,
+                            VerifiedDocuments(BTreeMap::from([(
+                                withdraw_offer_transition.base().id(),
+                                document,
+                            )])),
+                        ))
+                    }
+                    DocumentTransition::AcceptOffer(accept_offer_transition) => {
+                        let document = document.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain document with id {} expected to exist because of state transition (accept offer)", accept_offer_transition.base().id()))))?;
+
+                        if document.owner_id() != accept_offer_transition.bidder_id() {
+                            return Err(Error::Proof(ProofError::IncorrectProof(format!("proof of state transition execution did not have the transfer to the bidder executed after expected accepted offer with id {}", accept_offer_transition.base().id()))));
+                        }
+
                         Ok((
                             root_hash,
                             VerifiedDocuments(BTreeMap::from([(document.id(), Some(document))])),
@@ -344,6 +412,44 @@ impl Drive {
                 let identity = identity.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain update for identity {} expected to exist because of state transition (update)", identity_update_transition.identity_id()))))?;
                 Ok((root_hash, VerifiedPartialIdentity(identity)))
             }
+            StateTransition::IdentityRecovery(identity_recovery_transition) => {
+                let identity_id = identity_recovery_transition.identity_id();
+                match identity_recovery_transition.operation() {
+                    IdentityRecoveryOperation::SetConfig(expected_config) => {
+                        let (root_hash, config) = Drive::verify_identity_recovery_config(
+                            proof,
+                            identity_id.into_buffer(),
+                            false,
+                            platform_version,
+                        )?;
+                        if &config != expected_config {
+                            return Err(Error::Proof(ProofError::IncorrectProof(format!("proof of recovery config for identity {} does not match the state transition (recovery)", identity_id))));
+                        }
+                        Ok((
+                            root_hash,
+                            VerifiedIdentityRecoveryConfig(identity_id, config),
+                        ))
+                    }
+                    operation => {
+                        let (root_hash, pending_recovery) =
+                            Drive::verify_identity_pending_recovery(
+                                proof,
+                                identity_id.into_buffer(),
+                                false,
+                                platform_version,
+                            )?;
+                        let is_initiation =
+                            matches!(operation, IdentityRecoveryOperation::Initiate { .. });
+                        if pending_recovery.is_some() != is_initiation {
+                            return Err(Error::Proof(ProofError::IncorrectProof(format!("proof of pending recovery for identity {} does not match the {} state transition (recovery)", identity_id, operation))));
+                        }
+                        Ok((
+                            root_hash,
+                            VerifiedPendingIdentityRecovery(identity_id, pending_recovery),
+                        ))
+                    }
+                }
+            }
             StateTransition::IdentityCreditTransfer(identity_credit_transfer) => {
                 // we expect to get an identity that matches the state transition
                 let (root_hash_identity, balance_identity) =
@@ -391,6 +497,83 @@ impl Drive {
                     ),
                 ))
             }
+            StateTransition::IdentityCreditBatchTransfer(identity_credit_batch_transfer) => {
+                // we expect to get the balances of the sender and of every recipient
+                let (root_hash_identity, balance_identity) =
+                    Drive::verify_identity_balance_for_identity_id(
+                        proof,
+                        identity_credit_batch_transfer.identity_id().into_buffer(),
+                        true,
+                        platform_version,
+                    )?;
+
+                let balance_identity = balance_identity.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain balance for identity sender {} expected to exist because of state transition (batch transfer)", identity_credit_batch_transfer.identity_id()))))?;
+
+                let recipients = identity_credit_batch_transfer
+                    .outputs()
+                    .iter()
+                    .map(|output| {
+                        let (root_hash_recipient, balance_recipient) =
+                            Drive::verify_identity_balance_for_identity_id(
+                                proof,
+                                output.recipient_id.into_buffer(),
+                                true,
+                                platform_version,
+                            )?;
+
+                        if root_hash_identity != root_hash_recipient {
+                            return Err(Error::Proof(ProofError::CorruptedProof("proof is expected to have same root hash for all subsets (identity batch transfer)".to_string())));
+                        }
+
+                        let balance_recipient = balance_recipient.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain balance for identity recipient {} expected to exist because of state transition (batch transfer)", output.recipient_id))))?;
+
+                        Ok(PartialIdentity {
+                            id: output.recipient_id,
+                            loaded_public_keys: Default::default(),
+                            balance: Some(balance_recipient),
+                            revision: None,
+
+                            not_found_public_keys: Default::default(),
+                        })
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                Ok((
+                    root_hash_identity,
+                    VerifiedBalanceBatchTransfer(
+                        PartialIdentity {
+                            id: identity_credit_batch_transfer.identity_id(),
+                            loaded_public_keys: Default::default(),
+                            balance: Some(balance_identity),
+                            revision: None,
+
+                            not_found_public_keys: Default::default(),
+                        },
+                        recipients,
+                    ),
+                ))
+            }
+            StateTransition::AuctionBid(auction_bid) => {
+                // we expect to get the balance of the bidder, as the deposit of a bid is escrowed
+                let (root_hash, balance) = Drive::verify_identity_balance_for_identity_id(
+                    proof,
+                    auction_bid.identity_id().into_buffer(),
+                    false,
+                    platform_version,
+                )?;
+                let balance = balance.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain balance for identity {} expected to exist because of state transition (auction bid)", auction_bid.identity_id()))))?;
+                Ok((
+                    root_hash,
+                    VerifiedPartialIdentity(PartialIdentity {
+                        id: auction_bid.identity_id(),
+                        loaded_public_keys: Default::default(),
+                        balance: Some(balance),
+                        revision: None,
+
+                        not_found_public_keys: Default::default(),
+                    }),
+                ))
+            }
             StateTransition::MasternodeVote(masternode_vote) => {
                 let pro_tx_hash = masternode_vote.pro_tx_hash();
                 let vote = masternode_vote.vote();
@@ -407,7 +590,28 @@ impl Drive {
                                 contested_document_resource_vote_poll.contract_id
                             )),
                         ))?,
+                        VotePoll::GovernanceProposalVotePoll(_) => {
+                            return Err(Error::Proof(ProofError::IncorrectProof(
+                                "resource votes can not be cast on governance proposal vote polls"
+                                    .to_string(),
+                            )));
+                        }
                     },
+                    Vote::GovernanceProposalVote(governance_proposal_vote) => {
+                        // governance proposal votes are stored under the vote poll, not a contract
+                        let (root_hash, vote) = Drive::verify_governance_proposal_vote(
+                            proof,
+                            pro_tx_hash.to_buffer(),
+                            governance_proposal_vote,
+                            false,
+                            platform_version,
+                        )?;
+                        let vote = vote.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain actual governance proposal vote for masternode {} expected to exist because of state transition (masternode vote)", masternode_vote.pro_tx_hash()))))?;
+                        return Ok((
+                            root_hash,
+                            VerifiedMasternodeVote(Vote::GovernanceProposalVote(vote)),
+                        ));
+                    }
                 };
 
                 // we expect to get a vote that matches the state transition
@@ -0,0 +1,284 @@
+use crate::drive::Drive;
+use crate::error::proof::ProofError;
+use crate::error::Error;
+use crate::query::{
+    ContractLookupFn, SingleDocumentDriveQuery, SingleDocumentDriveQueryContestedStatus,
+};
+use crate::verify::RootHash;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::document::document_methods::DocumentMethodsV0;
+use dpp::document::property_names::PRICE;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::fee::Credits;
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+use dpp::prelude::Identifier;
+use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
+use dpp::state_transition::documents_batch_transition::document_base_transition::v0::v0_methods::DocumentBaseTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_create_transition::v0::v0_methods::DocumentCreateTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_create_transition::DocumentFromCreateTransition;
+use dpp::state_transition::documents_batch_transition::document_delete_transition::v0::v0_methods::DocumentDeleteTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_replace_transition::DocumentFromReplaceTransition;
+use dpp::state_transition::documents_batch_transition::document_replace_transition::v0::v0_methods::DocumentReplaceTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::document_accept_offer_transition::v0::v0_methods::DocumentAcceptOfferTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::document_make_offer_transition::v0::v0_methods::DocumentMakeOfferTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::document_purchase_transition::v0::v0_methods::DocumentPurchaseTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::document_transfer_transition::v0::v0_methods::DocumentTransferTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::document_update_price_transition::v0::v0_methods::DocumentUpdatePriceTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::document_withdraw_offer_transition::v0::v0_methods::DocumentWithdrawOfferTransitionV0Methods;
+use dpp::state_transition::documents_batch_transition::document_transition::{
+    DocumentTransition, DocumentTransitionV0Methods,
+};
+use dpp::state_transition::proof_result::StateTransitionProofResult;
+use dpp::state_transition::proof_result::StateTransitionProofResult::VerifiedDocuments;
+use dpp::state_transition::{StateTransition, StateTransitionLike};
+use platform_version::version::PlatformVersion;
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// Same as v0, but documents batch transitions can contain several document transitions.
+    /// All transitions must be proved by the same proof.
+    #[inline(always)]
+    pub(crate) fn verify_state_transition_was_executed_with_proof_v1(
+        state_transition: &StateTransition,
+        block_info: &BlockInfo,
+        proof: &[u8],
+        known_contracts_provider_fn: &ContractLookupFn,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, StateTransitionProofResult), Error> {
+        let StateTransition::DocumentsBatch(documents_batch_transition) = state_transition else {
+            return Drive::verify_state_transition_was_executed_with_proof_v0(
+                state_transition,
+                block_info,
+                proof,
+                known_contracts_provider_fn,
+                platform_version,
+            );
+        };
+
+        let transitions = documents_batch_transition.transitions();
+
+        if transitions.is_empty() {
+            return Err(Error::Proof(ProofError::InvalidTransition(
+                "no transition in a document batch transition".to_string(),
+            )));
+        }
+
+        // The proof contains all documents of the batch, so each of them is verified as a subset
+        let is_subset = transitions.len() > 1;
+
+        let mut expected_root_hash = None;
+        let mut documents = BTreeMap::new();
+
+        for transition in transitions {
+            let (root_hash, document_id, document) =
+                Drive::verify_document_transition_was_executed_with_proof(
+                    documents_batch_transition.owner_id(),
+                    transition,
+                    block_info,
+                    proof,
+                    is_subset,
+                    known_contracts_provider_fn,
+                    platform_version,
+                )?;
+
+            match expected_root_hash {
+                None => expected_root_hash = Some(root_hash),
+                Some(expected_root_hash) if expected_root_hash != root_hash => {
+                    return Err(Error::Proof(ProofError::CorruptedProof(format!(
+                        "proof of document {} has a different root hash than other documents of the batch",
+                        document_id
+                    ))));
+                }
+                Some(_) => {}
+            }
+
+            documents.insert(document_id, document);
+        }
+
+        let root_hash = expected_root_hash.ok_or(Error::Proof(ProofError::InvalidTransition(
+            "no transition in a document batch transition".to_string(),
+        )))?;
+
+        Ok((root_hash, VerifiedDocuments(documents)))
+    }
+
+    /// Verifies that a single document transition of a documents batch was executed.
+    ///
+    /// Returns the root hash, the id of the document and the document as it is after execution,
+    /// `None` if it was deleted. `is_subset` should be set when the proof also proves other
+    /// transitions of the same batch.
+    pub(crate) fn verify_document_transition_was_executed_with_proof(
+        owner_id: Identifier,
+        transition: &DocumentTransition,
+        block_info: &BlockInfo,
+        proof: &[u8],
+        is_subset: bool,
+        known_contracts_provider_fn: &ContractLookupFn,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Identifier, Option<Document>), Error> {
+        let data_contract_id = transition.data_contract_id();
+
+        let contract = known_contracts_provider_fn(&data_contract_id)?.ok_or(Error::Proof(
+            ProofError::UnknownContract(format!("unknown contract with id {}", data_contract_id)),
+        ))?;
+
+        let document_type = contract
+            .document_type_for_name(transition.document_type_name())
+            .map_err(|e| {
+                Error::Proof(ProofError::UnknownContract(format!(
+                    "cannot fetch contract for document {} with id {}: {}",
+                    transition.document_type_name(),
+                    transition.data_contract_id(),
+                    e
+                )))
+            })?;
+
+        let contested_status = if let DocumentTransition::Create(create_transition) = transition {
+            if create_transition.prefunded_voting_balance().is_some() {
+                SingleDocumentDriveQueryContestedStatus::Contested
+            } else {
+                SingleDocumentDriveQueryContestedStatus::NotContested
+            }
+        } else {
+            SingleDocumentDriveQueryContestedStatus::NotContested
+        };
+
+        match transition {
+            DocumentTransition::Create(_) => {}
+            DocumentTransition::Replace(_) => {}
+            DocumentTransition::Delete(_) => {}
+            DocumentTransition::Transfer(_) => {}
+            DocumentTransition::UpdatePrice(_) => {}
+            DocumentTransition::Purchase(_) => {}
+            DocumentTransition::MakeOffer(_) => {}
+            DocumentTransition::WithdrawOffer(_) => {}
+            DocumentTransition::AcceptOffer(_) => {}
+        }
+
+        let query = SingleDocumentDriveQuery {
+            contract_id: transition.data_contract_id().into_buffer(),
+            document_type_name: transition.document_type_name().clone(),
+            document_type_keeps_history: document_type.documents_keep_history(),
+            document_id: transition.base().id().into_buffer(),
+            block_time_ms: None, //None because we want latest
+            contested_status,
+        };
+        let (root_hash, document) =
+            query.verify_proof(is_subset, proof, document_type, platform_version)?;
+
+        match transition {
+            DocumentTransition::Create(create_transition) => {
+                let document = document.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain document with id {} expected to exist because of state transition (create)", create_transition.base().id()))))?;
+                let expected_document = Document::try_from_create_transition(
+                    create_transition,
+                    owner_id,
+                    block_info,
+                    &document_type,
+                    platform_version,
+                )?;
+
+                let transient_fields = document_type
+                    .transient_fields()
+                    .iter()
+                    .map(|a| a.as_str())
+                    .collect();
+
+                if !document.is_equal_ignoring_time_based_fields(
+                    &expected_document,
+                    Some(transient_fields),
+                    platform_version,
+                )? {
+                    return Err(Error::Proof(ProofError::IncorrectProof(format!("proof of state transition execution did not contain expected document (time fields were not checked) after create with id {}", create_transition.base().id()))));
+                }
+                Ok((root_hash, document.id(), Some(document)))
+            }
+            DocumentTransition::Replace(replace_transition) => {
+                let document = document.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain document with id {} expected to exist because of state transition (replace)", replace_transition.base().id()))))?;
+                let expected_document = Document::try_from_replace_transition(
+                    replace_transition,
+                    owner_id,
+                    document.created_at(), //we can trust the created at (as we don't care)
+                    document.created_at_block_height(), //we can trust the created at block height (as we don't care)
+                    document.created_at_core_block_height(), //we can trust the created at core block height (as we don't care)
+                    document.created_at(), //we can trust the created at (as we don't care)
+                    document.created_at_block_height(), //we can trust the created at block height (as we don't care)
+                    document.created_at_core_block_height(), //we can trust the created at core block height (as we don't care)
+                    block_info,
+                    &document_type,
+                    platform_version,
+                )?;
+
+                let transient_fields = document_type
+                    .transient_fields()
+                    .iter()
+                    .map(|a| a.as_str())
+                    .collect();
+
+                if !document.is_equal_ignoring_time_based_fields(
+                    &expected_document,
+                    Some(transient_fields),
+                    platform_version,
+                )? {
+                    return Err(Error::Proof(ProofError::IncorrectProof(format!("proof of state transition execution did not contain expected document (time fields were not checked) after replace with id {}", replace_transition.base().id()))));
+                }
+
+                Ok((root_hash, document.id(), Some(document)))
+            }
+            DocumentTransition::Transfer(transfer_transition) => {
+                let document = document.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain document with id {} expected to exist because of state transition (transfer)", transfer_transition.base().id()))))?;
+                let recipient_owner_id = transfer_transition.recipient_owner_id();
+
+                if document.owner_id() != recipient_owner_id {
+                    return Err(Error::Proof(ProofError::IncorrectProof(format!("proof of state transition execution did not have the transfer executed after expected transfer with id {}", transfer_transition.base().id()))));
+                }
+
+                Ok((root_hash, document.id(), Some(document)))
+            }
+            DocumentTransition::Delete(delete_transition) => {
+                if document.is_some() {
+                    return Err(Error::Proof(ProofError::IncorrectProof(format!("proof of state transition execution contained document after delete with id {}", delete_transition.base().id()))));
+                }
+                Ok((root_hash, delete_transition.base().id(), None))
+            }
+            DocumentTransition::UpdatePrice(update_price_transition) => {
+                let document = document.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain document with id {} expected to exist because of state transition (update price)", update_price_transition.base().id()))))?;
+                let new_document_price : Credits = document.properties().get_integer(PRICE).map_err(|e| Error::Proof(ProofError::IncorrectProof(format!("proof did not contain a document that contained a price field with id {} expected to exist because of state transition (update price): {}", update_price_transition.base().id(), e))))?;
+                if new_document_price != update_price_transition.price() {
+                    return Err(Error::Proof(ProofError::IncorrectProof(format!("proof of state transition execution did not contain expected document update of price after price update with id {}", update_price_transition.base().id()))));
+                }
+                Ok((root_hash, document.id(), Some(document)))
+            }
+            DocumentTransition::Purchase(purchase_transition) => {
+                let document = document.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain document with id {} expected to exist because of state transition (purchase)", purchase_transition.base().id()))))?;
+
+                if document.owner_id() != owner_id {
+                    return Err(Error::Proof(ProofError::IncorrectProof(format!("proof of state transition execution did not have the transfer executed after expected transfer with id {}", purchase_transition.base().id()))));
+                }
+
+                Ok((root_hash, document.id(), Some(document)))
+            }
+            DocumentTransition::MakeOffer(make_offer_transition) => {
+                // Making an offer doesn't change the document, the offer itself can be verified
+                // with a proof of the offers on the document
+                let document = document.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain document with id {} expected to exist because of state transition (make offer)", make_offer_transition.base().id()))))?;
+
+                Ok((root_hash, document.id(), Some(document)))
+            }
+            DocumentTransition::WithdrawOffer(withdraw_offer_transition) => {
+                // Offers can be withdrawn from documents that were deleted since
+                Ok((root_hash, withdraw_offer_transition.base().id(), document))
+            }
+            DocumentTransition::AcceptOffer(accept_offer_transition) => {
+                let document = document.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain document with id {} expected to exist because of state transition (accept offer)", accept_offer_transition.base().id()))))?;
+
+                if document.owner_id() != accept_offer_transition.bidder_id() {
+                    return Err(Error::Proof(ProofError::IncorrectProof(format!("proof of state transition execution did not have the transfer to the bidder executed after expected accepted offer with id {}", accept_offer_transition.base().id()))));
+                }
+
+                Ok((root_hash, document.id(), Some(document)))
+            }
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //! Voting verification
 
 mod verify_contests_proof;
+mod verify_governance_proposal_vote;
+mod verify_governance_proposal_vote_poll_stored_info;
 mod verify_identity_votes_given_proof;
 mod verify_masternode_vote;
 mod verify_specialized_balance;
@@ -0,0 +1,62 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::verify::RootHash;
+use dpp::version::PlatformVersion;
+use dpp::voting::votes::governance_proposal_vote::GovernanceProposalVote;
+
+impl Drive {
+    /// Verifies the vote of an evonode on a governance proposal using the provided proof.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the grovedb proof of the vote.
+    /// - `masternode_pro_tx_hash`: A 32-byte array representing the ProTxHash of the evonode.
+    /// - `vote`: A reference to the vote being verified.
+    /// - `verify_subset_of_proof`: A boolean indicating whether a subset of a larger proof is being verified.
+    /// - `platform_version`: A reference to the platform version against which to verify the vote.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple containing `RootHash` and an `Option<GovernanceProposalVote>`,
+    /// which contains the proved vote if the evonode voted on the proposal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is invalid.
+    /// - The proved vote choice differs from the one of the provided vote.
+    /// - An unknown or unsupported platform version is provided.
+    ///
+    pub fn verify_governance_proposal_vote(
+        proof: &[u8],
+        masternode_pro_tx_hash: [u8; 32],
+        vote: &GovernanceProposalVote,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Option<GovernanceProposalVote>), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .voting
+            .verify_governance_proposal_vote
+        {
+            0 => Self::verify_governance_proposal_vote_v0(
+                proof,
+                masternode_pro_tx_hash,
+                vote,
+                verify_subset_of_proof,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_governance_proposal_vote".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
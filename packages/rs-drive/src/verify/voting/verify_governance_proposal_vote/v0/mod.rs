@@ -0,0 +1,71 @@
+use crate::drive::Drive;
+use grovedb::GroveDb;
+
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use dpp::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use dpp::voting::votes::governance_proposal_vote::accessors::v0::GovernanceProposalVoteGettersV0;
+use dpp::voting::votes::governance_proposal_vote::GovernanceProposalVote;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    pub(crate) fn verify_governance_proposal_vote_v0(
+        proof: &[u8],
+        masternode_pro_tx_hash: [u8; 32],
+        vote: &GovernanceProposalVote,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Option<GovernanceProposalVote>), Error> {
+        let vote_poll_id = vote.vote_poll().unique_id()?;
+
+        let mut path_query =
+            Self::governance_proposal_vote_query(vote_poll_id.to_buffer(), masternode_pro_tx_hash);
+        path_query.query.limit = Some(1);
+
+        let (root_hash, mut proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query_with_absence_proof(
+                proof,
+                &path_query,
+                &platform_version.drive.grove_version,
+            )?
+        } else {
+            GroveDb::verify_query_with_absence_proof(
+                proof,
+                &path_query,
+                &platform_version.drive.grove_version,
+            )?
+        };
+        if proved_key_values.len() == 1 {
+            let (_, _, maybe_element) = proved_key_values.remove(0);
+            let maybe_vote = maybe_element
+                .map(|element| {
+                    let vote_choice_bytes = element.into_item_bytes()?;
+                    let [vote_choice] = vote_choice_bytes.as_slice() else {
+                        return Err(Error::Proof(ProofError::CorruptedProof(
+                            "the proved governance proposal vote choice is not a single byte"
+                                .to_string(),
+                        )));
+                    };
+                    let proved_vote_choice = YesNoAbstainVoteChoice::try_from(*vote_choice)?;
+                    if proved_vote_choice != vote.vote_choice() {
+                        Err(Error::Proof(ProofError::IncorrectProof(format!(
+                            "returned vote choice {} does not match the vote choice that was sent {}",
+                            proved_vote_choice,
+                            vote.vote_choice()
+                        ))))
+                    } else {
+                        Ok::<GovernanceProposalVote, Error>(vote.clone())
+                    }
+                })
+                .transpose()?;
+            Ok((root_hash, maybe_vote))
+        } else {
+            Err(Error::Proof(ProofError::TooManyElements(
+                "expected one governance proposal vote",
+            )))
+        }
+    }
+}
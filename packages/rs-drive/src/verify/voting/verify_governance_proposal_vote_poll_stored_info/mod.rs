@@ -0,0 +1,61 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_info_storage::governance_proposal_vote_poll_stored_info::GovernanceProposalVotePollStoredInfo;
+
+impl Drive {
+    /// Verifies the stored info of a governance proposal vote poll by its unique id.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the grovedb proof of the stored info.
+    /// - `vote_poll_id`: A 32-byte array representing the unique id of the vote poll.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    /// - `platform_version`: The platform version against which to verify the stored info.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<GovernanceProposalVotePollStoredInfo>`, `None` if nobody voted on the proposal yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is not valid.
+    ///
+    pub fn verify_governance_proposal_vote_poll_stored_info(
+        proof: &[u8],
+        vote_poll_id: [u8; 32],
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Option<GovernanceProposalVotePollStoredInfo>), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .voting
+            .verify_governance_proposal_vote_poll_stored_info
+        {
+            0 => Self::verify_governance_proposal_vote_poll_stored_info_v0(
+                proof,
+                vote_poll_id,
+                verify_subset_of_proof,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_governance_proposal_vote_poll_stored_info".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
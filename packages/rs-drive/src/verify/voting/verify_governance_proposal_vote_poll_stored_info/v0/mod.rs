@@ -0,0 +1,67 @@
+use crate::drive::Drive;
+
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use crate::drive::votes::paths::{
+    vote_decisions_vote_poll_path_vec, GOVERNANCE_PROPOSAL_STORED_INFO_KEY,
+};
+use dpp::serialization::PlatformDeserializable;
+use dpp::voting::vote_info_storage::governance_proposal_vote_poll_stored_info::GovernanceProposalVotePollStoredInfo;
+use grovedb::GroveDb;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    pub(crate) fn verify_governance_proposal_vote_poll_stored_info_v0(
+        proof: &[u8],
+        vote_poll_id: [u8; 32],
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Option<GovernanceProposalVotePollStoredInfo>), Error> {
+        let mut path_query = Self::governance_proposal_vote_poll_stored_info_query(vote_poll_id);
+        path_query.query.limit = Some(1);
+        let (root_hash, mut proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query_with_absence_proof(
+                proof,
+                &path_query,
+                &platform_version.drive.grove_version,
+            )?
+        } else {
+            GroveDb::verify_query_with_absence_proof(
+                proof,
+                &path_query,
+                &platform_version.drive.grove_version,
+            )?
+        };
+        if proved_key_values.len() == 1 {
+            let (path, key, maybe_element) = proved_key_values.remove(0);
+            if path != vote_decisions_vote_poll_path_vec(vote_poll_id.as_slice()) {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct path for the governance proposal vote poll stored info".to_string(),
+                )));
+            }
+            if key != vec![GOVERNANCE_PROPOSAL_STORED_INFO_KEY] {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "we did not get back an element for the correct key for the governance proposal vote poll stored info"
+                        .to_string(),
+                )));
+            }
+
+            let stored_info = maybe_element
+                .map(|element| {
+                    let bytes = element.into_item_bytes().map_err(Error::GroveDB)?;
+                    Ok::<GovernanceProposalVotePollStoredInfo, Error>(
+                        GovernanceProposalVotePollStoredInfo::deserialize_from_bytes(&bytes)?,
+                    )
+                })
+                .transpose()?;
+            Ok((root_hash, stored_info))
+        } else {
+            Err(Error::Proof(ProofError::TooManyElements(
+                "expected one governance proposal vote poll stored info",
+            )))
+        }
+    }
+}
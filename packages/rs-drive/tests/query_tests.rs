@@ -51,6 +51,8 @@ use std::sync::Arc;
 #[cfg(feature = "server")]
 use dpp::document::Document;
 #[cfg(feature = "server")]
+use dpp::platform_value::string_encoding::Encoding;
+#[cfg(feature = "server")]
 use dpp::platform_value::Value;
 use dpp::platform_value::{platform_value, Bytes32, Identifier};
 
@@ -2873,6 +2875,90 @@ fn test_family_sql_query() {
             .expect("should build query");
 
     assert_eq!(query1, query2);
+
+    // Between clause
+    let sql_string = "select * from person where age between 20 and 30 order by age limit 100";
+    let query =
+        DriveDocumentQuery::from_sql_expr(sql_string, &contract, Some(&DriveConfig::default()))
+            .expect("should build query");
+
+    assert_eq!(
+        query.internal_clauses.range_clause,
+        Some(WhereClause {
+            field: "age".to_string(),
+            operator: WhereOperator::Between,
+            value: Value::Array(vec![Value::I64(20), Value::I64(30)]),
+        })
+    );
+
+    // Offset cursor
+    let start_after = Identifier::from([7u8; 32]);
+    let sql_string = format!(
+        "select * from person order by firstName asc limit 100 offset '{}'",
+        start_after.to_string(Encoding::Base58)
+    );
+    let query =
+        DriveDocumentQuery::from_sql_expr(&sql_string, &contract, Some(&DriveConfig::default()))
+            .expect("should build query");
+
+    assert_eq!(query.start_at, Some(start_after.to_buffer()));
+    assert!(!query.start_at_included);
+    assert_eq!(query.offset, None);
+
+    // Numeric offset
+    let sql_string = "select * from person order by firstName asc limit 100 offset 5";
+    let query =
+        DriveDocumentQuery::from_sql_expr(sql_string, &contract, Some(&DriveConfig::default()))
+            .expect("should build query");
+
+    assert_eq!(query.offset, Some(5));
+    assert_eq!(query.start_at, None);
+}
+
+#[cfg(feature = "server")]
+#[test]
+fn test_family_sql_query_errors() {
+    let (_, contract) = setup_family_tests(1, 73509);
+
+    let sql_error = |sql_string: &str| {
+        DriveDocumentQuery::from_sql_expr(sql_string, &contract, Some(&DriveConfig::default()))
+            .expect_err("expected the query to be invalid")
+    };
+
+    assert!(matches!(
+        sql_error("select count(*) from person"),
+        Error::Query(QuerySyntaxError::Unsupported(_))
+    ));
+
+    assert!(matches!(
+        sql_error("select * from person where age not between 20 and 30"),
+        Error::Query(QuerySyntaxError::Unsupported(_))
+    ));
+
+    assert!(matches!(
+        sql_error("select * from person where age between 20 and lastName"),
+        Error::Query(QuerySyntaxError::InvalidBetweenClause(_))
+    ));
+
+    assert!(matches!(
+        sql_error("select * from person where 'C%' like firstName"),
+        Error::Query(QuerySyntaxError::InvalidStartsWithClause(_))
+    ));
+
+    assert!(matches!(
+        sql_error("select * from person where firstName like lastName"),
+        Error::Query(QuerySyntaxError::InvalidStartsWithClause(_))
+    ));
+
+    assert!(matches!(
+        sql_error("select * from person order by firstName offset 'not an id'"),
+        Error::Query(QuerySyntaxError::InvalidSQL(_))
+    ));
+
+    assert!(matches!(
+        sql_error("select * from person where nickname = 'Chris'"),
+        Error::Query(QuerySyntaxError::InvalidSQL(_))
+    ));
 }
 
 #[cfg(feature = "server")]
@@ -89,6 +89,9 @@ pub struct DocumentTypeValidationVersions {
     pub validate_update: FeatureVersion,
     pub unique_index_limit: u16,
     pub contested_index_limit: u16,
+    pub aggregated_index_limit: u16,
+    pub max_trade_mode: u8,
+    pub max_contested_index_resolution: u8,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -119,6 +122,9 @@ pub struct StateTransitionConversionVersions {
     pub identity_to_identity_create_transition: FeatureVersion,
     pub identity_to_identity_top_up_transition: FeatureVersion,
     pub identity_to_identity_withdrawal_transition: FeatureVersion,
+    pub identity_to_identity_transfer_transition: FeatureVersion,
+    pub identity_to_identity_batch_transfer_transition: FeatureVersion,
+    pub identity_to_auction_bid_transition: FeatureVersion,
     pub identity_to_identity_create_transition_with_signer: FeatureVersion,
 }
 
@@ -130,9 +136,13 @@ pub struct StateTransitionSerializationVersions {
     pub identity_top_up_state_transition: FeatureVersionBounds,
     pub identity_credit_withdrawal_state_transition: FeatureVersionBounds,
     pub identity_credit_transfer_state_transition: FeatureVersionBounds,
+    pub identity_credit_batch_transfer_state_transition: FeatureVersionBounds,
+    pub identity_recovery_state_transition: FeatureVersionBounds,
     pub masternode_vote_state_transition: FeatureVersionBounds,
+    pub auction_bid_state_transition: FeatureVersionBounds,
     pub contract_create_state_transition: FeatureVersionBounds,
     pub contract_update_state_transition: FeatureVersionBounds,
+    pub contract_delete_state_transition: FeatureVersionBounds,
     pub documents_batch_state_transition: FeatureVersionBounds,
     pub document_base_state_transition: FeatureVersionBounds,
     pub document_create_state_transition: DocumentFeatureVersionBounds,
@@ -141,6 +151,9 @@ pub struct StateTransitionSerializationVersions {
     pub document_transfer_state_transition: DocumentFeatureVersionBounds,
     pub document_update_price_state_transition: DocumentFeatureVersionBounds,
     pub document_purchase_state_transition: DocumentFeatureVersionBounds,
+    pub document_make_offer_state_transition: DocumentFeatureVersionBounds,
+    pub document_withdraw_offer_state_transition: DocumentFeatureVersionBounds,
+    pub document_accept_offer_state_transition: DocumentFeatureVersionBounds,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -237,6 +250,24 @@ pub struct IdentityVersions {
 pub struct VotingVersions {
     pub default_vote_poll_time_duration_ms: u64,
     pub contested_document_vote_poll_stored_info_version: FeatureVersion,
+    pub governance_proposal_vote_poll_stored_info_version: FeatureVersion,
+    /// The shortest time a governance proposal vote poll can run for
+    pub governance_proposal_min_vote_poll_duration_ms: u64,
+    /// The longest time a governance proposal vote poll can run for
+    pub governance_proposal_max_vote_poll_duration_ms: u64,
+    /// The share of the evonodes that must vote for the result of a governance proposal to count
+    pub governance_proposal_quorum_percentage: u8,
+    /// The share of the yes/no votes that must be yes for a governance proposal to be approved
+    pub governance_proposal_approval_percentage: u8,
+    /// The credits the evonode opening a governance proposal vote poll moves from its voting
+    /// identity to the prefunded balance that pays for the votes on the proposal
+    pub governance_proposal_vote_poll_funding_amount: u64,
+    /// How many governance proposal vote polls a single evonode can open during an epoch
+    pub governance_proposal_max_vote_polls_opened_per_epoch: u16,
+    pub contested_document_auction_bid_stored_info_version: FeatureVersion,
+    /// How long contenders have to reveal their bids once bidding on an auction has closed,
+    /// auction vote polls end this long after other contested resource vote polls would
+    pub auction_bid_reveal_duration_ms: u64,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -1,3 +1,4 @@
+use crate::version::fee::state_transition_output_min_fees::StateTransitionOutputMinFees;
 use crate::version::{FeatureVersion, FeatureVersionBounds, OptionalFeatureVersion};
 
 #[derive(Clone, Debug, Default)]
@@ -14,6 +15,11 @@ pub struct DriveAbciQueryVersions {
     pub response_metadata: FeatureVersion,
     pub proofs_query: FeatureVersionBounds,
     pub document_query: FeatureVersionBounds,
+    pub document_count_query: FeatureVersionBounds,
+    pub document_offers_query: FeatureVersionBounds,
+    pub document_history_query: FeatureVersionBounds,
+    pub state_transition_fee_estimation_query: FeatureVersionBounds,
+    pub platform_events_subscription: FeatureVersionBounds,
     pub prefunded_specialized_balances: DriveAbciQueryPrefundedSpecializedBalancesVersions,
     pub identity_based_queries: DriveAbciQueryIdentityVersions,
     pub data_contract_based_queries: DriveAbciQueryDataContractVersions,
@@ -36,6 +42,8 @@ pub struct DriveAbciQueryIdentityVersions {
     pub balance: FeatureVersionBounds,
     pub balance_and_revision: FeatureVersionBounds,
     pub identity_by_public_key_hash: FeatureVersionBounds,
+    pub identity_pending_recovery: FeatureVersionBounds,
+    pub identity_withdrawals: FeatureVersionBounds,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -45,6 +53,7 @@ pub struct DriveAbciQueryVotingVersions {
     pub contested_resource_voters_for_identity: FeatureVersionBounds,
     pub contested_resource_identity_vote_status: FeatureVersionBounds,
     pub contested_resources: FeatureVersionBounds,
+    pub governance_proposal_vote_poll_state: FeatureVersionBounds,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -100,6 +109,7 @@ pub struct DriveAbciValidationVersions {
     pub process_state_transition: FeatureVersion,
     pub state_transition_to_execution_event_for_check_tx: FeatureVersion,
     pub penalties: PenaltyAmounts,
+    pub state_transition_output_min_fees: StateTransitionOutputMinFees,
     pub event_constants: DriveAbciValidationConstants,
 }
 
@@ -107,6 +117,7 @@ pub struct DriveAbciValidationVersions {
 pub struct DriveAbciValidationConstants {
     pub maximum_vote_polls_to_process: u16,
     pub maximum_contenders_to_consider: u16,
+    pub maximum_documents_to_backfill_indexes: u16,
 }
 
 /// All of these penalty amounts are in credits
@@ -146,6 +157,12 @@ pub struct DriveAbciDocumentsStateTransitionValidationVersions {
     pub document_transfer_transition_state_validation: FeatureVersion,
     pub document_purchase_transition_state_validation: FeatureVersion,
     pub document_update_price_transition_state_validation: FeatureVersion,
+    pub document_make_offer_transition_structure_validation: OptionalFeatureVersion,
+    pub document_withdraw_offer_transition_structure_validation: OptionalFeatureVersion,
+    pub document_accept_offer_transition_structure_validation: OptionalFeatureVersion,
+    pub document_make_offer_transition_state_validation: OptionalFeatureVersion,
+    pub document_withdraw_offer_transition_state_validation: OptionalFeatureVersion,
+    pub document_accept_offer_transition_state_validation: OptionalFeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -185,9 +202,14 @@ pub struct DriveAbciStateTransitionValidationVersions {
     pub identity_top_up_state_transition: DriveAbciStateTransitionValidationVersion,
     pub identity_credit_withdrawal_state_transition: DriveAbciStateTransitionValidationVersion,
     pub identity_credit_transfer_state_transition: DriveAbciStateTransitionValidationVersion,
+    pub identity_credit_batch_transfer_state_transition:
+        Option<DriveAbciStateTransitionValidationVersion>,
+    pub identity_recovery_state_transition: Option<DriveAbciStateTransitionValidationVersion>,
     pub masternode_vote_state_transition: DriveAbciStateTransitionValidationVersion,
+    pub auction_bid_state_transition: Option<DriveAbciStateTransitionValidationVersion>,
     pub contract_create_state_transition: DriveAbciStateTransitionValidationVersion,
     pub contract_update_state_transition: DriveAbciStateTransitionValidationVersion,
+    pub contract_delete_state_transition: Option<DriveAbciStateTransitionValidationVersion>,
     pub documents_batch_state_transition: DriveAbciDocumentsStateTransitionValidationVersions,
 }
 
@@ -201,6 +223,7 @@ pub struct DriveAbciStateTransitionCommonValidationVersions {
     pub validate_unique_identity_public_key_hashes_in_state: FeatureVersion,
     pub validate_master_key_uniqueness: FeatureVersion,
     pub validate_simple_pre_check_balance: FeatureVersion,
+    pub fetch_data_contract_archived_at: OptionalFeatureVersion,
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -299,6 +322,8 @@ pub struct DriveAbciBlockEndMethodVersions {
     pub update_state_cache: FeatureVersion,
     pub update_drive_cache: FeatureVersion,
     pub validator_set_update: FeatureVersion,
+    pub consensus_param_updates: OptionalFeatureVersion,
+    pub backfill_document_indexes: OptionalFeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -306,8 +331,11 @@ pub struct DriveAbciVotingMethodVersions {
     pub keep_record_of_finished_contested_resource_vote_poll: FeatureVersion,
     pub clean_up_after_vote_poll_end: FeatureVersion,
     pub clean_up_after_contested_resources_vote_poll_end: FeatureVersion,
+    pub clean_up_after_governance_proposal_vote_polls_end: OptionalFeatureVersion,
     pub check_for_ended_vote_polls: FeatureVersion,
     pub tally_votes_for_contested_document_resource_vote_poll: FeatureVersion,
+    pub tally_votes_for_governance_proposal_vote_poll: OptionalFeatureVersion,
+    pub resolve_auction_for_contested_document_resource_vote_poll: OptionalFeatureVersion,
     pub award_document_to_winner: FeatureVersion,
     pub delay_vote_poll: FeatureVersion,
     pub run_dao_platform_events: FeatureVersion,
@@ -328,6 +356,7 @@ pub struct DriveAbciIdentityCreditWithdrawalMethodVersions {
 pub struct DriveAbciProtocolUpgradeMethodVersions {
     pub check_for_desired_protocol_upgrade: FeatureVersion,
     pub upgrade_protocol_version_on_epoch_change: FeatureVersion,
+    pub perform_events_on_first_block_of_protocol_change: OptionalFeatureVersion,
     pub protocol_version_upgrade_percentage_needed: u64,
 }
 
@@ -337,4 +366,5 @@ pub struct DriveAbciStateTransitionProcessingMethodVersions {
     pub process_raw_state_transitions: FeatureVersion,
     pub decode_raw_state_transitions: FeatureVersion,
     pub validate_fees_of_event: FeatureVersion,
+    pub estimate_state_transition_fee: FeatureVersion,
 }
@@ -50,19 +50,26 @@ pub struct DriveStateTransitionMethodVersions {
 pub struct DriveStateTransitionActionConvertToHighLevelOperationsMethodVersions {
     pub data_contract_create_transition: FeatureVersion,
     pub data_contract_update_transition: FeatureVersion,
+    pub data_contract_delete_transition: FeatureVersion,
     pub document_create_transition: FeatureVersion,
     pub document_delete_transition: FeatureVersion,
     pub document_purchase_transition: FeatureVersion,
     pub document_replace_transition: FeatureVersion,
     pub document_transfer_transition: FeatureVersion,
     pub document_update_price_transition: FeatureVersion,
+    pub document_make_offer_transition: FeatureVersion,
+    pub document_withdraw_offer_transition: FeatureVersion,
+    pub document_accept_offer_transition: FeatureVersion,
     pub documents_batch_transition: FeatureVersion,
     pub identity_create_transition: FeatureVersion,
     pub identity_credit_transfer_transition: FeatureVersion,
+    pub identity_credit_batch_transfer_transition: FeatureVersion,
     pub identity_credit_withdrawal_transition: FeatureVersion,
     pub identity_top_up_transition: FeatureVersion,
     pub identity_update_transition: FeatureVersion,
+    pub identity_recovery_transition: FeatureVersion,
     pub masternode_vote_transition: FeatureVersion,
+    pub auction_bid_transition: FeatureVersion,
     pub bump_identity_data_contract_nonce: FeatureVersion,
     pub bump_identity_nonce: FeatureVersion,
     pub partially_use_asset_lock: FeatureVersion,
@@ -112,6 +119,9 @@ pub struct DriveVerifyDocumentMethodVersions {
     pub verify_proof: FeatureVersion,
     pub verify_proof_keep_serialized: FeatureVersion,
     pub verify_start_at_document_in_proof: FeatureVersion,
+    pub verify_document_count_proof: FeatureVersion,
+    pub verify_document_offers_proof: FeatureVersion,
+    pub verify_document_history_proof: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -127,6 +137,8 @@ pub struct DriveVerifyIdentityMethodVersions {
     pub verify_identity_nonce: FeatureVersion,
     pub verify_identity_contract_nonce: FeatureVersion,
     pub verify_identities_contract_keys: FeatureVersion,
+    pub verify_identity_recovery_config: FeatureVersion,
+    pub verify_identity_pending_recovery: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -139,6 +151,8 @@ pub struct DriveVerifyVoteMethodVersions {
     pub verify_contests_proof: FeatureVersion,
     pub verify_vote_polls_by_end_date_proof: FeatureVersion,
     pub verify_specialized_balance: FeatureVersion,
+    pub verify_governance_proposal_vote: FeatureVersion,
+    pub verify_governance_proposal_vote_poll_stored_info: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -207,6 +221,7 @@ pub struct DriveContractMethodVersions {
     pub apply: DriveContractApplyMethodVersions,
     pub insert: DriveContractInsertMethodVersions,
     pub update: DriveContractUpdateMethodVersions,
+    pub delete: DriveContractDeleteMethodVersions,
     pub costs: DriveContractCostsMethodVersions,
     pub get: DriveContractGetMethodVersions,
 }
@@ -235,6 +250,12 @@ pub struct DriveContractUpdateMethodVersions {
     pub update_contract: FeatureVersion,
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct DriveContractDeleteMethodVersions {
+    pub archive_contract: FeatureVersion,
+    pub delete_contract: FeatureVersion,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct DriveContractGetMethodVersions {
     pub fetch_contract: FeatureVersion,
@@ -242,6 +263,8 @@ pub struct DriveContractGetMethodVersions {
     pub get_cached_contract_with_fetch_info: FeatureVersion,
     pub get_contract_with_fetch_info: FeatureVersion,
     pub get_contracts_with_fetch_info: FeatureVersion,
+    pub fetch_contract_archived_at: FeatureVersion,
+    pub count_contract_documents_up_to: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -288,12 +311,31 @@ pub struct DriveSystemEstimationCostsMethodVersions {
 pub struct DriveVoteMethodVersions {
     pub insert: DriveVoteInsertMethodVersions,
     pub contested_resource_insert: DriveVoteContestedResourceInsertMethodVersions,
+    pub governance_proposal: DriveVoteGovernanceProposalMethodVersions,
+    pub auction: DriveVoteAuctionMethodVersions,
     pub cleanup: DriveVoteCleanupMethodVersions,
     pub setup: DriveVoteSetupMethodVersions,
     pub storage_form: DriveVoteStorageFormMethodVersions,
     pub fetch: DriveVoteFetchMethodVersions,
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct DriveVoteGovernanceProposalMethodVersions {
+    pub register_governance_proposal_identity_vote: FeatureVersion,
+    pub insert_stored_info_for_governance_proposal_vote_poll: FeatureVersion,
+    pub fetch_governance_proposal_vote_poll_stored_info: FeatureVersion,
+    pub fetch_governance_proposal_votes: FeatureVersion,
+    pub remove_governance_proposal_vote_poll_operations: FeatureVersion,
+    pub prove_governance_proposal_vote_poll_stored_info: FeatureVersion,
+    pub fetch_governance_proposal_vote_polls_opened_in_epoch: FeatureVersion,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DriveVoteAuctionMethodVersions {
+    pub insert_auction_bid_operations: FeatureVersion,
+    pub fetch_auction_bid: FeatureVersion,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct DriveVoteFetchMethodVersions {
     pub fetch_identities_voting_for_contenders: FeatureVersion,
@@ -319,6 +361,7 @@ pub struct DriveVoteCleanupMethodVersions {
     pub remove_contested_resource_vote_poll_votes_operations: FeatureVersion,
     pub remove_contested_resource_vote_poll_documents_operations: FeatureVersion,
     pub remove_contested_resource_vote_poll_contenders_operations: FeatureVersion,
+    pub remove_contested_resource_vote_poll_auction_bids_operations: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -343,6 +386,39 @@ pub struct DriveDocumentMethodVersions {
     pub update: DriveDocumentUpdateMethodVersions,
     pub estimation_costs: DriveDocumentEstimationCostsMethodVersions,
     pub index_uniqueness: DriveDocumentIndexUniquenessMethodVersions,
+    pub aggregates: DriveDocumentAggregatesMethodVersions,
+    pub offers: DriveDocumentOffersMethodVersions,
+    pub history: DriveDocumentHistoryMethodVersions,
+    pub index_builds: DriveDocumentIndexBuildsMethodVersions,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DriveDocumentAggregatesMethodVersions {
+    pub update_aggregates_for_document_operations: FeatureVersion,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DriveDocumentOffersMethodVersions {
+    pub add_document_offer_operations: FeatureVersion,
+    pub remove_document_offer_operations: FeatureVersion,
+    pub fetch_document_offer: FeatureVersion,
+    pub fetch_document_offers: FeatureVersion,
+    pub prove_document_offers: FeatureVersion,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DriveDocumentHistoryMethodVersions {
+    pub fetch_document_history: FeatureVersion,
+    pub prove_document_history: FeatureVersion,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DriveDocumentIndexBuildsMethodVersions {
+    pub schedule_index_build_operations: FeatureVersion,
+    pub fetch_pending_index_build: FeatureVersion,
+    pub fetch_pending_index_builds: FeatureVersion,
+    pub backfill_pending_index_builds: FeatureVersion,
+    pub document_type_without_unbuilt_indexes: OptionalFeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -416,6 +492,7 @@ pub struct DriveDocumentIndexUniquenessMethodVersions {
     pub validate_document_transfer_transition_action_uniqueness: FeatureVersion,
     pub validate_document_purchase_transition_action_uniqueness: FeatureVersion,
     pub validate_document_update_price_transition_action_uniqueness: FeatureVersion,
+    pub validate_document_accept_offer_transition_action_uniqueness: FeatureVersion,
     pub validate_uniqueness_of_data: FeatureVersion,
 }
 
@@ -547,10 +624,21 @@ pub struct DriveIdentityMethodVersions {
     pub update: DriveIdentityUpdateMethodVersions,
     pub insert: DriveIdentityInsertMethodVersions,
     pub contract_info: DriveIdentityContractInfoMethodVersions,
+    pub recovery: DriveIdentityRecoveryMethodVersions,
     pub cost_estimation: DriveIdentityCostEstimationMethodVersions,
     pub withdrawals: DriveIdentityWithdrawalMethodVersions,
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct DriveIdentityRecoveryMethodVersions {
+    pub fetch_identity_recovery_config: FeatureVersion,
+    pub fetch_identity_pending_recovery: FeatureVersion,
+    pub prove_identity_pending_recovery: FeatureVersion,
+    pub set_identity_recovery_config: FeatureVersion,
+    pub set_identity_pending_recovery: FeatureVersion,
+    pub remove_identity_pending_recovery: FeatureVersion,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct DriveIdentityWithdrawalMethodVersions {
     pub document: DriveIdentityWithdrawalDocumentMethodVersions,
@@ -561,6 +649,8 @@ pub struct DriveIdentityWithdrawalMethodVersions {
 pub struct DriveIdentityWithdrawalDocumentMethodVersions {
     pub fetch_oldest_withdrawal_documents_by_status: FeatureVersion,
     pub find_up_to_100_withdrawal_documents_by_status_and_transaction_indices: FeatureVersion,
+    pub fetch_identity_withdrawal_documents: FeatureVersion,
+    pub prove_identity_withdrawal_documents: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -601,6 +691,7 @@ pub struct DriveIdentityCostEstimationMethodVersions {
     pub for_purpose_in_key_reference_tree: FeatureVersion,
     pub for_root_key_reference_tree: FeatureVersion,
     pub for_update_revision: FeatureVersion,
+    pub for_identity_recovery: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
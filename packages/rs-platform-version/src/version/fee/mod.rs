@@ -12,6 +12,7 @@ mod hashing;
 mod processing;
 pub mod signature;
 pub mod state_transition_min_fees;
+pub mod state_transition_output_min_fees;
 pub mod storage;
 pub mod v1;
 pub mod vote_resolution_fund_fees;
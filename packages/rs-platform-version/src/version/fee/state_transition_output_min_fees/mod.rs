@@ -0,0 +1,10 @@
+pub mod v1;
+
+/// Minimum fees paid for each output of a state transition, in credits.
+///
+/// These are not part of `FeeVersion`, as fee versions are stored in the platform state and
+/// their encoding must not change.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateTransitionOutputMinFees {
+    pub credit_batch_transfer_output: u64,
+}
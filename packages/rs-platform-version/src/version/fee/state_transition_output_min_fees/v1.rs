@@ -0,0 +1,6 @@
+use crate::version::fee::state_transition_output_min_fees::StateTransitionOutputMinFees;
+
+pub const STATE_TRANSITION_OUTPUT_MIN_FEES_VERSION1: StateTransitionOutputMinFees =
+    StateTransitionOutputMinFees {
+        credit_batch_transfer_output: 20000,
+    };
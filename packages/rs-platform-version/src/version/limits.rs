@@ -4,4 +4,8 @@ pub struct SystemLimits {
     pub max_field_value_size: u32,
     pub max_state_transition_size: u64,
     pub max_transitions_in_documents_batch: u16,
+    pub max_withdrawal_outputs_per_pooled_transaction: u16,
+    pub max_outputs_in_credit_batch_transfer: u16,
+    pub max_credit_transfer_memo_size: u16,
+    pub max_documents_deleted_with_contract: u16,
 }
@@ -36,15 +36,18 @@ use crate::version::drive_abci_versions::{
 use crate::version::drive_versions::{
     DriveAssetLockMethodVersions, DriveBalancesMethodVersions, DriveBatchOperationsMethodVersion,
     DriveContractApplyMethodVersions, DriveContractCostsMethodVersions,
-    DriveContractGetMethodVersions, DriveContractInsertMethodVersions, DriveContractMethodVersions,
+    DriveContractDeleteMethodVersions, DriveContractGetMethodVersions,
+    DriveContractInsertMethodVersions, DriveContractMethodVersions,
     DriveContractProveMethodVersions, DriveContractUpdateMethodVersions,
     DriveCreditPoolEpochsMethodVersions, DriveCreditPoolMethodVersions,
     DriveCreditPoolPendingEpochRefundsMethodVersions,
     DriveCreditPoolStorageFeeDistributionPoolMethodVersions,
     DriveCreditPoolUnpaidEpochMethodVersions, DriveDataContractOperationMethodVersions,
-    DriveDocumentDeleteMethodVersions, DriveDocumentEstimationCostsMethodVersions,
-    DriveDocumentIndexUniquenessMethodVersions, DriveDocumentInsertContestedMethodVersions,
-    DriveDocumentInsertMethodVersions, DriveDocumentMethodVersions,
+    DriveDocumentAggregatesMethodVersions, DriveDocumentDeleteMethodVersions,
+    DriveDocumentEstimationCostsMethodVersions, DriveDocumentHistoryMethodVersions,
+    DriveDocumentIndexBuildsMethodVersions, DriveDocumentIndexUniquenessMethodVersions,
+    DriveDocumentInsertContestedMethodVersions, DriveDocumentInsertMethodVersions,
+    DriveDocumentMethodVersions, DriveDocumentOffersMethodVersions,
     DriveDocumentQueryMethodVersions, DriveDocumentUpdateMethodVersions,
     DriveEstimatedCostsMethodVersions, DriveFeesMethodVersions, DriveFetchMethodVersions,
     DriveGroveApplyMethodVersions, DriveGroveBasicMethodVersions, DriveGroveBatchMethodVersions,
@@ -56,9 +59,9 @@ use crate::version::drive_versions::{
     DriveIdentityKeyHashesToIdentityInsertMethodVersions, DriveIdentityKeysFetchMethodVersions,
     DriveIdentityKeysInsertMethodVersions, DriveIdentityKeysMethodVersions,
     DriveIdentityKeysProveMethodVersions, DriveIdentityMethodVersions,
-    DriveIdentityProveMethodVersions, DriveIdentityUpdateMethodVersions,
-    DriveIdentityWithdrawalDocumentMethodVersions, DriveIdentityWithdrawalMethodVersions,
-    DriveIdentityWithdrawalTransactionIndexMethodVersions,
+    DriveIdentityProveMethodVersions, DriveIdentityRecoveryMethodVersions,
+    DriveIdentityUpdateMethodVersions, DriveIdentityWithdrawalDocumentMethodVersions,
+    DriveIdentityWithdrawalMethodVersions, DriveIdentityWithdrawalTransactionIndexMethodVersions,
     DriveIdentityWithdrawalTransactionMethodVersions,
     DriveIdentityWithdrawalTransactionQueueMethodVersions, DriveInitializationMethodVersions,
     DriveMethodVersions, DriveOperationsMethodVersion, DrivePlatformStateMethodVersions,
@@ -71,10 +74,12 @@ use crate::version::drive_versions::{
     DriveVerifyIdentityMethodVersions, DriveVerifyMethodVersions,
     DriveVerifySingleDocumentMethodVersions, DriveVerifyStateTransitionMethodVersions,
     DriveVerifySystemMethodVersions, DriveVerifyVoteMethodVersions, DriveVersion,
-    DriveVoteCleanupMethodVersions, DriveVoteContestedResourceInsertMethodVersions,
-    DriveVoteFetchMethodVersions, DriveVoteInsertMethodVersions, DriveVoteMethodVersions,
-    DriveVoteSetupMethodVersions, DriveVoteStorageFormMethodVersions,
+    DriveVoteAuctionMethodVersions, DriveVoteCleanupMethodVersions,
+    DriveVoteContestedResourceInsertMethodVersions, DriveVoteFetchMethodVersions,
+    DriveVoteGovernanceProposalMethodVersions, DriveVoteInsertMethodVersions,
+    DriveVoteMethodVersions, DriveVoteSetupMethodVersions, DriveVoteStorageFormMethodVersions,
 };
+use crate::version::fee::state_transition_output_min_fees::v1::STATE_TRANSITION_OUTPUT_MIN_FEES_VERSION1;
 use crate::version::fee::v1::FEE_VERSION1;
 use crate::version::limits::SystemLimits;
 use crate::version::mocks::TEST_PROTOCOL_VERSION_SHIFT_BYTES;
@@ -222,8 +227,30 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     validate_document_transfer_transition_action_uniqueness: 0,
                     validate_document_purchase_transition_action_uniqueness: 0,
                     validate_document_update_price_transition_action_uniqueness: 0,
+                    validate_document_accept_offer_transition_action_uniqueness: 0,
                     validate_uniqueness_of_data: 0,
                 },
+                aggregates: DriveDocumentAggregatesMethodVersions {
+                    update_aggregates_for_document_operations: 0,
+                },
+                offers: DriveDocumentOffersMethodVersions {
+                    add_document_offer_operations: 0,
+                    remove_document_offer_operations: 0,
+                    fetch_document_offer: 0,
+                    fetch_document_offers: 0,
+                    prove_document_offers: 0,
+                },
+                history: DriveDocumentHistoryMethodVersions {
+                    fetch_document_history: 0,
+                    prove_document_history: 0,
+                },
+                index_builds: DriveDocumentIndexBuildsMethodVersions {
+                    schedule_index_build_operations: 0,
+                    fetch_pending_index_build: 0,
+                    fetch_pending_index_builds: 0,
+                    backfill_pending_index_builds: 0,
+                    document_type_without_unbuilt_indexes: Some(0),
+                },
             },
             vote: DriveVoteMethodVersions {
                 insert: DriveVoteInsertMethodVersions {
@@ -235,6 +262,19 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     register_identity_vote: 0,
                     add_vote_poll_end_date_query_operations: 0,
                 },
+                governance_proposal: DriveVoteGovernanceProposalMethodVersions {
+                    register_governance_proposal_identity_vote: 0,
+                    insert_stored_info_for_governance_proposal_vote_poll: 0,
+                    fetch_governance_proposal_vote_poll_stored_info: 0,
+                    fetch_governance_proposal_votes: 0,
+                    remove_governance_proposal_vote_poll_operations: 0,
+                    prove_governance_proposal_vote_poll_stored_info: 0,
+                    fetch_governance_proposal_vote_polls_opened_in_epoch: 0,
+                },
+                auction: DriveVoteAuctionMethodVersions {
+                    insert_auction_bid_operations: 0,
+                    fetch_auction_bid: 0,
+                },
                 cleanup: DriveVoteCleanupMethodVersions {
                     remove_specific_vote_references_given_by_identity: 0,
                     remove_specific_votes_given_by_identity: 0,
@@ -242,6 +282,7 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     remove_contested_resource_vote_poll_votes_operations: 0,
                     remove_contested_resource_vote_poll_documents_operations: 0,
                     remove_contested_resource_vote_poll_contenders_operations: 0,
+                    remove_contested_resource_vote_poll_auction_bids_operations: 0,
                 },
                 setup: DriveVoteSetupMethodVersions {
                     add_initial_vote_tree_main_structure_operations: 0,
@@ -270,6 +311,10 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     insert_contract: 0,
                 },
                 update: DriveContractUpdateMethodVersions { update_contract: 0 },
+                delete: DriveContractDeleteMethodVersions {
+                    archive_contract: 0,
+                    delete_contract: 0,
+                },
                 costs: DriveContractCostsMethodVersions {
                     add_estimation_costs_for_contract_insertion: 0,
                 },
@@ -279,6 +324,8 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     get_cached_contract_with_fetch_info: 0,
                     get_contract_with_fetch_info: 0,
                     get_contracts_with_fetch_info: 0,
+                    fetch_contract_archived_at: 0,
+                    count_contract_documents_up_to: 0,
                 },
             },
             fees: DriveFeesMethodVersions { calculate_fee: 0 },
@@ -302,6 +349,9 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     verify_proof: 0,
                     verify_proof_keep_serialized: 0,
                     verify_start_at_document_in_proof: 0,
+                    verify_document_count_proof: 0,
+                    verify_document_offers_proof: 0,
+                    verify_document_history_proof: 0,
                 },
                 identity: DriveVerifyIdentityMethodVersions {
                     verify_full_identities_by_public_key_hashes: 0,
@@ -315,6 +365,8 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     verify_identity_nonce: 0,
                     verify_identity_contract_nonce: 0,
                     verify_identities_contract_keys: 0,
+                    verify_identity_recovery_config: 0,
+                    verify_identity_pending_recovery: 0,
                 },
                 single_document: DriveVerifySingleDocumentMethodVersions {
                     verify_proof: 0,
@@ -336,6 +388,8 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     verify_contests_proof: 0,
                     verify_vote_polls_by_end_date_proof: 0,
                     verify_specialized_balance: 0,
+                    verify_governance_proposal_vote: 0,
+                    verify_governance_proposal_vote_poll_stored_info: 0,
                 },
                 state_transition: DriveVerifyStateTransitionMethodVersions {
                     verify_state_transition_was_executed_with_proof: 0,
@@ -443,6 +497,14 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     refresh_potential_contract_info_key_references: 0,
                     merge_identity_contract_nonce: 0,
                 },
+                recovery: DriveIdentityRecoveryMethodVersions {
+                    fetch_identity_recovery_config: 0,
+                    fetch_identity_pending_recovery: 0,
+                    prove_identity_pending_recovery: 0,
+                    set_identity_recovery_config: 0,
+                    set_identity_pending_recovery: 0,
+                    remove_identity_pending_recovery: 0,
+                },
                 cost_estimation: DriveIdentityCostEstimationMethodVersions {
                     for_authentication_keys_security_level_in_key_reference_tree: 0,
                     for_balances: 0,
@@ -455,11 +517,14 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     for_purpose_in_key_reference_tree: 0,
                     for_root_key_reference_tree: 0,
                     for_update_revision: 0,
+                    for_identity_recovery: 0,
                 },
                 withdrawals: DriveIdentityWithdrawalMethodVersions {
                     document: DriveIdentityWithdrawalDocumentMethodVersions {
                         fetch_oldest_withdrawal_documents_by_status: 0,
                         find_up_to_100_withdrawal_documents_by_status_and_transaction_indices: 0,
+                        fetch_identity_withdrawal_documents: 0,
+                        prove_identity_withdrawal_documents: 0,
                     },
                     transaction: DriveIdentityWithdrawalTransactionMethodVersions {
                         index: DriveIdentityWithdrawalTransactionIndexMethodVersions {
@@ -502,19 +567,26 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 DriveStateTransitionActionConvertToHighLevelOperationsMethodVersions {
                     data_contract_create_transition: 0,
                     data_contract_update_transition: 0,
+                    data_contract_delete_transition: 0,
                     document_create_transition: 0,
                     document_delete_transition: 0,
                     document_purchase_transition: 0,
                     document_replace_transition: 0,
                     document_transfer_transition: 0,
                     document_update_price_transition: 0,
+                    document_make_offer_transition: 0,
+                    document_withdraw_offer_transition: 0,
+                    document_accept_offer_transition: 0,
                     documents_batch_transition: 0,
                     identity_create_transition: 0,
                     identity_credit_transfer_transition: 0,
+                    identity_credit_batch_transfer_transition: 0,
                     identity_credit_withdrawal_transition: 0,
                     identity_top_up_transition: 0,
                     identity_update_transition: 0,
+                    identity_recovery_transition: 0,
                     masternode_vote_transition: 0,
+                    auction_bid_transition: 0,
                     bump_identity_data_contract_nonce: 0,
                     bump_identity_nonce: 0,
                     partially_use_asset_lock: 0,
@@ -629,6 +701,7 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
             protocol_upgrade: DriveAbciProtocolUpgradeMethodVersions {
                 check_for_desired_protocol_upgrade: 0,
                 upgrade_protocol_version_on_epoch_change: 0,
+                perform_events_on_first_block_of_protocol_change: Some(0),
                 protocol_version_upgrade_percentage_needed: 75,
             },
             block_fee_processing: DriveAbciBlockFeeProcessingMethodVersions {
@@ -666,10 +739,13 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
             },
             voting: DriveAbciVotingMethodVersions {
                 keep_record_of_finished_contested_resource_vote_poll: 0,
-                clean_up_after_vote_poll_end: 0,
-                clean_up_after_contested_resources_vote_poll_end: 0,
-                check_for_ended_vote_polls: 0,
+                clean_up_after_vote_poll_end: 1,
+                clean_up_after_contested_resources_vote_poll_end: 1,
+                clean_up_after_governance_proposal_vote_polls_end: Some(0),
+                check_for_ended_vote_polls: 1,
                 tally_votes_for_contested_document_resource_vote_poll: 0,
+                tally_votes_for_governance_proposal_vote_poll: Some(0),
+                resolve_auction_for_contested_document_resource_vote_poll: Some(0),
                 award_document_to_winner: 0,
                 delay_vote_poll: 0,
                 run_dao_platform_events: 0,
@@ -680,6 +756,7 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 process_raw_state_transitions: 0,
                 decode_raw_state_transitions: 0,
                 validate_fees_of_event: 0,
+                estimate_state_transition_fee: 0,
             },
             epoch: DriveAbciEpochMethodVersions {
                 gather_epoch_info: 0,
@@ -692,6 +769,8 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 update_state_cache: 0,
                 update_drive_cache: 0,
                 validator_set_update: 0,
+                consensus_param_updates: Some(0),
+                backfill_document_indexes: Some(0),
             },
             platform_state_storage: DriveAbciPlatformStateStorageMethodVersions {
                 fetch_platform_state: 0,
@@ -705,13 +784,14 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                         fetch_asset_lock_transaction_output_sync: 0,
                         verify_asset_lock_is_not_spent_and_has_enough_balance: 0,
                     },
-                    validate_identity_public_key_contract_bounds: 0,
+                    validate_identity_public_key_contract_bounds: 1,
                     validate_identity_public_key_ids_dont_exist_in_state: 0,
                     validate_identity_public_key_ids_exist_in_state: 0,
-                    validate_state_transition_identity_signed: 0,
+                    validate_state_transition_identity_signed: 1,
                     validate_unique_identity_public_key_hashes_in_state: 0,
                     validate_master_key_uniqueness: 0,
                     validate_simple_pre_check_balance: 0,
+                    fetch_data_contract_archived_at: Some(0),
                 },
                 max_asset_lock_usage_attempts: 16,
                 identity_create_state_transition: DriveAbciStateTransitionValidationVersion {
@@ -761,6 +841,26 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     state: 0,
                     transform_into_action: 0,
                 },
+                identity_credit_batch_transfer_state_transition: Some(
+                    DriveAbciStateTransitionValidationVersion {
+                    basic_structure: Some(0),
+                    advanced_structure: None,
+                    identity_signatures: None,
+                    advanced_minimum_balance_pre_check: Some(0),
+                    nonce: Some(0),
+                    state: 0,
+                    transform_into_action: 0,
+                },
+                ),
+                identity_recovery_state_transition: Some(DriveAbciStateTransitionValidationVersion {
+                    basic_structure: Some(0),
+                    advanced_structure: None,
+                    identity_signatures: Some(0),
+                    advanced_minimum_balance_pre_check: None,
+                    nonce: Some(0),
+                    state: 0,
+                    transform_into_action: 0,
+                }),
                 masternode_vote_state_transition: DriveAbciStateTransitionValidationVersion {
                     basic_structure: None,
                     advanced_structure: Some(0),
@@ -770,6 +870,15 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     state: 0,
                     transform_into_action: 0,
                 },
+                auction_bid_state_transition: Some(DriveAbciStateTransitionValidationVersion {
+                    basic_structure: None,
+                    advanced_structure: None,
+                    identity_signatures: None,
+                    advanced_minimum_balance_pre_check: Some(0),
+                    nonce: Some(0),
+                    state: 0,
+                    transform_into_action: 0,
+                }),
                 contract_create_state_transition: DriveAbciStateTransitionValidationVersion {
                     basic_structure: Some(0),
                     advanced_structure: None,
@@ -788,6 +897,15 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     state: 0,
                     transform_into_action: 0,
                 },
+                contract_delete_state_transition: Some(DriveAbciStateTransitionValidationVersion {
+                    basic_structure: None,
+                    advanced_structure: None,
+                    identity_signatures: None,
+                    advanced_minimum_balance_pre_check: None,
+                    nonce: Some(0),
+                    state: 0,
+                    transform_into_action: 0,
+                }),
                 documents_batch_state_transition:
                 DriveAbciDocumentsStateTransitionValidationVersions {
                     balance_pre_check: 0,
@@ -821,6 +939,12 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     document_transfer_transition_state_validation: 0,
                     document_purchase_transition_state_validation: 0,
                     document_update_price_transition_state_validation: 0,
+                    document_make_offer_transition_structure_validation: Some(0),
+                    document_withdraw_offer_transition_structure_validation: Some(0),
+                    document_accept_offer_transition_structure_validation: Some(0),
+                    document_make_offer_transition_state_validation: Some(0),
+                    document_withdraw_offer_transition_state_validation: Some(0),
+                    document_accept_offer_transition_state_validation: Some(0),
                 },
             },
             process_state_transition: 0,
@@ -831,9 +955,11 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 validation_of_added_keys_structure_failure: 10000000,
                 validation_of_added_keys_proof_of_possession_failure: 50000000,
             },
+            state_transition_output_min_fees: STATE_TRANSITION_OUTPUT_MIN_FEES_VERSION1,
             event_constants: DriveAbciValidationConstants {
                 maximum_vote_polls_to_process: 2,
                 maximum_contenders_to_consider: 100,
+                maximum_documents_to_backfill_indexes: 100,
             },
         },
         query: DriveAbciQueryVersions {
@@ -849,6 +975,31 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 max_version: 0,
                 default_current_version: 0,
             },
+            document_count_query: FeatureVersionBounds {
+                min_version: 0,
+                max_version: 0,
+                default_current_version: 0,
+            },
+            document_offers_query: FeatureVersionBounds {
+                min_version: 0,
+                max_version: 0,
+                default_current_version: 0,
+            },
+            document_history_query: FeatureVersionBounds {
+                min_version: 0,
+                max_version: 0,
+                default_current_version: 0,
+            },
+            state_transition_fee_estimation_query: FeatureVersionBounds {
+                min_version: 0,
+                max_version: 0,
+                default_current_version: 0,
+            },
+            platform_events_subscription: FeatureVersionBounds {
+                min_version: 0,
+                max_version: 0,
+                default_current_version: 0,
+            },
             prefunded_specialized_balances: DriveAbciQueryPrefundedSpecializedBalancesVersions {
                 balance: FeatureVersionBounds {
                     min_version: 0,
@@ -892,6 +1043,16 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     max_version: 0,
                     default_current_version: 0,
                 },
+                identity_pending_recovery: FeatureVersionBounds {
+                    min_version: 0,
+                    max_version: 0,
+                    default_current_version: 0,
+                },
+                identity_withdrawals: FeatureVersionBounds {
+                    min_version: 0,
+                    max_version: 0,
+                    default_current_version: 0,
+                },
                 identities_contract_keys: FeatureVersionBounds {
                     min_version: 0,
                     max_version: 0,
@@ -941,6 +1102,11 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     max_version: 0,
                     default_current_version: 0,
                 },
+                governance_proposal_vote_poll_state: FeatureVersionBounds {
+                    min_version: 0,
+                    max_version: 0,
+                    default_current_version: 0,
+                },
             },
             system: DriveAbciQuerySystemVersions {
                 version_upgrade_state: FeatureVersionBounds {
@@ -996,9 +1162,12 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 validate_property_definition: 0,
             },
             document_type: DocumentTypeValidationVersions {
-                validate_update: 0,
+                validate_update: 1,
                 contested_index_limit: 1,
                 unique_index_limit: 10,
+                aggregated_index_limit: 0,
+                max_trade_mode: 3,
+                max_contested_index_resolution: 1,
             },
             voting: VotingValidationVersions {
                 allow_other_contenders_time_ms: 604_800_000, // 1 week in ms
@@ -1008,7 +1177,7 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
         state_transition_serialization_versions: StateTransitionSerializationVersions {
             identity_public_key_in_creation: FeatureVersionBounds {
                 min_version: 0,
-                max_version: 0,
+                max_version: 1,
                 default_current_version: 0,
             },
             identity_create_state_transition: FeatureVersionBounds {
@@ -1036,11 +1205,26 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 max_version: 0,
                 default_current_version: 0,
             },
+            identity_credit_batch_transfer_state_transition: FeatureVersionBounds {
+                min_version: 0,
+                max_version: 0,
+                default_current_version: 0,
+            },
+            identity_recovery_state_transition: FeatureVersionBounds {
+                min_version: 0,
+                max_version: 0,
+                default_current_version: 0,
+            },
             masternode_vote_state_transition: FeatureVersionBounds {
                 min_version: 0,
                 max_version: 0,
                 default_current_version: 0,
             },
+            auction_bid_state_transition: FeatureVersionBounds {
+                min_version: 0,
+                max_version: 0,
+                default_current_version: 0,
+            },
             contract_create_state_transition: FeatureVersionBounds {
                 min_version: 0,
                 max_version: 0,
@@ -1051,6 +1235,11 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                 max_version: 0,
                 default_current_version: 0,
             },
+            contract_delete_state_transition: FeatureVersionBounds {
+                min_version: 0,
+                max_version: 0,
+                default_current_version: 0,
+            },
             documents_batch_state_transition: FeatureVersionBounds {
                 min_version: 0,
                 max_version: 0,
@@ -1103,11 +1292,35 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
                     default_current_version: 0,
                 },
             },
+            document_make_offer_state_transition: DocumentFeatureVersionBounds {
+                bounds: FeatureVersionBounds {
+                    min_version: 0,
+                    max_version: 0,
+                    default_current_version: 0,
+                },
+            },
+            document_withdraw_offer_state_transition: DocumentFeatureVersionBounds {
+                bounds: FeatureVersionBounds {
+                    min_version: 0,
+                    max_version: 0,
+                    default_current_version: 0,
+                },
+            },
+            document_accept_offer_state_transition: DocumentFeatureVersionBounds {
+                bounds: FeatureVersionBounds {
+                    min_version: 0,
+                    max_version: 0,
+                    default_current_version: 0,
+                },
+            },
         },
         state_transition_conversion_versions: StateTransitionConversionVersions {
             identity_to_identity_create_transition: 0,
             identity_to_identity_top_up_transition: 0,
             identity_to_identity_withdrawal_transition: 0,
+            identity_to_identity_transfer_transition: 0,
+            identity_to_identity_batch_transfer_transition: 0,
+            identity_to_auction_bid_transition: 0,
             identity_to_identity_create_transition_with_signer: 0,
         },
         state_transition_method_versions: StateTransitionMethodVersions {
@@ -1224,6 +1437,15 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
         voting_versions: VotingVersions {
             default_vote_poll_time_duration_ms: 1_209_600_000, //2 weeks
             contested_document_vote_poll_stored_info_version: 0,
+            governance_proposal_vote_poll_stored_info_version: 0,
+            governance_proposal_min_vote_poll_duration_ms: 86_400_000, //1 day
+            governance_proposal_max_vote_poll_duration_ms: 2_592_000_000, //30 days
+            governance_proposal_quorum_percentage: 20,
+            governance_proposal_approval_percentage: 67,
+            governance_proposal_vote_poll_funding_amount: 100000000000, // 1 Dash
+            governance_proposal_max_vote_polls_opened_per_epoch: 1,
+            contested_document_auction_bid_stored_info_version: 0,
+            auction_bid_reveal_duration_ms: 259_200_000, //3 days
         },
         asset_lock_versions: AssetLockVersions {
             reduced_asset_lock_value: FeatureVersionBounds {
@@ -1247,5 +1469,9 @@ pub const TEST_PLATFORM_V2: PlatformVersion = PlatformVersion {
         max_field_value_size: 5000,
         max_state_transition_size: 20000,
         max_transitions_in_documents_batch: 1,
+        max_withdrawal_outputs_per_pooled_transaction: 1,
+        max_outputs_in_credit_batch_transfer: 100,
+        max_credit_transfer_memo_size: 64,
+        max_documents_deleted_with_contract: 100,
     },
 };
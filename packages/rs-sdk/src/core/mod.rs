@@ -1,4 +1,5 @@
 //! Dash Core SDK implementation.
 //!
 //! TODO: This is work in progress.
+pub mod spv;
 mod transaction;
@@ -0,0 +1,66 @@
+//! Trusted Core block the SPV state starts from.
+use std::collections::BTreeMap;
+
+use dpp::dashcore::{BlockHash, Header, Network};
+
+use super::pow::DGW_PAST_BLOCKS;
+use crate::Error;
+
+/// Number of headers, up to the checkpoint block, needed to verify the difficulty of the
+/// blocks following it
+pub const CHECKPOINT_HEADERS: usize = DGW_PAST_BLOCKS;
+
+/// Trusted Core block the SPV state starts from, meant to be hard-coded in applications.
+///
+/// Besides the block itself, the checkpoint holds the headers needed to verify the proof of work
+/// and difficulty of the blocks following it, and the chain lock quorums active at that block.
+/// The first masternode list is only trusted through a chain lock signed by one of these
+/// quorums, so the checkpoint must be recent enough for one of them to still sign chain locks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Checkpoint {
+    /// Network of the checkpoint block
+    pub network: Network,
+    /// Height of the checkpoint block
+    pub height: u32,
+    /// Headers of the last [CHECKPOINT_HEADERS] blocks up to the checkpoint block, oldest first
+    pub headers: Vec<Header>,
+    /// Public keys of the chain lock quorums active at the checkpoint block, by quorum hash in
+    /// display byte order
+    pub chain_lock_quorums: BTreeMap<[u8; 32], [u8; 48]>,
+}
+
+impl Checkpoint {
+    /// Hash of the checkpoint block
+    pub fn block_hash(&self) -> Option<BlockHash> {
+        self.headers.last().map(Header::block_hash)
+    }
+
+    /// Verify that the checkpoint holds enough connected headers
+    pub(super) fn validate(&self) -> Result<(), Error> {
+        if self.headers.len() < CHECKPOINT_HEADERS {
+            return Err(Error::Config(format!(
+                "checkpoint needs the headers of {} blocks, got {}",
+                CHECKPOINT_HEADERS,
+                self.headers.len()
+            )));
+        }
+        if self.headers.len() > self.height as usize {
+            return Err(Error::Config(format!(
+                "checkpoint at height {} can't have {} headers",
+                self.height,
+                self.headers.len()
+            )));
+        }
+        if self
+            .headers
+            .windows(2)
+            .any(|pair| pair[1].prev_blockhash != pair[0].block_hash())
+        {
+            return Err(Error::Config(
+                "checkpoint headers do not form a chain".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
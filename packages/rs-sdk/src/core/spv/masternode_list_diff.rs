@@ -0,0 +1,411 @@
+//! Simplified masternode list diffs, as streamed by DAPI `subscribeToMasternodeList`.
+//!
+//! Diffs are encoded the same way as the `mnlistdiff` P2P message of Dash Core.
+use std::io::{Read, Write};
+
+use dpp::bls_signatures;
+use dpp::dashcore::consensus::encode::{self, VarInt};
+use dpp::dashcore::consensus::{Decodable, Encodable};
+use dpp::dashcore::hashes::{sha256d, Hash};
+use dpp::dashcore::merkle_tree::PartialMerkleTree;
+use dpp::dashcore::{BlockHash, Transaction};
+
+/// Masternode type of a version 2 entry that runs Platform
+pub const EVO_MASTERNODE_TYPE: u16 = 1;
+
+/// Entry of the simplified masternode list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MasternodeListEntry {
+    /// Entry version, 1 for legacy BLS and 2 for basic BLS keys
+    pub version: u16,
+    /// Hash of the ProRegTx of the masternode
+    pub pro_reg_tx_hash: [u8; 32],
+    /// Hash of the block the collateral was confirmed in
+    pub confirmed_hash: [u8; 32],
+    /// IPv6 (or IPv4-mapped) address followed by the big endian port
+    pub service: [u8; 18],
+    /// BLS public key of the operator
+    pub operator_public_key: [u8; 48],
+    /// Key id of the voting key
+    pub voting_key_id: [u8; 20],
+    /// False if the masternode is PoSe banned
+    pub is_valid: bool,
+    /// Masternode type, only present in version 2 entries
+    pub masternode_type: u16,
+    /// Platform HTTP port, only present for evo masternodes
+    pub platform_http_port: u16,
+    /// Platform node id, only present for evo masternodes
+    pub platform_node_id: [u8; 20],
+}
+
+/// Final commitment of an LLMQ, as mined in a quorum commitment special transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuorumCommitment {
+    /// Commitment version
+    pub version: u16,
+    /// LLMQ type
+    pub quorum_type: u8,
+    /// Hash of the quorum, in internal byte order
+    pub quorum_hash: [u8; 32],
+    /// Index of the quorum for rotated quorums
+    pub quorum_index: Option<i16>,
+    /// Number of members of the `signers` bitset
+    pub signers_count: u64,
+    /// Members that signed the commitment
+    pub signers: Vec<u8>,
+    /// Number of members of the `valid_members` bitset
+    pub valid_members_count: u64,
+    /// Members considered valid during the DKG
+    pub valid_members: Vec<u8>,
+    /// BLS public key of the quorum
+    pub quorum_public_key: [u8; 48],
+    /// Hash of the verification vector
+    pub quorum_vvec_hash: [u8; 32],
+    /// Recovered threshold signature of the quorum
+    pub quorum_signature: [u8; 96],
+    /// Aggregated signature of the members
+    pub members_signature: [u8; 96],
+}
+
+impl QuorumCommitment {
+    /// Hash of the quorum in display byte order, as used by Platform proofs
+    pub fn display_quorum_hash(&self) -> [u8; 32] {
+        let mut quorum_hash = self.quorum_hash;
+        quorum_hash.reverse();
+        quorum_hash
+    }
+
+    /// Hash of the serialized commitment, used to compute the quorums merkle root
+    pub fn commitment_hash(&self) -> [u8; 32] {
+        sha256d::Hash::hash(&encode::serialize(self)).to_byte_array()
+    }
+
+    /// Hash signed by the quorum and its members, as built by Dash Core `BuildCommitmentHash`
+    pub fn signing_hash(&self) -> [u8; 32] {
+        let mut data = vec![self.quorum_type];
+        data.extend_from_slice(&self.quorum_hash);
+        data.extend(encode::serialize(&VarInt(self.valid_members_count)));
+        data.extend_from_slice(&self.valid_members);
+        data.extend_from_slice(&self.quorum_public_key);
+        data.extend_from_slice(&self.quorum_vvec_hash);
+
+        sha256d::Hash::hash(&data).to_byte_array()
+    }
+
+    /// Verify the member bitsets and the threshold signature of the commitment.
+    ///
+    /// Bitset sizes are only checked for LLMQ types of mainnet and testnet. The aggregated
+    /// signature of the members is not verified: it needs the quorum members, which are
+    /// selected from the masternode list of the quorum block, and diffs do not provide it.
+    pub fn verify(&self) -> Result<(), String> {
+        if let Some((size, min_size)) = llmq_size(self.quorum_type) {
+            if self.signers_count != size || self.valid_members_count != size {
+                return Err(format!(
+                    "member bitsets of {} and {} bits instead of {}",
+                    self.signers_count, self.valid_members_count, size
+                ));
+            }
+
+            let signers = count_bits(&self.signers);
+            let valid_members = count_bits(&self.valid_members);
+            if signers < min_size || valid_members < min_size {
+                return Err(format!(
+                    "{} signers and {} valid members, at least {} are required",
+                    signers, valid_members, min_size
+                ));
+            }
+        }
+
+        let public_key = bls_signatures::PublicKey::from_bytes(&self.quorum_public_key)
+            .map_err(|e| format!("invalid quorum public key: {}", e))?;
+        let signature = bls_signatures::G2Element::from_bytes(&self.quorum_signature)
+            .map_err(|e| format!("invalid quorum signature: {}", e))?;
+        if !public_key.verify(&signature, &self.signing_hash()) {
+            return Err("quorum signature does not match the commitment".to_string());
+        }
+
+        Ok(())
+    }
+
+    fn is_indexed(version: u16) -> bool {
+        version == 2 || version == 4
+    }
+}
+
+/// Size and minimum number of valid members and signers of LLMQ types of mainnet and testnet
+fn llmq_size(quorum_type: u8) -> Option<(u64, u64)> {
+    match quorum_type {
+        // LLMQ_50_60
+        1 => Some((50, 40)),
+        // LLMQ_400_60
+        2 => Some((400, 300)),
+        // LLMQ_400_85
+        3 => Some((400, 350)),
+        // LLMQ_100_67
+        4 => Some((100, 80)),
+        // LLMQ_60_75
+        5 => Some((60, 50)),
+        // LLMQ_25_67
+        6 => Some((25, 22)),
+        _ => None,
+    }
+}
+
+fn count_bits(bitset: &[u8]) -> u64 {
+    bitset.iter().map(|byte| byte.count_ones() as u64).sum()
+}
+
+/// Diff between the simplified masternode lists of two blocks
+#[derive(Debug, Clone, PartialEq)]
+pub struct MasternodeListDiff {
+    /// Diff version
+    pub version: u16,
+    /// Block the diff starts from, all zeros for a full list
+    pub base_block_hash: BlockHash,
+    /// Block the diff leads to
+    pub block_hash: BlockHash,
+    /// Proof of the coinbase transaction inclusion in `block_hash`
+    pub coinbase_merkle_tree: PartialMerkleTree,
+    /// Coinbase transaction of `block_hash`, committing to the masternode and quorum lists
+    pub coinbase_transaction: Transaction,
+    /// ProRegTx hashes of removed masternodes
+    pub deleted_masternodes: Vec<[u8; 32]>,
+    /// Added or updated masternodes
+    pub masternodes: Vec<MasternodeListEntry>,
+    /// LLMQ type and hash of quorums that are no longer active
+    pub deleted_quorums: Vec<(u8, [u8; 32])>,
+    /// Newly active quorums
+    pub new_quorums: Vec<QuorumCommitment>,
+    /// Chain lock signatures with indexes of the new quorums they sign
+    pub quorums_chain_lock_signatures: Vec<([u8; 96], Vec<u16>)>,
+}
+
+impl MasternodeListDiff {
+    /// Returns true if the diff contains the full list rather than changes to a known list
+    pub fn is_full_list(&self) -> bool {
+        self.base_block_hash == BlockHash::all_zeros()
+    }
+}
+
+pub(super) fn read_array<const N: usize, R: Read + ?Sized>(
+    reader: &mut R,
+) -> Result<[u8; N], encode::Error> {
+    let mut bytes = [0u8; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_count<R: Read + ?Sized>(reader: &mut R) -> Result<usize, encode::Error> {
+    let VarInt(count) = VarInt::consensus_decode(reader)?;
+
+    usize::try_from(count)
+        .ok()
+        .filter(|count| *count <= encode::MAX_VEC_SIZE)
+        .ok_or(encode::Error::ParseFailed("too many items"))
+}
+
+fn read_vec<T, R, F>(reader: &mut R, mut read_item: F) -> Result<Vec<T>, encode::Error>
+where
+    R: Read + ?Sized,
+    F: FnMut(&mut R) -> Result<T, encode::Error>,
+{
+    let count = read_count(reader)?;
+    let mut items = Vec::with_capacity(count.min(1024));
+    for _ in 0..count {
+        items.push(read_item(reader)?);
+    }
+    Ok(items)
+}
+
+fn write_count<W: Write + ?Sized>(writer: &mut W, count: usize) -> Result<usize, std::io::Error> {
+    VarInt(count as u64).consensus_encode(writer)
+}
+
+pub(super) fn write_bytes<W: Write + ?Sized>(
+    writer: &mut W,
+    bytes: &[u8],
+) -> Result<usize, std::io::Error> {
+    writer.write_all(bytes)?;
+    Ok(bytes.len())
+}
+
+impl Encodable for MasternodeListEntry {
+    fn consensus_encode<W: Write + ?Sized>(&self, writer: &mut W) -> Result<usize, std::io::Error> {
+        let mut len = self.version.consensus_encode(writer)?;
+        len += write_bytes(writer, &self.pro_reg_tx_hash)?;
+        len += write_bytes(writer, &self.confirmed_hash)?;
+        len += write_bytes(writer, &self.service)?;
+        len += write_bytes(writer, &self.operator_public_key)?;
+        len += write_bytes(writer, &self.voting_key_id)?;
+        len += self.is_valid.consensus_encode(writer)?;
+        if self.version >= 2 {
+            len += self.masternode_type.consensus_encode(writer)?;
+            if self.masternode_type == EVO_MASTERNODE_TYPE {
+                len += self.platform_http_port.consensus_encode(writer)?;
+                len += write_bytes(writer, &self.platform_node_id)?;
+            }
+        }
+        Ok(len)
+    }
+}
+
+impl Decodable for MasternodeListEntry {
+    fn consensus_decode<R: Read + ?Sized>(reader: &mut R) -> Result<Self, encode::Error> {
+        let version = u16::consensus_decode(reader)?;
+        let pro_reg_tx_hash = read_array(reader)?;
+        let confirmed_hash = read_array(reader)?;
+        let service = read_array(reader)?;
+        let operator_public_key = read_array(reader)?;
+        let voting_key_id = read_array(reader)?;
+        let is_valid = bool::consensus_decode(reader)?;
+
+        let mut masternode_type = 0;
+        let mut platform_http_port = 0;
+        let mut platform_node_id = [0u8; 20];
+
+        if version >= 2 {
+            masternode_type = u16::consensus_decode(reader)?;
+            if masternode_type == EVO_MASTERNODE_TYPE {
+                platform_http_port = u16::consensus_decode(reader)?;
+                platform_node_id = read_array(reader)?;
+            }
+        }
+
+        Ok(Self {
+            version,
+            pro_reg_tx_hash,
+            confirmed_hash,
+            service,
+            operator_public_key,
+            voting_key_id,
+            is_valid,
+            masternode_type,
+            platform_http_port,
+            platform_node_id,
+        })
+    }
+}
+
+impl Encodable for QuorumCommitment {
+    fn consensus_encode<W: Write + ?Sized>(&self, writer: &mut W) -> Result<usize, std::io::Error> {
+        let mut len = self.version.consensus_encode(writer)?;
+        len += self.quorum_type.consensus_encode(writer)?;
+        len += write_bytes(writer, &self.quorum_hash)?;
+        if Self::is_indexed(self.version) {
+            len += self
+                .quorum_index
+                .unwrap_or_default()
+                .consensus_encode(writer)?;
+        }
+        len += VarInt(self.signers_count).consensus_encode(writer)?;
+        len += write_bytes(writer, &self.signers)?;
+        len += VarInt(self.valid_members_count).consensus_encode(writer)?;
+        len += write_bytes(writer, &self.valid_members)?;
+        len += write_bytes(writer, &self.quorum_public_key)?;
+        len += write_bytes(writer, &self.quorum_vvec_hash)?;
+        len += write_bytes(writer, &self.quorum_signature)?;
+        len += write_bytes(writer, &self.members_signature)?;
+        Ok(len)
+    }
+}
+
+impl Decodable for QuorumCommitment {
+    fn consensus_decode<R: Read + ?Sized>(reader: &mut R) -> Result<Self, encode::Error> {
+        fn read_bitset<R: Read + ?Sized>(reader: &mut R) -> Result<(u64, Vec<u8>), encode::Error> {
+            let count = read_count(reader)?;
+            let mut bits = vec![0u8; count.div_ceil(8)];
+            reader.read_exact(&mut bits)?;
+            Ok((count as u64, bits))
+        }
+
+        let version = u16::consensus_decode(reader)?;
+        let quorum_type = u8::consensus_decode(reader)?;
+        let quorum_hash = read_array(reader)?;
+        let quorum_index = if Self::is_indexed(version) {
+            Some(i16::consensus_decode(reader)?)
+        } else {
+            None
+        };
+        let (signers_count, signers) = read_bitset(reader)?;
+        let (valid_members_count, valid_members) = read_bitset(reader)?;
+
+        Ok(Self {
+            version,
+            quorum_type,
+            quorum_hash,
+            quorum_index,
+            signers_count,
+            signers,
+            valid_members_count,
+            valid_members,
+            quorum_public_key: read_array(reader)?,
+            quorum_vvec_hash: read_array(reader)?,
+            quorum_signature: read_array(reader)?,
+            members_signature: read_array(reader)?,
+        })
+    }
+}
+
+impl Encodable for MasternodeListDiff {
+    fn consensus_encode<W: Write + ?Sized>(&self, writer: &mut W) -> Result<usize, std::io::Error> {
+        let mut len = self.version.consensus_encode(writer)?;
+        len += self.base_block_hash.consensus_encode(writer)?;
+        len += self.block_hash.consensus_encode(writer)?;
+        len += self.coinbase_merkle_tree.consensus_encode(writer)?;
+        len += self.coinbase_transaction.consensus_encode(writer)?;
+
+        len += write_count(writer, self.deleted_masternodes.len())?;
+        for pro_reg_tx_hash in &self.deleted_masternodes {
+            len += write_bytes(writer, pro_reg_tx_hash)?;
+        }
+        len += write_count(writer, self.masternodes.len())?;
+        for masternode in &self.masternodes {
+            len += masternode.consensus_encode(writer)?;
+        }
+
+        len += write_count(writer, self.deleted_quorums.len())?;
+        for (quorum_type, quorum_hash) in &self.deleted_quorums {
+            len += quorum_type.consensus_encode(writer)?;
+            len += write_bytes(writer, quorum_hash)?;
+        }
+        len += write_count(writer, self.new_quorums.len())?;
+        for quorum in &self.new_quorums {
+            len += quorum.consensus_encode(writer)?;
+        }
+
+        len += write_count(writer, self.quorums_chain_lock_signatures.len())?;
+        for (signature, indexes) in &self.quorums_chain_lock_signatures {
+            len += write_bytes(writer, signature)?;
+            len += write_count(writer, indexes.len())?;
+            for index in indexes {
+                len += index.consensus_encode(writer)?;
+            }
+        }
+
+        Ok(len)
+    }
+}
+
+impl Decodable for MasternodeListDiff {
+    fn consensus_decode<R: Read + ?Sized>(reader: &mut R) -> Result<Self, encode::Error> {
+        Ok(Self {
+            version: u16::consensus_decode(reader)?,
+            base_block_hash: BlockHash::consensus_decode(reader)?,
+            block_hash: BlockHash::consensus_decode(reader)?,
+            coinbase_merkle_tree: PartialMerkleTree::consensus_decode(reader)?,
+            coinbase_transaction: Transaction::consensus_decode(reader)?,
+            deleted_masternodes: read_vec(reader, read_array)?,
+            masternodes: read_vec(reader, MasternodeListEntry::consensus_decode)?,
+            deleted_quorums: read_vec(reader, |reader| {
+                Ok((u8::consensus_decode(reader)?, read_array(reader)?))
+            })?,
+            new_quorums: read_vec(reader, QuorumCommitment::consensus_decode)?,
+            quorums_chain_lock_signatures: read_vec(reader, |reader| {
+                Ok((
+                    read_array(reader)?,
+                    read_vec(reader, u16::consensus_decode)?,
+                ))
+            })?,
+        })
+    }
+}
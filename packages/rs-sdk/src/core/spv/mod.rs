@@ -0,0 +1,46 @@
+//! Simplified payment verification (SPV) of the Core chain.
+//!
+//! Builds the LLMQ quorum list from masternode list diffs and chain-locked block headers
+//! streamed by DAPI, verifying them locally instead of trusting a Core RPC node.
+//! [SpvContextProvider] uses the verified quorums to check Platform proofs.
+mod checkpoint;
+mod masternode_list_diff;
+mod pow;
+mod provider;
+mod quorum_list;
+mod state;
+
+use dpp::dashcore::hashes::{sha256d, Hash, HashEngine};
+
+pub use checkpoint::{Checkpoint, CHECKPOINT_HEADERS};
+pub use masternode_list_diff::{
+    MasternodeListDiff, MasternodeListEntry, QuorumCommitment, EVO_MASTERNODE_TYPE,
+};
+pub use provider::{SpvContextProvider, SpvContextProviderConfig};
+pub use quorum_list::QuorumList;
+pub use state::{MasternodeList, SpvState, CHAIN_LOCKED_HEADERS_KEPT};
+
+/// Bitcoin-style merkle root of `hashes`, duplicating the last hash of odd levels
+fn compute_merkle_root(mut hashes: Vec<[u8; 32]>) -> [u8; 32] {
+    if hashes.is_empty() {
+        return [0u8; 32];
+    }
+
+    while hashes.len() > 1 {
+        if hashes.len() % 2 == 1 {
+            hashes.push(*hashes.last().expect("hashes are not empty"));
+        }
+
+        hashes = hashes
+            .chunks(2)
+            .map(|pair| {
+                let mut engine = sha256d::Hash::engine();
+                engine.input(&pair[0]);
+                engine.input(&pair[1]);
+                sha256d::Hash::from_engine(engine).to_byte_array()
+            })
+            .collect();
+    }
+
+    hashes[0]
+}
@@ -0,0 +1,291 @@
+//! Proof of work and difficulty retargeting of Core block headers.
+//!
+//! Follows Dash Core `CheckProofOfWork` and Dark Gravity Wave v3, the difficulty adjustment
+//! algorithm of all blocks above its activation height.
+use std::cmp::Ordering;
+
+use dpp::dashcore::hashes::Hash;
+use dpp::dashcore::{BlockHash, Header, Network};
+
+/// Number of past blocks Dark Gravity Wave averages the difficulty of
+pub(super) const DGW_PAST_BLOCKS: usize = 24;
+
+/// Number of past blocks the median time is computed from
+const MEDIAN_TIME_SPAN: usize = 11;
+
+/// Target spacing between blocks, in seconds
+const POW_TARGET_SPACING: u32 = 150;
+
+/// Proof of work consensus parameters of a network
+struct PowParams {
+    /// Easiest allowed target, in compact form
+    pow_limit: u32,
+    /// Blocks mined long after their parent may lower the difficulty
+    allow_min_difficulty_blocks: bool,
+    /// The difficulty never changes
+    no_retargeting: bool,
+}
+
+impl PowParams {
+    fn for_network(network: Network) -> Self {
+        match network {
+            Network::Dash => Self {
+                pow_limit: 0x1e0fffff,
+                allow_min_difficulty_blocks: false,
+                no_retargeting: false,
+            },
+            Network::Testnet => Self {
+                pow_limit: 0x1e0fffff,
+                allow_min_difficulty_blocks: true,
+                no_retargeting: false,
+            },
+            Network::Regtest => Self {
+                pow_limit: 0x207fffff,
+                allow_min_difficulty_blocks: true,
+                no_retargeting: true,
+            },
+            _ => Self {
+                pow_limit: 0x207fffff,
+                allow_min_difficulty_blocks: true,
+                no_retargeting: false,
+            },
+        }
+    }
+}
+
+/// Verify the time, difficulty and proof of work of a block header.
+///
+/// `previous` are the headers of the blocks before it, oldest first. At least
+/// [DGW_PAST_BLOCKS] are needed to compute the expected difficulty.
+pub(super) fn verify_header(
+    network: Network,
+    previous: &[(BlockHash, Header)],
+    block_hash: &BlockHash,
+    header: &Header,
+) -> Result<(), String> {
+    if previous.len() < DGW_PAST_BLOCKS {
+        return Err(format!(
+            "the headers of {} previous blocks are needed to verify its difficulty, got {}",
+            DGW_PAST_BLOCKS,
+            previous.len()
+        ));
+    }
+
+    let mut times: Vec<u32> = previous[previous.len() - MEDIAN_TIME_SPAN..]
+        .iter()
+        .map(|(_, header)| header.time)
+        .collect();
+    times.sort_unstable();
+    if header.time <= times[MEDIAN_TIME_SPAN / 2] {
+        return Err("its time is not above the median time of the previous blocks".to_string());
+    }
+
+    let params = PowParams::for_network(network);
+    let bits = header.bits.to_consensus();
+    let expected_bits = next_work_required(&params, previous, header.time);
+    if bits != expected_bits {
+        return Err(format!(
+            "its difficulty bits {:#010x} do not match the expected {:#010x}",
+            bits, expected_bits
+        ));
+    }
+
+    let pow_limit = U256::from_compact(params.pow_limit).expect("valid proof of work limit");
+    let target = U256::from_compact(bits)
+        .filter(|target| *target != U256::ZERO && *target <= pow_limit)
+        .ok_or(format!("its difficulty bits {:#010x} are invalid", bits))?;
+    if U256::from_le_bytes(block_hash.to_byte_array()) > target {
+        return Err("its hash does not meet its difficulty target".to_string());
+    }
+
+    Ok(())
+}
+
+/// Difficulty bits of a block mined at `time` on top of `previous`, as computed by
+/// Dark Gravity Wave v3
+fn next_work_required(params: &PowParams, previous: &[(BlockHash, Header)], time: u32) -> u32 {
+    let pow_limit = U256::from_compact(params.pow_limit).expect("valid proof of work limit");
+    let (_, last) = previous.last().expect("previous headers are not empty");
+    let last_bits = last.bits.to_consensus();
+
+    if params.no_retargeting {
+        return last_bits;
+    }
+
+    if params.allow_min_difficulty_blocks {
+        // Parent is more than 2 hours old
+        if time > last.time.saturating_add(2 * 60 * 60) {
+            return pow_limit.to_compact();
+        }
+        // Parent is more than 4 target spacings old
+        if time > last.time.saturating_add(4 * POW_TARGET_SPACING) {
+            let target = U256::from_compact(last_bits)
+                .unwrap_or(pow_limit)
+                .mul_u64(10);
+            return target.min(pow_limit).to_compact();
+        }
+    }
+
+    // Not a real average, but this is how Dash Core computes it
+    let past = &previous[previous.len() - DGW_PAST_BLOCKS..];
+    let mut past_target_average = U256::ZERO;
+    for (index, (_, header)) in past.iter().rev().enumerate() {
+        let target = U256::from_compact(header.bits.to_consensus()).unwrap_or(pow_limit);
+        let count = index as u64 + 1;
+        past_target_average = if count == 1 {
+            target
+        } else {
+            past_target_average
+                .mul_u64(count)
+                .add(target)
+                .div_u64(count + 1)
+        };
+    }
+
+    let (_, first) = &past[0];
+    let target_timespan = DGW_PAST_BLOCKS as i64 * POW_TARGET_SPACING as i64;
+    let actual_timespan =
+        (last.time as i64 - first.time as i64).clamp(target_timespan / 3, target_timespan * 3);
+
+    past_target_average
+        .mul_u64(actual_timespan as u64)
+        .div_u64(target_timespan as u64)
+        .min(pow_limit)
+        .to_compact()
+}
+
+/// Unsigned 256-bit integer, as 64-bit limbs from the least significant one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct U256([u64; 4]);
+
+impl U256 {
+    const ZERO: Self = Self([0; 4]);
+
+    fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        let mut limbs = [0u64; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_le_bytes(chunk.try_into().expect("chunks of 8 bytes"));
+        }
+        Self(limbs)
+    }
+
+    /// Decode a target in compact form, returns `None` if it is negative or overflows
+    fn from_compact(bits: u32) -> Option<Self> {
+        let size = bits >> 24;
+        let word = bits & 0x007f_ffff;
+
+        if word != 0 && bits & 0x0080_0000 != 0 {
+            return None;
+        }
+        if size <= 3 {
+            return Some(Self([(word >> (8 * (3 - size))) as u64, 0, 0, 0]));
+        }
+        if word != 0 && (size > 34 || (word > 0xff && size > 33) || (word > 0xffff && size > 32)) {
+            return None;
+        }
+
+        Some(Self([word as u64, 0, 0, 0]).shl(8 * (size - 3)))
+    }
+
+    /// Encode in compact form, rounding down
+    fn to_compact(self) -> u32 {
+        let mut size = self.bits().div_ceil(8);
+        let mut compact = if size <= 3 {
+            (self.0[0] << (8 * (3 - size))) as u32
+        } else {
+            self.shr(8 * (size - 3)).0[0] as u32
+        };
+
+        // The sign bit must not be set
+        if compact & 0x0080_0000 != 0 {
+            compact >>= 8;
+            size += 1;
+        }
+
+        compact | (size << 24)
+    }
+
+    /// Number of significant bits
+    fn bits(&self) -> u32 {
+        self.0
+            .iter()
+            .rposition(|limb| *limb != 0)
+            .map(|index| 64 * index as u32 + 64 - self.0[index].leading_zeros())
+            .unwrap_or_default()
+    }
+
+    fn shl(self, shift: u32) -> Self {
+        let limbs = (shift / 64) as usize;
+        let bits = shift % 64;
+        let mut result = [0u64; 4];
+        for (index, limb) in result.iter_mut().enumerate().skip(limbs) {
+            *limb = self.0[index - limbs] << bits;
+            if bits > 0 && index > limbs {
+                *limb |= self.0[index - limbs - 1] >> (64 - bits);
+            }
+        }
+        Self(result)
+    }
+
+    fn shr(self, shift: u32) -> Self {
+        let limbs = (shift / 64) as usize;
+        let bits = shift % 64;
+        let mut result = [0u64; 4];
+        for (index, limb) in result
+            .iter_mut()
+            .take(4usize.saturating_sub(limbs))
+            .enumerate()
+        {
+            *limb = self.0[index + limbs] >> bits;
+            if bits > 0 && index + limbs + 1 < 4 {
+                *limb |= self.0[index + limbs + 1] << (64 - bits);
+            }
+        }
+        Self(result)
+    }
+
+    fn add(self, other: Self) -> Self {
+        let mut result = [0u64; 4];
+        let mut carry = 0u128;
+        for (limb, (a, b)) in result.iter_mut().zip(self.0.iter().zip(other.0.iter())) {
+            let sum = *a as u128 + *b as u128 + carry;
+            *limb = sum as u64;
+            carry = sum >> 64;
+        }
+        Self(result)
+    }
+
+    fn mul_u64(self, factor: u64) -> Self {
+        let mut result = self.0;
+        let mut carry = 0u128;
+        for limb in result.iter_mut() {
+            let product = *limb as u128 * factor as u128 + carry;
+            *limb = product as u64;
+            carry = product >> 64;
+        }
+        Self(result)
+    }
+
+    fn div_u64(self, divisor: u64) -> Self {
+        let mut result = self.0;
+        let mut remainder = 0u128;
+        for limb in result.iter_mut().rev() {
+            let dividend = (remainder << 64) | *limb as u128;
+            *limb = (dividend / divisor as u128) as u64;
+            remainder = dividend % divisor as u128;
+        }
+        Self(result)
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,262 @@
+//! [ContextProvider] verifying quorum public keys with the SPV state, without Core RPC access.
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+
+use arc_swap::ArcSwapAny;
+use dapi_grpc::core::v0::{
+    block_headers_with_chain_locks_request, block_headers_with_chain_locks_response,
+    BlockHeadersWithChainLocksRequest, GetBlockchainStatusRequest, MasternodeListRequest,
+};
+use dpp::dashcore::consensus::Decodable;
+use dpp::dashcore::{ChainLock, Header};
+use dpp::prelude::{CoreBlockHeight, DataContract, Identifier};
+use drive_proof_verifier::error::ContextProviderError;
+use drive_proof_verifier::ContextProvider;
+use pollster::FutureExt;
+use rs_dapi_client::{DapiRequestExecutor, RequestSettings};
+
+use super::checkpoint::Checkpoint;
+use super::masternode_list_diff::MasternodeListDiff;
+use super::state::SpvState;
+use crate::platform::Fetch;
+use crate::{Error, Sdk};
+
+/// Configuration of [SpvContextProvider]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpvContextProviderConfig {
+    /// Trusted block to start syncing from
+    pub checkpoint: Checkpoint,
+    /// LLMQ type of quorums signing chain locks
+    pub chain_lock_quorum_type: u8,
+    /// Core height of Platform activation
+    pub platform_activation_height: CoreBlockHeight,
+    /// File the verified state is persisted to, if any
+    pub state_path: Option<PathBuf>,
+}
+
+/// Context provider verifying quorum public keys locally, using block headers, chain locks and
+/// masternode list diffs streamed by DAPI.
+///
+/// Call [SpvContextProvider::sync] to catch up with the chain before verifying proofs. See
+/// [SpvState] for the trust model.
+pub struct SpvContextProvider {
+    config: SpvContextProviderConfig,
+    state: Mutex<SpvState>,
+    /// [Sdk] to use when fetching data contracts from Platform
+    ///
+    /// We use [Arc] as we have circular dependencies between Sdk and ContextProvider.
+    sdk: ArcSwapAny<Arc<Option<Sdk>>>,
+    /// Data contracts cache
+    data_contracts: RwLock<BTreeMap<Identifier, Arc<DataContract>>>,
+}
+
+impl SpvContextProvider {
+    /// Create new context provider.
+    ///
+    /// If the state file configured in `config` exists, the previously verified state is loaded
+    /// from it; otherwise sync starts from the checkpoint.
+    pub fn new(config: SpvContextProviderConfig) -> Result<Self, Error> {
+        let state = match &config.state_path {
+            Some(path) if path.exists() => SpvState::load(path)?,
+            _ => SpvState::new(config.chain_lock_quorum_type, config.checkpoint.clone())?,
+        };
+
+        Ok(Self {
+            config,
+            state: Mutex::new(state),
+            sdk: ArcSwapAny::new(Arc::new(None)),
+            data_contracts: RwLock::new(BTreeMap::new()),
+        })
+    }
+
+    /// Set the Sdk to use when fetching data contracts from Platform.
+    pub fn set_sdk(&self, sdk: Option<Sdk>) {
+        self.sdk.store(Arc::new(sdk));
+    }
+
+    /// Lock and return the verified state
+    pub fn state(&self) -> Result<MutexGuard<SpvState>, Error> {
+        self.state
+            .lock()
+            .map_err(|_| Error::Generic("SPV state lock poisoned".to_string()))
+    }
+
+    /// Apply serialized block headers, as received from DAPI
+    pub fn apply_block_headers(&self, headers: &[Vec<u8>]) -> Result<(), Error> {
+        let headers = headers
+            .iter()
+            .map(|header| Header::consensus_decode(&mut header.as_slice()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| Error::CoreError(e.into()))?;
+
+        self.state()?.apply_headers(&headers)
+    }
+
+    /// Apply a serialized chain lock, as received from DAPI.
+    ///
+    /// Returns `false` if the chain lock could not be applied yet, see
+    /// [SpvState::apply_chain_lock].
+    pub fn apply_chain_lock(&self, chain_lock: &[u8]) -> Result<bool, Error> {
+        let chain_lock = ChainLock::consensus_decode(&mut &chain_lock[..])
+            .map_err(|e| Error::CoreError(e.into()))?;
+
+        self.state()?.apply_chain_lock(&chain_lock)
+    }
+
+    /// Apply a serialized masternode list diff, as received from DAPI
+    pub fn apply_masternode_list_diff(&self, diff: &[u8]) -> Result<(), Error> {
+        let diff = MasternodeListDiff::consensus_decode(&mut &diff[..])
+            .map_err(|e| Error::CoreError(e.into()))?;
+
+        self.state()?.apply_masternode_list_diff(&diff)
+    }
+
+    /// Persist the verified state, if a state file is configured
+    pub fn save(&self) -> Result<(), Error> {
+        match &self.config.state_path {
+            Some(path) => self.state()?.save(path),
+            None => Ok(()),
+        }
+    }
+
+    /// Catch up with the chain: stream block headers and chain locks up to the best block,
+    /// then the current masternode list, and verify them.
+    pub async fn sync(&self, sdk: &Sdk) -> Result<(), Error> {
+        let mut masternode_list_stream = sdk
+            .execute(MasternodeListRequest {}, RequestSettings::default())
+            .await
+            .map_err(|e| Error::DapiClientError(e.to_string()))?;
+        let masternode_list = masternode_list_stream
+            .message()
+            .await
+            .map_err(|e| Error::DapiClientError(format!("can't receive message: {e}")))?
+            .ok_or(Error::DapiClientError(
+                "masternode list stream closed unexpectedly".to_string(),
+            ))?;
+
+        let best_height = sdk
+            .execute(GetBlockchainStatusRequest {}, RequestSettings::default())
+            .await?
+            .chain
+            .ok_or(Error::DapiClientError(
+                "missing chain in blockchain status".to_string(),
+            ))?
+            .blocks_count;
+
+        let (tip_height, _) = self.state()?.tip();
+        let mut chain_locks = Vec::new();
+        if best_height > tip_height {
+            let request = BlockHeadersWithChainLocksRequest {
+                from_block: Some(
+                    block_headers_with_chain_locks_request::FromBlock::FromBlockHeight(
+                        tip_height + 1,
+                    ),
+                ),
+                count: best_height - tip_height,
+            };
+            let mut stream = sdk
+                .execute(request, RequestSettings::default())
+                .await
+                .map_err(|e| Error::DapiClientError(e.to_string()))?;
+
+            while let Some(message) = stream
+                .message()
+                .await
+                .map_err(|e| Error::DapiClientError(format!("can't receive message: {e}")))?
+            {
+                match message.responses {
+                    Some(block_headers_with_chain_locks_response::Responses::BlockHeaders(
+                        block_headers,
+                    )) => self.apply_block_headers(&block_headers.headers)?,
+                    Some(block_headers_with_chain_locks_response::Responses::ChainLock(
+                        chain_lock,
+                    )) => chain_locks.push(chain_lock),
+                    None => {}
+                }
+            }
+        }
+
+        self.apply_masternode_list_diff(&masternode_list.masternode_list_diff)?;
+
+        // Chain locks may arrive before the headers of their block; apply the newest first
+        for chain_lock in chain_locks.iter().rev() {
+            if self.apply_chain_lock(chain_lock)? {
+                break;
+            }
+        }
+
+        self.save()
+    }
+}
+
+impl ContextProvider for SpvContextProvider {
+    fn get_quorum_public_key(
+        &self,
+        quorum_type: u32,
+        quorum_hash: [u8; 32], // quorum hash is 32 bytes
+        core_chain_locked_height: u32,
+    ) -> Result<[u8; 48], ContextProviderError> {
+        let state = self
+            .state()
+            .map_err(|e| ContextProviderError::Generic(e.to_string()))?;
+
+        let synced_height = state
+            .best_chain_lock()
+            .map(|chain_lock| chain_lock.block_height)
+            .unwrap_or_default();
+        if core_chain_locked_height > synced_height {
+            return Err(ContextProviderError::InvalidQuorum(format!(
+                "core chain locked height {} is above the synced chain lock height {}",
+                core_chain_locked_height, synced_height
+            )));
+        }
+
+        u8::try_from(quorum_type)
+            .ok()
+            .and_then(|quorum_type| state.quorum_public_key(quorum_type, &quorum_hash))
+            .ok_or(ContextProviderError::InvalidQuorum(format!(
+                "quorum {} of type {} is not active",
+                hex::encode(quorum_hash),
+                quorum_type
+            )))
+    }
+
+    fn get_data_contract(
+        &self,
+        data_contract_id: &Identifier,
+    ) -> Result<Option<Arc<DataContract>>, ContextProviderError> {
+        if let Some(contract) = self
+            .data_contracts
+            .read()
+            .map_err(|_| ContextProviderError::Generic("cache lock poisoned".to_string()))?
+            .get(data_contract_id)
+        {
+            return Ok(Some(Arc::clone(contract)));
+        };
+
+        let sdk_guard = self.sdk.load();
+        let Some(sdk) = sdk_guard.as_ref() else {
+            tracing::warn!("data contract cache miss and no sdk provided, skipping fetch");
+            return Ok(None);
+        };
+
+        let data_contract: Option<DataContract> = DataContract::fetch(sdk, *data_contract_id)
+            .block_on()
+            .map_err(|e| ContextProviderError::DataContractFailure(e.to_string()))?;
+
+        let data_contract = data_contract.map(Arc::new);
+        if let Some(ref dc) = data_contract {
+            self.data_contracts
+                .write()
+                .map_err(|_| ContextProviderError::Generic("cache lock poisoned".to_string()))?
+                .insert(*data_contract_id, Arc::clone(dc));
+        }
+
+        Ok(data_contract)
+    }
+
+    fn get_platform_activation_height(&self) -> Result<CoreBlockHeight, ContextProviderError> {
+        Ok(self.config.platform_activation_height)
+    }
+}
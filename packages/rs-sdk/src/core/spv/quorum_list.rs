@@ -0,0 +1,169 @@
+//! Active LLMQ quorums of a simplified masternode list
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+
+use dpp::bls_signatures;
+use dpp::dashcore::consensus::encode::{self, VarInt};
+use dpp::dashcore::consensus::{Decodable, Encodable};
+use dpp::dashcore::hashes::{sha256d, Hash, HashEngine};
+use dpp::dashcore::ChainLock;
+
+use super::compute_merkle_root;
+use super::masternode_list_diff::{MasternodeListDiff, QuorumCommitment};
+
+const CHAIN_LOCK_REQUEST_ID_PREFIX: &str = "clsig";
+
+/// Active quorums, by LLMQ type and quorum hash in display byte order
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QuorumList {
+    quorums: BTreeMap<(u8, [u8; 32]), QuorumCommitment>,
+}
+
+impl QuorumList {
+    /// Apply quorum changes of a masternode list diff
+    pub fn apply_diff(&mut self, diff: &MasternodeListDiff) {
+        for (quorum_type, quorum_hash) in &diff.deleted_quorums {
+            let mut display_quorum_hash = *quorum_hash;
+            display_quorum_hash.reverse();
+            self.quorums.remove(&(*quorum_type, display_quorum_hash));
+        }
+
+        for quorum in &diff.new_quorums {
+            self.quorums.insert(
+                (quorum.quorum_type, quorum.display_quorum_hash()),
+                quorum.clone(),
+            );
+        }
+    }
+
+    /// Get an active quorum by type and hash in display byte order
+    pub fn get(&self, quorum_type: u8, quorum_hash: &[u8; 32]) -> Option<&QuorumCommitment> {
+        self.quorums.get(&(quorum_type, *quorum_hash))
+    }
+
+    /// Iterate over active quorums
+    pub fn iter(&self) -> impl Iterator<Item = &QuorumCommitment> {
+        self.quorums.values()
+    }
+
+    /// Number of active quorums
+    pub fn len(&self) -> usize {
+        self.quorums.len()
+    }
+
+    /// Returns true if there is no active quorum
+    pub fn is_empty(&self) -> bool {
+        self.quorums.is_empty()
+    }
+
+    /// Merkle root of the active quorums, as committed to by the coinbase transaction
+    ///
+    /// Leaves are hashes of the serialized commitments, in ascending order.
+    pub fn merkle_root(&self) -> [u8; 32] {
+        let mut hashes: Vec<[u8; 32]> = self
+            .quorums
+            .values()
+            .map(QuorumCommitment::commitment_hash)
+            .collect();
+        hashes.sort();
+
+        compute_merkle_root(hashes)
+    }
+
+    /// Quorum of `quorum_type` selected to sign the chain lock of the block at `block_height`.
+    ///
+    /// The quorum is chosen as described in DIP 8, only non-rotated quorum types are supported.
+    pub fn chain_lock_quorum(
+        &self,
+        block_height: u32,
+        quorum_type: u8,
+    ) -> Option<&QuorumCommitment> {
+        let request_id = chain_lock_request_id(block_height);
+
+        self.quorums
+            .range((quorum_type, [0u8; 32])..=(quorum_type, [u8::MAX; 32]))
+            .map(|(_, quorum)| {
+                let mut engine = sha256d::Hash::engine();
+                engine.input(&[quorum_type]);
+                engine.input(&quorum.quorum_hash);
+                engine.input(&request_id);
+                (sha256d::Hash::from_engine(engine).to_byte_array(), quorum)
+            })
+            .min_by_key(|(score, _)| *score)
+            .map(|(_, quorum)| quorum)
+    }
+
+    /// Verify a chain lock with the quorum of `quorum_type` selected to sign it.
+    ///
+    /// Returns false if there is no quorum of that type or the signature is not valid.
+    pub fn verify_chain_lock(&self, chain_lock: &ChainLock, quorum_type: u8) -> bool {
+        self.chain_lock_quorum(chain_lock.block_height, quorum_type)
+            .is_some_and(|quorum| {
+                verify_chain_lock_signature(
+                    chain_lock,
+                    quorum_type,
+                    &quorum.quorum_hash,
+                    &quorum.quorum_public_key,
+                )
+            })
+    }
+}
+
+/// Request id of the chain lock of the block at `block_height`: SHA256("clsig", blockHeight)
+fn chain_lock_request_id(block_height: u32) -> [u8; 32] {
+    let mut engine = sha256d::Hash::engine();
+    engine.input(&[CHAIN_LOCK_REQUEST_ID_PREFIX.len() as u8]);
+    engine.input(CHAIN_LOCK_REQUEST_ID_PREFIX.as_bytes());
+    engine.input(block_height.to_le_bytes().as_slice());
+    sha256d::Hash::from_engine(engine).to_byte_array()
+}
+
+/// Verify the signature of a chain lock with the public key of the quorum that signed it.
+///
+/// `quorum_hash` is in internal byte order.
+pub(super) fn verify_chain_lock_signature(
+    chain_lock: &ChainLock,
+    quorum_type: u8,
+    quorum_hash: &[u8; 32],
+    quorum_public_key: &[u8; 48],
+) -> bool {
+    // The signature must verify against SHA256(llmqType, quorumHash, requestId, blockHash)
+    let mut engine = sha256d::Hash::engine();
+    engine.input(&[quorum_type]);
+    engine.input(quorum_hash);
+    engine.input(&chain_lock_request_id(chain_lock.block_height));
+    engine.input(chain_lock.block_hash.as_byte_array());
+    let message_digest = sha256d::Hash::from_engine(engine);
+
+    let Ok(public_key) = bls_signatures::PublicKey::from_bytes(quorum_public_key) else {
+        return false;
+    };
+    let Ok(signature) = bls_signatures::G2Element::from_bytes(chain_lock.signature.as_bytes())
+    else {
+        return false;
+    };
+
+    public_key.verify(&signature, message_digest.as_byte_array())
+}
+
+impl Encodable for QuorumList {
+    fn consensus_encode<W: Write + ?Sized>(&self, writer: &mut W) -> Result<usize, std::io::Error> {
+        let mut len = VarInt(self.quorums.len() as u64).consensus_encode(writer)?;
+        for quorum in self.quorums.values() {
+            len += quorum.consensus_encode(writer)?;
+        }
+        Ok(len)
+    }
+}
+
+impl Decodable for QuorumList {
+    fn consensus_decode<R: Read + ?Sized>(reader: &mut R) -> Result<Self, encode::Error> {
+        let VarInt(count) = VarInt::consensus_decode(reader)?;
+        let mut quorums = BTreeMap::new();
+        for _ in 0..count {
+            let quorum = QuorumCommitment::consensus_decode(reader)?;
+            quorums.insert((quorum.quorum_type, quorum.display_quorum_hash()), quorum);
+        }
+        Ok(Self { quorums })
+    }
+}
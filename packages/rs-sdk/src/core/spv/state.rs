@@ -0,0 +1,655 @@
+//! Locally verified Core chain state: block headers, masternode lists and chain locks.
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use dpp::dashcore::consensus::encode::{self, VarInt};
+use dpp::dashcore::consensus::{Decodable, Encodable};
+use dpp::dashcore::hashes::{sha256d, Hash};
+use dpp::dashcore::transaction::special_transaction::TransactionPayload;
+use dpp::dashcore::{BlockHash, ChainLock, Header, Network};
+
+use super::checkpoint::Checkpoint;
+use super::compute_merkle_root;
+use super::masternode_list_diff::{
+    read_array, write_bytes, MasternodeListDiff, MasternodeListEntry,
+};
+use super::pow;
+use super::quorum_list::{verify_chain_lock_signature, QuorumList};
+use crate::Error;
+
+/// Number of chain-locked headers kept to resolve masternode list diffs of recent blocks
+pub const CHAIN_LOCKED_HEADERS_KEPT: u32 = 576;
+
+/// Version of the persisted state format
+const PERSISTED_STATE_VERSION: u16 = 2;
+
+/// Simplified masternode list at a given block
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MasternodeList {
+    /// Height of the block of the list
+    pub block_height: u32,
+    /// Hash of the block of the list
+    pub block_hash: BlockHash,
+    /// Masternodes by ProRegTx hash
+    pub masternodes: BTreeMap<[u8; 32], MasternodeListEntry>,
+    /// Active quorums
+    pub quorums: QuorumList,
+}
+
+impl MasternodeList {
+    /// Merkle root of the masternodes, as committed to by the coinbase transaction
+    ///
+    /// Leaves are hashes of the serialized entries, ordered by ProRegTx hash.
+    pub fn masternodes_merkle_root(&self) -> [u8; 32] {
+        compute_merkle_root(
+            self.masternodes
+                .values()
+                .map(|entry| sha256d::Hash::hash(&encode::serialize(entry)).to_byte_array())
+                .collect(),
+        )
+    }
+
+    /// Create the list resulting from applying `diff` on top of this list
+    fn apply_diff(&self, diff: &MasternodeListDiff, block_height: u32) -> Self {
+        let mut list = if diff.is_full_list() {
+            Self::default()
+        } else {
+            self.clone()
+        };
+
+        for pro_reg_tx_hash in &diff.deleted_masternodes {
+            list.masternodes.remove(pro_reg_tx_hash);
+        }
+        for entry in &diff.masternodes {
+            list.masternodes
+                .insert(entry.pro_reg_tx_hash, entry.clone());
+        }
+        list.quorums.apply_diff(diff);
+
+        list.block_height = block_height;
+        list.block_hash = diff.block_hash;
+
+        list
+    }
+}
+
+impl Encodable for MasternodeList {
+    fn consensus_encode<W: Write + ?Sized>(&self, writer: &mut W) -> Result<usize, std::io::Error> {
+        let mut len = self.block_height.consensus_encode(writer)?;
+        len += self.block_hash.consensus_encode(writer)?;
+        len += VarInt(self.masternodes.len() as u64).consensus_encode(writer)?;
+        for entry in self.masternodes.values() {
+            len += entry.consensus_encode(writer)?;
+        }
+        len += self.quorums.consensus_encode(writer)?;
+        Ok(len)
+    }
+}
+
+impl Decodable for MasternodeList {
+    fn consensus_decode<R: Read + ?Sized>(reader: &mut R) -> Result<Self, encode::Error> {
+        let block_height = u32::consensus_decode(reader)?;
+        let block_hash = BlockHash::consensus_decode(reader)?;
+        let VarInt(count) = VarInt::consensus_decode(reader)?;
+        let mut masternodes = BTreeMap::new();
+        for _ in 0..count {
+            let entry = MasternodeListEntry::consensus_decode(reader)?;
+            masternodes.insert(entry.pro_reg_tx_hash, entry);
+        }
+        let quorums = QuorumList::consensus_decode(reader)?;
+
+        Ok(Self {
+            block_height,
+            block_hash,
+            masternodes,
+            quorums,
+        })
+    }
+}
+
+/// Core chain state verified by the SPV client.
+///
+/// ## Trust model
+///
+/// The state starts from a trusted [Checkpoint]. Headers are only accepted if they connect
+/// to it, with valid proof of work and the difficulty expected from the previous blocks. A
+/// masternode list is accepted once the coinbase of its block is proven to be included in the
+/// block header and commits to the resulting masternodes and quorums, and the commitments of its
+/// new quorums are signed by the quorums. Such a list only becomes active once a chain lock at
+/// or above its block is signed by a quorum of the active list.
+///
+/// The very first list cannot be verified by a previous one: it is activated by a chain lock
+/// signed by the quorum selected from it, which must be one of the chain lock quorums of the
+/// checkpoint, with the public key the checkpoint holds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpvState {
+    /// Network of the chain
+    network: Network,
+    /// LLMQ type of quorums signing chain locks
+    chain_lock_quorum_type: u8,
+    /// Height of the checkpoint block; blocks up to it are final
+    checkpoint_height: u32,
+    /// Public keys of the chain lock quorums active at the checkpoint block, by quorum hash in
+    /// display byte order
+    checkpoint_quorums: BTreeMap<[u8; 32], [u8; 48]>,
+    /// Height of the block preceding the first header of `headers`
+    anchor_height: u32,
+    /// Hash of the block preceding the first header of `headers`
+    anchor_hash: BlockHash,
+    /// Best known header chain, starting at `anchor_height + 1`
+    headers: Vec<(BlockHash, Header)>,
+    /// Best verified chain lock
+    best_chain_lock: Option<ChainLock>,
+    /// Active masternode list
+    current: Option<MasternodeList>,
+    /// Quorums active before the current masternode list
+    previous_quorums: QuorumList,
+    /// Masternode list waiting for a chain lock to become active
+    pending: Option<MasternodeList>,
+}
+
+impl SpvState {
+    /// Create a state starting from a trusted checkpoint
+    pub fn new(chain_lock_quorum_type: u8, checkpoint: Checkpoint) -> Result<Self, Error> {
+        checkpoint.validate()?;
+
+        Ok(Self {
+            network: checkpoint.network,
+            chain_lock_quorum_type,
+            checkpoint_height: checkpoint.height,
+            anchor_height: checkpoint.height - checkpoint.headers.len() as u32,
+            anchor_hash: checkpoint.headers[0].prev_blockhash,
+            headers: checkpoint
+                .headers
+                .iter()
+                .map(|header| (header.block_hash(), *header))
+                .collect(),
+            checkpoint_quorums: checkpoint.chain_lock_quorums,
+            best_chain_lock: None,
+            current: None,
+            previous_quorums: QuorumList::default(),
+            pending: None,
+        })
+    }
+
+    /// Height and hash of the best known header
+    pub fn tip(&self) -> (u32, BlockHash) {
+        match self.headers.last() {
+            Some((hash, _)) => (self.anchor_height + self.headers.len() as u32, *hash),
+            None => (self.anchor_height, self.anchor_hash),
+        }
+    }
+
+    /// Best verified chain lock
+    pub fn best_chain_lock(&self) -> Option<&ChainLock> {
+        self.best_chain_lock.as_ref()
+    }
+
+    /// Active masternode list
+    pub fn masternode_list(&self) -> Option<&MasternodeList> {
+        self.current.as_ref()
+    }
+
+    /// Masternode list waiting for a chain lock to become active
+    pub fn pending_masternode_list(&self) -> Option<&MasternodeList> {
+        self.pending.as_ref()
+    }
+
+    /// Public key of a quorum of the active masternode list or the one before it.
+    ///
+    /// `quorum_hash` is in display byte order.
+    pub fn quorum_public_key(&self, quorum_type: u8, quorum_hash: &[u8; 32]) -> Option<[u8; 48]> {
+        self.current
+            .as_ref()
+            .and_then(|list| list.quorums.get(quorum_type, quorum_hash))
+            .or_else(|| self.previous_quorums.get(quorum_type, quorum_hash))
+            .map(|quorum| quorum.quorum_public_key)
+    }
+
+    /// Height of a block of the known header chain
+    fn height_of(&self, block_hash: &BlockHash) -> Option<u32> {
+        if *block_hash == self.anchor_hash {
+            return Some(self.anchor_height);
+        }
+
+        self.headers
+            .iter()
+            .position(|(hash, _)| hash == block_hash)
+            .map(|index| self.anchor_height + 1 + index as u32)
+    }
+
+    /// Header of the known header chain at `height`
+    fn header_at(&self, height: u32) -> Option<&(BlockHash, Header)> {
+        height
+            .checked_sub(self.anchor_height + 1)
+            .and_then(|index| self.headers.get(index as usize))
+    }
+
+    /// Verify and append block headers to the header chain.
+    ///
+    /// Headers replacing blocks that are not chain-locked yet are accepted, dropping the
+    /// blocks they replace. Blocks up to the checkpoint can't be replaced.
+    pub fn apply_headers(&mut self, headers: &[Header]) -> Result<(), Error> {
+        let final_height = self
+            .best_chain_lock
+            .as_ref()
+            .map(|chain_lock| chain_lock.block_height)
+            .unwrap_or_default()
+            .max(self.checkpoint_height);
+
+        for header in headers {
+            let block_hash = header.block_hash();
+            if self.height_of(&block_hash).is_some() {
+                continue;
+            }
+
+            let Some(parent_height) = self.height_of(&header.prev_blockhash) else {
+                return Err(Error::CoreVerification(format!(
+                    "block header {} does not connect to the known chain",
+                    block_hash
+                )));
+            };
+
+            if parent_height < final_height {
+                return Err(Error::CoreVerification(format!(
+                    "block header {} conflicts with final block at height {}",
+                    block_hash,
+                    parent_height + 1
+                )));
+            }
+
+            let parent_index = (parent_height - self.anchor_height) as usize;
+            pow::verify_header(
+                self.network,
+                &self.headers[..parent_index],
+                &block_hash,
+                header,
+            )
+            .map_err(|e| {
+                Error::CoreVerification(format!("invalid block header {}: {}", block_hash, e))
+            })?;
+
+            self.headers.truncate(parent_index);
+            self.headers.push((block_hash, *header));
+        }
+
+        // Drop a pending masternode list of a block that was reorganized away
+        if let Some(pending) = &self.pending {
+            if self.height_of(&pending.block_hash) != Some(pending.block_height) {
+                self.pending = None;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verify and apply a masternode list diff.
+    ///
+    /// The block of the diff must be part of the header chain. The resulting list becomes
+    /// active if the block is already chain-locked; otherwise it waits for a chain lock.
+    pub fn apply_masternode_list_diff(&mut self, diff: &MasternodeListDiff) -> Result<(), Error> {
+        let base = if diff.is_full_list() {
+            None
+        } else {
+            let base = [self.pending.as_ref(), self.current.as_ref()]
+                .into_iter()
+                .flatten()
+                .find(|list| list.block_hash == diff.base_block_hash)
+                .ok_or(Error::CoreVerification(format!(
+                    "masternode list diff base block {} is unknown",
+                    diff.base_block_hash
+                )))?;
+            Some(base)
+        };
+
+        let block_height = self
+            .height_of(&diff.block_hash)
+            .ok_or(Error::CoreVerification(format!(
+                "masternode list diff block {} is unknown",
+                diff.block_hash
+            )))?;
+        if let Some(current) = &self.current {
+            if block_height <= current.block_height {
+                // Diff of an already active list, or older
+                return Ok(());
+            }
+        }
+        let header = self
+            .header_at(block_height)
+            .map(|(_, header)| header)
+            .ok_or(Error::CoreVerification(format!(
+                "header of masternode list diff block {} was pruned",
+                diff.block_hash
+            )))?;
+
+        // The coinbase transaction must be the first transaction of the block
+        let mut matches = Vec::new();
+        let mut indexes = Vec::new();
+        let merkle_root = diff
+            .coinbase_merkle_tree
+            .extract_matches(&mut matches, &mut indexes)?;
+        if merkle_root != header.merkle_root
+            || matches != vec![diff.coinbase_transaction.txid()]
+            || indexes != vec![0]
+            || !diff.coinbase_transaction.is_coin_base()
+        {
+            return Err(Error::CoreVerification(format!(
+                "invalid coinbase inclusion proof in masternode list diff of block {}",
+                diff.block_hash
+            )));
+        }
+
+        let Some(TransactionPayload::CoinbasePayloadType(coinbase_payload)) =
+            &diff.coinbase_transaction.special_transaction_payload
+        else {
+            return Err(Error::CoreVerification(format!(
+                "coinbase of block {} has no coinbase payload",
+                diff.block_hash
+            )));
+        };
+
+        for quorum in &diff.new_quorums {
+            quorum.verify().map_err(|e| {
+                Error::CoreVerification(format!(
+                    "invalid commitment of quorum {} in masternode list diff of block {}: {}",
+                    hex::encode(quorum.display_quorum_hash()),
+                    diff.block_hash,
+                    e
+                ))
+            })?;
+        }
+
+        let list = match base {
+            Some(base) => base.apply_diff(diff, block_height),
+            None => MasternodeList::default().apply_diff(diff, block_height),
+        };
+
+        if list.masternodes_merkle_root()
+            != coinbase_payload.merkle_root_masternode_list.to_byte_array()
+        {
+            return Err(Error::CoreVerification(format!(
+                "masternode list does not match the coinbase of block {}",
+                diff.block_hash
+            )));
+        }
+        if list.quorums.merkle_root() != coinbase_payload.merkle_root_quorums.to_byte_array() {
+            return Err(Error::CoreVerification(format!(
+                "quorum list does not match the coinbase of block {}",
+                diff.block_hash
+            )));
+        }
+
+        let chain_locked = self
+            .best_chain_lock
+            .as_ref()
+            .is_some_and(|chain_lock| chain_lock.block_height >= block_height);
+        if chain_locked && self.current.is_some() {
+            self.activate(list);
+        } else {
+            self.pending = Some(list);
+        }
+
+        Ok(())
+    }
+
+    /// Verify and apply a chain lock.
+    ///
+    /// Returns `false` if the chain lock is not newer than the best one, its block is not known
+    /// yet, or there is no masternode list to verify it with yet.
+    pub fn apply_chain_lock(&mut self, chain_lock: &ChainLock) -> Result<bool, Error> {
+        if self
+            .best_chain_lock
+            .as_ref()
+            .is_some_and(|best| best.block_height >= chain_lock.block_height)
+        {
+            return Ok(false);
+        }
+
+        match self.header_at(chain_lock.block_height) {
+            Some((block_hash, _)) if *block_hash == chain_lock.block_hash => {}
+            Some(_) => {
+                return Err(Error::CoreVerification(format!(
+                    "chain lock for block {} at height {} conflicts with the header chain",
+                    chain_lock.block_hash, chain_lock.block_height
+                )))
+            }
+            None => return Ok(false),
+        }
+
+        let verified = match &self.current {
+            Some(current) => {
+                current
+                    .quorums
+                    .verify_chain_lock(chain_lock, self.chain_lock_quorum_type)
+                    || self
+                        .previous_quorums
+                        .verify_chain_lock(chain_lock, self.chain_lock_quorum_type)
+            }
+            // Bootstrap: the first list is only trusted through the checkpoint. The quorum it
+            // selects to sign the chain lock must be a chain lock quorum of the checkpoint, and
+            // the signature is verified with the public key of the checkpoint. A quorum that is
+            // not part of the checkpoint is not conclusive; a later chain lock may be signed by
+            // one that is.
+            None => {
+                let Some(pending) = self
+                    .pending
+                    .as_ref()
+                    .filter(|pending| pending.block_height <= chain_lock.block_height)
+                else {
+                    return Ok(false);
+                };
+                let Some(quorum) = pending
+                    .quorums
+                    .chain_lock_quorum(chain_lock.block_height, self.chain_lock_quorum_type)
+                else {
+                    return Ok(false);
+                };
+                let Some(public_key) = self.checkpoint_quorums.get(&quorum.display_quorum_hash())
+                else {
+                    return Ok(false);
+                };
+
+                verify_chain_lock_signature(
+                    chain_lock,
+                    self.chain_lock_quorum_type,
+                    &quorum.quorum_hash,
+                    public_key,
+                )
+            }
+        };
+        if !verified {
+            return Err(Error::CoreVerification(format!(
+                "invalid chain lock signature for block {} at height {}",
+                chain_lock.block_hash, chain_lock.block_height
+            )));
+        }
+
+        self.best_chain_lock = Some(chain_lock.clone());
+
+        if let Some(pending) = self.pending.take() {
+            if pending.block_height <= chain_lock.block_height {
+                self.activate(pending);
+            } else {
+                self.pending = Some(pending);
+            }
+        }
+
+        self.prune();
+
+        Ok(true)
+    }
+
+    fn activate(&mut self, list: MasternodeList) {
+        if let Some(previous) = self.current.replace(list) {
+            self.previous_quorums = previous.quorums;
+        }
+    }
+
+    /// Drop old chain-locked headers that are no longer needed
+    fn prune(&mut self) {
+        let Some(chain_lock) = &self.best_chain_lock else {
+            return;
+        };
+
+        let mut keep_from = chain_lock
+            .block_height
+            .saturating_sub(CHAIN_LOCKED_HEADERS_KEPT);
+        if let Some(pending) = &self.pending {
+            keep_from = keep_from.min(pending.block_height);
+        }
+
+        if keep_from <= self.anchor_height + 1 {
+            return;
+        }
+
+        let pruned = (keep_from - self.anchor_height - 1) as usize;
+        let (anchor_hash, _) = self.headers[pruned - 1];
+        self.headers.drain(..pruned);
+        self.anchor_height += pruned as u32;
+        self.anchor_hash = anchor_hash;
+    }
+
+    /// Load a state persisted with [SpvState::save]
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let bytes = std::fs::read(path).map_err(|e| {
+            Error::Generic(format!(
+                "unable to read SPV state from {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        encode::deserialize(&bytes)
+            .map_err(|e| Error::Generic(format!("invalid SPV state in {}: {}", path.display(), e)))
+    }
+
+    /// Persist the state to `path`.
+    ///
+    /// The state is written to a temporary file first, so an interrupted write does not
+    /// corrupt previously persisted state.
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let temp_path = path.with_extension("tmp");
+
+        std::fs::write(&temp_path, encode::serialize(self))
+            .and_then(|_| std::fs::rename(&temp_path, path))
+            .map_err(|e| {
+                Error::Generic(format!(
+                    "unable to write SPV state to {}: {}",
+                    path.display(),
+                    e
+                ))
+            })
+    }
+}
+
+impl Encodable for SpvState {
+    fn consensus_encode<W: Write + ?Sized>(&self, writer: &mut W) -> Result<usize, std::io::Error> {
+        let network = network_code(self.network).ok_or(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "unsupported network",
+        ))?;
+
+        let mut len = PERSISTED_STATE_VERSION.consensus_encode(writer)?;
+        len += network.consensus_encode(writer)?;
+        len += self.chain_lock_quorum_type.consensus_encode(writer)?;
+        len += self.checkpoint_height.consensus_encode(writer)?;
+        len += VarInt(self.checkpoint_quorums.len() as u64).consensus_encode(writer)?;
+        for (quorum_hash, public_key) in &self.checkpoint_quorums {
+            len += write_bytes(writer, quorum_hash)?;
+            len += write_bytes(writer, public_key)?;
+        }
+        len += self.anchor_height.consensus_encode(writer)?;
+        len += self.anchor_hash.consensus_encode(writer)?;
+        len += VarInt(self.headers.len() as u64).consensus_encode(writer)?;
+        for (_, header) in &self.headers {
+            len += header.consensus_encode(writer)?;
+        }
+        len += encode_option(writer, &self.best_chain_lock)?;
+        len += encode_option(writer, &self.current)?;
+        len += self.previous_quorums.consensus_encode(writer)?;
+        len += encode_option(writer, &self.pending)?;
+        Ok(len)
+    }
+}
+
+impl Decodable for SpvState {
+    fn consensus_decode<R: Read + ?Sized>(reader: &mut R) -> Result<Self, encode::Error> {
+        let version = u16::consensus_decode(reader)?;
+        if version != PERSISTED_STATE_VERSION {
+            return Err(encode::Error::ParseFailed("unsupported SPV state version"));
+        }
+
+        let network = network_from_code(u8::consensus_decode(reader)?)
+            .ok_or(encode::Error::ParseFailed("unsupported network"))?;
+        let chain_lock_quorum_type = u8::consensus_decode(reader)?;
+        let checkpoint_height = u32::consensus_decode(reader)?;
+        let VarInt(count) = VarInt::consensus_decode(reader)?;
+        let mut checkpoint_quorums = BTreeMap::new();
+        for _ in 0..count {
+            checkpoint_quorums.insert(read_array(reader)?, read_array(reader)?);
+        }
+        let anchor_height = u32::consensus_decode(reader)?;
+        let anchor_hash = BlockHash::consensus_decode(reader)?;
+        let VarInt(count) = VarInt::consensus_decode(reader)?;
+        let mut headers = Vec::new();
+        for _ in 0..count {
+            let header = Header::consensus_decode(reader)?;
+            headers.push((header.block_hash(), header));
+        }
+
+        Ok(Self {
+            network,
+            chain_lock_quorum_type,
+            checkpoint_height,
+            checkpoint_quorums,
+            anchor_height,
+            anchor_hash,
+            headers,
+            best_chain_lock: decode_option(reader)?,
+            current: decode_option(reader)?,
+            previous_quorums: QuorumList::consensus_decode(reader)?,
+            pending: decode_option(reader)?,
+        })
+    }
+}
+
+fn network_code(network: Network) -> Option<u8> {
+    match network {
+        Network::Dash => Some(0),
+        Network::Testnet => Some(1),
+        Network::Devnet => Some(2),
+        Network::Regtest => Some(3),
+        _ => None,
+    }
+}
+
+fn network_from_code(code: u8) -> Option<Network> {
+    match code {
+        0 => Some(Network::Dash),
+        1 => Some(Network::Testnet),
+        2 => Some(Network::Devnet),
+        3 => Some(Network::Regtest),
+        _ => None,
+    }
+}
+
+fn encode_option<W: Write + ?Sized, T: Encodable>(
+    writer: &mut W,
+    value: &Option<T>,
+) -> Result<usize, std::io::Error> {
+    match value {
+        Some(value) => Ok(true.consensus_encode(writer)? + value.consensus_encode(writer)?),
+        None => false.consensus_encode(writer),
+    }
+}
+
+fn decode_option<R: Read + ?Sized, T: Decodable>(
+    reader: &mut R,
+) -> Result<Option<T>, encode::Error> {
+    if bool::consensus_decode(reader)? {
+        Ok(Some(T::consensus_decode(reader)?))
+    } else {
+        Ok(None)
+    }
+}
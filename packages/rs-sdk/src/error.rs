@@ -64,6 +64,14 @@ pub enum Error {
     #[error("Context provider error: {0}")]
     ContextProviderError(#[from] ContextProviderError),
 
+    /// Signer refused to sign a state transition
+    #[error("Signing refused: {0}")]
+    SigningRefused(String),
+
+    /// Core chain data received from DAPI could not be verified
+    #[error("Core chain data verification error: {0}")]
+    CoreVerification(String),
+
     /// Operation cancelled - cancel token was triggered, timeout, etc.
     #[error("Operation cancelled: {0}")]
     Cancelled(String),
@@ -5,19 +5,23 @@ use dpp::{
     dashcore::{hashes::Hash, ProTxHash},
     document::{serialization_traits::DocumentCborMethodsV0, Document},
     identifier::Identifier,
-    identity::IdentityPublicKey,
+    identity::{recovery::PendingIdentityRecovery, IdentityPublicKey},
     platform_serialization::{platform_encode_to_vec, platform_versioned_decode_from_slice},
     prelude::{DataContract, Identity},
     serialization::{
-        PlatformDeserializableWithPotentialValidationFromVersionedStructure,
+        PlatformDeserializable,
+        PlatformDeserializableWithPotentialValidationFromVersionedStructure, PlatformSerializable,
         PlatformSerializableWithPlatformVersion,
     },
-    voting::votes::{resource_vote::ResourceVote, Vote},
+    voting::{
+        vote_info_storage::governance_proposal_vote_poll_stored_info::GovernanceProposalVotePollStoredInfo,
+        votes::{resource_vote::ResourceVote, Vote},
+    },
 };
 use drive_proof_verifier::types::{
-    Contenders, ContestedResources, ElementFetchRequestItem, IdentityBalanceAndRevision,
-    MasternodeProtocolVote, PrefundedSpecializedBalance, TotalCreditsInPlatform,
-    VotePollsGroupedByTimestamp, Voters,
+    Contenders, ContestedResources, DocumentRevision, DocumentsCount, ElementFetchRequestItem,
+    IdentityBalanceAndRevision, IdentityWithdrawals, MasternodeProtocolVote,
+    PrefundedSpecializedBalance, TotalCreditsInPlatform, VotePollsGroupedByTimestamp, Voters,
 };
 use std::collections::BTreeMap;
 
@@ -164,6 +168,19 @@ impl MockResponse for Document {
     }
 }
 
+impl MockResponse for DocumentRevision {
+    fn mock_serialize(&self, sdk: &MockDashPlatformSdk) -> Vec<u8> {
+        self.0.mock_serialize(sdk)
+    }
+
+    fn mock_deserialize(sdk: &MockDashPlatformSdk, buf: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        Self(Document::mock_deserialize(sdk, buf))
+    }
+}
+
 impl MockResponse for drive_proof_verifier::types::IdentityNonceFetcher {
     fn mock_serialize(&self, _sdk: &MockDashPlatformSdk) -> Vec<u8> {
         (self.0).to_be_bytes().to_vec()
@@ -195,6 +212,34 @@ impl MockResponse for drive_proof_verifier::types::IdentityContractNonceFetcher
         ))
     }
 }
+impl MockResponse for PendingIdentityRecovery {
+    fn mock_serialize(&self, _sdk: &MockDashPlatformSdk) -> Vec<u8> {
+        self.serialize_to_bytes()
+            .expect("encode PendingIdentityRecovery")
+    }
+    fn mock_deserialize(_sdk: &MockDashPlatformSdk, buf: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        PendingIdentityRecovery::deserialize_from_bytes(buf)
+            .expect("decode PendingIdentityRecovery")
+    }
+}
+
+impl MockResponse for GovernanceProposalVotePollStoredInfo {
+    fn mock_serialize(&self, _sdk: &MockDashPlatformSdk) -> Vec<u8> {
+        self.serialize_to_bytes()
+            .expect("encode GovernanceProposalVotePollStoredInfo")
+    }
+    fn mock_deserialize(_sdk: &MockDashPlatformSdk, buf: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        GovernanceProposalVotePollStoredInfo::deserialize_from_bytes(buf)
+            .expect("decode GovernanceProposalVotePollStoredInfo")
+    }
+}
+
 impl MockResponse for ProTxHash {
     fn mock_serialize(&self, sdk: &MockDashPlatformSdk) -> Vec<u8> {
         let data = self.as_raw_hash().as_byte_array();
@@ -229,3 +274,5 @@ impl_mock_response!(VotePollsGroupedByTimestamp);
 impl_mock_response!(PrefundedSpecializedBalance);
 impl_mock_response!(TotalCreditsInPlatform);
 impl_mock_response!(ElementFetchRequestItem);
+impl_mock_response!(DocumentsCount);
+impl_mock_response!(IdentityWithdrawals);
@@ -2,7 +2,10 @@
 //!
 //! See [MockDashPlatformSdk] for more details.
 use crate::{
-    platform::{types::identity::IdentityRequest, DocumentQuery, Fetch, FetchMany, Query},
+    platform::{
+        types::identity::IdentityRequest, DocumentCountQuery, DocumentQuery, Fetch, FetchMany,
+        Query,
+    },
     Error, Sdk,
 };
 use arc_swap::ArcSwapOption;
@@ -117,6 +120,10 @@ impl MockDashPlatformSdk {
 
             match request_type {
                 "DocumentQuery" => self.load_expectation::<DocumentQuery>(filename).await?,
+                "DocumentCountQuery" => {
+                    self.load_expectation::<DocumentCountQuery>(filename)
+                        .await?
+                }
                 "GetEpochsInfoRequest" => {
                     self.load_expectation::<proto::GetEpochsInfoRequest>(filename)
                         .await?
@@ -147,6 +154,18 @@ impl MockDashPlatformSdk {
                     self.load_expectation::<proto::GetIdentityContractNonceRequest>(filename)
                         .await?
                 }
+                "GetIdentityPendingRecoveryRequest" => {
+                    self.load_expectation::<proto::GetIdentityPendingRecoveryRequest>(filename)
+                        .await?
+                }
+                "GetDocumentHistoryRequest" => {
+                    self.load_expectation::<proto::GetDocumentHistoryRequest>(filename)
+                        .await?
+                }
+                "GetIdentityWithdrawalsRequest" => {
+                    self.load_expectation::<proto::GetIdentityWithdrawalsRequest>(filename)
+                        .await?
+                }
                 "GetIdentityBalanceAndRevisionRequest" => {
                     self.load_expectation::<proto::GetIdentityBalanceAndRevisionRequest>(filename)
                         .await?
@@ -189,6 +208,12 @@ impl MockDashPlatformSdk {
                     self.load_expectation::<proto::GetVotePollsByEndDateRequest>(filename)
                         .await?
                 }
+                "GetGovernanceProposalVotePollStateRequest" => {
+                    self.load_expectation::<proto::GetGovernanceProposalVotePollStateRequest>(
+                        filename,
+                    )
+                    .await?
+                }
                 "GetPrefundedSpecializedBalanceRequest" => {
                     self.load_expectation::<proto::GetPrefundedSpecializedBalanceRequest>(filename)
                         .await?
@@ -347,6 +372,34 @@ impl MockDashPlatformSdk {
         Ok(self)
     }
 
+    /// Expect a raw request and return provided response.
+    ///
+    /// This method is used to define mock expectations for requests that are executed directly,
+    /// without parsing the response with [FromProof], like broadcasting a state transition
+    /// and waiting for its result.
+    ///
+    /// ## Arguments
+    ///
+    /// - `request`: Request that will be sent to Platform.
+    /// - `response`: Response that will be returned to the caller, including the proof, if any.
+    ///
+    /// ## Returns
+    ///
+    /// * Reference to self on success, to allow chaining
+    /// * Error when expectation cannot be set or is already defined for this request
+    pub async fn expect_request<R: TransportRequest + Mockable>(
+        &mut self,
+        request: R,
+        response: R::Response,
+    ) -> Result<&mut Self, Error>
+    where
+        R::Response: Mockable,
+    {
+        self.dapi.lock().await.expect(&request, &Ok(response))?;
+
+        Ok(self)
+    }
+
     /// Save expectations for a request.
     async fn expect<I: TransportRequest, O: MockResponse>(
         &mut self,
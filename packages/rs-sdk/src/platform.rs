@@ -7,7 +7,10 @@
 
 pub mod block_info_from_metadata;
 mod delegate;
+mod document_count_query;
 mod document_query;
+pub mod dpns;
+mod events;
 mod fetch;
 pub mod fetch_current_no_parameters;
 mod fetch_many;
@@ -28,8 +31,9 @@ pub use drive_proof_verifier::ContextProvider;
 pub use drive_proof_verifier::MockContextProvider;
 pub use rs_dapi_client as dapi;
 pub use {
+    document_count_query::DocumentCountQuery,
     document_query::DocumentQuery,
     fetch::Fetch,
     fetch_many::FetchMany,
-    query::{LimitQuery, Query, QueryStartInfo, DEFAULT_EPOCH_QUERY_LIMIT},
+    query::{DocumentHistoryQuery, LimitQuery, Query, QueryStartInfo, DEFAULT_EPOCH_QUERY_LIMIT},
 };
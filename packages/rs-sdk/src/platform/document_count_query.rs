@@ -0,0 +1,230 @@
+//! Method to count documents, or sum a document property, from the Drive.
+
+use std::sync::Arc;
+
+use crate::{error::Error, sdk::Sdk};
+use dapi_grpc::platform::v0::get_documents_count_request::{
+    GetDocumentsCountRequestV0, Version::V0,
+};
+use dapi_grpc::platform::v0::{
+    self as platform_proto, GetDocumentsCountRequest, Proof, ResponseMetadata,
+};
+use dpp::dashcore::Network;
+use dpp::version::PlatformVersion;
+use dpp::{
+    data_contract::{
+        accessors::v0::DataContractV0Getters, document_type::accessors::DocumentTypeV0Getters,
+    },
+    prelude::{DataContract, Identifier},
+    ProtocolError,
+};
+use drive::query::{DriveDocumentCountQuery, WhereClause};
+use drive_proof_verifier::{types::DocumentsCount, ContextProvider, FromProof};
+use rs_dapi_client::transport::{
+    AppliedRequestSettings, BoxFuture, TransportClient, TransportRequest,
+};
+
+use super::document_query::serialize_vec_to_cbor;
+use super::fetch::Fetch;
+
+/// Request that is used to count documents, or sum an integer document property, on the Dash
+/// Platform.
+///
+/// The where clauses must match a countable index, or a summable index when
+/// [sum_property](DocumentCountQuery::sum_property) is set.
+///
+/// Conversions are implemented between this type, [GetDocumentsCountRequest] and
+/// [DriveDocumentCountQuery] using [TryFrom] trait.
+#[derive(Debug, Clone, dapi_grpc_macros::Mockable)]
+#[cfg_attr(feature = "mocks", derive(serde::Serialize, serde::Deserialize))]
+pub struct DocumentCountQuery {
+    /// Data contract
+    pub data_contract: Arc<DataContract>,
+    /// Document type for the data contract
+    pub document_type_name: String,
+    /// `where` clauses for the query
+    pub where_clauses: Vec<WhereClause>,
+    /// Integer property to sum; documents are counted if none
+    pub sum_property: Option<String>,
+}
+
+impl DocumentCountQuery {
+    /// Create new DocumentCountQuery for provided contract and document type name.
+    pub fn new<C: Into<Arc<DataContract>>>(
+        contract: C,
+        document_type_name: &str,
+    ) -> Result<Self, Error> {
+        let contract = contract.into();
+        // ensure document type name is correct
+        contract
+            .document_type_for_name(document_type_name)
+            .map_err(ProtocolError::DataContractError)?;
+
+        Ok(Self {
+            data_contract: Arc::clone(&contract),
+            document_type_name: document_type_name.to_string(),
+            where_clauses: vec![],
+            sum_property: None,
+        })
+    }
+
+    /// Create new document count query from a SQL select statement.
+    ///
+    /// See [DriveDocumentCountQuery::from_sql_expr] for the supported syntax.
+    pub fn from_sql_expr<C: Into<Arc<DataContract>>>(
+        sql_string: &str,
+        contract: C,
+    ) -> Result<Self, Error> {
+        let contract = contract.into();
+        let drive_query =
+            DriveDocumentCountQuery::from_sql_expr(sql_string, &contract).map_err(Error::Drive)?;
+
+        let document_type_name = drive_query.document_type.name().clone();
+        let sum_property = drive_query.sum_property.clone();
+        let where_clauses = drive_query
+            .equal_clauses
+            .iter()
+            .chain(drive_query.range_clause.iter())
+            .cloned()
+            .collect();
+
+        Ok(Self {
+            data_contract: Arc::clone(&contract),
+            document_type_name,
+            where_clauses,
+            sum_property,
+        })
+    }
+
+    /// Create new document count query for provided document type name and data contract ID.
+    ///
+    /// Note that this method will fetch data contract first.
+    pub async fn new_with_data_contract_id(
+        api: &Sdk,
+        data_contract_id: Identifier,
+        document_type_name: &str,
+    ) -> Result<Self, Error> {
+        let data_contract =
+            DataContract::fetch(api, data_contract_id)
+                .await?
+                .ok_or(Error::MissingDependency(
+                    "DataContract".to_string(),
+                    format!("data contract {} not found", data_contract_id),
+                ))?;
+
+        Self::new(data_contract, document_type_name)
+    }
+
+    /// Add new where clause to the query.
+    ///
+    /// Existing where clauses will be preserved.
+    pub fn with_where(mut self, clause: WhereClause) -> Self {
+        self.where_clauses.push(clause);
+
+        self
+    }
+
+    /// Sum the provided integer property instead of counting documents.
+    pub fn with_sum_property(mut self, property: &str) -> Self {
+        self.sum_property = Some(property.to_string());
+
+        self
+    }
+}
+
+impl TransportRequest for DocumentCountQuery {
+    type Client = <GetDocumentsCountRequest as TransportRequest>::Client;
+    type Response = <GetDocumentsCountRequest as TransportRequest>::Response;
+    const SETTINGS_OVERRIDES: rs_dapi_client::RequestSettings =
+        <GetDocumentsCountRequest as TransportRequest>::SETTINGS_OVERRIDES;
+
+    fn request_name(&self) -> &'static str {
+        "GetDocumentsCountRequest"
+    }
+
+    fn method_name(&self) -> &'static str {
+        "get_documents_count"
+    }
+
+    fn execute_transport<'c>(
+        self,
+        client: &'c mut Self::Client,
+        settings: &AppliedRequestSettings,
+    ) -> BoxFuture<'c, Result<Self::Response, <Self::Client as TransportClient>::Error>> {
+        let request: GetDocumentsCountRequest = self
+            .try_into()
+            .expect("DocumentCountQuery should always be valid");
+        request.execute_transport(client, settings)
+    }
+}
+
+impl TryFrom<DocumentCountQuery> for platform_proto::GetDocumentsCountRequest {
+    type Error = Error;
+    fn try_from(dapi_request: DocumentCountQuery) -> Result<Self, Self::Error> {
+        let r#where = serialize_vec_to_cbor(dapi_request.where_clauses)?;
+
+        Ok(GetDocumentsCountRequest {
+            version: Some(V0(GetDocumentsCountRequestV0 {
+                data_contract_id: dapi_request.data_contract.id().to_vec(),
+                document_type: dapi_request.document_type_name,
+                r#where,
+                sum_property: dapi_request.sum_property,
+                prove: true,
+            })),
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a DocumentCountQuery> for DriveDocumentCountQuery<'a> {
+    type Error = Error;
+
+    fn try_from(request: &'a DocumentCountQuery) -> Result<Self, Self::Error> {
+        let document_type = request
+            .data_contract
+            .document_type_for_name(&request.document_type_name)
+            .map_err(ProtocolError::DataContractError)?;
+
+        DriveDocumentCountQuery::from_where_clauses(
+            request.where_clauses.clone(),
+            request.sum_property.clone(),
+            &request.data_contract,
+            document_type,
+        )
+        .map_err(Error::Drive)
+    }
+}
+
+impl FromProof<DocumentCountQuery> for DocumentsCount {
+    type Request = DocumentCountQuery;
+    type Response = platform_proto::GetDocumentsCountResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        network: Network,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata, Proof), drive_proof_verifier::Error>
+    where
+        Self: Sized + 'a,
+    {
+        let request: Self::Request = request.into();
+        let drive_query: DriveDocumentCountQuery =
+            (&request)
+                .try_into()
+                .map_err(|e| drive_proof_verifier::Error::RequestError {
+                    error: format!(
+                        "Failed to convert DocumentCountQuery to DriveDocumentCountQuery: {}",
+                        e
+                    ),
+                })?;
+
+        <DocumentsCount as FromProof<DriveDocumentCountQuery>>::maybe_from_proof_with_metadata(
+            drive_query,
+            response,
+            network,
+            platform_version,
+            provider,
+        )
+    }
+}
@@ -21,6 +21,7 @@ use dpp::{
     prelude::{DataContract, Identifier},
     ProtocolError,
 };
+use drive::error::query::QuerySyntaxError;
 use drive::query::{DriveDocumentQuery, InternalClauses, OrderClause, WhereClause, WhereOperator};
 use drive_proof_verifier::{types::Documents, ContextProvider, FromProof};
 use rs_dapi_client::transport::{
@@ -81,6 +82,25 @@ impl DocumentQuery {
         Self::from(d)
     }
 
+    /// Create new document query from a SQL select statement.
+    ///
+    /// See [DriveDocumentQuery::from_sql_expr] for the supported syntax. Proofs can't skip
+    /// documents, so `OFFSET` must be the id of the document to start after rather than a number.
+    pub fn from_sql_expr(sql_string: &str, contract: &DataContract) -> Result<Self, Error> {
+        let drive_query =
+            DriveDocumentQuery::from_sql_expr(sql_string, contract, None).map_err(Error::Drive)?;
+
+        if drive_query.offset.is_some() {
+            return Err(Error::Drive(drive::error::Error::Query(
+                QuerySyntaxError::RequestingProofWithOffset(
+                    "numeric offsets can not be proved, use a document id cursor".to_string(),
+                ),
+            )));
+        }
+
+        Ok(Self::from(&drive_query))
+    }
+
     /// Create new document query for provided document type name and data contract ID.
     ///
     /// Note that this method will fetch data contract first.
@@ -347,7 +367,7 @@ impl<'a> TryFrom<&'a DocumentQuery> for DriveDocumentQuery<'a> {
     }
 }
 
-fn serialize_vec_to_cbor<T: Into<Value>>(input: Vec<T>) -> Result<Vec<u8>, Error> {
+pub(super) fn serialize_vec_to_cbor<T: Into<Value>>(input: Vec<T>) -> Result<Vec<u8>, Error> {
     let values = Value::Array(
         input
             .into_iter()
@@ -0,0 +1,336 @@
+//! Resolution and registration of Dash Platform Name Service (DPNS) names.
+//!
+//! Names are stored as `domain` documents of the DPNS system data contract. Registering a name
+//! takes two state transitions: a `preorder` document committing to a salted hash of the name is
+//! created first, so the name can't be front-run once the `domain` document reveals it.
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
+use dpp::data_contract::DataContract;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::identity::IdentityPublicKey;
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+use dpp::platform_value::Value;
+use dpp::system_data_contracts::dpns_contract;
+use dpp::system_data_contracts::dpns_contract::v2::document_types::{domain, preorder};
+use dpp::util::entropy_generator::{DefaultEntropyGenerator, EntropyGenerator};
+use dpp::util::hash::hash_double;
+use dpp::util::strings::convert_to_homograph_safe_chars;
+use dpp::ProtocolError;
+use drive::query::{WhereClause, WhereOperator};
+
+use crate::platform::transition::documents_batch::DocumentsBatchBuilder;
+use crate::platform::transition::put_settings::PutSettings;
+use crate::platform::transition::signer::AsyncSigner;
+use crate::platform::{DocumentQuery, Fetch, Identifier};
+use crate::{Error, Sdk};
+
+/// A DPNS name, made of a label and the name of its parent domain, e.g. `alice` and `dash`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DpnsName {
+    /// The first segment of the name, as it should be displayed
+    pub label: String,
+    /// The rest of the name, empty for top level domains
+    pub parent_domain_name: String,
+}
+
+impl DpnsName {
+    /// Label converted to lowercase, with homographs replaced, as it is indexed by DPNS
+    pub fn normalized_label(&self) -> String {
+        convert_to_homograph_safe_chars(&self.label)
+    }
+
+    /// Parent domain name converted to lowercase, with homographs replaced, as it is indexed by DPNS
+    pub fn normalized_parent_domain_name(&self) -> String {
+        convert_to_homograph_safe_chars(&self.parent_domain_name)
+    }
+
+    /// Hash of the name that a preorder commits to.
+    ///
+    /// This must be computed the same way as the DPNS data trigger does when the domain is created.
+    pub fn salted_domain_hash(&self, salt: &[u8; 32]) -> [u8; 32] {
+        let full_domain_name = if self.parent_domain_name.is_empty() {
+            self.label.clone()
+        } else {
+            format!("{}.{}", self.normalized_label(), self.parent_domain_name)
+        };
+
+        let mut salted_domain_buffer = Vec::with_capacity(32 + full_domain_name.len());
+        salted_domain_buffer.extend_from_slice(salt);
+        salted_domain_buffer.extend_from_slice(full_domain_name.as_bytes());
+
+        hash_double(salted_domain_buffer)
+    }
+}
+
+impl FromStr for DpnsName {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let name = name.trim_end_matches('.');
+
+        let (label, parent_domain_name) = name.split_once('.').unwrap_or((name, ""));
+
+        if label.is_empty() {
+            return Err(Error::Generic(format!("invalid DPNS name '{}'", name)));
+        }
+
+        Ok(Self {
+            label: label.to_string(),
+            parent_domain_name: parent_domain_name.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for DpnsName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.parent_domain_name.is_empty() {
+            write!(f, "{}", self.label)
+        } else {
+            write!(f, "{}.{}", self.label, self.parent_domain_name)
+        }
+    }
+}
+
+/// Records a DPNS name points to.
+///
+/// Only `identity` is supported by the first version of the DPNS contract, the other records
+/// are validated by Platform when the name is registered.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DpnsRecords {
+    /// Identity the name belongs to
+    pub identity: Option<Identifier>,
+    /// Data contract the name refers to, it must exist
+    pub data_contract: Option<Identifier>,
+    /// Dash Core address payments to the name should be sent to
+    pub dash_core_payment_address: Option<String>,
+    /// URL of a website associated with the name
+    pub url: Option<String>,
+    /// Free text associated with the name
+    pub text: Option<String>,
+}
+
+impl DpnsRecords {
+    /// Reads the records of a `domain` document
+    pub fn from_domain_document(document: &Document) -> Result<Self, Error> {
+        let records = document
+            .properties()
+            .get(domain::properties::RECORDS)
+            .ok_or(Error::InvalidProvedResponse(format!(
+                "domain document {} has no records",
+                document.id()
+            )))?
+            .to_btree_ref_string_map()
+            .map_err(ProtocolError::ValueError)?;
+
+        Ok(Self {
+            identity: records
+                .get_optional_identifier(domain::records::IDENTITY)
+                .map_err(ProtocolError::ValueError)?,
+            data_contract: records
+                .get_optional_identifier(domain::records::DATA_CONTRACT)
+                .map_err(ProtocolError::ValueError)?,
+            dash_core_payment_address: records
+                .get_optional_string(domain::records::DASH_CORE_PAYMENT_ADDRESS)
+                .map_err(ProtocolError::ValueError)?,
+            url: records
+                .get_optional_string(domain::records::URL)
+                .map_err(ProtocolError::ValueError)?,
+            text: records
+                .get_optional_string(domain::records::TEXT)
+                .map_err(ProtocolError::ValueError)?,
+        })
+    }
+
+    /// Returns true if no record is set, DPNS requires at least one record
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    fn into_value(self) -> Value {
+        let mut records = vec![];
+
+        if let Some(identity) = self.identity {
+            records.push((
+                Value::Text(domain::records::IDENTITY.to_string()),
+                Value::Identifier(identity.to_buffer()),
+            ));
+        }
+
+        if let Some(data_contract) = self.data_contract {
+            records.push((
+                Value::Text(domain::records::DATA_CONTRACT.to_string()),
+                Value::Identifier(data_contract.to_buffer()),
+            ));
+        }
+
+        if let Some(address) = self.dash_core_payment_address {
+            records.push((
+                Value::Text(domain::records::DASH_CORE_PAYMENT_ADDRESS.to_string()),
+                Value::Text(address),
+            ));
+        }
+
+        if let Some(url) = self.url {
+            records.push((
+                Value::Text(domain::records::URL.to_string()),
+                Value::Text(url),
+            ));
+        }
+
+        if let Some(text) = self.text {
+            records.push((
+                Value::Text(domain::records::TEXT.to_string()),
+                Value::Text(text),
+            ));
+        }
+
+        Value::Map(records)
+    }
+}
+
+/// Fetches the DPNS system data contract
+async fn fetch_dpns_contract(sdk: &Sdk) -> Result<Arc<DataContract>, Error> {
+    let data_contract =
+        DataContract::fetch(sdk, dpns_contract::ID)
+            .await?
+            .ok_or(Error::MissingDependency(
+                "DataContract".to_string(),
+                format!("DPNS data contract {} not found", dpns_contract::ID),
+            ))?;
+
+    Ok(Arc::new(data_contract))
+}
+
+/// Fetches the proved `domain` document of a name, e.g. `alice.dash`.
+///
+/// The name is normalized the same way DPNS does, so the lookup is case-insensitive and
+/// homographs such as `o` and `0` resolve to the same name.
+/// Returns `None` if the name is not registered.
+pub async fn resolve_name(sdk: &Sdk, name: &str) -> Result<Option<Document>, Error> {
+    let name = DpnsName::from_str(name)?;
+
+    let data_contract = fetch_dpns_contract(sdk).await?;
+
+    let query = DocumentQuery::new(data_contract, domain::NAME)?
+        .with_where(WhereClause {
+            field: domain::properties::NORMALIZED_PARENT_DOMAIN_NAME.to_string(),
+            operator: WhereOperator::Equal,
+            value: Value::Text(name.normalized_parent_domain_name()),
+        })
+        .with_where(WhereClause {
+            field: domain::properties::NORMALIZED_LABEL.to_string(),
+            operator: WhereOperator::Equal,
+            value: Value::Text(name.normalized_label()),
+        });
+
+    Document::fetch(sdk, query).await
+}
+
+/// Registers a name, e.g. `alice.dash`, for `owner_id` and returns its proved `domain` document.
+///
+/// A `preorder` document committing to the name with a random salt is created and proved first,
+/// then the `domain` document revealing the name and the salt is created.
+/// Subdomains of the registered name can only be created by its owner.
+#[allow(clippy::too_many_arguments)]
+pub async fn register_name<S: AsyncSigner>(
+    sdk: &Sdk,
+    name: &str,
+    owner_id: Identifier,
+    records: DpnsRecords,
+    identity_public_key: &IdentityPublicKey,
+    signer: &S,
+    settings: Option<PutSettings>,
+) -> Result<Document, Error> {
+    let name = DpnsName::from_str(name)?;
+
+    if records.is_empty() {
+        return Err(Error::Generic(format!(
+            "at least one record must be set to register {}",
+            name
+        )));
+    }
+
+    let data_contract = fetch_dpns_contract(sdk).await?;
+
+    let preorder_document_type = data_contract
+        .document_type_cloned_for_name(preorder::NAME)
+        .map_err(ProtocolError::DataContractError)?;
+    let domain_document_type = data_contract
+        .document_type_cloned_for_name(domain::NAME)
+        .map_err(ProtocolError::DataContractError)?;
+
+    let entropy_generator = DefaultEntropyGenerator;
+    let generate = || {
+        entropy_generator
+            .generate()
+            .map_err(|e| Error::Generic(format!("unable to generate entropy: {}", e)))
+    };
+
+    let salt = generate()?;
+
+    let preorder_entropy = generate()?;
+    let preorder_document = preorder_document_type.as_ref().create_document_from_data(
+        Value::from([(
+            preorder::properties::SALTED_DOMAIN_HASH,
+            Value::Bytes32(name.salted_domain_hash(&salt)),
+        )]),
+        owner_id,
+        0,
+        0,
+        preorder_entropy,
+        sdk.version(),
+    )?;
+
+    DocumentsBatchBuilder::new(data_contract.clone(), owner_id)
+        .create_document(preorder_document, preorder_document_type, preorder_entropy)
+        .broadcast_and_wait_for_response(sdk, identity_public_key, signer, settings)
+        .await?;
+
+    let domain_entropy = generate()?;
+    let domain_document = domain_document_type.as_ref().create_document_from_data(
+        Value::from([
+            (domain::properties::LABEL, Value::Text(name.label.clone())),
+            (
+                domain::properties::NORMALIZED_LABEL,
+                Value::Text(name.normalized_label()),
+            ),
+            (
+                domain::properties::PARENT_DOMAIN_NAME,
+                Value::Text(name.parent_domain_name.clone()),
+            ),
+            (
+                domain::properties::NORMALIZED_PARENT_DOMAIN_NAME,
+                Value::Text(name.normalized_parent_domain_name()),
+            ),
+            (domain::properties::PREORDER_SALT, Value::Bytes32(salt)),
+            (domain::properties::RECORDS, records.into_value()),
+            (
+                "subdomainRules",
+                Value::from([("allowSubdomains", Value::Bool(false))]),
+            ),
+        ]),
+        owner_id,
+        0,
+        0,
+        domain_entropy,
+        sdk.version(),
+    )?;
+    let domain_document_id = domain_document.id();
+
+    let mut documents = DocumentsBatchBuilder::new(data_contract, owner_id)
+        .create_document(domain_document, domain_document_type, domain_entropy)
+        .broadcast_and_wait_for_response(sdk, identity_public_key, signer, settings)
+        .await?;
+
+    documents
+        .remove(&domain_document_id)
+        .flatten()
+        .ok_or(Error::InvalidProvedResponse(format!(
+            "did not prove the domain document of {}",
+            name
+        )))
+}
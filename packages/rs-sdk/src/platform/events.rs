@@ -0,0 +1,35 @@
+//! Subscriptions to platform events
+
+use crate::{Error, Sdk};
+use dapi_grpc::platform::v0::subscribe_platform_events_request::subscribe_platform_events_request_v0::EventFilter;
+use dapi_grpc::platform::v0::subscribe_platform_events_request::{
+    SubscribePlatformEventsRequestV0, Version,
+};
+use dapi_grpc::platform::v0::{SubscribePlatformEventsRequest, SubscribePlatformEventsResponse};
+use futures::{Stream, StreamExt};
+use rs_dapi_client::{DapiRequestExecutor, RequestSettings};
+
+impl Sdk {
+    /// Subscribes to platform events matching any of the filters
+    ///
+    /// The stream yields new blocks, executed state transitions and document changes as they
+    /// are committed, starting from the moment of subscription. Each event carries a proof
+    /// taken from the latest committed state when the event was processed. The proof height,
+    /// returned in the event metadata, is higher than the event height when the subscription
+    /// lags behind.
+    pub async fn subscribe_platform_events(
+        &self,
+        filters: Vec<EventFilter>,
+    ) -> Result<impl Stream<Item = Result<SubscribePlatformEventsResponse, Error>>, Error> {
+        let request = SubscribePlatformEventsRequest {
+            version: Some(Version::V0(SubscribePlatformEventsRequestV0 { filters })),
+        };
+
+        let stream = self
+            .execute(request, RequestSettings::default())
+            .await
+            .map_err(|e| Error::DapiClientError(e.to_string()))?;
+
+        Ok(stream.map(|response| response.map_err(|e| Error::DapiClientError(e.to_string()))))
+    }
+}
@@ -21,7 +21,7 @@ use rs_dapi_client::{transport::TransportRequest, DapiRequest, RequestSettings};
 use std::fmt::Debug;
 
 use super::types::identity::IdentityRequest;
-use super::DocumentQuery;
+use super::{DocumentCountQuery, DocumentQuery};
 
 /// Trait implemented by objects that can be fetched from Platform.
 ///
@@ -247,6 +247,10 @@ impl Fetch for Document {
     type Request = DocumentQuery;
 }
 
+impl Fetch for drive_proof_verifier::types::DocumentsCount {
+    type Request = DocumentCountQuery;
+}
+
 impl Fetch for drive_proof_verifier::types::IdentityBalance {
     type Request = platform_proto::GetIdentityBalanceRequest;
 }
@@ -263,6 +267,18 @@ impl Fetch for drive_proof_verifier::types::IdentityContractNonceFetcher {
     type Request = platform_proto::GetIdentityContractNonceRequest;
 }
 
+impl Fetch for dpp::identity::recovery::PendingIdentityRecovery {
+    type Request = platform_proto::GetIdentityPendingRecoveryRequest;
+}
+
+impl Fetch for drive_proof_verifier::types::IdentityWithdrawals {
+    type Request = platform_proto::GetIdentityWithdrawalsRequest;
+}
+
+impl Fetch for dpp::voting::vote_info_storage::governance_proposal_vote_poll_stored_info::GovernanceProposalVotePollStoredInfo {
+    type Request = platform_proto::GetGovernanceProposalVotePollStateRequest;
+}
+
 impl Fetch for drive_proof_verifier::types::IdentityBalanceAndRevision {
     type Request = platform_proto::GetIdentityBalanceAndRevisionRequest;
 }
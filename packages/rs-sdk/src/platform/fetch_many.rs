@@ -13,9 +13,9 @@ use crate::{
 use dapi_grpc::platform::v0::{
     GetContestedResourceIdentityVotesRequest, GetContestedResourceVoteStateRequest,
     GetContestedResourceVotersForIdentityRequest, GetContestedResourcesRequest,
-    GetDataContractsRequest, GetDocumentsResponse, GetEpochsInfoRequest, GetIdentityKeysRequest,
-    GetProtocolVersionUpgradeStateRequest, GetProtocolVersionUpgradeVoteStatusRequest,
-    GetVotePollsByEndDateRequest,
+    GetDataContractsRequest, GetDocumentHistoryRequest, GetDocumentsResponse, GetEpochsInfoRequest,
+    GetIdentityKeysRequest, GetProtocolVersionUpgradeStateRequest,
+    GetProtocolVersionUpgradeVoteStatusRequest, GetVotePollsByEndDateRequest,
 };
 use dashcore_rpc::dashcore::ProTxHash;
 use dpp::data_contract::DataContract;
@@ -29,9 +29,10 @@ use dpp::{
 };
 use dpp::{document::Document, voting::contender_structs::ContenderWithSerializedDocument};
 use drive_proof_verifier::types::{
-    Contenders, ContestedResource, ContestedResources, DataContracts, ExtendedEpochInfos,
-    IdentityPublicKeys, MasternodeProtocolVote, MasternodeProtocolVotes, ProtocolVersionUpgrades,
-    ResourceVotesByIdentity, VotePollsGroupedByTimestamp, Voter, Voters,
+    Contenders, ContestedResource, ContestedResources, DataContracts, DocumentHistory,
+    DocumentRevision, ExtendedEpochInfos, IdentityPublicKeys, MasternodeProtocolVote,
+    MasternodeProtocolVotes, ProtocolVersionUpgrades, ResourceVotesByIdentity,
+    VotePollsGroupedByTimestamp, Voter, Voters,
 };
 use drive_proof_verifier::{types::Documents, FromProof};
 use rs_dapi_client::{transport::TransportRequest, DapiRequest, RequestSettings};
@@ -244,6 +245,20 @@ impl FetchMany<Identifier, Documents> for Document {
     }
 }
 
+/// Fetch revisions of a document of a document type keeping history.
+///
+/// Returns [DocumentHistory](drive_proof_verifier::types::DocumentHistory) indexed by the block time,
+/// in milliseconds, at which each revision was stored, oldest first.
+///
+/// ## Supported query types
+///
+/// * [DocumentHistoryQuery](crate::platform::DocumentHistoryQuery) - document to fetch revisions of
+/// * [`LimitQuery<DocumentHistoryQuery>`](super::LimitQuery) - limit query that allows to specify maximum number of
+/// revisions to fetch; see also [FetchMany::fetch_many_with_limit()].
+impl FetchMany<u64, DocumentHistory> for DocumentRevision {
+    type Request = GetDocumentHistoryRequest;
+}
+
 /// Retrieve public keys for a given identity.
 ///
 /// Returns [IdentityPublicKeys](drive_proof_verifier::types::IdentityPublicKeys) indexed by
@@ -21,6 +21,8 @@ pub struct IdentitiesContractKeysQuery {
     pub document_type_name: Option<String>,
     /// The purposes we want to query for
     pub purposes: Vec<Purpose>,
+    /// The owner of the contract, set to also get the keys bound to all of its contracts
+    pub contracts_owner_id: Option<Identifier>,
 }
 
 impl IdentitiesContractKeysQuery {
@@ -37,8 +39,17 @@ impl IdentitiesContractKeysQuery {
             contract_id,
             document_type_name,
             purposes,
+            contracts_owner_id: None,
         })
     }
+
+    /// Also get the keys bound to all the contracts of `contracts_owner_id`, the owner of the
+    /// contract. A key bound to the contract itself is preferred over one of the same purpose
+    /// bound to all contracts of its owner.
+    pub fn with_contracts_owner_keys(mut self, contracts_owner_id: Identifier) -> Self {
+        self.contracts_owner_id = Some(contracts_owner_id);
+        self
+    }
 }
 
 impl TryFrom<IdentitiesContractKeysQuery> for GetIdentitiesContractKeysRequest {
@@ -49,6 +60,7 @@ impl TryFrom<IdentitiesContractKeysQuery> for GetIdentitiesContractKeysRequest {
             contract_id,
             document_type_name,
             purposes,
+            contracts_owner_id,
         } = dapi_request;
         //todo: transform this into PlatformVersionedTryFrom
         Ok(GetIdentitiesContractKeysRequest {
@@ -58,6 +70,7 @@ impl TryFrom<IdentitiesContractKeysQuery> for GetIdentitiesContractKeysRequest {
                 document_type_name,
                 purposes: purposes.into_iter().map(|purpose| purpose as i32).collect(),
                 prove: true,
+                contracts_owner_id: contracts_owner_id.map(|id| id.to_vec()),
             })),
         })
     }
@@ -6,6 +6,8 @@ use dapi_grpc::mock::Mockable;
 use dapi_grpc::platform::v0::get_contested_resource_identity_votes_request::GetContestedResourceIdentityVotesRequestV0;
 use dapi_grpc::platform::v0::get_contested_resource_voters_for_identity_request::GetContestedResourceVotersForIdentityRequestV0;
 use dapi_grpc::platform::v0::get_contested_resources_request::GetContestedResourcesRequestV0;
+use dapi_grpc::platform::v0::get_document_history_request::GetDocumentHistoryRequestV0;
+use dapi_grpc::platform::v0::get_governance_proposal_vote_poll_state_request::GetGovernanceProposalVotePollStateRequestV0;
 use dapi_grpc::platform::v0::get_path_elements_request::GetPathElementsRequestV0;
 use dapi_grpc::platform::v0::get_total_credits_in_platform_request::GetTotalCreditsInPlatformRequestV0;
 use dapi_grpc::platform::v0::{
@@ -17,7 +19,9 @@ use dapi_grpc::platform::v0::{
     GetProtocolVersionUpgradeVoteStatusRequest, GetTotalCreditsInPlatformRequest, KeyRequestType,
 };
 use dapi_grpc::platform::v0::{
-    GetContestedResourceIdentityVotesRequest, GetPrefundedSpecializedBalanceRequest,
+    get_document_history_request, get_governance_proposal_vote_poll_state_request,
+    GetContestedResourceIdentityVotesRequest, GetDocumentHistoryRequest,
+    GetGovernanceProposalVotePollStateRequest, GetPrefundedSpecializedBalanceRequest,
     GetVotePollsByEndDateRequest,
 };
 use dashcore_rpc::dashcore::{hashes::Hash, ProTxHash};
@@ -165,6 +169,7 @@ impl Query<proto::GetIdentityKeysRequest> for Identifier {
                     prove,
                     limit: None,
                     offset: None,
+                    valid_at: None,
                     request_type: Some(KeyRequestType {
                         request: Some(proto::key_request_type::Request::AllKeys(AllKeys {})),
                     }),
@@ -487,6 +492,25 @@ impl Query<GetPrefundedSpecializedBalanceRequest> for Identifier {
     }
 }
 
+impl Query<GetGovernanceProposalVotePollStateRequest> for Identifier {
+    fn query(self, prove: bool) -> Result<GetGovernanceProposalVotePollStateRequest, Error> {
+        if !prove {
+            unimplemented!("queries without proofs are not supported yet");
+        }
+
+        Ok(GetGovernanceProposalVotePollStateRequest {
+            version: Some(
+                get_governance_proposal_vote_poll_state_request::Version::V0(
+                    GetGovernanceProposalVotePollStateRequestV0 {
+                        vote_poll_id: self.to_vec(),
+                        prove,
+                    },
+                ),
+            ),
+        })
+    }
+}
+
 /// Query for single vote.
 #[derive(Debug, Clone)]
 pub struct VoteQuery {
@@ -583,3 +607,68 @@ impl Query<GetTotalCreditsInPlatformRequest> for NoParamQuery {
         Ok(request)
     }
 }
+
+/// Query for the revisions of a document of a document type that keeps history.
+///
+/// Revisions are returned oldest first, starting at the revision stored at or after
+/// [start_at_ms](DocumentHistoryQuery::start_at_ms).
+#[derive(Debug, Clone)]
+pub struct DocumentHistoryQuery {
+    /// Data contract the document belongs to
+    pub data_contract_id: Identifier,
+    /// Name of the document type; it must keep history
+    pub document_type_name: String,
+    /// Document identifier
+    pub document_id: Identifier,
+    /// Block time, in milliseconds, of the oldest revision to return
+    pub start_at_ms: u64,
+}
+
+impl DocumentHistoryQuery {
+    /// Create new query for all revisions of the document.
+    pub fn new(
+        data_contract_id: Identifier,
+        document_type_name: &str,
+        document_id: Identifier,
+    ) -> Self {
+        Self {
+            data_contract_id,
+            document_type_name: document_type_name.to_string(),
+            document_id,
+            start_at_ms: 0,
+        }
+    }
+
+    /// Only return revisions stored at or after the given block time, in milliseconds.
+    pub fn with_start_at_ms(mut self, start_at_ms: u64) -> Self {
+        self.start_at_ms = start_at_ms;
+        self
+    }
+}
+
+impl Query<GetDocumentHistoryRequest> for DocumentHistoryQuery {
+    fn query(self, prove: bool) -> Result<GetDocumentHistoryRequest, Error> {
+        LimitQuery::from(self).query(prove)
+    }
+}
+
+impl Query<GetDocumentHistoryRequest> for LimitQuery<DocumentHistoryQuery> {
+    fn query(self, prove: bool) -> Result<GetDocumentHistoryRequest, Error> {
+        if !prove {
+            unimplemented!("queries without proofs are not supported yet");
+        }
+
+        Ok(GetDocumentHistoryRequest {
+            version: Some(get_document_history_request::Version::V0(
+                GetDocumentHistoryRequestV0 {
+                    data_contract_id: self.query.data_contract_id.to_vec(),
+                    document_type: self.query.document_type_name,
+                    document_id: self.query.document_id.to_vec(),
+                    start_at_ms: self.query.start_at_ms,
+                    limit: self.limit,
+                    prove,
+                },
+            )),
+        })
+    }
+}
@@ -1,16 +1,28 @@
 //! State transitions used to put changed objects to the Dash Platform.
+pub mod batch_transfer;
 pub mod broadcast;
 pub(crate) mod broadcast_identity;
 pub mod broadcast_request;
 pub(crate) mod context;
+pub mod delete_document;
+pub mod documents_batch;
+pub mod estimate_fee;
 pub mod purchase_document;
 pub mod put_contract;
 pub mod put_document;
 pub mod put_identity;
 pub mod put_settings;
+pub mod replace_document;
+pub mod signer;
+#[cfg(unix)]
+pub mod socket_signer;
+pub mod threshold_signature;
 pub mod top_up_identity;
+pub mod transfer;
 pub mod transfer_document;
 mod txid;
+pub mod update_contract;
+pub mod update_identity;
 pub mod update_price_of_document;
 pub mod vote;
 pub mod withdraw_from_identity;
@@ -9,7 +9,6 @@ use std::fmt::Debug;
 
 use dapi_grpc::platform::v0::{self as proto, BroadcastStateTransitionRequest};
 use dpp::dashcore::PrivateKey;
-use dpp::identity::signer::Signer;
 use dpp::native_bls::NativeBlsModule;
 use dpp::prelude::{AssetLockProof, Identity};
 use dpp::state_transition::identity_create_transition::methods::IdentityCreateTransitionMethodsV0;
@@ -19,6 +18,7 @@ use dpp::version::PlatformVersion;
 use rs_dapi_client::transport::TransportRequest;
 
 use super::broadcast_request::BroadcastRequestForStateTransition;
+use super::signer::{sign_state_transition, AsyncSigner};
 use crate::error::Error;
 
 /// Trait implemented by objects that can be used to broadcast new identity state transitions.
@@ -38,18 +38,18 @@ use crate::error::Error;
 /// ```rust, ignore
 ///
 /// use dash_sdk::{Sdk, platform::{BroadcastNewIdentity, IdentityCreateTransition}};
-/// use dpp::identity::signer::Signer;
+/// use dash_sdk::platform::transition::signer::AsyncSigner;
 /// use dpp::prelude::{AssetLockProof, PrivateKey};
 /// use dpp::version::PlatformVersion;
 ///
 /// let mut sdk = Sdk::new_mock();
 /// let asset_lock_proof = AssetLockProof::new(/* parameters for the asset lock proof */);
 /// let private_key = PrivateKey::from(/* private key data */);
-/// let signer = /* implementation of Signer trait */;
+/// let signer = /* implementation of AsyncSigner trait */;
 /// let platform_version = PlatformVersion::latest();
 ///
 /// let identity_transition = IdentityCreateTransition::new(/* parameters for the transition */);
-/// let result = identity_transition.broadcast_new_identity(asset_lock_proof, private_key, &signer, &platform_version);
+/// let result = identity_transition.broadcast_new_identity(asset_lock_proof, private_key, &signer, &platform_version).await;
 ///
 /// match result {
 ///     Ok(transport_request) => {
@@ -63,7 +63,8 @@ use crate::error::Error;
 ///
 /// As [BroadcastRequestForNewIdentity] is a trait, it can be implemented for any type that represents
 /// a new identity creation operation, allowing for flexibility in how new identities are broadcasted.
-pub(crate) trait BroadcastRequestForNewIdentity<T: TransportRequest, S: Signer>:
+#[async_trait::async_trait]
+pub(crate) trait BroadcastRequestForNewIdentity<T: TransportRequest, S: AsyncSigner>:
     Send + Debug + Clone
 {
     /// Converts the current instance into an instance of the `TransportRequest` type, ready for broadcasting.
@@ -85,7 +86,7 @@ pub(crate) trait BroadcastRequestForNewIdentity<T: TransportRequest, S: Signer>:
     /// # Error Handling
     /// This method propagates any errors encountered during the signing or conversion process.
     /// These are returned as [`Error`] instances.
-    fn broadcast_request_for_new_identity(
+    async fn broadcast_request_for_new_identity(
         &self,
         asset_lock_proof: AssetLockProof,
         asset_lock_proof_private_key: &PrivateKey,
@@ -94,25 +95,29 @@ pub(crate) trait BroadcastRequestForNewIdentity<T: TransportRequest, S: Signer>:
     ) -> Result<(StateTransition, BroadcastStateTransitionRequest), Error>;
 }
 
-impl<S: Signer> BroadcastRequestForNewIdentity<proto::BroadcastStateTransitionRequest, S>
+#[async_trait::async_trait]
+impl<S: AsyncSigner> BroadcastRequestForNewIdentity<proto::BroadcastStateTransitionRequest, S>
     for Identity
 {
-    fn broadcast_request_for_new_identity(
+    async fn broadcast_request_for_new_identity(
         &self,
         asset_lock_proof: AssetLockProof,
         asset_lock_proof_private_key: &PrivateKey,
         signer: &S,
         platform_version: &PlatformVersion,
     ) -> Result<(StateTransition, BroadcastStateTransitionRequest), Error> {
-        let identity_create_transition = IdentityCreateTransition::try_from_identity_with_signer(
-            self,
-            asset_lock_proof,
-            asset_lock_proof_private_key.inner.as_ref(),
-            signer,
-            &NativeBlsModule,
-            0,
-            platform_version,
-        )?;
+        let identity_create_transition = sign_state_transition(signer, |signer| {
+            Ok(IdentityCreateTransition::try_from_identity_with_signer(
+                self,
+                asset_lock_proof.clone(),
+                asset_lock_proof_private_key.inner.as_ref(),
+                signer,
+                &NativeBlsModule,
+                0,
+                platform_version,
+            )?)
+        })
+        .await?;
         let request = identity_create_transition.broadcast_request_for_state_transition()?;
         Ok((identity_create_transition, request))
     }
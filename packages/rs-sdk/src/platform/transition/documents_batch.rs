@@ -0,0 +1,424 @@
+//! Broadcasting several document transitions of one contract in a single documents batch
+use crate::platform::transition::broadcast_request::BroadcastRequestForStateTransition;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::{Error, Sdk};
+
+use crate::platform::block_info_from_metadata::block_info_from_metadata;
+use crate::platform::transition::put_settings::PutSettings;
+use crate::platform::transition::signer::{sign_state_transition, AsyncSigner};
+use crate::platform::Identifier;
+use dapi_grpc::platform::VersionedGrpcResponse;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::DocumentType;
+use dpp::data_contract::DataContract;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::fee::Credits;
+use dpp::identity::IdentityPublicKey;
+use dpp::state_transition::documents_batch_transition::document_transition::{
+    DocumentCreateTransition, DocumentDeleteTransition, DocumentPurchaseTransition,
+    DocumentReplaceTransition, DocumentTransferTransition, DocumentTransition,
+};
+use dpp::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
+use dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
+use dpp::state_transition::proof_result::StateTransitionProofResult;
+use dpp::state_transition::StateTransition;
+use drive::drive::Drive;
+use rs_dapi_client::{DapiRequest, RequestSettings};
+
+/// A document operation to be included in a documents batch
+#[derive(Debug, Clone)]
+enum DocumentBatchOperation {
+    Create {
+        document: Document,
+        document_type: DocumentType,
+        entropy: [u8; 32],
+    },
+    Replace {
+        document: Document,
+        document_type: DocumentType,
+    },
+    Delete {
+        document: Document,
+        document_type: DocumentType,
+    },
+    Transfer {
+        document: Document,
+        document_type: DocumentType,
+        recipient_id: Identifier,
+    },
+    Purchase {
+        document: Document,
+        document_type: DocumentType,
+        price: Credits,
+    },
+}
+
+impl DocumentBatchOperation {
+    fn document(&self) -> &Document {
+        match self {
+            DocumentBatchOperation::Create { document, .. }
+            | DocumentBatchOperation::Replace { document, .. }
+            | DocumentBatchOperation::Delete { document, .. }
+            | DocumentBatchOperation::Transfer { document, .. }
+            | DocumentBatchOperation::Purchase { document, .. } => document,
+        }
+    }
+
+    fn document_type(&self) -> &DocumentType {
+        match self {
+            DocumentBatchOperation::Create { document_type, .. }
+            | DocumentBatchOperation::Replace { document_type, .. }
+            | DocumentBatchOperation::Delete { document_type, .. }
+            | DocumentBatchOperation::Transfer { document_type, .. }
+            | DocumentBatchOperation::Purchase { document_type, .. } => document_type,
+        }
+    }
+
+    fn into_transition(
+        self,
+        identity_contract_nonce: u64,
+        sdk: &Sdk,
+    ) -> Result<DocumentTransition, Error> {
+        let platform_version = sdk.version();
+
+        let transition = match self {
+            DocumentBatchOperation::Create {
+                document,
+                document_type,
+                entropy,
+            } => DocumentCreateTransition::from_document(
+                document,
+                document_type.as_ref(),
+                entropy,
+                identity_contract_nonce,
+                platform_version,
+                None,
+                None,
+            )?
+            .into(),
+            DocumentBatchOperation::Replace {
+                document,
+                document_type,
+            } => DocumentReplaceTransition::from_document(
+                document,
+                document_type.as_ref(),
+                identity_contract_nonce,
+                platform_version,
+                None,
+                None,
+            )?
+            .into(),
+            DocumentBatchOperation::Delete {
+                document,
+                document_type,
+            } => DocumentDeleteTransition::from_document(
+                document,
+                document_type.as_ref(),
+                identity_contract_nonce,
+                platform_version,
+                None,
+                None,
+            )?
+            .into(),
+            DocumentBatchOperation::Transfer {
+                document,
+                document_type,
+                recipient_id,
+            } => DocumentTransferTransition::from_document(
+                document,
+                document_type.as_ref(),
+                identity_contract_nonce,
+                recipient_id,
+                platform_version,
+                None,
+                None,
+            )?
+            .into(),
+            DocumentBatchOperation::Purchase {
+                document,
+                document_type,
+                price,
+            } => DocumentPurchaseTransition::from_document(
+                document,
+                document_type.as_ref(),
+                price,
+                identity_contract_nonce,
+                platform_version,
+                None,
+                None,
+            )?
+            .into(),
+        };
+
+        Ok(transition)
+    }
+}
+
+/// Builder collecting document operations of one identity on one data contract,
+/// which are then broadcast together as a single, atomic documents batch.
+///
+/// Every document transition gets its own identity contract nonce from the SDK nonce cache
+/// and the whole batch is signed once.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let documents = DocumentsBatchBuilder::new(data_contract, owner_id)
+///     .create_document(profile, profile_type, entropy)
+///     .delete_document(old_post, post_type)
+///     .broadcast_and_wait_for_response(&sdk, identity_public_key, &signer, None)
+///     .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct DocumentsBatchBuilder {
+    data_contract: Arc<DataContract>,
+    owner_id: Identifier,
+    operations: Vec<DocumentBatchOperation>,
+}
+
+impl DocumentsBatchBuilder {
+    /// Create a new, empty batch of operations made by `owner_id` on documents of `data_contract`
+    pub fn new(data_contract: Arc<DataContract>, owner_id: Identifier) -> Self {
+        Self {
+            data_contract,
+            owner_id,
+            operations: vec![],
+        }
+    }
+
+    /// Add the creation of a new document
+    pub fn create_document(
+        mut self,
+        document: Document,
+        document_type: DocumentType,
+        entropy: [u8; 32],
+    ) -> Self {
+        self.operations.push(DocumentBatchOperation::Create {
+            document,
+            document_type,
+            entropy,
+        });
+        self
+    }
+
+    /// Add the replacement of a document, the document must already have its revision incremented
+    pub fn replace_document(mut self, document: Document, document_type: DocumentType) -> Self {
+        self.operations.push(DocumentBatchOperation::Replace {
+            document,
+            document_type,
+        });
+        self
+    }
+
+    /// Add the deletion of a document
+    pub fn delete_document(mut self, document: Document, document_type: DocumentType) -> Self {
+        self.operations.push(DocumentBatchOperation::Delete {
+            document,
+            document_type,
+        });
+        self
+    }
+
+    /// Add the transfer of a document to another identity,
+    /// the document must already have its revision incremented
+    pub fn transfer_document(
+        mut self,
+        document: Document,
+        document_type: DocumentType,
+        recipient_id: Identifier,
+    ) -> Self {
+        self.operations.push(DocumentBatchOperation::Transfer {
+            document,
+            document_type,
+            recipient_id,
+        });
+        self
+    }
+
+    /// Add the purchase of a document for the given price,
+    /// the document must already have its revision incremented
+    pub fn purchase_document(
+        mut self,
+        document: Document,
+        document_type: DocumentType,
+        price: Credits,
+    ) -> Self {
+        self.operations.push(DocumentBatchOperation::Purchase {
+            document,
+            document_type,
+            price,
+        });
+        self
+    }
+
+    /// Number of operations in the batch
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Returns true if there are no operations in the batch
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    fn validate(&self, sdk: &Sdk) -> Result<(), Error> {
+        if self.operations.is_empty() {
+            return Err(Error::Generic(
+                "documents batch must contain at least one operation".to_string(),
+            ));
+        }
+
+        let max_transitions = sdk
+            .version()
+            .system_limits
+            .max_transitions_in_documents_batch;
+
+        if self.operations.len() > max_transitions as usize {
+            return Err(Error::Generic(format!(
+                "documents batch contains {} operations, but at most {} are allowed",
+                self.operations.len(),
+                max_transitions
+            )));
+        }
+
+        let data_contract_id = self.data_contract.id();
+
+        for operation in &self.operations {
+            let document_type = operation.document_type();
+
+            if document_type.data_contract_id() != data_contract_id {
+                return Err(Error::Generic(format!(
+                    "document type {} doesn't belong to data contract {}",
+                    document_type.name(),
+                    data_contract_id
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Builds, signs and broadcasts the documents batch.
+    /// Setting settings to `None` sets default connection behavior
+    pub async fn broadcast<S: AsyncSigner>(
+        &self,
+        sdk: &Sdk,
+        identity_public_key: &IdentityPublicKey,
+        signer: &S,
+        settings: Option<PutSettings>,
+    ) -> Result<StateTransition, Error> {
+        self.validate(sdk)?;
+
+        let mut transitions = Vec::with_capacity(self.operations.len());
+
+        for operation in &self.operations {
+            let identity_contract_nonce = sdk
+                .get_identity_contract_nonce(self.owner_id, self.data_contract.id(), true, settings)
+                .await?;
+
+            transitions.push(
+                operation
+                    .clone()
+                    .into_transition(identity_contract_nonce, sdk)?,
+            );
+        }
+
+        let settings = settings.unwrap_or_default();
+
+        let transition = sign_state_transition(signer, |signer| {
+            Ok(
+                DocumentsBatchTransition::new_documents_batch_transition_from_transitions(
+                    self.owner_id,
+                    transitions.clone(),
+                    identity_public_key,
+                    settings.user_fee_increase.unwrap_or_default(),
+                    signer,
+                    sdk.version(),
+                    None,
+                )?,
+            )
+        })
+        .await?;
+
+        let request = transition.broadcast_request_for_state_transition()?;
+
+        request
+            .clone()
+            .execute(sdk, settings.request_settings)
+            .await?;
+
+        // response is empty for a broadcast, result comes from the stream wait for state transition result
+
+        Ok(transition)
+    }
+
+    /// Waits for the response of a state transition after it has been broadcast and verifies
+    /// the proved state of every document of the batch.
+    ///
+    /// Returns the documents after execution by id, deleted documents are `None`.
+    pub async fn wait_for_response(
+        &self,
+        sdk: &Sdk,
+        state_transition: StateTransition,
+    ) -> Result<BTreeMap<Identifier, Option<Document>>, Error> {
+        let request = state_transition.wait_for_state_transition_result_request()?;
+
+        let response = request.execute(sdk, RequestSettings::default()).await?;
+
+        let block_info = block_info_from_metadata(response.metadata()?)?;
+
+        let proof = response.proof_owned()?;
+
+        let (_, result) = Drive::verify_state_transition_was_executed_with_proof(
+            &state_transition,
+            &block_info,
+            proof.grovedb_proof.as_slice(),
+            &|_| Ok(Some(self.data_contract.clone())),
+            sdk.version(),
+        )?;
+
+        let StateTransitionProofResult::VerifiedDocuments(documents) = result else {
+            return Err(Error::DapiClientError("proved a non document".to_string()));
+        };
+
+        for operation in &self.operations {
+            let document_id = operation.document().id();
+
+            let Some(document) = documents.get(&document_id) else {
+                return Err(Error::InvalidProvedResponse(format!(
+                    "did not prove document {} of the batch",
+                    document_id
+                )));
+            };
+
+            let is_deleted = matches!(operation, DocumentBatchOperation::Delete { .. });
+
+            if document.is_some() == is_deleted {
+                return Err(Error::InvalidProvedResponse(format!(
+                    "proved document {} doesn't match the batch operation",
+                    document_id
+                )));
+            }
+        }
+
+        Ok(documents)
+    }
+
+    /// Broadcasts the documents batch and waits for the proved result
+    pub async fn broadcast_and_wait_for_response<S: AsyncSigner>(
+        &self,
+        sdk: &Sdk,
+        identity_public_key: &IdentityPublicKey,
+        signer: &S,
+        settings: Option<PutSettings>,
+    ) -> Result<BTreeMap<Identifier, Option<Document>>, Error> {
+        let state_transition = self
+            .broadcast(sdk, identity_public_key, signer, settings)
+            .await?;
+
+        self.wait_for_response(sdk, state_transition).await
+    }
+}
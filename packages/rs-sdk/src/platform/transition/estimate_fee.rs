@@ -0,0 +1,134 @@
+//! Estimation of the fees a state transition would be charged, without broadcasting it.
+use crate::{Error, Sdk};
+use ciborium::Value as CborValue;
+use dapi_grpc::platform::v0::estimate_state_transition_fee_request::{
+    self, EstimateStateTransitionFeeRequestV0,
+};
+use dapi_grpc::platform::v0::estimate_state_transition_fee_response::{
+    self, estimate_state_transition_fee_response_v0, EstimateStateTransitionFeeResponseV0,
+};
+use dapi_grpc::platform::v0::{
+    EstimateStateTransitionFeeRequest, EstimateStateTransitionFeeResponse,
+    StateTransitionBroadcastError,
+};
+use dpp::consensus::ConsensusError;
+use dpp::fee::epoch::CreditsPerEpoch;
+use dpp::fee::fee_result::refunds::FeeRefunds;
+use dpp::fee::fee_result::FeeResult;
+use dpp::serialization::{PlatformDeserializable, PlatformSerializable};
+use dpp::state_transition::StateTransition;
+use dpp::ProtocolError;
+use rs_dapi_client::{DapiRequest, RequestSettings};
+
+/// Outcome of executing a state transition against the latest committed state of Platform
+#[derive(Debug, Clone)]
+pub struct StateTransitionFeeEstimate {
+    /// The fees that would be charged, `None` if the state transition would not be paid for
+    pub fee_result: Option<FeeResult>,
+    /// The consensus error the state transition would fail with, if any
+    pub consensus_error: Option<ConsensusError>,
+}
+
+impl StateTransitionFeeEstimate {
+    /// Whether the state transition would be executed successfully
+    pub fn is_valid(&self) -> bool {
+        self.consensus_error.is_none()
+    }
+}
+
+#[async_trait::async_trait]
+pub trait EstimateStateTransitionFee {
+    /// Asks Platform what the state transition would cost if it was broadcast now.
+    ///
+    /// The state transition is executed but never committed, so it can be broadcast afterwards.
+    /// The estimate may differ from the fees that are eventually charged if the state changes
+    /// before the state transition is included in a block.
+    async fn estimate_fee(&self, sdk: &Sdk) -> Result<StateTransitionFeeEstimate, Error>;
+}
+
+#[async_trait::async_trait]
+impl EstimateStateTransitionFee for StateTransition {
+    async fn estimate_fee(&self, sdk: &Sdk) -> Result<StateTransitionFeeEstimate, Error> {
+        let request = EstimateStateTransitionFeeRequest {
+            version: Some(estimate_state_transition_fee_request::Version::V0(
+                EstimateStateTransitionFeeRequestV0 {
+                    state_transition: self.serialize_to_bytes()?,
+                },
+            )),
+        };
+
+        let EstimateStateTransitionFeeResponse {
+            version:
+                Some(estimate_state_transition_fee_response::Version::V0(
+                    EstimateStateTransitionFeeResponseV0 {
+                        fee_result, error, ..
+                    },
+                )),
+        } = request.execute(sdk, RequestSettings::default()).await?
+        else {
+            return Err(Error::DapiClientError(String::from(
+                "missing V0 fee estimation response",
+            )));
+        };
+
+        Ok(StateTransitionFeeEstimate {
+            fee_result: fee_result.map(fee_result_from_proto),
+            consensus_error: error.map(consensus_error_from_proto).transpose()?,
+        })
+    }
+}
+
+fn fee_result_from_proto(
+    fee_result: estimate_state_transition_fee_response_v0::FeeResult,
+) -> FeeResult {
+    let fee_refunds = fee_result
+        .fee_refunds
+        .into_iter()
+        .filter_map(|identity_refunds| {
+            let identity_id: [u8; 32] = identity_refunds.identity_id.try_into().ok()?;
+            let credits_per_epoch: CreditsPerEpoch = identity_refunds
+                .epoch_refunds
+                .into_iter()
+                .map(|epoch_refund| (epoch_refund.epoch as u16, epoch_refund.credits))
+                .collect();
+
+            Some((identity_id, credits_per_epoch))
+        })
+        .collect();
+
+    FeeResult {
+        storage_fee: fee_result.storage_fee,
+        processing_fee: fee_result.processing_fee,
+        fee_refunds: FeeRefunds(fee_refunds),
+        removed_bytes_from_system: fee_result.removed_bytes_from_system,
+    }
+}
+
+/// The error data holds the serialized consensus error under the `serializedError` key
+fn consensus_error_from_proto(
+    error: StateTransitionBroadcastError,
+) -> Result<ConsensusError, Error> {
+    let data: CborValue = ciborium::de::from_reader(error.data.as_slice())
+        .map_err(|e| ProtocolError::InvalidCBOR(e.to_string()))?;
+
+    let serialized_error = data
+        .into_map()
+        .ok()
+        .and_then(|entries| {
+            entries.into_iter().find_map(|(key, value)| {
+                if key.as_text() == Some("serializedError") {
+                    value.into_bytes().ok()
+                } else {
+                    None
+                }
+            })
+        })
+        .ok_or_else(|| {
+            ProtocolError::DecodingError(format!(
+                "fee estimation error {} has no serialized consensus error: {}",
+                error.code, error.message
+            ))
+        })?;
+
+    Ok(ConsensusError::deserialize_from_bytes(&serialized_error)?)
+}
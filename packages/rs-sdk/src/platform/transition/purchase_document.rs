@@ -5,13 +5,13 @@ use crate::{Error, Sdk};
 
 use crate::platform::block_info_from_metadata::block_info_from_metadata;
 use crate::platform::transition::put_settings::PutSettings;
+use crate::platform::transition::signer::{sign_state_transition, AsyncSigner};
 use dapi_grpc::platform::VersionedGrpcResponse;
 use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use dpp::data_contract::document_type::DocumentType;
 use dpp::data_contract::DataContract;
 use dpp::document::{Document, DocumentV0Getters};
 use dpp::fee::Credits;
-use dpp::identity::signer::Signer;
 use dpp::identity::IdentityPublicKey;
 use dpp::prelude::Identifier;
 use dpp::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
@@ -23,7 +23,7 @@ use rs_dapi_client::{DapiRequest, RequestSettings};
 
 #[async_trait::async_trait]
 /// A trait for purchasing a document on Platform
-pub trait PurchaseDocument<S: Signer> {
+pub trait PurchaseDocument<S: AsyncSigner> {
     /// Tries to purchase a document on platform
     /// Setting settings to `None` sets default connection behavior
     async fn purchase_document(
@@ -59,7 +59,7 @@ pub trait PurchaseDocument<S: Signer> {
 }
 
 #[async_trait::async_trait]
-impl<S: Signer> PurchaseDocument<S> for Document {
+impl<S: AsyncSigner> PurchaseDocument<S> for Document {
     async fn purchase_document(
         &self,
         price: Credits,
@@ -81,20 +81,25 @@ impl<S: Signer> PurchaseDocument<S> for Document {
 
         let settings = settings.unwrap_or_default();
 
-        let transition = DocumentsBatchTransition::new_document_purchase_transition_from_document(
-            self.clone(),
-            document_type.as_ref(),
-            purchaser_id,
-            price,
-            &identity_public_key,
-            new_identity_contract_nonce,
-            settings.user_fee_increase.unwrap_or_default(),
-            signer,
-            sdk.version(),
-            None,
-            None,
-            None,
-        )?;
+        let transition = sign_state_transition(signer, |signer| {
+            Ok(
+                DocumentsBatchTransition::new_document_purchase_transition_from_document(
+                    self.clone(),
+                    document_type.as_ref(),
+                    purchaser_id,
+                    price,
+                    &identity_public_key,
+                    new_identity_contract_nonce,
+                    settings.user_fee_increase.unwrap_or_default(),
+                    signer,
+                    sdk.version(),
+                    None,
+                    None,
+                    None,
+                )?,
+            )
+        })
+        .await?;
 
         let request = transition.broadcast_request_for_state_transition()?;
 
@@ -5,11 +5,11 @@ use crate::{Error, Sdk};
 
 use crate::platform::block_info_from_metadata::block_info_from_metadata;
 use crate::platform::transition::put_settings::PutSettings;
+use crate::platform::transition::signer::{sign_state_transition, AsyncSigner};
 use dapi_grpc::platform::VersionedGrpcResponse;
 use dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dpp::data_contract::DataContract;
 use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
-use dpp::identity::signer::Signer;
 use dpp::identity::{IdentityPublicKey, PartialIdentity};
 use dpp::state_transition::data_contract_create_transition::methods::DataContractCreateTransitionMethodsV0;
 use dpp::state_transition::data_contract_create_transition::DataContractCreateTransition;
@@ -22,7 +22,7 @@ use rs_dapi_client::{DapiRequest, RequestSettings};
 
 #[async_trait::async_trait]
 /// A trait for putting a contract to platform
-pub trait PutContract<S: Signer> {
+pub trait PutContract<S: AsyncSigner> {
     /// Puts a document on platform
     /// setting settings to `None` sets default connection behavior
     async fn put_to_platform(
@@ -50,7 +50,7 @@ pub trait PutContract<S: Signer> {
 }
 
 #[async_trait::async_trait]
-impl<S: Signer> PutContract<S> for DataContract {
+impl<S: AsyncSigner> PutContract<S> for DataContract {
     async fn put_to_platform(
         &self,
         sdk: &Sdk,
@@ -71,15 +71,18 @@ impl<S: Signer> PutContract<S> for DataContract {
             revision: None,
             not_found_public_keys: Default::default(),
         };
-        let transition = DataContractCreateTransition::new_from_data_contract(
-            self.clone(),
-            new_identity_nonce,
-            &partial_identity,
-            key_id,
-            signer,
-            sdk.version(),
-            None,
-        )?;
+        let transition = sign_state_transition(signer, |signer| {
+            Ok(DataContractCreateTransition::new_from_data_contract(
+                self.clone(),
+                new_identity_nonce,
+                &partial_identity,
+                key_id,
+                signer,
+                sdk.version(),
+                None,
+            )?)
+        })
+        .await?;
 
         let request = transition.broadcast_request_for_state_transition()?;
 
@@ -5,12 +5,12 @@ use crate::{Error, Sdk};
 
 use crate::platform::block_info_from_metadata::block_info_from_metadata;
 use crate::platform::transition::put_settings::PutSettings;
+use crate::platform::transition::signer::{sign_state_transition, AsyncSigner};
 use dapi_grpc::platform::VersionedGrpcResponse;
 use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use dpp::data_contract::document_type::DocumentType;
 use dpp::data_contract::DataContract;
 use dpp::document::{Document, DocumentV0Getters};
-use dpp::identity::signer::Signer;
 use dpp::identity::IdentityPublicKey;
 use dpp::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
 use dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
@@ -21,7 +21,7 @@ use rs_dapi_client::{DapiRequest, RequestSettings};
 
 #[async_trait::async_trait]
 /// A trait for putting a document to platform
-pub trait PutDocument<S: Signer> {
+pub trait PutDocument<S: AsyncSigner> {
     /// Puts a document on platform
     /// setting settings to `None` sets default connection behavior
     async fn put_to_platform(
@@ -55,7 +55,7 @@ pub trait PutDocument<S: Signer> {
 }
 
 #[async_trait::async_trait]
-impl<S: Signer> PutDocument<S> for Document {
+impl<S: AsyncSigner> PutDocument<S> for Document {
     async fn put_to_platform(
         &self,
         sdk: &Sdk,
@@ -76,19 +76,24 @@ impl<S: Signer> PutDocument<S> for Document {
 
         let settings = settings.unwrap_or_default();
 
-        let transition = DocumentsBatchTransition::new_document_creation_transition_from_document(
-            self.clone(),
-            document_type.as_ref(),
-            document_state_transition_entropy,
-            &identity_public_key,
-            new_identity_contract_nonce,
-            settings.user_fee_increase.unwrap_or_default(),
-            signer,
-            sdk.version(),
-            None,
-            None,
-            None,
-        )?;
+        let transition = sign_state_transition(signer, |signer| {
+            Ok(
+                DocumentsBatchTransition::new_document_creation_transition_from_document(
+                    self.clone(),
+                    document_type.as_ref(),
+                    document_state_transition_entropy,
+                    &identity_public_key,
+                    new_identity_contract_nonce,
+                    settings.user_fee_increase.unwrap_or_default(),
+                    signer,
+                    sdk.version(),
+                    None,
+                    None,
+                    None,
+                )?,
+            )
+        })
+        .await?;
 
         let request = transition.broadcast_request_for_state_transition()?;
 
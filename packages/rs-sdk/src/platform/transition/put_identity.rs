@@ -1,12 +1,12 @@
 use crate::platform::transition::broadcast_identity::BroadcastRequestForNewIdentity;
 use crate::platform::transition::broadcast_request::BroadcastRequestForStateTransition;
+use crate::platform::transition::signer::AsyncSigner;
 use crate::platform::Fetch;
 use crate::{Error, Sdk};
 
 use dapi_grpc::platform::VersionedGrpcResponse;
 use dapi_grpc::tonic::Code;
 use dpp::dashcore::PrivateKey;
-use dpp::identity::signer::Signer;
 use dpp::prelude::{AssetLockProof, Identity};
 use drive_proof_verifier::error::ContextProviderError;
 use drive_proof_verifier::DataContractProvider;
@@ -18,7 +18,7 @@ use rs_dapi_client::{DapiClientError, DapiRequest, RequestSettings};
 
 #[async_trait::async_trait]
 /// A trait for putting an identity to platform
-pub trait PutIdentity<S: Signer> {
+pub trait PutIdentity<S: AsyncSigner> {
     /// Puts an identity on platform
     async fn put_to_platform(
         &self,
@@ -38,7 +38,7 @@ pub trait PutIdentity<S: Signer> {
 }
 
 #[async_trait::async_trait]
-impl<S: Signer> PutIdentity<S> for Identity {
+impl<S: AsyncSigner> PutIdentity<S> for Identity {
     async fn put_to_platform(
         &self,
         sdk: &Sdk,
@@ -46,12 +46,14 @@ impl<S: Signer> PutIdentity<S> for Identity {
         asset_lock_proof_private_key: &PrivateKey,
         signer: &S,
     ) -> Result<(), Error> {
-        let (_, request) = self.broadcast_request_for_new_identity(
-            asset_lock_proof,
-            asset_lock_proof_private_key,
-            signer,
-            sdk.version(),
-        )?;
+        let (_, request) = self
+            .broadcast_request_for_new_identity(
+                asset_lock_proof,
+                asset_lock_proof_private_key,
+                signer,
+                sdk.version(),
+            )
+            .await?;
 
         request
             .clone()
@@ -71,12 +73,14 @@ impl<S: Signer> PutIdentity<S> for Identity {
         signer: &S,
     ) -> Result<Identity, Error> {
         let identity_id = asset_lock_proof.create_identifier()?;
-        let (state_transition, request) = self.broadcast_request_for_new_identity(
-            asset_lock_proof,
-            asset_lock_proof_private_key,
-            signer,
-            sdk.version(),
-        )?;
+        let (state_transition, request) = self
+            .broadcast_request_for_new_identity(
+                asset_lock_proof,
+                asset_lock_proof_private_key,
+                signer,
+                sdk.version(),
+            )
+            .await?;
 
         let response_result = request
             .clone()
@@ -0,0 +1,136 @@
+//! Asynchronous signing of state transitions.
+//!
+//! [Signer](dpp::identity::signer::Signer) used by DPP is synchronous and expects private keys to
+//! be available in-process. [AsyncSigner] allows signatures to be produced elsewhere, like in a
+//! hardware wallet or a separate signing process, and lets the signer inspect the state transition
+//! it is asked to sign.
+//!
+//! Every type implementing [Signer](dpp::identity::signer::Signer) is also an [AsyncSigner].
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::sync::Mutex;
+
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::signer::Signer;
+use dpp::identity::{IdentityPublicKey, KeyID};
+use dpp::platform_value::BinaryData;
+use dpp::state_transition::StateTransition;
+use dpp::ProtocolError;
+
+use crate::Error;
+
+/// Signer producing signatures asynchronously, possibly outside of the current process.
+#[async_trait::async_trait]
+pub trait AsyncSigner: Send + Sync + Debug {
+    /// Sign `data` with the private key matching `identity_public_key`.
+    ///
+    /// `state_transition` is the transition the signature is requested for. Its signatures are
+    /// not set yet; it is only provided so the signer can decide whether to sign it.
+    async fn sign(
+        &self,
+        state_transition: &StateTransition,
+        identity_public_key: &IdentityPublicKey,
+        data: &[u8],
+    ) -> Result<BinaryData, Error>;
+}
+
+#[async_trait::async_trait]
+impl<S: Signer + Send> AsyncSigner for S {
+    async fn sign(
+        &self,
+        _state_transition: &StateTransition,
+        identity_public_key: &IdentityPublicKey,
+        data: &[u8],
+    ) -> Result<BinaryData, Error> {
+        Ok(Signer::sign(self, identity_public_key, data)?)
+    }
+}
+
+/// Synchronous [Signer] used to bridge DPP state transition constructors with an [AsyncSigner].
+///
+/// While collecting, it records every signature request and answers with an empty signature.
+/// Once the signatures are known, it answers with them.
+#[derive(Debug, Default)]
+struct PresignedSigner {
+    requests: Mutex<Vec<(IdentityPublicKey, Vec<u8>)>>,
+    signatures: BTreeMap<(KeyID, Vec<u8>), BinaryData>,
+}
+
+impl Signer for PresignedSigner {
+    fn sign(
+        &self,
+        identity_public_key: &IdentityPublicKey,
+        data: &[u8],
+    ) -> Result<BinaryData, ProtocolError> {
+        if self.signatures.is_empty() {
+            self.requests
+                .lock()
+                .map_err(|_| ProtocolError::Generic("signer lock poisoned".to_string()))?
+                .push((identity_public_key.clone(), data.to_vec()));
+
+            return Ok(BinaryData::default());
+        }
+
+        self.signatures
+            .get(&(identity_public_key.id(), data.to_vec()))
+            .cloned()
+            .ok_or(ProtocolError::Generic(format!(
+                "no signature was requested for key {}",
+                identity_public_key.id()
+            )))
+    }
+}
+
+/// Build a state transition with `build`, signing it with an [AsyncSigner].
+///
+/// `build` is called twice: first to collect the data to sign, then to create the final
+/// state transition with signatures provided by `signer`. It must be deterministic.
+pub(crate) async fn sign_state_transition<A, F>(
+    signer: &A,
+    build: F,
+) -> Result<StateTransition, Error>
+where
+    A: AsyncSigner + ?Sized,
+    F: Fn(&PresignedSignerHandle) -> Result<StateTransition, Error> + Send + Sync,
+{
+    let collector = PresignedSigner::default();
+    let unsigned_state_transition = build(&PresignedSignerHandle(&collector))?;
+
+    let requests = collector
+        .requests
+        .into_inner()
+        .map_err(|_| Error::Generic("signer lock poisoned".to_string()))?;
+
+    let mut signatures = BTreeMap::new();
+    for (identity_public_key, data) in requests {
+        let signature = signer
+            .sign(&unsigned_state_transition, &identity_public_key, &data)
+            .await?;
+        signatures.insert((identity_public_key.id(), data), signature);
+    }
+
+    if signatures.is_empty() {
+        return Ok(unsigned_state_transition);
+    }
+
+    let presigned = PresignedSigner {
+        requests: Mutex::default(),
+        signatures,
+    };
+
+    build(&PresignedSignerHandle(&presigned))
+}
+
+/// [Signer] passed to the state transition builder of [sign_state_transition].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PresignedSignerHandle<'a>(&'a PresignedSigner);
+
+impl Signer for PresignedSignerHandle<'_> {
+    fn sign(
+        &self,
+        identity_public_key: &IdentityPublicKey,
+        data: &[u8],
+    ) -> Result<BinaryData, ProtocolError> {
+        self.0.sign(identity_public_key, data)
+    }
+}
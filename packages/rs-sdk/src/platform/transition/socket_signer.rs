@@ -0,0 +1,197 @@
+//! Reference [AsyncSigner] talking to an out-of-process signer over a local Unix socket.
+//!
+//! Private keys stay in the signer process. For every signature, [SocketSigner] opens a connection
+//! to the socket, sends a [SignRequest] and reads back a [SignResponse]. Messages are
+//! bincode-encoded and prefixed with their length as a big-endian `u32`.
+//!
+//! Before contacting the signer process, the state transition is checked by the signing policy
+//! of the [SocketSigner]; signing is refused unless the policy approves it.
+use std::fmt::{Debug, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use bincode::{Decode, Encode};
+use dpp::identity::IdentityPublicKey;
+use dpp::platform_value::BinaryData;
+use dpp::serialization::PlatformSerializable;
+use dpp::state_transition::StateTransition;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::UnixStream;
+
+use super::signer::AsyncSigner;
+use crate::Error;
+
+/// Maximum size of a message exchanged with the signer process
+pub const MAX_SIGNER_MESSAGE_SIZE: u32 = 1024 * 1024;
+
+/// Signature request sent to the signer process
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct SignRequest {
+    /// Key to sign with, the signer process looks up the private key with it
+    pub identity_public_key: IdentityPublicKey,
+    /// Serialized state transition the signature is requested for
+    pub state_transition: Vec<u8>,
+    /// Data to sign
+    pub data: Vec<u8>,
+}
+
+/// Response of the signer process to a [SignRequest]
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub enum SignResponse {
+    /// Signature of the requested data
+    Signature(Vec<u8>),
+    /// The signer process refused to sign, with a reason
+    Refused(String),
+}
+
+/// Decides whether a state transition may be signed with the given key
+pub type SigningPolicy = dyn Fn(&StateTransition, &IdentityPublicKey) -> bool + Send + Sync;
+
+/// [AsyncSigner] delegating signatures to a signer process listening on a Unix socket.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let signer = SocketSigner::new("/run/dash/signer.sock", |state_transition, _| {
+///     matches!(state_transition, StateTransition::DocumentsBatch(_))
+/// });
+///
+/// document
+///     .put_to_platform_and_wait_for_response(&sdk, document_type, entropy, key, contract, &signer)
+///     .await?;
+/// ```
+#[derive(Clone)]
+pub struct SocketSigner {
+    socket_path: PathBuf,
+    policy: Arc<SigningPolicy>,
+}
+
+impl SocketSigner {
+    /// Create a signer connecting to `socket_path`, signing only state transitions approved
+    /// by `policy`
+    pub fn new<P, F>(socket_path: P, policy: F) -> Self
+    where
+        P: Into<PathBuf>,
+        F: Fn(&StateTransition, &IdentityPublicKey) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            socket_path: socket_path.into(),
+            policy: Arc::new(policy),
+        }
+    }
+
+    /// Path of the signer process socket
+    pub fn socket_path(&self) -> &Path {
+        &self.socket_path
+    }
+}
+
+impl Debug for SocketSigner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SocketSigner")
+            .field("socket_path", &self.socket_path)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSigner for SocketSigner {
+    async fn sign(
+        &self,
+        state_transition: &StateTransition,
+        identity_public_key: &IdentityPublicKey,
+        data: &[u8],
+    ) -> Result<BinaryData, Error> {
+        if !(self.policy)(state_transition, identity_public_key) {
+            return Err(Error::SigningRefused(format!(
+                "signing policy rejected {} state transition",
+                state_transition.name()
+            )));
+        }
+
+        let request = SignRequest {
+            identity_public_key: identity_public_key.clone(),
+            state_transition: state_transition.serialize_to_bytes()?,
+            data: data.to_vec(),
+        };
+
+        let mut stream = UnixStream::connect(&self.socket_path).await.map_err(|e| {
+            Error::Generic(format!(
+                "unable to connect to signer at {}: {}",
+                self.socket_path.display(),
+                e
+            ))
+        })?;
+
+        write_message(&mut stream, &request).await?;
+        let response: SignResponse = read_message(&mut stream).await?;
+
+        match response {
+            SignResponse::Signature(signature) => Ok(signature.into()),
+            SignResponse::Refused(reason) => Err(Error::SigningRefused(reason)),
+        }
+    }
+}
+
+/// Write a length-prefixed, bincode-encoded message
+pub async fn write_message<W, T>(writer: &mut W, message: &T) -> Result<(), Error>
+where
+    W: AsyncWrite + Unpin + Send,
+    T: Encode + Sync,
+{
+    let bytes = bincode::encode_to_vec(message, bincode::config::standard())
+        .map_err(|e| Error::Generic(format!("unable to encode signer message: {}", e)))?;
+
+    let length = u32::try_from(bytes.len())
+        .ok()
+        .filter(|length| *length <= MAX_SIGNER_MESSAGE_SIZE)
+        .ok_or(Error::Generic(format!(
+            "signer message of {} bytes is too large",
+            bytes.len()
+        )))?;
+
+    writer
+        .write_all(&length.to_be_bytes())
+        .await
+        .map_err(|e| Error::Generic(format!("unable to write signer message: {}", e)))?;
+    writer
+        .write_all(&bytes)
+        .await
+        .map_err(|e| Error::Generic(format!("unable to write signer message: {}", e)))?;
+    writer
+        .flush()
+        .await
+        .map_err(|e| Error::Generic(format!("unable to write signer message: {}", e)))
+}
+
+/// Read a length-prefixed, bincode-encoded message
+pub async fn read_message<R, T>(reader: &mut R) -> Result<T, Error>
+where
+    R: AsyncRead + Unpin + Send,
+    T: Decode,
+{
+    let mut length = [0u8; 4];
+    reader
+        .read_exact(&mut length)
+        .await
+        .map_err(|e| Error::Generic(format!("unable to read signer message: {}", e)))?;
+
+    let length = u32::from_be_bytes(length);
+    if length > MAX_SIGNER_MESSAGE_SIZE {
+        return Err(Error::Generic(format!(
+            "signer message of {} bytes is too large",
+            length
+        )));
+    }
+
+    let mut bytes = vec![0u8; length as usize];
+    reader
+        .read_exact(&mut bytes)
+        .await
+        .map_err(|e| Error::Generic(format!("unable to read signer message: {}", e)))?;
+
+    let (message, _) = bincode::decode_from_slice(&bytes, bincode::config::standard())
+        .map_err(|e| Error::Generic(format!("unable to decode signer message: {}", e)))?;
+
+    Ok(message)
+}
@@ -0,0 +1,105 @@
+//! Collecting signatures for state transitions signed with a threshold key.
+//!
+//! A key with a signature threshold only signs state transitions together with other keys of
+//! the same identity. All of them sign the same signable bytes, so the signatures can be
+//! gathered offline, one holder at a time, and combined with [ThresholdSignatureCollector].
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::identity_public_key::accessors::v1::IdentityPublicKeyGettersV1;
+use dpp::identity::{IdentityPublicKey, KeyID};
+use dpp::platform_value::BinaryData;
+use dpp::serialization::Signable;
+use dpp::state_transition::threshold_signature::ThresholdSignature;
+use dpp::state_transition::StateTransition;
+
+use super::signer::AsyncSigner;
+use crate::Error;
+
+/// Gathers the signatures of a threshold key and its co-signers for one state transition.
+#[derive(Debug, Clone)]
+pub struct ThresholdSignatureCollector {
+    state_transition: StateTransition,
+    signing_key: IdentityPublicKey,
+    signature_threshold: u8,
+    threshold_signature: ThresholdSignature,
+}
+
+impl ThresholdSignatureCollector {
+    /// Start collecting signatures for `state_transition`, which will reference `signing_key`.
+    ///
+    /// `signing_key` must have a signature threshold.
+    pub fn new(
+        state_transition: StateTransition,
+        signing_key: IdentityPublicKey,
+    ) -> Result<Self, Error> {
+        let signature_threshold = signing_key.signature_threshold().ok_or_else(|| {
+            Error::Generic(format!(
+                "key {} has no signature threshold",
+                signing_key.id()
+            ))
+        })?;
+
+        Ok(Self {
+            state_transition,
+            signing_key,
+            signature_threshold,
+            threshold_signature: ThresholdSignature::default(),
+        })
+    }
+
+    /// Bytes every signer must sign; share them with co-signers that sign offline.
+    pub fn signable_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.state_transition.signable_bytes()?)
+    }
+
+    /// Add a signature produced elsewhere by the key `key_id`.
+    pub fn add_signature(&mut self, key_id: KeyID, signature: BinaryData) {
+        self.threshold_signature.insert_signature(key_id, signature);
+    }
+
+    /// Sign with `identity_public_key` using `signer`.
+    pub async fn sign_with<S: AsyncSigner + ?Sized>(
+        &mut self,
+        identity_public_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<(), Error> {
+        let data = self.signable_bytes()?;
+        let signature = signer
+            .sign(&self.state_transition, identity_public_key, &data)
+            .await?;
+        self.add_signature(identity_public_key.id(), signature);
+        Ok(())
+    }
+
+    /// Amount of keys that signed so far.
+    pub fn signer_count(&self) -> usize {
+        self.threshold_signature.signer_count()
+    }
+
+    /// Whether the threshold key and enough co-signers signed.
+    pub fn is_complete(&self) -> bool {
+        self.threshold_signature
+            .signature_for(self.signing_key.id())
+            .is_some()
+            && self.signer_count() >= self.signature_threshold as usize
+    }
+
+    /// Set the collected signatures on the state transition.
+    ///
+    /// Platform only counts signatures of keys that could sign the state transition on their
+    /// own, so a complete collection can still be rejected.
+    pub fn finish(mut self) -> Result<StateTransition, Error> {
+        if !self.is_complete() {
+            return Err(Error::Generic(format!(
+                "key {} requires {} signatures including its own, {} collected",
+                self.signing_key.id(),
+                self.signature_threshold,
+                self.signer_count()
+            )));
+        }
+
+        self.state_transition
+            .set_threshold_signature(self.signing_key.id(), &self.threshold_signature)?;
+
+        Ok(self.state_transition)
+    }
+}
@@ -0,0 +1,107 @@
+use crate::platform::transition::signer::{sign_state_transition, AsyncSigner};
+use dapi_grpc::platform::VersionedGrpcResponse;
+use dpp::identity::accessors::IdentityGettersV0;
+use dpp::identity::{Identity, IdentityPublicKey};
+
+use crate::platform::block_info_from_metadata::block_info_from_metadata;
+use crate::platform::transition::broadcast_request::BroadcastRequestForStateTransition;
+use crate::platform::transition::put_settings::PutSettings;
+use crate::platform::Identifier;
+use crate::{Error, Sdk};
+use dpp::state_transition::identity_credit_transfer_transition::methods::IdentityCreditTransferTransitionMethodsV0;
+use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+use dpp::state_transition::proof_result::StateTransitionProofResult;
+use drive::drive::Drive;
+use drive_proof_verifier::error::ContextProviderError;
+use drive_proof_verifier::DataContractProvider;
+use rs_dapi_client::{DapiRequest, RequestSettings};
+
+#[async_trait::async_trait]
+pub trait TransferToIdentity {
+    /// Function to transfer credits from an identity to another identity.
+    /// Returns the final balances of the sender and the recipient.
+    ///
+    /// If `signing_transfer_key_to_use` is not set, the first transfer key of the identity is used.
+    async fn transfer_credits<S: AsyncSigner>(
+        &self,
+        sdk: &Sdk,
+        to_identity_id: Identifier,
+        amount: u64,
+        signing_transfer_key_to_use: Option<&IdentityPublicKey>,
+        signer: S,
+        settings: Option<PutSettings>,
+    ) -> Result<(u64, u64), Error>;
+}
+
+#[async_trait::async_trait]
+impl TransferToIdentity for Identity {
+    async fn transfer_credits<S: AsyncSigner>(
+        &self,
+        sdk: &Sdk,
+        to_identity_id: Identifier,
+        amount: u64,
+        signing_transfer_key_to_use: Option<&IdentityPublicKey>,
+        signer: S,
+        settings: Option<PutSettings>,
+    ) -> Result<(u64, u64), Error> {
+        let new_identity_nonce = sdk.get_identity_nonce(self.id(), true, settings).await?;
+        let settings = settings.unwrap_or_default();
+        let state_transition = sign_state_transition(&signer, |signer| {
+            Ok(IdentityCreditTransferTransition::try_from_identity(
+                self,
+                to_identity_id,
+                amount,
+                settings.user_fee_increase.unwrap_or_default(),
+                *signer,
+                signing_transfer_key_to_use,
+                new_identity_nonce,
+                sdk.version(),
+                None,
+            )?)
+        })
+        .await?;
+
+        let request = state_transition.broadcast_request_for_state_transition()?;
+
+        request
+            .clone()
+            .execute(sdk, settings.request_settings)
+            .await?;
+
+        let request = state_transition.wait_for_state_transition_result_request()?;
+
+        let response = request.execute(sdk, RequestSettings::default()).await?;
+
+        let block_info = block_info_from_metadata(response.metadata()?)?;
+
+        let proof = response.proof_owned()?;
+        let context_provider =
+            sdk.context_provider()
+                .ok_or(Error::from(ContextProviderError::Config(
+                    "Context provider not initialized".to_string(),
+                )))?;
+
+        let (_, result) = Drive::verify_state_transition_was_executed_with_proof(
+            &state_transition,
+            &block_info,
+            proof.grovedb_proof.as_slice(),
+            &context_provider.as_contract_lookup_fn(),
+            sdk.version(),
+        )?;
+
+        match result {
+            StateTransitionProofResult::VerifiedBalanceTransfer(sender, recipient) => {
+                let sender_balance = sender.balance.ok_or(Error::DapiClientError(
+                    "expected a sender identity balance".to_string(),
+                ))?;
+                let recipient_balance = recipient.balance.ok_or(Error::DapiClientError(
+                    "expected a recipient identity balance".to_string(),
+                ))?;
+                Ok((sender_balance, recipient_balance))
+            }
+            _ => Err(Error::DapiClientError(
+                "proved a non balance transfer".to_string(),
+            )),
+        }
+    }
+}
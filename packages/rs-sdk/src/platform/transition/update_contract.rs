@@ -0,0 +1,149 @@
+use crate::platform::transition::broadcast_request::BroadcastRequestForStateTransition;
+use std::collections::BTreeMap;
+
+use crate::{Error, Sdk};
+
+use crate::platform::block_info_from_metadata::block_info_from_metadata;
+use crate::platform::transition::put_settings::PutSettings;
+use crate::platform::transition::signer::{sign_state_transition, AsyncSigner};
+use dapi_grpc::platform::VersionedGrpcResponse;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::DataContract;
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::{IdentityPublicKey, PartialIdentity};
+use dpp::state_transition::data_contract_update_transition::methods::DataContractUpdateTransitionMethodsV0;
+use dpp::state_transition::data_contract_update_transition::DataContractUpdateTransition;
+use dpp::state_transition::proof_result::StateTransitionProofResult;
+use dpp::state_transition::StateTransition;
+use drive::drive::Drive;
+use drive_proof_verifier::error::ContextProviderError;
+use drive_proof_verifier::DataContractProvider;
+use rs_dapi_client::{DapiRequest, RequestSettings};
+
+#[async_trait::async_trait]
+/// A trait for updating a contract on platform
+pub trait UpdateContract<S: AsyncSigner> {
+    /// Updates a contract on platform, the contract must already have its version incremented
+    /// setting settings to `None` sets default connection behavior
+    async fn update_on_platform(
+        &self,
+        sdk: &Sdk,
+        identity_public_key: IdentityPublicKey,
+        signer: &S,
+        settings: Option<PutSettings>,
+    ) -> Result<StateTransition, Error>;
+
+    /// Waits for the response of a state transition after it has been broadcast
+    async fn wait_for_response(
+        &self,
+        sdk: &Sdk,
+        state_transition: StateTransition,
+    ) -> Result<DataContract, Error>;
+
+    /// Updates a contract on platform and waits for the confirmation proof
+    async fn update_on_platform_and_wait_for_response(
+        &self,
+        sdk: &Sdk,
+        identity_public_key: IdentityPublicKey,
+        signer: &S,
+    ) -> Result<DataContract, Error>;
+}
+
+#[async_trait::async_trait]
+impl<S: AsyncSigner> UpdateContract<S> for DataContract {
+    async fn update_on_platform(
+        &self,
+        sdk: &Sdk,
+        identity_public_key: IdentityPublicKey,
+        signer: &S,
+        settings: Option<PutSettings>,
+    ) -> Result<StateTransition, Error> {
+        let new_identity_contract_nonce = sdk
+            .get_identity_contract_nonce(self.owner_id(), self.id(), true, settings)
+            .await?;
+
+        let settings = settings.unwrap_or_default();
+
+        let key_id = identity_public_key.id();
+
+        let partial_identity = PartialIdentity {
+            id: self.owner_id(),
+            loaded_public_keys: BTreeMap::from([(key_id, identity_public_key)]),
+            balance: None,
+            revision: None,
+            not_found_public_keys: Default::default(),
+        };
+        let transition = sign_state_transition(signer, |signer| {
+            Ok(DataContractUpdateTransition::new_from_data_contract(
+                self.clone(),
+                &partial_identity,
+                key_id,
+                new_identity_contract_nonce,
+                settings.user_fee_increase.unwrap_or_default(),
+                signer,
+                sdk.version(),
+                None,
+            )?)
+        })
+        .await?;
+
+        let request = transition.broadcast_request_for_state_transition()?;
+
+        request
+            .clone()
+            .execute(sdk, settings.request_settings)
+            .await?;
+
+        // response is empty for a broadcast, result comes from the stream wait for state transition result
+
+        Ok(transition)
+    }
+
+    async fn wait_for_response(
+        &self,
+        sdk: &Sdk,
+        state_transition: StateTransition,
+    ) -> Result<DataContract, Error> {
+        let request = state_transition.wait_for_state_transition_result_request()?;
+
+        let response = request.execute(sdk, RequestSettings::default()).await?;
+
+        let block_info = block_info_from_metadata(response.metadata()?)?;
+
+        let proof = response.proof_owned()?;
+        let context_provider =
+            sdk.context_provider()
+                .ok_or(Error::from(ContextProviderError::Config(
+                    "Context provider not initialized".to_string(),
+                )))?;
+
+        let (_, result) = Drive::verify_state_transition_was_executed_with_proof(
+            &state_transition,
+            &block_info,
+            proof.grovedb_proof.as_slice(),
+            &context_provider.as_contract_lookup_fn(),
+            sdk.version(),
+        )?;
+
+        match result {
+            StateTransitionProofResult::VerifiedDataContract(data_contract) => Ok(data_contract),
+            _ => Err(Error::DapiClientError("proved a non contract".to_string())),
+        }
+    }
+
+    async fn update_on_platform_and_wait_for_response(
+        &self,
+        sdk: &Sdk,
+        identity_public_key: IdentityPublicKey,
+        signer: &S,
+    ) -> Result<DataContract, Error> {
+        let state_transition = self
+            .update_on_platform(sdk, identity_public_key, signer, None)
+            .await?;
+
+        let data_contract =
+            <Self as UpdateContract<S>>::wait_for_response(self, sdk, state_transition).await?;
+
+        Ok(data_contract)
+    }
+}
@@ -0,0 +1,102 @@
+use crate::platform::transition::signer::{sign_state_transition, AsyncSigner};
+use dapi_grpc::platform::VersionedGrpcResponse;
+use dpp::identity::accessors::{IdentityGettersV0, IdentitySettersV0};
+use dpp::identity::{Identity, IdentityPublicKey, KeyID, PartialIdentity};
+
+use crate::platform::block_info_from_metadata::block_info_from_metadata;
+use crate::platform::transition::broadcast_request::BroadcastRequestForStateTransition;
+use crate::platform::transition::put_settings::PutSettings;
+use crate::{Error, Sdk};
+use dpp::state_transition::identity_update_transition::methods::IdentityUpdateTransitionMethodsV0;
+use dpp::state_transition::identity_update_transition::IdentityUpdateTransition;
+use dpp::state_transition::proof_result::StateTransitionProofResult;
+use drive::drive::Drive;
+use drive_proof_verifier::error::ContextProviderError;
+use drive_proof_verifier::DataContractProvider;
+use rs_dapi_client::{DapiRequest, RequestSettings};
+
+#[async_trait::async_trait]
+pub trait UpdateIdentity {
+    /// Function to add new keys to an identity and/or disable existing ones.
+    /// Returns the identity with all its keys as proved after the update.
+    ///
+    /// The identity must be in its current state on platform, the revision is bumped automatically.
+    /// Keys to add must be signable by the signer, the transition is signed with the master key
+    /// `master_public_key_id`.
+    async fn update_identity<S: AsyncSigner>(
+        &self,
+        sdk: &Sdk,
+        master_public_key_id: KeyID,
+        add_public_keys: Vec<IdentityPublicKey>,
+        disable_public_keys: Vec<KeyID>,
+        signer: S,
+        settings: Option<PutSettings>,
+    ) -> Result<PartialIdentity, Error>;
+}
+
+#[async_trait::async_trait]
+impl UpdateIdentity for Identity {
+    async fn update_identity<S: AsyncSigner>(
+        &self,
+        sdk: &Sdk,
+        master_public_key_id: KeyID,
+        add_public_keys: Vec<IdentityPublicKey>,
+        disable_public_keys: Vec<KeyID>,
+        signer: S,
+        settings: Option<PutSettings>,
+    ) -> Result<PartialIdentity, Error> {
+        let new_identity_nonce = sdk.get_identity_nonce(self.id(), true, settings).await?;
+        let settings = settings.unwrap_or_default();
+
+        let mut identity = self.clone();
+        identity.bump_revision();
+
+        let state_transition = sign_state_transition(&signer, |signer| {
+            Ok(IdentityUpdateTransition::try_from_identity_with_signer(
+                &identity,
+                &master_public_key_id,
+                add_public_keys.clone(),
+                disable_public_keys.clone(),
+                new_identity_nonce,
+                settings.user_fee_increase.unwrap_or_default(),
+                signer,
+                sdk.version(),
+                None,
+            )?)
+        })
+        .await?;
+
+        let request = state_transition.broadcast_request_for_state_transition()?;
+
+        request
+            .clone()
+            .execute(sdk, settings.request_settings)
+            .await?;
+
+        let request = state_transition.wait_for_state_transition_result_request()?;
+
+        let response = request.execute(sdk, RequestSettings::default()).await?;
+
+        let block_info = block_info_from_metadata(response.metadata()?)?;
+
+        let proof = response.proof_owned()?;
+        let context_provider =
+            sdk.context_provider()
+                .ok_or(Error::from(ContextProviderError::Config(
+                    "Context provider not initialized".to_string(),
+                )))?;
+
+        let (_, result) = Drive::verify_state_transition_was_executed_with_proof(
+            &state_transition,
+            &block_info,
+            proof.grovedb_proof.as_slice(),
+            &context_provider.as_contract_lookup_fn(),
+            sdk.version(),
+        )?;
+
+        match result {
+            StateTransitionProofResult::VerifiedPartialIdentity(identity) => Ok(identity),
+            _ => Err(Error::DapiClientError("proved a non identity".to_string())),
+        }
+    }
+}
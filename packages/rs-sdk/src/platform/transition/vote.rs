@@ -2,18 +2,17 @@ use crate::platform::block_info_from_metadata::block_info_from_metadata;
 use crate::platform::query::VoteQuery;
 use crate::platform::transition::broadcast_request::BroadcastRequestForStateTransition;
 use crate::platform::transition::put_settings::PutSettings;
+use crate::platform::transition::signer::{sign_state_transition, AsyncSigner};
 use crate::platform::Fetch;
 use crate::{Error, Sdk};
 use dapi_grpc::platform::VersionedGrpcResponse;
 use dpp::identifier::MasternodeIdentifiers;
 use dpp::identity::hash::IdentityPublicKeyHashMethodsV0;
-use dpp::identity::signer::Signer;
 use dpp::identity::IdentityPublicKey;
 use dpp::prelude::Identifier;
 use dpp::state_transition::masternode_vote_transition::methods::MasternodeVoteTransitionMethodsV0;
 use dpp::state_transition::masternode_vote_transition::MasternodeVoteTransition;
 use dpp::state_transition::proof_result::StateTransitionProofResult;
-use dpp::voting::votes::resource_vote::accessors::v0::ResourceVoteGettersV0;
 use dpp::voting::votes::Vote;
 use drive::drive::Drive;
 use drive_proof_verifier::{error::ContextProviderError, DataContractProvider};
@@ -21,7 +20,7 @@ use rs_dapi_client::DapiRequest;
 
 #[async_trait::async_trait]
 /// A trait for putting a vote on platform
-pub trait PutVote<S: Signer> {
+pub trait PutVote<S: AsyncSigner> {
     /// Puts an identity on platform
     async fn put_to_platform(
         &self,
@@ -43,7 +42,7 @@ pub trait PutVote<S: Signer> {
 }
 
 #[async_trait::async_trait]
-impl<S: Signer> PutVote<S> for Vote {
+impl<S: AsyncSigner> PutVote<S> for Vote {
     async fn put_to_platform(
         &self,
         voter_pro_tx_hash: Identifier,
@@ -60,15 +59,18 @@ impl<S: Signer> PutVote<S> for Vote {
 
         let settings = settings.unwrap_or_default();
 
-        let masternode_vote_transition = MasternodeVoteTransition::try_from_vote_with_signer(
-            self.clone(),
-            signer,
-            voter_pro_tx_hash,
-            voting_public_key,
-            new_masternode_voting_nonce,
-            sdk.version(),
-            None,
-        )?;
+        let masternode_vote_transition = sign_state_transition(signer, |signer| {
+            Ok(MasternodeVoteTransition::try_from_vote_with_signer(
+                self.clone(),
+                signer,
+                voter_pro_tx_hash,
+                voting_public_key,
+                new_masternode_voting_nonce,
+                sdk.version(),
+                None,
+            )?)
+        })
+        .await?;
         let request = masternode_vote_transition.broadcast_request_for_state_transition()?;
 
         request.execute(sdk, settings.request_settings).await?;
@@ -92,18 +94,20 @@ impl<S: Signer> PutVote<S> for Vote {
 
         let settings = settings.unwrap_or_default();
 
-        let Vote::ResourceVote(resource_vote) = self;
-        let vote_poll_id = resource_vote.vote_poll().unique_id()?;
-
-        let masternode_vote_transition = MasternodeVoteTransition::try_from_vote_with_signer(
-            self.clone(),
-            signer,
-            voter_pro_tx_hash,
-            voting_public_key,
-            new_masternode_voting_nonce,
-            sdk.version(),
-            None,
-        )?;
+        let vote_poll_id = self.vote_poll_unique_id()?;
+
+        let masternode_vote_transition = sign_state_transition(signer, |signer| {
+            Ok(MasternodeVoteTransition::try_from_vote_with_signer(
+                self.clone(),
+                signer,
+                voter_pro_tx_hash,
+                voting_public_key,
+                new_masternode_voting_nonce,
+                sdk.version(),
+                None,
+            )?)
+        })
+        .await?;
         let request = masternode_vote_transition.broadcast_request_for_state_transition()?;
 
         let response_result = request.execute(sdk, settings.request_settings).await;
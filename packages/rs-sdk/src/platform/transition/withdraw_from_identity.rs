@@ -2,8 +2,8 @@ use dapi_grpc::platform::VersionedGrpcResponse;
 use dpp::dashcore::Address;
 use dpp::identity::accessors::IdentityGettersV0;
 
+use crate::platform::transition::signer::{sign_state_transition, AsyncSigner};
 use dpp::identity::core_script::CoreScript;
-use dpp::identity::signer::Signer;
 use dpp::identity::Identity;
 use dpp::prelude::UserFeeIncrease;
 
@@ -24,7 +24,7 @@ use rs_dapi_client::{DapiRequest, RequestSettings};
 #[async_trait::async_trait]
 pub trait WithdrawFromIdentity {
     /// Function to withdraw credits from an identity. Returns the final identity balance.
-    async fn withdraw<S: Signer + Send>(
+    async fn withdraw<S: AsyncSigner>(
         &self,
         sdk: &Sdk,
         address: Address,
@@ -38,7 +38,7 @@ pub trait WithdrawFromIdentity {
 
 #[async_trait::async_trait]
 impl WithdrawFromIdentity for Identity {
-    async fn withdraw<S: Signer + Send>(
+    async fn withdraw<S: AsyncSigner>(
         &self,
         sdk: &Sdk,
         address: Address,
@@ -49,19 +49,22 @@ impl WithdrawFromIdentity for Identity {
         settings: Option<PutSettings>,
     ) -> Result<u64, Error> {
         let new_identity_nonce = sdk.get_identity_nonce(self.id(), true, settings).await?;
-        let state_transition = IdentityCreditWithdrawalTransition::try_from_identity(
-            self,
-            None,
-            CoreScript::new(address.script_pubkey()),
-            amount,
-            Pooling::Never,
-            core_fee_per_byte.unwrap_or(1),
-            user_fee_increase.unwrap_or_default(),
-            signer,
-            new_identity_nonce,
-            sdk.version(),
-            None,
-        )?;
+        let state_transition = sign_state_transition(&signer, |signer| {
+            Ok(IdentityCreditWithdrawalTransition::try_from_identity(
+                self,
+                None,
+                CoreScript::new(address.script_pubkey()),
+                amount,
+                Pooling::Never,
+                core_fee_per_byte.unwrap_or(1),
+                user_fee_increase.unwrap_or_default(),
+                *signer,
+                new_identity_nonce,
+                sdk.version(),
+                None,
+            )?)
+        })
+        .await?;
 
         let request = state_transition.broadcast_request_for_state_transition()?;
 
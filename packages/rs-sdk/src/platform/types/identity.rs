@@ -2,7 +2,7 @@
 
 use crate::delegate_enum;
 use crate::{
-    platform::{proto, Query},
+    platform::{proto, LimitQuery, Query},
     Error,
 };
 use dapi_grpc::platform::v0::get_identity_balance_and_revision_request::GetIdentityBalanceAndRevisionRequestV0;
@@ -10,13 +10,17 @@ use dapi_grpc::platform::v0::get_identity_balance_request::GetIdentityBalanceReq
 use dapi_grpc::platform::v0::get_identity_by_public_key_hash_request::GetIdentityByPublicKeyHashRequestV0;
 use dapi_grpc::platform::v0::get_identity_contract_nonce_request::GetIdentityContractNonceRequestV0;
 use dapi_grpc::platform::v0::get_identity_nonce_request::GetIdentityNonceRequestV0;
+use dapi_grpc::platform::v0::get_identity_pending_recovery_request::GetIdentityPendingRecoveryRequestV0;
 use dapi_grpc::platform::v0::get_identity_request::GetIdentityRequestV0;
+use dapi_grpc::platform::v0::get_identity_withdrawals_request::GetIdentityWithdrawalsRequestV0;
 use dapi_grpc::platform::v0::{
     get_identity_balance_and_revision_request, get_identity_balance_request,
     get_identity_by_public_key_hash_request, get_identity_contract_nonce_request,
-    get_identity_nonce_request, get_identity_request, GetIdentityBalanceAndRevisionRequest,
+    get_identity_nonce_request, get_identity_pending_recovery_request, get_identity_request,
+    get_identity_withdrawals_request, GetIdentityBalanceAndRevisionRequest,
     GetIdentityBalanceRequest, GetIdentityByPublicKeyHashRequest, GetIdentityContractNonceRequest,
-    GetIdentityNonceRequest, GetIdentityRequest, ResponseMetadata,
+    GetIdentityNonceRequest, GetIdentityPendingRecoveryRequest, GetIdentityRequest,
+    GetIdentityWithdrawalsRequest, ResponseMetadata,
 };
 use dpp::prelude::Identity;
 
@@ -129,6 +133,63 @@ impl Query<GetIdentityContractNonceRequest>
     }
 }
 
+impl Query<GetIdentityPendingRecoveryRequest> for dpp::prelude::Identifier {
+    fn query(self, prove: bool) -> Result<GetIdentityPendingRecoveryRequest, Error> {
+        if !prove {
+            unimplemented!("queries without proofs are not supported yet");
+        }
+
+        let request: GetIdentityPendingRecoveryRequest = GetIdentityPendingRecoveryRequest {
+            version: Some(get_identity_pending_recovery_request::Version::V0(
+                GetIdentityPendingRecoveryRequestV0 {
+                    identity_id: self.to_vec(),
+                    prove,
+                },
+            )),
+        };
+
+        Ok(request)
+    }
+}
+
+impl Query<GetIdentityWithdrawalsRequest> for LimitQuery<dpp::prelude::Identifier> {
+    fn query(self, prove: bool) -> Result<GetIdentityWithdrawalsRequest, Error> {
+        if !prove {
+            unimplemented!("queries without proofs are not supported yet");
+        }
+
+        // withdrawals are paged by the id of the last withdrawal seen, which is never repeated
+        let start_after = match self.start_info {
+            Some(start_info) if start_info.start_included => {
+                return Err(Error::Generic(
+                    "identity withdrawals can only be queried after a withdrawal".to_string(),
+                ))
+            }
+            Some(start_info) => Some(start_info.start_key),
+            None => None,
+        };
+
+        let request: GetIdentityWithdrawalsRequest = GetIdentityWithdrawalsRequest {
+            version: Some(get_identity_withdrawals_request::Version::V0(
+                GetIdentityWithdrawalsRequestV0 {
+                    identity_id: self.query.to_vec(),
+                    start_after,
+                    limit: self.limit,
+                    prove,
+                },
+            )),
+        };
+
+        Ok(request)
+    }
+}
+
+impl Query<GetIdentityWithdrawalsRequest> for dpp::prelude::Identifier {
+    fn query(self, prove: bool) -> Result<GetIdentityWithdrawalsRequest, Error> {
+        LimitQuery::from(self).query(prove)
+    }
+}
+
 impl Query<GetIdentityBalanceAndRevisionRequest> for dpp::prelude::Identifier {
     fn query(self, prove: bool) -> Result<GetIdentityBalanceAndRevisionRequest, Error> {
         if !prove {
@@ -0,0 +1,168 @@
+//! Tests of the documents batch builder using mock API
+use std::sync::Arc;
+
+use dash_sdk::platform::transition::documents_batch::DocumentsBatchBuilder;
+use dash_sdk::{Error, Sdk};
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::DataContract;
+use dpp::document::{Document, DocumentV0};
+use dpp::identity::IdentityPublicKey;
+use dpp::prelude::Identifier;
+use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+use simple_signer::signer::SimpleSigner;
+
+fn system_data_contract(sdk: &Sdk, system_data_contract: SystemDataContract) -> DataContract {
+    load_system_data_contract(system_data_contract, sdk.version())
+        .expect("expected to load the system data contract")
+}
+
+fn document(id: u8, owner_id: Identifier) -> Document {
+    DocumentV0 {
+        id: Identifier::new([id; 32]),
+        owner_id,
+        revision: Some(1),
+        ..Default::default()
+    }
+    .into()
+}
+
+fn identity_public_key(sdk: &Sdk) -> IdentityPublicKey {
+    IdentityPublicKey::random_ecdsa_critical_level_authentication_key(0, Some(1), sdk.version())
+        .expect("expected a random key")
+        .0
+}
+
+/// Given a batch builder, when I add operations, then they are all collected in the batch.
+#[test]
+fn test_documents_batch_builder_collects_operations() {
+    let sdk = Sdk::new_mock();
+
+    let data_contract = system_data_contract(&sdk, SystemDataContract::Dashpay);
+    let profile = data_contract
+        .document_type_cloned_for_name("profile")
+        .expect("expected a profile document type");
+    let contact_info = data_contract
+        .document_type_cloned_for_name("contactInfo")
+        .expect("expected a contact info document type");
+
+    let owner_id = Identifier::new([100; 32]);
+
+    let builder = DocumentsBatchBuilder::new(Arc::new(data_contract), owner_id);
+
+    assert!(builder.is_empty());
+
+    let builder = builder
+        .create_document(document(1, owner_id), profile.clone(), [1; 32])
+        .replace_document(document(2, owner_id), contact_info.clone())
+        .delete_document(document(3, owner_id), contact_info.clone())
+        .transfer_document(
+            document(4, owner_id),
+            profile.clone(),
+            Identifier::new([101; 32]),
+        )
+        .purchase_document(document(5, owner_id), profile, 1000);
+
+    assert!(!builder.is_empty());
+    assert_eq!(builder.len(), 5);
+}
+
+/// Given an empty batch, when I broadcast it, then it is rejected before anything is sent to
+/// Platform.
+#[tokio::test]
+async fn test_mock_documents_batch_without_operations() {
+    // No expectations are set, so any request would fail with a mock error
+    let sdk = Sdk::new_mock();
+
+    let data_contract = system_data_contract(&sdk, SystemDataContract::Dashpay);
+
+    let error = DocumentsBatchBuilder::new(Arc::new(data_contract), Identifier::new([100; 32]))
+        .broadcast(
+            &sdk,
+            &identity_public_key(&sdk),
+            &SimpleSigner::default(),
+            None,
+        )
+        .await
+        .expect_err("expected an empty batch to be rejected");
+
+    assert!(
+        matches!(&error, Error::Generic(message) if message.contains("at least one operation")),
+        "unexpected error: {:?}",
+        error
+    );
+}
+
+/// Given a batch with more operations than allowed by the protocol, when I broadcast it, then it
+/// is rejected before anything is sent to Platform.
+#[tokio::test]
+async fn test_mock_documents_batch_with_too_many_operations() {
+    let sdk = Sdk::new_mock();
+
+    let data_contract = system_data_contract(&sdk, SystemDataContract::Dashpay);
+    let contact_info = data_contract
+        .document_type_cloned_for_name("contactInfo")
+        .expect("expected a contact info document type");
+
+    let owner_id = Identifier::new([100; 32]);
+
+    let max_transitions = sdk
+        .version()
+        .system_limits
+        .max_transitions_in_documents_batch;
+
+    let builder = (0..=max_transitions).fold(
+        DocumentsBatchBuilder::new(Arc::new(data_contract), owner_id),
+        |builder, id| builder.delete_document(document(id as u8, owner_id), contact_info.clone()),
+    );
+
+    let error = builder
+        .broadcast(
+            &sdk,
+            &identity_public_key(&sdk),
+            &SimpleSigner::default(),
+            None,
+        )
+        .await
+        .expect_err("expected a batch above the limit to be rejected");
+
+    assert!(
+        matches!(&error, Error::Generic(message) if message.contains(&format!("at most {}", max_transitions))),
+        "unexpected error: {:?}",
+        error
+    );
+}
+
+/// Given a batch with a document of another data contract, when I broadcast it, then it is
+/// rejected before anything is sent to Platform.
+#[tokio::test]
+async fn test_mock_documents_batch_with_document_of_another_contract() {
+    let sdk = Sdk::new_mock();
+
+    let data_contract = system_data_contract(&sdk, SystemDataContract::Dashpay);
+    let profile = data_contract
+        .document_type_cloned_for_name("profile")
+        .expect("expected a profile document type");
+    let domain = system_data_contract(&sdk, SystemDataContract::DPNS)
+        .document_type_cloned_for_name("domain")
+        .expect("expected a domain document type");
+
+    let owner_id = Identifier::new([100; 32]);
+
+    let error = DocumentsBatchBuilder::new(Arc::new(data_contract.clone()), owner_id)
+        .create_document(document(1, owner_id), profile, [1; 32])
+        .delete_document(document(2, owner_id), domain)
+        .broadcast(
+            &sdk,
+            &identity_public_key(&sdk),
+            &SimpleSigner::default(),
+            None,
+        )
+        .await
+        .expect_err("expected a document of another contract to be rejected");
+
+    assert!(
+        matches!(&error, Error::Generic(message) if message.contains(&data_contract.id().to_string())),
+        "unexpected error: {:?}",
+        error
+    );
+}
@@ -0,0 +1,288 @@
+//! Tests of DPNS name resolution and registration using mock API
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use dash_sdk::platform::dpns::{register_name, resolve_name, DpnsName, DpnsRecords};
+use dash_sdk::platform::DocumentQuery;
+use dash_sdk::{Error, Sdk};
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
+use dpp::data_contract::DataContract;
+use dpp::document::Document;
+use dpp::identity::accessors::IdentityGettersV0;
+use dpp::identity::{IdentityPublicKey, IdentityV0};
+use dpp::platform_value::Value;
+use dpp::prelude::{Identifier, Identity};
+use dpp::system_data_contracts::dpns_contract::v2::document_types::domain;
+use dpp::system_data_contracts::{dpns_contract, load_system_data_contract, SystemDataContract};
+use dpp::util::hash::hash_double;
+use drive::query::{WhereClause, WhereOperator};
+use simple_signer::signer::SimpleSigner;
+
+fn dpns_data_contract(sdk: &Sdk) -> DataContract {
+    load_system_data_contract(SystemDataContract::DPNS, sdk.version())
+        .expect("expected to load the DPNS contract")
+}
+
+fn domain_document(
+    data_contract: &DataContract,
+    label: &str,
+    records: Value,
+    sdk: &Sdk,
+) -> Document {
+    let name = DpnsName::from_str(&format!("{}.dash", label)).expect("expected a valid name");
+
+    data_contract
+        .document_type_for_name(domain::NAME)
+        .expect("expected the domain document type")
+        .create_document_from_data(
+            Value::from([
+                (domain::properties::LABEL, Value::Text(name.label.clone())),
+                (
+                    domain::properties::NORMALIZED_LABEL,
+                    Value::Text(name.normalized_label()),
+                ),
+                (
+                    domain::properties::PARENT_DOMAIN_NAME,
+                    Value::Text(name.parent_domain_name.clone()),
+                ),
+                (
+                    domain::properties::NORMALIZED_PARENT_DOMAIN_NAME,
+                    Value::Text(name.normalized_parent_domain_name()),
+                ),
+                (domain::properties::PREORDER_SALT, Value::Bytes32([7; 32])),
+                (domain::properties::RECORDS, records),
+                (
+                    "subdomainRules",
+                    Value::from([("allowSubdomains", Value::Bool(false))]),
+                ),
+            ]),
+            Identifier::random(),
+            0,
+            0,
+            [1; 32],
+            sdk.version(),
+        )
+        .expect("expected to create the domain document")
+}
+
+fn domain_query(data_contract: DataContract, name: &DpnsName) -> DocumentQuery {
+    DocumentQuery::new(Arc::new(data_contract), domain::NAME)
+        .expect("expected to create the domain query")
+        .with_where(WhereClause {
+            field: domain::properties::NORMALIZED_PARENT_DOMAIN_NAME.to_string(),
+            operator: WhereOperator::Equal,
+            value: Value::Text(name.normalized_parent_domain_name()),
+        })
+        .with_where(WhereClause {
+            field: domain::properties::NORMALIZED_LABEL.to_string(),
+            operator: WhereOperator::Equal,
+            value: Value::Text(name.normalized_label()),
+        })
+}
+
+/// Given a name, when I parse it, then its label and parent domain are normalized the DPNS way.
+#[test]
+fn test_dpns_name_normalization() {
+    let name = DpnsName::from_str("AlIce.Dash.").expect("expected a valid name");
+
+    assert_eq!(name.label, "AlIce");
+    assert_eq!(name.parent_domain_name, "Dash");
+    assert_eq!(name.normalized_label(), "a11ce");
+    assert_eq!(name.normalized_parent_domain_name(), "dash");
+    assert_eq!(name.to_string(), "AlIce.Dash");
+
+    let top_level_domain = DpnsName::from_str("dash").expect("expected a valid name");
+
+    assert_eq!(top_level_domain.parent_domain_name, "");
+
+    assert!(DpnsName::from_str(".dash").is_err());
+}
+
+/// Given a name and a salt, the salted domain hash is the one the DPNS data trigger expects.
+#[test]
+fn test_dpns_name_salted_domain_hash() {
+    let salt = [3; 32];
+
+    let name = DpnsName::from_str("AlIce.dash").expect("expected a valid name");
+
+    let mut expected_buffer = salt.to_vec();
+    expected_buffer.extend_from_slice(b"a11ce.dash");
+
+    assert_eq!(name.salted_domain_hash(&salt), hash_double(expected_buffer));
+
+    let top_level_domain = DpnsName::from_str("dash").expect("expected a valid name");
+
+    let mut expected_buffer = salt.to_vec();
+    expected_buffer.extend_from_slice(b"dash");
+
+    assert_eq!(
+        top_level_domain.salted_domain_hash(&salt),
+        hash_double(expected_buffer)
+    );
+}
+
+/// Given a registered name, when I resolve it with different case and homographs, then I get its
+/// domain document and its records.
+#[tokio::test]
+async fn test_mock_resolve_name() {
+    let mut sdk = Sdk::new_mock();
+
+    let data_contract = dpns_data_contract(&sdk);
+
+    let identity_id = Identifier::random();
+    let data_contract_id = Identifier::random();
+
+    let expected = domain_document(
+        &data_contract,
+        "alice",
+        Value::from([
+            (
+                domain::records::IDENTITY,
+                Value::Identifier(identity_id.to_buffer()),
+            ),
+            (
+                domain::records::DATA_CONTRACT,
+                Value::Identifier(data_contract_id.to_buffer()),
+            ),
+            (domain::records::URL, Value::Text("https://dash.org".into())),
+        ]),
+        &sdk,
+    );
+
+    let query = domain_query(
+        data_contract.clone(),
+        &DpnsName::from_str("alice.dash").expect("expected a valid name"),
+    );
+
+    sdk.mock()
+        .expect_fetch(data_contract.id(), Some(data_contract.clone()))
+        .await
+        .unwrap()
+        .expect_fetch(query, Some(expected.clone()))
+        .await
+        .unwrap();
+
+    for name in ["alice.dash", "ALICE.dash", "a1ice.DASH"] {
+        let resolved = resolve_name(&sdk, name)
+            .await
+            .expect("expected to resolve the name")
+            .expect("expected the name to be registered");
+
+        assert_eq!(resolved, expected);
+    }
+
+    let records = DpnsRecords::from_domain_document(&expected).expect("expected valid records");
+
+    assert_eq!(
+        records,
+        DpnsRecords {
+            identity: Some(identity_id),
+            data_contract: Some(data_contract_id),
+            url: Some("https://dash.org".to_string()),
+            ..Default::default()
+        }
+    );
+}
+
+/// Given a name that is not registered, when I resolve it, then I get nothing.
+#[tokio::test]
+async fn test_mock_resolve_name_not_found() {
+    let mut sdk = Sdk::new_mock();
+
+    let data_contract = dpns_data_contract(&sdk);
+
+    let query = domain_query(
+        data_contract.clone(),
+        &DpnsName::from_str("bob.dash").expect("expected a valid name"),
+    );
+
+    sdk.mock()
+        .expect_fetch(data_contract.id(), Some(data_contract.clone()))
+        .await
+        .unwrap()
+        .expect_fetch(query, None as Option<Document>)
+        .await
+        .unwrap();
+
+    let resolved = resolve_name(&sdk, "bob.dash")
+        .await
+        .expect("expected to resolve the name");
+
+    assert!(resolved.is_none());
+}
+
+/// Given the DPNS contract is not deployed, when I resolve a name, then I get a missing
+/// dependency error.
+#[tokio::test]
+async fn test_mock_resolve_name_without_dpns_contract() {
+    let mut sdk = Sdk::new_mock();
+
+    sdk.mock()
+        .expect_fetch(dpns_contract::ID, None as Option<DataContract>)
+        .await
+        .unwrap();
+
+    let error = resolve_name(&sdk, "alice.dash")
+        .await
+        .expect_err("expected the DPNS contract to be missing");
+
+    assert!(matches!(error, Error::MissingDependency(_, _)));
+}
+
+/// Given a name without records, when I register it, then it is rejected before anything is
+/// sent to Platform.
+#[tokio::test]
+async fn test_mock_register_name_without_records() {
+    // No expectations are set, so any request would fail with a mock error
+    let sdk = Sdk::new_mock();
+
+    let identity = Identity::from(IdentityV0::default());
+    let identity_public_key = IdentityPublicKey::random_ecdsa_critical_level_authentication_key(
+        0,
+        Some(1),
+        sdk.version(),
+    )
+    .expect("expected a random key")
+    .0;
+
+    let error = register_name(
+        &sdk,
+        "alice.dash",
+        identity.id(),
+        DpnsRecords::default(),
+        &identity_public_key,
+        &SimpleSigner::default(),
+        None,
+    )
+    .await
+    .expect_err("expected a name without records to be rejected");
+
+    assert!(
+        matches!(&error, Error::Generic(message) if message.contains("at least one record")),
+        "unexpected error: {:?}",
+        error
+    );
+
+    let error = register_name(
+        &sdk,
+        ".dash",
+        identity.id(),
+        DpnsRecords {
+            identity: Some(identity.id()),
+            ..Default::default()
+        },
+        &identity_public_key,
+        &SimpleSigner::default(),
+        None,
+    )
+    .await
+    .expect_err("expected an invalid name to be rejected");
+
+    assert!(
+        matches!(&error, Error::Generic(message) if message.contains("invalid DPNS name")),
+        "unexpected error: {:?}",
+        error
+    );
+}
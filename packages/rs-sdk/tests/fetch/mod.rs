@@ -16,6 +16,8 @@ mod contested_resource_vote_state;
 mod contested_resource_voters;
 mod data_contract;
 mod document;
+mod documents_batch;
+mod dpns;
 mod epoch;
 mod identity;
 mod identity_contract_nonce;
@@ -24,3 +26,5 @@ mod mock_fetch_many;
 mod prefunded_specialized_balance;
 mod protocol_version_vote_count;
 mod protocol_version_votes;
+mod spv;
+mod transition;
@@ -0,0 +1,838 @@
+//! Tests of the SPV state and context provider, using recorded mainnet and testnet block
+//! headers, and recorded mainnet masternode list diffs with synthetic quorums, blocks and chain
+//! locks.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use dash_sdk::core::spv::{
+    Checkpoint, MasternodeListDiff, MasternodeListEntry, QuorumCommitment, QuorumList,
+    SpvContextProvider, SpvContextProviderConfig, SpvState, CHECKPOINT_HEADERS,
+    EVO_MASTERNODE_TYPE,
+};
+use dash_sdk::Error;
+use dashcore_rpc::dashcore_rpc_json::{
+    DMNState, MasternodeListDiff as ProTxListDiff, MasternodeType,
+};
+use dpp::bls_signatures::PrivateKey as BlsPrivateKey;
+use dpp::dashcore::block::Version;
+use dpp::dashcore::bls_sig_utils::BLSSignature;
+use dpp::dashcore::consensus::encode::{deserialize, serialize};
+use dpp::dashcore::hash_types::{MerkleRootMasternodeList, MerkleRootQuorums};
+use dpp::dashcore::hashes::{sha256d, Hash, HashEngine};
+use dpp::dashcore::merkle_tree::PartialMerkleTree;
+use dpp::dashcore::transaction::special_transaction::coinbase::CoinbasePayload;
+use dpp::dashcore::transaction::special_transaction::TransactionPayload;
+use dpp::dashcore::{
+    BlockHash, ChainLock, CompactTarget, Header, Network, OutPoint, ScriptBuf, Transaction, TxIn,
+    TxMerkleNode,
+};
+use drive_proof_verifier::ContextProvider;
+
+/// LLMQ type of quorums signing chain locks in tests (LLMQ_400_60)
+const CHAIN_LOCK_QUORUM_TYPE: u8 = 2;
+/// LLMQ type of quorums signing Platform blocks in tests (LLMQ_100_67)
+const PLATFORM_QUORUM_TYPE: u8 = 4;
+
+/// Easiest difficulty of regtest, used by synthetic blocks
+const REGTEST_POW_LIMIT: u32 = 0x207fffff;
+
+const FIRST_DIFF_HEIGHT: u32 = 2128896;
+const SECOND_DIFF_HEIGHT: u32 = 2129440;
+
+/// Load recorded block headers, serialized in hex, one per line
+fn load_block_headers(name: &str) -> Vec<Header> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/vectors/spv_block_headers")
+        .join(name);
+
+    std::fs::read_to_string(path)
+        .expect("read recorded block headers")
+        .lines()
+        .map(|line| {
+            deserialize(&hex::decode(line).expect("hex encoded header")).expect("block header")
+        })
+        .collect()
+}
+
+/// Checkpoint at the last of the first [CHECKPOINT_HEADERS] `headers`
+fn recorded_checkpoint(network: Network, first_height: u32, headers: &[Header]) -> Checkpoint {
+    Checkpoint {
+        network,
+        height: first_height + CHECKPOINT_HEADERS as u32 - 1,
+        headers: headers[..CHECKPOINT_HEADERS].to_vec(),
+        chain_lock_quorums: BTreeMap::new(),
+    }
+}
+
+fn load_protx_diff(name: &str) -> ProTxListDiff {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../rs-drive-abci/tests/supporting_files/mainnet_protx_list_diffs")
+        .join(name);
+    let file = File::open(path).expect("open recorded protx diff");
+
+    serde_json::from_reader(BufReader::new(file)).expect("deserialize recorded protx diff")
+}
+
+fn entry(
+    pro_tx_hash: [u8; 32],
+    node_type: &MasternodeType,
+    state: &DMNState,
+) -> MasternodeListEntry {
+    let is_evo = matches!(node_type, MasternodeType::Evo);
+
+    MasternodeListEntry {
+        version: 2,
+        pro_reg_tx_hash: pro_tx_hash,
+        confirmed_hash: [0; 32],
+        service: service(&state.service),
+        operator_public_key: state
+            .pub_key_operator
+            .clone()
+            .try_into()
+            .expect("48 bytes operator key"),
+        voting_key_id: state.voting_address,
+        is_valid: state.pose_ban_height.is_none(),
+        masternode_type: if is_evo { EVO_MASTERNODE_TYPE } else { 0 },
+        platform_http_port: if is_evo {
+            state.platform_http_port.unwrap_or_default() as u16
+        } else {
+            0
+        },
+        platform_node_id: if is_evo {
+            state.platform_node_id.unwrap_or_default()
+        } else {
+            [0; 20]
+        },
+    }
+}
+
+fn service(address: &SocketAddr) -> [u8; 18] {
+    let ip = match address {
+        SocketAddr::V4(address) => address.ip().to_ipv6_mapped(),
+        SocketAddr::V6(address) => *address.ip(),
+    };
+
+    let mut service = [0u8; 18];
+    service[..16].copy_from_slice(&ip.octets());
+    service[16..].copy_from_slice(&address.port().to_be_bytes());
+    service
+}
+
+/// Commitment of a quorum with all members valid and signing, signed with `private_key`
+fn quorum(quorum_type: u8, seed: u8, private_key: &BlsPrivateKey) -> QuorumCommitment {
+    let size: u64 = if quorum_type == CHAIN_LOCK_QUORUM_TYPE {
+        400
+    } else {
+        100
+    };
+    let mut members = vec![0xff; size.div_ceil(8) as usize];
+    if size % 8 != 0 {
+        *members.last_mut().expect("members") = (1 << (size % 8)) - 1;
+    }
+
+    let mut quorum = QuorumCommitment {
+        version: 1,
+        quorum_type,
+        quorum_hash: [seed; 32],
+        quorum_index: None,
+        signers_count: size,
+        signers: members.clone(),
+        valid_members_count: size,
+        valid_members: members,
+        quorum_public_key: *private_key
+            .g1_element()
+            .expect("quorum public key")
+            .to_bytes(),
+        quorum_vvec_hash: [seed; 32],
+        quorum_signature: [0; 96],
+        members_signature: [0; 96],
+    };
+    sign_quorum(&mut quorum, private_key);
+
+    quorum
+}
+
+fn sign_quorum(quorum: &mut QuorumCommitment, private_key: &BlsPrivateKey) {
+    quorum.quorum_signature = *private_key.sign(&quorum.signing_hash()).to_bytes();
+}
+
+/// Coinbase transaction of a block, committing to `masternodes` and `quorums`
+fn coinbase(
+    height: u32,
+    masternodes: &BTreeMap<[u8; 32], MasternodeListEntry>,
+    quorums: &QuorumList,
+) -> Transaction {
+    let masternode_hashes: Vec<[u8; 32]> = masternodes
+        .values()
+        .map(|entry| sha256d::Hash::hash(&serialize(entry)).to_byte_array())
+        .collect();
+
+    Transaction {
+        version: 3,
+        lock_time: 0,
+        input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: ScriptBuf::from_bytes(height.to_le_bytes().to_vec()),
+            sequence: u32::MAX,
+            witness: Default::default(),
+        }],
+        output: vec![],
+        special_transaction_payload: Some(TransactionPayload::CoinbasePayloadType(
+            CoinbasePayload {
+                version: 2,
+                height,
+                merkle_root_masternode_list: MerkleRootMasternodeList::from_byte_array(
+                    merkle_root(masternode_hashes),
+                ),
+                merkle_root_quorums: MerkleRootQuorums::from_byte_array(quorums.merkle_root()),
+                best_cl_height: None,
+                best_cl_signature: None,
+                asset_locked_amount: None,
+            },
+        )),
+    }
+}
+
+fn merkle_root(mut hashes: Vec<[u8; 32]>) -> [u8; 32] {
+    while hashes.len() > 1 {
+        if hashes.len() % 2 == 1 {
+            hashes.push(*hashes.last().unwrap());
+        }
+        hashes = hashes
+            .chunks(2)
+            .map(|pair| {
+                let mut engine = sha256d::Hash::engine();
+                engine.input(&pair[0]);
+                engine.input(&pair[1]);
+                sha256d::Hash::from_engine(engine).to_byte_array()
+            })
+            .collect();
+    }
+    hashes.first().copied().unwrap_or_default()
+}
+
+/// Header mined with the regtest difficulty
+fn header(prev_blockhash: BlockHash, merkle_root: TxMerkleNode, time: u32) -> Header {
+    let mut header = Header {
+        version: Version::default(),
+        prev_blockhash,
+        merkle_root,
+        time,
+        bits: CompactTarget::from_consensus(REGTEST_POW_LIMIT),
+        nonce: 0,
+    };
+
+    // Hashes below 0x7f00..00 meet the 0x7fffff00..00 target
+    while header.block_hash().to_byte_array()[31] >= 0x7f {
+        header.nonce += 1;
+    }
+
+    header
+}
+
+/// Chain lock of `block_hash` signed by `quorum`
+fn chain_lock(
+    block_height: u32,
+    block_hash: BlockHash,
+    quorum: &QuorumCommitment,
+    private_key: &BlsPrivateKey,
+) -> ChainLock {
+    let mut engine = sha256d::Hash::engine();
+    engine.input(&[5]);
+    engine.input(b"clsig");
+    engine.input(&block_height.to_le_bytes());
+    let request_id = sha256d::Hash::from_engine(engine);
+
+    let mut engine = sha256d::Hash::engine();
+    engine.input(&[quorum.quorum_type]);
+    engine.input(&quorum.quorum_hash);
+    engine.input(request_id.as_byte_array());
+    engine.input(block_hash.as_byte_array());
+    let message_digest = sha256d::Hash::from_engine(engine);
+
+    ChainLock {
+        block_height,
+        block_hash,
+        signature: BLSSignature::from(*private_key.sign(message_digest.as_byte_array()).to_bytes()),
+    }
+}
+
+/// Synthetic chain built around the recorded masternode list diffs
+struct TestChain {
+    checkpoint: Checkpoint,
+    quorum_key: BlsPrivateKey,
+    first_quorums: Vec<QuorumCommitment>,
+    second_quorums: Vec<QuorumCommitment>,
+    first_diff: MasternodeListDiff,
+    first_header: Header,
+    second_diff: MasternodeListDiff,
+    /// Headers from `FIRST_DIFF_HEIGHT + 1` to `SECOND_DIFF_HEIGHT`
+    next_headers: Vec<Header>,
+}
+
+impl TestChain {
+    fn new() -> Self {
+        let quorum_key = BlsPrivateKey::from_bytes(&[7; 32], false).expect("quorum private key");
+
+        // Checkpoint: the blocks before the first diff
+        let mut checkpoint_headers = Vec::new();
+        let mut prev_blockhash = BlockHash::all_zeros();
+        for height in FIRST_DIFF_HEIGHT - CHECKPOINT_HEADERS as u32..FIRST_DIFF_HEIGHT {
+            let next = header(prev_blockhash, TxMerkleNode::all_zeros(), height);
+            prev_blockhash = next.block_hash();
+            checkpoint_headers.push(next);
+        }
+
+        // First diff: full list
+        let recorded = load_protx_diff("1-2128896.json");
+        let mut masternodes = BTreeMap::new();
+        for item in &recorded.added_mns {
+            let pro_tx_hash = item.pro_tx_hash.to_byte_array();
+            masternodes.insert(
+                pro_tx_hash,
+                entry(pro_tx_hash, &item.node_type, &item.state),
+            );
+        }
+        let first_quorums = vec![
+            quorum(CHAIN_LOCK_QUORUM_TYPE, 1, &quorum_key),
+            quorum(PLATFORM_QUORUM_TYPE, 2, &quorum_key),
+        ];
+        let checkpoint = Checkpoint {
+            network: Network::Regtest,
+            height: FIRST_DIFF_HEIGHT - 1,
+            headers: checkpoint_headers,
+            chain_lock_quorums: BTreeMap::from([(
+                first_quorums[0].display_quorum_hash(),
+                first_quorums[0].quorum_public_key,
+            )]),
+        };
+        let (first_diff, first_header) = Self::diff(
+            BlockHash::all_zeros(),
+            FIRST_DIFF_HEIGHT,
+            prev_blockhash,
+            &masternodes,
+            vec![],
+            masternodes.values().cloned().collect(),
+            vec![],
+            first_quorums.clone(),
+            &QuorumList::default(),
+        );
+
+        // Second diff: changes on top of the first list
+        let recorded = load_protx_diff("2128896-2129440.json");
+        let mut changed = Vec::new();
+        for pro_tx_hash in &recorded.removed_mns {
+            masternodes.remove(&pro_tx_hash.to_byte_array());
+        }
+        for item in &recorded.added_mns {
+            let pro_tx_hash = item.pro_tx_hash.to_byte_array();
+            let entry = entry(pro_tx_hash, &item.node_type, &item.state);
+            masternodes.insert(pro_tx_hash, entry.clone());
+            changed.push(entry);
+        }
+        for (pro_tx_hash, state_diff) in &recorded.updated_mns {
+            let entry = masternodes
+                .get_mut(&pro_tx_hash.to_byte_array())
+                .expect("updated masternode exists");
+            if let Some(service_address) = state_diff.service {
+                entry.service = service(&service_address);
+            }
+            if let Some(pose_ban_height) = state_diff.pose_ban_height {
+                entry.is_valid = pose_ban_height.is_none();
+            }
+            if let Some(voting_address) = state_diff.voting_address {
+                entry.voting_key_id = voting_address;
+            }
+            if let Some(pub_key_operator) = &state_diff.pub_key_operator {
+                entry.operator_public_key = pub_key_operator
+                    .clone()
+                    .try_into()
+                    .expect("48 bytes operator key");
+            }
+            changed.push(entry.clone());
+        }
+
+        let mut first_quorum_list = QuorumList::default();
+        first_quorum_list.apply_diff(&first_diff);
+
+        let second_quorums = vec![quorum(CHAIN_LOCK_QUORUM_TYPE, 3, &quorum_key)];
+
+        // Blocks between both diffs
+        let mut next_headers = Vec::new();
+        let mut prev_blockhash = first_header.block_hash();
+        for height in FIRST_DIFF_HEIGHT + 1..SECOND_DIFF_HEIGHT {
+            let next = header(prev_blockhash, TxMerkleNode::all_zeros(), height);
+            prev_blockhash = next.block_hash();
+            next_headers.push(next);
+        }
+
+        let (second_diff, second_header) = Self::diff(
+            first_diff.block_hash,
+            SECOND_DIFF_HEIGHT,
+            prev_blockhash,
+            &masternodes,
+            recorded
+                .removed_mns
+                .iter()
+                .map(|pro_tx_hash| pro_tx_hash.to_byte_array())
+                .collect(),
+            changed,
+            vec![(CHAIN_LOCK_QUORUM_TYPE, first_quorums[0].quorum_hash)],
+            second_quorums.clone(),
+            &first_quorum_list,
+        );
+        next_headers.push(second_header);
+
+        Self {
+            checkpoint,
+            quorum_key,
+            first_quorums,
+            second_quorums,
+            first_diff,
+            first_header,
+            second_diff,
+            next_headers,
+        }
+    }
+
+    /// Build a diff and the header of the block it leads to
+    #[allow(clippy::too_many_arguments)]
+    fn diff(
+        base_block_hash: BlockHash,
+        height: u32,
+        prev_blockhash: BlockHash,
+        masternodes: &BTreeMap<[u8; 32], MasternodeListEntry>,
+        deleted_masternodes: Vec<[u8; 32]>,
+        changed_masternodes: Vec<MasternodeListEntry>,
+        deleted_quorums: Vec<(u8, [u8; 32])>,
+        new_quorums: Vec<QuorumCommitment>,
+        base_quorums: &QuorumList,
+    ) -> (MasternodeListDiff, Header) {
+        let mut diff = MasternodeListDiff {
+            version: 1,
+            base_block_hash,
+            block_hash: BlockHash::all_zeros(),
+            coinbase_merkle_tree: PartialMerkleTree::from_txids(&[], &[]),
+            coinbase_transaction: Transaction {
+                version: 3,
+                lock_time: 0,
+                input: vec![],
+                output: vec![],
+                special_transaction_payload: None,
+            },
+            deleted_masternodes,
+            masternodes: changed_masternodes,
+            deleted_quorums,
+            new_quorums,
+            quorums_chain_lock_signatures: vec![],
+        };
+
+        let mut quorums = base_quorums.clone();
+        quorums.apply_diff(&diff);
+
+        let coinbase_transaction = coinbase(height, masternodes, &quorums);
+        let coinbase_merkle_tree =
+            PartialMerkleTree::from_txids(&[coinbase_transaction.txid()], &[true]);
+        let mut matches = Vec::new();
+        let mut indexes = Vec::new();
+        let merkle_root = coinbase_merkle_tree
+            .extract_matches(&mut matches, &mut indexes)
+            .expect("coinbase merkle root");
+        let block_header = header(prev_blockhash, merkle_root, height);
+
+        diff.block_hash = block_header.block_hash();
+        diff.coinbase_merkle_tree = coinbase_merkle_tree;
+        diff.coinbase_transaction = coinbase_transaction;
+
+        (diff, block_header)
+    }
+
+    fn spv_state(&self) -> SpvState {
+        SpvState::new(CHAIN_LOCK_QUORUM_TYPE, self.checkpoint.clone()).expect("valid checkpoint")
+    }
+
+    fn first_chain_lock(&self) -> ChainLock {
+        chain_lock(
+            FIRST_DIFF_HEIGHT,
+            self.first_diff.block_hash,
+            &self.first_quorums[0],
+            &self.quorum_key,
+        )
+    }
+
+    fn second_chain_lock(&self) -> ChainLock {
+        chain_lock(
+            SECOND_DIFF_HEIGHT,
+            self.second_diff.block_hash,
+            &self.first_quorums[0],
+            &self.quorum_key,
+        )
+    }
+
+    /// State with both recorded diffs applied and chain-locked
+    fn synced_spv_state(&self) -> SpvState {
+        let mut state = self.spv_state();
+        state
+            .apply_headers(&[self.first_header])
+            .expect("first header");
+        state
+            .apply_masternode_list_diff(&self.first_diff)
+            .expect("first diff");
+        assert!(state
+            .apply_chain_lock(&self.first_chain_lock())
+            .expect("first chain lock"));
+        state
+            .apply_headers(&self.next_headers)
+            .expect("next headers");
+        state
+            .apply_masternode_list_diff(&self.second_diff)
+            .expect("second diff");
+        assert!(state
+            .apply_chain_lock(&self.second_chain_lock())
+            .expect("second chain lock"));
+        state
+    }
+}
+
+/// Given recorded masternode list diffs, when I apply them with their headers and chain locks,
+/// then the masternode and quorum lists are verified and activated
+#[test]
+fn test_spv_state_applies_recorded_masternode_list_diffs() {
+    let chain = TestChain::new();
+
+    let mut state = chain.spv_state();
+    state
+        .apply_headers(&[chain.first_header])
+        .expect("first header");
+    state
+        .apply_masternode_list_diff(&chain.first_diff)
+        .expect("first diff");
+
+    // Not chain-locked yet
+    assert!(state.masternode_list().is_none());
+    assert_eq!(
+        state
+            .pending_masternode_list()
+            .expect("pending list")
+            .masternodes
+            .len(),
+        3728
+    );
+
+    assert!(state
+        .apply_chain_lock(&chain.first_chain_lock())
+        .expect("first chain lock"));
+    let list = state.masternode_list().expect("active list");
+    assert_eq!(list.block_height, FIRST_DIFF_HEIGHT);
+    assert_eq!(list.quorums.len(), 2);
+
+    let state = chain.synced_spv_state();
+    let list = state.masternode_list().expect("active list");
+    assert_eq!(list.block_height, SECOND_DIFF_HEIGHT);
+    assert_eq!(list.masternodes.len(), 3718);
+    assert_eq!(
+        state
+            .best_chain_lock()
+            .map(|chain_lock| chain_lock.block_height),
+        Some(SECOND_DIFF_HEIGHT)
+    );
+
+    let new_quorum = &chain.second_quorums[0];
+    assert_eq!(
+        state.quorum_public_key(CHAIN_LOCK_QUORUM_TYPE, &new_quorum.display_quorum_hash()),
+        Some(new_quorum.quorum_public_key)
+    );
+    // Quorums of the previous list can still be used
+    let removed_quorum = &chain.first_quorums[0];
+    assert_eq!(
+        state.quorum_public_key(
+            CHAIN_LOCK_QUORUM_TYPE,
+            &removed_quorum.display_quorum_hash()
+        ),
+        Some(removed_quorum.quorum_public_key)
+    );
+}
+
+/// Given a masternode list diff not matching its coinbase commitment, when I apply it,
+/// then it is rejected
+#[test]
+fn test_spv_state_rejects_tampered_masternode_list_diff() {
+    let chain = TestChain::new();
+
+    let mut state = chain.spv_state();
+    state
+        .apply_headers(&[chain.first_header])
+        .expect("first header");
+
+    let mut diff = chain.first_diff.clone();
+    diff.new_quorums[1].quorum_hash = [9; 32];
+    sign_quorum(&mut diff.new_quorums[1], &chain.quorum_key);
+
+    let result = state.apply_masternode_list_diff(&diff);
+    assert!(matches!(result, Err(Error::CoreVerification(_))));
+
+    let mut diff = chain.first_diff.clone();
+    diff.masternodes.pop();
+
+    let result = state.apply_masternode_list_diff(&diff);
+    assert!(matches!(result, Err(Error::CoreVerification(_))));
+}
+
+/// Given an active masternode list, when I apply a chain lock not signed by its quorums,
+/// then the chain lock is rejected
+#[test]
+fn test_spv_state_rejects_invalid_chain_lock() {
+    let chain = TestChain::new();
+
+    let mut state = chain.spv_state();
+    state
+        .apply_headers(&[chain.first_header])
+        .expect("first header");
+    state
+        .apply_masternode_list_diff(&chain.first_diff)
+        .expect("first diff");
+    state
+        .apply_chain_lock(&chain.first_chain_lock())
+        .expect("first chain lock");
+    state
+        .apply_headers(&chain.next_headers)
+        .expect("next headers");
+
+    let other_key = BlsPrivateKey::from_bytes(&[8; 32], false).expect("other private key");
+    let forged = chain_lock(
+        SECOND_DIFF_HEIGHT,
+        chain.second_diff.block_hash,
+        &chain.first_quorums[0],
+        &other_key,
+    );
+
+    let result = state.apply_chain_lock(&forged);
+    assert!(matches!(result, Err(Error::CoreVerification(_))));
+    assert_eq!(
+        state
+            .best_chain_lock()
+            .map(|chain_lock| chain_lock.block_height),
+        Some(FIRST_DIFF_HEIGHT)
+    );
+}
+
+/// Given a synced state, when I save and load it, then I get the same state
+#[test]
+fn test_spv_state_persistence() {
+    let chain = TestChain::new();
+    let state = chain.synced_spv_state();
+
+    let path = std::env::temp_dir().join(format!("dash-sdk-spv-{}.state", std::process::id()));
+    state.save(&path).expect("save state");
+    let loaded = SpvState::load(&path).expect("load state");
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(loaded, state);
+}
+
+/// Given serialized Core data received from DAPI, when I apply it to the SPV context provider,
+/// then it serves verified quorum public keys up to the synced height
+#[test]
+fn test_spv_context_provider_quorum_public_key() {
+    let chain = TestChain::new();
+
+    let provider = SpvContextProvider::new(SpvContextProviderConfig {
+        checkpoint: chain.checkpoint.clone(),
+        chain_lock_quorum_type: CHAIN_LOCK_QUORUM_TYPE,
+        platform_activation_height: FIRST_DIFF_HEIGHT,
+        state_path: None,
+    })
+    .expect("create provider");
+
+    provider
+        .apply_block_headers(&[serialize(&chain.first_header)])
+        .expect("apply headers");
+    provider
+        .apply_masternode_list_diff(&serialize(&chain.first_diff))
+        .expect("apply diff");
+    assert!(provider
+        .apply_chain_lock(&serialize(&chain.first_chain_lock()))
+        .expect("apply chain lock"));
+
+    let platform_quorum = &chain.first_quorums[1];
+    let public_key = provider
+        .get_quorum_public_key(
+            PLATFORM_QUORUM_TYPE as u32,
+            platform_quorum.display_quorum_hash(),
+            FIRST_DIFF_HEIGHT,
+        )
+        .expect("quorum public key");
+    assert_eq!(public_key, platform_quorum.quorum_public_key);
+
+    // Not synced up to that height yet
+    provider
+        .get_quorum_public_key(
+            PLATFORM_QUORUM_TYPE as u32,
+            platform_quorum.display_quorum_hash(),
+            FIRST_DIFF_HEIGHT + 1,
+        )
+        .expect_err("height above synced chain lock");
+
+    // Unknown quorum
+    provider
+        .get_quorum_public_key(PLATFORM_QUORUM_TYPE as u32, [3; 32], FIRST_DIFF_HEIGHT)
+        .expect_err("unknown quorum");
+}
+
+/// Given recorded block headers, when I apply them on top of a checkpoint of their first blocks,
+/// then their proof of work and difficulty are verified and they extend the chain
+#[test_case::test_case(Network::Dash, "mainnet_999900-1000399.txt", 999900; "mainnet")]
+#[test_case::test_case(Network::Testnet, "testnet_10000-10499.txt", 10000; "testnet with minimum difficulty blocks")]
+fn test_spv_state_verifies_recorded_block_headers(network: Network, name: &str, first_height: u32) {
+    let headers = load_block_headers(name);
+    let checkpoint = recorded_checkpoint(network, first_height, &headers);
+
+    let mut state = SpvState::new(CHAIN_LOCK_QUORUM_TYPE, checkpoint).expect("valid checkpoint");
+    state
+        .apply_headers(&headers[CHECKPOINT_HEADERS..])
+        .expect("recorded headers are valid");
+
+    let last = headers.last().expect("recorded headers");
+    assert_eq!(
+        state.tip(),
+        (first_height + headers.len() as u32 - 1, last.block_hash())
+    );
+}
+
+/// Given recorded mainnet block headers, when I apply a header with a wrong proof of work,
+/// difficulty or time, then it is rejected
+#[test]
+fn test_spv_state_rejects_block_headers_with_invalid_work() {
+    let headers = load_block_headers("mainnet_999900-1000399.txt");
+    let checkpoint = recorded_checkpoint(Network::Dash, 999900, &headers);
+    let mut state = SpvState::new(CHAIN_LOCK_QUORUM_TYPE, checkpoint).expect("valid checkpoint");
+    let next = headers[CHECKPOINT_HEADERS];
+
+    let mut tampered = next;
+    tampered.nonce = tampered.nonce.wrapping_add(1);
+    let result = state.apply_headers(&[tampered]);
+    assert!(
+        matches!(&result, Err(Error::CoreVerification(e)) if e.contains("does not meet")),
+        "{:?}",
+        result
+    );
+
+    let mut tampered = next;
+    tampered.bits = CompactTarget::from_consensus(0x1e0fffff);
+    let result = state.apply_headers(&[tampered]);
+    assert!(
+        matches!(&result, Err(Error::CoreVerification(e)) if e.contains("difficulty bits")),
+        "{:?}",
+        result
+    );
+
+    let mut tampered = next;
+    tampered.time = headers[..CHECKPOINT_HEADERS]
+        .iter()
+        .map(|header| header.time)
+        .min()
+        .expect("checkpoint headers")
+        - 1;
+    let result = state.apply_headers(&[tampered]);
+    assert!(
+        matches!(&result, Err(Error::CoreVerification(e)) if e.contains("median time")),
+        "{:?}",
+        result
+    );
+
+    // The chain is unchanged and still accepts the recorded header
+    assert_eq!(state.tip().0, 999900 + CHECKPOINT_HEADERS as u32 - 1);
+    state.apply_headers(&[next]).expect("recorded header");
+}
+
+/// Given a checkpoint without enough headers to verify the difficulty of the next blocks,
+/// when I create a state from it, then it is rejected
+#[test]
+fn test_spv_state_rejects_checkpoint_without_enough_headers() {
+    let headers = load_block_headers("mainnet_999900-1000399.txt");
+    let mut checkpoint = recorded_checkpoint(Network::Dash, 999900, &headers);
+    checkpoint.headers.remove(0);
+
+    let result = SpvState::new(CHAIN_LOCK_QUORUM_TYPE, checkpoint);
+    assert!(matches!(result, Err(Error::Config(_))));
+}
+
+/// Given a first masternode list whose chain lock quorum is not a quorum of the checkpoint,
+/// when I apply a chain lock signed by it, then the list is not activated
+#[test]
+fn test_spv_state_bootstraps_only_with_checkpoint_quorums() {
+    let chain = TestChain::new();
+
+    let apply_first_diff = |checkpoint: Checkpoint| {
+        let mut state = SpvState::new(CHAIN_LOCK_QUORUM_TYPE, checkpoint).expect("checkpoint");
+        state
+            .apply_headers(&[chain.first_header])
+            .expect("first header");
+        state
+            .apply_masternode_list_diff(&chain.first_diff)
+            .expect("first diff");
+        state
+    };
+
+    // The quorum is unknown to the checkpoint: the chain lock can't be verified yet
+    let mut checkpoint = chain.checkpoint.clone();
+    checkpoint.chain_lock_quorums.clear();
+    let mut state = apply_first_diff(checkpoint);
+    assert!(!state
+        .apply_chain_lock(&chain.first_chain_lock())
+        .expect("first chain lock"));
+    assert!(state.masternode_list().is_none());
+    assert!(state.best_chain_lock().is_none());
+
+    // The checkpoint knows the quorum with another public key: the list is forged
+    let other_key = BlsPrivateKey::from_bytes(&[8; 32], false).expect("other private key");
+    let mut checkpoint = chain.checkpoint.clone();
+    checkpoint.chain_lock_quorums.insert(
+        chain.first_quorums[0].display_quorum_hash(),
+        *other_key.g1_element().expect("other public key").to_bytes(),
+    );
+    let mut state = apply_first_diff(checkpoint);
+    let result = state.apply_chain_lock(&chain.first_chain_lock());
+    assert!(matches!(result, Err(Error::CoreVerification(_))));
+    assert!(state.masternode_list().is_none());
+}
+
+/// Given a masternode list diff with an invalid quorum commitment, when I apply it,
+/// then it is rejected
+#[test]
+fn test_spv_state_rejects_invalid_quorum_commitment() {
+    let chain = TestChain::new();
+
+    let mut state = chain.spv_state();
+    state
+        .apply_headers(&[chain.first_header])
+        .expect("first header");
+
+    // Not signed by the quorum
+    let other_key = BlsPrivateKey::from_bytes(&[8; 32], false).expect("other private key");
+    let mut diff = chain.first_diff.clone();
+    sign_quorum(&mut diff.new_quorums[0], &other_key);
+    let result = state.apply_masternode_list_diff(&diff);
+    assert!(
+        matches!(&result, Err(Error::CoreVerification(e)) if e.contains("invalid commitment")),
+        "{:?}",
+        result
+    );
+
+    // Not enough signers
+    let mut diff = chain.first_diff.clone();
+    diff.new_quorums[1].signers = vec![0; diff.new_quorums[1].signers.len()];
+    sign_quorum(&mut diff.new_quorums[1], &chain.quorum_key);
+    let result = state.apply_masternode_list_diff(&diff);
+    assert!(
+        matches!(&result, Err(Error::CoreVerification(e)) if e.contains("invalid commitment")),
+        "{:?}",
+        result
+    );
+
+    assert!(state.pending_masternode_list().is_none());
+}
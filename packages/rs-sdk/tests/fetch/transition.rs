@@ -0,0 +1,635 @@
+//! Tests of state transition helpers using mock API.
+//!
+//! Platform state after execution of each state transition is recreated in Drive, which produces
+//! the proofs returned by the mocked DAPI.
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use dapi_grpc::platform::v0::wait_for_state_transition_result_response::{
+    wait_for_state_transition_result_response_v0, Version, WaitForStateTransitionResultResponseV0,
+};
+use dapi_grpc::platform::v0::{
+    BroadcastStateTransitionResponse, Proof, ResponseMetadata, WaitForStateTransitionResultResponse,
+};
+use dash_sdk::platform::transition::broadcast_request::BroadcastRequestForStateTransition;
+use dash_sdk::platform::transition::delete_document::DeleteDocument;
+use dash_sdk::platform::transition::replace_document::ReplaceDocument;
+use dash_sdk::platform::transition::transfer::TransferToIdentity;
+use dash_sdk::platform::transition::update_contract::UpdateContract;
+use dash_sdk::platform::transition::update_identity::UpdateIdentity;
+use dash_sdk::{Error, Sdk};
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::{DataContractV0Getters, DataContractV0Setters};
+use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+use dpp::data_contract::document_type::DocumentType;
+use dpp::data_contract::DataContract;
+use dpp::document::{Document, DocumentV0Getters, DocumentV0Setters};
+use dpp::identity::accessors::{IdentityGettersV0, IdentitySettersV0};
+use dpp::identity::identity_public_key::accessors::v0::{
+    IdentityPublicKeyGettersV0, IdentityPublicKeySettersV0,
+};
+use dpp::identity::{Identity, IdentityPublicKey, IdentityV0, PartialIdentity, Purpose};
+use dpp::prelude::Identifier;
+use dpp::state_transition::data_contract_update_transition::methods::DataContractUpdateTransitionMethodsV0;
+use dpp::state_transition::data_contract_update_transition::DataContractUpdateTransition;
+use dpp::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
+use dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
+use dpp::state_transition::identity_credit_transfer_transition::methods::IdentityCreditTransferTransitionMethodsV0;
+use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
+use dpp::state_transition::identity_update_transition::methods::IdentityUpdateTransitionMethodsV0;
+use dpp::state_transition::identity_update_transition::IdentityUpdateTransition;
+use dpp::state_transition::StateTransition;
+use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+use dpp::version::PlatformVersion;
+use drive::drive::identity::{IdentityDriveQuery, IdentityProveRequestType};
+use drive::drive::Drive;
+use drive::query::{SingleDocumentDriveQuery, SingleDocumentDriveQueryContestedStatus};
+use drive::util::test_helpers::setup::{
+    setup_document, setup_drive_with_initial_state_structure, setup_system_data_contract,
+};
+use drive_proof_verifier::types::{IdentityContractNonceFetcher, IdentityNonceFetcher};
+use simple_signer::signer::SimpleSigner;
+
+fn identity(id: u8, public_keys: Vec<IdentityPublicKey>, balance: u64) -> Identity {
+    IdentityV0 {
+        id: Identifier::new([id; 32]),
+        public_keys: public_keys
+            .into_iter()
+            .map(|public_key| (public_key.id(), public_key))
+            .collect(),
+        balance,
+        revision: 0,
+    }
+    .into()
+}
+
+fn add_identity(drive: &Drive, identity: Identity, platform_version: &PlatformVersion) {
+    drive
+        .add_new_identity(
+            identity,
+            false,
+            &BlockInfo::default(),
+            true,
+            None,
+            platform_version,
+        )
+        .expect("expected to add an identity");
+}
+
+fn prove(
+    drive: &Drive,
+    identity_queries: &[IdentityDriveQuery],
+    contract_ids: &[([u8; 32], Option<bool>)],
+    document_queries: &[SingleDocumentDriveQuery],
+    platform_version: &PlatformVersion,
+) -> Vec<u8> {
+    drive
+        .prove_multiple_state_transition_results(
+            identity_queries,
+            contract_ids,
+            document_queries,
+            &[],
+            None,
+            platform_version,
+        )
+        .expect("expected to prove the state transition results")
+}
+
+fn document_query(document: &Document, data_contract: &DataContract) -> SingleDocumentDriveQuery {
+    SingleDocumentDriveQuery {
+        contract_id: data_contract.id().to_buffer(),
+        document_type_name: "profile".to_string(),
+        document_type_keeps_history: false,
+        document_id: document.id().to_buffer(),
+        block_time_ms: None,
+        contested_status: SingleDocumentDriveQueryContestedStatus::NotContested,
+    }
+}
+
+/// Expect `state_transition` to be broadcast, and the wait for its result to return `proof`.
+async fn expect_state_transition(
+    sdk: &mut Sdk,
+    state_transition: &StateTransition,
+    grovedb_proof: Vec<u8>,
+) {
+    let response = WaitForStateTransitionResultResponse {
+        version: Some(Version::V0(WaitForStateTransitionResultResponseV0 {
+            result: Some(wait_for_state_transition_result_response_v0::Result::Proof(
+                Proof {
+                    grovedb_proof,
+                    ..Default::default()
+                },
+            )),
+            metadata: Some(ResponseMetadata {
+                height: 2,
+                core_chain_locked_height: 1,
+                epoch: 0,
+                time_ms: 1_700_000_000_000,
+                protocol_version: sdk.version().protocol_version,
+                chain_id: "mock".to_string(),
+            }),
+        })),
+    };
+
+    let mut mock = sdk.mock();
+
+    mock.expect_request(
+        state_transition
+            .broadcast_request_for_state_transition()
+            .expect("expected a broadcast request"),
+        BroadcastStateTransitionResponse::default(),
+    )
+    .await
+    .expect("expected to add the broadcast expectation");
+
+    mock.expect_request(
+        state_transition
+            .wait_for_state_transition_result_request()
+            .expect("expected a wait request"),
+        response,
+    )
+    .await
+    .expect("expected to add the wait expectation");
+}
+
+/// Profile document of the Dashpay contract, with its revision bumped so it can be replaced.
+fn profile(
+    data_contract: &DataContract,
+    owner_id: Identifier,
+    platform_version: &PlatformVersion,
+) -> (DocumentType, Document) {
+    let document_type = data_contract
+        .document_type_cloned_for_name("profile")
+        .expect("expected a profile document type");
+
+    let mut document = document_type
+        .random_document(Some(1), platform_version)
+        .expect("expected a random document");
+    document.set_owner_id(owner_id);
+    document.set_revision(Some(2));
+
+    (document_type, document)
+}
+
+/// Given an identity with a transfer key, when I transfer credits, then the proved balances of
+/// the sender and the recipient are returned.
+#[tokio::test]
+async fn test_mock_transfer_credits() {
+    let mut sdk = Sdk::new_mock();
+    let platform_version = sdk.version();
+
+    let (master_key, _) =
+        IdentityPublicKey::random_ecdsa_master_authentication_key(0, Some(1), platform_version)
+            .expect("expected a master key");
+    let (mut transfer_key, transfer_private_key) =
+        IdentityPublicKey::random_ecdsa_critical_level_authentication_key(
+            1,
+            Some(2),
+            platform_version,
+        )
+        .expect("expected a transfer key");
+    transfer_key.set_purpose(Purpose::TRANSFER);
+    let (recipient_key, _) =
+        IdentityPublicKey::random_ecdsa_master_authentication_key(0, Some(3), platform_version)
+            .expect("expected a recipient key");
+
+    let mut signer = SimpleSigner::default();
+    signer.add_key(transfer_key.clone(), transfer_private_key);
+
+    let sender = identity(1, vec![master_key, transfer_key], 1_000_000_000);
+    let recipient = identity(2, vec![recipient_key], 0);
+
+    sdk.mock()
+        .expect_fetch(sender.id(), Some(IdentityNonceFetcher(4)))
+        .await
+        .expect("expected to add the nonce expectation");
+
+    let state_transition = IdentityCreditTransferTransition::try_from_identity(
+        &sender,
+        recipient.id(),
+        100_000_000,
+        0,
+        signer.clone(),
+        None,
+        5,
+        platform_version,
+        None,
+    )
+    .expect("expected a transfer transition");
+
+    let drive = setup_drive_with_initial_state_structure();
+    let mut sender_after_transfer = sender.clone();
+    sender_after_transfer.set_balance(899_000_000);
+    let mut recipient_after_transfer = recipient.clone();
+    recipient_after_transfer.set_balance(100_000_000);
+    add_identity(&drive, sender_after_transfer, platform_version);
+    add_identity(&drive, recipient_after_transfer, platform_version);
+
+    let proof = prove(
+        &drive,
+        &[
+            IdentityDriveQuery {
+                identity_id: sender.id().to_buffer(),
+                prove_request_type: IdentityProveRequestType::Balance,
+            },
+            IdentityDriveQuery {
+                identity_id: recipient.id().to_buffer(),
+                prove_request_type: IdentityProveRequestType::Balance,
+            },
+        ],
+        &[],
+        &[],
+        platform_version,
+    );
+
+    expect_state_transition(&mut sdk, &state_transition, proof).await;
+
+    let balances = sender
+        .transfer_credits(&sdk, recipient.id(), 100_000_000, None, signer, None)
+        .await
+        .expect("expected the transfer to succeed");
+
+    assert_eq!(balances, (899_000_000, 100_000_000));
+}
+
+/// Given an identity, when I add a key to it, then the proved keys of the identity are returned.
+#[tokio::test]
+async fn test_mock_update_identity() {
+    let mut sdk = Sdk::new_mock();
+    let platform_version = sdk.version();
+
+    let (master_key, master_private_key) =
+        IdentityPublicKey::random_ecdsa_master_authentication_key(0, Some(1), platform_version)
+            .expect("expected a master key");
+    let (new_key, new_private_key) =
+        IdentityPublicKey::random_ecdsa_critical_level_authentication_key(
+            1,
+            Some(2),
+            platform_version,
+        )
+        .expect("expected a new key");
+
+    let mut signer = SimpleSigner::default();
+    signer.add_key(master_key.clone(), master_private_key);
+    signer.add_key(new_key.clone(), new_private_key);
+
+    let identity = identity(1, vec![master_key.clone()], 1_000_000_000);
+
+    sdk.mock()
+        .expect_fetch(identity.id(), Some(IdentityNonceFetcher(0)))
+        .await
+        .expect("expected to add the nonce expectation");
+
+    let mut updated_identity = identity.clone();
+    updated_identity.bump_revision();
+
+    let state_transition = IdentityUpdateTransition::try_from_identity_with_signer(
+        &updated_identity,
+        &master_key.id(),
+        vec![new_key.clone()],
+        vec![],
+        1,
+        0,
+        &signer,
+        platform_version,
+        None,
+    )
+    .expect("expected an identity update transition");
+
+    let drive = setup_drive_with_initial_state_structure();
+    updated_identity.add_public_key(new_key.clone());
+    add_identity(&drive, updated_identity, platform_version);
+
+    let proof = prove(
+        &drive,
+        &[IdentityDriveQuery {
+            identity_id: identity.id().to_buffer(),
+            prove_request_type: IdentityProveRequestType::Keys,
+        }],
+        &[],
+        &[],
+        platform_version,
+    );
+
+    expect_state_transition(&mut sdk, &state_transition, proof).await;
+
+    let proved_identity = identity
+        .update_identity(
+            &sdk,
+            master_key.id(),
+            vec![new_key.clone()],
+            vec![],
+            signer,
+            None,
+        )
+        .await
+        .expect("expected the identity update to succeed");
+
+    assert_eq!(proved_identity.id, identity.id());
+    assert_eq!(
+        proved_identity.loaded_public_keys,
+        BTreeMap::from([(0, master_key), (1, new_key)])
+    );
+}
+
+/// Given a document with a bumped revision, when I replace it, then the proved document is
+/// returned.
+#[tokio::test]
+async fn test_mock_replace_document() {
+    let mut sdk = Sdk::new_mock();
+    let platform_version = sdk.version();
+
+    let data_contract = load_system_data_contract(SystemDataContract::Dashpay, platform_version)
+        .expect("expected to load the dashpay contract");
+    let owner_id = Identifier::new([1; 32]);
+    let (document_type, document) = profile(&data_contract, owner_id, platform_version);
+
+    let (key, private_key) = IdentityPublicKey::random_ecdsa_critical_level_authentication_key(
+        0,
+        Some(1),
+        platform_version,
+    )
+    .expect("expected a key");
+    let mut signer = SimpleSigner::default();
+    signer.add_key(key.clone(), private_key);
+
+    sdk.mock()
+        .expect_fetch(
+            (owner_id, data_contract.id()),
+            Some(IdentityContractNonceFetcher(0)),
+        )
+        .await
+        .expect("expected to add the nonce expectation");
+
+    let state_transition =
+        DocumentsBatchTransition::new_document_replacement_transition_from_document(
+            document.clone(),
+            document_type.as_ref(),
+            &key,
+            1,
+            0,
+            &signer,
+            platform_version,
+            None,
+            None,
+            None,
+        )
+        .expect("expected a replace transition");
+
+    let drive = setup_drive_with_initial_state_structure();
+    setup_system_data_contract(&drive, &data_contract, None);
+    setup_document(
+        &drive,
+        &document,
+        &data_contract,
+        document_type.as_ref(),
+        None,
+    );
+
+    let proof = prove(
+        &drive,
+        &[],
+        &[],
+        &[document_query(&document, &data_contract)],
+        platform_version,
+    );
+
+    expect_state_transition(&mut sdk, &state_transition, proof).await;
+
+    let proved_document = document
+        .replace_on_platform_and_wait_for_response(
+            &sdk,
+            document_type,
+            key,
+            Arc::new(data_contract),
+            &signer,
+        )
+        .await
+        .expect("expected the replace to succeed");
+
+    assert_eq!(proved_document.id(), document.id());
+    assert_eq!(proved_document.revision(), Some(2));
+    assert_eq!(proved_document.properties(), document.properties());
+}
+
+/// Given a document, when I delete it, then its absence is proved.
+#[tokio::test]
+async fn test_mock_delete_document() {
+    let mut sdk = Sdk::new_mock();
+    let platform_version = sdk.version();
+
+    let data_contract = load_system_data_contract(SystemDataContract::Dashpay, platform_version)
+        .expect("expected to load the dashpay contract");
+    let owner_id = Identifier::new([1; 32]);
+    let (document_type, document) = profile(&data_contract, owner_id, platform_version);
+
+    let (key, private_key) = IdentityPublicKey::random_ecdsa_critical_level_authentication_key(
+        0,
+        Some(1),
+        platform_version,
+    )
+    .expect("expected a key");
+    let mut signer = SimpleSigner::default();
+    signer.add_key(key.clone(), private_key);
+
+    sdk.mock()
+        .expect_fetch(
+            (owner_id, data_contract.id()),
+            Some(IdentityContractNonceFetcher(0)),
+        )
+        .await
+        .expect("expected to add the nonce expectation");
+
+    let state_transition =
+        DocumentsBatchTransition::new_document_deletion_transition_from_document(
+            document.clone(),
+            document_type.as_ref(),
+            &key,
+            1,
+            0,
+            &signer,
+            platform_version,
+            None,
+            None,
+            None,
+        )
+        .expect("expected a delete transition");
+
+    // The document is not stored, as it was deleted
+    let drive = setup_drive_with_initial_state_structure();
+    setup_system_data_contract(&drive, &data_contract, None);
+
+    let proof = prove(
+        &drive,
+        &[],
+        &[],
+        &[document_query(&document, &data_contract)],
+        platform_version,
+    );
+
+    expect_state_transition(&mut sdk, &state_transition, proof).await;
+
+    document
+        .delete_from_platform_and_wait_for_response(
+            &sdk,
+            document_type,
+            key,
+            Arc::new(data_contract),
+            &signer,
+        )
+        .await
+        .expect("expected the delete to succeed");
+}
+
+/// Given a document, when I delete it and the returned proof still contains the document, then
+/// the delete is rejected.
+#[tokio::test]
+async fn test_mock_delete_document_with_proof_of_existing_document() {
+    let mut sdk = Sdk::new_mock();
+    let platform_version = sdk.version();
+
+    let data_contract = load_system_data_contract(SystemDataContract::Dashpay, platform_version)
+        .expect("expected to load the dashpay contract");
+    let owner_id = Identifier::new([1; 32]);
+    let (document_type, document) = profile(&data_contract, owner_id, platform_version);
+
+    let (key, private_key) = IdentityPublicKey::random_ecdsa_critical_level_authentication_key(
+        0,
+        Some(1),
+        platform_version,
+    )
+    .expect("expected a key");
+    let mut signer = SimpleSigner::default();
+    signer.add_key(key.clone(), private_key);
+
+    sdk.mock()
+        .expect_fetch(
+            (owner_id, data_contract.id()),
+            Some(IdentityContractNonceFetcher(0)),
+        )
+        .await
+        .expect("expected to add the nonce expectation");
+
+    let state_transition =
+        DocumentsBatchTransition::new_document_deletion_transition_from_document(
+            document.clone(),
+            document_type.as_ref(),
+            &key,
+            1,
+            0,
+            &signer,
+            platform_version,
+            None,
+            None,
+            None,
+        )
+        .expect("expected a delete transition");
+
+    let drive = setup_drive_with_initial_state_structure();
+    setup_system_data_contract(&drive, &data_contract, None);
+    setup_document(
+        &drive,
+        &document,
+        &data_contract,
+        document_type.as_ref(),
+        None,
+    );
+
+    let proof = prove(
+        &drive,
+        &[],
+        &[],
+        &[document_query(&document, &data_contract)],
+        platform_version,
+    );
+
+    expect_state_transition(&mut sdk, &state_transition, proof).await;
+
+    let error = document
+        .delete_from_platform_and_wait_for_response(
+            &sdk,
+            document_type,
+            key,
+            Arc::new(data_contract),
+            &signer,
+        )
+        .await
+        .expect_err("expected the proof of an existing document to be rejected");
+
+    assert!(
+        matches!(error, Error::Drive(_)),
+        "unexpected error: {:?}",
+        error
+    );
+}
+
+/// Given a data contract with a bumped version, when I update it, then the proved data contract
+/// is returned.
+#[tokio::test]
+async fn test_mock_update_contract() {
+    let mut sdk = Sdk::new_mock();
+    let platform_version = sdk.version();
+
+    let mut data_contract =
+        load_system_data_contract(SystemDataContract::Dashpay, platform_version)
+            .expect("expected to load the dashpay contract");
+    data_contract.set_version(2);
+
+    let (key, private_key) = IdentityPublicKey::random_ecdsa_critical_level_authentication_key(
+        0,
+        Some(1),
+        platform_version,
+    )
+    .expect("expected a key");
+    let mut signer = SimpleSigner::default();
+    signer.add_key(key.clone(), private_key);
+
+    sdk.mock()
+        .expect_fetch(
+            (data_contract.owner_id(), data_contract.id()),
+            Some(IdentityContractNonceFetcher(0)),
+        )
+        .await
+        .expect("expected to add the nonce expectation");
+
+    let partial_identity = PartialIdentity {
+        id: data_contract.owner_id(),
+        loaded_public_keys: BTreeMap::from([(key.id(), key.clone())]),
+        balance: None,
+        revision: None,
+        not_found_public_keys: Default::default(),
+    };
+
+    let state_transition = DataContractUpdateTransition::new_from_data_contract(
+        data_contract.clone(),
+        &partial_identity,
+        key.id(),
+        1,
+        0,
+        &signer,
+        platform_version,
+        None,
+    )
+    .expect("expected a contract update transition");
+
+    let drive = setup_drive_with_initial_state_structure();
+    setup_system_data_contract(&drive, &data_contract, None);
+
+    let proof = prove(
+        &drive,
+        &[],
+        &[(data_contract.id().to_buffer(), None)],
+        &[],
+        platform_version,
+    );
+
+    expect_state_transition(&mut sdk, &state_transition, proof).await;
+
+    let proved_data_contract = data_contract
+        .update_on_platform_and_wait_for_response(&sdk, key, &signer)
+        .await
+        .expect("expected the contract update to succeed");
+
+    assert_eq!(proved_data_contract.id(), data_contract.id());
+    assert_eq!(proved_data_contract.version(), 2);
+}
@@ -0,0 +1,250 @@
+//! # Socket signer tests
+//!
+//! Tests of the socket signer against a signer running in a separate process.
+//!
+//! The signer process is this test binary started again with [SIGNER_SOCKET_ENV] set;
+//! in that case [signer_process] serves signatures instead of returning immediately.
+#![cfg(unix)]
+
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+use dash_sdk::platform::transition::signer::AsyncSigner;
+use dash_sdk::platform::transition::socket_signer::{
+    read_message, write_message, SignRequest, SignResponse, SocketSigner,
+};
+use dash_sdk::Error;
+use dpp::identity::signer::Signer;
+use dpp::identity::IdentityPublicKey;
+use dpp::prelude::Identifier;
+use dpp::state_transition::identity_credit_transfer_transition::v0::IdentityCreditTransferTransitionV0;
+use dpp::state_transition::StateTransition;
+use dpp::version::PlatformVersion;
+use simple_signer::signer::SimpleSigner;
+use tokio::net::UnixListener;
+
+/// Socket the signer process listens on; set only in the signer process
+const SIGNER_SOCKET_ENV: &str = "DASH_SDK_TEST_SIGNER_SOCKET";
+/// Seed of the only key held by the signer process
+const SIGNER_KEY_SEED_ENV: &str = "DASH_SDK_TEST_SIGNER_KEY_SEED";
+
+/// How long to wait for the signer process to start listening
+const SIGNER_START_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Entry point of the signer process.
+///
+/// Does nothing in a regular test run. When started by [SignerProcess], serves signatures
+/// with the key derived from [SIGNER_KEY_SEED_ENV] until killed.
+#[test]
+fn signer_process() {
+    let Ok(socket_path) = std::env::var(SIGNER_SOCKET_ENV) else {
+        return;
+    };
+
+    let seed = std::env::var(SIGNER_KEY_SEED_ENV)
+        .expect("signer key seed")
+        .parse()
+        .expect("signer key seed must be a number");
+    let (signer, _) = signer_with_key(seed);
+
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("signer process runtime")
+        .block_on(serve(&socket_path, signer));
+}
+
+/// Answer sign requests on `socket_path` with signatures of `signer`
+async fn serve(socket_path: &str, signer: SimpleSigner) {
+    let listener = UnixListener::bind(socket_path).expect("bind signer socket");
+
+    loop {
+        let Ok((mut stream, _)) = listener.accept().await else {
+            continue;
+        };
+
+        // Connections used to probe readiness are closed without a request
+        let Ok(request) = read_message::<_, SignRequest>(&mut stream).await else {
+            continue;
+        };
+
+        let response = match Signer::sign(&signer, &request.identity_public_key, &request.data) {
+            Ok(signature) => SignResponse::Signature(signature.to_vec()),
+            Err(e) => SignResponse::Refused(e.to_string()),
+        };
+
+        let _ = write_message(&mut stream, &response).await;
+    }
+}
+
+/// Signer running in a child process, killed on drop
+struct SignerProcess {
+    child: Child,
+    socket_path: PathBuf,
+}
+
+impl SignerProcess {
+    /// Start a signer process holding the key generated from `seed`
+    fn start(name: &str, seed: u64) -> Self {
+        let socket_path =
+            std::env::temp_dir().join(format!("dash-sdk-{}-{}.sock", name, std::process::id()));
+        let _ = std::fs::remove_file(&socket_path);
+
+        let child = Command::new(std::env::current_exe().expect("test binary path"))
+            .args([
+                "signer_process",
+                "--exact",
+                "--nocapture",
+                "--test-threads=1",
+            ])
+            .env(SIGNER_SOCKET_ENV, &socket_path)
+            .env(SIGNER_KEY_SEED_ENV, seed.to_string())
+            .stdout(Stdio::null())
+            .spawn()
+            .expect("start signer process");
+
+        let process = Self { child, socket_path };
+        process.wait_until_listening();
+
+        process
+    }
+
+    fn wait_until_listening(&self) {
+        let started = Instant::now();
+        while std::os::unix::net::UnixStream::connect(&self.socket_path).is_err() {
+            assert!(
+                started.elapsed() < SIGNER_START_TIMEOUT,
+                "signer process did not start listening on {}",
+                self.socket_path.display()
+            );
+            std::thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    fn socket_path(&self) -> &Path {
+        &self.socket_path
+    }
+}
+
+impl Drop for SignerProcess {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = std::fs::remove_file(&self.socket_path);
+    }
+}
+
+fn transfer_state_transition() -> StateTransition {
+    IdentityCreditTransferTransitionV0 {
+        identity_id: Identifier::new([1; 32]),
+        recipient_id: Identifier::new([2; 32]),
+        amount: 100_000,
+        nonce: 1,
+        ..Default::default()
+    }
+    .into()
+}
+
+fn signer_with_key(seed: u64) -> (SimpleSigner, IdentityPublicKey) {
+    let (identity_public_key, private_key) =
+        IdentityPublicKey::random_ecdsa_high_level_authentication_key(
+            1,
+            Some(seed),
+            PlatformVersion::latest(),
+        )
+        .expect("random key");
+
+    let mut signer = SimpleSigner::default();
+    signer.add_key(identity_public_key.clone(), private_key);
+
+    (signer, identity_public_key)
+}
+
+/// Given a signer process and a policy approving the transition,
+/// when I sign with the socket signer, then I get the signature of the signer process
+#[tokio::test]
+async fn test_socket_signer_signs_approved_transition() {
+    let (simple_signer, identity_public_key) = signer_with_key(1);
+    let data = b"signable bytes".to_vec();
+    let expected = Signer::sign(&simple_signer, &identity_public_key, &data).expect("sign locally");
+
+    let signer_process = SignerProcess::start("signer-approved", 1);
+    let signer = SocketSigner::new(signer_process.socket_path(), |state_transition, _| {
+        matches!(state_transition, StateTransition::IdentityCreditTransfer(_))
+    });
+
+    let signature = AsyncSigner::sign(
+        &signer,
+        &transfer_state_transition(),
+        &identity_public_key,
+        &data,
+    )
+    .await
+    .expect("signature from signer process");
+
+    assert_eq!(signature, expected);
+}
+
+/// Given a policy rejecting the transition, when I sign with the socket signer,
+/// then signing is refused
+#[tokio::test]
+async fn test_socket_signer_refuses_rejected_transition() {
+    let (_, identity_public_key) = signer_with_key(2);
+
+    let signer_process = SignerProcess::start("signer-rejected", 2);
+    let signer = SocketSigner::new(signer_process.socket_path(), |state_transition, _| {
+        matches!(state_transition, StateTransition::DocumentsBatch(_))
+    });
+
+    let result = AsyncSigner::sign(
+        &signer,
+        &transfer_state_transition(),
+        &identity_public_key,
+        b"signable bytes",
+    )
+    .await;
+
+    assert!(matches!(result, Err(Error::SigningRefused(_))));
+}
+
+/// Given a signer process not holding the key, when I sign with the socket signer,
+/// then the signer process refuses to sign
+#[tokio::test]
+async fn test_socket_signer_unknown_key() {
+    let (_, unknown_public_key) = signer_with_key(4);
+
+    let signer_process = SignerProcess::start("signer-unknown-key", 3);
+    let signer = SocketSigner::new(signer_process.socket_path(), |_, _| true);
+
+    let result = AsyncSigner::sign(
+        &signer,
+        &transfer_state_transition(),
+        &unknown_public_key,
+        b"signable bytes",
+    )
+    .await;
+
+    assert!(matches!(result, Err(Error::SigningRefused(_))));
+}
+
+/// Given no signer process listening, when I sign with the socket signer,
+/// then I get an error instead of a signature
+#[tokio::test]
+async fn test_socket_signer_process_gone() {
+    let (_, identity_public_key) = signer_with_key(5);
+
+    let signer_process = SignerProcess::start("signer-gone", 5);
+    let signer = SocketSigner::new(signer_process.socket_path(), |_, _| true);
+    drop(signer_process);
+
+    let result = AsyncSigner::sign(
+        &signer,
+        &transfer_state_transition(),
+        &identity_public_key,
+        b"signable bytes",
+    )
+    .await;
+
+    assert!(matches!(result, Err(Error::Generic(_))));
+}
@@ -683,8 +683,7 @@ pub fn create_identities_state_transitions(
     // Update keys with new KeyIDs and add them to signer
     let mut current_id_num = starting_id_num;
     for (key, _) in &mut keys {
-        let IdentityPublicKey::V0(ref mut id_pub_key_v0) = key;
-        id_pub_key_v0.set_id(current_id_num);
+        key.set_id(current_id_num);
         current_id_num += 1; // Increment for each key
     }
     signer.add_keys(keys);
@@ -704,9 +703,8 @@ pub fn create_identities_state_transitions(
                 .values_mut()
                 .enumerate()
                 .for_each(|(key_index, public_key)| {
-                    let IdentityPublicKey::V0(ref mut id_pub_key_v0) = public_key;
                     let new_id = identity_starting_id + key_index as u32;
-                    id_pub_key_v0.set_id(new_id);
+                    public_key.set_id(new_id);
                 });
 
             if let Some(proof_and_pk) = asset_lock_proofs.pop() {
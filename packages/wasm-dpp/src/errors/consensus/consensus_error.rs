@@ -54,16 +54,19 @@ use dpp::consensus::basic::BasicError::{
 };
 use dpp::consensus::basic::{BasicError, UnsupportedFeatureError};
 use dpp::consensus::fee::fee_error::FeeError;
-use dpp::consensus::signature::SignatureError;
+use dpp::consensus::signature::{
+    InvalidGuardianSignatureError, NotEnoughGuardianSignaturesError,
+    NotEnoughThresholdSignaturesError, PublicKeyIsNotValidAtTimeError, SignatureError,
+};
 use dpp::consensus::state::state_error::StateError;
 
 use dpp::consensus::state::data_trigger::DataTriggerError::{
   DataTriggerConditionError, DataTriggerExecutionError, DataTriggerInvalidResultError,
 };
 use wasm_bindgen::{JsError, JsValue};
-use dpp::consensus::basic::data_contract::{ContestedUniqueIndexOnMutableDocumentTypeError, ContestedUniqueIndexWithUniqueIndexError, InvalidDocumentTypeRequiredSecurityLevelError, UnknownDocumentCreationRestrictionModeError, UnknownSecurityLevelError, UnknownStorageKeyRequirementsError, UnknownTradeModeError, UnknownTransferableTypeError};
+use dpp::consensus::basic::data_contract::{AggregatedIndicesLimitReachedError, ContestedUniqueIndexOnMutableDocumentTypeError, ContestedUniqueIndexWithUniqueIndexError, InvalidDocumentTypeRequiredSecurityLevelError, UnknownDocumentCreationRestrictionModeError, UnknownSecurityLevelError, UnknownStorageKeyRequirementsError, UnknownTradeModeError, UnknownTransferableTypeError};
 use dpp::consensus::basic::document::{ContestedDocumentsTemporarilyNotAllowedError, DocumentCreationNotAllowedError, DocumentFieldMaxSizeExceededError, MaxDocumentsTransitionsExceededError, MissingPositionsInDocumentTypePropertiesError};
-use dpp::consensus::basic::identity::{DataContractBoundsNotPresentError, DisablingKeyIdAlsoBeingAddedInSameTransitionError, InvalidIdentityCreditWithdrawalTransitionAmountError, InvalidIdentityUpdateTransitionDisableKeysError, InvalidIdentityUpdateTransitionEmptyError, TooManyMasterPublicKeyError};
+use dpp::consensus::basic::identity::{DataContractBoundsNotPresentError, InvalidContractsOwnerKeyBoundsError, DisablingKeyIdAlsoBeingAddedInSameTransitionError, InvalidIdentityCreditWithdrawalTransitionAmountError, InvalidIdentityUpdateTransitionDisableKeysError, InvalidIdentityPublicKeySignatureThresholdError, InvalidIdentityPublicKeyValidityWindowError, InvalidIdentityRecoveryConfigError, InvalidIdentityRecoveryMasterKeyError, InvalidIdentityUpdateTransitionEmptyError, TooManyMasterPublicKeyError, InvalidIdentityCreditBatchTransferOutputsCountError, DuplicatedIdentityCreditTransferRecipientError, IdentityCreditTransferMemoTooLongError};
 use dpp::consensus::basic::overflow_error::OverflowError;
 use dpp::consensus::state::data_contract::document_type_update_error::DocumentTypeUpdateError;
 use dpp::consensus::state::document::document_contest_currently_locked_error::DocumentContestCurrentlyLockedError;
@@ -79,9 +82,30 @@ use dpp::consensus::state::voting::masternode_incorrect_voter_identity_id_error:
 use dpp::consensus::state::voting::masternode_incorrect_voting_address_error::MasternodeIncorrectVotingAddressError;
 use dpp::consensus::state::voting::masternode_not_found_error::MasternodeNotFoundError;
 use dpp::consensus::state::voting::masternode_vote_already_present_error::MasternodeVoteAlreadyPresentError;
+use dpp::consensus::state::data_contract::data_contract_is_archived_error::DataContractIsArchivedError;
+use dpp::consensus::state::data_contract::data_contract_is_not_deletable_error::DataContractIsNotDeletableError;
+use dpp::consensus::state::data_contract::data_contract_too_many_documents_to_delete_error::DataContractTooManyDocumentsToDeleteError;
+use dpp::consensus::state::document::document_offer_already_exists_error::DocumentOfferAlreadyExistsError;
+use dpp::consensus::state::document::document_offer_not_found_error::DocumentOfferNotFoundError;
+use dpp::consensus::state::document::document_offer_price_too_low_error::DocumentOfferPriceTooLowError;
+use dpp::consensus::state::identity::identity_recovery_already_pending_error::IdentityRecoveryAlreadyPendingError;
+use dpp::consensus::state::identity::identity_recovery_delay_not_elapsed_error::IdentityRecoveryDelayNotElapsedError;
+use dpp::consensus::state::identity::identity_recovery_guardian_not_found_error::IdentityRecoveryGuardianNotFoundError;
+use dpp::consensus::state::identity::identity_recovery_not_configured_error::IdentityRecoveryNotConfiguredError;
+use dpp::consensus::state::identity::no_pending_identity_recovery_error::NoPendingIdentityRecoveryError;
 use dpp::consensus::state::voting::masternode_voted_too_many_times::MasternodeVotedTooManyTimesError;
 use dpp::consensus::state::voting::vote_poll_not_available_for_voting_error::VotePollNotAvailableForVotingError;
 use dpp::consensus::state::voting::vote_poll_not_found_error::VotePollNotFoundError;
+use dpp::consensus::state::voting::governance_proposal_vote_poll_end_date_out_of_range_error::GovernanceProposalVotePollEndDateOutOfRangeError;
+use dpp::consensus::state::voting::governance_proposal_vote_poll_ended_error::GovernanceProposalVotePollEndedError;
+use dpp::consensus::state::voting::governance_proposal_vote_polls_opened_limit_reached_error::GovernanceProposalVotePollsOpenedLimitReachedError;
+use dpp::consensus::state::voting::masternode_not_evonode_error::MasternodeNotEvonodeError;
+use dpp::consensus::state::voting::vote_poll_resolved_by_auction_error::VotePollResolvedByAuctionError;
+use dpp::consensus::state::voting::vote_poll_not_resolved_by_auction_error::VotePollNotResolvedByAuctionError;
+use dpp::consensus::state::voting::auction_bid_outside_of_window_error::AuctionBidOutsideOfWindowError;
+use dpp::consensus::state::voting::auction_bidder_not_contender_error::AuctionBidderNotContenderError;
+use dpp::consensus::state::voting::auction_bid_already_committed_error::AuctionBidAlreadyCommittedError;
+use dpp::consensus::state::voting::invalid_auction_bid_reveal_error::InvalidAuctionBidRevealError;
 
 use crate::errors::consensus::basic::data_contract::{
     DataContractErrorWasm, DataContractHaveNewUniqueIndexErrorWasm,
@@ -286,12 +310,75 @@ pub fn from_state_error(state_error: &StateError) -> JsValue {
         StateError::MasternodeVoteAlreadyPresentError(e) => {
             generic_consensus_error!(MasternodeVoteAlreadyPresentError, e).into()
         }
+        StateError::DataContractIsArchivedError(e) => {
+            generic_consensus_error!(DataContractIsArchivedError, e).into()
+        }
+        StateError::DataContractIsNotDeletableError(e) => {
+            generic_consensus_error!(DataContractIsNotDeletableError, e).into()
+        }
+        StateError::DataContractTooManyDocumentsToDeleteError(e) => {
+            generic_consensus_error!(DataContractTooManyDocumentsToDeleteError, e).into()
+        }
+        StateError::DocumentOfferAlreadyExistsError(e) => {
+            generic_consensus_error!(DocumentOfferAlreadyExistsError, e).into()
+        }
+        StateError::DocumentOfferNotFoundError(e) => {
+            generic_consensus_error!(DocumentOfferNotFoundError, e).into()
+        }
+        StateError::DocumentOfferPriceTooLowError(e) => {
+            generic_consensus_error!(DocumentOfferPriceTooLowError, e).into()
+        }
+        StateError::IdentityRecoveryNotConfiguredError(e) => {
+            generic_consensus_error!(IdentityRecoveryNotConfiguredError, e).into()
+        }
+        StateError::IdentityRecoveryAlreadyPendingError(e) => {
+            generic_consensus_error!(IdentityRecoveryAlreadyPendingError, e).into()
+        }
+        StateError::NoPendingIdentityRecoveryError(e) => {
+            generic_consensus_error!(NoPendingIdentityRecoveryError, e).into()
+        }
+        StateError::IdentityRecoveryDelayNotElapsedError(e) => {
+            generic_consensus_error!(IdentityRecoveryDelayNotElapsedError, e).into()
+        }
+        StateError::IdentityRecoveryGuardianNotFoundError(e) => {
+            generic_consensus_error!(IdentityRecoveryGuardianNotFoundError, e).into()
+        }
         StateError::MasternodeIncorrectVotingAddressError(e) => {
             generic_consensus_error!(MasternodeIncorrectVotingAddressError, e).into()
         }
         StateError::MasternodeIncorrectVoterIdentityIdError(e) => {
             generic_consensus_error!(MasternodeIncorrectVoterIdentityIdError, e).into()
         }
+        StateError::MasternodeNotEvonodeError(e) => {
+            generic_consensus_error!(MasternodeNotEvonodeError, e).into()
+        }
+        StateError::GovernanceProposalVotePollEndDateOutOfRangeError(e) => {
+            generic_consensus_error!(GovernanceProposalVotePollEndDateOutOfRangeError, e).into()
+        }
+        StateError::GovernanceProposalVotePollEndedError(e) => {
+            generic_consensus_error!(GovernanceProposalVotePollEndedError, e).into()
+        }
+        StateError::VotePollResolvedByAuctionError(e) => {
+            generic_consensus_error!(VotePollResolvedByAuctionError, e).into()
+        }
+        StateError::VotePollNotResolvedByAuctionError(e) => {
+            generic_consensus_error!(VotePollNotResolvedByAuctionError, e).into()
+        }
+        StateError::AuctionBidOutsideOfWindowError(e) => {
+            generic_consensus_error!(AuctionBidOutsideOfWindowError, e).into()
+        }
+        StateError::AuctionBidderNotContenderError(e) => {
+            generic_consensus_error!(AuctionBidderNotContenderError, e).into()
+        }
+        StateError::AuctionBidAlreadyCommittedError(e) => {
+            generic_consensus_error!(AuctionBidAlreadyCommittedError, e).into()
+        }
+        StateError::InvalidAuctionBidRevealError(e) => {
+            generic_consensus_error!(InvalidAuctionBidRevealError, e).into()
+        }
+        StateError::GovernanceProposalVotePollsOpenedLimitReachedError(e) => {
+            generic_consensus_error!(GovernanceProposalVotePollsOpenedLimitReachedError, e).into()
+        }
     }
 }
 
@@ -548,6 +635,33 @@ fn from_basic_error(basic_error: &BasicError) -> JsValue {
         BasicError::ContestedDocumentsTemporarilyNotAllowedError(e) => {
             generic_consensus_error!(ContestedDocumentsTemporarilyNotAllowedError, e).into()
         }
+        BasicError::AggregatedIndicesLimitReachedError(e) => {
+            generic_consensus_error!(AggregatedIndicesLimitReachedError, e).into()
+        }
+        BasicError::InvalidIdentityPublicKeyValidityWindowError(e) => {
+            generic_consensus_error!(InvalidIdentityPublicKeyValidityWindowError, e).into()
+        }
+        BasicError::InvalidIdentityPublicKeySignatureThresholdError(e) => {
+            generic_consensus_error!(InvalidIdentityPublicKeySignatureThresholdError, e).into()
+        }
+        BasicError::InvalidContractsOwnerKeyBoundsError(e) => {
+            generic_consensus_error!(InvalidContractsOwnerKeyBoundsError, e).into()
+        }
+        BasicError::InvalidIdentityRecoveryConfigError(e) => {
+            generic_consensus_error!(InvalidIdentityRecoveryConfigError, e).into()
+        }
+        BasicError::InvalidIdentityRecoveryMasterKeyError(e) => {
+            generic_consensus_error!(InvalidIdentityRecoveryMasterKeyError, e).into()
+        }
+        BasicError::InvalidIdentityCreditBatchTransferOutputsCountError(e) => {
+            generic_consensus_error!(InvalidIdentityCreditBatchTransferOutputsCountError, e).into()
+        }
+        BasicError::DuplicatedIdentityCreditTransferRecipientError(e) => {
+            generic_consensus_error!(DuplicatedIdentityCreditTransferRecipientError, e).into()
+        }
+        BasicError::IdentityCreditTransferMemoTooLongError(e) => {
+            generic_consensus_error!(IdentityCreditTransferMemoTooLongError, e).into()
+        }
     }
 }
 
@@ -581,6 +695,18 @@ fn from_signature_error(signature_error: &SignatureError) -> JsValue {
         SignatureError::InvalidSignaturePublicKeyPurposeError(err) => {
             InvalidSignaturePublicKeyPurposeErrorWasm::from(err).into()
         }
+        SignatureError::PublicKeyIsNotValidAtTimeError(err) => {
+            generic_consensus_error!(PublicKeyIsNotValidAtTimeError, err).into()
+        }
+        SignatureError::NotEnoughThresholdSignaturesError(err) => {
+            generic_consensus_error!(NotEnoughThresholdSignaturesError, err).into()
+        }
+        SignatureError::InvalidGuardianSignatureError(err) => {
+            generic_consensus_error!(InvalidGuardianSignatureError, err).into()
+        }
+        SignatureError::NotEnoughGuardianSignaturesError(err) => {
+            generic_consensus_error!(NotEnoughGuardianSignaturesError, err).into()
+        }
     }
 }
 
@@ -124,6 +124,14 @@ impl IdentityPublicKeyWithWitnessWasm {
         self.0.set_contract_bounds(Some(contract_bounds))
     }
 
+    #[wasm_bindgen(js_name=setContractsOwnerBounds)]
+    pub fn set_contracts_owner_bounds(&mut self, owner_id: IdentifierWrapper) {
+        self.0
+            .set_contract_bounds(Some(ContractBounds::MultipleContractsOfSameOwner {
+                owner_id: owner_id.into(),
+            }))
+    }
+
     #[wasm_bindgen(js_name=getSecurityLevel)]
     pub fn get_security_level(&self) -> u8 {
         self.0.security_level() as u8
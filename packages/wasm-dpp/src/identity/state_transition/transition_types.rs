@@ -13,6 +13,10 @@ pub enum StateTransitionTypeWasm {
     IdentityCreditWithdrawal = 6,
     IdentityCreditTransfer = 7,
     MasternodeVote = 8,
+    DataContractDelete = 9,
+    IdentityRecovery = 10,
+    IdentityCreditBatchTransfer = 11,
+    AuctionBid = 12,
 }
 
 impl From<StateTransitionType> for StateTransitionTypeWasm {
@@ -31,6 +35,12 @@ impl From<StateTransitionType> for StateTransitionTypeWasm {
                 StateTransitionTypeWasm::IdentityCreditTransfer
             }
             StateTransitionType::MasternodeVote => StateTransitionTypeWasm::MasternodeVote,
+            StateTransitionType::DataContractDelete => StateTransitionTypeWasm::DataContractDelete,
+            StateTransitionType::IdentityRecovery => StateTransitionTypeWasm::IdentityRecovery,
+            StateTransitionType::IdentityCreditBatchTransfer => {
+                StateTransitionTypeWasm::IdentityCreditBatchTransfer
+            }
+            StateTransitionType::AuctionBid => StateTransitionTypeWasm::AuctionBid,
         }
     }
 }
@@ -12,7 +12,7 @@ use dpp::state_transition::errors::StateTransitionError;
 use dpp::state_transition::state_transition_factory::StateTransitionFactory;
 use dpp::state_transition::StateTransition;
 use dpp::ProtocolError;
-use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+use wasm_bindgen::{prelude::wasm_bindgen, JsError, JsValue};
 
 #[wasm_bindgen(js_name = StateTransitionFactory)]
 pub struct StateTransitionFactoryWasm(StateTransitionFactory);
@@ -62,6 +62,21 @@ impl StateTransitionFactoryWasm {
                 StateTransition::MasternodeVote(st) => {
                     Ok(MasternodeVoteTransitionWasm::from(st).into())
                 }
+                StateTransition::DataContractDelete(_) => Err(JsError::new(
+                    "DataContractDelete state transition is not supported yet",
+                )
+                .into()),
+                StateTransition::IdentityRecovery(_) => Err(JsError::new(
+                    "IdentityRecovery state transition is not supported yet",
+                )
+                .into()),
+                StateTransition::IdentityCreditBatchTransfer(_) => Err(JsError::new(
+                    "IdentityCreditBatchTransfer state transition is not supported yet",
+                )
+                .into()),
+                StateTransition::AuctionBid(_) => {
+                    Err(JsError::new("AuctionBid state transition is not supported yet").into())
+                }
             },
             Err(dpp::ProtocolError::StateTransitionError(e)) => match e {
                 StateTransitionError::InvalidStateTransitionError {
@@ -273,7 +273,9 @@ impl MasternodeVoteTransitionWasm {
 
                     Some(js_object)
                 }
+                VotePoll::GovernanceProposalVotePoll(_) => None,
             },
+            Vote::GovernanceProposalVote(_) => None,
         }
     }
 